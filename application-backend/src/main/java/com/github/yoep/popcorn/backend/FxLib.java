@@ -1,7 +1,11 @@
 package com.github.yoep.popcorn.backend;
 
+import com.github.yoep.popcorn.backend.adapters.platform.PlatformInfo;
+import com.github.yoep.popcorn.backend.adapters.screen.DisplayInfo;
 import com.github.yoep.popcorn.backend.adapters.screen.FullscreenCallback;
 import com.github.yoep.popcorn.backend.adapters.screen.IsFullscreenCallback;
+import com.github.yoep.popcorn.backend.adapters.screen.MoveToDisplayCallback;
+import com.github.yoep.popcorn.backend.adapters.screen.ScreenEventCallback;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentFileInfoWrapper;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentInfoWrapper;
 import com.github.yoep.popcorn.backend.adapters.torrent.state.TorrentState;
@@ -13,23 +17,31 @@ import com.github.yoep.popcorn.backend.lib.FxLibInstance;
 import com.github.yoep.popcorn.backend.lib.FxStringArray;
 import com.github.yoep.popcorn.backend.loader.LoaderEventC;
 import com.github.yoep.popcorn.backend.loader.LoaderEventCallback;
+import com.github.yoep.popcorn.backend.loader.LoadingHandleSet;
+import com.github.yoep.popcorn.backend.loader.LoadingPolicy;
 import com.github.yoep.popcorn.backend.logging.LogLevel;
+import com.github.yoep.popcorn.backend.logging.LogRecordCallback;
 import com.github.yoep.popcorn.backend.media.*;
 import com.github.yoep.popcorn.backend.media.favorites.FavoriteEventCallback;
+import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.github.yoep.popcorn.backend.media.filters.model.Genre;
 import com.github.yoep.popcorn.backend.media.filters.model.SortBy;
 import com.github.yoep.popcorn.backend.media.providers.Episode;
 import com.github.yoep.popcorn.backend.media.providers.MovieDetails;
+import com.github.yoep.popcorn.backend.media.providers.ProviderConnectionResultSet;
 import com.github.yoep.popcorn.backend.media.providers.ShowDetails;
+import com.github.yoep.popcorn.backend.media.resume.ContinueWatchingSet;
 import com.github.yoep.popcorn.backend.media.tracking.AuthorizationOpenCallback;
 import com.github.yoep.popcorn.backend.media.tracking.TrackingEventC;
 import com.github.yoep.popcorn.backend.media.tracking.TrackingEventCallback;
 import com.github.yoep.popcorn.backend.media.watched.WatchedEventCallback;
 import com.github.yoep.popcorn.backend.player.*;
 import com.github.yoep.popcorn.backend.playlists.Playlist;
+import com.github.yoep.popcorn.backend.playlists.PlaylistItem;
 import com.github.yoep.popcorn.backend.playlists.PlaylistManagerCallback;
 import com.github.yoep.popcorn.backend.playlists.PlaylistManagerEvent;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfigEventCallback;
+import com.github.yoep.popcorn.backend.settings.SettingsImportResult;
 import com.github.yoep.popcorn.backend.settings.models.*;
 import com.github.yoep.popcorn.backend.settings.models.subtitles.SubtitleLanguage;
 import com.github.yoep.popcorn.backend.subtitles.Subtitle;
@@ -38,8 +50,13 @@ import com.github.yoep.popcorn.backend.subtitles.model.SubtitleInfo;
 import com.github.yoep.popcorn.backend.subtitles.model.SubtitleInfoSet;
 import com.github.yoep.popcorn.backend.subtitles.model.SubtitleMatcher;
 import com.github.yoep.popcorn.backend.torrent.*;
+import com.github.yoep.popcorn.backend.torrent.collection.CollectionSort;
+import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrent;
 import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrentSet;
+import com.github.yoep.popcorn.backend.torrent.collection.TorrentCollectionCallback;
 import com.github.yoep.popcorn.backend.updater.UpdateCallback;
+import com.github.yoep.popcorn.backend.updater.UpdateChannel;
+import com.github.yoep.popcorn.backend.updater.UpdateChannelSet;
 import com.github.yoep.popcorn.backend.updater.UpdateState;
 import com.github.yoep.popcorn.backend.updater.VersionInfo;
 import com.sun.jna.Library;
@@ -102,6 +119,8 @@ public interface FxLib extends Library {
 
     void cleanup_subtitles_directory(PopcornFx instance);
 
+    void clear_subtitle_cache(PopcornFx instance);
+
     String download(PopcornFx instance, SubtitleInfo subtitle, SubtitleMatcher.ByValue matcher);
 
     Subtitle download_and_parse_subtitle(PopcornFx instance, SubtitleInfo subtitle, SubtitleMatcher.ByValue matcher);
@@ -112,12 +131,30 @@ public interface FxLib extends Library {
 
     void reset_movie_apis(PopcornFx instance);
 
+    void invalidate_provider_cache(PopcornFx instance);
+
+    byte is_category_supported(PopcornFx instance, Category category);
+
+    FxStringArray retrieve_available_genres(PopcornFx instance, Category category);
+
+    FxStringArray retrieve_available_sort_options(PopcornFx instance, Category category);
+
+    FxStringArray retrieve_provider_uris(PopcornFx instance, Category category);
+
+    byte update_provider_uris(PopcornFx instance, Category category, int len, StringArray uris);
+
+    void reset_provider_uris(PopcornFx instance, Category category);
+
+    ProviderConnectionResultSet.ByValue test_provider_connection(PopcornFx instance, Category category);
+
     MediaSetResult.ByValue retrieve_available_shows(PopcornFx instance, Genre genre, SortBy sort, String keywords, int page);
 
     void reset_show_apis(PopcornFx instance);
 
     FavoritesSet retrieve_available_favorites(PopcornFx instance, Genre genre, SortBy sort, String keywords, int page);
 
+    MediaSetResult.ByValue retrieve_favorites(PopcornFx instance, Genre genre, SortBy sort, String keywords, int page);
+
     MediaResult.ByValue retrieve_media_details(PopcornFx instance, MediaItem media);
 
     byte is_media_liked(PopcornFx instance, MediaItem.ByReference media);
@@ -138,6 +175,8 @@ public interface FxLib extends Library {
 
     void remove_from_watched(PopcornFx instance, MediaItem media);
 
+    ContinueWatchingSet.ByValue continue_watching(PopcornFx instance, int limit);
+
     void register_watched_event_callback(PopcornFx instance, WatchedEventCallback callback);
 
     void torrent_resolve_info_callback(PopcornFx instance, ResolveTorrentInfoCallback callback);
@@ -146,20 +185,32 @@ public interface FxLib extends Library {
 
     void torrent_cancel_callback(PopcornFx instance, CancelTorrentCallback callback);
 
+    void torrent_pause_callback(PopcornFx instance, PauseTorrentCallback callback);
+
     Long register_torrent_stream_event_callback(PopcornFx instance, Long streamHandle, TorrentStreamEventCallback callback);
 
     void remove_torrent_stream_event_callback(PopcornFx instance, Long streamHandle, Long callbackHandle);
 
+    ByteRangeSet.ByValue torrent_stream_available_ranges(PopcornFx instance, Long streamHandle);
+
+    Long register_torrent_event_callback(PopcornFx instance, String handle, TorrentEventCallback callback);
+
+    void remove_torrent_event_callback(PopcornFx instance, String handle, Long callbackHandle);
+
     void torrent_state_changed(PopcornFx instance, String handle, TorrentState state);
 
     void torrent_piece_finished(PopcornFx instance, String handle, int piece);
 
     void torrent_download_status(PopcornFx instance, String handle, DownloadStatusC.ByValue downloadStatus);
 
+    void torrent_write_error(PopcornFx instance, String handle, String error);
+
     void publish_event(PopcornFx instance, EventC.ByValue event);
 
     void register_event_callback(PopcornFx instance, EventBridgeCallback callback);
 
+    void register_event_subscriber(PopcornFx instance, EventSubscriberCallback callback, long lastSequence);
+
     byte torrent_collection_is_stored(PopcornFx instance, String magnetUrl);
 
     StoredTorrentSet torrent_collection_all(PopcornFx instance);
@@ -168,8 +219,24 @@ public interface FxLib extends Library {
 
     void torrent_collection_remove(PopcornFx instance, String magnetUrl);
 
+    StoredTorrentSet torrent_collection_search(PopcornFx instance, String query, CollectionSort sort);
+
+    StoredTorrent.ByReference torrent_collection_resolve(PopcornFx instance, String magnetUrl);
+
+    void torrent_collection_mark_used(PopcornFx instance, String magnetUrl);
+
+    void register_torrent_collection_callback(PopcornFx instance, TorrentCollectionCallback callback);
+
     void cleanup_torrents_directory(PopcornFx instance);
 
+    void pause_all_torrents(PopcornFx instance);
+
+    void resume_all_torrents(PopcornFx instance);
+
+    byte is_torrents_paused(PopcornFx instance);
+
+    TorrentSummarySet.ByValue active_torrents(PopcornFx instance);
+
     ApplicationSettings application_settings(PopcornFx instance);
 
     void reload_settings(PopcornFx instance);
@@ -186,6 +253,16 @@ public interface FxLib extends Library {
 
     void update_playback_settings(PopcornFx instance, PlaybackSettings.ByValue settings);
 
+    void update_network_settings(PopcornFx instance, NetworkSettings.ByValue settings);
+
+    byte export_settings(PopcornFx instance, String path);
+
+    SettingsImportResult.ByValue import_settings(PopcornFx instance, String path);
+
+    void reset_settings_section(PopcornFx instance, SettingsSection section);
+
+    void dispose_settings_import_result(SettingsImportResult.ByValue result);
+
     byte is_mouse_disabled(PopcornFx instance);
 
     byte is_tv_mode(PopcornFx instance);
@@ -202,6 +279,8 @@ public interface FxLib extends Library {
 
     VersionInfo version_info(PopcornFx instance);
 
+    PlatformInfo.ByReference retrieve_platform_info(PopcornFx instance);
+
     UpdateState update_state(PopcornFx instance);
 
     void check_for_updates(PopcornFx instance);
@@ -210,6 +289,14 @@ public interface FxLib extends Library {
 
     void install_update(PopcornFx instance);
 
+    UpdateChannel update_channel(PopcornFx instance);
+
+    UpdateChannelSet.ByValue available_update_channels(PopcornFx instance);
+
+    void set_update_channel(PopcornFx instance, UpdateChannel channel);
+
+    void rollback_update(PopcornFx instance);
+
     void register_update_callback(PopcornFx instance, UpdateCallback callback);
 
     FxStringArray retrieve_provider_genres(PopcornFx instance, String name);
@@ -228,6 +315,12 @@ public interface FxLib extends Library {
 
     ByteArray load_image(PopcornFx instance, String url);
 
+    ByteArray load_image_resized(PopcornFx instance, String url, int width, int height);
+
+    ImageCacheStats.ByValue image_cache_stats(PopcornFx instance);
+
+    void clear_image_cache(PopcornFx instance);
+
     Long play_playlist(PopcornFx instance, Playlist.ByValue set);
 
     void register_playlist_manager_callback(PopcornFx instance, PlaylistManagerCallback callback);
@@ -236,6 +329,12 @@ public interface FxLib extends Library {
 
     void stop_playlist(PopcornFx instance);
 
+    byte move_playlist_item(PopcornFx instance, int from, int to);
+
+    byte remove_playlist_item(PopcornFx instance, int index);
+
+    void insert_playlist_item(PopcornFx instance, int index, PlaylistItem.ByValue item);
+
     Playlist.ByValue playlist(PopcornFx instance);
 
     PlayerWrapper active_player(PopcornFx instance);
@@ -270,11 +369,31 @@ public interface FxLib extends Library {
 
     Long loader_load_torrent_file(PopcornFx instance, TorrentInfoWrapper.ByValue torrentInfo, TorrentFileInfoWrapper.ByValue torrentFile);
 
+    Long loader_load_torrent_file_index(PopcornFx instance, TorrentInfoWrapper.ByValue torrentInfo, int fileIndex);
+
+    Long loader_download_torrent_file(PopcornFx instance, TorrentInfoWrapper.ByValue torrentInfo, TorrentFileInfoWrapper.ByValue torrentFile);
+
     void loader_cancel(PopcornFx instance, Long handle);
 
+    void loader_set_policy(PopcornFx instance, LoadingPolicy policy);
+
+    Long loader_active_task(PopcornFx instance);
+
+    LoadingHandleSet.ByValue loader_queued_tasks(PopcornFx instance);
+
     void register_is_fullscreen_callback(PopcornFx instance, IsFullscreenCallback callback);
 
     void register_fullscreen_callback(PopcornFx instance, FullscreenCallback callback);
+
+    void register_move_to_display_callback(PopcornFx instance, MoveToDisplayCallback callback);
+
+    void register_screen_event_callback(PopcornFx instance, ScreenEventCallback callback);
+
+    void update_screen_displays(PopcornFx instance, DisplayInfo.ByReference displays, int len);
+
+    void set_fullscreen(PopcornFx instance, byte fullscreen);
+
+    byte move_to_display(PopcornFx instance, int id);
     
     void register_tracking_authorization_open(PopcornFx instance, AuthorizationOpenCallback callback);
 
@@ -290,6 +409,12 @@ public interface FxLib extends Library {
 
     void log(String target, String message, LogLevel level);
 
+    byte set_log_level(PopcornFx instance, String target, LogLevel level);
+
+    void register_log_record_callback(PopcornFx instance, LogRecordCallback callback);
+
+    void unregister_log_record_callback(PopcornFx instance);
+
     void dispose_subtitle_info_set(SubtitleInfoSet.ByReference set);
 
     void dispose_subtitle_info(SubtitleInfo.ByReference info);
@@ -304,6 +429,8 @@ public interface FxLib extends Library {
 
     void dispose_torrent_collection(StoredTorrentSet set);
 
+    void dispose_magnet_info(StoredTorrent.ByReference info);
+
     void dispose_byte_array(ByteArray byteArray);
 
     void dispose_string_array(FxStringArray array);
@@ -312,6 +439,12 @@ public interface FxLib extends Library {
 
     void dispose_favorites(FavoritesSet favorites);
 
+    void dispose_continue_watching_set(ContinueWatchingSet set);
+
+    void dispose_provider_connection_results(ProviderConnectionResultSet set);
+
+    void dispose_platform_info(PlatformInfo info);
+
     void dispose_player_manager_event(PlayerManagerEvent.ByValue event);
 
     void dispose_player_pointer(PlayerWrapperPointer ptr);
@@ -322,12 +455,20 @@ public interface FxLib extends Library {
 
     void dispose_loader_event_value(LoaderEventC.ByValue event);
 
+    void dispose_loading_handle_set(LoadingHandleSet set);
+
     void dispose_playlist_manager_event_value(PlaylistManagerEvent.ByValue event);
 
     void dispose_torrent_stream_event_value(TorrentStreamEventC.ByValue event);
 
+    void dispose_torrent_summary_set(TorrentSummarySet set);
+
+    void dispose_byte_range_set(ByteRangeSet set);
+
     void dispose_tracking_event_value(TrackingEventC.ByValue event);
 
+    void dispose_update_channel_set(UpdateChannelSet set);
+
     void dispose_popcorn_fx(PopcornFx instance);
 
     String version();
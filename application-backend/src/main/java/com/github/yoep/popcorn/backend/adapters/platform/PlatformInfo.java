@@ -0,0 +1,56 @@
+package com.github.yoep.popcorn.backend.adapters.platform;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.sun.jna.Structure;
+import com.sun.jna.ptr.LongByReference;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Optional;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"platformType", "cpuCores", "totalMemory", "availableMemory", "dataFreeSpace", "torrentFreeSpace", "crashCount"})
+public class PlatformInfo extends Structure implements Closeable {
+    public static class ByReference extends PlatformInfo implements Structure.ByReference {
+    }
+
+    public PlatformType platformType;
+    public int cpuCores;
+    public long totalMemory;
+    public long availableMemory;
+    public LongByReference dataFreeSpace;
+    public LongByReference torrentFreeSpace;
+    public int crashCount;
+
+    /**
+     * Retrieve the free disk space of the application data directory.
+     *
+     * @return Returns the free space in bytes if known, else {@link Optional#empty()}.
+     */
+    public Optional<Long> getDataFreeSpace() {
+        return Optional.ofNullable(dataFreeSpace)
+                .map(LongByReference::getValue);
+    }
+
+    /**
+     * Retrieve the free disk space of the torrent directory.
+     *
+     * @return Returns the free space in bytes if known, else {@link Optional#empty()}.
+     */
+    public Optional<Long> getTorrentFreeSpace() {
+        return Optional.ofNullable(torrentFreeSpace)
+                .map(LongByReference::getValue);
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        FxLib.INSTANCE.get().dispose_platform_info(this);
+    }
+}
@@ -0,0 +1,26 @@
+package com.github.yoep.popcorn.backend.adapters.platform;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+public enum PlatformType implements NativeMapped {
+    WINDOWS,
+    MACOS,
+    LINUX;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
@@ -116,6 +116,16 @@ public interface Player {
      */
     void volume(int volume);
 
+    /**
+     * Select the audio track of the current playback.
+     * Players which don't support audio track selection can ignore this invocation.
+     *
+     * @param id The unique identifier of the audio track to select.
+     */
+    default void audioTrack(int id) {
+        // no-op
+    }
+
     /**
      * Retrieve the current volume level of the player.
      *
@@ -0,0 +1,36 @@
+package com.github.yoep.popcorn.backend.adapters.screen;
+
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"id", "name", "x", "y", "width", "height", "primary"})
+public class DisplayInfo extends Structure implements Closeable {
+    public static class ByReference extends DisplayInfo implements Structure.ByReference {
+    }
+
+    public int id;
+    public String name;
+    public int x;
+    public int y;
+    public int width;
+    public int height;
+    public byte primary;
+
+    public boolean isPrimary() {
+        return primary == 1;
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.adapters.screen;
+
+import com.sun.jna.Callback;
+
+public interface MoveToDisplayCallback extends Callback {
+    void callback(int displayId);
+}
@@ -0,0 +1,113 @@
+package com.github.yoep.popcorn.backend.adapters.screen;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+import com.sun.jna.Structure;
+import com.sun.jna.Union;
+import lombok.EqualsAndHashCode;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.Optional;
+
+@Getter
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"tag", "union"})
+public class ScreenEventC extends Structure implements Closeable {
+    public static class ByValue extends ScreenEventC implements Structure.ByValue {
+    }
+
+    public Tag tag;
+    public ScreenEventCUnion union;
+
+    @Override
+    public void read() {
+        super.read();
+        updateUnionType();
+        union.read();
+    }
+
+    void updateUnionType() {
+        switch (tag) {
+            case FULLSCREEN_CHANGED -> union.setType(FullscreenChanged_Body.class);
+            case DISPLAY_CHANGED -> union.setType(DisplayChanged_Body.class);
+        }
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        getUnion().close();
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"fullscreen"})
+    public static class FullscreenChanged_Body extends Structure implements Closeable {
+        public byte fullscreen;
+
+        public boolean isFullscreen() {
+            return fullscreen == 1;
+        }
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"displayId"})
+    public static class DisplayChanged_Body extends Structure implements Closeable {
+        public int displayId;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
+    @Getter
+    @ToString
+    @EqualsAndHashCode(callSuper = false)
+    public static class ScreenEventCUnion extends Union implements Closeable {
+        public FullscreenChanged_Body fullscreenChanged_body;
+        public DisplayChanged_Body displayChanged_body;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            Optional.ofNullable(fullscreenChanged_body)
+                    .ifPresent(FullscreenChanged_Body::close);
+            Optional.ofNullable(displayChanged_body)
+                    .ifPresent(DisplayChanged_Body::close);
+        }
+    }
+
+    public enum Tag implements NativeMapped {
+        FULLSCREEN_CHANGED,
+        DISPLAY_CHANGED;
+
+        @Override
+        public Object fromNative(Object nativeValue, FromNativeContext context) {
+            return Arrays.stream(values())
+                    .filter(e -> e.ordinal() == (int) nativeValue)
+                    .findFirst()
+                    .orElse(null);
+        }
+
+        @Override
+        public Object toNative() {
+            return ordinal();
+        }
+
+        @Override
+        public Class<?> nativeType() {
+            return Integer.class;
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.adapters.screen;
+
+import com.sun.jna.Callback;
+
+public interface ScreenEventCallback extends Callback {
+    void callback(ScreenEventC.ByValue event);
+}
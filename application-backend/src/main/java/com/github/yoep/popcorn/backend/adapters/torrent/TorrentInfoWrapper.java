@@ -2,6 +2,7 @@ package com.github.yoep.popcorn.backend.adapters.torrent;
 
 import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentFileInfo;
 import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentInfo;
+import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentVersion;
 import com.sun.jna.Structure;
 import lombok.EqualsAndHashCode;
 import lombok.Getter;
@@ -17,7 +18,7 @@ import java.util.stream.Collectors;
 @Getter
 @ToString
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"magnetUri", "name", "directoryName", "totalFiles", "files"})
+@Structure.FieldOrder({"magnetUri", "name", "directoryName", "totalFiles", "files", "version"})
 public class TorrentInfoWrapper extends Structure implements TorrentInfo, Closeable {
     public static class ByValue extends TorrentInfoWrapper implements Structure.ByValue {
         public ByValue() {
@@ -42,6 +43,7 @@ public class TorrentInfoWrapper extends Structure implements TorrentInfo, Closea
     public String directoryName;
     public int totalFiles;
     public TorrentFileInfoSet.ByValue files;
+    public TorrentVersion version;
 
     private TorrentInfo info;
 
@@ -57,6 +59,7 @@ public class TorrentInfoWrapper extends Structure implements TorrentInfo, Closea
         this.files = new TorrentFileInfoSet.ByValue(info.getFiles().stream()
                 .map(e -> new TorrentFileInfoWrapper.ByReference(info, e))
                 .collect(Collectors.toList()));
+        this.version = info.getVersion();
         this.info = info;
         write();
     }
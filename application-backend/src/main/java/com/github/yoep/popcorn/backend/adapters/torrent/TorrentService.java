@@ -111,4 +111,23 @@ public interface TorrentService {
      * This will remove all torrents from the system.
      */
     void cleanup();
+
+    /**
+     * Pause all torrents at once.
+     * Torrents which are created while paused will start in a paused state.
+     */
+    void pauseAll();
+
+    /**
+     * Resume all torrents which have been paused through {@link #pauseAll()}.
+     * Torrents which have been paused individually remain paused.
+     */
+    void resumeAll();
+
+    /**
+     * Verify if all torrents have been paused.
+     *
+     * @return Returns true when all torrents are paused, else false.
+     */
+    boolean isPaused();
 }
@@ -1,5 +1,10 @@
 package com.github.yoep.popcorn.backend.adapters.torrent;
 
+import com.github.yoep.popcorn.backend.settings.models.EncryptionMode;
+
+import java.net.InetAddress;
+import java.nio.file.Path;
+
 /**
  * Service managing the torrent session settings.
  */
@@ -26,4 +31,99 @@ public interface TorrentSettingsService {
      * @param uploadRateLimit The upload rate limit in bytes per second.
      */
     TorrentSettingsService uploadRateLimit(int uploadRateLimit);
+
+    /**
+     * Bind all torrent traffic of the session to the given local address.
+     * When the interface of the address becomes unavailable, new connections will fail
+     * instead of falling back to another interface.
+     *
+     * @param address The local address to bind to, or null to use any interface.
+     */
+    TorrentSettingsService bindInterface(InetAddress address);
+
+    /**
+     * Update the encryption mode (MSE/PE) of the peer connections.
+     *
+     * @param encryptionMode The encryption mode to apply to the peer connections.
+     */
+    TorrentSettingsService encryptionMode(EncryptionMode encryptionMode);
+
+    /**
+     * Update the timeout for establishing a connection with a peer.
+     * The timeout is clamped between 1 and 120 seconds.
+     *
+     * @param peerConnectTimeout The peer connect timeout in seconds.
+     */
+    TorrentSettingsService peerConnectTimeout(int peerConnectTimeout);
+
+    /**
+     * Update if torrents are announced to all their trackers and tiers at once,
+     * instead of only to the first tracker which responds.
+     *
+     * @param announceToAllTrackers Indicates if all trackers should be announced to.
+     */
+    TorrentSettingsService announceToAllTrackers(boolean announceToAllTrackers);
+
+    /**
+     * Update the timeout after which a requested block which hasn't been received
+     * is cancelled and requested from another peer.
+     *
+     * @param requestTimeout The block request timeout in seconds.
+     */
+    TorrentSettingsService requestTimeout(int requestTimeout);
+
+    /**
+     * Block the peer ip ranges from the given blocklist file for both inbound and outbound connections.
+     * Invalid lines within the blocklist are skipped.
+     *
+     * @param path The path to the blocklist file, or null to allow all peers.
+     */
+    TorrentSettingsService ipFilter(Path path);
+
+    /**
+     * Update if the files of newly added torrents are fully allocated on disk,
+     * instead of being allocated as sparse files.
+     *
+     * @param preallocate Indicates if the torrent files should be preallocated.
+     */
+    TorrentSettingsService preallocate(boolean preallocate);
+
+    /**
+     * Verify if the files of newly added torrents are fully allocated on disk.
+     *
+     * @return Returns true when the torrent files are preallocated, else false.
+     */
+    boolean isPreallocate();
+
+    /**
+     * Update if the DHT node of the session runs in read-only mode (BEP43).
+     * A read-only node still looks up peers for its own torrents, but doesn't serve other nodes.
+     *
+     * @param dhtReadOnly Indicates if the DHT node should be read-only.
+     */
+    TorrentSettingsService dhtReadOnly(boolean dhtReadOnly);
+
+    /**
+     * Update the max size of the torrent metadata which is accepted from peers when resolving a magnet.
+     * Peers which advertise a larger metadata size are rejected.
+     *
+     * @param maxMetadataSize The max metadata size in bytes.
+     */
+    TorrentSettingsService maxMetadataSize(int maxMetadataSize);
+
+    /**
+     * Update the max number of outstanding block requests which are pipelined to a single peer.
+     * The effective depth of a peer adapts to its download rate and never exceeds this max.
+     *
+     * @param maxRequestQueueDepth The max request queue depth of a peer.
+     */
+    TorrentSettingsService maxRequestQueueDepth(int maxRequestQueueDepth);
+
+    /**
+     * Update if the session listens on both IPv4 and IPv6 when it's not bound to a specific interface.
+     * The DHT runs on each listen socket, so IPv6 peers are only found through the DHT when listening on IPv6.
+     *
+     * @param dualStack Indicates if the session should listen on both IPv4 and IPv6.
+     */
+    TorrentSettingsService dualStack(boolean dualStack);
 }
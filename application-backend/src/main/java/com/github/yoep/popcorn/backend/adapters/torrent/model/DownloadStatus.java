@@ -42,4 +42,13 @@ public interface DownloadStatus {
      * torrent size in case any pieces are prioritized to 0, i.e. not wanted.
      */
     long totalSize();
+
+    /**
+     * The effective block request queue depth, which is the average number of outstanding block requests
+     * that are pipelined to each connected peer. It adapts to the download rate of the peers.
+     * Returns 0 when the depth is unknown.
+     */
+    default int requestQueueDepth() {
+        return 0;
+    }
 }
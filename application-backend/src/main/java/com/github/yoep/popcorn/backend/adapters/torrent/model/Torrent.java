@@ -39,6 +39,14 @@ public interface Torrent {
      */
     String getFilename();
 
+    /**
+     * Get the info hash of the torrent.
+     * This is the hex encoded info hash as known by the torrent session.
+     *
+     * @return Returns the info hash of the torrent.
+     */
+    String getInfoHash();
+
     /**
      * Get the file of this torrent.
      *
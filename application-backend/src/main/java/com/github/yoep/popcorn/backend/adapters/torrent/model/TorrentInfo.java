@@ -44,6 +44,15 @@ public interface TorrentInfo extends Serializable {
      */
     List<TorrentFileInfo> getFiles();
 
+    /**
+     * Get the BitTorrent protocol version of the torrent.
+     *
+     * @return The protocol version of the torrent, being v1, v2 or hybrid.
+     */
+    default TorrentVersion getVersion() {
+        return TorrentVersion.V1;
+    }
+
     /**
      * Get the largest torrent file contained within this torrent.
      *
@@ -0,0 +1,38 @@
+package com.github.yoep.popcorn.backend.adapters.torrent.model;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+/**
+ * The BitTorrent protocol version of a torrent.
+ */
+public enum TorrentVersion implements NativeMapped {
+    /**
+     * The torrent only contains v1 (SHA-1) piece hashes.
+     */
+    V1,
+    /**
+     * The torrent only contains v2 (SHA-256) merkle trees.
+     */
+    V2,
+    /**
+     * The torrent contains both v1 piece hashes and v2 merkle trees.
+     */
+    HYBRID;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
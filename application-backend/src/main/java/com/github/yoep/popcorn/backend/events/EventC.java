@@ -85,6 +85,9 @@ public class EventC extends Structure implements Closeable {
             case CLOSE_PLAYER -> {
                 return new ClosePlayerEvent(this, ClosePlayerEvent.Reason.END_OF_VIDEO);
             }
+            case ERROR -> {
+                return new ErrorNotificationEvent(this, union.getError_body().getMessage());
+            }
             default -> {
                 log.error("Failed to create ApplicationEvent from {}", this);
                 return null;
@@ -98,6 +101,7 @@ public class EventC extends Structure implements Closeable {
             case PLAYBACK_STATE_CHANGED -> union.setType(PlaybackState_Body.class);
             case WATCH_STATE_CHANGED -> union.setType(WatchStateChanged_Body.class);
             case TORRENT_DETAILS_LOADED -> union.setType(TorrentDetailsLoaded_Body.class);
+            case ERROR -> union.setType(Error_Body.class);
         }
     }
 
@@ -158,6 +162,18 @@ public class EventC extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"message"})
+    public static class Error_Body extends Structure implements Closeable {
+        public String message;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
     @Getter
     @ToString
     @EqualsAndHashCode(callSuper = false)
@@ -169,6 +185,7 @@ public class EventC extends Structure implements Closeable {
         public PlaybackState_Body playbackState_body;
         public WatchStateChanged_Body watchStateChanged_body;
         public TorrentDetailsLoaded_Body torrentDetailsLoaded_body;
+        public Error_Body error_body;
 
         @Override
         public void close() {
@@ -181,6 +198,8 @@ public class EventC extends Structure implements Closeable {
                     .ifPresent(WatchStateChanged_Body::close);
             Optional.ofNullable(torrentDetailsLoaded_body)
                     .ifPresent(TorrentDetailsLoaded_Body::close);
+            Optional.ofNullable(error_body)
+                    .ifPresent(Error_Body::close);
         }
     }
 
@@ -193,7 +212,8 @@ public class EventC extends Structure implements Closeable {
         LOADING_STARTED,
         LOADING_COMPLETED,
         TORRENT_DETAILS_LOADED,
-        CLOSE_PLAYER;
+        CLOSE_PLAYER,
+        ERROR;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
@@ -4,14 +4,17 @@ import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
 import lombok.extern.slf4j.Slf4j;
 
+import java.util.concurrent.atomic.AtomicLong;
+
 /**
  * The FFI bridge for all events that can be thrown across the application.
  */
 @Slf4j
-public class EventPublisherBridge implements EventBridgeCallback {
+public class EventPublisherBridge implements EventSubscriberCallback {
     private final EventPublisher eventPublisher;
     private final FxLib fxLib;
     private final PopcornFx instance;
+    private final AtomicLong lastSequence = new AtomicLong();
 
     public EventPublisherBridge(EventPublisher eventPublisher, FxLib fxLib, PopcornFx instance) {
         this.eventPublisher = eventPublisher;
@@ -34,13 +37,18 @@ public class EventPublisherBridge implements EventBridgeCallback {
             return event;
         }, EventPublisher.HIGHEST_ORDER);
 
-        log.debug("Registering event bridge callback");
-        fxLib.register_event_callback(instance, this);
+        log.debug("Registering event bridge subscriber");
+        fxLib.register_event_subscriber(instance, this, lastSequence.get());
     }
 
     @Override
-    public void callback(EventC.ByValue event) {
+    public void callback(EventC.ByValue event, long sequence, byte replayed) {
         try (event) {
+            if (replayed == 1) {
+                log.debug("Received replayed EventC {} with sequence {}", event.getTag(), sequence);
+            }
+            lastSequence.set(sequence);
+
             switch (event.getTag()) {
                 case PLAYER_CHANGED,
                         PLAYER_STARTED,
@@ -48,7 +56,8 @@ public class EventPublisherBridge implements EventBridgeCallback {
                         LOADING_STARTED,
                         LOADING_COMPLETED,
                         TORRENT_DETAILS_LOADED,
-                        CLOSE_PLAYER -> eventPublisher.publish(event.toEvent());
+                        CLOSE_PLAYER,
+                        ERROR -> eventPublisher.publish(event.toEvent());
                 default -> log.warn("EventC callback of {} is currently not yet supported", event.getTag());
             }
         } catch (Exception ex) {
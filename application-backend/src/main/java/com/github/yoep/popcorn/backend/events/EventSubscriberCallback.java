@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.events;
+
+import com.sun.jna.Callback;
+
+public interface EventSubscriberCallback extends Callback {
+    void callback(EventC.ByValue event, long sequence, byte replayed);
+}
@@ -47,6 +47,8 @@ public class LoaderEventC extends Structure implements Closeable {
             case STATE_CHANGED -> union.setType(StateChanged_Body.class);
             case PROGRESS_CHANGED -> union.setType(ProgressChanged_Body.class);
             case LOADING_ERROR -> union.setType(LoadingError_Body.class);
+            case STAGE_PROGRESS_CHANGED -> union.setType(StageProgressChanged_Body.class);
+            case QUALITY_SELECTED -> union.setType(QualitySelected_Body.class);
         }
     }
 
@@ -106,6 +108,36 @@ public class LoaderEventC extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"handle", "stageProgress"})
+    public static class StageProgressChanged_Body extends Structure implements Closeable {
+        public Long handle;
+        public LoadingStageProgressC.ByValue stageProgress;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            Optional.ofNullable(stageProgress)
+                    .ifPresent(LoadingStageProgressC::close);
+        }
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"handle", "qualitySelection"})
+    public static class QualitySelected_Body extends Structure implements Closeable {
+        public Long handle;
+        public QualitySelectionC.ByValue qualitySelection;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            Optional.ofNullable(qualitySelection)
+                    .ifPresent(QualitySelectionC::close);
+        }
+    }
+
     @Getter
     @ToString
     @EqualsAndHashCode(callSuper = false)
@@ -117,6 +149,8 @@ public class LoaderEventC extends Structure implements Closeable {
         public StateChanged_Body stateChanged_body;
         public ProgressChanged_Body progressChanged_body;
         public LoadingError_Body loadingError_body;
+        public StageProgressChanged_Body stageProgressChanged_body;
+        public QualitySelected_Body qualitySelected_body;
 
         @Override
         public void close() {
@@ -129,6 +163,10 @@ public class LoaderEventC extends Structure implements Closeable {
                     .ifPresent(ProgressChanged_Body::close);
             Optional.ofNullable(loadingError_body)
                     .ifPresent(LoadingError_Body::close);
+            Optional.ofNullable(stageProgressChanged_body)
+                    .ifPresent(StageProgressChanged_Body::close);
+            Optional.ofNullable(qualitySelected_body)
+                    .ifPresent(QualitySelected_Body::close);
         }
     }
 
@@ -136,7 +174,9 @@ public class LoaderEventC extends Structure implements Closeable {
         LOADING_STARTED,
         STATE_CHANGED,
         PROGRESS_CHANGED,
-        LOADING_ERROR;
+        LOADING_ERROR,
+        STAGE_PROGRESS_CHANGED,
+        QUALITY_SELECTED;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
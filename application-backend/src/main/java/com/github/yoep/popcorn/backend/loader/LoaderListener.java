@@ -6,6 +6,24 @@ public interface LoaderListener {
     void onStateChanged(LoaderState newState);
 
     void onProgressChanged(LoadingProgress progress);
+
+    /**
+     * Invoked when the progress of a specific loading stage has changed.
+     *
+     * @param progress The progress of the loading stage.
+     */
+    default void onStageProgressChanged(LoadingStageProgressC progress) {
+        // no-op
+    }
+
+    /**
+     * Invoked when the quality of the media item has been selected, e.g. "Auto (1080p)".
+     *
+     * @param selection The selected quality and the reasons for the selection.
+     */
+    default void onQualitySelected(QualitySelectionC selection) {
+        // no-op
+    }
     
     void onError(LoadingErrorC error);
 }
@@ -13,7 +13,9 @@ import lombok.EqualsAndHashCode;
 import lombok.ToString;
 import lombok.extern.slf4j.Slf4j;
 
+import java.util.List;
 import java.util.Objects;
+import java.util.Optional;
 
 @Slf4j
 @ToString
@@ -46,10 +48,78 @@ public class LoaderService extends AbstractListenerService<LoaderListener> imple
         }
     }
 
+    /**
+     * Load the file of the given torrent by its index within the torrent, e.g. an episode of a season pack.
+     *
+     * @param torrentInfo The torrent info which contains the file.
+     * @param fileIndex   The index of the file within the torrent.
+     * @return Returns true when the loading process has been started, else false when the file index is invalid.
+     */
+    public boolean load(TorrentInfo torrentInfo, int fileIndex) {
+        Objects.requireNonNull(torrentInfo, "torrentInfo cannot be null");
+        try (var infoWrapper = new TorrentInfoWrapper.ByValue(torrentInfo)) {
+            var handle = fxLib.loader_load_torrent_file_index(instance, infoWrapper, fileIndex);
+            if (handle == null || handle == 0L) {
+                log.warn("Unable to load file index {} of torrent {}", fileIndex, torrentInfo.getName());
+                return false;
+            }
+
+            lastLoaderHandle = handle;
+            return true;
+        }
+    }
+
+    /**
+     * Download the given torrent file in the background without streaming or playing it.
+     * The torrent file can be loaded afterwards, which reuses the already downloaded pieces.
+     *
+     * @param torrentInfo     The torrent info of the file.
+     * @param torrentFileInfo The torrent file to download.
+     */
+    public void download(TorrentInfo torrentInfo, TorrentFileInfo torrentFileInfo) {
+        Objects.requireNonNull(torrentInfo, "torrentInfo cannot be null");
+        try (var infoWrapper = new TorrentInfoWrapper.ByValue(torrentInfo)) {
+            try (var fileWrapper = new TorrentFileInfoWrapper.ByValue(torrentInfo, torrentFileInfo)) {
+                fxLib.loader_download_torrent_file(instance, infoWrapper, fileWrapper);
+            }
+        }
+    }
+
     public void cancel() {
         fxLib.loader_cancel(instance, lastLoaderHandle);
     }
 
+    /**
+     * Update the policy which is applied when a new media item is loaded while another item is still loading.
+     *
+     * @param policy The new loading policy.
+     */
+    public void setPolicy(LoadingPolicy policy) {
+        Objects.requireNonNull(policy, "policy cannot be null");
+        fxLib.loader_set_policy(instance, policy);
+    }
+
+    /**
+     * Get the handle of the loading task which is currently being processed.
+     *
+     * @return Returns the handle of the active loading task, or empty when no media item is being loaded.
+     */
+    public Optional<Long> getActiveTask() {
+        return Optional.ofNullable(fxLib.loader_active_task(instance))
+                .filter(e -> e != 0L);
+    }
+
+    /**
+     * Get the handles of the loading tasks which are waiting for the active loading task to complete.
+     *
+     * @return Returns the queued handles in the order in which they will be started.
+     */
+    public List<Long> getQueuedTasks() {
+        try (var set = fxLib.loader_queued_tasks(instance)) {
+            return set.getItems();
+        }
+    }
+
     @Override
     public void callback(LoaderEventC.ByValue event) {
         try (event) {
@@ -72,6 +142,14 @@ public class LoaderService extends AbstractListenerService<LoaderListener> imple
                     var progressChangedBody = event.getUnion().getProgressChanged_body();
                     invokeListeners(e -> e.onProgressChanged(progressChangedBody.getLoadingProgress()));
                 }
+                case STAGE_PROGRESS_CHANGED -> {
+                    var stageProgressChangedBody = event.getUnion().getStageProgressChanged_body();
+                    invokeListeners(e -> e.onStageProgressChanged(stageProgressChangedBody.getStageProgress()));
+                }
+                case QUALITY_SELECTED -> {
+                    var qualitySelectedBody = event.getUnion().getQualitySelected_body();
+                    invokeListeners(e -> e.onQualitySelected(qualitySelectedBody.getQualitySelection()));
+                }
             }
         } catch (Exception ex) {
             log.error("An unexpected error occurred while handling the loader event C, {}", ex.getMessage(), ex);
@@ -0,0 +1,47 @@
+package com.github.yoep.popcorn.backend.loader;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.sun.jna.Pointer;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"items", "len"})
+public class LoadingHandleSet extends Structure implements Closeable {
+    public static class ByValue extends LoadingHandleSet implements Structure.ByValue {
+    }
+
+    public Pointer items;
+    public int len;
+
+    private List<Long> cachedItems;
+
+    public List<Long> getItems() {
+        return Optional.ofNullable(cachedItems)
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void read() {
+        super.read();
+        cachedItems = Optional.ofNullable(items)
+                .map(e -> Arrays.stream(e.getLongArray(0, len)).boxed().toList())
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        FxLib.INSTANCE.get().dispose_loading_handle_set(this);
+    }
+}
@@ -0,0 +1,38 @@
+package com.github.yoep.popcorn.backend.loader;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+/**
+ * The policy which is applied when a new media item is loaded while another item is still loading.
+ */
+public enum LoadingPolicy implements NativeMapped {
+    /**
+     * Start the new load right away, next to the other loading tasks.
+     */
+    CONCURRENT,
+    /**
+     * Cancel the active and queued loading tasks in favor of the new load.
+     */
+    CANCEL_ACTIVE,
+    /**
+     * Queue the new load behind the active and already queued loading tasks.
+     */
+    QUEUE;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
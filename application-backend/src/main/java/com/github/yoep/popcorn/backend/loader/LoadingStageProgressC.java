@@ -0,0 +1,31 @@
+package com.github.yoep.popcorn.backend.loader;
+
+import com.sun.jna.Pointer;
+import com.sun.jna.Structure;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Optional;
+
+@Getter
+@ToString
+@Structure.FieldOrder({"stage", "percent", "detail"})
+public class LoadingStageProgressC extends Structure implements Closeable {
+    public static class ByValue extends LoadingStageProgressC implements Structure.ByValue {
+    }
+
+    public LoaderState stage;
+    public float percent;
+    public Pointer detail;
+
+    public Optional<String> getDetail() {
+        return Optional.ofNullable(detail)
+                .map(e -> e.getString(0));
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
@@ -0,0 +1,40 @@
+package com.github.yoep.popcorn.backend.loader;
+
+import com.sun.jna.Pointer;
+import com.sun.jna.Structure;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.List;
+import java.util.Optional;
+
+@Getter
+@ToString
+@Structure.FieldOrder({"quality", "autoSelected", "label", "reasons"})
+public class QualitySelectionC extends Structure implements Closeable {
+    public static class ByValue extends QualitySelectionC implements Structure.ByValue {
+    }
+
+    public String quality;
+    public byte autoSelected;
+    public String label;
+    public Pointer reasons;
+
+    public boolean isAutoSelected() {
+        return autoSelected == 1;
+    }
+
+    public List<String> getReasons() {
+        return Optional.ofNullable(reasons)
+                .map(e -> e.getString(0))
+                .map(e -> Arrays.asList(e.split("\n")))
+                .orElse(List.of());
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
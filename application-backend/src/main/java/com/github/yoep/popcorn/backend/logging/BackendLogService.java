@@ -0,0 +1,88 @@
+package com.github.yoep.popcorn.backend.logging;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.github.yoep.popcorn.backend.PopcornFx;
+import lombok.extern.slf4j.Slf4j;
+
+import java.util.Objects;
+import java.util.concurrent.ConcurrentLinkedDeque;
+import java.util.function.Consumer;
+
+/**
+ * The backend log service controls the log levels of the backend modules at runtime
+ * and streams the backend log records to the frontend, e.g. for a debug panel.
+ */
+@Slf4j
+public class BackendLogService {
+    private final FxLib fxLib;
+    private final PopcornFx instance;
+
+    private final LogRecordCallback callback = createCallback();
+    private final ConcurrentLinkedDeque<Consumer<LogRecord>> listeners = new ConcurrentLinkedDeque<>();
+
+    public BackendLogService(FxLib fxLib, PopcornFx instance) {
+        this.fxLib = fxLib;
+        this.instance = instance;
+    }
+
+    //region Methods
+
+    /**
+     * Set the log level of the given backend module.
+     *
+     * @param target The module path, e.g. {@code popcorn_fx_torrent}.
+     * @param level  The new log level of the module.
+     * @return Returns true when the log level has been changed, else false.
+     */
+    public boolean setLogLevel(String target, LogLevel level) {
+        Objects.requireNonNull(target, "target cannot be null");
+        Objects.requireNonNull(level, "level cannot be null");
+        log.debug("Updating backend log level of {} to {}", target, level);
+        return fxLib.set_log_level(instance, target, level) == 1;
+    }
+
+    /**
+     * Register a new listener for the backend log records.
+     * The backend is subscribed to when the first listener is registered,
+     * after which the most recent backend log records are replayed.
+     *
+     * @param listener The listener which receives the backend log records.
+     */
+    public void addListener(Consumer<LogRecord> listener) {
+        Objects.requireNonNull(listener, "listener cannot be null");
+        synchronized (listeners) {
+            listeners.add(listener);
+            if (listeners.size() == 1) {
+                fxLib.register_log_record_callback(instance, callback);
+            }
+        }
+    }
+
+    /**
+     * Remove the given listener of the backend log records.
+     * The backend is unsubscribed from when no listeners remain.
+     *
+     * @param listener The listener to remove.
+     */
+    public void removeListener(Consumer<LogRecord> listener) {
+        synchronized (listeners) {
+            if (listeners.remove(listener) && listeners.isEmpty()) {
+                fxLib.unregister_log_record_callback(instance);
+            }
+        }
+    }
+
+    //endregion
+
+    private LogRecordCallback createCallback() {
+        return record -> {
+            for (var listener : listeners) {
+                try {
+                    listener.accept(record);
+                } catch (Exception ex) {
+                    log.error("Failed to invoke backend log listener, {}", ex.getMessage(), ex);
+                }
+            }
+        };
+    }
+}
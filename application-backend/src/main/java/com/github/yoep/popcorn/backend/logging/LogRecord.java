@@ -0,0 +1,38 @@
+package com.github.yoep.popcorn.backend.logging;
+
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+
+/**
+ * A log record of the backend which has been forwarded to the frontend.
+ */
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"timestamp", "level", "target", "message"})
+public class LogRecord extends Structure implements Closeable {
+    public static class ByValue extends LogRecord implements Structure.ByValue {
+    }
+
+    /**
+     * The unix timestamp in millis at which the record has been logged.
+     */
+    public long timestamp;
+    public LogLevel level;
+    /**
+     * The backend module which logged the record.
+     */
+    public String target;
+    public String message;
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.logging;
+
+import com.sun.jna.Callback;
+
+public interface LogRecordCallback extends Callback {
+    void callback(LogRecord.ByValue record);
+}
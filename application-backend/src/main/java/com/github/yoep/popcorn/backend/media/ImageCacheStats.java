@@ -0,0 +1,29 @@
+package com.github.yoep.popcorn.backend.media;
+
+import com.sun.jna.Structure;
+import lombok.*;
+
+import java.io.Closeable;
+
+@Getter
+@ToString
+@NoArgsConstructor
+@AllArgsConstructor
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"entries", "size", "maxSize", "hits", "misses", "revalidations"})
+public class ImageCacheStats extends Structure implements Closeable {
+    public static class ByValue extends ImageCacheStats implements Structure.ByValue {
+    }
+
+    public int entries;
+    public long size;
+    public long maxSize;
+    public long hits;
+    public long misses;
+    public long revalidations;
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
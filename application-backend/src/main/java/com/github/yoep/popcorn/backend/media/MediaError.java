@@ -8,13 +8,21 @@ import java.util.Arrays;
 public enum MediaError implements NativeMapped {
     Failed,
     NoItemsFound,
-    NoAvailableProviders;
+    NoAvailableProviders,
+    ProviderConnectionFailed,
+    ProviderRequestFailed,
+    ProviderParsingFailed,
+    MediaTypeNotSupported;
 
     public String getMessage() {
         return switch (this) {
             case Failed -> "Failed to retrieve media information";
             case NoItemsFound -> "No media items could be found";
             case NoAvailableProviders -> "No providers are available for retrieving information";
+            case ProviderConnectionFailed -> "Failed to connect to the media provider";
+            case ProviderRequestFailed -> "The media provider request failed";
+            case ProviderParsingFailed -> "Failed to parse the media provider response";
+            case MediaTypeNotSupported -> "The media type is not supported";
         };
     }
 
@@ -2,15 +2,17 @@ package com.github.yoep.popcorn.backend.media.providers;
 
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
-import com.github.yoep.popcorn.backend.media.FavoritesSet;
 import com.github.yoep.popcorn.backend.media.MediaItem;
 import com.github.yoep.popcorn.backend.media.MediaResult;
+import com.github.yoep.popcorn.backend.media.MediaSet;
+import com.github.yoep.popcorn.backend.media.MediaSetResult;
 import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.github.yoep.popcorn.backend.media.filters.model.Genre;
 import com.github.yoep.popcorn.backend.media.filters.model.SortBy;
 import lombok.RequiredArgsConstructor;
 import lombok.extern.slf4j.Slf4j;
 
+import java.util.ArrayList;
 import java.util.Collections;
 import java.util.List;
 import java.util.Optional;
@@ -65,10 +67,27 @@ public class FavoriteProviderService implements ProviderService<Media> {
     }
 
     private List<Media> doInternalPageRetrieval(Genre genre, SortBy sortBy, String keywords, int page) {
-        try (var favorites = fxLib.retrieve_available_favorites(instance, genre, sortBy, keywords, page)) {
-            return Optional.ofNullable(favorites)
-                    .map(FavoritesSet::<Media>getAll)
-                    .orElse(Collections.emptyList());
+        try (var mediaResult = fxLib.retrieve_favorites(instance, genre, sortBy, keywords, page)) {
+            if (mediaResult.getTag() == MediaSetResult.Tag.Ok) {
+                var mediaSet = Optional.ofNullable(mediaResult.getUnion())
+                        .map(MediaSetResult.MediaSetResultUnion::getOk)
+                        .map(MediaSetResult.OkBody::getMediaSet);
+                var favorites = new ArrayList<Media>();
+                mediaSet.map(MediaSet::getMovies).ifPresent(favorites::addAll);
+                mediaSet.map(MediaSet::getShows).ifPresent(favorites::addAll);
+                log.debug("Retrieved favorites {}", favorites);
+
+                return favorites;
+            } else {
+                var mediaError = mediaResult.getUnion().getErr().getMediaError();
+                switch (mediaError) {
+                    case NoAvailableProviders -> throw new MediaRetrievalException(mediaError.getMessage());
+                    case NoItemsFound -> {
+                        return Collections.emptyList();
+                    }
+                    default -> throw new MediaException(mediaError.getMessage());
+                }
+            }
         }
     }
 }
@@ -0,0 +1,42 @@
+package com.github.yoep.popcorn.backend.media.providers;
+
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Optional;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"uri", "latency", "error"})
+public class ProviderConnectionResult extends Structure implements Closeable {
+    public static class ByReference extends ProviderConnectionResult implements Structure.ByReference {
+    }
+
+    public String uri;
+    public long latency;
+    public String error;
+
+    /**
+     * Verify if the connection test of the provider uri succeeded.
+     *
+     * @return Returns true when the uri could be reached, else false.
+     */
+    public boolean isSuccess() {
+        return error == null;
+    }
+
+    public Optional<String> getError() {
+        return Optional.ofNullable(error);
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
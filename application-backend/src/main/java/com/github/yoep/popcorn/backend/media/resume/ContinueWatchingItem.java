@@ -0,0 +1,45 @@
+package com.github.yoep.popcorn.backend.media.resume;
+
+import com.github.yoep.popcorn.backend.media.MediaItem;
+import com.github.yoep.popcorn.backend.media.providers.Media;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Optional;
+
+@Data
+@ToString(of = {"media", "progress"})
+@EqualsAndHashCode(exclude = {"parentMedia", "media"}, callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"media", "parentMedia", "progress", "timestamp"})
+public class ContinueWatchingItem extends Structure implements Closeable {
+    public static class ByReference extends ContinueWatchingItem implements Structure.ByReference {
+    }
+
+    public MediaItem.ByReference media;
+    public MediaItem.ByReference parentMedia;
+    public float progress;
+    public Long timestamp;
+
+    public Media getMedia() {
+        return media.getMedia();
+    }
+
+    public Optional<Media> getParentMedia() {
+        return Optional.ofNullable(parentMedia)
+                .map(MediaItem::getMedia);
+    }
+
+    public Optional<Long> getTimestamp() {
+        return Optional.ofNullable(timestamp);
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
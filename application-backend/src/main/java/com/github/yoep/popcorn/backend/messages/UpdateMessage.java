@@ -14,6 +14,7 @@ public enum UpdateMessage implements Message {
     STARTING_DOWNLOAD("update_state_starting_download"),
     DOWNLOADING("update_state_downloading"),
     DOWNLOAD_FINISHED("update_state_download_finished"),
+    VERIFYING("update_state_verifying"),
     INSTALLING("update_state_installing"),
     ERROR("update_state_error");
 
@@ -12,7 +12,7 @@ import java.util.Optional;
 
 @Getter
 @ToString
-@Structure.FieldOrder({"url", "title", "caption", "thumb", "background", "quality", "autoResumeTimestamp", "streamHandle", "subtitlesEnabled"})
+@Structure.FieldOrder({"url", "title", "caption", "thumb", "background", "quality", "autoResumeTimestamp", "streamHandle", "subtitlesEnabled", "subtitleUrl", "audioTracks"})
 public class PlayRequestWrapper extends Structure implements Closeable, PlayRequest {
     public static class ByValue extends PlayRequestWrapper implements Structure.ByValue {
     }
@@ -26,6 +26,8 @@ public class PlayRequestWrapper extends Structure implements Closeable, PlayRequ
     public Pointer autoResumeTimestamp;
     public Pointer streamHandle;
     public byte subtitlesEnabled;
+    public Pointer subtitleUrl;
+    public Pointer audioTracks;
 
     private String cachedCaption;
     private String cachedThumb;
@@ -33,6 +35,7 @@ public class PlayRequestWrapper extends Structure implements Closeable, PlayRequ
     private String cachedQuality;
     private Long cachedAutoResumeTimestamp;
     private Handle cachedStreamHandle;
+    private String cachedSubtitleUrl;
 
     public boolean isSubtitlesEnabled() {
         return subtitlesEnabled == 1;
@@ -68,6 +71,10 @@ public class PlayRequestWrapper extends Structure implements Closeable, PlayRequ
         return Optional.ofNullable(cachedStreamHandle);
     }
 
+    public Optional<String> getSubtitleUrl() {
+        return Optional.ofNullable(cachedSubtitleUrl);
+    }
+
     @Override
     public void read() {
         super.read();
@@ -90,6 +97,9 @@ public class PlayRequestWrapper extends Structure implements Closeable, PlayRequ
                 .map(e -> e.getLong(0))
                 .map(Handle::new)
                 .orElse(null);
+        this.cachedSubtitleUrl = Optional.ofNullable(subtitleUrl)
+                .map(e -> e.getString(0))
+                .orElse(null);
     }
 
     @Override
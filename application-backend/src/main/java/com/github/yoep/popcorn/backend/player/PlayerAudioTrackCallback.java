@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.player;
+
+import com.sun.jna.Callback;
+
+public interface PlayerAudioTrackCallback extends Callback {
+    void callback(Integer id);
+}
@@ -8,7 +8,7 @@ import lombok.ToString;
 
 @Getter
 @ToString(callSuper = true)
-@Structure.FieldOrder({"playerPlayCallback", "playerPauseCallback", "playerResumeCallback", "playerSeekCallback", "playerStopCallback"})
+@Structure.FieldOrder({"playerPlayCallback", "playerPauseCallback", "playerResumeCallback", "playerSeekCallback", "playerStopCallback", "playerAudioTrackCallback"})
 public class PlayerWrapperRegistration extends PlayerWrapper {
     public static class ByValue extends PlayerWrapperRegistration implements Structure.ByValue {
         public ByValue() {
@@ -24,6 +24,7 @@ public class PlayerWrapperRegistration extends PlayerWrapper {
     public PlayerResumeCallback playerResumeCallback;
     public PlayerSeekCallback playerSeekCallback;
     public PlayerStopCallback playerStopCallback;
+    public PlayerAudioTrackCallback playerAudioTrackCallback;
 
     public PlayerWrapperRegistration() {
     }
@@ -35,6 +36,7 @@ public class PlayerWrapperRegistration extends PlayerWrapper {
         this.playerResumeCallback = onResume(player);
         this.playerSeekCallback = onSeek(player);
         this.playerStopCallback = onStop(player);
+        this.playerAudioTrackCallback = onAudioTrack(player);
     }
 
     private PlayerPlayCallback onPlay(Player player) {
@@ -56,4 +58,8 @@ public class PlayerWrapperRegistration extends PlayerWrapper {
     private PlayerStopCallback onStop(Player player) {
         return () -> Platform.runLater(player::stop);
     }
+
+    private PlayerAudioTrackCallback onAudioTrack(Player player) {
+        return id -> Platform.runLater(() -> player.audioTrack(id));
+    }
 }
@@ -21,6 +21,9 @@ public class PlaylistItem extends Structure implements Closeable {
     public static class ByReference extends PlaylistItem implements Structure.ByReference {
     }
 
+    public static class ByValue extends PlaylistItem implements Structure.ByValue {
+    }
+
     public String url;
     public String title;
     public String caption;
@@ -83,6 +83,19 @@ public class PlaylistManager extends AbstractListenerService<PlaylistManagerList
         fxLib.stop_playlist(instance);
     }
 
+    public boolean moveItem(int from, int to) {
+        return fxLib.move_playlist_item(instance, from, to) == 1;
+    }
+
+    public boolean removeItem(int index) {
+        return fxLib.remove_playlist_item(instance, index) == 1;
+    }
+
+    public void insertItem(int index, PlaylistItem.ByValue item) {
+        Objects.requireNonNull(item, "item cannot be null");
+        fxLib.insert_playlist_item(instance, index, item);
+    }
+
     public Playlist playlist() {
         return fxLib.playlist(instance);
     }
@@ -41,6 +41,7 @@ public class PlaylistManagerEvent extends Structure implements Closeable {
             }
             case PlayingNext -> union.setType(PlayingNext_Body.class);
             case StateChanged -> union.setType(StateChanged_Body.class);
+            case ItemSkipped -> union.setType(ItemSkipped_Body.class);
         }
     }
 
@@ -80,12 +81,25 @@ public class PlaylistManagerEvent extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"title"})
+    public static class ItemSkipped_Body extends Structure implements Closeable {
+        public String title;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
     @Getter
     @ToString
     @EqualsAndHashCode(callSuper = false)
     public static class PlaylistManagerEventUnion extends Union implements Closeable {
         public PlayingNext_Body playingNext_body;
         public StateChanged_Body stateChanged_body;
+        public ItemSkipped_Body itemSkipped_body;
 
         @Override
         public void close() {
@@ -94,13 +108,16 @@ public class PlaylistManagerEvent extends Structure implements Closeable {
                     .ifPresent(PlayingNext_Body::close);
             Optional.ofNullable(stateChanged_body)
                     .ifPresent(StateChanged_Body::close);
+            Optional.ofNullable(itemSkipped_body)
+                    .ifPresent(ItemSkipped_Body::close);
         }
     }
 
     public enum Tag implements NativeMapped {
         PlaylistChanged,
         PlayingNext,
-        StateChanged;
+        StateChanged,
+        ItemSkipped;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
@@ -6,6 +6,7 @@ import com.github.yoep.popcorn.backend.settings.models.*;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
 import lombok.extern.slf4j.Slf4j;
 
+import java.io.File;
 import java.util.*;
 import java.util.concurrent.ConcurrentLinkedDeque;
 import java.util.function.Consumer;
@@ -166,6 +167,64 @@ public class ApplicationConfig {
         fxLib.update_playback_settings(instance, settings_c);
     }
 
+    /**
+     * Update the network settings of the application with the new value.
+     * The new settings are applied to all outbound requests of the application.
+     *
+     * @param settings The new settings to use.
+     */
+    public void update(NetworkSettings settings) {
+        Objects.requireNonNull(settings, "settings cannot be null");
+        try (var settings_c = new NetworkSettings.ByValue(settings)) {
+            fxLib.update_network_settings(instance, settings_c);
+        }
+    }
+
+    /**
+     * Export the current settings of the application to the given file.
+     *
+     * @param file The file to which the settings should be written.
+     * @throws SettingsException Thrown when the settings couldn't be exported.
+     */
+    public void exportSettings(File file) {
+        Objects.requireNonNull(file, "file cannot be null");
+        if (fxLib.export_settings(instance, file.getAbsolutePath()) != 1) {
+            throw new SettingsException("Failed to export the settings to " + file.getAbsolutePath());
+        }
+    }
+
+    /**
+     * Import the settings of the application from the given file.
+     * The current settings remain unchanged when the import fails.
+     *
+     * @param file The file from which the settings should be read.
+     * @throws SettingsException Thrown when the file couldn't be read or contains invalid fields.
+     */
+    public void importSettings(File file) {
+        Objects.requireNonNull(file, "file cannot be null");
+        var result = fxLib.import_settings(instance, file.getAbsolutePath());
+        try {
+            switch (result.getTag()) {
+                case ReadFailed -> throw new SettingsException("Failed to read the settings from " + file.getAbsolutePath());
+                case InvalidFields -> throw new SettingsException("Settings contain unknown or invalid fields " +
+                        result.getUnion().getInvalidFields().getFields().values());
+                default -> log.debug("Settings have been imported from {}", file.getAbsolutePath());
+            }
+        } finally {
+            fxLib.dispose_settings_import_result(result);
+        }
+    }
+
+    /**
+     * Reset the given section of the settings to its default values.
+     *
+     * @param section The settings section to reset.
+     */
+    public void reset(SettingsSection section) {
+        Objects.requireNonNull(section, "section cannot be null");
+        fxLib.reset_settings_section(instance, section);
+    }
+
     /**
      * Get the list of supported UI scales for this application.
      *
@@ -245,6 +304,8 @@ public class ApplicationConfig {
                     .filter(e -> e.getDisplayLanguage().equalsIgnoreCase(language))
                     .findFirst()
                     .ifPresent(localeText::updateLocale);
+        } else if (event.tag == ApplicationConfigEvent.Tag.SETTINGS_ERROR) {
+            log.warn("Failed to load the application settings, the settings might not have been migrated");
         }
     }
 
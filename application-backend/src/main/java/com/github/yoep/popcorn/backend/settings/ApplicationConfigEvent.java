@@ -32,6 +32,7 @@ public class ApplicationConfigEvent extends Structure implements Closeable {
             case UI_SETTINGS_CHANGED -> union.setType(ApplicationConfigEvent.UiSettingsChanged_Body.class);
             case SERVER_SETTINGS_CHANGED -> union.setType(ApplicationConfigEvent.ServerSettingsChanged_Body.class);
             case PLAYBACK_SETTINGS_CHANGED -> union.setType(ApplicationConfigEvent.PlaybackSettingsChanged_Body.class);
+            case NETWORK_SETTINGS_CHANGED -> union.setType(ApplicationConfigEvent.NetworkSettingsChanged_Body.class);
         }
         union.read();
     }
@@ -114,6 +115,18 @@ public class ApplicationConfigEvent extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"settings"})
+    public static class NetworkSettingsChanged_Body extends Structure implements Closeable {
+        public NetworkSettings settings;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
     @Getter
     @ToString
     @EqualsAndHashCode(callSuper = false)
@@ -127,6 +140,7 @@ public class ApplicationConfigEvent extends Structure implements Closeable {
         public ServerSettingsChanged_Body serverSettingsChanged_body;
         public PlaybackSettingsChanged_Body playbackSettingsChanged_body;
         public TrackingSettingsChanged_Body trackingSettingsChanged_body;
+        public NetworkSettingsChanged_Body networkSettingsChanged_body;
 
         @Override
         public void close() {
@@ -143,6 +157,8 @@ public class ApplicationConfigEvent extends Structure implements Closeable {
                     .ifPresent(PlaybackSettingsChanged_Body::close);
             Optional.ofNullable(trackingSettingsChanged_body)
                     .ifPresent(TrackingSettingsChanged_Body::close);
+            Optional.ofNullable(networkSettingsChanged_body)
+                    .ifPresent(NetworkSettingsChanged_Body::close);
         }
     }
 
@@ -153,7 +169,9 @@ public class ApplicationConfigEvent extends Structure implements Closeable {
         UI_SETTINGS_CHANGED,
         SERVER_SETTINGS_CHANGED,
         PLAYBACK_SETTINGS_CHANGED,
-        TRACKING_SETTINGS_CHANGED;
+        TRACKING_SETTINGS_CHANGED,
+        NETWORK_SETTINGS_CHANGED,
+        SETTINGS_ERROR;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
@@ -0,0 +1,94 @@
+package com.github.yoep.popcorn.backend.settings;
+
+import com.github.yoep.popcorn.backend.lib.FxStringArray;
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+import com.sun.jna.Structure;
+import com.sun.jna.Union;
+import lombok.EqualsAndHashCode;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.Optional;
+
+@Getter
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"tag", "union"})
+public class SettingsImportResult extends Structure implements Closeable {
+    public static class ByValue extends SettingsImportResult implements Structure.ByValue {
+    }
+
+    public Tag tag;
+    public SettingsImportResultUnion.ByValue union;
+
+    @Override
+    public void read() {
+        super.read();
+        if (tag == Tag.InvalidFields) {
+            union.setType(InvalidFieldsBody.class);
+            union.read();
+        }
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        getUnion().close();
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"fields"})
+    public static class InvalidFieldsBody extends Structure implements Closeable {
+        public FxStringArray.ByValue fields;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            fields.close();
+        }
+    }
+
+    @Getter
+    @ToString
+    public static class SettingsImportResultUnion extends Union implements Closeable {
+        public static class ByValue extends SettingsImportResultUnion implements Union.ByValue {
+        }
+
+        public InvalidFieldsBody invalidFields;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            Optional.ofNullable(invalidFields)
+                    .ifPresent(InvalidFieldsBody::close);
+        }
+    }
+
+    public enum Tag implements NativeMapped {
+        Ok,
+        ReadFailed,
+        InvalidFields;
+
+        @Override
+        public Object fromNative(Object nativeValue, FromNativeContext context) {
+            return Arrays.stream(values())
+                    .filter(e -> e.ordinal() == (int) nativeValue)
+                    .findFirst()
+                    .orElse(null);
+        }
+
+        @Override
+        public Object toNative() {
+            return ordinal();
+        }
+
+        @Override
+        public Class<?> nativeType() {
+            return Integer.class;
+        }
+    }
+}
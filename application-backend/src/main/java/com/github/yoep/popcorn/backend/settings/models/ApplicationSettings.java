@@ -10,7 +10,7 @@ import java.io.Closeable;
 @Builder
 @NoArgsConstructor
 @AllArgsConstructor
-@Structure.FieldOrder({"subtitleSettings", "torrentSettings", "uiSettings", "serverSettings", "playbackSettings", "trackingSettings"})
+@Structure.FieldOrder({"subtitleSettings", "torrentSettings", "uiSettings", "serverSettings", "playbackSettings", "trackingSettings", "networkSettings"})
 public class ApplicationSettings extends Structure implements Closeable {
     public SubtitleSettings subtitleSettings;
     public TorrentSettings torrentSettings;
@@ -18,6 +18,7 @@ public class ApplicationSettings extends Structure implements Closeable {
     public ServerSettings serverSettings;
     public PlaybackSettings playbackSettings;
     public TrackingSettings trackingSettings;
+    public NetworkSettings networkSettings;
 
 
     //region Getters & Setters
@@ -31,6 +32,7 @@ public class ApplicationSettings extends Structure implements Closeable {
         serverSettings.close();
         playbackSettings.close();
         trackingSettings.close();
+        networkSettings.close();
     }
 
     //endregion
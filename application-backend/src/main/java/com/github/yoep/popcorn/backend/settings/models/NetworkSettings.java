@@ -0,0 +1,62 @@
+package com.github.yoep.popcorn.backend.settings.models;
+
+import com.sun.jna.Structure;
+import lombok.*;
+
+import java.io.Closeable;
+import java.util.Objects;
+
+@EqualsAndHashCode(callSuper = false)
+@Data
+@Builder
+@NoArgsConstructor
+@AllArgsConstructor
+@ToString(exclude = "proxyPassword")
+@Structure.FieldOrder({"proxy", "proxyUsername", "proxyPassword", "proxyBypass", "userAgent", "requestTimeout", "sendCrashReports"})
+public class NetworkSettings extends Structure implements Closeable {
+    public static class ByValue extends NetworkSettings implements Structure.ByValue {
+        public ByValue() {
+        }
+
+        public ByValue(NetworkSettings settings) {
+            Objects.requireNonNull(settings, "settings cannot be null");
+            this.proxy = settings.proxy;
+            this.proxyUsername = settings.proxyUsername;
+            this.proxyPassword = settings.proxyPassword;
+            this.proxyBypass = settings.proxyBypass;
+            this.userAgent = settings.userAgent;
+            this.requestTimeout = settings.requestTimeout;
+            this.sendCrashReports = settings.sendCrashReports;
+        }
+    }
+
+    public String proxy;
+    public String proxyUsername;
+    public String proxyPassword;
+    /**
+     * The comma separated hosts which bypass the proxy.
+     */
+    public String proxyBypass;
+    public String userAgent;
+    /**
+     * The timeout in seconds of each request, 0 disables the timeout.
+     */
+    public long requestTimeout;
+    /**
+     * Indicates if the last crash report is sent along with the next update check.
+     */
+    public byte sendCrashReports;
+
+    public boolean isSendCrashReports() {
+        return sendCrashReports == 1;
+    }
+
+    public void setSendCrashReports(boolean sendCrashReports) {
+        this.sendCrashReports = (byte) (sendCrashReports ? 1 : 0);
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
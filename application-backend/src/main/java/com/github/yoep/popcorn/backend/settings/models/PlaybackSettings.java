@@ -13,7 +13,7 @@ import java.util.Optional;
 import static java.util.Arrays.asList;
 
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"quality", "fullscreen", "autoPlayNextEpisodeEnabled"})
+@Structure.FieldOrder({"quality", "fullscreen", "autoPlayNextEpisodeEnabled", "defaultPlayer", "watchedThresholdPercent"})
 public class PlaybackSettings extends Structure implements Closeable {
     public static class ByValue extends PlaybackSettings implements Structure.ByValue {
         public ByValue() {
@@ -24,12 +24,16 @@ public class PlaybackSettings extends Structure implements Closeable {
             this.quality = settings.quality;
             this.fullscreen = settings.fullscreen;
             this.autoPlayNextEpisodeEnabled = settings.autoPlayNextEpisodeEnabled;
+            this.defaultPlayer = settings.defaultPlayer;
+            this.watchedThresholdPercent = settings.watchedThresholdPercent;
         }
     }
 
     public IntByReference quality;
     public byte fullscreen;
     public byte autoPlayNextEpisodeEnabled;
+    public String defaultPlayer;
+    public int watchedThresholdPercent;
 
     public Optional<Quality> getQuality() {
         if (quality != null) {
@@ -62,6 +66,22 @@ public class PlaybackSettings extends Structure implements Closeable {
         this.autoPlayNextEpisodeEnabled = (byte) (autoPlayNextEpisodeEnabled ? 1 : 0);
     }
 
+    public Optional<String> getDefaultPlayer() {
+        return Optional.ofNullable(defaultPlayer);
+    }
+
+    public void setDefaultPlayer(String defaultPlayer) {
+        this.defaultPlayer = defaultPlayer;
+    }
+
+    public int getWatchedThresholdPercent() {
+        return watchedThresholdPercent;
+    }
+
+    public void setWatchedThresholdPercent(int watchedThresholdPercent) {
+        this.watchedThresholdPercent = watchedThresholdPercent;
+    }
+
     @Override
     public void close() {
         setAutoSynch(false);
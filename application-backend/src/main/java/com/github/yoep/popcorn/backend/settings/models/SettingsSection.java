@@ -0,0 +1,34 @@
+package com.github.yoep.popcorn.backend.settings.models;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+import java.util.Arrays;
+
+public enum SettingsSection implements NativeMapped {
+    SUBTITLE,
+    TORRENT,
+    UI,
+    SERVER,
+    PLAYBACK,
+    TRACKING,
+    NETWORK;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        return Arrays.stream(values())
+                .filter(e -> e.ordinal() == (int) nativeValue)
+                .findFirst()
+                .orElse(null);
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
@@ -0,0 +1,56 @@
+package com.github.yoep.popcorn.backend.settings.models;
+
+import com.github.yoep.popcorn.backend.media.filters.model.Category;
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+import lombok.Getter;
+
+import java.util.Arrays;
+import java.util.Optional;
+
+@Getter
+public enum StartPage implements NativeMapped {
+    MOVIES(Category.MOVIES),
+    SERIES(Category.SERIES),
+    FAVORITES(Category.FAVORITES),
+    CONTINUE_WATCHING(null);
+
+    /**
+     * The media category which is shown by the start page, can be null.
+     */
+    private final Category category;
+
+    StartPage(Category category) {
+        this.category = category;
+    }
+
+    /**
+     * Get the start page which shows the given media category.
+     *
+     * @param category The media category.
+     * @return Returns the start page of the category if found, else {@link Optional#empty()}.
+     */
+    public static Optional<StartPage> of(Category category) {
+        return Arrays.stream(values())
+                .filter(e -> e.category == category)
+                .findFirst();
+    }
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        return Arrays.stream(values())
+                .filter(e -> e.ordinal() == (int) nativeValue)
+                .findFirst()
+                .orElse(null);
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
@@ -3,6 +3,7 @@ package com.github.yoep.popcorn.backend.settings.models;
 import com.github.yoep.popcorn.backend.settings.models.subtitles.DecorationType;
 import com.github.yoep.popcorn.backend.settings.models.subtitles.SubtitleFamily;
 import com.github.yoep.popcorn.backend.settings.models.subtitles.SubtitleLanguage;
+import com.github.yoep.popcorn.backend.settings.models.subtitles.TvModeSubtitle;
 import com.sun.jna.Structure;
 import lombok.*;
 
@@ -16,7 +17,8 @@ import java.util.Objects;
 @Builder
 @NoArgsConstructor
 @AllArgsConstructor
-@Structure.FieldOrder({"directory", "autoCleaningEnabled", "defaultSubtitle", "fontFamily", "fontSize", "decoration", "bold"})
+@ToString(exclude = {"apiKey", "userToken"})
+@Structure.FieldOrder({"directory", "autoCleaningEnabled", "defaultSubtitle", "fontFamily", "fontSize", "decoration", "bold", "maxLineLength", "maxLines", "tvModeSubtitle", "apiKey", "userToken"})
 public class SubtitleSettings extends Structure implements Closeable {
     public static class ByValue extends SubtitleSettings implements Structure.ByValue {
         public ByValue() {
@@ -31,6 +33,11 @@ public class SubtitleSettings extends Structure implements Closeable {
             this.fontSize = settings.fontSize;
             this.decoration = settings.decoration;
             this.bold = settings.bold;
+            this.maxLineLength = settings.maxLineLength;
+            this.maxLines = settings.maxLines;
+            this.tvModeSubtitle = settings.tvModeSubtitle;
+            this.apiKey = settings.apiKey;
+            this.userToken = settings.userToken;
         }
     }
 
@@ -64,6 +71,26 @@ public class SubtitleSettings extends Structure implements Closeable {
      * The indication if the subtitle must always be in the style "bold".
      */
     public byte bold;
+    /**
+     * The max number of characters per line of converted subtitles, 0 disables the line wrapping.
+     */
+    public int maxLineLength;
+    /**
+     * The max number of lines per converted subtitle cue.
+     */
+    public int maxLines;
+    /**
+     * The default subtitle selection when the application runs in TV or kiosk mode.
+     */
+    public TvModeSubtitle tvModeSubtitle;
+    /**
+     * The custom OpenSubtitles API key, or {@code null} to use the built-in API key.
+     */
+    public String apiKey;
+    /**
+     * The OpenSubtitles user token of the account, or {@code null} to access the subtitles anonymously.
+     */
+    public String userToken;
 
     //endregion
 
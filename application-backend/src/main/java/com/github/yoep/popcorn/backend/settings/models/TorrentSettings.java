@@ -9,7 +9,7 @@ import java.util.Objects;
 
 @Data
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"directory", "cleaningMode", "connectionsLimit", "downloadRateLimit", "uploadRateLimit"})
+@Structure.FieldOrder({"directory", "cleaningMode", "connectionsLimit", "downloadRateLimit", "uploadRateLimit", "streamIdleTimeout", "removeIdleTorrents", "bindInterface", "encryptionMode", "autoCollectFinished", "initialBufferSize", "peerConnectTimeout", "announceToAllTrackers", "requestTimeout", "ipFilter", "preallocate", "dhtReadOnly", "maxMetadataSize", "maxRequestQueueDepth", "dualStack"})
 public class TorrentSettings extends Structure implements Closeable {
     public static class ByValue extends TorrentSettings implements Structure.ByValue {
         public ByValue() {
@@ -22,6 +22,21 @@ public class TorrentSettings extends Structure implements Closeable {
             this.connectionsLimit = settings.connectionsLimit;
             this.downloadRateLimit = settings.downloadRateLimit;
             this.uploadRateLimit = settings.uploadRateLimit;
+            this.streamIdleTimeout = settings.streamIdleTimeout;
+            this.removeIdleTorrents = settings.removeIdleTorrents;
+            this.bindInterface = settings.bindInterface;
+            this.encryptionMode = settings.encryptionMode;
+            this.autoCollectFinished = settings.autoCollectFinished;
+            this.initialBufferSize = settings.initialBufferSize;
+            this.peerConnectTimeout = settings.peerConnectTimeout;
+            this.announceToAllTrackers = settings.announceToAllTrackers;
+            this.requestTimeout = settings.requestTimeout;
+            this.ipFilter = settings.ipFilter;
+            this.preallocate = settings.preallocate;
+            this.dhtReadOnly = settings.dhtReadOnly;
+            this.maxMetadataSize = settings.maxMetadataSize;
+            this.maxRequestQueueDepth = settings.maxRequestQueueDepth;
+            this.dualStack = settings.dualStack;
         }
     }
 
@@ -30,6 +45,21 @@ public class TorrentSettings extends Structure implements Closeable {
     public int connectionsLimit;
     public int downloadRateLimit;
     public int uploadRateLimit;
+    public int streamIdleTimeout;
+    public byte removeIdleTorrents;
+    public String bindInterface;
+    public EncryptionMode encryptionMode;
+    public byte autoCollectFinished;
+    public int initialBufferSize;
+    public int peerConnectTimeout;
+    public byte announceToAllTrackers;
+    public int requestTimeout;
+    public String ipFilter;
+    public byte preallocate;
+    public byte dhtReadOnly;
+    public int maxMetadataSize;
+    public int maxRequestQueueDepth;
+    public byte dualStack;
 
     //region Methods
 
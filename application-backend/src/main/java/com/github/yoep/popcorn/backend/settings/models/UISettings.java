@@ -1,6 +1,5 @@
 package com.github.yoep.popcorn.backend.settings.models;
 
-import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.sun.jna.Structure;
 import lombok.*;
 
@@ -16,7 +15,7 @@ import static java.util.Arrays.asList;
 @Builder
 @NoArgsConstructor
 @AllArgsConstructor
-@Structure.FieldOrder({"defaultLanguage", "uiScale", "startScreen", "maximized", "nativeWindowEnabled"})
+@Structure.FieldOrder({"defaultLanguage", "uiScale", "startPage", "posterQuality", "maximized", "nativeWindowEnabled"})
 public class UISettings extends Structure implements Closeable {
     public static class ByValue extends UISettings implements Structure.ByValue {
         public ByValue() {
@@ -26,7 +25,8 @@ public class UISettings extends Structure implements Closeable {
             Objects.requireNonNull(settings, "settings cannot be null");
             this.defaultLanguage = settings.defaultLanguage;
             this.uiScale = settings.uiScale;
-            this.startScreen = settings.startScreen;
+            this.startPage = settings.startPage;
+            this.posterQuality = settings.posterQuality;
             this.maximized = settings.maximized;
             this.nativeWindowEnabled = settings.nativeWindowEnabled;
         }
@@ -34,7 +34,8 @@ public class UISettings extends Structure implements Closeable {
 
     public String defaultLanguage;
     public UIScale uiScale;
-    public Category startScreen;
+    public StartPage startPage;
+    public PosterQuality posterQuality;
     public byte maximized;
     public byte nativeWindowEnabled;
 
@@ -0,0 +1,25 @@
+package com.github.yoep.popcorn.backend.settings.models.subtitles;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+public enum TvModeSubtitle implements NativeMapped {
+    USER_PREFERENCE,
+    INTERFACE_LANGUAGE;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
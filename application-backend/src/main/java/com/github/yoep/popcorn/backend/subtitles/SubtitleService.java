@@ -122,4 +122,10 @@ public interface SubtitleService {
      * Clean the subtitles directory of all subtitle files.
      */
     void cleanup();
+
+    /**
+     * Clear the cache of the downloaded subtitle files.
+     * The next playback of the same media will download the subtitle again.
+     */
+    void clearCache();
 }
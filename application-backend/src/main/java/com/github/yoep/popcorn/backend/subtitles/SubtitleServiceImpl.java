@@ -191,6 +191,11 @@ public class SubtitleServiceImpl implements SubtitleService {
         fxLib.cleanup_subtitles_directory(instance);
     }
 
+    @Override
+    public void clearCache() {
+        fxLib.clear_subtitle_cache(instance);
+    }
+
     //endregion
 
     private void init() {
@@ -0,0 +1,27 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"start", "end"})
+public class ByteRange extends Structure implements Closeable {
+    public static class ByReference extends ByteRange implements Structure.ByReference {
+    }
+
+    public long start;
+    public long end;
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
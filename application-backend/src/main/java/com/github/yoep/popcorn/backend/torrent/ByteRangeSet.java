@@ -0,0 +1,47 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+
+import static java.util.Arrays.asList;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"items", "len"})
+public class ByteRangeSet extends Structure implements Closeable {
+    public static class ByValue extends ByteRangeSet implements Structure.ByValue {
+    }
+
+    public ByteRange.ByReference items;
+    public int len;
+
+    private List<ByteRange> cachedItems;
+
+    public List<ByteRange> getItems() {
+        return Optional.ofNullable(cachedItems)
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void read() {
+        super.read();
+        cachedItems = Optional.ofNullable(items)
+                .map(e -> asList((ByteRange[]) e.toArray(len)))
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        FxLib.INSTANCE.get().dispose_byte_range_set(this);
+    }
+}
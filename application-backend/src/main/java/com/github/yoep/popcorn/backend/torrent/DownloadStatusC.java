@@ -12,7 +12,7 @@ import java.io.Closeable;
 @Getter
 @ToString
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"progress", "seeds", "peers", "downloadSpeed", "uploadSpeed", "downloaded", "total_size"})
+@Structure.FieldOrder({"progress", "seeds", "peers", "downloadSpeed", "uploadSpeed", "downloaded", "total_size", "requestQueueDepth"})
 public class DownloadStatusC extends Structure implements Closeable, DownloadStatus {
     public static class ByValue extends DownloadStatusC implements Structure.ByValue {
         public ByValue() {
@@ -20,8 +20,8 @@ public class DownloadStatusC extends Structure implements Closeable, DownloadSta
         }
 
         @Builder
-        public ByValue(Float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long total_size) {
-            super(progress, seeds, peers, downloadSpeed, uploadSpeed, downloaded, total_size);
+        public ByValue(Float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long total_size, int requestQueueDepth) {
+            super(progress, seeds, peers, downloadSpeed, uploadSpeed, downloaded, total_size, requestQueueDepth);
         }
     }
 
@@ -32,11 +32,12 @@ public class DownloadStatusC extends Structure implements Closeable, DownloadSta
     public int uploadSpeed;
     public long downloaded;
     public long total_size;
+    public int requestQueueDepth;
 
     public DownloadStatusC() {
     }
 
-    public DownloadStatusC(Float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long total_size) {
+    public DownloadStatusC(Float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long total_size, int requestQueueDepth) {
         this.progress = progress;
         this.seeds = seeds;
         this.peers = peers;
@@ -44,6 +45,7 @@ public class DownloadStatusC extends Structure implements Closeable, DownloadSta
         this.uploadSpeed = uploadSpeed;
         this.downloaded = downloaded;
         this.total_size = total_size;
+        this.requestQueueDepth = requestQueueDepth;
     }
 
     @Override
@@ -81,6 +83,11 @@ public class DownloadStatusC extends Structure implements Closeable, DownloadSta
         return total_size;
     }
 
+    @Override
+    public int requestQueueDepth() {
+        return requestQueueDepth;
+    }
+
     @Override
     public void close() {
         setAutoSynch(false);
@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.sun.jna.Callback;
+
+public interface PauseTorrentCallback extends Callback {
+    void callback(String handle, byte paused);
+}
@@ -0,0 +1,39 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.sun.jna.Structure;
+import lombok.EqualsAndHashCode;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.time.Duration;
+import java.util.Optional;
+
+@Getter
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"offset", "bufferedBytes", "initialBufferSize", "downloadSpeed", "eta"})
+public class StreamBufferStatusC extends Structure implements Closeable {
+    public static class ByValue extends StreamBufferStatusC implements Structure.ByValue {
+    }
+
+    public long offset;
+    public long bufferedBytes;
+    public long initialBufferSize;
+    public int downloadSpeed;
+    public long eta;
+
+    /**
+     * Get the estimated time until the initial buffer of the stream has been filled.
+     *
+     * @return Returns the estimated time, or empty when it's unknown.
+     */
+    public Optional<Duration> getEstimatedTime() {
+        return eta < 0 ? Optional.empty() : Optional.of(Duration.ofMillis(eta));
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
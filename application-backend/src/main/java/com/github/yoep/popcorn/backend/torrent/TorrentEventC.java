@@ -0,0 +1,96 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.github.yoep.popcorn.backend.adapters.torrent.state.TorrentState;
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+import com.sun.jna.Structure;
+import com.sun.jna.Union;
+import lombok.EqualsAndHashCode;
+import lombok.Getter;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.Optional;
+
+@Getter
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"tag", "union"})
+public class TorrentEventC extends Structure implements Closeable {
+    public static class ByValue extends TorrentEventC implements Structure.ByValue {
+    }
+
+    public Tag tag;
+    public TorrentEventCUnion union;
+
+    @Override
+    public void read() {
+        super.read();
+        updateUnionType();
+        union.read();
+    }
+
+    void updateUnionType() {
+        if (tag == Tag.STATE_CHANGED) {
+            union.setType(StateChanged_Body.class);
+        }
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        getUnion().close();
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"state"})
+    public static class StateChanged_Body extends Structure implements Closeable {
+        public TorrentState state;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
+    @Getter
+    @ToString
+    @EqualsAndHashCode(callSuper = false)
+    public static class TorrentEventCUnion extends Union implements Closeable {
+        public StateChanged_Body stateChanged_body;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            Optional.ofNullable(stateChanged_body)
+                    .ifPresent(StateChanged_Body::close);
+        }
+    }
+
+    public enum Tag implements NativeMapped {
+        STATE_CHANGED,
+        METADATA_RECEIVED,
+        COMPLETED,
+        REMOVED;
+
+        @Override
+        public Object fromNative(Object nativeValue, FromNativeContext context) {
+            return Arrays.stream(values())
+                    .filter(e -> e.ordinal() == (int) nativeValue)
+                    .findFirst()
+                    .orElse(null);
+        }
+
+        @Override
+        public Object toNative() {
+            return ordinal();
+        }
+
+        @Override
+        public Class<?> nativeType() {
+            return Integer.class;
+        }
+    }
+}
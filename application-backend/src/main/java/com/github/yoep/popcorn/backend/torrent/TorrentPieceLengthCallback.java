@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.sun.jna.Callback;
+
+interface TorrentPieceLengthCallback extends Callback {
+    long callback();
+}
@@ -41,6 +41,7 @@ public class TorrentStreamEventC extends Structure implements Closeable {
         switch (tag) {
             case STATE_CHANGED -> union.setType(StateChanged_Body.class);
             case DOWNLOAD_STATUS -> union.setType(DownloadStatus_Body.class);
+            case BUFFER_STATUS -> union.setType(BufferStatus_Body.class);
         }
     }
 
@@ -74,6 +75,18 @@ public class TorrentStreamEventC extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"status"})
+    public static class BufferStatus_Body extends Structure implements Closeable {
+        public StreamBufferStatusC.ByValue status;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
     @Getter
     @ToString
     @EqualsAndHashCode(callSuper = false)
@@ -81,6 +94,7 @@ public class TorrentStreamEventC extends Structure implements Closeable {
 
         public StateChanged_Body stateChanged_body;
         public DownloadStatus_Body downloadStatus_body;
+        public BufferStatus_Body bufferStatus_body;
 
         @Override
         public void close() {
@@ -89,12 +103,15 @@ public class TorrentStreamEventC extends Structure implements Closeable {
                     .ifPresent(StateChanged_Body::close);
             Optional.ofNullable(downloadStatus_body)
                     .ifPresent(DownloadStatus_Body::close);
+            Optional.ofNullable(bufferStatus_body)
+                    .ifPresent(BufferStatus_Body::close);
         }
     }
 
     public enum Tag implements NativeMapped {
         STATE_CHANGED,
-        DOWNLOAD_STATUS;
+        DOWNLOAD_STATUS,
+        BUFFER_STATUS;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
@@ -0,0 +1,38 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.github.yoep.popcorn.backend.adapters.torrent.state.TorrentState;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.NoArgsConstructor;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Optional;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@NoArgsConstructor
+@Structure.FieldOrder({"handle", "infoHash", "name", "state", "progress", "downloadSpeed", "uploadSpeed"})
+public class TorrentSummary extends Structure implements Closeable {
+    public static class ByReference extends TorrentSummary implements Structure.ByReference {
+    }
+
+    public String handle;
+    public String infoHash;
+    public String name;
+    public TorrentState state;
+    public float progress;
+    public int downloadSpeed;
+    public int uploadSpeed;
+
+    public Optional<String> getInfoHash() {
+        return Optional.ofNullable(infoHash);
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
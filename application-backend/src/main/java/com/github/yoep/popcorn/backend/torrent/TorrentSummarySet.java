@@ -0,0 +1,47 @@
+package com.github.yoep.popcorn.backend.torrent;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+
+import static java.util.Arrays.asList;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"items", "len"})
+public class TorrentSummarySet extends Structure implements Closeable {
+    public static class ByValue extends TorrentSummarySet implements Structure.ByValue {
+    }
+
+    public TorrentSummary.ByReference items;
+    public int len;
+
+    private List<TorrentSummary> cachedItems;
+
+    public List<TorrentSummary> getItems() {
+        return Optional.ofNullable(cachedItems)
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void read() {
+        super.read();
+        cachedItems = Optional.ofNullable(items)
+                .map(e -> asList((TorrentSummary[]) e.toArray(len)))
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        FxLib.INSTANCE.get().dispose_torrent_summary_set(this);
+    }
+}
@@ -24,8 +24,8 @@ import java.util.UUID;
 @Getter
 @ToString
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"handle", "filepath", "hasByteCallback", "hasPieceCallback", "torrentTotalPiecesCallback",
-        "prioritizeBytesCallback", "prioritizePiecesCallback", "sequentialModeCallback", "torrentStateCallback"})
+@Structure.FieldOrder({"handle", "filepath", "infoHash", "hasByteCallback", "hasPieceCallback", "torrentTotalPiecesCallback",
+        "torrentPieceLengthCallback", "prioritizeBytesCallback", "prioritizePiecesCallback", "sequentialModeCallback", "torrentStateCallback"})
 public class TorrentWrapper extends Structure implements Torrent, Closeable {
     public static class ByValue extends TorrentWrapper implements Structure.ByValue {
         public ByValue(PopcornFx instance, Torrent torrent) {
@@ -39,9 +39,11 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
 
     public String handle;
     public String filepath;
+    public String infoHash;
     public TorrentHasByteCallback hasByteCallback;
     public TorrentHasPieceCallback hasPieceCallback;
     public TorrentTotalPiecesCallback torrentTotalPiecesCallback;
+    public TorrentPieceLengthCallback torrentPieceLengthCallback;
     public PrioritizeBytesCallback prioritizeBytesCallback;
     public PrioritizePiecesCallback prioritizePiecesCallback;
     public SequentialModeCallback sequentialModeCallback;
@@ -59,9 +61,11 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
         this.torrent = torrent;
         this.handle = UUID.randomUUID().toString();
         this.filepath = torrent.getFile().getAbsolutePath();
+        this.infoHash = torrent.getInfoHash();
         this.hasByteCallback = createHasByteCallback();
         this.hasPieceCallback = (int index) -> (byte) (this.torrent.hasPiece(index) ? 1 : 0);
         this.torrentTotalPiecesCallback = torrent::getTotalPieces;
+        this.torrentPieceLengthCallback = createPieceLengthCallback();
         this.prioritizeBytesCallback = createPrioritizeBytesCallback();
         this.prioritizePiecesCallback = createPrioritizePiecesCallback();
         this.sequentialModeCallback = this.torrent::sequentialMode;
@@ -92,6 +96,11 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
         return torrent.getFilename();
     }
 
+    @Override
+    public String getInfoHash() {
+        return torrent.getInfoHash();
+    }
+
     @Override
     public File getFile() {
         return torrent.getFile();
@@ -187,7 +196,11 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
 
             @Override
             public void onError(TorrentException error) {
-
+                try {
+                    FxLibInstance.INSTANCE.get().torrent_write_error(instance, handle, error.getMessage());
+                } catch (Exception ex) {
+                    log.error("Failed to invoke C torrent write error, {}", ex.getMessage(), ex);
+                }
             }
 
             @Override
@@ -200,6 +213,7 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
                         .uploadSpeed(status.uploadSpeed())
                         .downloaded(status.downloaded())
                         .total_size(status.totalSize())
+                        .requestQueueDepth(status.requestQueueDepth())
                         .build()) {
                     FxLibInstance.INSTANCE.get().torrent_download_status(instance, handle,downloadStatusC );
                 } catch (Exception ex) {
@@ -234,6 +248,12 @@ public class TorrentWrapper extends Structure implements Torrent, Closeable {
         };
     }
 
+    private TorrentPieceLengthCallback createPieceLengthCallback() {
+        return () -> Optional.ofNullable(torrent.getPieceLength())
+                .map(Integer::longValue)
+                .orElse(0L);
+    }
+
     private PrioritizeBytesCallback createPrioritizeBytesCallback() {
         return (len, bytesPtr) -> {
             if (bytesPtr == null || len == 0) {
@@ -0,0 +1,27 @@
+package com.github.yoep.popcorn.backend.torrent.collection;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+public enum CollectionSort implements NativeMapped {
+    NAME,
+    ADDED_AT,
+    LAST_USED,
+    SIZE;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
@@ -10,13 +10,17 @@ import java.io.Closeable;
 @Getter
 @ToString
 @EqualsAndHashCode(callSuper = false)
-@Structure.FieldOrder({"name", "magnetUri"})
+@Structure.FieldOrder({"name", "magnetUri", "addedAt", "lastUsedAt", "totalSize", "totalFiles"})
 public class StoredTorrent extends Structure implements Closeable {
     public static class ByReference extends StoredTorrent implements Structure.ByReference {
     }
 
     public String name;
     public String magnetUri;
+    public long addedAt;
+    public long lastUsedAt;
+    public long totalSize;
+    public int totalFiles;
 
     @Override
     public void close() {
@@ -0,0 +1,7 @@
+package com.github.yoep.popcorn.backend.torrent.collection;
+
+import com.sun.jna.Callback;
+
+public interface TorrentCollectionCallback extends Callback {
+    void callback(TorrentCollectionEvent event);
+}
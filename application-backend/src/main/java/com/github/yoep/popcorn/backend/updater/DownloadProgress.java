@@ -6,10 +6,11 @@ import lombok.Getter;
 import java.io.Closeable;
 
 @Getter
-@Structure.FieldOrder({"totalSize", "downloaded"})
+@Structure.FieldOrder({"totalSize", "downloaded", "percentage"})
 public class DownloadProgress extends Structure implements Closeable {
     public long totalSize;
     public long downloaded;
+    public float percentage;
 
     @Override
     public void close() {
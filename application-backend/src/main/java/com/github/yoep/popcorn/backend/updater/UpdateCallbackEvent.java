@@ -28,6 +28,8 @@ public class UpdateCallbackEvent extends Structure implements Closeable {
             case StateChanged -> union.setType(StateChangedBody.class);
             case UpdateAvailable -> union.setType(UpdateAvailableBody.class);
             case DownloadProgress -> union.setType(DownloadProgressBody.class);
+            case Failed -> union.setType(FailedBody.class);
+            case VerificationProgress -> union.setType(VerificationProgressBody.class);
         }
         union.read();
     }
@@ -85,6 +87,37 @@ public class UpdateCallbackEvent extends Structure implements Closeable {
         }
     }
 
+    @Getter
+    @ToString
+    @FieldOrder({"error"})
+    public static class FailedBody extends Structure implements Closeable {
+        public static class ByReference extends FailedBody implements Structure.ByReference {
+        }
+
+        public UpdateError error;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+        }
+    }
+
+    @Getter
+    @ToString
+    @FieldOrder({"verificationProgress"})
+    public static class VerificationProgressBody extends Structure implements Closeable {
+        public static class ByReference extends VerificationProgressBody implements Structure.ByReference {
+        }
+
+        public VerificationProgress verificationProgress;
+
+        @Override
+        public void close() {
+            setAutoSynch(false);
+            verificationProgress.close();
+        }
+    }
+
     @Getter
     @ToString
     public static class UpdateEventCUnion extends Union implements Closeable {
@@ -95,6 +128,8 @@ public class UpdateCallbackEvent extends Structure implements Closeable {
         public StateChangedBody state_changed;
         public UpdateAvailableBody update_available;
         public DownloadProgressBody download_progress;
+        public FailedBody failed;
+        public VerificationProgressBody verification_progress;
 
         @Override
         public void close() {
@@ -104,6 +139,10 @@ public class UpdateCallbackEvent extends Structure implements Closeable {
                     .ifPresent(UpdateAvailableBody::close);
             Optional.ofNullable(download_progress)
                     .ifPresent(DownloadProgressBody::close);
+            Optional.ofNullable(failed)
+                    .ifPresent(FailedBody::close);
+            Optional.ofNullable(verification_progress)
+                    .ifPresent(VerificationProgressBody::close);
         }
     }
 
@@ -111,7 +150,9 @@ public class UpdateCallbackEvent extends Structure implements Closeable {
         StateChanged,
         UpdateAvailable,
         DownloadProgress,
-        InstallationProgress;
+        InstallationProgress,
+        Failed,
+        VerificationProgress;
 
         @Override
         public Object fromNative(Object nativeValue, FromNativeContext context) {
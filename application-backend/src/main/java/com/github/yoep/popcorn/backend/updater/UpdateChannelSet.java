@@ -0,0 +1,49 @@
+package com.github.yoep.popcorn.backend.updater;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.sun.jna.Pointer;
+import com.sun.jna.Structure;
+import lombok.Data;
+import lombok.EqualsAndHashCode;
+import lombok.ToString;
+
+import java.io.Closeable;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+
+@Data
+@ToString
+@EqualsAndHashCode(callSuper = false)
+@Structure.FieldOrder({"items", "len"})
+public class UpdateChannelSet extends Structure implements Closeable {
+    public static class ByValue extends UpdateChannelSet implements Structure.ByValue {
+    }
+
+    public Pointer items;
+    public int len;
+
+    private List<UpdateChannel> cachedItems;
+
+    public List<UpdateChannel> getItems() {
+        return Optional.ofNullable(cachedItems)
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void read() {
+        super.read();
+        cachedItems = Optional.ofNullable(items)
+                .map(e -> Arrays.stream(e.getIntArray(0, len))
+                        .mapToObj(ordinal -> UpdateChannel.values()[ordinal])
+                        .toList())
+                .orElse(Collections.emptyList());
+    }
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+        FxLib.INSTANCE.get().dispose_update_channel_set(this);
+    }
+}
@@ -0,0 +1,34 @@
+package com.github.yoep.popcorn.backend.updater;
+
+import com.sun.jna.FromNativeContext;
+import com.sun.jna.NativeMapped;
+
+public enum UpdateError implements NativeMapped {
+    CONNECTION_FAILED,
+    INVALID_UPDATE_CHANNEL,
+    INVALID_RESPONSE,
+    INVALID_VERSION,
+    PLATFORM_UPDATE_UNAVAILABLE,
+    DOWNLOAD_FAILED,
+    IO,
+    EXTRACTION_FAILED,
+    UPDATE_NOT_AVAILABLE,
+    CHECKSUM_MISMATCH,
+    ROLLBACK_UNAVAILABLE;
+
+    @Override
+    public Object fromNative(Object nativeValue, FromNativeContext context) {
+        var ordinal = (int) nativeValue;
+        return values()[ordinal];
+    }
+
+    @Override
+    public Object toNative() {
+        return ordinal();
+    }
+
+    @Override
+    public Class<?> nativeType() {
+        return Integer.class;
+    }
+}
@@ -10,6 +10,7 @@ import com.github.yoep.popcorn.backend.messages.UpdateMessage;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
 import lombok.extern.slf4j.Slf4j;
 
+import java.util.List;
 import java.util.Objects;
 import java.util.Optional;
 import java.util.Queue;
@@ -52,6 +53,36 @@ public class UpdateService {
         return fxLib.update_state(instance);
     }
 
+    /**
+     * Get the update channel from which new versions are received.
+     *
+     * @return Returns the active update channel.
+     */
+    public UpdateChannel getChannel() {
+        return fxLib.update_channel(instance);
+    }
+
+    /**
+     * Get the update channels which can be selected.
+     *
+     * @return Returns the available update channels.
+     */
+    public List<UpdateChannel> getAvailableChannels() {
+        try (var set = fxLib.available_update_channels(instance)) {
+            return set.getItems();
+        }
+    }
+
+    /**
+     * Set the update channel from which new versions are received.
+     *
+     * @param channel The update channel to select.
+     */
+    public void setChannel(UpdateChannel channel) {
+        Objects.requireNonNull(channel, "channel cannot be null");
+        fxLib.set_update_channel(instance, channel);
+    }
+
     //endregion
 
     //region Methods
@@ -64,6 +95,14 @@ public class UpdateService {
         fxLib.download_update(instance);
     }
 
+    /**
+     * Roll back the application to the previously installed version.
+     * The application will be relaunched when the rollback succeeded.
+     */
+    public void rollbackUpdate() {
+        fxLib.rollback_update(instance);
+    }
+
     public void register(UpdateCallback listener) {
         Objects.requireNonNull(listener, "listener cannot be null");
         listeners.add(listener);
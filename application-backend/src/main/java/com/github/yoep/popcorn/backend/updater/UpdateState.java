@@ -11,7 +11,8 @@ public enum UpdateState implements NativeMapped {
     DOWNLOAD_FINISHED,
     INSTALLING,
     INSTALLATION_FINISHED,
-    ERROR;
+    ERROR,
+    VERIFYING;
 
     @Override
     public Object fromNative(Object nativeValue, FromNativeContext context) {
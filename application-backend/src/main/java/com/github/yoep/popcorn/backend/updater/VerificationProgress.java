@@ -0,0 +1,18 @@
+package com.github.yoep.popcorn.backend.updater;
+
+import com.sun.jna.Structure;
+import lombok.Getter;
+
+import java.io.Closeable;
+
+@Getter
+@Structure.FieldOrder({"task", "totalTasks"})
+public class VerificationProgress extends Structure implements Closeable {
+    public short task;
+    public short totalTasks;
+
+    @Override
+    public void close() {
+        setAutoSynch(false);
+    }
+}
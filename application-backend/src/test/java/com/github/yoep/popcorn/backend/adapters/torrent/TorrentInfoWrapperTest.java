@@ -1,6 +1,7 @@
 package com.github.yoep.popcorn.backend.adapters.torrent;
 
 import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentFileInfo;
+import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentVersion;
 import org.junit.jupiter.api.Test;
 
 import java.util.Collections;
@@ -22,6 +23,7 @@ class TorrentInfoWrapperTest {
         when(info.getDirectoryName()).thenReturn(directoryName);
         when(info.getFiles()).thenReturn(Collections.singletonList(fileInfo));
         when(info.getTotalFiles()).thenReturn(1);
+        when(info.getVersion()).thenReturn(TorrentVersion.HYBRID);
 
         var result = new TorrentInfoWrapper(info);
 
@@ -29,5 +31,6 @@ class TorrentInfoWrapperTest {
         assertEquals(name, result.name);
         assertEquals(directoryName, result.directoryName);
         assertEquals(1, result.totalFiles);
+        assertEquals(TorrentVersion.HYBRID, result.version);
     }
 }
\ No newline at end of file
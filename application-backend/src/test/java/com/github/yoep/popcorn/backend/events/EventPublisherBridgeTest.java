@@ -28,15 +28,15 @@ class EventPublisherBridgeTest {
     private PopcornFx instance;
     private EventPublisherBridge bridge;
 
-    private final AtomicReference<EventBridgeCallback> callbackHolder = new AtomicReference<>();
+    private final AtomicReference<EventSubscriberCallback> callbackHolder = new AtomicReference<>();
 
     @BeforeEach
     void setUp() {
         FxLib.INSTANCE.set(fxLib);
         doAnswer(invocation -> {
-            callbackHolder.set(invocation.getArgument(1, EventBridgeCallback.class));
+            callbackHolder.set(invocation.getArgument(1, EventSubscriberCallback.class));
             return null;
-        }).when(fxLib).register_event_callback(isA(PopcornFx.class), isA(EventBridgeCallback.class));
+        }).when(fxLib).register_event_subscriber(isA(PopcornFx.class), isA(EventSubscriberCallback.class), eq(0L));
 
         bridge = new EventPublisherBridge(eventPublisher, fxLib, instance);
     }
@@ -74,9 +74,25 @@ class EventPublisherBridgeTest {
         when(changedEvent.getNewPlayerId()).thenReturn(newPlayerId);
         when(changedEvent.getNewPlayerName()).thenReturn(newPlayerName);
 
-        callback.callback(event);
+        callback.callback(event, 1L, (byte) 0);
 
         verify(eventPublisher).publish(new PlayerChangedEvent(bridge, oldPlayerId, newPlayerId, newPlayerName));
         verify(fxLib).dispose_event_value(event);
     }
+
+    @Test
+    void testCallback_Error() {
+        var message = "lorem ipsum";
+        var callback = callbackHolder.get();
+        var event = new EventC.ByValue();
+        event.tag = EventC.Tag.ERROR;
+        event.union = new EventC.EventCUnion.ByValue();
+        event.union.error_body = new EventC.Error_Body();
+        event.union.error_body.message = message;
+
+        callback.callback(event, 1L, (byte) 0);
+
+        verify(eventPublisher).publish(new ErrorNotificationEvent(bridge, message));
+        verify(fxLib).dispose_event_value(event);
+    }
 }
\ No newline at end of file
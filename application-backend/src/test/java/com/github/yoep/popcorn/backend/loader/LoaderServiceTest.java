@@ -9,10 +9,12 @@ import org.mockito.InjectMocks;
 import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
+import java.util.List;
+import java.util.Optional;
+
 import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.mockito.ArgumentMatchers.isA;
-import static org.mockito.Mockito.verify;
-import static org.mockito.Mockito.when;
+import static org.mockito.Mockito.*;
 
 @ExtendWith(MockitoExtension.class)
 class LoaderServiceTest {
@@ -47,4 +49,42 @@ class LoaderServiceTest {
 
         verify(fxLib).loader_cancel(instance, handle);
     }
+
+    @Test
+    void testSetPolicy() {
+        service.setPolicy(LoadingPolicy.QUEUE);
+
+        verify(fxLib).loader_set_policy(instance, LoadingPolicy.QUEUE);
+    }
+
+    @Test
+    void testGetActiveTask() {
+        var handle = 7841L;
+        when(fxLib.loader_active_task(isA(PopcornFx.class))).thenReturn(handle);
+
+        var result = service.getActiveTask();
+
+        assertEquals(Optional.of(handle), result);
+    }
+
+    @Test
+    void testGetActiveTask_whenNoTaskIsActive_shouldReturnEmpty() {
+        when(fxLib.loader_active_task(isA(PopcornFx.class))).thenReturn(0L);
+
+        var result = service.getActiveTask();
+
+        assertEquals(Optional.empty(), result);
+    }
+
+    @Test
+    void testGetQueuedTasks() {
+        var set = mock(LoadingHandleSet.ByValue.class);
+        when(set.getItems()).thenReturn(List.of(12L, 13L));
+        when(fxLib.loader_queued_tasks(isA(PopcornFx.class))).thenReturn(set);
+
+        var result = service.getQueuedTasks();
+
+        assertEquals(List.of(12L, 13L), result);
+        verify(set).close();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+package com.github.yoep.popcorn.backend.logging;
+
+import com.github.yoep.popcorn.backend.FxLib;
+import com.github.yoep.popcorn.backend.PopcornFx;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.InjectMocks;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.util.concurrent.atomic.AtomicReference;
+import java.util.function.Consumer;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+import static org.mockito.Mockito.*;
+
+@ExtendWith(MockitoExtension.class)
+class BackendLogServiceTest {
+    @Mock
+    private FxLib fxLib;
+    @Mock
+    private PopcornFx instance;
+    @InjectMocks
+    private BackendLogService service;
+
+    @Test
+    void testSetLogLevel() {
+        var target = "popcorn_fx_torrent";
+        when(fxLib.set_log_level(instance, target, LogLevel.DEBUG)).thenReturn((byte) 1);
+
+        var result = service.setLogLevel(target, LogLevel.DEBUG);
+
+        assertTrue(result, "expected the log level to have been changed");
+    }
+
+    @Test
+    void testAddListener() {
+        var callbackHolder = new AtomicReference<LogRecordCallback>();
+        var recordHolder = new AtomicReference<LogRecord>();
+        var record = new LogRecord.ByValue();
+        record.target = "popcorn_fx_torrent";
+        record.message = "lorem";
+        record.level = LogLevel.DEBUG;
+        doAnswer(invocation -> {
+            callbackHolder.set(invocation.getArgument(1, LogRecordCallback.class));
+            return null;
+        }).when(fxLib).register_log_record_callback(isA(PopcornFx.class), isA(LogRecordCallback.class));
+
+        service.addListener(recordHolder::set);
+        service.addListener(e -> {});
+        callbackHolder.get().callback(record);
+
+        verify(fxLib, times(1)).register_log_record_callback(isA(PopcornFx.class), any());
+        assertEquals(record, recordHolder.get());
+    }
+
+    @Test
+    void testRemoveListener() {
+        Consumer<LogRecord> listener = e -> {};
+        service.addListener(listener);
+
+        service.removeListener(listener);
+
+        verify(fxLib).unregister_log_record_callback(instance);
+    }
+}
@@ -19,7 +19,7 @@ import org.mockito.junit.jupiter.MockitoExtension;
 import java.util.Optional;
 import java.util.concurrent.atomic.AtomicReference;
 
-import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.*;
 import static org.mockito.ArgumentMatchers.isA;
 import static org.mockito.Mockito.*;
 
@@ -124,6 +124,35 @@ class PlaylistManagerTest {
         verify(fxLib).stop_playlist(instance);
     }
 
+    @Test
+    void testMoveItem() {
+        when(fxLib.move_playlist_item(isA(PopcornFx.class), isA(Integer.class), isA(Integer.class))).thenReturn((byte) 1);
+
+        var result = playlistManager.moveItem(2, 0);
+
+        assertTrue(result, "expected the item to have been moved");
+        verify(fxLib).move_playlist_item(instance, 2, 0);
+    }
+
+    @Test
+    void testRemoveItem() {
+        when(fxLib.remove_playlist_item(isA(PopcornFx.class), isA(Integer.class))).thenReturn((byte) 0);
+
+        var result = playlistManager.removeItem(5);
+
+        assertFalse(result, "expected the out of range item to not have been removed");
+        verify(fxLib).remove_playlist_item(instance, 5);
+    }
+
+    @Test
+    void testInsertItem() {
+        var item = new PlaylistItem.ByValue();
+
+        playlistManager.insertItem(1, item);
+
+        verify(fxLib).insert_playlist_item(instance, 1, item);
+    }
+
     @Test
     void testPlaylist() {
         var playlist = mock(Playlist.ByValue.class);
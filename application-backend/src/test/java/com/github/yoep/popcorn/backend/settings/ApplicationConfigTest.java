@@ -13,6 +13,7 @@ import org.mockito.junit.jupiter.MockitoExtension;
 import java.io.File;
 
 import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 import static org.mockito.Mockito.*;
 
@@ -96,6 +97,29 @@ class ApplicationConfigTest {
         verify(fxLib).update_torrent_settings(instance, expected);
     }
 
+    @Test
+    void testUpdateNetworkSettings() {
+        var settings = mock(ApplicationSettings.class);
+        var uiSettings = mock(UISettings.class);
+        when(fxLib.application_settings(instance)).thenReturn(settings);
+        when(settings.getUiSettings()).thenReturn(uiSettings);
+        when(uiSettings.getUiScale()).thenReturn(mock(UIScale.class));
+        when(uiSettings.getDefaultLanguage()).thenReturn("en-US");
+        var networkSettings = new NetworkSettings();
+        networkSettings.proxy = "http://localhost:3128";
+        networkSettings.requestTimeout = 30;
+        networkSettings.setSendCrashReports(true);
+        var expected = new NetworkSettings.ByValue();
+        expected.proxy = "http://localhost:3128";
+        expected.requestTimeout = 30;
+        expected.sendCrashReports = 1;
+        var config = new ApplicationConfig(fxLib, instance, localeText);
+
+        config.update(networkSettings);
+
+        verify(fxLib).update_network_settings(instance, expected);
+    }
+
     @Test
     void testIsTvMode() {
         var settings = mock(ApplicationSettings.class);
@@ -161,4 +185,55 @@ class ApplicationConfigTest {
         assertTrue(result);
         verify(fxLib).is_fx_video_player_enabled(instance);
     }
-}
\ No newline at end of file
+
+    @Test
+    void testExportSettings_failed() {
+        var settings = mock(ApplicationSettings.class);
+        var uiSettings = mock(UISettings.class);
+        var file = new File(workingDir, "export.json");
+        when(fxLib.application_settings(instance)).thenReturn(settings);
+        when(settings.getUiSettings()).thenReturn(uiSettings);
+        when(uiSettings.getUiScale()).thenReturn(mock(UIScale.class));
+        when(uiSettings.getDefaultLanguage()).thenReturn("en-US");
+        when(fxLib.export_settings(isA(PopcornFx.class), isA(String.class))).thenReturn((byte) 0);
+        var config = new ApplicationConfig(fxLib, instance, localeText);
+
+        assertThrows(SettingsException.class, () -> config.exportSettings(file));
+        verify(fxLib).export_settings(instance, file.getAbsolutePath());
+    }
+
+    @Test
+    void testImportSettings() {
+        var settings = mock(ApplicationSettings.class);
+        var uiSettings = mock(UISettings.class);
+        var file = new File(workingDir, "import.json");
+        var importResult = new SettingsImportResult.ByValue();
+        importResult.tag = SettingsImportResult.Tag.Ok;
+        when(fxLib.application_settings(instance)).thenReturn(settings);
+        when(settings.getUiSettings()).thenReturn(uiSettings);
+        when(uiSettings.getUiScale()).thenReturn(mock(UIScale.class));
+        when(uiSettings.getDefaultLanguage()).thenReturn("en-US");
+        when(fxLib.import_settings(isA(PopcornFx.class), isA(String.class))).thenReturn(importResult);
+        var config = new ApplicationConfig(fxLib, instance, localeText);
+
+        config.importSettings(file);
+
+        verify(fxLib).import_settings(instance, file.getAbsolutePath());
+        verify(fxLib).dispose_settings_import_result(importResult);
+    }
+
+    @Test
+    void testReset() {
+        var settings = mock(ApplicationSettings.class);
+        var uiSettings = mock(UISettings.class);
+        when(fxLib.application_settings(instance)).thenReturn(settings);
+        when(settings.getUiSettings()).thenReturn(uiSettings);
+        when(uiSettings.getUiScale()).thenReturn(mock(UIScale.class));
+        when(uiSettings.getDefaultLanguage()).thenReturn("en-US");
+        var config = new ApplicationConfig(fxLib, instance, localeText);
+
+        config.reset(SettingsSection.PLAYBACK);
+
+        verify(fxLib).reset_settings_section(instance, SettingsSection.PLAYBACK);
+    }
+}
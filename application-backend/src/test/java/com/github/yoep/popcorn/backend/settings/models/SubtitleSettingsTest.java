@@ -1,5 +1,6 @@
 package com.github.yoep.popcorn.backend.settings.models;
 
+import com.github.yoep.popcorn.backend.settings.models.subtitles.TvModeSubtitle;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.io.TempDir;
 
@@ -25,10 +26,12 @@ class SubtitleSettingsTest {
         var settings = new SubtitleSettings();
         settings.directory = workingDir.getAbsolutePath();
         settings.autoCleaningEnabled = (byte) 1;
+        settings.tvModeSubtitle = TvModeSubtitle.INTERFACE_LANGUAGE;
 
         var result = new SubtitleSettings.ByValue(settings);
 
         assertEquals(workingDir.getAbsolutePath(), result.directory);
         assertEquals(1, result.autoCleaningEnabled);
+        assertEquals(TvModeSubtitle.INTERFACE_LANGUAGE, result.tvModeSubtitle);
     }
 }
\ No newline at end of file
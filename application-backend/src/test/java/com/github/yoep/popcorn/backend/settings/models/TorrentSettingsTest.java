@@ -16,9 +16,11 @@ class TorrentSettingsTest {
         var settings = new TorrentSettings();
         settings.directory = workingDir.getAbsolutePath();
         settings.cleaningMode = CleaningMode.ON_SHUTDOWN;
+        settings.encryptionMode = EncryptionMode.REQUIRE;
         var expected = new TorrentSettings.ByValue();
         expected.directory = workingDir.getAbsolutePath();
         expected.cleaningMode = CleaningMode.ON_SHUTDOWN;
+        expected.encryptionMode = EncryptionMode.REQUIRE;
 
         var result = new TorrentSettings.ByValue(settings);
 
@@ -110,4 +110,11 @@ class SubtitleServiceImplTest {
 
         verify(fxLib).reset_subtitle(instance);
     }
+
+    @Test
+    void testClearCache() {
+        service.clearCache();
+
+        verify(fxLib).clear_subtitle_cache(instance);
+    }
 }
\ No newline at end of file
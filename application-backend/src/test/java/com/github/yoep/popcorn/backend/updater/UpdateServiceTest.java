@@ -121,6 +121,34 @@ class UpdateServiceTest {
         verify(fxLib).install_update(instance);
     }
 
+    @Test
+    void testGetChannel() {
+        when(fxLib.update_channel(instance)).thenReturn(UpdateChannel.BETA);
+        var service = new UpdateService(fxLib, instance, platform, eventPublisher, localeText);
+
+        var result = service.getChannel();
+
+        assertEquals(UpdateChannel.BETA, result);
+    }
+
+    @Test
+    void testSetChannel() {
+        var service = new UpdateService(fxLib, instance, platform, eventPublisher, localeText);
+
+        service.setChannel(UpdateChannel.BETA);
+
+        verify(fxLib).set_update_channel(instance, UpdateChannel.BETA);
+    }
+
+    @Test
+    void testRollbackUpdate() {
+        var service = new UpdateService(fxLib, instance, platform, eventPublisher, localeText);
+
+        service.rollbackUpdate();
+
+        verify(fxLib).rollback_update(instance);
+    }
+
     @Test
     void testCheckForUpdates() {
         var service = new UpdateService(fxLib, instance, platform, eventPublisher, localeText);
@@ -8,6 +8,7 @@ import com.github.yoep.popcorn.backend.adapters.video.VideoPlayback;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.events.EventPublisherBridge;
 import com.github.yoep.popcorn.backend.loader.LoaderService;
+import com.github.yoep.popcorn.backend.logging.BackendLogService;
 import com.github.yoep.popcorn.backend.media.favorites.FavoriteService;
 import com.github.yoep.popcorn.backend.media.providers.FavoriteProviderService;
 import com.github.yoep.popcorn.backend.media.providers.MovieProviderService;
@@ -97,6 +98,7 @@ public class PopcornTimeApplication extends Application {
             IOC.registerInstance(new ShowHelperService(localeText, watchedService));
             IOC.registerInstance(new SubtitlePickerService(localeText, viewManager, subtitleService));
             IOC.registerInstance(new TorrentCollectionService(fxLib, popcornFx));
+            IOC.registerInstance(new BackendLogService(fxLib, popcornFx));
 
             // services
             IOC.register(HealthService.class);
@@ -2,8 +2,10 @@ package com.github.yoep.popcorn.ui.screen;
 
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
+import com.github.yoep.popcorn.backend.adapters.screen.DisplayInfo;
 import com.github.yoep.popcorn.backend.adapters.screen.FullscreenCallback;
 import com.github.yoep.popcorn.backend.adapters.screen.IsFullscreenCallback;
+import com.github.yoep.popcorn.backend.adapters.screen.MoveToDisplayCallback;
 import com.github.yoep.popcorn.backend.adapters.screen.ScreenService;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.events.PlayerStoppedEvent;
@@ -14,8 +16,10 @@ import javafx.application.Platform;
 import javafx.beans.property.BooleanProperty;
 import javafx.beans.property.ReadOnlyBooleanProperty;
 import javafx.beans.property.SimpleBooleanProperty;
+import javafx.collections.ListChangeListener;
 import javafx.scene.input.KeyCode;
 import javafx.scene.input.KeyCombination;
+import javafx.stage.Screen;
 import javafx.stage.Stage;
 import lombok.extern.slf4j.Slf4j;
 
@@ -33,6 +37,7 @@ public class ScreenServiceImpl implements ScreenService {
     private final BooleanProperty fullscreen = new SimpleBooleanProperty(this, FULLSCREEN_PROPERTY, false);
     private final IsFullscreenCallback isFullscreenCallback = createIsFullscreenCallback();
     private final FullscreenCallback fullscreenCallback = createFullscreenCallback();
+    private final MoveToDisplayCallback moveToDisplayCallback = createMoveToDisplayCallback();
 
     private Stage primaryStage;
     private long lastChange;
@@ -97,6 +102,9 @@ public class ScreenServiceImpl implements ScreenService {
         });
         fxLib.register_is_fullscreen_callback(instance, isFullscreenCallback);
         fxLib.register_fullscreen_callback(instance, fullscreenCallback);
+        fxLib.register_move_to_display_callback(instance, moveToDisplayCallback);
+        Screen.getScreens().addListener((ListChangeListener<Screen>) change -> updateDisplays());
+        updateDisplays();
     }
 
     private void initializeViewManagerListeners() {
@@ -125,6 +133,55 @@ public class ScreenServiceImpl implements ScreenService {
         fullscreen.addListener((observable, oldValue, newValue) -> lastChange = System.currentTimeMillis());
     }
 
+    private void updateDisplays() {
+        var screens = Screen.getScreens();
+        if (screens.isEmpty()) {
+            log.warn("No displays are connected to the system");
+            return;
+        }
+
+        // the id of a display is its index within the connected screens
+        var displays = (DisplayInfo[]) new DisplayInfo.ByReference().toArray(screens.size());
+        for (int i = 0; i < screens.size(); i++) {
+            var screen = screens.get(i);
+            var bounds = screen.getBounds();
+            var display = displays[i];
+
+            display.id = i;
+            display.name = "Display " + (i + 1);
+            display.x = (int) bounds.getMinX();
+            display.y = (int) bounds.getMinY();
+            display.width = (int) bounds.getWidth();
+            display.height = (int) bounds.getHeight();
+            display.primary = (byte) (screen.equals(Screen.getPrimary()) ? 1 : 0);
+            display.write();
+        }
+
+        log.debug("Updating the connected displays to {}", displays.length);
+        fxLib.update_screen_displays(instance, (DisplayInfo.ByReference) displays[0], displays.length);
+    }
+
+    private void moveToDisplay(int id) {
+        Platform.runLater(() -> {
+            var screens = Screen.getScreens();
+            if (id < 0 || id >= screens.size() || primaryStage == null) {
+                log.warn("Unable to move the application to display {}", id);
+                return;
+            }
+
+            var bounds = screens.get(id).getVisualBounds();
+            var isFullscreen = primaryStage.isFullScreen();
+
+            log.debug("Moving the application to display {}", id);
+            lastChange = System.currentTimeMillis();
+            // the fullscreen needs to be exited before the stage can be moved to another display
+            primaryStage.setFullScreen(false);
+            primaryStage.setX(bounds.getMinX());
+            primaryStage.setY(bounds.getMinY());
+            primaryStage.setFullScreen(isFullscreen);
+        });
+    }
+
     private IsFullscreenCallback createIsFullscreenCallback() {
         return () -> (byte) (isFullscreen() ? 1 : 0);
     }
@@ -133,5 +190,9 @@ public class ScreenServiceImpl implements ScreenService {
         return activateFullscreen -> fullscreen(activateFullscreen == 1);
     }
 
+    private MoveToDisplayCallback createMoveToDisplayCallback() {
+        return this::moveToDisplay;
+    }
+
     //endregion
 }
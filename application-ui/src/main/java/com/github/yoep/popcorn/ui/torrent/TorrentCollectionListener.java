@@ -0,0 +1,8 @@
+package com.github.yoep.popcorn.ui.torrent;
+
+public interface TorrentCollectionListener {
+    /**
+     * Invoked when a torrent has been added to or removed from the torrent collection.
+     */
+    void onCollectionChanged();
+}
@@ -3,19 +3,30 @@ package com.github.yoep.popcorn.ui.torrent;
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentInfo;
+import com.github.yoep.popcorn.backend.services.AbstractListenerService;
+import com.github.yoep.popcorn.backend.torrent.collection.CollectionSort;
 import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrent;
-import lombok.RequiredArgsConstructor;
+import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrentSet;
+import com.github.yoep.popcorn.backend.torrent.collection.TorrentCollectionCallback;
+import com.github.yoep.popcorn.backend.torrent.collection.TorrentCollectionEvent;
 import lombok.extern.slf4j.Slf4j;
 
+import java.util.Collections;
 import java.util.List;
 import java.util.Objects;
+import java.util.Optional;
 
 @Slf4j
-@RequiredArgsConstructor
-public class TorrentCollectionService {
+public class TorrentCollectionService extends AbstractListenerService<TorrentCollectionListener> implements TorrentCollectionCallback {
     private final FxLib fxLib;
     private final PopcornFx instance;
 
+    public TorrentCollectionService(FxLib fxLib, PopcornFx instance) {
+        this.fxLib = fxLib;
+        this.instance = instance;
+        init();
+    }
+
     /**
      * Check if the given magnet uri has already been added to the torrent collection.
      *
@@ -38,6 +49,50 @@ public class TorrentCollectionService {
         }
     }
 
+    /**
+     * Search the stored torrents of which the name contains the given query.
+     *
+     * @param query The name query, an empty query returns all stored torrents.
+     * @param sort  The sort order of the stored torrents.
+     * @return Returns the matching stored torrents.
+     */
+    public List<StoredTorrent> searchStoredTorrents(String query, CollectionSort sort) {
+        Objects.requireNonNull(query, "query cannot be null");
+        Objects.requireNonNull(sort, "sort cannot be null");
+        try (var set = fxLib.torrent_collection_search(instance, query, sort)) {
+            return Optional.ofNullable(set)
+                    .map(StoredTorrentSet::getMagnets)
+                    .orElse(Collections.emptyList());
+        }
+    }
+
+    /**
+     * Retrieve the stored torrent with its resolved metadata, such as the total size and number of files.
+     * The metadata is only resolved the first time, after which the stored metadata is returned.
+     *
+     * @param magnetUri The magnet uri of the stored torrent.
+     * @return Returns the stored torrent if it could be resolved, else {@link Optional#empty()}.
+     */
+    public Optional<StoredTorrent> resolve(String magnetUri) {
+        Objects.requireNonNull(magnetUri, "magnetUri cannot be null");
+        return Optional.ofNullable(fxLib.torrent_collection_resolve(instance, magnetUri))
+                .map(e -> {
+                    e.close();
+                    fxLib.dispose_magnet_info(e);
+                    return e;
+                });
+    }
+
+    /**
+     * Mark the given magnet uri of the torrent collection as used.
+     *
+     * @param magnetUri The magnet uri which has been used.
+     */
+    public void markUsed(String magnetUri) {
+        Objects.requireNonNull(magnetUri, "magnetUri cannot be null");
+        fxLib.torrent_collection_mark_used(instance, magnetUri);
+    }
+
     /**
      * Add the given torrent to the torrent collection.
      *
@@ -57,4 +112,16 @@ public class TorrentCollectionService {
         Objects.requireNonNull(magnetUri, "magnetUri cannot be null");
         fxLib.torrent_collection_remove(instance, magnetUri);
     }
+
+    @Override
+    public void callback(TorrentCollectionEvent event) {
+        log.debug("Received torrent collection event {}", event);
+        if (event == TorrentCollectionEvent.COLLECTION_CHANGED) {
+            invokeListeners(TorrentCollectionListener::onCollectionChanged);
+        }
+    }
+
+    private void init() {
+        fxLib.register_torrent_collection_callback(instance, this);
+    }
 }
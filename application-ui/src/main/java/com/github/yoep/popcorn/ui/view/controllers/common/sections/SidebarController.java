@@ -1,5 +1,7 @@
 package com.github.yoep.popcorn.ui.view.controllers.common.sections;
 
+import com.github.yoep.popcorn.backend.FxLib;
+import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.events.ShowAboutEvent;
 import com.github.yoep.popcorn.backend.media.filters.model.Category;
@@ -32,6 +34,7 @@ import lombok.RequiredArgsConstructor;
 import lombok.extern.slf4j.Slf4j;
 
 import java.net.URL;
+import java.util.Optional;
 import java.util.ResourceBundle;
 
 @Slf4j
@@ -45,6 +48,8 @@ public class SidebarController implements Initializable {
     private final ViewLoader viewLoader;
     private final UpdateService updateService;
     private final LocaleText localeText;
+    private final FxLib fxLib;
+    private final PopcornFx instance;
 
     final FadeTransition slideAnimation = new FadeTransition(Duration.millis(500), new Pane());
     final Transition updateTransition = createColorTransition();
@@ -89,6 +94,7 @@ public class SidebarController implements Initializable {
         initializeSearch();
         initializeFocusListeners();
         initializeMode();
+        initializeCategories();
 
         sidebar.getColumnConstraints().get(0).setPrefWidth(searchIcon.getPrefWidth());
     }
@@ -155,6 +161,21 @@ public class SidebarController implements Initializable {
         }
     }
 
+    private void initializeCategories() {
+        if (!isCategorySupported(Category.MOVIES)) {
+            log.debug("Removing movies from sidebar, category is not supported by the providers");
+            sidebar.getChildren().removeAll(movieIcon, movieText);
+        }
+        if (!isCategorySupported(Category.SERIES)) {
+            log.debug("Removing series from sidebar, category is not supported by the providers");
+            sidebar.getChildren().removeAll(serieIcon, serieText);
+        }
+    }
+
+    private boolean isCategorySupported(Category category) {
+        return fxLib.is_category_supported(instance, category) == 1;
+    }
+
     private void onUpdateStateChanged(UpdateState newState) {
         Platform.runLater(() -> {
             if (newState == UpdateState.UPDATE_AVAILABLE) {
@@ -181,7 +202,10 @@ public class SidebarController implements Initializable {
      */
     private void activateStartCategory() {
         var settings = applicationConfig.getSettings().getUiSettings();
-        switchCategory(settings.getStartScreen(), true);
+        // the start pages without a media category don't have a page yet, so fallback to the movies
+        var category = Optional.ofNullable(settings.getStartPage().getCategory())
+                .orElse(Category.MOVIES);
+        switchCategory(category, true);
     }
 
     private void focusChanged(boolean newValue) {
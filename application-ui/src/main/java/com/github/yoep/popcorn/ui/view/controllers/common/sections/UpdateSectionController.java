@@ -5,6 +5,7 @@ import com.github.yoep.popcorn.backend.messages.UpdateMessage;
 import com.github.yoep.popcorn.backend.updater.DownloadProgress;
 import com.github.yoep.popcorn.backend.updater.UpdateService;
 import com.github.yoep.popcorn.backend.updater.UpdateState;
+import com.github.yoep.popcorn.backend.updater.VerificationProgress;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
 import com.github.yoep.popcorn.ui.events.CloseUpdateEvent;
 import com.github.yoep.popcorn.ui.view.controls.BackgroundImageCover;
@@ -58,6 +59,7 @@ public class UpdateSectionController implements Initializable {
             switch (event.getTag()) {
                 case StateChanged -> onUpdateStateChanged(event.getUnion().getState_changed().getNewState());
                 case DownloadProgress -> onUpdateDownloadProgress(event.getUnion().getDownload_progress().getDownloadProgress());
+                case VerificationProgress -> onUpdateVerificationProgress(event.getUnion().getVerification_progress().getVerificationProgress());
             }
         });
         updatePane.sceneProperty().addListener((observable, oldValue, newValue) -> {
@@ -78,6 +80,10 @@ public class UpdateSectionController implements Initializable {
         Platform.runLater(() -> {
             switch (newState) {
                 case DOWNLOADING -> handleStateChanged(UpdateMessage.STARTING_DOWNLOAD);
+                case VERIFYING -> {
+                    handleStateChanged(UpdateMessage.VERIFYING);
+                    progressBar.setProgress(ProgressIndicator.INDETERMINATE_PROGRESS);
+                }
                 case DOWNLOAD_FINISHED -> {
                     handleStateChanged(UpdateMessage.DOWNLOAD_FINISHED);
                     updateService.startUpdateInstallation();
@@ -101,6 +107,13 @@ public class UpdateSectionController implements Initializable {
         });
     }
 
+    private void onUpdateVerificationProgress(VerificationProgress verificationProgress) {
+        var totalTasks = Short.toUnsignedInt(verificationProgress.getTotalTasks());
+        var progress = totalTasks > 0 ? ((double) Short.toUnsignedInt(verificationProgress.getTask())) / totalTasks : 0.0;
+
+        Platform.runLater(() -> progressBar.setProgress(progress));
+    }
+
     private void handleStateChanged(UpdateMessage message) {
         progressBar.getStyleClass().removeIf(e -> e.equals(PROGRESS_ERROR_STYLE_CLASS));
         progressLabel.setText(localeText.get(message));
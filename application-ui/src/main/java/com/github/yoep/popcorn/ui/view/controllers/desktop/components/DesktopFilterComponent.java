@@ -48,7 +48,7 @@ public class DesktopFilterComponent implements Initializable {
     }
 
     private void updateGenres(Category category) {
-        try (var libGenres = fxLib.retrieve_provider_genres(instance, category.getProviderName())) {
+        try (var libGenres = fxLib.retrieve_available_genres(instance, category)) {
             var genres = libGenres.values().stream()
                     .map(e -> new Genre(e, localeText.get("genre_" + e)))
                     .sorted()
@@ -63,7 +63,7 @@ public class DesktopFilterComponent implements Initializable {
     }
 
     private void updateSortBy(Category category) {
-        try (var libSortBy = fxLib.retrieve_provider_sort_by(instance, category.getProviderName())) {
+        try (var libSortBy = fxLib.retrieve_available_sort_options(instance, category)) {
             var sortBy = libSortBy.values().stream()
                     .map(e -> new SortBy(e, localeText.get("sort-by_" + e)))
                     .toList();
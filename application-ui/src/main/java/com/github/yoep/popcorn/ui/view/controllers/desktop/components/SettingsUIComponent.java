@@ -3,6 +3,7 @@ package com.github.yoep.popcorn.ui.view.controllers.desktop.components;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
+import com.github.yoep.popcorn.backend.settings.models.StartPage;
 import com.github.yoep.popcorn.backend.settings.models.UIScale;
 import com.github.yoep.popcorn.backend.settings.models.UISettings;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
@@ -63,7 +64,7 @@ public class SettingsUIComponent extends AbstractSettingsUiComponent implements
         startScreen.setButtonCell(createStartScreenCell());
 
         startScreen.getItems().addAll(Category.values());
-        startScreen.getSelectionModel().select(getUiSettings().getStartScreen());
+        startScreen.getSelectionModel().select(getUiSettings().getStartPage().getCategory());
         startScreen.getSelectionModel().selectedItemProperty().addListener((observable, oldValue, newValue) -> updateStartScreen(newValue));
     }
 
@@ -89,7 +90,7 @@ public class SettingsUIComponent extends AbstractSettingsUiComponent implements
 
     private void updateStartScreen(Category startScreen) {
         var settings = getUiSettings();
-        settings.setStartScreen(startScreen);
+        settings.setStartPage(StartPage.of(startScreen).orElse(StartPage.MOVIES));
         applicationConfig.update(settings);
         showNotification();
     }
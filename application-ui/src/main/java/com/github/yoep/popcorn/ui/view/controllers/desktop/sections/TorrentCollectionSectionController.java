@@ -48,6 +48,7 @@ public class TorrentCollectionSectionController implements Initializable {
             updateTorrentCollection();
             return event;
         });
+        torrentCollectionService.addListener(this::updateTorrentCollection);
     }
 
     //endregion
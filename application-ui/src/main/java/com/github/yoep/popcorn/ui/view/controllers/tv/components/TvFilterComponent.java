@@ -114,7 +114,7 @@ public class TvFilterComponent implements Initializable {
     }
 
     private void updateGenres(Category category) {
-        try (var libGenres = fxLib.retrieve_provider_genres(instance, category.getProviderName())) {
+        try (var libGenres = fxLib.retrieve_available_genres(instance, category)) {
             var values = libGenres.values().stream()
                     .map(e -> new Genre(e, localeText.get("genre_" + e)))
                     .sorted()
@@ -129,7 +129,7 @@ public class TvFilterComponent implements Initializable {
     }
 
     private void updateSortBy(Category category) {
-        try (var libSortBy = fxLib.retrieve_provider_sort_by(instance, category.getProviderName())) {
+        try (var libSortBy = fxLib.retrieve_available_sort_options(instance, category)) {
             libSortBy.values().stream()
                     .findFirst()
                     .ifPresent(e -> eventPublisher.publish(new SortByChangeEvent(this, new SortBy(e, e))));
@@ -2,6 +2,7 @@ package com.github.yoep.popcorn.ui.view.controllers.tv.components;
 
 import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
+import com.github.yoep.popcorn.backend.settings.models.StartPage;
 import com.github.yoep.popcorn.backend.settings.models.UIScale;
 import com.github.yoep.popcorn.backend.settings.models.UISettings;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
@@ -77,11 +78,11 @@ public class TvSettingsUiComponent implements Initializable {
         startScreens.setItems(Category.values());
         startScreens.selectedItemProperty().addListener((observable, oldValue, newValue) -> {
             var settings = getSettings();
-            settings.setStartScreen(newValue);
+            settings.setStartPage(StartPage.of(newValue).orElse(StartPage.MOVIES));
             applicationConfig.update(settings);
             startScreen.setText(localeText.get("filter_" + newValue.name().toLowerCase()));
         });
-        startScreens.setSelectedItem(getSettings().getStartScreen());
+        startScreens.setSelectedItem(getSettings().getStartPage().getCategory());
     }
 
     private UISettings getSettings() {
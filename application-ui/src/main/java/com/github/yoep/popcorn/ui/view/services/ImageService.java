@@ -3,6 +3,7 @@ package com.github.yoep.popcorn.ui.view.services;
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.lib.ByteArray;
+import com.github.yoep.popcorn.backend.media.ImageCacheStats;
 import com.github.yoep.popcorn.backend.media.MediaItem;
 import com.github.yoep.popcorn.backend.media.providers.Media;
 import javafx.scene.image.Image;
@@ -140,6 +141,46 @@ public class ImageService {
         }, executorService);
     }
 
+    /**
+     * Load the given image, resized to fit within the given width and height.
+     * The image is resized by the backend, which reduces the image data that needs to be transferred for small tiles.
+     *
+     * @param url    The image url to load.
+     * @param width  The maximum width of the image, 0 for an unbounded width.
+     * @param height The maximum height of the image, 0 for an unbounded height.
+     * @return Returns the image data.
+     * @throws ImageException Is thrown when the image data failed to load.
+     */
+    public CompletableFuture<Image> load(String url, int width, int height) {
+        Objects.requireNonNull(url, "url cannot be null");
+        return CompletableFuture.supplyAsync(() -> {
+            try (var bytes = fxLib.load_image_resized(instance, url, width, height)) {
+                return Optional.ofNullable(bytes)
+                        .map(ByteArray::getBytes)
+                        .map(ByteArrayInputStream::new)
+                        .map(Image::new)
+                        .orElseThrow(() -> new ImageException(url, "Failed to load image data"));
+            }
+        }, executorService);
+    }
+
+    /**
+     * Retrieve the statistics of the image cache.
+     *
+     * @return Returns the image cache statistics.
+     */
+    public ImageCacheStats getCacheStats() {
+        return fxLib.image_cache_stats(instance);
+    }
+
+    /**
+     * Remove all cached images from the image cache.
+     */
+    public void clearCache() {
+        log.debug("Clearing the image cache");
+        fxLib.clear_image_cache(instance);
+    }
+
     /**
      * Load an image from the images resources.
      *
@@ -2,8 +2,10 @@ package com.github.yoep.popcorn.ui.screen;
 
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
+import com.github.yoep.popcorn.backend.adapters.screen.DisplayInfo;
 import com.github.yoep.popcorn.backend.adapters.screen.FullscreenCallback;
 import com.github.yoep.popcorn.backend.adapters.screen.IsFullscreenCallback;
+import com.github.yoep.popcorn.backend.adapters.screen.MoveToDisplayCallback;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.events.PlayerStoppedEvent;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
@@ -14,6 +16,7 @@ import javafx.beans.property.ReadOnlyProperty;
 import javafx.beans.property.SimpleBooleanProperty;
 import javafx.beans.value.ChangeListener;
 import javafx.scene.input.KeyCombination;
+import javafx.stage.Screen;
 import javafx.stage.Stage;
 import org.junit.jupiter.api.BeforeEach;
 import org.junit.jupiter.api.Test;
@@ -67,6 +70,8 @@ class ScreenServiceImplTest {
 
         verify(fxLib).register_fullscreen_callback(eq(instance), isA(FullscreenCallback.class));
         verify(fxLib).register_is_fullscreen_callback(eq(instance), isA(IsFullscreenCallback.class));
+        verify(fxLib).register_move_to_display_callback(eq(instance), isA(MoveToDisplayCallback.class));
+        verify(fxLib).update_screen_displays(eq(instance), isA(DisplayInfo.ByReference.class), eq(Screen.getScreens().size()));
         verify(eventPublisher).register(eq(PlayerStoppedEvent.class), isA(Function.class));
     }
 
@@ -3,12 +3,16 @@ package com.github.yoep.popcorn.ui.torrent;
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.adapters.torrent.model.TorrentInfo;
+import com.github.yoep.popcorn.backend.torrent.collection.CollectionSort;
+import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrent;
+import com.github.yoep.popcorn.backend.torrent.collection.TorrentCollectionEvent;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.extension.ExtendWith;
 import org.mockito.InjectMocks;
 import org.mockito.Mock;
 import org.mockito.junit.jupiter.MockitoExtension;
 
+import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 import static org.mockito.ArgumentMatchers.isA;
 import static org.mockito.Mockito.*;
@@ -22,6 +26,21 @@ class TorrentCollectionServiceTest {
     @InjectMocks
     private TorrentCollectionService service;
 
+    @Test
+    void testInit() {
+        verify(fxLib).register_torrent_collection_callback(instance, service);
+    }
+
+    @Test
+    void testCallback_CollectionChanged() {
+        var listener = mock(TorrentCollectionListener.class);
+        service.addListener(listener);
+
+        service.callback(TorrentCollectionEvent.COLLECTION_CHANGED);
+
+        verify(listener).onCollectionChanged();
+    }
+
     @Test
     void testIsStored() {
         var magnetUri = "magnet:MyMagnetUri";
@@ -45,4 +64,37 @@ class TorrentCollectionServiceTest {
 
         verify(fxLib).torrent_collection_add(instance, name, magnetUri);
     }
+
+    @Test
+    void testResolve() {
+        var magnetUri = "magnet:MyMagnetUri";
+        var storedTorrent = new StoredTorrent.ByReference();
+        storedTorrent.totalFiles = 2;
+        when(fxLib.torrent_collection_resolve(isA(PopcornFx.class), isA(String.class))).thenReturn(storedTorrent);
+
+        var result = service.resolve(magnetUri);
+
+        assertTrue(result.isPresent(), "expected the stored torrent to have been resolved");
+        assertEquals(2, result.get().getTotalFiles());
+        verify(fxLib).dispose_magnet_info(storedTorrent);
+    }
+
+    @Test
+    void testMarkUsed() {
+        var magnetUri = "magnet:MyMagnetUri";
+
+        service.markUsed(magnetUri);
+
+        verify(fxLib).torrent_collection_mark_used(instance, magnetUri);
+    }
+
+    @Test
+    void testSearchStoredTorrents_whenSearchFailed_shouldReturnEmptyList() {
+        when(fxLib.torrent_collection_search(isA(PopcornFx.class), isA(String.class), isA(CollectionSort.class))).thenReturn(null);
+
+        var result = service.searchStoredTorrents("lorem", CollectionSort.NAME);
+
+        assertTrue(result.isEmpty(), "expected an empty list to be returned");
+        verify(fxLib).torrent_collection_search(instance, "lorem", CollectionSort.NAME);
+    }
 }
\ No newline at end of file
@@ -1,10 +1,13 @@
 package com.github.yoep.popcorn.ui.view.controllers.common.sections;
 
+import com.github.yoep.popcorn.backend.FxLib;
+import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.events.EventPublisher;
 import com.github.yoep.popcorn.backend.events.ShowAboutEvent;
 import com.github.yoep.popcorn.backend.media.filters.model.Category;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
 import com.github.yoep.popcorn.backend.settings.models.ApplicationSettings;
+import com.github.yoep.popcorn.backend.settings.models.StartPage;
 import com.github.yoep.popcorn.backend.settings.models.UISettings;
 import com.github.yoep.popcorn.backend.updater.UpdateService;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
@@ -54,6 +57,10 @@ class SidebarControllerTest {
     private UpdateService updateService;
     @Mock
     private LocaleText localeText;
+    @Mock
+    private FxLib fxLib;
+    @Mock
+    private PopcornFx instance;
     @Spy
     private EventPublisher eventPublisher = new EventPublisher(false);
     @Mock
@@ -68,6 +75,7 @@ class SidebarControllerTest {
         lenient().when(applicationConfig.getSettings()).thenReturn(applicationSettings);
         lenient().when(applicationSettings.getUiSettings()).thenReturn(settings);
         lenient().when(viewLoader.load(isA(String.class))).thenReturn(new Pane());
+        lenient().when(fxLib.is_category_supported(isA(PopcornFx.class), isA(Category.class))).thenReturn((byte) 1);
 
         controller.sidebar = new GridPane();
         controller.searchIcon = new Icon("searchIcon");
@@ -100,9 +108,20 @@ class SidebarControllerTest {
         controller.infoIcon.setOnKeyPressed(controller::onInfoPressed);
     }
 
+    @Test
+    void testInitialize_whenCategoryIsNotSupported_shouldRemoveCategoryFromSidebar() {
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
+        when(fxLib.is_category_supported(instance, Category.SERIES)).thenReturn((byte) 0);
+
+        controller.initialize(url, resourceBundle);
+
+        assertTrue(controller.sidebar.getChildren().contains(controller.movieIcon));
+        assertFalse(controller.sidebar.getChildren().contains(controller.serieIcon));
+    }
+
     @Test
     void testInitialize_shouldActivePreferredDefaultCategory() {
-        when(settings.getStartScreen()).thenReturn(Category.SERIES);
+        when(settings.getStartPage()).thenReturn(StartPage.SERIES);
         var expectedEvent = new CategoryChangedEvent(controller, Category.SERIES);
 
         controller.initialize(url, resourceBundle);
@@ -123,7 +142,7 @@ class SidebarControllerTest {
     void testOnCategoryClicked() {
         var event = mock(MouseEvent.class);
         when(event.getSource()).thenReturn(controller.favoriteIcon);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         when(event.getSource()).thenReturn(controller.favoriteIcon);
         controller.initialize(url, resourceBundle);
         WaitForAsyncUtils.waitForFxEvents();
@@ -160,7 +179,7 @@ class SidebarControllerTest {
     @Test
     void testOnCategoryPressed() {
         var event = mock(KeyEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         when(event.getTarget()).thenReturn(controller.favoriteIcon);
         when(event.getCode()).thenReturn(KeyCode.ENTER);
         controller.initialize(url, resourceBundle);
@@ -174,7 +193,7 @@ class SidebarControllerTest {
     @Test
     void testOnHovering() {
         var event = mock(MouseEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onHovering(event);
@@ -187,7 +206,7 @@ class SidebarControllerTest {
     @Test
     void testOnHoverStopped() {
         var event = mock(MouseEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onHoverStopped(event);
@@ -200,7 +219,7 @@ class SidebarControllerTest {
     @Test
     void testOnSettingsClicked() {
         var event = mock(MouseEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onSettingsClicked(event);
@@ -213,7 +232,7 @@ class SidebarControllerTest {
     void testOnSettingsPressed() {
         var event = mock(KeyEvent.class);
         when(event.getCode()).thenReturn(KeyCode.ENTER);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onSettingsPressed(event);
@@ -226,7 +245,7 @@ class SidebarControllerTest {
     @Test
     void testOnInfoClicked() {
         var event = mock(MouseEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onInfoClicked(event);
@@ -240,7 +259,7 @@ class SidebarControllerTest {
     void testOnInfoPressed() {
         var event = mock(KeyEvent.class);
         when(event.getCode()).thenReturn(KeyCode.ENTER);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         controller.initialize(url, resourceBundle);
 
         controller.onInfoPressed(event);
@@ -255,7 +274,7 @@ class SidebarControllerTest {
         var event = mock(MouseEvent.class);
         var trigger = new CompletableFuture<Void>();
         when(event.getSource()).thenReturn(controller.serieIcon);
-        when(settings.getStartScreen()).thenReturn(Category.MOVIES);
+        when(settings.getStartPage()).thenReturn(StartPage.MOVIES);
         eventPublisher.register(ShowSettingsEvent.class, e -> {
             trigger.complete(null);
             return null;
@@ -298,7 +317,7 @@ class SidebarControllerTest {
 
     @Test
     void testHomeEvent() {
-        when(settings.getStartScreen()).thenReturn(Category.SERIES);
+        when(settings.getStartPage()).thenReturn(StartPage.SERIES);
         controller.initialize(url, resourceBundle);
 
         eventPublisher.publishEvent(new HomeEvent(this));
@@ -310,7 +329,7 @@ class SidebarControllerTest {
     @Test
     void testCollectionClicked() {
         var event = mock(MouseEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.SERIES);
+        when(settings.getStartPage()).thenReturn(StartPage.SERIES);
         controller.initialize(url, resourceBundle);
 
         controller.onCollectionClicked(event);
@@ -323,7 +342,7 @@ class SidebarControllerTest {
     @Test
     void testCollectionPressed() {
         var event = mock(KeyEvent.class);
-        when(settings.getStartScreen()).thenReturn(Category.SERIES);
+        when(settings.getStartPage()).thenReturn(StartPage.SERIES);
         when(event.getCode()).thenReturn(KeyCode.ENTER);
         controller.initialize(url, resourceBundle);
 
@@ -336,7 +355,7 @@ class SidebarControllerTest {
 
     @Test
     void testInitializeTvMode() {
-        when(settings.getStartScreen()).thenReturn(Category.SERIES);
+        when(settings.getStartPage()).thenReturn(StartPage.SERIES);
         when(applicationConfig.isTvMode()).thenReturn(true);
 
         controller.initialize(url, resourceBundle);
@@ -99,4 +99,28 @@ class UpdateSectionControllerTest {
 
         WaitForAsyncUtils.waitFor(200, TimeUnit.MILLISECONDS, () -> controller.progressBar.getProgress() == 0.5);
     }
+
+    @Test
+    void testOnVerificationProgress() throws TimeoutException {
+        var listenerHolder = new AtomicReference<UpdateCallback>();
+        var event = new UpdateCallbackEvent.ByValue();
+        event.tag = UpdateCallbackEvent.Tag.VerificationProgress;
+        event.union = new UpdateCallbackEvent.UpdateEventCUnion.ByValue();
+        event.union.verification_progress = new UpdateCallbackEvent.VerificationProgressBody();
+        event.union.verification_progress.verificationProgress = new VerificationProgress();
+        event.union.verification_progress.verificationProgress.task = 1;
+        event.union.verification_progress.verificationProgress.totalTasks = 4;
+        doAnswer(invocation -> {
+            listenerHolder.set(invocation.getArgument(0));
+            return null;
+        }).when(updateService).register(isA(UpdateCallback.class));
+        when(updateService.getState()).thenReturn(UpdateState.VERIFYING);
+        when(imageService.loadResource(isA(String.class))).thenReturn(new CompletableFuture<>());
+        controller.initialize(url, resourceBundle);
+
+        var listener = listenerHolder.get();
+        listener.callback(event);
+
+        WaitForAsyncUtils.waitFor(200, TimeUnit.MILLISECONDS, () -> controller.progressBar.getProgress() == 0.25);
+    }
 }
\ No newline at end of file
@@ -67,8 +67,8 @@ class DesktopFilterComponentTest {
         var event = new CategoryChangedEvent(component, Category.FAVORITES);
         var genres = mock(FxStringArray.class);
         var sortBy = mock(FxStringArray.class);
-        when(fxLib.retrieve_provider_genres(instance, Category.FAVORITES.getProviderName())).thenReturn(genres);
-        when(fxLib.retrieve_provider_sort_by(instance, Category.FAVORITES.getProviderName())).thenReturn(sortBy);
+        when(fxLib.retrieve_available_genres(instance, Category.FAVORITES)).thenReturn(genres);
+        when(fxLib.retrieve_available_sort_options(instance, Category.FAVORITES)).thenReturn(sortBy);
         when(genres.values()).thenReturn(Collections.singletonList("lorem"));
         when(sortBy.values()).thenReturn(Collections.singletonList("ipsum"));
         when(localeText.get("genre_lorem")).thenReturn(displayText);
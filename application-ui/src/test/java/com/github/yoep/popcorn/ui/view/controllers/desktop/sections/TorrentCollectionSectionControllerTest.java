@@ -5,6 +5,7 @@ import com.github.yoep.popcorn.backend.loader.LoaderService;
 import com.github.yoep.popcorn.backend.torrent.collection.StoredTorrent;
 import com.github.yoep.popcorn.backend.utils.LocaleText;
 import com.github.yoep.popcorn.ui.events.ShowTorrentCollectionEvent;
+import com.github.yoep.popcorn.ui.torrent.TorrentCollectionListener;
 import com.github.yoep.popcorn.ui.torrent.TorrentCollectionService;
 import com.github.yoep.popcorn.ui.torrent.controls.TorrentCollection;
 import javafx.scene.layout.Pane;
@@ -21,7 +22,10 @@ import org.testfx.util.WaitForAsyncUtils;
 import java.net.URL;
 import java.util.Collections;
 import java.util.ResourceBundle;
+import java.util.concurrent.atomic.AtomicReference;
 
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.mockito.ArgumentMatchers.isA;
 import static org.mockito.Mockito.*;
 
 @ExtendWith({MockitoExtension.class, ApplicationExtension.class})
@@ -61,4 +65,22 @@ class TorrentCollectionSectionControllerTest {
 
         verify(loaderService).load(uri);
     }
+
+    @Test
+    void testOnCollectionChanged() {
+        var listenerHolder = new AtomicReference<TorrentCollectionListener>();
+        var torrent = mock(StoredTorrent.class);
+        doAnswer(invocation -> {
+            listenerHolder.set(invocation.getArgument(0, TorrentCollectionListener.class));
+            return null;
+        }).when(torrentCollectionService).addListener(isA(TorrentCollectionListener.class));
+        when(torrentCollectionService.getStoredTorrents()).thenReturn(Collections.singletonList(torrent));
+        controller.initialize(url, resourceBundle);
+
+        listenerHolder.get().onCollectionChanged();
+        WaitForAsyncUtils.waitForFxEvents();
+
+        assertEquals(1, controller.collection.getItems().size());
+        assertEquals(torrent, controller.collection.getItems().get(0));
+    }
 }
\ No newline at end of file
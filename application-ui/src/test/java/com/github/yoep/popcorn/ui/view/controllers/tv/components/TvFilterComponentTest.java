@@ -85,7 +85,7 @@ class TvFilterComponentTest {
         var genreValues = mock(FxStringArray.class);
         component.initialize(url, resourceBundle);
         when(genreValues.values()).thenReturn(Collections.singletonList("lorem"));
-        when(fxLib.retrieve_provider_genres(instance, category.getProviderName())).thenReturn(genreValues);
+        when(fxLib.retrieve_available_genres(instance, category)).thenReturn(genreValues);
 
         eventPublisher.publish(new CategoryChangedEvent(this, category));
         WaitForAsyncUtils.waitForFxEvents();
@@ -4,6 +4,7 @@ import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
 import com.github.yoep.popcorn.backend.lib.ByteArray;
 import com.github.yoep.popcorn.backend.lib.NativeString;
+import com.github.yoep.popcorn.backend.media.ImageCacheStats;
 import com.github.yoep.popcorn.backend.media.MediaItem;
 import com.github.yoep.popcorn.backend.media.providers.Images;
 import com.github.yoep.popcorn.backend.media.providers.MovieDetails;
@@ -120,6 +121,37 @@ class ImageServiceTest {
         assertNotNull(image);
     }
 
+    @Test
+    void testLoadResized() throws ExecutionException, InterruptedException {
+        var url = "http://localhost/image.png";
+        var byteArray = mock(ByteArray.class);
+        when(byteArray.getBytes()).thenReturn(new byte[0]);
+        when(fxLib.load_image_resized(instance, url, 200, 0)).thenReturn(byteArray);
+
+        var future = imageService.load(url, 200, 0);
+        var image = future.get();
+
+        assertNotNull(image);
+    }
+
+    @Test
+    void testGetCacheStats() {
+        var stats = new ImageCacheStats.ByValue();
+        stats.entries = 5;
+        when(fxLib.image_cache_stats(instance)).thenReturn(stats);
+
+        var result = imageService.getCacheStats();
+
+        assertEquals(stats, result);
+    }
+
+    @Test
+    void testClearCache() {
+        imageService.clearCache();
+
+        verify(fxLib).clear_image_cache(instance);
+    }
+
     private MovieDetails createMovie(Images images) {
         return MovieDetails.builder()
                 .images(images)
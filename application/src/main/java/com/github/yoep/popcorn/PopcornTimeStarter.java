@@ -6,6 +6,7 @@ import com.github.yoep.player.popcorn.player.EmbeddablePopcornPlayer;
 import com.github.yoep.player.popcorn.player.PopcornPlayer;
 import com.github.yoep.player.popcorn.services.*;
 import com.github.yoep.popcorn.backend.FxLib;
+import com.github.yoep.popcorn.backend.adapters.torrent.TorrentSettingsService;
 import com.github.yoep.popcorn.backend.lib.FxLibInstance;
 import com.github.yoep.popcorn.backend.lib.PopcornFxInstance;
 import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
@@ -124,6 +125,7 @@ public class PopcornTimeStarter {
         ioC.register(TorrentServiceImpl.class);
         ioC.register(TorrentSessionManagerImpl.class);
         ioC.register(TorrentSettingsServiceImpl.class);
+        ioC.registerInstance(new TorrentSettingsSynchronizer(ioC.getInstance(ApplicationConfig.class), ioC.getInstance(TorrentSettingsService.class)));
         ioC.registerInstance(new TorrentResolverService(ioC.getInstance(TorrentSessionManager.class), client));
     }
 
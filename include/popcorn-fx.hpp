@@ -24,6 +24,18 @@ enum class CleaningMode : int32_t {
   Watched = 2,
 };
 
+/// The sorting options of the magnets within the [Collection].
+enum class CollectionSort : int32_t {
+  /// Sort the magnets alphabetically by their name.
+  Name = 0,
+  /// Sort the magnets by the moment they have been added, newest first.
+  AddedAt = 1,
+  /// Sort the magnets by the moment they have been last used, most recent first.
+  LastUsed = 2,
+  /// Sort the magnets by their total size, largest first.
+  Size = 3,
+};
+
 /// The decoration to apply to the subtitle during rendering.
 enum class DecorationType : int32_t {
   None = 0,
@@ -32,6 +44,29 @@ enum class DecorationType : int32_t {
   SeeThroughBackground = 3,
 };
 
+/// The encryption mode of the peer connections, also known as Message Stream Encryption (MSE/PE).
+enum class EncryptionMode : int32_t {
+  /// Encrypted connections are preferred, but peers which don't support encryption are connected in plaintext.
+  Prefer = 0,
+  /// Only encrypted connections are allowed, plaintext peers are rejected.
+  Require = 1,
+  /// Encryption is disabled, all connections are plaintext.
+  Disable = 2,
+};
+
+/// The policy which is applied when a new media item is loaded while another item is still loading.
+///
+/// Downloads without playback are not affected by the policy and are always started right away.
+enum class LoadingPolicy {
+  /// Start the new load right away, next to the other loading tasks.
+  /// The loading tasks aren't queued, so they're not tracked as active or queued task.
+  Concurrent,
+  /// Cancel the active and queued loading tasks in favor of the new load.
+  CancelActive,
+  /// Queue the new load behind the active and already queued loading tasks.
+  Queue,
+};
+
 enum class LoadingState : int32_t {
   Initializing,
   Starting,
@@ -62,6 +97,14 @@ enum class MediaErrorC : int32_t {
   Failed = 0,
   NoItemsFound = 1,
   NoAvailableProviders = 2,
+  /// The connection with the media provider couldn't be established, e.g. no network connection.
+  ProviderConnectionFailed = 3,
+  /// The media provider responded with an unexpected status code.
+  ProviderRequestFailed = 4,
+  /// The response of the media provider couldn't be parsed.
+  ProviderParsingFailed = 5,
+  /// The media type is not supported for the requested action.
+  MediaTypeNotSupported = 6,
 };
 
 enum class MediaTrackingSyncState : int32_t {
@@ -69,6 +112,16 @@ enum class MediaTrackingSyncState : int32_t {
   Failed = 1,
 };
 
+/// The platform type
+enum class PlatformType : int32_t {
+  /// The windows platform
+  Windows = 0,
+  /// The macos platform
+  MacOs = 1,
+  /// The linux platform
+  Linux = 2,
+};
+
 /// Events related to playback control, triggered by the media system of the OS.
 /// These events can be used to modify the player state based on the given media event.
 enum class PlaybackControlEvent : int32_t {
@@ -124,6 +177,14 @@ enum class PlaylistState : int32_t {
   Error,
 };
 
+/// The quality of the poster images which are requested by the application.
+/// A lower quality reduces the bandwidth and cache size of the posters.
+enum class PosterQuality {
+  Low,
+  Medium,
+  High,
+};
+
 /// The playback quality defined in a resolution size
 enum class Quality {
   P480,
@@ -132,6 +193,25 @@ enum class Quality {
   P2160,
 };
 
+/// The sections of the user settings which can be managed individually.
+enum class SettingsSection : int32_t {
+  Subtitle = 0,
+  Torrent = 1,
+  Ui = 2,
+  Server = 3,
+  Playback = 4,
+  Tracking = 5,
+  Network = 6,
+};
+
+/// The pages of the application which can be shown on startup.
+enum class StartPage {
+  Movies,
+  Series,
+  Favorites,
+  ContinueWatching,
+};
+
 /// The supported subtitle fonts to use for rendering subtitles.
 enum class SubtitleFamily : int32_t {
   Arial = 0,
@@ -182,6 +262,23 @@ enum class SubtitleLanguage : int32_t {
   Vietnamese = 35,
 };
 
+/// The type of a subtitle, indicating its format.
+enum class SubtitleType : int32_t {
+  /// SubRip subtitle format.
+  Srt = 0,
+  /// WebVTT subtitle format.
+  Vtt = 1,
+  /// Plain text transcript of the subtitle dialogue, without any timing information.
+  /// This is an output only format, it can't be parsed back into cues.
+  PlainText = 2,
+};
+
+/// The events of the torrent collection.
+enum class TorrentCollectionEvent : int32_t {
+  /// Invoked when a magnet has been added to or removed from the collection.
+  CollectionChanged = 0,
+};
+
 /// The state of a [Torrent] which is represented as a [i32].
 /// This state is abi compatible to be used over [std::ffi].
 enum class TorrentState : int32_t {
@@ -213,6 +310,25 @@ enum class TorrentStreamState : int32_t {
   Stopped = 2,
 };
 
+/// The BitTorrent protocol version of a torrent.
+enum class TorrentVersion {
+  /// The torrent only contains v1 (SHA-1) piece hashes.
+  V1,
+  /// The torrent only contains v2 (SHA-256) merkle trees.
+  V2,
+  /// The torrent contains both v1 piece hashes and v2 merkle trees.
+  Hybrid,
+};
+
+/// The default subtitle selection which is applied when the application runs in TV or kiosk mode.
+/// An explicit default subtitle language of the user always takes precedence over this selection.
+enum class TvModeSubtitle : int32_t {
+  /// Select the subtitle based on the user preference, the same as outside TV mode.
+  UserPreference = 0,
+  /// Enable the subtitle in the interface language when no default subtitle language has been set.
+  InterfaceLanguage = 1,
+};
+
 /// The C compatible update state
 enum class UpdateStateC : int32_t {
   CheckingForNewVersion = 0,
@@ -224,6 +340,43 @@ enum class UpdateStateC : int32_t {
   Installing = 5,
   InstallationFinished = 6,
   Error = 7,
+  /// Indicates that the downloaded update is being verified.
+  Verifying = 8,
+};
+
+/// The C compatible reason of a failed update process.
+enum class UpdateErrorC : int32_t {
+  /// The update channel or download couldn't be reached, e.g. no network connection.
+  ConnectionFailed = 0,
+  /// The configured update channel is invalid.
+  InvalidUpdateChannel = 1,
+  /// The update channel returned an invalid response.
+  InvalidResponse = 2,
+  /// The version information of the application or update is invalid.
+  InvalidVersion = 3,
+  /// No update is available for the current platform.
+  PlatformUpdateUnavailable = 4,
+  /// The update download failed.
+  DownloadFailed = 5,
+  /// The update couldn't be written to or read from the disk.
+  IO = 6,
+  /// The update couldn't be extracted.
+  ExtractionFailed = 7,
+  /// The update action isn't allowed in the current update state.
+  UpdateNotAvailable = 8,
+  /// The downloaded update doesn't match the expected checksum.
+  ChecksumMismatch = 9,
+  /// No previous version is available to roll back to.
+  RollbackUnavailable = 10,
+};
+
+/// The update channel from which new application versions are received.
+/// This channel is abi compatible to be used over [std::ffi].
+enum class UpdateChannel : int32_t {
+  /// The channel which only receives stable releases.
+  Stable = 0,
+  /// The channel which receives pre-releases before they're promoted to stable.
+  Beta = 1,
 };
 
 template<typename T = void>
@@ -235,11 +388,24 @@ struct CancelTorrentCallback;
 /// A type representing a callback function to set the fullscreen state of the application.
 struct FullscreenCallback;
 
+/// A callback function type for pausing or resuming a torrent.
+///
+/// The function takes a `String` argument representing the handle of the torrent,
+/// and a `bool` indicating whether the torrent should be paused (`true`) or resumed (`false`).
+struct PauseTorrentCallback;
+
 struct PlayerWrapperC;
 
 /// The [PopcornFX] application instance.
 /// This is the main entry into the FX application and manages all known data.
 ///
+/// # Thread safety
+///
+/// The instance can be shared between threads, all FFI functions only borrow it through a shared reference.
+/// Mutable state is guarded by the services of the instance, which makes concurrent calls safe.
+/// The only exception is [dispose_popcorn_fx], which takes ownership of the instance
+/// and should only be invoked once all other calls have completed.
+///
 /// # Examples
 ///
 /// Create a simple instance with default values.
@@ -411,6 +577,28 @@ struct MediaItemC {
   EpisodeC *episode;
 };
 
+/// A C-compatible struct representing a media item which can be continued watching.
+struct ContinueWatchingItemC {
+  /// A pointer to the media item to continue watching.
+  MediaItemC *media;
+  /// A pointer to the parent media item, if applicable.
+  MediaItemC *parent_media;
+  /// The playback progress of the media item as a percentage.
+  float progress;
+  /// A pointer to the timestamp to resume the playback from, if applicable.
+  const uint64_t *timestamp;
+};
+
+/// A C-compatible struct representing the connection test result of a single provider uri.
+struct ProviderConnectionResultC {
+  /// The tested provider uri.
+  char *uri;
+  /// The measured latency in millis, only applicable when the connection succeeded.
+  uint64_t latency;
+  /// The reason why the connection failed, or a null pointer when the connection succeeded.
+  char *error;
+};
+
 /// The C compatible subtitle settings.
 struct SubtitleSettingsC {
   /// The directory path for storing subtitles
@@ -428,6 +616,16 @@ struct SubtitleSettingsC {
   DecorationType decoration;
   /// Indicates if the subtitle should be rendered in a bold font
   bool bold;
+  /// The max number of characters per line of a converted subtitle, `0` disables the line wrapping
+  uint32_t max_line_length;
+  /// The max number of lines per converted subtitle cue
+  uint32_t max_lines;
+  /// The default subtitle selection in TV or kiosk mode
+  TvModeSubtitle tv_mode_subtitle;
+  /// The custom OpenSubtitles API key, can be `ptr::null()`
+  char *api_key;
+  /// The OpenSubtitles user token, can be `ptr::null()`
+  char *user_token;
 };
 
 /// The C compatible torrent settings.
@@ -442,6 +640,36 @@ struct TorrentSettingsC {
   uint32_t download_rate_limit;
   /// The upload rate limit
   uint32_t upload_rate_limit;
+  /// The idle timeout in seconds of a stream, `0` disables the stopping of idle streams
+  uint32_t stream_idle_timeout;
+  /// Indicates if the torrent of an idle stream should be removed
+  bool remove_idle_torrents;
+  /// The local address to which the torrent traffic is bound, can be `ptr::null()`
+  char *bind_interface;
+  /// The encryption mode of the peer connections
+  EncryptionMode encryption_mode;
+  /// Indicates if finished stream torrents are automatically added to the torrent collection
+  bool auto_collect_finished;
+  /// The initial buffer size in bytes of a stream
+  uint32_t initial_buffer_size;
+  /// The peer connect timeout in seconds
+  uint32_t peer_connect_timeout;
+  /// Indicates if torrents are announced to all trackers and tiers at once
+  bool announce_to_all_trackers;
+  /// The block request timeout in seconds
+  uint32_t request_timeout;
+  /// The path to the peer ip blocklist file, can be `ptr::null()`
+  char *ip_filter;
+  /// Indicates if the torrent files are fully allocated on disk
+  bool preallocate;
+  /// Indicates if the DHT node runs in read-only mode
+  bool dht_read_only;
+  /// The max size in bytes of the torrent metadata which is accepted from peers
+  uint32_t max_metadata_size;
+  /// The max number of outstanding block requests to a single peer
+  uint32_t max_request_queue_depth;
+  /// Indicates if the session listens on both IPv4 and IPv6
+  bool dual_stack;
 };
 
 /// The UI scale of the application
@@ -455,8 +683,10 @@ struct UiSettingsC {
   char *default_language;
   /// The ui scale of the application
   UiScale ui_scale;
-  /// The default start screen of the application
-  Category start_screen;
+  /// The page which is shown when the application is started
+  StartPage start_page;
+  /// The quality of the poster images which are requested
+  PosterQuality poster_quality;
   /// The indication if the UI was maximized the last time the application was closed
   bool maximized;
   /// The indication if the UI should use a native window rather than the borderless stage
@@ -477,6 +707,10 @@ struct PlaybackSettingsC {
   bool fullscreen;
   /// Indicates if the next episode of the show will be played
   bool auto_play_next_episode_enabled;
+  /// The id of the default player, or [ptr::null_mut] if no default player has been set
+  char *default_player;
+  /// The percentage of the media which should have been played before it's marked as watched
+  uint32_t watched_threshold_percent;
 };
 
 /// Represents the C-compatible struct for the last sync.
@@ -493,6 +727,24 @@ struct TrackingSettingsC {
   LastSyncC *last_sync;
 };
 
+/// The C compatible network settings.
+struct NetworkSettingsC {
+  /// The proxy uri through which requests are sent, can be `ptr::null()`
+  char *proxy;
+  /// The username to authenticate with the proxy, can be `ptr::null()`
+  char *proxy_username;
+  /// The password to authenticate with the proxy, can be `ptr::null()`
+  char *proxy_password;
+  /// The comma separated hosts which bypass the proxy, can be `ptr::null()`
+  char *proxy_bypass;
+  /// The custom user agent of the requests, can be `ptr::null()`
+  char *user_agent;
+  /// The timeout in seconds of each request, 0 disables the timeout
+  uint64_t request_timeout;
+  /// Indicates if the last crash report is sent along with the next update check
+  bool send_crash_reports;
+};
+
 /// The C compatible application settings.
 struct PopcornSettingsC {
   /// The subtitle settings of the application
@@ -507,6 +759,8 @@ struct PopcornSettingsC {
   PlaybackSettingsC playback_settings;
   /// The tracking settings of the application
   TrackingSettingsC tracking_settings;
+  /// The network settings of the application
+  NetworkSettingsC network_settings;
 };
 
 /// A C-compatible byte array that can be used to return byte array data from Rust functions.
@@ -520,6 +774,25 @@ struct ByteArray {
   int32_t len;
 };
 
+/// The C-compatible representation of the [ImageCacheStats] struct.
+///
+/// # Fields
+///
+/// * `entries` - The number of cached image urls.
+/// * `size` - The total size in bytes of the cached image files.
+/// * `max_size` - The maximum size in bytes of the cached image files.
+/// * `hits` - The number of requests which have been served from the cache.
+/// * `misses` - The number of requests which couldn't be served from the cache.
+/// * `revalidations` - The number of stale cached images which have been revalidated.
+struct ImageCacheStatsC {
+  int32_t entries;
+  uint64_t size;
+  uint64_t max_size;
+  uint64_t hits;
+  uint64_t misses;
+  uint64_t revalidations;
+};
+
 /// The C compatible [SubtitleFile] representation.
 struct SubtitleFileC {
   int32_t file_id;
@@ -592,6 +865,8 @@ struct TorrentInfoC {
   int32_t total_files;
   /// A set of `TorrentFileInfoC` structs representing individual files within the torrent.
   CArray<TorrentFileInfoC> files;
+  /// The BitTorrent protocol version of the torrent, being v1, v2 or hybrid.
+  TorrentVersion version;
 };
 
 /// The C compatible [Event] representation.
@@ -617,6 +892,9 @@ struct EventC {
     TorrentDetailsLoaded,
     /// Invoked when the player should be closed
     ClosePlayer,
+    /// Invoked when an unexpected error occurred within the application
+    /// 1st argument is a pointer to the error message (C string)
+    Error,
   };
 
   struct PlayerChanged_Body {
@@ -636,12 +914,17 @@ struct EventC {
     TorrentInfoC _0;
   };
 
+  struct Error_Body {
+    char *_0;
+  };
+
   Tag tag;
   union {
     PlayerChanged_Body player_changed;
     PlaybackStateChanged_Body playback_state_changed;
     WatchStateChanged_Body watch_state_changed;
     TorrentDetailsLoaded_Body torrent_details_loaded;
+    Error_Body error;
   };
 };
 
@@ -729,6 +1012,28 @@ struct LoadingErrorC {
   };
 };
 
+/// A C-compatible struct representing the progress of a specific loading stage.
+struct LoadingStageProgressC {
+  /// The loading stage to which the progress applies.
+  LoadingState stage;
+  /// The progress of the stage as a percentage between 0 and 100.
+  float percent;
+  /// Additional information about the progress of the stage, or `ptr::null()` if not available.
+  char *detail;
+};
+
+/// A C-compatible struct representing the quality which has been selected for a media item.
+struct QualitySelectionC {
+  /// The selected quality, e.g. `1080p`.
+  char *quality;
+  /// Indicates if the quality has been selected automatically.
+  bool auto_selected;
+  /// The display text of the selection, e.g. `Auto (1080p)`.
+  char *label;
+  /// The newline separated reasons which lead to the selected quality.
+  char *reasons;
+};
+
 /// A C-compatible enum representing loader events.
 struct LoaderEventC {
   enum class Tag {
@@ -736,6 +1041,8 @@ struct LoaderEventC {
     StateChanged,
     ProgressChanged,
     LoaderError,
+    StageProgressChanged,
+    QualitySelected,
   };
 
   struct LoadingStarted_Body {
@@ -758,12 +1065,24 @@ struct LoaderEventC {
     LoadingErrorC _1;
   };
 
+  struct StageProgressChanged_Body {
+    int64_t _0;
+    LoadingStageProgressC _1;
+  };
+
+  struct QualitySelected_Body {
+    int64_t _0;
+    QualitySelectionC _1;
+  };
+
   Tag tag;
   union {
     LoadingStarted_Body loading_started;
     StateChanged_Body state_changed;
     ProgressChanged_Body progress_changed;
     LoaderError_Body loader_error;
+    StageProgressChanged_Body stage_progress_changed;
+    QualitySelected_Body quality_selected;
   };
 };
 
@@ -785,6 +1104,12 @@ struct PlayerEventC {
     TimeChanged,
     StateChanged,
     VolumeChanged,
+    Reconnecting,
+    Reconnected,
+    ReconnectFailed,
+    AudioTrackChanged,
+    SubtitleTrackChanged,
+    SubtitleChanged,
   };
 
   struct DurationChanged_Body {
@@ -803,15 +1128,53 @@ struct PlayerEventC {
     uint32_t _0;
   };
 
+  struct Reconnecting_Body {
+    uint32_t _0;
+  };
+
+  struct AudioTrackChanged_Body {
+    int32_t _0;
+  };
+
+  struct SubtitleTrackChanged_Body {
+    int32_t _0;
+  };
+
+  struct SubtitleChanged_Body {
+    SubtitleLanguage _0;
+  };
+
   Tag tag;
   union {
     DurationChanged_Body duration_changed;
     TimeChanged_Body time_changed;
     StateChanged_Body state_changed;
     VolumeChanged_Body volume_changed;
+    Reconnecting_Body reconnecting;
+    AudioTrackChanged_Body audio_track_changed;
+    SubtitleTrackChanged_Body subtitle_track_changed;
+    SubtitleChanged_Body subtitle_changed;
   };
 };
 
+/// A C-compatible struct representing a media track of the active playback.
+struct MediaTrackC {
+  /// The unique identifier of the track within the media.
+  int32_t id;
+  /// A pointer to a null-terminated C string representing the name of the track.
+  char *name;
+  /// A pointer to a null-terminated C string representing the language of the track, or [ptr::null_mut] if unknown.
+  char *language;
+};
+
+/// Represents a set of media tracks in C-compatible form.
+struct MediaTrackSet {
+  /// Pointer to an array of media tracks.
+  MediaTrackC *tracks;
+  /// Length of the media track array.
+  int32_t len;
+};
+
 /// Represents a play request in C-compatible form.
 struct PlayRequestC {
   /// The URL of the media to be played.
@@ -833,6 +1196,10 @@ struct PlayRequestC {
   int64_t *stream_handle;
   /// Indicates whether subtitles are enabled for the media.
   bool subtitles_enabled;
+  /// The url on which the active subtitle is being served, or [ptr::null_mut] if not available.
+  char *subtitle_url;
+  /// The audio tracks which are available within the media, or [ptr::null_mut] if unknown.
+  MediaTrackSet *audio_tracks;
 };
 
 /// Represents events related to player management in C-compatible form.
@@ -850,6 +1217,20 @@ struct PlayerManagerEventC {
     PlayerTimeChanged,
     /// Indicates a change in the state of a player.
     PlayerStateChanged,
+    /// Indicates that the active player is trying to reconnect, containing the current attempt.
+    PlayerReconnecting,
+    /// Indicates that the active player has been reconnected.
+    PlayerReconnected,
+    /// Indicates that the active player gave up on reconnecting.
+    PlayerReconnectFailed,
+    /// Indicates a change in the active audio track of the active player.
+    PlayerAudioTrackChanged,
+    /// Indicates a change in the active embedded subtitle track of the active player.
+    PlayerSubtitleTrackChanged,
+    /// Indicates a change in the active subtitle of the active player.
+    PlayerSubtitleChanged,
+    /// Indicates that the playback fell back to another player.
+    PlayerFallback,
   };
 
   struct ActivePlayerChanged_Body {
@@ -872,6 +1253,26 @@ struct PlayerManagerEventC {
     PlayerState _0;
   };
 
+  struct PlayerReconnecting_Body {
+    uint32_t _0;
+  };
+
+  struct PlayerAudioTrackChanged_Body {
+    int32_t _0;
+  };
+
+  struct PlayerSubtitleTrackChanged_Body {
+    int32_t _0;
+  };
+
+  struct PlayerSubtitleChanged_Body {
+    SubtitleLanguage _0;
+  };
+
+  struct PlayerFallback_Body {
+    PlayerChangedEventC _0;
+  };
+
   Tag tag;
   union {
     ActivePlayerChanged_Body active_player_changed;
@@ -879,6 +1280,11 @@ struct PlayerManagerEventC {
     PlayerDurationChanged_Body player_duration_changed;
     PlayerTimeChanged_Body player_time_changed;
     PlayerStateChanged_Body player_state_changed;
+    PlayerReconnecting_Body player_reconnecting;
+    PlayerAudioTrackChanged_Body player_audio_track_changed;
+    PlayerSubtitleTrackChanged_Body player_subtitle_track_changed;
+    PlayerSubtitleChanged_Body player_subtitle_changed;
+    PlayerFallback_Body player_fallback;
   };
 };
 
@@ -921,6 +1327,8 @@ struct PlaylistManagerEventC {
     PlayingNext,
     /// Represents a state change event in the playlist manager.
     StateChanged,
+    /// Represents a playlist item which has been skipped while restoring the playlist.
+    ItemSkipped,
   };
 
   struct PlayingNext_Body {
@@ -931,10 +1339,15 @@ struct PlaylistManagerEventC {
     PlaylistState _0;
   };
 
+  struct ItemSkipped_Body {
+    char *_0;
+  };
+
   Tag tag;
   union {
     PlayingNext_Body playing_next;
     StateChanged_Body state_changed;
+    ItemSkipped_Body item_skipped;
   };
 };
 
@@ -947,6 +1360,27 @@ struct StringArray {
   int32_t len;
 };
 
+/// The C compatible result of importing the application settings.
+struct SettingsImportResultC {
+  enum class Tag {
+    /// Indicates that the settings have been imported
+    Ok,
+    /// Indicates that the settings file could not be read
+    ReadFailed,
+    /// Indicates that the settings file contains unknown or invalid fields
+    InvalidFields,
+  };
+
+  struct InvalidFields_Body {
+    StringArray _0;
+  };
+
+  Tag tag;
+  union {
+    InvalidFields_Body invalid_fields;
+  };
+};
+
 struct StyledTextC {
   char *text;
   bool italic;
@@ -986,12 +1420,28 @@ struct SubtitleC {
   int32_t len;
 };
 
+/// The C array of [SubtitleCue] items.
+struct SubtitleCueSet {
+  /// The subtitle cue array
+  SubtitleCueC *cues;
+  /// The length of the array
+  int32_t len;
+};
+
 /// The C compatible struct for [MagnetInfo].
 struct MagnetInfoC {
   /// The name of the magnet
   char *name;
   /// The magnet uri to the torrent
   char *magnet_uri;
+  /// The unix timestamp in millis at which the magnet has been added, or `0` when unknown
+  int64_t added_at;
+  /// The unix timestamp in millis at which the magnet has been last used, or `0` when never used
+  int64_t last_used_at;
+  /// The total size in bytes of the torrent files, or `0` when not resolved
+  uint64_t total_size;
+  /// The total number of files within the torrent, or `0` when not resolved
+  uint32_t total_files;
 };
 
 /// The collection of stored magnets.
@@ -1018,6 +1468,48 @@ struct DownloadStatusC {
   uint64_t downloaded;
   /// The total size of the torrent in bytes.
   uint64_t total_size;
+  /// The effective block request queue depth of the connected peers.
+  uint32_t request_queue_depth;
+};
+
+/// Represents the summary of an active torrent in C-compatible form.
+struct TorrentSummaryC {
+  /// The unique handle of the torrent.
+  char *handle;
+  /// The info hash of the torrent, or `ptr::null()` if not known.
+  char *info_hash;
+  /// The name of the torrent file which is being downloaded.
+  char *name;
+  /// The last known state of the torrent.
+  TorrentState state;
+  /// The last known progress between 0 and 1 of the torrent.
+  float progress;
+  /// The last known download rate in bytes per second.
+  uint32_t download_speed;
+  /// The last known upload rate in bytes per second.
+  uint32_t upload_speed;
+};
+
+/// Represents a downloaded byte range of a torrent stream in C-compatible form.
+struct ByteRangeC {
+  /// The first byte of the range, inclusive.
+  uint64_t start;
+  /// The last byte of the range, exclusive.
+  uint64_t end;
+};
+
+/// Represents the buffer health of a torrent stream in C-compatible form.
+struct StreamBufferStatusC {
+  /// The current playback offset within the stream in bytes.
+  uint64_t offset;
+  /// The contiguous downloaded bytes ahead of the playback offset.
+  uint64_t buffered_bytes;
+  /// The initial amount of bytes which should be buffered before the playback is started.
+  uint64_t initial_buffer_size;
+  /// The current download rate in bytes per second.
+  uint32_t download_speed;
+  /// The estimated time in millis until the initial buffer has been filled, or `-1` when unknown.
+  int64_t eta;
 };
 
 /// Represents a torrent stream event in C-compatible form.
@@ -1027,6 +1519,8 @@ struct TorrentStreamEventC {
     StateChanged,
     /// Indicates a change in the download status of the torrent stream.
     DownloadStatus,
+    /// Indicates a change in the buffer health of the torrent stream.
+    BufferStatus,
   };
 
   struct StateChanged_Body {
@@ -1037,13 +1531,49 @@ struct TorrentStreamEventC {
     DownloadStatusC _0;
   };
 
+  struct BufferStatus_Body {
+    StreamBufferStatusC _0;
+  };
+
   Tag tag;
   union {
     StateChanged_Body state_changed;
     DownloadStatus_Body download_status;
+    BufferStatus_Body buffer_status;
+  };
+};
+
+/// Represents an event of a single torrent in C-compatible form.
+struct TorrentHandleEventC {
+  enum class Tag {
+    /// Indicates a change in the state of the torrent.
+    StateChanged,
+    /// Indicates that the metadata of the torrent has been received.
+    MetadataReceived,
+    /// Indicates that the torrent has completed downloading.
+    Completed,
+    /// Indicates that the torrent has been removed, no further events will be received.
+    Removed,
+  };
+
+  struct StateChanged_Body {
+    TorrentState _0;
+  };
+
+  Tag tag;
+  union {
+    StateChanged_Body state_changed;
   };
 };
 
+/// Represents the info hash of a torrent in C-compatible form.
+struct InfoHashC {
+  /// The v1 (SHA-1) info hash as hex string, or `ptr::null()` if not available.
+  char *v1;
+  /// The v2 (SHA-256) info hash as hex string, or `ptr::null()` if not available.
+  char *v2;
+};
+
 /// Represents a C-compatible tracking event.
 struct TrackingEventC {
   enum class Tag {
@@ -1091,6 +1621,12 @@ struct PlayerSet {
 /// with the specified signature.
 using EventCCallback = void(*)(EventC);
 
+/// A type alias for a C-compatible event subscriber callback function.
+///
+/// The callback receives the `EventC`, the sequence number of the event,
+/// and a flag indicating if the event is being replayed.
+using EventSubscriberCCallback = void(*)(EventC, uint64_t, bool);
+
 struct FavoriteEventC {
   enum class Tag {
     /// Event indicating that the like state of a media item changed.
@@ -1117,6 +1653,69 @@ struct FavoriteEventC {
 /// whether the application is in fullscreen mode.
 using IsFullscreenCallback = bool(*)();
 
+/// Type alias for a callback which moves the application to the display with the given id.
+using MoveToDisplayCallbackC = void(*)(uint32_t);
+
+/// The C compatible representation of a connected display.
+struct DisplayInfoC {
+  /// The unique id of the display
+  uint32_t id;
+  /// The name of the display
+  char *name;
+  /// The horizontal position of the display within the virtual screen
+  int32_t x;
+  /// The vertical position of the display within the virtual screen
+  int32_t y;
+  /// The width of the display in pixels
+  uint32_t width;
+  /// The height of the display in pixels
+  uint32_t height;
+  /// Indicates if the display is the primary display of the system
+  bool primary;
+};
+
+/// Represents an event of the screen service in C-compatible form.
+struct ScreenEventC {
+  enum class Tag {
+    /// Indicates a change in the fullscreen state of the application.
+    FullscreenChanged,
+    /// Indicates that the application has been moved to another display.
+    DisplayChanged,
+  };
+
+  struct FullscreenChanged_Body {
+    bool _0;
+  };
+
+  struct DisplayChanged_Body {
+    uint32_t _0;
+  };
+
+  Tag tag;
+  union {
+    FullscreenChanged_Body fullscreen_changed;
+    DisplayChanged_Body display_changed;
+  };
+};
+
+/// Type alias for a callback that handles the screen events.
+using ScreenEventCallbackC = void(*)(ScreenEventC);
+
+/// The C compatible representation of a backend log record.
+struct LogRecordC {
+  /// The unix timestamp in millis at which the record has been logged
+  int64_t timestamp;
+  /// The level of the record
+  LogLevel level;
+  /// The target (module) of the record
+  char *target;
+  /// The formatted message of the record
+  char *message;
+};
+
+/// The C callback type which receives the forwarded backend log records.
+using LogRecordCallbackC = void(*)(LogRecordC);
+
 /// A C-compatible callback function type for loader events.
 using LoaderEventCallback = void(*)(LoaderEventC);
 
@@ -1138,6 +1737,9 @@ using PlayerSeekCallback = void(*)(uint64_t);
 /// A C-compatible callback function type for player stop events.
 using PlayerStopCallback = void(*)();
 
+/// A C-compatible callback function type for player audio track selection events.
+using PlayerAudioTrackCallback = void(*)(int32_t);
+
 /// A C-compatible struct representing player registration information.
 struct PlayerRegistrationC {
   /// A pointer to a null-terminated C string representing the player's unique identifier (ID).
@@ -1165,6 +1767,8 @@ struct PlayerRegistrationC {
   PlayerSeekCallback seek_callback;
   /// A callback function pointer for the "stop" action.
   PlayerStopCallback stop_callback;
+  /// A callback function pointer for the "audio track" selection action.
+  PlayerAudioTrackCallback audio_track_callback;
 };
 
 /// A C-compatible callback function type for player manager events.
@@ -1193,6 +1797,10 @@ struct ApplicationConfigEventC {
     PlaybackSettingsChanged,
     /// Invoked when the tracking settings have been changed
     TrackingSettingsChanged,
+    /// Invoked when the network settings have been changed
+    NetworkSettingsChanged,
+    /// Invoked when the persisted settings couldn't be loaded, e.g. because a migration failed
+    SettingsError,
   };
 
   struct SubtitleSettingsChanged_Body {
@@ -1219,6 +1827,10 @@ struct ApplicationConfigEventC {
     TrackingSettingsC _0;
   };
 
+  struct NetworkSettingsChanged_Body {
+    NetworkSettingsC _0;
+  };
+
   Tag tag;
   union {
     SubtitleSettingsChanged_Body subtitle_settings_changed;
@@ -1227,6 +1839,7 @@ struct ApplicationConfigEventC {
     ServerSettingsChanged_Body server_settings_changed;
     PlaybackSettingsChanged_Body playback_settings_changed;
     TrackingSettingsChanged_Body tracking_settings_changed;
+    NetworkSettingsChanged_Body network_settings_changed;
   };
 };
 
@@ -1261,12 +1874,33 @@ using SubtitleCallbackC = void(*)(SubtitleEventC);
 /// Type alias for a callback that handles torrent stream events.
 using TorrentStreamEventCallback = void(*)(TorrentStreamEventC);
 
+/// Type alias for a callback that handles the events of a single torrent.
+using TorrentHandleEventCallback = void(*)(TorrentHandleEventC);
+
 /// Type alias for the C-compatible authorization open function.
 using AuthorizationOpenC = bool(*)(char *uri);
 
 /// Type alias for the C-compatible tracking event callback function.
 using TrackingEventCCallback = void(*)(TrackingEventC event);
 
+/// The C compatible representation of the platform information, used for diagnostic purposes.
+struct PlatformInfoC {
+  /// The platform type
+  PlatformType platform_type;
+  /// The number of logical cpu cores available to the application
+  uint32_t cpu_cores;
+  /// The total memory of the system in bytes
+  uint64_t total_memory;
+  /// The available memory of the system in bytes
+  uint64_t available_memory;
+  /// The free disk space in bytes of the application data directory, if known
+  uint64_t *data_free_space;
+  /// The free disk space in bytes of the torrent directory, if known
+  uint64_t *torrent_free_space;
+  /// The total number of crashes which have been reported by the application
+  uint32_t crash_count;
+};
+
 /// The C compatible representation of the application runtime information.
 struct PatchInfoC {
   /// The runtime version of the application.
@@ -1289,9 +1923,11 @@ struct VersionInfoC {
 ///
 /// * `total_size` - The total size of the update download in bytes.
 /// * `downloaded` - The total number of bytes downloaded so far.
+/// * `percentage` - The download progress as a percentage between 0 and 100.
 struct DownloadProgressC {
   uint64_t total_size;
   uint64_t downloaded;
+  float percentage;
 };
 
 /// The C-compatible representation of the [InstallationProgress] struct.
@@ -1308,6 +1944,17 @@ struct InstallationProgressC {
   uint16_t total_tasks;
 };
 
+/// The C-compatible representation of the [VerificationProgress] struct.
+///
+/// # Fields
+///
+/// * `task` - The current task being verified.
+/// * `total_tasks` - The total number of tasks that need to be verified.
+struct VerificationProgressC {
+  uint16_t task;
+  uint16_t total_tasks;
+};
+
 /// The C compatible representation of the update events.
 ///
 /// This enum maps to the `UpdateEvent` enum but with C-compatible data types.
@@ -1317,12 +1964,17 @@ struct InstallationProgressC {
 /// * `StateChanged(state)` - Invoked when the state of the updater has changed
 /// * `UpdateAvailable(version)` - Invoked when a new update is available
 /// * `DownloadProgress(progress)` - Invoked when the update download progresses
+/// * `InstallationProgress(progress)` - Invoked when the update installation progresses
+/// * `Failed(error)` - Invoked when the update process failed
+/// * `VerificationProgress(progress)` - Invoked when the update verification progresses
 struct UpdateEventC {
   enum class Tag {
     StateChanged,
     UpdateAvailable,
     DownloadProgress,
     InstallationProgress,
+    Failed,
+    VerificationProgress,
   };
 
   struct StateChanged_Body {
@@ -1341,12 +1993,22 @@ struct UpdateEventC {
     InstallationProgressC _0;
   };
 
+  struct Failed_Body {
+    UpdateErrorC _0;
+  };
+
+  struct VerificationProgress_Body {
+    VerificationProgressC _0;
+  };
+
   Tag tag;
   union {
     StateChanged_Body state_changed;
     UpdateAvailable_Body update_available;
     DownloadProgress_Body download_progress;
     InstallationProgress_Body installation_progress;
+    Failed_Body failed;
+    VerificationProgress_Body verification_progress;
   };
 };
 
@@ -1439,23 +2101,78 @@ extern "C" {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerC` instance representing the active player, or a null pointer if there is no active player.
-PlayerC *active_player(PopcornFX *popcorn_fx);
+PlayerC *active_player(const PopcornFX *popcorn_fx);
+
+/// Retrieve the audio tracks of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a `MediaTrackSet` containing the audio tracks,
+/// or a null pointer if there is no active player or the active player is unable to enumerate the tracks.
+MediaTrackSet *active_player_audio_tracks(const PopcornFX *popcorn_fx);
+
+/// Retrieve the embedded subtitle tracks of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a `MediaTrackSet` containing the embedded subtitle tracks,
+/// or a null pointer if there is no active player or the active player is unable to enumerate the tracks.
+MediaTrackSet *active_player_embedded_subtitle_tracks(const PopcornFX *popcorn_fx);
+
+/// Select the audio track of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `track_id` - The unique identifier of the audio track to select.
+void active_player_set_audio_track(const PopcornFX *popcorn_fx, int32_t track_id);
+
+/// Select the embedded subtitle track of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `track_id` - The unique identifier of the embedded subtitle track to select.
+void active_player_set_embedded_subtitle_track(const PopcornFX *popcorn_fx, int32_t track_id);
+
+/// Retrieve the summaries of all active torrents.
+///
+/// The summaries are a snapshot of the last known stats of the torrents,
+/// which makes it cheap to list all torrents at once.
+/// Torrents which are being removed are not included.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+///
+/// # Returns
+///
+/// A CArray of TorrentSummaryC representing the active torrents.
+CArray<TorrentSummaryC> active_torrents(const PopcornFX *popcorn_fx);
 
 /// Add the media item to the favorites.
 /// Duplicate favorite media items are ignored.
-void add_to_favorites(PopcornFX *popcorn_fx, const MediaItemC *favorite);
+void add_to_favorites(const PopcornFX *popcorn_fx, const MediaItemC *favorite);
 
 /// Add the given media item to the watched list.
-void add_to_watched(PopcornFX *popcorn_fx, const MediaItemC *watchable);
+void add_to_watched(const PopcornFX *popcorn_fx, const MediaItemC *watchable);
 
 /// Retrieve the application settings.
 /// These are the setting preferences of the users for the popcorn FX instance.
-PopcornSettingsC *application_settings(PopcornFX *popcorn_fx);
+PopcornSettingsC *application_settings(const PopcornFX *popcorn_fx);
 
 /// Retrieve the default artwork (placeholder) image data as a C-compatible byte array.
 ///
@@ -1464,19 +2181,30 @@ PopcornSettingsC *application_settings(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
-ByteArray *artwork_placeholder(PopcornFX *popcorn_fx);
+ByteArray *artwork_placeholder(const PopcornFX *popcorn_fx);
+
+/// Retrieve the update channels which can be selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A C-compatible array of the available update channels.
+CArray<UpdateChannel> available_update_channels(const PopcornFX *popcorn_fx);
 
 /// Start polling the update channel for new application versions.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
-void check_for_updates(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+void check_for_updates(const PopcornFX *popcorn_fx);
 
 /// Clean the subtitles directory.
 ///
@@ -1487,12 +2215,65 @@ void check_for_updates(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
-void cleanup_subtitles_directory(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+void cleanup_subtitles_directory(const PopcornFX *popcorn_fx);
 
 /// Clean the torrents directory.
 /// This will remove all existing torrents from the system.
-void cleanup_torrents_directory(PopcornFX *popcorn_fx);
+void cleanup_torrents_directory(const PopcornFX *popcorn_fx);
+
+/// Remove all cached images from the image cache.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+void clear_image_cache(const PopcornFX *popcorn_fx);
+
+/// Clear the cache of the downloaded subtitle files.
+/// The next download of a subtitle will retrieve the file again from the subtitle provider.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+void clear_subtitle_cache(const PopcornFX *popcorn_fx);
+
+/// Compute the info hash of the given magnet uri or torrent file path without adding the torrent.
+///
+/// # Arguments
+///
+/// * `uri_or_path` - The magnet uri or the path to a torrent file.
+///
+/// # Returns
+///
+/// It returns the computed info hash, or a null pointer when the info hash couldn't be computed.
+InfoHashC *compute_info_hash(char *uri_or_path);
+
+/// Retrieve the media items which can be continued watching.
+///
+/// The items are ordered by the most recently played first, and at most `limit` items are returned.
+/// When an episode has been finished, the next episode of the show is returned instead.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `limit` - The maximum number of items to retrieve.
+///
+/// # Returns
+///
+/// A CArray of ContinueWatchingItemC representing the continue watching items.
+CArray<ContinueWatchingItemC> continue_watching(const PopcornFX *popcorn_fx, uint32_t limit);
+
+/// Retrieve the id of the default player which is used when no player has been selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a null-terminated C string representing the default player id,
+/// or a null pointer if no default player has been set.
+char *default_player(const PopcornFX *popcorn_fx);
 
 /// Retrieve the default options available for the subtitles.
 ///
@@ -1503,19 +2284,19 @@ void cleanup_torrents_directory(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// A pointer to a `SubtitleInfoSet` instance.
-SubtitleInfoSet *default_subtitle_options(PopcornFX *popcorn_fx);
+SubtitleInfoSet *default_subtitle_options(const PopcornFX *popcorn_fx);
 
 /// Disable the subtitle track on request of the user.
 /// This will make the [is_subtitle_disabled] return `true`.
-void disable_subtitle(PopcornFX *popcorn_fx);
+void disable_subtitle(const PopcornFX *popcorn_fx);
 
 /// Starts the discovery process for external players such as VLC and DLNA servers.
-void discover_external_players(PopcornFX *popcorn_fx);
+void discover_external_players(const PopcornFX *popcorn_fx);
 
 /// Frees the memory allocated for the given C-compatible byte array.
 ///
@@ -1526,6 +2307,20 @@ void discover_external_players(PopcornFX *popcorn_fx);
 /// This function should only be called on C-compatible byte arrays that have been allocated by Rust.
 void dispose_byte_array(Box<ByteArray> array);
 
+/// Dispose of a C-style array of byte ranges.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ByteRangeC` to be disposed of.
+void dispose_byte_range_set(Box<CArray<ByteRangeC>> set);
+
+/// Dispose of a C-style array of continue watching items.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ContinueWatchingItemC` to be disposed of.
+void dispose_continue_watching_set(Box<CArray<ContinueWatchingItemC>> set);
+
 /// Dispose of the given event from the event bridge.
 ///
 /// This function takes ownership of a boxed `EventC` object, releasing its resources.
@@ -1544,6 +2339,9 @@ void dispose_event_value(EventC event);
 /// * `favorites` - A C-compatible favorites collection to be disposed of.
 void dispose_favorites(Box<VecFavoritesC> favorites);
 
+/// Frees the memory allocated for the `InfoHashC` structure.
+void dispose_info_hash(Box<InfoHashC> info_hash);
+
 /// Dispose of a C-compatible LoaderEventC value.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible LoaderEventC value.
@@ -1553,9 +2351,19 @@ void dispose_favorites(Box<VecFavoritesC> favorites);
 /// * `event` - A C-compatible LoaderEventC value to be disposed of.
 void dispose_loader_event_value(LoaderEventC event);
 
-/// Dispose of a C-compatible MediaItemC value wrapped in a Box.
+/// Dispose of a C-style array of loading handles.
 ///
-/// This function is responsible for cleaning up resources associated with a C-compatible MediaItemC value
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of loading handles to be disposed of.
+void dispose_loading_handle_set(Box<CArray<int64_t>> set);
+
+/// Dispose the [MagnetInfoC] from memory.
+void dispose_magnet_info(Box<MagnetInfoC> info);
+
+/// Dispose of a C-compatible MediaItemC value wrapped in a Box.
+///
+/// This function is responsible for cleaning up resources associated with a C-compatible MediaItemC value
 /// wrapped in a Box.
 ///
 /// # Arguments
@@ -1581,6 +2389,25 @@ void dispose_media_item_value(MediaItemC media);
 /// * `media` - A C-compatible media set to be disposed of.
 void dispose_media_items(MediaSetC media);
 
+/// Disposes of the `MediaTrackSet` instance and deallocates its memory.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `set` pointer.
+///
+/// # Arguments
+///
+/// * `set` - A box containing the `MediaTrackSet` instance to be disposed of.
+void dispose_media_track_set(Box<MediaTrackSet> set);
+
+/// Dispose of the given platform info.
+///
+/// # Arguments
+///
+/// * `info` - A boxed `PlatformInfoC` to be disposed of.
+void dispose_platform_info(Box<PlatformInfoC> info);
+
 /// Disposes of the `PlayerC` instance and deallocates its memory.
 ///
 /// # Safety
@@ -1656,6 +2483,27 @@ void dispose_playlist_set(Box<CArray<PlaylistItemC>> set);
 /// This means that the original pointer will become invalid.
 void dispose_popcorn_fx(Box<PopcornFX> instance);
 
+/// Dispose of a C-style array of provider connection results.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ProviderConnectionResultC` to be disposed of.
+void dispose_provider_connection_results(Box<CArray<ProviderConnectionResultC>> set);
+
+/// Dispose of a C-style array of displays.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `DisplayInfoC` to be disposed of.
+void dispose_screen_displays(Box<CArray<DisplayInfoC>> set);
+
+/// Dispose of the given settings import result.
+///
+/// # Arguments
+///
+/// * `result` - The settings import result to dispose.
+void dispose_settings_import_result(SettingsImportResultC result);
+
 /// Dispose of a C-compatible string array.
 ///
 /// This function takes ownership of a boxed `StringArray` object, releasing its resources.
@@ -1674,6 +2522,15 @@ void dispose_string_array(Box<StringArray> array);
 /// or if the memory was already deallocated, calling this function could lead to undefined behavior.
 void dispose_subtitle(Box<SubtitleC> subtitle);
 
+/// Frees the memory allocated for the `SubtitleCueSet` structure.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it's assumed that the `SubtitleCueSet` structure was allocated using `Box`,
+/// and dropping a `Box` pointing to valid memory is safe. However, if the `SubtitleCueSet` was allocated in a different way
+/// or if the memory was already deallocated, calling this function could lead to undefined behavior.
+void dispose_subtitle_cue_set(Box<SubtitleCueSet> set);
+
 /// Frees the memory allocated for the `SubtitleInfoC` structure.
 ///
 /// # Safety
@@ -1697,6 +2554,13 @@ void dispose_torrent_collection(Box<TorrentCollectionSet> collection_set);
 
 void dispose_torrent_stream_event_value(TorrentStreamEventC event);
 
+/// Dispose of a C-style array of torrent summaries.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `TorrentSummaryC` to be disposed of.
+void dispose_torrent_summary_set(Box<CArray<TorrentSummaryC>> set);
+
 /// Disposes a tracking event value.
 ///
 /// # Arguments
@@ -1704,22 +2568,29 @@ void dispose_torrent_stream_event_value(TorrentStreamEventC event);
 /// * `event` - The tracking event to be disposed.
 void dispose_tracking_event_value(TrackingEventC event);
 
+/// Dispose of a C-style array of update channels.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `UpdateChannel` to be disposed of.
+void dispose_update_channel_set(Box<CArray<UpdateChannel>> set);
+
 /// Download the given [SubtitleInfo] based on the best match according to the [SubtitleMatcher].
 ///
 /// It returns the filepath to the subtitle on success, else [ptr::null_mut].
-char *download(PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle, SubtitleMatcherC matcher);
+char *download(const PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle, SubtitleMatcherC matcher);
 
 /// Download and parse the given subtitle info.
 ///
 /// It returns the [SubtitleC] reference on success, else [ptr::null_mut].
-SubtitleC *download_and_parse_subtitle(PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle, SubtitleMatcherC matcher);
+SubtitleC *download_and_parse_subtitle(const PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle, SubtitleMatcherC matcher);
 
 /// Start downloading the application update if available.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
-void download_update(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+void download_update(const PopcornFX *popcorn_fx);
 
 /// Retrieve the given subtitles for the given episode.
 ///
@@ -1729,7 +2600,7 @@ void download_update(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `show` - A reference to the `ShowDetailsC` containing information about the show.
 /// * `episode` - A reference to the `EpisodeC` for which subtitles are to be retrieved.
 ///
@@ -1737,17 +2608,78 @@ void download_update(PopcornFX *popcorn_fx);
 ///
 /// A pointer to the `SubtitleInfoSet` containing the available subtitles for the episode.
 /// <i>The returned reference should be managed by the caller.</i>
-SubtitleInfoSet *episode_subtitles(PopcornFX *popcorn_fx, const ShowDetailsC *show, const EpisodeC *episode);
+SubtitleInfoSet *episode_subtitles(const PopcornFX *popcorn_fx, const ShowDetailsC *show, const EpisodeC *episode);
+
+/// Export the current application settings to the given file path.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `path` - The path of the file to which the settings should be written.
+///
+/// # Returns
+///
+/// It returns `true` when the settings have been exported, else `false`.
+bool export_settings(const PopcornFX *popcorn_fx, char *path);
 
 /// Retrieve the available subtitles for the given filename
-SubtitleInfoSet *filename_subtitles(PopcornFX *popcorn_fx, char *filename);
+SubtitleInfoSet *filename_subtitles(const PopcornFX *popcorn_fx, char *filename);
+
+/// Forget a previously discovered external player device.
+///
+/// The device is removed from the known devices cache and its player is removed from the player manager.
+/// The device will be registered again when it's discovered on the network.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `device_id` - A pointer to a null-terminated C string representing the device's unique identifier (ID).
+void forget_device(const PopcornFX *popcorn_fx, char *device_id);
+
+/// Retrieve the statistics of the image cache.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// The C-compatible statistics of the image cache.
+ImageCacheStatsC image_cache_stats(const PopcornFX *popcorn_fx);
+
+/// Import the application settings from the given file path.
+/// The current settings remain unchanged when the file couldn't be read or contains invalid fields.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `path` - The path of the file from which the settings should be read.
+///
+/// # Returns
+///
+/// It returns the [SettingsImportResultC] of the import, which should be disposed through [dispose_settings_import_result].
+SettingsImportResultC import_settings(const PopcornFX *popcorn_fx, char *path);
+
+/// Insert the given item at the index within the queued items of the playlist from C.
+/// An index beyond the end of the playlist appends the item.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `index` - The index at which the item should be inserted.
+/// * `item` - The C-compatible playlist item to insert.
+void insert_playlist_item(const PopcornFX *popcorn_fx, uint32_t index, PlaylistItemC item);
 
 /// Install the latest available update.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
-void install_update(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+void install_update(const PopcornFX *popcorn_fx);
+
+/// Invalidate the cached media pages of the providers.
+/// The next retrieved pages will be requested from the providers again.
+void invalidate_provider_cache(const PopcornFX *popcorn_fx);
 
 /// Invoke a player event on a wrapped player instance.
 ///
@@ -1767,61 +2699,81 @@ void install_update(PopcornFX *popcorn_fx);
 /// If the conditions are met, it invokes the specified player event on the wrapped player.
 void invoke_player_event(PlayerWrapperC *player, PlayerEventC event);
 
+/// Verify if the given category is supported by one of the configured media providers.
+/// Categories which aren't supported should be hidden from the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to verify.
+///
+/// # Returns
+///
+/// It returns `true` when a provider is available for the category, else `false`.
+bool is_category_supported(const PopcornFX *popcorn_fx, Category category);
+
 /// Verify if the FX embedded video player has been disabled.
-bool is_fx_video_player_enabled(PopcornFX *popcorn_fx);
+bool is_fx_video_player_enabled(const PopcornFX *popcorn_fx);
 
 /// Verify if the application should started in kiosk mode.
 /// The behavior of kiosk mode is dependant on the UI implementation and not delegated by the backend.
-bool is_kiosk_mode(PopcornFX *popcorn_fx);
+bool is_kiosk_mode(const PopcornFX *popcorn_fx);
 
 /// Verify if the application should be maximized on startup.
-bool is_maximized(PopcornFX *popcorn_fx);
+bool is_maximized(const PopcornFX *popcorn_fx);
 
 /// Verify if the given media item is liked/favorite of the user.
 /// It will use the first non [ptr::null_mut] field from the [MediaItemC] struct.
 ///
 /// It will return false if all fields in the [MediaItemC] are [ptr::null_mut].
-bool is_media_liked(PopcornFX *popcorn_fx, MediaItemC *favorite);
+bool is_media_liked(const PopcornFX *popcorn_fx, MediaItemC *favorite);
 
 /// Verify if the given media item is watched by the user.
 ///
 /// It returns true when the item is watched, else false.
-bool is_media_watched(PopcornFX *popcorn_fx, const MediaItemC *watchable);
+bool is_media_watched(const PopcornFX *popcorn_fx, const MediaItemC *watchable);
 
 /// Verify if the application mouse should be disabled.
 /// The disabling of the mouse should be implemented by the UI implementation and has no behavior on
 /// the backend itself.
-bool is_mouse_disabled(PopcornFX *popcorn_fx);
+bool is_mouse_disabled(const PopcornFX *popcorn_fx);
 
 /// Verify if the subtitle has been disabled by the user.
 ///
 /// It returns true when the subtitle track should be disabled, else false.
-bool is_subtitle_disabled(PopcornFX *popcorn_fx);
+bool is_subtitle_disabled(const PopcornFX *popcorn_fx);
+
+/// Verify if all torrents have been paused.
+///
+/// # Returns
+///
+/// It returns `true` when all torrents are paused, else `false`.
+bool is_torrents_paused(const PopcornFX *popcorn_fx);
 
 /// Verify if the TV mode is activated for the application.
-bool is_tv_mode(PopcornFX *popcorn_fx);
+bool is_tv_mode(const PopcornFX *popcorn_fx);
 
 /// Checks if the VLC video player is enabled in the PopcornFX options.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// `true` if the VLC video player is enabled, otherwise `false`.
-bool is_vlc_video_player_enabled(PopcornFX *popcorn_fx);
+bool is_vlc_video_player_enabled(const PopcornFX *popcorn_fx);
 
 /// Checks if the YouTube video player is enabled in the PopcornFX options.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// `true` if the YouTube video player is enabled, otherwise `false`.
-bool is_youtube_video_player_enabled(PopcornFX *popcorn_fx);
+bool is_youtube_video_player_enabled(const PopcornFX *popcorn_fx);
 
 /// Loads the fanart image data for the given media item.
 ///
@@ -1829,7 +2781,7 @@ bool is_youtube_video_player_enabled(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to the `PopcornFX` instance that will load the image data.
+/// * `popcorn_fx` - a reference to the `PopcornFX` instance that will load the image data.
 /// * `media` - a C-compatible media item holder that contains information about the media item to load.
 ///
 /// # Returns
@@ -1840,7 +2792,7 @@ bool is_youtube_video_player_enabled(PopcornFX *popcorn_fx);
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
-ByteArray *load_fanart(PopcornFX *popcorn_fx, const MediaItemC *media);
+ByteArray *load_fanart(const PopcornFX *popcorn_fx, const MediaItemC *media);
 
 /// Load the image data from the given URL.
 ///
@@ -1849,13 +2801,35 @@ ByteArray *load_fanart(PopcornFX *popcorn_fx, const MediaItemC *media);
 ///
 /// # Arguments
 ///
-/// * popcorn_fx - a mutable reference to a PopcornFX instance.
+/// * popcorn_fx - a reference to a PopcornFX instance.
 /// * url - a pointer to a null-terminated C string that contains the URL from which to load the image data.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the dispose_byte_array function.
-ByteArray *load_image(PopcornFX *popcorn_fx, char *url);
+ByteArray *load_image(const PopcornFX *popcorn_fx, char *url);
+
+/// Load the image data from the given URL, resized to fit within the given width and height.
+///
+/// The aspect ratio of the image is preserved and the image is never upscaled.
+/// The resized image is cached separately for each requested size.
+/// If the image data couldn't be decoded, the original image data is returned instead.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `url` - a pointer to a null-terminated C string that contains the URL from which to load the image data.
+/// * `width` - the maximum width of the image, `0` for an unbounded width.
+/// * `height` - the maximum height of the image, `0` for an unbounded height.
+///
+/// # Returns
+///
+/// A pointer to a `ByteArray` containing the image data, or a null pointer when the data couldn't be loaded.
+///
+/// # Safety
+///
+/// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
+ByteArray *load_image_resized(const PopcornFX *popcorn_fx, char *url, uint32_t width, uint32_t height);
 
 /// Load the poster image data for the given media item.
 ///
@@ -1864,35 +2838,66 @@ ByteArray *load_image(PopcornFX *popcorn_fx, char *url);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `media` - a reference to a `MediaItemC` object that represents the media item to load.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
-ByteArray *load_poster(PopcornFX *popcorn_fx, const MediaItemC *media);
+ByteArray *load_poster(const PopcornFX *popcorn_fx, const MediaItemC *media);
+
+/// Retrieve the handle of the loading task which is currently being processed by the media loader.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns the handle of the active loading task, or a null pointer when no media item is being loaded.
+LoadingHandleC loader_active_task(const PopcornFX *instance);
 
 /// Cancels the current media loading process initiated by the `MediaLoader`.
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
-void loader_cancel(PopcornFX *instance, LoadingHandleC handle);
+/// * `instance` - A reference to the `PopcornFX` instance.
+void loader_cancel(const PopcornFX *instance, LoadingHandleC handle);
+
+/// Downloads a torrent file using its information and file details, without streaming or playing it.
+///
+/// The torrent file is downloaded in the background and can be streamed later on through `loader_load_torrent_file`,
+/// which reuses the already downloaded pieces.
+///
+/// # Safety
+///
+/// This function accepts values to C structs (`TorrentInfoC` and `TorrentFileInfoC`) as arguments.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the PopcornFX instance.
+/// * `torrent_info` - Information about the torrent.
+/// * `torrent_file` - Details of the torrent file.
+///
+/// # Returns
+///
+/// Returns a handle to the loading process.
+LoadingHandleC loader_download_torrent_file(const PopcornFX *instance, TorrentInfoC torrent_info, TorrentFileInfoC torrent_file);
 
 /// Load a media item using the media loader from a C-compatible URL.
 ///
-/// This function takes a mutable reference to a `PopcornFX` instance and a C-compatible string (`*mut c_char`) representing the URL of the media item to load.
+/// This function takes a reference to a `PopcornFX` instance and a C-compatible string (`*mut c_char`) representing the URL of the media item to load.
 /// It uses the media loader to load the media item asynchronously and returns a handle (represented as a `LoadingHandleC`) for the loading process.
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `url` - A C-compatible string representing the URL of the media item to load.
 ///
 /// # Returns
 ///
 /// A `LoadingHandleC` representing the loading process associated with the loaded item.
-LoadingHandleC loader_load(PopcornFX *instance, char *url);
+LoadingHandleC loader_load(const PopcornFX *instance, char *url);
 
 /// Loads a torrent file using its information and file details.
 ///
@@ -1902,14 +2907,58 @@ LoadingHandleC loader_load(PopcornFX *instance, char *url);
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the PopcornFX instance.
+/// * `instance` - A reference to the PopcornFX instance.
 /// * `torrent_info` - Information about the torrent.
 /// * `torrent_file` - Details of the torrent file.
 ///
 /// # Returns
 ///
 /// Returns a handle to the loading process.
-LoadingHandleC loader_load_torrent_file(PopcornFX *instance, TorrentInfoC torrent_info, TorrentFileInfoC torrent_file);
+LoadingHandleC loader_load_torrent_file(const PopcornFX *instance, TorrentInfoC torrent_info, TorrentFileInfoC torrent_file);
+
+/// Loads a single file of a multi-file torrent by its index within the torrent, e.g. an episode of a season pack.
+///
+/// # Safety
+///
+/// This function accepts a value of the C struct `TorrentInfoC` as argument.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the PopcornFX instance.
+/// * `torrent_info` - Information about the torrent.
+/// * `file_index` - The index of the file within the torrent to load.
+///
+/// # Returns
+///
+/// Returns a handle to the loading process, or a null pointer when the file index is out of range.
+LoadingHandleC loader_load_torrent_file_index(const PopcornFX *instance, TorrentInfoC torrent_info, int32_t file_index);
+
+/// Retrieve the handles of the loading tasks which are waiting for the active loading task to complete.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A CArray of the queued loading handles in the order in which they will be started.
+CArray<int64_t> loader_queued_tasks(const PopcornFX *instance);
+
+/// Update the policy which is applied when a new media item is loaded while another item is still loading.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `policy` - The new loading policy.
+void loader_set_policy(const PopcornFX *instance, LoadingPolicy policy);
+
+/// Update the preferred quality which is used to load media items without a quality.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `quality` - The preferred quality, or `ptr::null()` to select the quality automatically.
+void loader_set_preferred_quality(const PopcornFX *instance, Quality *quality);
 
 /// Logs a message sent over FFI using the Rust logger.
 ///
@@ -1919,6 +2968,33 @@ LoadingHandleC loader_load_torrent_file(PopcornFX *instance, TorrentInfoC torren
 /// * `level` - The log level of the message. Determines the verbosity of the message and how it will be formatted by the Rust logger.
 void log(char *target, char *message, LogLevel level);
 
+/// Move a queued item of the playlist to a new position from C.
+///
+/// The item which is currently being played is not part of the queued items and won't be interrupted.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `from` - The current index of the queued item.
+/// * `to` - The new index of the queued item, which is clamped to the end of the playlist.
+///
+/// # Returns
+///
+/// `true` when the item has been moved, else `false` when the `from` index is out of range.
+bool move_playlist_item(const PopcornFX *popcorn_fx, uint32_t from, uint32_t to);
+
+/// Move the application to the display with the given id.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `id` - The id of the display to move the application to.
+///
+/// # Returns
+///
+/// It returns `true` when the application is being moved, else `false` when the display is unknown.
+bool move_to_display(const PopcornFX *instance, uint32_t id);
+
 /// Retrieve the available subtitles for the given [MovieDetailsC].
 ///
 /// This function takes a reference to the `PopcornFX` instance and a reference to a `MovieDetailsC`.
@@ -1927,50 +3003,62 @@ void log(char *target, char *message, LogLevel level);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `movie` - A reference to the `MovieDetailsC` for which subtitles are to be retrieved.
 ///
 /// # Returns
 ///
 /// A pointer to the `SubtitleInfoSet` containing the available subtitles, or a null pointer on failure.
 /// <i>The returned reference should be managed by the caller.</i>
-SubtitleInfoSet *movie_subtitles(PopcornFX *popcorn_fx, const MovieDetailsC *movie);
+SubtitleInfoSet *movie_subtitles(const PopcornFX *popcorn_fx, const MovieDetailsC *movie);
 
 /// Create a new PopcornFX instance.
 /// The caller will become responsible for managing the memory of the struct.
 /// The instance can be safely deleted by using [dispose_popcorn_fx].
 PopcornFX *new_popcorn_fx(int32_t len, char **args);
 
+/// Pause all torrents at once.
+/// Torrents which are added while paused will start in a paused state.
+void pause_all_torrents(const PopcornFX *popcorn_fx);
+
+/// Pause the torrent with the given handle on behalf of the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent to pause.
+void pause_torrent(const PopcornFX *popcorn_fx, char *handle);
+
 /// Play the next item in the playlist from C.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and attempts to start playback of the next item in the playlist managed by the `PlaylistManager`.
+/// It takes a reference to a `PopcornFX` instance and attempts to start playback of the next item in the playlist managed by the `PlaylistManager`.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// A raw pointer to an `i64` representing the handle of the playlist item if playback was successfully started;
 /// otherwise, a null pointer if there are no more items to play or if an error occurred during playback initiation.
-const int64_t *play_next_playlist_item(PopcornFX *popcorn_fx);
+const int64_t *play_next_playlist_item(const PopcornFX *popcorn_fx);
 
 /// Play a playlist from C by converting it to the Rust data structure and starting playback asynchronously.
 ///
-/// This function takes a mutable reference to a `PopcornFX` instance and a C-compatible array of `PlaylistItemC` items.
+/// This function takes a reference to a `PopcornFX` instance and a C-compatible array of `PlaylistItemC` items.
 /// It converts the C array into a Rust `Playlist` and starts playback asynchronously using the playlist manager.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `playlist` - A C-compatible array of `PlaylistItemC` items representing the playlist to play.
 ///
 /// # Returns
 ///
 /// If the playlist playback is successfully started, a pointer to the internal playlist handle is returned.
 /// Otherwise, if an error occurs or the playlist is empty, a null pointer is returned.
-const int64_t *play_playlist(PopcornFX *popcorn_fx, CArray<PlaylistItemC> playlist);
+const int64_t *play_playlist(const PopcornFX *popcorn_fx, CArray<PlaylistItemC> playlist);
 
 /// Retrieve a pointer to a `PlayerC` instance by its unique identifier (ID) from the PopcornFX player manager.
 ///
@@ -1981,13 +3069,13 @@ const int64_t *play_playlist(PopcornFX *popcorn_fx, CArray<PlaylistItemC> playli
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerC` instance representing the player if found, or a null pointer if no player with the given ID exists.
-PlayerC *player_by_id(PopcornFX *popcorn_fx, char *player_id);
+PlayerC *player_by_id(const PopcornFX *popcorn_fx, char *player_id);
 
 /// Pauses the player associated with the given `PlayerWrapperC` instance.
 ///
@@ -2010,13 +3098,13 @@ void player_pause(PlayerWrapperC *player);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerWrapperC` instance representing the player if found, or a null pointer if no player with the given ID exists.
-PlayerWrapperC *player_pointer_by_id(PopcornFX *popcorn_fx, char *player_id);
+PlayerWrapperC *player_pointer_by_id(const PopcornFX *popcorn_fx, char *player_id);
 
 /// Resumes the player associated with the given `PlayerWrapperC` instance.
 ///
@@ -2043,6 +3131,32 @@ void player_resume(PlayerWrapperC *player);
 /// * `time` - The time position to seek to, in milliseconds.
 void player_seek(PlayerWrapperC *player, uint64_t time);
 
+/// Updates the mute state of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` pointer.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `muted` - `true` to mute the player, else `false`.
+void player_set_muted(PlayerWrapperC *player, bool muted);
+
+/// Updates the volume of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` pointer.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `volume` - The new volume level, between 0 and 100.
+void player_set_volume(PlayerWrapperC *player, uint32_t volume);
+
 /// Stops the player associated with the given `PlayerWrapperC` instance.
 ///
 /// # Safety
@@ -2055,6 +3169,20 @@ void player_seek(PlayerWrapperC *player, uint64_t time);
 /// * `player` - A mutable reference to a `PlayerWrapperC` instance.
 void player_stop(PlayerWrapperC *player);
 
+/// Updates the subtitle of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` and `subtitle` pointers.
+/// The ownership of the `subtitle` is transferred to the player.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `subtitle` - A pointer to the new subtitle, or a null pointer to disable the subtitle.
+void player_update_subtitle(PlayerWrapperC *player, SubtitleC *subtitle);
+
 /// Retrieve a pointer to a `PlayerSet` containing information about all players managed by PopcornFX.
 ///
 /// # Safety
@@ -2064,23 +3192,23 @@ void player_stop(PlayerWrapperC *player);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerSet` containing information about all players managed by PopcornFX.
-PlayerSet *players(PopcornFX *popcorn_fx);
+PlayerSet *players(const PopcornFX *popcorn_fx);
 
 /// Retrieves the playlist from PopcornFX.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// A CArray of PlaylistItemC representing the playlist.
-CArray<PlaylistItemC> playlist(PopcornFX *popcorn_fx);
+CArray<PlaylistItemC> playlist(const PopcornFX *popcorn_fx);
 
 /// Retrieve the default poster (placeholder) image data as a C compatible byte array.
 ///
@@ -2089,7 +3217,7 @@ CArray<PlaylistItemC> playlist(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 ///
 /// # Returns
 ///
@@ -2098,13 +3226,37 @@ CArray<PlaylistItemC> playlist(PopcornFX *popcorn_fx);
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
-ByteArray *poster_placeholder(PopcornFX *popcorn_fx);
+ByteArray *poster_placeholder(const PopcornFX *popcorn_fx);
+
+/// Prefetch the image data of the given URLs into the image cache.
+///
+/// The images are fetched concurrently and this function returns once all URLs have been cached or failed.
+/// URLs which are already cached or being fetched are skipped.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `len` - the number of URLs within the array.
+/// * `urls` - a pointer to an array of null-terminated C strings that contain the image URLs.
+///
+/// # Returns
+///
+/// The number of images which have been cached by the prefetch.
+int32_t prefetch_images(const PopcornFX *popcorn_fx, int32_t len, char **urls);
+
+/// Preview the given subtitle info by retrieving the first cues of the best matching subtitle file.
+///
+/// It returns the [SubtitleCueSet] reference on success, else [ptr::null_mut].
+SubtitleCueSet *preview_subtitle(const PopcornFX *popcorn_fx,
+                                 const SubtitleInfoC *subtitle,
+                                 SubtitleMatcherC matcher,
+                                 int32_t cue_count);
 
 /// Publish a new application event over the FFI layer.
 /// This will invoke the [popcorn_fx_core::core::events::EventPublisher] publisher on the backend.
 ///
 /// _Please keep in mind that the consumption of the event chain is not communicated over the FFI layer_
-void publish_event(PopcornFX *popcorn_fx, EventC event);
+void publish_event(const PopcornFX *popcorn_fx, EventC event);
 
 /// Register an event callback with the PopcornFX event publisher.
 ///
@@ -2115,12 +3267,27 @@ void publish_event(PopcornFX *popcorn_fx, EventC event);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A C-compatible function pointer representing the callback to be registered.
-void register_event_callback(PopcornFX *popcorn_fx, EventCCallback callback);
+void register_event_callback(const PopcornFX *popcorn_fx, EventCCallback callback);
+
+/// Register an event subscriber with the PopcornFX event publisher.
+///
+/// The subscriber first receives the buffered events which have been published before the subscription,
+/// these events are flagged as replayed. Reconnecting subscribers should provide the last sequence number they've received,
+/// so only the events published after it are replayed.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `callback` - A C-compatible function pointer representing the subscriber to be registered.
+/// * `last_sequence` - The last received event sequence number, or `0` when no events have been received.
+void register_event_subscriber(const PopcornFX *popcorn_fx,
+                               EventSubscriberCCallback callback,
+                               uint64_t last_sequence);
 
 /// Register a new callback listener for favorite events.
-void register_favorites_event_callback(PopcornFX *popcorn_fx, void (*callback)(FavoriteEventC));
+void register_favorites_event_callback(const PopcornFX *popcorn_fx, void (*callback)(FavoriteEventC));
 
 /// Register a fullscreen callback function.
 ///
@@ -2128,9 +3295,9 @@ void register_favorites_event_callback(PopcornFX *popcorn_fx, void (*callback)(F
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `callback` - The fullscreen callback function to be registered.
-void register_fullscreen_callback(PopcornFX *instance, FullscreenCallback callback);
+void register_fullscreen_callback(const PopcornFX *instance, FullscreenCallback callback);
 
 /// Register a callback function to check if the application is in fullscreen mode.
 ///
@@ -2138,9 +3305,9 @@ void register_fullscreen_callback(PopcornFX *instance, FullscreenCallback callba
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `callback` - The callback function to be registered for checking the fullscreen state.
-void register_is_fullscreen_callback(PopcornFX *instance, IsFullscreenCallback callback);
+void register_is_fullscreen_callback(const PopcornFX *instance, IsFullscreenCallback callback);
 
 /// Register a loader event callback to receive loader state change events.
 ///
@@ -2149,15 +3316,33 @@ void register_is_fullscreen_callback(PopcornFX *instance, IsFullscreenCallback c
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the PopcornFX instance to register the callback with.
+/// * `instance` - A reference to the PopcornFX instance to register the callback with.
 /// * `callback` - A C-compatible callback function that will be invoked when loader state change events occur.
-void register_loader_callback(PopcornFX *instance, LoaderEventCallback callback);
+void register_loader_callback(const PopcornFX *instance, LoaderEventCallback callback);
+
+/// Subscribe to the backend log records.
+/// The most recent log records are replayed to the callback before new records are forwarded.
+/// Only one subscriber is supported, a new subscription replaces the previous one.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback which receives the backend log records.
+void register_log_record_callback(const PopcornFX *popcorn_fx, LogRecordCallbackC callback);
+
+/// Register a callback function which moves the application to the display with the given id.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback function to be registered for moving the application window.
+void register_move_to_display_callback(const PopcornFX *instance, MoveToDisplayCallbackC callback);
 
 /// Register a new callback listener for the system playback controls.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `callback` - a callback function pointer of type `PlaybackControlsCallbackC`.
 ///
 /// # Safety
@@ -2165,7 +3350,7 @@ void register_loader_callback(PopcornFX *instance, LoaderEventCallback callback)
 /// This function should only be called from C code and the callback function should be implemented in C as well.
 /// The `callback` function pointer should point to a valid C function that can receive a `PlaybackControlsEventC` parameter and return nothing.
 /// The callback function will be invoked whenever a playback control event occurs in the system.
-void register_playback_controls(PopcornFX *popcorn_fx, PlaybackControlsCallbackC callback);
+void register_playback_controls(const PopcornFX *popcorn_fx, PlaybackControlsCallbackC callback);
 
 /// Register a player with the PopcornFX player manager.
 ///
@@ -2176,14 +3361,14 @@ void register_playback_controls(PopcornFX *popcorn_fx, PlaybackControlsCallbackC
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player` - A `PlayerRegistrationC` instance to be registered with the player manager.
 ///
 /// # Notes
 ///
 /// This function registers a player with the PopcornFX player manager using the provided `PlayerC` instance.
 /// It logs an info message if the registration is successful and a warning message if registration fails.
-void register_player(PopcornFX *popcorn_fx, PlayerRegistrationC player);
+void register_player(const PopcornFX *popcorn_fx, PlayerRegistrationC player);
 
 /// Register a callback function to be notified of player manager events.
 ///
@@ -2194,14 +3379,14 @@ void register_player(PopcornFX *popcorn_fx, PlayerRegistrationC player);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A C-compatible callback function that will be invoked when player manager events occur.
-void register_player_callback(PopcornFX *popcorn_fx, PlayerManagerEventCallback callback);
+void register_player_callback(const PopcornFX *popcorn_fx, PlayerManagerEventCallback callback);
 
 /// Registers a C-compatible callback function to receive playlist manager events.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and a C-compatible callback function as arguments.
+/// It takes a reference to a `PopcornFX` instance and a C-compatible callback function as arguments.
 ///
 /// The function registers the provided callback function with the `PlaylistManager` from the `PopcornFX` instance.
 /// When a playlist manager event occurs, the callback function is invoked with the corresponding C-compatible event data.
@@ -2213,12 +3398,20 @@ void register_player_callback(PopcornFX *popcorn_fx, PlayerManagerEventCallback
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `callback` - The C-compatible callback function to be registered.
-void register_playlist_manager_callback(PopcornFX *popcorn_fx, PlaylistManagerCallbackC callback);
+void register_playlist_manager_callback(const PopcornFX *popcorn_fx, PlaylistManagerCallbackC callback);
+
+/// Register a new callback for the events of the screen service.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback function which receives the screen events.
+void register_screen_event_callback(const PopcornFX *instance, ScreenEventCallbackC callback);
 
 /// Register a new callback for all setting events.
-void register_settings_callback(PopcornFX *popcorn_fx, ApplicationConfigCallbackC callback);
+void register_settings_callback(const PopcornFX *popcorn_fx, ApplicationConfigCallbackC callback);
 
 /// Register a new callback for subtitle events.
 ///
@@ -2231,9 +3424,28 @@ void register_settings_callback(PopcornFX *popcorn_fx, ApplicationConfigCallback
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A function pointer to the C callback function.
-void register_subtitle_callback(PopcornFX *popcorn_fx, SubtitleCallbackC callback);
+void register_subtitle_callback(const PopcornFX *popcorn_fx, SubtitleCallbackC callback);
+
+/// Register a new callback listener for the torrent collection events.
+void register_torrent_collection_callback(const PopcornFX *popcorn_fx, void (*callback)(TorrentCollectionEvent));
+
+/// Registers a new callback for the events of the torrent with the given handle.
+///
+/// When the torrent has already been removed, the callback is invoked once with the `Removed` event
+/// and no callback is registered.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent.
+/// * `callback` - The callback function to be invoked when torrent events occur.
+///
+/// # Returns
+///
+/// A pointer to an integer value representing the handle of the registered callback, or a null pointer if the torrent has been removed.
+const int64_t *register_torrent_event_callback(const PopcornFX *popcorn_fx, char *handle, TorrentHandleEventCallback callback);
 
 /// A callback function for resolving torrents.
 ///
@@ -2250,9 +3462,9 @@ void register_subtitle_callback(PopcornFX *popcorn_fx, SubtitleCallbackC callbac
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `callback` - The `ResolveTorrentCallback` function to be registered.
-void register_torrent_resolve_callback(PopcornFX *popcorn_fx, ResolveTorrentCallback callback);
+void register_torrent_resolve_callback(const PopcornFX *popcorn_fx, ResolveTorrentCallback callback);
 
 /// Registers a new torrent stream event callback.
 ///
@@ -2260,30 +3472,30 @@ void register_torrent_resolve_callback(PopcornFX *popcorn_fx, ResolveTorrentCall
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `stream_handle` - The handle of the torrent stream.
 /// * `callback` - The callback function to be invoked when torrent stream events occur.
 ///
 /// # Returns
 ///
 /// A pointer to an integer value representing the handle of the registered callback, or a null pointer if registration fails.
-const int64_t *register_torrent_stream_event_callback(PopcornFX *popcorn_fx, int64_t stream_handle, TorrentStreamEventCallback callback);
+const int64_t *register_torrent_stream_event_callback(const PopcornFX *popcorn_fx, int64_t stream_handle, TorrentStreamEventCallback callback);
 
 /// Registers a callback function to handle authorization URI openings from C code.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - The callback function to be registered.
-void register_tracking_authorization_open(PopcornFX *popcorn_fx, AuthorizationOpenC callback);
+void register_tracking_authorization_open(const PopcornFX *popcorn_fx, AuthorizationOpenC callback);
 
 /// Registers a callback function to handle tracking provider events from C code.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - The callback function to be registered.
-void register_tracking_provider_callback(PopcornFX *popcorn_fx, TrackingEventCCallback callback);
+void register_tracking_provider_callback(const PopcornFX *popcorn_fx, TrackingEventCCallback callback);
 
 /// Register a new callback for update events.
 ///
@@ -2295,25 +3507,25 @@ void register_tracking_provider_callback(PopcornFX *popcorn_fx, TrackingEventCCa
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `callback` - a C-compatible function that will be invoked when an update event occurs.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the provided `callback` function should be a valid C function pointer.
-void register_update_callback(PopcornFX *popcorn_fx, UpdateCallbackC callback);
+void register_update_callback(const PopcornFX *popcorn_fx, UpdateCallbackC callback);
 
 /// Register a new callback listener for watched events.
-void register_watched_event_callback(PopcornFX *popcorn_fx, void (*callback)(WatchedEventC));
+void register_watched_event_callback(const PopcornFX *popcorn_fx, void (*callback)(WatchedEventC));
 
 /// Reload the settings of the application.
-void reload_settings(PopcornFX *popcorn_fx);
+void reload_settings(const PopcornFX *popcorn_fx);
 
 /// Remove the media item from favorites.
-void remove_from_favorites(PopcornFX *popcorn_fx, const MediaItemC *favorite);
+void remove_from_favorites(const PopcornFX *popcorn_fx, const MediaItemC *favorite);
 
 /// Remove the given media item from the watched list.
-void remove_from_watched(PopcornFX *popcorn_fx, const MediaItemC *watchable);
+void remove_from_watched(const PopcornFX *popcorn_fx, const MediaItemC *watchable);
 
 /// Remove a player with the specified ID from the PopcornFX player manager.
 ///
@@ -2324,38 +3536,88 @@ void remove_from_watched(PopcornFX *popcorn_fx, const MediaItemC *watchable);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Notes
 ///
 /// This function removes a player with the specified ID from the PopcornFX player manager.
 /// It converts the `player_id` C string to a Rust String and logs a trace message to indicate the removal.
-void remove_player(PopcornFX *popcorn_fx, char *player_id);
+void remove_player(const PopcornFX *popcorn_fx, char *player_id);
+
+/// Remove the queued item at the given index from the playlist from C.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `index` - The index of the queued item to remove.
+///
+/// # Returns
+///
+/// `true` when the item has been removed, else `false` when the index is out of range.
+bool remove_playlist_item(const PopcornFX *popcorn_fx, uint32_t index);
+
+/// Removes a torrent event callback which has been registered through [register_torrent_event_callback].
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent.
+/// * `callback_handle` - The handle of the registered callback.
+void remove_torrent_event_callback(const PopcornFX *popcorn_fx, char *handle, const int64_t *callback_handle);
 
-void remove_torrent_stream_event_callback(PopcornFX *popcorn_fx, const int64_t *stream_handle, const int64_t *callback_handle);
+void remove_torrent_stream_event_callback(const PopcornFX *popcorn_fx, const int64_t *stream_handle, const int64_t *callback_handle);
 
 /// Reset all available api stats for the movie api.
 /// This will make all disabled api's available again.
-void reset_movie_apis(PopcornFX *popcorn_fx);
+void reset_movie_apis(const PopcornFX *popcorn_fx);
+
+/// Remove the provider uri overrides of the given category.
+/// This restores the bundled provider uri's and resets the provider of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to reset the provider uri's of.
+void reset_provider_uris(const PopcornFX *popcorn_fx, Category category);
+
+/// Reset the given section of the application settings to its default values.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `section` - The settings section to reset.
+void reset_settings_section(const PopcornFX *popcorn_fx, SettingsSection section);
 
 /// Reset all available api stats for the movie api.
 /// This will make all disabled api's available again.
-void reset_show_apis(PopcornFX *popcorn_fx);
+void reset_show_apis(const PopcornFX *popcorn_fx);
 
 /// Reset the current preferred subtitle configuration.
 /// This will remove any selected [SubtitleInfo] or custom subtitle file.
-void reset_subtitle(PopcornFX *popcorn_fx);
+void reset_subtitle(const PopcornFX *popcorn_fx);
+
+/// Resume all torrents which have been paused by [pause_all_torrents].
+/// Torrents which have been paused individually through [pause_torrent] remain paused.
+void resume_all_torrents(const PopcornFX *popcorn_fx);
+
+/// Resume the torrent with the given handle which has been paused by the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent to resume.
+void resume_torrent(const PopcornFX *popcorn_fx, char *handle);
 
 /// Retrieve all favorites of the user.
 ///
 /// It will return an array of favorites on success, else [ptr::null_mut].
-VecFavoritesC *retrieve_all_favorites(PopcornFX *popcorn_fx);
+VecFavoritesC *retrieve_all_favorites(const PopcornFX *popcorn_fx);
 
 /// Retrieve all watched media item id's.
 ///
 /// It returns an array of watched id's.
-StringArray retrieve_all_watched(PopcornFX *popcorn_fx);
+StringArray retrieve_all_watched(const PopcornFX *popcorn_fx);
 
 /// Retrieves available favorites from a PopcornFX instance.
 ///
@@ -2369,7 +3631,7 @@ StringArray retrieve_all_watched(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a PopcornFX instance.
+/// * `popcorn_fx` - A reference to a PopcornFX instance.
 /// * `genre` - A pointer to a GenreC struct, representing the genre filter.
 /// * `sort_by` - A pointer to a SortByC struct, representing the sorting criteria.
 /// * `keywords` - A pointer to a C-style string containing search keywords.
@@ -2379,60 +3641,159 @@ StringArray retrieve_all_watched(PopcornFX *popcorn_fx);
 ///
 /// If successful, returns a pointer to a VecFavoritesC struct containing the retrieved favorites.
 /// Returns a null pointer if an error occurs during the retrieval process.
-VecFavoritesC *retrieve_available_favorites(PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
+///
+/// Deprecated: use [retrieve_favorites] instead, which reports the reason of a failure.
+VecFavoritesC *retrieve_available_favorites(const PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
+
+/// Retrieve the available genres of the given category.
+///
+/// The genres advertised by the media provider are merged with the configured genres of the category,
+/// which allows new genres of a provider to be shown without a config update.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the genres of.
+///
+/// # Returns
+///
+/// It returns the array of genre keys, which should be disposed with `dispose_string_array`.
+StringArray *retrieve_available_genres(const PopcornFX *popcorn_fx, Category category);
 
 /// Retrieve the available movies for the given criteria.
 ///
 /// It returns the [VecMovieC] reference on success, else [ptr::null_mut].
-MediaSetResult retrieve_available_movies(PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
+MediaSetResult retrieve_available_movies(const PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
 
 /// Retrieve the available [ShowOverviewC] items for the given criteria.
 ///
 /// It returns an array of [ShowOverviewC] items on success, else a [ptr::null_mut].
-MediaSetResult retrieve_available_shows(PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
+MediaSetResult retrieve_available_shows(const PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
+
+/// Retrieve the available sort options of the given category.
+///
+/// The sort options advertised by the media provider are merged with the configured sort options of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the sort options of.
+///
+/// # Returns
+///
+/// It returns the array of sort keys, which should be disposed with `dispose_string_array`.
+StringArray *retrieve_available_sort_options(const PopcornFX *popcorn_fx, Category category);
+
+/// Retrieves the available favorites from a PopcornFX instance.
+///
+/// This function retrieves favorites from the provided `popcorn_fx` instance,
+/// filtering them based on the specified `genre`, `sort_by`, `keywords`, and `page`.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a PopcornFX instance.
+/// * `genre` - A pointer to a GenreC struct, representing the genre filter.
+/// * `sort_by` - A pointer to a SortByC struct, representing the sorting criteria.
+/// * `keywords` - A pointer to a C-style string containing search keywords.
+/// * `page` - The page number for pagination.
+///
+/// # Returns
+///
+/// It returns the [MediaSetC] of favorites on success,
+/// else the [MediaErrorC] describing the reason of the failure.
+/// The media set should be disposed with `dispose_media_items`.
+MediaSetResult retrieve_favorites(const PopcornFX *popcorn_fx, const GenreC *genre, const SortByC *sort_by, char *keywords, uint32_t page);
 
 /// Retrieve the details of a favorite item on the given IMDB ID.
 /// The details contain all information about the media item.
 ///
 /// It returns the [MediaItemC] on success, else a [ptr::null_mut].
-MediaResult retrieve_media_details(PopcornFX *popcorn_fx, const MediaItemC *media);
+MediaResult retrieve_media_details(const PopcornFX *popcorn_fx, const MediaItemC *media);
+
+/// Retrieve the platform information of the current system, which is used for diagnostic purposes.
+///
+/// The information, such as the available memory and free disk space of the application data and torrent directories,
+/// is gathered at the moment of the call.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+///
+/// # Returns
+///
+/// It returns the platform info, which should be disposed with `dispose_platform_info`.
+PlatformInfoC *retrieve_platform_info(const PopcornFX *popcorn_fx);
 
 /// Retrieves the preferred subtitle from the PopcornFX instance.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to the preferred subtitle information in C-compatible format.
 /// If no preferred subtitle is found, it returns a null pointer.
-SubtitleInfoC *retrieve_preferred_subtitle(PopcornFX *popcorn_fx);
+SubtitleInfoC *retrieve_preferred_subtitle(const PopcornFX *popcorn_fx);
 
 /// Retrieve the preferred subtitle language for the next [Media] item playback.
 ///
 /// It returns the preferred subtitle language.
-SubtitleLanguage retrieve_preferred_subtitle_language(PopcornFX *popcorn_fx);
+SubtitleLanguage retrieve_preferred_subtitle_language(const PopcornFX *popcorn_fx);
 
 /// Retrieve the array of available genres for the given provider.
 ///
 /// It returns an empty list when the provider name doesn't exist.
-StringArray *retrieve_provider_genres(PopcornFX *popcorn_fx, char *name);
+StringArray *retrieve_provider_genres(const PopcornFX *popcorn_fx, char *name);
 
 /// Retrieve the array of available sorts for the given provider.
 ///
 /// It returns an empty list when the provider name doesn't exist.
-StringArray *retrieve_provider_sort_by(PopcornFX *popcorn_fx, char *name);
+StringArray *retrieve_provider_sort_by(const PopcornFX *popcorn_fx, char *name);
+
+/// Retrieve the provider uri's of the given category.
+/// The uri overrides of the user take precedence over the bundled provider uri's.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the provider uri's of.
+///
+/// # Returns
+///
+/// It returns the array of uri's, which should be disposed with `dispose_string_array`.
+StringArray *retrieve_provider_uris(const PopcornFX *popcorn_fx, Category category);
+
+/// Retrieve the displays which are connected to the system.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A CArray of DisplayInfoC, which should be disposed with `dispose_screen_displays`.
+CArray<DisplayInfoC> retrieve_screen_displays(const PopcornFX *instance);
 
 /// Retrieve all watched movie id's.
 ///
 /// It returns an array of watched movie id's.
-StringArray *retrieve_watched_movies(PopcornFX *popcorn_fx);
+StringArray *retrieve_watched_movies(const PopcornFX *popcorn_fx);
 
 /// Retrieve all watched show media id's.
 ///
 /// It returns  an array of watched show id's.
-StringArray *retrieve_watched_shows(PopcornFX *popcorn_fx);
+StringArray *retrieve_watched_shows(const PopcornFX *popcorn_fx);
+
+/// Roll back the application to the previously installed version.
+///
+/// The application needs to be relaunched when the rollback succeeded,
+/// which is indicated by the [UpdateStateC::InstallationFinished] state.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+void rollback_update(const PopcornFX *popcorn_fx);
 
 /// Selects the default subtitle from the given list of subtitles provided in C-compatible form.
 ///
@@ -2441,14 +3802,14 @@ StringArray *retrieve_watched_shows(PopcornFX *popcorn_fx);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `subtitles_ptr` - Pointer to the array of subtitles in C-compatible form.
 /// * `len` - The length of the subtitles array.
 ///
 /// # Returns
 ///
 /// A pointer to the selected default subtitle in C-compatible form.
-SubtitleInfoC *select_or_default_subtitle(PopcornFX *popcorn_fx, SubtitleInfoSet *set);
+SubtitleInfoC *select_or_default_subtitle(const PopcornFX *popcorn_fx, SubtitleInfoSet *set);
 
 /// Set the active player in the PopcornFX player manager.
 ///
@@ -2459,19 +3820,130 @@ SubtitleInfoC *select_or_default_subtitle(PopcornFX *popcorn_fx, SubtitleInfoSet
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
-void set_active_player(PopcornFX *popcorn_fx, char *player_id);
+void set_active_player(const PopcornFX *popcorn_fx, char *player_id);
+
+/// Set the active subtitle of the given player during playback.
+///
+/// The subtitle is downloaded and parsed based on the current play request of the player, after which the player loads it.
+/// The "disabled" subtitle removes the subtitle track from the player.
+/// The player confirms the change through a `SubtitleChanged` player event.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
+/// * `subtitle` - A reference to the subtitle info which should become active.
+void set_active_subtitle(const PopcornFX *popcorn_fx, char *player_id, const SubtitleInfoC *subtitle);
+
+/// Set the default player which is used when no player has been selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID),
+///   or a null pointer to clear the default player.
+void set_default_player(const PopcornFX *popcorn_fx, char *player_id);
+
+/// Update the fullscreen state of the application.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `fullscreen` - `true` to activate fullscreen mode, `false` to deactivate it.
+void set_fullscreen(const PopcornFX *instance, bool fullscreen);
+
+/// Set the log level of the given backend module at runtime.
+/// The level applies to the module and all its submodules, unless they have a level of their own.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `target` - The module path, e.g. `popcorn_fx_torrent`.
+/// * `level` - The new log level of the module.
+///
+/// # Returns
+///
+/// It returns `true` when the log level has been changed, else `false` when the log levels can't be controlled.
+bool set_log_level(const PopcornFX *popcorn_fx, char *target, LogLevel level);
+
+/// Switch the update channel from which new application versions are received.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `channel` - the update channel to select.
+void set_update_channel(const PopcornFX *popcorn_fx, UpdateChannel channel);
 
 /// Stop the playback of the current playlist from C.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and stops the playback of the currently playing item in the playlist.
+/// It takes a reference to a `PopcornFX` instance and stops the playback of the currently playing item in the playlist.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+void stop_playlist(const PopcornFX *popcorn_fx);
+
+/// Removes the given key within the namespace from the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+void storage_delete(const PopcornFX *popcorn_fx, char *namespace_, char *key);
+
+/// Retrieves the value of the given key within the namespace from the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+///
+/// # Returns
+///
+/// A pointer to a C-style string containing the stored value, or a null pointer if the key doesn't exist.
+char *storage_get(const PopcornFX *popcorn_fx, char *namespace_, char *key);
+
+/// Retrieves the keys which are stored within the given namespace of the key-value storage.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
-void stop_playlist(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace.
+///
+/// # Returns
+///
+/// A pointer to a `StringArray` containing the keys of the namespace.
+StringArray *storage_keys(const PopcornFX *popcorn_fx, char *namespace_);
+
+/// Stores the given value for the key within the namespace in the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+/// * `value` - A pointer to a C-style string containing the value to store.
+///
+/// # Returns
+///
+/// Returns `true` if the value has been stored, otherwise `false`.
+bool storage_set(const PopcornFX *popcorn_fx, char *namespace_, char *key, char *value);
+
+/// Retrieves the number of cues within the given subtitle.
+///
+/// # Arguments
+///
+/// * `subtitle` - A reference to the `SubtitleC` instance.
+///
+/// # Returns
+///
+/// The number of cues of the subtitle, or `0` when the subtitle doesn't contain any cues.
+uintptr_t subtitle_cue_count(const SubtitleC *subtitle);
 
 /// Retrieve a special [SubtitleInfo::custom] instance of the application.
 ///
@@ -2495,6 +3967,48 @@ SubtitleInfoC *subtitle_custom();
 /// A pointer to a `SubtitleInfoC` instance representing "none".
 SubtitleInfoC *subtitle_none();
 
+/// Converts the given subtitle to the raw format of the given subtitle type.
+///
+/// The [SubtitleType::PlainText] type outputs a readable transcript of the subtitle dialogue without any timestamps.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `subtitle` - A reference to the `SubtitleC` instance to convert.
+/// * `subtitle_type` - The raw output format of the subtitle.
+///
+/// # Returns
+///
+/// The raw subtitle data, or a null pointer when the conversion failed.
+/// <i>The returned reference should be managed by the caller.</i>
+char *subtitle_to_raw(const PopcornFX *popcorn_fx, const SubtitleC *subtitle, SubtitleType subtitle_type);
+
+/// Retrieves the total duration of the given subtitle.
+///
+/// # Arguments
+///
+/// * `subtitle` - A reference to the `SubtitleC` instance.
+///
+/// # Returns
+///
+/// The end time in millis of the last cue of the subtitle, or `0` when the subtitle doesn't contain any cues.
+uint64_t subtitle_total_duration(const SubtitleC *subtitle);
+
+/// Test the connection of each configured provider uri of the given category.
+///
+/// This is a diagnostic call which measures the latency of each uri with a short timeout,
+/// without affecting the disabled uri's or failure statistics of the provider.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to test the provider uri's of.
+///
+/// # Returns
+///
+/// A CArray of ProviderConnectionResultC, which should be disposed with `dispose_provider_connection_results`.
+CArray<ProviderConnectionResultC> test_provider_connection(const PopcornFX *popcorn_fx, Category category);
+
 /// Register a new C-compatible cancel torrent callback with a Rust PopcornFX instance.
 ///
 /// This function registers a callback that handles the cancellation of torrent-related operations.
@@ -2508,40 +4022,65 @@ SubtitleInfoC *subtitle_none();
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `callback` - A `CancelTorrentCallback` function that will be registered to handle cancel torrent events.
-void torrent_cancel_callback(PopcornFX *popcorn_fx, CancelTorrentCallback callback);
+void torrent_cancel_callback(const PopcornFX *popcorn_fx, CancelTorrentCallback callback);
 
 /// Add the given magnet info to the torrent collection.
-void torrent_collection_add(PopcornFX *popcorn_fx, char *name, char *magnet_uri);
+void torrent_collection_add(const PopcornFX *popcorn_fx, char *name, char *magnet_uri);
 
 /// Retrieve all stored magnets from the torrent collection.
 /// It returns the set on success, else [ptr::null_mut].
-TorrentCollectionSet *torrent_collection_all(PopcornFX *popcorn_fx);
+TorrentCollectionSet *torrent_collection_all(const PopcornFX *popcorn_fx);
 
 /// Verify if the given magnet uri has already been stored.
-bool torrent_collection_is_stored(PopcornFX *popcorn_fx, char *magnet_uri);
+bool torrent_collection_is_stored(const PopcornFX *popcorn_fx, char *magnet_uri);
+
+/// Mark the given magnet uri of the torrent collection as used.
+void torrent_collection_mark_used(const PopcornFX *popcorn_fx, char *magnet_uri);
 
 /// Remove the given magnet uri from the torrent collection.
-void torrent_collection_remove(PopcornFX *popcorn_fx, char *magnet_uri);
+void torrent_collection_remove(const PopcornFX *popcorn_fx, char *magnet_uri);
+
+/// Retrieve the stored magnet of the torrent collection with its resolved torrent metadata.
+/// The metadata is only resolved the first time, after which the stored metadata is returned.
+///
+/// It returns the magnet info on success, else [ptr::null_mut].
+MagnetInfoC *torrent_collection_resolve(const PopcornFX *popcorn_fx, char *magnet_uri);
+
+/// Search the stored magnets of the torrent collection of which the name contains the given query.
+/// It returns the set of matching magnets in the given sort order on success, else [ptr::null_mut].
+TorrentCollectionSet *torrent_collection_search(const PopcornFX *popcorn_fx,
+                                                char *query,
+                                                CollectionSort sort);
 
 /// Callback function for handling changes in the download status of a torrent.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `download_status` - The new download status of the torrent.
-void torrent_download_status(PopcornFX *popcorn_fx, char *handle, DownloadStatusC download_status);
+void torrent_download_status(const PopcornFX *popcorn_fx, char *handle, DownloadStatusC download_status);
+
+/// Register a new C-compatible pause torrent callback with a Rust PopcornFX instance.
+///
+/// This function registers a callback that handles the pausing and resuming of torrents.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `callback` - A `PauseTorrentCallback` function that will be invoked when a torrent should be paused or resumed.
+void torrent_pause_callback(const PopcornFX *popcorn_fx, PauseTorrentCallback callback);
 
 /// Callback function for handling the completion of downloading a piece in a torrent.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `piece` - The index of the finished piece.
-void torrent_piece_finished(PopcornFX *popcorn_fx, char *handle, uint32_t piece);
+void torrent_piece_finished(const PopcornFX *popcorn_fx, char *handle, uint32_t piece);
 
 /// Registers a new C-compatible resolve torrent callback function with PopcornFX.
 ///
@@ -2549,7 +4088,7 @@ void torrent_piece_finished(PopcornFX *popcorn_fx, char *handle, uint32_t piece)
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `callback` - The C-compatible resolve torrent callback function to be registered.
 ///
 /// # Example
@@ -2571,75 +4110,147 @@ void torrent_piece_finished(PopcornFX *popcorn_fx, char *handle, uint32_t piece)
 /// # Safety
 ///
 /// This function performs unsafe operations, as it deals with raw C-compatible function pointers.
-void torrent_resolve_info_callback(PopcornFX *popcorn_fx, ResolveTorrentInfoCallback callback);
+void torrent_resolve_info_callback(const PopcornFX *popcorn_fx, ResolveTorrentInfoCallback callback);
 
 /// Callback function for handling changes in the state of a torrent.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `state` - The new state of the torrent.
-void torrent_state_changed(PopcornFX *popcorn_fx, char *handle, TorrentState state);
+void torrent_state_changed(const PopcornFX *popcorn_fx, char *handle, TorrentState state);
+
+/// Retrieve the downloaded byte ranges of the torrent stream with the given handle.
+///
+/// The ranges are file-relative, sorted and coalesced, which allows the ranges to be polled
+/// frequently to visualize the buffered parts of the stream.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `stream_handle` - The handle of the torrent stream.
+///
+/// # Returns
+///
+/// A CArray of ByteRangeC representing the downloaded ranges, which is empty when the stream handle is unknown.
+CArray<ByteRangeC> torrent_stream_available_ranges(const PopcornFX *popcorn_fx, int64_t stream_handle);
+
+/// Callback function for handling a failure to write the torrent data to the storage.
+///
+/// Transient failures are retried by the torrent, after too many consecutive failures the torrent
+/// is put in an error state and a [popcorn_fx_core::core::torrents::TorrentEvent::Error] is invoked.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle to the torrent.
+/// * `error` - The description of the write failure.
+void torrent_write_error(const PopcornFX *popcorn_fx, char *handle, char *error);
 
 /// Initiates the authorization process with the tracking provider.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
-void tracking_authorize(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+void tracking_authorize(const PopcornFX *popcorn_fx);
 
 /// Disconnects from the tracking provider.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
-void tracking_disconnect(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+void tracking_disconnect(const PopcornFX *popcorn_fx);
 
 /// Checks if the current tracking provider is authorized.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns `true` if the tracking provider is authorized, otherwise `false`.
-bool tracking_is_authorized(PopcornFX *popcorn_fx);
+bool tracking_is_authorized(const PopcornFX *popcorn_fx);
+
+/// Remove the subscription of the backend log records.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+void unregister_log_record_callback(const PopcornFX *popcorn_fx);
+
+/// Retrieve the update channel from which new application versions are received.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+UpdateChannel update_channel(const PopcornFX *popcorn_fx);
+
+/// Update the network settings with the new value.
+void update_network_settings(const PopcornFX *popcorn_fx, NetworkSettingsC settings);
 
 /// Update the playback settings with the new value.
-void update_playback_settings(PopcornFX *popcorn_fx, PlaybackSettingsC settings);
+void update_playback_settings(const PopcornFX *popcorn_fx, PlaybackSettingsC settings);
+
+/// Override the provider uri's of the given category.
+///
+/// Each uri is verified with a test request against the provider before the overrides are accepted.
+/// Once accepted, the provider of the category is reset to use the new uri's.
+/// An empty array removes the overrides of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to override the provider uri's of.
+/// * `len` - The number of uri's within the array.
+/// * `uris` - A pointer to an array of null-terminated C strings that contain the provider uri's.
+///
+/// # Returns
+///
+/// It returns `true` when the uri's have been accepted, else `false`.
+bool update_provider_uris(const PopcornFX *popcorn_fx, Category category, int32_t len, char **uris);
+
+/// Update the displays which are connected to the system.
+/// This should be invoked by the UI shell each time the connected displays have been changed.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `displays` - A pointer to the array of connected displays.
+/// * `len` - The number of displays within the array.
+void update_screen_displays(const PopcornFX *instance, DisplayInfoC *displays, int32_t len);
 
 /// Update the server settings with the new value.
-void update_server_settings(PopcornFX *popcorn_fx, ServerSettingsC settings);
+void update_server_settings(const PopcornFX *popcorn_fx, ServerSettingsC settings);
 
 /// Retrieve the current update state of the application.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// The current update state of the application as a [UpdateStateC] value.
-UpdateStateC update_state(PopcornFX *popcorn_fx);
+UpdateStateC update_state(const PopcornFX *popcorn_fx);
 
 /// Update the preferred subtitle for the [Media] item playback.
 /// This action will reset any custom configured subtitle files.
-void update_subtitle(PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle);
+void update_subtitle(const PopcornFX *popcorn_fx, const SubtitleInfoC *subtitle);
 
 /// Update the preferred subtitle to a custom subtitle filepath.
 /// This action will reset any preferred subtitle.
-void update_subtitle_custom_file(PopcornFX *popcorn_fx, char *custom_filepath);
+void update_subtitle_custom_file(const PopcornFX *popcorn_fx, char *custom_filepath);
 
 /// Update the subtitle settings with the new value.
-void update_subtitle_settings(PopcornFX *popcorn_fx, SubtitleSettingsC subtitle_settings);
+void update_subtitle_settings(const PopcornFX *popcorn_fx, SubtitleSettingsC subtitle_settings);
 
 /// Update the torrent settings with the new value.
-void update_torrent_settings(PopcornFX *popcorn_fx, TorrentSettingsC torrent_settings);
+void update_torrent_settings(const PopcornFX *popcorn_fx, TorrentSettingsC torrent_settings);
 
 /// Update the ui settings with the new value.
-void update_ui_settings(PopcornFX *popcorn_fx, UiSettingsC settings);
+void update_ui_settings(const PopcornFX *popcorn_fx, UiSettingsC settings);
 
 /// Retrieve the version of Popcorn FX.
 char *version();
@@ -2648,7 +4259,7 @@ char *version();
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
-VersionInfoC *version_info(PopcornFX *popcorn_fx);
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+VersionInfoC *version_info(const PopcornFX *popcorn_fx);
 
 } // extern "C"
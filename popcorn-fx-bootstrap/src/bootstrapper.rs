@@ -1,13 +1,16 @@
-use std::{env, thread};
+use std::{env, fs, thread};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus};
+use std::process::{ChildStderr, Command, ExitStatus, Stdio};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use directories::BaseDirs;
-use log::{debug, error, LevelFilter, trace, warn};
+use log::{debug, error, info, LevelFilter, trace, warn};
 use log4rs::append::console::ConsoleAppender;
 use log4rs::config::{Appender, Root};
 use log4rs::Config;
@@ -16,6 +19,7 @@ use thiserror::Error;
 
 use popcorn_fx_core::core::launcher::LauncherOptions;
 
+use crate::crash_state::{CRASH_WINDOW, CrashAction, CrashState};
 use crate::data_installer::{DataInstaller, DefaultDataInstaller};
 
 const CONSOLE_APPENDER: &str = "stdout";
@@ -31,6 +35,10 @@ const EXECUTABLE_NAME: &str = "java";
 #[cfg(target_family = "unix")]
 const PATH_SEPARATOR: &str = ":";
 const JAR_NAME: &str = "popcorn-time.jar";
+/// The exit code used by the application to request a restart, e.g. after an update has been installed.
+const RESTART_EXIT_CODE: i32 = 3;
+/// The number of stderr lines of the application process which are kept for diagnostics.
+const STDERR_TAIL_LINES: usize = 20;
 
 /// The bootstrap specific results.
 pub type Result<T> = std::result::Result<T, BootstrapError>;
@@ -44,6 +52,10 @@ pub enum BootstrapError {
     ExecuteFailed(Command, String),
     #[error("invalid process handle, {0}")]
     InvalidHandle(String),
+    #[error("failed to roll back the application, {0}")]
+    RollbackFailed(String),
+    #[error("application is stuck in a crash loop after {0} consecutive crashes")]
+    CrashLoop(u32),
 }
 
 /// The action to take after an instance process has completed.
@@ -51,6 +63,7 @@ pub enum BootstrapError {
 enum Action {
     Shutdown,
     Restart,
+    Crashed,
 }
 
 /// The `Bootstrapper` is responsible for launching the correct application version, and restarting the application when needed.
@@ -93,17 +106,23 @@ impl Bootstrapper {
         self.data_installer
             .prepare()
             .map_err(|e| BootstrapError::InitialSetupFailed(e.to_string()))?;
+        // remove the versions which can no longer be launched or rolled back to
+        if let Err(e) = self.data_installer.prune() {
+            warn!("Failed to prune old application versions, {}", e);
+        }
 
         loop {
             match self.launch_instance() {
-                Ok(action) => {
-                    if action == Action::Shutdown {
-                        debug!("Shutting down application");
-                        return Ok(());
-                    } else {
-                        debug!("Restarting application");
-                    }
+                Ok(Action::Shutdown) => {
+                    debug!("Shutting down application");
+                    CrashState::clear(&self.data_path);
+                    return Ok(());
+                }
+                Ok(Action::Restart) => {
+                    debug!("Restarting application");
+                    CrashState::clear(&self.data_path);
                 }
+                Ok(Action::Crashed) => self.handle_crash()?,
                 Err(e) => {
                     error!("Unable to start application, {}", e);
                     return Err(e);
@@ -120,15 +139,20 @@ impl Bootstrapper {
 
     fn launch_instance(&self) -> Result<Action> {
         let mut command = self.command();
+        command.stderr(Stdio::piped());
         trace!("Spawning process {:?}", command);
         let mut child = command
             .spawn()
             .map_err(|e| BootstrapError::ExecuteFailed(command, e.to_string()))?;
+        let stderr_tail = child.stderr.take().map(Self::capture_stderr);
 
         while !self.shutting_down.load(Ordering::Relaxed) {
             match child.try_wait() {
                 Ok(None) => thread::sleep(Duration::from_millis(100)),
-                Ok(Some(exit_status)) => return Ok(Self::handle_exit_status(exit_status)),
+                Ok(Some(exit_status)) => {
+                    let stderr_tail = stderr_tail.and_then(|e| e.join().ok()).unwrap_or_default();
+                    return Ok(Self::handle_exit_status(exit_status, &stderr_tail));
+                }
                 Err(e) => {
                     error!("Failed to wait for the application process, {}", e);
                     return Err(BootstrapError::InvalidHandle(e.to_string()));
@@ -208,19 +232,108 @@ impl Bootstrapper {
             .join(EXECUTABLE_NAME)
     }
 
-    fn handle_exit_status(exit_status: ExitStatus) -> Action {
-        exit_status
-            .code()
-            .map(|e| {
-                if e == 0 {
-                    trace!("Application process exited with {}", exit_status);
-                    Action::Shutdown
-                } else {
-                    warn!("Application process exited with {}", exit_status);
-                    Action::Restart
+    /// Handle an abnormal exit of the application process.
+    /// The application is rolled back to the previous version when it keeps crashing.
+    fn handle_crash(&self) -> Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|e| e.as_secs())
+            .unwrap_or_default();
+        let mut state = CrashState::load(&self.data_path);
+        let action = state.record_crash(timestamp);
+
+        if let Err(e) = state.write(&self.data_path) {
+            warn!("Failed to write crash state, {}", e);
+        }
+
+        match action {
+            CrashAction::Restart => {
+                warn!(
+                    "Restarting application after {} consecutive crash(es)",
+                    state.consecutive_crashes
+                );
+                Ok(())
+            }
+            CrashAction::Rollback => {
+                warn!(
+                    "Application crashed repeatedly within {:?}, rolling back to the previous version",
+                    CRASH_WINDOW
+                );
+                self.rollback()
+            }
+            CrashAction::Abort => {
+                error!("Application keeps crashing after the rollback, giving up");
+                Err(BootstrapError::CrashLoop(state.consecutive_crashes))
+            }
+        }
+    }
+
+    /// Roll back the application to the previously installed version.
+    /// When no previous version is available, the initial installation of the application is restored.
+    fn rollback(&self) -> Result<()> {
+        let launcher_options_path = self.data_path.join(LauncherOptions::filename());
+        let mut options = Self::get_launcher_options(&self.data_path);
+        let previous_version_exists = options
+            .previous_version
+            .as_ref()
+            .map(|e| self.data_path.join(e).exists())
+            .unwrap_or(false);
+
+        if previous_version_exists && options.rollback() {
+            info!("Rolling back application to version {}", options.version);
+            options
+                .write(launcher_options_path)
+                .map_err(|e| BootstrapError::RollbackFailed(e.to_string()))
+        } else {
+            info!("No previous version available, restoring the initial installation");
+            if launcher_options_path.exists() {
+                fs::remove_file(&launcher_options_path)
+                    .map_err(|e| BootstrapError::RollbackFailed(e.to_string()))?;
+            }
+
+            self.data_installer
+                .prepare()
+                .map_err(|e| BootstrapError::RollbackFailed(e.to_string()))
+        }
+    }
+
+    /// Capture the stderr output of the application process.
+    /// The output is forwarded to the stderr of the bootstrapper and the last lines are kept for diagnostics.
+    fn capture_stderr(stderr: ChildStderr) -> JoinHandle<Vec<String>> {
+        thread::spawn(move || {
+            let mut tail = VecDeque::with_capacity(STDERR_TAIL_LINES);
+
+            for line in BufReader::new(stderr).lines().map_while(|e| e.ok()) {
+                eprintln!("{}", line);
+                if tail.len() == STDERR_TAIL_LINES {
+                    tail.pop_front();
                 }
-            })
-            .unwrap_or(Action::Restart)
+                tail.push_back(line);
+            }
+
+            tail.into_iter().collect()
+        })
+    }
+
+    fn handle_exit_status(exit_status: ExitStatus, stderr_tail: &[String]) -> Action {
+        match exit_status.code() {
+            Some(0) => {
+                trace!("Application process exited with {}", exit_status);
+                Action::Shutdown
+            }
+            Some(RESTART_EXIT_CODE) => {
+                debug!("Application process requested a restart");
+                Action::Restart
+            }
+            code => {
+                error!(
+                    "Application process exited abnormally with code {:?}, stderr:\n{}",
+                    code,
+                    stderr_tail.join("\n")
+                );
+                Action::Crashed
+            }
+        }
     }
 
     fn initialize_logger() {
@@ -445,6 +558,7 @@ mod test {
         let temp_path = temp_dir.path().to_str().unwrap();
         let mut data_installer = MockDataInstaller::new();
         data_installer.expect_prepare().returning(|| Ok(()));
+        data_installer.expect_prune().returning(|| Ok(()));
         let bootstrap = Bootstrapper {
             path: "".to_string(),
             args: vec!["popcorn-fx".to_string()],
@@ -501,6 +615,7 @@ mod test {
         let temp_path = temp_dir.path().to_str().unwrap();
         let mut data_installer = MockDataInstaller::new();
         data_installer.expect_prepare().returning(|| Ok(()));
+        data_installer.expect_prune().returning(|| Ok(()));
         let bootstrap = Bootstrapper {
             path: "".to_string(),
             args: vec![],
@@ -523,6 +638,108 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_handle_crash_rollback() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let data_path = PathBuf::from(temp_dir.path());
+        fs::create_dir_all(data_path.join("1.0.0")).unwrap();
+        LauncherOptions {
+            version: "2.0.0".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            ..Default::default()
+        }
+        .write(data_path.join(LauncherOptions::filename()))
+        .unwrap();
+        CrashState {
+            consecutive_crashes: 2,
+            first_crash: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            rolled_back: false,
+        }
+        .write(&data_path)
+        .unwrap();
+        let bootstrap = Bootstrapper {
+            path: "".to_string(),
+            args: vec![],
+            data_base_path: data_path.join(DATA_DIRECTORY_NAME),
+            data_path: data_path.clone(),
+            process_path: Some("echo".to_string()),
+            data_installer: Box::new(MockDataInstaller::new()),
+            shutting_down: Arc::new(Default::default()),
+        };
+
+        let result = bootstrap.handle_crash();
+
+        assert!(result.is_ok(), "expected the crash to have been handled");
+        let options = LauncherOptions::new(&data_path);
+        assert_eq!("1.0.0".to_string(), options.version);
+        assert_eq!(Some("2.0.0".to_string()), options.previous_version);
+        assert_eq!(true, CrashState::load(&data_path).rolled_back);
+    }
+
+    #[test]
+    fn test_handle_crash_restore_initial_installation() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let data_path = PathBuf::from(temp_dir.path());
+        CrashState {
+            consecutive_crashes: 2,
+            first_crash: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            rolled_back: false,
+        }
+        .write(&data_path)
+        .unwrap();
+        let mut data_installer = MockDataInstaller::new();
+        data_installer
+            .expect_prepare()
+            .times(1)
+            .returning(|| Ok(()));
+        let bootstrap = Bootstrapper {
+            path: "".to_string(),
+            args: vec![],
+            data_base_path: data_path.join(DATA_DIRECTORY_NAME),
+            data_path: data_path.clone(),
+            process_path: Some("echo".to_string()),
+            data_installer: Box::new(data_installer),
+            shutting_down: Arc::new(Default::default()),
+        };
+
+        let result = bootstrap.handle_crash();
+
+        assert!(result.is_ok(), "expected the crash to have been handled");
+    }
+
+    #[cfg(target_family = "unix")]
+    #[test]
+    fn test_handle_exit_status() {
+        use std::os::unix::process::ExitStatusExt;
+
+        init_logger();
+        let stderr_tail = vec!["lorem".to_string()];
+
+        assert_eq!(
+            Action::Shutdown,
+            Bootstrapper::handle_exit_status(ExitStatus::from_raw(0), &stderr_tail)
+        );
+        assert_eq!(
+            Action::Restart,
+            Bootstrapper::handle_exit_status(
+                ExitStatus::from_raw(RESTART_EXIT_CODE << 8),
+                &stderr_tail
+            )
+        );
+        assert_eq!(
+            Action::Crashed,
+            Bootstrapper::handle_exit_status(ExitStatus::from_raw(1 << 8), &stderr_tail)
+        );
+    }
+
     #[test]
     fn test_build_process_path() {
         init_logger();
@@ -542,6 +759,7 @@ mod test {
                 version: "1.0.0".to_string(),
                 runtime_version: "10.0.3".to_string(),
                 vm_args: vec![],
+                ..Default::default()
             },
         );
 
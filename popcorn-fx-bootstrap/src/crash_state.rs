@@ -0,0 +1,219 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::{debug, trace, warn};
+use serde::{Deserialize, Serialize};
+
+const FILENAME: &str = "bootstrap-state.yml";
+/// The number of consecutive abnormal exits after which the application is considered to be in a crash loop.
+pub const MAX_CONSECUTIVE_CRASHES: u32 = 3;
+/// The time window in which the abnormal exits need to occur to be considered consecutive.
+pub const CRASH_WINDOW: Duration = Duration::from_secs(120);
+
+/// The action to take after the application process exited abnormally.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrashAction {
+    /// Restart the application with the current version.
+    Restart,
+    /// Roll back the application to the previous version before restarting it.
+    Rollback,
+    /// Stop restarting the application, as the rollback didn't resolve the crash loop.
+    Abort,
+}
+
+/// The crash state of the application which is persisted between launches of the bootstrapper.
+///
+/// It tracks the abnormal exits of the application process within the [CRASH_WINDOW]
+/// to detect when the application is stuck in a crash loop.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashState {
+    /// The number of consecutive abnormal exits within the crash window.
+    #[serde(default)]
+    pub consecutive_crashes: u32,
+    /// The unix timestamp in seconds of the first abnormal exit within the crash window.
+    #[serde(default)]
+    pub first_crash: u64,
+    /// Indicates if a rollback has already been executed for the current crash loop.
+    #[serde(default)]
+    pub rolled_back: bool,
+}
+
+impl CrashState {
+    /// Load the crash state from the given data path.
+    /// It returns the default state when no state file exists or it couldn't be parsed.
+    pub fn load<P: AsRef<Path>>(data_path: P) -> Self {
+        let path = Self::filepath(data_path);
+
+        trace!("Loading crash state from {:?}", path);
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|e| match serde_yaml::from_str(e.as_str()) {
+                Ok(state) => Some(state),
+                Err(e) => {
+                    warn!("Failed to parse crash state {:?}, {}", path, e);
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    /// Write the crash state to the given data path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message when the state couldn't be written.
+    pub fn write<P: AsRef<Path>>(&self, data_path: P) -> Result<(), String> {
+        let value = serde_yaml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(Self::filepath(data_path), value).map_err(|e| e.to_string())
+    }
+
+    /// Remove the persisted crash state from the given data path, if present.
+    pub fn clear<P: AsRef<Path>>(data_path: P) {
+        let path = Self::filepath(data_path);
+
+        if path.exists() {
+            debug!("Clearing crash state {:?}", path);
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("Failed to remove crash state {:?}, {}", path, e);
+            }
+        }
+    }
+
+    /// Record an abnormal exit of the application process.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The unix timestamp in seconds at which the process exited.
+    ///
+    /// # Returns
+    ///
+    /// The action to take for the abnormal exit.
+    pub fn record_crash(&mut self, timestamp: u64) -> CrashAction {
+        if (self.consecutive_crashes == 0 && !self.rolled_back)
+            || timestamp.saturating_sub(self.first_crash) > CRASH_WINDOW.as_secs()
+        {
+            // the application ran long enough, so this is the start of a new crash window
+            self.consecutive_crashes = 0;
+            self.first_crash = timestamp;
+            self.rolled_back = false;
+        }
+
+        self.consecutive_crashes += 1;
+        if self.consecutive_crashes < MAX_CONSECUTIVE_CRASHES {
+            CrashAction::Restart
+        } else if !self.rolled_back {
+            // the rolled back version gets its own crash window within the same crash loop
+            self.consecutive_crashes = 0;
+            self.first_crash = timestamp;
+            self.rolled_back = true;
+            CrashAction::Rollback
+        } else {
+            CrashAction::Abort
+        }
+    }
+
+    fn filepath<P: AsRef<Path>>(data_path: P) -> PathBuf {
+        data_path.as_ref().join(FILENAME)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempfile::tempdir;
+
+    use popcorn_fx_core::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_record_crash() {
+        let mut state = CrashState::default();
+
+        let result = state.record_crash(1000);
+
+        assert_eq!(CrashAction::Restart, result);
+        assert_eq!(1, state.consecutive_crashes);
+        assert_eq!(1000, state.first_crash);
+    }
+
+    #[test]
+    fn test_record_crash_loop() {
+        let mut state = CrashState::default();
+
+        assert_eq!(CrashAction::Restart, state.record_crash(1000));
+        assert_eq!(CrashAction::Restart, state.record_crash(1010));
+        assert_eq!(CrashAction::Rollback, state.record_crash(1020));
+        assert_eq!(true, state.rolled_back);
+
+        // the rolled back version keeps crashing
+        assert_eq!(CrashAction::Restart, state.record_crash(1030));
+        assert_eq!(CrashAction::Restart, state.record_crash(1040));
+        assert_eq!(CrashAction::Abort, state.record_crash(1050));
+        assert_eq!(CrashAction::Abort, state.record_crash(1060));
+    }
+
+    #[test]
+    fn test_record_crash_rolled_back_outside_window() {
+        let mut state = CrashState::default();
+
+        assert_eq!(CrashAction::Restart, state.record_crash(1000));
+        assert_eq!(CrashAction::Restart, state.record_crash(1010));
+        assert_eq!(CrashAction::Rollback, state.record_crash(1020));
+
+        // the rolled back version ran long enough before crashing
+        let result = state.record_crash(1020 + CRASH_WINDOW.as_secs() + 1);
+
+        assert_eq!(CrashAction::Restart, result);
+        assert_eq!(1, state.consecutive_crashes);
+        assert_eq!(false, state.rolled_back);
+    }
+
+    #[test]
+    fn test_record_crash_outside_window() {
+        let mut state = CrashState {
+            consecutive_crashes: 2,
+            first_crash: 1000,
+            rolled_back: true,
+        };
+
+        let result = state.record_crash(1000 + CRASH_WINDOW.as_secs() + 1);
+
+        assert_eq!(CrashAction::Restart, result);
+        assert_eq!(1, state.consecutive_crashes);
+        assert_eq!(false, state.rolled_back);
+    }
+
+    #[test]
+    fn test_write_and_load() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let state = CrashState {
+            consecutive_crashes: 2,
+            first_crash: 1000,
+            rolled_back: true,
+        };
+
+        state.write(temp_dir.path()).unwrap();
+        let result = CrashState::load(temp_dir.path());
+
+        assert_eq!(state, result);
+    }
+
+    #[test]
+    fn test_clear() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        CrashState {
+            consecutive_crashes: 1,
+            first_crash: 1000,
+            rolled_back: false,
+        }
+        .write(temp_dir.path())
+        .unwrap();
+
+        CrashState::clear(temp_dir.path());
+
+        assert_eq!(CrashState::default(), CrashState::load(temp_dir.path()));
+    }
+}
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use log::{debug, trace};
 use mockall::automock;
+use semver::Version;
 use thiserror::Error;
 
 use popcorn_fx_core::core::launcher::{LauncherError, LauncherOptions};
@@ -45,6 +46,14 @@ pub trait DataInstaller: Debug + Send + Sync {
     ///
     /// Returns an error when the data directory couldn't be initialized.
     fn prepare(&self) -> Result<()>;
+
+    /// Removes the application versions from the user's data system which can no longer be launched or rolled back to.
+    /// Only the active version and the previous version are retained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when an old version couldn't be removed.
+    fn prune(&self) -> Result<()>;
 }
 
 /// The data installer is responsible for making sure that the initial application version libraries and data are available
@@ -94,6 +103,11 @@ impl DefaultDataInstaller {
         Ok(())
     }
 
+    /// Verify if the given directory name represents an application version directory.
+    fn is_version_directory(name: &str) -> bool {
+        Version::parse(name).is_ok()
+    }
+
     fn write_default_launcher_options<T: AsRef<Path>>(launcher_options_path: T) -> Result<()> {
         let options = LauncherOptions::default();
         options
@@ -127,6 +141,35 @@ impl DataInstaller for DefaultDataInstaller {
 
         Ok(())
     }
+
+    fn prune(&self) -> Result<()> {
+        let options = LauncherOptions::new(self.data_path.as_path());
+        let retained_versions = options.retained_versions();
+
+        trace!(
+            "Pruning application versions, retaining {:?}",
+            retained_versions
+        );
+        for entry in
+            fs::read_dir(&self.data_path).map_err(|e| DataInstallerError::IoError(e.to_string()))?
+        {
+            let entry = entry.map_err(|e| DataInstallerError::IoError(e.to_string()))?;
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+
+            if path.is_dir()
+                && Self::is_version_directory(name.as_ref())
+                && !retained_versions.contains(&name.as_ref())
+            {
+                debug!("Removing old application version {:?}", path);
+                fs::remove_dir_all(&path)
+                    .map_err(|e| DataInstallerError::IoError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +249,38 @@ mod test {
         assert!(file2.exists(), "expected {:?} to exist", file2);
     }
 
+    #[test]
+    fn test_prune() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let data_path = PathBuf::from(temp_dir.path());
+        for directory in ["1.0.0", "2.0.0", "3.0.0", "runtimes"] {
+            fs::create_dir_all(data_path.join(directory)).unwrap();
+        }
+        LauncherOptions {
+            version: "3.0.0".to_string(),
+            previous_version: Some("2.0.0".to_string()),
+            ..Default::default()
+        }
+        .write(data_path.join(LauncherOptions::filename()))
+        .unwrap();
+        let installer = DefaultDataInstaller {
+            data_path: data_path.clone(),
+            installation_path: data_path.join(INITIAL_INSTALL_DIRECTORY),
+        };
+
+        let result = installer.prune();
+
+        assert_eq!(Ok(()), result);
+        assert!(
+            !data_path.join("1.0.0").exists(),
+            "expected the old version to have been removed"
+        );
+        assert!(data_path.join("2.0.0").exists());
+        assert!(data_path.join("3.0.0").exists());
+        assert!(data_path.join("runtimes").exists());
+    }
+
     #[test]
     fn test_from_launcher_options() {
         let parser_error = "my parser error";
@@ -15,6 +15,7 @@ use tokio::runtime::Runtime;
 use crate::bootstrapper::{BootstrapError, Bootstrapper};
 
 mod bootstrapper;
+mod crash_state;
 mod data_installer;
 
 const ENV_INSTALLATION_DIR: &str = "INSTALLATION_DIR";
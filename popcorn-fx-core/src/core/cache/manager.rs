@@ -228,6 +228,71 @@ impl CacheManager {
             .await
     }
 
+    /// Verify if the cache contains a valid entry for the given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cache.
+    /// * `key` - The key of the cache entry.
+    /// * `options` - The cache options which are used to verify the expiration of the entry.
+    ///
+    /// # Returns
+    ///
+    /// `true` when a non-expired entry is present, else `false`.
+    pub async fn contains(&self, name: &str, key: &str, options: &CacheOptions) -> bool {
+        self.inner.cache_entry(name, key, options).await.is_some()
+    }
+
+    /// Retrieve the number of entries which are stored within the given cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cache.
+    pub async fn count(&self, name: &str) -> usize {
+        let cache = self.inner.cache_info.lock().await;
+        cache.entries(name).map(|e| e.len()).unwrap_or(0)
+    }
+
+    /// Remove the entries of the given cache for which the key matches the given predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the cache.
+    /// * `predicate` - The predicate which is tested against the key of each entry.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries which have been removed.
+    pub async fn remove_matching<P>(&self, name: &str, predicate: P) -> usize
+    where
+        P: Fn(&str) -> bool,
+    {
+        let mut cache = self.inner.cache_info.lock().await;
+        let entries: Vec<CacheEntry> = cache
+            .entries(name)
+            .map(|e| e.iter().filter(|e| predicate(e.key())).cloned().collect())
+            .unwrap_or_default();
+        let mut removed = 0;
+
+        for entry in entries.into_iter() {
+            if let Err(e) = Storage::delete(entry.path()) {
+                warn!(
+                    "Failed to delete cache file {}, {}",
+                    entry.absolute_path(),
+                    e
+                );
+            }
+
+            cache.remove(name, entry.key());
+            removed += 1;
+        }
+
+        drop(cache);
+        debug!("Removed {} entries from cache {}", removed, name);
+        let _ = self.inner.write_cache_info().await;
+        removed
+    }
+
     fn run_cleanup(&self) {
         let cache_manager = self.inner.clone();
         self.runtime.spawn(async move {
@@ -1333,6 +1398,76 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_contains() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache_manager = CacheManagerBuilder::default()
+            .storage_path(temp_path)
+            .build();
+        let options = CacheOptions {
+            cache_type: CacheType::CacheFirst,
+            expires_after: Duration::hours(1),
+        };
+        let runtime = Runtime::new().unwrap();
+
+        let (before, after, count) = runtime.block_on(async {
+            let before = cache_manager.contains("test", "lorem", &options).await;
+            let _ = cache_manager
+                .operation()
+                .name("test")
+                .key("lorem")
+                .options(options.clone())
+                .execute(async { Ok::<Vec<u8>, MediaError>(vec![1, 2, 3]) })
+                .await;
+            let after = cache_manager.contains("test", "lorem", &options).await;
+            let count = cache_manager.count("test").await;
+            (before, after, count)
+        });
+
+        assert_eq!(false, before);
+        assert_eq!(true, after);
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn test_remove_matching() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache_manager = CacheManagerBuilder::default()
+            .storage_path(temp_path)
+            .build();
+        let options = CacheOptions {
+            cache_type: CacheType::CacheFirst,
+            expires_after: Duration::hours(1),
+        };
+        let runtime = Runtime::new().unwrap();
+
+        let (removed, lorem, ipsum) = runtime.block_on(async {
+            for key in ["lorem", "ipsum"] {
+                let _ = cache_manager
+                    .operation()
+                    .name("test")
+                    .key(key)
+                    .options(options.clone())
+                    .execute(async { Ok::<Vec<u8>, MediaError>(vec![1, 2, 3]) })
+                    .await;
+            }
+            let removed = cache_manager
+                .remove_matching("test", |key| key == "lorem")
+                .await;
+            let lorem = cache_manager.contains("test", "lorem", &options).await;
+            let ipsum = cache_manager.contains("test", "ipsum", &options).await;
+            (removed, lorem, ipsum)
+        });
+
+        assert_eq!(1, removed);
+        assert_eq!(false, lorem);
+        assert_eq!(true, ipsum);
+    }
+
     #[test]
     fn test_map_parser_error() {
         if let CacheExecutionError::Operation(e) = InnerCacheManager::map_cache_parser_error(
@@ -1,15 +1,25 @@
+use std::fmt::{Debug, Formatter};
+use std::fs;
+use std::path::Path;
+
 use derive_more::Display;
 use log::{debug, error, info, trace, warn};
+use serde_json::{Map, Value};
 use tokio::sync::{Mutex, MutexGuard};
 
 use crate::core::{block_in_place, Callbacks, CoreCallback, CoreCallbacks};
 use crate::core::config::{
-    ConfigError, PlaybackSettings, PopcornProperties, PopcornSettings, ServerSettings,
-    SubtitleSettings, TorrentSettings, Tracker, TrackingSettings, UiSettings,
+    CleaningMode, ConfigError, NetworkSettings, PlaybackSettings, PopcornProperties,
+    PopcornSettings, ServerSettings, SettingsSection, SubtitleSettings, TorrentSettings, Tracker,
+    TrackingSettings, UiSettings,
 };
+use crate::core::media::Category;
 use crate::core::storage::Storage;
 
 const DEFAULT_SETTINGS_FILENAME: &str = "settings.json";
+/// The current version of the persisted settings document.
+const SETTINGS_VERSION: u32 = 2;
+const SETTINGS_VERSION_KEY: &str = "version";
 
 /// The config result type for all results returned by the config package.
 pub type Result<T> = std::result::Result<T, ConfigError>;
@@ -17,6 +27,48 @@ pub type Result<T> = std::result::Result<T, ConfigError>;
 /// The callback type for the settings.
 pub type ApplicationConfigCallback = CoreCallback<ApplicationConfigEvent>;
 
+/// The function which is invoked to migrate the settings document to a newer settings version.
+/// It receives the json sections of the persisted settings document.
+pub type SettingsMigrationFn = fn(&mut Map<String, Value>) -> std::result::Result<(), String>;
+
+/// A single migration step of the persisted settings document.
+#[derive(Clone)]
+pub struct SettingsMigration {
+    /// The settings version which is reached by this migration.
+    pub version: u32,
+    /// A short description of the migration.
+    pub description: &'static str,
+    /// The function which executes the migration.
+    pub migrate: SettingsMigrationFn,
+}
+
+impl Debug for SettingsMigration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SettingsMigration")
+            .field("version", &self.version)
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+/// Retrieve the known migrations of the settings document.
+/// New migrations should be registered here, together with an increase of the [SETTINGS_VERSION],
+/// when settings fields are renamed or their values change meaning.
+pub fn settings_migrations() -> Vec<SettingsMigration> {
+    vec![
+        SettingsMigration {
+            version: 1,
+            description: "replace the torrent auto cleaning flag with the cleaning mode",
+            migrate: migrate_torrent_cleaning_mode,
+        },
+        SettingsMigration {
+            version: 2,
+            description: "rename the ui start screen to the start page",
+            migrate: migrate_ui_start_page,
+        },
+    ]
+}
+
 /// The events that can occur within the application settings.
 #[derive(Debug, Clone, Display)]
 pub enum ApplicationConfigEvent {
@@ -41,6 +93,12 @@ pub enum ApplicationConfigEvent {
     /// Invoked when the tracking settings have been changed
     #[display(fmt = "Tracking settings have changed")]
     TrackingSettingsChanged(TrackingSettings),
+    /// Invoked when the network settings have been changed
+    #[display(fmt = "Network settings have been changed")]
+    NetworkSettingsChanged(NetworkSettings),
+    /// Invoked when the persisted settings couldn't be loaded, e.g. because a migration failed
+    #[display(fmt = "Settings error occurred, {}", _0)]
+    SettingsError(ConfigError),
 }
 
 /// The application properties & settings of Popcorn FX.
@@ -128,8 +186,10 @@ impl ApplicationConfig {
 
     /// Update the torrent settings of the application.
     /// The update will be ignored if no fields have been changed.
-    pub fn update_torrent(&self, settings: TorrentSettings) {
+    pub fn update_torrent(&self, mut settings: TorrentSettings) {
         let mut torrent_settings: Option<TorrentSettings> = None;
+        // store the effective peer connect timeout, so it's reflected when the settings are read
+        settings.peer_connect_timeout = settings.peer_connect_timeout().as_secs() as u32;
         {
             let mut mutex = block_in_place(self.settings.lock());
             if mutex.torrent_settings != settings {
@@ -207,6 +267,75 @@ impl ApplicationConfig {
         }
     }
 
+    /// Update the network settings of the application.
+    /// The update will be ignored if no fields have been changed.
+    pub fn update_network(&self, settings: NetworkSettings) {
+        trace!("Updating network settings");
+        let mut network_settings: Option<NetworkSettings> = None;
+        {
+            let mut mutex = block_in_place(self.settings.lock());
+            if mutex.network_settings != settings {
+                mutex.network_settings = settings;
+                network_settings = Some(mutex.network().clone());
+                debug!("Network settings have been updated");
+            }
+        }
+
+        if let Some(settings) = network_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::NetworkSettingsChanged(settings));
+            self.save();
+        }
+    }
+
+    /// Retrieve the provider uri's of the given category.
+    /// The uri overrides of the user take precedence over the uri's of the bundled provider properties.
+    pub fn provider_uris(&self, category: &Category) -> Vec<String> {
+        let name = category.name();
+        if let Some(uris) = self
+            .user_settings_ref()
+            .server()
+            .provider_uris(name.as_str())
+        {
+            trace!("Using provider uri overrides {:?} for {}", uris, category);
+            return uris.clone();
+        }
+
+        match self.properties_ref().provider(name.as_str()) {
+            Ok(e) => e.uris().to_vec(),
+            Err(e) => {
+                warn!(
+                    "Failed to retrieve the provider uris of {}, {}",
+                    category, e
+                );
+                vec![]
+            }
+        }
+    }
+
+    /// Override the provider uri's of the given category.
+    /// The overrides are stored within the user settings, which keeps them separate from the bundled provider properties.
+    /// An empty list of uri's removes the overrides of the category.
+    pub fn update_provider_uris(&self, category: &Category, uris: Vec<String>) {
+        trace!("Updating provider uris of {} to {:?}", category, uris);
+        let mut settings = self.user_settings_ref().server().clone();
+
+        if uris.is_empty() {
+            settings.provider_uris.remove(&category.name());
+        } else {
+            settings.provider_uris.insert(category.name(), uris);
+        }
+
+        self.update_server(settings);
+    }
+
+    /// Remove the provider uri overrides of the given category.
+    /// This restores the uri's of the bundled provider properties for the category.
+    pub fn reset_provider_uris(&self, category: &Category) {
+        trace!("Resetting provider uris of {}", category);
+        self.update_provider_uris(category, vec![]);
+    }
+
     /// Update the tracking settings of the application.
     /// This will update an individual tracker of the application without affecting any other trackers.
     pub fn update_tracker(&self, name: &str, tracker: Tracker) {
@@ -252,12 +381,7 @@ impl ApplicationConfig {
     /// Reload the application config.
     pub fn reload(&self) {
         trace!("Reloading application settings");
-        match self
-            .storage
-            .options()
-            .serializer(DEFAULT_SETTINGS_FILENAME)
-            .read::<PopcornSettings>()
-        {
+        match read_settings(&self.storage) {
             Ok(e) => {
                 debug!("Application settings have been read from storage");
                 let old_settings: PopcornSettings;
@@ -275,39 +399,94 @@ impl ApplicationConfig {
                 // start invoking events
                 self.callbacks
                     .invoke(ApplicationConfigEvent::SettingsLoaded);
-
-                if old_settings.subtitle_settings != new_settings.subtitle_settings {
-                    self.callbacks
-                        .invoke(ApplicationConfigEvent::SubtitleSettingsChanged(
-                            new_settings.subtitle().clone(),
-                        ));
-                }
-                if old_settings.torrent_settings != new_settings.torrent_settings {
-                    self.callbacks
-                        .invoke(ApplicationConfigEvent::TorrentSettingsChanged(
-                            new_settings.torrent().clone(),
-                        ))
-                }
-                if old_settings.ui_settings != new_settings.ui_settings {
-                    self.callbacks
-                        .invoke(ApplicationConfigEvent::UiSettingsChanged(
-                            new_settings.ui().clone(),
-                        ))
-                }
-                if old_settings.server_settings != new_settings.server_settings {
-                    self.callbacks
-                        .invoke(ApplicationConfigEvent::ServerSettingsChanged(
-                            new_settings.server().clone(),
-                        ))
-                }
-                if old_settings.playback_settings != new_settings.playback_settings {
+                self.invoke_changes(&old_settings, &new_settings);
+            }
+            Err(e) => {
+                warn!("Failed to reload settings from storage, {}", e);
+                if let ConfigError::MigrationFailed(_, _) = &e {
                     self.callbacks
-                        .invoke(ApplicationConfigEvent::PlaybackSettingsChanged(
-                            new_settings.playback().clone(),
-                        ))
+                        .invoke(ApplicationConfigEvent::SettingsError(e));
                 }
             }
-            Err(e) => warn!("Failed to reload settings from storage, {}", e),
+        }
+    }
+
+    /// Export the current user settings to the given file path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to which the settings should be written.
+    pub fn export(&self, path: &Path) -> Result<()> {
+        trace!("Exporting application settings to {:?}", path);
+        let settings = self.user_settings();
+        let data = versioned_settings(&settings)
+            .and_then(|e| serde_json::to_string_pretty(&e))
+            .map_err(|e| {
+                ConfigError::SettingsFile(path.to_string_lossy().to_string(), e.to_string())
+            })?;
+
+        fs::write(path, data).map_err(|e| {
+            ConfigError::SettingsFile(path.to_string_lossy().to_string(), e.to_string())
+        })?;
+        info!("Settings have been exported to {:?}", path);
+        Ok(())
+    }
+
+    /// Import the user settings from the given file path.
+    /// The settings are validated before they replace the current settings, missing fields will use their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file from which the settings should be read.
+    ///
+    /// # Returns
+    ///
+    /// It returns [ConfigError::InvalidSettings] when the file contains unknown or invalid fields,
+    /// in which case the current settings remain unchanged.
+    pub fn import(&self, path: &Path) -> Result<()> {
+        trace!("Importing application settings from {:?}", path);
+        let data = fs::read_to_string(path).map_err(|e| {
+            ConfigError::SettingsFile(path.to_string_lossy().to_string(), e.to_string())
+        })?;
+        let value = serde_json::from_str::<Value>(data.as_str()).map_err(|e| {
+            ConfigError::SettingsFile(path.to_string_lossy().to_string(), e.to_string())
+        })?;
+        let mut value = migrate_settings(value, SETTINGS_VERSION, &settings_migrations())?;
+        if let Some(sections) = value.as_object_mut() {
+            sections.remove(SETTINGS_VERSION_KEY);
+        }
+        let settings = PopcornSettings::from_json(&value)?;
+        let old_settings: PopcornSettings;
+
+        {
+            let mut mutex = block_in_place(self.settings.lock());
+            old_settings = mutex.clone();
+            *mutex = settings.clone();
+        }
+        info!("Settings have been imported from {:?}", path);
+
+        self.invoke_changes(&old_settings, &settings);
+        self.save();
+        Ok(())
+    }
+
+    /// Reset the given section of the user settings to its default values.
+    pub fn reset_section(&self, section: SettingsSection) {
+        trace!("Resetting {} settings", section);
+        let old_settings: PopcornSettings;
+        let new_settings: PopcornSettings;
+
+        {
+            let mut mutex = block_in_place(self.settings.lock());
+            old_settings = mutex.clone();
+            mutex.reset(section);
+            new_settings = mutex.clone();
+        }
+        debug!("The {} settings have been reset to their defaults", section);
+
+        if old_settings != new_settings {
+            self.invoke_changes(&old_settings, &new_settings);
+            self.save();
         }
     }
 
@@ -326,13 +505,67 @@ impl ApplicationConfig {
         self.internal_save(&settings).await
     }
 
+    /// Invoke the change events of each settings section which differs between the old and new settings.
+    fn invoke_changes(&self, old_settings: &PopcornSettings, new_settings: &PopcornSettings) {
+        if old_settings.subtitle_settings != new_settings.subtitle_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::SubtitleSettingsChanged(
+                    new_settings.subtitle().clone(),
+                ));
+        }
+        if old_settings.torrent_settings != new_settings.torrent_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::TorrentSettingsChanged(
+                    new_settings.torrent().clone(),
+                ))
+        }
+        if old_settings.ui_settings != new_settings.ui_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::UiSettingsChanged(
+                    new_settings.ui().clone(),
+                ))
+        }
+        if old_settings.server_settings != new_settings.server_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::ServerSettingsChanged(
+                    new_settings.server().clone(),
+                ))
+        }
+        if old_settings.playback_settings != new_settings.playback_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::PlaybackSettingsChanged(
+                    new_settings.playback().clone(),
+                ))
+        }
+        if old_settings.tracking_settings != new_settings.tracking_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::TrackingSettingsChanged(
+                    new_settings.tracking().clone(),
+                ))
+        }
+        if old_settings.network_settings != new_settings.network_settings {
+            self.callbacks
+                .invoke(ApplicationConfigEvent::NetworkSettingsChanged(
+                    new_settings.network().clone(),
+                ))
+        }
+    }
+
     async fn internal_save(&self, settings: &PopcornSettings) {
         trace!("Saving application settings {:?}", settings);
+        let value = match versioned_settings(settings) {
+            Ok(e) => e,
+            Err(e) => {
+                error!("Failed to save settings, {}", e);
+                return;
+            }
+        };
+
         match self
             .storage
             .options()
             .serializer(DEFAULT_SETTINGS_FILENAME)
-            .write_async(settings)
+            .write_async(&value)
             .await
         {
             Ok(_) => info!("Settings have been saved"),
@@ -456,16 +689,31 @@ impl ApplicationConfigBuilder {
     /// ```
     pub fn build(self) -> ApplicationConfig {
         let storage = self.storage.expect("storage path has not been set");
-        let settings = self.settings
-            .or_else(|| {
-                match storage.options()
-                    .serializer(DEFAULT_SETTINGS_FILENAME)
-                    .read::<PopcornSettings>() {
-                    Ok(e) => Some(e),
-                    Err(e) => {
-                        warn!("Failed to read settings from storage, using default settings instead, {}", e);
-                        Some(PopcornSettings::default())
-                    }
+        let callbacks = self.callbacks;
+        let settings = self
+            .settings
+            .or_else(|| match read_settings(&storage) {
+                Ok(e) => Some(e),
+                Err(e @ ConfigError::MigrationFailed(_, _)) => {
+                    warn!(
+                        "Failed to migrate settings, using the unmigrated settings instead, {}",
+                        e
+                    );
+                    callbacks.invoke(ApplicationConfigEvent::SettingsError(e));
+                    Some(
+                        storage
+                            .options()
+                            .serializer(DEFAULT_SETTINGS_FILENAME)
+                            .read::<PopcornSettings>()
+                            .unwrap_or_default(),
+                    )
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to read settings from storage, using default settings instead, {}",
+                        e
+                    );
+                    Some(PopcornSettings::default())
                 }
             })
             .unwrap();
@@ -478,9 +726,180 @@ impl ApplicationConfigBuilder {
             storage,
             properties: Mutex::new(properties),
             settings: Mutex::new(settings),
-            callbacks: self.callbacks,
+            callbacks,
+        }
+    }
+}
+
+/// Create the persisted settings document, which stores the settings together with the settings version.
+fn versioned_settings(settings: &PopcornSettings) -> serde_json::Result<Value> {
+    let mut value = serde_json::to_value(settings)?;
+    if let Some(sections) = value.as_object_mut() {
+        sections.insert(
+            SETTINGS_VERSION_KEY.to_string(),
+            Value::from(SETTINGS_VERSION),
+        );
+    }
+    Ok(value)
+}
+
+/// Read the persisted settings from the given storage.
+///
+/// Settings of an older settings version are migrated to the current [SETTINGS_VERSION].
+/// The original settings file is backed up next to it before the migration is executed,
+/// and is only replaced by the migrated settings when all migrations succeeded.
+fn read_settings(storage: &Storage) -> Result<PopcornSettings> {
+    let data = storage
+        .options()
+        .binary(DEFAULT_SETTINGS_FILENAME)
+        .read()
+        .map_err(|e| {
+            ConfigError::SettingsFile(DEFAULT_SETTINGS_FILENAME.to_string(), e.to_string())
+        })?;
+    let value = serde_json::from_slice::<Value>(&data).map_err(|e| {
+        ConfigError::SettingsFile(DEFAULT_SETTINGS_FILENAME.to_string(), e.to_string())
+    })?;
+    let stored_version = settings_version(&value);
+
+    if stored_version > SETTINGS_VERSION {
+        warn!(
+            "Settings version {} is newer than the supported version {}, skipping migrations",
+            stored_version, SETTINGS_VERSION
+        );
+    }
+    if stored_version >= SETTINGS_VERSION {
+        return serde_json::from_value::<PopcornSettings>(value).map_err(|e| {
+            ConfigError::SettingsFile(DEFAULT_SETTINGS_FILENAME.to_string(), e.to_string())
+        });
+    }
+
+    let backup_filename = format!("{}.v{}.bak", DEFAULT_SETTINGS_FILENAME, stored_version);
+    storage
+        .options()
+        .binary(backup_filename.as_str())
+        .write(&data)
+        .map_err(|e| ConfigError::SettingsFile(backup_filename.clone(), e.to_string()))?;
+    debug!("Settings have been backed up to {}", backup_filename);
+
+    let value = migrate_settings(value, SETTINGS_VERSION, &settings_migrations())?;
+    let settings = serde_json::from_value::<PopcornSettings>(value)
+        .map_err(|e| ConfigError::MigrationFailed(SETTINGS_VERSION, e.to_string()))?;
+    let value = versioned_settings(&settings)
+        .map_err(|e| ConfigError::MigrationFailed(SETTINGS_VERSION, e.to_string()))?;
+
+    storage
+        .options()
+        .serializer(DEFAULT_SETTINGS_FILENAME)
+        .write(&value)
+        .map_err(|e| {
+            ConfigError::SettingsFile(DEFAULT_SETTINGS_FILENAME.to_string(), e.to_string())
+        })?;
+    info!(
+        "Settings have been migrated from version {} to {}",
+        stored_version, SETTINGS_VERSION
+    );
+    Ok(settings)
+}
+
+/// Retrieve the settings version of the given settings document.
+/// Settings documents without a version are from before the settings were versioned.
+fn settings_version(value: &Value) -> u32 {
+    value
+        .get(SETTINGS_VERSION_KEY)
+        .and_then(|e| e.as_u64())
+        .map(|e| e as u32)
+        .unwrap_or(0)
+}
+
+/// Execute the pending migrations of the given settings document in order, up until the given version.
+///
+/// It returns the migrated settings document,
+/// or the [ConfigError::MigrationFailed] of the first migration which failed.
+fn migrate_settings(
+    mut value: Value,
+    version: u32,
+    migrations: &[SettingsMigration],
+) -> Result<Value> {
+    let stored_version = settings_version(&value);
+    if stored_version >= version {
+        trace!("Settings are up-to-date with version {}", stored_version);
+        return Ok(value);
+    }
+
+    let sections = value.as_object_mut().ok_or_else(|| {
+        ConfigError::MigrationFailed(version, "expected a settings object".to_string())
+    })?;
+    let mut pending: Vec<&SettingsMigration> = migrations
+        .iter()
+        .filter(|e| e.version > stored_version && e.version <= version)
+        .collect();
+    pending.sort_by_key(|e| e.version);
+
+    for migration in pending {
+        debug!(
+            "Executing settings migration {}, {}",
+            migration.version, migration.description
+        );
+        (migration.migrate)(sections).map_err(|e| {
+            error!(
+                "Settings migration {} ({}) failed, {}",
+                migration.version, migration.description, e
+            );
+            ConfigError::MigrationFailed(migration.version, e)
+        })?;
+    }
+
+    sections.insert(SETTINGS_VERSION_KEY.to_string(), Value::from(version));
+    Ok(value)
+}
+
+/// Replace the retired `auto_cleaning_enabled` flag of the torrent settings with the cleaning mode.
+fn migrate_torrent_cleaning_mode(
+    sections: &mut Map<String, Value>,
+) -> std::result::Result<(), String> {
+    let torrent_settings = match sections.get_mut("torrent_settings") {
+        Some(Value::Object(e)) => e,
+        Some(_) => return Err("expected torrent_settings to be an object".to_string()),
+        None => return Ok(()),
+    };
+
+    if let Some(value) = torrent_settings.remove("auto_cleaning_enabled") {
+        let enabled = value
+            .as_bool()
+            .ok_or_else(|| format!("expected a boolean auto_cleaning_enabled, got {}", value))?;
+        let cleaning_mode = if enabled {
+            CleaningMode::OnShutdown
+        } else {
+            CleaningMode::Off
+        };
+
+        if !torrent_settings.contains_key("cleaning_mode") {
+            torrent_settings.insert(
+                "cleaning_mode".to_string(),
+                serde_json::to_value(cleaning_mode).map_err(|e| e.to_string())?,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Rename the `start_screen` of the ui settings to the `start_page`.
+/// The start screen categories share their names with the start pages, so the value is kept as is.
+fn migrate_ui_start_page(sections: &mut Map<String, Value>) -> std::result::Result<(), String> {
+    let ui_settings = match sections.get_mut("ui_settings") {
+        Some(Value::Object(e)) => e,
+        Some(_) => return Err("expected ui_settings to be an object".to_string()),
+        None => return Ok(()),
+    };
+
+    if let Some(value) = ui_settings.remove("start_screen") {
+        if !ui_settings.contains_key("start_page") {
+            ui_settings.insert("start_page".to_string(), value);
         }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -492,9 +911,9 @@ mod test {
     use tempfile::tempdir;
 
     use crate::core::config::{
-        CleaningMode, DecorationType, Quality, SubtitleFamily, SubtitleSettings, UiScale,
+        CleaningMode, DecorationType, EncryptionMode, PosterQuality, Quality, StartPage,
+        SubtitleFamily, SubtitleSettings, TvModeSubtitle, UiScale,
     };
-    use crate::core::media::Category;
     use crate::core::subtitles::language::SubtitleLanguage;
     use crate::testing::{copy_test_file, init_logger, read_temp_dir_file_as_string};
 
@@ -533,6 +952,7 @@ mod test {
             torrent_settings: Default::default(),
             playback_settings: Default::default(),
             tracking_settings: Default::default(),
+            network_settings: Default::default(),
         };
 
         let result = application.user_settings();
@@ -607,6 +1027,11 @@ mod test {
             font_size: 24,
             decoration: DecorationType::None,
             bold: true,
+            max_line_length: 0,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: None,
         };
         application
             .storage
@@ -619,6 +1044,7 @@ mod test {
                 torrent_settings: Default::default(),
                 playback_settings: Default::default(),
                 tracking_settings: Default::default(),
+                network_settings: Default::default(),
             })
             .expect("expected the test file to have been written");
 
@@ -654,6 +1080,11 @@ mod test {
             font_size: 22,
             decoration: DecorationType::None,
             bold: false,
+            max_line_length: 0,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: None,
         };
         let application = ApplicationConfig {
             storage: Storage::from(temp_path),
@@ -691,6 +1122,21 @@ mod test {
             connections_limit: 100,
             download_rate_limit: 0,
             upload_rate_limit: 0,
+            stream_idle_timeout: 0,
+            remove_idle_torrents: false,
+            bind_interface: None,
+            encryption_mode: EncryptionMode::Prefer,
+            auto_collect_finished: false,
+            initial_buffer_size: 1024,
+            peer_connect_timeout: 30,
+            announce_to_all_trackers: true,
+            request_timeout: 20,
+            ip_filter: None,
+            preallocate: false,
+            dht_read_only: false,
+            max_metadata_size: 10 * 1024 * 1024,
+            max_request_queue_depth: 500,
+            dual_stack: true,
         };
         let application = ApplicationConfig {
             storage: Storage::from(temp_path),
@@ -716,6 +1162,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_update_torrent_peer_connect_timeout() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+
+        application.update_torrent(TorrentSettings {
+            peer_connect_timeout: 0,
+            ..Default::default()
+        });
+
+        let result = application.user_settings().torrent_settings;
+        assert_eq!(1, result.peer_connect_timeout);
+    }
+
     #[test]
     fn test_update_ui() {
         init_logger();
@@ -724,7 +1191,8 @@ mod test {
         let settings = UiSettings {
             default_language: "en".to_string(),
             ui_scale: UiScale::new(1.2).unwrap(),
-            start_screen: Category::Favorites,
+            start_page: StartPage::Favorites,
+            poster_quality: PosterQuality::High,
             maximized: false,
             native_window_enabled: false,
         };
@@ -756,6 +1224,7 @@ mod test {
         let temp_path = temp_dir.path().to_str().unwrap();
         let settings = ServerSettings {
             api_server: Some("http://localhost:8080".to_string()),
+            provider_uris: Default::default(),
         };
         let application = ApplicationConfig {
             storage: Storage::from(temp_path),
@@ -781,6 +1250,127 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_provider_uris() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let override_uri = "https://my-mirror.local".to_string();
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let bundled_uris = PopcornProperties::default()
+            .provider("movies")
+            .unwrap()
+            .uris()
+            .to_vec();
+
+        assert_eq!(bundled_uris, application.provider_uris(&Category::Movies));
+
+        application.update_provider_uris(&Category::Movies, vec![override_uri.clone()]);
+        assert_eq!(
+            vec![override_uri.clone()],
+            application.provider_uris(&Category::Movies),
+            "expected the override to take precedence over the bundled uris"
+        );
+        assert_ne!(
+            vec![override_uri],
+            application.provider_uris(&Category::Series),
+            "expected the override to only apply to the movies category"
+        );
+        assert_eq!(
+            bundled_uris,
+            application
+                .properties()
+                .provider("movies")
+                .unwrap()
+                .uris()
+                .to_vec(),
+            "expected the bundled provider properties to be untouched"
+        );
+    }
+
+    #[test]
+    fn test_reset_provider_uris() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let (tx, rx) = channel();
+        application.update_provider_uris(
+            &Category::Series,
+            vec!["https://my-mirror.local".to_string()],
+        );
+
+        application.register(Box::new(move |event| tx.send(event).unwrap()));
+        application.reset_provider_uris(&Category::Series);
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+
+        match result {
+            ApplicationConfigEvent::ServerSettingsChanged(result) => {
+                assert_eq!(None, result.provider_uris("series"));
+            }
+            _ => assert!(
+                false,
+                "expected ApplicationConfigEvent::ServerSettingsChanged"
+            ),
+        }
+        assert_eq!(
+            PopcornProperties::default()
+                .provider("series")
+                .unwrap()
+                .uris()
+                .to_vec(),
+            application.provider_uris(&Category::Series)
+        );
+    }
+
+    #[test]
+    fn test_update_network() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = NetworkSettings {
+            proxy: Some("http://localhost:3128".to_string()),
+            proxy_username: None,
+            proxy_password: None,
+            proxy_bypass: vec!["localhost".to_string()],
+            user_agent: Some("PopcornFX".to_string()),
+            request_timeout: 30,
+            send_crash_reports: false,
+        };
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let (tx, rx) = channel();
+
+        application.register(Box::new(move |event| tx.send(event).unwrap()));
+        application.update_network(settings.clone());
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+
+        match result {
+            ApplicationConfigEvent::NetworkSettingsChanged(result) => {
+                assert_eq!(settings, result);
+                assert_eq!(settings, application.user_settings().network_settings);
+            }
+            _ => assert!(
+                false,
+                "expected ApplicationConfigEvent::NetworkSettingsChanged"
+            ),
+        }
+    }
+
     #[test]
     fn test_save() {
         init_logger();
@@ -796,9 +1386,12 @@ mod test {
             quality: Some(Quality::P1080),
             fullscreen: true,
             auto_play_next_episode_enabled: true,
+            default_player: Some("MyPlayer".to_string()),
+            watched_threshold_percent: 90,
         };
         let server = ServerSettings {
             api_server: Some("http://localhost:8080".to_string()),
+            provider_uris: Default::default(),
         };
 
         application.update_server(server.clone());
@@ -808,8 +1401,337 @@ mod test {
         let result = read_temp_dir_file_as_string(&temp_dir, DEFAULT_SETTINGS_FILENAME);
         assert!(!result.is_empty(), "expected a non-empty json file");
 
-        let settings: PopcornSettings = serde_json::from_str(result.as_str()).unwrap();
+        let value: Value = serde_json::from_str(result.as_str()).unwrap();
+        assert_eq!(SETTINGS_VERSION, settings_version(&value));
+        let settings: PopcornSettings = serde_json::from_value(value).unwrap();
         assert_eq!(server, settings.server_settings);
         assert_eq!(playback, settings.playback_settings);
     }
+
+    #[test]
+    fn test_export_import() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = temp_dir.path().join("export.json");
+        let server = ServerSettings {
+            api_server: Some("http://localhost:8080".to_string()),
+            provider_uris: Default::default(),
+        };
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let target = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let (tx, rx) = channel();
+
+        application.update_server(server.clone());
+        application
+            .export(filepath.as_path())
+            .expect("expected the settings to have been exported");
+        target.register(Box::new(move |event| tx.send(event).unwrap()));
+        target
+            .import(filepath.as_path())
+            .expect("expected the settings to have been imported");
+
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        match result {
+            ApplicationConfigEvent::ServerSettingsChanged(result) => {
+                assert_eq!(server, result)
+            }
+            _ => assert!(
+                false,
+                "expected ApplicationConfigEvent::ServerSettingsChanged"
+            ),
+        }
+        assert_eq!(application.user_settings(), target.user_settings());
+    }
+
+    #[test]
+    fn test_import_older_settings() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = copy_test_file(temp_path, "settings.json", Some("import.json"));
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+
+        application
+            .import(PathBuf::from(filepath).as_path())
+            .expect("expected the settings to have been imported");
+        let result = application.user_settings();
+
+        assert_eq!(
+            &SubtitleLanguage::English,
+            result.subtitle().default_subtitle()
+        );
+        assert_eq!(TorrentSettings::default(), result.torrent_settings);
+    }
+
+    #[test]
+    fn test_import_invalid_fields() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = temp_dir.path().join("invalid.json");
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        fs::write(
+            &filepath,
+            r#"{"ui_settings":{"maximized":"lorem","ipsum":true},"dolor":{}}"#,
+        )
+        .unwrap();
+
+        let result = application.import(filepath.as_path());
+
+        assert_eq!(
+            Err(ConfigError::InvalidSettings(vec![
+                "dolor".to_string(),
+                "ui_settings.ipsum".to_string(),
+                "ui_settings.maximized".to_string(),
+            ])),
+            result
+        );
+        assert_eq!(PopcornSettings::default(), application.user_settings());
+    }
+
+    #[test]
+    fn test_reset_section() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let application = ApplicationConfig {
+            storage: Storage::from(temp_path),
+            properties: Default::default(),
+            settings: Default::default(),
+            callbacks: Default::default(),
+        };
+        let (tx, rx) = channel();
+
+        application.update_ui(UiSettings {
+            default_language: "fr".to_string(),
+            ui_scale: UiScale::new(1.5).unwrap(),
+            start_page: StartPage::Series,
+            poster_quality: PosterQuality::Medium,
+            maximized: true,
+            native_window_enabled: false,
+        });
+        application.register(Box::new(move |event| tx.send(event).unwrap()));
+        application.reset_section(SettingsSection::Ui);
+
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        match result {
+            ApplicationConfigEvent::UiSettingsChanged(result) => {
+                assert_eq!(UiSettings::default(), result);
+            }
+            _ => assert!(false, "expected ApplicationConfigEvent::UiSettingsChanged"),
+        }
+        assert_eq!(
+            UiSettings::default(),
+            application.user_settings().ui_settings
+        );
+    }
+
+    #[test]
+    fn test_new_auto_should_migrate_settings() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        copy_test_file(temp_path, "settings.json", None);
+        let original = read_temp_dir_file_as_string(&temp_dir, DEFAULT_SETTINGS_FILENAME);
+
+        let application = ApplicationConfig::builder().storage(temp_path).build();
+        let result = application.user_settings();
+
+        assert_eq!(CleaningMode::OnShutdown, result.torrent().cleaning_mode);
+        assert_eq!(StartPage::Movies, result.ui().start_page);
+        assert_eq!(
+            original,
+            read_temp_dir_file_as_string(&temp_dir, "settings.json.v0.bak"),
+            "expected the original settings to have been backed up"
+        );
+        let migrated: Value = serde_json::from_str(
+            read_temp_dir_file_as_string(&temp_dir, DEFAULT_SETTINGS_FILENAME).as_str(),
+        )
+        .unwrap();
+        assert_eq!(SETTINGS_VERSION, settings_version(&migrated));
+        assert_eq!(
+            None,
+            migrated["torrent_settings"].get("auto_cleaning_enabled"),
+            "expected the retired field to have been removed"
+        );
+        assert_eq!(
+            None,
+            migrated["ui_settings"].get("start_screen"),
+            "expected the start screen to have been renamed"
+        );
+    }
+
+    #[test]
+    fn test_migrate_ui_start_page() {
+        let mut value = serde_json::json!({
+            "ui_settings": {
+                "start_screen": "SERIES",
+                "maximized": true
+            }
+        });
+
+        migrate_ui_start_page(value.as_object_mut().unwrap())
+            .expect("expected the migration to succeed");
+        let settings: PopcornSettings = serde_json::from_value(value).unwrap();
+
+        assert_eq!(StartPage::Series, settings.ui().start_page);
+        assert_eq!(true, settings.ui().maximized);
+    }
+
+    #[test]
+    fn test_new_auto_migration_failed() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let original = r#"{"ui_settings":{"maximized":true},"torrent_settings":{"auto_cleaning_enabled":"lorem"}}"#;
+        fs::write(temp_dir.path().join(DEFAULT_SETTINGS_FILENAME), original).unwrap();
+        let (tx, rx) = channel();
+
+        let application = ApplicationConfig::builder()
+            .storage(temp_path)
+            .with_callback(Box::new(move |event| tx.send(event).unwrap()))
+            .build();
+
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        match result {
+            ApplicationConfigEvent::SettingsError(e) => {
+                assert_eq!(
+                    ConfigError::MigrationFailed(
+                        1,
+                        "expected a boolean auto_cleaning_enabled, got \"lorem\"".to_string()
+                    ),
+                    e
+                )
+            }
+            _ => assert!(false, "expected ApplicationConfigEvent::SettingsError"),
+        }
+        assert_eq!(
+            original,
+            read_temp_dir_file_as_string(&temp_dir, DEFAULT_SETTINGS_FILENAME),
+            "expected the original settings file to be untouched"
+        );
+        assert_eq!(true, application.user_settings().ui().maximized);
+    }
+
+    #[test]
+    fn test_migrate_settings() {
+        init_logger();
+        let migrations = vec![
+            SettingsMigration {
+                version: 2,
+                description: "store the ui scale as an object",
+                migrate: migrate_ui_scale,
+            },
+            SettingsMigration {
+                version: 1,
+                description: "rename the ui language",
+                migrate: rename_ui_language,
+            },
+        ];
+        let version_0 = serde_json::json!({
+            "ui_settings": {
+                "language": "fr",
+                "ui_scale": 1.5
+            }
+        });
+        let version_1 = serde_json::json!({
+            "version": 1,
+            "ui_settings": {
+                "default_language": "de",
+                "language": "ignored",
+                "ui_scale": 2.0
+            }
+        });
+
+        let result_0 = migrate_settings(version_0, 2, &migrations)
+            .expect("expected the version 0 settings to have been migrated");
+        let result_1 = migrate_settings(version_1, 2, &migrations)
+            .expect("expected the version 1 settings to have been migrated");
+
+        assert_eq!(2, settings_version(&result_0));
+        let settings: PopcornSettings = serde_json::from_value(result_0).unwrap();
+        assert_eq!("fr", settings.ui().default_language.as_str());
+        assert_eq!(UiScale::new(1.5).unwrap(), settings.ui().ui_scale);
+
+        assert_eq!(2, settings_version(&result_1));
+        let settings: PopcornSettings = serde_json::from_value(result_1).unwrap();
+        assert_eq!("de", settings.ui().default_language.as_str());
+        assert_eq!(UiScale::new(2.0).unwrap(), settings.ui().ui_scale);
+    }
+
+    #[test]
+    fn test_migrate_settings_failed() {
+        init_logger();
+        let migrations = vec![
+            SettingsMigration {
+                version: 1,
+                description: "rename the ui language",
+                migrate: rename_ui_language,
+            },
+            SettingsMigration {
+                version: 2,
+                description: "fail",
+                migrate: |_| Err("invalid settings format".to_string()),
+            },
+        ];
+        let value = serde_json::json!({
+            "ui_settings": {
+                "language": "fr"
+            }
+        });
+
+        let result = migrate_settings(value, 2, &migrations);
+
+        assert_eq!(
+            Err(ConfigError::MigrationFailed(
+                2,
+                "invalid settings format".to_string()
+            )),
+            result
+        );
+    }
+
+    fn rename_ui_language(sections: &mut Map<String, Value>) -> std::result::Result<(), String> {
+        if let Some(Value::Object(ui_settings)) = sections.get_mut("ui_settings") {
+            if let Some(language) = ui_settings.remove("language") {
+                if !ui_settings.contains_key("default_language") {
+                    ui_settings.insert("default_language".to_string(), language);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn migrate_ui_scale(sections: &mut Map<String, Value>) -> std::result::Result<(), String> {
+        if let Some(Value::Object(ui_settings)) = sections.get_mut("ui_settings") {
+            if let Some(scale) = ui_settings.get("ui_scale").and_then(|e| e.as_f64()) {
+                ui_settings.insert(
+                    "ui_scale".to_string(),
+                    serde_json::json!({ "value": scale }),
+                );
+            }
+        }
+        Ok(())
+    }
 }
@@ -12,4 +12,13 @@ pub enum ConfigError {
     /// Indicates that a tracking provider with the given name is unknown.
     #[error("tracking provider with name \"{0}\" is unknown")]
     UnknownTrackingProvider(String),
+    /// Indicates that the settings contain unknown or invalid fields.
+    #[error("settings contain unknown or invalid fields {0:?}")]
+    InvalidSettings(Vec<String>),
+    /// Indicates that the settings file could not be read or written.
+    #[error("failed to access settings file {0}, {1}")]
+    SettingsFile(String, String),
+    /// Indicates that the settings couldn't be migrated to the given settings version.
+    #[error("failed to migrate settings to version {0}, {1}")]
+    MigrationFailed(u32, String),
 }
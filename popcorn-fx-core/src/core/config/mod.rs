@@ -1,5 +1,6 @@
 pub use application::*;
 pub use errors::*;
+pub use network_settings::*;
 pub use playback_settings::*;
 pub use properties::*;
 pub use provider::*;
@@ -12,6 +13,7 @@ pub use ui_settings::*;
 
 mod application;
 mod errors;
+mod network_settings;
 mod playback_settings;
 mod properties;
 mod provider;
@@ -0,0 +1,149 @@
+use std::fmt::{Debug, Formatter};
+
+use derive_more::Display;
+use serde::Deserialize;
+use serde::Serialize;
+
+const DEFAULT_PROXY: fn() -> Option<String> = || None;
+const DEFAULT_PROXY_USERNAME: fn() -> Option<String> = || None;
+const DEFAULT_PROXY_PASSWORD: fn() -> Option<String> = || None;
+const DEFAULT_PROXY_BYPASS: fn() -> Vec<String> = Vec::new;
+const DEFAULT_USER_AGENT: fn() -> Option<String> = || None;
+const DEFAULT_REQUEST_TIMEOUT: fn() -> u64 = || 0;
+const DEFAULT_SEND_CRASH_REPORTS: fn() -> bool = || false;
+const REDACTED: &str = "***";
+
+/// The network preferences of the user which are applied to all outbound HTTP requests of the application.
+#[derive(Display, Clone, Serialize, Deserialize, PartialEq)]
+#[display(
+    fmt = "proxy: {:?}, proxy_bypass: {:?}, user_agent: {:?}, request_timeout: {}",
+    proxy,
+    proxy_bypass,
+    user_agent,
+    request_timeout
+)]
+pub struct NetworkSettings {
+    /// The proxy uri through which outbound requests are sent, e.g. `http://localhost:8080`.
+    /// The proxy of the system environment is used when not set.
+    #[serde(default = "DEFAULT_PROXY")]
+    pub proxy: Option<String>,
+    /// The username to authenticate with the proxy
+    #[serde(default = "DEFAULT_PROXY_USERNAME")]
+    pub proxy_username: Option<String>,
+    /// The password to authenticate with the proxy
+    #[serde(default = "DEFAULT_PROXY_PASSWORD")]
+    pub proxy_password: Option<String>,
+    /// The hosts, domains or ip ranges which should bypass the proxy
+    #[serde(default = "DEFAULT_PROXY_BYPASS")]
+    pub proxy_bypass: Vec<String>,
+    /// The custom user agent which is sent with each request
+    #[serde(default = "DEFAULT_USER_AGENT")]
+    pub user_agent: Option<String>,
+    /// The timeout in seconds of each request, 0 disables the timeout
+    #[serde(default = "DEFAULT_REQUEST_TIMEOUT")]
+    pub request_timeout: u64,
+    /// Indicates if the last crash report should be sent along with the next update check
+    #[serde(default = "DEFAULT_SEND_CRASH_REPORTS")]
+    pub send_crash_reports: bool,
+}
+
+impl NetworkSettings {
+    /// The configured proxy uri to use for outbound requests.
+    /// It returns [None] when no proxy has been configured.
+    pub fn proxy(&self) -> Option<&String> {
+        self.proxy.as_ref().filter(|e| !e.trim().is_empty())
+    }
+
+    /// The configured custom user agent to use for outbound requests.
+    pub fn user_agent(&self) -> Option<&String> {
+        self.user_agent.as_ref().filter(|e| !e.trim().is_empty())
+    }
+}
+
+impl Debug for NetworkSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkSettings")
+            .field("proxy", &self.proxy)
+            .field("proxy_username", &self.proxy_username)
+            .field(
+                "proxy_password",
+                &self.proxy_password.as_ref().map(|_| REDACTED),
+            )
+            .field("proxy_bypass", &self.proxy_bypass)
+            .field("user_agent", &self.user_agent)
+            .field("request_timeout", &self.request_timeout)
+            .field("send_crash_reports", &self.send_crash_reports)
+            .finish()
+    }
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            proxy: DEFAULT_PROXY(),
+            proxy_username: DEFAULT_PROXY_USERNAME(),
+            proxy_password: DEFAULT_PROXY_PASSWORD(),
+            proxy_bypass: DEFAULT_PROXY_BYPASS(),
+            user_agent: DEFAULT_USER_AGENT(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT(),
+            send_crash_reports: DEFAULT_SEND_CRASH_REPORTS(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_network_settings_default() {
+        let expected_result = NetworkSettings {
+            proxy: None,
+            proxy_username: None,
+            proxy_password: None,
+            proxy_bypass: vec![],
+            user_agent: None,
+            request_timeout: 0,
+            send_crash_reports: false,
+        };
+
+        let result = NetworkSettings::default();
+
+        assert_eq!(expected_result, result)
+    }
+
+    #[test]
+    fn test_proxy_empty() {
+        let settings = NetworkSettings {
+            proxy: Some(" ".to_string()),
+            ..Default::default()
+        };
+
+        let result = settings.proxy();
+
+        assert_eq!(None, result)
+    }
+
+    #[test]
+    fn test_debug_redacts_proxy_password() {
+        let settings = NetworkSettings {
+            proxy: Some("http://localhost:8080".to_string()),
+            proxy_username: Some("user".to_string()),
+            proxy_password: Some("MySecretPassword".to_string()),
+            ..Default::default()
+        };
+
+        let result = format!("{:?}", settings);
+
+        assert!(
+            !result.contains("MySecretPassword"),
+            "expected the proxy password to have been redacted, got {}",
+            result
+        );
+        assert!(
+            result.contains("user"),
+            "expected the username, got {}",
+            result
+        );
+    }
+}
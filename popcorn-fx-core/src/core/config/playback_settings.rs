@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 const DEFAULT_QUALITY: fn() -> Option<Quality> = || None;
 const DEFAULT_FULLSCREEN: fn() -> bool = || true;
 const DEFAULT_AUTO_PLAY_NEXT_EPISODE: fn() -> bool = || true;
+const DEFAULT_PLAYER: fn() -> Option<String> = || None;
+const DEFAULT_WATCHED_THRESHOLD_PERCENT: fn() -> u32 = || 85;
 
 /// The preferences for the video playbacks
 #[derive(Debug, Display, Clone, Serialize, Deserialize, PartialEq)]
@@ -18,6 +20,12 @@ pub struct PlaybackSettings {
     /// Indicates if the next episode should be started automatically
     #[serde(default = "DEFAULT_AUTO_PLAY_NEXT_EPISODE")]
     pub auto_play_next_episode_enabled: bool,
+    /// The id of the player which should be used for playbacks when no player has been selected
+    #[serde(default = "DEFAULT_PLAYER")]
+    pub default_player: Option<String>,
+    /// The percentage of the media which should have been played before it's automatically marked as watched
+    #[serde(default = "DEFAULT_WATCHED_THRESHOLD_PERCENT")]
+    pub watched_threshold_percent: u32,
 }
 
 impl Default for PlaybackSettings {
@@ -26,6 +34,8 @@ impl Default for PlaybackSettings {
             quality: DEFAULT_QUALITY(),
             fullscreen: DEFAULT_FULLSCREEN(),
             auto_play_next_episode_enabled: DEFAULT_AUTO_PLAY_NEXT_EPISODE(),
+            default_player: DEFAULT_PLAYER(),
+            watched_threshold_percent: DEFAULT_WATCHED_THRESHOLD_PERCENT(),
         }
     }
 }
@@ -62,6 +72,8 @@ mod test {
             quality: DEFAULT_QUALITY(),
             fullscreen: DEFAULT_FULLSCREEN(),
             auto_play_next_episode_enabled: DEFAULT_AUTO_PLAY_NEXT_EPISODE(),
+            default_player: DEFAULT_PLAYER(),
+            watched_threshold_percent: DEFAULT_WATCHED_THRESHOLD_PERCENT(),
         };
 
         let result = PlaybackSettings::default();
@@ -69,6 +81,16 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_deserialize_watched_threshold_percent() {
+        let result: PlaybackSettings = serde_json::from_str(r#"{"fullscreen":false}"#).unwrap();
+        assert_eq!(85, result.watched_threshold_percent);
+
+        let result: PlaybackSettings =
+            serde_json::from_str(r#"{"watched_threshold_percent":95}"#).unwrap();
+        assert_eq!(95, result.watched_threshold_percent);
+    }
+
     #[test]
     fn test_quality_resolution() {
         assert_eq!(480, Quality::P480.resolution());
@@ -9,7 +9,9 @@ use log::{debug, trace, warn};
 use serde::Deserialize;
 
 use crate::core::config;
-use crate::core::config::{ConfigError, EnhancerProperties, ProviderProperties};
+use crate::core::config::{
+    ConfigError, EnhancerProperties, ProviderProperties, ProviderType, TorrentSourceProperties,
+};
 
 const DEFAULT_SUBTITLE_URL: fn() -> String = || "https://api.opensubtitles.com/api/v1".to_string();
 const DEFAULT_USER_AGENT: fn() -> String = || "Popcorn Time v1".to_string();
@@ -21,6 +23,7 @@ const DEFAULT_PROVIDERS: fn() -> HashMap<String, ProviderProperties> = || {
         (
             "movies".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![
                     "https://shows.cf/".to_string(),
                     "https://fusme.link".to_string(),
@@ -60,11 +63,13 @@ const DEFAULT_PROVIDERS: fn() -> HashMap<String, ProviderProperties> = || {
                     "title".to_string(),
                     "rating".to_string(),
                 ],
+                categories: vec![],
             },
         ),
         (
             "series".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![
                     "https://shows.cf/".to_string(),
                     "https://fusme.link".to_string(),
@@ -106,11 +111,13 @@ const DEFAULT_PROVIDERS: fn() -> HashMap<String, ProviderProperties> = || {
                     "name".to_string(),
                     "rating".to_string(),
                 ],
+                categories: vec![],
             },
         ),
         (
             "favorites".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![],
                 genres: vec!["all".to_string(), "movies".to_string(), "tv".to_string()],
                 sort_by: vec![
@@ -119,6 +126,7 @@ const DEFAULT_PROVIDERS: fn() -> HashMap<String, ProviderProperties> = || {
                     "title".to_string(),
                     "rating".to_string(),
                 ],
+                categories: vec![],
             },
         ),
     ]
@@ -135,6 +143,7 @@ const DEFAULT_ENHANCERS: fn() -> HashMap<String, EnhancerProperties> = || {
     .into_iter()
     .collect()
 };
+const DEFAULT_TORRENT_SOURCES: fn() -> HashMap<String, TorrentSourceProperties> = || HashMap::new();
 const DEFAULT_LOGGERS: fn() -> HashMap<String, LoggingProperties> = || HashMap::new();
 const DEFAULT_TRACKING: fn() -> HashMap<String, TrackingProperties> = || {
     vec![(
@@ -186,6 +195,11 @@ pub struct PopcornProperties {
     /// Enhancer properties to enhance media items.
     #[serde(default = "DEFAULT_ENHANCERS")]
     pub enhancers: HashMap<String, EnhancerProperties>,
+    /// Configuration for additional torrent sources.
+    /// The torrents of these sources are merged with the torrents of the media items, none are configured by default.
+    #[serde(alias = "torrent-sources")]
+    #[serde(default = "DEFAULT_TORRENT_SOURCES")]
+    pub torrent_sources: HashMap<String, TorrentSourceProperties>,
     /// Configuration for subtitles.
     #[serde(default)]
     pub subtitle: SubtitleProperties,
@@ -313,6 +327,7 @@ impl Default for PopcornProperties {
             update_channel: DEFAULT_UPDATE_CHANNEL(),
             providers: DEFAULT_PROVIDERS(),
             enhancers: DEFAULT_ENHANCERS(),
+            torrent_sources: DEFAULT_TORRENT_SOURCES(),
             subtitle: SubtitleProperties::default(),
             tracking: DEFAULT_TRACKING(),
         }
@@ -439,6 +454,7 @@ mod test {
             update_channel: "https://raw.githubusercontent.com/yoep/popcorn-fx/master/".to_string(),
             providers: PopcornProperties::default_providers(),
             enhancers: PopcornProperties::default_enhancers(),
+            torrent_sources: Default::default(),
             subtitle: SubtitleProperties {
                 url: String::from("https://api.opensubtitles.com/api/v1"),
                 user_agent: String::from("Popcorn Time v1"),
@@ -466,6 +482,7 @@ popcorn:
             update_channel: "https://raw.githubusercontent.com/yoep/popcorn-fx/master/".to_string(),
             providers: PopcornProperties::default_providers(),
             enhancers: PopcornProperties::default_enhancers(),
+            torrent_sources: Default::default(),
             subtitle: SubtitleProperties {
                 url: String::from("http://my-url"),
                 user_agent: "lorem".to_string(),
@@ -491,6 +508,7 @@ popcorn:
             update_channel: "https://raw.githubusercontent.com/yoep/popcorn-fx/master/".to_string(),
             providers: PopcornProperties::default_providers(),
             enhancers: PopcornProperties::default_enhancers(),
+            torrent_sources: Default::default(),
             subtitle: SubtitleProperties {
                 url: String::from("https://api.opensubtitles.com/api/v1"),
                 user_agent: String::from("lorem"),
@@ -504,6 +522,28 @@ popcorn:
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_from_str_torrent_sources() {
+        init_logger();
+        let config_value = r#"
+popcorn:
+  torrent-sources:
+    torrentio:
+      uri: https://torrentio.lorem
+      timeout-seconds: 3"#;
+        let expected_result = HashMap::from([(
+            "torrentio".to_string(),
+            TorrentSourceProperties {
+                uri: "https://torrentio.lorem".to_string(),
+                timeout_seconds: 3,
+            },
+        )]);
+
+        let result = PopcornProperties::from(config_value);
+
+        assert_eq!(expected_result, result.torrent_sources)
+    }
+
     #[test]
     fn test_provider_unknown_name() {
         init_logger();
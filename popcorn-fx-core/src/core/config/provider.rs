@@ -1,10 +1,26 @@
+use std::time::Duration;
+
 use derive_more::Display;
 use serde::Deserialize;
 
+use crate::core::media::Category;
+
+const DEFAULT_TORRENT_SOURCE_TIMEOUT: fn() -> u64 = || 5;
+
 /// The [crate::core::media::MediaIdentifier] provider properties which can be used to query a [crate::core::media::providers::MediaProvider].
 #[derive(Debug, Display, Clone, PartialEq, Deserialize)]
-#[display(fmt = "uris: {:?}, genres: {:?}, sort_by: {:?}", uris, genres, sort_by)]
+#[display(
+    fmt = "provider_type: {}, uris: {:?}, genres: {:?}, sort_by: {:?}",
+    provider_type,
+    uris,
+    genres,
+    sort_by
+)]
 pub struct ProviderProperties {
+    /// The type of api which is exposed by the provider uri's
+    #[serde(alias = "provider-type")]
+    #[serde(default)]
+    pub provider_type: ProviderType,
     /// The provider uri's to use
     pub uris: Vec<String>,
     /// The provider supported genres
@@ -12,6 +28,10 @@ pub struct ProviderProperties {
     pub genres: Vec<String>,
     /// The provider sorting options
     pub sort_by: Vec<String>,
+    /// The categories which are supported by the provider
+    /// When empty, the category is derived from the provider name
+    #[serde(default)]
+    pub categories: Vec<Category>,
 }
 
 impl ProviderProperties {
@@ -29,6 +49,36 @@ impl ProviderProperties {
     pub fn sort_by(&self) -> &[String] {
         &self.sort_by[..]
     }
+
+    /// Retrieve the categories supported by the provider with the given name.
+    /// It returns the configured categories if present, else the category matching the provider name.
+    pub fn categories(&self, name: &str) -> Vec<Category> {
+        if !self.categories.is_empty() {
+            return self.categories.clone();
+        }
+
+        [Category::Movies, Category::Series, Category::Favorites]
+            .into_iter()
+            .filter(|e| e.name() == name)
+            .collect()
+    }
+}
+
+/// The type of api which is exposed by a media provider.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderType {
+    /// The provider exposes the Popcorn Time api.
+    Popcorn,
+    /// The provider exposes a custom catalog document.
+    /// See [crate::core::media::providers::CatalogProvider] for the expected format.
+    Catalog,
+}
+
+impl Default for ProviderType {
+    fn default() -> Self {
+        ProviderType::Popcorn
+    }
 }
 
 /// The [crate::core::media::MediaIdentifier] enhancer properties which can be used by any enhancer.
@@ -38,3 +88,72 @@ pub struct EnhancerProperties {
     /// The enhancer uri to use for retrieving additional information
     pub uri: String,
 }
+
+/// The properties of an additional torrent source which is queried for the torrents of a media item.
+/// See [crate::core::media::providers::torrent_sources::TorrentioSourceProvider] for the expected api.
+#[derive(Debug, Display, Clone, PartialEq, Deserialize)]
+#[display(fmt = "uri: {}, timeout_seconds: {}", uri, timeout_seconds)]
+pub struct TorrentSourceProperties {
+    /// The base uri of the torrent source
+    pub uri: String,
+    /// The maximum time in seconds to wait for the torrent source to respond
+    #[serde(alias = "timeout-seconds")]
+    #[serde(default = "DEFAULT_TORRENT_SOURCE_TIMEOUT")]
+    pub timeout_seconds: u64,
+}
+
+impl TorrentSourceProperties {
+    /// Retrieve the maximum time to wait for the torrent source to respond.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_seconds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_provider_properties_deserialize_defaults() {
+        let result: ProviderProperties =
+            serde_yaml::from_str("uris:\n  - https://lorem.ipsum\ngenres: []\nsort_by: []\n")
+                .unwrap();
+
+        assert_eq!(ProviderType::Popcorn, result.provider_type);
+        assert_eq!(Vec::<Category>::new(), result.categories);
+    }
+
+    #[test]
+    fn test_torrent_source_properties_deserialize_defaults() {
+        let result: TorrentSourceProperties =
+            serde_yaml::from_str("uri: https://lorem.ipsum\n").unwrap();
+
+        assert_eq!("https://lorem.ipsum", result.uri.as_str());
+        assert_eq!(Duration::from_secs(5), result.timeout());
+    }
+
+    #[test]
+    fn test_provider_properties_deserialize_catalog() {
+        let result: ProviderProperties = serde_yaml::from_str(
+            "provider-type: catalog\nuris:\n  - https://lorem.ipsum/catalog.json\ngenres: []\nsort_by: []\ncategories:\n  - MOVIES\n",
+        )
+        .unwrap();
+
+        assert_eq!(ProviderType::Catalog, result.provider_type);
+        assert_eq!(vec![Category::Movies], result.categories("lorem"));
+    }
+
+    #[test]
+    fn test_provider_properties_categories_from_name() {
+        let properties = ProviderProperties {
+            provider_type: ProviderType::Catalog,
+            uris: vec![],
+            genres: vec![],
+            sort_by: vec![],
+            categories: vec![],
+        };
+
+        assert_eq!(vec![Category::Series], properties.categories("series"));
+        assert_eq!(Vec::<Category>::new(), properties.categories("my-catalog"));
+    }
+}
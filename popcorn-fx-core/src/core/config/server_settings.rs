@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+
 use derive_more::Display;
 use serde::Deserialize;
 use serde::Serialize;
 
 const DEFAULT_API_SERVER: fn() -> Option<String> = || None;
+const DEFAULT_PROVIDER_URIS: fn() -> HashMap<String, Vec<String>> = HashMap::new;
 
 /// The api server preferences of the user for the application.
 #[derive(Debug, Display, Clone, Serialize, Deserialize, PartialEq)]
-#[display(fmt = "api_server: {:?}", api_server)]
+#[display(
+    fmt = "api_server: {:?}, provider_uris: {:?}",
+    api_server,
+    provider_uris
+)]
 pub struct ServerSettings {
     /// The api server to use
     #[serde(default = "DEFAULT_API_SERVER")]
     pub api_server: Option<String>,
+    /// The provider uri overrides of the user, stored by provider name.
+    /// These take precedence over the uri's of the bundled provider properties.
+    #[serde(
+        default = "DEFAULT_PROVIDER_URIS",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub provider_uris: HashMap<String, Vec<String>>,
 }
 
 impl ServerSettings {
@@ -21,12 +35,19 @@ impl ServerSettings {
             Some(e) => Some(e),
         }
     }
+
+    /// The uri overrides of the user for the provider with the given name.
+    /// It returns [None] when the provider uri's have not been overridden.
+    pub fn provider_uris(&self, name: &str) -> Option<&Vec<String>> {
+        self.provider_uris.get(name).filter(|e| !e.is_empty())
+    }
 }
 
 impl Default for ServerSettings {
     fn default() -> Self {
         Self {
             api_server: DEFAULT_API_SERVER(),
+            provider_uris: DEFAULT_PROVIDER_URIS(),
         }
     }
 }
@@ -39,10 +60,38 @@ mod test {
     fn test_server_settings_default() {
         let expected_result = ServerSettings {
             api_server: DEFAULT_API_SERVER(),
+            provider_uris: DEFAULT_PROVIDER_URIS(),
         };
 
         let result = ServerSettings::default();
 
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_provider_uris() {
+        let settings = ServerSettings {
+            api_server: None,
+            provider_uris: HashMap::from([
+                ("movies".to_string(), vec!["https://lorem".to_string()]),
+                ("series".to_string(), vec![]),
+            ]),
+        };
+
+        assert_eq!(
+            Some(&vec!["https://lorem".to_string()]),
+            settings.provider_uris("movies")
+        );
+        assert_eq!(None, settings.provider_uris("series"));
+        assert_eq!(None, settings.provider_uris("favorites"));
+    }
+
+    #[test]
+    fn test_deserialize_without_provider_uris() {
+        let result: ServerSettings =
+            serde_json::from_str(r#"{"api_server":"https://ipsum"}"#).unwrap();
+
+        assert_eq!(Some(&"https://ipsum".to_string()), result.api_server());
+        assert_eq!(HashMap::<String, Vec<String>>::new(), result.provider_uris);
+    }
 }
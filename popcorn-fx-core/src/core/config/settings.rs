@@ -1,10 +1,13 @@
 use derive_more::Display;
 use log::{debug, trace, warn};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::core::config;
 use crate::core::config::{
-    PlaybackSettings, ServerSettings, SubtitleSettings, TorrentSettings, TrackingSettings,
-    UiSettings,
+    ConfigError, NetworkSettings, PlaybackSettings, ServerSettings, SubtitleSettings,
+    TorrentSettings, TrackingSettings, UiSettings,
 };
 
 const DEFAULT_SUBTITLES: fn() -> SubtitleSettings = SubtitleSettings::default;
@@ -13,18 +16,40 @@ const DEFAULT_SERVER: fn() -> ServerSettings = ServerSettings::default;
 const DEFAULT_TORRENT: fn() -> TorrentSettings = TorrentSettings::default;
 const DEFAULT_PLAYBACK: fn() -> PlaybackSettings = PlaybackSettings::default;
 const DEFAULT_TRACKING: fn() -> TrackingSettings = TrackingSettings::default;
+const DEFAULT_NETWORK: fn() -> NetworkSettings = NetworkSettings::default;
+
+/// The sections of the user settings which can be managed individually.
+#[repr(i32)]
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum SettingsSection {
+    #[display(fmt = "subtitle")]
+    Subtitle = 0,
+    #[display(fmt = "torrent")]
+    Torrent = 1,
+    #[display(fmt = "ui")]
+    Ui = 2,
+    #[display(fmt = "server")]
+    Server = 3,
+    #[display(fmt = "playback")]
+    Playback = 4,
+    #[display(fmt = "tracking")]
+    Tracking = 5,
+    #[display(fmt = "network")]
+    Network = 6,
+}
 
 /// The Popcorn FX user settings.
 /// These contain the preferences of the user for the application.
 #[derive(Debug, Display, Default, Clone, Serialize, Deserialize, PartialEq)]
 #[display(
-    fmt = "subtitle_settings: {}, ui_settings: {}, server_settings: {}, torrent_settings: {}, playback_settings: {}, tracking_settings: {}",
+    fmt = "subtitle_settings: {}, ui_settings: {}, server_settings: {}, torrent_settings: {}, playback_settings: {}, tracking_settings: {}, network_settings: {}",
     subtitle_settings,
     ui_settings,
     server_settings,
     torrent_settings,
     playback_settings,
-    tracking_settings
+    tracking_settings,
+    network_settings
 )]
 pub struct PopcornSettings {
     #[serde(default = "DEFAULT_SUBTITLES")]
@@ -39,6 +64,8 @@ pub struct PopcornSettings {
     pub playback_settings: PlaybackSettings,
     #[serde(default = "DEFAULT_TRACKING")]
     pub tracking_settings: TrackingSettings,
+    #[serde(default = "DEFAULT_NETWORK")]
+    pub network_settings: NetworkSettings,
 }
 
 impl PopcornSettings {
@@ -76,6 +103,105 @@ impl PopcornSettings {
     pub fn tracking_mut(&mut self) -> &mut TrackingSettings {
         &mut self.tracking_settings
     }
+
+    /// Retrieve the network settings of the application.
+    pub fn network(&self) -> &NetworkSettings {
+        &self.network_settings
+    }
+
+    /// Reset the given section of the settings to its default values.
+    pub fn reset(&mut self, section: SettingsSection) {
+        match section {
+            SettingsSection::Subtitle => self.subtitle_settings = DEFAULT_SUBTITLES(),
+            SettingsSection::Torrent => self.torrent_settings = DEFAULT_TORRENT(),
+            SettingsSection::Ui => self.ui_settings = DEFAULT_UI(),
+            SettingsSection::Server => self.server_settings = DEFAULT_SERVER(),
+            SettingsSection::Playback => self.playback_settings = DEFAULT_PLAYBACK(),
+            SettingsSection::Tracking => self.tracking_settings = DEFAULT_TRACKING(),
+            SettingsSection::Network => self.network_settings = DEFAULT_NETWORK(),
+        }
+    }
+
+    /// Parse the given json value into settings, validating it against the current settings schema.
+    ///
+    /// Fields which are missing from the value use their default, which allows settings of older versions to be parsed.
+    /// It returns [ConfigError::InvalidSettings] with the path of each unknown or invalid field.
+    pub fn from_json(value: &Value) -> config::Result<Self> {
+        let mut invalid_fields: Vec<String> = vec![];
+
+        match value.as_object() {
+            None => invalid_fields.push("settings".to_string()),
+            Some(sections) => {
+                for (name, section) in sections {
+                    match name.as_str() {
+                        "subtitle_settings" => {
+                            Self::validate::<SubtitleSettings>(name, section, &mut invalid_fields)
+                        }
+                        "ui_settings" => {
+                            Self::validate::<UiSettings>(name, section, &mut invalid_fields)
+                        }
+                        "server_settings" => {
+                            Self::validate::<ServerSettings>(name, section, &mut invalid_fields)
+                        }
+                        "torrent_settings" => {
+                            Self::validate::<TorrentSettings>(name, section, &mut invalid_fields)
+                        }
+                        "playback_settings" => {
+                            Self::validate::<PlaybackSettings>(name, section, &mut invalid_fields)
+                        }
+                        "tracking_settings" => {
+                            Self::validate::<TrackingSettings>(name, section, &mut invalid_fields)
+                        }
+                        "network_settings" => {
+                            Self::validate::<NetworkSettings>(name, section, &mut invalid_fields)
+                        }
+                        _ => invalid_fields.push(name.clone()),
+                    }
+                }
+            }
+        }
+
+        if !invalid_fields.is_empty() {
+            invalid_fields.sort();
+            return Err(ConfigError::InvalidSettings(invalid_fields));
+        }
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| ConfigError::InvalidSettings(vec![e.to_string()]))
+    }
+
+    /// Validate each field of the given settings section against the default values of the section.
+    /// The path of each unknown or invalid field is added to the given invalid fields.
+    fn validate<T: Default + Serialize + DeserializeOwned>(
+        name: &str,
+        section: &Value,
+        invalid_fields: &mut Vec<String>,
+    ) {
+        let defaults = serde_json::to_value(T::default())
+            .ok()
+            .and_then(|e| e.as_object().cloned())
+            .unwrap_or_default();
+
+        match section.as_object() {
+            None => invalid_fields.push(name.to_string()),
+            Some(fields) => {
+                for (field, value) in fields {
+                    let path = format!("{}.{}", name, field);
+                    if !defaults.contains_key(field) {
+                        invalid_fields.push(path);
+                        continue;
+                    }
+
+                    let mut section = defaults.clone();
+                    section.insert(field.clone(), value.clone());
+                    if let Err(e) = serde_json::from_value::<T>(Value::Object(section)) {
+                        trace!("Settings field {} is invalid, {}", path, e);
+                        invalid_fields.push(path);
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl From<&str> for PopcornSettings {
@@ -101,7 +227,7 @@ impl From<&str> for PopcornSettings {
 
 #[cfg(test)]
 mod test {
-    use crate::core::config::{DecorationType, SubtitleFamily};
+    use crate::core::config::{DecorationType, SubtitleFamily, TvModeSubtitle};
     use crate::core::subtitles::language::SubtitleLanguage;
     use crate::testing::init_logger;
 
@@ -130,12 +256,18 @@ mod test {
                 font_size: 32,
                 decoration: DecorationType::Outline,
                 bold: false,
+                max_line_length: 0,
+                max_lines: 2,
+                tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+                api_key: None,
+                user_token: None,
             },
             ui_settings: Default::default(),
             server_settings: Default::default(),
             torrent_settings: Default::default(),
             playback_settings: Default::default(),
             tracking_settings: Default::default(),
+            network_settings: Default::default(),
         };
 
         let result = PopcornSettings::from(value);
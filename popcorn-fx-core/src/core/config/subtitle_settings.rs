@@ -1,3 +1,4 @@
+use std::fmt::{Debug, Formatter};
 use std::path::PathBuf;
 
 use derive_more::Display;
@@ -24,10 +25,16 @@ const DEFAULT_SUBTITLE_FAMILY: fn() -> SubtitleFamily = || SubtitleFamily::Arial
 const DEFAULT_FONT_SIZE: fn() -> u32 = || 28;
 const DEFAULT_DECORATION: fn() -> DecorationType = || DecorationType::Outline;
 const DEFAULT_BOLD: fn() -> bool = || true;
+const DEFAULT_MAX_LINE_LENGTH: fn() -> u32 = || 0;
+const DEFAULT_MAX_LINES: fn() -> u32 = || 2;
+const DEFAULT_TV_MODE_SUBTITLE: fn() -> TvModeSubtitle = || TvModeSubtitle::InterfaceLanguage;
+const DEFAULT_API_KEY: fn() -> Option<String> = || None;
+const DEFAULT_USER_TOKEN: fn() -> Option<String> = || None;
+const REDACTED: &str = "***";
 
 /// The subtitle settings of the application.
 /// These are the subtitle preferences of the user.
-#[derive(Debug, Display, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Display, Clone, Serialize, Deserialize, PartialEq)]
 #[display(
     fmt = "directory: {}, auto_cleaning_enabled: {}, default_subtitle: {}",
     directory,
@@ -57,6 +64,21 @@ pub struct SubtitleSettings {
     /// The subtitle should be rendered in a bold font
     #[serde(default = "DEFAULT_BOLD")]
     pub bold: bool,
+    /// The max number of characters per line of a converted subtitle cue, `0` disables the line wrapping
+    #[serde(default = "DEFAULT_MAX_LINE_LENGTH")]
+    pub max_line_length: u32,
+    /// The max number of lines per converted subtitle cue when line wrapping is enabled
+    #[serde(default = "DEFAULT_MAX_LINES")]
+    pub max_lines: u32,
+    /// The default subtitle selection when the application runs in TV or kiosk mode
+    #[serde(default = "DEFAULT_TV_MODE_SUBTITLE")]
+    pub tv_mode_subtitle: TvModeSubtitle,
+    /// The custom OpenSubtitles API key to use instead of the built-in one
+    #[serde(default = "DEFAULT_API_KEY")]
+    pub api_key: Option<String>,
+    /// The OpenSubtitles user token of a (VIP) account which is used to authenticate the requests
+    #[serde(default = "DEFAULT_USER_TOKEN")]
+    pub user_token: Option<String>,
 }
 
 impl SubtitleSettings {
@@ -83,6 +105,11 @@ impl SubtitleSettings {
             font_size: font_size.or_else(|| Some(DEFAULT_FONT_SIZE())).unwrap(),
             decoration: decoration.or_else(|| Some(DEFAULT_DECORATION())).unwrap(),
             bold: bold.or_else(|| Some(DEFAULT_BOLD())).unwrap(),
+            max_line_length: DEFAULT_MAX_LINE_LENGTH(),
+            max_lines: DEFAULT_MAX_LINES(),
+            tv_mode_subtitle: DEFAULT_TV_MODE_SUBTITLE(),
+            api_key: DEFAULT_API_KEY(),
+            user_token: DEFAULT_USER_TOKEN(),
         }
     }
 
@@ -99,6 +126,42 @@ impl SubtitleSettings {
     pub fn default_subtitle(&self) -> &SubtitleLanguage {
         &self.default_subtitle
     }
+
+    /// Indicates if the lines of converted subtitles should be wrapped
+    pub fn line_wrapping_enabled(&self) -> bool {
+        self.max_line_length > 0
+    }
+
+    /// The configured custom OpenSubtitles API key.
+    /// It returns [None] when the built-in API key should be used.
+    pub fn api_key(&self) -> Option<&String> {
+        self.api_key.as_ref().filter(|e| !e.trim().is_empty())
+    }
+
+    /// The configured OpenSubtitles user token.
+    /// It returns [None] when the subtitles should be accessed anonymously.
+    pub fn user_token(&self) -> Option<&String> {
+        self.user_token.as_ref().filter(|e| !e.trim().is_empty())
+    }
+}
+
+impl Debug for SubtitleSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubtitleSettings")
+            .field("directory", &self.directory)
+            .field("auto_cleaning_enabled", &self.auto_cleaning_enabled)
+            .field("default_subtitle", &self.default_subtitle)
+            .field("font_family", &self.font_family)
+            .field("font_size", &self.font_size)
+            .field("decoration", &self.decoration)
+            .field("bold", &self.bold)
+            .field("max_line_length", &self.max_line_length)
+            .field("max_lines", &self.max_lines)
+            .field("tv_mode_subtitle", &self.tv_mode_subtitle)
+            .field("api_key", &self.api_key.as_ref().map(|_| REDACTED))
+            .field("user_token", &self.user_token.as_ref().map(|_| REDACTED))
+            .finish()
+    }
 }
 
 impl Default for SubtitleSettings {
@@ -111,6 +174,11 @@ impl Default for SubtitleSettings {
             font_size: DEFAULT_FONT_SIZE(),
             decoration: DEFAULT_DECORATION(),
             bold: DEFAULT_BOLD(),
+            max_line_length: DEFAULT_MAX_LINE_LENGTH(),
+            max_lines: DEFAULT_MAX_LINES(),
+            tv_mode_subtitle: DEFAULT_TV_MODE_SUBTITLE(),
+            api_key: DEFAULT_API_KEY(),
+            user_token: DEFAULT_USER_TOKEN(),
         }
     }
 }
@@ -153,12 +221,25 @@ pub enum DecorationType {
     SeeThroughBackground = 3,
 }
 
+/// The default subtitle selection which is applied when the application runs in TV or kiosk mode.
+/// An explicit default subtitle language of the user always takes precedence over this selection.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TvModeSubtitle {
+    /// Select the subtitle based on the user preference, the same as outside TV mode.
+    UserPreference = 0,
+    /// Enable the subtitle in the interface language when no default subtitle language has been set.
+    InterfaceLanguage = 1,
+}
+
 #[cfg(test)]
 mod test {
     use crate::core::config::{SubtitleFamily, SubtitleSettings};
     use crate::core::config::subtitle_settings::{
-        DEFAULT_AUTO_CLEANING, DEFAULT_BOLD, DEFAULT_DECORATION, DEFAULT_FONT_SIZE,
-        DEFAULT_SUBTITLE_FAMILY, DEFAULT_SUBTITLE_LANGUAGE,
+        DEFAULT_API_KEY, DEFAULT_AUTO_CLEANING, DEFAULT_BOLD, DEFAULT_DECORATION,
+        DEFAULT_FONT_SIZE, DEFAULT_MAX_LINES, DEFAULT_MAX_LINE_LENGTH, DEFAULT_SUBTITLE_FAMILY,
+        DEFAULT_SUBTITLE_LANGUAGE, DEFAULT_TV_MODE_SUBTITLE, DEFAULT_USER_TOKEN,
     };
 
     #[test]
@@ -172,6 +253,11 @@ mod test {
             font_size: DEFAULT_FONT_SIZE(),
             decoration: DEFAULT_DECORATION(),
             bold: DEFAULT_BOLD(),
+            max_line_length: DEFAULT_MAX_LINE_LENGTH(),
+            max_lines: DEFAULT_MAX_LINES(),
+            tv_mode_subtitle: DEFAULT_TV_MODE_SUBTITLE(),
+            api_key: DEFAULT_API_KEY(),
+            user_token: DEFAULT_USER_TOKEN(),
         };
 
         let result = SubtitleSettings::new(
@@ -187,6 +273,35 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_subtitle_credentials() {
+        let settings = SubtitleSettings {
+            api_key: Some(" ".to_string()),
+            user_token: Some("MyToken".to_string()),
+            ..SubtitleSettings::default()
+        };
+
+        assert_eq!(None, settings.api_key());
+        assert_eq!(Some(&"MyToken".to_string()), settings.user_token());
+    }
+
+    #[test]
+    fn test_subtitle_debug_redacts_credentials() {
+        let settings = SubtitleSettings {
+            api_key: Some("MyApiKey".to_string()),
+            user_token: Some("MyToken".to_string()),
+            ..SubtitleSettings::default()
+        };
+
+        let result = format!("{:?}", settings);
+
+        assert!(
+            !result.contains("MyApiKey") && !result.contains("MyToken"),
+            "expected the credentials to have been redacted, got {}",
+            result
+        );
+    }
+
     #[test]
     fn test_subtitle_family() {
         let tm = SubtitleFamily::TrebuchetMs.family();
@@ -1,4 +1,6 @@
+use std::net::IpAddr;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use derive_more::Display;
 use directories::UserDirs;
@@ -20,6 +22,22 @@ const DEFAULT_CLEANING_MODE: fn() -> CleaningMode = || CleaningMode::OnShutdown;
 const DEFAULT_CONNECTIONS_LIMIT: fn() -> u32 = || 300;
 const DEFAULT_DOWNLOAD_RATE_LIMIT: fn() -> u32 = || 0;
 const DEFAULT_UPLOAD_RATE_LIMIT: fn() -> u32 = || 0;
+const DEFAULT_STREAM_IDLE_TIMEOUT: fn() -> u32 = || 300;
+const DEFAULT_REMOVE_IDLE_TORRENTS: fn() -> bool = || true;
+const DEFAULT_ENCRYPTION_MODE: fn() -> EncryptionMode = || EncryptionMode::Prefer;
+const DEFAULT_INITIAL_BUFFER_SIZE: fn() -> u32 = || 10 * 1024 * 1024;
+const DEFAULT_PEER_CONNECT_TIMEOUT: fn() -> u32 = || 15;
+const MIN_PEER_CONNECT_TIMEOUT: u32 = 1;
+const MAX_PEER_CONNECT_TIMEOUT: u32 = 120;
+const DEFAULT_ANNOUNCE_TO_ALL_TRACKERS: fn() -> bool = || true;
+const DEFAULT_REQUEST_TIMEOUT: fn() -> u32 = || 20;
+const DEFAULT_MAX_METADATA_SIZE: fn() -> u32 = || 10 * 1024 * 1024;
+const MIN_MAX_METADATA_SIZE: u32 = 1024 * 1024;
+const MAX_MAX_METADATA_SIZE: u32 = 64 * 1024 * 1024;
+const DEFAULT_MAX_REQUEST_QUEUE_DEPTH: fn() -> u32 = || 500;
+const MIN_MAX_REQUEST_QUEUE_DEPTH: u32 = 1;
+const MAX_MAX_REQUEST_QUEUE_DEPTH: u32 = 5000;
+const DEFAULT_DUAL_STACK: fn() -> bool = || true;
 
 /// The torrent user's settings for the application.
 #[derive(Debug, Display, Clone, Serialize, Deserialize, PartialEq)]
@@ -40,6 +58,71 @@ pub struct TorrentSettings {
     /// The upload rate limit, in bytes per second. A value of 0 means unlimited.
     #[serde(default = "DEFAULT_UPLOAD_RATE_LIMIT")]
     pub upload_rate_limit: u32,
+    /// The time, in seconds, after which a stream without any reader activity is stopped.
+    /// A value of 0 disables the stopping of idle streams.
+    #[serde(default = "DEFAULT_STREAM_IDLE_TIMEOUT")]
+    pub stream_idle_timeout: u32,
+    /// Indicates if the torrent of an idle stream should also be removed when it's stopped.
+    #[serde(default = "DEFAULT_REMOVE_IDLE_TORRENTS")]
+    pub remove_idle_torrents: bool,
+    /// The local address to which all torrent traffic is bound, e.g. the address of a VPN interface.
+    /// When the interface of the address is no longer available, connections fail instead of
+    /// falling back to another interface.
+    #[serde(default)]
+    pub bind_interface: Option<IpAddr>,
+    /// The encryption mode (MSE/PE) of the peer connections.
+    #[serde(default = "DEFAULT_ENCRYPTION_MODE")]
+    pub encryption_mode: EncryptionMode,
+    /// Indicates if the torrent of a stream is automatically added to the torrent collection
+    /// when it has been fully downloaded and the playback has finished.
+    #[serde(default)]
+    pub auto_collect_finished: bool,
+    /// The amount of contiguous bytes, ahead of the playback offset, which should be buffered before
+    /// the playback is started. It's used to estimate the time until a stream is ready to be played.
+    #[serde(default = "DEFAULT_INITIAL_BUFFER_SIZE")]
+    pub initial_buffer_size: u32,
+    /// The timeout, in seconds, for establishing a connection with a peer.
+    /// Use [TorrentSettings::peer_connect_timeout] to retrieve the effective timeout.
+    #[serde(default = "DEFAULT_PEER_CONNECT_TIMEOUT")]
+    pub peer_connect_timeout: u32,
+    /// Indicates if a torrent is announced to all its trackers and tiers at once,
+    /// instead of only to the first tracker which responds.
+    /// Each tracker keeps its own announce interval, so only the initial and forced announces fan out.
+    #[serde(default = "DEFAULT_ANNOUNCE_TO_ALL_TRACKERS")]
+    pub announce_to_all_trackers: bool,
+    /// The timeout, in seconds, after which a requested block which hasn't been received from a peer
+    /// is cancelled and requested from another peer.
+    #[serde(default = "DEFAULT_REQUEST_TIMEOUT")]
+    pub request_timeout: u32,
+    /// The path to the blocklist file of peer IP ranges which are blocked for inbound and outbound connections.
+    /// The file contains a CIDR, `first-last` or P2P formatted range on each line.
+    #[serde(default)]
+    pub ip_filter: Option<PathBuf>,
+    /// Indicates if the files of a torrent are fully allocated on disk when the torrent is added,
+    /// which reserves the disk space upfront instead of failing when the disk runs full mid-download.
+    /// Files are allocated as sparse files when disabled.
+    #[serde(default)]
+    pub preallocate: bool,
+    /// Indicates if the DHT node runs in read-only mode (BEP43).
+    /// A read-only node still looks up peers for its own torrents, but doesn't respond to incoming queries
+    /// and isn't added to the routing tables of other nodes.
+    #[serde(default)]
+    pub dht_read_only: bool,
+    /// The max size, in bytes, of the torrent metadata (info dictionary) which is accepted from peers when resolving a magnet.
+    /// Peers advertising a larger metadata size are rejected, which bounds the memory a malicious peer can claim.
+    /// Use [TorrentSettings::max_metadata_size] to retrieve the effective size.
+    #[serde(default = "DEFAULT_MAX_METADATA_SIZE")]
+    pub max_metadata_size: u32,
+    /// The max number of outstanding block requests which are pipelined to a single peer.
+    /// The effective depth of each peer adapts to its download rate, so slow peers keep less requests in flight,
+    /// while a higher max allows fast peers on high latency connections to saturate the link.
+    /// Use [TorrentSettings::max_request_queue_depth] to retrieve the effective max depth.
+    #[serde(default = "DEFAULT_MAX_REQUEST_QUEUE_DEPTH")]
+    pub max_request_queue_depth: u32,
+    /// Indicates if the session listens on both IPv4 and IPv6 when no [TorrentSettings::bind_interface] is set.
+    /// The DHT runs on each listen socket, so an IPv6 listen socket is required to find peers on IPv6-only networks.
+    #[serde(default = "DEFAULT_DUAL_STACK")]
+    pub dual_stack: bool,
 }
 
 impl TorrentSettings {
@@ -47,6 +130,40 @@ impl TorrentSettings {
     pub fn directory(&self) -> &PathBuf {
         &self.directory
     }
+
+    /// The idle timeout of a stream, or [None] when idle streams should never be stopped.
+    pub fn stream_idle_timeout(&self) -> Option<Duration> {
+        if self.stream_idle_timeout > 0 {
+            Some(Duration::from_secs(self.stream_idle_timeout as u64))
+        } else {
+            None
+        }
+    }
+
+    /// The effective timeout for establishing a connection with a peer.
+    /// The timeout is clamped between 1 and 120 seconds, as a pathological value either drops all peers
+    /// or stalls the connection attempts of the session.
+    pub fn peer_connect_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.peer_connect_timeout
+                .clamp(MIN_PEER_CONNECT_TIMEOUT, MAX_PEER_CONNECT_TIMEOUT) as u64,
+        )
+    }
+
+    /// The effective max metadata size, in bytes, which is accepted from peers.
+    /// The size is clamped between 1MB and 64MB, as large torrents with many files have a legitimately
+    /// large info dictionary, while an unbounded size allows peers to exhaust the memory.
+    pub fn max_metadata_size(&self) -> u32 {
+        self.max_metadata_size
+            .clamp(MIN_MAX_METADATA_SIZE, MAX_MAX_METADATA_SIZE)
+    }
+
+    /// The effective max number of outstanding block requests to a single peer.
+    /// The depth is clamped between 1 and 5000 requests.
+    pub fn max_request_queue_depth(&self) -> u32 {
+        self.max_request_queue_depth
+            .clamp(MIN_MAX_REQUEST_QUEUE_DEPTH, MAX_MAX_REQUEST_QUEUE_DEPTH)
+    }
 }
 
 impl Default for TorrentSettings {
@@ -57,6 +174,21 @@ impl Default for TorrentSettings {
             connections_limit: DEFAULT_CONNECTIONS_LIMIT(),
             download_rate_limit: DEFAULT_DOWNLOAD_RATE_LIMIT(),
             upload_rate_limit: DEFAULT_UPLOAD_RATE_LIMIT(),
+            stream_idle_timeout: DEFAULT_STREAM_IDLE_TIMEOUT(),
+            remove_idle_torrents: DEFAULT_REMOVE_IDLE_TORRENTS(),
+            bind_interface: None,
+            encryption_mode: DEFAULT_ENCRYPTION_MODE(),
+            auto_collect_finished: false,
+            initial_buffer_size: DEFAULT_INITIAL_BUFFER_SIZE(),
+            peer_connect_timeout: DEFAULT_PEER_CONNECT_TIMEOUT(),
+            announce_to_all_trackers: DEFAULT_ANNOUNCE_TO_ALL_TRACKERS(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT(),
+            ip_filter: None,
+            preallocate: false,
+            dht_read_only: false,
+            max_metadata_size: DEFAULT_MAX_METADATA_SIZE(),
+            max_request_queue_depth: DEFAULT_MAX_REQUEST_QUEUE_DEPTH(),
+            dual_stack: DEFAULT_DUAL_STACK(),
         }
     }
 }
@@ -76,6 +208,21 @@ pub enum CleaningMode {
     Watched = 2,
 }
 
+/// The encryption mode of the peer connections, also known as Message Stream Encryption (MSE/PE).
+#[repr(i32)]
+#[derive(Debug, Clone, Display, Serialize, Deserialize, PartialEq)]
+pub enum EncryptionMode {
+    /// Encrypted connections are preferred, but peers which don't support encryption are connected in plaintext.
+    #[display(fmt = "Prefer encryption")]
+    Prefer = 0,
+    /// Only encrypted connections are allowed, plaintext peers are rejected.
+    #[display(fmt = "Require encryption")]
+    Require = 1,
+    /// Encryption is disabled, all connections are plaintext.
+    #[display(fmt = "Disabled")]
+    Disable = 2,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -88,10 +235,202 @@ mod test {
             connections_limit: DEFAULT_CONNECTIONS_LIMIT(),
             download_rate_limit: DEFAULT_DOWNLOAD_RATE_LIMIT(),
             upload_rate_limit: DEFAULT_UPLOAD_RATE_LIMIT(),
+            stream_idle_timeout: DEFAULT_STREAM_IDLE_TIMEOUT(),
+            remove_idle_torrents: DEFAULT_REMOVE_IDLE_TORRENTS(),
+            bind_interface: None,
+            encryption_mode: DEFAULT_ENCRYPTION_MODE(),
+            auto_collect_finished: false,
+            initial_buffer_size: DEFAULT_INITIAL_BUFFER_SIZE(),
+            peer_connect_timeout: DEFAULT_PEER_CONNECT_TIMEOUT(),
+            announce_to_all_trackers: DEFAULT_ANNOUNCE_TO_ALL_TRACKERS(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT(),
+            ip_filter: None,
+            preallocate: false,
+            dht_read_only: false,
+            max_metadata_size: DEFAULT_MAX_METADATA_SIZE(),
+            max_request_queue_depth: DEFAULT_MAX_REQUEST_QUEUE_DEPTH(),
+            dual_stack: DEFAULT_DUAL_STACK(),
         };
 
         let result = TorrentSettings::default();
 
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_stream_idle_timeout() {
+        let settings = TorrentSettings {
+            stream_idle_timeout: 60,
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(Duration::from_secs(60)),
+            settings.stream_idle_timeout()
+        );
+
+        let settings = TorrentSettings {
+            stream_idle_timeout: 0,
+            ..Default::default()
+        };
+        assert_eq!(None, settings.stream_idle_timeout());
+    }
+
+    #[test]
+    fn test_peer_connect_timeout() {
+        let settings = TorrentSettings {
+            peer_connect_timeout: 30,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_secs(30), settings.peer_connect_timeout());
+
+        let settings = TorrentSettings {
+            peer_connect_timeout: 0,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_secs(1), settings.peer_connect_timeout());
+
+        let settings = TorrentSettings {
+            peer_connect_timeout: 3600,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_secs(120), settings.peer_connect_timeout());
+    }
+
+    #[test]
+    fn test_max_metadata_size() {
+        let settings = TorrentSettings {
+            max_metadata_size: 20 * 1024 * 1024,
+            ..Default::default()
+        };
+        assert_eq!(20 * 1024 * 1024, settings.max_metadata_size());
+
+        let settings = TorrentSettings {
+            max_metadata_size: 0,
+            ..Default::default()
+        };
+        assert_eq!(MIN_MAX_METADATA_SIZE, settings.max_metadata_size());
+
+        let settings = TorrentSettings {
+            max_metadata_size: u32::MAX,
+            ..Default::default()
+        };
+        assert_eq!(MAX_MAX_METADATA_SIZE, settings.max_metadata_size());
+    }
+
+    #[test]
+    fn test_max_request_queue_depth() {
+        let settings = TorrentSettings {
+            max_request_queue_depth: 1000,
+            ..Default::default()
+        };
+        assert_eq!(1000, settings.max_request_queue_depth());
+
+        let settings = TorrentSettings {
+            max_request_queue_depth: 0,
+            ..Default::default()
+        };
+        assert_eq!(MIN_MAX_REQUEST_QUEUE_DEPTH, settings.max_request_queue_depth());
+
+        let settings = TorrentSettings {
+            max_request_queue_depth: 100_000,
+            ..Default::default()
+        };
+        assert_eq!(MAX_MAX_REQUEST_QUEUE_DEPTH, settings.max_request_queue_depth());
+    }
+
+    #[test]
+    fn test_deserialize_announce_to_all_trackers() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"announce_to_all_trackers":false}"#).unwrap();
+        assert_eq!(false, result.announce_to_all_trackers);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(true, result.announce_to_all_trackers);
+    }
+
+    #[test]
+    fn test_deserialize_request_timeout() {
+        let result: TorrentSettings = serde_json::from_str(r#"{"request_timeout":10}"#).unwrap();
+        assert_eq!(10, result.request_timeout);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(DEFAULT_REQUEST_TIMEOUT(), result.request_timeout);
+    }
+
+    #[test]
+    fn test_deserialize_ip_filter() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"ip_filter":"/tmp/blocklist.p2p"}"#).unwrap();
+        assert_eq!(Some(PathBuf::from("/tmp/blocklist.p2p")), result.ip_filter);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(None, result.ip_filter);
+    }
+
+    #[test]
+    fn test_deserialize_preallocate() {
+        let result: TorrentSettings = serde_json::from_str(r#"{"preallocate":true}"#).unwrap();
+        assert_eq!(true, result.preallocate);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(false, result.preallocate);
+    }
+
+    #[test]
+    fn test_deserialize_dht_read_only() {
+        let result: TorrentSettings = serde_json::from_str(r#"{"dht_read_only":true}"#).unwrap();
+        assert_eq!(true, result.dht_read_only);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(false, result.dht_read_only);
+    }
+
+    #[test]
+    fn test_deserialize_dual_stack() {
+        let result: TorrentSettings = serde_json::from_str(r#"{"dual_stack":false}"#).unwrap();
+        assert_eq!(false, result.dual_stack);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(true, result.dual_stack);
+    }
+
+    #[test]
+    fn test_deserialize_bind_interface() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"bind_interface":"10.8.0.2"}"#).unwrap();
+        assert_eq!(Some(IpAddr::from([10, 8, 0, 2])), result.bind_interface);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(None, result.bind_interface);
+    }
+
+    #[test]
+    fn test_deserialize_encryption_mode() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"encryption_mode":"Require"}"#).unwrap();
+        assert_eq!(EncryptionMode::Require, result.encryption_mode);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(EncryptionMode::Prefer, result.encryption_mode);
+    }
+
+    #[test]
+    fn test_deserialize_auto_collect_finished() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"auto_collect_finished":true}"#).unwrap();
+        assert_eq!(true, result.auto_collect_finished);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(false, result.auto_collect_finished);
+    }
+
+    #[test]
+    fn test_deserialize_initial_buffer_size() {
+        let result: TorrentSettings =
+            serde_json::from_str(r#"{"initial_buffer_size":2048}"#).unwrap();
+        assert_eq!(2048, result.initial_buffer_size);
+
+        let result: TorrentSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(DEFAULT_INITIAL_BUFFER_SIZE(), result.initial_buffer_size);
+    }
 }
@@ -8,10 +8,13 @@ use crate::core::config::ConfigError;
 use crate::core::media::Category;
 
 const UI_SCALE_SUFFIX: &str = "%";
+const TMDB_IMAGE_HOST: &str = "image.tmdb.org";
+const TMDB_IMAGE_PATH: &str = "/t/p/";
 const DEFAULT_LANGUAGE: fn() -> String = || "en".to_string();
 const DEFAULT_UI_SCALE: fn() -> UiScale =
     || UiScale::new(1f32).expect("Expected the ui scale to be valid");
-const DEFAULT_START_SCREEN: fn() -> Category = || Category::Movies;
+const DEFAULT_START_PAGE: fn() -> StartPage = || StartPage::Movies;
+const DEFAULT_POSTER_QUALITY: fn() -> PosterQuality = || PosterQuality::Medium;
 const DEFAULT_MAXIMIZED: fn() -> bool = || false;
 const DEFAULT_NATIVE_WINDOW: fn() -> bool = || false;
 
@@ -24,9 +27,12 @@ pub struct UiSettings {
     /// The ui scale of the application
     #[serde(default = "DEFAULT_UI_SCALE")]
     pub ui_scale: UiScale,
-    /// The default start screen of the application
-    #[serde(default = "DEFAULT_START_SCREEN")]
-    pub start_screen: Category,
+    /// The page which is shown when the application is started
+    #[serde(default = "DEFAULT_START_PAGE")]
+    pub start_page: StartPage,
+    /// The quality of the poster images which are requested
+    #[serde(default = "DEFAULT_POSTER_QUALITY")]
+    pub poster_quality: PosterQuality,
     /// The indication if the UI was maximized the last time the application was closed
     #[serde(default = "DEFAULT_MAXIMIZED")]
     pub maximized: bool,
@@ -40,7 +46,8 @@ impl Default for UiSettings {
         Self {
             default_language: DEFAULT_LANGUAGE(),
             ui_scale: DEFAULT_UI_SCALE(),
-            start_screen: DEFAULT_START_SCREEN(),
+            start_page: DEFAULT_START_PAGE(),
+            poster_quality: DEFAULT_POSTER_QUALITY(),
             maximized: DEFAULT_MAXIMIZED(),
             native_window_enabled: DEFAULT_NATIVE_WINDOW(),
         }
@@ -53,6 +60,79 @@ impl UiSettings {
     }
 }
 
+/// The pages of the application which can be shown on startup.
+#[repr(C)]
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StartPage {
+    Movies,
+    Series,
+    Favorites,
+    ContinueWatching,
+}
+
+impl StartPage {
+    /// The media category which is shown by the start page.
+    /// It returns [None] when the page doesn't show a media category.
+    pub fn category(&self) -> Option<Category> {
+        match self {
+            StartPage::Movies => Some(Category::Movies),
+            StartPage::Series => Some(Category::Series),
+            StartPage::Favorites => Some(Category::Favorites),
+            StartPage::ContinueWatching => None,
+        }
+    }
+}
+
+/// The quality of the poster images which are requested by the application.
+/// A lower quality reduces the bandwidth and cache size of the posters.
+#[repr(C)]
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PosterQuality {
+    Low,
+    Medium,
+    High,
+}
+
+impl PosterQuality {
+    /// The image size variant of the TMDB image api which corresponds with this quality.
+    pub fn tmdb_size(&self) -> &'static str {
+        match self {
+            PosterQuality::Low => "w185",
+            PosterQuality::Medium => "w500",
+            PosterQuality::High => "w780",
+        }
+    }
+
+    /// Convert the given poster url into the image variant of this quality.
+    /// Urls which don't support image variants are returned as is.
+    pub fn poster_url(&self, url: &str) -> String {
+        if url.contains(TMDB_IMAGE_HOST) {
+            if let Some(start) = url.find(TMDB_IMAGE_PATH) {
+                let size_start = start + TMDB_IMAGE_PATH.len();
+
+                if let Some(size_len) = url[size_start..].find('/') {
+                    return format!(
+                        "{}{}{}",
+                        &url[..size_start],
+                        self.tmdb_size(),
+                        &url[size_start + size_len..]
+                    );
+                }
+            }
+        }
+
+        url.to_string()
+    }
+
+    /// Verify if the given image url is the image variant of this quality.
+    pub fn is_variant(&self, url: &str) -> bool {
+        url.contains(TMDB_IMAGE_HOST)
+            && url.contains(format!("{}{}/", TMDB_IMAGE_PATH, self.tmdb_size()).as_str())
+    }
+}
+
 /// The UI scale of the application
 #[repr(C)]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -90,7 +170,8 @@ mod test {
         let expected_result = UiSettings {
             default_language: DEFAULT_LANGUAGE(),
             ui_scale: DEFAULT_UI_SCALE(),
-            start_screen: DEFAULT_START_SCREEN(),
+            start_page: DEFAULT_START_PAGE(),
+            poster_quality: DEFAULT_POSTER_QUALITY(),
             maximized: DEFAULT_MAXIMIZED(),
             native_window_enabled: DEFAULT_NATIVE_WINDOW(),
         };
@@ -100,6 +181,54 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_ui_settings_without_new_fields() {
+        let value = r#"{"default_language":"fr","ui_scale":{"value":1.0},"maximized":true}"#;
+
+        let result: UiSettings = serde_json::from_str(value).unwrap();
+
+        assert_eq!(StartPage::Movies, result.start_page);
+        assert_eq!(PosterQuality::Medium, result.poster_quality);
+        assert_eq!(true, result.maximized);
+    }
+
+    #[test]
+    fn test_start_page_category() {
+        assert_eq!(Some(Category::Series), StartPage::Series.category());
+        assert_eq!(None, StartPage::ContinueWatching.category());
+    }
+
+    #[test]
+    fn test_poster_quality_poster_url() {
+        let url = "http://image.tmdb.org/t/p/w500/poster.jpg";
+
+        assert_eq!(
+            "http://image.tmdb.org/t/p/w185/poster.jpg".to_string(),
+            PosterQuality::Low.poster_url(url)
+        );
+        assert_eq!(url.to_string(), PosterQuality::Medium.poster_url(url));
+        assert_eq!(
+            "http://image.tmdb.org/t/p/w780/poster.jpg".to_string(),
+            PosterQuality::High.poster_url(url)
+        );
+        assert_eq!(
+            "http://localhost/t/p/w500/poster.jpg".to_string(),
+            PosterQuality::Low.poster_url("http://localhost/t/p/w500/poster.jpg")
+        );
+    }
+
+    #[test]
+    fn test_poster_quality_is_variant() {
+        let url = "http://image.tmdb.org/t/p/w185/poster.jpg";
+
+        assert_eq!(true, PosterQuality::Low.is_variant(url));
+        assert_eq!(false, PosterQuality::High.is_variant(url));
+        assert_eq!(
+            false,
+            PosterQuality::Low.is_variant("http://localhost/poster.jpg")
+        );
+    }
+
     #[test]
     fn test_ui_scale_display_text() {
         let scale = UiScale { value: 1.25f32 };
@@ -0,0 +1,3 @@
+pub use reporter::*;
+
+mod reporter;
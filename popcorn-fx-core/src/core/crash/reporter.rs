@@ -0,0 +1,318 @@
+use std::backtrace::Backtrace;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::panic;
+#[allow(deprecated)]
+use std::panic::PanicInfo;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Once, Weak};
+use std::thread;
+
+use chrono::{TimeZone, Utc};
+use log::{debug, error, info, trace, warn};
+
+use crate::core::events::{Event, EventPublisher};
+use crate::VERSION;
+
+const CRASH_DIRECTORY: &str = "crashes";
+const CRASH_REPORT_PREFIX: &str = "crash-";
+const CRASH_REPORT_EXTENSION: &str = ".log";
+/// The file which stores the name of the last crash report that has been sent as telemetry.
+const REPORTED_FILENAME: &str = "reported";
+
+static INSTALL_HOOK: Once = Once::new();
+/// The crash reporter which handles the panics captured by the installed panic hook.
+static ACTIVE_REPORTER: Mutex<Option<Weak<CrashReporter>>> = Mutex::new(None);
+
+/// The report of a panic which occurred within the application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrashReport {
+    /// The unix timestamp in millis at which the panic occurred.
+    pub timestamp: i64,
+    /// The version of the application which crashed.
+    pub version: String,
+    /// The name of the thread which panicked.
+    pub thread: String,
+    /// The panic payload message.
+    pub message: String,
+    /// The source location of the panic, if known.
+    pub location: Option<String>,
+    /// The captured backtrace of the panic.
+    pub backtrace: String,
+}
+
+impl CrashReport {
+    /// Create a new crash report from the given panic info.
+    /// The backtrace is always captured, regardless of the `RUST_BACKTRACE` environment variable.
+    // `PanicInfo` is kept over its `PanicHookInfo` alias to support the minimum rust version of the crates
+    #[allow(deprecated)]
+    pub fn from_panic(info: &PanicInfo) -> Self {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|e| e.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_string());
+
+        Self {
+            timestamp: Utc::now().timestamp_millis(),
+            version: VERSION.to_string(),
+            thread: thread::current().name().unwrap_or("unnamed").to_string(),
+            message,
+            location: info.location().map(|e| e.to_string()),
+            backtrace: Backtrace::force_capture().to_string(),
+        }
+    }
+
+    /// The filename under which the report is stored.
+    pub fn filename(&self) -> String {
+        format!(
+            "{}{}{}",
+            CRASH_REPORT_PREFIX, self.timestamp, CRASH_REPORT_EXTENSION
+        )
+    }
+}
+
+impl Display for CrashReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let datetime = Utc
+            .timestamp_millis_opt(self.timestamp)
+            .single()
+            .map(|e| e.to_rfc3339())
+            .unwrap_or_else(|| self.timestamp.to_string());
+
+        writeln!(f, "Popcorn FX {} crashed at {}", self.version, datetime)?;
+        writeln!(f, "thread: {}", self.thread)?;
+        writeln!(f, "message: {}", self.message)?;
+        writeln!(
+            f,
+            "location: {}",
+            self.location.as_deref().unwrap_or("unknown")
+        )?;
+        writeln!(f)?;
+        write!(f, "{}", self.backtrace)
+    }
+}
+
+/// A stored crash report which hasn't been sent as telemetry yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingCrashReport {
+    /// The filename of the stored crash report.
+    pub name: String,
+    /// The contents of the crash report.
+    pub contents: String,
+}
+
+/// The crash reporter of the application which captures the panics of all threads,
+/// including the async tasks of the runtimes.
+///
+/// Each panic is written as a timestamped report to the crash directory of the application
+/// and published as an [Event::Error], so the UI is able to notify the user.
+#[derive(Debug)]
+pub struct CrashReporter {
+    directory: PathBuf,
+    crash_count: AtomicU32,
+    event_publisher: Arc<EventPublisher>,
+}
+
+impl CrashReporter {
+    /// Create a new crash reporter which stores the reports within the given application directory.
+    ///
+    /// The crash count is initialized with the number of reports which are already stored.
+    pub fn new<P: AsRef<Path>>(app_directory: P, event_publisher: Arc<EventPublisher>) -> Self {
+        let directory = app_directory.as_ref().join(CRASH_DIRECTORY);
+        let crash_count = Self::report_names(&directory).len() as u32;
+
+        Self {
+            directory,
+            crash_count: AtomicU32::new(crash_count),
+            event_publisher,
+        }
+    }
+
+    /// Install the panic hook of the application which passes the captured panics to this reporter.
+    /// The previously installed hook is still invoked, so panics keep being printed to the console.
+    pub fn install(self: &Arc<Self>) {
+        if let Ok(mut reporter) = ACTIVE_REPORTER.lock() {
+            *reporter = Some(Arc::downgrade(self));
+        }
+
+        INSTALL_HOOK.call_once(|| {
+            let previous_hook = panic::take_hook();
+
+            panic::set_hook(Box::new(move |info| {
+                previous_hook(info);
+
+                // a poisoned lock should never result in a panic within the panic hook
+                let reporter = ACTIVE_REPORTER
+                    .lock()
+                    .ok()
+                    .and_then(|e| e.as_ref().and_then(Weak::upgrade));
+                if let Some(reporter) = reporter {
+                    reporter.report(CrashReport::from_panic(info));
+                }
+            }));
+            debug!("Crash reporter panic hook has been installed");
+        });
+    }
+
+    /// Store the given crash report and notify the application about the crash.
+    pub fn report(&self, report: CrashReport) {
+        let count = self.crash_count.fetch_add(1, Ordering::Relaxed) + 1;
+        let path = self.directory.join(report.filename());
+
+        error!(
+            "Application panicked at {}, {}",
+            report.location.as_deref().unwrap_or("unknown"),
+            report.message
+        );
+        match fs::create_dir_all(&self.directory).and_then(|_| fs::write(&path, report.to_string()))
+        {
+            Ok(_) => info!("Crash report has been written to {:?}", path),
+            Err(e) => error!("Failed to write crash report {:?}, {}", path, e),
+        }
+
+        trace!("Application has crashed a total of {} times", count);
+        self.event_publisher.publish(Event::Error(report.message));
+    }
+
+    /// The total number of crashes which have been reported.
+    pub fn crash_count(&self) -> u32 {
+        self.crash_count.load(Ordering::Relaxed)
+    }
+
+    /// Retrieve the last stored crash report, if it hasn't been sent as telemetry yet.
+    pub fn pending_report(&self) -> Option<PendingCrashReport> {
+        let reported = fs::read_to_string(self.directory.join(REPORTED_FILENAME)).ok();
+        let name = Self::report_names(&self.directory).pop()?;
+
+        if reported.as_deref() == Some(name.as_str()) {
+            trace!("Crash report {} has already been reported", name);
+            return None;
+        }
+
+        match fs::read_to_string(self.directory.join(&name)) {
+            Ok(contents) => Some(PendingCrashReport { name, contents }),
+            Err(e) => {
+                warn!("Failed to read crash report {}, {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Mark the given crash report as sent, so it won't be sent again.
+    pub fn mark_reported(&self, report: &PendingCrashReport) {
+        if let Err(e) = fs::write(self.directory.join(REPORTED_FILENAME), &report.name) {
+            warn!(
+                "Failed to mark crash report {} as reported, {}",
+                report.name, e
+            );
+        } else {
+            debug!("Crash report {} has been marked as reported", report.name);
+        }
+    }
+
+    /// Retrieve the crash report filenames within the given directory, ordered from oldest to newest.
+    fn report_names(directory: &Path) -> Vec<String> {
+        let mut names: Vec<(i64, String)> = fs::read_dir(directory)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter_map(|e| e.file_name().to_str().map(|e| e.to_string()))
+                    .filter_map(|name| {
+                        name.strip_prefix(CRASH_REPORT_PREFIX)
+                            .and_then(|e| e.strip_suffix(CRASH_REPORT_EXTENSION))
+                            .and_then(|e| e.parse::<i64>().ok())
+                            .map(|timestamp| (timestamp, name.clone()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        names.sort();
+        names.into_iter().map(|(_, name)| name).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let reporter = CrashReporter::new(temp_dir.path(), Arc::new(EventPublisher::default()));
+        let report = create_report(1000);
+
+        reporter.report(report.clone());
+
+        let result = fs::read_to_string(
+            temp_dir
+                .path()
+                .join(CRASH_DIRECTORY)
+                .join(report.filename()),
+        )
+        .expect("expected the crash report to have been written");
+        assert!(result.contains("message: lorem ipsum"), "got {}", result);
+        assert_eq!(1, reporter.crash_count());
+    }
+
+    #[test]
+    fn test_new_crash_count() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let event_publisher = Arc::new(EventPublisher::default());
+        let reporter = CrashReporter::new(temp_dir.path(), event_publisher.clone());
+        reporter.report(create_report(1000));
+        reporter.report(create_report(2000));
+
+        let result = CrashReporter::new(temp_dir.path(), event_publisher);
+
+        assert_eq!(2, result.crash_count());
+    }
+
+    #[test]
+    fn test_pending_report() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let reporter = CrashReporter::new(temp_dir.path(), Arc::new(EventPublisher::default()));
+        let report = create_report(2000);
+        reporter.report(create_report(1000));
+        reporter.report(report.clone());
+
+        let result = reporter
+            .pending_report()
+            .expect("expected a pending crash report");
+        assert_eq!(report.filename(), result.name);
+        assert_eq!(report.to_string(), result.contents);
+
+        reporter.mark_reported(&result);
+        assert_eq!(None, reporter.pending_report());
+    }
+
+    #[test]
+    fn test_pending_report_no_crashes() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let reporter = CrashReporter::new(temp_dir.path(), Arc::new(EventPublisher::default()));
+
+        assert_eq!(None, reporter.pending_report());
+    }
+
+    fn create_report(timestamp: i64) -> CrashReport {
+        CrashReport {
+            timestamp,
+            version: VERSION.to_string(),
+            thread: "main".to_string(),
+            message: "lorem ipsum".to_string(),
+            location: Some("src/lib.rs:10:5".to_string()),
+            backtrace: "dolor".to_string(),
+        }
+    }
+}
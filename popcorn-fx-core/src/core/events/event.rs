@@ -57,6 +57,9 @@ pub enum Event {
     /// Invoked when the player should be closed
     #[display(fmt = "Closing player")]
     ClosePlayer,
+    /// Invoked when an unexpected error occurred within the application, such as a panic
+    #[display(fmt = "An unexpected error occurred, {}", _0)]
+    Error(String),
 }
 
 /// Represents an event indicating a change in the active player within a multimedia application.
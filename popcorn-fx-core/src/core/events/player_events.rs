@@ -24,8 +24,8 @@ pub struct PlayerStartedEvent {
     pub subtitles_enabled: bool,
 }
 
-impl From<&Box<dyn PlayRequest>> for PlayerStartedEvent {
-    fn from(value: &Box<dyn PlayRequest>) -> Self {
+impl From<&PlayRequest> for PlayerStartedEvent {
+    fn from(value: &PlayRequest) -> Self {
         Self {
             url: value.url().to_string(),
             title: value.title().to_string(),
@@ -46,6 +46,8 @@ pub struct PlayerStoppedEvent {
     pub url: String,
     /// The media item that was being played
     pub media: Option<Box<dyn MediaIdentifier>>,
+    /// The parent media item of the media that was being played, such as the show of an episode
+    pub parent_media: Option<Box<dyn MediaIdentifier>>,
     /// The last known video time of the player in millis
     pub time: Option<u64>,
     /// The duration of the video playback in millis
@@ -102,6 +104,11 @@ impl PlayerStoppedEvent {
         self.media.as_ref()
     }
 
+    /// The parent media item of the media that was being played.
+    pub fn parent_media(&self) -> Option<&Box<dyn MediaIdentifier>> {
+        self.parent_media.as_ref()
+    }
+
     /// The last known time of the video playback.
     ///
     /// It returns [None] when the playback didn't start and there is no
@@ -135,6 +142,10 @@ impl Clone for PlayerStoppedEvent {
         PlayerStoppedEvent {
             url: self.url.clone(),
             media: cloned_media,
+            parent_media: self
+                .parent_media
+                .as_ref()
+                .and_then(|e| e.clone_identifier()),
             time: self.time,
             duration: self.duration,
         }
@@ -152,7 +163,7 @@ mod test {
     use std::collections::HashMap;
 
     use crate::core::media::{Episode, Images, Rating, ShowOverview};
-    use crate::core::players::PlayUrlRequestBuilder;
+    use crate::core::players::PlayRequestBuilder;
     use crate::testing::init_logger;
 
     use super::*;
@@ -164,7 +175,7 @@ mod test {
         let thumb = "MyThumb";
         let auto_resume = 50000;
         let background = "MyBackground.jpg";
-        let request = PlayUrlRequestBuilder::builder()
+        let request = PlayRequestBuilder::builder()
             .url(url)
             .title(title)
             .thumb(thumb)
@@ -182,7 +193,7 @@ mod test {
             subtitles_enabled: true,
         };
 
-        let result = PlayerStartedEvent::from(&(Box::new(request) as Box<dyn PlayRequest>));
+        let result = PlayerStartedEvent::from(&request);
 
         assert_eq!(expected_result, result);
     }
@@ -205,6 +216,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: String::from("https://example.com/video.mp4"),
             media: Some(boxed_media),
+            parent_media: None,
             time: Some(100),
             duration: Some(500),
         };
@@ -244,6 +256,7 @@ mod test {
         let event_with_rating = PlayerStoppedEvent {
             url: String::from("https://example.com/video.mp4"),
             media: Some(boxed_media_with_rating),
+            parent_media: None,
             time: Some(100),
             duration: Some(500),
         };
@@ -264,6 +277,7 @@ mod test {
         let event1 = PlayerStoppedEvent {
             url: String::from("http://example.com/video.mp4"),
             media: None,
+            parent_media: None,
             time: Some(5000),
             duration: Some(10000),
         };
@@ -271,6 +285,7 @@ mod test {
         let event2 = PlayerStoppedEvent {
             url: String::from("http://example.com/video.mp4"),
             media: None,
+            parent_media: None,
             time: Some(5000),
             duration: Some(10000),
         };
@@ -283,6 +298,7 @@ mod test {
         let event1 = PlayerStoppedEvent {
             url: String::from("http://example.com/video.mp4"),
             media: None,
+            parent_media: None,
             time: Some(5000),
             duration: Some(10000),
         };
@@ -290,6 +306,7 @@ mod test {
         let event2 = PlayerStoppedEvent {
             url: String::from("http://example.com/video.mp4"),
             media: None,
+            parent_media: None,
             time: Some(8000),
             duration: Some(30000),
         };
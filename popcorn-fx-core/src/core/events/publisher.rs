@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::mem::Discriminant;
 use std::sync::Arc;
 
 use log::{debug, info, trace};
@@ -52,6 +54,23 @@ pub type EventCallback = Box<dyn Fn(Event) -> Option<Event> + Send>;
 /// The event ordering priority type that determines the order in which the event consumers/listeners will be invoked.
 pub type Order = i32;
 
+/// The event subscriber type which receives the published events, together with their sequence number,
+/// after the event chain has been completed.
+///
+/// Subscribers are unable to consume events, they're only informed about events which haven't been consumed by the chain.
+pub type EventSubscriber = Box<dyn Fn(SequencedEvent) + Send>;
+
+/// An event which has been published by the [EventPublisher] together with its publishing information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequencedEvent {
+    /// The sequence number of the event, which is incremented for each published event
+    pub sequence: u64,
+    /// Indicates if the event is being replayed from the replay buffer
+    pub replayed: bool,
+    /// The published event
+    pub event: Event,
+}
+
 /// The event publisher allows for the publishing and listing to application wide events.
 /// It decouples components by allowing a central system to handle events to which each component can subscribe without needing the requirement
 /// to know who the original publisher is of the event.
@@ -67,6 +86,7 @@ pub type Order = i32;
 /// publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
 ///     url: "".to_string(),
 ///     media: None,
+///     parent_media: None,
 ///     time: None,
 ///     duration: None,
 /// }));
@@ -83,10 +103,26 @@ pub type Order = i32;
 pub struct EventPublisher {
     /// The callbacks that need to be invoked for the listener
     callbacks: Arc<Mutex<Vec<EventCallbackHolder>>>,
+    /// The subscribers and the replay buffer of the published events
+    replay: Arc<Mutex<EventReplay>>,
     runtime: Runtime,
 }
 
 impl EventPublisher {
+    /// Create a new event publisher which keeps the last `replay_buffer_size` events of each event type.
+    /// These events are replayed to new subscribers, see [EventPublisher::subscribe].
+    ///
+    /// A `replay_buffer_size` of `0` disables the replaying of events.
+    pub fn with_replay_buffer(replay_buffer_size: usize) -> Self {
+        Self {
+            replay: Arc::new(Mutex::new(EventReplay {
+                buffer_size: replay_buffer_size,
+                ..Default::default()
+            })),
+            ..Self::default()
+        }
+    }
+
     /// Register a new event consumer/listener with the `EventPublisher`.
     ///
     /// # Arguments
@@ -120,6 +156,22 @@ impl EventPublisher {
         debug!("Added event callback, new total callbacks {}", mutex.len());
     }
 
+    /// Subscribe to the events which have been published and not consumed by the event chain.
+    ///
+    /// The buffered events are replayed first to the subscriber, in order of their sequence number.
+    /// Reconnecting subscribers can provide the `last_sequence` they've received,
+    /// which only replays the buffered events that were published after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscriber` - The subscriber which receives the events.
+    /// * `last_sequence` - The last known event sequence of the subscriber, if any.
+    pub fn subscribe(&self, subscriber: EventSubscriber, last_sequence: Option<u64>) {
+        trace!("Registering a new subscriber to the EventPublisher");
+        let mut mutex = block_in_place(self.replay.lock());
+        mutex.subscribe(subscriber, last_sequence);
+    }
+
     /// Publish a new application event.
     ///
     /// This method asynchronously invokes the registered event callbacks with the provided event.
@@ -129,6 +181,7 @@ impl EventPublisher {
     /// * `event` - The event to publish.
     pub fn publish(&self, event: Event) {
         let callbacks = self.callbacks.clone();
+        let replay = self.replay.clone();
         self.runtime.spawn(async move {
             let invocations = callbacks.lock().await;
             info!("Publishing event {}", event);
@@ -144,9 +197,14 @@ impl EventPublisher {
                     arg = event;
                 } else {
                     debug!("Event publisher chain has been interrupted");
-                    break;
+                    return;
                 }
             }
+
+            // the sequence is assigned while the callbacks are still locked,
+            // so the sequence numbers follow the order in which the events passed the chain
+            replay.lock().await.publish(arg);
+            drop(invocations);
         });
     }
 }
@@ -155,6 +213,7 @@ impl Default for EventPublisher {
     fn default() -> Self {
         Self {
             callbacks: Arc::new(Default::default()),
+            replay: Arc::new(Default::default()),
             runtime: tokio::runtime::Builder::new_multi_thread()
                 .enable_all()
                 .worker_threads(2)
@@ -168,8 +227,10 @@ impl Default for EventPublisher {
 impl Debug for EventPublisher {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mutex = block_in_place(self.callbacks.lock());
+        let replay = block_in_place(self.replay.lock());
         f.debug_struct("EventPublisher")
             .field("callbacks", &mutex.len())
+            .field("replay", &*replay)
             .finish()
     }
 }
@@ -210,6 +271,85 @@ impl Debug for EventCallbackHolder {
     }
 }
 
+/// The replay information of the [EventPublisher].
+/// It keeps track of the subscribers and a bounded buffer of the last published events of each event type.
+#[derive(Default)]
+struct EventReplay {
+    buffer_size: usize,
+    sequence: u64,
+    buffer: HashMap<Discriminant<Event>, VecDeque<SequencedEvent>>,
+    subscribers: Vec<EventSubscriber>,
+}
+
+impl EventReplay {
+    fn subscribe(&mut self, subscriber: EventSubscriber, last_sequence: Option<u64>) {
+        let last_sequence = last_sequence.unwrap_or(0);
+        let mut events: Vec<&SequencedEvent> = self
+            .buffer
+            .values()
+            .flatten()
+            .filter(|e| e.sequence > last_sequence)
+            .collect();
+        events.sort_by_key(|e| e.sequence);
+
+        debug!(
+            "Replaying a total of {} events to the new subscriber",
+            events.len()
+        );
+        for event in events {
+            subscriber(SequencedEvent {
+                replayed: true,
+                ..event.clone()
+            });
+        }
+
+        self.subscribers.push(subscriber);
+        debug!(
+            "Added event subscriber, new total subscribers {}",
+            self.subscribers.len()
+        );
+    }
+
+    fn publish(&mut self, event: Event) {
+        self.sequence += 1;
+        let event = SequencedEvent {
+            sequence: self.sequence,
+            replayed: false,
+            event,
+        };
+
+        if self.buffer_size > 0 {
+            let events = self
+                .buffer
+                .entry(std::mem::discriminant(&event.event))
+                .or_default();
+            events.push_back(event.clone());
+            while events.len() > self.buffer_size {
+                events.pop_front();
+            }
+        }
+
+        trace!(
+            "Invoking a total of {} subscribers for event {}",
+            self.subscribers.len(),
+            event.sequence
+        );
+        for subscriber in self.subscribers.iter() {
+            subscriber(event.clone());
+        }
+    }
+}
+
+impl Debug for EventReplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventReplay")
+            .field("buffer_size", &self.buffer_size)
+            .field("sequence", &self.sequence)
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::mpsc::channel;
@@ -241,6 +381,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/video.mkv".to_string(),
             media: None,
+            parent_media: None,
             time: Some(140000),
             duration: Some(2000000),
         };
@@ -291,6 +432,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/video.mkv".to_string(),
             media: None,
+            parent_media: None,
             time: Some(140000),
             duration: Some(2000000),
         };
@@ -336,6 +478,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "https::/localhost:8457/my_video.mkv".to_string(),
             media: None,
+            parent_media: None,
             time: None,
             duration: None,
         };
@@ -352,4 +495,130 @@ mod test {
             "expected the rx_callback1 to not have been invoked"
         );
     }
+
+    #[test]
+    fn test_event_publisher_subscribe_replay() {
+        init_logger();
+        let (tx, rx) = channel();
+        let publisher = EventPublisher::with_replay_buffer(2);
+
+        publish_and_wait(&publisher, Event::LoadingStarted, 1);
+        publish_and_wait(&publisher, Event::LoadingStarted, 2);
+        publish_and_wait(&publisher, Event::LoadingStarted, 3);
+        publish_and_wait(&publisher, Event::ClosePlayer, 4);
+        publisher.subscribe(Box::new(move |event| tx.send(event).unwrap()), None);
+        publisher.publish(Event::LoadingCompleted);
+
+        let result: Vec<SequencedEvent> = (0..4)
+            .map(|_| rx.recv_timeout(Duration::from_millis(200)).unwrap())
+            .collect();
+        assert_eq!(
+            vec![
+                SequencedEvent {
+                    sequence: 2,
+                    replayed: true,
+                    event: Event::LoadingStarted,
+                },
+                SequencedEvent {
+                    sequence: 3,
+                    replayed: true,
+                    event: Event::LoadingStarted,
+                },
+                SequencedEvent {
+                    sequence: 4,
+                    replayed: true,
+                    event: Event::ClosePlayer,
+                },
+                SequencedEvent {
+                    sequence: 5,
+                    replayed: false,
+                    event: Event::LoadingCompleted,
+                },
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_event_publisher_subscribe_last_sequence() {
+        init_logger();
+        let (tx, rx) = channel();
+        let publisher = EventPublisher::with_replay_buffer(5);
+
+        publish_and_wait(&publisher, Event::LoadingStarted, 1);
+        publish_and_wait(&publisher, Event::LoadingCompleted, 2);
+        publisher.subscribe(Box::new(move |event| tx.send(event).unwrap()), Some(1));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            SequencedEvent {
+                sequence: 2,
+                replayed: true,
+                event: Event::LoadingCompleted,
+            },
+            result
+        );
+        assert!(
+            rx.recv_timeout(Duration::from_millis(50)).is_err(),
+            "expected no other events to have been replayed"
+        );
+    }
+
+    #[test]
+    fn test_event_publisher_subscribe_sequence_order() {
+        init_logger();
+        let (tx, rx) = channel();
+        let publisher = EventPublisher::default();
+        publisher.subscribe(
+            Box::new(move |event| tx.send(event.sequence).unwrap()),
+            None,
+        );
+
+        for _ in 0..50 {
+            publisher.publish(Event::LoadingStarted);
+        }
+
+        let mut last_sequence = 0;
+        for _ in 0..50 {
+            let sequence = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+            assert!(
+                sequence > last_sequence,
+                "expected sequence {} to be received after {}",
+                sequence,
+                last_sequence
+            );
+            last_sequence = sequence;
+        }
+    }
+
+    #[test]
+    fn test_event_publisher_subscribe_event_consumed() {
+        init_logger();
+        let (tx, rx) = channel();
+        let publisher = EventPublisher::with_replay_buffer(5);
+        publisher.register(Box::new(|_| None), DEFAULT_ORDER);
+        publisher.subscribe(Box::new(move |event| tx.send(event).unwrap()), None);
+
+        publisher.publish(Event::ClosePlayer);
+
+        assert!(
+            rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "expected the subscriber to not have been invoked"
+        );
+    }
+
+    fn publish_and_wait(publisher: &EventPublisher, event: Event, sequence: u64) {
+        publisher.publish(event);
+        for _ in 0..20 {
+            if block_in_place(publisher.replay.lock()).sequence >= sequence {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        panic!(
+            "expected the event with sequence {} to have been published",
+            sequence
+        );
+    }
 }
@@ -0,0 +1,614 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Local};
+use derive_more::Display;
+use log::{debug, trace, warn};
+use reqwest::header::{HeaderMap, CACHE_CONTROL, ETAG, LAST_MODIFIED};
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+
+use crate::core::storage::Storage;
+
+/// The default maximum size in bytes of the image cache.
+pub const DEFAULT_IMAGE_CACHE_SIZE: u64 = 250 * 1024 * 1024;
+const DIRECTORY: &str = "images";
+const INDEX_FILENAME: &str = "index.json";
+const EXTENSION: &str = ".img";
+/// The default duration for which an image is considered fresh when the server didn't specify a max-age.
+const DEFAULT_MAX_AGE: fn() -> Duration = || Duration::days(3);
+
+/// The statistics of the image cache.
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(
+    fmt = "entries: {}, size: {}, max_size: {}, hits: {}, misses: {}, revalidations: {}",
+    entries,
+    size,
+    max_size,
+    hits,
+    misses,
+    revalidations
+)]
+pub struct ImageCacheStats {
+    /// The number of cached image urls.
+    pub entries: usize,
+    /// The total size in bytes of the cached image files.
+    pub size: u64,
+    /// The maximum size in bytes of the cached image files.
+    pub max_size: u64,
+    /// The number of requests which have been served from the cache.
+    pub hits: u64,
+    /// The number of requests which couldn't be served from the cache.
+    pub misses: u64,
+    /// The number of stale cached images which have been revalidated with the server.
+    pub revalidations: u64,
+}
+
+/// The caching directives of a retrieved image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageCachePolicy {
+    /// Indicates if the image is allowed to be stored in the cache.
+    pub store: bool,
+    /// The duration for which the cached image is considered fresh.
+    pub max_age: Duration,
+    /// The entity tag of the image, used for revalidating the cached image.
+    pub etag: Option<String>,
+    /// The last modified date of the image, used for revalidating the cached image.
+    pub last_modified: Option<String>,
+}
+
+impl ImageCachePolicy {
+    /// Create the cache policy from the `Cache-Control`, `ETag` and `Last-Modified` headers of a response.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut policy = Self::default();
+        let directives = headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .filter_map(|e| e.to_str().ok())
+            .flat_map(|e| e.split(','))
+            .map(|e| e.trim().to_lowercase())
+            .collect::<Vec<String>>();
+
+        for directive in directives.iter() {
+            if directive == "no-store" {
+                policy.store = false;
+            } else if directive == "no-cache" {
+                policy.max_age = Duration::zero();
+            } else if let Some(max_age) = directive
+                .strip_prefix("max-age=")
+                .and_then(|e| e.trim_matches('"').parse::<i64>().ok())
+            {
+                if !directives.iter().any(|e| e == "no-cache") {
+                    policy.max_age = Duration::seconds(max_age.max(0));
+                }
+            }
+        }
+
+        policy.etag = headers
+            .get(ETAG)
+            .and_then(|e| e.to_str().ok())
+            .map(|e| e.to_string());
+        policy.last_modified = headers
+            .get(LAST_MODIFIED)
+            .and_then(|e| e.to_str().ok())
+            .map(|e| e.to_string());
+        policy
+    }
+}
+
+impl Default for ImageCachePolicy {
+    fn default() -> Self {
+        Self {
+            store: true,
+            max_age: DEFAULT_MAX_AGE(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+}
+
+/// An image which has been retrieved from the cache.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedImage {
+    /// The binary data of the image.
+    pub data: Vec<u8>,
+    /// Indicates if the image is still fresh, else it needs to be revalidated before being used.
+    pub fresh: bool,
+    /// The entity tag of the cached image.
+    pub etag: Option<String>,
+    /// The last modified date of the cached image.
+    pub last_modified: Option<String>,
+}
+
+/// The disk backed cache of the retrieved images.
+///
+/// The image files are content-addressed, which means that urls serving the same image share the same file.
+/// The total size of the image files is kept within the maximum size of the cache by evicting the least recently used images.
+#[derive(Debug)]
+pub struct ImageCache {
+    storage: Storage,
+    max_size: u64,
+    index: Mutex<ImageCacheIndex>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    revalidations: AtomicU64,
+}
+
+impl ImageCache {
+    /// Creates a new `ImageCache` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage_path` - The application data directory in which the image cache is stored.
+    /// * `max_size` - The maximum size in bytes of the cached image files.
+    pub fn new(storage_path: &str, max_size: u64) -> Self {
+        let storage = Storage::from(&PathBuf::from(storage_path).join(DIRECTORY));
+        let index = storage
+            .options()
+            .serializer(INDEX_FILENAME)
+            .read::<ImageCacheIndex>()
+            .unwrap_or_else(|e| {
+                debug!("Creating new image cache index, reason: {}", e);
+                ImageCacheIndex::default()
+            });
+
+        Self {
+            storage,
+            max_size,
+            index: Mutex::new(index),
+            hits: Default::default(),
+            misses: Default::default(),
+            revalidations: Default::default(),
+        }
+    }
+
+    /// Retrieve the cached image of the given url.
+    ///
+    /// A cached image which is no longer fresh is still returned, so it can be revalidated.
+    /// It returns [None] when the url is not cached.
+    pub fn get(&self, url: &str) -> Option<CachedImage> {
+        let mut index = self.index.lock().unwrap();
+        let entry = match index.entries.get_mut(url) {
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+            Some(entry) => entry,
+        };
+
+        match self.storage.options().binary(&entry.filename).read() {
+            Ok(data) => {
+                let fresh = entry.expires_at > Local::now();
+                entry.last_accessed = Local::now();
+                if fresh {
+                    trace!("Image cache hit for {}", url);
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                } else {
+                    trace!("Cached image of {} is stale", url);
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                }
+
+                Some(CachedImage {
+                    data,
+                    fresh,
+                    etag: entry.etag.clone(),
+                    last_modified: entry.last_modified.clone(),
+                })
+            }
+            Err(e) => {
+                warn!("Failed to read cached image of {}, {}", url, e);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                self.remove_entry(&mut index, url);
+                None
+            }
+        }
+    }
+
+    /// Store the image data of the given url in the cache.
+    ///
+    /// It returns `true` when the image has been stored, else `false`.
+    pub fn store(&self, url: &str, data: &[u8], policy: &ImageCachePolicy) -> bool {
+        if !policy.store {
+            debug!("Image {} is not allowed to be stored in the cache", url);
+            return false;
+        }
+
+        let filename = Self::content_filename(data);
+        let mut index = self.index.lock().unwrap();
+        if !index.entries.values().any(|e| e.filename == filename) {
+            if let Err(e) = self
+                .storage
+                .options()
+                .make_dirs(true)
+                .binary(&filename)
+                .write(data)
+            {
+                warn!("Failed to write cached image of {}, {}", url, e);
+                return false;
+            }
+        }
+
+        if index
+            .entries
+            .get(url)
+            .filter(|e| e.filename != filename)
+            .is_some()
+        {
+            self.remove_entry(&mut index, url);
+        }
+        trace!("Caching image {} as {}", url, filename);
+        index.entries.insert(
+            url.to_string(),
+            ImageCacheEntry {
+                filename,
+                size: data.len() as u64,
+                last_accessed: Local::now(),
+                expires_at: Local::now() + policy.max_age,
+                etag: policy.etag.clone(),
+                last_modified: policy.last_modified.clone(),
+            },
+        );
+        self.evict(&mut index);
+        self.write_index(&index);
+        index.entries.contains_key(url)
+    }
+
+    /// Refresh the cached image of the given url after it has been revalidated with the server.
+    pub fn refresh(&self, url: &str, policy: &ImageCachePolicy) {
+        let mut index = self.index.lock().unwrap();
+        if let Some(entry) = index.entries.get_mut(url) {
+            trace!("Refreshing cached image {}", url);
+            self.revalidations.fetch_add(1, Ordering::Relaxed);
+            entry.expires_at = Local::now() + policy.max_age;
+            entry.etag = policy.etag.clone().or(entry.etag.take());
+            entry.last_modified = policy.last_modified.clone().or(entry.last_modified.take());
+            self.write_index(&index);
+        }
+    }
+
+    /// Verify if a fresh image is cached for the given url.
+    pub fn contains(&self, url: &str) -> bool {
+        self.index
+            .lock()
+            .unwrap()
+            .entries
+            .get(url)
+            .map(|e| e.expires_at > Local::now())
+            .unwrap_or(false)
+    }
+
+    /// The number of cached image urls.
+    pub fn len(&self) -> usize {
+        self.index.lock().unwrap().entries.len()
+    }
+
+    /// Verify if the image cache doesn't contain any images.
+    pub fn is_empty(&self) -> bool {
+        self.index.lock().unwrap().entries.is_empty()
+    }
+
+    /// Remove the cached images of which the url matches the given predicate.
+    ///
+    /// It returns the number of removed images.
+    pub fn remove_matching<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&str) -> bool,
+    {
+        let mut index = self.index.lock().unwrap();
+        let urls = index
+            .entries
+            .keys()
+            .filter(|e| predicate(e.as_str()))
+            .cloned()
+            .collect::<Vec<String>>();
+
+        for url in urls.iter() {
+            self.remove_entry(&mut index, url);
+        }
+        if !urls.is_empty() {
+            debug!("Removed {} cached images", urls.len());
+            self.write_index(&index);
+        }
+
+        urls.len()
+    }
+
+    /// Remove all cached images from the cache.
+    pub fn clear(&self) {
+        let mut index = self.index.lock().unwrap();
+        let urls = index.entries.keys().cloned().collect::<Vec<String>>();
+
+        for url in urls.iter() {
+            self.remove_entry(&mut index, url);
+        }
+        debug!("Image cache has been cleared");
+        self.write_index(&index);
+    }
+
+    /// Retrieve the statistics of the image cache.
+    pub fn stats(&self) -> ImageCacheStats {
+        let index = self.index.lock().unwrap();
+
+        ImageCacheStats {
+            entries: index.entries.len(),
+            size: index.size(),
+            max_size: self.max_size,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            revalidations: self.revalidations.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Evict the least recently used images until the cache size is within the maximum size.
+    fn evict(&self, index: &mut ImageCacheIndex) {
+        while index.size() > self.max_size {
+            let url = index
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_accessed)
+                .map(|(url, _)| url.clone());
+
+            match url {
+                Some(url) => {
+                    debug!("Evicting least recently used image {}", url);
+                    self.remove_entry(index, url.as_str());
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Remove the entry of the given url from the index.
+    /// The image file is only removed when it's no longer used by another url.
+    fn remove_entry(&self, index: &mut ImageCacheIndex, url: &str) {
+        if let Some(entry) = index.entries.remove(url) {
+            if !index.entries.values().any(|e| e.filename == entry.filename) {
+                if let Err(e) = self.storage.delete_path(&entry.filename) {
+                    warn!(
+                        "Failed to remove cached image file {}, {}",
+                        entry.filename, e
+                    );
+                }
+            }
+        }
+    }
+
+    fn write_index(&self, index: &ImageCacheIndex) {
+        if let Err(e) = self
+            .storage
+            .options()
+            .make_dirs(true)
+            .serializer(INDEX_FILENAME)
+            .write(index)
+        {
+            warn!("Failed to save the image cache index, {}", e);
+        }
+    }
+
+    fn content_filename(data: &[u8]) -> String {
+        digest(&SHA256, data)
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+            + EXTENSION
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ImageCacheIndex {
+    /// The cached images by their url.
+    entries: HashMap<String, ImageCacheEntry>,
+}
+
+impl ImageCacheIndex {
+    /// The total size of the unique image files within the cache.
+    fn size(&self) -> u64 {
+        let mut filenames = HashSet::new();
+        self.entries
+            .values()
+            .filter(|e| filenames.insert(e.filename.as_str()))
+            .map(|e| e.size)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ImageCacheEntry {
+    filename: String,
+    size: u64,
+    last_accessed: DateTime<Local>,
+    expires_at: DateTime<Local>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_policy_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=60"),
+        );
+        headers.insert(ETAG, HeaderValue::from_static("\"lorem\""));
+
+        let result = ImageCachePolicy::from_headers(&headers);
+
+        assert_eq!(
+            ImageCachePolicy {
+                store: true,
+                max_age: Duration::seconds(60),
+                etag: Some("\"lorem\"".to_string()),
+                last_modified: None,
+            },
+            result
+        );
+    }
+
+    #[test]
+    fn test_policy_from_headers_no_cache() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static("max-age=3600, no-cache"),
+        );
+
+        let result = ImageCachePolicy::from_headers(&headers);
+
+        assert_eq!(true, result.store);
+        assert_eq!(Duration::zero(), result.max_age);
+    }
+
+    #[test]
+    fn test_policy_from_headers_no_store() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+
+        let result = ImageCachePolicy::from_headers(&headers);
+
+        assert_eq!(false, result.store);
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let url = "http://localhost/poster.png";
+        let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+
+        assert_eq!(None, cache.get(url));
+        assert_eq!(
+            true,
+            cache.store(url, &[1, 2, 3], &ImageCachePolicy::default())
+        );
+        let result = cache
+            .get(url)
+            .expect("expected the image to have been cached");
+
+        assert_eq!(vec![1, 2, 3], result.data);
+        assert_eq!(true, result.fresh);
+        let stats = cache.stats();
+        assert_eq!(1, stats.entries);
+        assert_eq!(3, stats.size);
+        assert_eq!(1, stats.hits);
+        assert_eq!(1, stats.misses);
+    }
+
+    #[test]
+    fn test_store_content_addressed() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+        let policy = ImageCachePolicy::default();
+
+        cache.store("http://localhost/a.png", &[1, 2, 3], &policy);
+        cache.store("http://localhost/b.png", &[1, 2, 3], &policy);
+        cache.remove_matching(|e| e.ends_with("a.png"));
+
+        let stats = cache.stats();
+        assert_eq!(1, stats.entries);
+        assert_eq!(3, stats.size, "expected the image file to be shared");
+        assert_eq!(
+            Some(vec![1, 2, 3]),
+            cache.get("http://localhost/b.png").map(|e| e.data)
+        );
+    }
+
+    #[test]
+    fn test_store_evict_least_recently_used() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = ImageCache::new(temp_path, 5);
+        let policy = ImageCachePolicy::default();
+
+        cache.store("http://localhost/a.png", &[1, 2], &policy);
+        cache.store("http://localhost/b.png", &[3, 4], &policy);
+        let _ = cache.get("http://localhost/a.png");
+        cache.store("http://localhost/c.png", &[5, 6], &policy);
+
+        assert_eq!(true, cache.contains("http://localhost/a.png"));
+        assert_eq!(
+            false,
+            cache.contains("http://localhost/b.png"),
+            "expected the least recently used image to have been evicted"
+        );
+        assert_eq!(true, cache.contains("http://localhost/c.png"));
+        assert_eq!(4, cache.stats().size);
+    }
+
+    #[test]
+    fn test_refresh() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let url = "http://localhost/poster.png";
+        let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+        let policy = ImageCachePolicy {
+            store: true,
+            max_age: Duration::zero(),
+            etag: Some("lorem".to_string()),
+            last_modified: None,
+        };
+
+        cache.store(url, &[1, 2, 3], &policy);
+        let result = cache.get(url).unwrap();
+        assert_eq!(false, result.fresh);
+        assert_eq!(Some("lorem".to_string()), result.etag);
+
+        cache.refresh(url, &ImageCachePolicy::default());
+        let result = cache.get(url).unwrap();
+        assert_eq!(true, result.fresh);
+        assert_eq!(Some("lorem".to_string()), result.etag);
+        assert_eq!(1, cache.stats().revalidations);
+    }
+
+    #[test]
+    fn test_clear() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+
+        cache.store(
+            "http://localhost/poster.png",
+            &[1, 2, 3],
+            &ImageCachePolicy::default(),
+        );
+        cache.clear();
+
+        let stats = cache.stats();
+        assert_eq!(0, stats.entries);
+        assert_eq!(0, stats.size);
+        assert_eq!(None, cache.get("http://localhost/poster.png"));
+    }
+
+    #[test]
+    fn test_new_existing_index() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let url = "http://localhost/poster.png";
+
+        {
+            let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+            cache.store(url, &[1, 2, 3], &ImageCachePolicy::default());
+        }
+        let cache = ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE);
+
+        assert_eq!(Some(vec![1, 2, 3]), cache.get(url).map(|e| e.data));
+    }
+}
@@ -9,4 +9,10 @@ pub enum ImageError {
     /// Failed to load the image data.
     #[error("failed to load image data: {0}")]
     Load(String),
+    /// Failed to decode the image data.
+    #[error("failed to decode image data: {0}")]
+    Decode(String),
+    /// Failed to encode the resized image data.
+    #[error("failed to encode image data: {0}")]
+    Encode(String),
 }
@@ -1,19 +1,30 @@
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
-use chrono::Duration;
-use log::{debug, trace, warn};
-use reqwest::Client;
+use futures::StreamExt;
+use log::{debug, info, trace, warn};
+use reqwest::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use tokio::sync::OnceCell;
 use url::Url;
 
-use crate::core::cache::{CacheManager, CacheOptions, CacheType};
-use crate::core::images::ImageError;
+use crate::core::config::{ApplicationConfig, ApplicationConfigEvent, PosterQuality};
+use crate::core::images::{
+    resize_image, CachedImage, ImageCache, ImageCachePolicy, ImageCacheStats, ImageError, ImageSize,
+};
 use crate::core::media::MediaOverview;
+use crate::core::utils::http::HttpClientFactory;
 
 const POSTER_PLACEHOLDER: &[u8] = include_bytes!("../../../resources/posterholder.png");
 const ART_PLACEHOLDER: &[u8] = include_bytes!("../../../resources/artholder.png");
 const BACKGROUND_HOLDER: &[u8] = include_bytes!("../../../resources/background.jpg");
-const CACHE_NAME: &str = "images";
+/// The maximum number of images which are fetched concurrently during a prefetch.
+const PREFETCH_CONCURRENCY: usize = 4;
+/// The maximum number of cached images up to which a prefetch is allowed to fill the image cache.
+/// Prefetching stops at this threshold so it won't push the cache beyond its intended size,
+/// images requested on demand are still cached as usual.
+const PREFETCH_CACHE_LIMIT: usize = 1000;
 
 /// The `ImageLoader` trait is responsible for loading image data from local or remote locations.
 ///
@@ -24,7 +35,7 @@ const CACHE_NAME: &str = "images";
 ///
 /// All methods in this trait are asynchronous and return a `Future` that will resolve to the image data when it's available.
 #[async_trait]
-pub trait ImageLoader {
+pub trait ImageLoader: Send + Sync {
     /// Retrieve the default poster (placeholder) image data.
     ///
     /// This method returns a `Vec<u8>` containing the data for the default poster holder image.
@@ -72,16 +83,59 @@ pub trait ImageLoader {
     /// * `Some(Vec<u8>)` - The binary data of the image on success.
     /// * `None` - If the operation fails.
     async fn load(&self, url: &str) -> Option<Vec<u8>>;
+
+    /// Load the image data from the given URL, resized to fit within the given size.
+    ///
+    /// The resized image is cached separately from the original image for each requested size.
+    /// The original image data is returned when it couldn't be decoded or already fits within the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL from where to fetch the image data.
+    /// * `size` - The bounds within which the returned image should fit.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<u8>)` - The binary data of the (resized) image on success.
+    /// * `None` - If the image data couldn't be fetched.
+    async fn load_resized(&self, url: &str, size: ImageSize) -> Option<Vec<u8>>;
+
+    /// Prefetch the image data of the given URLs into the cache.
+    ///
+    /// The images are fetched concurrently and URLs which are already cached or being fetched are skipped.
+    /// A failing URL doesn't affect the other URLs of the batch.
+    /// This method returns once all URLs have been cached or failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `urls` - The URLs of the images to prefetch.
+    ///
+    /// # Returns
+    ///
+    /// The number of images which have been cached by this prefetch.
+    async fn prefetch(&self, urls: Vec<String>) -> usize;
+
+    /// Retrieve the statistics of the image cache.
+    fn cache_stats(&self) -> ImageCacheStats;
+
+    /// Remove all cached images from the image cache.
+    fn clear_cache(&self);
 }
 
 /// The DefaultImageLoader struct is an implementation of the ImageLoader trait and is responsible for loading image data from local or remote locations.
 /// This implementation is the default image loader used by the Popcorn FX library.
 ///
 /// Most methods implemented from the [ImageLoader] trait are asynchronous and return a Future that will resolve to the image data when it's available.
+///
+/// Concurrent requests for the same image url are de-duplicated, which results in a single download of the image.
 #[derive(Debug)]
 pub struct DefaultImageLoader {
-    client: Client,
-    cache_manager: Arc<CacheManager>,
+    client_factory: HttpClientFactory,
+    cache: Arc<ImageCache>,
+    /// The poster quality of the ui settings.
+    poster_quality: Arc<Mutex<PosterQualityState>>,
+    /// The image urls which are currently being fetched, together with the shared result of the fetch.
+    in_flight: Mutex<HashMap<String, Arc<OnceCell<Option<Vec<u8>>>>>>,
 }
 
 impl DefaultImageLoader {
@@ -89,20 +143,82 @@ impl DefaultImageLoader {
     ///
     /// # Arguments
     ///
-    /// * `cache_manager` - The cache manager for storing and retrieving image data.
+    /// * `cache` - The image cache for storing and retrieving image data.
+    /// * `client_factory` - The factory of the http client used to fetch remote images.
+    /// * `settings` - The application settings from which the poster quality is used.
     ///
     /// # Returns
     ///
     /// A new `DefaultImageLoader` instance.
-    pub fn new(cache_manager: Arc<CacheManager>) -> Self {
+    pub fn new(
+        cache: Arc<ImageCache>,
+        client_factory: HttpClientFactory,
+        settings: Arc<ApplicationConfig>,
+    ) -> Self {
+        let poster_quality = Arc::new(Mutex::new(PosterQualityState {
+            quality: settings.user_settings_ref().ui().poster_quality.clone(),
+            invalidated: false,
+        }));
+
+        let state = Arc::downgrade(&poster_quality);
+        settings.register(Box::new(move |event| {
+            if let ApplicationConfigEvent::UiSettingsChanged(settings) = event {
+                if let Some(state) = state.upgrade() {
+                    state.lock().unwrap().update(settings.poster_quality);
+                }
+            }
+        }));
+
         Self {
-            client: Client::builder().build().expect("expected a new client"),
-            cache_manager,
+            client_factory,
+            cache,
+            poster_quality,
+            in_flight: Default::default(),
         }
     }
 
+    /// Retrieve the poster url of the current poster quality for the given url.
+    /// The cached posters of the other qualities are removed when the quality has been changed.
+    fn poster_url(&self, url: &str) -> String {
+        let (quality, invalidated) = {
+            let mut state = self.poster_quality.lock().unwrap();
+            let invalidated = state.invalidated;
+            state.invalidated = false;
+            (state.quality.clone(), invalidated)
+        };
+
+        if invalidated {
+            debug!(
+                "Removing cached posters which don't match quality {}",
+                quality
+            );
+            self.cache.remove_matching(|key| {
+                [
+                    PosterQuality::Low,
+                    PosterQuality::Medium,
+                    PosterQuality::High,
+                ]
+                .iter()
+                .filter(|e| **e != quality)
+                .any(|e| e.is_variant(key))
+            });
+        }
+
+        quality.poster_url(url)
+    }
+
+    /// Prefetch the image data of the given URL.
+    ///
+    /// It returns `true` when the image data has been cached, else `false`.
+    async fn prefetch_image(&self, image_url: String) -> bool {
+        self.retrieve_image_data(image_url.as_str()).await.is_some()
+            && self.cache.contains(image_url.as_str())
+    }
+
     /// Retrieves the image data from the cache or fetches it remotely if not available in the cache.
     ///
+    /// Concurrent requests for the same URL share the result of a single retrieval.
+    ///
     /// # Arguments
     ///
     /// * `image_url` - The URL of the image to retrieve.
@@ -111,44 +227,90 @@ impl DefaultImageLoader {
     ///
     /// The image data as a `Vec<u8>`, or `None` if the data could not be retrieved.
     async fn retrieve_image_data(&self, image_url: &str) -> Option<Vec<u8>> {
+        let cell = self
+            .in_flight
+            .lock()
+            .unwrap()
+            .entry(image_url.to_string())
+            .or_default()
+            .clone();
+        let result = cell
+            .get_or_init(|| self.retrieve_cached_image_data(image_url))
+            .await
+            .clone();
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(image_url)
+            .map(|e| Arc::ptr_eq(e, &cell))
+            .unwrap_or(false)
+        {
+            in_flight.remove(image_url);
+        }
+
+        result
+    }
+
+    /// Retrieves the image data from the cache, the stale cached image data is revalidated with the server before being used.
+    async fn retrieve_cached_image_data(&self, image_url: &str) -> Option<Vec<u8>> {
+        let cached_image = self.cache.get(image_url);
+        if let Some(image) = cached_image.as_ref().filter(|e| e.fresh) {
+            return Some(image.data.clone());
+        }
+
         match self
-            .cache_manager
-            .operation()
-            .name(CACHE_NAME)
-            .key(image_url)
-            .options(CacheOptions {
-                cache_type: CacheType::CacheFirst,
-                expires_after: Duration::days(3),
-            })
-            .execute(self.fetch_remote_image_data(image_url))
+            .fetch_remote_image_data(image_url, cached_image.as_ref())
             .await
         {
-            Ok(e) => Some(e),
+            Ok(RemoteImage::Modified(data, policy)) => {
+                self.cache.store(image_url, data.as_slice(), &policy);
+                Some(data)
+            }
+            Ok(RemoteImage::NotModified(policy)) => {
+                debug!("Cached image of {} is still valid", image_url);
+                self.cache.refresh(image_url, &policy);
+                cached_image.map(|e| e.data)
+            }
             Err(e) => {
                 warn!("Failed to retrieve image data, {}", e);
-                None
+                cached_image.map(|e| {
+                    debug!("Using stale cached image of {}", image_url);
+                    e.data
+                })
             }
         }
     }
 
-    async fn fetch_remote_image_data(&self, image_url: &str) -> Result<Vec<u8>, ImageError> {
+    async fn fetch_remote_image_data(
+        &self,
+        image_url: &str,
+        cached_image: Option<&CachedImage>,
+    ) -> Result<RemoteImage, ImageError> {
         trace!("Parsing image url {}", image_url);
         let url = Url::parse(image_url)
             .map_err(|e| ImageError::ParseUrl(image_url.to_string(), e.to_string()))?;
 
         debug!("Retrieving image data from {:?}", url);
-        let response = self
-            .client
-            .get(url)
+        let mut request = self.client_factory.client().get(url);
+        if let Some(etag) = cached_image.and_then(|e| e.etag.as_ref()) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = cached_image.and_then(|e| e.last_modified.as_ref()) {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+        let response = request
             .send()
             .await
             .map_err(|e| ImageError::Load(e.to_string()))?;
 
         trace!("Retrieved image data with status {}", response.status());
-        if response.status().is_success() {
+        let policy = ImageCachePolicy::from_headers(response.headers());
+        if response.status() == StatusCode::NOT_MODIFIED && cached_image.is_some() {
+            Ok(RemoteImage::NotModified(policy))
+        } else if response.status().is_success() {
             debug!("Retrieved image data from {}", image_url);
             match response.bytes().await {
-                Ok(bytes) => Ok(bytes.to_vec()),
+                Ok(bytes) => Ok(RemoteImage::Modified(bytes.to_vec(), policy)),
                 Err(e) => Err(ImageError::Load(format!(
                     "failed to retrieve the image binary data, {}",
                     e
@@ -190,9 +352,9 @@ impl ImageLoader for DefaultImageLoader {
 
     async fn load_poster(&self, media: &Box<dyn MediaOverview>) -> Vec<u8> {
         trace!("Loading poster image for {:?}", media);
-        let poster_url = media.images().poster();
+        let poster_url = self.poster_url(media.images().poster());
 
-        self.retrieve_image_data(poster_url)
+        self.retrieve_image_data(poster_url.as_str())
             .await
             .or_else(|| Some(POSTER_PLACEHOLDER.to_vec()))
             .unwrap()
@@ -202,15 +364,130 @@ impl ImageLoader for DefaultImageLoader {
         trace!("Loading image data from url for {}", url);
         self.retrieve_image_data(url).await
     }
+
+    async fn load_resized(&self, url: &str, size: ImageSize) -> Option<Vec<u8>> {
+        if size.is_unbounded() {
+            return self.load(url).await;
+        }
+
+        trace!("Loading image data from url {} with size {}", url, size);
+        let cache_key = size.cache_key(url);
+        if let Some(image) = self.cache.get(cache_key.as_str()).filter(|e| e.fresh) {
+            return Some(image.data);
+        }
+
+        let data = self.retrieve_image_data(url).await?;
+        let result = tokio::task::spawn_blocking(move || {
+            let resized = resize_image(data.as_slice(), &size);
+            (data, resized)
+        })
+        .await;
+
+        match result {
+            Ok((_, Ok(resized))) => {
+                self.cache.store(
+                    cache_key.as_str(),
+                    resized.as_slice(),
+                    &ImageCachePolicy::default(),
+                );
+                Some(resized)
+            }
+            Ok((data, Err(e))) => {
+                warn!("Failed to resize image {}, using original data, {}", url, e);
+                Some(data)
+            }
+            Err(e) => {
+                warn!("Failed to resize image {}, {}", url, e);
+                None
+            }
+        }
+    }
+
+    async fn prefetch(&self, urls: Vec<String>) -> usize {
+        let remaining = PREFETCH_CACHE_LIMIT.saturating_sub(self.cache.len());
+        let mut unique_urls = HashSet::new();
+        let mut pending = vec![];
+
+        for url in urls.into_iter().filter(|e| !e.is_empty()) {
+            if !unique_urls.insert(url.clone()) || self.cache.contains(url.as_str()) {
+                continue;
+            }
+            if pending.len() >= remaining {
+                warn!(
+                    "Image cache limit of {} has been reached, skipping remaining prefetch urls",
+                    PREFETCH_CACHE_LIMIT
+                );
+                break;
+            }
+            if self.in_flight.lock().unwrap().contains_key(&url) {
+                trace!("Image {} is already being fetched", url);
+            } else {
+                pending.push(url);
+            }
+        }
+
+        debug!("Prefetching {} images", pending.len());
+        let cached = futures::stream::iter(pending)
+            .map(|url| self.prefetch_image(url))
+            .buffer_unordered(PREFETCH_CONCURRENCY)
+            .filter(|e| futures::future::ready(*e))
+            .count()
+            .await;
+
+        info!("Prefetched {} images", cached);
+        cached
+    }
+
+    fn cache_stats(&self) -> ImageCacheStats {
+        self.cache.stats()
+    }
+
+    fn clear_cache(&self) {
+        info!("Clearing the image cache");
+        self.cache.clear();
+    }
+}
+
+/// The result of fetching a remote image.
+#[derive(Debug)]
+enum RemoteImage {
+    /// The image data has been retrieved.
+    Modified(Vec<u8>, ImageCachePolicy),
+    /// The cached image is still valid.
+    NotModified(ImageCachePolicy),
+}
+
+/// The poster quality which is used by the image loader.
+#[derive(Debug)]
+struct PosterQualityState {
+    quality: PosterQuality,
+    /// Indicates if the cached posters of the other qualities still need to be removed.
+    invalidated: bool,
+}
+
+impl PosterQualityState {
+    fn update(&mut self, quality: PosterQuality) {
+        if self.quality != quality {
+            trace!(
+                "Updating poster quality from {} to {}",
+                self.quality,
+                quality
+            );
+            self.quality = quality;
+            self.invalidated = true;
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use httpmock::Method::GET;
     use httpmock::MockServer;
+    use image::GenericImageView;
     use tempfile::tempdir;
     use tokio::runtime::Runtime;
 
+    use crate::core::images::DEFAULT_IMAGE_CACHE_SIZE;
     use crate::core::media::{Images, MovieOverview, ShowOverview};
     use crate::testing::{init_logger, read_test_file_to_bytes};
 
@@ -221,8 +498,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
 
         assert_eq!(POSTER_PLACEHOLDER.to_vec(), loader.default_poster())
     }
@@ -249,8 +527,9 @@ mod test {
                 banner: "".to_string(),
             },
         }) as Box<dyn MediaOverview>;
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
         let runtime = Runtime::new().unwrap();
 
         let (result, _) =
@@ -275,8 +554,9 @@ mod test {
                 banner: "".to_string(),
             },
         }) as Box<dyn MediaOverview>;
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
         let runtime = Runtime::new().unwrap();
 
         let (result, _) =
@@ -306,8 +586,9 @@ mod test {
                 banner: "".to_string(),
             },
         }) as Box<dyn MediaOverview>;
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
         let runtime = Runtime::new().unwrap();
 
         let (result, _) =
@@ -340,8 +621,9 @@ mod test {
             },
             rating: None,
         }) as Box<dyn MediaOverview>;
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
         let runtime = Runtime::new().unwrap();
 
         let (result, _) =
@@ -350,6 +632,56 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_load_poster_quality_changed() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/poster.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.png").as_slice());
+        });
+        let media = Box::new(MovieOverview {
+            title: "lorem ipsum".to_string(),
+            imdb_id: "tt121212".to_string(),
+            year: "2010".to_string(),
+            rating: None,
+            images: Images {
+                poster: server.url("/poster.png"),
+                fanart: "".to_string(),
+                banner: "".to_string(),
+            },
+        }) as Box<dyn MediaOverview>;
+        let medium_url = "http://image.tmdb.org/t/p/w500/poster.jpg";
+        let high_url = "http://image.tmdb.org/t/p/w780/poster.jpg";
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(
+            cache.clone(),
+            HttpClientFactory::default(),
+            settings.clone(),
+        );
+        let runtime = Runtime::new().unwrap();
+
+        cache.store(medium_url, &[1, 2, 3], &ImageCachePolicy::default());
+        cache.store(high_url, &[4, 5, 6], &ImageCachePolicy::default());
+        let mut ui_settings = settings.user_settings().ui().clone();
+        ui_settings.poster_quality = PosterQuality::High;
+        settings.update_ui(ui_settings);
+
+        runtime.block_on(loader.load_poster(&media));
+        let medium = cache.contains(medium_url);
+        let high = cache.contains(high_url);
+
+        assert_eq!(
+            false, medium,
+            "expected the posters of the previous quality to have been removed"
+        );
+        assert_eq!(true, high);
+    }
+
     #[test]
     fn test_load_url() {
         init_logger();
@@ -362,8 +694,9 @@ mod test {
             then.status(200).body(expected_result.as_slice());
         });
         let url = server.url("/my-image.png");
-        let cache_manager = Arc::new(CacheManager::builder().storage_path(temp_path).build());
-        let loader = DefaultImageLoader::new(cache_manager);
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
         let runtime = Runtime::new().unwrap();
 
         let (result, _) =
@@ -371,4 +704,233 @@ mod test {
 
         assert_eq!(Some(expected_result), result)
     }
+
+    #[test]
+    fn test_load_url_cached() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let expected_result = read_test_file_to_bytes("image.png");
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/my-image.png");
+            then.status(200).body(expected_result.as_slice());
+        });
+        let url = server.url("/my-image.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let result = runtime.block_on(loader.load(url.as_str()));
+        assert_eq!(Some(expected_result.clone()), result);
+
+        let result = runtime.block_on(loader.load(url.as_str()));
+        assert_eq!(Some(expected_result), result);
+        mock.assert_hits(1);
+        assert_eq!(1, loader.cache_stats().hits);
+    }
+
+    #[test]
+    fn test_load_resized() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/fanart.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.png").as_slice());
+        });
+        let url = server.url("/fanart.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let result = runtime
+            .block_on(loader.load_resized(url.as_str(), ImageSize::new(480, 0)))
+            .expect("expected the image data to have been returned");
+        let image = image::load_from_memory(result.as_slice()).unwrap();
+        assert_eq!((480, 270), image.dimensions());
+
+        let result = runtime
+            .block_on(loader.load_resized(url.as_str(), ImageSize::new(0, 540)))
+            .expect("expected the image data to have been returned");
+        let image = image::load_from_memory(result.as_slice()).unwrap();
+        assert_eq!((960, 540), image.dimensions());
+        mock.assert_hits(1);
+    }
+
+    #[test]
+    fn test_load_resized_cached() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/poster.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.png").as_slice());
+        });
+        let url = server.url("/poster.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let expected_result = runtime
+            .block_on(loader.load_resized(url.as_str(), ImageSize::new(480, 0)))
+            .expect("expected the image data to have been returned");
+        let result = runtime.block_on(loader.load_resized(url.as_str(), ImageSize::new(480, 0)));
+
+        assert_eq!(Some(expected_result), result);
+        mock.assert_hits(1);
+        assert_eq!(1, loader.cache_stats().hits);
+        assert_eq!(2, loader.cache_stats().entries);
+    }
+
+    #[test]
+    fn test_load_resized_invalid_image() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/image.png");
+            then.status(200).body("lorem ipsum");
+        });
+        let url = server.url("/image.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let result = runtime.block_on(loader.load_resized(url.as_str(), ImageSize::new(200, 0)));
+
+        assert_eq!(Some("lorem ipsum".as_bytes().to_vec()), result);
+        assert_eq!(1, loader.cache_stats().entries);
+    }
+
+    #[test]
+    fn test_load_url_concurrent_requests() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let expected_result = read_test_file_to_bytes("image.png");
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/fanart.png");
+            then.status(200)
+                .delay(std::time::Duration::from_millis(100))
+                .body(expected_result.as_slice());
+        });
+        let url = server.url("/fanart.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let result = runtime.block_on(futures::future::join_all(
+            (0..10).map(|_| loader.load(url.as_str())),
+        ));
+
+        assert_eq!(10, result.len());
+        assert!(result.iter().all(|e| e.as_ref() == Some(&expected_result)));
+        mock.assert_hits(1);
+    }
+
+    #[test]
+    fn test_load_url_revalidate() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let image = read_test_file_to_bytes("image.png");
+        let revalidate_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/poster.png")
+                .header("if-none-match", "\"lorem\"");
+            then.status(304).header("cache-control", "max-age=600");
+        });
+        let url = server.url("/poster.png");
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache.clone(), HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+        cache.store(
+            url.as_str(),
+            image.as_slice(),
+            &ImageCachePolicy {
+                store: true,
+                max_age: chrono::Duration::zero(),
+                etag: Some("\"lorem\"".to_string()),
+                last_modified: None,
+            },
+        );
+
+        let result = runtime.block_on(loader.load(url.as_str()));
+
+        assert_eq!(Some(image), result);
+        revalidate_mock.assert_hits(1);
+        assert_eq!(true, cache.contains(url.as_str()));
+        assert_eq!(1, loader.cache_stats().revalidations);
+    }
+
+    #[test]
+    fn test_clear_cache() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let url = "http://localhost/poster.png";
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache.clone(), HttpClientFactory::default(), settings);
+        cache.store(url, &[1, 2, 3], &ImageCachePolicy::default());
+
+        loader.clear_cache();
+
+        assert_eq!(false, cache.contains(url));
+        assert_eq!(0, loader.cache_stats().entries);
+    }
+
+    #[test]
+    fn test_prefetch() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let image = read_test_file_to_bytes("image.png");
+        let poster_mock = server.mock(|when, then| {
+            when.method(GET).path("/poster.png");
+            then.status(200).body(image.as_slice());
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/invalid.png");
+            then.status(500).body("");
+        });
+        let poster_url = server.url("/poster.png");
+        let urls = vec![
+            poster_url.clone(),
+            server.url("/invalid.png"),
+            poster_url.clone(),
+        ];
+        let cache = Arc::new(ImageCache::new(temp_path, DEFAULT_IMAGE_CACHE_SIZE));
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let loader = DefaultImageLoader::new(cache, HttpClientFactory::default(), settings);
+        let runtime = Runtime::new().unwrap();
+
+        let (result, loader) =
+            runtime.block_on(async move { (loader.prefetch(urls).await, loader) });
+        assert_eq!(
+            1, result,
+            "expected only the valid image to have been cached"
+        );
+        poster_mock.assert_hits(1);
+
+        let (result, _) =
+            runtime.block_on(async move { (loader.prefetch(vec![poster_url]).await, loader) });
+        assert_eq!(0, result, "expected the cached image to have been skipped");
+        poster_mock.assert_hits(1);
+    }
 }
@@ -1,5 +1,9 @@
+pub use cache::*;
 pub use error::*;
 pub use loader::*;
+pub use resize::*;
 
+mod cache;
 mod error;
 mod loader;
+mod resize;
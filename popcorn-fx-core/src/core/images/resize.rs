@@ -0,0 +1,185 @@
+use std::fmt::{Display, Formatter};
+use std::io::Cursor;
+
+use image::imageops::FilterType;
+use image::{GenericImageView, ImageFormat};
+use log::{debug, trace};
+
+use crate::core::images::ImageError;
+
+/// The filter which is used for resizing images.
+const RESIZE_FILTER: FilterType = FilterType::CatmullRom;
+
+/// The bounds within which a resized image should fit.
+///
+/// A dimension of [None] is unbounded, the aspect ratio of the original image is always preserved.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ImageSize {
+    /// The maximum width of the resized image.
+    pub width: Option<u32>,
+    /// The maximum height of the resized image.
+    pub height: Option<u32>,
+}
+
+impl ImageSize {
+    /// Create a new image size from the given C compatible dimensions, where `0` is unbounded.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width: Some(width).filter(|e| *e > 0),
+            height: Some(height).filter(|e| *e > 0),
+        }
+    }
+
+    /// Verify if both dimensions of this size are unbounded.
+    pub fn is_unbounded(&self) -> bool {
+        self.width.is_none() && self.height.is_none()
+    }
+
+    /// Retrieve the cache key of the resized variant of the given image url.
+    pub fn cache_key(&self, url: &str) -> String {
+        format!("{}#size={}", url, self)
+    }
+}
+
+impl Display for ImageSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let dimension = |e: Option<u32>| e.map(|e| e.to_string()).unwrap_or("auto".to_string());
+        write!(f, "{}x{}", dimension(self.width), dimension(self.height))
+    }
+}
+
+/// Resize the given image data to fit within the given size.
+///
+/// JPEG images are re-encoded as JPEG, all other formats are re-encoded as PNG to preserve their transparency.
+/// Images are never upscaled, the original data is returned when the image already fits within the given size.
+///
+/// # Arguments
+///
+/// * `data` - The encoded image data (jpeg, png or webp).
+/// * `size` - The bounds within which the resized image should fit.
+///
+/// # Returns
+///
+/// The encoded resized image data, or an [ImageError] when the image couldn't be decoded or encoded.
+pub fn resize_image(data: &[u8], size: &ImageSize) -> Result<Vec<u8>, ImageError> {
+    let format = image::guess_format(data).map_err(|e| ImageError::Decode(e.to_string()))?;
+    let image = image::load_from_memory_with_format(data, format)
+        .map_err(|e| ImageError::Decode(e.to_string()))?;
+    let (width, height) = image.dimensions();
+    let max_width = size.width.unwrap_or(u32::MAX);
+    let max_height = size.height.unwrap_or(u32::MAX);
+
+    if width <= max_width && height <= max_height {
+        trace!("Image of {}x{} already fits within {}", width, height, size);
+        return Ok(data.to_vec());
+    }
+
+    let resized = image.resize(max_width, max_height, RESIZE_FILTER);
+    let output_format = match format {
+        ImageFormat::Jpeg => ImageFormat::Jpeg,
+        _ => ImageFormat::Png,
+    };
+    let mut buffer = Cursor::new(vec![]);
+    resized
+        .write_to(&mut buffer, output_format)
+        .map_err(|e| ImageError::Encode(e.to_string()))?;
+
+    debug!(
+        "Resized image from {}x{} to {}x{}",
+        width,
+        height,
+        resized.width(),
+        resized.height()
+    );
+    Ok(buffer.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::{init_logger, read_test_file_to_bytes};
+
+    use super::*;
+
+    #[test]
+    fn test_image_size_new() {
+        assert_eq!(
+            ImageSize {
+                width: Some(200),
+                height: None,
+            },
+            ImageSize::new(200, 0)
+        );
+        assert_eq!(true, ImageSize::new(0, 0).is_unbounded());
+    }
+
+    #[test]
+    fn test_image_size_cache_key() {
+        let size = ImageSize::new(200, 0);
+
+        let result = size.cache_key("http://localhost/poster.png");
+
+        assert_eq!("http://localhost/poster.png#size=200xauto", result)
+    }
+
+    #[test]
+    fn test_resize_image() {
+        init_logger();
+        let data = read_test_file_to_bytes("image.png");
+
+        let result = resize_image(data.as_slice(), &ImageSize::new(480, 0))
+            .expect("expected the image to have been resized");
+        let image = image::load_from_memory(result.as_slice()).unwrap();
+
+        assert_eq!((480, 270), image.dimensions());
+        assert_eq!(
+            ImageFormat::Png,
+            image::guess_format(result.as_slice()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resize_image_jpeg() {
+        init_logger();
+        let data = read_test_file_to_bytes("image.png");
+        let mut jpeg = Cursor::new(vec![]);
+        image::load_from_memory(data.as_slice())
+            .unwrap()
+            .to_rgb8()
+            .write_to(&mut jpeg, ImageFormat::Jpeg)
+            .unwrap();
+
+        let result = resize_image(jpeg.get_ref().as_slice(), &ImageSize::new(0, 540))
+            .expect("expected the image to have been resized");
+        let image = image::load_from_memory(result.as_slice()).unwrap();
+
+        assert_eq!((960, 540), image.dimensions());
+        assert_eq!(
+            ImageFormat::Jpeg,
+            image::guess_format(result.as_slice()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resize_image_no_upscale() {
+        init_logger();
+        let data = read_test_file_to_bytes("image.png");
+
+        let result = resize_image(data.as_slice(), &ImageSize::new(3840, 2160)).unwrap();
+
+        assert_eq!(data, result)
+    }
+
+    #[test]
+    fn test_resize_image_invalid_data() {
+        init_logger();
+        let data = "lorem ipsum".as_bytes();
+
+        let result = resize_image(data, &ImageSize::new(200, 200));
+
+        assert!(
+            result.is_err(),
+            "expected an error to have been returned, got {:?} instead",
+            result
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
+use std::mem;
 use std::path::{Path, PathBuf};
 
 use log::{debug, trace, warn};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::core::updater::UpdateChannel;
 use crate::VERSION;
 
 const FILENAME: &str = "launcher";
@@ -38,6 +40,7 @@ pub enum LauncherError {
 ///
 /// `LauncherOptions` is a struct that contains options used to bootstrap an application. It includes the application version to launch,
 /// the default Java Virtual Machine (JVM) runtime version to use, and the JVM arguments to apply to the application.
+/// It also keeps track of the previously launched version, which allows an update to be rolled back.
 ///
 /// # Examples
 ///
@@ -48,6 +51,7 @@ pub enum LauncherError {
 ///     version: "1.0.0".to_string(),
 ///     runtime_version: "11".to_string(),
 ///     vm_args: vec!["-Xms512m".to_string(), "-Xmx1024m".to_string()],
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -61,6 +65,15 @@ pub struct LauncherOptions {
     /// The JVM arguments to apply to the application.
     #[serde(default = "DEFAULT_VM_ARGS")]
     pub vm_args: Vec<String>,
+    /// The application version which was launched before the current version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_version: Option<String>,
+    /// The JVM runtime version which was used before the current runtime version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_runtime_version: Option<String>,
+    /// The update channel from which new versions are received.
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
 }
 
 impl LauncherOptions {
@@ -117,6 +130,74 @@ impl LauncherOptions {
             .map_err(|e| LauncherError::IoError(e.to_string()))
     }
 
+    /// Activate the given application and runtime version as the version to launch.
+    ///
+    /// The currently active version is kept as the previous version, so it can be restored through [LauncherOptions::rollback].
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The application version to activate.
+    /// * `runtime_version` - The JVM runtime version to activate.
+    pub fn activate(&mut self, version: &str, runtime_version: &str) {
+        if self.version != version {
+            trace!(
+                "Activating version {}, previous version {}",
+                version,
+                self.version
+            );
+            self.previous_version = Some(mem::replace(&mut self.version, version.to_string()));
+            self.previous_runtime_version = Some(mem::replace(
+                &mut self.runtime_version,
+                runtime_version.to_string(),
+            ));
+        } else {
+            self.runtime_version = runtime_version.to_string();
+        }
+    }
+
+    /// Switch the active version back to the previous version.
+    ///
+    /// The current version becomes the previous version, which allows the rollback to be undone.
+    ///
+    /// # Returns
+    ///
+    /// It returns `true` when the active version has been switched, else `false` when no previous version is known.
+    pub fn rollback(&mut self) -> bool {
+        match self.previous_version.take() {
+            Some(previous_version) => {
+                let previous_runtime_version = self
+                    .previous_runtime_version
+                    .take()
+                    .unwrap_or_else(|| self.runtime_version.clone());
+
+                debug!(
+                    "Rolling back version {} to {}",
+                    self.version, previous_version
+                );
+                self.previous_version = Some(mem::replace(&mut self.version, previous_version));
+                self.previous_runtime_version = Some(mem::replace(
+                    &mut self.runtime_version,
+                    previous_runtime_version,
+                ));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Retrieve the application versions which should be retained within the data directory.
+    ///
+    /// These are the active version and, if known, the previous version.
+    pub fn retained_versions(&self) -> Vec<&str> {
+        let mut versions = vec![self.version.as_str()];
+
+        if let Some(previous_version) = self.previous_version.as_ref() {
+            versions.push(previous_version.as_str());
+        }
+
+        versions
+    }
+
     /// Retrieve the default filename for the launcher options configuration file.
     pub fn filename() -> String {
         format!("{}.{}", FILENAME, &EXTENSIONS[0])
@@ -157,6 +238,9 @@ impl Default for LauncherOptions {
             version: DEFAULT_VERSION(),
             runtime_version: DEFAULT_RUNTIME_VERSION(),
             vm_args: DEFAULT_VM_ARGS(),
+            previous_version: None,
+            previous_runtime_version: None,
+            update_channel: Default::default(),
         }
     }
 }
@@ -195,6 +279,9 @@ mod test {
             version: "0.1.0".to_string(),
             runtime_version: "17.0.0".to_string(),
             vm_args: vec!["test".to_string()],
+            previous_version: Some("0.0.9".to_string()),
+            previous_runtime_version: None,
+            update_channel: UpdateChannel::Beta,
         };
 
         let options = LauncherOptions::from(
@@ -203,6 +290,8 @@ version: 0.1.0
 runtime_version: 17.0.0
 vm_args:
     - test
+previous_version: 0.0.9
+update_channel: beta
         "#,
         );
 
@@ -219,6 +308,9 @@ vm_args:
             version: "99.0.0".to_string(),
             runtime_version: "101.0.0".to_string(),
             vm_args: vec!["lorem".to_string(), "ipsum".to_string()],
+            previous_version: None,
+            previous_runtime_version: None,
+            update_channel: UpdateChannel::Stable,
         };
 
         let result = LauncherOptions::new(Path::new(temp_path));
@@ -248,4 +340,80 @@ vm_args:
 
         assert_eq!(expected_result.to_str().unwrap(), result.as_str())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_activate() {
+        let mut options = LauncherOptions {
+            version: "1.0.0".to_string(),
+            runtime_version: "21.0.0".to_string(),
+            ..Default::default()
+        };
+
+        options.activate("1.1.0", "21.0.3");
+
+        assert_eq!("1.1.0".to_string(), options.version);
+        assert_eq!("21.0.3".to_string(), options.runtime_version);
+        assert_eq!(Some("1.0.0".to_string()), options.previous_version);
+        assert_eq!(Some("21.0.0".to_string()), options.previous_runtime_version);
+    }
+
+    #[test]
+    fn test_activate_same_version() {
+        let mut options = LauncherOptions {
+            version: "1.0.0".to_string(),
+            runtime_version: "21.0.0".to_string(),
+            previous_version: Some("0.9.0".to_string()),
+            previous_runtime_version: Some("21.0.0".to_string()),
+            ..Default::default()
+        };
+
+        options.activate("1.0.0", "21.0.3");
+
+        assert_eq!("1.0.0".to_string(), options.version);
+        assert_eq!("21.0.3".to_string(), options.runtime_version);
+        assert_eq!(Some("0.9.0".to_string()), options.previous_version);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut options = LauncherOptions {
+            version: "1.1.0".to_string(),
+            runtime_version: "21.0.3".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            previous_runtime_version: Some("21.0.0".to_string()),
+            ..Default::default()
+        };
+
+        let result = options.rollback();
+
+        assert_eq!(true, result, "expected the rollback to have been executed");
+        assert_eq!("1.0.0".to_string(), options.version);
+        assert_eq!("21.0.0".to_string(), options.runtime_version);
+        assert_eq!(Some("1.1.0".to_string()), options.previous_version);
+        assert_eq!(Some("21.0.3".to_string()), options.previous_runtime_version);
+    }
+
+    #[test]
+    fn test_rollback_without_previous_version() {
+        let mut options = LauncherOptions::default();
+        let expected_result = options.clone();
+
+        let result = options.rollback();
+
+        assert_eq!(false, result, "expected the rollback to have been ignored");
+        assert_eq!(expected_result, options);
+    }
+
+    #[test]
+    fn test_retained_versions() {
+        let options = LauncherOptions {
+            version: "1.1.0".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            ..Default::default()
+        };
+
+        let result = options.retained_versions();
+
+        assert_eq!(vec!["1.1.0", "1.0.0"], result);
+    }
+}
@@ -1,6 +1,11 @@
+use std::path::Path;
 use std::sync::Weak;
 
+use percent_encoding::percent_decode_str;
+use url::Url;
+
 use crate::core::media::{MediaIdentifier, TorrentInfo};
+use crate::core::players::MediaTrack;
 use crate::core::playlists::PlaylistItem;
 use crate::core::subtitles::model::Subtitle;
 use crate::core::torrents::{Torrent, TorrentFileInfo, TorrentStream};
@@ -10,6 +15,9 @@ use crate::core::torrents::{Torrent, TorrentFileInfo, TorrentStream};
 /// This struct is used to provide loading data for a media item. Either a `url` or an `media` is always present
 /// to specify the source of the media item. Additionally, it may contain optional information about the media
 /// torrent, torrent stream, or other related data.
+///
+/// When `download_only` is set, the torrent is only downloaded to the local system and no stream or playback is started.
+/// The torrent keeps downloading in the background and can still be streamed later on, reusing the downloaded pieces.
 #[derive(Debug)]
 pub struct LoadingData {
     pub url: Option<String>,
@@ -22,11 +30,40 @@ pub struct LoadingData {
     pub torrent_file_info: Option<TorrentFileInfo>,
     pub quality: Option<String>,
     pub auto_resume_timestamp: Option<u64>,
+    pub auto_resume_progress: Option<f32>,
     pub subtitles_enabled: Option<bool>,
     pub subtitle: Option<Subtitle>,
     pub media_torrent_info: Option<TorrentInfo>,
     pub torrent: Option<Weak<Box<dyn Torrent>>>,
     pub torrent_stream: Option<Weak<Box<dyn TorrentStream>>>,
+    pub audio_tracks: Option<Vec<MediaTrack>>,
+    pub download_only: bool,
+}
+
+impl LoadingData {
+    /// Retrieve the filename of the media url, if any.
+    ///
+    /// The filename is the last segment of the url path, or the filename of a local path.
+    ///
+    /// # Returns
+    ///
+    /// It returns the decoded filename when it could be determined, else [None].
+    pub fn url_filename(&self) -> Option<String> {
+        let url = self.url.as_ref()?;
+
+        match Url::parse(url.as_str()) {
+            // single letter schemes are windows drive letters of a local path
+            Ok(e) if e.scheme().len() > 1 => e
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|e| !e.is_empty())
+                .map(|e| percent_decode_str(e).decode_utf8_lossy().to_string()),
+            _ => Path::new(url.as_str())
+                .file_name()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_string()),
+        }
+    }
 }
 
 impl PartialEq for LoadingData {
@@ -41,8 +78,11 @@ impl PartialEq for LoadingData {
             && self.torrent_file_info == other.torrent_file_info
             && self.quality == other.quality
             && self.auto_resume_timestamp == other.auto_resume_timestamp
+            && self.auto_resume_progress == other.auto_resume_progress
             && self.torrent.is_some() == other.torrent.is_some()
             && self.torrent_stream.is_some() == other.torrent_stream.is_some()
+            && self.audio_tracks == other.audio_tracks
+            && self.download_only == other.download_only
     }
 
     fn ne(&self, other: &Self) -> bool {
@@ -72,11 +112,14 @@ impl Clone for LoadingData {
             torrent_file_info: self.torrent_file_info.clone(),
             quality: self.quality.clone(),
             auto_resume_timestamp: self.auto_resume_timestamp,
+            auto_resume_progress: self.auto_resume_progress,
             subtitles_enabled: self.subtitles_enabled,
             subtitle: self.subtitle.clone(),
             media_torrent_info: self.media_torrent_info.clone(),
             torrent: self.torrent.clone(),
             torrent_stream: self.torrent_stream.clone(),
+            audio_tracks: self.audio_tracks.clone(),
+            download_only: self.download_only,
         }
     }
 }
@@ -94,11 +137,14 @@ impl From<&str> for LoadingData {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: None,
+            auto_resume_progress: None,
             subtitles_enabled: None,
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         }
     }
 }
@@ -116,11 +162,14 @@ impl From<PlaylistItem> for LoadingData {
             torrent_file_info: value.torrent_file_info,
             quality: value.quality,
             auto_resume_timestamp: value.auto_resume_timestamp,
+            auto_resume_progress: None,
             subtitles_enabled: Some(value.subtitles_enabled),
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         }
     }
 }
@@ -165,4 +214,22 @@ mod tests {
         assert_eq!(Some(thumb.to_string()), result.thumb);
         assert_eq!(Some(quality.to_string()), result.quality);
     }
+
+    #[test]
+    fn test_url_filename() {
+        assert_eq!(
+            Some("lorem ipsum.mkv".to_string()),
+            LoadingData::from("https://localhost:8080/media/lorem%20ipsum.mkv?token=123")
+                .url_filename()
+        );
+        assert_eq!(
+            Some("ipsum.mp4".to_string()),
+            LoadingData::from("file:///tmp/ipsum.mp4").url_filename()
+        );
+        assert_eq!(
+            Some("dolor.mp4".to_string()),
+            LoadingData::from("/tmp/videos/dolor.mp4").url_filename()
+        );
+        assert_eq!(None, LoadingData::from("https://localhost/").url_filename());
+    }
 }
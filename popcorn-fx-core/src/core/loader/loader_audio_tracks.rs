@@ -0,0 +1,200 @@
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use derive_more::Display;
+use log::{debug, trace, warn};
+use tokio_util::sync::CancellationToken;
+
+use crate::core::loader::matroska::probe_audio_tracks;
+use crate::core::loader::{
+    CancellationResult, LoadingData, LoadingEvent, LoadingResult, LoadingStrategy,
+};
+use crate::core::players::MediaTrack;
+use crate::core::torrents::TorrentStream;
+
+/// The number of bytes at the start of the media file which are probed for the container metadata.
+const PROBE_HEADER_SIZE: u64 = 256 * 1024;
+/// The interval in bytes at which the availability of the header bytes is verified.
+const PROBE_AVAILABILITY_INTERVAL: u64 = 16 * 1024;
+/// The number of times the availability of the header bytes is verified before giving up.
+const PROBE_AVAILABILITY_ATTEMPTS: u32 = 10;
+/// The delay between the availability verifications of the header bytes.
+const PROBE_AVAILABILITY_DELAY: Duration = Duration::from_millis(500);
+
+/// A loading strategy which extracts the audio tracks from the container of a torrent stream.
+///
+/// The container is probed once the header bytes of the stream have been buffered.
+/// Probing failures don't abort the loading process, the media is played without any known audio tracks instead.
+#[derive(Display, Default)]
+#[display(fmt = "Audio tracks loading strategy")]
+pub struct AudioTracksLoadingStrategy {}
+
+impl AudioTracksLoadingStrategy {
+    /// Creates a new instance of `AudioTracksLoadingStrategy`.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Wait for the header bytes of the given stream to become available.
+    ///
+    /// It returns `true` when the header bytes are available, else `false`.
+    async fn wait_for_header(
+        stream: &Box<dyn TorrentStream>,
+        cancel_token: &CancellationToken,
+    ) -> bool {
+        let bytes: Vec<u64> = (0..PROBE_HEADER_SIZE)
+            .step_by(PROBE_AVAILABILITY_INTERVAL as usize)
+            .collect();
+
+        for attempt in 0..PROBE_AVAILABILITY_ATTEMPTS {
+            if stream.has_bytes(&bytes[..]) {
+                return true;
+            }
+            if attempt == 0 {
+                trace!(
+                    "Prioritizing the header bytes of {}",
+                    stream.stream_handle()
+                );
+                stream.prioritize_bytes(&bytes[..]);
+            }
+
+            tokio::select! {
+                _ = cancel_token.cancelled() => return false,
+                _ = tokio::time::sleep(PROBE_AVAILABILITY_DELAY) => {},
+            }
+        }
+
+        false
+    }
+
+    fn probe(path: &Path) -> std::io::Result<Vec<MediaTrack>> {
+        let mut header = vec![];
+        File::open(path)?
+            .take(PROBE_HEADER_SIZE)
+            .read_to_end(&mut header)?;
+
+        probe_audio_tracks(&header)
+    }
+}
+
+impl Debug for AudioTracksLoadingStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AudioTracksLoadingStrategy").finish()
+    }
+}
+
+#[async_trait]
+impl LoadingStrategy for AudioTracksLoadingStrategy {
+    async fn process(
+        &self,
+        mut data: LoadingData,
+        _: Sender<LoadingEvent>,
+        cancel_token: CancellationToken,
+    ) -> LoadingResult {
+        if data.audio_tracks.is_some() {
+            trace!("Audio tracks are already known for {:?}", data);
+            return LoadingResult::Ok(data);
+        }
+
+        if let Some(stream) = data.torrent_stream.as_ref().and_then(|e| e.upgrade()) {
+            if !Self::wait_for_header(&stream, &cancel_token).await {
+                warn!(
+                    "Unable to probe audio tracks, header of {} is not available",
+                    stream.stream_handle()
+                );
+                return LoadingResult::Ok(data);
+            }
+
+            let path = stream.file();
+            match Self::probe(path.as_path()) {
+                Ok(tracks) => {
+                    debug!("Probed {} audio tracks from {:?}", tracks.len(), path);
+                    data.audio_tracks = Some(tracks);
+                }
+                Err(e) => debug!("Unable to probe audio tracks of {:?}, {}", path, e),
+            }
+        }
+
+        LoadingResult::Ok(data)
+    }
+
+    async fn cancel(&self, mut data: LoadingData) -> CancellationResult {
+        data.audio_tracks = None;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+    use std::sync::Arc;
+
+    use tempfile::tempdir;
+
+    use crate::core::block_in_place;
+    use crate::core::playlists::PlaylistItem;
+    use crate::testing::{copy_test_file, init_logger, MockTorrentStream};
+
+    use super::*;
+
+    #[test]
+    fn test_process() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = copy_test_file(temp_path, "two-audio-tracks.mkv", None);
+        let mut stream = MockTorrentStream::new();
+        stream.expect_has_bytes().return_const(true);
+        stream
+            .expect_file()
+            .returning(move || Path::new(filepath.as_str()).to_path_buf());
+        let stream = Arc::new(Box::new(stream) as Box<dyn TorrentStream>);
+        let mut data = LoadingData::from(PlaylistItem {
+            url: None,
+            title: "MyStream".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        });
+        data.torrent_stream = Some(Arc::downgrade(&stream));
+        let (tx, _) = channel();
+        let strategy = AudioTracksLoadingStrategy::new();
+
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            let tracks = result.audio_tracks.expect("expected audio tracks");
+            assert_eq!(2, tracks.len());
+            assert_eq!(Some("jpn".to_string()), tracks[1].language);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_no_torrent_stream() {
+        init_logger();
+        let data = LoadingData::from("http://localhost:8080/my-video.mkv");
+        let (tx, _) = channel();
+        let strategy = AudioTracksLoadingStrategy::new();
+
+        let result = block_in_place(strategy.process(data.clone(), tx, CancellationToken::new()));
+
+        assert_eq!(LoadingResult::Ok(data), result);
+    }
+}
@@ -10,7 +10,7 @@ use tokio_util::sync::CancellationToken;
 use crate::core::loader::{
     CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult, LoadingStrategy,
 };
-use crate::core::media::resume::AutoResumeService;
+use crate::core::media::resume::{AutoResume, AutoResumeService};
 
 /// Represents a strategy for loading auto resume timestamps.
 #[derive(Display)]
@@ -61,6 +61,7 @@ impl LoadingStrategy for AutoResumeLoadingStrategy {
     ) -> LoadingResult {
         trace!("Processing auto resume timestamp for {:?}", data);
         let mut id: Option<&str> = None;
+        let url_id: Option<String>;
         let filename = data.torrent_file_info.as_ref().map(|e| e.filename.as_str());
 
         if cancel.is_cancelled() {
@@ -72,6 +73,16 @@ impl LoadingStrategy for AutoResumeLoadingStrategy {
                 media.imdb_id()
             );
             id = Some(media.imdb_id());
+        } else if filename.is_none() {
+            // direct media urls have no media or torrent file and are identified by their url
+            url_id = data.url.as_ref().map(|e| AutoResume::url_id(e.as_str()));
+            if let Some(url_id) = url_id.as_ref() {
+                debug!(
+                    "Using url id {} for retrieving auto resume timestamp",
+                    url_id
+                );
+                id = Some(url_id.as_str());
+            }
         }
 
         if cancel.is_cancelled() {
@@ -84,7 +95,8 @@ impl LoadingStrategy for AutoResumeLoadingStrategy {
         );
         if let Some(timestamp) = self.auto_resume.resume_timestamp(id, filename) {
             debug!("Using auto resume timestamp {} for {:?}", timestamp, data);
-            data.auto_resume_timestamp = Some(timestamp)
+            data.auto_resume_timestamp = Some(timestamp);
+            data.auto_resume_progress = self.auto_resume.resume_progress(id, filename);
         } else {
             debug!("No auto resume timestamp could be found for {:?}", data);
         }
@@ -94,6 +106,7 @@ impl LoadingStrategy for AutoResumeLoadingStrategy {
 
     async fn cancel(&self, mut data: LoadingData) -> CancellationResult {
         let _ = data.auto_resume_timestamp.take();
+        let _ = data.auto_resume_progress.take();
         Ok(data)
     }
 }
@@ -154,6 +167,10 @@ mod tests {
                 tx_filename.send(filename.map(|e| e.to_string())).unwrap();
                 Some(timestamp)
             });
+        auto_resume
+            .expect_resume_progress()
+            .times(1)
+            .return_const(Some(0.2));
         let strategy = AutoResumeLoadingStrategy::new(Arc::new(
             Box::new(auto_resume) as Box<dyn AutoResumeService>
         ));
@@ -162,6 +179,7 @@ mod tests {
 
         if let LoadingResult::Ok(result) = result {
             assert_eq!(Some(timestamp), result.auto_resume_timestamp);
+            assert_eq!(Some(0.2), result.auto_resume_progress);
 
             let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
             assert_eq!(
@@ -222,6 +240,10 @@ mod tests {
                 tx_filename.send(filename.map(|e| e.to_string())).unwrap();
                 Some(timestamp)
             });
+        auto_resume
+            .expect_resume_progress()
+            .times(1)
+            .return_const(Some(0.2));
         let strategy = AutoResumeLoadingStrategy::new(Arc::new(
             Box::new(auto_resume) as Box<dyn AutoResumeService>
         ));
@@ -230,6 +252,7 @@ mod tests {
 
         if let LoadingResult::Ok(result) = result {
             assert_eq!(Some(timestamp), result.auto_resume_timestamp);
+            assert_eq!(Some(0.2), result.auto_resume_progress);
 
             let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
             assert_eq!(None, result, "expected no media id to have been given");
@@ -251,6 +274,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_process_direct_url() {
+        let url = "https://localhost:8080/media/MyVideo.mp4";
+        let data = LoadingData::from(url);
+        let (tx, rx) = channel();
+        let (tx_event, _rx_event) = channel();
+        let mut auto_resume = MockAutoResumeService::new();
+        auto_resume
+            .expect_resume_timestamp()
+            .times(1)
+            .returning(move |id, filename| {
+                tx.send((id.map(|e| e.to_string()), filename.map(|e| e.to_string())))
+                    .unwrap();
+                Some(12000)
+            });
+        auto_resume
+            .expect_resume_progress()
+            .times(1)
+            .return_const(None);
+        let strategy = AutoResumeLoadingStrategy::new(Arc::new(
+            Box::new(auto_resume) as Box<dyn AutoResumeService>
+        ));
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            assert_eq!(Some(12000), result.auto_resume_timestamp);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            (Some(AutoResume::url_id(url)), None),
+            result,
+            "expected the url id to have been given"
+        );
+    }
+
     #[test]
     fn test_cancel() {
         let url = "http://localhost:8520/video.mp4";
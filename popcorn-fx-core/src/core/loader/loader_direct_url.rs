@@ -0,0 +1,447 @@
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+
+use async_trait::async_trait;
+use derive_more::Display;
+use log::{debug, trace, warn};
+use reqwest::header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::StatusCode;
+use tokio_util::sync::CancellationToken;
+use url::Url;
+
+use crate::core::loader::{
+    CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult, LoadingState,
+    LoadingStrategy,
+};
+use crate::core::utils::http::HttpClientFactory;
+
+const HTTP_SCHEMES: [&str; 2] = ["http", "https"];
+const FILE_SCHEME: &str = "file";
+const TORRENT_EXTENSION: &str = ".torrent";
+const ACCEPT_RANGES_BYTES: &str = "bytes";
+
+/// A loading strategy for playing direct media urls, such as `http(s)://` and `file://` urls, which are not torrents.
+///
+/// Remote urls are probed through a `HEAD` request to verify that the media is reachable,
+/// while local files are verified to exist.
+/// Direct urls don't contain any torrent information, which results in the torrent stages of the loading chain being skipped.
+#[derive(Display)]
+#[display(fmt = "Direct url loading strategy")]
+pub struct DirectUrlLoadingStrategy {
+    client_factory: HttpClientFactory,
+}
+
+impl DirectUrlLoadingStrategy {
+    /// Creates a new `DirectUrlLoadingStrategy` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_factory` - The factory of the HTTP clients used to probe remote urls.
+    ///
+    /// # Returns
+    ///
+    /// A new `DirectUrlLoadingStrategy` instance.
+    pub fn new(client_factory: HttpClientFactory) -> Self {
+        Self { client_factory }
+    }
+
+    /// Probe the given remote url for the media information.
+    ///
+    /// Redirects are followed, resulting in the final url of the media being returned.
+    /// Servers which don't support `HEAD` requests are assumed to serve the media at the given url.
+    async fn probe(&self, url: &str) -> Result<ProbeResult, LoadingError> {
+        debug!("Probing direct media url {}", url);
+        let response = self
+            .client_factory
+            .client()
+            .head(url)
+            .send()
+            .await
+            .map_err(|e| LoadingError::MediaError(format!("failed to reach {}, {}", url, e)))?;
+        let status = response.status();
+
+        if status == StatusCode::METHOD_NOT_ALLOWED || status == StatusCode::NOT_IMPLEMENTED {
+            warn!(
+                "Unable to probe {}, server doesn't support HEAD requests",
+                url
+            );
+            return Ok(ProbeResult {
+                url: url.to_string(),
+                content_length: None,
+                content_type: None,
+                accepts_ranges: false,
+            });
+        }
+        if !status.is_success() {
+            return Err(LoadingError::MediaError(format!(
+                "failed to reach {}, received status {}",
+                url, status
+            )));
+        }
+
+        let headers = response.headers();
+        let result = ProbeResult {
+            url: response.url().to_string(),
+            content_length: headers
+                .get(CONTENT_LENGTH)
+                .and_then(|e| e.to_str().ok())
+                .and_then(|e| e.parse::<u64>().ok()),
+            content_type: headers
+                .get(CONTENT_TYPE)
+                .and_then(|e| e.to_str().ok())
+                .map(|e| e.to_string()),
+            accepts_ranges: headers
+                .get(ACCEPT_RANGES)
+                .and_then(|e| e.to_str().ok())
+                .map(|e| e.eq_ignore_ascii_case(ACCEPT_RANGES_BYTES))
+                .unwrap_or(false),
+        };
+
+        trace!("Probed direct media url {} as {:?}", url, result);
+        Ok(result)
+    }
+
+    /// Retrieve the kind of direct url of the given url.
+    ///
+    /// It returns [None] when the url is not a direct media url, e.g. a magnet or torrent file.
+    fn direct_url(url: &str) -> Option<DirectUrl> {
+        if url.to_lowercase().ends_with(TORRENT_EXTENSION) {
+            return None;
+        }
+
+        match Url::parse(url) {
+            Ok(e) if HTTP_SCHEMES.contains(&e.scheme()) => Some(DirectUrl::Remote),
+            Ok(e) if e.scheme() == FILE_SCHEME => e.to_file_path().ok().map(DirectUrl::File),
+            // single letter schemes are windows drive letters of a local path
+            Ok(e) if e.scheme().len() > 1 => None,
+            _ => Some(PathBuf::from(url))
+                .filter(|e| e.is_absolute())
+                .map(DirectUrl::File),
+        }
+    }
+}
+
+impl Debug for DirectUrlLoadingStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DirectUrlLoadingStrategy").finish()
+    }
+}
+
+#[async_trait]
+impl LoadingStrategy for DirectUrlLoadingStrategy {
+    async fn process(
+        &self,
+        mut data: LoadingData,
+        event_channel: Sender<LoadingEvent>,
+        cancel: CancellationToken,
+    ) -> LoadingResult {
+        if data.torrent_info.is_some() || data.torrent_file_info.is_some() {
+            trace!("Loading data contains torrent information, skipping direct url loading");
+            return LoadingResult::Ok(data);
+        }
+
+        let url = data.url.clone().unwrap_or_default();
+        match Self::direct_url(url.as_str()) {
+            Some(DirectUrl::Remote) => {
+                if cancel.is_cancelled() {
+                    return LoadingResult::Err(LoadingError::Cancelled);
+                }
+
+                event_channel
+                    .send(LoadingEvent::StateChanged(LoadingState::Connecting))
+                    .unwrap();
+                match self.probe(url.as_str()).await {
+                    Ok(result) => {
+                        if !result.accepts_ranges {
+                            warn!("Server of {} doesn't support range requests, seeking might not be possible", url);
+                        }
+                        if let Some(content_type) = result.content_type.as_ref() {
+                            debug!("Direct media url {} has content type {}", url, content_type);
+                        }
+
+                        event_channel
+                            .send(LoadingEvent::Progress {
+                                stage: LoadingState::Connecting,
+                                percent: 100.0,
+                                detail: result.content_length.map(|e| format!("{} bytes", e)),
+                            })
+                            .unwrap();
+                        if result.url != url {
+                            debug!("Direct media url {} redirected to {}", url, result.url);
+                            data.url = Some(result.url);
+                        }
+                    }
+                    Err(e) => return LoadingResult::Err(e),
+                }
+            }
+            Some(DirectUrl::File(path)) => {
+                trace!("Verifying direct media file {:?}", path);
+                if !path.exists() {
+                    return LoadingResult::Err(LoadingError::InvalidData(format!(
+                        "media file {:?} doesn't exist",
+                        path
+                    )));
+                }
+
+                debug!("Direct media file {:?} is available", path);
+            }
+            None => trace!("Url {} is not a direct media url", url),
+        }
+
+        LoadingResult::Ok(data)
+    }
+
+    async fn cancel(&self, data: LoadingData) -> CancellationResult {
+        Ok(data)
+    }
+}
+
+/// The kind of direct media url.
+#[derive(Debug, Clone, PartialEq)]
+enum DirectUrl {
+    /// A remote url served over http(s).
+    Remote,
+    /// A local file.
+    File(PathBuf),
+}
+
+/// The media information of a probed remote url.
+#[derive(Debug, Clone, PartialEq)]
+struct ProbeResult {
+    /// The final url of the media after following redirects.
+    url: String,
+    content_length: Option<u64>,
+    content_type: Option<String>,
+    /// Indicates if the server supports range requests, which are required for seeking.
+    accepts_ranges: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use httpmock::Method::HEAD;
+    use httpmock::MockServer;
+    use tempfile::tempdir;
+
+    use crate::core::block_in_place;
+    use crate::core::config::NetworkSettings;
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_direct_url() {
+        assert_eq!(
+            Some(DirectUrl::Remote),
+            DirectUrlLoadingStrategy::direct_url("https://localhost/lorem.mp4")
+        );
+        assert_eq!(
+            Some(DirectUrl::File(PathBuf::from("/tmp/lorem.mkv"))),
+            DirectUrlLoadingStrategy::direct_url("file:///tmp/lorem.mkv")
+        );
+        assert_eq!(
+            Some(DirectUrl::File(PathBuf::from("/tmp/ipsum.mkv"))),
+            DirectUrlLoadingStrategy::direct_url("/tmp/ipsum.mkv")
+        );
+        assert_eq!(
+            None,
+            DirectUrlLoadingStrategy::direct_url(
+                "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7"
+            )
+        );
+        assert_eq!(
+            None,
+            DirectUrlLoadingStrategy::direct_url("https://localhost/debian.torrent")
+        );
+        assert_eq!(None, DirectUrlLoadingStrategy::direct_url("lorem"));
+    }
+
+    #[test]
+    fn test_probe() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(HEAD).path("/lorem.mp4");
+            then.status(200)
+                .header("content-type", "video/mp4")
+                .header("content-length", "28000")
+                .header("accept-ranges", "bytes");
+        });
+        let url = server.url("/lorem.mp4");
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.probe(url.as_str())).unwrap();
+
+        assert_eq!(
+            ProbeResult {
+                url,
+                content_length: Some(28000),
+                content_type: Some("video/mp4".to_string()),
+                accepts_ranges: true,
+            },
+            result
+        );
+    }
+
+    #[test]
+    fn test_probe_proxy() {
+        init_logger();
+        let proxy = MockServer::start();
+        let mock = proxy.mock(|when, then| {
+            when.method(HEAD)
+                .path("/lorem.mp4")
+                .header("user-agent", "PopcornFX/1.0");
+            then.status(200).header("content-type", "video/mp4");
+        });
+        let url = "http://popcorn-fx.invalid/lorem.mp4";
+        let strategy = DirectUrlLoadingStrategy::new(
+            HttpClientFactory::builder()
+                .settings(NetworkSettings {
+                    proxy: Some(proxy.base_url()),
+                    user_agent: Some("PopcornFX/1.0".to_string()),
+                    ..Default::default()
+                })
+                .build(),
+        );
+
+        let result = block_in_place(strategy.probe(url))
+            .expect("expected the probe to have been sent through the proxy");
+
+        assert_eq!(Some("video/mp4".to_string()), result.content_type);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_probe_without_range_support() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(HEAD).path("/lorem.mp4");
+            then.status(200)
+                .header("content-type", "video/mp4")
+                .header("accept-ranges", "none");
+        });
+        let url = server.url("/lorem.mp4");
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.probe(url.as_str())).unwrap();
+
+        assert_eq!(false, result.accepts_ranges);
+        assert_eq!(url, result.url);
+    }
+
+    #[test]
+    fn test_process_redirect() {
+        init_logger();
+        let server = MockServer::start();
+        let expected_url = server.url("/media/lorem.mp4");
+        server.mock(|when, then| {
+            when.method(HEAD).path("/lorem.mp4");
+            then.status(302).header("location", expected_url.as_str());
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/media/lorem.mp4");
+            then.status(200)
+                .header("content-type", "video/mp4")
+                .header("content-length", "28000")
+                .header("accept-ranges", "bytes");
+        });
+        let data = LoadingData::from(server.url("/lorem.mp4").as_str());
+        let (tx, rx) = channel();
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            assert_eq!(Some(expected_url), result.url);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+        let events: Vec<LoadingEvent> = rx.try_iter().collect();
+        assert_eq!(
+            vec![
+                LoadingEvent::StateChanged(LoadingState::Connecting),
+                LoadingEvent::Progress {
+                    stage: LoadingState::Connecting,
+                    percent: 100.0,
+                    detail: Some("28000 bytes".to_string()),
+                },
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn test_process_head_not_supported() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(HEAD).path("/lorem.mp4");
+            then.status(405);
+        });
+        let url = server.url("/lorem.mp4");
+        let data = LoadingData::from(url.as_str());
+        let (tx, _rx) = channel();
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.process(data.clone(), tx, CancellationToken::new()));
+
+        assert_eq!(LoadingResult::Ok(data), result);
+    }
+
+    #[test]
+    fn test_process_unreachable() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(HEAD).path("/lorem.mp4");
+            then.status(404);
+        });
+        let data = LoadingData::from(server.url("/lorem.mp4").as_str());
+        let (tx, _rx) = channel();
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+
+        if let LoadingResult::Err(LoadingError::MediaError(_)) = result {
+        } else {
+            assert!(
+                false,
+                "expected LoadingError::MediaError, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_file() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let filepath = temp_dir.path().join("lorem.mp4");
+        std::fs::write(&filepath, "").unwrap();
+        let data = LoadingData::from(filepath.to_str().unwrap());
+        let (tx, _rx) = channel();
+        let strategy = DirectUrlLoadingStrategy::new(HttpClientFactory::default());
+
+        let result = block_in_place(strategy.process(data.clone(), tx, CancellationToken::new()));
+        assert_eq!(LoadingResult::Ok(data), result);
+
+        let data = LoadingData::from(temp_dir.path().join("ipsum.mp4").to_str().unwrap());
+        let (tx, _rx) = channel();
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+        if let LoadingResult::Err(LoadingError::InvalidData(_)) = result {
+        } else {
+            assert!(
+                false,
+                "expected LoadingError::InvalidData, but got {:?} instead",
+                result
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 use std::sync::mpsc::Sender;
 
 use async_trait::async_trait;
@@ -7,23 +9,75 @@ use log::{debug, info, trace};
 use tokio_util::sync::CancellationToken;
 
 use crate::core::loader::{
-    CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult, LoadingStrategy,
+    AUTO_QUALITY, CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult,
+    LoadingStrategy, PreferredQuality, QualitySelector,
 };
 use crate::core::media::{DEFAULT_AUDIO_LANGUAGE, Episode, MediaType, MovieDetails, TorrentInfo};
+use crate::core::media::providers::torrent_sources::{TorrentSourceAggregator, TorrentSourceQuery};
 
 /// Represents a strategy for loading media torrent URLs.
+///
+/// When the media item doesn't specify a quality, or requests the [AUTO_QUALITY],
+/// the quality is selected by the quality selector of the strategy.
+/// The torrents of the media item are merged with the torrents of the configured torrent sources.
 #[derive(Display)]
 #[display(fmt = "Media torrent URL loading strategy")]
-pub struct MediaTorrentUrlLoadingStrategy {}
+pub struct MediaTorrentUrlLoadingStrategy {
+    quality_selector: Arc<QualitySelector>,
+    torrent_sources: Arc<TorrentSourceAggregator>,
+}
 
 impl MediaTorrentUrlLoadingStrategy {
     /// Creates a new `MediaTorrentUrlLoadingStrategy` instance.
     ///
+    /// # Arguments
+    ///
+    /// * `quality_selector` - The selector used to determine the quality of media items without a quality.
+    /// * `torrent_sources` - The additional torrent sources of the media items.
+    ///
     /// # Returns
     ///
     /// A new `MediaTorrentUrlLoadingStrategy` instance.
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(
+        quality_selector: Arc<QualitySelector>,
+        torrent_sources: Arc<TorrentSourceAggregator>,
+    ) -> Self {
+        Self {
+            quality_selector,
+            torrent_sources,
+        }
+    }
+
+    /// Update the preferred quality which is used for media items without a quality.
+    pub fn set_preferred_quality(&self, quality: PreferredQuality) {
+        self.quality_selector.set_preferred_quality(quality)
+    }
+
+    /// Retrieve the torrent source query of the media item within the given loading data.
+    ///
+    /// It returns `None` when the IMDB id of the media item is unknown.
+    fn torrent_source_query(data: &LoadingData) -> Option<TorrentSourceQuery> {
+        let media = data.media.as_ref()?;
+
+        match media.media_type() {
+            MediaType::Movie if !media.imdb_id().is_empty() => Some(TorrentSourceQuery::Movie {
+                imdb_id: media.imdb_id().to_string(),
+            }),
+            MediaType::Episode => {
+                let episode = media.downcast_ref::<Episode>()?;
+                let show = data
+                    .parent_media
+                    .as_ref()
+                    .filter(|e| !e.imdb_id().is_empty())?;
+
+                Some(TorrentSourceQuery::Episode {
+                    imdb_id: show.imdb_id().to_string(),
+                    season: episode.season,
+                    episode: episode.episode,
+                })
+            }
+            _ => None,
+        }
     }
 }
 
@@ -38,7 +92,10 @@ impl Debug for MediaTorrentUrlLoadingStrategy {
     ///
     /// A result containing the formatted output.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MediaTorrentUrlLoadingStrategy").finish()
+        f.debug_struct("MediaTorrentUrlLoadingStrategy")
+            .field("quality_selector", &self.quality_selector)
+            .field("torrent_sources", &self.torrent_sources)
+            .finish()
     }
 }
 
@@ -47,72 +104,104 @@ impl LoadingStrategy for MediaTorrentUrlLoadingStrategy {
     async fn process(
         &self,
         mut data: LoadingData,
-        _: Sender<LoadingEvent>,
+        event_channel: Sender<LoadingEvent>,
         cancel: CancellationToken,
     ) -> LoadingResult {
         if let Some(media) = data.media.as_ref() {
-            if let Some(quality) = data.quality.as_ref() {
-                debug!(
-                    "Processing media torrent url for {} and quality {}",
-                    media, quality
-                );
-                let media_torrent_info: Option<TorrentInfo>;
-
-                if cancel.is_cancelled() {
-                    return LoadingResult::Err(LoadingError::Cancelled);
+            let preferred_quality = match data.quality.as_ref() {
+                Some(quality) if quality.eq_ignore_ascii_case(AUTO_QUALITY) => {
+                    Some(PreferredQuality::Auto)
                 }
-                match media.media_type() {
-                    MediaType::Movie => {
-                        trace!(
-                            "Processing movie details for torrent information of {:?}",
-                            media
-                        );
-                        media_torrent_info = media
-                            .downcast_ref::<MovieDetails>()
-                            .and_then(|movie| {
-                                movie.torrents().get(&DEFAULT_AUDIO_LANGUAGE.to_string())
-                            })
-                            .and_then(|media_torrents| media_torrents.get(&quality.to_string()))
-                            .cloned();
-                    }
-                    MediaType::Episode => {
-                        trace!("Processing episode for torrent information");
-                        media_torrent_info = media
-                            .downcast_ref::<Episode>()
-                            .and_then(|episode| {
-                                let episode_torrents = episode.torrents();
-                                trace!(
-                                    "Retrieving {} from episode torrents {:?}",
-                                    quality,
-                                    episode_torrents
-                                );
-                                episode_torrents.get(&quality.to_string())
-                            })
-                            .cloned();
+                Some(_) => None,
+                None if data.url.is_none() => Some(self.quality_selector.preferred_quality()),
+                None => return LoadingResult::Ok(data),
+            };
+            debug!(
+                "Processing media torrent url for {} and quality {:?}",
+                media, data.quality
+            );
+            let mut media_torrents: HashMap<String, TorrentInfo>;
+
+            if cancel.is_cancelled() {
+                return LoadingResult::Err(LoadingError::Cancelled);
+            }
+            match media.media_type() {
+                MediaType::Movie => {
+                    trace!(
+                        "Processing movie details for torrent information of {:?}",
+                        media
+                    );
+                    media_torrents = media
+                        .downcast_ref::<MovieDetails>()
+                        .and_then(|movie| movie.torrents().get(&DEFAULT_AUDIO_LANGUAGE.to_string()))
+                        .cloned()
+                        .unwrap_or_default();
+                }
+                MediaType::Episode => {
+                    trace!("Processing episode for torrent information");
+                    media_torrents = media
+                        .downcast_ref::<Episode>()
+                        .map(|episode| episode.torrents().clone())
+                        .unwrap_or_default();
+                }
+                _ => {
+                    return LoadingResult::Err(LoadingError::MediaError(format!(
+                        "media type {} is not supported",
+                        media.media_type()
+                    )));
+                }
+            }
+
+            if !self.torrent_sources.is_empty() {
+                if let Some(query) = Self::torrent_source_query(&data) {
+                    media_torrents = self.torrent_sources.aggregate(&query, media_torrents).await;
+                }
+            }
+
+            if cancel.is_cancelled() {
+                return LoadingResult::Err(LoadingError::Cancelled);
+            }
+            if let Some(preferred_quality) = preferred_quality {
+                match self
+                    .quality_selector
+                    .select(&preferred_quality, &media_torrents)
+                {
+                    Some(selection) => {
+                        info!("Selected quality {} for media {}", selection, media);
+                        data.quality = Some(selection.quality.clone());
+                        event_channel
+                            .send(LoadingEvent::QualitySelected(selection))
+                            .unwrap();
                     }
-                    _ => {
+                    None => {
                         return LoadingResult::Err(LoadingError::MediaError(format!(
-                            "media type {} is not supported",
-                            media.media_type()
+                            "failed to select a quality for {}",
+                            media
                         )));
                     }
                 }
+            }
 
-                if cancel.is_cancelled() {
-                    return LoadingResult::Err(LoadingError::Cancelled);
-                }
-                if let Some(torrent_info) = media_torrent_info {
-                    let url = torrent_info.url().to_string();
-                    debug!("Updating playlist item url to {} for media {}", url, media);
-                    data.url = Some(url.clone());
-                    data.media_torrent_info = Some(torrent_info);
-                    info!("Loading media url {}", url);
-                } else {
-                    return LoadingResult::Err(LoadingError::MediaError(format!(
-                        "failed to resolve media torrent url for {}",
-                        media
-                    )));
-                }
+            if cancel.is_cancelled() {
+                return LoadingResult::Err(LoadingError::Cancelled);
+            }
+            let quality = data.quality.clone().unwrap_or_default();
+            trace!(
+                "Retrieving {} from media torrents {:?}",
+                quality,
+                media_torrents
+            );
+            if let Some(torrent_info) = media_torrents.get(&quality).cloned() {
+                let url = torrent_info.url().to_string();
+                debug!("Updating playlist item url to {} for media {}", url, media);
+                data.url = Some(url.clone());
+                data.media_torrent_info = Some(torrent_info);
+                info!("Loading media url {}", url);
+            } else {
+                return LoadingResult::Err(LoadingError::MediaError(format!(
+                    "failed to resolve media torrent url for {}",
+                    media
+                )));
             }
         }
 
@@ -126,15 +215,24 @@ impl LoadingStrategy for MediaTorrentUrlLoadingStrategy {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
     use std::sync::mpsc::channel;
 
+    use tempfile::tempdir;
+
     use crate::core::block_in_place;
+    use crate::core::loader::BandwidthHistory;
+    use crate::core::media::providers::torrent_sources::MockTorrentSourceProvider;
     use crate::core::playlists::PlaylistItem;
     use crate::testing::init_logger;
 
     use super::*;
 
+    fn quality_selector(temp_path: &str) -> Arc<QualitySelector> {
+        Arc::new(QualitySelector::new(Arc::new(BandwidthHistory::new(
+            temp_path,
+        ))))
+    }
+
     #[test]
     fn test_process_movie() {
         init_logger();
@@ -181,7 +279,12 @@ mod tests {
         };
         let data = LoadingData::from(item);
         let (tx, _) = channel();
-        let strategy = MediaTorrentUrlLoadingStrategy::new();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let strategy = MediaTorrentUrlLoadingStrategy::new(
+            quality_selector(temp_path),
+            Arc::new(TorrentSourceAggregator::default()),
+        );
 
         let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
 
@@ -197,6 +300,162 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_process_episode_auto_quality() {
+        init_logger();
+        let torrent_info = |quality: &str| {
+            TorrentInfo::builder()
+                .url(format!("magnet:?{}", quality))
+                .provider("")
+                .source("")
+                .title("")
+                .quality(quality)
+                .seed(0)
+                .peer(0)
+                .build()
+        };
+        let item = PlaylistItem {
+            url: None,
+            title: "LoremIpsum".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: Some(Box::new(Episode::new_with_torrents(
+                1,
+                2,
+                0,
+                "".to_string(),
+                "".to_string(),
+                0,
+                HashMap::from([
+                    ("720p".to_string(), torrent_info("720p")),
+                    ("1080p".to_string(), torrent_info("1080p")),
+                    ("2160p".to_string(), torrent_info("2160p")),
+                ]),
+            ))),
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: Some(AUTO_QUALITY.to_string()),
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+        let data = LoadingData::from(item);
+        let (tx, rx) = channel();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let strategy = MediaTorrentUrlLoadingStrategy::new(
+            quality_selector(temp_path),
+            Arc::new(TorrentSourceAggregator::default()),
+        );
+
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            assert_eq!(Some("magnet:?1080p".to_string()), result.url);
+            assert_eq!(Some("1080p".to_string()), result.quality);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+        let events: Vec<LoadingEvent> = rx.try_iter().collect();
+        if let Some(LoadingEvent::QualitySelected(selection)) = events.first() {
+            assert_eq!(true, selection.auto);
+            assert_eq!("Auto (1080p)", selection.to_string());
+        } else {
+            assert!(
+                false,
+                "expected LoadingEvent::QualitySelected, but got {:?} instead",
+                events
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_movie_torrent_sources() {
+        init_logger();
+        let torrent_info = |url: &str, quality: &str, seeds: u32| {
+            TorrentInfo::builder()
+                .url(url)
+                .provider("")
+                .source("")
+                .title("")
+                .quality(quality)
+                .seed(seeds)
+                .peer(0)
+                .build()
+        };
+        let source_torrent = torrent_info("magnet:?xt=urn:btih:bbbb", "1080p", 20);
+        let mut provider = MockTorrentSourceProvider::new();
+        provider
+            .expect_timeout()
+            .return_const(std::time::Duration::from_secs(1));
+        let provider_torrent = source_torrent.clone();
+        provider
+            .expect_torrents()
+            .withf(|query| {
+                *query
+                    == TorrentSourceQuery::Movie {
+                        imdb_id: "tt0000001".to_string(),
+                    }
+            })
+            .times(1)
+            .returning(move |_| Ok(vec![provider_torrent.clone()]));
+        let item = PlaylistItem {
+            url: None,
+            title: "LoremIpsum".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: Some(Box::new(MovieDetails {
+                title: "".to_string(),
+                imdb_id: "tt0000001".to_string(),
+                year: "".to_string(),
+                runtime: "".to_string(),
+                genres: vec![],
+                synopsis: "".to_string(),
+                rating: None,
+                images: Default::default(),
+                trailer: "".to_string(),
+                torrents: HashMap::from([(
+                    DEFAULT_AUDIO_LANGUAGE.to_string(),
+                    HashMap::from([(
+                        "1080p".to_string(),
+                        torrent_info("magnet:?xt=urn:btih:aaaa", "1080p", 2),
+                    )]),
+                )]),
+            })),
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: Some("1080p".to_string()),
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+        let data = LoadingData::from(item);
+        let (tx, _) = channel();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let strategy = MediaTorrentUrlLoadingStrategy::new(
+            quality_selector(temp_path),
+            Arc::new(TorrentSourceAggregator::new(vec![Box::new(provider)])),
+        );
+
+        let result = block_in_place(strategy.process(data, tx, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            assert_eq!(Some(source_torrent.url().to_string()), result.url);
+            assert_eq!(Some(source_torrent), result.media_torrent_info);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+    }
+
     #[test]
     fn test_cancel() {
         let url = "http://localhost:9090/DolorEsta.mp4";
@@ -215,7 +474,12 @@ mod tests {
             subtitles_enabled: false,
         };
         let data = LoadingData::from(item);
-        let strategy = MediaTorrentUrlLoadingStrategy::new();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let strategy = MediaTorrentUrlLoadingStrategy::new(
+            quality_selector(temp_path),
+            Arc::new(TorrentSourceAggregator::default()),
+        );
 
         let result = block_in_place(strategy.cancel(data.clone()));
 
@@ -11,9 +11,7 @@ use crate::core::loader::{
     CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult, LoadingState,
     LoadingStrategy,
 };
-use crate::core::players::{
-    PlayerManager, PlayMediaRequest, PlayRequest, PlayStreamRequest, PlayUrlRequest,
-};
+use crate::core::players::{PlayerManager, PlayRequest};
 
 /// A loading strategy specifically designed for player loading.
 /// This strategy will translate the [PlaylistItem] into a [PlayRequest] which is invoked on the [PlayerManager].
@@ -45,12 +43,12 @@ impl PlayerLoadingStrategy {
     ///
     /// # Returns
     ///
-    /// A result containing a boxed `PlayRequest` if successful, or a `LoadingError` if an error occurs.
-    fn convert(&self, data: LoadingData) -> Result<Box<dyn PlayRequest>, LoadingError> {
+    /// A result containing the `PlayRequest` if successful, or a `LoadingError` if an error occurs.
+    fn convert(&self, data: LoadingData) -> Result<PlayRequest, LoadingError> {
         if data.media.is_some() {
             trace!("Trying to start media playback for {:?}", data);
             return if data.torrent_stream.is_some() {
-                Ok(Box::new(PlayMediaRequest::from(data)))
+                Ok(PlayRequest::from(data))
             } else {
                 Err(LoadingError::InvalidData(format!(
                     "Missing torrent stream for {:?}",
@@ -59,11 +57,11 @@ impl PlayerLoadingStrategy {
             };
         } else if data.torrent_stream.is_some() {
             trace!("Trying to start torrent stream playback for {:?}", data);
-            return Ok(Box::new(PlayStreamRequest::from(data)));
+            return Ok(PlayRequest::from(data));
         }
 
         trace!("Starting URL playback for {:?}", data);
-        Ok(Box::new(PlayUrlRequest::from(data)))
+        Ok(PlayRequest::from(data))
     }
 }
 
@@ -97,6 +95,11 @@ impl LoadingStrategy for PlayerLoadingStrategy {
         event_channel: Sender<LoadingEvent>,
         _: CancellationToken,
     ) -> LoadingResult {
+        if data.download_only {
+            debug!("Download only mode is active, playback won't be started");
+            return LoadingResult::Completed;
+        }
+
         if let Some(url) = data.url.as_ref() {
             debug!("Starting playlist item playback for {}", url);
             return match self.convert(data) {
@@ -170,6 +173,33 @@ mod tests {
         assert_eq!(title, result.title());
     }
 
+    #[test]
+    fn test_process_download_only() {
+        init_logger();
+        let mut data = LoadingData::from(PlaylistItem {
+            url: Some("magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7".to_string()),
+            title: "MyDownload".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        });
+        data.download_only = true;
+        let (tx_event, _rx_event) = channel();
+        let mut manager = MockPlayerManager::new();
+        manager.expect_play().times(0).return_const(());
+        let strategy = PlayerLoadingStrategy::new(Arc::new(Box::new(manager)));
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        assert_eq!(LoadingResult::Completed, result);
+    }
+
     #[test]
     fn test_process_media_item() {
         init_logger();
@@ -216,22 +246,17 @@ mod tests {
         block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
 
-        if let Some(result) = result.downcast_ref::<PlayMediaRequest>() {
-            if let Some(media) = result.media.downcast_ref::<MovieDetails>() {
-                assert_eq!(movie, *media);
-                assert_eq!(Some(quality.to_string()), result.quality());
-            } else {
-                assert!(
-                    false,
-                    "expected MovieDetails, but got {:?} instead",
-                    result.media
-                );
-            }
+        assert_eq!(Some(quality.to_string()), result.quality());
+        if let Some(media) = result
+            .media()
+            .and_then(|e| e.downcast_ref::<MovieDetails>())
+        {
+            assert_eq!(movie, *media);
         } else {
             assert!(
                 false,
-                "expected PlayMediaRequest, but got {:?} instead",
-                result
+                "expected MovieDetails, but got {:?} instead",
+                result.media()
             );
         }
     }
@@ -332,18 +357,19 @@ mod tests {
         block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
 
-        if let Some(result) = result.downcast_ref::<PlayStreamRequest>() {
-            assert_eq!(Some(quality.to_string()), result.quality());
-            assert!(
-                result.torrent_stream.upgrade().is_some(),
-                "expected Some(torrent_stream), but got None instead"
-            );
-        } else {
-            assert!(
-                false,
-                "expected PlayMediaRequest, but got {:?} instead",
-                result
-            );
-        }
+        assert_eq!(Some(quality.to_string()), result.quality());
+        assert!(
+            result.media().is_none(),
+            "expected no media, but got {:?} instead",
+            result.media()
+        );
+        assert!(
+            result
+                .torrent_stream
+                .as_ref()
+                .and_then(|e| e.upgrade())
+                .is_some(),
+            "expected Some(torrent_stream), but got None instead"
+        );
     }
 }
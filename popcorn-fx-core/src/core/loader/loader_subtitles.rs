@@ -68,6 +68,11 @@ impl SubtitlesLoadingStrategy {
                 .subtitle_provider
                 .file_subtitles(file_info.filename.as_str())
                 .await
+        } else if let Some(filename) = data.url_filename() {
+            subtitles = self
+                .subtitle_provider
+                .file_subtitles(filename.as_str())
+                .await
         } else {
             warn!("Unable to retrieve subtitles, no information known about the played item");
             return;
@@ -221,6 +226,13 @@ impl LoadingStrategy for SubtitlesLoadingStrategy {
                         ))
                         .unwrap();
                     self.update_to_default_subtitle(&data).await;
+                    event_channel
+                        .send(LoadingEvent::Progress {
+                            stage: LoadingState::RetrievingSubtitles,
+                            percent: 100.0,
+                            detail: None,
+                        })
+                        .unwrap();
                 } else {
                     debug!("Subtitle has already been selected for {:?}", data);
                 }
@@ -238,6 +250,13 @@ impl LoadingStrategy for SubtitlesLoadingStrategy {
                     trace!("Downloading subtitle for {:?}", data);
                     if let Some(subtitle) = self.download_subtitle(&info, &data).await {
                         let subtitle_filename = subtitle.file().to_string();
+                        event_channel
+                            .send(LoadingEvent::Progress {
+                                stage: LoadingState::DownloadingSubtitle,
+                                percent: 100.0,
+                                detail: Some(subtitle_filename.clone()),
+                            })
+                            .unwrap();
                         data.subtitle = Some(subtitle);
                         info!(
                             "Subtitle {} has been downloaded for {:?}",
@@ -313,7 +332,7 @@ mod tests {
         };
         let data = LoadingData::from(playlist_item);
         let (tx, rx) = channel();
-        let (tx_event, _rx_event) = channel();
+        let (tx_event, rx_event) = channel();
         let mut provider = MockSubtitleProvider::new();
         provider
             .expect_movie_subtitles()
@@ -363,6 +382,17 @@ mod tests {
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(movie_details, result);
+
+        let events: Vec<LoadingEvent> = rx_event.try_iter().collect();
+        assert!(
+            events.contains(&LoadingEvent::Progress {
+                stage: LoadingState::DownloadingSubtitle,
+                percent: 100.0,
+                detail: Some("MySubtitleFile".to_string()),
+            }),
+            "expected the subtitle download progress to have been reported, got {:?} instead",
+            events
+        );
     }
 
     #[test]
@@ -442,6 +472,65 @@ mod tests {
         assert_eq!(filename.to_string(), result);
     }
 
+    #[test]
+    fn test_process_direct_url_subtitles() {
+        init_logger();
+        let data = LoadingData::from(
+            PlaylistItem::builder()
+                .url("https://localhost:8080/media/lorem%20ipsum.mp4")
+                .title("lorem ipsum")
+                .subtitles_enabled(true)
+                .build(),
+        );
+        let (tx, rx) = channel();
+        let (tx_event, _rx_event) = channel();
+        let mut provider = MockSubtitleProvider::new();
+        provider
+            .expect_file_subtitles()
+            .times(1)
+            .returning(move |e| {
+                tx.send(e.to_string()).unwrap();
+                Ok(Vec::new())
+            });
+        provider
+            .expect_download_and_parse()
+            .times(1)
+            .return_const(Ok(Subtitle::new(
+                vec![],
+                None,
+                "MySubtitleFile".to_string(),
+            )));
+        let mut manager = MockSubtitleManager::new();
+        manager
+            .expect_is_disabled_async()
+            .times(1)
+            .return_const(false);
+        manager
+            .expect_preferred_language()
+            .times(1)
+            .return_const(SubtitleLanguage::None);
+        manager
+            .expect_preferred_subtitle()
+            .times(..2)
+            .returning(|| Some(SubtitleInfo::none()));
+        manager
+            .expect_select_or_default()
+            .times(1)
+            .returning(|_| SubtitleInfo::none());
+        manager.expect_update_subtitle().times(1).return_const(());
+        let loader = SubtitlesLoadingStrategy::new(
+            Arc::new(Box::new(provider)),
+            Arc::new(Box::new(manager)),
+        );
+
+        let result =
+            block_in_place(loader.process(data.clone(), tx_event, CancellationToken::new()));
+        assert_eq!(LoadingResult::Ok(data), result);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("lorem ipsum.mp4".to_string(), result);
+    }
+
     #[test]
     fn test_process_subtitle_manager_disabled() {
         init_logger();
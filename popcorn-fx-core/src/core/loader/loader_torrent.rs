@@ -1,24 +1,29 @@
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use std::sync::mpsc::Sender;
 
 use async_trait::async_trait;
 use derive_more::Display;
 use log::{debug, trace};
+use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
+use crate::core::block_in_place;
 use crate::core::config::ApplicationConfig;
 use crate::core::loader;
 use crate::core::loader::{
     CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingState, LoadingStrategy,
 };
-use crate::core::torrents::TorrentManager;
+use crate::core::torrents::{Magnet, Torrent, TorrentFileInfo, TorrentManager};
 
 #[derive(Display)]
 #[display(fmt = "Torrent loading strategy")]
 pub struct TorrentLoadingStrategy {
     torrent_manager: Arc<Box<dyn TorrentManager>>,
     application_settings: Arc<ApplicationConfig>,
+    /// The info hashes which are being processed by loading tasks, together with the number of tasks processing it.
+    references: Arc<Mutex<HashMap<String, usize>>>,
 }
 
 impl TorrentLoadingStrategy {
@@ -29,8 +34,74 @@ impl TorrentLoadingStrategy {
         Self {
             torrent_manager,
             application_settings,
+            references: Default::default(),
         }
     }
+
+    /// Retrieve the normalized info hash of the torrent info from the given data, if available.
+    fn info_hash(data: &LoadingData) -> Option<String> {
+        data.torrent_info
+            .as_ref()
+            .and_then(|e| Magnet::from_str(e.uri.as_str()).ok())
+            .and_then(|e| e.info_hash())
+    }
+
+    /// Register a reference to the given info hash for as long as the returned reference is in scope.
+    fn reference(&self, info_hash: String) -> InfoHashReference {
+        {
+            let mut references = block_in_place(self.references.lock());
+            *references.entry(info_hash.clone()).or_insert(0) += 1;
+        }
+
+        InfoHashReference {
+            info_hash,
+            references: self.references.clone(),
+        }
+    }
+
+    /// Verify if the given info hash is still being processed by another loading task.
+    fn is_referenced(&self, info_hash: &str) -> bool {
+        let references = block_in_place(self.references.lock());
+        references.get(info_hash).map(|e| *e > 0).unwrap_or(false)
+    }
+
+    /// Retrieve an existing torrent session for the given torrent file, if one is still available.
+    /// This is the case when the same media is reopened while its torrent has not been removed yet,
+    /// or when a torrent which is being downloaded in download only mode is streamed.
+    fn existing_torrent(
+        &self,
+        data: &LoadingData,
+        torrent_file_info: &TorrentFileInfo,
+    ) -> Option<Weak<Box<dyn Torrent>>> {
+        Self::info_hash(data)
+            .and_then(|info_hash| {
+                trace!(
+                    "Searching for existing torrent with info hash {}",
+                    info_hash
+                );
+                self.torrent_manager
+                    .by_info_hash(info_hash.as_str(), torrent_file_info)
+            })
+            .filter(|e| e.upgrade().is_some())
+    }
+
+    /// Report the progress of retrieving the torrent metadata.
+    /// The torrent manager doesn't expose the individual metadata pieces, so only the start and completion are reported.
+    fn send_metadata_progress(event_channel: &Sender<LoadingEvent>, percent: f32) {
+        let detail = if percent < 100.0 {
+            "Retrieving torrent metadata"
+        } else {
+            "Torrent metadata has been retrieved"
+        };
+
+        event_channel
+            .send(LoadingEvent::Progress {
+                stage: LoadingState::Connecting,
+                percent,
+                detail: Some(detail.to_string()),
+            })
+            .unwrap();
+    }
 }
 
 impl Debug for TorrentLoadingStrategy {
@@ -38,10 +109,31 @@ impl Debug for TorrentLoadingStrategy {
         f.debug_struct("TorrentLoadingStrategy")
             .field("torrent_manager", &self.torrent_manager)
             .field("application_settings", &self.application_settings)
+            .field("references", &self.references)
             .finish()
     }
 }
 
+/// A reference to an info hash which is being processed by a loading task.
+/// The reference is released when it goes out of scope, which includes the process future being dropped on cancellation.
+#[derive(Debug)]
+struct InfoHashReference {
+    info_hash: String,
+    references: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl Drop for InfoHashReference {
+    fn drop(&mut self) {
+        let mut references = block_in_place(self.references.lock());
+        if let Some(count) = references.get_mut(&self.info_hash) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                references.remove(&self.info_hash);
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl LoadingStrategy for TorrentLoadingStrategy {
     async fn process(
@@ -52,6 +144,7 @@ impl LoadingStrategy for TorrentLoadingStrategy {
     ) -> loader::LoadingResult {
         if let Some(torrent_file_info) = data.torrent_file_info.as_ref() {
             trace!("Processing torrent info of {:?}", torrent_file_info);
+            let _reference = Self::info_hash(&data).map(|e| self.reference(e));
             event_channel
                 .send(LoadingEvent::StateChanged(LoadingState::Connecting))
                 .unwrap();
@@ -67,14 +160,29 @@ impl LoadingStrategy for TorrentLoadingStrategy {
                     .expect("expected a valid torrent directory from the user settings");
             }
 
+            if let Some(torrent) = self.existing_torrent(&data, torrent_file_info) {
+                debug!("Reusing existing torrent for {}", torrent_file_info);
+                Self::send_metadata_progress(&event_channel, 100.0);
+                data.torrent = Some(torrent);
+                return loader::LoadingResult::Ok(data);
+            }
+
+            Self::send_metadata_progress(&event_channel, 0.0);
             match self
                 .torrent_manager
                 .create(torrent_file_info, torrent_directory.as_str(), true)
                 .await
             {
-                Ok(torrent) => {
+                Ok(result) => {
+                    if result.already_present {
+                        debug!(
+                            "Torrent of {} is already active, reusing it",
+                            torrent_file_info
+                        );
+                    }
                     debug!("Enhancing playlist item with torrent");
-                    data.torrent = Some(torrent);
+                    Self::send_metadata_progress(&event_channel, 100.0);
+                    data.torrent = Some(result.torrent);
                 }
                 Err(e) => return loader::LoadingResult::Err(LoadingError::TorrentError(e)),
             }
@@ -83,10 +191,36 @@ impl LoadingStrategy for TorrentLoadingStrategy {
         loader::LoadingResult::Ok(data)
     }
 
+    /// Cancel the torrent of the given data and remove it from the session.
+    ///
+    /// When the torrent hasn't been stored yet in the data, e.g. because the process was dropped during the creation,
+    /// the torrent is searched by its info hash. The torrent is kept when another loading task is still processing
+    /// the same info hash, in which case it remains present in the returned data.
     async fn cancel(&self, mut data: LoadingData) -> CancellationResult {
-        if let Some(torrent) = data.torrent.take().and_then(|e| e.upgrade()) {
-            debug!("Cancelling the torrent downloading");
-            self.torrent_manager.remove(torrent.handle());
+        let torrent = data
+            .torrent
+            .take()
+            .or_else(|| {
+                data.torrent_file_info
+                    .as_ref()
+                    .and_then(|e| self.existing_torrent(&data, e))
+            })
+            .and_then(|e| e.upgrade());
+
+        if let Some(torrent) = torrent {
+            if Self::info_hash(&data)
+                .map(|e| self.is_referenced(e.as_str()))
+                .unwrap_or(false)
+            {
+                debug!(
+                    "Torrent {} is still referenced by another loading task, keeping the torrent",
+                    torrent.handle()
+                );
+                data.torrent = Some(Arc::downgrade(&torrent));
+            } else {
+                debug!("Cancelling the torrent downloading of {}", torrent.handle());
+                self.torrent_manager.remove(torrent.handle());
+            }
         } else {
             trace!("No torrent available to cancel");
         }
@@ -145,6 +279,90 @@ mod tests {
         assert_eq!(LoadingResult::Ok(data), result);
     }
 
+    #[test]
+    fn test_process_reuse_existing_torrent() {
+        init_logger();
+        let handle = "ExistingTorrentHandle";
+        let torrent_file_info = TorrentFileInfo {
+            filename: "lorem.mp4".to_string(),
+            file_path: "lorem.mp4".to_string(),
+            file_size: 128000,
+            file_index: 0,
+        };
+        let torrent_info = TorrentInfo {
+            uri: "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7".to_string(),
+            name: "lorem".to_string(),
+            directory_name: None,
+            total_files: 1,
+            files: vec![torrent_file_info.clone()],
+        };
+        let item = PlaylistItem {
+            url: None,
+            title: "Lorem ipsum".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: Some(torrent_info),
+            torrent_file_info: Some(torrent_file_info),
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+        let data = LoadingData::from(item);
+        let (tx_event, rx_event) = channel();
+        let (tx, rx) = channel();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        let existing_torrent = Arc::downgrade(&torrent);
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager
+            .expect_by_info_hash()
+            .times(1)
+            .returning(move |info_hash, _| {
+                tx.send(info_hash.to_string()).unwrap();
+                Some(existing_torrent.clone())
+            });
+        torrent_manager.expect_create().times(0);
+        let strategy = TorrentLoadingStrategy::new(Arc::new(Box::new(torrent_manager)), settings);
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            let result = result
+                .torrent
+                .and_then(|e| e.upgrade())
+                .expect("expected the existing torrent to have been set");
+            assert_eq!(handle, result.handle());
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("eadaf0efea39406914414d359e0ea16416409bd7", result);
+
+        let events: Vec<LoadingEvent> = rx_event.try_iter().collect();
+        assert_eq!(
+            vec![
+                LoadingEvent::StateChanged(LoadingState::Connecting),
+                LoadingEvent::Progress {
+                    stage: LoadingState::Connecting,
+                    percent: 100.0,
+                    detail: Some("Torrent metadata has been retrieved".to_string()),
+                },
+            ],
+            events
+        );
+    }
+
     #[test]
     fn test_cancel() {
         init_logger();
@@ -196,4 +414,128 @@ mod tests {
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(handle.to_string(), result);
     }
+
+    #[test]
+    fn test_cancel_should_remove_torrent_by_info_hash() {
+        init_logger();
+        let handle = "CreatedTorrentHandle";
+        let data = create_magnet_data();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        let created_torrent = Arc::downgrade(&torrent);
+        let (tx, rx) = channel();
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager
+            .expect_by_info_hash()
+            .times(1)
+            .returning(move |_, _| Some(created_torrent.clone()));
+        torrent_manager
+            .expect_remove()
+            .times(1)
+            .returning(move |e| {
+                tx.send(e.to_string()).unwrap();
+            });
+        let strategy = TorrentLoadingStrategy::new(Arc::new(Box::new(torrent_manager)), settings);
+
+        let result = block_in_place(strategy.cancel(data));
+        if let Ok(result) = result {
+            assert!(
+                result.torrent.is_none(),
+                "expected the torrent to have been removed from the data"
+            );
+        } else {
+            assert!(
+                false,
+                "expected CancellationResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(handle.to_string(), result);
+    }
+
+    #[test]
+    fn test_cancel_should_keep_referenced_torrent() {
+        init_logger();
+        let handle = "SharedTorrentHandle";
+        let mut data = create_magnet_data();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        data.torrent = Some(Arc::downgrade(&torrent));
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager.expect_remove().times(0);
+        let strategy = TorrentLoadingStrategy::new(Arc::new(Box::new(torrent_manager)), settings);
+        let _reference = strategy.reference("eadaf0efea39406914414d359e0ea16416409bd7".to_string());
+
+        let result = block_in_place(strategy.cancel(data));
+
+        if let Ok(result) = result {
+            assert!(
+                result.torrent.is_some(),
+                "expected the referenced torrent to have been kept"
+            );
+        } else {
+            assert!(
+                false,
+                "expected CancellationResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_info_hash_reference_drop() {
+        init_logger();
+        let info_hash = "eadaf0efea39406914414d359e0ea16416409bd7";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let strategy =
+            TorrentLoadingStrategy::new(Arc::new(Box::new(MockTorrentManager::new())), settings);
+
+        let reference = strategy.reference(info_hash.to_string());
+        assert_eq!(true, strategy.is_referenced(info_hash));
+
+        drop(reference);
+        assert_eq!(false, strategy.is_referenced(info_hash));
+    }
+
+    fn create_magnet_data() -> LoadingData {
+        let torrent_file_info = TorrentFileInfo {
+            filename: "lorem.mp4".to_string(),
+            file_path: "lorem.mp4".to_string(),
+            file_size: 128000,
+            file_index: 0,
+        };
+        let torrent_info = TorrentInfo {
+            uri: "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7".to_string(),
+            name: "lorem".to_string(),
+            directory_name: None,
+            total_files: 1,
+            files: vec![torrent_file_info.clone()],
+        };
+
+        LoadingData::from(PlaylistItem {
+            url: None,
+            title: "Lorem ipsum".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: Some(torrent_info),
+            torrent_file_info: Some(torrent_file_info),
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        })
+    }
 }
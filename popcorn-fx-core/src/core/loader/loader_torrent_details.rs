@@ -102,11 +102,14 @@ mod tests {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: None,
+            auto_resume_progress: None,
             subtitles_enabled: None,
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         };
         let (tx, rx) = channel();
         let (tx_event, _) = channel();
@@ -149,11 +152,14 @@ mod tests {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: None,
+            auto_resume_progress: None,
             subtitles_enabled: None,
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         };
         let event_publisher = Arc::new(EventPublisher::default());
         let strategy = TorrentDetailsLoadingStrategy::new(event_publisher);
@@ -1,5 +1,7 @@
 use std::fmt::{Debug, Formatter};
+use std::fs;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::{channel, Sender};
 
 use async_trait::async_trait;
@@ -8,23 +10,74 @@ use log::{debug, trace, warn};
 use tokio_util::sync::CancellationToken;
 
 use crate::core::loader::{
-    CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingProgress, LoadingResult,
-    LoadingState, LoadingStrategy,
+    BandwidthHistory, CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingProgress,
+    LoadingResult, LoadingState, LoadingStrategy,
 };
+use crate::core::torrents::collection::TorrentAutoCollector;
 use crate::core::torrents::{
-    TorrentError, TorrentStreamEvent, TorrentStreamServer, TorrentStreamState,
+    DownloadNotifier, DownloadStatus, TorrentError, TorrentStreamEvent, TorrentStreamServer,
+    TorrentStreamState,
 };
 
+/// The number of pieces which are prioritized around the auto resume offset of the stream.
+const RESUME_PRIORITY_WINDOW: u32 = 10;
+const MAGNET_PREFIX: &str = "magnet:";
+
 #[derive(Display)]
 #[display(fmt = "Torrent stream loading strategy")]
 pub struct TorrentStreamLoadingStrategy {
     torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>,
+    download_notifier: DownloadNotifier,
+    auto_collector: TorrentAutoCollector,
+    bandwidth_history: Arc<BandwidthHistory>,
 }
 
 impl TorrentStreamLoadingStrategy {
-    pub fn new(torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>) -> Self {
+    pub fn new(
+        torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>,
+        download_notifier: DownloadNotifier,
+        auto_collector: TorrentAutoCollector,
+        bandwidth_history: Arc<BandwidthHistory>,
+    ) -> Self {
         Self {
             torrent_stream_server,
+            download_notifier,
+            auto_collector,
+            bandwidth_history,
+        }
+    }
+
+    /// Retrieve the pieces which should be prioritized to resume the playback at the given progress.
+    /// The returned piece indexes are relative to the streamed file, not to the whole torrent.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - The playback progress of the video as a value between `0.0` and `1.0`.
+    /// * `file_size` - The size in bytes of the streamed file.
+    /// * `piece_length` - The length in bytes of a single piece of the torrent.
+    fn resume_pieces(progress: f32, file_size: u64, piece_length: u64) -> Vec<u32> {
+        if file_size == 0 || piece_length == 0 {
+            return vec![];
+        }
+
+        let file_pieces = ((file_size + piece_length - 1) / piece_length) as u32;
+        let offset = (progress.clamp(0.0, 1.0) as f64 * file_size as f64) as u64;
+        let piece = ((offset / piece_length) as u32).min(file_pieces - 1);
+        let start = piece.saturating_sub(RESUME_PRIORITY_WINDOW / 2);
+        let end = (start + RESUME_PRIORITY_WINDOW).min(file_pieces);
+
+        (start..end).collect()
+    }
+
+    /// Create the stage progress event of the stream preparation for the given download status.
+    fn preparation_progress(status: &DownloadStatus) -> LoadingEvent {
+        LoadingEvent::Progress {
+            stage: LoadingState::Downloading,
+            percent: (status.progress * 100.0).clamp(0.0, 100.0),
+            detail: Some(format!(
+                "{} of {} bytes",
+                status.downloaded, status.total_size
+            )),
         }
     }
 }
@@ -33,6 +86,9 @@ impl Debug for TorrentStreamLoadingStrategy {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TorrentStreamLoadingStrategy")
             .field("torrent_stream_server", &self.torrent_stream_server)
+            .field("download_notifier", &self.download_notifier)
+            .field("auto_collector", &self.auto_collector)
+            .field("bandwidth_history", &self.bandwidth_history)
             .finish()
     }
 }
@@ -45,8 +101,26 @@ impl LoadingStrategy for TorrentStreamLoadingStrategy {
         event_channel: Sender<LoadingEvent>,
         cancel_token: CancellationToken,
     ) -> LoadingResult {
+        if data.download_only {
+            debug!("Download only mode is active, the torrent stream won't be started");
+            if let Some(torrent) = data.torrent.as_ref() {
+                if let Some(torrent) = torrent.upgrade() {
+                    let title = data.title.clone().unwrap_or_else(|| torrent.to_string());
+                    self.download_notifier.watch(&torrent, title.as_str());
+                }
+                event_channel
+                    .send(LoadingEvent::StateChanged(LoadingState::Downloading))
+                    .unwrap();
+            }
+            return LoadingResult::Ok(data);
+        }
+
         if let Some(torrent) = data.torrent.take() {
             trace!("Processing torrent stream for {:?}", torrent);
+            // the user is actively watching the torrent, so its completion shouldn't be notified
+            if let Some(torrent) = torrent.upgrade() {
+                self.download_notifier.suppress(torrent.handle());
+            }
             event_channel
                 .send(LoadingEvent::StateChanged(LoadingState::Starting))
                 .unwrap();
@@ -56,11 +130,41 @@ impl LoadingStrategy for TorrentStreamLoadingStrategy {
                         let (tx, rx) = channel();
                         trace!("Updating playlist item url to stream {}", stream.url());
                         data.url = Some(stream.url().to_string());
+                        if let Some(info) = data
+                            .torrent_info
+                            .as_ref()
+                            .filter(|e| e.uri.starts_with(MAGNET_PREFIX))
+                        {
+                            let name = data.title.as_deref().unwrap_or(info.name.as_str());
+                            self.auto_collector.watch(
+                                &**stream,
+                                stream.url().as_str(),
+                                name,
+                                info.uri.as_str(),
+                            );
+                        }
+                        if let Some(progress) = data.auto_resume_progress {
+                            let file_size = data
+                                .torrent_file_info
+                                .as_ref()
+                                .map(|e| e.file_size.max(0) as u64)
+                                .or_else(|| fs::metadata(stream.file()).ok().map(|e| e.len()))
+                                .unwrap_or(0);
+                            let pieces =
+                                Self::resume_pieces(progress, file_size, stream.piece_length());
+                            debug!(
+                                "Prioritizing pieces {:?} for auto resume progress {}",
+                                pieces, progress
+                            );
+                            stream.prioritize_pieces(&pieces[..]);
+                        }
                         event_channel
                             .send(LoadingEvent::StateChanged(LoadingState::Downloading))
                             .unwrap();
 
                         let event_channel_stream = event_channel.clone();
+                        let peak_download_speed = Arc::new(AtomicU32::new(0));
+                        let stream_peak_download_speed = peak_download_speed.clone();
                         let callback_id = stream.subscribe_stream(Box::new(move |event| {
                             if cancel_token.is_cancelled() {
                                 debug!("Cancelling the torrent stream loading process");
@@ -83,12 +187,18 @@ impl LoadingStrategy for TorrentStreamLoadingStrategy {
                                         .unwrap(),
                                 },
                                 TorrentStreamEvent::DownloadStatus(status) => {
+                                    stream_peak_download_speed
+                                        .fetch_max(status.download_speed, Ordering::Relaxed);
+                                    event_channel_stream
+                                        .send(Self::preparation_progress(&status))
+                                        .unwrap();
                                     event_channel_stream
                                         .send(LoadingEvent::ProgressChanged(LoadingProgress::from(
                                             status,
                                         )))
                                         .unwrap();
                                 }
+                                TorrentStreamEvent::BufferStatus(_) => {}
                             }
                         }));
                         match rx.recv() {
@@ -100,6 +210,8 @@ impl LoadingStrategy for TorrentStreamLoadingStrategy {
                                     .unwrap();
                                 stream.unsubscribe_stream(callback_id);
                                 trace!("Received stream ready signal");
+                                self.bandwidth_history
+                                    .record(peak_download_speed.load(Ordering::Relaxed));
                             }
                             Err(e) => {
                                 return LoadingResult::Err(LoadingError::TimeoutError(
@@ -146,16 +258,222 @@ impl LoadingStrategy for TorrentStreamLoadingStrategy {
 mod tests {
     use std::time::Duration;
 
+    use tempfile::tempdir;
+    use tokio::runtime::Runtime;
+    use url::Url;
+
     use crate::core::{block_in_place, Handle};
+    use crate::core::config::ApplicationConfig;
+    use crate::core::events::EventPublisher;
+    use crate::core::platform::PlatformData;
     use crate::core::playlists::PlaylistItem;
-    use crate::core::torrents::{MockTorrentStreamServer, TorrentStream};
-    use crate::testing::{init_logger, MockTorrentStream};
+    use crate::core::torrents::collection::TorrentCollection;
+    use crate::core::torrents::{
+        MockTorrent, MockTorrentManager, MockTorrentStreamServer, Torrent, TorrentFileInfo,
+        TorrentManager, TorrentState, TorrentStream,
+    };
+    use crate::testing::{init_logger, MockDummyPlatformData, MockTorrentStream};
 
     use super::*;
 
+    #[test]
+    fn test_resume_pieces() {
+        assert_eq!(
+            (45..55).collect::<Vec<u32>>(),
+            TorrentStreamLoadingStrategy::resume_pieces(0.5, 100 * 1024, 1024)
+        );
+        assert_eq!(
+            (0..10).collect::<Vec<u32>>(),
+            TorrentStreamLoadingStrategy::resume_pieces(0.01, 100 * 1024, 1024)
+        );
+        assert_eq!(
+            (95..101).collect::<Vec<u32>>(),
+            TorrentStreamLoadingStrategy::resume_pieces(1.0, 100 * 1024 + 512, 1024)
+        );
+        assert_eq!(
+            Vec::<u32>::new(),
+            TorrentStreamLoadingStrategy::resume_pieces(0.5, 0, 1024)
+        );
+    }
+
+    #[test]
+    fn test_preparation_progress() {
+        let status = DownloadStatus {
+            progress: 0.25,
+            seeds: 10,
+            peers: 5,
+            download_speed: 1024,
+            upload_speed: 0,
+            downloaded: 2048,
+            total_size: 8192,
+            request_queue_depth: 24,
+        };
+
+        let result = TorrentStreamLoadingStrategy::preparation_progress(&status);
+
+        assert_eq!(
+            LoadingEvent::Progress {
+                stage: LoadingState::Downloading,
+                percent: 25.0,
+                detail: Some("2048 of 8192 bytes".to_string()),
+            },
+            result
+        );
+    }
+
+    #[test]
+    fn test_process_download_only() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut data = LoadingData::from(PlaylistItem {
+            url: None,
+            title: "MyDownload".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        });
+        let mut torrent = MockTorrent::new();
+        torrent
+            .expect_handle()
+            .return_const("MyTorrent".to_string());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        torrent
+            .expect_subscribe()
+            .times(1)
+            .return_const(Handle::new());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        data.torrent = Some(Arc::downgrade(&torrent));
+        data.download_only = true;
+        let (tx_event, rx_event) = channel();
+        let mut stream_server = MockTorrentStreamServer::new();
+        stream_server.expect_start_stream().times(0);
+        let download_notifier = create_download_notifier();
+        let strategy = TorrentStreamLoadingStrategy {
+            torrent_stream_server: Arc::new(Box::new(stream_server) as Box<dyn TorrentStreamServer>),
+            download_notifier: download_notifier.clone(),
+            auto_collector: create_auto_collector(temp_path),
+            bandwidth_history: Arc::new(BandwidthHistory::new(temp_path)),
+        };
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        let event = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(LoadingEvent::StateChanged(LoadingState::Downloading), event);
+        if let LoadingResult::Ok(result) = result {
+            assert!(
+                result.torrent.is_some(),
+                "expected the torrent to have been kept"
+            );
+            assert!(
+                result.torrent_stream.is_none(),
+                "expected no torrent stream to have been started"
+            );
+            assert!(
+                download_notifier.is_watched("MyTorrent"),
+                "expected the torrent to be watched for its completion"
+            );
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, got {:?} instead",
+                result
+            )
+        }
+    }
+
+    #[test]
+    fn test_process_auto_resume() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut data = LoadingData::from(PlaylistItem {
+            url: None,
+            title: "MyStream".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: Some(TorrentFileInfo {
+                filename: "MyVideo.mp4".to_string(),
+                file_path: "MyVideo.mp4".to_string(),
+                file_size: 200 * 1024,
+                file_index: 1,
+            }),
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        });
+        let mut torrent = MockTorrent::new();
+        torrent
+            .expect_handle()
+            .return_const("MyTorrent".to_string());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        data.torrent = Some(Arc::downgrade(&torrent));
+        data.auto_resume_progress = Some(0.25);
+        let (tx_pieces, rx_pieces) = channel();
+        let mut stream = MockTorrentStream::new();
+        stream
+            .expect_url()
+            .return_const(Url::parse("http://localhost:8080/MyVideo.mp4").unwrap());
+        stream.expect_total_pieces().return_const(500);
+        stream.expect_piece_length().return_const(1024u64);
+        stream
+            .expect_prioritize_pieces()
+            .times(1)
+            .returning(move |pieces| {
+                tx_pieces.send(pieces.to_vec()).unwrap();
+            });
+        stream
+            .expect_subscribe_stream()
+            .times(1)
+            .returning(|callback| {
+                callback(TorrentStreamEvent::StateChanged(
+                    TorrentStreamState::Streaming,
+                ));
+                Handle::new()
+            });
+        stream.expect_unsubscribe_stream().times(1).return_const(());
+        let stream = Arc::new(Box::new(stream) as Box<dyn TorrentStream>);
+        let stream_ref = Arc::downgrade(&stream);
+        let (tx_event, _rx_event) = channel();
+        let mut stream_server = MockTorrentStreamServer::new();
+        stream_server
+            .expect_start_stream()
+            .times(1)
+            .returning(move |_| Ok(stream_ref.clone()));
+        let strategy = TorrentStreamLoadingStrategy {
+            torrent_stream_server: Arc::new(Box::new(stream_server) as Box<dyn TorrentStreamServer>),
+            download_notifier: create_download_notifier(),
+            auto_collector: create_auto_collector(temp_path),
+            bandwidth_history: Arc::new(BandwidthHistory::new(temp_path)),
+        };
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        assert!(
+            matches!(result, LoadingResult::Ok(_)),
+            "expected LoadingResult::Ok, got {:?} instead",
+            result
+        );
+        let pieces = rx_pieces.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!((45..55).collect::<Vec<u32>>(), pieces);
+    }
+
     #[test]
     fn test_cancel() {
         init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
         let handle = "MyTorrentHandle";
         let stream_handle = Handle::new();
         let mut data = LoadingData::from(PlaylistItem {
@@ -188,6 +506,9 @@ mod tests {
             });
         let strategy = TorrentStreamLoadingStrategy {
             torrent_stream_server: Arc::new(Box::new(stream_server) as Box<dyn TorrentStreamServer>),
+            download_notifier: create_download_notifier(),
+            auto_collector: create_auto_collector(temp_path),
+            bandwidth_history: Arc::new(BandwidthHistory::new(temp_path)),
         };
 
         let result = block_in_place(strategy.cancel(data));
@@ -207,4 +528,23 @@ mod tests {
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(stream_handle, result);
     }
+
+    fn create_download_notifier() -> DownloadNotifier {
+        DownloadNotifier::new(
+            Arc::new(Box::new(MockDummyPlatformData::new()) as Box<dyn PlatformData>),
+            Arc::new(Runtime::new().unwrap()),
+        )
+    }
+
+    fn create_auto_collector(temp_path: &str) -> TorrentAutoCollector {
+        TorrentAutoCollector::new(
+            Arc::new(TorrentCollection::new(
+                temp_path,
+                Arc::new(Box::new(MockTorrentManager::new()) as Box<dyn TorrentManager>),
+            )),
+            Arc::new(ApplicationConfig::builder().storage(temp_path).build()),
+            Arc::new(EventPublisher::default()),
+            Arc::new(Runtime::new().unwrap()),
+        )
+    }
 }
@@ -0,0 +1,520 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use derive_more::Display;
+use log::{debug, trace, warn};
+use serde::Deserialize;
+use serde_json::json;
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+use url::Url;
+
+use crate::core::loader::{
+    CancellationResult, LoadingData, LoadingError, LoadingEvent, LoadingResult, LoadingState,
+    LoadingStrategy,
+};
+use crate::core::utils::http::HttpClientFactory;
+
+const INNERTUBE_PLAYER_URI: &str = "https://www.youtube.com/youtubei/v1/player";
+const INNERTUBE_CLIENT_NAME: &str = "ANDROID";
+const INNERTUBE_CLIENT_VERSION: &str = "19.09.37";
+const INNERTUBE_ANDROID_SDK_VERSION: u32 = 30;
+const PLAYABILITY_STATUS_OK: &str = "OK";
+const YOUTUBE_HOSTS: [&str; 4] = [
+    "youtube.com",
+    "www.youtube.com",
+    "m.youtube.com",
+    "music.youtube.com",
+];
+const YOUTUBE_SHORT_HOST: &str = "youtu.be";
+const YOUTUBE_PATH_PREFIXES: [&str; 3] = ["/embed/", "/v/", "/shorts/"];
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The youtube specific results.
+pub type YoutubeResult<T> = Result<T, YoutubeError>;
+
+/// Represents the errors which can occur while resolving a youtube video stream.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum YoutubeError {
+    #[error("failed to retrieve the youtube player info, {0}")]
+    Request(String),
+    #[error("youtube player info request timed out")]
+    Timeout,
+    #[error("failed to parse the youtube player info, {0}")]
+    Parsing(String),
+    #[error("youtube video {0} is not playable, {1}")]
+    Unplayable(String, String),
+    #[error("no direct stream available for youtube video {0}")]
+    NoStreamAvailable(String),
+}
+
+impl From<YoutubeError> for LoadingError {
+    fn from(value: YoutubeError) -> Self {
+        LoadingError::YoutubeError(value)
+    }
+}
+
+impl From<reqwest::Error> for YoutubeError {
+    fn from(value: reqwest::Error) -> Self {
+        if value.is_timeout() {
+            YoutubeError::Timeout
+        } else {
+            YoutubeError::Request(value.to_string())
+        }
+    }
+}
+
+/// A loading strategy which resolves youtube urls, such as trailers, into a direct media stream url.
+/// This allows the selected player to play the video instead of relying on a browser.
+///
+/// The strategy is skipped when the dedicated youtube video player is enabled, as that player handles youtube urls itself.
+/// Resolution failures abort the loading chain with a [LoadingError::YoutubeError].
+#[derive(Display)]
+#[display(fmt = "Youtube loading strategy")]
+pub struct YoutubeLoadingStrategy {
+    client_factory: HttpClientFactory,
+    request_timeout: Duration,
+    player_uri: String,
+    youtube_player_enabled: bool,
+}
+
+impl YoutubeLoadingStrategy {
+    /// Creates a new `YoutubeLoadingStrategy` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_factory` - The factory of the HTTP clients used to resolve the youtube videos.
+    /// * `youtube_player_enabled` - Indicates if the dedicated youtube video player is enabled.
+    ///
+    /// # Returns
+    ///
+    /// A new `YoutubeLoadingStrategy` instance.
+    pub fn new(client_factory: HttpClientFactory, youtube_player_enabled: bool) -> Self {
+        Self {
+            client_factory,
+            request_timeout: REQUEST_TIMEOUT,
+            player_uri: INNERTUBE_PLAYER_URI.to_string(),
+            youtube_player_enabled,
+        }
+    }
+
+    /// Retrieve the youtube video id from the given url.
+    ///
+    /// It returns [None] when the url is not a youtube video url.
+    fn video_id(url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+        let host = url.host_str()?.to_lowercase();
+
+        let video_id = if host == YOUTUBE_SHORT_HOST {
+            url.path()
+                .trim_start_matches('/')
+                .split('/')
+                .next()
+                .map(|e| e.to_string())
+        } else if YOUTUBE_HOSTS.contains(&host.as_str()) {
+            if url.path() == "/watch" {
+                url.query_pairs()
+                    .find(|(key, _)| key == "v")
+                    .map(|(_, value)| value.to_string())
+            } else {
+                YOUTUBE_PATH_PREFIXES
+                    .iter()
+                    .find_map(|prefix| url.path().strip_prefix(prefix))
+                    .and_then(|e| e.split('/').next())
+                    .map(|e| e.to_string())
+            }
+        } else {
+            None
+        };
+
+        video_id.filter(|e| {
+            !e.is_empty()
+                && e.chars()
+                    .all(|e| e.is_ascii_alphanumeric() || e == '-' || e == '_')
+        })
+    }
+
+    /// Resolve the direct media stream url of the given youtube video.
+    async fn resolve(&self, video_id: &str) -> YoutubeResult<String> {
+        debug!("Retrieving youtube player info for video {}", video_id);
+        let body = json!({
+            "videoId": video_id,
+            "context": {
+                "client": {
+                    "clientName": INNERTUBE_CLIENT_NAME,
+                    "clientVersion": INNERTUBE_CLIENT_VERSION,
+                    "androidSdkVersion": INNERTUBE_ANDROID_SDK_VERSION,
+                    "hl": "en",
+                }
+            },
+            "contentCheckOk": true,
+            "racyCheckOk": true,
+        });
+        let response = self
+            .client_factory
+            .client()
+            .post(self.player_uri.as_str())
+            .timeout(self.request_timeout)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|e| e.error_for_status())?;
+        let body = response.text().await?;
+
+        Self::parse_stream_url(video_id, body.as_str())
+    }
+
+    /// Parse the innertube player response and select the highest resolution stream which contains both audio and video.
+    fn parse_stream_url(video_id: &str, body: &str) -> YoutubeResult<String> {
+        let response = serde_json::from_str::<PlayerResponse>(body)
+            .map_err(|e| YoutubeError::Parsing(e.to_string()))?;
+
+        if response.playability_status.status != PLAYABILITY_STATUS_OK {
+            return Err(YoutubeError::Unplayable(
+                video_id.to_string(),
+                response
+                    .playability_status
+                    .reason
+                    .unwrap_or(response.playability_status.status),
+            ));
+        }
+
+        response
+            .streaming_data
+            .map(|e| e.formats)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| e.mime_type.starts_with("video/"))
+            .filter(|e| e.url.is_some())
+            .max_by_key(|e| e.height.unwrap_or(0))
+            .and_then(|e| {
+                trace!(
+                    "Selected youtube stream format {} ({:?}) for video {}",
+                    e.itag,
+                    e.quality_label,
+                    video_id
+                );
+                e.url
+            })
+            .ok_or(YoutubeError::NoStreamAvailable(video_id.to_string()))
+    }
+}
+
+impl Debug for YoutubeLoadingStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YoutubeLoadingStrategy")
+            .field("player_uri", &self.player_uri)
+            .field("youtube_player_enabled", &self.youtube_player_enabled)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoadingStrategy for YoutubeLoadingStrategy {
+    async fn process(
+        &self,
+        mut data: LoadingData,
+        event_channel: Sender<LoadingEvent>,
+        cancel: CancellationToken,
+    ) -> LoadingResult {
+        if self.youtube_player_enabled {
+            trace!("Youtube video player is enabled, skipping youtube url resolving");
+            return LoadingResult::Ok(data);
+        }
+
+        if let Some(video_id) = data.url.as_ref().and_then(|e| Self::video_id(e.as_str())) {
+            if cancel.is_cancelled() {
+                return LoadingResult::Err(LoadingError::Cancelled);
+            }
+
+            event_channel
+                .send(LoadingEvent::StateChanged(LoadingState::Starting))
+                .unwrap();
+            let result = tokio::select! {
+                _ = cancel.cancelled() => return LoadingResult::Err(LoadingError::Cancelled),
+                result = self.resolve(video_id.as_str()) => result,
+            };
+
+            match result {
+                Ok(stream_url) => {
+                    debug!("Resolved youtube video {} to a direct stream", video_id);
+                    data.url = Some(stream_url);
+                }
+                Err(e) => {
+                    warn!("Unable to resolve youtube video {}, {}", video_id, e);
+                    return LoadingResult::Err(LoadingError::from(e));
+                }
+            }
+        }
+
+        LoadingResult::Ok(data)
+    }
+
+    async fn cancel(&self, data: LoadingData) -> CancellationResult {
+        Ok(data)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayerResponse {
+    playability_status: PlayabilityStatus,
+    streaming_data: Option<StreamingData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayabilityStatus {
+    status: String,
+    reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamingData {
+    #[serde(default)]
+    formats: Vec<StreamFormat>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamFormat {
+    itag: u32,
+    url: Option<String>,
+    mime_type: String,
+    height: Option<u32>,
+    quality_label: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    use httpmock::Method::POST;
+    use httpmock::MockServer;
+
+    use crate::core::block_in_place;
+    use crate::core::playlists::PlaylistItem;
+    use crate::testing::{init_logger, read_test_file_to_string};
+
+    use super::*;
+
+    const EXPECTED_STREAM_URL: &str = "https://rr1---sn-5hne6nzk.googlevideo.com/videoplayback?expire=1709154348&itag=22&mime=video%2Fmp4";
+
+    #[test]
+    fn test_video_id() {
+        assert_eq!(
+            Some("dQw4w9WgXcQ".to_string()),
+            YoutubeLoadingStrategy::video_id("https://www.youtube.com/watch?v=dQw4w9WgXcQ")
+        );
+        assert_eq!(
+            Some("dQw4w9WgXcQ".to_string()),
+            YoutubeLoadingStrategy::video_id("https://youtu.be/dQw4w9WgXcQ?t=10")
+        );
+        assert_eq!(
+            Some("dQw4w9WgXcQ".to_string()),
+            YoutubeLoadingStrategy::video_id("https://youtube.com/embed/dQw4w9WgXcQ")
+        );
+        assert_eq!(
+            Some("qwe654874a".to_string()),
+            YoutubeLoadingStrategy::video_id("https://youtube.com/v/qwe654874a")
+        );
+        assert_eq!(
+            None,
+            YoutubeLoadingStrategy::video_id("https://www.youtube.com")
+        );
+        assert_eq!(
+            None,
+            YoutubeLoadingStrategy::video_id("https://vimeo.com/watch?v=dQw4w9WgXcQ")
+        );
+        assert_eq!(
+            None,
+            YoutubeLoadingStrategy::video_id(
+                "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_stream_url() {
+        let body = read_test_file_to_string("youtube-player-response.json");
+
+        let result = YoutubeLoadingStrategy::parse_stream_url("dQw4w9WgXcQ", body.as_str());
+
+        assert_eq!(Ok(EXPECTED_STREAM_URL.to_string()), result);
+    }
+
+    #[test]
+    fn test_parse_stream_url_unplayable() {
+        let body = read_test_file_to_string("youtube-player-unplayable.json");
+
+        let result = YoutubeLoadingStrategy::parse_stream_url("dQw4w9WgXcQ", body.as_str());
+
+        assert_eq!(
+            Err(YoutubeError::Unplayable(
+                "dQw4w9WgXcQ".to_string(),
+                "This video is not available".to_string()
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_parse_stream_url_invalid() {
+        let result = YoutubeLoadingStrategy::parse_stream_url("dQw4w9WgXcQ", "lorem");
+
+        if let Err(YoutubeError::Parsing(_)) = result {
+        } else {
+            assert!(
+                false,
+                "expected YoutubeError::Parsing, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_process() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/youtubei/v1/player")
+                .body_contains("dQw4w9WgXcQ");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("youtube-player-response.json"));
+        });
+        let data = create_loading_data("https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        let (tx_event, _rx_event) = channel();
+        let strategy = create_strategy(&server, false);
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        if let LoadingResult::Ok(result) = result {
+            assert_eq!(Some(EXPECTED_STREAM_URL.to_string()), result.url);
+        } else {
+            assert!(
+                false,
+                "expected LoadingResult::Ok, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_resolve_failure() {
+        init_logger();
+        let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/youtubei/v1/player");
+            then.status(500);
+        });
+        let data = create_loading_data(url);
+        let (tx_event, _rx_event) = channel();
+        let strategy = create_strategy(&server, false);
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        if let LoadingResult::Err(LoadingError::YoutubeError(YoutubeError::Request(_))) = result {
+        } else {
+            assert!(
+                false,
+                "expected LoadingError::YoutubeError, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_timeout() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/youtubei/v1/player");
+            then.status(200).delay(Duration::from_secs(5));
+        });
+        let data = create_loading_data("https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        let (tx_event, _rx_event) = channel();
+        let strategy = YoutubeLoadingStrategy {
+            client_factory: HttpClientFactory::default(),
+            request_timeout: Duration::from_millis(100),
+            player_uri: server.url("/youtubei/v1/player"),
+            youtube_player_enabled: false,
+        };
+
+        let result = block_in_place(strategy.process(data, tx_event, CancellationToken::new()));
+
+        assert_eq!(
+            LoadingResult::Err(LoadingError::YoutubeError(YoutubeError::Timeout)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_process_cancelled() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/youtubei/v1/player");
+            then.status(200).delay(Duration::from_secs(5));
+        });
+        let data = create_loading_data("https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        let (tx_event, _rx_event) = channel();
+        let strategy = create_strategy(&server, false);
+        let cancel = CancellationToken::new();
+        let cancel_trigger = cancel.clone();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            cancel_trigger.cancel();
+        });
+        let result = block_in_place(strategy.process(data, tx_event, cancel));
+
+        assert_eq!(LoadingResult::Err(LoadingError::Cancelled), result);
+    }
+
+    #[test]
+    fn test_process_youtube_player_enabled() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/youtubei/v1/player");
+            then.status(200);
+        });
+        let data = create_loading_data("https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        let (tx_event, _rx_event) = channel();
+        let strategy = create_strategy(&server, true);
+
+        let result =
+            block_in_place(strategy.process(data.clone(), tx_event, CancellationToken::new()));
+
+        assert_eq!(LoadingResult::Ok(data), result);
+        mock.assert_hits(0);
+    }
+
+    fn create_strategy(
+        server: &MockServer,
+        youtube_player_enabled: bool,
+    ) -> YoutubeLoadingStrategy {
+        YoutubeLoadingStrategy {
+            client_factory: HttpClientFactory::default(),
+            request_timeout: REQUEST_TIMEOUT,
+            player_uri: server.url("/youtubei/v1/player"),
+            youtube_player_enabled,
+        }
+    }
+
+    fn create_loading_data(url: &str) -> LoadingData {
+        LoadingData::from(
+            PlaylistItem::builder()
+                .url(url)
+                .title("Never Gonna Give You Up")
+                .build(),
+        )
+    }
+}
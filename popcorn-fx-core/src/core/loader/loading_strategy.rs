@@ -9,7 +9,9 @@ use derive_more::Display;
 use mockall::automock;
 use tokio_util::sync::CancellationToken;
 
-use crate::core::loader::{LoadingData, LoadingError, LoadingProgress, LoadingState};
+use crate::core::loader::{
+    LoadingData, LoadingError, LoadingProgress, LoadingState, QualitySelection,
+};
 
 /// An event representing a change in the loading process.
 ///
@@ -25,6 +27,19 @@ pub enum LoadingEvent {
     /// An error has occurred during the loading process.
     #[display(fmt = "Loading failed, {:?}", _0)]
     LoadingError(LoadingError),
+    /// The progress within a specific loading stage has changed.
+    #[display(fmt = "Loading stage {:?} progress changed to {}%", stage, percent)]
+    Progress {
+        /// The loading stage to which the progress applies.
+        stage: LoadingState,
+        /// The progress of the stage as a percentage between 0 and 100.
+        percent: f32,
+        /// Additional information about the progress of the stage, if available.
+        detail: Option<String>,
+    },
+    /// The quality of the media item has been selected.
+    #[display(fmt = "Loading quality {} has been selected", _0)]
+    QualitySelected(QualitySelection),
 }
 
 /// A trait for defining loading strategies for media items in a playlist.
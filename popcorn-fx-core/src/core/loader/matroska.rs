@@ -0,0 +1,276 @@
+use std::io;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use log::trace;
+
+use crate::core::players::MediaTrack;
+
+const EBML_HEADER_ID: u32 = 0x1A45DFA3;
+const EBML_DOC_TYPE_ID: u32 = 0x4282;
+const SEGMENT_ID: u32 = 0x18538067;
+const CLUSTER_ID: u32 = 0x1F43B675;
+const TRACKS_ID: u32 = 0x1654AE6B;
+const TRACK_ENTRY_ID: u32 = 0xAE;
+const TRACK_NUMBER_ID: u32 = 0xD7;
+const TRACK_TYPE_ID: u32 = 0x83;
+const TRACK_NAME_ID: u32 = 0x536E;
+const TRACK_LANGUAGE_ID: u32 = 0x22B59C;
+const TRACK_LANGUAGE_BCP47_ID: u32 = 0x22B59D;
+/// The track type value of audio tracks.
+const TRACK_TYPE_AUDIO: u64 = 2;
+/// The supported EBML document types.
+const DOC_TYPES: [&str; 2] = ["matroska", "webm"];
+/// The language of a track when the language element is absent.
+const DEFAULT_LANGUAGE: &str = "eng";
+/// The language value which indicates that the language of a track is undetermined.
+const UNDETERMINED_LANGUAGE: &str = "und";
+
+/// The header of an EBML element.
+#[derive(Debug)]
+struct Element {
+    id: u32,
+    /// The size of the element data, or [None] when the size is unknown.
+    size: Option<u64>,
+}
+
+/// Probe the audio tracks of the given Matroska/WebM container header bytes.
+///
+/// The header bytes should at least contain the `Tracks` element of the container,
+/// which is in most cases located within the first few kilobytes of the media file.
+///
+/// # Returns
+///
+/// The audio tracks of the container, or an [io::Error] when the bytes are not a valid Matroska container.
+pub fn probe_audio_tracks(header: &[u8]) -> io::Result<Vec<MediaTrack>> {
+    let mut reader = Cursor::new(header);
+    let len = header.len() as u64;
+
+    let element = read_element(&mut reader)?;
+    if element.id != EBML_HEADER_ID {
+        return Err(invalid_data("missing EBML header"));
+    }
+    let doc_type = read_doc_type(&mut reader, element.size)?;
+    if !DOC_TYPES.contains(&doc_type.as_str()) {
+        return Err(invalid_data(format!(
+            "unsupported document type {}",
+            doc_type
+        )));
+    }
+
+    let element = read_element(&mut reader)?;
+    if element.id != SEGMENT_ID {
+        return Err(invalid_data("missing segment"));
+    }
+
+    while reader.position() < len {
+        let element = read_element(&mut reader)?;
+        trace!("Reading Matroska segment element {:X}", element.id);
+        match element.id {
+            TRACKS_ID => {
+                let end = element_end(&reader, &element, len);
+                return read_audio_tracks(&mut reader, end);
+            }
+            CLUSTER_ID => break,
+            _ => skip(&mut reader, &element)?,
+        }
+    }
+
+    Err(invalid_data("tracks element not found within the header"))
+}
+
+fn read_doc_type(reader: &mut Cursor<&[u8]>, size: Option<u64>) -> io::Result<String> {
+    let size = size.ok_or_else(|| invalid_data("EBML header size is unknown"))?;
+    let end = reader.position() + size;
+    let mut doc_type = String::new();
+
+    while reader.position() < end {
+        let element = read_element(reader)?;
+        if element.id == EBML_DOC_TYPE_ID {
+            doc_type = read_string(reader, &element)?;
+        } else {
+            skip(reader, &element)?;
+        }
+    }
+
+    Ok(doc_type)
+}
+
+fn read_audio_tracks(reader: &mut Cursor<&[u8]>, end: u64) -> io::Result<Vec<MediaTrack>> {
+    let mut tracks = vec![];
+
+    while reader.position() < end {
+        let element = read_element(reader)?;
+        if element.id != TRACK_ENTRY_ID {
+            skip(reader, &element)?;
+            continue;
+        }
+
+        let entry_end = element_end(reader, &element, end);
+        let mut number: Option<u64> = None;
+        let mut track_type: Option<u64> = None;
+        let mut name: Option<String> = None;
+        let mut language: Option<String> = None;
+        let mut language_bcp47: Option<String> = None;
+
+        while reader.position() < entry_end {
+            let element = read_element(reader)?;
+            match element.id {
+                TRACK_NUMBER_ID => number = Some(read_uint(reader, &element)?),
+                TRACK_TYPE_ID => track_type = Some(read_uint(reader, &element)?),
+                TRACK_NAME_ID => name = Some(read_string(reader, &element)?),
+                TRACK_LANGUAGE_ID => language = Some(read_string(reader, &element)?),
+                TRACK_LANGUAGE_BCP47_ID => language_bcp47 = Some(read_string(reader, &element)?),
+                _ => skip(reader, &element)?,
+            }
+        }
+
+        if track_type != Some(TRACK_TYPE_AUDIO) {
+            continue;
+        }
+        if let Some(number) = number {
+            let language = language_bcp47
+                .or(language)
+                .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+            let language = Some(language).filter(|e| e.as_str() != UNDETERMINED_LANGUAGE);
+            let name = name
+                .filter(|e| !e.is_empty())
+                .or_else(|| language.clone())
+                .unwrap_or_else(|| format!("Audio track {}", tracks.len() + 1));
+
+            tracks.push(MediaTrack {
+                id: number as i32,
+                name,
+                language,
+            });
+        }
+    }
+
+    Ok(tracks)
+}
+
+fn read_element(reader: &mut Cursor<&[u8]>) -> io::Result<Element> {
+    let (id, _) = read_vint(reader, false)?;
+    let (size, unknown) = read_vint(reader, true)?;
+
+    Ok(Element {
+        id: id as u32,
+        size: if unknown { None } else { Some(size) },
+    })
+}
+
+/// Read an EBML variable size integer.
+///
+/// It returns the integer value and if all value bits are set, which indicates an unknown element size.
+fn read_vint(reader: &mut Cursor<&[u8]>, strip_marker: bool) -> io::Result<(u64, bool)> {
+    let mut first = [0u8; 1];
+    reader.read_exact(&mut first)?;
+    let len = first[0].leading_zeros() as usize + 1;
+    if len > 8 {
+        return Err(invalid_data("invalid variable size integer"));
+    }
+
+    let marker_mask = (0xFFu16 >> len) as u64;
+    let mut value = if strip_marker {
+        first[0] as u64 & marker_mask
+    } else {
+        first[0] as u64
+    };
+    let mut all_ones = value == marker_mask;
+    let mut remaining = vec![0u8; len - 1];
+    reader.read_exact(&mut remaining)?;
+    for byte in remaining {
+        value = (value << 8) | byte as u64;
+        all_ones &= byte == 0xFF;
+    }
+
+    Ok((value, strip_marker && all_ones))
+}
+
+fn read_uint(reader: &mut Cursor<&[u8]>, element: &Element) -> io::Result<u64> {
+    let data = read_data(reader, element)?;
+    if data.len() > 8 {
+        return Err(invalid_data("invalid unsigned integer element"));
+    }
+
+    Ok(data.iter().fold(0u64, |value, e| (value << 8) | *e as u64))
+}
+
+fn read_string(reader: &mut Cursor<&[u8]>, element: &Element) -> io::Result<String> {
+    let data = read_data(reader, element)?;
+    Ok(String::from_utf8_lossy(&data)
+        .trim_end_matches('\0')
+        .to_string())
+}
+
+fn read_data(reader: &mut Cursor<&[u8]>, element: &Element) -> io::Result<Vec<u8>> {
+    let size = element
+        .size
+        .ok_or_else(|| invalid_data("element size is unknown"))?;
+    let mut data = vec![0u8; size as usize];
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+fn skip(reader: &mut Cursor<&[u8]>, element: &Element) -> io::Result<()> {
+    let size = element
+        .size
+        .ok_or_else(|| invalid_data("unable to skip element of unknown size"))?;
+    reader.seek(SeekFrom::Current(size as i64))?;
+    Ok(())
+}
+
+/// Retrieve the end position of the given element, limited by the given parent end position.
+fn element_end(reader: &Cursor<&[u8]>, element: &Element, parent_end: u64) -> u64 {
+    element
+        .size
+        .map(|e| reader.position() + e)
+        .unwrap_or(parent_end)
+        .min(parent_end)
+}
+
+fn invalid_data<S: Into<String>>(message: S) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::read_test_file_to_bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_probe_audio_tracks() {
+        let header = read_test_file_to_bytes("two-audio-tracks.mkv");
+
+        let result = probe_audio_tracks(&header).unwrap();
+
+        assert_eq!(
+            vec![
+                MediaTrack {
+                    id: 2,
+                    name: "English 5.1".to_string(),
+                    language: Some("eng".to_string()),
+                },
+                MediaTrack {
+                    id: 3,
+                    name: "jpn".to_string(),
+                    language: Some("jpn".to_string()),
+                },
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_probe_audio_tracks_invalid_container() {
+        let header = read_test_file_to_bytes("simple.txt");
+
+        let result = probe_audio_tracks(&header);
+
+        assert!(
+            result.is_err(),
+            "expected an error to be returned, got {:?} instead",
+            result
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -11,12 +12,10 @@ use tokio::runtime::Runtime;
 use tokio::sync::Mutex;
 
 use crate::core::{block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks, Handle};
-use crate::core::loader::{LoadingData, LoadingEvent, LoadingStrategy};
+use crate::core::loader::{LoadingData, LoadingEvent, LoadingStrategy, QualitySelection, YoutubeError};
 use crate::core::loader::loading_chain::{LoadingChain, Order};
 use crate::core::loader::task::LoadingTask;
-use crate::core::media::{
-    Episode, Images, MediaIdentifier, MediaOverview, MovieDetails, ShowDetails,
-};
+use crate::core::media::{Episode, Images, MediaIdentifier, MediaOverview, MovieDetails, ShowDetails};
 use crate::core::playlists::PlaylistItem;
 use crate::core::torrents::{DownloadStatus, Magnet, TorrentError};
 
@@ -53,6 +52,12 @@ pub enum LoaderEvent {
     /// Indicates that an error has occurred during loading with the associated error details.
     #[display(fmt = "Loading {} encountered an error, {}", _0, _1)]
     LoadingError(LoadingHandle, LoadingError),
+    /// Indicates a change in the progress of a specific loading stage.
+    #[display(fmt = "Loading stage progress changed to {}", _1)]
+    StageProgressChanged(LoadingHandle, LoadingStageProgress),
+    /// Indicates that the quality of the media item has been selected.
+    #[display(fmt = "Loading quality selected {}", _1)]
+    QualitySelected(LoadingHandle, QualitySelection),
 }
 
 /// Represents the result of a loading strategy's processing.
@@ -206,6 +211,18 @@ impl From<DownloadStatus> for LoadingProgress {
     }
 }
 
+/// The progress of a specific stage within the loading process.
+#[derive(Debug, Clone, Display, PartialEq)]
+#[display(fmt = "stage: {:?}, percent: {}, detail: {:?}", stage, percent, detail)]
+pub struct LoadingStageProgress {
+    /// The loading stage to which the progress applies.
+    pub stage: LoadingState,
+    /// The progress of the stage as a percentage between 0 and 100.
+    pub percent: f32,
+    /// Additional information about the progress of the stage, if available.
+    pub detail: Option<String>,
+}
+
 /// Represents an error that may occur during media item loading.
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum LoadingError {
@@ -215,6 +232,8 @@ pub enum LoadingError {
     TorrentError(TorrentError),
     #[error("Failed to process media information, {0}")]
     MediaError(String),
+    #[error("Failed to resolve youtube video, {0}")]
+    YoutubeError(YoutubeError),
     #[error("Loading timed-out, {0}")]
     TimeoutError(String),
     #[error("Loading data is invalid, {0}")]
@@ -228,6 +247,22 @@ pub enum LoadingError {
 /// This handle is used to identify and manage individual loading processes.
 pub type LoadingHandle = Handle;
 
+/// The policy which is applied when a new media item is loaded while another item is still loading.
+///
+/// Downloads without playback are not affected by the policy and are always started right away.
+#[repr(C)]
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq)]
+pub enum LoadingPolicy {
+    /// Start the new load right away, next to the other loading tasks.
+    /// The loading tasks aren't queued, so they're not tracked as active or queued task.
+    Concurrent,
+    /// Cancel the active and queued loading tasks in favor of the new load.
+    #[default]
+    CancelActive,
+    /// Queue the new load behind the active and already queued loading tasks.
+    Queue,
+}
+
 #[cfg_attr(any(test, feature = "testing"), automock)]
 /// A trait for managing media loading in a playlist.
 ///
@@ -262,6 +297,37 @@ pub trait MediaLoader: Debug + Send + Sync {
     /// Returns a `LoadingHandle` representing the loading process associated with the loaded item.
     fn load_playlist_item(&self, item: PlaylistItem) -> LoadingHandle;
 
+    /// Download a media item in the playlist without streaming or playing it.
+    ///
+    /// The torrent of the item is downloaded in the background without the streaming overhead, such as the sequential download mode.
+    /// Loading the same item afterwards through [MediaLoader::load_playlist_item] reuses the already downloaded pieces.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The playlist item to be downloaded.
+    ///
+    /// Returns a `LoadingHandle` representing the loading process associated with the downloaded item.
+    fn download_playlist_item(&self, item: PlaylistItem) -> LoadingHandle;
+
+    /// Update the policy which is applied when a new media item is loaded while another item is still loading.
+    /// The policy only applies to new loads, the active and queued loading tasks are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The new loading policy.
+    fn set_policy(&self, policy: LoadingPolicy);
+
+    /// Get the handle of the loading task which is currently being processed.
+    /// Loading tasks which are started with the [LoadingPolicy::Concurrent] policy are never active.
+    ///
+    /// Returns the `LoadingHandle` of the active loading task, or `None` when no media item is being loaded.
+    fn active_task(&self) -> Option<LoadingHandle>;
+
+    /// Get the handles of the loading tasks which are waiting for the active loading task to complete.
+    ///
+    /// Returns the queued `LoadingHandle`s in the order in which they will be started.
+    fn queued_tasks(&self) -> Vec<LoadingHandle>;
+
     /// Get the current loading state for a specific loading process represented by the provided `LoadingHandle`.
     ///
     /// # Arguments
@@ -294,6 +360,7 @@ pub trait MediaLoader: Debug + Send + Sync {
     fn unsubscribe_loading(&self, handle: LoadingHandle, callback_handle: CallbackHandle);
 
     /// Cancel the loading process associated with the provided `LoadingHandle`.
+    /// A queued loading process which hasn't been started yet is cancelled immediately.
     ///
     /// # Arguments
     ///
@@ -312,6 +379,12 @@ impl DefaultMediaLoader {
             inner: Arc::new(InnerMediaLoader::new(loading_chain)),
         }
     }
+
+    /// Set the policy which is applied when a new media item is loaded while another item is still loading.
+    pub fn with_policy(self, policy: LoadingPolicy) -> Self {
+        self.inner.set_policy(policy);
+        self
+    }
 }
 
 #[async_trait]
@@ -332,6 +405,22 @@ impl MediaLoader for DefaultMediaLoader {
         self.inner.load_playlist_item(item)
     }
 
+    fn download_playlist_item(&self, item: PlaylistItem) -> LoadingHandle {
+        self.inner.download_playlist_item(item)
+    }
+
+    fn set_policy(&self, policy: LoadingPolicy) {
+        self.inner.set_policy(policy)
+    }
+
+    fn active_task(&self) -> Option<LoadingHandle> {
+        self.inner.active_task()
+    }
+
+    fn queued_tasks(&self) -> Vec<LoadingHandle> {
+        self.inner.queued_tasks()
+    }
+
     fn state(&self, handle: LoadingHandle) -> Option<LoadingState> {
         self.inner.state(handle)
     }
@@ -353,10 +442,20 @@ impl MediaLoader for DefaultMediaLoader {
     }
 }
 
+/// A loading task which is waiting for the active loading task to complete.
 #[derive(Debug)]
+struct QueuedTask {
+    task: Arc<LoadingTask>,
+    data: LoadingData,
+}
+
+#[derive(Debug, Clone)]
 struct InnerMediaLoader {
     loading_chain: Arc<LoadingChain>,
+    policy: Arc<Mutex<LoadingPolicy>>,
     tasks: Arc<Mutex<Vec<Arc<LoadingTask>>>>,
+    active_task: Arc<Mutex<Option<Arc<LoadingTask>>>>,
+    queued_tasks: Arc<Mutex<VecDeque<QueuedTask>>>,
     callbacks: CoreCallbacks<LoaderEvent>,
     runtime: Arc<Runtime>,
 }
@@ -365,7 +464,10 @@ impl InnerMediaLoader {
     fn new(loading_chain: Vec<Box<dyn LoadingStrategy>>) -> Self {
         Self {
             loading_chain: Arc::new(LoadingChain::from(loading_chain)),
+            policy: Default::default(),
             tasks: Arc::new(Mutex::new(Vec::default())),
+            active_task: Default::default(),
+            queued_tasks: Default::default(),
             callbacks: Default::default(),
             runtime: Arc::new(
                 tokio::runtime::Builder::new_multi_thread()
@@ -407,13 +509,66 @@ impl InnerMediaLoader {
                 LoadingEvent::LoadingError(e) => {
                     loader_event = LoaderEvent::LoadingError(task_callback_handle, e)
                 }
+                LoadingEvent::Progress {
+                    stage,
+                    percent,
+                    detail,
+                } => {
+                    loader_event = LoaderEvent::StageProgressChanged(
+                        task_callback_handle,
+                        LoadingStageProgress {
+                            stage,
+                            percent,
+                            detail,
+                        },
+                    )
+                }
+                LoadingEvent::QualitySelected(e) => {
+                    loader_event = LoaderEvent::QualitySelected(task_callback_handle, e)
+                }
             }
 
             task_callbacks.invoke(loader_event);
         }));
 
-        let tasks = self.tasks.clone();
-        let callbacks = self.callbacks.clone();
+        let policy = *block_in_place(self.policy.lock());
+        // downloads don't compete for the player, so they're started right away
+        if data.download_only || policy == LoadingPolicy::Concurrent {
+            self.run_task(task, data);
+        } else {
+            if policy == LoadingPolicy::CancelActive {
+                self.cancel_pending_tasks();
+            }
+
+            debug!("Queueing loading task {}", loading_handle);
+            block_in_place(self.queued_tasks.lock()).push_back(QueuedTask { task, data });
+            self.start_next_task();
+        }
+
+        self.callbacks.invoke(LoaderEvent::LoadingStarted(
+            loading_handle.clone(),
+            started_event,
+        ));
+        loading_handle
+    }
+
+    /// Start the next queued loading task if no other loading task is active.
+    fn start_next_task(&self) {
+        let mut active_task = block_in_place(self.active_task.lock());
+        if let Some(task) = active_task.as_ref() {
+            trace!("Loading task {} is still active", task.handle());
+            return;
+        }
+
+        if let Some(next) = block_in_place(self.queued_tasks.lock()).pop_front() {
+            debug!("Starting loading task {}", next.task.handle());
+            *active_task = Some(next.task.clone());
+            self.run_task(next.task, next.data);
+        }
+    }
+
+    fn run_task(&self, task: Arc<LoadingTask>, data: LoadingData) {
+        let loader = self.clone();
         self.runtime.spawn(async move {
             let task_handle = task.handle();
             match task.load(data).await {
@@ -422,23 +577,57 @@ impl InnerMediaLoader {
                 }
                 Err(e) => {
                     error!("Loading task {} failed, {}", task_handle, e);
-                    callbacks.invoke(LoaderEvent::LoadingError(task_handle, e));
+                    loader
+                        .callbacks
+                        .invoke(LoaderEvent::LoadingError(task_handle, e));
                 }
             }
 
             trace!("Removing task handle of {}", task_handle);
-            Self::remove_task(task_handle, tasks);
+            loader.remove_task(task_handle);
+            loader.on_task_finished(task_handle);
         });
+    }
 
-        self.callbacks.invoke(LoaderEvent::LoadingStarted(
-            loading_handle.clone(),
-            started_event,
-        ));
-        loading_handle
+    fn on_task_finished(&self, handle: LoadingHandle) {
+        {
+            let mut active_task = block_in_place(self.active_task.lock());
+            if active_task.as_ref().map(|e| e.handle()) != Some(handle) {
+                return;
+            }
+
+            *active_task = None;
+        }
+
+        self.start_next_task();
+    }
+
+    /// Cancel the queued loading tasks and the active loading task.
+    fn cancel_pending_tasks(&self) {
+        let queued_tasks: Vec<QueuedTask> =
+            block_in_place(self.queued_tasks.lock()).drain(..).collect();
+        for queued_task in queued_tasks.into_iter() {
+            self.cancel_queued_task(queued_task);
+        }
+
+        if let Some(task) = block_in_place(self.active_task.lock()).as_ref() {
+            info!("Cancelling active loading task {}", task.handle());
+            task.cancel();
+        }
+    }
+
+    /// Cancel the given queued loading task.
+    /// The task has never been started, so no loading strategies need to be cancelled.
+    fn cancel_queued_task(&self, queued_task: QueuedTask) {
+        let handle = queued_task.task.handle();
+        info!("Cancelling queued loading task {}", handle);
+        self.remove_task(handle);
+        self.callbacks
+            .invoke(LoaderEvent::LoadingError(handle, LoadingError::Cancelled));
     }
 
-    fn remove_task(handle: LoadingHandle, tasks: Arc<Mutex<Vec<Arc<LoadingTask>>>>) {
-        let mut tasks = block_in_place(tasks.lock());
+    fn remove_task(&self, handle: LoadingHandle) {
+        let mut tasks = block_in_place(self.tasks.lock());
         let position = tasks.iter().position(|e| e.handle() == handle);
 
         if let Some(position) = position {
@@ -468,6 +657,31 @@ impl MediaLoader for InnerMediaLoader {
         self.do_internal_load(LoadingData::from(item))
     }
 
+    fn download_playlist_item(&self, item: PlaylistItem) -> LoadingHandle {
+        trace!("Starting download procedure for {}", item);
+        let mut data = LoadingData::from(item);
+        data.download_only = true;
+        self.do_internal_load(data)
+    }
+
+    fn set_policy(&self, policy: LoadingPolicy) {
+        debug!("Updating media loader policy to {}", policy);
+        *block_in_place(self.policy.lock()) = policy;
+    }
+
+    fn active_task(&self) -> Option<LoadingHandle> {
+        block_in_place(self.active_task.lock())
+            .as_ref()
+            .map(|e| e.handle())
+    }
+
+    fn queued_tasks(&self) -> Vec<LoadingHandle> {
+        block_in_place(self.queued_tasks.lock())
+            .iter()
+            .map(|e| e.task.handle())
+            .collect()
+    }
+
     fn state(&self, handle: LoadingHandle) -> Option<LoadingState> {
         block_in_place(self.tasks.lock())
             .iter()
@@ -497,6 +711,18 @@ impl MediaLoader for InnerMediaLoader {
     }
 
     fn cancel(&self, handle: LoadingHandle) {
+        let queued_task = {
+            let mut queued_tasks = block_in_place(self.queued_tasks.lock());
+            queued_tasks
+                .iter()
+                .position(|e| e.task.handle() == handle)
+                .and_then(|position| queued_tasks.remove(position))
+        };
+        if let Some(queued_task) = queued_task {
+            self.cancel_queued_task(queued_task);
+            return;
+        }
+
         if let Some(task) = block_in_place(self.tasks.lock())
             .iter()
             .find(|e| e.handle() == handle)
@@ -509,15 +735,40 @@ impl MediaLoader for InnerMediaLoader {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::mpsc::channel;
+    use std::sync::mpsc::{channel, Sender};
     use std::time::Duration;
 
+    use tokio_util::sync::CancellationToken;
+
     use crate::core::loader::loading_chain::DEFAULT_ORDER;
     use crate::core::loader::MockLoadingStrategy;
     use crate::testing::init_logger;
 
     use super::*;
 
+    #[derive(Debug, Display)]
+    #[display(fmt = "DelayStrategy")]
+    struct DelayStrategy {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl LoadingStrategy for DelayStrategy {
+        async fn process(
+            &self,
+            data: LoadingData,
+            _: Sender<LoadingEvent>,
+            _: CancellationToken,
+        ) -> LoadingResult {
+            tokio::time::sleep(self.delay).await;
+            LoadingResult::Ok(data)
+        }
+
+        async fn cancel(&self, data: LoadingData) -> CancellationResult {
+            CancellationResult::Ok(data)
+        }
+    }
+
     #[test]
     fn test_load_data_from_str() {
         init_logger();
@@ -533,11 +784,14 @@ mod tests {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: None,
+            auto_resume_progress: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
             subtitles_enabled: None,
             subtitle: None,
+            audio_tracks: None,
+            download_only: false,
         };
 
         let result = LoadingData::from(url);
@@ -572,11 +826,14 @@ mod tests {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: None,
+            auto_resume_progress: None,
             subtitles_enabled: Some(false),
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         };
 
         let result = LoadingData::from(item);
@@ -620,6 +877,38 @@ mod tests {
         assert_eq!(expected_result, result);
     }
 
+    #[test]
+    fn test_download_playlist_item() {
+        init_logger();
+        let item = PlaylistItem {
+            url: None,
+            title: "LoremIpsum".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+        let (tx, rx) = channel();
+        let mut strategy = MockLoadingStrategy::new();
+        strategy.expect_process().returning(move |e, _, _| {
+            tx.send(e).unwrap();
+            LoadingResult::Completed
+        });
+        let chain: Vec<Box<dyn LoadingStrategy>> = vec![Box::new(strategy)];
+        let loader = DefaultMediaLoader::new(chain);
+
+        let _ = loader.download_playlist_item(item);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(true, result.download_only);
+        assert_eq!(Some("LoremIpsum".to_string()), result.title);
+    }
+
     #[test]
     fn test_load_playlist_item_bind_task_events() {
         init_logger();
@@ -672,4 +961,162 @@ mod tests {
         let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(expected_result, result);
     }
+
+    #[test]
+    fn test_load_playlist_item_stage_progress() {
+        init_logger();
+        let (tx, rx) = channel();
+        let (tx_event, rx_event) = channel();
+        let item = PlaylistItem::builder().url("").title("").build();
+        let expected_result = LoadingStageProgress {
+            stage: LoadingState::DownloadingSubtitle,
+            percent: 50.0,
+            detail: Some("lorem.srt".to_string()),
+        };
+        let mut strategy = MockLoadingStrategy::new();
+        strategy
+            .expect_process()
+            .times(1)
+            .returning(Box::new(move |_, event_channel, _| {
+                tx.send(event_channel).unwrap();
+                LoadingResult::Completed
+            }));
+        let loader = DefaultMediaLoader::new(vec![]);
+
+        loader.subscribe(Box::new(move |e| {
+            if let LoaderEvent::StageProgressChanged(_, e) = e {
+                tx_event.send(e).unwrap();
+            }
+        }));
+        loader.add(Box::new(strategy), DEFAULT_ORDER);
+        let _ = loader.load_playlist_item(item);
+        let callback = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+
+        callback
+            .send(LoadingEvent::Progress {
+                stage: LoadingState::DownloadingSubtitle,
+                percent: 50.0,
+                detail: Some("lorem.srt".to_string()),
+            })
+            .unwrap();
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn test_load_playlist_item_concurrent() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut player_strategy = MockLoadingStrategy::new();
+        player_strategy.expect_process().returning(move |e, _, _| {
+            tx.send(e.title.unwrap()).unwrap();
+            LoadingResult::Completed
+        });
+        let chain: Vec<Box<dyn LoadingStrategy>> = vec![
+            Box::new(DelayStrategy {
+                delay: Duration::from_millis(100),
+            }),
+            Box::new(player_strategy),
+        ];
+        let loader = DefaultMediaLoader::new(chain).with_policy(LoadingPolicy::Concurrent);
+
+        let _ = loader.load_playlist_item(PlaylistItem::builder().url("").title("1").build());
+        let _ = loader.load_playlist_item(PlaylistItem::builder().url("").title("2").build());
+        assert_eq!(None, loader.active_task());
+        assert_eq!(Vec::<LoadingHandle>::new(), loader.queued_tasks());
+
+        let mut result = vec![
+            rx.recv_timeout(Duration::from_millis(500)).unwrap(),
+            rx.recv_timeout(Duration::from_millis(500)).unwrap(),
+        ];
+        result.sort();
+        assert_eq!(vec!["1".to_string(), "2".to_string()], result);
+    }
+
+    #[test]
+    fn test_load_playlist_item_cancel_active() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut player_strategy = MockLoadingStrategy::new();
+        player_strategy.expect_process().returning(move |e, _, _| {
+            tx.send(e.title.unwrap()).unwrap();
+            LoadingResult::Completed
+        });
+        let chain: Vec<Box<dyn LoadingStrategy>> = vec![
+            Box::new(DelayStrategy {
+                delay: Duration::from_millis(100),
+            }),
+            Box::new(player_strategy),
+        ];
+        let loader = DefaultMediaLoader::new(chain);
+
+        let _ = loader.load_playlist_item(PlaylistItem::builder().url("").title("1").build());
+        let _ = loader.load_playlist_item(PlaylistItem::builder().url("").title("2").build());
+        let _ = loader.load_playlist_item(PlaylistItem::builder().url("").title("3").build());
+
+        let result = rx.recv_timeout(Duration::from_millis(500)).unwrap();
+        assert_eq!("3".to_string(), result);
+        let result = rx.recv_timeout(Duration::from_millis(300));
+        assert!(
+            result.is_err(),
+            "expected only the last item to reach the player, got {:?} instead",
+            result
+        );
+    }
+
+    #[test]
+    fn test_load_playlist_item_queue() {
+        init_logger();
+        let (tx_event, rx_event) = channel();
+        let chain: Vec<Box<dyn LoadingStrategy>> = vec![Box::new(DelayStrategy {
+            delay: Duration::from_secs(30),
+        })];
+        let loader = DefaultMediaLoader::new(chain).with_policy(LoadingPolicy::Queue);
+
+        loader.subscribe(Box::new(move |e| match e {
+            LoaderEvent::LoadingError(_, _) | LoaderEvent::StateChanged(_, _) => {
+                tx_event.send(e).unwrap()
+            }
+            _ => {}
+        }));
+        let handle1 = loader.load_playlist_item(PlaylistItem::builder().url("").title("1").build());
+        let handle2 = loader.load_playlist_item(PlaylistItem::builder().url("").title("2").build());
+        let handle3 = loader.load_playlist_item(PlaylistItem::builder().url("").title("3").build());
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            LoaderEvent::StateChanged(handle1, LoadingState::Initializing),
+            result
+        );
+
+        assert_eq!(Some(handle1), loader.active_task());
+        assert_eq!(vec![handle2, handle3], loader.queued_tasks());
+        assert_eq!(Some(LoadingState::Initializing), loader.state(handle3));
+
+        loader.cancel(handle2);
+        assert_eq!(
+            vec![handle3],
+            loader.queued_tasks(),
+            "expected the queued task to have been cancelled immediately"
+        );
+        let result = rx_event.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(
+            LoaderEvent::LoadingError(handle2, LoadingError::Cancelled),
+            result
+        );
+
+        loader.cancel(handle1);
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            LoaderEvent::LoadingError(handle1, LoadingError::Cancelled),
+            result
+        );
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            LoaderEvent::StateChanged(handle3, LoadingState::Initializing),
+            result,
+            "expected the next queued task to have been started"
+        );
+        assert_eq!(Some(handle3), loader.active_task());
+        assert_eq!(Vec::<LoadingHandle>::new(), loader.queued_tasks());
+    }
 }
@@ -1,5 +1,7 @@
 pub use data::*;
+pub use loader_audio_tracks::*;
 pub use loader_auto_resume::*;
+pub use loader_direct_url::*;
 pub use loader_media_torrent::*;
 pub use loader_player::*;
 pub use loader_subtitles::*;
@@ -7,12 +9,16 @@ pub use loader_torrent::*;
 pub use loader_torrent_details::*;
 pub use loader_torrent_info::*;
 pub use loader_torrent_stream::*;
+pub use loader_youtube::*;
 pub use loading_chain::*;
 pub use loading_strategy::*;
 pub use media_loader::*;
+pub use quality::*;
 
 mod data;
+mod loader_audio_tracks;
 mod loader_auto_resume;
+mod loader_direct_url;
 mod loader_media_torrent;
 mod loader_player;
 mod loader_subtitles;
@@ -20,7 +26,10 @@ mod loader_torrent;
 mod loader_torrent_details;
 mod loader_torrent_info;
 mod loader_torrent_stream;
+mod loader_youtube;
 mod loading_chain;
 mod loading_strategy;
+mod matroska;
 mod media_loader;
+mod quality;
 mod task;
@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use log::{debug, error, info, trace, warn};
+use tokio::sync::Mutex;
+
+use crate::core::block_in_place;
+use crate::core::config::Quality;
+use crate::core::media::TorrentInfo;
+use crate::core::storage::{Storage, StorageError};
+
+const FILENAME: &str = "bandwidth-history.json";
+/// The maximum number of bandwidth samples which are kept for the rolling estimate.
+const MAX_BANDWIDTH_SAMPLES: usize = 10;
+/// The highest quality which is selected in auto mode when no bandwidth has been measured yet.
+const DEFAULT_AUTO_QUALITY: Quality = Quality::P1080;
+/// The minimum number of seeds a torrent should have to be considered healthy.
+const MIN_HEALTHY_SEEDS: u32 = 5;
+/// The quality value which requests the automatic selection of the quality.
+pub const AUTO_QUALITY: &str = "auto";
+
+/// The preferred quality to use when loading a media item.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreferredQuality {
+    /// Automatically select the quality based on the measured bandwidth and torrent health.
+    Auto,
+    /// Use the given quality, or the closest available quality if it's not available.
+    Fixed(Quality),
+}
+
+impl Display for PreferredQuality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreferredQuality::Auto => write!(f, "Auto"),
+            PreferredQuality::Fixed(e) => write!(f, "{}p", e.resolution()),
+        }
+    }
+}
+
+/// The quality which has been selected for a media item, including the reasons for the decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualitySelection {
+    /// The selected quality as known within the torrents of the media item, e.g. `1080p`.
+    pub quality: String,
+    /// Indicates if the quality has been selected automatically.
+    pub auto: bool,
+    /// The reasons which lead to the selected quality.
+    pub reasons: Vec<String>,
+}
+
+impl Display for QualitySelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.auto {
+            write!(f, "Auto ({})", self.quality)
+        } else {
+            write!(f, "{}", self.quality)
+        }
+    }
+}
+
+/// A persisted rolling history of the download bandwidth measured during previous torrent sessions.
+#[derive(Debug)]
+pub struct BandwidthHistory {
+    storage: Storage,
+    samples: Mutex<Vec<u32>>,
+}
+
+impl BandwidthHistory {
+    /// Create a new bandwidth history.
+    ///
+    /// * `storage_path` - The directory to use to read & store the bandwidth history.
+    pub fn new(storage_path: &str) -> Self {
+        let storage = Storage::from(storage_path);
+        let samples = match storage.options().serializer(FILENAME).read::<Vec<u32>>() {
+            Ok(e) => {
+                debug!("Loaded {} bandwidth samples", e.len());
+                e
+            }
+            Err(error) => {
+                match error {
+                    StorageError::NotFound(file) => {
+                        debug!("Creating new bandwidth history file {}", file);
+                    }
+                    _ => {
+                        warn!("Failed to load bandwidth history, {}", error);
+                    }
+                }
+
+                vec![]
+            }
+        };
+
+        Self {
+            storage,
+            samples: Mutex::new(samples),
+        }
+    }
+
+    /// Retrieve the estimated download bandwidth in bytes per second.
+    ///
+    /// It returns `None` when no bandwidth has been measured yet.
+    pub fn estimate(&self) -> Option<u32> {
+        let samples = block_in_place(self.samples.lock());
+        if samples.is_empty() {
+            return None;
+        }
+
+        let total: u64 = samples.iter().map(|e| *e as u64).sum();
+        Some((total / samples.len() as u64) as u32)
+    }
+
+    /// Record the given download bandwidth, in bytes per second, of a torrent session.
+    /// Only the last [MAX_BANDWIDTH_SAMPLES] samples are kept.
+    pub fn record(&self, bandwidth: u32) {
+        if bandwidth == 0 {
+            trace!("Ignoring empty bandwidth sample");
+            return;
+        }
+
+        block_in_place(async {
+            let mut samples = self.samples.lock().await;
+            samples.push(bandwidth);
+            if samples.len() > MAX_BANDWIDTH_SAMPLES {
+                let overflow = samples.len() - MAX_BANDWIDTH_SAMPLES;
+                samples.drain(..overflow);
+            }
+
+            debug!("Recorded bandwidth sample of {} bytes/s", bandwidth);
+            match self
+                .storage
+                .options()
+                .serializer(FILENAME)
+                .write_async(&*samples)
+                .await
+            {
+                Ok(_) => trace!("Bandwidth history has been saved"),
+                Err(e) => error!("Failed to save bandwidth history, {}", e),
+            }
+        })
+    }
+}
+
+/// Selects the quality to load for a media item based on the preferred quality.
+///
+/// In auto mode, the highest quality is selected which is supported by the estimated bandwidth
+/// of previous torrent sessions and of which the torrent is healthy.
+#[derive(Debug)]
+pub struct QualitySelector {
+    preferred_quality: Mutex<PreferredQuality>,
+    bandwidth_history: Arc<BandwidthHistory>,
+}
+
+impl QualitySelector {
+    /// Create a new quality selector which prefers the automatic selection of the quality.
+    pub fn new(bandwidth_history: Arc<BandwidthHistory>) -> Self {
+        Self {
+            preferred_quality: Mutex::new(PreferredQuality::Auto),
+            bandwidth_history,
+        }
+    }
+
+    /// Retrieve the preferred quality of the selector.
+    pub fn preferred_quality(&self) -> PreferredQuality {
+        block_in_place(self.preferred_quality.lock()).clone()
+    }
+
+    /// Update the preferred quality of the selector.
+    pub fn set_preferred_quality(&self, quality: PreferredQuality) {
+        info!("Updating preferred quality to {}", quality);
+        *block_in_place(self.preferred_quality.lock()) = quality;
+    }
+
+    /// Select the quality to load from the given media torrents.
+    ///
+    /// It returns `None` when none of the torrents has a known quality.
+    pub fn select(
+        &self,
+        preferred: &PreferredQuality,
+        torrents: &HashMap<String, TorrentInfo>,
+    ) -> Option<QualitySelection> {
+        let mut candidates: Vec<(Quality, &String, &TorrentInfo)> = torrents
+            .iter()
+            .filter_map(|(key, info)| Self::parse_quality(key).map(|e| (e, key, info)))
+            .collect();
+        candidates.sort_by(|a, b| b.0.resolution().cmp(&a.0.resolution()));
+        trace!("Selecting {} quality from {:?}", preferred, candidates);
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        match preferred {
+            PreferredQuality::Auto => Some(self.select_auto(&candidates)),
+            PreferredQuality::Fixed(quality) => Some(Self::select_fixed(quality, &candidates)),
+        }
+    }
+
+    fn select_fixed(
+        quality: &Quality,
+        candidates: &Vec<(Quality, &String, &TorrentInfo)>,
+    ) -> QualitySelection {
+        let label = PreferredQuality::Fixed(quality.clone()).to_string();
+        let mut reasons = vec![];
+        let key = match candidates.iter().find(|(e, _, _)| e == quality) {
+            Some((_, key, _)) => {
+                reasons.push(format!("preferred quality {} is available", label));
+                *key
+            }
+            None => {
+                reasons.push(format!("preferred quality {} is unavailable", label));
+                candidates
+                    .iter()
+                    .find(|(e, _, _)| e.resolution() < quality.resolution())
+                    .or(candidates.last())
+                    .map(|(_, key, _)| *key)
+                    .expect("expected at least one candidate")
+            }
+        };
+
+        QualitySelection {
+            quality: key.clone(),
+            auto: false,
+            reasons,
+        }
+    }
+
+    fn select_auto(&self, candidates: &Vec<(Quality, &String, &TorrentInfo)>) -> QualitySelection {
+        let estimate = self.bandwidth_history.estimate();
+        let health_known = candidates
+            .iter()
+            .any(|(_, _, info)| *info.seed() > 0 || *info.peer() > 0);
+        let mut reasons = vec![];
+
+        match estimate {
+            Some(e) => reasons.push(format!("estimated bandwidth is {} KB/s", e / 1000)),
+            None => reasons.push(format!(
+                "no bandwidth has been measured yet, limiting to {}p",
+                DEFAULT_AUTO_QUALITY.resolution()
+            )),
+        }
+        if !health_known {
+            reasons.push("torrent health is unknown".to_string());
+        }
+
+        let mut supported = vec![];
+        for (quality, key, info) in candidates.iter() {
+            let bandwidth_supported = match estimate {
+                Some(e) => e >= Self::required_bandwidth(quality),
+                None => quality.resolution() <= DEFAULT_AUTO_QUALITY.resolution(),
+            };
+            if !bandwidth_supported {
+                if estimate.is_some() {
+                    reasons.push(format!(
+                        "{} requires {} KB/s",
+                        key,
+                        Self::required_bandwidth(quality) / 1000
+                    ));
+                }
+                continue;
+            }
+
+            if health_known && *info.seed() < MIN_HEALTHY_SEEDS {
+                reasons.push(format!("{} has only {} seeds", key, info.seed()));
+                supported.push((key, info));
+                continue;
+            }
+
+            return QualitySelection {
+                quality: (*key).clone(),
+                auto: true,
+                reasons,
+            };
+        }
+
+        // none of the candidates matched all conditions, use the healthiest supported torrent
+        // or the lowest available quality as last resort
+        let key = match supported.iter().max_by_key(|(_, info)| *info.seed()) {
+            Some((key, _)) => {
+                reasons.push(format!("{} is the healthiest supported torrent", key));
+                **key
+            }
+            None => {
+                let (_, key, _) = candidates.last().expect("expected at least one candidate");
+                reasons.push(format!("{} is the lowest available quality", key));
+                *key
+            }
+        };
+
+        QualitySelection {
+            quality: key.clone(),
+            auto: true,
+            reasons,
+        }
+    }
+
+    /// Retrieve the minimum bandwidth in bytes per second which is required to stream the given quality.
+    fn required_bandwidth(quality: &Quality) -> u32 {
+        match quality {
+            Quality::P480 => 250_000,
+            Quality::P720 => 625_000,
+            Quality::P1080 => 1_250_000,
+            Quality::P2160 => 3_750_000,
+        }
+    }
+
+    /// Parse the given torrent quality key, e.g. `720p`, into a known quality.
+    fn parse_quality(value: &str) -> Option<Quality> {
+        match value.to_lowercase().as_str() {
+            "480p" => Some(Quality::P480),
+            "720p" => Some(Quality::P720),
+            "1080p" => Some(Quality::P1080),
+            "2160p" | "4k" => Some(Quality::P2160),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    fn torrents(qualities: Vec<(&str, u32)>) -> HashMap<String, TorrentInfo> {
+        qualities
+            .into_iter()
+            .map(|(quality, seeds)| {
+                (
+                    quality.to_string(),
+                    TorrentInfo::builder()
+                        .url(format!("magnet:?{}", quality))
+                        .provider("")
+                        .source("")
+                        .title("")
+                        .quality(quality)
+                        .seed(seeds)
+                        .peer(seeds)
+                        .build(),
+                )
+            })
+            .collect()
+    }
+
+    fn selector(temp_path: &str, samples: Vec<u32>) -> QualitySelector {
+        let history = Arc::new(BandwidthHistory::new(temp_path));
+        for sample in samples {
+            history.record(sample);
+        }
+
+        QualitySelector::new(history)
+    }
+
+    #[test]
+    fn test_bandwidth_history_record() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let history = BandwidthHistory::new(temp_path);
+
+        assert_eq!(None, history.estimate());
+        for sample in 1..=MAX_BANDWIDTH_SAMPLES as u32 + 1 {
+            history.record(sample * 1000);
+        }
+
+        let result = BandwidthHistory::new(temp_path).estimate();
+        assert_eq!(
+            Some(6500),
+            result,
+            "expected only the last samples to have been persisted"
+        );
+    }
+
+    #[test]
+    fn test_select_auto_decision_matrix() {
+        init_logger();
+        let available = vec![("720p", 50), ("1080p", 50), ("2160p", 50)];
+        let cases: Vec<(Vec<u32>, Vec<(&str, u32)>, &str)> = vec![
+            // no bandwidth history, healthy torrents
+            (vec![], available.clone(), "1080p"),
+            // high bandwidth, healthy torrents
+            (vec![5_000_000], available.clone(), "2160p"),
+            // low bandwidth, healthy torrents
+            (vec![700_000], available.clone(), "720p"),
+            // high bandwidth, unhealthy high quality torrents
+            (
+                vec![5_000_000],
+                vec![("720p", 50), ("1080p", 2), ("2160p", 0)],
+                "720p",
+            ),
+            // high bandwidth, unknown torrent health
+            (
+                vec![5_000_000],
+                vec![("720p", 0), ("1080p", 0), ("2160p", 0)],
+                "2160p",
+            ),
+            // insufficient bandwidth for all torrents
+            (vec![100_000], available.clone(), "720p"),
+            // supported torrents are all unhealthy
+            (vec![2_000_000], vec![("720p", 3), ("1080p", 1)], "720p"),
+        ];
+
+        for (samples, torrents_info, expected_quality) in cases {
+            let temp_dir = tempdir().unwrap();
+            let temp_path = temp_dir.path().to_str().unwrap();
+            let selector = selector(temp_path, samples.clone());
+
+            let result = selector
+                .select(&PreferredQuality::Auto, &torrents(torrents_info.clone()))
+                .expect("expected a quality to have been selected");
+
+            assert_eq!(
+                expected_quality, result.quality,
+                "expected {} for samples {:?} and torrents {:?}, got {:?} instead",
+                expected_quality, samples, torrents_info, result
+            );
+            assert_eq!(true, result.auto);
+            assert_eq!(format!("Auto ({})", expected_quality), result.to_string());
+            assert!(!result.reasons.is_empty(), "expected reasons to be present");
+        }
+    }
+
+    #[test]
+    fn test_select_fixed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let selector = selector(temp_path, vec![100_000]);
+        let torrents = torrents(vec![("720p", 10), ("1080p", 10)]);
+
+        let result = selector
+            .select(&PreferredQuality::Fixed(Quality::P1080), &torrents)
+            .unwrap();
+        assert_eq!("1080p", result.quality);
+        assert_eq!(false, result.auto);
+        assert_eq!("1080p", result.to_string());
+
+        let result = selector
+            .select(&PreferredQuality::Fixed(Quality::P2160), &torrents)
+            .unwrap();
+        assert_eq!("1080p", result.quality);
+
+        let result = selector
+            .select(&PreferredQuality::Fixed(Quality::P480), &torrents)
+            .unwrap();
+        assert_eq!("720p", result.quality);
+    }
+
+    #[test]
+    fn test_select_no_known_qualities() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let selector = selector(temp_path, vec![]);
+
+        let result = selector.select(&PreferredQuality::Auto, &torrents(vec![("3D", 10)]));
+
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn test_set_preferred_quality() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let selector = selector(temp_path, vec![]);
+        assert_eq!(PreferredQuality::Auto, selector.preferred_quality());
+
+        selector.set_preferred_quality(PreferredQuality::Fixed(Quality::P720));
+
+        assert_eq!(
+            PreferredQuality::Fixed(Quality::P720),
+            selector.preferred_quality()
+        );
+    }
+}
@@ -112,14 +112,22 @@ impl LoadingTask {
         }
 
         if let Some(e) = inner {
-            select! {
+            let result = select! {
                 _ = e.cancel_token.cancelled() => {
                     Err(LoadingError::Cancelled)
                 },
                 result = e.load(data) => {
                     result
                 }
+            };
+
+            // the in-flight strategy might have been dropped by the cancellation,
+            // so the strategies which have been started need to clean up their resources
+            if let Err(LoadingError::Cancelled) = result {
+                return e.cancel_strategies().await;
             }
+
+            result
         } else {
             panic!("Expected the inner loading task to still be available")
         }
@@ -177,6 +185,8 @@ struct InnerLoadingTask {
     cancel_token: CancellationToken,
     state: Mutex<LoadingState>,
     chain: Arc<LoadingChain>,
+    /// The index of the last started strategy together with the latest data it has been given or returned
+    processed: Mutex<Option<(usize, LoadingData)>>,
     sender_channel: Sender<LoadingEvent>,
     callbacks: CoreCallbacks<LoadingEvent>,
 }
@@ -188,6 +198,7 @@ impl InnerLoadingTask {
             cancel_token: Default::default(),
             state: Mutex::new(LoadingState::Initializing),
             chain,
+            processed: Default::default(),
             sender_channel,
             callbacks: Default::default(),
         }
@@ -204,7 +215,6 @@ impl InnerLoadingTask {
 
     pub async fn load(&self, mut data: LoadingData) -> Result<(), LoadingError> {
         let strategies = self.chain.strategies();
-        let mut index: i32 = 0;
 
         trace!(
             "Processing a total of {} loading strategies for {}",
@@ -212,20 +222,23 @@ impl InnerLoadingTask {
             self.handle
         );
         self.handle_state_callback(LoadingState::Initializing);
-        for strategy in strategies.iter() {
+        for (index, strategy) in strategies.iter().enumerate() {
             if self.cancel_token.is_cancelled() {
                 info!("Loading process is being cancelled");
-                break;
+                return Err(LoadingError::Cancelled);
             }
 
             if let Some(strategy) = strategy.upgrade() {
-                index += 1;
                 trace!("Executing {}", strategy);
+                self.update_processed(index, &data);
                 match strategy
                     .process(data, self.sender_channel.clone(), self.cancel_token.clone())
                     .await
                 {
-                    LoadingResult::Ok(updated_data) => data = updated_data,
+                    LoadingResult::Ok(updated_data) => {
+                        self.update_processed(index, &updated_data);
+                        data = updated_data
+                    }
                     LoadingResult::Completed => {
                         debug!("Loading strategies have been completed");
                         return Ok(());
@@ -244,9 +257,25 @@ impl InnerLoadingTask {
         }
 
         if self.cancel_token.is_cancelled() {
-            debug!("Cancelling a total of {} loading strategies", index);
-            while index >= 0 {
-                if let Some(strategy) = strategies.get(index as usize).and_then(|e| e.upgrade()) {
+            return Err(LoadingError::Cancelled);
+        }
+
+        Ok(())
+    }
+
+    /// Cancel the strategies which have been started by this task in reverse order.
+    /// Each strategy receives the data which has been returned by the cancellation of the previous strategy,
+    /// allowing the final data to reflect the resources which have been cleaned up.
+    ///
+    /// It returns [LoadingError::Cancelled] when all strategies have been cancelled, else the cancellation error.
+    pub async fn cancel_strategies(&self) -> Result<(), LoadingError> {
+        let processed = block_in_place(self.processed.lock()).take();
+
+        if let Some((index, mut data)) = processed {
+            let strategies = self.chain.strategies();
+            debug!("Cancelling a total of {} loading strategies", index + 1);
+            for strategy in strategies.iter().take(index + 1).rev() {
+                if let Some(strategy) = strategy.upgrade() {
                     trace!("Cancelling {}", strategy);
                     match strategy.cancel(data).await {
                         Ok(new_data) => data = new_data,
@@ -258,21 +287,26 @@ impl InnerLoadingTask {
                 } else {
                     warn!("Unable to cancel loading strategy, strategy went out of scope");
                 }
-
-                index -= 1;
             }
 
-            debug!("Finished cancelling loading task {}", self.handle);
-            return Err(LoadingError::Cancelled);
+            debug!(
+                "Finished cancelling loading task {}, resulting data {:?}",
+                self.handle, data
+            );
         }
 
-        Ok(())
+        Err(LoadingError::Cancelled)
     }
 
     pub fn subscribe(&self, callback: CoreCallback<LoadingEvent>) -> CallbackHandle {
         self.callbacks.add(callback)
     }
 
+    fn update_processed(&self, index: usize, data: &LoadingData) {
+        let mut mutex = block_in_place(self.processed.lock());
+        *mutex = Some((index, data.clone()));
+    }
+
     pub fn unsubscribe(&self, callback_handle: CallbackHandle) {
         self.callbacks.remove(callback_handle)
     }
@@ -335,6 +369,31 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Display)]
+    #[display(fmt = "PendingStrategy")]
+    struct PendingStrategy {
+        pub initiated: Sender<()>,
+        pub cancelled: Sender<()>,
+    }
+
+    #[async_trait]
+    impl LoadingStrategy for PendingStrategy {
+        async fn process(
+            &self,
+            _: LoadingData,
+            _: Sender<LoadingEvent>,
+            _: CancellationToken,
+        ) -> LoadingResult {
+            self.initiated.send(()).unwrap();
+            futures::future::pending::<LoadingResult>().await
+        }
+
+        async fn cancel(&self, data: LoadingData) -> CancellationResult {
+            self.cancelled.send(()).unwrap();
+            CancellationResult::Ok(data)
+        }
+    }
+
     #[test]
     fn test_handle() {
         init_logger();
@@ -600,4 +659,71 @@ mod tests {
             .expect("expected the cancel fn to have been invoked");
         assert_eq!(data, result);
     }
+
+    #[test]
+    fn test_cancel_should_cancel_started_strategies_when_in_flight() {
+        init_logger();
+        let data = LoadingData::from(PlaylistItem {
+            url: None,
+            title: "MyInFlightTest".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        });
+        let (tx, rx) = channel();
+        let (tx_pending_cancel, rx_pending_cancel) = channel();
+        let (tx_cancel, rx_cancel) = channel();
+        let (tx_result, rx_result) = channel();
+        let mut strategy = MockLoadingStrategy::new();
+        strategy
+            .expect_process()
+            .times(1)
+            .returning(|e, _, _| LoadingResult::Ok(e));
+        strategy.expect_cancel().times(1).returning(move |e| {
+            tx_cancel.send(e.clone()).unwrap();
+            CancellationResult::Ok(e)
+        });
+        let pending_strategy = PendingStrategy {
+            initiated: tx,
+            cancelled: tx_pending_cancel,
+        };
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let task = Arc::new(LoadingTask::new(
+            Arc::new(LoadingChain::from(vec![
+                Box::new(strategy) as Box<dyn LoadingStrategy>,
+                Box::new(pending_strategy) as Box<dyn LoadingStrategy>,
+            ])),
+            runtime.clone(),
+        ));
+        let runtime = Runtime::new().unwrap();
+
+        let del_task = task.clone();
+        let data_copy = data.clone();
+        runtime.spawn(async move {
+            let result = del_task.load(data_copy).await;
+            tx_result.send(result).unwrap();
+        });
+
+        let _ = rx
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the pending strategy to have been started");
+        task.cancel();
+
+        let _ = rx_pending_cancel
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the in-flight strategy to have been cancelled");
+        let result = rx_cancel
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the completed strategy to have been cancelled");
+        assert_eq!(data, result);
+
+        let result = rx_result.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(Err(LoadingError::Cancelled), result);
+    }
 }
@@ -39,6 +39,9 @@ pub enum MediaError {
     /// No provider could be found for the requested category.
     #[error("no provider found for {0}")]
     ProviderNotFound(String),
+    /// The uri can't be used as api of the media provider.
+    #[error("invalid provider uri {0}")]
+    InvalidProviderUri(String),
     /// Failed to load auto-resume data.
     #[error("failed to load auto-resume data: {0}")]
     AutoResumeLoadingFailed(String),
@@ -6,10 +6,13 @@ use serde::{Deserialize, Serialize};
 #[display(fmt = "poster: {}, fanart: {}, banner: {}", poster, fanart, banner)]
 pub struct Images {
     /// The vertical rectangle poster image URL.
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub poster: String,
     /// The background fanart image URL.
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub fanart: String,
     /// The banner image URL.
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub banner: String,
 }
 
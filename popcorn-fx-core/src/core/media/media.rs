@@ -8,6 +8,7 @@ use downcast_rs::{Downcast, DowncastSync, impl_downcast};
 use log::{error, warn};
 #[cfg(test)]
 use mockall::automock;
+use serde::{Deserialize, Deserializer};
 
 use crate::core::media::{
     Category, Episode, Images, MovieDetails, MovieOverview, Rating, ShowDetails, ShowOverview,
@@ -147,6 +148,16 @@ pub trait MediaDetails: MediaOverview {
     fn runtime(&self) -> i32;
 }
 
+/// Deserialize the field value of a media item, falling back to the default value when the provider returned `null`.
+/// It should be combined with `#[serde(default)]` to also cover fields which have been omitted by the provider.
+pub fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod test {
     use std::cmp::Ordering;
@@ -21,12 +21,15 @@ pub const DEFAULT_AUDIO_LANGUAGE: &str = "en";
 )]
 pub struct MovieOverview {
     /// The title of the movie
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub title: String,
     /// The IMDB identifier of the movie
     pub imdb_id: String,
     /// The year the movie has been released
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub year: String,
     pub rating: Option<Rating>,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub images: Images,
 }
 
@@ -1,26 +1,32 @@
 use std::thread;
+use std::time::Instant;
 
 use chrono::Duration;
 use derive_more::Display;
 use log::{debug, error, trace, warn};
-use reqwest::redirect::Policy;
 use reqwest::{Client, Response, Url};
 use serde::de::DeserializeOwned;
 
 use crate::core::cache::{CacheOptions, CacheType};
-use crate::core::media::{Genre, MediaError, SortBy};
+use crate::core::media::providers::ConnectionTestResult;
+use crate::core::media::{Genre, MediaError, MediaIdentifier, SortBy};
+use crate::core::utils::http::HttpClientFactory;
 
 const SORT_QUERY: &str = "sort";
 const ORDER_QUERY: &str = "order";
 const GENRE_QUERY: &str = "genre";
 const KEYWORDS_QUERY: &str = "keywords";
 const ORDER_QUERY_VALUE: &str = "-1";
+const VERIFY_SORT_VALUE: &str = "trending";
+/// The maximum time a single uri is given to respond during a connection test.
+const CONNECTION_TEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// A basic provider which provides common functionality for each provider.
 /// It is meant to be used within other providers and not on it's own.
 ///
 /// ```no_run
 /// use popcorn_fx_core::core::media::providers::BaseProvider;
+/// use popcorn_fx_core::core::utils::http::HttpClientFactory;
 ///
 /// struct MyProvider {
 ///   base: BaseProvider,
@@ -29,14 +35,14 @@ const ORDER_QUERY_VALUE: &str = "-1";
 /// impl MyProvider {
 ///     pub fn new(xxx: xxx) -> Self {
 ///         Self {
-///             base: BaseProvider::new(xxx, false)
+///             base: BaseProvider::new(xxx, HttpClientFactory::default())
 ///         }
 ///     }
 /// }
 /// ```
 #[derive(Debug)]
 pub struct BaseProvider {
-    client: Client,
+    client_factory: HttpClientFactory,
     uri_providers: Vec<UriProvider>,
 }
 
@@ -46,18 +52,14 @@ impl BaseProvider {
     /// # Arguments
     ///
     /// * `uris` - The available host URIs to use for this provider.
-    /// * `insecure` - A flag indicating whether to accept invalid certificates.
+    /// * `client_factory` - The factory of the HTTP clients to use for the requests.
     ///
     /// # Returns
     ///
     /// A new `BaseProvider` instance.
-    pub fn new(uris: Vec<String>, insecure: bool) -> Self {
+    pub fn new(uris: Vec<String>, client_factory: HttpClientFactory) -> Self {
         Self {
-            client: Client::builder()
-                .redirect(Policy::limited(3))
-                .danger_accept_invalid_certs(insecure)
-                .build()
-                .expect("Client should have been created"),
+            client_factory,
             uri_providers: uris.into_iter().map(UriProvider::new).collect(),
         }
     }
@@ -69,6 +71,70 @@ impl BaseProvider {
         }
     }
 
+    /// Replace the available host URIs of this provider.
+    /// The api stats of the previous URIs are discarded.
+    pub fn update_uris(&mut self, uris: Vec<String>) {
+        trace!("Updating provider uris to {:?}", uris);
+        self.uri_providers = uris.into_iter().map(UriProvider::new).collect();
+    }
+
+    /// Verify if the given host URI can be used as api for the given resource.
+    /// It performs a test request for the first page of the resource, without using or affecting the known URIs.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The host URI to verify.
+    /// * `resource` - The resource to request from the host.
+    ///
+    /// # Returns
+    ///
+    /// An empty result when the host responded with a valid page, else the `MediaError` of the request.
+    pub async fn verify_uri(&self, uri: &str, resource: &str) -> crate::core::media::Result<()> {
+        let genre = Genre::all();
+        let sort = SortBy::new(VERIFY_SORT_VALUE.to_string(), String::new());
+        let url = Self::create_search_uri(&uri.to_string(), resource, &genre, &sort, "", 1)
+            .ok_or_else(|| MediaError::InvalidProviderUri(uri.to_string()))?;
+
+        debug!("Verifying provider uri {}", url);
+        Self::send_request::<Vec<serde_json::Value>>(&self.client_factory.client(), &url)
+            .await
+            .map(|_| ())
+    }
+
+    /// Test the connection of each known host URI for the given resource.
+    /// The URIs are tested in parallel, including the disabled ones, without affecting their api stats.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The resource to request from each host.
+    ///
+    /// # Returns
+    ///
+    /// The latency of each host URI on success, or the reason why the connection test failed.
+    pub async fn test_connection(&self, resource: &str) -> Vec<ConnectionTestResult> {
+        futures::future::join_all(self.uri_providers.iter().map(|provider| async move {
+            let uri = provider.uri().clone();
+            let started_at = Instant::now();
+            let result = match tokio::time::timeout(
+                CONNECTION_TEST_TIMEOUT,
+                self.verify_uri(&uri, resource),
+            )
+            .await
+            {
+                Ok(Ok(_)) => Ok(started_at.elapsed()),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!(
+                    "connection timed out after {} seconds",
+                    CONNECTION_TEST_TIMEOUT.as_secs()
+                )),
+            };
+
+            debug!("Provider uri {} connection test result {:?}", uri, result);
+            (uri, result)
+        }))
+        .await
+    }
+
     /// Retrieve the `[T]` for the given resource.
     /// The retrieval will try all known APIs and disable the ones which are unavailable along the way.
     ///
@@ -83,6 +149,7 @@ impl BaseProvider {
     /// # Returns
     ///
     /// An array of `[T]` items on success, or a `providers::ProviderError` if there was an error.
+    /// Items which can't be parsed or which are missing their IMDB identifier are skipped.
     pub async fn retrieve_provider_page<T>(
         &mut self,
         resource: &str,
@@ -92,9 +159,9 @@ impl BaseProvider {
         page: u32,
     ) -> crate::core::media::Result<Vec<T>>
     where
-        T: DeserializeOwned,
+        T: DeserializeOwned + MediaIdentifier,
     {
-        let client = self.client.clone();
+        let client = self.client_factory.client();
         let available_providers: Vec<&mut UriProvider> = self.available_providers();
 
         if available_providers.is_empty() {
@@ -111,9 +178,13 @@ impl BaseProvider {
                 }
                 Some(url) => {
                     debug!("Retrieving media items from {}", &url);
-                    match Self::send_request_with_provider(&client, &url, provider).await {
+                    match Self::send_request_with_provider::<Vec<serde_json::Value>>(
+                        &client, &url, provider,
+                    )
+                    .await
+                    {
                         None => {}
-                        Some(e) => return e,
+                        Some(e) => return e.map(|items| Self::parse_media_items(items, &url)),
                     }
                 }
             }
@@ -140,7 +211,7 @@ impl BaseProvider {
     where
         T: DeserializeOwned,
     {
-        let client = self.client.clone();
+        let client = self.client_factory.client();
         let available_providers: Vec<&mut UriProvider> = self.available_providers();
 
         if available_providers.is_empty() {
@@ -180,6 +251,39 @@ impl BaseProvider {
         }
     }
 
+    /// Parse the given provider items into media items.
+    /// A single malformed item should not fail the whole page, so invalid items are skipped instead.
+    fn parse_media_items<T>(items: Vec<serde_json::Value>, url: &Url) -> Vec<T>
+    where
+        T: DeserializeOwned + MediaIdentifier,
+    {
+        let total_items = items.len();
+        let media_items: Vec<T> = items
+            .into_iter()
+            .filter_map(|item| match serde_json::from_value::<T>(item) {
+                Ok(media) if media.imdb_id().is_empty() => {
+                    trace!("Skipping media item {} without imdb id", media);
+                    None
+                }
+                Ok(media) => Some(media),
+                Err(e) => {
+                    trace!("Skipping invalid media item, {}", e);
+                    None
+                }
+            })
+            .collect();
+
+        let skipped_items = total_items - media_items.len();
+        if skipped_items > 0 {
+            warn!(
+                "Skipped {} out of {} invalid media items from {}",
+                skipped_items, total_items, url
+            );
+        }
+
+        media_items
+    }
+
     async fn send_request_with_provider<T>(
         client: &Client,
         url: &Url,
@@ -431,9 +535,15 @@ mod test {
             then.status(status_code);
         });
         let url = Url::parse(server.url(path).as_str()).unwrap();
-        let provider = BaseProvider::new(vec![server.url("")], false);
+        let provider = BaseProvider::new(vec![server.url("")], HttpClientFactory::default());
 
-        let response = provider.client.get(url.clone()).send().await.unwrap();
+        let response = provider
+            .client_factory
+            .client()
+            .get(url.clone())
+            .send()
+            .await
+            .unwrap();
 
         let result = BaseProvider::handle_response::<()>(response, &url).await;
 
@@ -446,4 +556,85 @@ mod test {
             assert!(false, "expected a MediaError to be returned");
         }
     }
+
+    #[tokio::test]
+    async fn test_verify_uri() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/movies/1")
+                .query_param("sort", "trending");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("[]");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/shows/1");
+            then.status(200).body("lorem ipsum");
+        });
+        let provider = BaseProvider::new(vec![], HttpClientFactory::default());
+
+        let result = provider.verify_uri(server.url("").as_str(), "movies").await;
+        assert_eq!(Ok(()), result);
+
+        let result = provider.verify_uri(server.url("").as_str(), "shows").await;
+        assert!(
+            matches!(result, Err(MediaError::ProviderParsingFailed(_))),
+            "expected the invalid page to have been rejected, got {:?}",
+            result
+        );
+
+        let result = provider.verify_uri("lorem", "movies").await;
+        assert_eq!(
+            Err(MediaError::InvalidProviderUri("lorem".to_string())),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_test_connection() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("[]");
+        });
+        let invalid_server = MockServer::start();
+        invalid_server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(503);
+        });
+        let mut provider = BaseProvider::new(
+            vec![server.url(""), invalid_server.url("")],
+            HttpClientFactory::default(),
+        );
+        provider.uri_providers[1].disable();
+
+        let result = provider.test_connection("movies").await;
+
+        assert_eq!(2, result.len());
+        assert_eq!(server.url(""), result[0].0);
+        assert!(
+            result[0].1.is_ok(),
+            "expected the connection test to succeed, got {:?}",
+            result[0].1
+        );
+        assert_eq!(invalid_server.url(""), result[1].0);
+        assert!(
+            result[1].1.is_err(),
+            "expected the connection test to fail, got {:?}",
+            result[1].1
+        );
+        assert_eq!(
+            0, provider.uri_providers[0].failed_attempts,
+            "expected the api stats to not have been modified"
+        );
+        assert_eq!(
+            true, provider.uri_providers[1].disabled,
+            "expected the disabled uri to remain disabled"
+        );
+    }
 }
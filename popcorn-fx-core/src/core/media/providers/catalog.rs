@@ -0,0 +1,541 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use log::{debug, info, trace, warn};
+use serde::Deserialize;
+
+use crate::core::config::ProviderProperties;
+use crate::core::media;
+use crate::core::media::providers::{MediaDetailsProvider, MediaProvider};
+use crate::core::media::{
+    Category, Genre, MediaDetails, MediaError, MediaOverview, MediaType, MovieDetails, ShowDetails,
+    SortBy,
+};
+use crate::core::utils::http::HttpClientFactory;
+
+const PAGE_SIZE: usize = 50;
+const GENRE_ALL_KEY: &str = "all";
+const SORT_YEAR_KEY: &str = "year";
+const SORT_TITLE_KEY: &str = "title";
+const SORT_NAME_KEY: &str = "name";
+const SORT_RATING_KEY: &str = "rating";
+
+/// The custom catalog document which is served by a self-hosted media index.
+///
+/// The catalog is a single JSON document containing all available media items of the index.
+/// Each item uses the same format as the details of the Popcorn Time api.
+///
+/// ```json
+/// {
+///   "movies": [ { "imdb_id": "tt0000001", "title": "Lorem", ... } ],
+///   "shows": [ { "imdb_id": "tt0000002", "tvdb_id": "1", "title": "Ipsum", ... } ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Catalog {
+    /// The movies within the catalog.
+    #[serde(default)]
+    pub movies: Vec<MovieDetails>,
+    /// The shows within the catalog.
+    #[serde(default)]
+    pub shows: Vec<ShowDetails>,
+}
+
+/// The `CatalogProvider` retrieves media items from a custom catalog document instead of the Popcorn Time api.
+///
+/// The catalog is retrieved once from the first available uri and filtered, sorted and paged locally.
+/// Each provider instance serves exactly one [Category], which allows a catalog to only provide movies or shows.
+///
+/// # Cloning
+///
+/// Cloning the `CatalogProvider` will create a new instance that shares the cached catalog with the original.
+#[derive(Debug, Clone)]
+pub struct CatalogProvider {
+    category: Category,
+    uris: Vec<String>,
+    client_factory: HttpClientFactory,
+    catalog: Arc<Mutex<Option<Arc<Catalog>>>>,
+}
+
+impl CatalogProvider {
+    /// Creates a new `CatalogProvider` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category which is served by this provider.
+    /// * `properties` - The provider properties containing the catalog uri's.
+    /// * `client_factory` - The factory of the http client used to retrieve the catalog.
+    ///
+    /// # Returns
+    ///
+    /// A new `CatalogProvider` instance.
+    pub fn new(
+        category: Category,
+        properties: &ProviderProperties,
+        client_factory: HttpClientFactory,
+    ) -> Self {
+        Self {
+            category,
+            uris: properties.uris().to_vec(),
+            client_factory,
+            catalog: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Retrieve the catalog document of the provider.
+    /// The catalog is only retrieved once and cached until the api is reset.
+    async fn catalog(&self) -> media::Result<Arc<Catalog>> {
+        let cached_catalog = self.catalog.lock().unwrap().clone();
+        if let Some(catalog) = cached_catalog {
+            return Ok(catalog);
+        }
+
+        for uri in self.uris.iter() {
+            debug!("Retrieving media catalog from {}", uri);
+            match self.fetch(uri).await {
+                Ok(catalog) => {
+                    info!(
+                        "Retrieved media catalog from {} with {} movies and {} shows",
+                        uri,
+                        catalog.movies.len(),
+                        catalog.shows.len()
+                    );
+                    let catalog = Arc::new(catalog);
+                    *self.catalog.lock().unwrap() = Some(catalog.clone());
+                    return Ok(catalog);
+                }
+                Err(e) => warn!("Failed to retrieve media catalog from {}, {}", uri, e),
+            }
+        }
+
+        Err(MediaError::NoAvailableProviders)
+    }
+
+    async fn fetch(&self, uri: &str) -> media::Result<Catalog> {
+        let response = self
+            .client_factory
+            .client()
+            .get(uri)
+            .send()
+            .await
+            .map_err(|e| {
+                debug!("Catalog request to {} failed, {}", uri, e);
+                MediaError::ProviderConnectionFailed
+            })?;
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(MediaError::ProviderRequestFailed(
+                uri.to_string(),
+                status.as_u16(),
+            ));
+        }
+
+        response
+            .json::<Catalog>()
+            .await
+            .map_err(|e| MediaError::ProviderParsingFailed(e.to_string()))
+    }
+
+    /// Retrieve the overview items of the catalog for the category of this provider, together with their genres.
+    fn items(&self, catalog: &Catalog) -> Vec<(Vec<String>, Box<dyn MediaOverview>)> {
+        match self.category {
+            Category::Movies => catalog
+                .movies
+                .iter()
+                .map(|e| {
+                    (
+                        e.genres().clone(),
+                        Box::new(e.to_overview()) as Box<dyn MediaOverview>,
+                    )
+                })
+                .collect(),
+            Category::Series => catalog
+                .shows
+                .iter()
+                .map(|e| {
+                    (
+                        e.genres().clone(),
+                        Box::new(e.to_overview()) as Box<dyn MediaOverview>,
+                    )
+                })
+                .collect(),
+            Category::Favorites => vec![],
+        }
+    }
+
+    fn filter_genre(genres: &[String], genre: &Genre) -> bool {
+        genre.key() == GENRE_ALL_KEY || genres.iter().any(|e| e.eq_ignore_ascii_case(genre.key()))
+    }
+
+    fn filter_keywords(media: &Box<dyn MediaOverview>, keywords: &String) -> bool {
+        let normalized_keywords = keywords.trim().to_lowercase();
+
+        normalized_keywords.is_empty()
+            || media.title().to_lowercase().contains(&normalized_keywords)
+    }
+
+    /// Sort the given items based on the sort key.
+    /// Unknown sort keys will keep the order of the catalog document.
+    fn sort_by(
+        sort_by: &SortBy,
+        a: &Box<dyn MediaOverview>,
+        b: &Box<dyn MediaOverview>,
+    ) -> Ordering {
+        match sort_by.key() {
+            SORT_YEAR_KEY => a.year().cmp(b.year()).reverse(),
+            SORT_TITLE_KEY | SORT_NAME_KEY => a.title().cmp(&b.title()),
+            SORT_RATING_KEY => match (a.rating(), b.rating()) {
+                (Some(a), Some(b)) => a.cmp(b).reverse(),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+impl Display for CatalogProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CatalogProvider ({})", self.category)
+    }
+}
+
+#[async_trait]
+impl MediaProvider for CatalogProvider {
+    fn supports(&self, category: &Category) -> bool {
+        category == &self.category
+    }
+
+    fn reset_api(&self) {
+        debug!("Clearing the cached media catalog of {}", self);
+        *self.catalog.lock().unwrap() = None;
+    }
+
+    async fn retrieve(
+        &self,
+        genre: &Genre,
+        sort_by: &SortBy,
+        keywords: &String,
+        page: u32,
+    ) -> media::Result<Vec<Box<dyn MediaOverview>>> {
+        let catalog = self.catalog().await?;
+        let offset = (page.max(1) as usize - 1) * PAGE_SIZE;
+
+        trace!(
+            "Retrieving catalog page {} for {} with genre {}, sort by {} and keywords {}",
+            page,
+            self.category,
+            genre,
+            sort_by,
+            keywords
+        );
+        let items: Vec<Box<dyn MediaOverview>> = self
+            .items(&catalog)
+            .into_iter()
+            .filter(|(genres, _)| Self::filter_genre(genres, genre))
+            .map(|(_, media)| media)
+            .filter(|e| Self::filter_keywords(e, keywords))
+            .sorted_by(|a, b| Self::sort_by(sort_by, a, b))
+            .skip(offset)
+            .take(PAGE_SIZE)
+            .collect();
+
+        debug!(
+            "Retrieved a total of {} catalog items for page {}",
+            items.len(),
+            page
+        );
+        Ok(items)
+    }
+
+    async fn genres(&self) -> Vec<String> {
+        match self.catalog().await {
+            Ok(catalog) => {
+                let genres = self
+                    .items(&catalog)
+                    .into_iter()
+                    .flat_map(|(genres, _)| genres)
+                    .map(|e| e.to_lowercase())
+                    .unique()
+                    .collect::<Vec<String>>();
+
+                vec![GENRE_ALL_KEY.to_string()]
+                    .into_iter()
+                    .chain(genres.into_iter().filter(|e| e != GENRE_ALL_KEY))
+                    .collect()
+            }
+            Err(e) => {
+                warn!("Unable to retrieve the genres of {}, {}", self, e);
+                vec![]
+            }
+        }
+    }
+
+    async fn sort_options(&self) -> Vec<String> {
+        vec![
+            SORT_TITLE_KEY.to_string(),
+            SORT_YEAR_KEY.to_string(),
+            SORT_RATING_KEY.to_string(),
+        ]
+    }
+}
+
+#[async_trait]
+impl MediaDetailsProvider for CatalogProvider {
+    fn supports(&self, media_type: &MediaType) -> bool {
+        match self.category {
+            Category::Movies => media_type == &MediaType::Movie,
+            Category::Series => media_type == &MediaType::Show,
+            Category::Favorites => false,
+        }
+    }
+
+    fn reset_api(&self) {
+        MediaProvider::reset_api(self)
+    }
+
+    async fn retrieve_details(&self, imdb_id: &str) -> media::Result<Box<dyn MediaDetails>> {
+        let catalog = self.catalog().await?;
+        let media = match self.category {
+            Category::Movies => catalog
+                .movies
+                .iter()
+                .find(|e| e.imdb_id == imdb_id)
+                .map(|e| Box::new(e.clone()) as Box<dyn MediaDetails>),
+            Category::Series => catalog
+                .shows
+                .iter()
+                .find(|e| e.imdb_id == imdb_id)
+                .map(|e| Box::new(e.clone()) as Box<dyn MediaDetails>),
+            Category::Favorites => None,
+        };
+
+        media.ok_or_else(|| {
+            warn!("Media item {} not found within the catalog", imdb_id);
+            MediaError::ProviderParsingFailed(format!("media item {} not found", imdb_id))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+
+    use crate::core::config::ProviderType;
+    use crate::testing::{init_logger, read_test_file_to_string};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_retrieve() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("catalog.json"));
+        });
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider
+            .retrieve(
+                &Genre::all(),
+                &SortBy::new("title".to_string(), String::new()),
+                &String::new(),
+                1,
+            )
+            .await
+            .expect("expected the catalog items to be returned");
+        // retrieve a second page to verify the catalog is cached
+        let page_2 = provider
+            .retrieve(
+                &Genre::all(),
+                &SortBy::new("title".to_string(), String::new()),
+                &String::new(),
+                2,
+            )
+            .await
+            .expect("expected the catalog items to be returned");
+
+        assert_eq!(
+            vec!["Consectetur", "Dolor", "Lorem"],
+            result.iter().map(|e| e.title()).collect::<Vec<String>>()
+        );
+        assert_eq!(0, page_2.len());
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_filter_genre_and_keywords() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("catalog.json"));
+        });
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider
+            .retrieve(
+                &Genre::new("comedy".to_string(), String::new()),
+                &SortBy::new("year".to_string(), String::new()),
+                &"o".to_string(),
+                1,
+            )
+            .await
+            .expect("expected the catalog items to be returned");
+
+        assert_eq!(
+            vec!["tt0000003", "tt0000001"],
+            result.iter().map(|e| e.imdb_id()).collect::<Vec<&str>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_details() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("catalog.json"));
+        });
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider
+            .retrieve_details("tt0000002")
+            .await
+            .expect("expected the details to be returned")
+            .into_any()
+            .downcast::<MovieDetails>()
+            .expect("expected media to be a movie");
+
+        assert_eq!("Dolor", result.title.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_unavailable_catalog() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(500);
+        });
+        let provider = CatalogProvider::new(
+            Category::Series,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider
+            .retrieve(
+                &Genre::all(),
+                &SortBy::new("trending".to_string(), String::new()),
+                &String::new(),
+                1,
+            )
+            .await;
+
+        assert_eq!(Err(MediaError::NoAvailableProviders), result.map(|_| ()));
+    }
+
+    #[test]
+    fn test_supports() {
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![]),
+            HttpClientFactory::default(),
+        );
+
+        assert_eq!(true, MediaProvider::supports(&provider, &Category::Movies));
+        assert_eq!(false, MediaProvider::supports(&provider, &Category::Series));
+        assert_eq!(
+            true,
+            MediaDetailsProvider::supports(&provider, &MediaType::Movie)
+        );
+        assert_eq!(
+            false,
+            MediaDetailsProvider::supports(&provider, &MediaType::Show)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_genres() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("catalog.json"));
+        });
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider.genres().await;
+
+        assert_eq!(
+            vec![
+                "all".to_string(),
+                "comedy".to_string(),
+                "family".to_string(),
+                "drama".to_string(),
+            ],
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_genres_catalog_unavailable() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/catalog.json");
+            then.status(500);
+        });
+        let provider = CatalogProvider::new(
+            Category::Movies,
+            &create_properties(vec![server.url("/catalog.json")]),
+            HttpClientFactory::default(),
+        );
+
+        let result = provider.genres().await;
+
+        assert_eq!(Vec::<String>::new(), result);
+    }
+
+    fn create_properties(uris: Vec<String>) -> ProviderProperties {
+        ProviderProperties {
+            provider_type: ProviderType::Catalog,
+            uris,
+            genres: vec![],
+            sort_by: vec![],
+            categories: vec![Category::Movies],
+        }
+    }
+}
@@ -6,7 +6,6 @@ use chrono::Duration;
 use derive_more::Display;
 use log::{debug, error, trace, warn};
 use regex::Regex;
-use reqwest::Client;
 use thiserror::Error;
 use url::Url;
 
@@ -14,6 +13,7 @@ use crate::core::cache::{CacheManager, CacheOptions, CacheType};
 use crate::core::config::EnhancerProperties;
 use crate::core::media::{Category, Episode, MediaDetails, ShowDetails};
 use crate::core::media::providers::enhancers::Enhancer;
+use crate::core::utils::http::HttpClientFactory;
 
 const CACHE_NAME: &str = "thumb_enhancer";
 
@@ -35,19 +35,21 @@ pub struct ThumbEnhancer {
     properties: EnhancerProperties,
     /// the regex used to retrieve the thumb
     regex: Regex,
-    client: Client,
+    client_factory: HttpClientFactory,
     cache_manager: Arc<CacheManager>,
 }
 
 impl ThumbEnhancer {
     /// Create a new episode enhancer which will use TVDB information based on the given enhancer properties.
-    pub fn new(properties: EnhancerProperties, cache_manager: Arc<CacheManager>) -> Self {
+    pub fn new(
+        properties: EnhancerProperties,
+        cache_manager: Arc<CacheManager>,
+        client_factory: HttpClientFactory,
+    ) -> Self {
         Self {
             properties,
             regex: Regex::new("https://artworks.thetvdb.com/banners/([a-zA-Z0-9/\\.]+)").unwrap(),
-            client: Client::builder()
-                .build()
-                .expect("Client should have been created"),
+            client_factory,
             cache_manager,
         }
     }
@@ -94,7 +96,7 @@ impl ThumbEnhancer {
         let url = self.build_url(tvdb_id);
 
         trace!("Retrieving additional TVDB info from {}", url);
-        match self.client.get(url).send().await {
+        match self.client_factory.client().get(url).send().await {
             Ok(response) => {
                 if response.status().is_success() {
                     match response.text().await {
@@ -177,6 +179,7 @@ mod test {
                 uri: "".to_string(),
             },
             cache_manager,
+            HttpClientFactory::default(),
         );
 
         assert!(
@@ -234,6 +237,7 @@ mod test {
                 uri: server.url(""),
             },
             cache_manager,
+            HttpClientFactory::default(),
         );
         let runtime = Runtime::new().unwrap();
 
@@ -269,6 +273,7 @@ mod test {
                 uri: "".to_string(),
             },
             cache_manager,
+            HttpClientFactory::default(),
         );
         let runtime = Runtime::new().unwrap();
 
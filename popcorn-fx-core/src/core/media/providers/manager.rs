@@ -1,15 +1,25 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use log::{debug, trace, warn};
 
+use crate::core::config::{PopcornProperties, ProviderProperties};
 use crate::core::media;
 use crate::core::media::{
     Category, Genre, MediaDetails, MediaError, MediaIdentifier, MediaOverview, MediaType, SortBy,
 };
-use crate::core::media::providers::{MediaDetailsProvider, MediaProvider};
+use crate::core::media::providers::{
+    ConnectionTestResult, MediaDetailsProvider, MediaProvider, ProviderResultCache,
+    DEFAULT_PROVIDER_CACHE_TTL, DEFAULT_PROVIDER_SEARCH_CACHE_TTL,
+};
 use crate::core::media::providers::enhancers::Enhancer;
 
 /// Manages the available [MediaProvider]'s that can be used to retrieve [Media] items.
 /// Multiple providers for the same [Category] can be registered to overrule an existing one.
 ///
+/// Retrieved pages are cached in-memory for a limited time, so navigating back and forth
+/// between pages doesn't result in a new request to the provider each time.
+///
 /// # Example new instance
 ///
 /// Use the [ProviderManagerBuilder] to build new instance of this manager.
@@ -27,6 +37,10 @@ pub struct ProviderManager {
     details_providers: Vec<Box<dyn MediaDetailsProvider>>,
     /// The enhancers
     enhancers: Vec<Box<dyn Enhancer>>,
+    /// The configured provider properties, used as fallback for the filter options
+    properties: HashMap<String, ProviderProperties>,
+    /// The cache of the retrieved provider pages
+    cache: ProviderResultCache,
 }
 
 impl ProviderManager {
@@ -37,6 +51,9 @@ impl ProviderManager {
     /// Retrieve a page of [MediaOverview] items based on the given criteria.
     /// The media items only contain basic information to present as an overview.
     ///
+    /// Pages which have been retrieved recently are returned from the cache,
+    /// except for the [Category::Favorites] which are always retrieved from the provider.
+    ///
     /// It returns the retrieves page on success, else the [providers::ProviderError].
     pub async fn retrieve(
         &self,
@@ -50,13 +67,30 @@ impl ProviderManager {
         match self.provider(category) {
             None => Err(MediaError::ProviderNotFound(category.to_string())),
             Some(provider) => {
+                let cacheable = category != &Category::Favorites;
+                if cacheable {
+                    if let Some(items) = self.cache.get(category, genre, sort_by, keywords, page) {
+                        debug!(
+                            "Retrieved provider page {} for {} from cache",
+                            page, category
+                        );
+                        return Ok(items);
+                    }
+                }
+
                 trace!(
                     "Retrieving provider page {} for category {} with {:?}",
                     page,
                     category,
                     provider
                 );
-                provider.retrieve(genre, sort_by, keywords, page).await
+                let items = provider.retrieve(genre, sort_by, keywords, page).await?;
+                if cacheable {
+                    self.cache
+                        .insert(category, genre, sort_by, keywords, page, &items);
+                }
+
+                Ok(items)
             }
         }
     }
@@ -81,6 +115,73 @@ impl ProviderManager {
         }
     }
 
+    /// Retrieve the categories which are supported by the registered providers.
+    /// Categories without a provider should not be offered to the user.
+    pub fn supported_categories(&self) -> Vec<Category> {
+        [Category::Movies, Category::Series, Category::Favorites]
+            .into_iter()
+            .filter(|e| self.provider(e).is_some())
+            .collect()
+    }
+
+    /// Retrieve the available genre keys of the given category.
+    ///
+    /// The configured genres are merged with the genres advertised by the provider of the category.
+    /// The configured genres keep their display order, while new genres of the provider are appended.
+    pub async fn available_genres(&self, category: &Category) -> Vec<String> {
+        let configured = self
+            .properties
+            .get(&category.name())
+            .map(|e| e.genres().to_vec())
+            .unwrap_or_default();
+        let advertised = match self.provider(category) {
+            None => vec![],
+            Some(provider) => provider.genres().await,
+        };
+
+        trace!(
+            "Merging configured genres {:?} with advertised genres {:?} for {}",
+            configured,
+            advertised,
+            category
+        );
+        Self::merge_options(configured, advertised)
+    }
+
+    /// Retrieve the available sort keys of the given category.
+    ///
+    /// The configured sort options are merged with the sort options advertised by the provider of the category.
+    /// The configured sort options keep their display order, while new options of the provider are appended.
+    pub async fn available_sort_options(&self, category: &Category) -> Vec<String> {
+        let configured = self
+            .properties
+            .get(&category.name())
+            .map(|e| e.sort_by().to_vec())
+            .unwrap_or_default();
+        let advertised = match self.provider(category) {
+            None => vec![],
+            Some(provider) => provider.sort_options().await,
+        };
+
+        trace!(
+            "Merging configured sort options {:?} with advertised sort options {:?} for {}",
+            configured,
+            advertised,
+            category
+        );
+        Self::merge_options(configured, advertised)
+    }
+
+    /// Invalidate the cached provider pages, which forces the next pages to be retrieved from the providers.
+    pub fn invalidate_cache(&self) {
+        self.cache.invalidate()
+    }
+
+    /// Verify if the given category is supported by one of the registered providers.
+    pub fn is_supported(&self, category: &Category) -> bool {
+        self.provider(category).is_some()
+    }
+
     /// Reset the api statics and re-enable all disabled api's.
     pub fn reset_api(&self, category: &Category) {
         trace!("Starting reset of api provider for category {}", category);
@@ -98,6 +199,50 @@ impl ProviderManager {
         }
     }
 
+    /// Verify if the given uri can be used as api of the provider of the given category.
+    /// It performs a test request against the uri, which should be done before the uri is accepted as provider override.
+    ///
+    /// It returns an empty result when the uri can be used, else the [MediaError] why it was rejected.
+    pub async fn verify_uri(&self, category: &Category, uri: &str) -> media::Result<()> {
+        trace!("Verifying provider uri {} for category {}", uri, category);
+        match self.provider(category) {
+            None => Err(MediaError::ProviderNotFound(category.to_string())),
+            Some(provider) => provider.verify_uri(uri).await,
+        }
+    }
+
+    /// Test the connection of each configured uri of the provider of the given category.
+    /// This is a diagnostic call which doesn't affect the api statistics of the provider.
+    ///
+    /// It returns the latency or failure reason of each uri, or an empty list when no provider supports the category.
+    pub async fn test_connection(&self, category: &Category) -> Vec<ConnectionTestResult> {
+        trace!("Testing provider connection for category {}", category);
+        match self.provider(category) {
+            None => {
+                warn!(
+                    "Unable to test connection, no provider found for {}",
+                    category
+                );
+                vec![]
+            }
+            Some(provider) => provider.test_connection().await,
+        }
+    }
+
+    /// Merge the given option keys into a single de-duplicated list.
+    /// The keys are compared case-insensitive and the first occurrence of a key is kept.
+    fn merge_options(configured: Vec<String>, advertised: Vec<String>) -> Vec<String> {
+        let mut options: Vec<String> = vec![];
+
+        for option in configured.into_iter().chain(advertised) {
+            if !options.iter().any(|e| e.eq_ignore_ascii_case(&option)) {
+                options.push(option);
+            }
+        }
+
+        options
+    }
+
     async fn enhance_media_item(
         &self,
         category: &Category,
@@ -155,6 +300,9 @@ pub struct ProviderManagerBuilder {
     media_providers: Vec<Box<dyn MediaProvider>>,
     details_providers: Vec<Box<dyn MediaDetailsProvider>>,
     enhancers: Vec<Box<dyn Enhancer>>,
+    properties: HashMap<String, ProviderProperties>,
+    cache_ttl: Option<Duration>,
+    search_cache_ttl: Option<Duration>,
 }
 
 impl ProviderManagerBuilder {
@@ -180,11 +328,37 @@ impl ProviderManagerBuilder {
         self
     }
 
+    /// Set the configured provider properties which are used as fallback for the genres and sort options.
+    pub fn with_properties(mut self, properties: &PopcornProperties) -> Self {
+        self.properties = properties.providers.clone();
+        self
+    }
+
+    /// Set the duration for which the retrieved pages are cached, zero disables the cache.
+    /// If not set, the [DEFAULT_PROVIDER_CACHE_TTL] will be used.
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the duration for which the pages retrieved with keywords are cached, zero disables the cache for searches.
+    /// If not set, the [DEFAULT_PROVIDER_SEARCH_CACHE_TTL] will be used.
+    pub fn search_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.search_cache_ttl = Some(ttl);
+        self
+    }
+
     pub fn build(self) -> ProviderManager {
         ProviderManager {
             media_providers: self.media_providers,
             details_providers: self.details_providers,
             enhancers: self.enhancers,
+            properties: self.properties,
+            cache: ProviderResultCache::new(
+                self.cache_ttl.unwrap_or(DEFAULT_PROVIDER_CACHE_TTL),
+                self.search_cache_ttl
+                    .unwrap_or(DEFAULT_PROVIDER_SEARCH_CACHE_TTL),
+            ),
         }
     }
 }
@@ -196,11 +370,12 @@ mod test {
     use tokio::runtime::Runtime;
 
     use crate::core::cache::CacheManagerBuilder;
-    use crate::core::config::ApplicationConfig;
-    use crate::core::media::{Episode, ShowDetails, ShowOverview};
+    use crate::core::config::{ApplicationConfig, ProviderType};
+    use crate::core::media::{Episode, MovieOverview, ShowDetails, ShowOverview};
     use crate::core::media::providers::enhancers::MockEnhancer;
-    use crate::core::media::providers::MockMediaDetailsProvider;
+    use crate::core::media::providers::{MockMediaDetailsProvider, MockMediaProvider};
     use crate::core::media::providers::ShowProvider;
+    use crate::core::utils::http::HttpClientFactory;
     use crate::testing::init_logger;
 
     use super::*;
@@ -229,6 +404,91 @@ mod test {
         }
     }
 
+    #[tokio::test]
+    async fn test_retrieve_cached() {
+        init_logger();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider
+            .expect_retrieve()
+            .times(1)
+            .returning(|_, _, _, _| Ok(vec![create_movie("tt0000001")]));
+        let manager = ProviderManager::builder()
+            .with_provider(Box::new(provider))
+            .build();
+
+        let _ = manager
+            .retrieve(&Category::Movies, &genre, &sort_by, &String::new(), 1)
+            .await
+            .expect("expected the page to have been retrieved");
+        let result = manager
+            .retrieve(&Category::Movies, &genre, &sort_by, &String::new(), 1)
+            .await
+            .expect("expected the page to have been retrieved");
+
+        assert_eq!(1, result.len());
+        assert_eq!("tt0000001", result.get(0).unwrap().imdb_id());
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_search_not_cached() {
+        init_logger();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+        let keywords = "lorem".to_string();
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider
+            .expect_retrieve()
+            .times(2)
+            .returning(|_, _, _, _| Ok(vec![create_movie("tt0000001")]));
+        let manager = ProviderManager::builder()
+            .with_provider(Box::new(provider))
+            .search_cache_ttl(Duration::ZERO)
+            .build();
+
+        for _ in 0..2 {
+            let _ = manager
+                .retrieve(&Category::Movies, &genre, &sort_by, &keywords, 1)
+                .await
+                .expect("expected the page to have been retrieved");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_cache() {
+        init_logger();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider
+            .expect_retrieve()
+            .times(2)
+            .returning(|_, _, _, _| Ok(vec![create_movie("tt0000001")]));
+        let manager = ProviderManager::builder()
+            .with_provider(Box::new(provider))
+            .build();
+
+        let _ = manager
+            .retrieve(&Category::Movies, &genre, &sort_by, &String::new(), 1)
+            .await
+            .expect("expected the page to have been retrieved");
+        manager.invalidate_cache();
+        let _ = manager
+            .retrieve(&Category::Movies, &genre, &sort_by, &String::new(), 1)
+            .await
+            .expect("expected the page to have been retrieved");
+    }
+
     #[test]
     fn test_get_supported_category() {
         init_logger();
@@ -240,8 +500,11 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider: Box<dyn MediaProvider> =
-            Box::new(ShowProvider::new(settings, cache_manager, false));
+        let provider: Box<dyn MediaProvider> = Box::new(ShowProvider::new(
+            settings,
+            cache_manager,
+            HttpClientFactory::default(),
+        ));
         let manager = ProviderManagerBuilder::new()
             .with_provider(provider)
             .build();
@@ -266,6 +529,155 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_supported_categories() {
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        let manager = ProviderManagerBuilder::new()
+            .with_provider(Box::new(provider))
+            .build();
+
+        let result = manager.supported_categories();
+
+        assert_eq!(vec![Category::Movies], result);
+        assert_eq!(true, manager.is_supported(&Category::Movies));
+        assert_eq!(false, manager.is_supported(&Category::Series));
+    }
+
+    #[tokio::test]
+    async fn test_verify_uri() {
+        init_logger();
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider
+            .expect_verify_uri()
+            .times(1)
+            .returning(|uri| Err(MediaError::InvalidProviderUri(uri.to_string())));
+        let manager = ProviderManagerBuilder::new()
+            .with_provider(Box::new(provider))
+            .build();
+
+        let result = manager.verify_uri(&Category::Movies, "lorem").await;
+        assert_eq!(
+            Err(MediaError::InvalidProviderUri("lorem".to_string())),
+            result
+        );
+
+        let result = manager.verify_uri(&Category::Series, "lorem").await;
+        assert_eq!(
+            Err(MediaError::ProviderNotFound(Category::Series.to_string())),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_test_connection() {
+        init_logger();
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider.expect_test_connection().times(1).returning(|| {
+            vec![
+                ("https://lorem".to_string(), Ok(Duration::from_millis(120))),
+                ("https://ipsum".to_string(), Err("timed out".to_string())),
+            ]
+        });
+        let manager = ProviderManagerBuilder::new()
+            .with_provider(Box::new(provider))
+            .build();
+
+        let result = manager.test_connection(&Category::Movies).await;
+        assert_eq!(
+            vec![
+                ("https://lorem".to_string(), Ok(Duration::from_millis(120))),
+                ("https://ipsum".to_string(), Err("timed out".to_string())),
+            ],
+            result
+        );
+
+        let result = manager.test_connection(&Category::Series).await;
+        assert_eq!(Vec::<ConnectionTestResult>::new(), result);
+    }
+
+    #[tokio::test]
+    async fn test_available_genres() {
+        init_logger();
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider.expect_genres().returning(|| {
+            vec![
+                "all".to_string(),
+                "Comedy".to_string(),
+                "documentary".to_string(),
+            ]
+        });
+        let manager = ProviderManager::builder()
+            .with_provider(Box::new(provider))
+            .with_properties(&create_properties(
+                vec!["all", "action", "comedy"],
+                vec!["trending"],
+            ))
+            .build();
+
+        let result = manager.available_genres(&Category::Movies).await;
+
+        assert_eq!(
+            vec![
+                "all".to_string(),
+                "action".to_string(),
+                "comedy".to_string(),
+                "documentary".to_string(),
+            ],
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_available_genres_provider_not_found() {
+        init_logger();
+        let manager = ProviderManager::builder()
+            .with_properties(&create_properties(vec!["all", "action"], vec!["trending"]))
+            .build();
+
+        let result = manager.available_genres(&Category::Movies).await;
+
+        assert_eq!(vec!["all".to_string(), "action".to_string()], result);
+    }
+
+    #[tokio::test]
+    async fn test_available_sort_options() {
+        init_logger();
+        let mut provider = MockMediaProvider::new();
+        provider
+            .expect_supports()
+            .returning(|category| category == &Category::Movies);
+        provider
+            .expect_sort_options()
+            .returning(|| vec!["year".to_string(), "title".to_string()]);
+        let manager = ProviderManager::builder()
+            .with_provider(Box::new(provider))
+            .with_properties(&create_properties(vec!["all"], vec!["trending", "year"]))
+            .build();
+
+        let result = manager.available_sort_options(&Category::Movies).await;
+
+        assert_eq!(
+            vec![
+                "trending".to_string(),
+                "year".to_string(),
+                "title".to_string(),
+            ],
+            result
+        );
+    }
+
     #[test]
     fn test_enhance_details() {
         init_logger();
@@ -344,4 +756,29 @@ mod test {
             .expect("expected at least one episode");
         assert_eq!(Some(thumb.to_string()), episode.thumb)
     }
+
+    fn create_movie(imdb_id: &str) -> Box<dyn MediaOverview> {
+        Box::new(MovieOverview::new(
+            "Lorem".to_string(),
+            imdb_id.to_string(),
+            "2013".to_string(),
+        ))
+    }
+
+    fn create_properties(genres: Vec<&str>, sort_by: Vec<&str>) -> PopcornProperties {
+        let mut properties = PopcornProperties::default();
+        properties.providers = vec![(
+            Category::Movies.name(),
+            ProviderProperties {
+                provider_type: ProviderType::Popcorn,
+                uris: vec![],
+                genres: genres.into_iter().map(|e| e.to_string()).collect(),
+                sort_by: sort_by.into_iter().map(|e| e.to_string()).collect(),
+                categories: vec![],
+            },
+        )]
+        .into_iter()
+        .collect();
+        properties
+    }
 }
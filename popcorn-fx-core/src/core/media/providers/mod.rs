@@ -1,16 +1,21 @@
 pub use base::*;
+pub use catalog::*;
 pub use favorites::*;
 pub use manager::*;
 pub use movie::*;
 pub use provider::*;
+pub use result_cache::*;
 pub use show::*;
 
 mod base;
+mod catalog;
 mod favorites;
 mod manager;
 mod movie;
 mod provider;
+mod result_cache;
 mod show;
 mod utils;
 
 pub mod enhancers;
+pub mod torrent_sources;
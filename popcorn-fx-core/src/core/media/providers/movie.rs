@@ -13,10 +13,12 @@ use crate::core::media::{
     Category, Genre, MediaDetails, MediaError, MediaOverview, MediaType, MovieDetails,
     MovieOverview, SortBy,
 };
-use crate::core::media::providers::{BaseProvider, MediaDetailsProvider, MediaProvider};
+use crate::core::media::providers::{
+    BaseProvider, ConnectionTestResult, MediaDetailsProvider, MediaProvider,
+};
 use crate::core::media::providers::utils::available_uris;
+use crate::core::utils::http::HttpClientFactory;
 
-const PROVIDER_NAME: &str = "movies";
 const SEARCH_RESOURCE_NAME: &str = "movies";
 const DETAILS_RESOURCE_NAME: &str = "movie";
 const CACHE_NAME: &str = "movies";
@@ -32,6 +34,7 @@ const CACHE_NAME: &str = "movies";
 /// This means that any modifications or disabled URIs in the original provider will be reflected in the cloned provider as well.
 #[derive(Debug, Clone)]
 pub struct MovieProvider {
+    settings: Arc<ApplicationConfig>,
     base: Arc<Mutex<BaseProvider>>,
     cache_manager: Arc<CacheManager>,
 }
@@ -42,7 +45,8 @@ impl MovieProvider {
     /// # Arguments
     ///
     /// * `settings` - The application settings for configuring the provider.
-    /// * `insecure` - A flag indicating whether to allow insecure connections.
+    /// * `cache_manager` - The cache manager to use for caching the provider responses.
+    /// * `client_factory` - The factory of the HTTP clients to use for the requests.
     ///
     /// # Returns
     ///
@@ -50,27 +54,29 @@ impl MovieProvider {
     pub fn new(
         settings: Arc<ApplicationConfig>,
         cache_manager: Arc<CacheManager>,
-        insecure: bool,
+        client_factory: HttpClientFactory,
     ) -> Self {
-        let uris = available_uris(&settings, PROVIDER_NAME);
+        let uris = available_uris(&settings, &Category::Movies);
 
         Self {
-            base: Arc::new(Mutex::new(BaseProvider::new(uris, insecure))),
+            settings,
+            base: Arc::new(Mutex::new(BaseProvider::new(uris, client_factory))),
             cache_manager,
         }
     }
 
     /// Resets the internal API statistics of the provider.
     ///
-    /// This method resets the API statistics of the underlying `BaseProvider`,
-    /// allowing it to re-enable all disabled URIs.
+    /// This method reloads the available URIs of the underlying `BaseProvider`,
+    /// allowing it to re-enable all disabled URIs and to apply any changed provider URI overrides.
     fn internal_api_reset(&self) {
+        let uris = available_uris(&self.settings, &Category::Movies);
         let base_arc = &self.base.clone();
         let runtime =
             tokio::runtime::Runtime::new().expect("expected a runtime to have been created");
         let mut base = runtime.block_on(base_arc.lock());
 
-        base.reset_api_stats();
+        base.update_uris(uris);
     }
 }
 
@@ -90,6 +96,16 @@ impl MediaProvider for MovieProvider {
         self.internal_api_reset()
     }
 
+    async fn verify_uri(&self, uri: &str) -> crate::core::media::Result<()> {
+        let base = self.base.lock().await;
+        base.verify_uri(uri, SEARCH_RESOURCE_NAME).await
+    }
+
+    async fn test_connection(&self) -> Vec<ConnectionTestResult> {
+        let base = self.base.lock().await;
+        base.test_connection(SEARCH_RESOURCE_NAME).await
+    }
+
     async fn retrieve(
         &self,
         genre: &Genre,
@@ -199,6 +215,7 @@ impl MediaDetailsProvider for MovieProvider {
 #[cfg(test)]
 mod test {
     use httpmock::Method::GET;
+    use httpmock::MockServer;
     use tokio::runtime;
 
     use crate::core::cache::CacheManagerBuilder;
@@ -242,7 +259,7 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider = MovieProvider::new(settings, cache_manager, false);
+        let provider = MovieProvider::new(settings, cache_manager, HttpClientFactory::default());
         let runtime = runtime::Runtime::new().unwrap();
 
         // make the api fail and become disabled
@@ -257,6 +274,75 @@ mod test {
             .expect("expected a response");
     }
 
+    #[test]
+    fn test_reset_api_provider_uri_override() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), "".to_string());
+        let (server, settings) = start_mock_server(&temp_dir);
+        let mirror = MockServer::start();
+        let bundled_mock = server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("movie-search.json"));
+        });
+        let mirror_mock = mirror.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("movie-search.json"));
+        });
+        let cache_manager = Arc::new(
+            CacheManagerBuilder::default()
+                .storage_path(temp_path)
+                .build(),
+        );
+        let provider = MovieProvider::new(
+            settings.clone(),
+            cache_manager,
+            HttpClientFactory::default(),
+        );
+
+        settings.update_provider_uris(&Category::Movies, vec![mirror.url("")]);
+        MediaProvider::reset_api(&provider);
+        let runtime = runtime::Runtime::new().unwrap();
+        let _ = runtime
+            .block_on(provider.retrieve(&genre, &sort_by, &String::new(), 1))
+            .expect("expected a response");
+
+        mirror_mock.assert();
+        bundled_mock.assert_hits(0);
+    }
+
+    #[test]
+    fn test_verify_uri() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (_server, settings) = start_mock_server(&temp_dir);
+        let mirror = MockServer::start();
+        mirror.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("movie-search.json"));
+        });
+        let cache_manager = Arc::new(
+            CacheManagerBuilder::default()
+                .storage_path(temp_path)
+                .build(),
+        );
+        let provider = MovieProvider::new(settings, cache_manager, HttpClientFactory::default());
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime.block_on(provider.verify_uri(mirror.url("").as_str()));
+
+        assert_eq!(Ok(()), result);
+    }
+
     #[test]
     fn test_retrieve() {
         init_logger();
@@ -270,7 +356,7 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider = MovieProvider::new(settings, cache_manager, false);
+        let provider = MovieProvider::new(settings, cache_manager, HttpClientFactory::default());
         let expected_result = MovieOverview::new_detailed(
             "Lorem Ipsum".to_string(),
             "tt9764362".to_string(),
@@ -308,6 +394,49 @@ mod test {
         assert_eq!(expected_result.title(), movie_result.title());
     }
 
+    #[test]
+    fn test_retrieve_partial_items() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = start_mock_server(&temp_dir);
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), "".to_string());
+        let cache_manager = Arc::new(
+            CacheManagerBuilder::default()
+                .storage_path(temp_path)
+                .build(),
+        );
+        let provider = MovieProvider::new(settings, cache_manager, HttpClientFactory::default());
+        server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("movie-search-partial.json"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime
+            .block_on(provider.retrieve(&genre, &sort_by, &String::new(), 1))
+            .expect("expected the valid media items to have been returned");
+
+        assert_eq!(
+            vec!["tt9764362", "tt0000002", "tt0000005"],
+            result.iter().map(|e| e.imdb_id()).collect::<Vec<&str>>(),
+            "expected the items without imdb id to have been skipped"
+        );
+        let movie = result.get(1).unwrap();
+        assert_eq!("", movie.title());
+        assert_eq!("", movie.year().as_str());
+        assert_eq!("", movie.images().poster());
+        assert_eq!(
+            "http://image.tmdb.org/t/p/w500/fanart.jpg",
+            movie.images().fanart()
+        );
+        assert_eq!(Some(&Rating::new(64)), movie.rating());
+        assert_eq!(&Images::none(), result.get(2).unwrap().images());
+    }
+
     #[test]
     fn test_retrieve_details() {
         init_logger();
@@ -326,7 +455,7 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider = MovieProvider::new(settings, cache_manager, false);
+        let provider = MovieProvider::new(settings, cache_manager, HttpClientFactory::default());
         let runtime = runtime::Runtime::new().unwrap();
 
         let result = runtime
@@ -1,4 +1,5 @@
 use std::fmt::{Debug, Display};
+use std::time::Duration;
 #[cfg(any(test, feature = "testing"))]
 use std::fmt::Formatter;
 
@@ -9,6 +10,10 @@ use mockall::automock;
 use crate::core::media;
 use crate::core::media::{Category, Genre, MediaDetails, MediaOverview, MediaType, SortBy};
 
+/// The outcome of a connection test against a single provider uri.
+/// It contains the tested uri together with the measured latency on success, or the error message on failure.
+pub type ConnectionTestResult = (String, Result<Duration, String>);
+
 /// A common definition of a `Media` item provider.
 /// It provides details about certain `Media` items based on the `Category` it supports.
 #[cfg_attr(any(test, feature = "testing"), automock)]
@@ -28,6 +33,35 @@ pub trait MediaProvider: Debug + Display + Send + Sync {
     /// Resets the API statistics and re-enables all disabled APIs.
     fn reset_api(&self);
 
+    /// Verifies if the given uri can be used as api of the provider.
+    ///
+    /// Providers which support uri overrides perform a test request against the uri,
+    /// other providers reject every uri.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The uri to verify.
+    ///
+    /// # Returns
+    ///
+    /// An empty result when the uri can be used, else the `MediaError` why the uri was rejected.
+    async fn verify_uri(&self, uri: &str) -> media::Result<()> {
+        Err(media::MediaError::InvalidProviderUri(uri.to_string()))
+    }
+
+    /// Tests the connection to each configured uri of the provider.
+    ///
+    /// The test doesn't affect the api statistics of the provider, so disabled uris remain disabled
+    /// and the failures of the test aren't counted towards disabling an uri.
+    ///
+    /// # Returns
+    ///
+    /// The connection test result of each configured uri,
+    /// providers without configurable uris return an empty list.
+    async fn test_connection(&self) -> Vec<ConnectionTestResult> {
+        vec![]
+    }
+
     /// Retrieves a page of `MediaOverview` items based on the given criteria.
     ///
     /// The media items only contain basic information to present as an overview.
@@ -49,6 +83,22 @@ pub trait MediaProvider: Debug + Display + Send + Sync {
         keywords: &String,
         page: u32,
     ) -> media::Result<Vec<Box<dyn MediaOverview>>>;
+
+    /// Retrieves the genre keys which are advertised by the provider.
+    ///
+    /// Providers which don't advertise their genres return an empty list,
+    /// in which case the configured genres of the provider are used instead.
+    async fn genres(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Retrieves the sort keys which are advertised by the provider.
+    ///
+    /// Providers which don't advertise their sort options return an empty list,
+    /// in which case the configured sort options of the provider are used instead.
+    async fn sort_options(&self) -> Vec<String> {
+        vec![]
+    }
 }
 
 #[cfg_attr(any(test, feature = "testing"), automock)]
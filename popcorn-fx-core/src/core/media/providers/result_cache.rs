@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::{debug, trace};
+
+use crate::core::media::{Category, Genre, MediaOverview, SortBy};
+
+/// The default duration for which a retrieved page is cached.
+pub const DEFAULT_PROVIDER_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+/// The default duration for which a retrieved search page is cached.
+/// Search results change more often, so they're cached for a shorter period.
+pub const DEFAULT_PROVIDER_SEARCH_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// The in-memory cache of the media pages which have been retrieved from the providers.
+///
+/// Pages are cached per category, genre, sort, keywords and page number.
+/// Pages retrieved with keywords use the search ttl, which allows search results to be cached
+/// for a shorter period, or not at all when the search ttl is zero.
+#[derive(Debug)]
+pub struct ProviderResultCache {
+    ttl: Duration,
+    search_ttl: Duration,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+}
+
+impl ProviderResultCache {
+    /// Creates a new `ProviderResultCache` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - The duration for which a page is cached, zero disables the cache.
+    /// * `search_ttl` - The duration for which a page retrieved with keywords is cached, zero disables the cache for searches.
+    pub fn new(ttl: Duration, search_ttl: Duration) -> Self {
+        Self {
+            ttl,
+            search_ttl,
+            entries: Default::default(),
+        }
+    }
+
+    /// Retrieve the cached media items of the given page criteria.
+    ///
+    /// It returns [None] when the page is not cached or the cached page has expired.
+    pub fn get(
+        &self,
+        category: &Category,
+        genre: &Genre,
+        sort_by: &SortBy,
+        keywords: &str,
+        page: u32,
+    ) -> Option<Vec<Box<dyn MediaOverview>>> {
+        let key = CacheKey::new(category, genre, sort_by, keywords, page);
+        let ttl = self.ttl(keywords);
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(&key) {
+            Some(entry) if entry.cached_at.elapsed() < ttl => {
+                trace!("Provider cache hit for {:?}", key);
+                Some(
+                    entry
+                        .items
+                        .iter()
+                        .filter_map(|e| e.into_overview())
+                        .collect(),
+                )
+            }
+            Some(_) => {
+                trace!("Provider cache entry {:?} has expired", key);
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store the retrieved media items of the given page criteria.
+    /// The items are not stored when caching has been disabled for the criteria.
+    pub fn insert(
+        &self,
+        category: &Category,
+        genre: &Genre,
+        sort_by: &SortBy,
+        keywords: &str,
+        page: u32,
+        items: &[Box<dyn MediaOverview>],
+    ) {
+        let ttl = self.ttl(keywords);
+        if ttl.is_zero() {
+            return;
+        }
+
+        let key = CacheKey::new(category, genre, sort_by, keywords, page);
+        let mut entries = self.entries.lock().unwrap();
+
+        // remove the expired entries, so the cache doesn't keep growing while browsing
+        entries.retain(|key, entry| entry.cached_at.elapsed() < self.ttl(&key.keywords));
+        trace!("Caching {} provider items for {:?}", items.len(), key);
+        entries.insert(
+            key,
+            CacheEntry {
+                items: items.iter().filter_map(|e| e.into_overview()).collect(),
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Remove all cached pages from the cache.
+    pub fn invalidate(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        debug!("Invalidating {} cached provider pages", entries.len());
+        entries.clear();
+    }
+
+    fn ttl(&self, keywords: &str) -> Duration {
+        if keywords.trim().is_empty() {
+            self.ttl
+        } else {
+            self.search_ttl
+        }
+    }
+}
+
+impl Default for ProviderResultCache {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_PROVIDER_CACHE_TTL,
+            DEFAULT_PROVIDER_SEARCH_CACHE_TTL,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    category: Category,
+    genre: String,
+    sort_by: String,
+    keywords: String,
+    page: u32,
+}
+
+impl CacheKey {
+    fn new(
+        category: &Category,
+        genre: &Genre,
+        sort_by: &SortBy,
+        keywords: &str,
+        page: u32,
+    ) -> Self {
+        Self {
+            category: category.clone(),
+            genre: genre.key().to_string(),
+            sort_by: sort_by.key().to_string(),
+            keywords: keywords.trim().to_lowercase(),
+            page,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    items: Vec<Box<dyn MediaOverview>>,
+    cached_at: Instant,
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use crate::core::media::MovieOverview;
+
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let cache = ProviderResultCache::default();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+        let items = vec![create_movie("tt0000001")];
+
+        cache.insert(&Category::Movies, &genre, &sort_by, "", 1, &items);
+        let result = cache
+            .get(&Category::Movies, &genre, &sort_by, "", 1)
+            .expect("expected the page to have been cached");
+
+        assert_eq!(1, result.len());
+        assert_eq!("tt0000001", result.get(0).unwrap().imdb_id());
+        assert!(cache
+            .get(&Category::Movies, &genre, &sort_by, "", 2)
+            .is_none());
+        assert!(cache
+            .get(&Category::Series, &genre, &sort_by, "", 1)
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_expired() {
+        let cache = ProviderResultCache::new(Duration::from_millis(50), Duration::ZERO);
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+
+        cache.insert(
+            &Category::Movies,
+            &genre,
+            &sort_by,
+            "",
+            1,
+            &[create_movie("tt0000001")],
+        );
+        thread::sleep(Duration::from_millis(100));
+        let result = cache.get(&Category::Movies, &genre, &sort_by, "", 1);
+
+        assert!(result.is_none(), "expected the cached page to have expired");
+    }
+
+    #[test]
+    fn test_insert_search_disabled() {
+        let cache = ProviderResultCache::new(DEFAULT_PROVIDER_CACHE_TTL, Duration::ZERO);
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+
+        cache.insert(
+            &Category::Movies,
+            &genre,
+            &sort_by,
+            "lorem",
+            1,
+            &[create_movie("tt0000001")],
+        );
+        let result = cache.get(&Category::Movies, &genre, &sort_by, "lorem", 1);
+
+        assert!(result.is_none(), "expected search results to not be cached");
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let cache = ProviderResultCache::default();
+        let genre = Genre::all();
+        let sort_by = SortBy::new("trending".to_string(), String::new());
+
+        cache.insert(
+            &Category::Movies,
+            &genre,
+            &sort_by,
+            "",
+            1,
+            &[create_movie("tt0000001")],
+        );
+        cache.invalidate();
+        let result = cache.get(&Category::Movies, &genre, &sort_by, "", 1);
+
+        assert!(
+            result.is_none(),
+            "expected the cache to have been invalidated"
+        );
+    }
+
+    fn create_movie(imdb_id: &str) -> Box<dyn MediaOverview> {
+        Box::new(MovieOverview {
+            title: "Lorem".to_string(),
+            imdb_id: imdb_id.to_string(),
+            year: "2013".to_string(),
+            rating: None,
+            images: Default::default(),
+        })
+    }
+}
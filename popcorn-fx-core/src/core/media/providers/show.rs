@@ -13,10 +13,12 @@ use crate::core::media::{
     Category, Genre, MediaDetails, MediaError, MediaOverview, MediaType, ShowDetails, ShowOverview,
     SortBy,
 };
-use crate::core::media::providers::{BaseProvider, MediaDetailsProvider, MediaProvider};
+use crate::core::media::providers::{
+    BaseProvider, ConnectionTestResult, MediaDetailsProvider, MediaProvider,
+};
 use crate::core::media::providers::utils::available_uris;
+use crate::core::utils::http::HttpClientFactory;
 
-const PROVIDER_NAME: &str = "series";
 const SEARCH_RESOURCE_NAME: &str = "shows";
 const DETAILS_RESOURCE_NAME: &str = "show";
 const CACHE_NAME: &str = "shows";
@@ -32,6 +34,7 @@ const CACHE_NAME: &str = "shows";
 /// This means that any modifications or disabled URIs in the original provider will be reflected in the cloned provider as well.
 #[derive(Debug, Clone)]
 pub struct ShowProvider {
+    settings: Arc<ApplicationConfig>,
     base: Arc<Mutex<BaseProvider>>,
     cache_manager: Arc<CacheManager>,
 }
@@ -43,7 +46,8 @@ impl ShowProvider {
     ///
     /// * `settings` - The application settings for configuring the provider.
     /// * `cache_manager` - The cache manager for caching provider responses.
-    /// * `insecure` - A flag indicating whether to allow insecure connections.
+    /// * `cache_manager` - The cache manager to use for caching the provider responses.
+    /// * `client_factory` - The factory of the HTTP clients to use for the requests.
     ///
     /// # Returns
     ///
@@ -51,27 +55,29 @@ impl ShowProvider {
     pub fn new(
         settings: Arc<ApplicationConfig>,
         cache_manager: Arc<CacheManager>,
-        insecure: bool,
+        client_factory: HttpClientFactory,
     ) -> Self {
-        let uris = available_uris(&settings, PROVIDER_NAME);
+        let uris = available_uris(&settings, &Category::Series);
 
         Self {
-            base: Arc::new(Mutex::new(BaseProvider::new(uris, insecure))),
+            settings,
+            base: Arc::new(Mutex::new(BaseProvider::new(uris, client_factory))),
             cache_manager,
         }
     }
 
     /// Resets the internal API statistics of the provider.
     ///
-    /// This method resets the API statistics of the underlying `BaseProvider`,
-    /// allowing it to re-enable all disabled URIs.
+    /// This method reloads the available URIs of the underlying `BaseProvider`,
+    /// allowing it to re-enable all disabled URIs and to apply any changed provider URI overrides.
     fn internal_api_reset(&self) {
+        let uris = available_uris(&self.settings, &Category::Series);
         let base_arc = &self.base.clone();
         let runtime =
             tokio::runtime::Runtime::new().expect("expected a runtime to have been created");
         let mut base = runtime.block_on(base_arc.lock());
 
-        base.reset_api_stats();
+        base.update_uris(uris);
     }
 }
 
@@ -91,6 +97,16 @@ impl MediaProvider for ShowProvider {
         self.internal_api_reset()
     }
 
+    async fn verify_uri(&self, uri: &str) -> crate::core::media::Result<()> {
+        let base = self.base.lock().await;
+        base.verify_uri(uri, SEARCH_RESOURCE_NAME).await
+    }
+
+    async fn test_connection(&self) -> Vec<ConnectionTestResult> {
+        let base = self.base.lock().await;
+        base.test_connection(SEARCH_RESOURCE_NAME).await
+    }
+
     async fn retrieve(
         &self,
         genre: &Genre,
@@ -232,7 +248,7 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider = ShowProvider::new(settings, cache_manager, false);
+        let provider = ShowProvider::new(settings, cache_manager, HttpClientFactory::default());
         let runtime = runtime::Runtime::new().unwrap();
 
         let result = runtime
@@ -260,7 +276,7 @@ mod test {
                 .storage_path(temp_path)
                 .build(),
         );
-        let provider = ShowProvider::new(settings, cache_manager, false);
+        let provider = ShowProvider::new(settings, cache_manager, HttpClientFactory::default());
         let runtime = runtime::Runtime::new().unwrap();
 
         let result = runtime
@@ -0,0 +1,5 @@
+pub use torrent_source::*;
+pub use torrentio::*;
+
+mod torrent_source;
+mod torrentio;
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+#[cfg(any(test, feature = "testing"))]
+use std::fmt::Formatter;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use derive_more::Display;
+use log::{debug, trace, warn};
+#[cfg(any(test, feature = "testing"))]
+use mockall::automock;
+use thiserror::Error;
+
+use crate::core::media::TorrentInfo;
+use crate::core::torrents::Magnet;
+
+/// The query to search the torrents of a media item within a torrent source.
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum TorrentSourceQuery {
+    /// Search the torrents of the movie with the given IMDB id.
+    #[display(fmt = "movie {}", imdb_id)]
+    Movie { imdb_id: String },
+    /// Search the torrents of an episode of the show with the given IMDB id.
+    #[display(fmt = "episode {}:{}:{}", imdb_id, season, episode)]
+    Episode {
+        imdb_id: String,
+        season: u32,
+        episode: u32,
+    },
+}
+
+/// The errors which can occur while retrieving torrents from a torrent source.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum TorrentSourceError {
+    #[error("failed to retrieve torrents, {0}")]
+    Unavailable(String),
+    #[error("failed to parse torrents, {0}")]
+    Parsing(String),
+}
+
+/// A source of torrents which can be queried for additional torrents of a media item.
+///
+/// ## async
+///
+/// The TorrentSourceProvider should be able to be sent across threads in a safe manner.
+/// This means that each implementation must guarantee [Send] & [Sync] compatibility.
+#[cfg_attr(any(test, feature = "testing"), automock)]
+#[async_trait]
+pub trait TorrentSourceProvider: Debug + Display + Send + Sync {
+    /// Retrieve the maximum time to wait for the torrents of this source.
+    fn timeout(&self) -> Duration;
+
+    /// Retrieve the torrents of this source which match the given query.
+    ///
+    /// It returns the found torrents, else the [TorrentSourceError].
+    async fn torrents(
+        &self,
+        query: &TorrentSourceQuery,
+    ) -> Result<Vec<TorrentInfo>, TorrentSourceError>;
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Display for MockTorrentSourceProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MockTorrentSourceProvider")
+    }
+}
+
+/// Aggregates the torrents of a media item across the configured torrent sources.
+///
+/// The torrents of all sources are merged with the given torrents, deduplicated by info hash
+/// and ranked by their health, resulting in the healthiest torrent for each quality.
+/// A source which doesn't respond within its timeout is ignored.
+#[derive(Debug, Default)]
+pub struct TorrentSourceAggregator {
+    providers: Vec<Box<dyn TorrentSourceProvider>>,
+}
+
+impl TorrentSourceAggregator {
+    /// Create a new aggregator for the given torrent sources.
+    pub fn new(providers: Vec<Box<dyn TorrentSourceProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Verify if no torrent sources have been configured.
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    /// Merge the given torrents, mapped by quality, with the torrents of all sources which match the query.
+    ///
+    /// It returns the healthiest torrent for each known quality.
+    pub async fn aggregate(
+        &self,
+        query: &TorrentSourceQuery,
+        torrents: HashMap<String, TorrentInfo>,
+    ) -> HashMap<String, TorrentInfo> {
+        if self.providers.is_empty() {
+            trace!(
+                "No torrent sources configured, skipping aggregation of {}",
+                query
+            );
+            return torrents;
+        }
+
+        debug!(
+            "Aggregating torrents of {} across {} sources",
+            query,
+            self.providers.len()
+        );
+        let source_torrents = futures::future::join_all(
+            self.providers
+                .iter()
+                .map(|provider| Self::provider_torrents(provider, query)),
+        )
+        .await;
+
+        let mut candidates: HashMap<String, Vec<TorrentInfo>> = HashMap::new();
+        for (quality, torrent) in torrents.into_iter() {
+            candidates.entry(quality).or_default().push(torrent);
+        }
+        for torrent in source_torrents.into_iter().flatten() {
+            candidates
+                .entry(torrent.quality().clone())
+                .or_default()
+                .push(torrent);
+        }
+
+        candidates
+            .into_iter()
+            .filter_map(|(quality, torrents)| Self::healthiest(torrents).map(|e| (quality, e)))
+            .collect()
+    }
+
+    async fn provider_torrents(
+        provider: &Box<dyn TorrentSourceProvider>,
+        query: &TorrentSourceQuery,
+    ) -> Vec<TorrentInfo> {
+        match tokio::time::timeout(provider.timeout(), provider.torrents(query)).await {
+            Ok(Ok(torrents)) => {
+                debug!(
+                    "Torrent source {} returned {} torrents",
+                    provider,
+                    torrents.len()
+                );
+                torrents
+            }
+            Ok(Err(e)) => {
+                warn!("Torrent source {} failed for {}, {}", provider, query, e);
+                vec![]
+            }
+            Err(_) => {
+                warn!(
+                    "Torrent source {} didn't respond within {:?} for {}",
+                    provider,
+                    provider.timeout(),
+                    query
+                );
+                vec![]
+            }
+        }
+    }
+
+    /// Retrieve the healthiest torrent of the given torrents after deduplicating them by info hash.
+    fn healthiest(torrents: Vec<TorrentInfo>) -> Option<TorrentInfo> {
+        let mut unique: Vec<TorrentInfo> = vec![];
+
+        for torrent in torrents.into_iter() {
+            let info_hash = Self::info_hash(&torrent);
+            match unique
+                .iter_mut()
+                .find(|e| info_hash.is_some() && Self::info_hash(e) == info_hash)
+            {
+                Some(existing) => {
+                    if torrent.seed() > existing.seed() {
+                        *existing = torrent;
+                    }
+                }
+                None => unique.push(torrent),
+            }
+        }
+
+        unique.into_iter().max_by_key(|e| (*e.seed(), *e.peer()))
+    }
+
+    fn info_hash(torrent: &TorrentInfo) -> Option<String> {
+        Magnet::from_str(torrent.url())
+            .ok()
+            .and_then(|e| e.info_hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::block_in_place;
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[derive(Debug, Display)]
+    #[display(fmt = "UnresponsiveTorrentSource")]
+    struct UnresponsiveTorrentSource {}
+
+    #[async_trait]
+    impl TorrentSourceProvider for UnresponsiveTorrentSource {
+        fn timeout(&self) -> Duration {
+            Duration::from_millis(50)
+        }
+
+        async fn torrents(
+            &self,
+            _: &TorrentSourceQuery,
+        ) -> Result<Vec<TorrentInfo>, TorrentSourceError> {
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            Ok(vec![torrent_info("ffff", "1080p", 9999)])
+        }
+    }
+
+    fn torrent_info(info_hash: &str, quality: &str, seeds: u32) -> TorrentInfo {
+        TorrentInfo::builder()
+            .url(format!("magnet:?xt=urn:btih:{}", info_hash))
+            .provider("")
+            .source("")
+            .title("")
+            .quality(quality)
+            .seed(seeds)
+            .peer(0)
+            .build()
+    }
+
+    #[test]
+    fn test_aggregate() {
+        init_logger();
+        let query = TorrentSourceQuery::Movie {
+            imdb_id: "tt0000001".to_string(),
+        };
+        let mut provider = MockTorrentSourceProvider::new();
+        provider
+            .expect_timeout()
+            .return_const(Duration::from_secs(1));
+        provider.expect_torrents().times(1).returning(|_| {
+            Ok(vec![
+                torrent_info("AAAA", "720p", 50),
+                torrent_info("bbbb", "1080p", 80),
+                torrent_info("cccc", "2160p", 10),
+            ])
+        });
+        let aggregator = TorrentSourceAggregator::new(vec![Box::new(provider)]);
+        let torrents = HashMap::from([
+            ("720p".to_string(), torrent_info("aaaa", "720p", 2)),
+            ("1080p".to_string(), torrent_info("dddd", "1080p", 5)),
+        ]);
+
+        let result = block_in_place(aggregator.aggregate(&query, torrents));
+
+        assert_eq!(3, result.len());
+        assert_eq!(
+            &50,
+            result.get("720p").unwrap().seed(),
+            "expected the duplicate info hash to use the healthiest torrent"
+        );
+        assert_eq!(
+            "magnet:?xt=urn:btih:bbbb",
+            result.get("1080p").unwrap().url(),
+            "expected the healthiest torrent to have been selected"
+        );
+        assert_eq!(
+            "magnet:?xt=urn:btih:cccc",
+            result.get("2160p").unwrap().url()
+        );
+    }
+
+    #[test]
+    fn test_aggregate_provider_error() {
+        init_logger();
+        let query = TorrentSourceQuery::Movie {
+            imdb_id: "tt0000001".to_string(),
+        };
+        let mut provider = MockTorrentSourceProvider::new();
+        provider
+            .expect_timeout()
+            .return_const(Duration::from_secs(1));
+        provider
+            .expect_torrents()
+            .times(1)
+            .returning(|_| Err(TorrentSourceError::Unavailable("lorem".to_string())));
+        let aggregator = TorrentSourceAggregator::new(vec![Box::new(provider)]);
+        let torrents = HashMap::from([("720p".to_string(), torrent_info("aaaa", "720p", 2))]);
+
+        let result = block_in_place(aggregator.aggregate(&query, torrents.clone()));
+
+        assert_eq!(torrents, result);
+    }
+
+    #[test]
+    fn test_aggregate_provider_timeout() {
+        init_logger();
+        let query = TorrentSourceQuery::Episode {
+            imdb_id: "tt0000002".to_string(),
+            season: 1,
+            episode: 3,
+        };
+        let aggregator = TorrentSourceAggregator::new(vec![Box::new(UnresponsiveTorrentSource {})]);
+        let torrents = HashMap::from([("720p".to_string(), torrent_info("aaaa", "720p", 2))]);
+
+        let result = block_in_place(aggregator.aggregate(&query, torrents.clone()));
+
+        assert_eq!(
+            torrents, result,
+            "expected the unresponsive source to have been ignored"
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use derive_more::Display;
+use log::{debug, trace};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::core::config::TorrentSourceProperties;
+use crate::core::media::providers::torrent_sources::{
+    TorrentSourceError, TorrentSourceProvider, TorrentSourceQuery,
+};
+use crate::core::media::TorrentInfo;
+use crate::core::utils::http::HttpClientFactory;
+
+const QUALITY_PATTERN: &str = "(?i)\\b(2160p|4k|1080p|720p|480p)\\b";
+const SEEDS_PATTERN: &str = "👤\\s*(\\d+)";
+const SIZE_PATTERN: &str = "💾\\s*([\\d.]+\\s*[KMGT]?B)";
+const SOURCE_PATTERN: &str = "⚙️\\s*(.+)";
+
+#[derive(Debug, Deserialize)]
+struct StreamsResponse {
+    #[serde(default)]
+    streams: Vec<Stream>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Stream {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    title: String,
+    info_hash: Option<String>,
+}
+
+/// A torrent source which uses the stream api of a Torrentio compatible addon.
+///
+/// The torrents are retrieved from `{uri}/stream/movie/{imdb_id}.json` for movies,
+/// and from `{uri}/stream/series/{imdb_id}:{season}:{episode}.json` for episodes.
+#[derive(Debug, Display)]
+#[display(fmt = "{} ({})", name, "properties.uri")]
+pub struct TorrentioSourceProvider {
+    name: String,
+    properties: TorrentSourceProperties,
+    client_factory: HttpClientFactory,
+    quality_regex: Regex,
+    seeds_regex: Regex,
+    size_regex: Regex,
+    source_regex: Regex,
+}
+
+impl TorrentioSourceProvider {
+    /// Create a new torrent source for the given properties.
+    ///
+    /// * `name` - The name of the torrent source, which is used as provider of the torrents.
+    /// * `properties` - The properties of the torrent source.
+    /// * `client_factory` - The factory of the http client used to query the torrent source.
+    pub fn new(
+        name: &str,
+        properties: TorrentSourceProperties,
+        client_factory: HttpClientFactory,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            properties,
+            client_factory,
+            quality_regex: Regex::new(QUALITY_PATTERN).expect("quality pattern should be valid"),
+            seeds_regex: Regex::new(SEEDS_PATTERN).expect("seeds pattern should be valid"),
+            size_regex: Regex::new(SIZE_PATTERN).expect("size pattern should be valid"),
+            source_regex: Regex::new(SOURCE_PATTERN).expect("source pattern should be valid"),
+        }
+    }
+
+    fn build_url(&self, query: &TorrentSourceQuery) -> String {
+        let uri = self.properties.uri.trim_end_matches('/');
+
+        match query {
+            TorrentSourceQuery::Movie { imdb_id } => {
+                format!("{}/stream/movie/{}.json", uri, imdb_id)
+            }
+            TorrentSourceQuery::Episode {
+                imdb_id,
+                season,
+                episode,
+            } => format!(
+                "{}/stream/series/{}:{}:{}.json",
+                uri, imdb_id, season, episode
+            ),
+        }
+    }
+
+    /// Convert the given stream into a torrent.
+    ///
+    /// It returns `None` when the stream has no info hash or no known quality.
+    fn to_torrent_info(&self, stream: Stream) -> Option<TorrentInfo> {
+        let info_hash = stream.info_hash.as_ref()?.to_lowercase();
+        let description = format!("{}\n{}", stream.name, stream.title);
+        let quality = self
+            .quality_regex
+            .captures(description.as_str())
+            .and_then(|e| e.get(1))
+            .map(|e| match e.as_str().to_lowercase().as_str() {
+                "4k" => "2160p".to_string(),
+                quality => quality.to_string(),
+            })?;
+        let title = stream.title.lines().next().unwrap_or_default().to_string();
+        let seeds = self
+            .seeds_regex
+            .captures(stream.title.as_str())
+            .and_then(|e| e.get(1))
+            .and_then(|e| e.as_str().parse::<u32>().ok())
+            .unwrap_or(0);
+        let source = self
+            .source_regex
+            .captures(stream.title.as_str())
+            .and_then(|e| e.get(1))
+            .map(|e| e.as_str().trim().to_string())
+            .unwrap_or_else(|| self.name.clone());
+        let url = format!(
+            "magnet:?xt=urn:btih:{}&dn={}",
+            info_hash,
+            url::form_urlencoded::byte_serialize(title.as_bytes()).collect::<String>()
+        );
+
+        let mut builder = TorrentInfo::builder()
+            .url(url)
+            .provider(self.name.as_str())
+            .source(source)
+            .title(title)
+            .quality(quality)
+            .seed(seeds)
+            .peer(0);
+        if let Some(size) = self
+            .size_regex
+            .captures(stream.title.as_str())
+            .and_then(|e| e.get(1))
+        {
+            builder = builder.size(size.as_str());
+        }
+
+        Some(builder.build())
+    }
+}
+
+#[async_trait]
+impl TorrentSourceProvider for TorrentioSourceProvider {
+    fn timeout(&self) -> Duration {
+        self.properties.timeout()
+    }
+
+    async fn torrents(
+        &self,
+        query: &TorrentSourceQuery,
+    ) -> Result<Vec<TorrentInfo>, TorrentSourceError> {
+        let url = self.build_url(query);
+
+        trace!("Retrieving torrent source streams from {}", url);
+        let response = self
+            .client_factory
+            .client()
+            .get(url.as_str())
+            .send()
+            .await
+            .map_err(|e| TorrentSourceError::Unavailable(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TorrentSourceError::Unavailable(format!(
+                "received invalid response status code {}",
+                response.status()
+            )));
+        }
+
+        let response = response
+            .json::<StreamsResponse>()
+            .await
+            .map_err(|e| TorrentSourceError::Parsing(e.to_string()))?;
+        let total_streams = response.streams.len();
+        let torrents: Vec<TorrentInfo> = response
+            .streams
+            .into_iter()
+            .filter_map(|e| self.to_torrent_info(e))
+            .collect();
+
+        debug!(
+            "Retrieved {} torrents out of {} streams from {}",
+            torrents.len(),
+            total_streams,
+            url
+        );
+        Ok(torrents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+
+    use crate::core::block_in_place;
+    use crate::testing::{init_logger, read_test_file_to_string};
+
+    use super::*;
+
+    fn provider(server: &MockServer) -> TorrentioSourceProvider {
+        TorrentioSourceProvider::new(
+            "torrentio",
+            TorrentSourceProperties {
+                uri: server.url("/"),
+                timeout_seconds: 5,
+            },
+            HttpClientFactory::default(),
+        )
+    }
+
+    #[test]
+    fn test_torrents_movie() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/stream/movie/tt0000001.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("torrentio-streams.json"));
+        });
+        let provider = provider(&server);
+        let query = TorrentSourceQuery::Movie {
+            imdb_id: "tt0000001".to_string(),
+        };
+
+        let result = block_in_place(provider.torrents(&query)).unwrap();
+
+        assert_eq!(
+            3,
+            result.len(),
+            "expected the streams without hash or quality to have been ignored"
+        );
+        let torrent = result.get(0).unwrap();
+        assert_eq!(
+            "magnet:?xt=urn:btih:8a2f5c4d3e1b0a9f8e7d6c5b4a3928171615141a&dn=Lorem.Ipsum.2014.1080p.BluRay.x264-GROUP",
+            torrent.url()
+        );
+        assert_eq!("torrentio", torrent.provider());
+        assert_eq!("ThePirateBay", torrent.source());
+        assert_eq!("1080p", torrent.quality());
+        assert_eq!(&125, torrent.seed());
+        assert_eq!(Some(&"2.1 GB".to_string()), torrent.size());
+        assert_eq!("720p", result.get(1).unwrap().quality());
+        assert_eq!("2160p", result.get(2).unwrap().quality());
+    }
+
+    #[test]
+    fn test_torrents_episode() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/stream/series/tt0000002:1:3.json");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"streams":[]}"#);
+        });
+        let provider = provider(&server);
+        let query = TorrentSourceQuery::Episode {
+            imdb_id: "tt0000002".to_string(),
+            season: 1,
+            episode: 3,
+        };
+
+        let result = block_in_place(provider.torrents(&query)).unwrap();
+
+        mock.assert();
+        assert_eq!(Vec::<TorrentInfo>::new(), result);
+    }
+
+    #[test]
+    fn test_torrents_invalid_status() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/stream/movie/tt0000001.json");
+            then.status(503);
+        });
+        let provider = provider(&server);
+        let query = TorrentSourceQuery::Movie {
+            imdb_id: "tt0000001".to_string(),
+        };
+
+        let result = block_in_place(provider.torrents(&query));
+
+        if let Err(TorrentSourceError::Unavailable(_)) = result {
+        } else {
+            assert!(
+                false,
+                "expected TorrentSourceError::Unavailable, got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_torrents_invalid_body() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/stream/movie/tt0000001.json");
+            then.status(200).body("lorem ipsum");
+        });
+        let provider = provider(&server);
+        let query = TorrentSourceQuery::Movie {
+            imdb_id: "tt0000001".to_string(),
+        };
+
+        let result = block_in_place(provider.torrents(&query));
+
+        if let Err(TorrentSourceError::Parsing(_)) = result {
+        } else {
+            assert!(
+                false,
+                "expected TorrentSourceError::Parsing, got {:?} instead",
+                result
+            );
+        }
+    }
+}
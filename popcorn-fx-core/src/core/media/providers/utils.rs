@@ -1,18 +1,20 @@
-use log::error;
-
 use crate::core::config::ApplicationConfig;
+use crate::core::media::Category;
 
-/// Retrieves the available URIs for a given provider name based on the application configuration.
+/// Retrieves the available URIs for a given category based on the application configuration.
+///
+/// The configured api server is always tried first, followed by the provider uri's of the category.
+/// See [ApplicationConfig::provider_uris] for the precedence of the provider uri's.
 ///
 /// # Arguments
 ///
 /// * `config` - A reference to the `ApplicationConfig` containing the configuration settings.
-/// * `provider_name` - The name of the provider for which URIs are to be retrieved.
+/// * `category` - The category of the provider for which URIs are to be retrieved.
 ///
 /// # Returns
 ///
 /// Returns a vector of URIs representing the available endpoints for the specified provider.
-pub fn available_uris(config: &ApplicationConfig, provider_name: &str) -> Vec<String> {
+pub fn available_uris(config: &ApplicationConfig, category: &Category) -> Vec<String> {
     let settings = config.user_settings();
     let api_server = settings.server().api_server().filter(|e| !e.is_empty());
     let mut uris: Vec<String> = vec![];
@@ -22,16 +24,7 @@ pub fn available_uris(config: &ApplicationConfig, provider_name: &str) -> Vec<St
         Some(e) => uris.push(e.clone()),
     }
 
-    let properties = config.properties();
-    match properties.provider(provider_name) {
-        Ok(e) => {
-            for uri in e.uris() {
-                uris.push(uri.clone());
-            }
-        }
-        Err(err) => error!("Failed to retrieve provider info, {}", err),
-    };
-
+    uris.extend(config.provider_uris(category));
     uris
 }
 
@@ -40,7 +33,7 @@ mod test {
     use std::collections::HashMap;
 
     use crate::core::config::{
-        PopcornProperties, PopcornSettings, ProviderProperties, ServerSettings,
+        PopcornProperties, PopcornSettings, ProviderProperties, ProviderType, ServerSettings,
     };
     use crate::testing::init_logger;
 
@@ -51,7 +44,6 @@ mod test {
         init_logger();
         let api_server = "http://lorem".to_string();
         let provider = "http://ipsum".to_string();
-        let provider_name = "my-provider".to_string();
         let temp_dir = tempfile::tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
         let settings = ApplicationConfig::builder()
@@ -60,14 +52,17 @@ mod test {
                 loggers: Default::default(),
                 update_channel: String::new(),
                 providers: HashMap::from([(
-                    provider_name.clone(),
+                    "movies".to_string(),
                     ProviderProperties {
+                        provider_type: ProviderType::Popcorn,
                         uris: vec![provider.clone()],
                         genres: vec![],
                         sort_by: vec![],
+                        categories: vec![],
                     },
                 )]),
                 enhancers: Default::default(),
+                torrent_sources: Default::default(),
                 subtitle: Default::default(),
                 tracking: Default::default(),
             })
@@ -76,19 +71,69 @@ mod test {
                 ui_settings: Default::default(),
                 server_settings: ServerSettings {
                     api_server: Some(api_server.clone()),
+                    provider_uris: Default::default(),
                 },
                 torrent_settings: Default::default(),
                 playback_settings: Default::default(),
                 tracking_settings: Default::default(),
+                network_settings: Default::default(),
             })
             .build();
         let expected_result = vec![api_server, provider];
 
-        let result = available_uris(&settings, provider_name.as_str());
+        let result = available_uris(&settings, &Category::Movies);
 
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_available_uris_provider_override() {
+        init_logger();
+        let override_uri = "http://dolor".to_string();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = ApplicationConfig::builder()
+            .storage(temp_path)
+            .properties(PopcornProperties {
+                loggers: Default::default(),
+                update_channel: String::new(),
+                providers: HashMap::from([(
+                    "series".to_string(),
+                    ProviderProperties {
+                        provider_type: ProviderType::Popcorn,
+                        uris: vec!["http://ipsum".to_string()],
+                        genres: vec![],
+                        sort_by: vec![],
+                        categories: vec![],
+                    },
+                )]),
+                enhancers: Default::default(),
+                torrent_sources: Default::default(),
+                subtitle: Default::default(),
+                tracking: Default::default(),
+            })
+            .settings(PopcornSettings {
+                subtitle_settings: Default::default(),
+                ui_settings: Default::default(),
+                server_settings: ServerSettings {
+                    api_server: None,
+                    provider_uris: HashMap::from([(
+                        "series".to_string(),
+                        vec![override_uri.clone()],
+                    )]),
+                },
+                torrent_settings: Default::default(),
+                playback_settings: Default::default(),
+                tracking_settings: Default::default(),
+                network_settings: Default::default(),
+            })
+            .build();
+
+        let result = available_uris(&settings, &Category::Series);
+
+        assert_eq!(vec![override_uri], result)
+    }
+
     #[test]
     fn test_available_uris_provider_not_available() {
         init_logger();
@@ -102,6 +147,7 @@ mod test {
                 update_channel: String::new(),
                 providers: HashMap::new(),
                 enhancers: Default::default(),
+                torrent_sources: Default::default(),
                 subtitle: Default::default(),
                 tracking: Default::default(),
             })
@@ -110,15 +156,17 @@ mod test {
                 ui_settings: Default::default(),
                 server_settings: ServerSettings {
                     api_server: Some(api_server.clone()),
+                    provider_uris: Default::default(),
                 },
                 torrent_settings: Default::default(),
                 playback_settings: Default::default(),
                 tracking_settings: Default::default(),
+                network_settings: Default::default(),
             })
             .build();
         let expected_result = vec![api_server];
 
-        let result = available_uris(&settings, "lorem");
+        let result = available_uris(&settings, &Category::Series);
 
         assert_eq!(expected_result, result)
     }
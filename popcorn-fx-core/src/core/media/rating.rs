@@ -5,10 +5,15 @@ use serde::{Deserialize, Serialize};
 /// The rating information of a media item.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
 pub struct Rating {
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub percentage: u16,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub watching: u32,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub votes: u32,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub loved: u32,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub hated: u32,
 }
 
@@ -1,8 +1,13 @@
 use derive_more::Display;
 use itertools::Itertools;
 use log::{debug, trace};
+use ring::digest;
+use ring::digest::digest;
 use serde::{Deserialize, Serialize};
 
+use crate::core::media::{Episode, MediaIdentifier};
+use crate::core::playlists::StoredMedia;
+
 /// The auto-resume data structure.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AutoResume {
@@ -11,12 +16,25 @@ pub struct AutoResume {
 }
 
 impl AutoResume {
+    /// Create the resume id of the given media url.
+    ///
+    /// The id is used for playbacks without a media item, such as direct media urls,
+    /// as the filename of such urls isn't guaranteed to be unique.
+    pub fn url_id(url: &str) -> String {
+        digest(&digest::SHA256, url.as_bytes())
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    }
+
     /// Find the video timestamp by the given filename.
     ///
     /// It returns the video timestamp reference when found, else [None].
     pub fn find_filename(&self, filename: &str) -> Option<&VideoTimestamp> {
         self.video_timestamps
             .iter()
+            .filter(|e| !e.finished)
             .find(|e| Self::internal_find_by_filename(filename, e))
     }
 
@@ -24,25 +42,39 @@ impl AutoResume {
     ///
     /// It returns the video timestamp reference when found, else [None].
     pub fn find_id(&self, id: &str) -> Option<&VideoTimestamp> {
-        self.video_timestamps.iter().find(|e| {
-            if let Some(video_id) = e.id() {
-                return video_id.as_str() == id;
-            }
+        self.video_timestamps
+            .iter()
+            .filter(|e| !e.finished)
+            .find(|e| {
+                if let Some(video_id) = e.id() {
+                    return video_id.as_str() == id;
+                }
 
-            false
-        })
+                false
+            })
     }
 
     /// Add or update a video `timestamp` within the resume data.
     /// The `timestamp` will be update if a record already exists,
     /// else a new one will be created.
-    pub fn insert<'a>(&mut self, id: Option<&'a str>, filename: &'a str, timestamp: u64) {
+    ///
+    /// The `duration` of the video is stored alongside the timestamp when known,
+    /// which allows the playback progress to be determined when the video is resumed.
+    ///
+    /// The timestamps are ordered by their last playback, the most recent one being last.
+    pub fn insert<'a>(
+        &mut self,
+        id: Option<&'a str>,
+        filename: &'a str,
+        timestamp: u64,
+        duration: Option<u64>,
+    ) -> &mut VideoTimestamp {
         // check if the timestamp already exists
         // if so, we update the information of the existing one
         match self
             .video_timestamps
-            .iter_mut()
-            .find(|e| Self::internal_find_by_filename(filename, &&**e))
+            .iter()
+            .position(|e| Self::internal_find_by_filename(filename, &e))
         {
             None => {
                 trace!(
@@ -50,21 +82,60 @@ impl AutoResume {
                     id,
                     filename
                 );
-                self.video_timestamps.push(VideoTimestamp::new(
-                    id.map(|e| e.to_string()),
-                    filename,
-                    timestamp,
-                ));
+                self.video_timestamps.push(
+                    VideoTimestamp::new(id.map(|e| e.to_string()), filename, timestamp)
+                        .with_duration(duration),
+                );
             }
-            Some(e) => {
+            Some(position) => {
                 trace!(
                     "Updating existing video timestamp for id: {:?}, filename: {}",
                     id,
                     filename
                 );
+                let mut e = self.video_timestamps.remove(position);
                 e.last_known_time = timestamp;
+                e.finished = false;
+                if duration.is_some() {
+                    e.duration = duration;
+                }
+                self.video_timestamps.push(e);
             }
         }
+
+        self.video_timestamps
+            .last_mut()
+            .expect("expected the video timestamp to have been inserted")
+    }
+
+    /// Mark the video of the given `parent_media` as finished.
+    /// A finished video can no longer be resumed, but is used to determine the next video to watch of the parent media.
+    ///
+    /// Only the last finished video is kept for each parent media.
+    pub fn finish<'a>(
+        &mut self,
+        id: Option<&'a str>,
+        filename: &'a str,
+        timestamp: u64,
+        media: Option<StoredMedia>,
+        parent_media: StoredMedia,
+    ) {
+        let parent_id = parent_media.id();
+        self.remove(id, filename);
+        self.video_timestamps.retain(|e| {
+            !e.finished || e.parent_media.as_ref().map(|e| e.id()).as_ref() != Some(&parent_id)
+        });
+
+        trace!(
+            "Adding finished video timestamp for id: {:?}, filename: {}",
+            id,
+            filename
+        );
+        let mut video_timestamp =
+            VideoTimestamp::new(id.map(|e| e.to_string()), filename, timestamp);
+        video_timestamp.update_media(media, Some(parent_media));
+        video_timestamp.finished = true;
+        self.video_timestamps.push(video_timestamp);
     }
 
     /// Remove a possible known timestamp from the resume data.
@@ -98,7 +169,7 @@ impl AutoResume {
     }
 }
 
-#[derive(Debug, Display, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
 #[display(
     fmt = "id: {:?}, filename: {}, last_known_time: {}",
     id,
@@ -109,6 +180,14 @@ pub struct VideoTimestamp {
     id: Option<String>,
     filename: String,
     last_known_time: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    media: Option<StoredMedia>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent_media: Option<StoredMedia>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    finished: bool,
 }
 
 impl VideoTimestamp {
@@ -117,6 +196,26 @@ impl VideoTimestamp {
             id,
             filename: filename.to_string(),
             last_known_time,
+            duration: None,
+            media: None,
+            parent_media: None,
+            finished: false,
+        }
+    }
+
+    /// Set the known total duration of the video.
+    pub fn with_duration(mut self, duration: Option<u64>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Update the media item and parent media item which have been played by the video.
+    pub fn update_media(&mut self, media: Option<StoredMedia>, parent_media: Option<StoredMedia>) {
+        if media.is_some() {
+            self.media = media;
+        }
+        if parent_media.is_some() {
+            self.parent_media = parent_media;
         }
     }
 
@@ -134,10 +233,77 @@ impl VideoTimestamp {
     pub fn last_known_timestamp(&self) -> &u64 {
         &self.last_known_time
     }
+
+    /// The known total duration of the video, if available.
+    pub fn duration(&self) -> Option<&u64> {
+        self.duration.as_ref()
+    }
+
+    /// The playback progress of the video as a value between `0.0` and `1.0`.
+    ///
+    /// It returns [None] when the duration of the video is unknown.
+    pub fn progress(&self) -> Option<f32> {
+        self.duration
+            .filter(|e| *e > 0)
+            .map(|duration| (self.last_known_time as f64 / duration as f64).min(1.0) as f32)
+    }
+
+    /// The media item which has been played by the video, if known.
+    pub fn media(&self) -> Option<&StoredMedia> {
+        self.media.as_ref()
+    }
+
+    /// The parent media item of the played media, such as the show of an episode, if known.
+    pub fn parent_media(&self) -> Option<&StoredMedia> {
+        self.parent_media.as_ref()
+    }
+
+    /// Verify if the video has been watched till the end.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Retrieve the episodes of the parent show which follow the finished episode of this video.
+    ///
+    /// It returns an empty list when the video is not a finished episode of a known show.
+    pub fn next_episodes(&self) -> Vec<Episode> {
+        if let (true, Some(StoredMedia::Episode(episode)), Some(StoredMedia::ShowDetails(show))) =
+            (self.finished, self.media(), self.parent_media())
+        {
+            return show
+                .episodes
+                .iter()
+                .filter(|e| (e.season, e.episode) > (episode.season, episode.episode))
+                .sorted_by_key(|e| (e.season, e.episode))
+                .map(|e| {
+                    let mut episode = e.clone();
+                    // the identifier value is not persisted and needs to be restored from the tvdb id
+                    episode.tvdb_id_value = episode.tvdb_id.to_string();
+                    episode
+                })
+                .collect();
+        }
+
+        vec![]
+    }
+}
+
+/// A media item which can be continued watching.
+#[derive(Debug)]
+pub struct ContinueWatchingItem {
+    /// The media item to continue watching
+    pub media: Box<dyn MediaIdentifier>,
+    /// The parent media item, such as the show of an episode
+    pub parent_media: Option<Box<dyn MediaIdentifier>>,
+    /// The playback progress of the media item as a percentage between `0` and `100`
+    pub progress: f32,
+    /// The timestamp in millis to resume the playback from, if any
+    pub timestamp: Option<u64>,
 }
 
 #[cfg(test)]
 mod test {
+    use crate::core::media::{Images, ShowDetails};
     use crate::testing::init_logger;
 
     use super::*;
@@ -162,6 +328,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_url_id() {
+        let result = AutoResume::url_id("https://localhost/lorem.mp4");
+
+        assert_eq!(64, result.len());
+        assert_eq!(result, AutoResume::url_id("https://localhost/lorem.mp4"));
+        assert_ne!(
+            result,
+            AutoResume::url_id("https://localhost/ipsum/lorem.mp4")
+        );
+    }
+
     #[test]
     fn test_find_id() {
         let id = "tt875554";
@@ -191,7 +369,7 @@ mod test {
             video_timestamps: vec![],
         };
 
-        resume.insert(Some("tt11111"), filename, timestamp.clone());
+        resume.insert(Some("tt11111"), filename, timestamp.clone(), None);
         let result = resume
             .find_filename(filename)
             .expect("expected video timestamp to be found");
@@ -213,12 +391,13 @@ mod test {
             )],
         };
 
-        resume.insert(id, filename, timestamp.clone());
+        resume.insert(id, filename, timestamp.clone(), Some(480000));
         let result = resume
             .find_filename(filename)
             .expect("expected video timestamp to be found");
 
-        assert_eq!(timestamp, result.last_known_time)
+        assert_eq!(timestamp, result.last_known_time);
+        assert_eq!(Some(0.25), result.progress());
     }
 
     #[test]
@@ -257,4 +436,89 @@ mod test {
 
         assert_eq!(vec![remaining_timestamp], result)
     }
+
+    #[test]
+    fn test_insert_existing_should_move_to_last() {
+        let mut resume = AutoResume {
+            video_timestamps: vec![
+                VideoTimestamp::new(None, "lorem.mp4", 60000),
+                VideoTimestamp::new(None, "ipsum.mp4", 60000),
+            ],
+        };
+
+        resume.insert(None, "lorem.mp4", 80000, None);
+        let result = resume.video_timestamps.last().unwrap();
+
+        assert_eq!("lorem.mp4", result.filename());
+        assert_eq!(80000, result.last_known_time);
+    }
+
+    #[test]
+    fn test_finish() {
+        init_logger();
+        let show = StoredMedia::ShowDetails(ShowDetails::new(
+            "tt8000111".to_string(),
+            "8000111".to_string(),
+            "My show".to_string(),
+            "2020".to_string(),
+            1,
+            Images::none(),
+            None,
+        ));
+        let mut resume = AutoResume {
+            video_timestamps: vec![VideoTimestamp::new(
+                Some("1002".to_string()),
+                "my-show-s01e02.mkv",
+                60000,
+            )],
+        };
+
+        resume.finish(
+            Some("1001"),
+            "my-show-s01e01.mkv",
+            590000,
+            None,
+            show.clone(),
+        );
+        resume.finish(Some("1002"), "my-show-s01e02.mkv", 590000, None, show);
+
+        assert_eq!(1, resume.video_timestamps.len());
+        assert_eq!(true, resume.video_timestamps.get(0).unwrap().is_finished());
+        assert_eq!(None, resume.find_filename("my-show-s01e02.mkv"));
+        assert_eq!(None, resume.find_id("1002"));
+    }
+
+    #[test]
+    fn test_next_episodes() {
+        let mut show = ShowDetails::new(
+            "tt8000111".to_string(),
+            "8000111".to_string(),
+            "My show".to_string(),
+            "2020".to_string(),
+            2,
+            Images::none(),
+            None,
+        );
+        show.episodes = vec![
+            Episode::new(2, 1, 0, "Episode 1".to_string(), "".to_string(), 2001),
+            Episode::new(1, 2, 0, "Episode 2".to_string(), "".to_string(), 1002),
+            Episode::new(1, 1, 0, "Episode 1".to_string(), "".to_string(), 1001),
+        ];
+        let episode = show.episodes.get(2).cloned().unwrap();
+        let mut resume = AutoResume::default();
+
+        resume.finish(
+            Some("1001"),
+            "my-show-s01e01.mkv",
+            590000,
+            Some(StoredMedia::Episode(episode)),
+            StoredMedia::ShowDetails(show),
+        );
+        let result = resume.video_timestamps.get(0).unwrap().next_episodes();
+
+        assert_eq!(
+            vec!["1002", "2001"],
+            result.iter().map(|e| e.imdb_id()).collect::<Vec<&str>>()
+        );
+    }
 }
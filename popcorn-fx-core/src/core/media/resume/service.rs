@@ -9,8 +9,10 @@ use tokio::sync::Mutex;
 
 use crate::core::{block_in_place, media};
 use crate::core::events::{Event, EventPublisher, HIGHEST_ORDER, PlayerStoppedEvent};
-use crate::core::media::MediaError;
-use crate::core::media::resume::AutoResume;
+use crate::core::media::{MediaError, MediaIdentifier};
+use crate::core::media::resume::{AutoResume, ContinueWatchingItem, VideoTimestamp};
+use crate::core::media::watched::WatchedService;
+use crate::core::playlists::StoredMedia;
 use crate::core::storage::{Storage, StorageError};
 
 const FILENAME: &str = "auto-resume.json";
@@ -19,6 +21,12 @@ const VIDEO_DURATION_THRESHOLD: u64 = 5 * 60 * 1000;
 /// The percentage of the video that should have been watched
 /// to be assumed as "viewed"
 const RESUME_PERCENTAGE_THRESHOLD: u32 = 85;
+/// The minimum progress of a video for it to be continued watching,
+/// anything below is assumed as not being started
+const CONTINUE_WATCHING_MIN_PROGRESS: f32 = 0.05;
+/// The maximum progress of a video for it to be continued watching,
+/// anything above is assumed as being finished
+const CONTINUE_WATCHING_MAX_PROGRESS: f32 = 0.95;
 
 /// The auto-resume service which handles the resume timestamp for video playbacks.
 /// It stores the last known timestamp when needed based on a player stopped event.
@@ -29,6 +37,17 @@ pub trait AutoResumeService: Debug + Send + Sync {
     /// It retrieves the timestamp when found, else [None].
     fn resume_timestamp<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<u64>;
 
+    /// Retrieve the playback progress of the resume timestamp for the given media id and/or filename.
+    ///
+    /// It retrieves the progress as a value between `0.0` and `1.0` when found and the duration of the video is known, else [None].
+    fn resume_progress<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<f32>;
+
+    /// Retrieve the media items which can be continued watching, ordered by the most recently played first.
+    ///
+    /// Videos which have barely been started or have almost been finished are excluded.
+    /// When an episode has been finished, the next episode of the show is returned instead.
+    fn continue_watching(&self, limit: usize) -> Vec<ContinueWatchingItem>;
+
     /// Handle a player stopped event.
     /// The event should contain the information of the player before it stopped.
     ///
@@ -53,6 +72,14 @@ impl AutoResumeService for DefaultAutoResumeService {
         self.inner.resume_timestamp(id, filename)
     }
 
+    fn resume_progress<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<f32> {
+        self.inner.resume_progress(id, filename)
+    }
+
+    fn continue_watching(&self, limit: usize) -> Vec<ContinueWatchingItem> {
+        self.inner.continue_watching(limit)
+    }
+
     fn player_stopped(&self, event: &PlayerStoppedEvent) {
         self.inner.player_stopped(event)
     }
@@ -76,6 +103,7 @@ impl AutoResumeService for DefaultAutoResumeService {
 pub struct DefaultAutoResumeServiceBuilder {
     storage_directory: Option<String>,
     event_publisher: Option<Arc<EventPublisher>>,
+    watched_service: Option<Arc<Box<dyn WatchedService>>>,
 }
 
 impl DefaultAutoResumeServiceBuilder {
@@ -108,6 +136,14 @@ impl DefaultAutoResumeServiceBuilder {
         self
     }
 
+    /// Sets the `watched_service` field for the `DefaultAutoResumeService`.
+    ///
+    /// The watched service is used to skip already watched episodes when determining the next episode to continue watching.
+    pub fn watched_service(mut self, watched_service: Arc<Box<dyn WatchedService>>) -> Self {
+        self.watched_service = Some(watched_service);
+        self
+    }
+
     /// Builds a new `DefaultAutoResumeService`.
     ///
     /// # Panics
@@ -122,6 +158,7 @@ impl DefaultAutoResumeServiceBuilder {
                         .as_str(),
                 )),
                 cache: Mutex::new(None),
+                watched_service: self.watched_service,
             }),
         };
 
@@ -148,6 +185,7 @@ impl DefaultAutoResumeServiceBuilder {
 struct InnerAutoResumeService {
     storage: Mutex<Storage>,
     cache: Mutex<Option<AutoResume>>,
+    watched_service: Option<Arc<Box<dyn WatchedService>>>,
 }
 
 impl InnerAutoResumeService {
@@ -190,26 +228,11 @@ impl InnerAutoResumeService {
         }
     }
 
-    fn save(&self, resume: &AutoResume) {
-        block_in_place(self.save_async(resume))
-    }
-
-    async fn save_async(&self, resume: &AutoResume) {
-        let mutex = self.storage.lock().await;
-        match mutex
-            .options()
-            .serializer(FILENAME)
-            .write_async(resume)
-            .await
-        {
-            Ok(_) => info!("Auto-resume data has been saved"),
-            Err(e) => error!("Failed to save auto-resume, {}", e),
-        }
-    }
-}
-
-impl AutoResumeService for InnerAutoResumeService {
-    fn resume_timestamp<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<u64> {
+    fn find_video_timestamp<'a>(
+        &self,
+        id: Option<&'a str>,
+        filename: Option<&'a str>,
+    ) -> Option<VideoTimestamp> {
         match futures::executor::block_on(self.load_resume_cache()) {
             Ok(_) => {
                 debug!(
@@ -235,7 +258,7 @@ impl AutoResumeService for InnerAutoResumeService {
                                     e.last_known_timestamp(),
                                     filename
                                 );
-                                return Some(*e.last_known_timestamp());
+                                return Some(e.clone());
                             }
                         }
                     }
@@ -250,7 +273,7 @@ impl AutoResumeService for InnerAutoResumeService {
                                     e.last_known_timestamp(),
                                     id
                                 );
-                                return Some(*e.last_known_timestamp());
+                                return Some(e.clone());
                             }
                         }
                     }
@@ -265,6 +288,125 @@ impl AutoResumeService for InnerAutoResumeService {
         }
     }
 
+    fn in_progress_item(video_timestamp: &VideoTimestamp) -> Option<ContinueWatchingItem> {
+        let media = video_timestamp.media()?;
+        let progress = video_timestamp.progress()?;
+
+        if progress < CONTINUE_WATCHING_MIN_PROGRESS || progress > CONTINUE_WATCHING_MAX_PROGRESS {
+            trace!(
+                "Video timestamp {} progress {} is outside the continue watching range",
+                video_timestamp,
+                progress
+            );
+            return None;
+        }
+
+        Some(ContinueWatchingItem {
+            media: media.clone().into_identifier(),
+            parent_media: video_timestamp
+                .parent_media()
+                .map(|e| e.clone().into_identifier()),
+            progress: progress * 100f32,
+            timestamp: Some(*video_timestamp.last_known_timestamp()),
+        })
+    }
+
+    fn next_episode_item(&self, video_timestamp: &VideoTimestamp) -> Option<ContinueWatchingItem> {
+        let episode = video_timestamp.next_episodes().into_iter().find(|e| {
+            self.watched_service
+                .as_ref()
+                .map(|service| !service.is_watched(e.imdb_id()))
+                .unwrap_or(true)
+        })?;
+
+        debug!(
+            "Continuing with the next episode {} of {}",
+            episode, video_timestamp
+        );
+        Some(ContinueWatchingItem {
+            media: Box::new(episode),
+            parent_media: video_timestamp
+                .parent_media()
+                .map(|e| e.clone().into_identifier()),
+            progress: 0f32,
+            timestamp: None,
+        })
+    }
+
+    fn save(&self, resume: &AutoResume) {
+        block_in_place(self.save_async(resume))
+    }
+
+    async fn save_async(&self, resume: &AutoResume) {
+        let mutex = self.storage.lock().await;
+        match mutex
+            .options()
+            .serializer(FILENAME)
+            .write_async(resume)
+            .await
+        {
+            Ok(_) => info!("Auto-resume data has been saved"),
+            Err(e) => error!("Failed to save auto-resume, {}", e),
+        }
+    }
+}
+
+impl AutoResumeService for InnerAutoResumeService {
+    fn resume_timestamp<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<u64> {
+        self.find_video_timestamp(id, filename)
+            .map(|e| *e.last_known_timestamp())
+    }
+
+    fn resume_progress<'a>(&self, id: Option<&'a str>, filename: Option<&'a str>) -> Option<f32> {
+        self.find_video_timestamp(id, filename)
+            .and_then(|e| e.progress())
+    }
+
+    fn continue_watching(&self, limit: usize) -> Vec<ContinueWatchingItem> {
+        if let Err(e) = futures::executor::block_on(self.load_resume_cache()) {
+            error!("Failed to retrieve the continue watching items, {}", e);
+            return vec![];
+        }
+
+        let mutex = futures::executor::block_on(self.cache.lock());
+        let cache = mutex.as_ref().expect("expected the auto-resume cache");
+        let mut media_ids: Vec<String> = vec![];
+        let mut items = vec![];
+
+        for video_timestamp in cache.video_timestamps.iter().rev() {
+            if items.len() >= limit {
+                break;
+            }
+
+            let media_id = match video_timestamp.parent_media().or(video_timestamp.media()) {
+                Some(e) => e.id(),
+                None => continue,
+            };
+
+            // only the most recent playback of a media item, or show, is taken into account
+            if media_ids.contains(&media_id) {
+                continue;
+            }
+            media_ids.push(media_id);
+
+            let item = if video_timestamp.is_finished() {
+                self.next_episode_item(video_timestamp)
+            } else {
+                Self::in_progress_item(video_timestamp)
+            };
+
+            if let Some(item) = item {
+                items.push(item);
+            }
+        }
+
+        debug!(
+            "Retrieved a total of {} continue watching items",
+            items.len()
+        );
+        items
+    }
+
     fn player_stopped(&self, event: &PlayerStoppedEvent) {
         trace!("Received player stop event {:?}", event);
         if let (Some(time), Some(duration)) = (event.time(), event.duration()) {
@@ -289,21 +431,35 @@ impl AutoResumeService for InnerAutoResumeService {
                         .to_str()
                         .unwrap();
 
+                    // playbacks without a media item, such as direct media urls, are identified by their url
+                    let id = event
+                        .media()
+                        .map(|e| e.imdb_id().to_string())
+                        .unwrap_or_else(|| AutoResume::url_id(event.url()));
+                    let id = Some(id.as_str());
+                    let media = event.media().and_then(StoredMedia::from_identifier);
+                    let parent_media = event.parent_media().and_then(StoredMedia::from_identifier);
+
                     trace!(
                         "Video playback {} has been played for {}%",
                         event.url(),
                         percentage_watched
                     );
                     if percentage_watched < RESUME_PERCENTAGE_THRESHOLD {
-                        let id = event.media().map(|e| e.imdb_id());
                         debug!(
                             "Adding auto resume timestamp {} for id: {:?}, filename: {}",
                             time, id, filename
                         );
-                        cache.insert(id, filename, time.clone());
+                        cache
+                            .insert(id, filename, time.clone(), Some(*duration))
+                            .update_media(media, parent_media);
+                    } else if let Some(show @ StoredMedia::ShowDetails(_)) = parent_media {
+                        debug!(
+                            "Marking auto resume timestamp as finished for id: {:?}, filename: {}",
+                            id, filename
+                        );
+                        cache.finish(id, filename, *time, media, show);
                     } else {
-                        let id = event.media().map(|e| e.imdb_id());
-
                         debug!(
                             "Removing auto resume timestamp for id: {:?}, filename: {}",
                             id, filename
@@ -339,7 +495,8 @@ impl Drop for InnerAutoResumeService {
 mod test {
     use tempfile::tempdir;
 
-    use crate::core::media::{MediaIdentifier, MovieOverview};
+    use crate::core::media::watched::MockWatchedService;
+    use crate::core::media::{Episode, Images, MediaIdentifier, MovieOverview, ShowDetails};
     use crate::testing::{copy_test_file, init_logger, read_temp_dir_file_as_string};
 
     use super::*;
@@ -422,6 +579,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/ipsum.mp4".to_string(),
             media: None,
+            parent_media: None,
             time: Some(30000),
             duration: Some(120000),
         };
@@ -450,6 +608,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/lorem.mp4".to_string(),
             media: Some(movie),
+            parent_media: None,
             time: Some(expected_timestamp.clone()),
             duration: Some(350000),
         };
@@ -462,6 +621,56 @@ mod test {
         assert_eq!(expected_timestamp, result)
     }
 
+    #[test]
+    fn test_player_stopped_add_resume_data_by_url() {
+        init_logger();
+        let url = "https://localhost:8080/media/lorem.mp4?token=123";
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let service = DefaultAutoResumeService::builder()
+            .storage_directory(temp_path)
+            .build();
+        let expected_timestamp = 60000;
+        let event = PlayerStoppedEvent {
+            url: url.to_string(),
+            media: None,
+            parent_media: None,
+            time: Some(expected_timestamp.clone()),
+            duration: Some(350000),
+        };
+
+        service.player_stopped(&event);
+        let result = service
+            .resume_timestamp(Some(AutoResume::url_id(url).as_str()), None)
+            .expect("expected a timestamp to be returned");
+
+        assert_eq!(expected_timestamp, result)
+    }
+
+    #[test]
+    fn test_resume_progress() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let service = DefaultAutoResumeService::builder()
+            .storage_directory(temp_path)
+            .build();
+        let event = PlayerStoppedEvent {
+            url: "http://localhost/ipsum.mp4".to_string(),
+            media: None,
+            parent_media: None,
+            time: Some(150000),
+            duration: Some(600000),
+        };
+
+        service.player_stopped(&event);
+        let result = service
+            .resume_progress(None, Some("ipsum.mp4"))
+            .expect("expected a progress to be returned");
+
+        assert_eq!(0.25, result)
+    }
+
     #[test]
     fn test_player_stopped_remove_resume_data() {
         init_logger();
@@ -480,6 +689,7 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/already-started-watching.mkv".to_string(),
             media: Some(movie),
+            parent_media: None,
             time: Some(550000),
             duration: Some(600000),
         };
@@ -507,14 +717,131 @@ mod test {
         let event = PlayerStoppedEvent {
             url: "http://localhost/already-started-watching.mkv".to_string(),
             media: Some(movie),
+            parent_media: None,
             time: Some(20000),
             duration: Some(600000),
         };
-        let expected_result = "{\"video_timestamps\":[{\"id\":\"tt00001212\",\"filename\":\"already-started-watching.mkv\",\"last_known_time\":20000}]}";
+        let expected_result = "{\"video_timestamps\":[{\"id\":\"tt00001212\",\"filename\":\"already-started-watching.mkv\",\"last_known_time\":20000,\"duration\":600000,\"media\":{\"type\":\"Movie\",\"value\":{\"title\":\"My video\",\"imdb_id\":\"tt00001212\",\"year\":\"2022\",\"rating\":null,\"images\":{\"poster\":\"\",\"fanart\":\"\",\"banner\":\"\"}}}}]}";
 
         service.player_stopped(&event);
         let result = read_temp_dir_file_as_string(&temp_dir, FILENAME).replace("\r\n", "\n");
 
         assert_eq!(expected_result, result.as_str())
     }
+
+    #[test]
+    fn test_continue_watching() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let service = DefaultAutoResumeService::builder()
+            .storage_directory(temp_path)
+            .build();
+
+        service.player_stopped(&create_movie_event("tt0000001", "lorem.mp4", 120000));
+        service.player_stopped(&create_movie_event("tt0000002", "ipsum.mp4", 240000));
+        service.player_stopped(&create_movie_event("tt0000003", "dolor.mp4", 6000));
+        let result = service.continue_watching(10);
+
+        assert_eq!(2, result.len());
+        assert_eq!("tt0000002", result.get(0).unwrap().media.imdb_id());
+        assert_eq!(40f32, result.get(0).unwrap().progress);
+        assert_eq!(Some(240000), result.get(0).unwrap().timestamp);
+        assert_eq!("tt0000001", result.get(1).unwrap().media.imdb_id());
+        assert_eq!(20f32, result.get(1).unwrap().progress);
+    }
+
+    #[test]
+    fn test_continue_watching_limit() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let service = DefaultAutoResumeService::builder()
+            .storage_directory(temp_path)
+            .build();
+
+        service.player_stopped(&create_movie_event("tt0000001", "lorem.mp4", 120000));
+        service.player_stopped(&create_movie_event("tt0000002", "ipsum.mp4", 240000));
+        service.player_stopped(&create_movie_event("tt0000001", "lorem.mp4", 180000));
+        let result = service.continue_watching(1);
+
+        assert_eq!(1, result.len());
+        assert_eq!("tt0000001", result.get(0).unwrap().media.imdb_id());
+        let progress = result.get(0).unwrap().progress;
+        assert!(
+            (progress - 30f32).abs() < 0.001,
+            "expected progress 30, got {} instead",
+            progress
+        );
+    }
+
+    #[test]
+    fn test_continue_watching_next_episode() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut watched_service = MockWatchedService::new();
+        watched_service
+            .expect_is_watched()
+            .returning(|id: &str| id == "1002");
+        let service = DefaultAutoResumeService::builder()
+            .storage_directory(temp_path)
+            .watched_service(Arc::new(Box::new(watched_service)))
+            .build();
+        let mut show = ShowDetails::new(
+            "tt8000111".to_string(),
+            "8000111".to_string(),
+            "My show".to_string(),
+            "2020".to_string(),
+            1,
+            Images::none(),
+            None,
+        );
+        show.episodes = vec![
+            Episode::new(1, 3, 0, "Episode 3".to_string(), "".to_string(), 1003),
+            Episode::new(1, 1, 0, "Episode 1".to_string(), "".to_string(), 1001),
+            Episode::new(1, 2, 0, "Episode 2".to_string(), "".to_string(), 1002),
+        ];
+        let episode = show.episodes.get(1).cloned().unwrap();
+        let event = PlayerStoppedEvent {
+            url: "http://localhost/my-show-s01e01.mkv".to_string(),
+            media: Some(Box::new(episode)),
+            parent_media: Some(Box::new(show)),
+            time: Some(590000),
+            duration: Some(600000),
+        };
+
+        service.player_stopped(&event);
+        let result = service.continue_watching(10);
+
+        assert_eq!(
+            None,
+            service.resume_timestamp(None, Some("my-show-s01e01.mkv"))
+        );
+        assert_eq!(1, result.len());
+        let item = result.get(0).unwrap();
+        assert_eq!("1003", item.media.imdb_id());
+        assert_eq!(
+            "tt8000111",
+            item.parent_media
+                .as_ref()
+                .expect("expected a parent media")
+                .imdb_id()
+        );
+        assert_eq!(None, item.timestamp);
+    }
+
+    fn create_movie_event(id: &str, filename: &str, time: u64) -> PlayerStoppedEvent {
+        PlayerStoppedEvent {
+            url: format!("http://localhost/{}", filename),
+            media: Some(Box::new(MovieOverview::new(
+                "My video".to_string(),
+                id.to_string(),
+                "2022".to_string(),
+            ))),
+            parent_media: None,
+            time: Some(time),
+            duration: Some(600000),
+        }
+    }
 }
@@ -16,10 +16,15 @@ use crate::core::media::{
 )]
 pub struct ShowOverview {
     pub imdb_id: String,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub tvdb_id: String,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub title: String,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub year: String,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub num_seasons: i32,
+    #[serde(default, deserialize_with = "crate::core::media::null_as_default")]
     pub images: Images,
     pub rating: Option<Rating>,
 }
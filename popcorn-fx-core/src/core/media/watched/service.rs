@@ -7,14 +7,13 @@ use mockall::automock;
 use tokio::runtime::Handle;
 use tokio::sync::Mutex;
 
-use crate::core::{block_in_place, Callbacks, CoreCallbacks, events, media};
-use crate::core::events::{Event, EventPublisher, PlayerStoppedEvent};
+use crate::core::{block_in_place, Callbacks, CoreCallbacks, media};
+use crate::core::events::{Event, EventPublisher};
 use crate::core::media::{MediaError, MediaIdentifier, MediaType};
 use crate::core::media::watched::Watched;
 use crate::core::storage::{Storage, StorageError};
 
 const FILENAME: &str = "watched.json";
-const WATCHED_PERCENTAGE_THRESHOLD: f64 = 85 as f64;
 
 /// The callback to listen on events of the watched service.
 pub type WatchedCallback = Box<dyn Fn(WatchedEvent) + Send>;
@@ -92,28 +91,14 @@ pub struct DefaultWatchedService {
 
 impl DefaultWatchedService {
     pub fn new(storage_directory: &str, event_publisher: Arc<EventPublisher>) -> Self {
-        let instance = Self {
+        Self {
             inner: Arc::new(InnerWatchedService {
                 storage: Storage::from(storage_directory),
                 cache: Arc::new(Mutex::new(None)),
                 callbacks: CoreCallbacks::default(),
                 event_publisher,
             }),
-        };
-
-        let cloned_instance = instance.inner.clone();
-        instance.inner.event_publisher.register(
-            Box::new(move |event| {
-                if let Event::PlayerStopped(e) = &event {
-                    cloned_instance.on_player_stopped_event(e.clone())
-                }
-
-                Some(event)
-            }),
-            events::DEFAULT_ORDER,
-        );
-
-        instance
+        }
     }
 }
 
@@ -217,40 +202,6 @@ impl InnerWatchedService {
             Err(e) => error!("Failed to save watched items, {}", e),
         }
     }
-
-    fn on_player_stopped_event(&self, event: PlayerStoppedEvent) {
-        trace!("Received player stopped event for {:?}", event);
-        if let Some(media) = event.media {
-            if let (Some(time), Some(duration)) = (&event.time, &event.duration) {
-                let imdb_id = media.imdb_id().to_string();
-                let title = media.title();
-                let percentage_watched = (*time as f64 / *duration as f64) * 100 as f64;
-
-                trace!(
-                    "Media item {} has been watched for {:.2}%",
-                    media.imdb_id(),
-                    percentage_watched
-                );
-                if percentage_watched >= WATCHED_PERCENTAGE_THRESHOLD {
-                    if let Err(e) = self.add(media) {
-                        error!(
-                            "Failed to add media item {} to the watch list, {}",
-                            imdb_id, e
-                        );
-                    } else {
-                        info!(
-                            "Added media \"{}\" ({}) to the watched list",
-                            title, imdb_id
-                        );
-                    }
-                }
-            } else {
-                debug!("Player stopped event has an unknown time and/or duration, skipping watched check")
-            }
-        } else {
-            debug!("Player stopped event doesn't have contain media information, skipping watched check")
-        }
-    }
 }
 
 impl WatchedService for InnerWatchedService {
@@ -416,7 +367,6 @@ mod test {
 
     use tempfile::tempdir;
 
-    use crate::assert_timeout;
     use crate::core::media::{Images, MovieOverview, ShowOverview};
     use crate::testing::{copy_test_file, init_logger};
 
@@ -587,71 +537,4 @@ mod test {
             }
         }
     }
-
-    #[test]
-    fn test_on_player_stopped_watched() {
-        init_logger();
-        let imdb_id = "tt12455512";
-        let temp_dir = tempdir().unwrap();
-        let temp_path = temp_dir.path().to_str().unwrap();
-        let event_publisher = Arc::new(EventPublisher::default());
-        let service = DefaultWatchedService::new(temp_path, event_publisher.clone());
-
-        event_publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
-            url: "http://localhost:8052/example.mp4".to_string(),
-            media: Some(Box::new(MovieOverview {
-                imdb_id: imdb_id.to_string(),
-                title: "Lorem ipsum dolor".to_string(),
-                year: "2013".to_string(),
-                rating: None,
-                images: Default::default(),
-            })),
-            time: Some(55000),
-            duration: Some(60000),
-        }));
-
-        assert_timeout!(
-            Duration::from_millis(100),
-            service.is_watched(imdb_id),
-            "expected the media item to have been watched"
-        );
-    }
-
-    #[test]
-    fn test_on_player_stopped_not_fully_watched() {
-        init_logger();
-        let imdb_id = "tt0001212";
-        let temp_dir = tempdir().unwrap();
-        let temp_path = temp_dir.path().to_str().unwrap();
-        let event_publisher = Arc::new(EventPublisher::default());
-        let service = DefaultWatchedService::new(temp_path, event_publisher.clone());
-        let (tx, rx) = channel();
-
-        event_publisher.register(
-            Box::new(move |event| {
-                tx.send(true).unwrap();
-                Some(event)
-            }),
-            events::LOWEST_ORDER,
-        );
-        event_publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
-            url: "http://localhost:8052/example.mp4".to_string(),
-            media: Some(Box::new(MovieOverview {
-                imdb_id: imdb_id.to_string(),
-                title: "Lorem dolor esta amit".to_string(),
-                year: "2009".to_string(),
-                rating: None,
-                images: Default::default(),
-            })),
-            time: Some(90000),
-            duration: Some(120000),
-        }));
-
-        rx.recv_timeout(Duration::from_millis(100)).unwrap();
-        assert_eq!(
-            false,
-            service.is_watched(imdb_id),
-            "expected the media item to not have been watched"
-        );
-    }
 }
@@ -5,6 +5,7 @@ pub use runtime::*;
 #[cfg(feature = "cache")]
 pub mod cache;
 pub mod config;
+pub mod crash;
 pub mod events;
 pub mod images;
 #[cfg(feature = "launcher")]
@@ -22,6 +22,26 @@ pub trait Platform: Debug + Send + Sync {
     /// It returns `true` if the screensaver was enabled with success, else `false`.
     fn enable_screensaver(&self) -> bool;
 
+    /// Prevent the system from going to sleep on the current platform.
+    /// This is different from the screensaver, as some systems still go to sleep while the screensaver is inhibited.
+    /// It returns `true` if the system sleep has been prevented with success, else `false`.
+    fn prevent_sleep(&self) -> bool;
+
+    /// Allow the system to go to sleep again on the current platform.
+    /// It returns `true` if the system sleep has been allowed with success, else `false`.
+    fn allow_sleep(&self) -> bool;
+
+    /// Inhibit the system from suspending while a media playback is active.
+    ///
+    /// The inhibition is reference counted, each call needs to be balanced by a call to [Platform::release_suspend].
+    /// It returns `true` if the system suspend has been inhibited with success, else `false`.
+    fn inhibit_suspend(&self) -> bool;
+
+    /// Release an inhibition of the system suspend.
+    /// The system is only allowed to suspend again when all inhibitions have been released.
+    /// It returns `true` if the inhibition has been released with success, else `false`.
+    fn release_suspend(&self) -> bool;
+
     /// Notify the system that a new media playback has been started.
     fn notify_media_event(&self, notification: MediaNotificationEvent);
 
@@ -32,6 +52,7 @@ pub trait Platform: Debug + Send + Sync {
 /// The information data of the current system platform.
 pub trait PlatformData: Platform {
     /// Retrieve the platform info of the current system.
+    /// The resource information, such as the available memory, is gathered at the moment of the call.
     fn info(&self) -> PlatformInfo;
 }
 
@@ -45,16 +66,31 @@ pub enum PlatformEvent {
     ForwardMedia,
     #[display(fmt = "Rewind the current media playback time")]
     RewindMedia,
+    /// Invoked when the current media playback time needs to be moved by the given offset in millis.
+    /// A negative offset rewinds the playback.
+    #[display(fmt = "Seek the current media playback time by {} millis", _0)]
+    SeekMedia(i64),
 }
 
 /// PlatformInfo defines the info of the current platform
 #[derive(Debug, Clone, Display, PartialEq)]
-#[display(fmt = "platform_type: {}, arch: {}", platform_type, arch)]
+#[display(
+    fmt = "platform_type: {}, arch: {}, cpu_cores: {}",
+    platform_type,
+    arch,
+    cpu_cores
+)]
 pub struct PlatformInfo {
     /// The platform type
     pub platform_type: PlatformType,
     /// The cpu architecture of the platform
     pub arch: String,
+    /// The number of logical cpu cores available to the application
+    pub cpu_cores: u32,
+    /// The total memory of the system in bytes
+    pub total_memory: u64,
+    /// The memory of the system in bytes which is available at the moment the info was retrieved
+    pub available_memory: u64,
 }
 
 /// The platform type
@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use log::{debug, trace, warn};
 
@@ -8,6 +9,7 @@ use crate::core::platform::{PlatformData, PlatformEvent};
 use crate::core::playback::{
     MediaInfo, MediaNotificationEvent, PlaybackControlCallback, PlaybackControlEvent, PlaybackState,
 };
+use crate::core::players::{PlayerManager, PlayerManagerEvent};
 
 /// Manages media playback state and communication with the operating system's media control system for
 /// the application.
@@ -25,6 +27,9 @@ use crate::core::playback::{
 /// provided, the `PlaybackControls` struct will still function normally, but the application will not
 /// be able to publish media playback state events to the operating system on it's own.
 ///
+/// A `PlayerManager` can be provided as well, which allows the `PlaybackControls` to expose the duration of the
+/// playback to the operating system and to seek the active player by the exact offset requested by the system.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -85,6 +90,7 @@ impl PlaybackControls {
 pub struct PlaybackControlsBuilder {
     platform: Option<Arc<Box<dyn PlatformData>>>,
     event_publisher: Option<Arc<EventPublisher>>,
+    player_manager: Option<Arc<Box<dyn PlayerManager>>>,
 }
 
 impl PlaybackControlsBuilder {
@@ -105,6 +111,14 @@ impl PlaybackControlsBuilder {
         self
     }
 
+    /// Sets the `player_manager` field for the `PlaybackControls`.
+    /// When not set, the duration of the playback won't be known to the system and seek commands
+    /// are translated into a forward or rewind of the playback.
+    pub fn player_manager(mut self, player_manager: Arc<Box<dyn PlayerManager>>) -> Self {
+        self.player_manager = Some(player_manager);
+        self
+    }
+
     /// Builds a new `PlaybackControls`.
     ///
     /// # Panics
@@ -114,7 +128,10 @@ impl PlaybackControlsBuilder {
         let instance = PlaybackControls {
             inner: Arc::new(InnerPlaybackControls {
                 platform: self.platform.expect("Platform not set"),
+                player_manager: self.player_manager,
                 callbacks: Default::default(),
+                media_info: Default::default(),
+                time: Default::default(),
             }),
         };
 
@@ -146,6 +163,19 @@ impl PlaybackControlsBuilder {
             warn!("Unable to handle control events for PlaybackControls, EventPublisher has not been set");
         }
 
+        if let Some(player_manager) = instance.inner.player_manager.as_ref() {
+            let inner = instance.inner.clone();
+            player_manager.subscribe(Box::new(move |event| match event {
+                PlayerManagerEvent::PlayerDurationChanged(duration) => {
+                    inner.notify_media_duration(duration)
+                }
+                PlayerManagerEvent::PlayerTimeChanged(time) => {
+                    inner.time.store(time, Ordering::Relaxed)
+                }
+                _ => {}
+            }));
+        }
+
         instance
     }
 }
@@ -153,18 +183,45 @@ impl PlaybackControlsBuilder {
 #[derive(Debug)]
 struct InnerPlaybackControls {
     platform: Arc<Box<dyn PlatformData>>,
+    player_manager: Option<Arc<Box<dyn PlayerManager>>>,
     callbacks: CoreCallbacks<PlaybackControlEvent>,
+    /// The media information of the current playback
+    media_info: Mutex<Option<MediaInfo>>,
+    /// The last known time of the active player in millis
+    time: AtomicU64,
 }
 
 impl InnerPlaybackControls {
     fn notify_media_playback(&self, event: PlayerStartedEvent) {
         debug!("Notifying system that a new media playback is being started");
+        let info = MediaInfo {
+            title: event.title,
+            subtitle: event.quality,
+            thumb: event.thumbnail,
+            duration: None,
+        };
+
+        *self.media_info.lock().unwrap() = Some(info.clone());
+        self.time.store(0, Ordering::Relaxed);
         self.platform
-            .notify_media_event(MediaNotificationEvent::StateStarting(MediaInfo {
-                title: event.title,
-                subtitle: event.quality,
-                thumb: event.thumbnail,
-            }))
+            .notify_media_event(MediaNotificationEvent::StateStarting(info))
+    }
+
+    fn notify_media_duration(&self, duration: u64) {
+        let mut mutex = self.media_info.lock().unwrap();
+        if let Some(info) = mutex.as_mut() {
+            if info.duration == Some(duration) {
+                return;
+            }
+
+            debug!(
+                "Notifying system that the media duration has changed to {}",
+                duration
+            );
+            info.duration = Some(duration);
+            self.platform
+                .notify_media_event(MediaNotificationEvent::MediaInfoChanged(info.clone()))
+        }
     }
 
     fn notify_media_state_changed(&self, state: PlaybackState) {
@@ -185,6 +242,7 @@ impl InnerPlaybackControls {
 
     fn notify_media_stopped(&self) {
         debug!("Notifying system that the media playback has stopped");
+        let _ = self.media_info.lock().unwrap().take();
         self.platform
             .notify_media_event(MediaNotificationEvent::StateStopped)
     }
@@ -201,6 +259,34 @@ impl InnerPlaybackControls {
                 .invoke(PlaybackControlEvent::TogglePlaybackState),
             PlatformEvent::ForwardMedia => self.callbacks.invoke(PlaybackControlEvent::Forward),
             PlatformEvent::RewindMedia => self.callbacks.invoke(PlaybackControlEvent::Rewind),
+            PlatformEvent::SeekMedia(offset) => self.seek(offset),
+        }
+    }
+
+    fn seek(&self, offset: i64) {
+        let player = self
+            .player_manager
+            .as_ref()
+            .and_then(|e| e.active_player())
+            .and_then(|e| e.upgrade());
+
+        match player {
+            Some(player) => {
+                let time = self
+                    .time
+                    .load(Ordering::Relaxed)
+                    .saturating_add_signed(offset);
+                debug!("Seeking active player {} to {}", player.id(), time);
+                player.seek(time);
+            }
+            None => {
+                // without an active player, the seek is translated into a forward or rewind of the playback
+                if offset < 0 {
+                    self.callbacks.invoke(PlaybackControlEvent::Rewind)
+                } else {
+                    self.callbacks.invoke(PlaybackControlEvent::Forward)
+                }
+            }
         }
     }
 }
@@ -211,7 +297,9 @@ mod test {
     use std::time::Duration;
 
     use crate::core::events::PlayerStoppedEvent;
-    use crate::testing::{init_logger, MockDummyPlatformData};
+    use crate::core::players::{MockPlayerManager, Player};
+    use crate::core::Handle;
+    use crate::testing::{init_logger, MockDummyPlatformData, MockPlayer};
 
     use super::*;
 
@@ -273,6 +361,112 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_platform_event_seek_media() {
+        init_logger();
+        let (tx, rx) = channel();
+        let (tx_manager, rx_manager) = channel();
+        let (tx_seek, rx_seek) = channel();
+        let mut platform = MockDummyPlatformData::new();
+        platform
+            .expect_register()
+            .returning(move |callback| tx.send(callback).unwrap());
+        let mut player = MockPlayer::default();
+        player.expect_id().return_const("MyPlayer".to_string());
+        player
+            .expect_seek()
+            .returning(move |time| tx_seek.send(time).unwrap());
+        let player = Arc::new(Box::new(player) as Box<dyn Player>);
+        let mut player_manager = MockPlayerManager::new();
+        let active_player = Arc::downgrade(&player);
+        player_manager
+            .expect_active_player()
+            .returning(move || Some(active_player.clone()));
+        player_manager
+            .expect_subscribe()
+            .returning(move |callback| {
+                tx_manager.send(callback).unwrap();
+                Handle::new()
+            });
+        let _controls = PlaybackControls::builder()
+            .platform(Arc::new(Box::new(platform)))
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .build();
+
+        let manager_callback = rx_manager.recv_timeout(Duration::from_millis(100)).unwrap();
+        manager_callback(PlayerManagerEvent::PlayerTimeChanged(25000));
+
+        let callback = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        callback(PlatformEvent::SeekMedia(-10000));
+        let result = rx_seek.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(15000, result);
+
+        callback(PlatformEvent::SeekMedia(-30000));
+        let result = rx_seek.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(
+            0, result,
+            "expected the seek to not go below the start of the media"
+        );
+    }
+
+    #[test]
+    fn test_on_player_duration_changed() {
+        init_logger();
+        let (tx, rx) = channel();
+        let (tx_manager, rx_manager) = channel();
+        let mut platform = MockDummyPlatformData::new();
+        platform.expect_register().returning(|_| {});
+        platform
+            .expect_notify_media_event()
+            .returning(move |notification: MediaNotificationEvent| tx.send(notification).unwrap());
+        let mut player_manager = MockPlayerManager::new();
+        player_manager
+            .expect_subscribe()
+            .returning(move |callback| {
+                tx_manager.send(callback).unwrap();
+                Handle::new()
+            });
+        let event_publisher = Arc::new(EventPublisher::default());
+        let _controls = PlaybackControls::builder()
+            .platform(Arc::new(Box::new(platform)))
+            .event_publisher(event_publisher.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .build();
+
+        event_publisher.publish(Event::PlayerStarted(PlayerStartedEvent {
+            url: "https://my-url".to_string(),
+            title: "Lorem ipsum".to_string(),
+            thumbnail: None,
+            background: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        }));
+        let _ = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+
+        let manager_callback = rx_manager.recv_timeout(Duration::from_millis(100)).unwrap();
+        manager_callback(PlayerManagerEvent::PlayerDurationChanged(120000));
+
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(
+            MediaNotificationEvent::MediaInfoChanged(MediaInfo {
+                title: "Lorem ipsum".to_string(),
+                subtitle: None,
+                thumb: None,
+                duration: Some(120000),
+            }),
+            result
+        );
+
+        // the same duration should not notify the system again
+        manager_callback(PlayerManagerEvent::PlayerDurationChanged(120000));
+        let result = rx.recv_timeout(Duration::from_millis(100));
+        assert!(
+            result.is_err(),
+            "expected the media info to not have been notified again"
+        );
+    }
+
     #[test]
     fn test_on_player_started_event() {
         init_logger();
@@ -306,6 +500,7 @@ mod test {
                     title: "Lorem ipsum".to_string(),
                     subtitle: Some("My showname".to_string()),
                     thumb: Some("MyThumb".to_string()),
+                    duration: None,
                 }
             ),
             _ => panic!("Expected MediaNotificationEvent::PlaybackStarted"),
@@ -330,6 +525,7 @@ mod test {
         event_publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
             url: "http://localhost/my-video.mp4".to_string(),
             media: None,
+            parent_media: None,
             time: Some(10000),
             duration: Some(50000),
         }));
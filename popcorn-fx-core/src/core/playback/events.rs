@@ -30,6 +30,12 @@ pub enum MediaNotificationEvent {
     /// Invoked when the playback state is changed to stopped
     /// This state cannot be resumed anymore and requires a new [MediaNotificationEvent::StateStarting]
     StateStopped,
+    /// Invoked when the information of the current playback has been changed,
+    /// such as the duration becoming known after the playback has started.
+    MediaInfoChanged(MediaInfo),
+    /// Invoked when one or more background downloads have completed.
+    /// Downloads which complete shortly after each other are batched into a single notification.
+    DownloadCompleted(DownloadInfo),
 }
 
 /// Information about the media being played.
@@ -41,4 +47,13 @@ pub struct MediaInfo {
     pub subtitle: Option<String>,
     /// The thumbnail of the currently playing media item
     pub thumb: Option<String>,
+    /// The duration of the media in millis, if known.
+    pub duration: Option<u64>,
+}
+
+/// Information about the background downloads which have completed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadInfo {
+    /// The titles of the completed downloads.
+    pub titles: Vec<String>,
 }
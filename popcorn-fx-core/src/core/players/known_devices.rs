@@ -0,0 +1,301 @@
+use chrono::Utc;
+use derive_more::Display;
+use log::{debug, error, info, trace, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::core::block_in_place;
+use crate::core::storage::{Storage, StorageError};
+
+const FILENAME: &str = "known-devices.json";
+/// The number of failed verifications after which a known device is dropped from the cache.
+pub const MAX_FAILED_VERIFICATIONS: u32 = 2;
+
+/// The type of external player device which has been discovered before.
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
+pub enum KnownDeviceType {
+    #[display(fmt = "Chromecast")]
+    Chromecast,
+    #[display(fmt = "DLNA")]
+    Dlna,
+}
+
+/// A previously discovered external player device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnownDevice {
+    /// The unique identifier of the device, which is the same as the id of its player.
+    pub id: String,
+    /// The display name of the device.
+    pub name: String,
+    /// The type of the device.
+    pub device_type: KnownDeviceType,
+    /// The network address of the device.
+    pub address: String,
+    /// The network port of the device.
+    pub port: u16,
+    /// The model of the device, if known.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// The location of the device description, if applicable.
+    #[serde(default)]
+    pub location: Option<String>,
+    /// The timestamp, in seconds since epoch, when the device was last seen.
+    pub last_seen: i64,
+    /// The number of consecutive failed verifications of the device.
+    #[serde(default)]
+    pub failed_verifications: u32,
+}
+
+/// A persisted cache of external player devices which have been discovered before.
+///
+/// The cache allows the discovery services to register previously known devices immediately on startup,
+/// instead of waiting for the network discovery to complete.
+#[derive(Debug)]
+pub struct KnownDevicesCache {
+    storage: Storage,
+    devices: Mutex<Vec<KnownDevice>>,
+}
+
+impl KnownDevicesCache {
+    /// Create a new known devices cache.
+    ///
+    /// * `storage_path` - The directory to use to read & store the known devices.
+    pub fn new(storage_path: &str) -> Self {
+        let storage = Storage::from(storage_path);
+        let devices = match storage
+            .options()
+            .serializer(FILENAME)
+            .read::<Vec<KnownDevice>>()
+        {
+            Ok(e) => {
+                info!("Loaded {} known player devices", e.len());
+                e
+            }
+            Err(error) => {
+                match error {
+                    StorageError::NotFound(file) => {
+                        debug!("Creating new known devices file {}", file);
+                    }
+                    _ => {
+                        warn!("Failed to load known player devices, {}", error);
+                    }
+                }
+
+                vec![]
+            }
+        };
+
+        Self {
+            storage,
+            devices: Mutex::new(devices),
+        }
+    }
+
+    /// Retrieve the known devices of the given type.
+    pub fn devices(&self, device_type: &KnownDeviceType) -> Vec<KnownDevice> {
+        let devices = block_in_place(self.devices.lock());
+        devices
+            .iter()
+            .filter(|e| &e.device_type == device_type)
+            .cloned()
+            .collect()
+    }
+
+    /// Add or replace the given device within the cache.
+    /// The device is marked as seen now and its failed verifications are reset.
+    pub fn update(&self, mut device: KnownDevice) {
+        trace!("Updating known {} device {}", device.device_type, device.id);
+        device.last_seen = Utc::now().timestamp();
+        device.failed_verifications = 0;
+
+        block_in_place(async {
+            let mut devices = self.devices.lock().await;
+            match devices.iter_mut().find(|e| e.id == device.id) {
+                Some(existing) => *existing = device,
+                None => devices.push(device),
+            }
+
+            self.save(&devices).await;
+        })
+    }
+
+    /// Mark the given device as successfully verified.
+    pub fn verified(&self, id: &str) {
+        block_in_place(async {
+            let mut devices = self.devices.lock().await;
+            if let Some(device) = devices.iter_mut().find(|e| e.id == id) {
+                trace!("Known device {} has been verified", id);
+                device.last_seen = Utc::now().timestamp();
+                device.failed_verifications = 0;
+                self.save(&devices).await;
+            }
+        })
+    }
+
+    /// Register a failed verification of the given device.
+    /// The device is dropped from the cache when it failed [MAX_FAILED_VERIFICATIONS] times.
+    ///
+    /// It returns `true` when the device has been dropped, else `false`.
+    pub fn verification_failed(&self, id: &str) -> bool {
+        block_in_place(async {
+            let mut devices = self.devices.lock().await;
+            let failed_verifications = match devices.iter_mut().find(|e| e.id == id) {
+                Some(device) => {
+                    device.failed_verifications += 1;
+                    device.failed_verifications
+                }
+                None => return false,
+            };
+
+            let dropped = failed_verifications >= MAX_FAILED_VERIFICATIONS;
+            if dropped {
+                info!(
+                    "Dropping known device {} after {} failed verifications",
+                    id, failed_verifications
+                );
+                devices.retain(|e| e.id != id);
+            } else {
+                debug!("Known device {} failed verification", id);
+            }
+
+            self.save(&devices).await;
+            dropped
+        })
+    }
+
+    /// Remove the given device from the cache.
+    ///
+    /// It returns `true` when the device was known, else `false`.
+    pub fn forget(&self, id: &str) -> bool {
+        block_in_place(async {
+            let mut devices = self.devices.lock().await;
+            let len = devices.len();
+            devices.retain(|e| e.id != id);
+
+            if devices.len() != len {
+                info!("Known device {} has been forgotten", id);
+                self.save(&devices).await;
+                true
+            } else {
+                debug!("Known device {} doesn't exist", id);
+                false
+            }
+        })
+    }
+
+    async fn save(&self, devices: &Vec<KnownDevice>) {
+        match self
+            .storage
+            .options()
+            .serializer(FILENAME)
+            .write_async(devices)
+            .await
+        {
+            Ok(_) => trace!("Known devices have been saved"),
+            Err(e) => error!("Failed to save known devices, {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    fn known_device(id: &str) -> KnownDevice {
+        KnownDevice {
+            id: id.to_string(),
+            name: "Living room".to_string(),
+            device_type: KnownDeviceType::Chromecast,
+            address: "192.168.0.10".to_string(),
+            port: 8009,
+            model: Some("Chromecast".to_string()),
+            location: None,
+            last_seen: 0,
+            failed_verifications: 5,
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = KnownDevicesCache::new(temp_path);
+
+        cache.update(known_device("MyDevice"));
+
+        let result = KnownDevicesCache::new(temp_path).devices(&KnownDeviceType::Chromecast);
+        assert_eq!(
+            1,
+            result.len(),
+            "expected the device to have been persisted"
+        );
+        assert_eq!("MyDevice", result[0].id);
+        assert_eq!(0, result[0].failed_verifications);
+        assert_ne!(0, result[0].last_seen);
+        assert_eq!(
+            Vec::<KnownDevice>::new(),
+            cache.devices(&KnownDeviceType::Dlna)
+        );
+    }
+
+    #[test]
+    fn test_verification_failed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = KnownDevicesCache::new(temp_path);
+        cache.update(known_device("MyDevice"));
+
+        assert_eq!(false, cache.verification_failed("MyDevice"));
+        assert_eq!(1, cache.devices(&KnownDeviceType::Chromecast).len());
+
+        assert_eq!(true, cache.verification_failed("MyDevice"));
+        assert_eq!(
+            0,
+            cache.devices(&KnownDeviceType::Chromecast).len(),
+            "expected the device to have been dropped"
+        );
+        assert_eq!(false, cache.verification_failed("MyDevice"));
+    }
+
+    #[test]
+    fn test_verified() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = KnownDevicesCache::new(temp_path);
+        cache.update(known_device("MyDevice"));
+
+        cache.verification_failed("MyDevice");
+        cache.verified("MyDevice");
+        let result = cache.verification_failed("MyDevice");
+
+        assert_eq!(
+            false, result,
+            "expected the failed verifications to have been reset"
+        );
+    }
+
+    #[test]
+    fn test_forget() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let cache = KnownDevicesCache::new(temp_path);
+        cache.update(known_device("MyDevice"));
+
+        assert_eq!(true, cache.forget("MyDevice"));
+        assert_eq!(false, cache.forget("MyDevice"));
+        assert_eq!(
+            0,
+            KnownDevicesCache::new(temp_path)
+                .devices(&KnownDeviceType::Chromecast)
+                .len()
+        );
+    }
+}
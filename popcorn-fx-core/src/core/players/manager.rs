@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 use std::sync::{Arc, RwLock, Weak};
 use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use derive_more::Display;
@@ -9,15 +10,19 @@ use log::{debug, error, info, trace, warn};
 use mockall::automock;
 use tokio::runtime::Runtime;
 use tokio::sync::Mutex;
+use tokio::time;
 
 use crate::core::{block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks};
 use crate::core::config::ApplicationConfig;
 use crate::core::events::{
     Event, EventPublisher, PlayerChangedEvent, PlayerStartedEvent, PlayerStoppedEvent,
 };
+use crate::core::media::watched::WatchedService;
 use crate::core::media::MediaIdentifier;
-use crate::core::players::{Player, PlayerEvent, PlayerState, PlayMediaRequest, PlayRequest};
+use crate::core::platform::PlatformData;
+use crate::core::players::{Player, PlayerEvent, PlayerState, PlayRequest};
 use crate::core::screen::ScreenService;
+use crate::core::subtitles::language::SubtitleLanguage;
 use crate::core::torrents::{TorrentManager, TorrentStreamServer};
 
 /// An event representing changes to the player manager.
@@ -31,7 +36,7 @@ pub enum PlayerManagerEvent {
     PlayersChanged,
     /// Indicates that the active player playback has been changed with a new [PlayRequest].
     #[display(fmt = "Player playback changed to {:?}", _0)]
-    PlayerPlaybackChanged(Weak<Box<dyn PlayRequest>>),
+    PlayerPlaybackChanged(Weak<PlayRequest>),
     /// Indicates that the duration of the active player has changed.
     ///
     /// This event acts as a convenient wrapper around the [Player]'s [PlayerEvent] callbacks,
@@ -50,11 +55,36 @@ pub enum PlayerManagerEvent {
     /// automatically switching to the new active player whenever it changes.
     #[display(fmt = "Active player state changed to {}", _0)]
     PlayerStateChanged(PlayerState),
+    /// Indicates that the active player lost its connection and is trying to reconnect.
+    /// It contains the current reconnection attempt.
+    #[display(fmt = "Active player is reconnecting, attempt {}", _0)]
+    PlayerReconnecting(u32),
+    /// Indicates that the active player has been reconnected.
+    #[display(fmt = "Active player has been reconnected")]
+    PlayerReconnected,
+    /// Indicates that the active player gave up on reconnecting.
+    #[display(fmt = "Active player failed to reconnect")]
+    PlayerReconnectFailed,
+    /// Indicates that the active audio track of the active player has changed.
+    #[display(fmt = "Active player audio track changed to {}", _0)]
+    PlayerAudioTrackChanged(i32),
+    /// Indicates that the active embedded subtitle track of the active player has changed.
+    #[display(fmt = "Active player subtitle track changed to {}", _0)]
+    PlayerSubtitleTrackChanged(i32),
+    /// Indicates that the active subtitle of the active player has changed.
+    #[display(fmt = "Active player subtitle changed to {:?}", _0)]
+    PlayerSubtitleChanged(SubtitleLanguage),
+    /// Indicates that the playback failed to start on the active player and fell back to another player.
+    #[display(fmt = "Playback fell back to player {}", "_0.new_player_id")]
+    PlayerFallback(PlayerChange),
 }
 
 /// A callback type for handling `PlayerManagerEvent` events.
 pub type PlayerManagerCallback = CoreCallback<PlayerManagerEvent>;
 
+/// The maximum time a player is given to start the playback before falling back to the next player.
+const PLAY_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// A struct representing changes in the active player.
 #[derive(Debug, Display, Clone)]
 #[display(fmt = "Active player changed to {}", new_player_id)]
@@ -118,23 +148,26 @@ pub trait PlayerManager: Debug + Send + Sync {
     /// # Arguments
     ///
     /// * `request` - A boxed trait object representing the play request.
-    async fn play(&self, request: Box<dyn PlayRequest>);
+    async fn play(&self, request: PlayRequest);
 }
 
 /// A wrapper for PlayerEvent with an optional event and shutdown flag.
 ///
-/// The `PlayerEventWrapper` is used to wrap a `PlayerEvent` with an optional event payload and a flag to indicate
+/// The `PlayerEventWrapper` is used to wrap a `PlayerEvent` of a player with an optional event payload and a flag to indicate
 /// whether it represents a shutdown signal.
 #[derive(Debug)]
 struct PlayerEventWrapper {
+    /// The id of the player which invoked the event.
+    player_id: String,
     event: Option<PlayerEvent>,
     is_shutdown: bool,
 }
 
-impl From<PlayerEvent> for PlayerEventWrapper {
-    fn from(value: PlayerEvent) -> Self {
+impl PlayerEventWrapper {
+    fn new(player_id: &str, event: PlayerEvent) -> Self {
         Self {
-            event: Some(value),
+            player_id: player_id.to_string(),
+            event: Some(event),
             is_shutdown: false,
         }
     }
@@ -159,6 +192,8 @@ impl DefaultPlayerManager {
     /// * `event_publisher` - An Arc wrapped EventPublisher for publishing player-related events.
     /// * `torrent_stream_server` - An Arc wrapped Box of a trait object implementing TorrentStreamServer.
     /// * `screen_service` - An Arc wrapped Box of a trait object implementing ScreenService.
+    /// * `watched_service` - The watched service which is updated when a media playback has been completed.
+    /// * `platform` - The platform which inhibits the system suspend while a player is playing.
     ///
     /// # Returns
     ///
@@ -169,6 +204,8 @@ impl DefaultPlayerManager {
         torrent_manager: Arc<Box<dyn TorrentManager>>,
         torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>,
         screen_service: Arc<Box<dyn ScreenService>>,
+        watched_service: Arc<Box<dyn WatchedService>>,
+        platform: Arc<Box<dyn PlatformData>>,
     ) -> Self {
         let runtime = Runtime::new().unwrap();
         let (listener_sender, listener_receiver) = channel::<PlayerEventWrapper>();
@@ -179,13 +216,15 @@ impl DefaultPlayerManager {
             torrent_manager,
             torrent_stream_server,
             screen_service,
+            watched_service,
+            platform,
         ));
 
         let receiver_manager = inner.clone();
         runtime.spawn(async move {
             for received in listener_receiver {
                 if let Some(event) = received.event {
-                    receiver_manager.handle_player_event(event);
+                    receiver_manager.handle_player_event(received.player_id.as_str(), event);
                 }
                 if received.is_shutdown {
                     trace!("Received shutdown signal for the player event receiver");
@@ -233,7 +272,7 @@ impl PlayerManager for DefaultPlayerManager {
         self.inner.subscribe(callback)
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, request: PlayRequest) {
         self.inner.play(request).await
     }
 }
@@ -243,6 +282,7 @@ impl Drop for DefaultPlayerManager {
         self.inner
             .listener_sender
             .send(PlayerEventWrapper {
+                player_id: String::new(),
                 event: None,
                 is_shutdown: true,
             })
@@ -257,11 +297,14 @@ struct InnerPlayerManager {
     active_player: Mutex<Option<String>>,
     last_known_player_info: Arc<Mutex<PlayerData>>,
     players: RwLock<Vec<Arc<Box<dyn Player>>>>,
-    listener_id: Mutex<Option<CallbackHandle>>,
     listener_sender: Sender<PlayerEventWrapper>,
     torrent_manager: Arc<Box<dyn TorrentManager>>,
     torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>,
     screen_service: Arc<Box<dyn ScreenService>>,
+    watched_service: Arc<Box<dyn WatchedService>>,
+    platform: Arc<Box<dyn PlatformData>>,
+    /// The ids of the players which currently hold an inhibition of the system suspend
+    suspend_inhibitors: Mutex<Vec<String>>,
     callbacks: CoreCallbacks<PlayerManagerEvent>,
     event_publisher: Arc<EventPublisher>,
 }
@@ -274,17 +317,21 @@ impl InnerPlayerManager {
         torrent_manager: Arc<Box<dyn TorrentManager>>,
         torrent_stream_server: Arc<Box<dyn TorrentStreamServer>>,
         screen_service: Arc<Box<dyn ScreenService>>,
+        watched_service: Arc<Box<dyn WatchedService>>,
+        platform: Arc<Box<dyn PlatformData>>,
     ) -> Self {
         let instance = Self {
             application_config,
             active_player: Mutex::default(),
             last_known_player_info: Arc::new(Default::default()),
             players: RwLock::default(),
-            listener_id: Default::default(),
             listener_sender,
             torrent_manager,
             torrent_stream_server,
             screen_service,
+            watched_service,
+            platform,
+            suspend_inhibitors: Default::default(),
             callbacks: CoreCallbacks::default(),
             event_publisher,
         };
@@ -300,49 +347,66 @@ impl InnerPlayerManager {
             .any(|e| e.id() == player_id)
     }
 
-    fn update_player_listener(&self, old_player_id: Option<&String>) {
-        if let Some(old_player) = old_player_id
-            .and_then(|player_id| self.by_id(player_id.as_str()))
-            .and_then(|player_ref| player_ref.upgrade())
-        {
-            if let Some(callback_handle) = block_in_place(self.listener_id.lock()).as_ref() {
-                trace!(
-                    "Removing internal player callback handle {}",
-                    callback_handle
-                );
-                old_player.remove(callback_handle.clone());
+    fn is_active_player(&self, player_id: &str) -> bool {
+        block_in_place(self.active_player.lock()).as_deref() == Some(player_id)
+    }
+
+    /// Register the internal listener of the manager on the given player.
+    /// The events of all players are received, as each player can inhibit the system suspend.
+    fn register_player_listener(&self, player: &dyn Player) {
+        trace!(
+            "Registering new internal player callback listener to {}",
+            player
+        );
+        let player_id = player.id().to_string();
+        let sender = self.listener_sender.clone();
+        let callback_handle = player.add(Box::new(move |e| {
+            let wrapper = PlayerEventWrapper::new(player_id.as_str(), e);
+            if let Err(e) = sender.send(wrapper) {
+                error!("Failed to send player event, {}", e);
             }
+        }));
+        trace!("Registered internal player callback {}", callback_handle);
+    }
+
+    fn handle_player_event(&self, player_id: &str, event: PlayerEvent) {
+        if let PlayerEvent::StateChanged(state) = &event {
+            self.handle_suspend_inhibition(player_id, state);
         }
 
-        if let Some(new_player) = block_in_place(self.active_player.lock())
-            .as_ref()
-            .and_then(|e| self.by_id(e.as_str()))
-            .and_then(|e| e.upgrade())
-        {
+        // only the events of the active player are propagated
+        if !self.is_active_player(player_id) {
             trace!(
-                "Registering new internal player callback listener to {}",
-                new_player
+                "Ignoring event {:?} of inactive player {}",
+                event,
+                player_id
             );
-            let sender = self.listener_sender.clone();
-            let callback_handle = new_player.add(Box::new(move |e| {
-                let wrapper = PlayerEventWrapper::from(e);
-                if let Err(e) = sender.send(wrapper) {
-                    error!("Failed to send player event, {}", e);
-                }
-            }));
-
-            let mut listener_id = block_in_place(self.listener_id.lock());
-            trace!("Updating listener callback id to {}", callback_handle);
-            *listener_id = Some(callback_handle);
+            return;
         }
-    }
 
-    fn handle_player_event(&self, event: PlayerEvent) {
         match event {
             PlayerEvent::DurationChanged(e) => self.handle_player_duration_event(e),
             PlayerEvent::TimeChanged(e) => self.handle_player_time_event(e),
             PlayerEvent::StateChanged(e) => self.handle_player_state_changed(e),
             PlayerEvent::VolumeChanged(_) => {}
+            PlayerEvent::Reconnecting(attempt) => self
+                .callbacks
+                .invoke(PlayerManagerEvent::PlayerReconnecting(attempt)),
+            PlayerEvent::Reconnected => {
+                self.callbacks.invoke(PlayerManagerEvent::PlayerReconnected)
+            }
+            PlayerEvent::ReconnectFailed => self
+                .callbacks
+                .invoke(PlayerManagerEvent::PlayerReconnectFailed),
+            PlayerEvent::AudioTrackChanged(id) => self
+                .callbacks
+                .invoke(PlayerManagerEvent::PlayerAudioTrackChanged(id)),
+            PlayerEvent::SubtitleTrackChanged(id) => self
+                .callbacks
+                .invoke(PlayerManagerEvent::PlayerSubtitleTrackChanged(id)),
+            PlayerEvent::SubtitleChanged(language) => self
+                .callbacks
+                .invoke(PlayerManagerEvent::PlayerSubtitleChanged(language)),
         }
     }
 
@@ -377,10 +441,12 @@ impl InnerPlayerManager {
             {
                 let mut mutex = block_in_place(self.last_known_player_info.lock());
                 trace!("Last known player info {:?}", mutex);
+                self.handle_playback_completion(&mutex);
                 duration = mutex.duration.take().unwrap_or(0);
                 let event = Event::PlayerStopped(PlayerStoppedEvent {
                     url: mutex.url.take().unwrap_or(String::new()),
                     media: mutex.media.take(),
+                    parent_media: mutex.parent_media.take(),
                     time: mutex.time.take(),
                     duration: Some(duration),
                 });
@@ -396,9 +462,8 @@ impl InnerPlayerManager {
                         trace!("Last known playback request {:?}", e);
                         e
                     }) {
-                        if let Some(stream) = request
-                            .downcast_ref::<PlayMediaRequest>()
-                            .and_then(|e| e.torrent_stream.upgrade())
+                        if let Some(stream) =
+                            request.torrent_stream.as_ref().and_then(|e| e.upgrade())
                         {
                             debug!("Stopping player stream of {}", stream);
                             self.torrent_stream_server
@@ -428,6 +493,177 @@ impl InnerPlayerManager {
             .invoke(PlayerManagerEvent::PlayerStateChanged(new_state))
     }
 
+    /// Inhibit or release the system suspend based on the new state of the given player.
+    fn handle_suspend_inhibition(&self, player_id: &str, state: &PlayerState) {
+        match state {
+            PlayerState::Playing => self.inhibit_suspend(player_id),
+            PlayerState::Paused | PlayerState::Stopped | PlayerState::Error => {
+                self.release_suspend(player_id)
+            }
+            _ => {}
+        }
+    }
+
+    /// Inhibit the system suspend for the given player.
+    /// Each player holds at most one inhibition, so players never release each other's inhibition.
+    fn inhibit_suspend(&self, player_id: &str) {
+        let mut inhibitors = block_in_place(self.suspend_inhibitors.lock());
+        if inhibitors.iter().any(|e| e == player_id) {
+            trace!(
+                "Player {} is already inhibiting the system suspend",
+                player_id
+            );
+            return;
+        }
+
+        if self.platform.inhibit_suspend() {
+            debug!("Player {} is inhibiting the system suspend", player_id);
+            inhibitors.push(player_id.to_string());
+        } else {
+            warn!(
+                "Unable to inhibit the system suspend for player {}",
+                player_id
+            );
+        }
+    }
+
+    /// Release the system suspend inhibition of the given player, if it holds one.
+    fn release_suspend(&self, player_id: &str) {
+        let mut inhibitors = block_in_place(self.suspend_inhibitors.lock());
+        if let Some(index) = inhibitors.iter().position(|e| e == player_id) {
+            if self.platform.release_suspend() {
+                debug!(
+                    "Player {} released the system suspend inhibition",
+                    player_id
+                );
+                inhibitors.remove(index);
+            } else {
+                warn!(
+                    "Unable to release the system suspend inhibition of player {}",
+                    player_id
+                );
+            }
+        }
+    }
+
+    /// Mark the media of the stopped playback as watched when it has been played past the watched threshold.
+    ///
+    /// Playbacks without a media item, such as trailers, are never marked as watched.
+    fn handle_playback_completion(&self, player_info: &PlayerData) {
+        let media = match player_info
+            .media
+            .as_ref()
+            .filter(|e| !e.imdb_id().is_empty())
+        {
+            Some(e) => e,
+            None => {
+                trace!("Stopped playback has no media identifier, skipping watched check");
+                return;
+            }
+        };
+        let (time, duration) = match (player_info.time, player_info.duration) {
+            (Some(time), Some(duration)) if duration > 0 => (time, duration),
+            _ => {
+                debug!(
+                    "Stopped playback has an unknown time and/or duration, skipping watched check"
+                );
+                return;
+            }
+        };
+
+        let threshold = self
+            .application_config
+            .user_settings()
+            .playback_settings
+            .watched_threshold_percent;
+        let percentage_watched = (time as f64 / duration as f64) * 100f64;
+        trace!(
+            "Media item {} has been watched for {:.2}%",
+            media.imdb_id(),
+            percentage_watched
+        );
+        if percentage_watched < threshold as f64 {
+            return;
+        }
+
+        if let Some(watchable) = media.clone_identifier() {
+            let imdb_id = watchable.imdb_id().to_string();
+            match self.watched_service.add(watchable) {
+                Ok(_) => info!(
+                    "Media item {} has been automatically marked as watched",
+                    imdb_id
+                ),
+                Err(e) => error!("Failed to mark media item {} as watched, {}", imdb_id, e),
+            }
+        } else {
+            warn!(
+                "Unable to mark media item {} as watched, media type is not supported",
+                media.imdb_id()
+            );
+        }
+    }
+
+    /// Resolve the next player which should start the playback, skipping the given player ids.
+    ///
+    /// The player is resolved in the following order: the active player, the default player and the embedded player.
+    fn next_player(&self, excluded_ids: &[String]) -> Option<Arc<Box<dyn Player>>> {
+        let is_candidate = |id: &str| !excluded_ids.iter().any(|e| e.as_str() == id);
+        let active_player = block_in_place(self.active_player.lock()).clone();
+        let default_player = self
+            .application_config
+            .user_settings()
+            .playback_settings
+            .default_player;
+
+        active_player
+            .into_iter()
+            .chain(default_player)
+            .filter(|id| is_candidate(id.as_str()))
+            .find_map(|id| self.by_id(id.as_str()).and_then(|e| e.upgrade()))
+            .or_else(|| {
+                self.players
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .find(|e| is_candidate(e.id()) && e.embedded_playback_supported())
+                    .cloned()
+            })
+    }
+
+    /// Try to start the playback of the given request on the given player.
+    ///
+    /// It returns `true` when the playback has been started, else `false` when the player failed to start the playback
+    /// or didn't start it within the [PLAY_TIMEOUT].
+    async fn try_play(&self, player: &Arc<Box<dyn Player>>, request: PlayRequest) -> bool {
+        debug!("Starting playback of {} in {}", request.url(), player);
+        let player_started_event = PlayerStartedEvent::from(&request);
+
+        if time::timeout(PLAY_TIMEOUT, player.play(request))
+            .await
+            .is_err()
+        {
+            warn!(
+                "Player {} failed to start the playback within {:?}",
+                player, PLAY_TIMEOUT
+            );
+            return false;
+        }
+        if player.state() == PlayerState::Error {
+            warn!("Player {} failed to start the playback", player);
+            return false;
+        }
+
+        self.event_publisher
+            .publish(Event::PlayerStarted(player_started_event));
+        if let Some(request) = player.request() {
+            // invoke the playback changed event
+            self.callbacks
+                .invoke(PlayerManagerEvent::PlayerPlaybackChanged(request));
+        }
+
+        true
+    }
+
     fn handle_fullscreen_mode(&self) {
         let is_fullscreen_enabled: bool;
         {
@@ -474,9 +710,6 @@ impl PlayerManager for InnerPlayerManager {
                 *active_player = Some(player_id.to_string());
             }
 
-            debug!("Updating internal player listener");
-            self.update_player_listener(old_player_id.as_ref());
-
             trace!("Publishing player changed event for {}", player_id);
             self.callbacks
                 .invoke(PlayerManagerEvent::ActivePlayerChanged(PlayerChange {
@@ -531,6 +764,7 @@ impl PlayerManager for InnerPlayerManager {
                     "Adding new player {} to player manager",
                     player_info.as_str()
                 );
+                self.register_player_listener(player.as_ref());
                 players.push(Arc::new(player));
                 info!("New player {} has been added", player_info.as_str());
             }
@@ -552,6 +786,7 @@ impl PlayerManager for InnerPlayerManager {
             info!("Removed player {}", player);
 
             drop(players);
+            self.release_suspend(player_id);
             self.callbacks.invoke(PlayerManagerEvent::PlayersChanged);
         } else {
             warn!("Unable to remove player {}, player not found", player_id);
@@ -562,32 +797,54 @@ impl PlayerManager for InnerPlayerManager {
         self.callbacks.add(callback)
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, request: PlayRequest) {
         trace!("Processing play request {:?}", request);
         {
             let mut mutex = self.last_known_player_info.lock().await;
             mutex.url = Some(request.url().to_string());
 
-            if let Some(e) = request.downcast_ref::<PlayMediaRequest>() {
-                mutex.media = e.media.clone_identifier();
-            }
+            // requests without media, e.g. a trailer, reset the media of a previous playback
+            mutex.media = request.media().and_then(|e| e.clone_identifier());
+            mutex.parent_media = request.parent_media().and_then(|e| e.clone_identifier());
         }
 
-        if let Some(player) = self.active_player().and_then(|e| e.upgrade()) {
-            debug!("Starting playback of {} in {}", request.url(), player);
-            let player_started_event = PlayerStartedEvent::from(&request);
-
-            player.play(request).await;
+        let mut request = Some(request);
+        let mut attempted_players: Vec<String> = vec![];
+        while let Some(current_request) = request.take() {
+            let player = match self.next_player(&attempted_players) {
+                Some(e) => e,
+                None if attempted_players.is_empty() => {
+                    error!("Unable to start playback, no active player found");
+                    break;
+                }
+                None => {
+                    error!("Unable to start playback, no fallback player available");
+                    break;
+                }
+            };
 
-            self.event_publisher
-                .publish(Event::PlayerStarted(player_started_event));
-            if let Some(request) = player.request() {
-                // invoke the playback changed event
+            self.set_active_player(player.id());
+            if let Some(previous_player_id) = attempted_players.last() {
+                info!(
+                    "Falling back from player {} to {}",
+                    previous_player_id, player
+                );
                 self.callbacks
-                    .invoke(PlayerManagerEvent::PlayerPlaybackChanged(request));
+                    .invoke(PlayerManagerEvent::PlayerFallback(PlayerChange {
+                        old_player_id: Some(previous_player_id.clone()),
+                        new_player_id: player.id().to_string(),
+                        new_player_name: player.name().to_string(),
+                    }));
             }
-        } else {
-            error!("Unable to start playback, no active player found");
+
+            // keep a copy of the request in case we need to fall back to another player
+            let fallback_request = Some(current_request.clone());
+            attempted_players.push(player.id().to_string());
+            if self.try_play(&player, current_request).await {
+                break;
+            }
+
+            request = fallback_request;
         }
 
         // verify if we need to active the fullscreen mode
@@ -599,6 +856,7 @@ impl PlayerManager for InnerPlayerManager {
 struct PlayerData {
     url: Option<String>,
     media: Option<Box<dyn MediaIdentifier>>,
+    parent_media: Option<Box<dyn MediaIdentifier>>,
     duration: Option<u64>,
     time: Option<u64>,
 }
@@ -614,11 +872,12 @@ mod tests {
     use crate::core::{CallbackHandle, Handle};
     use crate::core::config::{PlaybackSettings, PopcornSettings};
     use crate::core::events::DEFAULT_ORDER;
-    use crate::core::media::MockMediaIdentifier;
-    use crate::core::players::{PlayUrlRequest, PlayUrlRequestBuilder};
+    use crate::core::media::watched::MockWatchedService;
+    use crate::core::media::{MockMediaIdentifier, MovieOverview};
+    use crate::core::players::PlayRequestBuilder;
     use crate::core::screen::MockScreenService;
     use crate::core::torrents::{MockTorrentManager, MockTorrentStreamServer, TorrentStream};
-    use crate::testing::{init_logger, MockPlayer, MockTorrentStream};
+    use crate::testing::{init_logger, MockDummyPlatformData, MockPlayer, MockTorrentStream};
 
     use super::*;
 
@@ -670,11 +929,11 @@ mod tests {
             PlayerState::Unknown
         }
 
-        fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+        fn request(&self) -> Option<Weak<PlayRequest>> {
             todo!()
         }
 
-        async fn play(&self, _: Box<dyn PlayRequest>) {
+        async fn play(&self, _: PlayRequest) {
             todo!()
         }
 
@@ -716,6 +975,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.add_player(player);
@@ -756,6 +1017,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         event_publisher.register(
@@ -808,6 +1071,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         event_publisher.register(
@@ -859,6 +1124,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.subscribe(Box::new(move |e| {
@@ -895,6 +1162,121 @@ mod tests {
         assert!(result.is_err(), "expected the PlayerManagerEvent::PlayerDurationChanged to not have been invoked a 2nd time")
     }
 
+    #[test]
+    fn test_player_reconnect_events() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let player = Box::new(DummyPlayer::new("ReconnectingPlayer"));
+        let (tx, rx) = channel();
+        let torrent_manager = MockTorrentManager::new();
+        let torrent_stream_server = MockTorrentStreamServer::new();
+        let screen_service = Arc::new(Box::new(MockScreenService::new()) as Box<dyn ScreenService>);
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(torrent_manager)),
+            Arc::new(Box::new(torrent_stream_server)),
+            screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
+        );
+
+        manager.subscribe(Box::new(move |e| match &e {
+            PlayerManagerEvent::PlayerReconnecting(_)
+            | PlayerManagerEvent::PlayerReconnected
+            | PlayerManagerEvent::PlayerReconnectFailed => tx.send(e).unwrap(),
+            _ => {}
+        }));
+        manager.add_player(player.clone());
+        manager.set_active_player(player.id());
+
+        player.callbacks.invoke(PlayerEvent::Reconnecting(2));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(result, PlayerManagerEvent::PlayerReconnecting(2)),
+            "expected PlayerManagerEvent::PlayerReconnecting, got {} instead",
+            result
+        );
+
+        player.callbacks.invoke(PlayerEvent::Reconnected);
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(result, PlayerManagerEvent::PlayerReconnected),
+            "expected PlayerManagerEvent::PlayerReconnected, got {} instead",
+            result
+        );
+
+        player.callbacks.invoke(PlayerEvent::ReconnectFailed);
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(result, PlayerManagerEvent::PlayerReconnectFailed),
+            "expected PlayerManagerEvent::PlayerReconnectFailed, got {} instead",
+            result
+        );
+    }
+
+    #[test]
+    fn test_player_track_events() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let player = Box::new(DummyPlayer::new("TrackPlayer"));
+        let (tx, rx) = channel();
+        let torrent_manager = MockTorrentManager::new();
+        let torrent_stream_server = MockTorrentStreamServer::new();
+        let screen_service = Arc::new(Box::new(MockScreenService::new()) as Box<dyn ScreenService>);
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(torrent_manager)),
+            Arc::new(Box::new(torrent_stream_server)),
+            screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
+        );
+
+        manager.subscribe(Box::new(move |e| match &e {
+            PlayerManagerEvent::PlayerAudioTrackChanged(_)
+            | PlayerManagerEvent::PlayerSubtitleTrackChanged(_)
+            | PlayerManagerEvent::PlayerSubtitleChanged(_) => tx.send(e).unwrap(),
+            _ => {}
+        }));
+        manager.add_player(player.clone());
+        manager.set_active_player(player.id());
+
+        player.callbacks.invoke(PlayerEvent::AudioTrackChanged(3));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(result, PlayerManagerEvent::PlayerAudioTrackChanged(3)),
+            "expected PlayerManagerEvent::PlayerAudioTrackChanged, got {} instead",
+            result
+        );
+
+        player.callbacks.invoke(PlayerEvent::SubtitleTrackChanged(5));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(result, PlayerManagerEvent::PlayerSubtitleTrackChanged(5)),
+            "expected PlayerManagerEvent::PlayerSubtitleTrackChanged, got {} instead",
+            result
+        );
+
+        player
+            .callbacks
+            .invoke(PlayerEvent::SubtitleChanged(SubtitleLanguage::None));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            matches!(
+                result,
+                PlayerManagerEvent::PlayerSubtitleChanged(SubtitleLanguage::None)
+            ),
+            "expected PlayerManagerEvent::PlayerSubtitleChanged, got {} instead",
+            result
+        );
+    }
+
     #[test]
     fn test_register_new_player() {
         init_logger();
@@ -903,6 +1285,7 @@ mod tests {
         let player_id = "MyPlayerId";
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
+        player.expect_add().returning(|_| Handle::new());
         let player = Box::new(player) as Box<dyn Player>;
         let torrent_manager = MockTorrentManager::new();
         let torrent_stream_server = MockTorrentStreamServer::new();
@@ -914,6 +1297,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.add_player(player);
@@ -933,6 +1318,7 @@ mod tests {
         let player_id = "SomePlayer123";
         let mut player1 = MockPlayer::default();
         player1.expect_id().return_const(player_id.to_string());
+        player1.expect_add().returning(|_| Handle::new());
         let player = Box::new(player1) as Box<dyn Player>;
         let mut player2 = MockPlayer::default();
         player2.expect_id().return_const(player_id.to_string());
@@ -947,6 +1333,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.add_player(player);
@@ -980,22 +1368,14 @@ mod tests {
             .return_const(torrent_handle.to_string());
         stream.expect_stream_handle().return_const(stream_handle);
         let stream = Arc::new(Box::new(stream) as Box<dyn TorrentStream>);
-        let request: Arc<Box<dyn PlayRequest>> = Arc::new(Box::new(PlayMediaRequest {
-            base: PlayUrlRequest {
-                url: "".to_string(),
-                title: "".to_string(),
-                caption: None,
-                thumb: None,
-                background: None,
-                auto_resume_timestamp: None,
-                subtitles_enabled: false,
-                subtitle: None,
-            },
-            parent_media: None,
-            media: Box::new(MockMediaIdentifier::new()),
-            quality: "".to_string(),
-            torrent_stream: Arc::downgrade(&stream),
-        }));
+        let request = Arc::new(
+            PlayRequestBuilder::builder()
+                .url("")
+                .title("")
+                .media(Box::new(MockMediaIdentifier::new()))
+                .torrent_stream(Arc::downgrade(&stream))
+                .build(),
+        );
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_name().return_const("MyPlayer".to_string());
@@ -1027,6 +1407,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         let result = manager.add_player(Box::new(player));
@@ -1043,12 +1425,12 @@ mod tests {
         let url = "MyUrl";
         let title = "FooBar";
         let player_id = "LoremIpsumPlayer";
-        let request = PlayUrlRequestBuilder::builder()
+        let request = PlayRequestBuilder::builder()
             .url(url)
             .title(title)
             .subtitles_enabled(false)
             .build();
-        let request_ref = Arc::new(Box::new(request.clone()) as Box<dyn PlayRequest>);
+        let request_ref = Arc::new(request.clone());
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
         let (tx, rx) = channel();
@@ -1059,6 +1441,7 @@ mod tests {
         player.expect_play().times(1).returning(move |e| {
             tx.send(e).unwrap();
         });
+        player.expect_state().return_const(PlayerState::Playing);
         player
             .expect_request()
             .return_const(Arc::downgrade(&request_ref));
@@ -1084,8 +1467,11 @@ mod tests {
                         quality: None,
                         fullscreen: true,
                         auto_play_next_episode_enabled: false,
+                        default_player: None,
+                        watched_threshold_percent: 85,
                     },
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         );
@@ -1095,12 +1481,14 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             Arc::new(Box::new(screen_service) as Box<dyn ScreenService>),
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.add_player(Box::new(player));
         manager.set_active_player(player_id);
 
-        block_in_place(manager.play(Box::new(request) as Box<dyn PlayRequest>));
+        block_in_place(manager.play(request));
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
 
         assert_eq!(url, result.url());
@@ -1110,6 +1498,145 @@ mod tests {
         assert_eq!(true, result);
     }
 
+    #[test]
+    fn test_play_default_player() {
+        init_logger();
+        let player_id = "MyDefaultPlayer";
+        let request = PlayRequestBuilder::builder()
+            .url("MyUrl")
+            .title("FooBar")
+            .build();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut player = MockPlayer::default();
+        player.expect_id().return_const(player_id.to_string());
+        player.expect_name().return_const("FooBar".to_string());
+        player.expect_add().returning(|_| Handle::new());
+        player.expect_play().times(1).returning(move |e| {
+            tx.send(e).unwrap();
+        });
+        player.expect_state().return_const(PlayerState::Playing);
+        player.expect_request().returning(|| None);
+        let mut screen_service = MockScreenService::new();
+        screen_service.expect_fullscreen().return_const(());
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        settings.update_playback(PlaybackSettings {
+            default_player: Some(player_id.to_string()),
+            ..Default::default()
+        });
+        let manager = DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(MockTorrentManager::new())),
+            Arc::new(Box::new(MockTorrentStreamServer::new())),
+            Arc::new(Box::new(screen_service) as Box<dyn ScreenService>),
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
+        );
+
+        manager.add_player(Box::new(player));
+        block_in_place(manager.play(request));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("MyUrl", result.url());
+        let result = manager
+            .active_player()
+            .and_then(|e| e.upgrade())
+            .expect("expected an active player");
+        assert_eq!(player_id, result.id());
+    }
+
+    #[test]
+    fn test_play_fallback() {
+        init_logger();
+        let failing_player_id = "FailingPlayer";
+        let embedded_player_id = "EmbeddedPlayer";
+        let request = PlayRequestBuilder::builder()
+            .url("MyUrl")
+            .title("FooBar")
+            .build();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let (tx_event, rx_event) = channel();
+        let mut failing_player = MockPlayer::default();
+        failing_player
+            .expect_id()
+            .return_const(failing_player_id.to_string());
+        failing_player
+            .expect_name()
+            .return_const("Failing".to_string());
+        failing_player.expect_add().returning(|_| Handle::new());
+        failing_player.expect_remove().return_const(());
+        failing_player.expect_play().times(1).return_const(());
+        failing_player
+            .expect_state()
+            .return_const(PlayerState::Error);
+        failing_player
+            .expect_embedded_playback_supported()
+            .return_const(false);
+        let mut embedded_player = MockPlayer::default();
+        embedded_player
+            .expect_id()
+            .return_const(embedded_player_id.to_string());
+        embedded_player
+            .expect_name()
+            .return_const("Embedded".to_string());
+        embedded_player.expect_add().returning(|_| Handle::new());
+        embedded_player.expect_play().times(1).returning(move |e| {
+            tx.send(e).unwrap();
+        });
+        embedded_player
+            .expect_state()
+            .return_const(PlayerState::Playing);
+        embedded_player
+            .expect_embedded_playback_supported()
+            .return_const(true);
+        embedded_player.expect_request().returning(|| None);
+        let mut screen_service = MockScreenService::new();
+        screen_service.expect_fullscreen().return_const(());
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(MockTorrentManager::new())),
+            Arc::new(Box::new(MockTorrentStreamServer::new())),
+            Arc::new(Box::new(screen_service) as Box<dyn ScreenService>),
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
+        );
+        manager.subscribe(Box::new(move |e| {
+            if let PlayerManagerEvent::PlayerFallback(_) = &e {
+                tx_event.send(e).unwrap();
+            }
+        }));
+
+        manager.add_player(Box::new(failing_player));
+        manager.add_player(Box::new(embedded_player));
+        manager.set_active_player(failing_player_id);
+        block_in_place(manager.play(request));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("MyUrl", result.url());
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerManagerEvent::PlayerFallback(change) = result {
+            assert_eq!(Some(failing_player_id.to_string()), change.old_player_id);
+            assert_eq!(embedded_player_id.to_string(), change.new_player_id);
+        } else {
+            assert!(
+                false,
+                "expected PlayerManagerEvent::PlayerFallback, got {} instead",
+                result
+            );
+        }
+        let result = manager
+            .active_player()
+            .and_then(|e| e.upgrade())
+            .expect("expected an active player");
+        assert_eq!(embedded_player_id, result.id());
+    }
+
     #[test]
     fn test_remove() {
         init_logger();
@@ -1118,6 +1645,7 @@ mod tests {
         let player_id = "SomePlayer123";
         let mut player1 = MockPlayer::default();
         player1.expect_id().return_const(player_id.to_string());
+        player1.expect_add().returning(|_| Handle::new());
         let player = Box::new(player1) as Box<dyn Player>;
         let torrent_manager = MockTorrentManager::new();
         let torrent_stream_server = MockTorrentStreamServer::new();
@@ -1129,6 +1657,8 @@ mod tests {
             Arc::new(Box::new(torrent_manager)),
             Arc::new(Box::new(torrent_stream_server)),
             screen_service,
+            Arc::new(Box::new(MockWatchedService::new())),
+            create_platform(),
         );
 
         manager.add_player(player);
@@ -1143,4 +1673,244 @@ mod tests {
             "expected the player to have been removed"
         );
     }
+
+    #[test]
+    fn test_suspend_inhibition_released_out_of_order() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let player1 = Box::new(DummyPlayer::new("Player1"));
+        let player2 = Box::new(DummyPlayer::new("Player2"));
+        let (tx, rx) = channel();
+        let tx_inhibit = tx.clone();
+        let mut platform = MockDummyPlatformData::new();
+        platform
+            .expect_inhibit_suspend()
+            .times(2)
+            .returning(move || {
+                tx_inhibit.send("inhibit").unwrap();
+                true
+            });
+        platform
+            .expect_release_suspend()
+            .times(2)
+            .returning(move || {
+                tx.send("release").unwrap();
+                true
+            });
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(MockTorrentManager::new())),
+            Arc::new(Box::new(MockTorrentStreamServer::new())),
+            Arc::new(Box::new(MockScreenService::new()) as Box<dyn ScreenService>),
+            Arc::new(Box::new(MockWatchedService::new())),
+            Arc::new(Box::new(platform)),
+        );
+
+        manager.add_player(player1.clone());
+        manager.add_player(player2.clone());
+        manager.set_active_player(player2.id());
+
+        player1
+            .callbacks
+            .invoke(PlayerEvent::StateChanged(PlayerState::Playing));
+        assert_eq!(
+            "inhibit",
+            rx.recv_timeout(Duration::from_millis(200)).unwrap()
+        );
+        player2
+            .callbacks
+            .invoke(PlayerEvent::StateChanged(PlayerState::Playing));
+        assert_eq!(
+            "inhibit",
+            rx.recv_timeout(Duration::from_millis(200)).unwrap()
+        );
+
+        // a repeated playing state should not acquire a second inhibition for the same player
+        player1
+            .callbacks
+            .invoke(PlayerEvent::StateChanged(PlayerState::Playing));
+        // the first player releases its inhibition before the second player
+        player1
+            .callbacks
+            .invoke(PlayerEvent::StateChanged(PlayerState::Stopped));
+        assert_eq!(
+            "release",
+            rx.recv_timeout(Duration::from_millis(200)).unwrap()
+        );
+        assert_eq!(
+            Err(RecvTimeoutError::Timeout),
+            rx.recv_timeout(Duration::from_millis(50)),
+            "expected the second player to still inhibit the system suspend"
+        );
+
+        player2
+            .callbacks
+            .invoke(PlayerEvent::StateChanged(PlayerState::Paused));
+        assert_eq!(
+            "release",
+            rx.recv_timeout(Duration::from_millis(200)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_player_stopped_mark_watched() {
+        init_logger();
+        let imdb_id = "tt12455512";
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut watched_service = MockWatchedService::new();
+        watched_service.expect_add().returning(move |e| {
+            tx.send(e.imdb_id().to_string()).unwrap();
+            Ok(())
+        });
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = create_watched_manager(settings, watched_service);
+
+        play_and_stop(&manager, create_media_request(imdb_id), 55000, 60000);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(imdb_id, result.as_str());
+    }
+
+    #[test]
+    fn test_player_stopped_below_watched_threshold() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut watched_service = MockWatchedService::new();
+        watched_service.expect_add().returning(move |e| {
+            tx.send(e.imdb_id().to_string()).unwrap();
+            Ok(())
+        });
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        settings.update_playback(PlaybackSettings {
+            watched_threshold_percent: 95,
+            ..Default::default()
+        });
+        let manager = create_watched_manager(settings, watched_service);
+
+        play_and_stop(&manager, create_media_request("tt0001212"), 55000, 60000);
+
+        let result = rx.recv_timeout(Duration::from_millis(200));
+        assert_eq!(
+            Err(RecvTimeoutError::Timeout),
+            result,
+            "expected the media item to not have been marked as watched"
+        );
+    }
+
+    #[test]
+    fn test_player_stopped_trailer_not_marked_watched() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut watched_service = MockWatchedService::new();
+        watched_service.expect_add().returning(move |e| {
+            tx.send(e.imdb_id().to_string()).unwrap();
+            Ok(())
+        });
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let manager = create_watched_manager(settings, watched_service);
+        let request = PlayRequestBuilder::builder()
+            .url("https://youtube.com/watch?v=lorem")
+            .title("Lorem trailer")
+            .build();
+
+        play_and_stop(&manager, request, 60000, 60000);
+
+        let result = rx.recv_timeout(Duration::from_millis(200));
+        assert_eq!(
+            Err(RecvTimeoutError::Timeout),
+            result,
+            "expected the trailer to not have been marked as watched"
+        );
+    }
+
+    fn create_watched_manager(
+        settings: Arc<ApplicationConfig>,
+        watched_service: MockWatchedService,
+    ) -> DefaultPlayerManager {
+        let mut screen_service = MockScreenService::new();
+        screen_service.expect_fullscreen().return_const(());
+
+        DefaultPlayerManager::new(
+            settings,
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(MockTorrentManager::new())),
+            Arc::new(Box::new(MockTorrentStreamServer::new())),
+            Arc::new(Box::new(screen_service) as Box<dyn ScreenService>),
+            Arc::new(Box::new(watched_service)),
+            create_platform(),
+        )
+    }
+
+    fn create_platform() -> Arc<Box<dyn PlatformData>> {
+        let mut platform = MockDummyPlatformData::new();
+        platform.expect_inhibit_suspend().return_const(true);
+        platform.expect_release_suspend().return_const(true);
+
+        Arc::new(Box::new(platform))
+    }
+
+    fn create_media_request(imdb_id: &str) -> PlayRequest {
+        PlayRequestBuilder::builder()
+            .url("http://localhost:8080/lorem.mp4")
+            .title("Lorem ipsum")
+            .quality("720p")
+            .media(Box::new(MovieOverview {
+                imdb_id: imdb_id.to_string(),
+                title: "Lorem ipsum".to_string(),
+                year: "2013".to_string(),
+                rating: None,
+                images: Default::default(),
+            }))
+            .build()
+    }
+
+    /// Play the given request on a mock player and stop it at the given time.
+    fn play_and_stop(
+        manager: &DefaultPlayerManager,
+        request: PlayRequest,
+        time: u64,
+        duration: u64,
+    ) {
+        let player_id = "MockPlayer";
+        let (tx_callback, rx_callback) = channel();
+        let (tx_state, rx_state) = channel();
+        let mut player = MockPlayer::default();
+        player.expect_id().return_const(player_id.to_string());
+        player.expect_name().return_const("MockPlayer".to_string());
+        player.expect_add().returning(move |e| {
+            tx_callback.send(e).unwrap();
+            Handle::new()
+        });
+        player.expect_play().return_const(());
+        player.expect_state().return_const(PlayerState::Playing);
+        player.expect_request().returning(|| None);
+
+        manager.add_player(Box::new(player));
+        manager.set_active_player(player_id);
+        manager.subscribe(Box::new(move |e| {
+            if let PlayerManagerEvent::PlayerStateChanged(PlayerState::Stopped) = e {
+                tx_state.send(()).unwrap();
+            }
+        }));
+        block_in_place(manager.play(request));
+
+        let callback = rx_callback
+            .recv_timeout(Duration::from_millis(200))
+            .unwrap();
+        callback(PlayerEvent::DurationChanged(duration));
+        callback(PlayerEvent::TimeChanged(time));
+        callback(PlayerEvent::StateChanged(PlayerState::Stopped));
+        rx_state
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the player to have been stopped");
+    }
 }
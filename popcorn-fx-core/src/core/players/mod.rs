@@ -1,7 +1,9 @@
+pub use known_devices::*;
 pub use manager::*;
 pub use play_request::*;
 pub use player::*;
 
+mod known_devices;
 mod manager;
 mod play_request;
 mod player;
@@ -1,77 +1,39 @@
-use std::fmt::{Debug, Display};
-use std::fmt::Formatter;
+use std::fmt::{Debug, Formatter};
 use std::sync::Weak;
 
 use derive_more::Display;
-use downcast_rs::{DowncastSync, impl_downcast};
-#[cfg(any(test, feature = "testing"))]
-use mockall::automock;
 
 use crate::core::loader::LoadingData;
 use crate::core::media::MediaIdentifier;
-use crate::core::subtitles::model::Subtitle;
+use crate::core::players::MediaTrack;
+use crate::core::subtitles::model::{Subtitle, SubtitleInfo};
 use crate::core::torrents::TorrentStream;
 
-/// A trait representing a play request for media playback.
-#[cfg_attr(any(test, feature = "testing"), automock)]
-pub trait PlayRequest: Debug + Display + DowncastSync {
-    /// Get the URL of the media to be played.
-    fn url(&self) -> &str;
-
-    /// Get the title of the media (if available).
-    ///
-    /// Returns a `String` containing the title.
-    fn title(&self) -> &str;
-
-    /// Get the optional caption of this request.
-    ///
-    /// Returns an optional `String` containing the caption of the request, or `None` if not available.
-    fn caption(&self) -> Option<String>;
-
-    /// Get the URL of the thumbnail associated with the media (if available).
-    ///
-    /// Returns an optional `String` containing the thumbnail URL, or `None` if not available.
-    fn thumbnail(&self) -> Option<String>;
-
-    /// Get the URL of the background image associated with the media (if available).
-    /// This image can be shown during the loading of the media stream.
-    ///
-    /// Returns an optional `String` containing the background URL, or `None` if not available.
-    fn background(&self) -> Option<String>;
-
-    /// Get the quality information of the media (if available).
-    ///
-    /// Returns an optional `String` containing quality information, or `None` if not available.
-    fn quality(&self) -> Option<String>;
-
-    /// Get the auto-resume timestamp for the media playback (if available).
-    ///
-    /// Returns an optional `u64` representing the auto-resume timestamp in seconds, or `None` if not available.
-    fn auto_resume_timestamp(&self) -> Option<u64>;
-
-    /// Check if subtitles are enabled for the media playback.
-    ///
-    /// Returns `true` if subtitles are enabled, `false` otherwise.
-    fn subtitles_enabled(&self) -> bool;
-
+/// The subtitle information of a [PlayRequest].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PlaySubtitleRequest {
+    /// Indicates whether subtitles are enabled for the media playback.
+    pub enabled: bool,
     /// The selected subtitle for the media playback (if available).
-    ///
-    /// Returns the selected subtitle for the media playback if set, else `None`.
-    fn subtitle<'a>(&'a self) -> Option<&'a Subtitle>;
+    pub subtitle: Option<Subtitle>,
+    /// The URL on which the selected subtitle is being served (if available).
+    pub url: Option<String>,
 }
-impl_downcast!(sync PlayRequest);
 
-#[cfg(any(test, feature = "testing"))]
-impl Display for MockPlayRequest {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "MockPlayRequest")
+impl PlaySubtitleRequest {
+    /// Get the subtitle info of the selected subtitle (if available).
+    pub fn info(&self) -> Option<&SubtitleInfo> {
+        self.subtitle.as_ref().and_then(|e| e.info())
     }
 }
 
-/// A struct representing a play request for a URL-based media.
-#[derive(Display, Clone, PartialEq)]
+/// A request for the playback of a media stream by a player.
+///
+/// The request carries the context of the loading process to the player,
+/// such as the selected quality, subtitle, auto-resume timestamp and the originating media item.
+#[derive(Display)]
 #[display(fmt = "{}", title)]
-pub struct PlayUrlRequest {
+pub struct PlayRequest {
     /// The URL of the media to be played.
     pub url: String,
     /// The title of the media.
@@ -82,529 +44,163 @@ pub struct PlayUrlRequest {
     pub thumb: Option<String>,
     /// The URL of the background image associated with the media (if available).
     pub background: Option<String>,
+    /// The quality label of the media (if available).
+    pub quality: Option<String>,
     /// The auto-resume timestamp for media playback (if available).
     pub auto_resume_timestamp: Option<u64>,
-    /// Indicates whether subtitles are enabled for the media playback.
-    pub subtitles_enabled: bool,
-    /// The selected subtitle for the media playback (if available).
-    pub subtitle: Option<Subtitle>,
+    /// The subtitle information of the media playback.
+    pub subtitle: PlaySubtitleRequest,
+    /// The audio tracks which are available within the media.
+    pub audio_tracks: Vec<MediaTrack>,
+    /// The media identifier from which the playback originates (if available).
+    pub media: Option<Box<dyn MediaIdentifier>>,
+    /// The parent media identifier of the media (if applicable).
+    pub parent_media: Option<Box<dyn MediaIdentifier>>,
+    /// The torrent stream that is being used to stream the media (if applicable).
+    pub torrent_stream: Option<Weak<Box<dyn TorrentStream>>>,
 }
 
-impl PlayUrlRequest {
-    pub fn builder() -> PlayUrlRequestBuilder {
-        PlayUrlRequestBuilder::builder()
+impl PlayRequest {
+    /// Creates a new builder for `PlayRequest`.
+    pub fn builder() -> PlayRequestBuilder {
+        PlayRequestBuilder::builder()
     }
-}
 
-/// Implementing the `PlayRequest` trait for `PlayUrlRequest`.
-impl PlayRequest for PlayUrlRequest {
-    fn url(&self) -> &str {
+    /// Get the URL of the media to be played.
+    pub fn url(&self) -> &str {
         self.url.as_str()
     }
 
-    fn title(&self) -> &str {
+    /// Get the title of the media.
+    pub fn title(&self) -> &str {
         self.title.as_str()
     }
 
-    fn caption(&self) -> Option<String> {
+    /// Get the optional caption of this request.
+    pub fn caption(&self) -> Option<String> {
         self.caption.clone()
     }
 
-    fn thumbnail(&self) -> Option<String> {
+    /// Get the URL of the thumbnail associated with the media (if available).
+    pub fn thumbnail(&self) -> Option<String> {
         self.thumb.clone()
     }
 
-    fn background(&self) -> Option<String> {
+    /// Get the URL of the background image associated with the media (if available).
+    /// This image can be shown during the loading of the media stream.
+    pub fn background(&self) -> Option<String> {
         self.background.clone()
     }
 
-    fn quality(&self) -> Option<String> {
-        None
-    }
-
-    fn auto_resume_timestamp(&self) -> Option<u64> {
-        self.auto_resume_timestamp.clone()
-    }
-
-    fn subtitles_enabled(&self) -> bool {
-        self.subtitles_enabled
-    }
-
-    fn subtitle(&self) -> Option<&Subtitle> {
-        self.subtitle.as_ref()
-    }
-}
-
-impl Debug for PlayUrlRequest {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("PlayUrlRequest")
-            .field("url", &self.url)
-            .field("title", &self.title)
-            .field("caption", &self.caption)
-            .field("thumb", &self.thumb)
-            .field("background", &self.background)
-            .field("auto_resume_timestamp", &self.auto_resume_timestamp)
-            .field("subtitles_enabled", &self.subtitles_enabled)
-            .field("subtitle", &self.subtitle.is_some())
-            .finish()
-    }
-}
-
-impl<S> From<S> for PlayUrlRequest
-    where
-        S: Into<String>,
-{
-    fn from(value: S) -> Self {
-        PlayUrlRequestBuilder::builder()
-            .url(value.into().as_str())
-            .title("")
-            .build()
-    }
-}
-
-impl From<LoadingData> for PlayUrlRequest {
-    fn from(value: LoadingData) -> Self {
-        let subtitles_enabled = value.subtitles_enabled.unwrap_or(false);
-        let mut builder = PlayUrlRequestBuilder::builder()
-            .url(
-                value
-                    .url
-                    .expect("expected an url to have been present")
-                    .as_str(),
-            )
-            .title(value.title.unwrap_or(String::new()).as_str())
-            .subtitles_enabled(subtitles_enabled);
-
-        if let Some(e) = value.thumb {
-            builder = builder.thumb(e.as_str());
-        }
-        if let Some(e) = value.auto_resume_timestamp {
-            builder = builder.auto_resume_timestamp(e);
-        }
-        if subtitles_enabled {
-            if let Some(e) = value.subtitle {
-                builder = builder.subtitle(e);
-            }
-        }
-
-        builder.build()
-    }
-}
-
-/// A builder for constructing a `PlayUrlRequest` with optional parameters.
-#[derive(Debug, Default, Clone)]
-pub struct PlayUrlRequestBuilder {
-    url: Option<String>,
-    title: Option<String>,
-    caption: Option<String>,
-    thumb: Option<String>,
-    background: Option<String>,
-    auto_resume_timestamp: Option<u64>,
-    subtitles_enabled: bool,
-    subtitle: Option<Subtitle>,
-}
-
-impl PlayUrlRequestBuilder {
-    /// Creates a new instance of the builder with default values.
-    pub fn builder() -> Self {
-        Default::default()
-    }
-
-    /// Sets the URL for the media to be played.
-    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
-        self.url = Some(url.into());
-        self
-    }
-
-    /// Sets the title of the media.
-    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
-        self.title = Some(title.into());
-        self
-    }
-
-    /// Sets the caption of the associated media.
-    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.caption = Some(caption.into());
-        self
-    }
-
-    /// Sets the URL of the thumbnail associated with the media.
-    pub fn thumb<S: Into<String>>(mut self, thumb: S) -> Self {
-        self.thumb = Some(thumb.into());
-        self
-    }
-
-    /// Sets the URL of the background associated with the media.
-    pub fn background<S: Into<String>>(mut self, background: S) -> Self {
-        self.background = Some(background.into());
-        self
-    }
-
-    /// Sets the auto-resume timestamp for media playback.
-    pub fn auto_resume_timestamp(mut self, auto_resume_timestamp: u64) -> Self {
-        self.auto_resume_timestamp = Some(auto_resume_timestamp);
-        self
-    }
-
-    /// Sets whether subtitles are enabled for the media playback.
-    pub fn subtitles_enabled(mut self, subtitles_enabled: bool) -> Self {
-        self.subtitles_enabled = subtitles_enabled;
-        self
-    }
-
-    /// Sets the selected subtitle for the media playback.
-    pub fn subtitle(mut self, subtitle: Subtitle) -> Self {
-        self.subtitle = Some(subtitle);
-        self
-    }
-
-    /// Builds and returns a `PlayUrlRequest` based on the provided parameters.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the required fields (`url`, `title`) is not provided.
-    pub fn build(self) -> PlayUrlRequest {
-        PlayUrlRequest {
-            url: self.url.expect("url has not been set"),
-            title: self.title.expect("title has not been set"),
-            caption: self.caption,
-            thumb: self.thumb,
-            background: self.background,
-            auto_resume_timestamp: self.auto_resume_timestamp,
-            subtitles_enabled: self.subtitles_enabled,
-            subtitle: self.subtitle,
-        }
-    }
-}
-
-/// Represents a request for streaming media.
-#[derive(Debug, Display, Clone)]
-#[display(fmt = "{}", base)]
-pub struct PlayStreamRequest {
-    /// The base play request for URL-based media.
-    pub base: PlayUrlRequest,
-    /// The quality of the media.
-    pub quality: Option<String>,
-    /// The torrent stream being used to stream the media item.
-    pub torrent_stream: Weak<Box<dyn TorrentStream>>,
-}
-
-impl PlayStreamRequest {
-    /// Creates a new builder for `PlayStreamRequest`.
-    pub fn builder() -> PlayStreamRequestBuilder {
-        PlayStreamRequestBuilder::builder()
-    }
-}
-
-impl PlayRequest for PlayStreamRequest {
-    fn url(&self) -> &str {
-        self.base.url()
-    }
-
-    fn title(&self) -> &str {
-        self.base.title()
-    }
-
-    fn caption(&self) -> Option<String> {
-        self.base.caption()
-    }
-
-    fn thumbnail(&self) -> Option<String> {
-        self.base.thumbnail()
-    }
-
-    fn background(&self) -> Option<String> {
-        self.base.background()
-    }
-
-    fn quality(&self) -> Option<String> {
+    /// Get the quality label of the media (if available).
+    pub fn quality(&self) -> Option<String> {
         self.quality.clone()
     }
 
-    fn auto_resume_timestamp(&self) -> Option<u64> {
-        self.base.auto_resume_timestamp()
-    }
-
-    fn subtitles_enabled(&self) -> bool {
-        self.base.subtitles_enabled()
-    }
-
-    fn subtitle(&self) -> Option<&Subtitle> {
-        self.base.subtitle()
-    }
-}
-
-impl PartialEq for PlayStreamRequest {
-    fn eq(&self, other: &Self) -> bool {
-        self.base.eq(&other.base) && self.quality == other.quality
-    }
-}
-
-impl From<LoadingData> for PlayStreamRequest {
-    fn from(value: LoadingData) -> Self {
-        let subtitles_enabled = value.subtitles_enabled.unwrap_or(false);
-        let mut builder = Self::builder()
-            .url(
-                value
-                    .url
-                    .expect("expected a url to have been present")
-                    .as_str(),
-            )
-            .title(
-                value
-                    .title
-                    .expect("expected a title to have been present")
-                    .as_str(),
-            )
-            .subtitles_enabled(subtitles_enabled);
-
-        if let Some(e) = value.caption {
-            builder = builder.caption(e);
-        }
-        if let Some(e) = value.thumb {
-            builder = builder.thumb(e);
-        }
-        if let Some(e) = value.auto_resume_timestamp {
-            builder = builder.auto_resume_timestamp(e);
-        }
-        if let Some(e) = value.quality {
-            builder = builder.quality(e.as_str());
-        }
-        if let Some(e) = value.torrent_stream {
-            builder = builder.torrent_stream(e);
-        }
-        if subtitles_enabled {
-            if let Some(e) = value.subtitle {
-                builder = builder.subtitle(e);
-            }
-        }
-
-        builder.build()
-    }
-}
-
-/// A builder for `PlayStreamRequest`.
-#[derive(Debug, Default)]
-pub struct PlayStreamRequestBuilder {
-    url: Option<String>,
-    title: Option<String>,
-    caption: Option<String>,
-    thumb: Option<String>,
-    background: Option<String>,
-    auto_resume_timestamp: Option<u64>,
-    subtitles_enabled: bool,
-    subtitle: Option<Subtitle>,
-    quality: Option<String>,
-    torrent_stream: Option<Weak<Box<dyn TorrentStream>>>,
-}
-
-impl PlayStreamRequestBuilder {
-    /// Creates a new instance of the builder with default values.
-    pub fn builder() -> Self {
-        Self::default()
-    }
-
-    /// Sets the URL for the media to be played.
-    pub fn url<S>(mut self, url: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.url = Some(url.into());
-        self
-    }
-
-    /// Sets the title of the media.
-    pub fn title<S>(mut self, title: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.title = Some(title.into());
-        self
-    }
-
-    /// Sets the caption of the media.
-    pub fn caption<S>(mut self, caption: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.caption = Some(caption.into());
-        self
-    }
-
-    /// Sets the URL of the thumbnail associated with the media.
-    pub fn thumb<S>(mut self, thumb: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.thumb = Some(thumb.into());
-        self
-    }
-
-    /// Sets the URL of the background associated with the media.
-    pub fn background<S>(mut self, background: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.background = Some(background.into());
-        self
-    }
-
-    /// Sets the auto-resume timestamp for media playback.
-    pub fn auto_resume_timestamp(mut self, auto_resume_timestamp: u64) -> Self {
-        self.auto_resume_timestamp = Some(auto_resume_timestamp);
-        self
-    }
-
-    /// Sets whether subtitles are enabled for the media playback.
-    pub fn subtitles_enabled(mut self, subtitles_enabled: bool) -> Self {
-        self.subtitles_enabled = subtitles_enabled;
-        self
-    }
-
-    /// Sets the selected subtitle for the media.
-    pub fn subtitle(mut self, subtitle: Subtitle) -> Self {
-        self.subtitle = Some(subtitle);
-        self
-    }
-
-    /// Sets the quality information for the media.
-    pub fn quality<S>(mut self, quality: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.quality = Some(quality.into());
-        self
-    }
-
-    /// Sets the torrent stream of the media.
-    pub fn torrent_stream(mut self, torrent_stream: Weak<Box<dyn TorrentStream>>) -> Self {
-        self.torrent_stream = Some(torrent_stream);
-        self
-    }
-
-    /// Builds the `PlayStreamRequest`.
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the `url` or `title` fields are not provided.
-    pub fn build(self) -> PlayStreamRequest {
-        if self.url.is_none() || self.title.is_none() {
-            panic!("url and title fields must be provided to build PlayMediaRequest");
-        }
-
-        let base = PlayUrlRequest {
-            url: self.url.unwrap(),
-            title: self.title.unwrap(),
-            caption: self.caption,
-            thumb: self.thumb,
-            background: self.background,
-            auto_resume_timestamp: self.auto_resume_timestamp,
-            subtitles_enabled: self.subtitles_enabled,
-            subtitle: self.subtitle,
-        };
-
-        PlayStreamRequest {
-            base,
-            quality: self.quality,
-            torrent_stream: self
-                .torrent_stream
-                .expect("torrent_stream has not been set"),
-        }
-    }
-}
-
-/// A struct representing a play request for media with additional metadata.
-#[derive(Debug, Display)]
-#[display(fmt = "{}", base)]
-pub struct PlayMediaRequest {
-    /// The base play request for URL-based media.
-    pub base: PlayUrlRequest,
-    /// The parent media identifier (if applicable).
-    pub parent_media: Option<Box<dyn MediaIdentifier>>,
-    /// The media identifier for the requested media.
-    pub media: Box<dyn MediaIdentifier>,
-    /// The quality information for the media.
-    pub quality: String,
-    /// The torrent stream that is being used to stream the media item
-    pub torrent_stream: Weak<Box<dyn TorrentStream>>,
-}
-
-impl PlayMediaRequest {
-    pub fn builder() -> PlayMediaRequestBuilder {
-        PlayMediaRequestBuilder::default()
-    }
-}
-
-impl PartialEq for PlayMediaRequest {
-    fn eq(&self, other: &Self) -> bool {
-        self.base.eq(&other.base)
-            && self.parent_media.is_some() == other.parent_media.is_some()
-            && self.media.imdb_id() == other.media.imdb_id()
-            && self.quality == other.quality
-    }
-}
-
-/// Implementing the `PlayRequest` trait for `PlayMediaRequest`.
-impl PlayRequest for PlayMediaRequest {
-    fn url(&self) -> &str {
-        self.base.url()
-    }
-
-    fn title(&self) -> &str {
-        self.base.title()
-    }
-
-    fn caption(&self) -> Option<String> {
-        self.base.caption()
-    }
-
-    fn thumbnail(&self) -> Option<String> {
-        self.base.thumbnail()
+    /// Get the auto-resume timestamp for the media playback (if available).
+    pub fn auto_resume_timestamp(&self) -> Option<u64> {
+        self.auto_resume_timestamp.clone()
     }
 
-    fn background(&self) -> Option<String> {
-        self.base.background()
+    /// Check if subtitles are enabled for the media playback.
+    pub fn subtitles_enabled(&self) -> bool {
+        self.subtitle.enabled
     }
 
-    fn quality(&self) -> Option<String> {
-        Some(self.quality.clone())
+    /// The selected subtitle for the media playback (if available).
+    pub fn subtitle(&self) -> Option<&Subtitle> {
+        self.subtitle.subtitle.as_ref()
     }
 
-    fn auto_resume_timestamp(&self) -> Option<u64> {
-        self.base.auto_resume_timestamp()
+    /// Get the audio tracks which are available within the media.
+    pub fn audio_tracks(&self) -> Vec<MediaTrack> {
+        self.audio_tracks.clone()
     }
 
-    fn subtitles_enabled(&self) -> bool {
-        self.base.subtitles_enabled()
+    /// Get the media item from which the playback originates (if available).
+    pub fn media(&self) -> Option<&Box<dyn MediaIdentifier>> {
+        self.media.as_ref()
     }
 
-    fn subtitle(&self) -> Option<&Subtitle> {
-        self.base.subtitle()
+    /// Get the parent media item of the playback (if available).
+    pub fn parent_media(&self) -> Option<&Box<dyn MediaIdentifier>> {
+        self.parent_media.as_ref()
     }
 }
 
-impl Clone for PlayMediaRequest {
+impl Clone for PlayRequest {
     fn clone(&self) -> Self {
         Self {
-            base: self.base.clone(),
+            url: self.url.clone(),
+            title: self.title.clone(),
+            caption: self.caption.clone(),
+            thumb: self.thumb.clone(),
+            background: self.background.clone(),
+            quality: self.quality.clone(),
+            auto_resume_timestamp: self.auto_resume_timestamp.clone(),
+            subtitle: self.subtitle.clone(),
+            audio_tracks: self.audio_tracks.clone(),
+            media: self.media.as_ref().and_then(|e| e.clone_identifier()),
             parent_media: self
                 .parent_media
                 .as_ref()
                 .and_then(|e| e.clone_identifier()),
-            media: self
-                .media
-                .clone_identifier()
-                .expect("expected the media identifier to have been cloned"),
-            quality: self.quality.clone(),
             torrent_stream: self.torrent_stream.clone(),
         }
     }
 }
 
-impl From<LoadingData> for PlayMediaRequest {
+impl PartialEq for PlayRequest {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.title == other.title
+            && self.caption == other.caption
+            && self.thumb == other.thumb
+            && self.background == other.background
+            && self.quality == other.quality
+            && self.auto_resume_timestamp == other.auto_resume_timestamp
+            && self.subtitle == other.subtitle
+            && self.audio_tracks == other.audio_tracks
+            && self.media.as_ref().map(|e| e.imdb_id().to_string())
+                == other.media.as_ref().map(|e| e.imdb_id().to_string())
+            && self.parent_media.is_some() == other.parent_media.is_some()
+    }
+}
+
+impl Debug for PlayRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlayRequest")
+            .field("url", &self.url)
+            .field("title", &self.title)
+            .field("caption", &self.caption)
+            .field("thumb", &self.thumb)
+            .field("background", &self.background)
+            .field("quality", &self.quality)
+            .field("auto_resume_timestamp", &self.auto_resume_timestamp)
+            .field("subtitles_enabled", &self.subtitle.enabled)
+            .field("subtitle", &self.subtitle.subtitle.is_some())
+            .field("subtitle_url", &self.subtitle.url)
+            .field("audio_tracks", &self.audio_tracks)
+            .field("media", &self.media)
+            .field("parent_media", &self.parent_media)
+            .field("torrent_stream", &self.torrent_stream.is_some())
+            .finish()
+    }
+}
+
+impl<S> From<S> for PlayRequest
+where
+    S: Into<String>,
+{
+    fn from(value: S) -> Self {
+        PlayRequestBuilder::builder()
+            .url(value.into().as_str())
+            .title("")
+            .build()
+    }
+}
+
+impl From<LoadingData> for PlayRequest {
     fn from(value: LoadingData) -> Self {
         let subtitles_enabled = value.subtitles_enabled.unwrap_or(false);
         let mut builder = Self::builder()
@@ -614,12 +210,7 @@ impl From<LoadingData> for PlayMediaRequest {
                     .expect("expected a url to have been present")
                     .as_str(),
             )
-            .title(
-                value
-                    .title
-                    .expect("expected a title to have been present")
-                    .as_str(),
-            )
+            .title(value.title.unwrap_or(String::new()).as_str())
             .subtitles_enabled(subtitles_enabled);
 
         if let Some(e) = value.caption {
@@ -628,111 +219,103 @@ impl From<LoadingData> for PlayMediaRequest {
         if let Some(e) = value.thumb {
             builder = builder.thumb(e);
         }
-        if let Some(media_identifier) = value.media.as_ref() {
-            if let Some(media) = media_identifier.into_overview() {
-                builder = builder.background(media.images().fanart());
-            }
-        }
         if let Some(e) = value.auto_resume_timestamp {
             builder = builder.auto_resume_timestamp(e);
         }
-        if let Some(media_identifier) = value.parent_media {
-            if let Some(media) = media_identifier.into_overview() {
-                builder = builder.background(media.images().fanart());
-            }
-
-            builder = builder.parent_media(media_identifier);
-        }
         if let Some(e) = value.quality {
-            builder = builder.quality(e.as_str());
+            builder = builder.quality(e);
         }
         if let Some(e) = value.torrent_stream {
             builder = builder.torrent_stream(e);
         }
+        if let Some(e) = value.audio_tracks {
+            builder = builder.audio_tracks(e);
+        }
         if subtitles_enabled {
             if let Some(e) = value.subtitle {
                 builder = builder.subtitle(e);
             }
         }
+        if let Some(media_identifier) = value.media {
+            if let Some(media) = media_identifier.into_overview() {
+                builder = builder.background(media.images().fanart());
+            }
+
+            builder = builder.media(media_identifier);
+        }
+        if let Some(media_identifier) = value.parent_media {
+            if let Some(media) = media_identifier.into_overview() {
+                builder = builder.background(media.images().fanart());
+            }
 
-        builder
-            .media(
-                value
-                    .media
-                    .expect("expected a media item to have been present"),
-            )
-            .build()
+            builder = builder.parent_media(media_identifier);
+        }
+
+        builder.build()
     }
 }
 
-/// A builder for constructing a `PlayMediaRequest` with optional parameters.
+/// A builder for constructing a `PlayRequest` with optional parameters.
 #[derive(Debug, Default)]
-pub struct PlayMediaRequestBuilder {
+pub struct PlayRequestBuilder {
     url: Option<String>,
     title: Option<String>,
     caption: Option<String>,
     thumb: Option<String>,
     background: Option<String>,
+    quality: Option<String>,
     auto_resume_timestamp: Option<u64>,
     subtitles_enabled: bool,
     subtitle: Option<Subtitle>,
+    subtitle_url: Option<String>,
+    audio_tracks: Vec<MediaTrack>,
     media: Option<Box<dyn MediaIdentifier>>,
     parent_media: Option<Box<dyn MediaIdentifier>>,
-    quality: Option<String>,
     torrent_stream: Option<Weak<Box<dyn TorrentStream>>>,
 }
 
-impl PlayMediaRequestBuilder {
+impl PlayRequestBuilder {
     /// Creates a new instance of the builder with default values.
     pub fn builder() -> Self {
         Self::default()
     }
 
     /// Sets the URL for the media to be played.
-    pub fn url<S>(mut self, url: S) -> Self
-        where
-            S: Into<String>,
-    {
+    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
         self.url = Some(url.into());
         self
     }
 
     /// Sets the title of the media.
-    pub fn title<S>(mut self, title: S) -> Self
-        where
-            S: Into<String>,
-    {
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
         self.title = Some(title.into());
         self
     }
 
     /// Sets the caption of the media.
-    pub fn caption<S>(mut self, caption: S) -> Self
-        where
-            S: Into<String>,
-    {
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
         self.caption = Some(caption.into());
         self
     }
 
     /// Sets the URL of the thumbnail associated with the media.
-    pub fn thumb<S>(mut self, thumb: S) -> Self
-        where
-            S: Into<String>,
-    {
+    pub fn thumb<S: Into<String>>(mut self, thumb: S) -> Self {
         self.thumb = Some(thumb.into());
         self
     }
 
     /// Sets the URL of the background associated with the media.
-    pub fn background<S>(mut self, background: S) -> Self
-        where
-            S: Into<String>,
-    {
+    pub fn background<S: Into<String>>(mut self, background: S) -> Self {
         self.background = Some(background.into());
         self
     }
 
+    /// Sets the quality label of the media.
+    pub fn quality<S: Into<String>>(mut self, quality: S) -> Self {
+        self.quality = Some(quality.into());
+        self
+    }
+
     /// Sets the auto-resume timestamp for media playback.
     pub fn auto_resume_timestamp(mut self, auto_resume_timestamp: u64) -> Self {
         self.auto_resume_timestamp = Some(auto_resume_timestamp);
@@ -751,7 +334,19 @@ impl PlayMediaRequestBuilder {
         self
     }
 
-    /// Sets the media identifier for the requested media.
+    /// Sets the URL on which the selected subtitle is being served.
+    pub fn subtitle_url<S: Into<String>>(mut self, subtitle_url: S) -> Self {
+        self.subtitle_url = Some(subtitle_url.into());
+        self
+    }
+
+    /// Sets the audio tracks which are available within the media.
+    pub fn audio_tracks(mut self, audio_tracks: Vec<MediaTrack>) -> Self {
+        self.audio_tracks = audio_tracks;
+        self
+    }
+
+    /// Sets the media identifier from which the playback originates.
     pub fn media(mut self, media: Box<dyn MediaIdentifier>) -> Self {
         self.media = Some(media);
         self
@@ -763,50 +358,35 @@ impl PlayMediaRequestBuilder {
         self
     }
 
-    /// Sets the quality information for the media.
-    pub fn quality<S>(mut self, quality: S) -> Self
-        where
-            S: Into<String>,
-    {
-        self.quality = Some(quality.into());
-        self
-    }
-
     /// Sets the torrent stream of the media.
     pub fn torrent_stream(mut self, torrent_stream: Weak<Box<dyn TorrentStream>>) -> Self {
         self.torrent_stream = Some(torrent_stream);
         self
     }
 
-    /// Builds and returns a `PlayMediaRequest` based on the provided parameters.
+    /// Builds and returns a `PlayRequest` based on the provided parameters.
     ///
     /// # Panics
     ///
-    /// Panics if the required fields (`url`, `title`, and `media`) are not provided.
-    pub fn build(self) -> PlayMediaRequest {
-        if self.url.is_none() || self.title.is_none() {
-            panic!("url and title fields must be provided to build PlayMediaRequest");
-        }
-
-        let base = PlayUrlRequest {
-            url: self.url.unwrap(),
-            title: self.title.unwrap(),
+    /// Panics if the required fields (`url`, `title`) are not provided.
+    pub fn build(self) -> PlayRequest {
+        PlayRequest {
+            url: self.url.expect("url has not been set"),
+            title: self.title.expect("title has not been set"),
             caption: self.caption,
             thumb: self.thumb,
             background: self.background,
+            quality: self.quality,
             auto_resume_timestamp: self.auto_resume_timestamp,
-            subtitles_enabled: self.subtitles_enabled,
-            subtitle: self.subtitle,
-        };
-
-        PlayMediaRequest {
-            base,
+            subtitle: PlaySubtitleRequest {
+                enabled: self.subtitles_enabled,
+                subtitle: self.subtitle,
+                url: self.subtitle_url,
+            },
+            audio_tracks: self.audio_tracks,
+            media: self.media,
             parent_media: self.parent_media,
-            media: self.media.expect("media has not been set"),
-            quality: self.quality.unwrap_or_else(|| "".to_string()),
-            torrent_stream: self
-                .torrent_stream
-                .expect("torrent_stream has not been set"),
+            torrent_stream: self.torrent_stream,
         }
     }
 }
@@ -817,30 +397,39 @@ mod tests {
 
     use crate::core::media::{Episode, Images, MovieOverview, ShowOverview};
     use crate::core::playlists::PlaylistItem;
+    use crate::core::subtitles::language::SubtitleLanguage;
     use crate::testing::MockTorrentStream;
 
     use super::*;
 
     #[test]
-    fn test_play_url_request_builder() {
+    fn test_play_request_builder() {
         let url = "https://localhost:8054/my-video.mp4";
         let title = "DolorEsta";
         let caption = "lorem ipsum dolor esta";
         let thumb = "https://imgur.com/something.jpg";
         let background = "https://imgur.com/background.jpg";
         let auto_resume = 84000u64;
-        let expected_result = PlayUrlRequest {
+        let expected_result = PlayRequest {
             url: url.to_string(),
             title: title.to_string(),
             caption: Some(caption.to_string()),
             thumb: Some(thumb.to_string()),
             background: Some(background.to_string()),
+            quality: None,
             auto_resume_timestamp: Some(auto_resume),
-            subtitles_enabled: true,
-            subtitle: None,
+            subtitle: PlaySubtitleRequest {
+                enabled: true,
+                subtitle: None,
+                url: None,
+            },
+            audio_tracks: vec![],
+            media: None,
+            parent_media: None,
+            torrent_stream: None,
         };
 
-        let result = PlayUrlRequestBuilder::builder()
+        let result = PlayRequestBuilder::builder()
             .url(url)
             .title(title)
             .caption(caption)
@@ -854,10 +443,92 @@ mod tests {
     }
 
     #[test]
-    fn test_player_url_request_from() {
+    fn test_play_request_builder_media() {
+        let url = "https://localhost:8054/my-video.mp4";
+        let title = "DolorEsta";
+        let thumb = "https://imgur.com/something.jpg";
+        let quality = "720p";
+        let subtitle_url = "http://localhost:9000/subtitle.vtt";
+        let show = ShowOverview {
+            imdb_id: "tt2157488".to_string(),
+            tvdb_id: "".to_string(),
+            title: "".to_string(),
+            year: "".to_string(),
+            num_seasons: 0,
+            images: Default::default(),
+            rating: None,
+        };
+        let episode = Episode {
+            season: 0,
+            episode: 0,
+            first_aired: 0,
+            title: "".to_string(),
+            overview: "".to_string(),
+            tvdb_id: 0,
+            tvdb_id_value: "".to_string(),
+            thumb: None,
+            torrents: Default::default(),
+        };
+        let subtitle = Subtitle::new(
+            vec![],
+            Some(
+                SubtitleInfo::builder()
+                    .imdb_id("tt2157488")
+                    .language(SubtitleLanguage::English)
+                    .build(),
+            ),
+            "subtitle.srt".to_string(),
+        );
+        let stream = Arc::new(Box::new(MockTorrentStream::new()) as Box<dyn TorrentStream>);
+        let expected_result = PlayRequest {
+            url: url.to_string(),
+            title: title.to_string(),
+            caption: None,
+            thumb: Some(thumb.to_string()),
+            background: None,
+            quality: Some(quality.to_string()),
+            auto_resume_timestamp: None,
+            subtitle: PlaySubtitleRequest {
+                enabled: true,
+                subtitle: Some(subtitle.clone()),
+                url: Some(subtitle_url.to_string()),
+            },
+            audio_tracks: vec![],
+            media: Some(Box::new(episode.clone())),
+            parent_media: Some(Box::new(show.clone())),
+            torrent_stream: Some(Arc::downgrade(&stream)),
+        };
+
+        let result = PlayRequestBuilder::builder()
+            .url(url)
+            .title(title)
+            .thumb(thumb)
+            .quality(quality)
+            .subtitles_enabled(true)
+            .subtitle(subtitle)
+            .subtitle_url(subtitle_url)
+            .parent_media(Box::new(show))
+            .media(Box::new(episode))
+            .torrent_stream(Arc::downgrade(&stream))
+            .build();
+
+        assert_eq!(expected_result, result);
+        assert_eq!(
+            Some(&SubtitleLanguage::English),
+            result.subtitle.info().map(|e| e.language())
+        );
+    }
+
+    #[test]
+    fn test_play_request_from_loading_data() {
         let url = "http://localhost:8090/my-video.mkv";
         let title = "MyVideoItem";
         let auto_resume = 50000u64;
+        let audio_track = MediaTrack {
+            id: 2,
+            name: "Audio 2".to_string(),
+            language: Some("eng".to_string()),
+        };
         let data = LoadingData {
             url: Some(url.to_string()),
             title: Some(title.to_string()),
@@ -869,87 +540,74 @@ mod tests {
             torrent_file_info: None,
             quality: None,
             auto_resume_timestamp: Some(auto_resume.clone()),
+            auto_resume_progress: None,
             subtitles_enabled: None,
             subtitle: None,
             media_torrent_info: None,
             torrent: None,
             torrent_stream: None,
+            audio_tracks: Some(vec![audio_track.clone()]),
+            download_only: false,
         };
-        let expected_result = PlayUrlRequest {
+        let expected_result = PlayRequest {
             url: url.to_string(),
             title: title.to_string(),
             caption: None,
             thumb: None,
             background: None,
+            quality: None,
             auto_resume_timestamp: Some(auto_resume),
-            subtitles_enabled: false,
-            subtitle: None,
+            subtitle: Default::default(),
+            audio_tracks: vec![audio_track],
+            media: None,
+            parent_media: None,
+            torrent_stream: None,
         };
 
-        let result = PlayUrlRequest::from(data);
+        let result = PlayRequest::from(data);
 
         assert_eq!(expected_result, result);
     }
 
     #[test]
-    fn test_play_media_request_builder() {
-        let url = "https://localhost:8054/my-video.mp4";
-        let title = "DolorEsta";
-        let thumb = "https://imgur.com/something.jpg";
-        let quality = "720p";
-        let show = ShowOverview {
-            imdb_id: "tt2157488".to_string(),
-            tvdb_id: "".to_string(),
-            title: "".to_string(),
-            year: "".to_string(),
-            num_seasons: 0,
-            images: Default::default(),
-            rating: None,
-        };
-        let episode = Episode {
-            season: 0,
-            episode: 0,
-            first_aired: 0,
-            title: "".to_string(),
-            overview: "".to_string(),
-            tvdb_id: 0,
-            tvdb_id_value: "".to_string(),
-            thumb: None,
-            torrents: Default::default(),
-        };
-        let stream = Arc::new(Box::new(MockTorrentStream::new()) as Box<dyn TorrentStream>);
-        let expected_result = PlayMediaRequest {
-            base: PlayUrlRequest {
-                url: url.to_string(),
-                title: title.to_string(),
-                caption: None,
-                thumb: Some(thumb.to_string()),
-                background: None,
-                auto_resume_timestamp: None,
-                subtitles_enabled: false,
-                subtitle: None,
-            },
-            parent_media: Some(Box::new(show.clone())),
-            media: Box::new(episode.clone()),
-            quality: quality.to_string(),
-            torrent_stream: Arc::downgrade(&stream),
+    fn test_play_request_from_loading_data_thumb() {
+        let url = "http://localhost:8080/movie.mp4";
+        let title = "FooBar";
+        let thumb = "http://localhost:8080/thumbnail.jpg";
+        let data = LoadingData {
+            url: Some(url.to_string()),
+            title: Some(title.to_string()),
+            caption: None,
+            thumb: Some(thumb.to_string()),
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            auto_resume_progress: None,
+            subtitles_enabled: Some(true),
+            subtitle: None,
+            media_torrent_info: None,
+            torrent: None,
+            torrent_stream: None,
+            audio_tracks: None,
+            download_only: false,
         };
-
-        let result = PlayMediaRequestBuilder::builder()
+        let expected = PlayRequest::builder()
             .url(url)
             .title(title)
             .thumb(thumb)
-            .quality(quality)
-            .parent_media(Box::new(show))
-            .media(Box::new(episode))
-            .torrent_stream(Arc::downgrade(&stream))
+            .subtitles_enabled(true)
             .build();
 
-        assert_eq!(expected_result, result)
+        let result = PlayRequest::from(data);
+
+        assert_eq!(expected, result);
     }
 
     #[test]
-    fn test_player_media_request_from_movie() {
+    fn test_play_request_from_movie() {
         let url = "https://exmaple.com";
         let title = "FooBar";
         let subtitles_enabled = true;
@@ -982,30 +640,32 @@ mod tests {
         let stream = Arc::new(Box::new(MockTorrentStream::new()) as Box<dyn TorrentStream>);
         let mut data = LoadingData::from(item);
         data.torrent_stream = Some(Arc::downgrade(&stream));
-        let expected_result = PlayMediaRequest {
-            base: PlayUrlRequest {
-                url: url.to_string(),
-                title: title.to_string(),
-                caption: None,
-                thumb: None,
-                background: Some(background.to_string()),
-                auto_resume_timestamp: None,
-                subtitles_enabled,
+        let expected_result = PlayRequest {
+            url: url.to_string(),
+            title: title.to_string(),
+            caption: None,
+            thumb: None,
+            background: Some(background.to_string()),
+            quality: Some(quality.to_string()),
+            auto_resume_timestamp: None,
+            subtitle: PlaySubtitleRequest {
+                enabled: subtitles_enabled,
                 subtitle: None,
+                url: None,
             },
+            audio_tracks: vec![],
+            media: Some(Box::new(media)),
             parent_media: None,
-            media: Box::new(media),
-            quality: quality.to_string(),
-            torrent_stream: Arc::downgrade(&stream),
+            torrent_stream: Some(Arc::downgrade(&stream)),
         };
 
-        let result = PlayMediaRequest::from(data);
+        let result = PlayRequest::from(data);
 
         assert_eq!(expected_result, result)
     }
 
     #[test]
-    fn test_player_media_request_from_episode() {
+    fn test_play_request_from_episode() {
         let url = "https://localhost:87445/my-episode.mkv";
         let title = "FooBar";
         let subtitles_enabled = true;
@@ -1047,63 +707,49 @@ mod tests {
         let stream = Arc::new(Box::new(MockTorrentStream::new()) as Box<dyn TorrentStream>);
         let mut data = LoadingData::from(item);
         data.torrent_stream = Some(Arc::downgrade(&stream));
-        let expected_result = PlayMediaRequest {
-            base: PlayUrlRequest {
-                url: url.to_string(),
-                title: title.to_string(),
-                caption: None,
-                thumb: None,
-                background: Some(background.to_string()),
-                auto_resume_timestamp: None,
-                subtitles_enabled,
+        let expected_result = PlayRequest {
+            url: url.to_string(),
+            title: title.to_string(),
+            caption: None,
+            thumb: None,
+            background: Some(background.to_string()),
+            quality: Some(quality.to_string()),
+            auto_resume_timestamp: None,
+            subtitle: PlaySubtitleRequest {
+                enabled: subtitles_enabled,
                 subtitle: None,
+                url: None,
             },
+            audio_tracks: vec![],
+            media: Some(Box::new(episode)),
             parent_media: Some(Box::new(media)),
-            media: Box::new(episode),
-            quality: quality.to_string(),
-            torrent_stream: Arc::downgrade(&stream),
+            torrent_stream: Some(Arc::downgrade(&stream)),
         };
 
-        let result = PlayMediaRequest::from(data);
+        let result = PlayRequest::from(data);
 
         assert_eq!(expected_result, result)
     }
 
     #[test]
-    fn test_from_play_url_request() {
-        let url = "http://localhost:8080/movie.mp4";
-        let title = "FooBar";
-        let thumb = "http://localhost:8080/thumbnail.jpg";
-        let data = LoadingData {
-            url: Some(url.to_string()),
-            title: Some(title.to_string()),
-            caption: None,
-            thumb: Some(thumb.to_string()),
-            parent_media: None,
-            media: None,
-            torrent_info: None,
-            torrent_file_info: None,
-            quality: None,
-            auto_resume_timestamp: None,
-            subtitles_enabled: Some(true),
-            subtitle: None,
-            media_torrent_info: None,
-            torrent: None,
-            torrent_stream: None,
-        };
-        let expected = PlayUrlRequest {
-            url: url.to_string(),
-            title: title.to_string(),
-            caption: None,
-            thumb: Some(thumb.to_string()),
-            background: None,
-            auto_resume_timestamp: None,
-            subtitles_enabled: true,
-            subtitle: None,
+    fn test_play_request_clone() {
+        let media = MovieOverview {
+            imdb_id: "tt0000111".to_string(),
+            title: "MyMovie".to_string(),
+            year: "2020".to_string(),
+            rating: None,
+            images: Default::default(),
         };
+        let request = PlayRequest::builder()
+            .url("http://localhost:8080/movie.mp4")
+            .title("FooBar")
+            .quality("1080p")
+            .media(Box::new(media))
+            .build();
 
-        let result = PlayUrlRequest::from(data);
+        let result = request.clone();
 
-        assert_eq!(expected, result);
+        assert_eq!(request, result);
+        assert_eq!(Some("tt0000111"), result.media().map(|e| e.imdb_id()));
     }
 }
@@ -7,6 +7,8 @@ use downcast_rs::{DowncastSync, impl_downcast};
 
 use crate::core::Callbacks;
 use crate::core::players::PlayRequest;
+use crate::core::subtitles::language::SubtitleLanguage;
+use crate::core::subtitles::model::Subtitle;
 
 /// A trait representing a Popcorn FX supported media player for media playback.
 #[async_trait]
@@ -47,19 +49,29 @@ pub trait Player: Debug + Display + DowncastSync + Callbacks<PlayerEvent> {
     /// The current state of the player.
     fn state(&self) -> PlayerState;
 
+    /// Verify if the player plays the media embedded within the application.
+    /// The embedded player is used as last resort when no other player is able to start a playback.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player is the embedded player, else `false`.
+    fn embedded_playback_supported(&self) -> bool {
+        false
+    }
+
     /// Get the current playback request, if any.
     ///
     /// # Returns
     ///
     /// An optional weak reference to the current playback request.
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>>;
+    fn request(&self) -> Option<Weak<PlayRequest>>;
 
     /// Start playback with the given request.
     ///
     /// # Arguments
     ///
     /// * `request` - The playback request to start.
-    async fn play(&self, request: Box<dyn PlayRequest>);
+    async fn play(&self, request: PlayRequest);
 
     /// Pause the current playback of the player.
     fn pause(&self);
@@ -77,6 +89,80 @@ pub trait Player: Debug + Display + DowncastSync + Callbacks<PlayerEvent> {
 
     /// Stop playback.
     fn stop(&self);
+
+    /// Update the subtitle of the current playback.
+    /// Players which render the subtitle themselves, or don't support subtitles, can ignore this invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `subtitle` - The new subtitle to show, or `None` to disable the subtitle.
+    fn update_subtitle(&self, _subtitle: Option<Subtitle>) {}
+
+    /// Update the volume of the current playback.
+    /// Players which don't support remote volume control can ignore this invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - The new volume level, between 0 and 100.
+    fn set_volume(&self, _volume: u32) {}
+
+    /// Update the mute state of the current playback.
+    /// Players which don't support remote volume control can ignore this invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `muted` - `true` to mute the playback, else `false`.
+    fn set_muted(&self, _muted: bool) {}
+
+    /// Verify if the player is able to play media of the given MIME type without transcoding.
+    /// Players which don't know their media capabilities report every MIME type as supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime` - The MIME type of the media, e.g. `video/mp4`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the media can be played directly, else `false`.
+    fn supports(&self, _mime: &str) -> bool {
+        true
+    }
+
+    /// Retrieve the audio tracks of the media which is currently being played.
+    /// Players which are unable to enumerate the tracks of the media report `None`.
+    ///
+    /// # Returns
+    ///
+    /// The available audio tracks, or `None` when not supported by the player.
+    fn audio_tracks(&self) -> Option<Vec<MediaTrack>> {
+        None
+    }
+
+    /// Select the audio track of the current playback.
+    /// Players which don't support track selection can ignore this invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the audio track to select.
+    fn set_audio_track(&self, _id: i32) {}
+
+    /// Retrieve the subtitle tracks which are embedded within the media which is currently being played.
+    /// Players which are unable to enumerate the tracks of the media report `None`.
+    ///
+    /// # Returns
+    ///
+    /// The available embedded subtitle tracks, or `None` when not supported by the player.
+    fn embedded_subtitle_tracks(&self) -> Option<Vec<MediaTrack>> {
+        None
+    }
+
+    /// Select the embedded subtitle track of the current playback.
+    /// Players which don't support track selection can ignore this invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the embedded subtitle track to select.
+    fn set_embedded_subtitle_track(&self, _id: i32) {}
 }
 impl_downcast!(sync Player);
 
@@ -106,6 +192,18 @@ impl Default for PlayerState {
     }
 }
 
+/// A track of the media which is being played by a player, such as an audio or subtitle track.
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(fmt = "{} ({})", name, id)]
+pub struct MediaTrack {
+    /// The unique identifier of the track within the media.
+    pub id: i32,
+    /// The display name of the track.
+    pub name: String,
+    /// The language of the track, if known.
+    pub language: Option<String>,
+}
+
 /// An enumeration representing events related to a player.
 #[repr(i32)]
 #[derive(Debug, Display, Clone, PartialEq)]
@@ -122,6 +220,26 @@ pub enum PlayerEvent {
     /// The volume of the player has changed.
     #[display(fmt = "Player volume changed to {}", _0)]
     VolumeChanged(u32),
+    /// The player lost the connection with the playback device and is trying to reconnect.
+    /// It contains the current reconnection attempt.
+    #[display(fmt = "Player is reconnecting, attempt {}", _0)]
+    Reconnecting(u32),
+    /// The player has been reconnected with the playback device.
+    #[display(fmt = "Player has been reconnected")]
+    Reconnected,
+    /// The player gave up on reconnecting with the playback device.
+    #[display(fmt = "Player failed to reconnect")]
+    ReconnectFailed,
+    /// The active audio track of the player has changed.
+    #[display(fmt = "Player audio track changed to {}", _0)]
+    AudioTrackChanged(i32),
+    /// The active embedded subtitle track of the player has changed.
+    #[display(fmt = "Player subtitle track changed to {}", _0)]
+    SubtitleTrackChanged(i32),
+    /// The active subtitle of the player has changed.
+    /// It contains the language of the new subtitle, or [SubtitleLanguage::None] when the subtitle has been disabled.
+    #[display(fmt = "Player subtitle changed to {:?}", _0)]
+    SubtitleChanged(SubtitleLanguage),
 }
 
 #[cfg(test)]
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// The playlist package specific results.
+pub type Result<T> = std::result::Result<T, PlaylistError>;
+
+/// The playlist error describes exceptions which have occurred when modifying a playlist.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PlaylistError {
+    #[error("playlist index {index} is out of range for {len} items")]
+    IndexOutOfRange { index: usize, len: usize },
+}
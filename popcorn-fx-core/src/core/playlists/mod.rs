@@ -1,5 +1,9 @@
+pub use errors::*;
 pub use playlist::*;
 pub use playlist_manager::*;
+pub use stored_playlist::*;
 
+mod errors;
 mod playlist;
 mod playlist_manager;
+mod stored_playlist;
@@ -5,6 +5,7 @@ use derive_more::Display;
 use log::{debug, info};
 
 use crate::core::media::MediaIdentifier;
+use crate::core::playlists::{PlaylistError, Result};
 use crate::core::torrents::{TorrentFileInfo, TorrentInfo};
 
 /// A struct representing a playlist of media items.
@@ -43,6 +44,43 @@ impl Playlist {
         }
     }
 
+    /// Moves the item at the given index to a new position within the playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current index of the item to move.
+    /// * `to` - The new index of the item, which is clamped to the last position of the playlist.
+    ///
+    /// It returns [PlaylistError::IndexOutOfRange] when no item exists at the `from` index.
+    pub fn move_item(&mut self, from: usize, to: usize) -> Result<()> {
+        let item = self.remove_item(from)?;
+        let to = to.min(self.items.len());
+
+        debug!("Moving playlist item {} from {} to {}", item, from, to);
+        self.items.insert(to, item);
+        Ok(())
+    }
+
+    /// Removes the item at the given index from the playlist.
+    ///
+    /// It returns the removed item, or [PlaylistError::IndexOutOfRange] when no item exists at the index.
+    pub fn remove_item(&mut self, index: usize) -> Result<PlaylistItem> {
+        let len = self.items.len();
+
+        self.items
+            .remove(index)
+            .ok_or(PlaylistError::IndexOutOfRange { index, len })
+    }
+
+    /// Inserts the item at the given index within the playlist.
+    /// An index beyond the end of the playlist appends the item to the playlist.
+    pub fn insert_at(&mut self, index: usize, item: PlaylistItem) {
+        let index = index.min(self.items.len());
+
+        debug!("Inserting media item {:?} at {} in playlist", item, index);
+        self.items.insert(index, item);
+    }
+
     /// Clears all media items from the playlist.
     pub fn clear(&mut self) {
         debug!("Clearing playlist");
@@ -561,4 +599,79 @@ mod test {
         assert_eq!(1, result.items.len());
         assert_eq!(title, result.items.get(0).unwrap().title.as_str());
     }
+
+    #[test]
+    fn test_move_item() {
+        init_logger();
+        let mut playlist = create_playlist(vec!["Episode 1", "Episode 2", "Episode 3"]);
+
+        playlist.move_item(2, 0).unwrap();
+        assert_eq!(
+            vec!["Episode 3", "Episode 1", "Episode 2"],
+            titles(&playlist)
+        );
+
+        playlist.move_item(0, 10).unwrap();
+        assert_eq!(
+            vec!["Episode 1", "Episode 2", "Episode 3"],
+            titles(&playlist)
+        );
+    }
+
+    #[test]
+    fn test_move_item_out_of_range() {
+        init_logger();
+        let mut playlist = create_playlist(vec!["Episode 1", "Episode 2", "Episode 3"]);
+
+        let result = playlist.move_item(3, 0);
+
+        assert_eq!(
+            Err(PlaylistError::IndexOutOfRange { index: 3, len: 3 }),
+            result
+        );
+        assert_eq!(
+            vec!["Episode 1", "Episode 2", "Episode 3"],
+            titles(&playlist)
+        );
+    }
+
+    #[test]
+    fn test_remove_item() {
+        init_logger();
+        let mut playlist = create_playlist(vec!["Episode 1", "Episode 2"]);
+
+        let result = playlist.remove_item(0).unwrap();
+
+        assert_eq!("Episode 1", result.title.as_str());
+        assert_eq!(vec!["Episode 2"], titles(&playlist));
+        assert_eq!(
+            Err(PlaylistError::IndexOutOfRange { index: 1, len: 1 }),
+            playlist.remove_item(1)
+        );
+    }
+
+    #[test]
+    fn test_insert_at() {
+        init_logger();
+        let mut playlist = create_playlist(vec!["Episode 1", "Episode 3"]);
+
+        playlist.insert_at(1, PlaylistItem::builder().title("Episode 2").build());
+        playlist.insert_at(99, PlaylistItem::builder().title("Episode 4").build());
+
+        assert_eq!(
+            vec!["Episode 1", "Episode 2", "Episode 3", "Episode 4"],
+            titles(&playlist)
+        );
+    }
+
+    fn create_playlist(titles: Vec<&str>) -> Playlist {
+        titles
+            .into_iter()
+            .map(|e| PlaylistItem::builder().title(e).build())
+            .collect()
+    }
+
+    fn titles(playlist: &Playlist) -> Vec<&str> {
+        playlist.iter().map(|e| e.title.as_str()).collect()
+    }
 }
@@ -1,16 +1,18 @@
 use std::sync::Arc;
 
 use derive_more::Display;
-use log::{debug, info, trace};
+use log::{debug, error, info, trace, warn};
 use tokio::sync::Mutex;
 
 use crate::core::events::{Event, EventPublisher, HIGHEST_ORDER};
 use crate::core::loader::{LoadingHandle, MediaLoader};
 use crate::core::players::{PlayerManager, PlayerManagerEvent, PlayerState};
-use crate::core::playlists::{Playlist, PlaylistItem};
+use crate::core::playlists::{Playlist, PlaylistItem, Result, StoredPlaylist, StoredPlaylistItem};
+use crate::core::storage::{Storage, StorageError};
 use crate::core::{block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks, Handle};
 
 const PLAYING_NEXT_IN_THRESHOLD_SECONDS: u64 = 60;
+const FILENAME: &str = "playlist.json";
 
 /// An event representing changes to the playlist manager.
 #[derive(Debug, Display, Clone, PartialEq)]
@@ -24,6 +26,11 @@ pub enum PlaylistManagerEvent {
     /// Event indicating a change in the playlist state.
     #[display(fmt = "Playlist state changed to {}", _0)]
     StateChanged(PlaylistState),
+    /// Event indicating that a playlist item has been skipped while restoring the playlist,
+    /// as its source media could no longer be resolved.
+    /// * `String` - The title of the skipped item.
+    #[display(fmt = "Playlist item {} has been skipped", _0)]
+    ItemSkipped(String),
 }
 
 /// Information about the next item to be played in the playlist.
@@ -58,6 +65,7 @@ impl PlaylistManager {
     ///
     /// # Arguments
     ///
+    /// * `storage_path` - The directory in which the active playlist is persisted.
     /// * `player_manager` - A reference to the player manager.
     /// * `event_publisher` - A reference to the event publisher.
     ///
@@ -65,12 +73,14 @@ impl PlaylistManager {
     ///
     /// A new `PlaylistManager` instance.
     pub fn new(
+        storage_path: &str,
         player_manager: Arc<Box<dyn PlayerManager>>,
         event_publisher: Arc<EventPublisher>,
         loader: Arc<Box<dyn MediaLoader>>,
     ) -> Self {
         let manager = Self {
             inner: Arc::new(InnerPlaylistManager::new(
+                storage_path,
                 player_manager,
                 event_publisher,
                 loader,
@@ -178,11 +188,53 @@ impl PlaylistManager {
     pub fn stop(&self) {
         self.inner.stop();
     }
+
+    /// Move a queued item of the playlist to a new position.
+    ///
+    /// Only the queued items are affected, so the item which is currently being played is never interrupted.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current index of the queued item.
+    /// * `to` - The new index of the queued item, which is clamped to the end of the playlist.
+    pub fn move_item(&self, from: usize, to: usize) -> Result<()> {
+        self.inner.move_item(from, to)
+    }
+
+    /// Remove the queued item at the given index from the playlist.
+    ///
+    /// # Returns
+    ///
+    /// The removed item, or an error when the index is out of range.
+    pub fn remove_item(&self, index: usize) -> Result<PlaylistItem> {
+        self.inner.remove_item(index)
+    }
+
+    /// Insert the item at the given index within the queued items of the playlist.
+    /// An index beyond the end of the playlist appends the item.
+    pub fn insert_at(&self, index: usize, item: PlaylistItem) {
+        self.inner.insert_at(index, item)
+    }
+
+    /// Restore the playlist which was active when the application was last closed.
+    ///
+    /// The playlist is re-created without starting the playback, the item which was playing is placed at the front of the playlist.
+    /// Items of which the source media can no longer be resolved are skipped and reported through [PlaylistManagerEvent::ItemSkipped].
+    ///
+    /// # Returns
+    ///
+    /// `true` when a playlist has been restored, else `false`.
+    pub fn restore_playlist(&self) -> bool {
+        self.inner.restore_playlist()
+    }
 }
 
 #[derive(Debug)]
 struct InnerPlaylistManager {
+    storage: Storage,
     playlist: Mutex<Playlist>,
+    /// The playlist item which is currently being played.
+    current_item: Mutex<Option<PlaylistItem>>,
     player_manager: Arc<Box<dyn PlayerManager>>,
     player_duration: Mutex<u64>,
     player_playing_in: Mutex<Option<(Option<u64>, PlaylistItem)>>,
@@ -195,12 +247,15 @@ struct InnerPlaylistManager {
 
 impl InnerPlaylistManager {
     fn new(
+        storage_path: &str,
         player_manager: Arc<Box<dyn PlayerManager>>,
         event_publisher: Arc<EventPublisher>,
         loader: Arc<Box<dyn MediaLoader>>,
     ) -> Self {
         let instance = Self {
+            storage: Storage::from(storage_path),
             playlist: Default::default(),
+            current_item: Default::default(),
             player_manager,
             player_duration: Default::default(),
             player_playing_in: Default::default(),
@@ -236,6 +291,9 @@ impl InnerPlaylistManager {
             trace!("Processing next item in playlist {}", item);
             Some(self.play_item(item))
         } else {
+            drop(mutex);
+            *block_in_place(self.current_item.lock()) = None;
+            self.save();
             self.update_state(PlaylistState::Completed);
             debug!("End of playlist has been reached");
             None
@@ -244,6 +302,8 @@ impl InnerPlaylistManager {
 
     fn play_item(&self, item: PlaylistItem) -> Handle {
         debug!("Starting playback of next playlist item {}", item);
+        *block_in_place(self.current_item.lock()) = Some(item.clone());
+        self.save();
         self.update_state(PlaylistState::Playing);
         let handle = self.loader.load_playlist_item(item);
 
@@ -379,9 +439,108 @@ impl InnerPlaylistManager {
             mutex.clear();
             debug!("Active playlist has been cleared");
         }
+        *block_in_place(self.current_item.lock()) = None;
+        self.save();
         self.event_publisher.publish(Event::ClosePlayer);
     }
 
+    fn move_item(&self, from: usize, to: usize) -> Result<()> {
+        block_in_place(self.playlist.lock()).move_item(from, to)?;
+        self.on_playlist_modified();
+        Ok(())
+    }
+
+    fn remove_item(&self, index: usize) -> Result<PlaylistItem> {
+        let item = block_in_place(self.playlist.lock()).remove_item(index)?;
+        debug!("Removed playlist item {} at {}", item, index);
+        self.on_playlist_modified();
+        Ok(item)
+    }
+
+    fn insert_at(&self, index: usize, item: PlaylistItem) {
+        block_in_place(self.playlist.lock()).insert_at(index, item);
+        self.on_playlist_modified();
+    }
+
+    /// Persist the modified playlist and inform the subscribers about the change.
+    fn on_playlist_modified(&self) {
+        self.save();
+        self.callbacks.invoke(PlaylistManagerEvent::PlaylistChanged);
+    }
+
+    fn restore_playlist(&self) -> bool {
+        let stored = match self
+            .storage
+            .options()
+            .serializer(FILENAME)
+            .read::<StoredPlaylist>()
+        {
+            Ok(e) => e,
+            Err(StorageError::NotFound(_)) => {
+                trace!("No stored playlist available to restore");
+                return false;
+            }
+            Err(e) => {
+                warn!("Failed to read the stored playlist, {}", e);
+                return false;
+            }
+        };
+
+        let start = stored.current_index.unwrap_or(0);
+        let mut playlist = Playlist::default();
+        for item in stored.items.into_iter().skip(start) {
+            if item.is_resolvable() {
+                playlist.add(PlaylistItem::from(item));
+            } else {
+                warn!(
+                    "Skipping stored playlist item {}, source media can no longer be resolved",
+                    item.title
+                );
+                self.callbacks
+                    .invoke(PlaylistManagerEvent::ItemSkipped(item.title));
+            }
+        }
+
+        if !playlist.has_next() {
+            debug!("Stored playlist contains no items to restore");
+            return false;
+        }
+
+        info!("Restoring playlist with {} items", playlist.items.len());
+        *block_in_place(self.playlist.lock()) = playlist;
+        self.callbacks.invoke(PlaylistManagerEvent::PlaylistChanged);
+        self.update_state(PlaylistState::Idle);
+        true
+    }
+
+    /// Persist the active playlist, including the item which is currently being played.
+    fn save(&self) {
+        let current_item = block_in_place(self.current_item.lock())
+            .as_ref()
+            .map(StoredPlaylistItem::from);
+        let current_index = current_item.as_ref().map(|_| 0);
+        let items: Vec<StoredPlaylistItem> = current_item
+            .into_iter()
+            .chain(
+                block_in_place(self.playlist.lock())
+                    .iter()
+                    .map(StoredPlaylistItem::from),
+            )
+            .collect();
+
+        match self
+            .storage
+            .options()
+            .serializer(FILENAME)
+            .write(&StoredPlaylist {
+                items,
+                current_index,
+            }) {
+            Ok(_) => trace!("Active playlist has been stored"),
+            Err(e) => error!("Failed to store the active playlist, {}", e),
+        }
+    }
+
     /// Determine with-either the next item is allowed to be played.
     fn is_next_allowed(&self) -> bool {
         let duration = block_in_place(self.player_duration.lock()).clone();
@@ -418,6 +577,7 @@ mod test {
     use crate::core::events::{DEFAULT_ORDER, LOWEST_ORDER};
     use crate::core::loader::MockMediaLoader;
     use crate::core::players::MockPlayerManager;
+    use crate::core::playlists::PlaylistError;
     use crate::core::Handle;
     use crate::testing::init_logger;
 
@@ -456,7 +616,10 @@ mod test {
                 tx.send(e).unwrap();
                 Handle::new()
             });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -499,7 +662,10 @@ mod test {
         loader
             .expect_load_playlist_item()
             .returning(move |_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -539,6 +705,106 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_restore_playlist() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let new_manager = || {
+            let mut player_manager = Box::new(MockPlayerManager::new());
+            player_manager
+                .expect_subscribe()
+                .return_const(Handle::new());
+            let mut loader = MockMediaLoader::new();
+            loader
+                .expect_load_playlist_item()
+                .returning(move |_| Handle::new());
+            PlaylistManager::new(
+                temp_path,
+                Arc::new(player_manager as Box<dyn PlayerManager>),
+                Arc::new(EventPublisher::default()),
+                Arc::new(Box::new(loader)),
+            )
+        };
+        let playlist: Playlist = vec!["FooBar1", "FooBar2", "FooBar3"]
+            .into_iter()
+            .map(|title| {
+                PlaylistItem::builder()
+                    .url(format!("http://localhost/{}.mp4", title))
+                    .title(title)
+                    .build()
+            })
+            .collect();
+        let manager = new_manager();
+        manager.play(playlist);
+        manager.play_next();
+
+        let manager = new_manager();
+        let result = manager.restore_playlist();
+
+        assert_eq!(true, result, "expected the playlist to have been restored");
+        assert_eq!(PlaylistState::Idle, manager.state());
+        let titles: Vec<String> = manager.playlist().iter().map(|e| e.title.clone()).collect();
+        assert_eq!(vec!["FooBar2".to_string(), "FooBar3".to_string()], titles);
+    }
+
+    #[test]
+    fn test_restore_playlist_unresolvable_item() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let missing_file = temp_dir.path().join("missing.mp4");
+        let item = |title: &str, url: String| StoredPlaylistItem {
+            url: Some(url),
+            title: title.to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+        Storage::from(temp_path)
+            .options()
+            .serializer(FILENAME)
+            .write(&StoredPlaylist {
+                items: vec![
+                    item("Missing", missing_file.to_str().unwrap().to_string()),
+                    item("Remote", "http://localhost/remote.mp4".to_string()),
+                ],
+                current_index: Some(0),
+            })
+            .unwrap();
+        let mut player_manager = Box::new(MockPlayerManager::new());
+        player_manager
+            .expect_subscribe()
+            .return_const(Handle::new());
+        let manager = PlaylistManager::new(
+            temp_path,
+            Arc::new(player_manager as Box<dyn PlayerManager>),
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(MockMediaLoader::new())),
+        );
+        let (tx, rx) = channel();
+        manager.subscribe(Box::new(move |e| {
+            if let PlaylistManagerEvent::ItemSkipped(title) = e {
+                tx.send(title).unwrap();
+            }
+        }));
+
+        let result = manager.restore_playlist();
+
+        assert_eq!(true, result, "expected the playlist to have been restored");
+        let skipped = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("Missing", skipped);
+        let playlist = manager.playlist();
+        assert_eq!(1, playlist.items.len());
+        assert_eq!("Remote", playlist.items[0].title);
+    }
+
     #[test]
     fn test_player_stopped_event() {
         init_logger();
@@ -567,7 +833,10 @@ mod test {
                 tx.send(e).unwrap();
                 Handle::new()
             });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -649,7 +918,10 @@ mod test {
             .expect_load_playlist_item()
             .times(2)
             .returning(move |_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -728,7 +1000,10 @@ mod test {
         loader
             .expect_load_playlist_item()
             .returning(move |_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -820,7 +1095,10 @@ mod test {
                 tx.send(e).unwrap();
                 Handle::new()
             });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -908,7 +1186,10 @@ mod test {
         loader
             .expect_load_playlist_item()
             .returning(move |_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -986,7 +1267,10 @@ mod test {
         loader
             .expect_load_playlist_item()
             .returning(move |_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
         let manager = PlaylistManager::new(
+            temp_path,
             player_manager.clone(),
             event_publisher.clone(),
             Arc::new(Box::new(loader)),
@@ -1050,4 +1334,53 @@ mod test {
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(Event::ClosePlayer, result);
     }
+
+    #[test]
+    fn test_move_item() {
+        init_logger();
+        let mut player_manager = MockPlayerManager::new();
+        player_manager
+            .expect_subscribe()
+            .returning(|_| Handle::new());
+        let mut loader = MockMediaLoader::new();
+        loader
+            .expect_load_playlist_item()
+            .times(1)
+            .returning(|_| Handle::new());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let manager = PlaylistManager::new(
+            temp_path,
+            Arc::new(Box::new(player_manager) as Box<dyn PlayerManager>),
+            Arc::new(EventPublisher::default()),
+            Arc::new(Box::new(loader)),
+        );
+        let playlist: Playlist = vec!["Playing", "Episode 1", "Episode 2", "Episode 3"]
+            .into_iter()
+            .map(|e| PlaylistItem::builder().title(e).build())
+            .collect();
+        let (tx, rx) = channel();
+
+        manager.play(playlist);
+        manager.subscribe(Box::new(move |e| {
+            if let PlaylistManagerEvent::PlaylistChanged = e {
+                tx.send(e).unwrap();
+            }
+        }));
+        manager.move_item(0, 2).unwrap();
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlaylistManagerEvent::PlaylistChanged, result);
+        let result: Vec<String> = manager.playlist().iter().map(|e| e.title.clone()).collect();
+        assert_eq!(vec!["Episode 2", "Episode 3", "Episode 1"], result);
+        assert_eq!(
+            PlaylistState::Playing,
+            manager.state(),
+            "expected the playback to not have been interrupted"
+        );
+        assert_eq!(
+            Err(PlaylistError::IndexOutOfRange { index: 5, len: 3 }),
+            manager.move_item(5, 0)
+        );
+    }
 }
@@ -0,0 +1,281 @@
+use std::path::Path;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::core::media::{
+    Episode, MediaIdentifier, MovieDetails, MovieOverview, ShowDetails, ShowOverview,
+};
+use crate::core::playlists::PlaylistItem;
+use crate::core::torrents::{TorrentFileInfo, TorrentInfo};
+
+/// The media of a playlist item in its persisted form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum StoredMedia {
+    Movie(MovieOverview),
+    MovieDetails(MovieDetails),
+    Show(ShowOverview),
+    ShowDetails(ShowDetails),
+    Episode(Episode),
+}
+
+impl StoredMedia {
+    /// Create the persisted form of the given media identifier.
+    ///
+    /// It returns [None] when the media type is not supported.
+    pub fn from_identifier(media: &Box<dyn MediaIdentifier>) -> Option<Self> {
+        if let Some(e) = media.downcast_ref::<Episode>() {
+            Some(StoredMedia::Episode(e.clone()))
+        } else if let Some(e) = media.downcast_ref::<ShowOverview>() {
+            Some(StoredMedia::Show(e.clone()))
+        } else if let Some(e) = media.downcast_ref::<ShowDetails>() {
+            Some(StoredMedia::ShowDetails(e.clone()))
+        } else if let Some(e) = media.downcast_ref::<MovieOverview>() {
+            Some(StoredMedia::Movie(e.clone()))
+        } else if let Some(e) = media.downcast_ref::<MovieDetails>() {
+            Some(StoredMedia::MovieDetails(e.clone()))
+        } else {
+            warn!("Unable to store playlist media {}, unsupported type", media);
+            None
+        }
+    }
+
+    /// The unique identifier of the persisted media.
+    pub fn id(&self) -> String {
+        match self {
+            StoredMedia::Movie(e) => e.imdb_id.clone(),
+            StoredMedia::MovieDetails(e) => e.imdb_id.clone(),
+            StoredMedia::Show(e) => e.imdb_id.clone(),
+            StoredMedia::ShowDetails(e) => e.imdb_id.clone(),
+            StoredMedia::Episode(e) => e.tvdb_id.to_string(),
+        }
+    }
+
+    /// Convert the persisted media back into a media identifier.
+    pub fn into_identifier(self) -> Box<dyn MediaIdentifier> {
+        match self {
+            StoredMedia::Movie(e) => Box::new(e),
+            StoredMedia::MovieDetails(e) => Box::new(e),
+            StoredMedia::Show(e) => Box::new(e),
+            StoredMedia::ShowDetails(e) => Box::new(e),
+            StoredMedia::Episode(mut e) => {
+                // the identifier value is not persisted and needs to be restored from the tvdb id
+                e.tvdb_id_value = e.tvdb_id.to_string();
+                Box::new(e)
+            }
+        }
+    }
+}
+
+/// A playlist item in its persisted form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredPlaylistItem {
+    pub url: Option<String>,
+    pub title: String,
+    pub caption: Option<String>,
+    pub thumb: Option<String>,
+    pub parent_media: Option<StoredMedia>,
+    pub media: Option<StoredMedia>,
+    pub torrent_info: Option<TorrentInfo>,
+    pub torrent_file_info: Option<TorrentFileInfo>,
+    pub quality: Option<String>,
+    pub auto_resume_timestamp: Option<u64>,
+    pub subtitles_enabled: bool,
+}
+
+impl StoredPlaylistItem {
+    /// Verify if the source of the playlist item can still be resolved.
+    ///
+    /// An item is resolvable when it has a torrent, a remote url, or a local file which still exists.
+    pub fn is_resolvable(&self) -> bool {
+        if self.torrent_info.is_some() {
+            return true;
+        }
+
+        match self.url.as_ref() {
+            None => false,
+            Some(url) => match Url::parse(url.as_str()) {
+                Ok(e) if e.scheme() == "file" => {
+                    e.to_file_path().map(|path| path.exists()).unwrap_or(false)
+                }
+                // single letter schemes are windows drive letters of a local path
+                Ok(e) if e.scheme().len() > 1 => true,
+                _ => Path::new(url.as_str()).exists(),
+            },
+        }
+    }
+}
+
+impl From<&PlaylistItem> for StoredPlaylistItem {
+    fn from(value: &PlaylistItem) -> Self {
+        Self {
+            url: value.url.clone(),
+            title: value.title.clone(),
+            caption: value.caption.clone(),
+            thumb: value.thumb.clone(),
+            parent_media: value
+                .parent_media
+                .as_ref()
+                .and_then(StoredMedia::from_identifier),
+            media: value.media.as_ref().and_then(StoredMedia::from_identifier),
+            torrent_info: value.torrent_info.clone(),
+            torrent_file_info: value.torrent_file_info.clone(),
+            quality: value.quality.clone(),
+            auto_resume_timestamp: value.auto_resume_timestamp,
+            subtitles_enabled: value.subtitles_enabled,
+        }
+    }
+}
+
+impl From<StoredPlaylistItem> for PlaylistItem {
+    fn from(value: StoredPlaylistItem) -> Self {
+        Self {
+            url: value.url,
+            title: value.title,
+            caption: value.caption,
+            thumb: value.thumb,
+            parent_media: value.parent_media.map(StoredMedia::into_identifier),
+            media: value.media.map(StoredMedia::into_identifier),
+            torrent_info: value.torrent_info,
+            torrent_file_info: value.torrent_file_info,
+            quality: value.quality,
+            auto_resume_timestamp: value.auto_resume_timestamp,
+            subtitles_enabled: value.subtitles_enabled,
+        }
+    }
+}
+
+/// The persisted state of the active playlist.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StoredPlaylist {
+    /// The items of the playlist, including the item which was playing.
+    pub items: Vec<StoredPlaylistItem>,
+    /// The index of the item which was playing, if any.
+    pub current_index: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_movie_item() {
+        let item = PlaylistItem::builder()
+            .url("magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7")
+            .title("lorem")
+            .media(Box::new(MovieOverview::new(
+                "lorem".to_string(),
+                "tt1156398".to_string(),
+                "2010".to_string(),
+            )))
+            .quality("720p")
+            .subtitles_enabled(true)
+            .build();
+        let stored = StoredPlaylistItem::from(&item);
+
+        let json = serde_json::to_string(&stored).unwrap();
+        let result: StoredPlaylistItem = serde_json::from_str(json.as_str()).unwrap();
+
+        assert_eq!(stored, result);
+        let result = PlaylistItem::from(result);
+        assert_eq!(item, result);
+        assert_eq!(
+            "tt1156398",
+            result
+                .media
+                .expect("expected the media to be present")
+                .imdb_id()
+        );
+    }
+
+    #[test]
+    fn test_serialize_episode_item() {
+        let show = ShowOverview {
+            imdb_id: "tt2861424".to_string(),
+            tvdb_id: "275274".to_string(),
+            title: "Rick and Morty".to_string(),
+            year: "2013".to_string(),
+            num_seasons: 6,
+            images: Default::default(),
+            rating: None,
+        };
+        let episode = Episode::new_with_torrents(
+            1,
+            2,
+            1386478800,
+            "Lawnmower Dog".to_string(),
+            "Rick helps Jerry with the dog.".to_string(),
+            4283018,
+            HashMap::new(),
+        );
+        let item = PlaylistItem::builder()
+            .url("https://localhost:8080/lawnmower-dog.mp4")
+            .title("Lawnmower Dog")
+            .parent_media(Box::new(show))
+            .media(Box::new(episode))
+            .build();
+        let stored = StoredPlaylistItem::from(&item);
+
+        let json = serde_json::to_string(&stored).unwrap();
+        let result =
+            PlaylistItem::from(serde_json::from_str::<StoredPlaylistItem>(json.as_str()).unwrap());
+
+        assert_eq!(item, result);
+        let parent_media = result.parent_media.expect("expected the parent media");
+        assert_eq!("tt2861424", parent_media.imdb_id());
+        let media = result.media.expect("expected the media");
+        assert_eq!("4283018", media.imdb_id());
+        assert!(
+            media.downcast_ref::<Episode>().is_some(),
+            "expected the media to be restored as an episode"
+        );
+    }
+
+    #[test]
+    fn test_is_resolvable() {
+        let temp_dir = tempdir().unwrap();
+        let existing_file = temp_dir.path().join("lorem.mp4");
+        std::fs::write(&existing_file, "").unwrap();
+        let item = |url: Option<String>| StoredPlaylistItem {
+            url,
+            title: "lorem".to_string(),
+            caption: None,
+            thumb: None,
+            parent_media: None,
+            media: None,
+            torrent_info: None,
+            torrent_file_info: None,
+            quality: None,
+            auto_resume_timestamp: None,
+            subtitles_enabled: false,
+        };
+
+        assert_eq!(
+            true,
+            item(Some("https://localhost/lorem.mp4".to_string())).is_resolvable()
+        );
+        assert_eq!(
+            true,
+            item(Some(existing_file.to_str().unwrap().to_string())).is_resolvable()
+        );
+        assert_eq!(
+            false,
+            item(Some(
+                temp_dir
+                    .path()
+                    .join("ipsum.mp4")
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            ))
+            .is_resolvable()
+        );
+        assert_eq!(false, item(None).is_resolvable());
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// The screen package specific results.
+pub type Result<T> = std::result::Result<T, ScreenError>;
+
+/// The screen error describes exceptions which have occurred when managing the application screen.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ScreenError {
+    #[error("display {0} is not connected")]
+    DisplayNotFound(u32),
+}
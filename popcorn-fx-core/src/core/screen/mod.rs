@@ -1,3 +1,5 @@
+pub use errors::*;
 pub use service::*;
 
+mod errors;
 mod service;
@@ -1,12 +1,48 @@
 use std::fmt::{Debug, Formatter};
 
+use derive_more::Display;
 use downcast_rs::{DowncastSync, impl_downcast};
-use log::{debug, trace, warn};
+use log::{debug, info, trace, warn};
 #[cfg(any(test, feature = "testing"))]
 use mockall::automock;
 use tokio::sync::Mutex;
 
-use crate::core::block_in_place;
+use crate::core::{block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks};
+use crate::core::screen::{Result, ScreenError};
+
+/// The screen event specific callback type.
+pub type ScreenCallback = CoreCallback<ScreenEvent>;
+
+/// The events of the screen service.
+#[derive(Debug, Clone, Display, PartialEq)]
+pub enum ScreenEvent {
+    /// Invoked when the fullscreen state of the application has been changed
+    #[display(fmt = "Fullscreen changed to {}", _0)]
+    FullscreenChanged(bool),
+    /// Invoked when the application has been moved to another display
+    #[display(fmt = "Display changed to {}", _0)]
+    DisplayChanged(u32),
+}
+
+/// A display which is connected to the system and on which the application can be shown.
+#[derive(Debug, Clone, Display, PartialEq)]
+#[display(fmt = "{} ({}x{})", name, width, height)]
+pub struct DisplayInfo {
+    /// The unique id of the display
+    pub id: u32,
+    /// The name of the display
+    pub name: String,
+    /// The horizontal position of the display within the virtual screen
+    pub x: i32,
+    /// The vertical position of the display within the virtual screen
+    pub y: i32,
+    /// The width of the display in pixels
+    pub width: u32,
+    /// The height of the display in pixels
+    pub height: u32,
+    /// Indicates if the display is the primary display of the system
+    pub primary: bool,
+}
 
 /// A trait for managing the application screen.
 ///
@@ -30,6 +66,28 @@ pub trait ScreenService: Debug + DowncastSync {
     ///
     /// * `active_fullscreen` - `true` to activate fullscreen mode, `false` to deactivate it.
     fn fullscreen(&self, active_fullscreen: bool);
+
+    /// Retrieve the displays which are connected to the system.
+    ///
+    /// # Returns
+    ///
+    /// The connected displays, or an empty list when the displays are unknown.
+    fn displays(&self) -> Vec<DisplayInfo>;
+
+    /// Move the application to the given display.
+    /// The fullscreen state of the application is retained on the new display.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the display to move the application to.
+    ///
+    /// # Returns
+    ///
+    /// An empty result when the application is being moved, else the [ScreenError] why it couldn't be moved.
+    fn move_to_display(&self, id: u32) -> Result<()>;
+
+    /// Register a new callback for the [ScreenEvent]'s.
+    fn register(&self, callback: ScreenCallback) -> CallbackHandle;
 }
 impl_downcast!(sync ScreenService);
 
@@ -42,11 +100,20 @@ pub type ToggleFullScreenCallback = Box<dyn Fn() + Send + Sync>;
 /// A type representing a callback function to set the fullscreen state of the application.
 pub type FullscreenCallback = Box<dyn Fn(bool) + Send + Sync>;
 
+/// A type representing a callback function to move the application to the display with the given id.
+pub type MoveToDisplayCallback = Box<dyn Fn(u32) + Send + Sync>;
+
 /// A struct implementing ScreenService for managing screen-related actions and information.
+///
+/// The actual window of the application is managed by the UI shell, which registers the callbacks
+/// to execute the screen actions and informs the service about the connected displays.
 pub struct DefaultScreenService {
     pub is_fullscreen_callback: Mutex<IsFullScreenCallback>,
     pub toggle_fullscreen_callback: Mutex<ToggleFullScreenCallback>,
     pub fullscreen_callback: Mutex<FullscreenCallback>,
+    pub move_to_display_callback: Mutex<MoveToDisplayCallback>,
+    displays: Mutex<Vec<DisplayInfo>>,
+    callbacks: CoreCallbacks<ScreenEvent>,
 }
 
 impl DefaultScreenService {
@@ -62,6 +129,11 @@ impl DefaultScreenService {
             fullscreen_callback: Mutex::new(Box::new(|_| {
                 warn!("fullscreen_callback has not been configured");
             })),
+            move_to_display_callback: Mutex::new(Box::new(|_| {
+                warn!("move_to_display_callback has not been configured");
+            })),
+            displays: Mutex::new(vec![]),
+            callbacks: CoreCallbacks::default(),
         }
     }
 
@@ -82,6 +154,20 @@ impl DefaultScreenService {
         let mut mutex = block_in_place(self.fullscreen_callback.lock());
         *mutex = callback;
     }
+
+    pub fn register_move_to_display_callback(&self, callback: MoveToDisplayCallback) {
+        debug!("Registering new MoveToDisplayCallback");
+        let mut mutex = block_in_place(self.move_to_display_callback.lock());
+        *mutex = callback;
+    }
+
+    /// Update the displays which are connected to the system.
+    /// This should be invoked by the UI shell each time the connected displays have been changed.
+    pub fn update_displays(&self, displays: Vec<DisplayInfo>) {
+        debug!("Updating the connected displays to {:?}", displays);
+        let mut mutex = block_in_place(self.displays.lock());
+        *mutex = displays;
+    }
 }
 
 impl ScreenService for DefaultScreenService {
@@ -100,14 +186,48 @@ impl ScreenService for DefaultScreenService {
             "Updating screen service fullscreen to {}",
             active_fullscreen
         );
-        let callback = block_in_place(self.fullscreen_callback.lock());
-        callback(active_fullscreen)
+        let is_fullscreen = self.is_fullscreen();
+        {
+            let callback = block_in_place(self.fullscreen_callback.lock());
+            callback(active_fullscreen);
+        }
+
+        if is_fullscreen != active_fullscreen {
+            self.callbacks
+                .invoke(ScreenEvent::FullscreenChanged(active_fullscreen));
+        }
+    }
+
+    fn displays(&self) -> Vec<DisplayInfo> {
+        block_in_place(self.displays.lock()).clone()
+    }
+
+    fn move_to_display(&self, id: u32) -> Result<()> {
+        if !self.displays().iter().any(|e| e.id == id) {
+            return Err(ScreenError::DisplayNotFound(id));
+        }
+
+        info!("Moving the application to display {}", id);
+        {
+            let callback = block_in_place(self.move_to_display_callback.lock());
+            callback(id);
+        }
+
+        self.callbacks.invoke(ScreenEvent::DisplayChanged(id));
+        Ok(())
+    }
+
+    fn register(&self, callback: ScreenCallback) -> CallbackHandle {
+        self.callbacks.add(callback)
     }
 }
 
 impl Debug for DefaultScreenService {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DefaultScreenService").finish()
+        f.debug_struct("DefaultScreenService")
+            .field("displays", &self.displays)
+            .field("callbacks", &self.callbacks)
+            .finish()
     }
 }
 
@@ -173,4 +293,75 @@ mod tests {
             .expect("expected the callback to have been invoked");
         assert_eq!(true, result);
     }
+
+    #[test]
+    fn test_fullscreen_changed_event() {
+        init_logger();
+        let (tx, rx) = channel();
+        let service = DefaultScreenService::new();
+        service.register_is_fullscreen_callback(Box::new(|| false));
+        service.register_fullscreen_callback(Box::new(|_| {}));
+        service.register(Box::new(move |event| {
+            tx.send(event).unwrap();
+        }));
+
+        service.fullscreen(false);
+        assert!(
+            rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "expected no event when the fullscreen state didn't change"
+        );
+
+        service.fullscreen(true);
+        let result = rx
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the event to have been invoked");
+        assert_eq!(ScreenEvent::FullscreenChanged(true), result);
+    }
+
+    #[test]
+    fn test_move_to_display() {
+        init_logger();
+        let (tx, rx) = channel();
+        let (tx_event, rx_event) = channel();
+        let display = DisplayInfo {
+            id: 2,
+            name: "HDMI-1".to_string(),
+            x: 1920,
+            y: 0,
+            width: 3840,
+            height: 2160,
+            primary: false,
+        };
+        let service = DefaultScreenService::new();
+        service.register_move_to_display_callback(Box::new(move |id| {
+            tx.send(id).unwrap();
+        }));
+        service.register(Box::new(move |event| {
+            tx_event.send(event).unwrap();
+        }));
+        service.update_displays(vec![display.clone()]);
+
+        let result = service.move_to_display(2);
+        assert_eq!(Ok(()), result);
+        assert_eq!(vec![display], service.displays());
+
+        let result = rx
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the callback to have been invoked");
+        assert_eq!(2, result);
+        let result = rx_event
+            .recv_timeout(Duration::from_millis(200))
+            .expect("expected the event to have been invoked");
+        assert_eq!(ScreenEvent::DisplayChanged(2), result);
+    }
+
+    #[test]
+    fn test_move_to_display_unknown() {
+        init_logger();
+        let service = DefaultScreenService::new();
+
+        let result = service.move_to_display(5);
+
+        assert_eq!(Err(ScreenError::DisplayNotFound(5)), result);
+    }
 }
@@ -18,4 +18,10 @@ pub enum StorageError {
     /// It contains the `filepath` and `error_message`.
     #[error("an io error occurred on {0}, {1}")]
     IO(String, String),
+    /// The value of the given `key` exceeds the maximum allowed size in bytes.
+    #[error("value of {0} exceeds the maximum size of {1} bytes")]
+    ValueTooLarge(String, usize),
+    /// The data migration of the given `version` failed, it returned `error_message` while migrating.
+    #[error("data migration {0} failed, {1}")]
+    MigrationFailed(String, String),
 }
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use log::{debug, error, info, trace, warn};
+use tokio::sync::Mutex;
+
+use crate::core::storage::{Storage, StorageError};
+use crate::core::{block_in_place, storage};
+
+const FILENAME: &str = "key-value-storage.json";
+/// The maximum size in bytes of a single stored value.
+pub const MAX_VALUE_SIZE: usize = 64 * 1024;
+
+type Namespaces = HashMap<String, HashMap<String, String>>;
+
+/// A namespaced key-value storage for small bits of application state, such as UI preferences.
+///
+/// The entries are persisted as JSON within the storage directory of the application.
+/// Modifications are serialized, which makes the storage safe to be shared across multiple threads.
+#[derive(Debug)]
+pub struct KeyValueStorage {
+    storage: Storage,
+    namespaces: Mutex<Namespaces>,
+}
+
+impl KeyValueStorage {
+    /// Create a new key-value storage.
+    ///
+    /// * `storage_path` - The directory to use to read & store the key-value entries.
+    pub fn new(storage_path: &str) -> Self {
+        let storage = Storage::from(storage_path);
+        let namespaces = match storage.options().serializer(FILENAME).read::<Namespaces>() {
+            Ok(e) => {
+                info!(
+                    "Key-value storage has been loaded from {} ({} namespaces)",
+                    FILENAME,
+                    e.len()
+                );
+                e
+            }
+            Err(error) => {
+                match error {
+                    StorageError::NotFound(file) => {
+                        debug!("Creating new key-value storage file {}", file);
+                    }
+                    StorageError::ReadingFailed(_, error) => {
+                        error!("Failed to load key-value storage, {}", error);
+                    }
+                    _ => {
+                        warn!("Unexpected error returned from storage, {}", error);
+                    }
+                }
+
+                Namespaces::default()
+            }
+        };
+
+        Self {
+            storage,
+            namespaces: Mutex::new(namespaces),
+        }
+    }
+
+    /// Retrieve the value of the given key within the namespace.
+    ///
+    /// It returns the stored value when found, else [None].
+    pub fn get(&self, namespace: &str, key: &str) -> Option<String> {
+        trace!("Retrieving key-value entry {}.{}", namespace, key);
+        let namespaces = block_in_place(self.namespaces.lock());
+        namespaces.get(namespace).and_then(|e| e.get(key)).cloned()
+    }
+
+    /// Store the given value for the key within the namespace.
+    /// An existing value of the key will be replaced.
+    /// The entries are only updated when they have been saved successfully.
+    ///
+    /// It returns [StorageError::ValueTooLarge] when the value exceeds the [MAX_VALUE_SIZE].
+    pub fn set(&self, namespace: &str, key: &str, value: &str) -> storage::Result<()> {
+        if value.len() > MAX_VALUE_SIZE {
+            return Err(StorageError::ValueTooLarge(
+                format!("{}.{}", namespace, key),
+                MAX_VALUE_SIZE,
+            ));
+        }
+
+        trace!("Updating key-value entry {}.{}", namespace, key);
+        block_in_place(async {
+            let mut namespaces = self.namespaces.lock().await;
+            let mut updated = namespaces.clone();
+            updated
+                .entry(namespace.to_string())
+                .or_default()
+                .insert(key.to_string(), value.to_string());
+
+            self.save(&updated).await?;
+            *namespaces = updated;
+            Ok(())
+        })
+    }
+
+    /// Remove the given key from the namespace.
+    /// Unknown keys will just be ignored and not result in an error.
+    /// The entry is only removed when the entries have been saved successfully.
+    pub fn delete(&self, namespace: &str, key: &str) -> storage::Result<()> {
+        trace!("Removing key-value entry {}.{}", namespace, key);
+        block_in_place(async {
+            let mut namespaces = self.namespaces.lock().await;
+            let mut updated = namespaces.clone();
+            let removed = match updated.get_mut(namespace) {
+                Some(entries) => {
+                    let removed = entries.remove(key).is_some();
+                    if entries.is_empty() {
+                        updated.remove(namespace);
+                    }
+                    removed
+                }
+                None => false,
+            };
+
+            if removed {
+                self.save(&updated).await?;
+                *namespaces = updated;
+                Ok(())
+            } else {
+                debug!("Key-value entry {}.{} doesn't exist", namespace, key);
+                Ok(())
+            }
+        })
+    }
+
+    /// Retrieve the keys which are stored within the given namespace.
+    ///
+    /// It returns the keys in alphabetical order.
+    pub fn keys(&self, namespace: &str) -> Vec<String> {
+        let namespaces = block_in_place(self.namespaces.lock());
+        let mut keys: Vec<String> = namespaces
+            .get(namespace)
+            .map(|e| e.keys().cloned().collect())
+            .unwrap_or_default();
+
+        keys.sort();
+        keys
+    }
+
+    async fn save(&self, namespaces: &Namespaces) -> storage::Result<()> {
+        self.storage
+            .options()
+            .serializer(FILENAME)
+            .write_async(namespaces)
+            .await
+            .map(|_| debug!("Key-value storage has been saved"))
+            .map_err(|e| {
+                error!("Failed to save key-value storage, {}", e);
+                e
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+
+        let result = storage.set("ui", "last_tab", "shows");
+        assert_eq!(Ok(()), result);
+
+        let result = storage.get("ui", "last_tab");
+        assert_eq!(Some("shows".to_string()), result);
+
+        let result = KeyValueStorage::new(temp_path).get("ui", "last_tab");
+        assert_eq!(
+            Some("shows".to_string()),
+            result,
+            "expected the value to have been persisted"
+        );
+    }
+
+    #[test]
+    fn test_set_value_too_large() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+        let value = "a".repeat(MAX_VALUE_SIZE + 1);
+
+        let result = storage.set("ui", "geometry", value.as_str());
+
+        assert_eq!(
+            Err(StorageError::ValueTooLarge(
+                "ui.geometry".to_string(),
+                MAX_VALUE_SIZE
+            )),
+            result
+        );
+        assert_eq!(None, storage.get("ui", "geometry"));
+    }
+
+    #[test]
+    fn test_set_save_failed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+        // a directory at the location of the storage file prevents it from being written
+        fs::create_dir(temp_dir.path().join(FILENAME)).unwrap();
+
+        let result = storage.set("ui", "last_tab", "shows");
+
+        assert!(result.is_err(), "expected the save to have failed");
+        assert_eq!(None, storage.get("ui", "last_tab"));
+        assert_eq!(Vec::<String>::new(), storage.keys("ui"));
+    }
+
+    #[test]
+    fn test_delete() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+        storage.set("ui", "last_tab", "movies").unwrap();
+
+        let result = storage.delete("ui", "last_tab");
+        assert_eq!(Ok(()), result);
+        assert_eq!(None, storage.get("ui", "last_tab"));
+
+        let result = storage.delete("ui", "unknown");
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    fn test_delete_save_failed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+        storage.set("ui", "last_tab", "movies").unwrap();
+        let filepath = temp_dir.path().join(FILENAME);
+        fs::remove_file(&filepath).unwrap();
+        fs::create_dir(&filepath).unwrap();
+
+        let result = storage.delete("ui", "last_tab");
+
+        assert!(result.is_err(), "expected the save to have failed");
+        assert_eq!(Some("movies".to_string()), storage.get("ui", "last_tab"));
+    }
+
+    #[test]
+    fn test_keys() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let storage = KeyValueStorage::new(temp_path);
+        storage.set("ui", "width", "800").unwrap();
+        storage.set("ui", "height", "600").unwrap();
+        storage.set("player", "volume", "80").unwrap();
+
+        let result = storage.keys("ui");
+
+        assert_eq!(vec!["height".to_string(), "width".to_string()], result);
+        assert_eq!(Vec::<String>::new(), storage.keys("unknown"));
+    }
+}
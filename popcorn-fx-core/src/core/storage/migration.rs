@@ -0,0 +1,393 @@
+use std::fmt::{Debug, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use log::{debug, error, info, trace, warn};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::core::storage;
+use crate::core::storage::{Storage, StorageError};
+use crate::VERSION;
+
+const FILENAME: &str = "data-version.json";
+const BACKUP_DIRECTORY: &str = "migration-backup";
+/// The version which is assumed for data directories that have never been migrated before.
+const UNVERSIONED: Version = Version::new(0, 0, 0);
+
+/// The function which is invoked to migrate the data directory.
+/// It receives the path to the data directory of the application.
+pub type MigrationFn = fn(&Path) -> Result<(), String>;
+
+/// A single migration step of the data directory.
+#[derive(Clone)]
+pub struct Migration {
+    /// The application version which introduced the data format of this migration.
+    pub version: Version,
+    /// A short description of the migration.
+    pub description: &'static str,
+    /// The files, relative to the data directory, which are modified by the migration.
+    /// These files are backed up before the migration is executed.
+    pub files: Vec<&'static str>,
+    /// The function which executes the migration.
+    pub migrate: MigrationFn,
+}
+
+impl Debug for Migration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migration")
+            .field("version", &self.version)
+            .field("description", &self.description)
+            .field("files", &self.files)
+            .finish()
+    }
+}
+
+/// Retrieve the known migrations of the application data directory.
+/// New migrations should be registered here when the data format of a release changes.
+pub fn data_migrations() -> Vec<Migration> {
+    vec![]
+}
+
+/// The persisted version of the data directory format.
+#[derive(Debug, Serialize, Deserialize)]
+struct DataVersion {
+    version: String,
+}
+
+/// The migration runner is responsible for upgrading the data directory of the application
+/// when the data format has changed between application versions.
+///
+/// The runner compares the stored data version against the application version and executes
+/// the pending migrations in order. The applied version is persisted after each successful migration.
+/// When a migration fails, the original files are restored from their backup and the remaining migrations are skipped.
+#[derive(Debug)]
+pub struct MigrationRunner {
+    storage: Storage,
+    storage_path: PathBuf,
+    version: Version,
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRunner {
+    /// Create a new migration runner for the given data directory which migrates towards the current application [VERSION].
+    ///
+    /// * `storage_path` - The data directory of the application.
+    /// * `migrations` - The known migrations of the data directory.
+    pub fn new(storage_path: &str, migrations: Vec<Migration>) -> Self {
+        Self::with_version(
+            storage_path,
+            Version::parse(VERSION).expect("expected the current version to be valid"),
+            migrations,
+        )
+    }
+
+    fn with_version(storage_path: &str, version: Version, mut migrations: Vec<Migration>) -> Self {
+        migrations.sort_by(|a, b| a.version.cmp(&b.version));
+
+        Self {
+            storage: Storage::from(storage_path),
+            storage_path: PathBuf::from(storage_path),
+            version,
+            migrations,
+        }
+    }
+
+    /// Retrieve the version of the data directory which is currently stored.
+    ///
+    /// It returns [None] when no valid version has been stored yet.
+    pub fn stored_version(&self) -> Option<Version> {
+        self.storage
+            .options()
+            .serializer(FILENAME)
+            .read::<DataVersion>()
+            .ok()
+            .and_then(|e| Version::parse(e.version.as_str()).ok())
+    }
+
+    /// Execute the pending migrations of the data directory.
+    ///
+    /// It returns the data version after the migrations have been executed,
+    /// or the [StorageError::MigrationFailed] when one of the migrations failed.
+    pub fn run(&self) -> storage::Result<Version> {
+        let stored_version = self.stored_version().unwrap_or(UNVERSIONED);
+
+        if stored_version > self.version {
+            warn!(
+                "Data directory version {} is newer than the application version {}, skipping migrations",
+                stored_version, self.version
+            );
+            return Ok(stored_version);
+        }
+        if stored_version == self.version {
+            trace!("Data directory is up-to-date with version {}", self.version);
+            return Ok(stored_version);
+        }
+
+        let pending: Vec<&Migration> = self
+            .migrations
+            .iter()
+            .filter(|e| e.version > stored_version && e.version <= self.version)
+            .collect();
+        info!(
+            "Migrating data directory from {} to {} ({} pending migrations)",
+            stored_version,
+            self.version,
+            pending.len()
+        );
+
+        for migration in pending {
+            self.execute(migration)?;
+            self.store_version(&migration.version)?;
+        }
+
+        self.store_version(&self.version)?;
+        info!("Data directory has been migrated to {}", self.version);
+        Ok(self.version.clone())
+    }
+
+    fn execute(&self, migration: &Migration) -> storage::Result<()> {
+        debug!(
+            "Executing data migration {}, {}",
+            migration.version, migration.description
+        );
+        let backup_path = self
+            .storage_path
+            .join(BACKUP_DIRECTORY)
+            .join(migration.version.to_string());
+        self.backup(migration, &backup_path)?;
+
+        match (migration.migrate)(self.storage_path.as_path()) {
+            Ok(_) => {
+                debug!("Data migration {} has been completed", migration.version);
+                if backup_path.exists() {
+                    if let Err(e) = Storage::delete(&backup_path) {
+                        warn!("Failed to remove data migration backup, {}", e);
+                    }
+                }
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    "Data migration {} ({}) failed, {}. The original files have been backed up to {:?}",
+                    migration.version, migration.description, e, backup_path
+                );
+                self.restore(migration, &backup_path);
+                Err(StorageError::MigrationFailed(
+                    migration.version.to_string(),
+                    e,
+                ))
+            }
+        }
+    }
+
+    fn backup(&self, migration: &Migration, backup_path: &Path) -> storage::Result<()> {
+        for file in migration.files.iter() {
+            let source = self.storage_path.join(file);
+            if !source.exists() {
+                trace!(
+                    "Data migration file {:?} doesn't exist, skipping backup",
+                    source
+                );
+                continue;
+            }
+
+            let target = backup_path.join(file);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    StorageError::IO(parent.to_string_lossy().to_string(), e.to_string())
+                })?;
+            }
+
+            trace!("Backing up {:?} to {:?}", source, target);
+            fs::copy(&source, &target).map_err(|e| {
+                StorageError::IO(target.to_string_lossy().to_string(), e.to_string())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn restore(&self, migration: &Migration, backup_path: &Path) {
+        for file in migration.files.iter() {
+            let source = backup_path.join(file);
+            if !source.exists() {
+                continue;
+            }
+
+            let target = self.storage_path.join(file);
+            trace!("Restoring {:?} from {:?}", target, source);
+            if let Err(e) = fs::copy(&source, &target) {
+                error!(
+                    "Failed to restore {:?} from the migration backup, {}",
+                    target, e
+                );
+            }
+        }
+    }
+
+    fn store_version(&self, version: &Version) -> storage::Result<()> {
+        self.storage
+            .options()
+            .serializer(FILENAME)
+            .write(&DataVersion {
+                version: version.to_string(),
+            })
+            .map(|_| trace!("Stored data directory version {}", version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use crate::testing::{copy_test_file, init_logger, read_temp_dir_file_as_string};
+
+    use super::*;
+
+    fn rename_settings(path: &Path) -> Result<(), String> {
+        let source = path.join("settings.json");
+        if source.exists() {
+            fs::rename(source, path.join("settings-v2.json")).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn corrupt_and_fail(path: &Path) -> Result<(), String> {
+        fs::write(path.join("settings.json"), "corrupt").map_err(|e| e.to_string())?;
+        Err("invalid data format".to_string())
+    }
+
+    #[test]
+    fn test_run() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        copy_test_file(temp_path, "settings.json", None);
+        let runner = MigrationRunner::with_version(
+            temp_path,
+            Version::new(1, 0, 0),
+            vec![Migration {
+                version: Version::new(0, 9, 0),
+                description: "rename settings",
+                files: vec!["settings.json"],
+                migrate: rename_settings,
+            }],
+        );
+
+        let result = runner.run();
+
+        assert_eq!(Ok(Version::new(1, 0, 0)), result);
+        assert_eq!(Some(Version::new(1, 0, 0)), runner.stored_version());
+        assert!(
+            temp_dir.path().join("settings-v2.json").exists(),
+            "expected the migration to have been executed"
+        );
+        assert!(
+            !temp_dir
+                .path()
+                .join(BACKUP_DIRECTORY)
+                .join("0.9.0")
+                .exists(),
+            "expected the backup to have been removed"
+        );
+    }
+
+    #[test]
+    fn test_run_already_applied() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        copy_test_file(temp_path, "settings.json", None);
+        let migrations = vec![Migration {
+            version: Version::new(0, 9, 0),
+            description: "rename settings",
+            files: vec!["settings.json"],
+            migrate: rename_settings,
+        }];
+        MigrationRunner::with_version(temp_path, Version::new(0, 9, 0), vec![])
+            .run()
+            .unwrap();
+        let runner = MigrationRunner::with_version(temp_path, Version::new(1, 0, 0), migrations);
+
+        let result = runner.run();
+
+        assert_eq!(Ok(Version::new(1, 0, 0)), result);
+        assert!(
+            temp_dir.path().join("settings.json").exists(),
+            "expected the already applied migration to not have been executed"
+        );
+    }
+
+    #[test]
+    fn test_run_failed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        copy_test_file(temp_path, "settings.json", None);
+        let original = read_temp_dir_file_as_string(&temp_dir, "settings.json");
+        let runner = MigrationRunner::with_version(
+            temp_path,
+            Version::new(1, 0, 0),
+            vec![
+                Migration {
+                    version: Version::new(0, 8, 0),
+                    description: "noop",
+                    files: vec![],
+                    migrate: |_| Ok(()),
+                },
+                Migration {
+                    version: Version::new(0, 9, 0),
+                    description: "reshape settings",
+                    files: vec!["settings.json"],
+                    migrate: corrupt_and_fail,
+                },
+            ],
+        );
+
+        let result = runner.run();
+
+        assert_eq!(
+            Err(StorageError::MigrationFailed(
+                "0.9.0".to_string(),
+                "invalid data format".to_string()
+            )),
+            result
+        );
+        assert_eq!(
+            Some(Version::new(0, 8, 0)),
+            runner.stored_version(),
+            "expected the last successful migration version to have been stored"
+        );
+        assert_eq!(
+            original,
+            read_temp_dir_file_as_string(&temp_dir, "settings.json"),
+            "expected the original file to have been restored"
+        );
+        assert!(
+            temp_dir
+                .path()
+                .join(BACKUP_DIRECTORY)
+                .join("0.9.0")
+                .join("settings.json")
+                .exists(),
+            "expected the backup to have been kept"
+        );
+    }
+
+    #[test]
+    fn test_run_newer_data_version() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        MigrationRunner::with_version(temp_path, Version::new(2, 0, 0), vec![])
+            .run()
+            .unwrap();
+        let runner = MigrationRunner::with_version(temp_path, Version::new(1, 0, 0), vec![]);
+
+        let result = runner.run();
+
+        assert_eq!(Ok(Version::new(2, 0, 0)), result);
+        assert_eq!(Some(Version::new(2, 0, 0)), runner.stored_version());
+    }
+}
@@ -1,5 +1,9 @@
 pub use error::*;
+pub use key_value::*;
+pub use migration::*;
 pub use storage::*;
 
 mod error;
+mod key_value;
+mod migration;
 mod storage;
@@ -759,7 +759,7 @@ mod test {
             base_path: PathBuf::from(temp_path),
         };
         let settings = UiSettings::default();
-        let expected_result = "{\"default_language\":\"en\",\"ui_scale\":{\"value\":1.0},\"start_screen\":\"MOVIES\",\"maximized\":false,\"native_window_enabled\":false}".to_string();
+        let expected_result = "{\"default_language\":\"en\",\"ui_scale\":{\"value\":1.0},\"start_page\":\"MOVIES\",\"poster_quality\":\"MEDIUM\",\"maximized\":false,\"native_window_enabled\":false}".to_string();
 
         let result = storage.options().serializer(filename).write(&settings);
         assert!(result.is_ok(), "expected no error to have occurred");
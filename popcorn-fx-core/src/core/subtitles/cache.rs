@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::{debug, trace};
+
+use crate::core::subtitles::language::SubtitleLanguage;
+use crate::core::subtitles::model::SubtitleInfo;
+
+/// The default duration for which a downloaded subtitle file is cached.
+pub const DEFAULT_SUBTITLE_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// The default maximum number of downloaded subtitle files which are cached.
+pub const DEFAULT_SUBTITLE_CACHE_CAPACITY: usize = 100;
+
+/// The in-memory cache of the subtitle files which have been downloaded.
+///
+/// Files are cached per IMDB ID, season, episode and language, so re-opening the same media
+/// reuses the downloaded file instead of downloading it again.
+/// Entries of which the file has been removed from the disk are invalidated when retrieved.
+#[derive(Debug)]
+pub struct SubtitleCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<SubtitleCacheKey, CacheEntry>>,
+}
+
+impl SubtitleCache {
+    /// Creates a new `SubtitleCache` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - The duration for which a file is cached when expiration is applied.
+    /// * `capacity` - The maximum number of cached files, zero disables the cache.
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity,
+            entries: Default::default(),
+        }
+    }
+
+    /// Retrieve the cached subtitle file path of the given subtitle info.
+    ///
+    /// * `expire` - Indicates if entries older than the ttl should be evicted.
+    ///
+    /// It returns [None] when the subtitle is not cached, has expired or the file no longer exists.
+    pub fn get(&self, info: &SubtitleInfo, expire: bool) -> Option<PathBuf> {
+        let key = SubtitleCacheKey::from_info(info)?;
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(&key) {
+            Some(entry) if expire && entry.cached_at.elapsed() >= self.ttl => {
+                trace!("Subtitle cache entry {:?} has expired", key);
+                entries.remove(&key);
+                None
+            }
+            Some(entry) if !entry.path.exists() => {
+                debug!(
+                    "Subtitle cache entry {:?} file {:?} has been removed",
+                    key, entry.path
+                );
+                entries.remove(&key);
+                None
+            }
+            Some(entry) => {
+                trace!("Subtitle cache hit for {:?}", key);
+                Some(entry.path.clone())
+            }
+            None => None,
+        }
+    }
+
+    /// Store the downloaded subtitle file path of the given subtitle info.
+    /// The oldest entry is evicted when the cache is at its capacity.
+    pub fn insert<P: AsRef<Path>>(&self, info: &SubtitleInfo, path: P) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(key) = SubtitleCacheKey::from_info(info) {
+            let mut entries = self.entries.lock().unwrap();
+
+            if !entries.contains_key(&key) && entries.len() >= self.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.cached_at)
+                    .map(|(key, _)| key.clone());
+
+                if let Some(oldest) = oldest {
+                    trace!("Evicting subtitle cache entry {:?}", oldest);
+                    entries.remove(&oldest);
+                }
+            }
+
+            trace!("Caching subtitle file {:?} for {:?}", path.as_ref(), key);
+            entries.insert(
+                key,
+                CacheEntry {
+                    path: path.as_ref().to_path_buf(),
+                    cached_at: Instant::now(),
+                },
+            );
+        }
+    }
+
+    /// Remove all cached subtitle files from the cache.
+    /// This doesn't remove the files from the disk.
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        debug!("Clearing {} cached subtitle files", entries.len());
+        entries.clear();
+    }
+
+    /// The number of cached subtitle files.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Verify if no subtitle files are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for SubtitleCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBTITLE_CACHE_TTL, DEFAULT_SUBTITLE_CACHE_CAPACITY)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SubtitleCacheKey {
+    imdb_id: String,
+    season: Option<u32>,
+    episode: Option<u32>,
+    language: SubtitleLanguage,
+}
+
+impl SubtitleCacheKey {
+    /// Create the cache key of the given subtitle info.
+    /// Special subtitles and subtitles without an IMDB ID can't be cached.
+    fn from_info(info: &SubtitleInfo) -> Option<Self> {
+        if info.is_special() {
+            return None;
+        }
+
+        info.imdb_id().map(|imdb_id| Self {
+            imdb_id: imdb_id.clone(),
+            season: info.season().cloned(),
+            episode: info.episode().cloned(),
+            language: info.language().clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    path: PathBuf,
+    cached_at: Instant,
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use tempfile::tempdir;
+
+    use crate::testing::{copy_test_file, init_logger};
+
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = copy_test_file(temp_path, "example.srt", None);
+        let info = create_episode_info(1, 2, SubtitleLanguage::English);
+        let cache = SubtitleCache::default();
+
+        cache.insert(&info, &filepath);
+        let result = cache.get(&info, true);
+
+        assert_eq!(Some(PathBuf::from(filepath)), result);
+        assert_eq!(
+            None,
+            cache.get(&create_episode_info(1, 3, SubtitleLanguage::English), true)
+        );
+        assert_eq!(
+            None,
+            cache.get(&create_episode_info(1, 2, SubtitleLanguage::French), true)
+        );
+    }
+
+    #[test]
+    fn test_get_expired() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = copy_test_file(temp_path, "example.srt", None);
+        let info = create_episode_info(1, 2, SubtitleLanguage::English);
+        let cache = SubtitleCache::new(Duration::from_millis(50), 10);
+
+        cache.insert(&info, &filepath);
+        thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(
+            Some(PathBuf::from(&filepath)),
+            cache.get(&info, false),
+            "expected the entry to be kept when expiration is not applied"
+        );
+        assert_eq!(None, cache.get(&info, true));
+        assert_eq!(0, cache.len());
+    }
+
+    #[test]
+    fn test_get_file_removed() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = copy_test_file(temp_path, "example.srt", None);
+        let info = create_episode_info(1, 2, SubtitleLanguage::English);
+        let cache = SubtitleCache::default();
+
+        cache.insert(&info, &filepath);
+        std::fs::remove_file(&filepath).unwrap();
+        let result = cache.get(&info, true);
+
+        assert_eq!(None, result);
+        assert_eq!(0, cache.len());
+    }
+
+    #[test]
+    fn test_insert_capacity() {
+        init_logger();
+        let cache = SubtitleCache::new(DEFAULT_SUBTITLE_CACHE_TTL, 2);
+
+        cache.insert(
+            &create_episode_info(1, 1, SubtitleLanguage::English),
+            "a.srt",
+        );
+        thread::sleep(Duration::from_millis(5));
+        cache.insert(
+            &create_episode_info(1, 2, SubtitleLanguage::English),
+            "b.srt",
+        );
+        thread::sleep(Duration::from_millis(5));
+        cache.insert(
+            &create_episode_info(1, 3, SubtitleLanguage::English),
+            "c.srt",
+        );
+
+        let entries = cache.entries.lock().unwrap();
+        assert_eq!(2, entries.len());
+        assert!(
+            !entries.values().any(|e| e.path == PathBuf::from("a.srt")),
+            "expected the oldest entry to have been evicted"
+        );
+    }
+
+    #[test]
+    fn test_insert_special_subtitle() {
+        let cache = SubtitleCache::default();
+
+        cache.insert(&SubtitleInfo::none(), "lorem.srt");
+
+        assert_eq!(0, cache.len());
+    }
+
+    #[test]
+    fn test_clear() {
+        let cache = SubtitleCache::default();
+
+        cache.insert(
+            &create_episode_info(1, 1, SubtitleLanguage::English),
+            "a.srt",
+        );
+        cache.clear();
+
+        assert_eq!(0, cache.len());
+    }
+
+    fn create_episode_info(season: u32, episode: u32, language: SubtitleLanguage) -> SubtitleInfo {
+        SubtitleInfo::builder()
+            .imdb_id("tt2861424")
+            .season(season)
+            .episode(episode)
+            .language(language)
+            .build()
+    }
+}
@@ -2,6 +2,9 @@ use std::cmp::Ordering;
 
 use derive_more::Display;
 
+/// A character of a subtitle line, together with its italic, bold and underline styling.
+type StyledChar = (char, bool, bool, bool);
+
 /// A parsed subtitle cue line from a subtitle file.
 #[derive(Debug, Display, Clone, Eq, PartialEq)]
 #[display(
@@ -16,6 +19,8 @@ pub struct SubtitleCue {
     start_time: u64,
     end_time: u64,
     lines: Vec<SubtitleLine>,
+    /// The comments which precede the cue within the subtitle file, such as VTT `NOTE` blocks.
+    notes: Vec<String>,
 }
 
 impl SubtitleCue {
@@ -25,6 +30,7 @@ impl SubtitleCue {
             start_time,
             end_time,
             lines,
+            notes: vec![],
         }
     }
 
@@ -43,6 +49,65 @@ impl SubtitleCue {
     pub fn lines(&self) -> &Vec<SubtitleLine> {
         &self.lines
     }
+
+    /// The comments which precede the cue within the subtitle file.
+    pub fn notes(&self) -> &Vec<String> {
+        &self.notes
+    }
+
+    /// Shift the timing of the cue by the given offset in millis.
+    /// The start and end time of the cue are clamped at `0` when the offset is negative.
+    pub fn shift(&self, offset: i64) -> SubtitleCue {
+        let shift = |time: u64| {
+            if offset < 0 {
+                time.saturating_sub(offset.unsigned_abs())
+            } else {
+                time.saturating_add(offset as u64)
+            }
+        };
+
+        SubtitleCue {
+            id: self.id.clone(),
+            start_time: shift(self.start_time),
+            end_time: shift(self.end_time),
+            lines: self.lines.clone(),
+            notes: self.notes.clone(),
+        }
+    }
+
+    /// Wrap the lines of the cue to the given maximum number of characters per line.
+    /// Each existing line of the cue is wrapped separately, which respects the manual line breaks within the cue.
+    /// Lines are wrapped on word boundaries, or on character boundaries for text without spaces, such as CJK text.
+    ///
+    /// When the wrapped cue exceeds `max_lines`, the line length is increased until the text fits within the lines.
+    /// A `max_lines` of `0` doesn't limit the number of lines.
+    pub fn wrap(&self, max_line_length: usize, max_lines: usize) -> SubtitleCue {
+        let longest_line = self.lines.iter().map(|e| e.len()).max().unwrap_or(0);
+        let mut line_length = max_line_length.max(1);
+
+        loop {
+            let lines: Vec<SubtitleLine> = self
+                .lines
+                .iter()
+                .flat_map(|e| e.wrap(line_length))
+                .collect();
+
+            if max_lines == 0
+                || lines.len() <= max_lines.max(self.lines.len())
+                || line_length >= longest_line
+            {
+                return SubtitleCue {
+                    id: self.id.clone(),
+                    start_time: self.start_time,
+                    end_time: self.end_time,
+                    lines,
+                    notes: self.notes.clone(),
+                };
+            }
+
+            line_length += 1;
+        }
+    }
 }
 
 impl PartialOrd<Self> for SubtitleCue {
@@ -63,6 +128,7 @@ pub struct SubtitleCueBuilder {
     start_time: u64,
     end_time: u64,
     lines: Vec<SubtitleLine>,
+    notes: Vec<String>,
 }
 
 impl SubtitleCueBuilder {
@@ -72,16 +138,18 @@ impl SubtitleCueBuilder {
             start_time: 0,
             end_time: 0,
             lines: vec![],
+            notes: vec![],
         }
     }
 
     pub fn build(&self) -> SubtitleCue {
-        SubtitleCue::new(
-            self.id.clone(),
-            self.start_time.clone(),
-            self.end_time.clone(),
-            self.lines.clone(),
-        )
+        SubtitleCue {
+            id: self.id.clone(),
+            start_time: self.start_time.clone(),
+            end_time: self.end_time.clone(),
+            lines: self.lines.clone(),
+            notes: self.notes.clone(),
+        }
     }
 
     pub fn id(&mut self, id: String) -> &mut Self {
@@ -103,6 +171,11 @@ impl SubtitleCueBuilder {
         self.lines.push(line);
         self
     }
+
+    pub fn add_note(&mut self, note: String) -> &mut Self {
+        self.notes.push(note);
+        self
+    }
 }
 
 /// The subtitle line which is a new line within a subtitle
@@ -120,6 +193,97 @@ impl SubtitleLine {
     pub fn texts(&self) -> &Vec<StyledText> {
         &self.texts
     }
+
+    /// The total number of characters within the line.
+    fn len(&self) -> usize {
+        self.texts.iter().map(|e| e.text.chars().count()).sum()
+    }
+
+    /// Wrap the line into multiple lines of at most `max_line_length` characters.
+    /// Words which are longer than the max line length are split over multiple lines.
+    fn wrap(&self, max_line_length: usize) -> Vec<SubtitleLine> {
+        if self.len() <= max_line_length {
+            return vec![self.clone()];
+        }
+
+        let chars: Vec<StyledChar> = self
+            .texts
+            .iter()
+            .flat_map(|text| {
+                text.text
+                    .chars()
+                    .map(move |c| (c, text.italic, text.bold, text.underline))
+            })
+            .collect();
+        let mut lines = vec![];
+        let mut start = 0;
+
+        while chars.len() - start > max_line_length {
+            let end = start + max_line_length;
+            // search for the last break opportunity within the allowed line length,
+            // if none is found, the line is split at the max line length
+            let break_index = (start + 1..=end)
+                .rev()
+                .find(|index| Self::is_break_opportunity(&chars, *index))
+                .unwrap_or(end);
+
+            lines.push(Self::from_styled_chars(&chars[start..break_index]));
+            start = break_index;
+            while start < chars.len() && chars[start].0.is_whitespace() {
+                start += 1;
+            }
+        }
+
+        if start < chars.len() {
+            lines.push(Self::from_styled_chars(&chars[start..]));
+        }
+
+        lines
+    }
+
+    fn is_break_opportunity(chars: &[StyledChar], index: usize) -> bool {
+        let previous = chars[index - 1].0;
+        let current = chars[index].0;
+
+        current.is_whitespace()
+            || (!previous.is_whitespace() && (Self::is_cjk(previous) || Self::is_cjk(current)))
+    }
+
+    /// Verify if the given character is a CJK character, which can be wrapped without any spaces.
+    fn is_cjk(c: char) -> bool {
+        matches!(c,
+            '\u{1100}'..='\u{11FF}' // Hangul Jamo
+            | '\u{2E80}'..='\u{2FDF}' // CJK radicals
+            | '\u{3000}'..='\u{30FF}' // CJK symbols & punctuation, Hiragana and Katakana
+            | '\u{3100}'..='\u{31FF}' // Bopomofo, Hangul compatibility Jamo and Katakana extensions
+            | '\u{3400}'..='\u{4DBF}' // CJK unified ideographs extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+            | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+            | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+            | '\u{FF00}'..='\u{FFEF}' // Halfwidth and fullwidth forms
+        )
+    }
+
+    fn from_styled_chars(chars: &[StyledChar]) -> Self {
+        let mut texts: Vec<StyledText> = vec![];
+
+        for (c, italic, bold, underline) in chars.iter().cloned() {
+            match texts.last_mut() {
+                Some(e) if e.italic == italic && e.bold == bold && e.underline == underline => {
+                    e.text.push(c)
+                }
+                _ => texts.push(StyledText::new(c.to_string(), italic, bold, underline)),
+            }
+        }
+
+        // remove the trailing whitespace of the line
+        if let Some(text) = texts.last_mut() {
+            let length = text.text.trim_end().len();
+            text.text.truncate(length);
+        }
+
+        Self::new(texts)
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -156,3 +320,136 @@ impl StyledText {
         &self.underline
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cue_shift() {
+        let cue = SubtitleCue::new("1".to_string(), 1000, 3000, vec![]);
+
+        assert_eq!(
+            SubtitleCue::new("1".to_string(), 2500, 4500, vec![]),
+            cue.shift(1500)
+        );
+        assert_eq!(
+            SubtitleCue::new("1".to_string(), 0, 1000, vec![]),
+            cue.shift(-2000)
+        );
+    }
+
+    #[test]
+    fn test_cue_wrap() {
+        let text = "The quick brown fox jumps over the lazy dog while the curious cat watches closely from the old wooden fence at daybreak.";
+        let cue = SubtitleCue::new(
+            "1".to_string(),
+            1000,
+            5000,
+            vec![SubtitleLine::new(vec![StyledText::new(
+                text.to_string(),
+                false,
+                false,
+                false,
+            )])],
+        );
+
+        let result = cue.wrap(42, 3);
+
+        assert_eq!(
+            vec![
+                "The quick brown fox jumps over the lazy",
+                "dog while the curious cat watches closely",
+                "from the old wooden fence at daybreak.",
+            ],
+            line_texts(&result)
+        );
+    }
+
+    #[test]
+    fn test_cue_wrap_max_lines() {
+        let text = "The quick brown fox jumps over the lazy dog while the curious cat watches closely from the old wooden fence at daybreak.";
+        let cue = SubtitleCue::new(
+            "1".to_string(),
+            1000,
+            5000,
+            vec![SubtitleLine::new(vec![StyledText::new(
+                text.to_string(),
+                false,
+                false,
+                false,
+            )])],
+        );
+
+        let result = cue.wrap(42, 2);
+
+        assert_eq!(2, result.lines().len());
+        assert_eq!(text, line_texts(&result).join(" "));
+    }
+
+    #[test]
+    fn test_cue_wrap_respect_existing_lines() {
+        let cue = SubtitleCue::new(
+            "1".to_string(),
+            1000,
+            5000,
+            vec![
+                SubtitleLine::new(vec![StyledText::new(
+                    "Lorem ipsum".to_string(),
+                    false,
+                    false,
+                    false,
+                )]),
+                SubtitleLine::new(vec![
+                    StyledText::new("dolor sit amet, ".to_string(), true, false, false),
+                    StyledText::new("consectetur".to_string(), false, false, false),
+                ]),
+            ],
+        );
+
+        let result = cue.wrap(20, 0);
+
+        assert_eq!(
+            vec!["Lorem ipsum", "dolor sit amet,", "consectetur"],
+            line_texts(&result)
+        );
+        assert_eq!(
+            &vec![StyledText::new(
+                "dolor sit amet,".to_string(),
+                true,
+                false,
+                false
+            )],
+            result.lines().get(1).unwrap().texts()
+        );
+    }
+
+    #[test]
+    fn test_cue_wrap_cjk() {
+        let cue = SubtitleCue::new(
+            "1".to_string(),
+            1000,
+            5000,
+            vec![SubtitleLine::new(vec![StyledText::new(
+                "今日はとても良い天気ですね散歩に行きましょう".to_string(),
+                false,
+                false,
+                false,
+            )])],
+        );
+
+        let result = cue.wrap(10, 3);
+
+        assert_eq!(
+            vec!["今日はとても良い天気", "ですね散歩に行きまし", "ょう"],
+            line_texts(&result)
+        );
+    }
+
+    fn line_texts(cue: &SubtitleCue) -> Vec<String> {
+        cue.lines()
+            .iter()
+            .map(|line| line.texts().iter().map(|e| e.text().as_str()).collect())
+            .collect()
+    }
+}
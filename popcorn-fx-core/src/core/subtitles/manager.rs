@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 use std::ops::DerefMut;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -9,7 +10,7 @@ use log::{debug, error, info, trace};
 use tokio::sync::Mutex;
 
 use crate::core::{block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks};
-use crate::core::config::ApplicationConfig;
+use crate::core::config::{ApplicationConfig, TvModeSubtitle};
 use crate::core::events::{DEFAULT_ORDER, Event, EventPublisher};
 use crate::core::storage::Storage;
 use crate::core::subtitles::language::SubtitleLanguage;
@@ -121,6 +122,13 @@ impl DefaultSubtitleManager {
 
         Self { inner: instance }
     }
+
+    /// Indicates if the application runs in TV or kiosk mode.
+    /// The [SubtitleSettings::tv_mode_subtitle] default is only applied while this mode is enabled.
+    pub fn with_tv_mode(self, tv_mode: bool) -> Self {
+        self.inner.tv_mode.store(tv_mode, Ordering::Relaxed);
+        self
+    }
 }
 
 impl Callbacks<SubtitleEvent> for DefaultSubtitleManager {
@@ -184,6 +192,8 @@ struct InnerSubtitleManager {
     preferred_language: Arc<Mutex<SubtitleLanguage>>,
     /// Indicates if the subtitle has been disabled by the user.
     disabled_by_user: Mutex<bool>,
+    /// Indicates if the application runs in TV or kiosk mode.
+    tv_mode: AtomicBool,
     /// Callbacks for handling subtitle events.
     callbacks: CoreCallbacks<SubtitleEvent>,
     /// Application settings.
@@ -201,6 +211,7 @@ impl InnerSubtitleManager {
             subtitle_info: Arc::new(Mutex::new(None)),
             preferred_language: Arc::new(Mutex::new(SubtitleLanguage::None)),
             disabled_by_user: Mutex::new(false),
+            tv_mode: Default::default(),
             callbacks: Default::default(),
             settings,
         }
@@ -251,6 +262,27 @@ impl InnerSubtitleManager {
         self.reset_subtitle_info();
     }
 
+    /// Find the subtitle for the TV mode default of the application.
+    /// This uses the [SubtitleSettings::tv_mode_subtitle] setting, which is only applied in TV mode
+    /// when the user didn't configure a default subtitle language or disabled the subtitle.
+    fn find_for_tv_mode(&self, subtitles: &[SubtitleInfo]) -> Option<SubtitleInfo> {
+        if !self.tv_mode.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let settings = self.settings.user_settings();
+        let subtitle_settings = settings.subtitle();
+        if subtitle_settings.tv_mode_subtitle == TvModeSubtitle::UserPreference
+            || subtitle_settings.default_subtitle() != &SubtitleLanguage::None
+            || self.is_disabled()
+        {
+            return None;
+        }
+
+        trace!("Selecting subtitle for the interface language in TV mode");
+        self.find_for_interface_language(subtitles)
+    }
+
     /// Find the subtitle for the default configured subtitle language.
     /// This uses the [SubtitleSettings::default_subtitle] setting.
     fn find_for_default_subtitle_language(
@@ -387,7 +419,8 @@ impl SubtitleManager for InnerSubtitleManager {
     fn select_or_default(&self, subtitles: &[SubtitleInfo]) -> SubtitleInfo {
         trace!("Selecting subtitle out of {:?}", subtitles);
         let subtitle = self
-            .find_for_default_subtitle_language(subtitles)
+            .find_for_tv_mode(subtitles)
+            .or_else(|| self.find_for_default_subtitle_language(subtitles))
             .or_else(|| self.find_for_interface_language(subtitles))
             .unwrap_or(SubtitleInfo::none());
         debug!("Selected subtitle {:?}", &subtitle);
@@ -450,9 +483,8 @@ mod test {
 
     use tempfile::tempdir;
 
-    use crate::core::config::{DecorationType, PopcornProperties, PopcornSettings, SubtitleFamily, SubtitleSettings, UiScale, UiSettings};
+    use crate::core::config::{DecorationType, PopcornProperties, PopcornSettings, PosterQuality, StartPage, SubtitleFamily, SubtitleSettings, TvModeSubtitle, UiScale, UiSettings};
     use crate::core::events::{LOWEST_ORDER, PlayerStoppedEvent};
-    use crate::core::media::Category;
     use crate::core::subtitles::language::SubtitleLanguage::English;
     use crate::testing::{copy_test_file, init_logger};
 
@@ -484,6 +516,7 @@ mod test {
         event_publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
             url: "http://localhost/my-video".to_string(),
             media: None,
+            parent_media: None,
             time: Some(12000),
             duration: Some(47000),
         }));
@@ -642,7 +675,8 @@ mod test {
         settings.update_ui(UiSettings {
             default_language: "fr".to_string(),
             ui_scale: UiScale::new(1.0).unwrap(),
-            start_screen: Category::Movies,
+            start_page: StartPage::Movies,
+            poster_quality: PosterQuality::Medium,
             maximized: false,
             native_window_enabled: false,
         });
@@ -659,6 +693,102 @@ mod test {
         assert_eq!(subtitle_info, result)
     }
 
+    #[test]
+    fn test_select_or_default_tv_mode() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = tv_mode_settings(temp_path, SubtitleLanguage::None);
+        let manager =
+            DefaultSubtitleManager::new(settings.clone(), Arc::new(EventPublisher::default()))
+                .with_tv_mode(true);
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("ipsum")
+            .language(SubtitleLanguage::French)
+            .build();
+        let subtitles: Vec<SubtitleInfo> = vec![SubtitleInfo::none(), subtitle_info.clone()];
+
+        let result = manager.select_or_default(&subtitles);
+
+        assert_eq!(subtitle_info, result);
+        assert_eq!(
+            &SubtitleLanguage::None,
+            settings.user_settings().subtitle().default_subtitle(),
+            "expected the tv mode default to not have been stored"
+        );
+    }
+
+    #[test]
+    fn test_select_or_default_tv_mode_disabled() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = tv_mode_settings(temp_path, SubtitleLanguage::None);
+        let tv_manager =
+            DefaultSubtitleManager::new(settings.clone(), Arc::new(EventPublisher::default()))
+                .with_tv_mode(true);
+        let manager = DefaultSubtitleManager::new(settings, Arc::new(EventPublisher::default()));
+        let subtitles: Vec<SubtitleInfo> = vec![
+            SubtitleInfo::none(),
+            SubtitleInfo::builder()
+                .imdb_id("ipsum")
+                .language(SubtitleLanguage::French)
+                .build(),
+        ];
+
+        let _ = tv_manager.select_or_default(&subtitles);
+        let result = manager.select_or_default(&subtitles);
+
+        assert_eq!(SubtitleInfo::none(), result);
+    }
+
+    #[test]
+    fn test_select_or_default_tv_mode_user_preference() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = tv_mode_settings(temp_path, SubtitleLanguage::English);
+        let manager = DefaultSubtitleManager::new(settings, Arc::new(EventPublisher::default()))
+            .with_tv_mode(true);
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("lorem")
+            .language(SubtitleLanguage::English)
+            .build();
+        let subtitles: Vec<SubtitleInfo> = vec![
+            SubtitleInfo::builder()
+                .imdb_id("ipsum")
+                .language(SubtitleLanguage::French)
+                .build(),
+            subtitle_info.clone(),
+        ];
+
+        let result = manager.select_or_default(&subtitles);
+
+        assert_eq!(subtitle_info, result);
+    }
+
+    #[test]
+    fn test_select_or_default_tv_mode_disabled_by_user() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = tv_mode_settings(temp_path, SubtitleLanguage::None);
+        let manager = DefaultSubtitleManager::new(settings, Arc::new(EventPublisher::default()))
+            .with_tv_mode(true);
+        let subtitles: Vec<SubtitleInfo> = vec![
+            SubtitleInfo::none(),
+            SubtitleInfo::builder()
+                .imdb_id("ipsum")
+                .language(SubtitleLanguage::French)
+                .build(),
+        ];
+
+        manager.disable_subtitle();
+        let result = manager.select_or_default(&subtitles);
+
+        assert_eq!(SubtitleInfo::none(), result);
+    }
+
     #[test]
     fn test_drop_cleanup_subtitles() {
         init_logger();
@@ -683,6 +813,25 @@ mod test {
         );
     }
 
+    fn tv_mode_settings(
+        temp_path: &str,
+        default_subtitle: SubtitleLanguage,
+    ) -> Arc<ApplicationConfig> {
+        let settings = default_settings(temp_path, false);
+        let mut subtitle_settings = settings.user_settings().subtitle().clone();
+        subtitle_settings.default_subtitle = default_subtitle;
+        settings.update_subtitle(subtitle_settings);
+        settings.update_ui(UiSettings {
+            default_language: "fr".to_string(),
+            ui_scale: UiScale::new(1.0).unwrap(),
+            start_page: StartPage::Movies,
+            poster_quality: PosterQuality::Medium,
+            maximized: false,
+            native_window_enabled: false,
+        });
+        settings
+    }
+
     fn default_settings(temp_path: &str, auto_cleaning_enabled: bool) -> Arc<ApplicationConfig> {
         Arc::new(
             ApplicationConfig::builder()
@@ -697,12 +846,18 @@ mod test {
                         font_size: 28,
                         decoration: DecorationType::None,
                         bold: false,
+                        max_line_length: 0,
+                        max_lines: 2,
+                        tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+                        api_key: None,
+                        user_token: None,
                     },
                     ui_settings: Default::default(),
                     server_settings: Default::default(),
                     torrent_settings: Default::default(),
                     playback_settings: Default::default(),
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         )
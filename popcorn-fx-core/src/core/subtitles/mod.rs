@@ -1,8 +1,11 @@
+pub use cache::*;
 pub use error::*;
 pub use manager::*;
 pub use provider::*;
 pub use server::*;
 pub use subtitle_file::*;
+#[cfg(feature = "subtitle-sync")]
+pub use sync::*;
 
 pub mod cue;
 pub mod language;
@@ -10,8 +13,11 @@ pub mod matcher;
 pub mod model;
 pub mod parsers;
 
+mod cache;
 mod error;
 mod manager;
 mod provider;
 mod server;
 mod subtitle_file;
+#[cfg(feature = "subtitle-sync")]
+mod sync;
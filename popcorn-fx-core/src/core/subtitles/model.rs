@@ -1,6 +1,8 @@
 extern crate derive_more;
 
 use std::cmp::Ordering;
+#[cfg(feature = "subtitle-sync")]
+use std::path::Path;
 use std::path::PathBuf;
 
 use derive_more::Display;
@@ -14,12 +16,19 @@ use crate::core::subtitles::error::{SubtitleError, SubtitleParseError};
 use crate::core::subtitles::language::SubtitleLanguage;
 use crate::core::subtitles::matcher::SubtitleMatcher;
 use crate::core::subtitles::SubtitleFile;
+#[cfg(feature = "subtitle-sync")]
+use crate::core::subtitles::{estimate_offset, AutoSyncResult};
 
 const SRT_EXTENSION: &str = "srt";
 const VTT_EXTENSION: &str = "vtt";
+const TXT_EXTENSION: &str = "txt";
 const NORMALIZATION_PATTERN: &str = "[\\.\\[\\]\\(\\)_\\-+]";
 
-const SUBTITLE_TYPES: [SubtitleType; 2] = [SubtitleType::Srt, SubtitleType::Vtt];
+const SUBTITLE_TYPES: [SubtitleType; 3] = [
+    SubtitleType::Srt,
+    SubtitleType::Vtt,
+    SubtitleType::PlainText,
+];
 
 /// The type of a subtitle, indicating its format.
 #[repr(i32)]
@@ -29,6 +38,9 @@ pub enum SubtitleType {
     Srt = 0,
     /// WebVTT subtitle format.
     Vtt = 1,
+    /// Plain text transcript of the subtitle dialogue, without any timing information.
+    /// This is an output only format, it can't be parsed back into cues.
+    PlainText = 2,
 }
 
 impl SubtitleType {
@@ -42,7 +54,11 @@ impl SubtitleType {
     ///
     /// The corresponding `SubtitleType` if found, or an error if the extension is not supported.
     pub fn from_extension(extension: &String) -> Result<SubtitleType, SubtitleParseError> {
-        for subtitle in SUBTITLE_TYPES {
+        // plain text transcripts don't contain any cue timings, so they can't be parsed
+        for subtitle in SUBTITLE_TYPES
+            .into_iter()
+            .filter(|e| e != &SubtitleType::PlainText)
+        {
             if extension == &subtitle.extension() {
                 return Ok(subtitle);
             }
@@ -73,6 +89,7 @@ impl SubtitleType {
         match self {
             SubtitleType::Srt => SRT_EXTENSION.to_string(),
             SubtitleType::Vtt => VTT_EXTENSION.to_string(),
+            SubtitleType::PlainText => TXT_EXTENSION.to_string(),
         }
     }
 
@@ -85,6 +102,7 @@ impl SubtitleType {
         match self {
             SubtitleType::Srt => "text/srt",
             SubtitleType::Vtt => "text/vtt",
+            SubtitleType::PlainText => "text/plain",
         }
     }
 }
@@ -119,6 +137,10 @@ impl SubtitleType {
 pub struct SubtitleInfo {
     /// The IMDB ID of the subtitle title.
     imdb_id: Option<String>,
+    /// The season number of the subtitle title, if it's an episode.
+    season: Option<u32>,
+    /// The episode number of the subtitle title, if it's an episode.
+    episode: Option<u32>,
     /// The language of the subtitle.
     language: SubtitleLanguage,
     /// The list of available subtitle files.
@@ -157,6 +179,16 @@ impl SubtitleInfo {
         }
     }
 
+    /// Retrieves the season number of the subtitle, if it's an episode.
+    pub fn season(&self) -> Option<&u32> {
+        self.season.as_ref()
+    }
+
+    /// Retrieves the episode number of the subtitle, if it's an episode.
+    pub fn episode(&self) -> Option<&u32> {
+        self.episode.as_ref()
+    }
+
     /// Retrieves the language of the subtitle.
     pub fn language(&self) -> &SubtitleLanguage {
         &self.language
@@ -321,6 +353,8 @@ impl Ord for SubtitleInfo {
 #[derive(Debug, Default)]
 pub struct SubtitleInfoBuilder {
     imdb_id: Option<String>,
+    season: Option<u32>,
+    episode: Option<u32>,
     language: Option<SubtitleLanguage>,
     files: Option<Vec<SubtitleFile>>,
 }
@@ -337,6 +371,18 @@ impl SubtitleInfoBuilder {
         self
     }
 
+    /// Sets the season number for the subtitle info.
+    pub fn season(mut self, season: u32) -> Self {
+        self.season = Some(season);
+        self
+    }
+
+    /// Sets the episode number for the subtitle info.
+    pub fn episode(mut self, episode: u32) -> Self {
+        self.episode = Some(episode);
+        self
+    }
+
     /// Sets the language for the subtitle info.
     pub fn language(mut self, language: SubtitleLanguage) -> Self {
         self.language = Some(language);
@@ -357,6 +403,8 @@ impl SubtitleInfoBuilder {
     pub fn build(self) -> SubtitleInfo {
         SubtitleInfo {
             imdb_id: self.imdb_id,
+            season: self.season,
+            episode: self.episode,
             language: self.language.expect("language is not set"),
             files: self.files,
             normalize_regex: Regex::new(NORMALIZATION_PATTERN).unwrap(),
@@ -400,6 +448,48 @@ impl Subtitle {
     pub fn file(&self) -> &str {
         self.file.as_str()
     }
+
+    /// Wrap the lines of all cues within the subtitle.
+    /// See [SubtitleCue::wrap] for more info.
+    pub fn wrap(self, max_line_length: usize, max_lines: usize) -> Self {
+        let cues = self
+            .cues
+            .iter()
+            .map(|e| e.wrap(max_line_length, max_lines))
+            .collect();
+
+        Self {
+            cues,
+            info: self.info,
+            file: self.file,
+        }
+    }
+
+    /// Try to correct a constant offset of the subtitle against the audio of the media.
+    /// The reference should be a 16-bit PCM WAV file of the media's audio track.
+    ///
+    /// When no reliable offset could be determined, the cues are left untouched
+    /// and [AutoSyncResult::Inconclusive] is returned.
+    #[cfg(feature = "subtitle-sync")]
+    pub fn auto_sync(&mut self, reference: &Path) -> subtitles::Result<AutoSyncResult> {
+        debug!(
+            "Auto syncing subtitle {} against {:?}",
+            self.file, reference
+        );
+        let result = estimate_offset(&self.cues, reference)?;
+
+        match &result {
+            AutoSyncResult::Synced { offset, .. } => {
+                info!("Shifting subtitle {} by {}ms", self.file, offset);
+                self.cues = self.cues.iter().map(|e| e.shift(*offset)).collect();
+            }
+            AutoSyncResult::Inconclusive { .. } => {
+                warn!("Auto sync of subtitle {} was inconclusive", self.file)
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 impl PartialEq for Subtitle {
@@ -410,6 +500,8 @@ impl PartialEq for Subtitle {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "subtitle-sync")]
+    use crate::core::subtitles::sync;
     use crate::testing::init_logger;
 
     use super::*;
@@ -528,6 +620,18 @@ mod test {
         assert_eq!(SubtitleType::Vtt, result.unwrap());
     }
 
+    #[test]
+    fn test_subtitle_type_extension_plain_text() {
+        let extension = "txt".to_string();
+
+        let result = SubtitleType::from_extension(&extension);
+
+        assert_eq!(
+            Err(SubtitleParseError::ExtensionNotSupported(extension)),
+            result
+        );
+    }
+
     #[test]
     fn test_subtitle_type_when_extension_not_support_should_return_error() {
         let extension = "lorem".to_string();
@@ -676,4 +780,53 @@ mod test {
 
         assert_eq!(expected_file, result)
     }
+
+    #[cfg(feature = "subtitle-sync")]
+    #[test]
+    fn test_subtitle_auto_sync() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reference = temp_dir.path().join("audio.wav");
+        let cues = sync::test::create_cues();
+        let mut subtitle = Subtitle::new(cues.clone(), None, "lorem.srt".to_string());
+        sync::test::write_wav(
+            &reference,
+            &sync::test::speech_intervals(&cues, 2000),
+            25_000,
+        );
+
+        let result = subtitle.auto_sync(&reference).unwrap();
+
+        assert!(
+            matches!(result, AutoSyncResult::Synced { offset: 2000, .. }),
+            "expected the subtitle to have been synced, but got {:?} instead",
+            result
+        );
+        assert_eq!(
+            cues.iter()
+                .map(|e| e.shift(2000))
+                .collect::<Vec<SubtitleCue>>(),
+            subtitle.cues().clone()
+        );
+    }
+
+    #[cfg(feature = "subtitle-sync")]
+    #[test]
+    fn test_subtitle_auto_sync_inconclusive() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reference = temp_dir.path().join("audio.wav");
+        let cues = sync::test::create_cues();
+        let mut subtitle = Subtitle::new(cues.clone(), None, "lorem.srt".to_string());
+        sync::test::write_wav(&reference, &[], 25_000);
+
+        let result = subtitle.auto_sync(&reference).unwrap();
+
+        assert!(
+            matches!(result, AutoSyncResult::Inconclusive { .. }),
+            "expected the auto sync to be inconclusive, but got {:?} instead",
+            result
+        );
+        assert_eq!(&cues, subtitle.cues());
+    }
 }
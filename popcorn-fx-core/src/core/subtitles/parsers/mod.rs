@@ -5,10 +5,12 @@ use crate::core::subtitles::cue::SubtitleCue;
 use crate::core::subtitles::error::SubtitleParseError;
 pub use crate::core::subtitles::parsers::srt::SrtParser;
 pub use crate::core::subtitles::parsers::style_parser::StyleParser;
+pub use crate::core::subtitles::parsers::transcript::TranscriptParser;
 pub use crate::core::subtitles::parsers::vtt::VttParser;
 
 mod srt;
 mod style_parser;
+mod transcript;
 mod vtt;
 
 const NEWLINE: &str = "\n";
@@ -0,0 +1,200 @@
+use std::fs::File;
+
+use log::{debug, trace, warn};
+use regex::Regex;
+
+use crate::core::subtitles::cue::SubtitleCue;
+use crate::core::subtitles::error::SubtitleParseError;
+use crate::core::subtitles::parsers::{Parser, NEWLINE};
+
+const ELLIPSIS: &str = "...";
+const TAG_PATTERN: &str = "<[^>]*>|\\{[^}]*\\}";
+const DIALOGUE_DASH_PATTERN: &str = "^[-\u{2010}\u{2013}\u{2014}]+\\s*";
+const SPEAKER_PATTERN: &str = "^(?:[\\[(][\\p{Lu}\\d .'-]+[\\])]|\\p{Lu}[\\p{Lu}\\d .'-]+:)\\s*";
+const SENTENCE_TERMINATORS: [char; 7] = ['.', '!', '?', '\u{2026}', '"', '\u{201d}', '\u{266a}'];
+
+/// A parser which converts subtitle cues into a plain text transcript of the dialogue.
+///
+/// The transcript contains one paragraph per cue, without any timing information.
+/// Styling tags and speaker markers are stripped, consecutive duplicate cues are removed
+/// and cues which continue the sentence of the previous cue are collapsed into a single paragraph.
+#[derive(Debug)]
+pub struct TranscriptParser {
+    tag_regex: Regex,
+    dialogue_dash_regex: Regex,
+    speaker_regex: Regex,
+}
+
+impl TranscriptParser {
+    /// Retrieve the transcript text of the given cue.
+    /// The lines of the cue are joined into a single line.
+    fn cue_text(&self, cue: &SubtitleCue) -> String {
+        cue.lines()
+            .iter()
+            .map(|line| {
+                let text: String = line.texts().iter().map(|e| e.text().as_str()).collect();
+                self.strip_line(text.as_str())
+            })
+            .filter(|e| !e.is_empty())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Strip the styling tags and speaker markers from the given line.
+    fn strip_line(&self, line: &str) -> String {
+        let line = self.tag_regex.replace_all(line, "");
+        let line = self.dialogue_dash_regex.replace(line.trim(), "");
+        let line = self.speaker_regex.replace(line.as_ref(), "");
+
+        line.trim().to_string()
+    }
+
+    /// Verify if the given text continues the sentence of the given paragraph.
+    fn is_continuation(paragraph: &str, text: &str) -> bool {
+        text.starts_with(ELLIPSIS) || !paragraph.ends_with(SENTENCE_TERMINATORS)
+    }
+}
+
+impl Default for TranscriptParser {
+    fn default() -> Self {
+        Self {
+            tag_regex: Regex::new(TAG_PATTERN).expect("tag pattern should be valid"),
+            dialogue_dash_regex: Regex::new(DIALOGUE_DASH_PATTERN)
+                .expect("dialogue dash pattern should be valid"),
+            speaker_regex: Regex::new(SPEAKER_PATTERN).expect("speaker pattern should be valid"),
+        }
+    }
+}
+
+impl Parser for TranscriptParser {
+    fn parse_file(&self, _: File) -> Vec<SubtitleCue> {
+        warn!("Unable to parse transcript file, transcripts don't contain any cue timings");
+        vec![]
+    }
+
+    fn parse_string(&self, _: &String) -> Vec<SubtitleCue> {
+        warn!("Unable to parse transcript data, transcripts don't contain any cue timings");
+        vec![]
+    }
+
+    /// Convert the given cues to a plain text transcript.
+    ///
+    /// Each paragraph of the transcript is separated by an empty line.
+    fn convert(&self, cues: &Vec<SubtitleCue>) -> Result<String, SubtitleParseError> {
+        trace!("Starting conversion to transcript");
+        let mut paragraphs: Vec<String> = vec![];
+        let mut previous_text: Option<String> = None;
+
+        for cue in cues.iter() {
+            let text = self.cue_text(cue);
+            if text.is_empty() || previous_text.as_ref() == Some(&text) {
+                continue;
+            }
+
+            match paragraphs.last_mut() {
+                Some(paragraph) if Self::is_continuation(paragraph, text.as_str()) => {
+                    let trimmed_len = paragraph.trim_end_matches(ELLIPSIS).trim_end().len();
+                    paragraph.truncate(trimmed_len);
+                    paragraph.push(' ');
+                    paragraph.push_str(text.trim_start_matches(ELLIPSIS).trim_start());
+                }
+                _ => paragraphs.push(text.clone()),
+            }
+
+            previous_text = Some(text);
+        }
+
+        let mut output = paragraphs.join(format!("{}{}", NEWLINE, NEWLINE).as_str());
+        if !output.is_empty() {
+            output.push_str(NEWLINE);
+        }
+
+        debug!("Conversion to transcript completed");
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::subtitles::cue::{StyledText, SubtitleLine};
+    use crate::core::subtitles::parsers::SrtParser;
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_srt() {
+        init_logger();
+        let srt = r#"1
+00:00:01,518 --> 00:00:04,681
+All right,
+I'm moving my infantry division...
+
+2
+00:00:04,854 --> 00:00:07,789
+...augmented by a battalion of orcs.
+
+3
+00:00:07,957 --> 00:00:09,618
+<i>JOHN: Not so fast.</i>
+
+4
+00:00:09,793 --> 00:00:12,956
+<i>JOHN: Not so fast.</i>
+
+5
+00:00:13,129 --> 00:00:16,189
+- Orcs are magic.
+- [SHELDON] No, they aren't."#
+            .to_string();
+        let cues = SrtParser::new().parse_string(&srt);
+        let parser = TranscriptParser::default();
+        let expected_result =
+            "All right, I'm moving my infantry division augmented by a battalion of orcs.
+
+Not so fast.
+
+Orcs are magic. No, they aren't.
+";
+
+        let result = parser.convert(&cues).unwrap();
+
+        assert_eq!(expected_result, result.as_str());
+        assert!(
+            !result.contains("-->"),
+            "expected no timestamps to remain, got {} instead",
+            result
+        );
+    }
+
+    #[test]
+    fn test_convert_strip_tags() {
+        init_logger();
+        let cues = vec![SubtitleCue::new(
+            "1".to_string(),
+            1000,
+            2000,
+            vec![SubtitleLine::new(vec![StyledText::new(
+                "{\\an8}<font color=\"#ffffff\">Lorem ipsum.</font>".to_string(),
+                false,
+                false,
+                false,
+            )])],
+        )];
+        let parser = TranscriptParser::default();
+
+        let result = parser.convert(&cues).unwrap();
+
+        assert_eq!("Lorem ipsum.\n", result.as_str());
+    }
+
+    #[test]
+    fn test_parse_string() {
+        init_logger();
+        let parser = TranscriptParser::default();
+
+        let result = parser.parse_string(&"Lorem ipsum".to_string());
+
+        assert_eq!(Vec::<SubtitleCue>::new(), result);
+    }
+}
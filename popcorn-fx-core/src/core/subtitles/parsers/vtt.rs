@@ -1,25 +1,120 @@
 use std::fs::File;
+use std::io::Read;
 
 use chrono::NaiveTime;
-use log::{debug, trace};
-use regex::Regex;
+use log::{debug, trace, warn};
+use regex::{Captures, Regex};
 
-use crate::core::subtitles::cue::SubtitleCue;
+use crate::core::subtitles::cue::{SubtitleCue, SubtitleCueBuilder};
 use crate::core::subtitles::error::SubtitleParseError;
 use crate::core::subtitles::parsers::{NEWLINE, Parser, StyleParser};
 use crate::core::utils::time::parse_time_from_millis;
 
 const HEADER: &str = "WEBVTT";
+const NOTE: &str = "NOTE";
+const STYLE: &str = "STYLE";
+const REGION: &str = "REGION";
 const TIME_INDICATOR: &str = "-->";
 const TIME_FORMAT: &str = "%H:%M:%S.%3f";
+const TIME_PATTERN: &str =
+    "(?:(\\d+):)?(\\d{2}):(\\d{2})\\.(\\d{3})\\s+-->\\s+(?:(\\d+):)?(\\d{2}):(\\d{2})\\.(\\d{3})";
 
 #[derive(Debug)]
 pub struct VttParser {
-    _time_regex: Regex,
+    time_regex: Regex,
     style_parser: StyleParser,
 }
 
 impl VttParser {
+    fn parse(&self, value: &str) -> Vec<SubtitleCue> {
+        let value = value
+            .trim_start_matches('\u{feff}')
+            .replace("\r\n", NEWLINE);
+        let mut blocks = value
+            .split("\n\n")
+            .map(|e| e.trim_matches('\n'))
+            .filter(|e| !e.is_empty());
+        let mut cues: Vec<SubtitleCue> = vec![];
+        let mut notes: Vec<String> = vec![];
+
+        match blocks.next() {
+            Some(header) if header.starts_with(HEADER) => {}
+            _ => {
+                warn!("VTT data is missing the {} signature", HEADER);
+                return cues;
+            }
+        }
+
+        for block in blocks {
+            if Self::is_block_of_type(block, NOTE) {
+                notes.push(block[NOTE.len()..].trim_start_matches(' ').to_string());
+            } else if Self::is_block_of_type(block, STYLE) || Self::is_block_of_type(block, REGION)
+            {
+                trace!("Ignoring VTT block {}", block);
+            } else if let Some(mut builder) = self.parse_cue(block) {
+                for note in notes.drain(..) {
+                    builder.add_note(note);
+                }
+                cues.push(builder.build());
+            }
+        }
+
+        if !notes.is_empty() {
+            debug!("Ignoring {} VTT notes after the last cue", notes.len());
+        }
+
+        cues
+    }
+
+    fn parse_cue(&self, block: &str) -> Option<SubtitleCueBuilder> {
+        let mut lines = block.lines().peekable();
+        let mut builder = SubtitleCueBuilder::new();
+
+        if let Some(id) = lines.next_if(|e| !e.contains(TIME_INDICATOR)) {
+            builder.id(id.trim().to_string());
+        }
+
+        let timing = lines.next()?;
+        match self.time_regex.captures(timing) {
+            Some(caps) => {
+                builder
+                    .start_time(Self::parse_millis(&caps, 1))
+                    .end_time(Self::parse_millis(&caps, 5));
+            }
+            None => {
+                warn!("Ignoring VTT cue with invalid timing \"{}\"", timing);
+                return None;
+            }
+        }
+
+        for line in lines {
+            builder.add_line(self.style_parser.parse_line_style(&line.to_string()));
+        }
+
+        Some(builder)
+    }
+
+    /// Verify if the given block starts with the given keyword, followed by a space, newline or the end of the block.
+    fn is_block_of_type(block: &str, keyword: &str) -> bool {
+        block
+            .strip_prefix(keyword)
+            .map(|e| {
+                e.is_empty() || e.starts_with(' ') || e.starts_with('\t') || e.starts_with('\n')
+            })
+            .unwrap_or(false)
+    }
+
+    /// Parse the millis of the timestamp which starts at the given capture group.
+    fn parse_millis(caps: &Captures, group: usize) -> u64 {
+        let value = |index: usize| {
+            caps.get(group + index)
+                .and_then(|e| e.as_str().parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+
+        ((value(0) * 60 + value(1)) * 60 + value(2)) * 1000 + value(3)
+    }
+
     fn convert_time_to_string(time: NaiveTime) -> String {
         time.format(TIME_FORMAT).to_string()
     }
@@ -28,27 +123,51 @@ impl VttParser {
 impl Default for VttParser {
     fn default() -> Self {
         Self {
-            _time_regex: Regex::new(TIME_FORMAT).expect("VTT time format should be valid"),
+            time_regex: Regex::new(TIME_PATTERN).expect("VTT time pattern should be valid"),
             style_parser: StyleParser::new(),
         }
     }
 }
 
 impl Parser for VttParser {
-    fn parse_file(&self, _file: File) -> Vec<SubtitleCue> {
-        todo!()
+    fn parse_file(&self, mut file: File) -> Vec<SubtitleCue> {
+        let mut data = String::new();
+
+        match file.read_to_string(&mut data) {
+            Ok(_) => self.parse(data.as_str()),
+            Err(e) => {
+                warn!("Failed to read VTT file, {}", e);
+                vec![]
+            }
+        }
     }
 
-    fn parse_string(&self, _value: &String) -> Vec<SubtitleCue> {
-        todo!()
+    fn parse_string(&self, value: &String) -> Vec<SubtitleCue> {
+        self.parse(value.as_str())
     }
 
+    /// Convert the given cues to a WebVTT document.
+    ///
+    /// The output always starts with the `WEBVTT` signature and the cues are identified by a sequential number,
+    /// as some receivers, such as Chromecast, silently ignore malformed VTT data.
     fn convert(&self, cues: &Vec<SubtitleCue>) -> Result<String, SubtitleParseError> {
         trace!("Starting conversion to VTT");
         let mut output = format!("{}{}{}", HEADER, NEWLINE, NEWLINE);
 
-        for cue in cues.iter() {
-            let id = cue.id().clone();
+        for (index, cue) in cues.iter().enumerate() {
+            for note in cue.notes() {
+                let separator = if note.is_empty() || note.starts_with(NEWLINE) {
+                    ""
+                } else {
+                    " "
+                };
+
+                output.push_str(format!("{}{}{}", NOTE, separator, note).as_str());
+                output.push_str(NEWLINE);
+                output.push_str(NEWLINE);
+            }
+
+            let id = (index + 1).to_string();
             let start_time = parse_time_from_millis(cue.start_time().clone());
             let end_time = parse_time_from_millis(cue.end_time().clone());
 
@@ -130,4 +249,105 @@ mod test {
             result.expect("Expected the parsing to have succeeded")
         )
     }
+
+    #[test]
+    fn test_parse_string() {
+        let value = "WEBVTT - lorem\n\nNOTE lorem ipsum\n\nSTYLE\n::cue { color: white }\n\n01:02.500 --> 00:01:04.000 align:start\n<i>dolor</i>\n\nNOTE\nsit amet\n\nfoo\n01:00:00.000 --> 01:00:01.250\nconsectetur\n".to_string();
+        let parser = VttParser::default();
+        let mut first_cue = SubtitleCueBuilder::new();
+        first_cue
+            .start_time(62500)
+            .end_time(64000)
+            .add_line(SubtitleLine::new(vec![StyledText::new(
+                "dolor".to_string(),
+                true,
+                false,
+                false,
+            )]))
+            .add_note("lorem ipsum".to_string());
+        let mut second_cue = SubtitleCueBuilder::new();
+        second_cue
+            .id("foo".to_string())
+            .start_time(3600000)
+            .end_time(3601250)
+            .add_line(SubtitleLine::new(vec![StyledText::new(
+                "consectetur".to_string(),
+                false,
+                false,
+                false,
+            )]))
+            .add_note("\nsit amet".to_string());
+
+        let result = parser.parse_string(&value);
+
+        assert_eq!(vec![first_cue.build(), second_cue.build()], result)
+    }
+
+    #[test]
+    fn test_parse_string_missing_header() {
+        let value = "1\n00:00:01.000 --> 00:00:02.000\nlorem\n".to_string();
+        let parser = VttParser::default();
+
+        let result = parser.parse_string(&value);
+
+        assert_eq!(Vec::<SubtitleCue>::new(), result)
+    }
+
+    #[test]
+    fn test_convert_parse_back() {
+        let mut cue = SubtitleCueBuilder::new();
+        cue.start_time(1000)
+            .end_time(2500)
+            .add_line(SubtitleLine::new(vec![StyledText::new(
+                "lorem".to_string(),
+                false,
+                true,
+                false,
+            )]))
+            .add_note("ipsum dolor".to_string());
+        let cues = vec![
+            cue.build(),
+            SubtitleCue::new(
+                "".to_string(),
+                5000,
+                6000,
+                vec![SubtitleLine::new(vec![StyledText::new(
+                    "sit amet".to_string(),
+                    false,
+                    false,
+                    false,
+                )])],
+            ),
+        ];
+        let parser = VttParser::default();
+
+        let result = parser
+            .convert(&cues)
+            .expect("expected the conversion to have succeeded");
+        assert!(
+            result.starts_with("WEBVTT\n\n"),
+            "expected the WEBVTT signature, but got {} instead",
+            result
+        );
+        assert!(
+            result.contains("NOTE ipsum dolor\n\n1\n00:00:01.000 --> 00:00:02.500\n"),
+            "expected the note and cue id to be present, but got {} instead",
+            result
+        );
+        assert!(
+            result.contains("\n2\n00:00:05.000 --> 00:00:06.000\n"),
+            "expected a sequential cue id, but got {} instead",
+            result
+        );
+
+        let parsed = parser.parse_string(&result);
+        assert_eq!(2, parsed.len());
+        assert_eq!("1", parsed[0].id().as_str());
+        assert_eq!(cues[0].notes(), parsed[0].notes());
+        assert_eq!(cues[0].lines(), parsed[0].lines());
+        assert_eq!("2", parsed[1].id().as_str());
+        assert_eq!(cues[1].start_time(), parsed[1].start_time());
+        assert_eq!(cues[1].end_time(), parsed[1].end_time());
+        assert_eq!(cues[1].lines(), parsed[1].lines());
+    }
 }
@@ -7,6 +7,7 @@ use mockall::automock;
 
 use crate::core::media::{Episode, MovieDetails, ShowDetails};
 use crate::core::subtitles;
+use crate::core::subtitles::cue::SubtitleCue;
 use crate::core::subtitles::matcher::SubtitleMatcher;
 use crate::core::subtitles::model::{Subtitle, SubtitleInfo, SubtitleType};
 
@@ -52,6 +53,25 @@ pub trait SubtitleProvider: Debug + Send + Sync {
         matcher: &SubtitleMatcher,
     ) -> subtitles::Result<Subtitle>;
 
+    /// Preview the subtitle for the given [SubtitleInfo] by retrieving the first cues of the best matching file.
+    /// This allows the contents of a subtitle to be verified before it's selected, without storing the file.
+    ///
+    /// Only the head of the subtitle file is retrieved when the file hasn't been downloaded before.
+    ///
+    /// * `cue_count` - The maximum number of cues to return.
+    ///
+    /// It returns the first cues of the subtitle on success, else the [subtitles::SubtitleError].
+    async fn preview(
+        &self,
+        subtitle_info: &SubtitleInfo,
+        matcher: &SubtitleMatcher,
+        cue_count: usize,
+    ) -> subtitles::Result<Vec<SubtitleCue>>;
+
+    /// Clear the cache of the downloaded subtitle files.
+    /// This causes the next download of a subtitle to retrieve the file again from the provider.
+    fn clear_cache(&self) {}
+
     /// Parse the given file path to a subtitle struct.
     ///
     /// It returns a [SubtitleError] when the path doesn't exist of the file failed to be parsed.
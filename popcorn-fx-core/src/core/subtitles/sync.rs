@@ -0,0 +1,374 @@
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read};
+use std::path::Path;
+
+use derive_more::Display;
+use log::{debug, trace};
+
+use crate::core::subtitles;
+use crate::core::subtitles::cue::SubtitleCue;
+use crate::core::subtitles::error::SubtitleError;
+
+/// The duration of a single audio analysis frame in millis.
+const FRAME_DURATION_MS: u64 = 50;
+/// The max offset in millis which is searched in both directions.
+const MAX_OFFSET_MS: u64 = 10_000;
+/// The range in millis around the best offset which is ignored when searching the runner-up offset.
+const PEAK_EXCLUSION_MS: u64 = 1_000;
+/// The relative energy level between the noise floor and the peak above which a frame is considered speech.
+const SPEECH_THRESHOLD_RATIO: f64 = 0.3;
+/// The minimum confidence required before an offset is applied.
+const MIN_CONFIDENCE: f64 = 0.25;
+
+/// The result of an auto sync attempt of a subtitle.
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum AutoSyncResult {
+    /// The cues have been shifted by the given offset in millis.
+    #[display(fmt = "synced with offset {}ms (confidence {:.2})", offset, confidence)]
+    Synced { offset: i64, confidence: f64 },
+    /// No reliable offset could be determined and the cues have been left untouched.
+    #[display(fmt = "inconclusive (confidence {:.2})", confidence)]
+    Inconclusive { confidence: f64 },
+}
+
+/// Estimate the global offset in millis of the given cues against the speech activity of the reference audio.
+///
+/// The reference should be a 16-bit PCM WAV file of the media's audio track.
+/// The offset is determined by correlating the frames covered by the cues with the frames that contain speech,
+/// where speech is detected through the energy of the audio signal.
+pub(crate) fn estimate_offset(
+    cues: &[SubtitleCue],
+    reference: &Path,
+) -> subtitles::Result<AutoSyncResult> {
+    let speech = speech_frames(reference)?;
+    let cue_frames = cue_frames(cues);
+    let max_offset = (MAX_OFFSET_MS / FRAME_DURATION_MS) as i64;
+    let scores: Vec<usize> = (-max_offset..=max_offset)
+        .map(|offset| {
+            cue_frames
+                .iter()
+                .filter(|frame| {
+                    let speech_frame = **frame as i64 + offset;
+                    speech_frame >= 0 && speech.get(speech_frame as usize).cloned().unwrap_or(false)
+                })
+                .count()
+        })
+        .collect();
+
+    let (best_index, best_score) = scores
+        .iter()
+        .cloned()
+        .enumerate()
+        .max_by_key(|(_, score)| *score)
+        .unwrap_or((0, 0));
+    if best_score == 0 {
+        debug!("Unable to auto sync subtitle, no speech overlaps with the cues");
+        return Ok(AutoSyncResult::Inconclusive { confidence: 0.0 });
+    }
+
+    let exclusion = (PEAK_EXCLUSION_MS / FRAME_DURATION_MS) as usize;
+    let runner_up_score = scores
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| index.abs_diff(best_index) > exclusion)
+        .map(|(_, score)| *score)
+        .max()
+        .unwrap_or(0);
+    let confidence = (best_score - runner_up_score) as f64 / best_score as f64;
+    let offset = (best_index as i64 - max_offset) * FRAME_DURATION_MS as i64;
+    trace!(
+        "Auto sync best offset {}ms scored {}, runner-up scored {}",
+        offset,
+        best_score,
+        runner_up_score
+    );
+
+    if confidence < MIN_CONFIDENCE {
+        debug!(
+            "Auto sync is inconclusive, confidence {:.2} is below {:.2}",
+            confidence, MIN_CONFIDENCE
+        );
+        return Ok(AutoSyncResult::Inconclusive { confidence });
+    }
+
+    Ok(AutoSyncResult::Synced { offset, confidence })
+}
+
+/// Retrieve the frame indexes which are covered by at least one of the given cues.
+fn cue_frames(cues: &[SubtitleCue]) -> Vec<usize> {
+    let total_frames = cues
+        .iter()
+        .map(|e| *e.end_time() / FRAME_DURATION_MS)
+        .max()
+        .unwrap_or(0) as usize;
+    let mut frames = vec![false; total_frames];
+
+    for cue in cues {
+        let start = (*cue.start_time() / FRAME_DURATION_MS) as usize;
+        let end = (*cue.end_time() / FRAME_DURATION_MS) as usize;
+
+        for frame in start..end.min(total_frames) {
+            frames[frame] = true;
+        }
+    }
+
+    frames
+        .into_iter()
+        .enumerate()
+        .filter(|(_, active)| *active)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Detect the frames of the given WAV file which contain speech based on the energy of each frame.
+fn speech_frames(reference: &Path) -> subtitles::Result<Vec<bool>> {
+    let energies = frame_energies(reference)?;
+    if energies.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut sorted = energies.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let percentile = |value: f64| sorted[((sorted.len() - 1) as f64 * value) as usize];
+
+    let noise_floor = percentile(0.1);
+    let peak = percentile(0.99);
+    let threshold = noise_floor + (peak - noise_floor) * SPEECH_THRESHOLD_RATIO;
+    trace!(
+        "Detecting speech with noise floor {:.2}, peak {:.2} and threshold {:.2}",
+        noise_floor,
+        peak,
+        threshold
+    );
+
+    if peak <= noise_floor {
+        return Ok(vec![false; energies.len()]);
+    }
+
+    Ok(energies.into_iter().map(|e| e > threshold).collect())
+}
+
+/// Calculate the RMS energy of each frame within the given WAV file.
+fn frame_energies(reference: &Path) -> subtitles::Result<Vec<f64>> {
+    let filepath = reference.to_str().unwrap_or_default().to_string();
+    let invalid = |message: &str| SubtitleError::InvalidFile(filepath.clone(), message.to_string());
+    let file = File::open(reference).map_err(|e| invalid(e.to_string().as_str()))?;
+    let mut reader = BufReader::new(file);
+
+    let mut header = [0u8; 12];
+    reader
+        .read_exact(&mut header)
+        .map_err(|e| invalid(e.to_string().as_str()))?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err(invalid("reference is not a WAV file"));
+    }
+
+    let mut format: Option<(u16, u32)> = None;
+    loop {
+        let mut chunk_header = [0u8; 8];
+        reader
+            .read_exact(&mut chunk_header)
+            .map_err(|_| invalid("WAV file contains no audio data"))?;
+        let chunk_size = u32::from_le_bytes(chunk_header[4..8].try_into().unwrap()) as u64;
+
+        match &chunk_header[0..4] {
+            b"fmt " => {
+                let mut chunk = vec![0u8; chunk_size as usize];
+                reader
+                    .read_exact(&mut chunk)
+                    .map_err(|e| invalid(e.to_string().as_str()))?;
+                if chunk.len() < 16 {
+                    return Err(invalid("WAV format chunk is too small"));
+                }
+
+                let audio_format = u16::from_le_bytes([chunk[0], chunk[1]]);
+                let channels = u16::from_le_bytes([chunk[2], chunk[3]]);
+                let sample_rate = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+                let bits_per_sample = u16::from_le_bytes([chunk[14], chunk[15]]);
+                if (audio_format != 1 && audio_format != 0xFFFE) || bits_per_sample != 16 {
+                    return Err(invalid("only 16-bit PCM audio is supported"));
+                }
+                if channels == 0 || sample_rate == 0 {
+                    return Err(invalid("WAV format is invalid"));
+                }
+
+                format = Some((channels, sample_rate));
+            }
+            b"data" => {
+                let (channels, sample_rate) =
+                    format.ok_or_else(|| invalid("WAV data chunk precedes the format chunk"))?;
+                return read_energies(&mut reader.take(chunk_size), channels, sample_rate)
+                    .map_err(|e| invalid(e.to_string().as_str()));
+            }
+            _ => {
+                // skip unknown chunks, which are padded to an even size
+                std::io::copy(
+                    &mut (&mut reader).take(chunk_size + chunk_size % 2),
+                    &mut std::io::sink(),
+                )
+                .map_err(|e| invalid(e.to_string().as_str()))?;
+            }
+        }
+    }
+}
+
+fn read_energies<R: Read>(
+    reader: &mut R,
+    channels: u16,
+    sample_rate: u32,
+) -> std::io::Result<Vec<f64>> {
+    let samples_per_frame =
+        ((sample_rate as u64 * FRAME_DURATION_MS / 1000) as usize * channels as usize).max(1);
+    let mut energies = vec![];
+    let mut buffer = [0u8; 2];
+    let mut sum = 0f64;
+    let mut count = 0usize;
+
+    loop {
+        match reader.read_exact(&mut buffer) {
+            Ok(_) => {
+                let sample = i16::from_le_bytes(buffer) as f64;
+                sum += sample * sample;
+                count += 1;
+
+                if count == samples_per_frame {
+                    energies.push((sum / count as f64).sqrt());
+                    sum = 0.0;
+                    count = 0;
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    if count > 0 {
+        energies.push((sum / count as f64).sqrt());
+    }
+
+    Ok(energies)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::f64::consts::PI;
+    use std::fs;
+
+    use crate::core::subtitles::cue::{StyledText, SubtitleLine};
+
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 8000;
+
+    #[test]
+    fn test_estimate_offset() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reference = temp_dir.path().join("audio.wav");
+        let cues = create_cues();
+        write_wav(&reference, &speech_intervals(&cues, 1500), 25_000);
+
+        let result = estimate_offset(&cues, &reference).unwrap();
+
+        if let AutoSyncResult::Synced { offset, .. } = result {
+            assert_eq!(1500, offset);
+        } else {
+            assert!(false, "expected Synced, but got {:?} instead", result);
+        }
+    }
+
+    #[test]
+    fn test_estimate_offset_silence() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reference = temp_dir.path().join("audio.wav");
+        let cues = create_cues();
+        write_wav(&reference, &[], 25_000);
+
+        let result = estimate_offset(&cues, &reference).unwrap();
+
+        assert_eq!(AutoSyncResult::Inconclusive { confidence: 0.0 }, result);
+    }
+
+    #[test]
+    fn test_estimate_offset_invalid_reference() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reference = temp_dir.path().join("audio.wav");
+        fs::write(&reference, "lorem ipsum dolor").unwrap();
+
+        let result = estimate_offset(&create_cues(), &reference);
+
+        assert!(
+            matches!(result, Err(SubtitleError::InvalidFile(_, _))),
+            "expected SubtitleError::InvalidFile, but got {:?} instead",
+            result
+        );
+    }
+
+    pub(crate) fn create_cues() -> Vec<SubtitleCue> {
+        [
+            (1000, 2500),
+            (4000, 4800),
+            (7300, 9000),
+            (12000, 12600),
+            (15500, 17500),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (start, end))| {
+            SubtitleCue::new(
+                index.to_string(),
+                start,
+                end,
+                vec![SubtitleLine::new(vec![StyledText::new(
+                    "lorem".to_string(),
+                    false,
+                    false,
+                    false,
+                )])],
+            )
+        })
+        .collect()
+    }
+
+    pub(crate) fn speech_intervals(cues: &[SubtitleCue], offset: u64) -> Vec<(u64, u64)> {
+        cues.iter()
+            .map(|e| (*e.start_time() + offset, *e.end_time() + offset))
+            .collect()
+    }
+
+    /// Write a mono 16-bit PCM WAV file containing a tone within the given speech intervals.
+    pub(crate) fn write_wav(path: &Path, speech: &[(u64, u64)], duration: u64) {
+        let total_samples = (SAMPLE_RATE as u64 * duration / 1000) as usize;
+        let mut data = Vec::with_capacity(total_samples * 2);
+
+        for i in 0..total_samples {
+            let millis = i as u64 * 1000 / SAMPLE_RATE as u64;
+            let sample = if speech
+                .iter()
+                .any(|(start, end)| millis >= *start && millis < *end)
+            {
+                ((2.0 * PI * 440.0 * i as f64 / SAMPLE_RATE as f64).sin() * 8000.0) as i16
+            } else {
+                0
+            };
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+        bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&data);
+
+        fs::write(path, bytes).unwrap();
+    }
+}
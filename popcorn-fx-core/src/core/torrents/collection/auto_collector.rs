@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+
+use log::{debug, info, trace};
+use tokio::runtime::Runtime;
+
+use crate::core::config::ApplicationConfig;
+use crate::core::events::{Event, EventPublisher, DEFAULT_ORDER};
+use crate::core::torrents::collection::TorrentCollection;
+use crate::core::torrents::{Torrent, TorrentEvent, TorrentState};
+
+/// The collector which automatically adds the torrents of finished streams to the [TorrentCollection].
+///
+/// A watched torrent is collected once it has been fully downloaded and the playback of its stream has finished,
+/// regardless of the order in which both occur.
+/// Torrents are only collected when [crate::core::config::TorrentSettings::auto_collect_finished] is enabled,
+/// and magnets of a torrent which is already stored are ignored.
+#[derive(Debug, Clone)]
+pub struct TorrentAutoCollector {
+    inner: Arc<InnerTorrentAutoCollector>,
+}
+
+impl TorrentAutoCollector {
+    /// Create a new auto collector for the given torrent collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` - The collection to which the finished torrents are added.
+    /// * `settings` - The application settings which indicate if finished torrents should be collected.
+    /// * `event_publisher` - The publisher of the player events.
+    /// * `runtime` - The runtime on which the collection is updated.
+    pub fn new(
+        collection: Arc<TorrentCollection>,
+        settings: Arc<ApplicationConfig>,
+        event_publisher: Arc<EventPublisher>,
+        runtime: Arc<Runtime>,
+    ) -> Self {
+        let inner = Arc::new(InnerTorrentAutoCollector {
+            collection,
+            settings,
+            runtime,
+            watched: Default::default(),
+        });
+
+        let event_inner = inner.clone();
+        event_publisher.register(
+            Box::new(move |event| {
+                if let Event::PlayerStopped(e) = &event {
+                    event_inner.on_player_stopped(e.url());
+                }
+
+                Some(event)
+            }),
+            DEFAULT_ORDER,
+        );
+
+        Self { inner }
+    }
+
+    /// Watch the given stream torrent, so it's added to the collection when it has finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `torrent` - The torrent which backs the stream.
+    /// * `stream_url` - The playback url of the stream.
+    /// * `name` - The name under which the magnet is stored, such as the media title.
+    /// * `magnet_uri` - The magnet uri of the torrent.
+    pub fn watch<T: Torrent + ?Sized>(
+        &self,
+        torrent: &T,
+        stream_url: &str,
+        name: &str,
+        magnet_uri: &str,
+    ) {
+        let handle = torrent.handle().to_string();
+        let entry = CollectEntry {
+            name: name.to_string(),
+            magnet_uri: magnet_uri.to_string(),
+            stream_url: stream_url.to_string(),
+            completed: torrent.state() == TorrentState::Completed,
+            playback_finished: false,
+        };
+
+        if self
+            .inner
+            .watched
+            .lock()
+            .unwrap()
+            .insert(handle.clone(), entry)
+            .is_some()
+        {
+            trace!("Torrent {} is already being watched for collection", handle);
+            return;
+        }
+
+        debug!("Watching torrent {} for the torrent collection", handle);
+        let inner = self.inner.clone();
+        torrent.subscribe(Box::new(move |event| {
+            if let TorrentEvent::StateChanged(TorrentState::Completed) = event {
+                inner.on_completed(&handle);
+            }
+        }));
+    }
+
+    /// Verify if the given torrent is being watched for the torrent collection.
+    pub fn is_watched(&self, handle: &str) -> bool {
+        self.inner.watched.lock().unwrap().contains_key(handle)
+    }
+}
+
+#[derive(Debug)]
+struct CollectEntry {
+    name: String,
+    magnet_uri: String,
+    stream_url: String,
+    completed: bool,
+    playback_finished: bool,
+}
+
+struct InnerTorrentAutoCollector {
+    collection: Arc<TorrentCollection>,
+    settings: Arc<ApplicationConfig>,
+    runtime: Arc<Runtime>,
+    /// The torrents which are watched, stored by their handle
+    watched: Mutex<HashMap<String, CollectEntry>>,
+}
+
+impl InnerTorrentAutoCollector {
+    fn on_completed(&self, handle: &str) {
+        let mut watched = self.watched.lock().unwrap();
+
+        if let Some(entry) = watched.get_mut(handle) {
+            debug!("Torrent {} has been fully downloaded", handle);
+            entry.completed = true;
+
+            if entry.playback_finished {
+                if let Some(entry) = watched.remove(handle) {
+                    drop(watched);
+                    self.collect(entry);
+                }
+            }
+        }
+    }
+
+    fn on_player_stopped(&self, url: &str) {
+        let mut watched = self.watched.lock().unwrap();
+        let handle = watched
+            .iter()
+            .find(|(_, entry)| entry.stream_url.as_str() == url)
+            .map(|(handle, _)| handle.clone());
+
+        if let Some(handle) = handle {
+            let entry = watched.get_mut(&handle).unwrap();
+            debug!("Playback of torrent {} has finished", handle);
+            entry.playback_finished = true;
+
+            if entry.completed {
+                if let Some(entry) = watched.remove(&handle) {
+                    drop(watched);
+                    self.collect(entry);
+                }
+            }
+        } else {
+            trace!(
+                "Stopped playback {} is not backed by a watched torrent",
+                url
+            );
+        }
+    }
+
+    fn collect(&self, entry: CollectEntry) {
+        let collection = self.collection.clone();
+        let settings = self.settings.clone();
+
+        // the collection and settings use blocking locks which can't be acquired from an async context
+        self.runtime.spawn_blocking(move || {
+            if !settings.user_settings().torrent().auto_collect_finished {
+                trace!(
+                    "Auto collection of finished torrents is disabled, ignoring {}",
+                    entry.name
+                );
+                return;
+            }
+
+            if collection.insert(entry.name.as_str(), entry.magnet_uri.as_str()) {
+                info!("Added finished torrent {} to the collection", entry.name);
+            } else {
+                debug!("Finished torrent {} is already collected", entry.name);
+            }
+        });
+    }
+}
+
+impl Debug for InnerTorrentAutoCollector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerTorrentAutoCollector")
+            .field("collection", &self.collection)
+            .field("settings", &self.settings)
+            .field("watched", &self.watched)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    use tempfile::tempdir;
+
+    use crate::core::config::TorrentSettings;
+    use crate::core::events::PlayerStoppedEvent;
+    use crate::core::torrents::collection::TorrentCollectionEvent;
+    use crate::core::torrents::{MockTorrent, MockTorrentManager, TorrentCallback, TorrentManager};
+    use crate::core::Handle;
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    const MAGNET_URI: &str = "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7";
+    const STREAM_URL: &str = "http://localhost:8080/lorem.mp4";
+
+    #[test]
+    fn test_collect_finished_stream() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (collection, settings) = create_collection(temp_path, true);
+        let event_publisher = Arc::new(EventPublisher::default());
+        let collector = TorrentAutoCollector::new(
+            collection.clone(),
+            settings,
+            event_publisher.clone(),
+            Arc::new(Runtime::new().unwrap()),
+        );
+        let (torrent, callback) = create_torrent("torrent1");
+        let (tx, rx) = channel();
+        collection.subscribe(Box::new(move |e| tx.send(e).unwrap()));
+
+        collector.watch(&*torrent, STREAM_URL, "Lorem", MAGNET_URI);
+        invoke_completed(&callback);
+        event_publisher.publish(create_player_stopped_event());
+
+        let result = rx.recv_timeout(Duration::from_millis(500)).unwrap();
+        assert_eq!(TorrentCollectionEvent::CollectionChanged, result);
+        assert_eq!(true, collection.is_stored(MAGNET_URI));
+        assert_eq!(false, collector.is_watched("torrent1"));
+    }
+
+    #[test]
+    fn test_collect_finished_stream_disabled() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (collection, settings) = create_collection(temp_path, false);
+        let event_publisher = Arc::new(EventPublisher::default());
+        let collector = TorrentAutoCollector::new(
+            collection.clone(),
+            settings,
+            event_publisher.clone(),
+            Arc::new(Runtime::new().unwrap()),
+        );
+        let (torrent, callback) = create_torrent("torrent1");
+        let (tx, rx) = channel();
+        collection.subscribe(Box::new(move |e| tx.send(e).unwrap()));
+
+        collector.watch(&*torrent, STREAM_URL, "Lorem", MAGNET_URI);
+        event_publisher.publish(create_player_stopped_event());
+        invoke_completed(&callback);
+
+        assert!(
+            rx.recv_timeout(Duration::from_millis(300)).is_err(),
+            "expected the torrent to not have been collected"
+        );
+        assert_eq!(false, collection.is_stored(MAGNET_URI));
+    }
+
+    fn create_collection(
+        temp_path: &str,
+        auto_collect_finished: bool,
+    ) -> (Arc<TorrentCollection>, Arc<ApplicationConfig>) {
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        settings.update_torrent(TorrentSettings {
+            auto_collect_finished,
+            ..Default::default()
+        });
+        let collection = Arc::new(TorrentCollection::new(
+            temp_path,
+            Arc::new(Box::new(MockTorrentManager::new()) as Box<dyn TorrentManager>),
+        ));
+
+        (collection, settings)
+    }
+
+    fn create_torrent(handle: &str) -> (Box<dyn Torrent>, Arc<Mutex<Option<TorrentCallback>>>) {
+        let callback: Arc<Mutex<Option<TorrentCallback>>> = Default::default();
+        let subscribe_callback = callback.clone();
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        torrent.expect_subscribe().returning(move |e| {
+            *subscribe_callback.lock().unwrap() = Some(e);
+            Handle::new()
+        });
+
+        (Box::new(torrent), callback)
+    }
+
+    fn create_player_stopped_event() -> Event {
+        Event::PlayerStopped(PlayerStoppedEvent {
+            url: STREAM_URL.to_string(),
+            media: None,
+            parent_media: None,
+            time: Some(20000),
+            duration: Some(20000),
+        })
+    }
+
+    fn invoke_completed(callback: &Arc<Mutex<Option<TorrentCallback>>>) {
+        let mutex = callback.lock().unwrap();
+        let callback = mutex
+            .as_ref()
+            .expect("expected the torrent to be subscribed");
+
+        callback(TorrentEvent::StateChanged(TorrentState::Completed));
+    }
+}
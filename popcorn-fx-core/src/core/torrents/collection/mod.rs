@@ -1,5 +1,7 @@
+pub use auto_collector::*;
 pub use model::*;
 pub use torrent_collection::*;
 
+mod auto_collector;
 mod model;
 mod torrent_collection;
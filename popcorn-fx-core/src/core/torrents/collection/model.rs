@@ -1,7 +1,26 @@
+use std::cmp::Ordering;
+
+use chrono::Utc;
 use derive_more::Display;
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
 
+use crate::core::torrents::InfoHash;
+
+/// The sorting options of the magnets within the [Collection].
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Display, PartialEq)]
+pub enum CollectionSort {
+    /// Sort the magnets alphabetically by their name.
+    Name = 0,
+    /// Sort the magnets by the moment they have been added, newest first.
+    AddedAt = 1,
+    /// Sort the magnets by the moment they have been last used, most recent first.
+    LastUsed = 2,
+    /// Sort the magnets by their total size, largest first.
+    Size = 3,
+}
+
 /// The collection information of magnet torrents.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Collection {
@@ -15,18 +34,72 @@ impl Collection {
         self.torrents.iter().any(|e| e.magnet_uri.as_str() == uri)
     }
 
+    /// Verify if the collection contains a magnet of the same torrent as the given uri.
+    /// Magnets are matched by their info hash, so magnets of the same torrent with different trackers
+    /// or display names are considered equal.
+    pub fn contains_torrent(&self, uri: &str) -> bool {
+        if self.contains(uri) {
+            return true;
+        }
+
+        match InfoHash::from_magnet(uri) {
+            Ok(info_hash) => self
+                .torrents
+                .iter()
+                .filter_map(|e| InfoHash::from_magnet(e.magnet_uri.as_str()).ok())
+                .any(|e| e.matches(&info_hash)),
+            Err(_) => false,
+        }
+    }
+
     /// Insert the given magnet info into the collection.
-    /// If the magnet already exists, it will be ignored.
-    pub fn insert(&mut self, name: &str, magnet_uri: &str) {
-        if self.contains(magnet_uri) {
+    /// If a magnet of the same torrent already exists, it will be ignored.
+    ///
+    /// It returns `true` when the magnet has been inserted, else `false`.
+    pub fn insert(&mut self, name: &str, magnet_uri: &str) -> bool {
+        if self.contains_torrent(magnet_uri) {
             debug!("Magnet info already stored for {}", magnet_uri);
-            return;
+            return false;
         }
 
         self.torrents.push(MagnetInfo {
             name: name.to_string(),
             magnet_uri: magnet_uri.to_string(),
-        })
+            added_at: Some(Utc::now().timestamp_millis()),
+            last_used_at: None,
+            total_size: None,
+            total_files: None,
+        });
+        true
+    }
+
+    /// Retrieve a mutable reference to the magnet info of the given uri.
+    pub fn get_mut(&mut self, magnet_uri: &str) -> Option<&mut MagnetInfo> {
+        self.torrents
+            .iter_mut()
+            .find(|e| e.magnet_uri.as_str() == magnet_uri)
+    }
+
+    /// Search the magnets of which the name contains the given query, ignoring the case.
+    /// An empty query matches all magnets.
+    ///
+    /// Magnets without a value for the given sort, such as unresolved sizes, are always sorted last.
+    pub fn search(&self, query: &str, sort: CollectionSort) -> Vec<MagnetInfo> {
+        let query = query.trim().to_lowercase();
+        let mut result: Vec<MagnetInfo> = self
+            .torrents
+            .iter()
+            .filter(|e| query.is_empty() || e.name.to_lowercase().contains(query.as_str()))
+            .cloned()
+            .collect();
+
+        result.sort_by(|a, b| match sort {
+            CollectionSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            CollectionSort::AddedAt => Self::compare_descending(a.added_at, b.added_at),
+            CollectionSort::LastUsed => Self::compare_descending(a.last_used_at, b.last_used_at),
+            CollectionSort::Size => Self::compare_descending(a.total_size, b.total_size),
+        });
+        result
     }
 
     /// Remove the given magnet uri from this collection.
@@ -42,6 +115,16 @@ impl Collection {
             info!("Removed magnet {} from collection", info)
         }
     }
+
+    /// Compare the given values in descending order, in which a missing value is always ordered last.
+    fn compare_descending<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Display, Serialize, Deserialize, PartialEq)]
@@ -51,6 +134,25 @@ pub struct MagnetInfo {
     pub name: String,
     /// The magnet uri of the torrent
     pub magnet_uri: String,
+    /// The unix timestamp in millis at which the magnet has been added to the collection
+    #[serde(default)]
+    pub added_at: Option<i64>,
+    /// The unix timestamp in millis at which the magnet has been last used
+    #[serde(default)]
+    pub last_used_at: Option<i64>,
+    /// The total size in bytes of the torrent files, if the metadata has been resolved
+    #[serde(default)]
+    pub total_size: Option<u64>,
+    /// The total number of files within the torrent, if the metadata has been resolved
+    #[serde(default)]
+    pub total_files: Option<u32>,
+}
+
+impl MagnetInfo {
+    /// Verify if the torrent metadata of the magnet has been resolved.
+    pub fn is_resolved(&self) -> bool {
+        self.total_size.is_some() && self.total_files.is_some()
+    }
 }
 
 #[cfg(test)]
@@ -64,6 +166,7 @@ mod test {
             torrents: vec![MagnetInfo {
                 name: "lorem".to_string(),
                 magnet_uri: uri.to_string(),
+                ..Default::default()
             }],
         };
 
@@ -111,6 +214,24 @@ mod test {
         assert_eq!(1, result)
     }
 
+    #[test]
+    fn test_insert_duplicate_info_hash() {
+        let mut collection = Collection { torrents: vec![] };
+
+        let result = collection.insert(
+            "lorem",
+            "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7&dn=lorem",
+        );
+        assert_eq!(true, result);
+
+        let result = collection.insert(
+            "ipsum",
+            "magnet:?xt=urn:btih:eadaf0efea39406914414d359e0ea16416409bd7&tr=udp://tracker",
+        );
+        assert_eq!(false, result, "expected the same torrent to be ignored");
+        assert_eq!(1, collection.torrents.len());
+    }
+
     #[test]
     fn test_remove_existing_item() {
         let name = "toBeRemoved";
@@ -130,6 +251,7 @@ mod test {
         let info = MagnetInfo {
             name: "alreadyExistingItem".to_string(),
             magnet_uri: "magnet:?alreadyExistingItemUrl".to_string(),
+            ..Default::default()
         };
         let mut collection = Collection {
             torrents: vec![info.clone()],
@@ -138,4 +260,106 @@ mod test {
         collection.remove(uri);
         assert_eq!(&info, collection.torrents.get(0).unwrap())
     }
+
+    #[test]
+    fn test_insert_added_at() {
+        let uri = "magnet:?something-random";
+        let mut collection = Collection { torrents: vec![] };
+
+        collection.insert("lorem", uri);
+        let result = collection.get_mut(uri).unwrap();
+
+        assert!(result.added_at.is_some(), "expected added_at to be set");
+        assert_eq!(None, result.last_used_at);
+        assert_eq!(false, result.is_resolved());
+    }
+
+    #[test]
+    fn test_search_name() {
+        let collection = Collection {
+            torrents: vec![
+                create_magnet("Lorem ipsum", Some(1000), None, None),
+                create_magnet("Dolor", Some(2000), None, None),
+                create_magnet("ipsum estla", Some(3000), None, None),
+            ],
+        };
+
+        let result: Vec<String> = collection
+            .search("IPSUM", CollectionSort::Name)
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+
+        assert_eq!(
+            vec!["ipsum estla".to_string(), "Lorem ipsum".to_string()],
+            result
+        );
+        assert_eq!(3, collection.search("", CollectionSort::Name).len());
+    }
+
+    #[test]
+    fn test_search_sort() {
+        let collection = Collection {
+            torrents: vec![
+                create_magnet("Lorem", Some(1000), None, Some(2048)),
+                create_magnet("Ipsum", None, Some(5000), None),
+                create_magnet("Dolor", Some(2000), Some(4000), Some(4096)),
+            ],
+        };
+        let search = |sort: CollectionSort| -> Vec<String> {
+            collection
+                .search("", sort)
+                .into_iter()
+                .map(|e| e.name)
+                .collect()
+        };
+
+        assert_eq!(
+            vec!["Dolor", "Lorem", "Ipsum"],
+            search(CollectionSort::AddedAt)
+        );
+        assert_eq!(
+            vec!["Ipsum", "Dolor", "Lorem"],
+            search(CollectionSort::LastUsed)
+        );
+        assert_eq!(
+            vec!["Dolor", "Lorem", "Ipsum"],
+            search(CollectionSort::Size)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy_magnet_info() {
+        let json = r#"{"torrents":[{"name":"Lorem","magnet_uri":"magnet:?Lorem"}]}"#;
+
+        let result = serde_json::from_str::<Collection>(json).unwrap();
+
+        assert_eq!(
+            vec![MagnetInfo {
+                name: "Lorem".to_string(),
+                magnet_uri: "magnet:?Lorem".to_string(),
+                added_at: None,
+                last_used_at: None,
+                total_size: None,
+                total_files: None,
+            }],
+            result.torrents
+        );
+    }
+
+    fn create_magnet(
+        name: &str,
+        added_at: Option<i64>,
+        last_used_at: Option<i64>,
+        total_size: Option<u64>,
+    ) -> MagnetInfo {
+        MagnetInfo {
+            name: name.to_string(),
+            magnet_uri: format!("magnet:?{}", name),
+            added_at,
+            last_used_at,
+            total_size,
+            total_files: total_size.map(|_| 1),
+        }
+    }
 }
@@ -1,29 +1,59 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use derive_more::Display;
 use log::{debug, error, info, trace, warn};
 use tokio::sync::Mutex;
 
-use crate::core::{block_in_place, torrents};
+use crate::core::{block_in_place, torrents, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks};
 use crate::core::storage::{Storage, StorageError};
-use crate::core::torrents::collection::{Collection, MagnetInfo};
-use crate::core::torrents::TorrentError;
+use crate::core::torrents::collection::{Collection, CollectionSort, MagnetInfo};
+use crate::core::torrents::{TorrentError, TorrentManager};
 
 const FILENAME: &str = "torrent-collection.json";
 
+/// The events of the torrent collection.
+#[repr(i32)]
+#[derive(Debug, Clone, Display, PartialEq)]
+pub enum TorrentCollectionEvent {
+    /// Invoked when a magnet has been added to or removed from the collection.
+    #[display(fmt = "Torrent collection has been changed")]
+    CollectionChanged = 0,
+}
+
 /// The torrent collections stores magnet uri information.
 /// This information can be queried later on for more information about the torrent itself.
+///
+/// The torrent metadata of a magnet, such as the total size and number of files, is resolved lazily
+/// through the [TorrentManager] the first time it's requested and cached within the collection afterwards.
 #[derive(Debug)]
 pub struct TorrentCollection {
     storage: Storage,
+    torrent_manager: Arc<Box<dyn TorrentManager>>,
     cache: Mutex<Option<Collection>>,
+    callbacks: CoreCallbacks<TorrentCollectionEvent>,
 }
 
 impl TorrentCollection {
-    pub fn new(storage_directory: &str) -> Self {
+    pub fn new(storage_directory: &str, torrent_manager: Arc<Box<dyn TorrentManager>>) -> Self {
         Self {
             storage: Storage::from(storage_directory),
+            torrent_manager,
             cache: Mutex::new(None),
+            callbacks: Default::default(),
         }
     }
 
+    /// Subscribe to the events of the torrent collection.
+    pub fn subscribe(&self, callback: CoreCallback<TorrentCollectionEvent>) -> CallbackHandle {
+        self.callbacks.add(callback)
+    }
+
+    /// Unsubscribe from the events of the torrent collection.
+    pub fn unsubscribe(&self, handle: CallbackHandle) {
+        self.callbacks.remove(handle)
+    }
+
     /// Verify if the given uri is already stored.
     pub fn is_stored(&self, uri: &str) -> bool {
         match futures::executor::block_on(self.load_collection_cache()) {
@@ -54,18 +84,115 @@ impl TorrentCollection {
         }
     }
 
-    /// Insert the given magnet info into the collection.
-    pub fn insert(&self, name: &str, magnet_uri: &str) {
+    /// Search the stored magnets of which the name contains the given query.
+    /// An empty query returns all stored magnets in the given sort order.
+    ///
+    /// It returns the matching [MagnetInfo] items, else the [TorrentError].
+    pub fn search(&self, query: &str, sort: CollectionSort) -> torrents::Result<Vec<MagnetInfo>> {
+        match futures::executor::block_on(self.load_collection_cache()) {
+            Ok(_) => {
+                let mutex = self.cache.blocking_lock();
+                let cache = mutex.as_ref().expect("expected the cache to be present");
+
+                Ok(cache.search(query, sort))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieve the stored magnet info of the given uri with its torrent metadata.
+    /// The metadata is resolved the first time through the [TorrentManager] and stored within the collection.
+    ///
+    /// It returns the resolved [MagnetInfo], else the [TorrentError].
+    pub async fn resolve(&self, magnet_uri: &str) -> torrents::Result<MagnetInfo> {
+        self.load_collection_cache().await?;
+        {
+            let mutex = self.cache.lock().await;
+            let cache = mutex.as_ref().expect("expected the cache to be present");
+
+            match cache.torrents.iter().find(|e| e.magnet_uri == magnet_uri) {
+                None => return Err(TorrentError::MagnetNotStored(magnet_uri.to_string())),
+                Some(info) if info.is_resolved() => {
+                    trace!("Torrent metadata of {} is already resolved", magnet_uri);
+                    return Ok(info.clone());
+                }
+                _ => {}
+            }
+        }
+
+        debug!("Resolving torrent metadata of {}", magnet_uri);
+        let torrent_info = self.torrent_manager.info(magnet_uri).await?;
+        let total_size = torrent_info
+            .files
+            .iter()
+            .map(|e| e.file_size.max(0) as u64)
+            .sum();
+        let total_files = torrent_info.total_files.max(0) as u32;
+
+        let mut mutex = self.cache.lock().await;
+        let cache = mutex.as_mut().expect("expected the cache to be present");
+        let info = cache
+            .get_mut(magnet_uri)
+            .map(|info| {
+                info.total_size = Some(total_size);
+                info.total_files = Some(total_files);
+                info.clone()
+            })
+            .ok_or_else(|| TorrentError::MagnetNotStored(magnet_uri.to_string()))?;
+
+        self.save_async(cache).await;
+        info!("Resolved torrent metadata of {}", info);
+        Ok(info)
+    }
+
+    /// Mark the given magnet uri as used at this moment.
+    pub fn mark_used(&self, magnet_uri: &str) {
         match futures::executor::block_on(self.load_collection_cache()) {
             Ok(_) => {
                 let mut mutex = self.cache.blocking_lock();
                 let cache = mutex.as_mut().expect("expected the cache to be present");
 
-                cache.insert(name, magnet_uri);
-                self.save(cache);
+                if let Some(info) = cache.get_mut(magnet_uri) {
+                    info.last_used_at = Some(Utc::now().timestamp_millis());
+                    self.save(cache);
+                } else {
+                    warn!(
+                        "Unable to mark magnet {} as used, magnet is not stored",
+                        magnet_uri
+                    );
+                }
+            }
+            Err(e) => error!("Failed to load torrent collection, {}", e),
+        }
+    }
+
+    /// Insert the given magnet info into the collection.
+    /// Magnets of a torrent which is already stored are ignored.
+    ///
+    /// It returns `true` when the magnet has been inserted, else `false`.
+    pub fn insert(&self, name: &str, magnet_uri: &str) -> bool {
+        match futures::executor::block_on(self.load_collection_cache()) {
+            Ok(_) => {
+                let inserted = {
+                    let mut mutex = self.cache.blocking_lock();
+                    let cache = mutex.as_mut().expect("expected the cache to be present");
+
+                    let inserted = cache.insert(name, magnet_uri);
+                    if inserted {
+                        self.save(cache);
+                    }
+                    inserted
+                };
+
+                if inserted {
+                    self.callbacks
+                        .invoke(TorrentCollectionEvent::CollectionChanged);
+                }
+                inserted
             }
             Err(e) => {
                 error!("Failed to load torrent collection, {}", e);
+                false
             }
         }
     }
@@ -74,11 +201,21 @@ impl TorrentCollection {
     pub fn remove(&self, magnet_uri: &str) {
         match futures::executor::block_on(self.load_collection_cache()) {
             Ok(_) => {
-                let mut mutex = self.cache.blocking_lock();
-                let cache = mutex.as_mut().expect("expected the cache to be present");
+                {
+                    let mut mutex = self.cache.blocking_lock();
+                    let cache = mutex.as_mut().expect("expected the cache to be present");
+
+                    if !cache.contains(magnet_uri) {
+                        debug!("Magnet {} is not stored, nothing to remove", magnet_uri);
+                        return;
+                    }
+
+                    cache.remove(magnet_uri);
+                    self.save(cache);
+                }
 
-                cache.remove(magnet_uri);
-                self.save(cache);
+                self.callbacks
+                    .invoke(TorrentCollectionEvent::CollectionChanged);
             }
             Err(e) => error!("Failed to remove the magnet from the collection, {}", e),
         }
@@ -149,6 +286,7 @@ impl TorrentCollection {
 mod test {
     use tempfile::tempdir;
 
+    use crate::core::torrents::{MockTorrentManager, TorrentFileInfo, TorrentInfo};
     use crate::testing::{copy_test_file, init_logger};
 
     use super::*;
@@ -159,7 +297,7 @@ mod test {
         let magnet_uri = "magnet:?MyMagnetUri1";
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let collection = TorrentCollection::new(temp_path);
+        let collection = create_collection(temp_path, MockTorrentManager::new());
         copy_test_file(temp_path, "torrent-collection.json", None);
 
         let result = collection.is_stored(magnet_uri);
@@ -174,11 +312,7 @@ mod test {
         let uri = "magnet:?LoremIpsumConn";
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let collection = TorrentCollection::new(temp_path);
-        let expected_result = vec![MagnetInfo {
-            name: name.to_string(),
-            magnet_uri: uri.to_string(),
-        }];
+        let collection = create_collection(temp_path, MockTorrentManager::new());
 
         collection.insert(name, uri);
 
@@ -186,6 +320,18 @@ mod test {
         assert_eq!(true, result);
 
         let magnets = collection.all().expect("expected magnet to be returned");
+        assert!(
+            magnets[0].added_at.is_some(),
+            "expected the added_at timestamp to have been set"
+        );
+        let expected_result = vec![MagnetInfo {
+            name: name.to_string(),
+            magnet_uri: uri.to_string(),
+            added_at: magnets[0].added_at,
+            last_used_at: None,
+            total_size: None,
+            total_files: None,
+        }];
         assert_eq!(expected_result, magnets)
     }
 
@@ -195,11 +341,12 @@ mod test {
         let uri = "magnet:?MyMagnetUri1";
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let collection = TorrentCollection::new(temp_path);
+        let collection = create_collection(temp_path, MockTorrentManager::new());
         copy_test_file(temp_path, "torrent-collection.json", None);
         let expected_result = vec![MagnetInfo {
             name: "MyMagnet2".to_string(),
             magnet_uri: "magnet:?MyMagnet2MagnetUrl".to_string(),
+            ..Default::default()
         }];
 
         collection.remove(uri);
@@ -209,4 +356,125 @@ mod test {
 
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_search() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let collection = create_collection(temp_path, MockTorrentManager::new());
+        copy_test_file(temp_path, "torrent-collection.json", None);
+
+        let result = collection
+            .search("magnet", CollectionSort::Name)
+            .expect("expected the magnets to be returned");
+
+        assert_eq!(1, result.len());
+        assert_eq!("MyMagnet2", result[0].name.as_str());
+    }
+
+    #[test]
+    fn test_resolve() {
+        init_logger();
+        let uri = "magnet:?MyMagnetUri1";
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager.expect_info().times(1).returning(|e| {
+            Ok(TorrentInfo {
+                uri: e.to_string(),
+                name: "Lorem ipsum dolor".to_string(),
+                directory_name: None,
+                total_files: 2,
+                files: vec![
+                    create_file_info("lorem.mp4", 0, 2048),
+                    create_file_info("lorem.srt", 1, 512),
+                ],
+            })
+        });
+        let collection = create_collection(temp_path, torrent_manager);
+        copy_test_file(temp_path, "torrent-collection.json", None);
+
+        let result = block_in_place(collection.resolve(uri)).unwrap();
+        assert_eq!(Some(2560), result.total_size);
+        assert_eq!(Some(2), result.total_files);
+
+        let result = block_in_place(collection.resolve(uri)).unwrap();
+        assert_eq!(
+            Some(2560),
+            result.total_size,
+            "expected the cached metadata to have been returned"
+        );
+    }
+
+    #[test]
+    fn test_resolve_not_stored() {
+        init_logger();
+        let uri = "magnet:?LoremIpsumDolor";
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager.expect_info().times(0);
+        let collection = create_collection(temp_path, torrent_manager);
+
+        let result = block_in_place(collection.resolve(uri));
+
+        assert_eq!(Err(TorrentError::MagnetNotStored(uri.to_string())), result);
+    }
+
+    #[test]
+    fn test_migrate_legacy_collection() {
+        init_logger();
+        let uri = "magnet:?MyMagnetUri1";
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        copy_test_file(temp_path, "torrent-collection.json", None);
+        let collection = create_collection(temp_path, MockTorrentManager::new());
+
+        let result = collection
+            .all()
+            .expect("expected the legacy collection to load");
+        assert_eq!(2, result.len());
+        assert_eq!(
+            MagnetInfo {
+                name: "Lorem ipsum dolor".to_string(),
+                magnet_uri: uri.to_string(),
+                added_at: None,
+                last_used_at: None,
+                total_size: None,
+                total_files: None,
+            },
+            result[0]
+        );
+
+        collection.mark_used(uri);
+        let collection = create_collection(temp_path, MockTorrentManager::new());
+        let result = collection
+            .all()
+            .expect("expected the migrated collection to load");
+        assert!(
+            result[0].last_used_at.is_some(),
+            "expected the last used timestamp to have been stored"
+        );
+        assert_eq!("Lorem ipsum dolor", result[0].name.as_str());
+    }
+
+    fn create_collection(
+        temp_path: &str,
+        torrent_manager: MockTorrentManager,
+    ) -> TorrentCollection {
+        TorrentCollection::new(
+            temp_path,
+            Arc::new(Box::new(torrent_manager) as Box<dyn TorrentManager>),
+        )
+    }
+
+    fn create_file_info(filename: &str, file_index: i32, file_size: i64) -> TorrentFileInfo {
+        TorrentFileInfo {
+            filename: filename.to_string(),
+            file_path: filename.to_string(),
+            file_size,
+            file_index,
+        }
+    }
 }
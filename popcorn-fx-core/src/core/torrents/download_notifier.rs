@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use log::{debug, trace};
+use tokio::runtime::Runtime;
+
+use crate::core::platform::PlatformData;
+use crate::core::playback::{DownloadInfo, MediaNotificationEvent};
+use crate::core::torrents::{Torrent, TorrentEvent, TorrentState};
+
+/// The default duration within which completed downloads are batched into a single notification.
+pub const DEFAULT_DOWNLOAD_NOTIFICATION_BATCH_WINDOW: Duration = Duration::from_secs(3);
+
+/// The notifier of the background downloads which have completed.
+///
+/// Only the torrents which are explicitly watched, such as the torrents of the download only mode,
+/// result in a platform notification when they complete.
+/// Watched torrents which are being streamed afterwards should be suppressed, as the user is actively watching them.
+///
+/// Downloads which complete within the batch window of each other are combined into a single notification.
+#[derive(Debug, Clone)]
+pub struct DownloadNotifier {
+    inner: Arc<InnerDownloadNotifier>,
+}
+
+impl DownloadNotifier {
+    /// Create a new download notifier which uses the [DEFAULT_DOWNLOAD_NOTIFICATION_BATCH_WINDOW].
+    ///
+    /// # Arguments
+    ///
+    /// * `platform` - The platform which shows the notifications.
+    /// * `runtime` - The runtime on which the batched notifications are scheduled.
+    pub fn new(platform: Arc<Box<dyn PlatformData>>, runtime: Arc<Runtime>) -> Self {
+        Self::with_batch_window(
+            platform,
+            runtime,
+            DEFAULT_DOWNLOAD_NOTIFICATION_BATCH_WINDOW,
+        )
+    }
+
+    /// Create a new download notifier with the given batch window.
+    pub fn with_batch_window(
+        platform: Arc<Box<dyn PlatformData>>,
+        runtime: Arc<Runtime>,
+        batch_window: Duration,
+    ) -> Self {
+        Self {
+            inner: Arc::new(InnerDownloadNotifier {
+                platform,
+                runtime,
+                batch_window,
+                watched: Default::default(),
+                pending: Default::default(),
+            }),
+        }
+    }
+
+    /// Watch the given torrent, so a notification is shown when its download completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `torrent` - The torrent to watch.
+    /// * `title` - The title of the download which is shown within the notification.
+    pub fn watch(&self, torrent: &Box<dyn Torrent>, title: &str) {
+        let handle = torrent.handle().to_string();
+
+        if torrent.state() == TorrentState::Completed {
+            trace!(
+                "Torrent {} has already been completed, not watching it",
+                handle
+            );
+            return;
+        }
+
+        {
+            let mut watched = self.inner.watched.lock().unwrap();
+            if watched.insert(handle.clone(), title.to_string()).is_some() {
+                trace!("Torrent {} is already being watched", handle);
+                return;
+            }
+        }
+
+        debug!("Watching torrent {} for its download completion", handle);
+        let inner = self.inner.clone();
+        torrent.subscribe(Box::new(move |event| {
+            if let TorrentEvent::StateChanged(TorrentState::Completed) = event {
+                inner.on_completed(&handle);
+            }
+        }));
+    }
+
+    /// Suppress the completion notification of the given torrent.
+    /// This should be used when the torrent is being streamed, as the user is actively watching it.
+    pub fn suppress(&self, handle: &str) {
+        if self.inner.watched.lock().unwrap().remove(handle).is_some() {
+            debug!(
+                "Suppressed the completion notification of torrent {}",
+                handle
+            );
+        }
+    }
+
+    /// Verify if the given torrent is being watched for its download completion.
+    pub fn is_watched(&self, handle: &str) -> bool {
+        self.inner.watched.lock().unwrap().contains_key(handle)
+    }
+}
+
+struct InnerDownloadNotifier {
+    platform: Arc<Box<dyn PlatformData>>,
+    runtime: Arc<Runtime>,
+    batch_window: Duration,
+    /// The titles of the watched torrents, stored by their handle
+    watched: Mutex<HashMap<String, String>>,
+    /// The titles of the completed downloads which still need to be notified
+    pending: Mutex<Vec<String>>,
+}
+
+impl InnerDownloadNotifier {
+    fn on_completed(self: &Arc<Self>, handle: &str) {
+        let title = match self.watched.lock().unwrap().remove(handle) {
+            Some(title) => title,
+            None => {
+                trace!(
+                    "Torrent {} is no longer watched, ignoring its completion",
+                    handle
+                );
+                return;
+            }
+        };
+
+        let mut pending = self.pending.lock().unwrap();
+        debug!("Download {} of torrent {} has completed", title, handle);
+        pending.push(title);
+
+        // the first completion of a batch schedules the notification of the whole batch
+        if pending.len() == 1 {
+            let inner = self.clone();
+            self.runtime.spawn(async move {
+                tokio::time::sleep(inner.batch_window).await;
+                inner.notify_pending();
+            });
+        }
+    }
+
+    fn notify_pending(&self) {
+        let titles: Vec<String> = self.pending.lock().unwrap().drain(..).collect();
+
+        if !titles.is_empty() {
+            debug!("Notifying platform of {} completed downloads", titles.len());
+            self.platform
+                .notify_media_event(MediaNotificationEvent::DownloadCompleted(DownloadInfo {
+                    titles,
+                }));
+        }
+    }
+}
+
+impl Debug for InnerDownloadNotifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerDownloadNotifier")
+            .field("platform", &self.platform)
+            .field("batch_window", &self.batch_window)
+            .field("watched", &self.watched)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc::channel;
+
+    use crate::core::torrents::{MockTorrent, TorrentCallback};
+    use crate::core::Handle;
+    use crate::testing::{init_logger, MockDummyPlatformData};
+
+    use super::*;
+
+    #[test]
+    fn test_watch_batches_completions() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut platform = MockDummyPlatformData::new();
+        platform
+            .expect_notify_media_event()
+            .returning(move |event| tx.send(event).unwrap());
+        let notifier = create_notifier(platform);
+        let (torrent1, callback1) = create_torrent("torrent1");
+        let (torrent2, callback2) = create_torrent("torrent2");
+
+        notifier.watch(&torrent1, "Lorem");
+        notifier.watch(&torrent2, "Ipsum");
+        invoke_completed(&callback1);
+        invoke_completed(&callback2);
+
+        let result = rx.recv_timeout(Duration::from_millis(500)).unwrap();
+        assert_eq!(
+            MediaNotificationEvent::DownloadCompleted(DownloadInfo {
+                titles: vec!["Lorem".to_string(), "Ipsum".to_string()],
+            }),
+            result
+        );
+        assert!(
+            rx.recv_timeout(Duration::from_millis(200)).is_err(),
+            "expected only a single notification"
+        );
+    }
+
+    #[test]
+    fn test_suppress() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut platform = MockDummyPlatformData::new();
+        platform
+            .expect_notify_media_event()
+            .returning(move |event| tx.send(event).unwrap());
+        let notifier = create_notifier(platform);
+        let (torrent, callback) = create_torrent("torrent1");
+
+        notifier.watch(&torrent, "Lorem");
+        assert!(notifier.is_watched("torrent1"));
+        notifier.suppress("torrent1");
+        invoke_completed(&callback);
+
+        assert!(!notifier.is_watched("torrent1"));
+        assert!(
+            rx.recv_timeout(Duration::from_millis(200)).is_err(),
+            "expected no notification for a suppressed torrent"
+        );
+    }
+
+    fn create_notifier(platform: MockDummyPlatformData) -> DownloadNotifier {
+        DownloadNotifier::with_batch_window(
+            Arc::new(Box::new(platform) as Box<dyn PlatformData>),
+            Arc::new(Runtime::new().unwrap()),
+            Duration::from_millis(50),
+        )
+    }
+
+    fn create_torrent(handle: &str) -> (Box<dyn Torrent>, Arc<Mutex<Option<TorrentCallback>>>) {
+        let callback: Arc<Mutex<Option<TorrentCallback>>> = Default::default();
+        let subscribe_callback = callback.clone();
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        torrent.expect_subscribe().returning(move |e| {
+            *subscribe_callback.lock().unwrap() = Some(e);
+            Handle::new()
+        });
+
+        (Box::new(torrent), callback)
+    }
+
+    fn invoke_completed(callback: &Arc<Mutex<Option<TorrentCallback>>>) {
+        let mutex = callback.lock().unwrap();
+        let callback = mutex
+            .as_ref()
+            .expect("expected the torrent to be subscribed");
+
+        callback(TorrentEvent::StateChanged(TorrentState::Completed));
+    }
+}
@@ -13,6 +13,8 @@ pub enum TorrentError {
     InvalidUrl(String),
     #[error("Torrent file {0} cannot be found")]
     FileNotFound(String),
+    #[error("Torrent file index {0} is out of range, torrent contains {1} files")]
+    InvalidFileIndex(i32, usize),
     #[error("Torrent file encountered an error, {0}")]
     FileError(String),
     #[error("Torrent stream has invalid state {0}")]
@@ -25,4 +27,8 @@ pub enum TorrentError {
     TorrentResolvingFailed(String),
     #[error("Failed to load the torrent collection, {0}")]
     TorrentCollectionLoadingFailed(String),
+    #[error("Magnet {0} is not stored within the torrent collection")]
+    MagnetNotStored(String),
+    #[error("Failed to write the torrent data, {0}")]
+    StorageError(String),
 }
@@ -0,0 +1,455 @@
+use log::{trace, warn};
+use ring::digest;
+use ring::digest::digest;
+use thiserror::Error;
+use url::Url;
+
+pub type InfoHashResult = Result<InfoHash, InfoHashError>;
+
+const MAGNET_SCHEME: &str = "magnet";
+const INFO_HASH_V1_PREFIX: &str = "urn:btih:";
+const INFO_HASH_V2_PREFIX: &str = "urn:btmh:";
+/// The multihash prefix of a SHA-256 digest with a length of 32 bytes.
+const MULTIHASH_SHA256_PREFIX: &str = "1220";
+const INFO_KEY: &[u8] = b"info";
+const PIECES_KEY: &[u8] = b"pieces";
+const META_VERSION_KEY: &[u8] = b"meta version";
+const META_VERSION_V2: &[u8] = b"i2e";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Represents possible errors that can occur when computing an info hash.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InfoHashError {
+    #[error("invalid magnet uri, {0}")]
+    InvalidMagnet(String),
+    #[error("invalid torrent metadata, {0}")]
+    InvalidMetadata(String),
+    #[error("no info hash could be found")]
+    NotFound,
+}
+
+/// The BitTorrent protocol version of a torrent.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TorrentVersion {
+    /// The torrent only contains v1 (SHA-1) piece hashes.
+    V1,
+    /// The torrent only contains v2 (SHA-256) merkle trees.
+    V2,
+    /// The torrent contains both v1 piece hashes and v2 merkle trees.
+    Hybrid,
+}
+
+/// The info hash which uniquely identifies a torrent.
+///
+/// A torrent can be identified by a v1 (SHA-1) hash, a v2 (SHA-256) hash or both in case of a hybrid torrent.
+/// The hashes are stored as lowercase hex strings to allow them to be compared with other info hashes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InfoHash {
+    v1: Option<String>,
+    v2: Option<String>,
+}
+
+impl InfoHash {
+    /// Get the v1 (SHA-1) info hash as a hex string, if present.
+    pub fn v1(&self) -> Option<&str> {
+        self.v1.as_deref()
+    }
+
+    /// Get the v2 (SHA-256) info hash as a hex string, if present.
+    pub fn v2(&self) -> Option<&str> {
+        self.v2.as_deref()
+    }
+
+    /// Verify if the info hash contains both a v1 and v2 hash.
+    pub fn is_hybrid(&self) -> bool {
+        self.v1.is_some() && self.v2.is_some()
+    }
+
+    /// Verify if the given info hash identifies the same torrent as this info hash.
+    /// This is the case when either the v1 or the v2 hashes are equal, so a hybrid info hash matches
+    /// the info hash of each of its versions.
+    pub fn matches(&self, other: &InfoHash) -> bool {
+        let v1_matches = self.v1.is_some() && self.v1 == other.v1;
+        let v2_matches = self.v2.is_some() && self.v2 == other.v2;
+
+        v1_matches || v2_matches
+    }
+
+    /// Get the BitTorrent protocol version of the torrent identified by this info hash.
+    pub fn version(&self) -> TorrentVersion {
+        match (self.v1.is_some(), self.v2.is_some()) {
+            (true, true) => TorrentVersion::Hybrid,
+            (false, true) => TorrentVersion::V2,
+            _ => TorrentVersion::V1,
+        }
+    }
+
+    /// Compute the info hash of the given magnet uri.
+    ///
+    /// All exact topics of the magnet are inspected, so a hybrid magnet which contains both a `btih` and `btmh` topic
+    /// returns both hashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The magnet uri to compute the info hash of.
+    ///
+    /// # Returns
+    ///
+    /// The info hash of the magnet, or an [InfoHashError] when the magnet doesn't contain a valid BitTorrent topic.
+    pub fn from_magnet(uri: &str) -> InfoHashResult {
+        let uri = Url::parse(uri).map_err(|e| InfoHashError::InvalidMagnet(e.to_string()))?;
+        if uri.scheme() != MAGNET_SCHEME {
+            return Err(InfoHashError::InvalidMagnet(format!(
+                "unsupported scheme {}",
+                uri.scheme()
+            )));
+        }
+
+        let mut v1 = None;
+        let mut v2 = None;
+        for (key, value) in uri.query_pairs() {
+            if key.to_lowercase() != "xt" {
+                continue;
+            }
+
+            let exact_topic = value.to_lowercase();
+            if let Some(hash) = exact_topic.strip_prefix(INFO_HASH_V1_PREFIX) {
+                v1 = Some(Self::parse_v1_topic(hash)?);
+            } else if let Some(hash) = exact_topic.strip_prefix(INFO_HASH_V2_PREFIX) {
+                v2 = Some(Self::parse_v2_topic(hash)?);
+            } else {
+                warn!("Unsupported magnet exact topic {}", exact_topic);
+            }
+        }
+
+        Self::new(v1, v2)
+    }
+
+    /// Compute the info hash of the given torrent file bytes.
+    ///
+    /// The v1 hash is computed when the info dictionary contains the v1 piece hashes,
+    /// the v2 hash is computed when the info dictionary has a meta version of 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bencoded bytes of the torrent file.
+    ///
+    /// # Returns
+    ///
+    /// The info hash of the torrent, or an [InfoHashError] when the bytes are not valid torrent metadata.
+    pub fn from_file_bytes(bytes: &[u8]) -> InfoHashResult {
+        let (entries, _) = bencode::dict_entries(bytes, 0)?;
+        let (info_start, info_end) = entries
+            .iter()
+            .find(|(key, _, _)| *key == INFO_KEY)
+            .map(|(_, start, end)| (*start, *end))
+            .ok_or(InfoHashError::InvalidMetadata(
+                "missing info dictionary".to_string(),
+            ))?;
+        let info = &bytes[info_start..info_end];
+        let (info_entries, _) = bencode::dict_entries(info, 0)?;
+        trace!("Computing info hash of {} info bytes", info.len());
+
+        let has_v1 = info_entries.iter().any(|(key, _, _)| *key == PIECES_KEY);
+        let has_v2 = info_entries.iter().any(|(key, start, end)| {
+            *key == META_VERSION_KEY && &info[*start..*end] == META_VERSION_V2
+        });
+
+        Self::new(
+            Some(Self::hex(
+                digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, info).as_ref(),
+            ))
+            .filter(|_| has_v1),
+            Some(Self::hex(digest(&digest::SHA256, info).as_ref())).filter(|_| has_v2),
+        )
+    }
+
+    fn new(v1: Option<String>, v2: Option<String>) -> InfoHashResult {
+        if v1.is_none() && v2.is_none() {
+            return Err(InfoHashError::NotFound);
+        }
+
+        Ok(Self { v1, v2 })
+    }
+
+    /// Parse the v1 hash of a `btih` topic, which is either hex or base32 encoded.
+    fn parse_v1_topic(hash: &str) -> Result<String, InfoHashError> {
+        match hash.len() {
+            40 if hash.chars().all(|e| e.is_ascii_hexdigit()) => Ok(hash.to_string()),
+            32 => Self::base32_decode(hash)
+                .map(|e| Self::hex(e.as_slice()))
+                .ok_or_else(|| {
+                    InfoHashError::InvalidMagnet(format!("invalid base32 info hash {}", hash))
+                }),
+            _ => Err(InfoHashError::InvalidMagnet(format!(
+                "invalid v1 info hash {}",
+                hash
+            ))),
+        }
+    }
+
+    /// Parse the v2 hash of a `btmh` topic, which is a hex encoded SHA-256 multihash.
+    fn parse_v2_topic(hash: &str) -> Result<String, InfoHashError> {
+        hash.strip_prefix(MULTIHASH_SHA256_PREFIX)
+            .filter(|e| e.len() == 64 && e.chars().all(|e| e.is_ascii_hexdigit()))
+            .map(|e| e.to_string())
+            .ok_or_else(|| InfoHashError::InvalidMagnet(format!("invalid v2 info hash {}", hash)))
+    }
+
+    fn base32_decode(value: &str) -> Option<Vec<u8>> {
+        let mut result = Vec::new();
+        let mut buffer = 0u32;
+        let mut bits = 0;
+
+        for byte in value.bytes() {
+            let index = BASE32_ALPHABET.iter().position(|e| *e == byte)? as u32;
+            buffer = (buffer << 5) | index;
+            bits += 5;
+
+            if bits >= 8 {
+                bits -= 8;
+                result.push((buffer >> bits) as u8);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+
+        Some(result)
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    }
+}
+
+/// Minimal bencode scanner which locates the raw byte ranges of values without decoding them.
+mod bencode {
+    use super::InfoHashError;
+
+    /// A dictionary entry which consists out of the key and the start & end offset of the raw value.
+    pub type DictEntry<'a> = (&'a [u8], usize, usize);
+
+    /// Retrieve the entries of the dictionary which starts at the given offset.
+    /// It returns the entries and the offset after the end of the dictionary.
+    pub fn dict_entries(
+        data: &[u8],
+        offset: usize,
+    ) -> Result<(Vec<DictEntry<'_>>, usize), InfoHashError> {
+        if data.get(offset) != Some(&b'd') {
+            return Err(invalid("expected a dictionary"));
+        }
+
+        let mut entries = Vec::new();
+        let mut position = offset + 1;
+        while data.get(position) != Some(&b'e') {
+            let (key_start, key_end) = string_range(data, position)?;
+            let value_end = skip_value(data, key_end)?;
+            entries.push((&data[key_start..key_end], key_end, value_end));
+            position = value_end;
+        }
+
+        Ok((entries, position + 1))
+    }
+
+    /// Skip the value at the given offset and return the offset after the end of the value.
+    fn skip_value(data: &[u8], offset: usize) -> Result<usize, InfoHashError> {
+        match data.get(offset) {
+            Some(b'i') => data[offset..]
+                .iter()
+                .position(|e| *e == b'e')
+                .map(|e| offset + e + 1)
+                .ok_or(invalid("unterminated integer")),
+            Some(b'l') => {
+                let mut position = offset + 1;
+                while data.get(position) != Some(&b'e') {
+                    position = skip_value(data, position)?;
+                }
+                Ok(position + 1)
+            }
+            Some(b'd') => dict_entries(data, offset).map(|(_, end)| end),
+            Some(e) if e.is_ascii_digit() => string_range(data, offset).map(|(_, end)| end),
+            Some(_) => Err(invalid("unexpected value type")),
+            None => Err(invalid("unexpected end of data")),
+        }
+    }
+
+    /// Retrieve the start and end offset of the byte string contents at the given offset.
+    fn string_range(data: &[u8], offset: usize) -> Result<(usize, usize), InfoHashError> {
+        let separator = data
+            .get(offset..)
+            .and_then(|e| e.iter().position(|e| *e == b':'))
+            .map(|e| offset + e)
+            .ok_or(invalid("expected a byte string"))?;
+        let len = std::str::from_utf8(&data[offset..separator])
+            .ok()
+            .and_then(|e| e.parse::<usize>().ok())
+            .ok_or(invalid("invalid byte string length"))?;
+        let start = separator + 1;
+        let end = start + len;
+
+        if end > data.len() {
+            return Err(invalid("byte string exceeds the data length"));
+        }
+
+        Ok((start, end))
+    }
+
+    fn invalid(message: &str) -> InfoHashError {
+        InfoHashError::InvalidMetadata(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::read_test_file_to_bytes;
+
+    use super::*;
+
+    const V1_INFO: &str = "d6:lengthi12e4:name9:lorem.txt12:piece lengthi16384e6:pieces0:e";
+    const V2_INFO: &str = "d12:meta versioni2e4:name9:lorem.txt12:piece lengthi16384ee";
+    const HYBRID_INFO: &str =
+        "d6:lengthi12e12:meta versioni2e4:name9:lorem.txt12:piece lengthi16384e6:pieces0:e";
+
+    fn torrent(info: &str) -> Vec<u8> {
+        format!("d8:announce14:http://tracker4:info{}e", info).into_bytes()
+    }
+
+    #[test]
+    fn test_from_magnet_v1() {
+        let result =
+            InfoHash::from_magnet("magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7")
+                .unwrap();
+
+        assert_eq!(
+            Some("eadaf0efea39406914414d359e0ea16416409bd7"),
+            result.v1()
+        );
+        assert_eq!(None, result.v2());
+    }
+
+    #[test]
+    fn test_from_magnet_v1_base32() {
+        let result =
+            InfoHash::from_magnet("magnet:?xt=urn:btih:5LNPB37KHFAGSFCBJU2Z4DVBMQLEBG6X").unwrap();
+
+        assert_eq!(
+            Some("eadaf0efea39406914414d359e0ea16416409bd7"),
+            result.v1()
+        );
+    }
+
+    #[test]
+    fn test_from_magnet_hybrid() {
+        let result = InfoHash::from_magnet("magnet:?xt=urn:btih:631a31dd0a46257d5078c0dee4e66e26f73e42ac&xt=urn:btmh:1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb&dn=bittorrent-v1-v2-hybrid-test").unwrap();
+
+        assert_eq!(
+            Some("631a31dd0a46257d5078c0dee4e66e26f73e42ac"),
+            result.v1()
+        );
+        assert_eq!(
+            Some("d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb"),
+            result.v2()
+        );
+        assert_eq!(true, result.is_hybrid());
+        assert_eq!(TorrentVersion::Hybrid, result.version());
+    }
+
+    #[test]
+    fn test_from_magnet_v2_only() {
+        let result = InfoHash::from_magnet("magnet:?xt=urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e&dn=bittorrent-v2-test").unwrap();
+
+        assert_eq!(None, result.v1());
+        assert_eq!(
+            Some("caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e"),
+            result.v2()
+        );
+        assert_eq!(TorrentVersion::V2, result.version());
+    }
+
+    #[test]
+    fn test_matches() {
+        let hybrid = InfoHash::from_magnet("magnet:?xt=urn:btih:631a31dd0a46257d5078c0dee4e66e26f73e42ac&xt=urn:btmh:1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb").unwrap();
+        let v1 = InfoHash::from_magnet(
+            "magnet:?xt=urn:btih:631A31DD0A46257D5078C0DEE4E66E26F73E42AC&dn=lorem",
+        )
+        .unwrap();
+        let other =
+            InfoHash::from_magnet("magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7")
+                .unwrap();
+
+        assert_eq!(true, hybrid.matches(&v1));
+        assert_eq!(true, v1.matches(&hybrid));
+        assert_eq!(false, v1.matches(&other));
+    }
+
+    #[test]
+    fn test_from_magnet_invalid() {
+        assert_eq!(
+            Err(InfoHashError::NotFound),
+            InfoHash::from_magnet("magnet:?xt=urn:sha1:YNCKHTQCWBTRNJIV4WNAE52SJUQCZO5C")
+        );
+        assert!(InfoHash::from_magnet("https://localhost/lorem.torrent").is_err());
+        assert!(InfoHash::from_magnet("magnet:?xt=urn:btih:lorem").is_err());
+    }
+
+    #[test]
+    fn test_from_file_bytes_v1() {
+        let result = InfoHash::from_file_bytes(torrent(V1_INFO).as_slice()).unwrap();
+
+        assert_eq!(
+            Some("7510f3ba36f619d005eb3919c526ad2d1aa39120"),
+            result.v1()
+        );
+        assert_eq!(None, result.v2());
+        assert_eq!(TorrentVersion::V1, result.version());
+    }
+
+    #[test]
+    fn test_from_file_bytes_v2() {
+        let result = InfoHash::from_file_bytes(torrent(V2_INFO).as_slice()).unwrap();
+
+        assert_eq!(None, result.v1());
+        assert_eq!(
+            Some("2efa141ba629e4f764c93abaffbbcbf9d3c063acb777546276df706ce52c579f"),
+            result.v2()
+        );
+        assert_eq!(TorrentVersion::V2, result.version());
+    }
+
+    #[test]
+    fn test_from_file_bytes_hybrid() {
+        let result = InfoHash::from_file_bytes(torrent(HYBRID_INFO).as_slice()).unwrap();
+
+        assert_eq!(true, result.is_hybrid());
+        assert_eq!(TorrentVersion::Hybrid, result.version());
+    }
+
+    #[test]
+    fn test_from_file_bytes_invalid() {
+        assert_eq!(
+            Err(InfoHashError::InvalidMetadata(
+                "missing info dictionary".to_string()
+            )),
+            InfoHash::from_file_bytes(b"d8:announce14:http://trackere")
+        );
+        assert!(InfoHash::from_file_bytes(b"d4:infod6:pieces20:abce").is_err());
+        assert!(InfoHash::from_file_bytes(b"lorem").is_err());
+    }
+
+    #[test]
+    fn test_from_file_bytes_v2_fixture() {
+        let bytes = read_test_file_to_bytes("bittorrent-v2-test.torrent");
+
+        let result = InfoHash::from_file_bytes(bytes.as_slice()).unwrap();
+
+        assert_eq!(None, result.v1());
+        assert_eq!(
+            Some("ef813dfa2e6fd12de11283e1c959831e7211deedfd9aaace6bd4aad3a7a20b90"),
+            result.v2()
+        );
+        assert_eq!(TorrentVersion::V2, result.version());
+    }
+}
@@ -6,6 +6,11 @@ use url::Url;
 
 pub type MagnetResult = Result<Magnet, MagnetError>;
 
+const INFO_HASH_V1_PREFIX: &str = "urn:btih:";
+const INFO_HASH_V2_PREFIX: &str = "urn:btmh:";
+/// The multihash prefix of a SHA-256 digest with a length of 32 bytes.
+const SHA256_MULTIHASH_PREFIX: &str = "1220";
+
 /// Represents possible errors that can occur when parsing a magnet URI.
 #[derive(Debug, Error)]
 pub enum MagnetError {
@@ -87,6 +92,27 @@ impl Magnet {
         self.peer.as_ref().map(|e| e.as_str())
     }
 
+    /// Gets the info hash of the torrent from the 'xt' (exact topic) value.
+    /// The info hash is normalized to lowercase to allow it to be compared with other info hashes.
+    /// The multihash prefix of a v2 info hash is stripped, so only the SHA-256 digest is returned.
+    ///
+    /// # Returns
+    ///
+    /// The info hash if the exact topic is a BitTorrent urn, else [None].
+    pub fn info_hash(&self) -> Option<String> {
+        let exact_topic = self.exact_topic.to_lowercase();
+
+        exact_topic
+            .strip_prefix(INFO_HASH_V1_PREFIX)
+            .or_else(|| {
+                exact_topic
+                    .strip_prefix(INFO_HASH_V2_PREFIX)
+                    .map(|e| e.strip_prefix(SHA256_MULTIHASH_PREFIX).unwrap_or(e))
+            })
+            .filter(|e| !e.is_empty())
+            .map(|e| e.to_string())
+    }
+
     /// Parses a magnet URI and constructs a `Magnet` instance.
     pub fn from_str(uri: &str) -> MagnetResult {
         let uri = Url::parse(uri).map_err(|e| MagnetError::Parse(e.to_string()))?;
@@ -315,6 +341,46 @@ mod tests {
         assert_eq!(Some(display_name), result)
     }
 
+    #[test]
+    fn test_info_hash() {
+        init_logger();
+        let magnet =
+            Magnet::from_str("magnet:?xt=urn:btih:6B0CD35C4A6B7240B93D1E159F8C82B841D83A7A")
+                .unwrap();
+
+        let result = magnet.info_hash();
+
+        assert_eq!(
+            Some("6b0cd35c4a6b7240b93d1e159f8c82b841d83a7a".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn test_info_hash_v2() {
+        init_logger();
+        let magnet = Magnet::from_str("magnet:?xt=urn:btmh:1220EF813DFA2E6FD12DE11283E1C959831E7211DEEDFD9AAACE6BD4AAD3A7A20B90")
+            .unwrap();
+
+        let result = magnet.info_hash();
+
+        assert_eq!(
+            Some("ef813dfa2e6fd12de11283e1c959831e7211deedfd9aaace6bd4aad3a7a20b90".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn test_info_hash_unknown_topic() {
+        init_logger();
+        let magnet = Magnet::from_str("magnet:?xt=urn:sha1:YNCKHTQCWBTRNJIV4WNAE52SJUQCZO5C")
+            .unwrap();
+
+        let result = magnet.info_hash();
+
+        assert_eq!(None, result);
+    }
+
     #[test]
     fn test_from_str() {
         init_logger();
@@ -7,12 +7,15 @@ use downcast_rs::{DowncastSync, impl_downcast};
 #[cfg(any(test, feature = "testing"))]
 use mockall::automock;
 
-use crate::core::{CoreCallback, torrents};
-use crate::core::torrents::{Torrent, TorrentFileInfo, TorrentInfo};
+use crate::core::{CallbackHandle, CoreCallback, torrents};
+use crate::core::torrents::{Torrent, TorrentFileInfo, TorrentInfo, TorrentState};
 
 /// The callback type for the torrent manager events.
 pub type TorrentManagerCallback = CoreCallback<TorrentManagerEvent>;
 
+/// The callback type for the events of a single torrent within the manager.
+pub type TorrentHandleCallback = CoreCallback<TorrentHandleEvent>;
+
 /// The states of the [TorrentManager].
 #[repr(i32)]
 #[derive(Debug, Display, Clone, PartialEq)]
@@ -46,6 +49,56 @@ impl Display for TorrentManagerEvent {
     }
 }
 
+/// The events of a single torrent session within the [TorrentManager].
+#[derive(Debug, Clone, Display, PartialEq)]
+pub enum TorrentHandleEvent {
+    /// Indicates that the state of the torrent has changed.
+    #[display(fmt = "Torrent state changed to {}", _0)]
+    StateChanged(TorrentState),
+    /// Indicates that the metadata of the torrent has been received.
+    #[display(fmt = "Torrent metadata has been received")]
+    MetadataReceived,
+    /// Indicates that the torrent has completed downloading.
+    #[display(fmt = "Torrent download has completed")]
+    Completed,
+    /// Indicates that the torrent has been removed from the manager.
+    /// This is always the last event of a subscription, no further events will be received after it.
+    #[display(fmt = "Torrent has been removed")]
+    Removed,
+}
+
+/// The result of adding a torrent to the [TorrentManager].
+#[derive(Debug, Clone)]
+pub struct AddTorrentResult {
+    /// The torrent which has been added, or the torrent which was already present.
+    pub torrent: Weak<Box<dyn Torrent>>,
+    /// Indicates if the torrent was already present within the manager.
+    /// When `true`, no new torrent has been created and the existing torrent is returned instead.
+    pub already_present: bool,
+}
+
+/// The summary of an active torrent within the [TorrentManager].
+///
+/// The summary is a snapshot of the last known stats of the torrent,
+/// which makes it cheap to retrieve for all active torrents at once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TorrentSummary {
+    /// The unique handle of the torrent.
+    pub handle: String,
+    /// The info hash of the torrent, if known.
+    pub info_hash: Option<String>,
+    /// The name of the torrent file which is being downloaded.
+    pub name: String,
+    /// The last known state of the torrent.
+    pub state: TorrentState,
+    /// The last known progress between 0 and 1 of the torrent.
+    pub progress: f32,
+    /// The last known download rate in bytes per second.
+    pub download_speed: u32,
+    /// The last known upload rate in bytes per second.
+    pub upload_speed: u32,
+}
+
 /// The torrent manager stores the active sessions and torrents that are being processed.
 #[cfg_attr(any(test, feature = "testing"), automock)]
 #[async_trait]
@@ -79,6 +132,9 @@ pub trait TorrentManager: Debug + DowncastSync {
 
     /// Create a new torrent session based on the provided file information.
     ///
+    /// If a torrent session is already active for the same file within the torrent directory,
+    /// the existing session is returned instead of creating a second one downloading to the same file.
+    ///
     /// # Arguments
     ///
     /// * `file_info` - The file information for the torrent.
@@ -87,14 +143,14 @@ pub trait TorrentManager: Debug + DowncastSync {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a weak reference to the created torrent session on success,
+    /// A `Result` containing the created or already present torrent session on success,
     /// or a [torrent::TorrentError] on failure.
     async fn create(
         &self,
         file_info: &TorrentFileInfo,
         torrent_directory: &str,
         auto_download: bool,
-    ) -> torrents::Result<Weak<Box<dyn Torrent>>>;
+    ) -> torrents::Result<AddTorrentResult>;
 
     /// Retrieve a torrent session by its unique handle.
     ///
@@ -107,6 +163,49 @@ pub trait TorrentManager: Debug + DowncastSync {
     /// An `Option` containing a weak reference to the torrent session if found, or `None` if not found.
     fn by_handle(&self, handle: &str) -> Option<Weak<Box<dyn Torrent>>>;
 
+    /// Retrieve an existing torrent session by the info hash of the torrent and the file being downloaded.
+    ///
+    /// This allows a torrent which is still present within the session, e.g. from a previous playback of the same media,
+    /// to be reused instead of creating a new torrent session.
+    ///
+    /// # Arguments
+    ///
+    /// * `info_hash` - The info hash of the torrent.
+    /// * `file_info` - The file information of the torrent file that is being downloaded.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a weak reference to the existing torrent session if found, or `None` if not found.
+    fn by_info_hash(
+        &self,
+        info_hash: &str,
+        file_info: &TorrentFileInfo,
+    ) -> Option<Weak<Box<dyn Torrent>>>;
+
+    /// Find an active torrent session by the info hash of the torrent.
+    ///
+    /// The info hash is the same for a magnet link and a torrent file of the same content,
+    /// which allows duplicate torrents to be detected regardless of how they have been added.
+    ///
+    /// # Arguments
+    ///
+    /// * `info_hash` - The info hash of the torrent.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a weak reference to the active torrent session if found, or `None` if not found.
+    fn find_torrent(&self, info_hash: &str) -> Option<Weak<Box<dyn Torrent>>>;
+
+    /// Retrieve the summaries of all active torrent sessions.
+    ///
+    /// The summaries are based on the last known stats of the torrents, the torrents themselves are not queried.
+    /// Torrents which are being removed are not included.
+    ///
+    /// # Returns
+    ///
+    /// The summaries of the active torrents in the order they have been added.
+    fn active_torrents(&self) -> Vec<TorrentSummary>;
+
     /// Remove a torrent session by its unique handle.
     ///
     /// # Arguments
@@ -114,6 +213,64 @@ pub trait TorrentManager: Debug + DowncastSync {
     /// * `handle` - The unique handle of the torrent session to remove.
     fn remove(&self, handle: &str);
 
+    /// Subscribe to the events of the torrent session with the given handle.
+    ///
+    /// When the torrent session no longer exists, the callback immediately receives [TorrentHandleEvent::Removed]
+    /// and no subscription is made.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The unique handle of the torrent session to subscribe to.
+    /// * `callback` - The callback which is invoked for each event of the torrent session.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the subscription if the torrent session is active, else `None`.
+    fn subscribe(&self, handle: &str, callback: TorrentHandleCallback) -> Option<CallbackHandle>;
+
+    /// Unsubscribe from the events of the torrent session with the given handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The unique handle of the torrent session.
+    /// * `callback_handle` - The subscription handle returned by [TorrentManager::subscribe].
+    fn unsubscribe(&self, handle: &str, callback_handle: CallbackHandle);
+
+    /// Pause the torrent session with the given handle on behalf of the user.
+    ///
+    /// A torrent which has been paused by the user stays paused when the global pause is lifted through [TorrentManager::resume_all].
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The unique handle of the torrent session to pause.
+    fn pause(&self, handle: &str);
+
+    /// Resume the torrent session with the given handle which has been paused by the user.
+    ///
+    /// The torrent stays paused while all torrents are paused through [TorrentManager::pause_all].
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The unique handle of the torrent session to resume.
+    fn resume(&self, handle: &str);
+
+    /// Pause all torrent sessions of the manager at once.
+    ///
+    /// Torrents which are added while the manager is paused, will start in a paused state.
+    fn pause_all(&self);
+
+    /// Resume all torrent sessions which have been paused through [TorrentManager::pause_all].
+    ///
+    /// Torrents which have been paused individually by the user are not resumed.
+    fn resume_all(&self);
+
+    /// Verify if all torrent sessions of the manager are paused.
+    ///
+    /// # Returns
+    ///
+    /// It returns `true` when the manager is globally paused, else `false`.
+    fn is_paused(&self) -> bool;
+
     /// Cleanup the torrents directory.
     ///
     /// This operation removes all torrents from the filesystem.
@@ -123,7 +280,9 @@ impl_downcast!(sync TorrentManager);
 
 #[cfg(test)]
 mod test {
-    use crate::core::torrents::{TorrentManagerEvent, TorrentManagerState};
+    use crate::core::torrents::{
+        TorrentHandleEvent, TorrentManagerEvent, TorrentManagerState, TorrentState,
+    };
 
     #[test]
     fn test_torrent_manager_event_display() {
@@ -133,4 +292,13 @@ mod test {
         assert_eq!("Manager state changed to Error".to_string(), error);
         assert_eq!("Manager state changed to Running".to_string(), running);
     }
+
+    #[test]
+    fn test_torrent_handle_event_display() {
+        let state = TorrentHandleEvent::StateChanged(TorrentState::Paused).to_string();
+        let removed = TorrentHandleEvent::Removed.to_string();
+
+        assert_eq!("Torrent state changed to Paused".to_string(), state);
+        assert_eq!("Torrent has been removed".to_string(), removed);
+    }
 }
@@ -1,4 +1,6 @@
+pub use download_notifier::*;
 pub use errors::*;
+pub use info_hash::*;
 pub use magnet::*;
 pub use manager::*;
 pub use stream_server::*;
@@ -7,7 +9,9 @@ pub use torrents::*;
 pub use wrapper::*;
 
 pub mod collection;
+mod download_notifier;
 mod errors;
+mod info_hash;
 mod magnet;
 mod manager;
 pub mod stream;
@@ -1,7 +1,12 @@
 use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
+use futures::Stream;
 use hyper::Body;
 use itertools::Itertools;
 use log::{debug, error, info, trace, warn};
@@ -14,11 +19,12 @@ use warp::http::{HeaderValue, Response, StatusCode};
 use warp::hyper::HeaderMap;
 use warp::{hyper, Filter, Rejection};
 
+use crate::core::config::ApplicationConfig;
 use crate::core::torrents::stream::torrent_stream::DefaultTorrentStream;
 use crate::core::torrents::stream::{MediaType, MediaTypeFactory, Range};
 use crate::core::torrents::{
-    Torrent, TorrentError, TorrentStream, TorrentStreamCallback, TorrentStreamServer,
-    TorrentStreamServerState,
+    StreamBytesResult, Torrent, TorrentError, TorrentManager, TorrentStream, TorrentStreamCallback,
+    TorrentStreamServer, TorrentStreamServerState, TorrentStreamingResourceWrapper,
 };
 use crate::core::utils::network::available_socket;
 use crate::core::{block_in_place, torrents, CallbackHandle, Handle};
@@ -36,9 +42,12 @@ const DLNA_REAL_TIME_TYPE: &str = "DLNA.ORG_TLAG=*";
 const DLNA_CONTENT_FEATURES: &str =
     "DLNA.ORG_OP=01;DLNA.ORG_CI=0;DLNA.ORG_FLAGS=01100000000000000000000000000000";
 const PLAIN_TEXT_TYPE: &str = "text/plain";
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
 
 /// The stream mutex type used within the server.
 type StreamMutex = HashMap<String, Arc<Box<dyn TorrentStream>>>;
+/// The stream activity mutex type used within the server.
+type ActivityMutex = HashMap<String, Arc<StreamActivity>>;
 
 /// The default server implementation for streaming torrents over HTTP.
 #[derive(Debug)]
@@ -47,6 +56,11 @@ pub struct DefaultTorrentStreamServer {
 }
 
 impl DefaultTorrentStreamServer {
+    /// Create a new builder for the torrent stream server.
+    pub fn builder() -> DefaultTorrentStreamServerBuilder {
+        DefaultTorrentStreamServerBuilder::default()
+    }
+
     fn instance(&self) -> Arc<TorrentStreamServerInner> {
         self.inner.clone()
     }
@@ -68,6 +82,10 @@ impl TorrentStreamServer for DefaultTorrentStreamServer {
         self.inner.stop_stream(handle)
     }
 
+    fn available_ranges(&self, handle: Handle) -> Option<Vec<std::ops::Range<u64>>> {
+        self.inner.available_ranges(handle)
+    }
+
     fn subscribe(&self, handle: Handle, callback: TorrentStreamCallback) -> Option<CallbackHandle> {
         self.inner.subscribe(handle, callback)
     }
@@ -79,12 +97,50 @@ impl TorrentStreamServer for DefaultTorrentStreamServer {
 
 impl Default for DefaultTorrentStreamServer {
     fn default() -> Self {
-        let wrapper = TorrentStreamServerInner::default();
-        let instance = Self {
+        Self::builder().build()
+    }
+}
+
+/// The builder for the [DefaultTorrentStreamServer].
+///
+/// When the application settings are provided, streams which have no reader activity
+/// for the configured idle timeout will be stopped automatically,
+/// and the configured initial buffer size is applied to the created streams.
+#[derive(Debug, Default)]
+pub struct DefaultTorrentStreamServerBuilder {
+    settings: Option<Arc<ApplicationConfig>>,
+    torrent_manager: Option<Arc<Box<dyn TorrentManager>>>,
+}
+
+impl DefaultTorrentStreamServerBuilder {
+    /// Set the application settings used to determine the idle timeout and initial buffer size of streams.
+    pub fn settings(mut self, settings: Arc<ApplicationConfig>) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Set the torrent manager used to remove the torrents of idle streams.
+    pub fn torrent_manager(mut self, torrent_manager: Arc<Box<dyn TorrentManager>>) -> Self {
+        self.torrent_manager = Some(torrent_manager);
+        self
+    }
+
+    /// Build a new torrent stream server and start it.
+    pub fn build(self) -> DefaultTorrentStreamServer {
+        let wrapper = TorrentStreamServerInner {
+            settings: self.settings.clone(),
+            torrent_manager: self.torrent_manager,
+            ..Default::default()
+        };
+        let instance = DefaultTorrentStreamServer {
             inner: Arc::new(wrapper),
         };
 
         TorrentStreamServerInner::start_server(instance.instance());
+        if let Some(settings) = self.settings {
+            TorrentStreamServerInner::start_idle_monitor(&instance.inner, settings);
+        }
+
         instance
     }
 }
@@ -94,8 +150,11 @@ struct TorrentStreamServerInner {
     runtime: Arc<tokio::runtime::Runtime>,
     socket: Arc<SocketAddr>,
     streams: Arc<Mutex<StreamMutex>>,
+    activities: Arc<Mutex<ActivityMutex>>,
     state: Arc<Mutex<TorrentStreamServerState>>,
     media_type_factory: Arc<MediaTypeFactory>,
+    settings: Option<Arc<ApplicationConfig>>,
+    torrent_manager: Option<Arc<Box<dyn TorrentManager>>>,
 }
 
 impl TorrentStreamServerInner {
@@ -111,11 +170,19 @@ impl TorrentStreamServerInner {
                 .and_then(move |filename: String, headers: HeaderMap| {
                     let filename = Self::url_decode(filename.as_str());
                     let streams = instance_get.streams.clone();
+                    let activities = instance_get.activities.clone();
                     let factory = instance_get.media_type_factory.clone();
 
                     async move {
                         let mutex = streams.lock().await;
-                        Self::handle_video_request(mutex, factory, filename.as_str(), headers)
+                        let activity = activities.lock().await.get(filename.as_str()).cloned();
+                        Self::handle_video_request(
+                            mutex,
+                            factory,
+                            activity,
+                            filename.as_str(),
+                            headers,
+                        )
                     }
                 });
             let head = warp::head().and(warp::path!("video" / String)).and_then(
@@ -156,9 +223,72 @@ impl TorrentStreamServerInner {
         });
     }
 
+    /// Start monitoring the reader activity of the streams.
+    /// Streams which have been idle for longer than the configured timeout will be stopped.
+    fn start_idle_monitor(
+        instance: &Arc<TorrentStreamServerInner>,
+        settings: Arc<ApplicationConfig>,
+    ) {
+        let runtime = instance.runtime.clone();
+        let instance = Arc::downgrade(instance);
+
+        runtime.spawn(async move {
+            let mut interval = tokio::time::interval(IDLE_CHECK_INTERVAL);
+
+            loop {
+                interval.tick().await;
+                let torrent_settings = settings.user_settings().torrent().clone();
+
+                match instance.upgrade() {
+                    None => break,
+                    Some(instance) => {
+                        if let Some(idle_timeout) = torrent_settings.stream_idle_timeout() {
+                            instance.stop_idle_streams(
+                                idle_timeout,
+                                torrent_settings.remove_idle_torrents,
+                            );
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Stop the streams which haven't had any reader activity for the given timeout.
+    /// A stream which still has an open reader, e.g. a paused player, is never considered idle.
+    fn stop_idle_streams(&self, idle_timeout: Duration, remove_torrents: bool) {
+        let idle_streams: Vec<(Handle, String)> = {
+            let streams = block_in_place(self.streams.lock());
+            let activities = block_in_place(self.activities.lock());
+
+            activities
+                .iter()
+                .filter(|(_, activity)| activity.is_idle(idle_timeout))
+                .filter_map(|(filename, _)| streams.get(filename))
+                .map(|stream| (stream.stream_handle(), stream.handle().to_string()))
+                .collect()
+        };
+
+        for (stream_handle, torrent_handle) in idle_streams {
+            info!(
+                "Torrent stream {} has been idle for {:?}, stopping stream",
+                stream_handle, idle_timeout
+            );
+            self.stop_stream(stream_handle);
+
+            if remove_torrents {
+                if let Some(torrent_manager) = self.torrent_manager.as_ref() {
+                    debug!("Removing torrent {} of idle stream", torrent_handle);
+                    torrent_manager.remove(torrent_handle.as_str());
+                }
+            }
+        }
+    }
+
     fn handle_video_request(
         mutex: MutexGuard<StreamMutex>,
         media_type_factory: Arc<MediaTypeFactory>,
+        activity: Option<Arc<StreamActivity>>,
         filename: &str,
         headers: HeaderMap,
     ) -> Result<warp::reply::Response, Rejection> {
@@ -223,7 +353,9 @@ impl TorrentStreamServerInner {
                             .header(CONTENT_LENGTH, resource.content_length())
                             .header(CONNECTION, CONNECTION_TYPE)
                             .header(CONTENT_TYPE, media_type)
-                            .body(Body::wrap_stream(stream))
+                            .body(Body::wrap_stream(ReaderTrackingStream::new(
+                                stream, activity,
+                            )))
                             .unwrap())
                     }
                     Err(e) => {
@@ -384,12 +516,18 @@ impl TorrentStreamServer for TorrentStreamServerInner {
             match self.build_url(filename) {
                 Ok(url) => {
                     debug!("Starting url stream for {}", &url);
-                    let stream =
-                        Arc::new(Box::new(DefaultTorrentStream::new(url, torrent))
-                            as Box<dyn TorrentStream>);
+                    let mut stream = DefaultTorrentStream::new(url, torrent);
+                    if let Some(settings) = self.settings.as_ref() {
+                        let initial_buffer_size =
+                            settings.user_settings().torrent().initial_buffer_size;
+                        stream = stream.with_initial_buffer_size(initial_buffer_size as u64);
+                    }
+                    let stream = Arc::new(Box::new(stream) as Box<dyn TorrentStream>);
                     let stream_ref = Arc::downgrade(&stream);
 
                     mutex.insert(filename.to_string(), stream);
+                    block_in_place(self.activities.lock())
+                        .insert(filename.to_string(), Arc::new(StreamActivity::default()));
 
                     Ok(stream_ref)
                 }
@@ -416,6 +554,7 @@ impl TorrentStreamServer for TorrentStreamServerInner {
             .map(|(filename, _)| filename.clone())
         {
             debug!("Trying to stop stream of {}", filename);
+            block_in_place(self.activities.lock()).remove(filename.as_str());
             match mutex.remove(filename.as_str()) {
                 None => warn!("Unable to stop stream of {}, stream not found", filename),
                 Some(stream) => {
@@ -426,6 +565,21 @@ impl TorrentStreamServer for TorrentStreamServerInner {
         }
     }
 
+    fn available_ranges(&self, handle: Handle) -> Option<Vec<std::ops::Range<u64>>> {
+        let mutex = block_in_place(self.streams.lock());
+
+        match mutex.values().find(|e| e.stream_handle() == handle) {
+            Some(stream) => Some(stream.available_ranges()),
+            None => {
+                debug!(
+                    "Unable to retrieve available ranges, stream handle {} not found",
+                    handle
+                );
+                None
+            }
+        }
+    }
+
     fn subscribe(&self, handle: Handle, callback: TorrentStreamCallback) -> Option<CallbackHandle> {
         let mutex = block_in_place(self.streams.lock());
         let position = mutex.iter().position(|(_, e)| e.stream_handle() == handle);
@@ -465,18 +619,108 @@ impl Default for TorrentStreamServerInner {
             ),
             socket: Arc::new(socket),
             streams: Arc::new(Mutex::new(HashMap::new())),
+            activities: Arc::new(Mutex::new(HashMap::new())),
             state: Arc::new(Mutex::new(TorrentStreamServerState::Stopped)),
             media_type_factory: Arc::new(MediaTypeFactory::default()),
+            settings: None,
+            torrent_manager: None,
+        }
+    }
+}
+
+/// The reader activity of a stream within the server.
+#[derive(Debug)]
+struct StreamActivity {
+    /// The number of readers which are currently connected to the stream
+    readers: AtomicUsize,
+    /// The last time that data has been read from the stream
+    last_read: std::sync::Mutex<Instant>,
+}
+
+impl StreamActivity {
+    fn reader_connected(&self) {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        self.touch();
+    }
+
+    fn reader_disconnected(&self) {
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+        self.touch();
+    }
+
+    fn touch(&self) {
+        *self.last_read.lock().expect("expected the activity lock") = Instant::now();
+    }
+
+    /// Verify if the stream has no connected readers and no data has been read
+    /// within the given timeout.
+    fn is_idle(&self, timeout: Duration) -> bool {
+        let last_read = *self.last_read.lock().expect("expected the activity lock");
+        self.readers.load(Ordering::SeqCst) == 0 && last_read.elapsed() >= timeout
+    }
+}
+
+impl Default for StreamActivity {
+    fn default() -> Self {
+        Self {
+            readers: AtomicUsize::new(0),
+            last_read: std::sync::Mutex::new(Instant::now()),
+        }
+    }
+}
+
+/// A streaming resource wrapper which registers the reader activity of the stream.
+/// The reader is considered disconnected when the wrapper is dropped.
+struct ReaderTrackingStream {
+    stream: TorrentStreamingResourceWrapper,
+    activity: Option<Arc<StreamActivity>>,
+}
+
+impl ReaderTrackingStream {
+    fn new(stream: TorrentStreamingResourceWrapper, activity: Option<Arc<StreamActivity>>) -> Self {
+        if let Some(activity) = activity.as_ref() {
+            activity.reader_connected();
+        }
+
+        Self { stream, activity }
+    }
+}
+
+impl Stream for ReaderTrackingStream {
+    type Item = StreamBytesResult;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let result = Pin::new(&mut self.stream).poll_next(cx);
+
+        if let Poll::Ready(Some(Ok(_))) = &result {
+            if let Some(activity) = self.activity.as_ref() {
+                activity.touch();
+            }
+        }
+
+        result
+    }
+}
+
+impl Drop for ReaderTrackingStream {
+    fn drop(&mut self) {
+        if let Some(activity) = self.activity.as_ref() {
+            activity.reader_disconnected();
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
     use reqwest::Client;
 
     use crate::assert_timeout_eq;
-    use crate::core::torrents::{MockTorrent, TorrentCallback, TorrentEvent, TorrentState};
+    use crate::core::torrents::{
+        MockTorrent, MockTorrentManager, TorrentCallback, TorrentEvent, TorrentState,
+    };
     use crate::testing::{copy_test_file, init_logger, read_test_file_to_string};
 
     use super::*;
@@ -644,6 +888,42 @@ mod test {
         assert_eq!(expected_result, result.replace("\r\n", "\n"))
     }
 
+    #[test]
+    fn test_available_ranges() {
+        init_logger();
+        let filename = "large-[123].txt";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join(filename);
+        let server = DefaultTorrentStreamServer::default();
+        let mut torrent = MockTorrent::new();
+        torrent.expect_file().returning(move || file.clone());
+        torrent.expect_has_piece().returning(|_: u32| true);
+        torrent.expect_total_pieces().returning(|| 10);
+        torrent.expect_piece_length().return_const(1024u64);
+        torrent.expect_prioritize_pieces().returning(|_: &[u32]| {});
+        torrent.expect_sequential_mode().returning(|| {});
+        torrent
+            .expect_subscribe()
+            .returning(|_: TorrentCallback| Handle::new());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        copy_test_file(temp_dir.path().to_str().unwrap(), filename, None);
+        let file_length = fs::metadata(temp_dir.path().join(filename)).unwrap().len();
+
+        let stream = server
+            .start_stream(Arc::downgrade(&torrent))
+            .expect("expected the torrent stream to have started");
+        let handle = stream.upgrade().unwrap().stream_handle();
+
+        let result = server.available_ranges(handle);
+        assert_eq!(Some(vec![0..file_length]), result);
+
+        let result = server.available_ranges(Handle::new());
+        assert_eq!(None, result);
+    }
+
     #[test]
     fn test_stop_stream() {
         init_logger();
@@ -697,6 +977,71 @@ mod test {
         assert_eq!(reqwest::StatusCode::NOT_FOUND, result)
     }
 
+    #[test]
+    fn test_stop_idle_streams() {
+        init_logger();
+        let filename = "large-[123].txt";
+        let torrent_handle = "MyTorrentHandle";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join(filename);
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager
+            .expect_remove()
+            .withf(move |handle| handle == torrent_handle)
+            .times(1)
+            .return_const(());
+        let server = DefaultTorrentStreamServer::builder()
+            .torrent_manager(Arc::new(
+                Box::new(torrent_manager) as Box<dyn TorrentManager>
+            ))
+            .build();
+        let torrent =
+            Arc::new(Box::new(create_idle_torrent(file, torrent_handle)) as Box<dyn Torrent>);
+
+        let stream = server
+            .start_stream(Arc::downgrade(&torrent))
+            .expect("expected the torrent stream to have started");
+        server.inner.stop_idle_streams(Duration::ZERO, true);
+
+        assert!(
+            stream.upgrade().is_none(),
+            "expected the idle stream to have been stopped"
+        );
+    }
+
+    #[test]
+    fn test_stop_idle_streams_active_reader() {
+        init_logger();
+        let filename = "large-[123].txt";
+        let torrent_handle = "MyTorrentHandle";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join(filename);
+        let mut torrent_manager = MockTorrentManager::new();
+        torrent_manager.expect_remove().times(0).return_const(());
+        let server = DefaultTorrentStreamServer::builder()
+            .torrent_manager(Arc::new(
+                Box::new(torrent_manager) as Box<dyn TorrentManager>
+            ))
+            .build();
+        let torrent =
+            Arc::new(Box::new(create_idle_torrent(file, torrent_handle)) as Box<dyn Torrent>);
+
+        let stream = server
+            .start_stream(Arc::downgrade(&torrent))
+            .expect("expected the torrent stream to have started");
+        let activity = block_in_place(server.inner.activities.lock())
+            .get(filename)
+            .cloned()
+            .expect("expected the stream activity to have been registered");
+        activity.reader_connected();
+        server.inner.stop_idle_streams(Duration::ZERO, true);
+
+        assert!(
+            stream.upgrade().is_some(),
+            "expected the stream with an active reader to not have been stopped"
+        );
+    }
+
     #[test]
     fn test_stream_not_found() {
         init_logger();
@@ -724,6 +1069,19 @@ mod test {
         assert_eq!(reqwest::StatusCode::NOT_FOUND, result)
     }
 
+    #[test]
+    fn test_stream_activity_is_idle() {
+        let activity = StreamActivity::default();
+        assert_eq!(true, activity.is_idle(Duration::ZERO));
+        assert_eq!(false, activity.is_idle(Duration::from_secs(60)));
+
+        activity.reader_connected();
+        assert_eq!(false, activity.is_idle(Duration::ZERO));
+
+        activity.reader_disconnected();
+        assert_eq!(true, activity.is_idle(Duration::ZERO));
+    }
+
     #[test]
     fn test_url_decode() {
         assert_eq!(
@@ -731,4 +1089,19 @@ mod test {
             TorrentStreamServerInner::url_decode("lorem%20ipsum%3D%5Bdolor%5D.txt")
         )
     }
+
+    fn create_idle_torrent(file: PathBuf, handle: &str) -> MockTorrent {
+        let mut torrent = MockTorrent::new();
+        torrent.expect_handle().return_const(handle.to_string());
+        torrent.expect_file().returning(move || file.clone());
+        torrent.expect_total_pieces().returning(|| 10);
+        torrent.expect_prioritize_pieces().returning(|_: &[u32]| {});
+        torrent
+            .expect_subscribe()
+            .returning(|_: TorrentCallback| Handle::new());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        torrent
+    }
 }
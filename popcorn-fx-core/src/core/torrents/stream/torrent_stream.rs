@@ -2,11 +2,13 @@ use std::cmp::{max, min};
 use std::fmt::{Debug, Display, Formatter};
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Once};
 use std::task::{Context, Poll};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{fs, thread};
 
 use derive_more::Display;
@@ -17,15 +19,19 @@ use tokio::sync::Mutex;
 use url::Url;
 
 use crate::core::torrents::{
-    DownloadStatus, StreamBytesResult, Torrent, TorrentCallback, TorrentError, TorrentEvent,
-    TorrentState, TorrentStream, TorrentStreamCallback, TorrentStreamEvent, TorrentStreamState,
-    TorrentStreamingResource, TorrentStreamingResourceWrapper,
+    DownloadStatus, StreamBufferStatus, StreamBytesResult, Torrent, TorrentCallback, TorrentError,
+    TorrentEvent, TorrentState, TorrentStream, TorrentStreamCallback, TorrentStreamEvent,
+    TorrentStreamState, TorrentStreamingResource, TorrentStreamingResourceWrapper,
 };
 use crate::core::{block_in_place, torrents, CallbackHandle, Callbacks, CoreCallbacks, Handle};
 
 /// The default buffer size used while streaming in bytes
 const BUFFER_SIZE: usize = 10000;
 const BUFFER_AVAILABILITY_CHECK: usize = 100;
+/// The default initial buffer size of a stream in bytes
+const DEFAULT_INITIAL_BUFFER_SIZE: u64 = 10 * 1024 * 1024;
+/// The minimum interval between two buffer status events of a stream
+const BUFFER_STATUS_INTERVAL: Duration = Duration::from_secs(1);
 
 /// The default implementation of [TorrentStream] which provides a [Stream]
 /// over the [File] resource.
@@ -49,6 +55,15 @@ impl DefaultTorrentStream {
         instance
     }
 
+    /// Set the amount of bytes which should be buffered before the playback is started.
+    /// It's used to estimate the time until the initial buffer has been filled.
+    pub fn with_initial_buffer_size(self, initial_buffer_size: u64) -> Self {
+        self.internal
+            .initial_buffer_size
+            .store(initial_buffer_size, Ordering::Relaxed);
+        self
+    }
+
     pub fn torrent(&self) -> Arc<Box<dyn Torrent>> {
         self.internal.torrent()
     }
@@ -87,6 +102,10 @@ impl Torrent for DefaultTorrentStream {
         self.internal.total_pieces()
     }
 
+    fn piece_length(&self) -> u64 {
+        self.internal.piece_length()
+    }
+
     fn sequential_mode(&self) {
         self.internal.sequential_mode()
     }
@@ -125,6 +144,10 @@ impl TorrentStream for DefaultTorrentStream {
         self.internal.stream_state()
     }
 
+    fn available_ranges(&self) -> Vec<Range<u64>> {
+        self.internal.available_ranges()
+    }
+
     fn subscribe_stream(&self, callback: TorrentStreamCallback) -> CallbackHandle {
         self.internal.subscribe_stream(callback)
     }
@@ -159,8 +182,16 @@ struct TorrentStreamWrapper {
     url: Url,
     /// The pieces which should be prepared for the stream
     preparing_pieces: Arc<Mutex<Vec<u32>>>,
+    /// The bitfield of the downloaded file pieces, which is lazily loaded on the first request
+    available_pieces: Arc<Mutex<Option<Vec<bool>>>>,
     /// The state of this stream
     state: Arc<Mutex<TorrentStreamState>>,
+    /// The offset of the last requested streaming resource, which represents the playback offset
+    playback_offset: AtomicU64,
+    /// The amount of bytes which should be buffered before the playback is started
+    initial_buffer_size: AtomicU64,
+    /// The last time the buffer status has been emitted
+    last_buffer_status: Mutex<Option<Instant>>,
     /// The callbacks for this stream
     callbacks: Arc<CoreCallbacks<TorrentStreamEvent>>,
 }
@@ -174,7 +205,11 @@ impl TorrentStreamWrapper {
             torrent,
             url,
             preparing_pieces: Arc::new(Mutex::new(prepare_pieces)),
+            available_pieces: Arc::new(Mutex::new(None)),
             state: Arc::new(Mutex::new(TorrentStreamState::Preparing)),
+            playback_offset: Default::default(),
+            initial_buffer_size: AtomicU64::new(DEFAULT_INITIAL_BUFFER_SIZE),
+            last_buffer_status: Default::default(),
             callbacks: Arc::new(CoreCallbacks::default()),
         }
     }
@@ -194,6 +229,13 @@ impl TorrentStreamWrapper {
                 }
                 TorrentEvent::PieceFinished(piece) => instance.on_piece_finished(piece),
                 TorrentEvent::DownloadStatus(status) => instance.on_download_status(status),
+                TorrentEvent::Error(e) => {
+                    warn!(
+                        "Torrent stream of {} is being stopped, {}",
+                        instance.torrent, e
+                    );
+                    instance.update_state(TorrentStreamState::Stopped)
+                }
             }
         }));
     }
@@ -212,6 +254,12 @@ impl TorrentStreamWrapper {
     }
 
     fn on_piece_finished(&self, piece: u32) {
+        if let Some(available_pieces) = block_in_place(self.available_pieces.lock()).as_mut() {
+            if let Some(available) = available_pieces.get_mut(piece as usize) {
+                *available = true;
+            }
+        }
+
         let mut pieces = block_in_place(self.preparing_pieces.lock());
         let torrent = self.torrent.clone();
 
@@ -240,8 +288,48 @@ impl TorrentStreamWrapper {
     }
 
     fn on_download_status(&self, download_status: DownloadStatus) {
+        let download_speed = download_status.download_speed;
+        self.callbacks
+            .invoke(TorrentStreamEvent::DownloadStatus(download_status));
+        self.on_buffer_status(download_speed);
+    }
+
+    /// Emit the buffer status of the stream, throttled to once every [BUFFER_STATUS_INTERVAL].
+    fn on_buffer_status(&self, download_speed: u32) {
+        {
+            let mut last_buffer_status = block_in_place(self.last_buffer_status.lock());
+            if last_buffer_status
+                .filter(|e| e.elapsed() < BUFFER_STATUS_INTERVAL)
+                .is_some()
+            {
+                return;
+            }
+
+            *last_buffer_status = Some(Instant::now());
+        }
+
+        let buffer_status = self.buffer_status(download_speed);
+        trace!("Stream {} buffer status {}", self.handle, buffer_status);
         self.callbacks
-            .invoke(TorrentStreamEvent::DownloadStatus(download_status))
+            .invoke(TorrentStreamEvent::BufferStatus(buffer_status));
+    }
+
+    fn buffer_status(&self, download_speed: u32) -> StreamBufferStatus {
+        let offset = self.playback_offset.load(Ordering::Relaxed);
+        let initial_buffer_size = self.initial_buffer_size.load(Ordering::Relaxed);
+        let buffered_bytes = Self::buffered_bytes(&self.available_ranges()[..], offset);
+
+        StreamBufferStatus {
+            offset,
+            buffered_bytes,
+            initial_buffer_size,
+            download_speed,
+            eta: StreamBufferStatus::estimate_eta(
+                buffered_bytes,
+                initial_buffer_size,
+                download_speed,
+            ),
+        }
     }
 
     fn verify_ready_to_stream(&self) {
@@ -304,6 +392,32 @@ impl TorrentStreamWrapper {
 
         pieces.into_iter().map(|e| e as u32).unique().collect()
     }
+
+    /// Retrieve the contiguous bytes of the given ranges which are available from the given offset.
+    fn buffered_bytes(ranges: &[Range<u64>], offset: u64) -> u64 {
+        ranges
+            .iter()
+            .find(|e| e.contains(&offset))
+            .map(|e| e.end - offset)
+            .unwrap_or(0)
+    }
+
+    /// Convert the given piece bitfield into the coalesced byte ranges of the file.
+    fn byte_ranges(pieces: &[bool], piece_length: u64, file_length: u64) -> Vec<Range<u64>> {
+        let mut ranges: Vec<Range<u64>> = vec![];
+
+        for (index, _) in pieces.iter().enumerate().filter(|(_, e)| **e) {
+            let start = index as u64 * piece_length;
+            let end = min(start + piece_length, file_length);
+
+            match ranges.last_mut() {
+                Some(range) if range.end == start => range.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+
+        ranges
+    }
 }
 
 impl Torrent for TorrentStreamWrapper {
@@ -335,6 +449,10 @@ impl Torrent for TorrentStreamWrapper {
         self.torrent.total_pieces()
     }
 
+    fn piece_length(&self) -> u64 {
+        self.torrent.piece_length()
+    }
+
     fn sequential_mode(&self) {
         self.torrent.sequential_mode()
     }
@@ -361,6 +479,7 @@ impl TorrentStream for TorrentStreamWrapper {
         tokio::task::block_in_place(|| {
             let mutex = block_in_place(self.state.lock());
             if *mutex == TorrentStreamState::Streaming {
+                self.playback_offset.store(0, Ordering::Relaxed);
                 DefaultTorrentStreamingResource::new(&self.torrent)
                     .map(|e| TorrentStreamingResourceWrapper::new(e))
             } else {
@@ -377,6 +496,7 @@ impl TorrentStream for TorrentStreamWrapper {
         tokio::task::block_in_place(|| {
             let mutex = block_in_place(self.state.lock());
             if *mutex == TorrentStreamState::Streaming {
+                self.playback_offset.store(offset, Ordering::Relaxed);
                 DefaultTorrentStreamingResource::new_offset(&self.torrent, offset, len)
                     .map(|e| TorrentStreamingResourceWrapper::new(e))
             } else {
@@ -389,6 +509,31 @@ impl TorrentStream for TorrentStreamWrapper {
         block_in_place(self.state.lock()).clone()
     }
 
+    fn available_ranges(&self) -> Vec<Range<u64>> {
+        let piece_length = self.torrent.piece_length();
+        let file_length = match fs::metadata(self.torrent.file()) {
+            Ok(e) => e.len(),
+            Err(e) => {
+                trace!("Unable to determine the file length of {}, {}", self, e);
+                return vec![];
+            }
+        };
+        if piece_length == 0 || file_length == 0 {
+            return vec![];
+        }
+
+        let mut mutex = block_in_place(self.available_pieces.lock());
+        let pieces = mutex.get_or_insert_with(|| {
+            let total_pieces = (file_length + piece_length - 1) / piece_length;
+            debug!("Loading the bitfield of {} stream pieces", total_pieces);
+            (0..total_pieces)
+                .map(|piece| self.torrent.has_piece(piece as u32))
+                .collect()
+        });
+
+        Self::byte_ranges(&pieces[..], piece_length, file_length)
+    }
+
     fn subscribe_stream(&self, callback: TorrentStreamCallback) -> CallbackHandle {
         debug!("Adding a new callback to stream {}", self);
         self.callbacks.add(callback)
@@ -694,7 +839,7 @@ mod test {
     use tokio::runtime;
 
     use crate::core::torrents::{MockTorrent, StreamBytes};
-    use crate::testing::{copy_test_file, init_logger, read_test_file_to_string};
+    use crate::testing::{copy_test_file, init_logger, read_test_file_to_string, write_tmp_dir_file};
 
     use super::*;
 
@@ -736,6 +881,109 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_torrent_stream_available_ranges() {
+        init_logger();
+        let filename = "ranges.txt";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().join(filename);
+        let mut mock = MockTorrent::new();
+        let url = Url::parse("http://localhost").unwrap();
+        let (tx, rx) = channel();
+        mock.expect_file().returning(move || temp_path.clone());
+        mock.expect_has_piece().returning(|piece| piece != 2);
+        mock.expect_total_pieces().returning(|| 4);
+        mock.expect_piece_length().return_const(3u64);
+        mock.expect_prioritize_pieces().returning(|_: &[u32]| {});
+        mock.expect_sequential_mode().returning(|| {});
+        mock.expect_subscribe()
+            .times(1)
+            .returning(move |callback: TorrentCallback| {
+                tx.send(callback).unwrap();
+                Handle::new()
+            });
+        mock.expect_state().return_const(TorrentState::Downloading);
+        write_tmp_dir_file(&temp_dir, filename, "0123456789");
+        let torrent_stream = DefaultTorrentStream::new(url, Arc::new(Box::new(mock)));
+
+        let result = torrent_stream.available_ranges();
+        assert_eq!(vec![0..6, 9..10], result);
+
+        let callback = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        callback(TorrentEvent::PieceFinished(2));
+        let result = torrent_stream.available_ranges();
+        assert_eq!(
+            vec![0..10],
+            result,
+            "expected the finished piece to have been coalesced"
+        );
+    }
+
+    #[test]
+    fn test_torrent_stream_buffer_status() {
+        init_logger();
+        let filename = "buffer.txt";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().join(filename);
+        let mut mock = MockTorrent::new();
+        let url = Url::parse("http://localhost").unwrap();
+        let (tx, rx) = channel();
+        let (tx_event, rx_event) = channel();
+        mock.expect_file().returning(move || temp_path.clone());
+        mock.expect_has_piece().returning(|piece| piece < 2);
+        mock.expect_total_pieces().returning(|| 4);
+        mock.expect_piece_length().return_const(3u64);
+        mock.expect_prioritize_pieces().returning(|_: &[u32]| {});
+        mock.expect_sequential_mode().returning(|| {});
+        mock.expect_subscribe()
+            .times(1)
+            .returning(move |callback: TorrentCallback| {
+                tx.send(callback).unwrap();
+                Handle::new()
+            });
+        mock.expect_state().return_const(TorrentState::Downloading);
+        write_tmp_dir_file(&temp_dir, filename, "0123456789");
+        let torrent_stream =
+            DefaultTorrentStream::new(url, Arc::new(Box::new(mock))).with_initial_buffer_size(10);
+        let download_status = DownloadStatus {
+            progress: 0.5,
+            seeds: 10,
+            peers: 5,
+            download_speed: 2,
+            upload_speed: 0,
+            downloaded: 6,
+            total_size: 10,
+            request_queue_depth: 24,
+        };
+
+        torrent_stream.subscribe_stream(Box::new(move |event| {
+            if let TorrentStreamEvent::BufferStatus(e) = event {
+                tx_event.send(e).unwrap();
+            }
+        }));
+        let callback = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        callback(TorrentEvent::DownloadStatus(download_status.clone()));
+        callback(TorrentEvent::DownloadStatus(download_status));
+
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            StreamBufferStatus {
+                offset: 0,
+                buffered_bytes: 6,
+                initial_buffer_size: 10,
+                download_speed: 2,
+                eta: Some(Duration::from_secs(2)),
+            },
+            result
+        );
+        let result = rx_event.recv_timeout(Duration::from_millis(100));
+        assert!(
+            result.is_err(),
+            "expected the buffer status to have been throttled, got {:?} instead",
+            result
+        );
+    }
+
     #[test]
     fn test_content_range() {
         init_logger();
@@ -927,6 +1175,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_torrent_error_stops_stream() {
+        init_logger();
+        let filename = "simple.txt";
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().join(filename);
+        let mut mock = MockTorrent::new();
+        let url = Url::parse("http://localhost").unwrap();
+        let (tx, rx) = channel();
+        mock.expect_file().returning(move || temp_path.clone());
+        mock.expect_has_bytes().return_const(true);
+        mock.expect_has_piece().return_const(false);
+        mock.expect_total_pieces().returning(|| 10);
+        mock.expect_prioritize_pieces().returning(|_: &[u32]| {});
+        mock.expect_subscribe()
+            .times(1)
+            .returning(move |callback: TorrentCallback| {
+                tx.send(callback).unwrap();
+                Handle::new()
+            });
+        mock.expect_state().return_const(TorrentState::Downloading);
+        copy_test_file(temp_dir.path().to_str().unwrap(), filename, None);
+        let torrent_stream = DefaultTorrentStream::new(url, Arc::new(Box::new(mock)));
+
+        let callback = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        callback(TorrentEvent::Error(TorrentError::StorageError(
+            "No space left on device".to_string(),
+        )));
+        let result = torrent_stream.stream_state();
+
+        assert_eq!(TorrentStreamState::Stopped, result)
+    }
+
     fn read_stream(mut stream: DefaultTorrentStreamingResource) -> String {
         let runtime = runtime::Runtime::new().unwrap();
         runtime
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::ops::Range;
 use std::sync::Weak;
 
 use derive_more::Display;
@@ -50,6 +51,17 @@ pub trait TorrentStreamServer: Debug + DowncastSync {
     /// * `handle` - An identifier for the torrent stream to stop.
     fn stop_stream(&self, handle: Handle);
 
+    /// Get the downloaded byte ranges of a torrent stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - An identifier for the torrent stream.
+    ///
+    /// # Returns
+    ///
+    /// The coalesced file-relative byte ranges which have been downloaded, or `None` if the stream handle is unknown.
+    fn available_ranges(&self, handle: Handle) -> Option<Vec<Range<u64>>>;
+
     /// Subscribe to events from a torrent stream.
     ///
     /// # Arguments
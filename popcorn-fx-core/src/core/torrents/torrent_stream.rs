@@ -1,5 +1,7 @@
+use std::ops::Range;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use derive_more::Display;
 use downcast_rs::impl_downcast;
@@ -48,6 +50,65 @@ pub enum TorrentStreamEvent {
     /// * `DownloadStatus` - The download status of the torrent stream.
     #[display(fmt = "Torrent stream download status changed to {}", _0)]
     DownloadStatus(DownloadStatus),
+    /// Buffer health update for the torrent stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `BufferStatus` - The buffer status of the torrent stream.
+    #[display(fmt = "Torrent stream buffer status changed to {}", _0)]
+    BufferStatus(StreamBufferStatus),
+}
+
+/// The buffer health of a [TorrentStream] in regards to the current playback offset.
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(
+    fmt = "offset: {}, buffered_bytes: {}, download_speed: {}, eta: {:?}",
+    offset,
+    buffered_bytes,
+    download_speed,
+    eta
+)]
+pub struct StreamBufferStatus {
+    /// The current playback offset within the stream in bytes.
+    pub offset: u64,
+    /// The contiguous downloaded bytes ahead of the playback offset.
+    pub buffered_bytes: u64,
+    /// The initial amount of bytes which should be buffered before the playback is started.
+    pub initial_buffer_size: u64,
+    /// The current download rate in bytes per second.
+    pub download_speed: u32,
+    /// The estimated time until the initial buffer has been filled.
+    /// This is [Duration::ZERO] when the buffer is already filled, or `None` when it can't be estimated.
+    pub eta: Option<Duration>,
+}
+
+impl StreamBufferStatus {
+    /// Estimate the time it takes to fill the initial buffer at the given download rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffered_bytes` - The contiguous bytes which have already been buffered.
+    /// * `initial_buffer_size` - The amount of bytes which need to be buffered.
+    /// * `download_speed` - The current download rate in bytes per second.
+    ///
+    /// It returns the estimated time, or `None` when nothing is being downloaded.
+    pub fn estimate_eta(
+        buffered_bytes: u64,
+        initial_buffer_size: u64,
+        download_speed: u32,
+    ) -> Option<Duration> {
+        let remaining_bytes = initial_buffer_size.saturating_sub(buffered_bytes);
+        if remaining_bytes == 0 {
+            return Some(Duration::ZERO);
+        }
+        if download_speed == 0 {
+            return None;
+        }
+
+        Some(Duration::from_secs_f64(
+            remaining_bytes as f64 / download_speed as f64,
+        ))
+    }
 }
 
 /// A trait for a torrent stream that provides access to torrent streaming information.
@@ -91,6 +152,13 @@ pub trait TorrentStream: Torrent {
     /// Get the current state of the stream.
     fn stream_state(&self) -> TorrentStreamState;
 
+    /// Get the byte ranges of the streamed file which have been fully downloaded.
+    ///
+    /// The ranges are expressed in file-relative byte offsets, are sorted and don't overlap,
+    /// as adjacent downloaded pieces are coalesced into a single range.
+    /// This is cheap enough to be polled frequently, e.g. to visualize the buffered parts of the stream.
+    fn available_ranges(&self) -> Vec<Range<u64>>;
+
     /// Subscribe to stream events with the provided callback.
     ///
     /// # Arguments
@@ -164,3 +232,34 @@ impl Stream for TorrentStreamingResourceWrapper {
         self.inner.size_hint()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_eta() {
+        let result =
+            StreamBufferStatus::estimate_eta(2 * 1024 * 1024, 10 * 1024 * 1024, 512 * 1024);
+        assert_eq!(Some(Duration::from_secs(16)), result);
+
+        let result = StreamBufferStatus::estimate_eta(0, 1000, 400);
+        assert_eq!(Some(Duration::from_millis(2500)), result);
+    }
+
+    #[test]
+    fn test_estimate_eta_buffer_filled() {
+        let result = StreamBufferStatus::estimate_eta(2048, 1024, 0);
+        assert_eq!(Some(Duration::ZERO), result);
+
+        let result = StreamBufferStatus::estimate_eta(1024, 1024, 200);
+        assert_eq!(Some(Duration::ZERO), result);
+    }
+
+    #[test]
+    fn test_estimate_eta_no_download_speed() {
+        let result = StreamBufferStatus::estimate_eta(512, 1024, 0);
+
+        assert_eq!(None, result);
+    }
+}
@@ -8,8 +8,10 @@ use downcast_rs::{DowncastSync, impl_downcast};
 use log::{debug, trace};
 #[cfg(any(test, feature = "testing"))]
 use mockall::automock;
+use serde::{Deserialize, Serialize};
 
-use crate::core::{CallbackHandle, CoreCallback};
+use crate::core::{torrents, CallbackHandle, CoreCallback};
+use crate::core::torrents::{InfoHash, TorrentError, TorrentVersion};
 
 const TORRENT_STATES: [TorrentState; 7] = [
     TorrentState::Creating,
@@ -36,6 +38,10 @@ pub enum TorrentEvent {
     /// Indicates a change in the download status of the torrent.
     #[display(fmt = "Torrent download status changed, {}", _0)]
     DownloadStatus(DownloadStatus),
+    /// Indicates that the torrent encountered an unrecoverable error, such as failing to write
+    /// the downloaded data to the disk.
+    #[display(fmt = "Torrent encountered an error, {}", _0)]
+    Error(TorrentError),
 }
 
 /// The state of a [Torrent] which is represented as a [i32].
@@ -98,6 +104,9 @@ pub struct DownloadStatus {
     pub downloaded: u64,
     /// The total size of the torrent in bytes.
     pub total_size: u64,
+    /// The effective block request queue depth, which is the average number of outstanding block requests
+    /// that are pipelined to each connected peer. It adapts to the download rate of the peers.
+    pub request_queue_depth: u32,
 }
 
 /// The torrent describes the meta-info of a shared file that can be queried over the network.
@@ -129,6 +138,10 @@ pub trait Torrent: Display + Debug + DowncastSync {
     /// The total number of pieces that are available for download.
     fn total_pieces(&self) -> i32;
 
+    /// The length in bytes of a piece of this [Torrent].
+    /// It returns 0 when the piece length is unknown.
+    fn piece_length(&self) -> u64;
+
     /// Update the download mode of the torrent to sequential.
     fn sequential_mode(&self);
 
@@ -150,7 +163,7 @@ impl Display for MockTorrent {
 }
 
 /// The torrent information
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
 #[display(
     fmt = "uri: {}, name: {}, directory_name: {:?}, total_files: {}",
     uri,
@@ -172,6 +185,15 @@ pub struct TorrentInfo {
 }
 
 impl TorrentInfo {
+    /// Get the BitTorrent protocol version of the torrent based on the info hashes of its magnet uri.
+    ///
+    /// It returns [None] when the uri doesn't contain a valid info hash.
+    pub fn version(&self) -> Option<TorrentVersion> {
+        InfoHash::from_magnet(self.uri.as_str())
+            .map(|e| e.version())
+            .ok()
+    }
+
     pub fn by_filename(&self, filename: &str) -> Option<TorrentFileInfo> {
         trace!(
             "Searching for torrent file {} within {:?}",
@@ -186,6 +208,22 @@ impl TorrentInfo {
             .cloned()
     }
 
+    /// Retrieve the file of the torrent by its index within the torrent.
+    ///
+    /// It returns [TorrentError::InvalidFileIndex] when the torrent doesn't contain a file with the given index.
+    pub fn by_file_index(&self, file_index: i32) -> torrents::Result<TorrentFileInfo> {
+        trace!(
+            "Searching for torrent file index {} within {:?}",
+            file_index,
+            self.files
+        );
+        self.files
+            .iter()
+            .find(|e| e.file_index == file_index)
+            .cloned()
+            .ok_or(TorrentError::InvalidFileIndex(file_index, self.files.len()))
+    }
+
     pub fn largest_file(&self) -> Option<TorrentFileInfo> {
         let mut largest_file_index: Option<usize> = None;
         let mut largest_file_size = 0i64;
@@ -255,7 +293,7 @@ impl TorrentInfo {
 }
 
 /// Represents information about a file within a torrent.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Serialize, Deserialize)]
 #[display(
     fmt = "filename: {}, path: {}, size: {}, index: {}",
     filename,
@@ -403,4 +441,58 @@ mod test {
 
         assert_eq!(Some(largest_file), result);
     }
+
+    #[test]
+    fn test_torrent_info_by_file_index() {
+        let episode = TorrentFileInfo {
+            filename: "episode5.mkv".to_string(),
+            file_path: "Season 1/episode5.mkv".to_string(),
+            file_size: 1024,
+            file_index: 4,
+        };
+        let info = TorrentInfo {
+            uri: String::new(),
+            name: "Season 1".to_string(),
+            directory_name: Some("Season 1".to_string()),
+            total_files: 2,
+            files: vec![
+                TorrentFileInfo {
+                    filename: "episode1.mkv".to_string(),
+                    file_path: "Season 1/episode1.mkv".to_string(),
+                    file_size: 1024,
+                    file_index: 0,
+                },
+                episode.clone(),
+            ],
+        };
+
+        let result = info.by_file_index(4);
+        assert_eq!(Ok(episode), result);
+
+        let result = info.by_file_index(7);
+        assert_eq!(Err(TorrentError::InvalidFileIndex(7, 2)), result);
+    }
+
+    #[test]
+    fn test_torrent_info_version() {
+        let v2 = TorrentInfo {
+            uri: "magnet:?xt=urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e".to_string(),
+            name: "bittorrent-v2-test".to_string(),
+            directory_name: None,
+            total_files: 0,
+            files: vec![],
+        };
+        let hybrid = TorrentInfo {
+            uri: "magnet:?xt=urn:btih:631a31dd0a46257d5078c0dee4e66e26f73e42ac&xt=urn:btmh:1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb".to_string(),
+            ..v2.clone()
+        };
+        let invalid = TorrentInfo {
+            uri: "lorem".to_string(),
+            ..v2.clone()
+        };
+
+        assert_eq!(Some(TorrentVersion::V2), v2.version());
+        assert_eq!(Some(TorrentVersion::Hybrid), hybrid.version());
+        assert_eq!(None, invalid.version());
+    }
 }
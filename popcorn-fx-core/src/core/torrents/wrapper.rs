@@ -1,12 +1,19 @@
 use std::fmt::{Debug, Formatter};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 use derive_more::Display;
-use log::trace;
+use log::{error, trace, warn};
 use tokio::sync::Mutex;
 
 use crate::core::{CallbackHandle, Callbacks, CoreCallbacks};
-use crate::core::torrents::{DownloadStatus, Torrent, TorrentCallback, TorrentEvent, TorrentState};
+use crate::core::torrents::{
+    DownloadStatus, Torrent, TorrentCallback, TorrentError, TorrentEvent, TorrentState,
+};
+
+/// The maximum number of consecutive write failures before the torrent is put in an error state.
+/// Write failures below this threshold are considered transient and are retried by the torrent session.
+pub const MAX_WRITE_ATTEMPTS: u32 = 3;
 
 /// The has byte callback.
 pub type HasBytesCallback = Box<dyn Fn(&[u64]) -> bool + Send>;
@@ -17,6 +24,9 @@ pub type HasPieceCallback = Box<dyn Fn(u32) -> bool + Send>;
 /// The total number of pieces callback.
 pub type TotalPiecesCallback = Box<dyn Fn() -> i32 + Send>;
 
+/// The piece length callback.
+pub type PieceLengthCallback = Box<dyn Fn() -> u64 + Send>;
+
 /// The prioritization of bytes callback.
 pub type PrioritizeBytesCallback = Box<dyn Fn(&[u64]) + Send>;
 
@@ -40,12 +50,16 @@ pub struct TorrentWrapper {
     pub handle: String,
     /// The filepath of the torrent.
     pub filepath: PathBuf,
+    /// The info hash of the torrent as reported by the torrent session, if known.
+    pub info_hash: Option<String>,
     /// Mutex for the callback to check if a byte exists in the torrent.
     pub has_bytes: Mutex<HasBytesCallback>,
     /// Mutex for the callback to check if a piece exists in the torrent.
     pub has_piece: Mutex<HasPieceCallback>,
     /// Mutex for the callback to retrieve the total number of pieces in the torrent.
     pub total_pieces: Mutex<TotalPiecesCallback>,
+    /// Mutex for the callback to retrieve the piece length of the torrent.
+    pub piece_length: Mutex<PieceLengthCallback>,
     /// Mutex for the callback to prioritize bytes in the torrent.
     pub prioritize_bytes: Mutex<PrioritizeBytesCallback>,
     /// Mutex for the callback to prioritize pieces in the torrent.
@@ -54,6 +68,8 @@ pub struct TorrentWrapper {
     pub sequential_mode: Mutex<SequentialModeCallback>,
     /// Mutex for the callback to handle torrent state changes.
     pub torrent_state: Mutex<TorrentStateCallback>,
+    /// The number of consecutive failed writes of the torrent data.
+    pub write_failures: AtomicU32,
     /// Callbacks for handling torrent events.
     pub callbacks: CoreCallbacks<TorrentEvent>,
 }
@@ -65,9 +81,11 @@ impl TorrentWrapper {
     ///
     /// * `handle` - The handle for identifying the torrent.
     /// * `filepath` - The filepath of the torrent.
+    /// * `info_hash` - The info hash of the torrent as reported by the torrent session, if known.
     /// * `has_byte` - The callback for checking if a byte exists in the torrent.
     /// * `has_piece` - The callback for checking if a piece exists in the torrent.
     /// * `total_pieces` - The callback for retrieving the total number of pieces in the torrent.
+    /// * `piece_length` - The callback for retrieving the piece length of the torrent.
     /// * `prioritize_bytes` - The callback for prioritizing bytes in the torrent.
     /// * `prioritize_pieces` - The callback for prioritizing pieces in the torrent.
     /// * `sequential_mode` - The callback for setting sequential mode in the torrent.
@@ -79,9 +97,11 @@ impl TorrentWrapper {
     pub fn new(
         handle: String,
         filepath: String,
+        info_hash: Option<String>,
         has_byte: HasBytesCallback,
         has_piece: HasPieceCallback,
         total_pieces: TotalPiecesCallback,
+        piece_length: PieceLengthCallback,
         prioritize_bytes: PrioritizeBytesCallback,
         prioritize_pieces: PrioritizePiecesCallback,
         sequential_mode: SequentialModeCallback,
@@ -90,13 +110,16 @@ impl TorrentWrapper {
         Self {
             handle,
             filepath: PathBuf::from(filepath),
+            info_hash: info_hash.map(|e| e.to_lowercase()),
             has_bytes: Mutex::new(has_byte),
             has_piece: Mutex::new(has_piece),
             total_pieces: Mutex::new(total_pieces),
+            piece_length: Mutex::new(piece_length),
             prioritize_bytes: Mutex::new(prioritize_bytes),
             prioritize_pieces: Mutex::new(prioritize_pieces),
             sequential_mode: Mutex::new(sequential_mode),
             torrent_state: Mutex::new(torrent_state),
+            write_failures: Default::default(),
             callbacks: CoreCallbacks::default(),
         }
     }
@@ -116,9 +139,45 @@ impl TorrentWrapper {
     ///
     /// * `piece` - The index of the finished piece.
     pub fn piece_finished(&self, piece: u32) {
+        self.write_failures.store(0, Ordering::Relaxed);
         self.callbacks.invoke(TorrentEvent::PieceFinished(piece))
     }
 
+    /// Notifies the wrapper that the torrent data could not be written to the storage.
+    ///
+    /// Failures are retried up to [MAX_WRITE_ATTEMPTS] consecutive times, after which the torrent
+    /// is put in the [TorrentState::Error] state and a [TorrentEvent::Error] is invoked.
+    /// A successfully finished piece resets the failure counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The description of the write failure, e.g. "No space left on device".
+    pub fn write_failed(&self, error: String) {
+        let failures = self.write_failures.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if failures < MAX_WRITE_ATTEMPTS {
+            warn!(
+                "Torrent {} failed to write data (attempt {}/{}), {}",
+                self.handle, failures, MAX_WRITE_ATTEMPTS, error
+            );
+        } else if failures == MAX_WRITE_ATTEMPTS {
+            error!(
+                "Torrent {} failed to write data after {} attempts, {}",
+                self.handle, failures, error
+            );
+            self.callbacks
+                .invoke(TorrentEvent::Error(TorrentError::StorageError(error)));
+            self.callbacks
+                .invoke(TorrentEvent::StateChanged(TorrentState::Error));
+        } else {
+            trace!(
+                "Torrent {} is already in an error state, ignoring write failure {}",
+                self.handle,
+                error
+            );
+        }
+    }
+
     /// Notifies the wrapper of the torrent's download status.
     ///
     /// # Arguments
@@ -134,6 +193,7 @@ impl Debug for TorrentWrapper {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TorrentWrapper")
             .field("filepath", &self.filepath)
+            .field("info_hash", &self.info_hash)
             .field("callbacks", &self.callbacks)
             .finish()
     }
@@ -184,6 +244,10 @@ impl Torrent for TorrentWrapper {
         })
     }
 
+    fn piece_length(&self) -> u64 {
+        tokio::task::block_in_place(move || (self.piece_length.blocking_lock())())
+    }
+
     fn sequential_mode(&self) {
         tokio::task::block_in_place(move || (self.sequential_mode.blocking_lock())())
     }
@@ -213,6 +277,7 @@ mod test {
         });
         let has_piece = Box::new(|_: u32| true);
         let total_pieces = Box::new(|| 0);
+        let piece_length = Box::new(|| 0);
         let prioritize_bytes = Box::new(|_: &[u64]| {});
         let prioritize_pieces = Box::new(|_: &[u32]| {});
         let sequential_mode = Box::new(|| {});
@@ -220,9 +285,11 @@ mod test {
         let wrapper = TorrentWrapper::new(
             "MyHandle".to_string(),
             "lorem.txt".to_string(),
+            None,
             has_bytes,
             has_piece,
             total_pieces,
+            piece_length,
             prioritize_bytes,
             prioritize_pieces,
             sequential_mode,
@@ -242,6 +309,7 @@ mod test {
         let has_bytes: HasBytesCallback = Box::new(move |_| true);
         let has_piece = Box::new(|_: u32| true);
         let total_pieces = Box::new(|| 0);
+        let piece_length = Box::new(|| 0);
         let prioritize_bytes = Box::new(|_: &[u64]| {});
         let prioritize_pieces = Box::new(|_: &[u32]| {});
         let sequential_mode = Box::new(|| {});
@@ -249,9 +317,11 @@ mod test {
         let wrapper = TorrentWrapper::new(
             "MyHandle".to_string(),
             "lorem.txt".to_string(),
+            None,
             has_bytes,
             has_piece,
             total_pieces,
+            piece_length,
             prioritize_bytes,
             prioritize_pieces,
             sequential_mode,
@@ -262,4 +332,92 @@ mod test {
 
         assert_eq!(TorrentState::Completed, result)
     }
+
+    #[test]
+    fn test_piece_length() {
+        let wrapper = create_wrapper();
+
+        let result = wrapper.piece_length();
+
+        assert_eq!(1024, result)
+    }
+
+    #[test]
+    fn test_write_failed() {
+        let (tx, rx) = channel();
+        let wrapper = create_wrapper();
+        wrapper.subscribe(Box::new(move |event| tx.send(event).unwrap()));
+
+        for _ in 0..MAX_WRITE_ATTEMPTS - 1 {
+            wrapper.write_failed("No space left on device".to_string());
+        }
+        assert!(
+            rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "expected no event to have been invoked for transient failures"
+        );
+
+        wrapper.write_failed("No space left on device".to_string());
+        let result = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        match result {
+            TorrentEvent::Error(e) => assert_eq!(
+                TorrentError::StorageError("No space left on device".to_string()),
+                e
+            ),
+            _ => assert!(
+                false,
+                "expected TorrentEvent::Error, got {} instead",
+                result
+            ),
+        }
+        let result = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        match result {
+            TorrentEvent::StateChanged(state) => assert_eq!(TorrentState::Error, state),
+            _ => assert!(
+                false,
+                "expected TorrentEvent::StateChanged, got {} instead",
+                result
+            ),
+        }
+
+        wrapper.write_failed("No space left on device".to_string());
+        assert!(
+            rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "expected the error to only have been invoked once"
+        );
+    }
+
+    #[test]
+    fn test_piece_finished_resets_write_failures() {
+        let (tx, rx) = channel();
+        let wrapper = create_wrapper();
+
+        for _ in 0..MAX_WRITE_ATTEMPTS - 1 {
+            wrapper.write_failed("Input/output error".to_string());
+        }
+        wrapper.piece_finished(2);
+        wrapper.subscribe(Box::new(move |event| tx.send(event).unwrap()));
+        wrapper.write_failed("Input/output error".to_string());
+
+        assert_eq!(1, wrapper.write_failures.load(Ordering::Relaxed));
+        assert!(
+            rx.recv_timeout(Duration::from_millis(100)).is_err(),
+            "expected no error event to have been invoked"
+        );
+    }
+
+    fn create_wrapper() -> TorrentWrapper {
+        TorrentWrapper::new(
+            "MyHandle".to_string(),
+            "lorem.txt".to_string(),
+            None,
+            Box::new(|_| true),
+            Box::new(|_| true),
+            Box::new(|| 10),
+            Box::new(|| 1024),
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+            Box::new(|| {}),
+            Box::new(|| TorrentState::Downloading),
+        )
+    }
 }
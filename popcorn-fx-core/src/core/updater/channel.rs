@@ -0,0 +1,53 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+const STABLE_INFO_FILE: &str = "versions.json";
+const BETA_INFO_FILE: &str = "versions-beta.json";
+
+/// The update channel from which new application versions are received.
+/// This channel is abi compatible to be used over [std::ffi].
+#[repr(i32)]
+#[derive(Debug, Default, Clone, Copy, Display, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// The channel which only receives stable releases.
+    #[default]
+    #[display(fmt = "stable")]
+    Stable = 0,
+    /// The channel which receives pre-releases before they're promoted to stable.
+    #[display(fmt = "beta")]
+    Beta = 1,
+}
+
+impl UpdateChannel {
+    /// Retrieve all update channels which can be selected by the user.
+    pub fn available_channels() -> Vec<UpdateChannel> {
+        vec![UpdateChannel::Stable, UpdateChannel::Beta]
+    }
+
+    /// Retrieve the filename of the version information for this channel on the update server.
+    pub fn info_filename(&self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => STABLE_INFO_FILE,
+            UpdateChannel::Beta => BETA_INFO_FILE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_info_filename() {
+        assert_eq!("versions.json", UpdateChannel::Stable.info_filename());
+        assert_eq!("versions-beta.json", UpdateChannel::Beta.info_filename());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let result: UpdateChannel = serde_yaml::from_str("beta").unwrap();
+
+        assert_eq!(UpdateChannel::Beta, result);
+    }
+}
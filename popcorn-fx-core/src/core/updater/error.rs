@@ -16,6 +16,9 @@ pub enum UpdateError {
     InvalidRuntimeVersion(String, String),
     #[error("Unable to start update process, no version info is known at this time")]
     UnknownVersion,
+    /// Indicates that the update channel or download couldn't be reached, e.g. no network connection
+    #[error("Failed to connect to {0}")]
+    ConnectionFailed(String),
     #[error("Received invalid update channel response, {0}")]
     Response(String),
     #[error("The specified download url {0} is invalid")]
@@ -34,4 +37,10 @@ pub enum UpdateError {
     ExtractionFailed(String),
     #[error("The archive location has already been set")]
     ArchiveLocationAlreadyExists,
+    /// Indicates that the downloaded file `Filename` doesn't match the expected checksum
+    #[error("The update file {0} doesn't match the expected checksum")]
+    ChecksumMismatch(String),
+    /// Indicates that no previous version is available to roll back to
+    #[error("No previous version is available to roll back to")]
+    RollbackUnavailable,
 }
@@ -1,8 +1,11 @@
+pub use channel::*;
 pub use error::*;
 pub use update::*;
 pub use versions::*;
 
+mod channel;
 mod error;
+mod patch;
 mod task;
 mod update;
 mod versions;
@@ -0,0 +1,171 @@
+use crate::core::updater;
+use crate::core::updater::UpdateError;
+
+/// The length of a single control block within the patch.
+const CONTROL_LENGTH: usize = 24;
+
+/// Apply the given binary diff on the old file data.
+///
+/// The patch is expected to be in the raw bsdiff format, which is a sequence of control blocks.
+/// Each control block starts with the add length, copy length and seek offset as 8 byte integers,
+/// followed by the diff bytes which are added to the old data and the extra bytes which are copied as-is.
+///
+/// # Arguments
+///
+/// * `old` - The data of the file from the base version.
+/// * `patch` - The binary diff which needs to be applied.
+///
+/// # Returns
+///
+/// It returns the data of the patched file, or an error when the patch is corrupt.
+pub fn apply(old: &[u8], patch: &[u8]) -> updater::Result<Vec<u8>> {
+    let mut new = Vec::with_capacity(old.len());
+    let mut old_position: i64 = 0;
+    let mut position = 0;
+
+    while position < patch.len() {
+        let control = slice(patch, position, CONTROL_LENGTH)
+            .ok_or_else(|| corrupt("control block is truncated"))?;
+        let add_length = to_length(offtin(&control[0..8]))?;
+        let copy_length = to_length(offtin(&control[8..16]))?;
+        let seek = offtin(&control[16..24]);
+        position += CONTROL_LENGTH;
+
+        let diff =
+            slice(patch, position, add_length).ok_or_else(|| corrupt("diff block is truncated"))?;
+        let old_start =
+            usize::try_from(old_position).map_err(|_| corrupt("old position is negative"))?;
+        let old_data = slice(old, old_start, add_length)
+            .ok_or_else(|| corrupt("diff block exceeds the old file"))?;
+        new.extend(
+            diff.iter()
+                .zip(old_data.iter())
+                .map(|(diff, old)| diff.wrapping_add(*old)),
+        );
+        position += add_length;
+
+        let extra = slice(patch, position, copy_length)
+            .ok_or_else(|| corrupt("extra block is truncated"))?;
+        new.extend_from_slice(extra);
+        position += copy_length;
+
+        old_position = old_position
+            .saturating_add(add_length as i64)
+            .saturating_add(seek);
+    }
+
+    Ok(new)
+}
+
+/// Read a signed 8 byte integer which is stored as little endian with the sign in the highest bit.
+fn offtin(bytes: &[u8]) -> i64 {
+    let mut value = [0u8; 8];
+    value.copy_from_slice(bytes);
+    let magnitude = (u64::from_le_bytes(value) & !(1 << 63)) as i64;
+
+    if bytes[7] & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+fn slice(data: &[u8], start: usize, length: usize) -> Option<&[u8]> {
+    data.get(start..start.checked_add(length)?)
+}
+
+fn to_length(value: i64) -> updater::Result<usize> {
+    usize::try_from(value).map_err(|_| corrupt("block length is negative"))
+}
+
+fn corrupt(reason: &str) -> UpdateError {
+    UpdateError::ExtractionFailed(format!("binary patch is corrupt, {}", reason))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let old = b"lorem ipsum";
+        let mut patch = control(5, 6, 0);
+        patch.extend_from_slice(&[0u8; 5]);
+        patch.extend_from_slice(b" dolor");
+        patch.extend(control(6, 0, 0));
+        patch.extend_from_slice(&[0u8; 6]);
+
+        let result = apply(old, patch.as_slice()).unwrap();
+
+        assert_eq!(b"lorem dolor ipsum".to_vec(), result);
+    }
+
+    #[test]
+    fn test_apply_diff_bytes() {
+        let old = b"abc";
+        let mut patch = control(3, 0, 0);
+        patch.extend_from_slice(&[1, 1, 1]);
+
+        let result = apply(old, patch.as_slice()).unwrap();
+
+        assert_eq!(b"bcd".to_vec(), result);
+    }
+
+    #[test]
+    fn test_apply_negative_seek() {
+        let old = b"ab";
+        let mut patch = control(2, 0, -2);
+        patch.extend_from_slice(&[0, 0]);
+        patch.extend(control(2, 0, 0));
+        patch.extend_from_slice(&[0, 0]);
+
+        let result = apply(old, patch.as_slice()).unwrap();
+
+        assert_eq!(b"abab".to_vec(), result);
+    }
+
+    #[test]
+    fn test_apply_truncated_patch() {
+        let mut patch = control(5, 6, 0);
+        patch.extend_from_slice(&[0u8; 5]);
+
+        let result = apply(b"lorem", patch.as_slice());
+
+        assert_eq!(
+            Err(UpdateError::ExtractionFailed(
+                "binary patch is corrupt, extra block is truncated".to_string()
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_apply_exceeds_old_file() {
+        let mut patch = control(5, 0, 0);
+        patch.extend_from_slice(&[0u8; 5]);
+
+        let result = apply(b"lor", patch.as_slice());
+
+        assert_eq!(
+            Err(UpdateError::ExtractionFailed(
+                "binary patch is corrupt, diff block exceeds the old file".to_string()
+            )),
+            result
+        );
+    }
+
+    fn control(add_length: i64, copy_length: i64, seek: i64) -> Vec<u8> {
+        [add_length, copy_length, seek]
+            .iter()
+            .flat_map(|value| offtout(*value))
+            .collect()
+    }
+
+    fn offtout(value: i64) -> [u8; 8] {
+        let mut bytes = value.unsigned_abs().to_le_bytes();
+        if value < 0 {
+            bytes[7] |= 0x80;
+        }
+        bytes
+    }
+}
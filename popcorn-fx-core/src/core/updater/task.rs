@@ -5,7 +5,7 @@ use semver::Version;
 use url::Url;
 
 use crate::core::updater;
-use crate::core::updater::UpdateError;
+use crate::core::updater::{DeltaFile, UpdateError};
 
 /// An update task which allows updating a component from the application.
 ///
@@ -16,6 +16,9 @@ use crate::core::updater::UpdateError;
 /// * `current_version` - The current version of the component to be updated.
 /// * `new_version` - The new version of the component to be installed.
 /// * `download_link` - The URL where the new version of the component can be downloaded.
+/// * `checksum` - The optional SHA-256 checksum which the downloaded archive should match.
+/// * `delta_base` - The optional installation subdirectory on which the downloaded delta archive should be applied.
+/// * `delta_files` - The files which are changed by the downloaded delta archive.
 /// * `archive_location` - An optional file path where the downloaded update archive should be stored. This is only used if the update is being downloaded as a file rather than in memory.
 ///
 /// # Example
@@ -48,6 +51,9 @@ pub struct UpdateTask {
     pub new_version: Version,
     pub download_link: Url,
     install_directory: String,
+    checksum: Option<String>,
+    delta_base: Option<String>,
+    delta_files: Vec<DeltaFile>,
     archive_location: Option<PathBuf>,
 }
 
@@ -62,6 +68,23 @@ impl UpdateTask {
         self.install_directory.as_str()
     }
 
+    /// Returns the expected hex encoded SHA-256 checksum of the downloaded archive, if known.
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    /// Returns the installation subdirectory on which the archive should be applied as a delta archive.
+    ///
+    /// When `None`, the archive is a full archive of the new version.
+    pub fn delta_base(&self) -> Option<&str> {
+        self.delta_base.as_deref()
+    }
+
+    /// Returns the files which are changed by the delta archive.
+    pub fn delta_files(&self) -> &[DeltaFile] {
+        self.delta_files.as_slice()
+    }
+
     /// Returns the current archive location, if one has been set.
     pub fn archive_location(&self) -> Option<&PathBuf> {
         self.archive_location.as_ref()
@@ -94,6 +117,9 @@ pub struct UpdateTaskBuilder {
     new_version: Option<Version>,
     download_link: Option<Url>,
     install_directory: Option<String>,
+    checksum: Option<String>,
+    delta_base: Option<String>,
+    delta_files: Vec<DeltaFile>,
 }
 
 impl UpdateTaskBuilder {
@@ -121,6 +147,19 @@ impl UpdateTaskBuilder {
         self
     }
 
+    /// Sets the expected SHA-256 checksum of the downloaded archive.
+    pub fn checksum(mut self, checksum: String) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+
+    /// Sets the installation subdirectory and changed files of the downloaded delta archive.
+    pub fn delta(mut self, delta_base: String, delta_files: Vec<DeltaFile>) -> Self {
+        self.delta_base = Some(delta_base);
+        self.delta_files = delta_files;
+        self
+    }
+
     /// Builds an `UpdateTask` object with the specified parameters.
     ///
     /// # Panics
@@ -153,6 +192,9 @@ impl UpdateTaskBuilder {
             new_version,
             download_link,
             install_directory,
+            checksum: self.checksum,
+            delta_base: self.delta_base,
+            delta_files: self.delta_files,
             archive_location: None,
         }
     }
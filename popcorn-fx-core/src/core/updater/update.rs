@@ -1,14 +1,18 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::fs::OpenOptions;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 
 use derive_more::Display;
 use flate2::read::GzDecoder;
 use futures::StreamExt;
 use log::{debug, error, info, trace, warn};
-use reqwest::{Client, ClientBuilder, Response, StatusCode};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{Response, StatusCode};
+use ring::digest::{Context, SHA256};
 use semver::Version;
 use tar::Archive;
 use tokio::runtime::Runtime;
@@ -17,16 +21,24 @@ use url::Url;
 
 use crate::core::{Callbacks, CoreCallback, CoreCallbacks, updater};
 use crate::core::config::ApplicationConfig;
+use crate::core::crash::{CrashReporter, PendingCrashReport};
 use crate::core::launcher::LauncherOptions;
 use crate::core::platform::PlatformData;
 use crate::core::storage::Storage;
-use crate::core::updater::{UpdateError, VersionInfo};
-use crate::core::updater::task::UpdateTask;
+use crate::core::updater::{DeltaAction, PatchInfo, UpdateChannel, UpdateError, VersionInfo};
+use crate::core::updater::patch;
+use crate::core::updater::task::{UpdateTask, UpdateTaskBuilder};
+use crate::core::utils::http::HttpClientFactory;
 use crate::VERSION;
 
-const UPDATE_INFO_FILE: &str = "versions.json";
 const UPDATE_DIRECTORY: &str = "updates";
 const RUNTIMES_DIRECTORY: &str = "runtimes";
+/// The maximum number of times an update file is downloaded before it's considered corrupt.
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 2;
+/// The header which contains the last crash report when crash reports are sent.
+const CRASH_REPORT_HEADER: &str = "X-Crash-Report";
+/// The maximum length of the percent-encoded crash report header which is sent along with the update check.
+const CRASH_REPORT_MAX_BYTES: usize = 4096;
 
 /// A type representing a callback function that can handle update events.
 pub type UpdateCallback = CoreCallback<UpdateEvent>;
@@ -46,6 +58,12 @@ pub enum UpdateEvent {
     /// Indicates that the update installation has progressed.
     #[display(fmt = "The update installation has progressed to {:?}", _0)]
     InstallationProgress(InstallationProgress),
+    /// Indicates that the update process has failed with the given error.
+    #[display(fmt = "The update process failed, {}", _0)]
+    Failed(UpdateError),
+    /// Indicates that the verification of the downloaded update has progressed.
+    #[display(fmt = "The update verification has progressed to {:?}", _0)]
+    VerificationProgress(VerificationProgress),
 }
 
 /// Represents the state of the updater.
@@ -59,6 +77,8 @@ pub enum UpdateState {
     NoUpdateAvailable,
     /// The updater is currently downloading the update.
     Downloading,
+    /// The updater is currently verifying the checksums of the downloaded update.
+    Verifying,
     /// The download has finished and the update is ready to be installed.
     DownloadFinished,
     /// The updater is currently installing the update.
@@ -78,6 +98,15 @@ pub struct DownloadProgress {
     pub downloaded: u64,
 }
 
+/// Represents the current progress of an update being verified.
+#[derive(Debug, Clone)]
+pub struct VerificationProgress {
+    /// The current verified task.
+    pub task: u16,
+    /// The total number of tasks which need to be verified.
+    pub total_tasks: u16,
+}
+
 /// Represents the current progress of an update being installed.
 #[derive(Debug, Clone)]
 pub struct InstallationProgress {
@@ -163,6 +192,46 @@ impl Updater {
         self.inner.install(self.inner.clone())
     }
 
+    /// Retrieve the update channel from which new versions are received.
+    pub fn channel(&self) -> UpdateChannel {
+        self.inner.channel()
+    }
+
+    /// Retrieve the update channels which can be selected.
+    pub fn available_channels(&self) -> Vec<UpdateChannel> {
+        UpdateChannel::available_channels()
+    }
+
+    /// Switch the update channel from which new versions are received.
+    ///
+    /// The selected channel is persisted and will be polled for a new version when it differs from the current channel.
+    ///
+    /// # Returns
+    ///
+    /// An error when the selected channel couldn't be persisted.
+    pub fn set_channel(&self, channel: UpdateChannel) -> updater::Result<()> {
+        if self.inner.set_channel(channel)? {
+            self.start_polling();
+        }
+
+        Ok(())
+    }
+
+    /// Roll back the application to the previously installed version.
+    ///
+    /// The previous version becomes the active version and the updater will transition to [UpdateState::InstallationFinished],
+    /// which indicates that the application needs to be relaunched.
+    ///
+    /// # Returns
+    ///
+    /// An error when no previous version is available.
+    pub async fn rollback_update(&self) -> updater::Result<()> {
+        self.inner.rollback_update().await.map_err(|e| {
+            warn!("Failed to roll back update, {}", e);
+            e
+        })
+    }
+
     /// Poll the update channel for new versions.
     ///
     /// If the updater state is [UpdateState::CheckingForNewVersion], then the call will be ignored.
@@ -218,11 +287,12 @@ impl Updater {
 #[derive(Default)]
 pub struct UpdaterBuilder {
     settings: Option<Arc<ApplicationConfig>>,
-    insecure: bool,
+    client_factory: Option<HttpClientFactory>,
     platform: Option<Arc<Box<dyn PlatformData>>>,
     data_path: Option<String>,
     callbacks: Vec<UpdateCallback>,
     runtime: Option<Arc<Runtime>>,
+    crash_reporter: Option<Arc<CrashReporter>>,
 }
 
 impl UpdaterBuilder {
@@ -232,9 +302,9 @@ impl UpdaterBuilder {
         self
     }
 
-    /// Sets whether the updater should use insecure connections to download updates.
-    pub fn insecure(mut self, insecure: bool) -> Self {
-        self.insecure = insecure;
+    /// Sets the http client factory used to poll the update channel and download updates.
+    pub fn client_factory(mut self, client_factory: HttpClientFactory) -> Self {
+        self.client_factory = Some(client_factory);
         self
     }
 
@@ -262,6 +332,13 @@ impl UpdaterBuilder {
         self
     }
 
+    /// Sets the crash reporter of which the last crash report is sent along with the update check.
+    /// The report is only sent when the user enabled [crate::core::config::NetworkSettings::send_crash_reports].
+    pub fn crash_reporter(mut self, crash_reporter: Arc<CrashReporter>) -> Self {
+        self.crash_reporter = Some(crash_reporter);
+        self
+    }
+
     /// Constructs a new updater and starts polling the update channel.
     ///
     /// This method constructs a new `Updater` instance using the settings, platform, storage path, and callbacks configured
@@ -279,13 +356,14 @@ impl UpdaterBuilder {
         let instance = Updater {
             inner: Arc::new(InnerUpdater::new(
                 self.settings.expect("Settings are not set"),
-                self.insecure,
+                self.client_factory.unwrap_or_default(),
                 self.platform.expect("Platform is not set"),
                 self.data_path.expect("Data path is not set").as_str(),
                 self.callbacks,
                 self.runtime
                     .or_else(|| Some(Arc::new(Runtime::new().unwrap())))
                     .unwrap(),
+                self.crash_reporter,
             )),
         };
 
@@ -298,10 +376,11 @@ impl Debug for UpdaterBuilder {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UpdaterBuilder")
             .field("settings", &self.settings)
-            .field("insecure", &self.insecure)
+            .field("client_factory", &self.client_factory)
             .field("platform", &self.platform)
             .field("storage_path", &self.data_path)
             .field("runtime", &self.runtime)
+            .field("crash_reporter", &self.crash_reporter)
             .finish()
     }
 }
@@ -314,8 +393,8 @@ struct InnerUpdater {
     settings: Arc<ApplicationConfig>,
     /// The Operating System specific data used for updates.
     platform: Arc<Box<dyn PlatformData>>,
-    /// The client used for polling the information
-    client: Client,
+    /// The http client factory used for polling the information
+    client_factory: HttpClientFactory,
     /// The cached version information if available
     cache: Mutex<Option<VersionInfo>>,
     /// The last know state of the updater
@@ -326,17 +405,21 @@ struct InnerUpdater {
     data_path: PathBuf,
     download_progress: Mutex<Option<DownloadProgress>>,
     tasks: Mutex<Vec<UpdateTask>>,
-    launcher_options: LauncherOptions,
+    /// The launcher options which contain the active version pointer and the selected update channel
+    launcher_options: Mutex<LauncherOptions>,
+    /// The crash reporter of which the last crash report is sent along with the update check
+    crash_reporter: Option<Arc<CrashReporter>>,
 }
 
 impl InnerUpdater {
     fn new(
         settings: Arc<ApplicationConfig>,
-        insecure: bool,
+        client_factory: HttpClientFactory,
         platform: Arc<Box<dyn PlatformData>>,
         data_path: &str,
         callbacks: Vec<UpdateCallback>,
         runtime: Arc<Runtime>,
+        crash_reporter: Option<Arc<CrashReporter>>,
     ) -> Self {
         let core_callbacks: CoreCallbacks<UpdateEvent> = Default::default();
 
@@ -348,10 +431,7 @@ impl InnerUpdater {
         Self {
             settings,
             platform,
-            client: ClientBuilder::new()
-                .danger_accept_invalid_certs(insecure)
-                .build()
-                .unwrap(),
+            client_factory,
             cache: Mutex::new(None),
             state: Mutex::new(UpdateState::CheckingForNewVersion),
             runtime,
@@ -359,7 +439,8 @@ impl InnerUpdater {
             data_path: PathBuf::from(data_path),
             download_progress: Default::default(),
             tasks: Default::default(),
-            launcher_options: LauncherOptions::new(data_path),
+            launcher_options: Mutex::new(LauncherOptions::new(data_path)),
+            crash_reporter,
         }
     }
 
@@ -380,20 +461,91 @@ impl InnerUpdater {
         mutex.clone()
     }
 
+    fn channel(&self) -> UpdateChannel {
+        let mutex = self.launcher_options.blocking_lock();
+        mutex.update_channel
+    }
+
+    /// Update the active update channel.
+    /// It returns `true` when the channel has been changed, else `false`.
+    fn set_channel(&self, channel: UpdateChannel) -> updater::Result<bool> {
+        let mut launcher_options = self.launcher_options.blocking_lock();
+        if launcher_options.update_channel == channel {
+            trace!("Update channel is already {}", channel);
+            return Ok(false);
+        }
+
+        let mut options = launcher_options.clone();
+        options.update_channel = channel;
+        self.write_launcher_options(&options)?;
+        *launcher_options = options;
+        drop(launcher_options);
+
+        // the known version info and tasks belong to the previous channel
+        *self.cache.blocking_lock() = None;
+        self.tasks.blocking_lock().clear();
+        info!("Update channel has been changed to {}", channel);
+        Ok(true)
+    }
+
+    /// Switch the active version pointer back to the previous version.
+    async fn rollback_update(&self) -> updater::Result<()> {
+        let state = self.state.lock().await.clone();
+        if state == UpdateState::Downloading || state == UpdateState::Installing {
+            return Err(UpdateError::UpdateNotAvailable(state));
+        }
+
+        let mut launcher_options = self.launcher_options.lock().await;
+        let previous_version_exists = launcher_options
+            .previous_version
+            .as_ref()
+            .map(|e| self.data_path.join(e).exists())
+            .unwrap_or(false);
+        if !previous_version_exists {
+            return Err(UpdateError::RollbackUnavailable);
+        }
+
+        let mut options = launcher_options.clone();
+        options.rollback();
+        self.write_launcher_options(&options)?;
+        info!("Rolled back application version to {}", options.version);
+        *launcher_options = options;
+        drop(launcher_options);
+
+        self.update_state_async(UpdateState::InstallationFinished)
+            .await;
+        Ok(())
+    }
+
+    fn write_launcher_options(&self, options: &LauncherOptions) -> updater::Result<()> {
+        options
+            .write(self.data_path.join(LauncherOptions::filename()))
+            .map_err(|e| UpdateError::IO(e.to_string()))?;
+        debug!("Launcher options have been updated");
+        Ok(())
+    }
+
     /// Poll the update channel for a new version.
     async fn poll(&self) -> updater::Result<VersionInfo> {
         trace!("Polling for application information on the update channel");
         let properties = self.settings.properties();
         let update_channel = properties.update_channel();
+        let channel = self.launcher_options.lock().await.update_channel;
 
         self.update_state_async(UpdateState::CheckingForNewVersion)
             .await;
         trace!("Parsing update channel url {}", update_channel);
         match Url::parse(update_channel) {
             Ok(mut url) => {
-                url = url.join(UPDATE_INFO_FILE).unwrap();
-                let response = self.poll_info_from_url(url).await?;
-                let version_info = Self::handle_query_response(response).await?;
+                url = url.join(channel.info_filename()).unwrap();
+                let response = match self.poll_info_from_url(url).await {
+                    Ok(e) => e,
+                    Err(e) => return Err(self.update_failed(e).await),
+                };
+                let version_info = match Self::handle_query_response(response).await {
+                    Ok(e) => e,
+                    Err(e) => return Err(self.update_failed(e).await),
+                };
 
                 self.update_version_info(&version_info)
                     .await
@@ -401,10 +553,11 @@ impl InnerUpdater {
             }
             Err(e) => {
                 error!("Failed to poll update channel, {}", e);
-                self.update_state_async(UpdateState::Error).await;
-                Err(UpdateError::InvalidUpdateChannel(
-                    update_channel.to_string(),
-                ))
+                Err(self
+                    .update_failed(UpdateError::InvalidUpdateChannel(
+                        update_channel.to_string(),
+                    ))
+                    .await)
             }
         }
     }
@@ -420,7 +573,6 @@ impl InnerUpdater {
     }
 
     async fn create_update_tasks(&self, version_info: &VersionInfo) -> updater::Result<()> {
-        let platform_identifier = self.platform_identifier();
         let current_version = Self::current_application_version();
         let application_version =
             Version::parse(version_info.application.version()).map_err(|e| {
@@ -435,6 +587,7 @@ impl InnerUpdater {
                 e.to_string(),
             )
         })?;
+        let current_runtime_version = self.launcher_options.lock().await.runtime_version.clone();
         let mut tasks_mutex = self.tasks.lock().await;
 
         debug!(
@@ -450,17 +603,20 @@ impl InnerUpdater {
                 "New application version {} is available",
                 application_version
             );
+            let base_directory = current_version.to_string();
+            let builder = UpdateTask::builder()
+                .current_version(current_version.clone())
+                .install_directory(application_version.to_string())
+                .new_version(application_version);
+
             tasks_mutex.push(
-                UpdateTask::builder()
-                    .current_version(current_version)
-                    .install_directory(application_version.to_string())
-                    .new_version(application_version)
-                    .download_link(Self::convert_download_link_to_url(
-                        version_info
-                            .application
-                            .download_link(platform_identifier.as_str()),
-                    )?)
-                    .build(),
+                self.with_download(
+                    builder,
+                    &version_info.application,
+                    &current_version,
+                    base_directory.as_str(),
+                )?
+                .build(),
             );
         } else {
             info!("Application version {} is up-to-date", VERSION);
@@ -468,7 +624,7 @@ impl InnerUpdater {
 
         debug!(
             "Checking channel runtime version {} against local version {}",
-            self.launcher_options.runtime_version,
+            current_runtime_version,
             runtime_version.to_string()
         );
         if self
@@ -476,26 +632,26 @@ impl InnerUpdater {
             .await
         {
             info!("New runtime version {} is available", runtime_version);
-            tasks_mutex.push(
-                UpdateTask::builder()
-                    .current_version(
-                        Version::parse(self.launcher_options.runtime_version.as_str()).map_err(
-                            |e| {
-                                UpdateError::InvalidRuntimeVersion(
-                                    self.launcher_options.runtime_version.clone(),
-                                    e.to_string(),
-                                )
-                            },
-                        )?,
+            let current_runtime_version = Version::parse(current_runtime_version.as_str())
+                .map_err(|e| {
+                    UpdateError::InvalidRuntimeVersion(
+                        current_runtime_version.clone(),
+                        e.to_string(),
                     )
-                    .new_version(runtime_version)
-                    .download_link(Self::convert_download_link_to_url(
-                        version_info
-                            .runtime
-                            .download_link(platform_identifier.as_str()),
-                    )?)
-                    .install_directory(RUNTIMES_DIRECTORY.to_string())
-                    .build(),
+                })?;
+            let builder = UpdateTask::builder()
+                .current_version(current_runtime_version.clone())
+                .new_version(runtime_version)
+                .install_directory(RUNTIMES_DIRECTORY.to_string());
+
+            tasks_mutex.push(
+                self.with_download(
+                    builder,
+                    &version_info.runtime,
+                    &current_runtime_version,
+                    RUNTIMES_DIRECTORY,
+                )?
+                .build(),
             );
         }
 
@@ -515,6 +671,63 @@ impl InnerUpdater {
         Ok(())
     }
 
+    /// Set the download of the given task builder.
+    ///
+    /// The delta patch of the platform is preferred when it can be applied on the local installation,
+    /// otherwise the full archive of the platform is used.
+    fn with_download(
+        &self,
+        builder: UpdateTaskBuilder,
+        patch_info: &PatchInfo,
+        current_version: &Version,
+        base_directory: &str,
+    ) -> updater::Result<UpdateTaskBuilder> {
+        let platform_identifier = self.platform_identifier();
+
+        if let Some(delta) = patch_info.delta(platform_identifier.as_str()) {
+            match Version::parse(delta.base_version.as_str()) {
+                Ok(base_version) if base_version == *current_version => {
+                    if self.data_path.join(base_directory).is_dir() {
+                        debug!(
+                            "Using delta patch {} from base version {}",
+                            delta.url, base_version
+                        );
+                        return Ok(builder
+                            .download_link(Self::convert_download_link_to_url(Some(&delta.url))?)
+                            .checksum(delta.checksum.clone())
+                            .delta(base_directory.to_string(), delta.files.clone()));
+                    }
+
+                    info!(
+                        "Delta patch base {} is not installed, using full archive instead",
+                        base_directory
+                    );
+                }
+                Ok(base_version) => info!(
+                    "Local version {} doesn't match delta patch base {}, using full archive instead",
+                    current_version, base_version
+                ),
+                Err(e) => warn!(
+                    "Delta patch base version {} is invalid, {}",
+                    delta.base_version, e
+                ),
+            }
+        }
+
+        let mut builder = builder.download_link(Self::convert_download_link_to_url(
+            patch_info.download_link(platform_identifier.as_str()),
+        )?);
+        match patch_info.checksum(platform_identifier.as_str()) {
+            Some(checksum) => builder = builder.checksum(checksum.clone()),
+            None => warn!(
+                "No checksum available for version {}, download won't be verified",
+                patch_info.version()
+            ),
+        }
+
+        Ok(builder)
+    }
+
     async fn update_state_async(&self, state: UpdateState) {
         let mut mutex = self.state.lock().await;
         if *mutex == state {
@@ -526,12 +739,75 @@ impl InnerUpdater {
         self.callbacks.invoke(UpdateEvent::StateChanged(state));
     }
 
+    /// Update the state of the updater to [UpdateState::Error] and inform the callbacks about the failure.
+    /// It returns the given error, so it can be propagated to the caller.
+    async fn update_failed(&self, error: UpdateError) -> UpdateError {
+        self.update_state_async(UpdateState::Error).await;
+        self.callbacks.invoke(UpdateEvent::Failed(error.clone()));
+        error
+    }
+
     async fn poll_info_from_url(&self, url: Url) -> updater::Result<Response> {
         debug!("Polling update information from {}", url.as_str());
-        self.client.get(url.clone()).send().await.map_err(|e| {
+        let mut request = self.client_factory.client().get(url.clone());
+        let crash_report = self.pending_crash_report();
+
+        if let Some(report) = crash_report.as_ref() {
+            debug!("Sending crash report {} with the update check", report.name);
+            request = request.header(CRASH_REPORT_HEADER, Self::crash_report_header(report));
+        }
+
+        let response = request.send().await.map_err(|e| {
             error!("Failed to poll update channel, {}", e);
-            UpdateError::InvalidUpdateChannel(url.to_string())
-        })
+            UpdateError::ConnectionFailed(url.to_string())
+        })?;
+
+        if let (Some(reporter), Some(report)) = (self.crash_reporter.as_ref(), crash_report) {
+            if response.status().is_success() {
+                reporter.mark_reported(&report);
+            } else {
+                warn!(
+                    "Crash report {} has not been delivered, update check returned {}",
+                    report.name,
+                    response.status()
+                );
+            }
+        }
+        Ok(response)
+    }
+
+    /// Retrieve the last crash report which should be sent with the update check.
+    /// It returns [None] when the user didn't opt in to send crash reports.
+    fn pending_crash_report(&self) -> Option<PendingCrashReport> {
+        let reporter = self.crash_reporter.as_ref()?;
+
+        if self.settings.user_settings().network().send_crash_reports {
+            reporter.pending_report()
+        } else {
+            None
+        }
+    }
+
+    /// Create the header value of the given crash report.
+    /// The report is percent-encoded and truncated so the encoded value doesn't exceed [CRASH_REPORT_MAX_BYTES].
+    fn crash_report_header(report: &PendingCrashReport) -> String {
+        let mut encoded_len = 0;
+        let end = report
+            .contents
+            .char_indices()
+            .find(|(_, c)| {
+                // every byte of a non-alphanumeric character is encoded as %XX
+                encoded_len += if c.is_ascii_alphanumeric() {
+                    1
+                } else {
+                    c.len_utf8() * 3
+                };
+                encoded_len > CRASH_REPORT_MAX_BYTES
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(report.contents.len());
+
+        utf8_percent_encode(&report.contents[..end], NON_ALPHANUMERIC).to_string()
     }
 
     async fn download(&self) -> updater::Result<()> {
@@ -546,23 +822,132 @@ impl InnerUpdater {
         let mut tasks_mutex = self.tasks.lock().await;
         let mut futures = vec![];
 
-        for task in tasks_mutex.iter_mut() {
+        for task in tasks_mutex.iter() {
             trace!("Starting download task of {}", task.download_link);
             futures.push(self.download_update_task(task));
         }
 
         self.update_state_async(UpdateState::Downloading).await;
-        let results: Vec<updater::Result<()>> = futures::future::join_all(futures).await;
+        let results: Vec<updater::Result<PathBuf>> = futures::future::join_all(futures).await;
+        let mut archives = vec![];
 
         for result in results {
-            result?;
+            archives.push(result?);
+        }
+
+        self.update_state_async(UpdateState::Verifying).await;
+        let total_tasks = tasks_mutex.len() as u16;
+        for (index, (task, archive)) in tasks_mutex.iter_mut().zip(archives).enumerate() {
+            let archive = self.verify_update_task(task, archive).await?;
+
+            task.set_archive_location(archive)?;
+            self.callbacks
+                .invoke(UpdateEvent::VerificationProgress(VerificationProgress {
+                    task: index as u16 + 1,
+                    total_tasks,
+                }));
         }
 
         self.update_state_async(UpdateState::DownloadFinished).await;
         Ok(())
     }
 
-    async fn download_update_task(&self, task: &mut UpdateTask) -> updater::Result<()> {
+    /// Verify the downloaded archive of the given task against its checksum.
+    /// A corrupt archive will be downloaded again until [MAX_DOWNLOAD_ATTEMPTS] has been reached.
+    ///
+    /// It returns the location of the verified archive.
+    async fn verify_update_task(
+        &self,
+        task: &UpdateTask,
+        archive: PathBuf,
+    ) -> updater::Result<PathBuf> {
+        let checksum = match task.checksum() {
+            None => {
+                warn!("Unable to verify {:?}, no checksum available", archive);
+                return Ok(archive);
+            }
+            Some(e) => e,
+        };
+        let mut archive = archive;
+        let mut attempt = 1;
+
+        loop {
+            trace!("Verifying update archive {:?}", archive);
+            let actual_checksum = Self::file_checksum(archive.clone()).await?;
+            if actual_checksum.eq_ignore_ascii_case(checksum) {
+                debug!("Update archive {:?} has been verified", archive);
+                return Ok(archive);
+            }
+
+            warn!(
+                "Update archive {:?} checksum {} doesn't match expected checksum {} (attempt {} of {})",
+                archive, actual_checksum, checksum, attempt, MAX_DOWNLOAD_ATTEMPTS
+            );
+            if attempt >= MAX_DOWNLOAD_ATTEMPTS {
+                let filename = archive
+                    .file_name()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or_default()
+                    .to_string();
+
+                if let Err(e) = tokio::fs::remove_file(&archive).await {
+                    warn!(
+                        "Failed to remove corrupt update archive {:?}, {}",
+                        archive, e
+                    );
+                }
+                return Err(self
+                    .update_failed(UpdateError::ChecksumMismatch(filename))
+                    .await);
+            }
+
+            attempt += 1;
+            self.update_state_async(UpdateState::Downloading).await;
+            archive = self.download_update_task(task).await?;
+            self.update_state_async(UpdateState::Verifying).await;
+        }
+    }
+
+    /// Calculate the hex encoded SHA-256 checksum of the given file.
+    async fn file_checksum(path: PathBuf) -> updater::Result<String> {
+        tokio::task::spawn_blocking(move || {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .open(&path)
+                .map_err(|e| UpdateError::IO(e.to_string()))?;
+            let mut context = Context::new(&SHA256);
+            let mut buffer = [0u8; 64 * 1024];
+
+            loop {
+                let len = file
+                    .read(&mut buffer)
+                    .map_err(|e| UpdateError::IO(e.to_string()))?;
+                if len == 0 {
+                    break;
+                }
+
+                context.update(&buffer[..len]);
+            }
+
+            Ok(Self::to_hex(context.finish().as_ref()))
+        })
+        .await
+        .map_err(|e| UpdateError::IO(e.to_string()))?
+    }
+
+    /// Calculate the hex encoded SHA-256 checksum of the given data.
+    fn data_checksum(data: &[u8]) -> String {
+        Self::to_hex(ring::digest::digest(&SHA256, data).as_ref())
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    }
+
+    async fn download_update_task(&self, task: &UpdateTask) -> updater::Result<PathBuf> {
         let directory = self.update_directory_path();
         let url_path = PathBuf::from(task.download_link.path());
         let filename = url_path
@@ -576,7 +961,13 @@ impl InnerUpdater {
             "Downloading update patch from {}",
             task.download_link.as_str()
         );
-        match self.client.get(task.download_link.as_ref()).send().await {
+        match self
+            .client_factory
+            .client()
+            .get(task.download_link.as_ref())
+            .send()
+            .await
+        {
             Ok(response) => {
                 let status_code = response.status();
 
@@ -610,15 +1001,15 @@ impl InnerUpdater {
                             .await;
                     }
 
-                    task.set_archive_location(directory.join(filename))?;
-                    return Ok(());
+                    return Ok(directory.join(filename));
                 }
 
-                self.update_state_async(UpdateState::Error).await;
-                Err(UpdateError::DownloadFailed(
-                    status_code.to_string(),
-                    filename.to_string(),
-                ))
+                Err(self
+                    .update_failed(UpdateError::DownloadFailed(
+                        status_code.to_string(),
+                        filename.to_string(),
+                    ))
+                    .await)
             }
             Err(e) => {
                 trace!(
@@ -626,11 +1017,11 @@ impl InnerUpdater {
                     task.download_link.as_str(),
                     e.to_string()
                 );
-                self.update_state_async(UpdateState::Error).await;
-                Err(UpdateError::DownloadFailed(
-                    "UNKNOWN".to_string(),
-                    e.to_string(),
-                ))
+                Err(self
+                    .update_failed(UpdateError::ConnectionFailed(
+                        task.download_link.to_string(),
+                    ))
+                    .await)
             }
         }
     }
@@ -725,7 +1116,7 @@ impl InnerUpdater {
                     }
                     Err(e) => {
                         error!("Update installation failed, {}", e);
-                        inner.update_state_async(UpdateState::Error).await;
+                        inner.update_failed(e).await;
                     }
                 }
             });
@@ -761,28 +1152,171 @@ impl InnerUpdater {
             let gz = GzDecoder::new(file);
             let mut archive = Archive::new(gz);
 
-            debug!(
-                "Extracting archive {:?} to {:?}",
-                task.archive_location().unwrap(),
-                destination
-            );
-            archive
-                .unpack(destination)
-                .map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?;
+            if let Some(delta_base) = task.delta_base() {
+                let base = updater.data_path.join(delta_base);
+                let staging = updater
+                    .update_directory_path()
+                    .join(format!("{}.staging", task.install_directory()));
+
+                debug!(
+                    "Applying delta archive {:?} on {:?}",
+                    task.archive_location().unwrap(),
+                    base
+                );
+                // the delta is applied on a copy of the base, so a corrupt delta never damages the installation
+                if let Err(e) = Self::apply_delta(&mut archive, task, &base, &staging) {
+                    let _ = std::fs::remove_dir_all(&staging);
+                    return Err(e);
+                }
+                if destination.exists() {
+                    std::fs::remove_dir_all(&destination)
+                        .map_err(|e| UpdateError::IO(e.to_string()))?;
+                }
+                std::fs::rename(&staging, &destination)
+                    .map_err(|e| UpdateError::IO(e.to_string()))?;
+            } else {
+                debug!(
+                    "Extracting archive {:?} to {:?}",
+                    task.archive_location().unwrap(),
+                    destination
+                );
+                archive
+                    .unpack(destination)
+                    .map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?;
+            }
             index += 1;
             info!("Installation task {} of {} completed", index, total_tasks);
+            updater
+                .callbacks
+                .invoke(UpdateEvent::InstallationProgress(InstallationProgress {
+                    task: index,
+                    total_tasks: total_tasks as u16,
+                }));
         }
 
         trace!("Updating launcher options");
         let info = updater.version_info().await?;
-        let mut launcher_options = updater.launcher_options.clone();
+        let mut launcher_options = updater.launcher_options.lock().await;
+        let mut options = launcher_options.clone();
 
-        launcher_options.version = info.application.version;
-        launcher_options.runtime_version = info.runtime.version;
-        launcher_options
-            .write(updater.data_path.join(LauncherOptions::filename()))
-            .map_err(|e| UpdateError::IO(e.to_string()))?;
-        debug!("Launcher options have been updated");
+        options.activate(info.application.version(), info.runtime.version());
+        updater.write_launcher_options(&options)?;
+        *launcher_options = options;
+
+        Ok(())
+    }
+
+    /// Apply the delta archive of the given task on a copy of the base directory within the staging directory.
+    ///
+    /// Every patched or added file is verified against its checksum after it has been written.
+    fn apply_delta<R: Read>(
+        archive: &mut Archive<R>,
+        task: &UpdateTask,
+        base: &Path,
+        staging: &Path,
+    ) -> updater::Result<()> {
+        if staging.exists() {
+            std::fs::remove_dir_all(staging).map_err(|e| UpdateError::IO(e.to_string()))?;
+        }
+        Self::copy_directory(base, staging)?;
+
+        let mut entries: HashMap<String, Vec<u8>> = HashMap::new();
+        for entry in archive
+            .entries()
+            .map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?
+        {
+            let mut entry = entry.map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry
+                .path()
+                .map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?
+                .to_string_lossy()
+                .to_string();
+            let mut data = vec![];
+            entry
+                .read_to_end(&mut data)
+                .map_err(|e| UpdateError::ExtractionFailed(e.to_string()))?;
+            entries.insert(path, data);
+        }
+
+        for file in task.delta_files() {
+            let relative_path = Path::new(file.path.as_str());
+            if relative_path.is_absolute()
+                || relative_path
+                    .components()
+                    .any(|e| matches!(e, Component::ParentDir))
+            {
+                return Err(UpdateError::ExtractionFailed(format!(
+                    "delta file {} is outside of the installation",
+                    file.path
+                )));
+            }
+
+            let target = staging.join(relative_path);
+            trace!("Applying delta action {:?} on {:?}", file.action, target);
+            let data = match file.action {
+                DeltaAction::Delete => {
+                    if target.exists() {
+                        std::fs::remove_file(&target)
+                            .map_err(|e| UpdateError::IO(e.to_string()))?;
+                    }
+                    continue;
+                }
+                DeltaAction::Add => entries.remove(file.path.as_str()),
+                DeltaAction::Patch => match entries.remove(file.path.as_str()) {
+                    Some(diff) => {
+                        let old =
+                            std::fs::read(&target).map_err(|e| UpdateError::IO(e.to_string()))?;
+                        Some(patch::apply(old.as_slice(), diff.as_slice())?)
+                    }
+                    None => None,
+                },
+            }
+            .ok_or_else(|| {
+                UpdateError::ExtractionFailed(format!(
+                    "delta archive is missing file {}",
+                    file.path
+                ))
+            })?;
+
+            let checksum = file.checksum.as_ref().ok_or_else(|| {
+                UpdateError::ExtractionFailed(format!("delta file {} has no checksum", file.path))
+            })?;
+            let actual_checksum = Self::data_checksum(data.as_slice());
+            if !actual_checksum.eq_ignore_ascii_case(checksum) {
+                warn!(
+                    "Delta file {} checksum {} doesn't match expected checksum {}",
+                    file.path, actual_checksum, checksum
+                );
+                return Err(UpdateError::ChecksumMismatch(file.path.clone()));
+            }
+
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| UpdateError::IO(e.to_string()))?;
+            }
+            std::fs::write(&target, data).map_err(|e| UpdateError::IO(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Recursively copy the contents of the source directory into the destination directory.
+    fn copy_directory(source: &Path, destination: &Path) -> updater::Result<()> {
+        std::fs::create_dir_all(destination).map_err(|e| UpdateError::IO(e.to_string()))?;
+
+        for entry in std::fs::read_dir(source).map_err(|e| UpdateError::IO(e.to_string()))? {
+            let entry = entry.map_err(|e| UpdateError::IO(e.to_string()))?;
+            let target = destination.join(entry.file_name());
+
+            if entry.path().is_dir() {
+                Self::copy_directory(&entry.path(), &target)?;
+            } else {
+                std::fs::copy(entry.path(), &target).map_err(|e| UpdateError::IO(e.to_string()))?;
+            }
+        }
 
         Ok(())
     }
@@ -808,7 +1342,7 @@ impl InnerUpdater {
                 .download_link(platform_identifier.as_str())
             {
                 trace!("Verifying if application download link exists for {}", url);
-                return match self.client.head(url.as_str()).send().await {
+                return match self.client_factory.client().head(url.as_str()).send().await {
                     Ok(response) => {
                         if response.status().is_success() || response.status() == StatusCode::FOUND
                         {
@@ -847,7 +1381,7 @@ impl InnerUpdater {
         runtime_version: &Version,
     ) -> bool {
         let current_runtime_version =
-            Version::parse(self.launcher_options.runtime_version.as_str()).unwrap();
+            Version::parse(self.launcher_options.lock().await.runtime_version.as_str()).unwrap();
 
         if runtime_version.cmp(&current_runtime_version) == Ordering::Greater {
             let platform_identifier = self.platform_identifier();
@@ -857,7 +1391,7 @@ impl InnerUpdater {
                 .get(platform_identifier.as_str())
             {
                 trace!("Verifying if runtime download link exists for {}", url);
-                return match self.client.head(url.as_str()).send().await {
+                return match self.client_factory.client().head(url.as_str()).send().await {
                     Ok(response) => {
                         if response.status().is_success() || response.status() == StatusCode::FOUND
                         {
@@ -954,12 +1488,16 @@ mod test {
     use std::sync::mpsc::channel;
     use std::time::Duration;
 
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
     use httpmock::Method::{GET, HEAD};
     use httpmock::MockServer;
     use tempfile::tempdir;
 
     use crate::assert_timeout_eq;
-    use crate::core::config::PopcornProperties;
+    use crate::core::config::{NetworkSettings, PopcornProperties};
+    use crate::core::crash::CrashReport;
+    use crate::core::events::EventPublisher;
     use crate::core::platform::{PlatformInfo, PlatformType};
     use crate::core::updater::PatchInfo;
     use crate::testing::{
@@ -970,6 +1508,8 @@ mod test {
 
     use super::*;
 
+    const UPDATE_INFO_FILE: &str = "versions.json";
+
     #[test]
     fn test_poll_version() {
         init_logger();
@@ -1004,7 +1544,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         let expected_result = VersionInfo {
             application: PatchInfo {
@@ -1013,6 +1552,8 @@ mod test {
                     "debian.x86_64".to_string(),
                     "http://localhost/v1.0.0/popcorn-time_1.0.0.deb".to_string(),
                 )]),
+                checksums: Default::default(),
+                deltas: Default::default(),
             },
             runtime: PatchInfo {
                 version: "17.0.6".to_string(),
@@ -1020,6 +1561,8 @@ mod test {
                     "debian.x86_64".to_string(),
                     "http://localhost/runtime_debian_x86_64.tar.gz".to_string(),
                 )]),
+                checksums: Default::default(),
+                deltas: Default::default(),
             },
         };
 
@@ -1030,6 +1573,130 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_poll_version_send_crash_report() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let crash_reporter = Arc::new(CrashReporter::new(
+            temp_path,
+            Arc::new(EventPublisher::default()),
+        ));
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/{}", UPDATE_INFO_FILE))
+                .header_exists(CRASH_REPORT_HEADER);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+  "application": {
+    "version": "1.0.0",
+    "platforms": {}
+  },
+  "runtime": {
+    "version": "17.0.6",
+    "platforms": {}
+  }
+}"#,
+                );
+        });
+        settings.update_network(NetworkSettings {
+            send_crash_reports: true,
+            ..Default::default()
+        });
+        crash_reporter.report(CrashReport {
+            timestamp: 1000,
+            version: "0.1.0".to_string(),
+            thread: "main".to_string(),
+            message: "lorem ipsum".to_string(),
+            location: None,
+            backtrace: "dolor".to_string(),
+        });
+        let platform = default_platform_info();
+
+        let _updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .crash_reporter(crash_reporter.clone())
+            .build();
+
+        assert_timeout_eq!(
+            Duration::from_millis(500),
+            true,
+            crash_reporter.pending_report().is_none()
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn test_poll_version_crash_report_failed_status() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let crash_reporter = Arc::new(CrashReporter::new(
+            temp_path,
+            Arc::new(EventPublisher::default()),
+        ));
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/{}", UPDATE_INFO_FILE))
+                .header_exists(CRASH_REPORT_HEADER);
+            then.status(500);
+        });
+        settings.update_network(NetworkSettings {
+            send_crash_reports: true,
+            ..Default::default()
+        });
+        crash_reporter.report(CrashReport {
+            timestamp: 1000,
+            version: "0.1.0".to_string(),
+            thread: "main".to_string(),
+            message: "lorem ipsum".to_string(),
+            location: None,
+            backtrace: "dolor".to_string(),
+        });
+        let platform = default_platform_info();
+
+        let _updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .crash_reporter(crash_reporter.clone())
+            .build();
+
+        assert_timeout_eq!(Duration::from_millis(500), 1, mock.hits());
+        thread::sleep(Duration::from_millis(50));
+        assert!(
+            crash_reporter.pending_report().is_some(),
+            "expected the crash report to still be pending"
+        );
+    }
+
+    #[test]
+    fn test_crash_report_header_encoded_length() {
+        let report = PendingCrashReport {
+            name: "crash-1000.log".to_string(),
+            contents: "lorem ipsum é\n".repeat(1000),
+        };
+
+        let result = InnerUpdater::crash_report_header(&report);
+
+        assert!(
+            result.len() <= CRASH_REPORT_MAX_BYTES,
+            "expected the encoded header to not exceed {} bytes, got {} instead",
+            CRASH_REPORT_MAX_BYTES,
+            result.len()
+        );
+        assert!(
+            result.starts_with("lorem%20ipsum%20%C3%A9%0A"),
+            "expected the header to have been percent-encoded"
+        );
+    }
+
     #[test]
     fn test_poll_older_version() {
         init_logger();
@@ -1061,7 +1728,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .with_callback(Box::new(move |event| tx.send(event).unwrap()))
             .build();
 
@@ -1108,7 +1774,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         assert_timeout_eq!(
@@ -1149,7 +1814,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         assert_timeout_eq!(
@@ -1160,14 +1824,45 @@ mod test {
     }
 
     #[test]
-    fn test_download_application() {
+    fn test_poll_invalid_response() {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
         let (server, settings) = create_server_and_settings(temp_path);
-        let filename = "popcorn-time_99.0.0.deb";
-        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
-        server.mock(move |when, then| {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(500);
+        });
+        let platform = default_platform_info();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .with_callback(Box::new(move |event| {
+                if let UpdateEvent::Failed(error) = event {
+                    tx.send(error).unwrap();
+                }
+            }))
+            .build();
+
+        let result = rx.recv_timeout(Duration::from_millis(500)).unwrap();
+        match result {
+            UpdateError::Response(_) => {}
+            _ => assert!(false, "expected UpdateError::Response, got {:?}", result),
+        }
+        assert_eq!(UpdateState::Error, updater.state());
+    }
+
+    #[test]
+    fn test_download_application() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let filename = "popcorn-time_99.0.0.deb";
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        server.mock(move |when, then| {
             when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
             then.status(200)
                 .header("content-type", "application/json")
@@ -1203,7 +1898,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         let expected_result = read_test_file_to_string(filename);
 
@@ -1267,7 +1961,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         let expected_result = read_test_file_to_bytes(filename);
 
@@ -1323,7 +2016,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         // wait for state update available
@@ -1344,6 +2036,307 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_download_verify_checksum() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let filename = "popcorn-time_99.0.0.deb";
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        let checksum = test_file_checksum(filename);
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+  "application": {{
+    "version": "99.0.0",
+    "platforms": {{
+        "debian.x86_64": "{}"
+    }},
+    "checksums": {{
+        "debian.x86_64": "{}"
+    }}
+  }},
+  "runtime": {{
+    "version": "1.0.0",
+    "platforms": {{}}
+  }}
+}}"#,
+                    app_url, checksum
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(302);
+        });
+        let download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body_from_file(test_resource_filepath(filename).to_str().unwrap());
+        });
+        let (tx, rx) = channel();
+        let platform = default_platform_info();
+        let runtime = Runtime::new().unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .with_callback(Box::new(move |event| {
+                if let UpdateEvent::VerificationProgress(progress) = event {
+                    tx.send(progress).unwrap();
+                }
+            }))
+            .build();
+
+        // wait for state update available
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        let _ = runtime
+            .block_on(async { updater.download().await })
+            .expect("expected the download to succeed");
+        let progress = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+
+        download.assert_hits(1);
+        assert_eq!(UpdateState::DownloadFinished, updater.state());
+        assert_eq!(1, progress.task);
+        assert_eq!(1, progress.total_tasks);
+    }
+
+    #[test]
+    fn test_download_checksum_mismatch() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let filename = "popcorn-time_99.0.0.deb";
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+  "application": {{
+    "version": "99.0.0",
+    "platforms": {{
+        "debian.x86_64": "{}"
+    }},
+    "checksums": {{
+        "debian.x86_64": "0000000000000000000000000000000000000000000000000000000000000000"
+    }}
+  }},
+  "runtime": {{
+    "version": "1.0.0",
+    "platforms": {{}}
+  }}
+}}"#,
+                    app_url
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(302);
+        });
+        let download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body_from_file(test_resource_filepath(filename).to_str().unwrap());
+        });
+        let platform = default_platform_info();
+        let runtime = Runtime::new().unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .build();
+
+        // wait for state update available
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        let result = runtime.block_on(async { updater.download().await });
+
+        download.assert_hits(2);
+        assert_eq!(UpdateState::Error, updater.state());
+        match result {
+            Err(UpdateError::ChecksumMismatch(file)) => assert_eq!(filename, file),
+            _ => assert!(
+                false,
+                "expected UpdateError::ChecksumMismatch, got {:?}",
+                result
+            ),
+        }
+        assert!(
+            !temp_dir.path().join("updates").join(filename).exists(),
+            "expected the corrupt update file to have been removed"
+        );
+    }
+
+    #[test]
+    fn test_download_delta_patch() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        let delta_url = server.url("/v99.0.0/application.tar.gz");
+        let checksum = test_file_checksum("application.tar.gz");
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+  "application": {{
+    "version": "99.0.0",
+    "platforms": {{
+        "debian.x86_64": "{}"
+    }},
+    "deltas": {{
+        "debian.x86_64": {{
+            "base_version": "{}",
+            "url": "{}",
+            "checksum": "{}",
+            "files": []
+        }}
+    }}
+  }},
+  "runtime": {{
+    "version": "1.0.0",
+    "platforms": {{}}
+  }}
+}}"#,
+                    app_url, VERSION, delta_url, checksum
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(302);
+        });
+        let full_download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(200);
+        });
+        let delta_download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/application.tar.gz");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body_from_file(
+                    test_resource_filepath("application.tar.gz")
+                        .to_str()
+                        .unwrap(),
+                );
+        });
+        fs::create_dir_all(temp_dir.path().join(VERSION)).unwrap();
+        let platform = default_platform_info();
+        let runtime = Runtime::new().unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .build();
+
+        // wait for state update available
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        let _ = runtime
+            .block_on(async { updater.download().await })
+            .expect("expected the download to succeed");
+
+        delta_download.assert_hits(1);
+        full_download.assert_hits(0);
+    }
+
+    #[test]
+    fn test_download_delta_patch_base_mismatch() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        let filename = "popcorn-time_99.0.0.deb";
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        let delta_url = server.url("/v99.0.0/application.tar.gz");
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+  "application": {{
+    "version": "99.0.0",
+    "platforms": {{
+        "debian.x86_64": "{}"
+    }},
+    "deltas": {{
+        "debian.x86_64": {{
+            "base_version": "0.0.1",
+            "url": "{}",
+            "checksum": "0000000000000000000000000000000000000000000000000000000000000000",
+            "files": []
+        }}
+    }}
+  }},
+  "runtime": {{
+    "version": "1.0.0",
+    "platforms": {{}}
+  }}
+}}"#,
+                    app_url, delta_url
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(302);
+        });
+        let full_download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body_from_file(test_resource_filepath(filename).to_str().unwrap());
+        });
+        let delta_download = server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/application.tar.gz");
+            then.status(200);
+        });
+        let platform = default_platform_info();
+        let runtime = Runtime::new().unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .build();
+
+        // wait for state update available
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        let _ = runtime
+            .block_on(async { updater.download().await })
+            .expect("expected the download to succeed");
+
+        full_download.assert_hits(1);
+        delta_download.assert_hits(0);
+    }
+
     #[test]
     fn test_install_no_update() {
         init_logger();
@@ -1357,7 +2350,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .with_callback(Box::new(move |event| tx.send(event).unwrap()))
             .build();
 
@@ -1421,7 +2413,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         let runtime = Runtime::new().unwrap();
 
@@ -1457,6 +2448,163 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_install_delta_patch() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let base_path = temp_dir.path().join(VERSION);
+        let (server, settings) = create_server_and_settings(temp_path);
+        let mut diff = bsdiff_control(5, 6, 0);
+        diff.extend_from_slice(&[0u8; 5]);
+        diff.extend_from_slice(b" dolor");
+        diff.extend(bsdiff_control(6, 0, 0));
+        diff.extend_from_slice(&[0u8; 6]);
+        let files = format!(
+            r#"[
+                {{"path": "lib/base.txt", "action": "patch", "checksum": "{}"}},
+                {{"path": "test.txt", "action": "add", "checksum": "{}"}},
+                {{"path": "obsolete.txt", "action": "delete"}}
+            ]"#,
+            InnerUpdater::data_checksum(b"lorem dolor ipsum"),
+            InnerUpdater::data_checksum(b"new file")
+        );
+        delta_patch_response(
+            &server,
+            files.as_str(),
+            delta_archive(vec![
+                ("lib/base.txt", diff.as_slice()),
+                ("test.txt", b"new file"),
+            ]),
+        );
+        fs::create_dir_all(base_path.join("lib")).unwrap();
+        fs::write(base_path.join("lib").join("base.txt"), "lorem ipsum").unwrap();
+        fs::write(base_path.join("obsolete.txt"), "obsolete").unwrap();
+        let (tx, rx) = channel();
+        let platform = default_platform_info();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .with_callback(Box::new(move |event| {
+                if let UpdateEvent::InstallationProgress(progress) = event {
+                    tx.send(progress).unwrap();
+                }
+            }))
+            .build();
+        let runtime = Runtime::new().unwrap();
+
+        // wait for the UpdateAvailable state
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        // download the update
+        if let Err(err) = runtime.block_on(updater.download()) {
+            assert!(false, "expected the download to succeed, {}", err);
+        }
+
+        // install the update
+        if let Err(err) = updater.install() {
+            assert!(false, "expected the installation to succeed, {}", err);
+        }
+
+        let progress = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(1, progress.task);
+        assert_eq!(1, progress.total_tasks);
+
+        // wait for the installation to complete
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::InstallationFinished,
+            updater.state()
+        );
+
+        let installation_path = temp_dir.path().join("99.0.0");
+        assert_eq!(
+            "lorem dolor ipsum",
+            fs::read_to_string(installation_path.join("lib").join("base.txt")).unwrap()
+        );
+        assert_eq!(
+            "new file",
+            fs::read_to_string(installation_path.join("test.txt")).unwrap()
+        );
+        assert!(
+            !installation_path.join("obsolete.txt").exists(),
+            "expected the deleted file to have been removed"
+        );
+        assert_eq!(
+            "lorem ipsum",
+            fs::read_to_string(base_path.join("lib").join("base.txt")).unwrap(),
+            "expected the base installation to be untouched"
+        );
+    }
+
+    #[test]
+    fn test_install_delta_patch_checksum_mismatch() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let base_path = temp_dir.path().join(VERSION);
+        let (server, settings) = create_server_and_settings(temp_path);
+        delta_patch_response(
+            &server,
+            r#"[
+                {"path": "test.txt", "action": "add", "checksum": "0000000000000000000000000000000000000000000000000000000000000000"}
+            ]"#,
+            delta_archive(vec![("test.txt", b"new file")]),
+        );
+        fs::create_dir_all(&base_path).unwrap();
+        fs::write(base_path.join("base.txt"), "lorem ipsum").unwrap();
+        let (tx, rx) = channel();
+        let platform = default_platform_info();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(platform)
+            .data_path(temp_path)
+            .with_callback(Box::new(move |event| {
+                if let UpdateEvent::Failed(error) = event {
+                    tx.send(error).unwrap();
+                }
+            }))
+            .build();
+        let runtime = Runtime::new().unwrap();
+
+        // wait for the UpdateAvailable state
+        assert_timeout_eq!(
+            Duration::from_millis(200),
+            UpdateState::UpdateAvailable,
+            updater.state()
+        );
+
+        // download the update
+        if let Err(err) = runtime.block_on(updater.download()) {
+            assert!(false, "expected the download to succeed, {}", err);
+        }
+
+        // install the update
+        if let Err(err) = updater.install() {
+            assert!(false, "expected the installation to start, {}", err);
+        }
+
+        let error = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(UpdateError::ChecksumMismatch("test.txt".to_string()), error);
+        assert!(
+            !temp_dir.path().join("99.0.0").exists(),
+            "expected the new version to not have been installed"
+        );
+        assert!(
+            !temp_dir
+                .path()
+                .join(UPDATE_DIRECTORY)
+                .join("99.0.0.staging")
+                .exists(),
+            "expected the staging directory to have been removed"
+        );
+    }
+
     #[test]
     fn test_install_update() {
         init_logger();
@@ -1499,7 +2647,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         let runtime = Runtime::new().unwrap();
 
@@ -1552,6 +2699,7 @@ mod test {
                     update_channel: String::new(),
                     providers: Default::default(),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: Default::default(),
                     tracking: Default::default(),
                 })
@@ -1561,7 +2709,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
         copy_test_file(updates_directory.to_str().unwrap(), filename, None);
 
@@ -1584,6 +2731,128 @@ mod test {
         assert_eq!(0, num_files);
     }
 
+    #[test]
+    fn test_set_channel() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        no_update_response(&server);
+        let beta_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/{}", UpdateChannel::Beta.info_filename()));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+  "application": {
+    "version": "0.0.6",
+    "platforms": {}
+  },
+  "runtime": {
+    "version": "0.2.1",
+    "platforms": {}
+  }
+ }"#,
+                );
+        });
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(default_platform_info())
+            .data_path(temp_path)
+            .build();
+
+        assert_timeout_eq!(
+            Duration::from_millis(500),
+            UpdateState::NoUpdateAvailable,
+            updater.state()
+        );
+
+        updater
+            .set_channel(UpdateChannel::Beta)
+            .expect("expected the channel to have been updated");
+
+        assert_eq!(UpdateChannel::Beta, updater.channel());
+        assert_timeout_eq!(Duration::from_millis(500), 1, beta_mock.hits());
+        assert_eq!(
+            UpdateChannel::Beta,
+            LauncherOptions::new(temp_path).update_channel,
+            "expected the channel to have been persisted"
+        );
+    }
+
+    #[test]
+    fn test_rollback_update() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        no_update_response(&server);
+        fs::create_dir_all(temp_dir.path().join("1.0.0")).unwrap();
+        LauncherOptions {
+            version: "2.0.0".to_string(),
+            runtime_version: "21.0.3".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            previous_runtime_version: Some("21.0.0".to_string()),
+            ..Default::default()
+        }
+        .write(temp_dir.path().join(LauncherOptions::filename()))
+        .unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(default_platform_info())
+            .data_path(temp_path)
+            .build();
+        let runtime = Runtime::new().unwrap();
+
+        assert_timeout_eq!(
+            Duration::from_millis(500),
+            UpdateState::NoUpdateAvailable,
+            updater.state()
+        );
+
+        let result = runtime.block_on(updater.rollback_update());
+
+        assert_eq!(Ok(()), result);
+        assert_eq!(UpdateState::InstallationFinished, updater.state());
+        let result = LauncherOptions::new(temp_path);
+        assert_eq!("1.0.0".to_string(), result.version);
+        assert_eq!("21.0.0".to_string(), result.runtime_version);
+        assert_eq!(Some("2.0.0".to_string()), result.previous_version);
+        assert_eq!(Some("21.0.3".to_string()), result.previous_runtime_version);
+    }
+
+    #[test]
+    fn test_rollback_update_previous_version_missing() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (server, settings) = create_server_and_settings(temp_path);
+        no_update_response(&server);
+        LauncherOptions {
+            version: "2.0.0".to_string(),
+            previous_version: Some("1.0.0".to_string()),
+            ..Default::default()
+        }
+        .write(temp_dir.path().join(LauncherOptions::filename()))
+        .unwrap();
+        let updater = Updater::builder()
+            .settings(settings)
+            .platform(default_platform_info())
+            .data_path(temp_path)
+            .build();
+        let runtime = Runtime::new().unwrap();
+
+        let result = runtime.block_on(updater.rollback_update());
+
+        assert_eq!(Err(UpdateError::RollbackUnavailable), result);
+        assert_eq!(
+            "2.0.0".to_string(),
+            LauncherOptions::new(temp_path).version,
+            "expected the active version to be unchanged"
+        );
+    }
+
     #[test]
     fn test_check_for_updates() {
         init_logger();
@@ -1613,7 +2882,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         updater.register(Box::new(move |event| {
@@ -1676,7 +2944,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         let result = updater
@@ -1685,10 +2952,14 @@ mod test {
                 application: PatchInfo {
                     version: "lorem".to_string(),
                     platforms: Default::default(),
+                    checksums: Default::default(),
+                    deltas: Default::default(),
                 },
                 runtime: PatchInfo {
                     version: "ipsum".to_string(),
                     platforms: Default::default(),
+                    checksums: Default::default(),
+                    deltas: Default::default(),
                 },
             })
             .await;
@@ -1720,7 +2991,6 @@ mod test {
                 _ => {}
             }))
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         let event = rx.recv_timeout(Duration::from_millis(300)).unwrap();
@@ -1744,7 +3014,6 @@ mod test {
             .settings(settings)
             .platform(platform)
             .data_path(temp_path)
-            .insecure(false)
             .build();
 
         updater.register(Box::new(move |event| match event {
@@ -1769,14 +3038,14 @@ mod test {
             .settings(create_simple_settings(temp_path))
             .platform(default_platform_info())
             .data_path(temp_path)
-            .insecure(false)
+            .client_factory(HttpClientFactory::default())
             .runtime(Arc::new(Runtime::new().unwrap()));
 
         let debug_output = format!("{:?}", builder);
 
         assert!(debug_output.contains("UpdaterBuilder"));
         assert!(debug_output.contains("settings: Some"));
-        assert!(debug_output.contains("insecure: false"));
+        assert!(debug_output.contains("client_factory: Some"));
         assert!(debug_output.contains("platform: Some"));
         assert!(debug_output.contains("storage_path: Some"));
         assert!(debug_output.contains("runtime: Some"));
@@ -1787,6 +3056,9 @@ mod test {
         platform_mock.expect_info().returning(|| PlatformInfo {
             platform_type: PlatformType::Linux,
             arch: "x86_64".to_string(),
+            cpu_cores: 4,
+            total_memory: 8_000_000_000,
+            available_memory: 4_000_000_000,
         });
         let platform = Arc::new(Box::new(platform_mock) as Box<dyn PlatformData>);
         platform
@@ -1813,6 +3085,78 @@ mod test {
         });
     }
 
+    fn delta_patch_response(server: &MockServer, files: &str, archive: Vec<u8>) {
+        let app_url = server.url("/v99.0.0/popcorn-time_99.0.0.deb");
+        let delta_url = server.url("/v99.0.0/application.tar.gz");
+        let checksum = InnerUpdater::data_checksum(archive.as_slice());
+        let files = files.to_string();
+        server.mock(move |when, then| {
+            when.method(GET).path(format!("/{}", UPDATE_INFO_FILE));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+  "application": {{
+    "version": "99.0.0",
+    "platforms": {{
+        "debian.x86_64": "{}"
+    }},
+    "deltas": {{
+        "debian.x86_64": {{
+            "base_version": "{}",
+            "url": "{}",
+            "checksum": "{}",
+            "files": {}
+        }}
+    }}
+  }},
+  "runtime": {{
+    "version": "1.0.0",
+    "platforms": {{}}
+  }}
+}}"#,
+                    app_url, VERSION, delta_url, checksum, files
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(HEAD).path("/v99.0.0/popcorn-time_99.0.0.deb");
+            then.status(302);
+        });
+        server.mock(move |when, then| {
+            when.method(GET).path("/v99.0.0/application.tar.gz");
+            then.status(200)
+                .header("content-type", "application/octet-stream")
+                .body(archive);
+        });
+    }
+
+    fn delta_archive(entries: Vec<(&str, &[u8])>) -> Vec<u8> {
+        let mut builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        for (path, data) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn bsdiff_control(add_length: u64, copy_length: u64, seek: u64) -> Vec<u8> {
+        [add_length, copy_length, seek]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    fn test_file_checksum(filename: &str) -> String {
+        ring::digest::digest(&SHA256, read_test_file_to_bytes(filename).as_slice())
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()
+    }
+
     fn create_simple_settings(temp_path: &str) -> Arc<ApplicationConfig> {
         Arc::new(
             ApplicationConfig::builder()
@@ -1822,6 +3166,7 @@ mod test {
                     update_channel: "http://localhost:8080/update.json".to_string(),
                     providers: Default::default(),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: Default::default(),
                     tracking: Default::default(),
                 })
@@ -1843,6 +3188,7 @@ mod test {
                         update_channel,
                         providers: Default::default(),
                         enhancers: Default::default(),
+                        torrent_sources: Default::default(),
                         subtitle: Default::default(),
                         tracking: Default::default(),
                     })
@@ -24,10 +24,16 @@ pub struct VersionInfo {
 ///
 /// * `version` - The version number of the patch in semantic format.
 /// * `platforms` - A mapping of platform names to update versions.
+/// * `checksums` - A mapping of platform names to the SHA-256 checksum of the full archive.
+/// * `deltas` - A mapping of platform names to the delta patch from the previous version.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct PatchInfo {
     pub version: String,
     pub platforms: HashMap<String, String>,
+    #[serde(default)]
+    pub checksums: HashMap<String, String>,
+    #[serde(default)]
+    pub deltas: HashMap<String, DeltaPatch>,
 }
 
 impl PatchInfo {
@@ -52,4 +58,81 @@ impl PatchInfo {
     pub fn download_link(&self, platform: &str) -> Option<&String> {
         self.platforms.get(platform)
     }
+
+    /// Returns the SHA-256 checksum of the full archive for the specified platform.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform` - A string slice representing the name of the platform to retrieve the checksum for.
+    ///
+    /// # Returns
+    ///
+    /// An optional hex encoded checksum. Returns `None` if no checksum is known for the specified platform.
+    pub fn checksum(&self, platform: &str) -> Option<&String> {
+        self.checksums.get(platform)
+    }
+
+    /// Returns the delta patch for the specified platform.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform` - A string slice representing the name of the platform to retrieve the delta patch for.
+    ///
+    /// # Returns
+    ///
+    /// An optional delta patch. Returns `None` if no delta patch is available for the specified platform.
+    pub fn delta(&self, platform: &str) -> Option<&DeltaPatch> {
+        self.deltas.get(platform)
+    }
+}
+
+/// A delta patch which updates an installation of the base version to the new version.
+///
+/// The delta archive is a gzip compressed tar archive which contains an entry for each [DeltaFile] with the
+/// [DeltaAction::Patch] or [DeltaAction::Add] action, stored under the path of the file.
+/// Patched files contain a binary diff in the raw bsdiff format against the file of the base version,
+/// added files are stored in full. Files with the [DeltaAction::Delete] action have no archive entry.
+///
+/// The delta patch can only be applied on top of an installation of the base version,
+/// otherwise the full archive of the [PatchInfo] needs to be used.
+///
+/// # Fields
+///
+/// * `base_version` - The version on which the delta patch needs to be applied.
+/// * `url` - The download link of the delta archive.
+/// * `checksum` - The hex encoded SHA-256 checksum of the delta archive.
+/// * `files` - The files which are changed by the delta patch.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DeltaPatch {
+    pub base_version: String,
+    pub url: String,
+    pub checksum: String,
+    pub files: Vec<DeltaFile>,
+}
+
+/// A file which is changed by a [DeltaPatch].
+///
+/// # Fields
+///
+/// * `path` - The path of the file, relative to the installation directory.
+/// * `action` - The action which needs to be applied to the file.
+/// * `checksum` - The hex encoded SHA-256 checksum of the file after the action has been applied, required for patched and added files.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DeltaFile {
+    pub path: String,
+    pub action: DeltaAction,
+    #[serde(default)]
+    pub checksum: Option<String>,
+}
+
+/// The action which is applied to a [DeltaFile].
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeltaAction {
+    /// The file of the base version is patched with the binary diff from the delta archive.
+    Patch,
+    /// The file is extracted from the delta archive.
+    Add,
+    /// The file of the base version is removed.
+    Delete,
 }
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use log::{debug, trace};
+use sysinfo::Disks;
+
+/// Retrieve the available space in bytes of the disk on which the given path is stored.
+///
+/// When the path doesn't exist (yet), the nearest existing parent directory is used instead,
+/// as that's the disk on which the path will be created.
+///
+/// # Arguments
+///
+/// * `path` - The path to retrieve the available disk space of.
+///
+/// # Returns
+///
+/// The available space in bytes, or [None] when the disk of the path couldn't be determined.
+pub fn available_space<P: AsRef<Path>>(path: P) -> Option<u64> {
+    let path = path.as_ref();
+    let existing_path = path
+        .ancestors()
+        .find(|e| e.exists())
+        .and_then(|e| e.canonicalize().ok());
+
+    match existing_path {
+        None => {
+            debug!(
+                "Unable to determine the disk of {:?}, path doesn't exist",
+                path
+            );
+            None
+        }
+        Some(existing_path) => {
+            let disks = Disks::new_with_refreshed_list();
+            let result = disks
+                .list()
+                .iter()
+                .filter(|e| existing_path.starts_with(e.mount_point()))
+                .max_by_key(|e| e.mount_point().as_os_str().len())
+                .map(|e| e.available_space());
+
+            trace!(
+                "Path {:?} has {:?} bytes of disk space available",
+                path,
+                result
+            );
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempfile::tempdir;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_available_space() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let expected_result = available_space(temp_dir.path());
+
+        let result = available_space(temp_dir.path().join("lorem").join("ipsum"));
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn test_available_space_non_existing_path() {
+        init_logger();
+
+        let result = available_space("lorem/ipsum/dolor");
+
+        assert_eq!(None, result);
+    }
+}
@@ -0,0 +1,333 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::{debug, error, trace, warn};
+use reqwest::{Client, ClientBuilder, NoProxy, Proxy};
+
+use crate::core::config::{ApplicationConfig, ApplicationConfigEvent, NetworkSettings};
+
+/// The factory of the HTTP clients which are used for the outbound requests of the application.
+///
+/// All clients are configured from the [NetworkSettings] of the application, which includes the proxy,
+/// custom user agent and request timeout. When the factory is created from an [ApplicationConfig],
+/// the shared client is recreated each time the network settings are changed.
+///
+/// Services should retrieve the client from the factory for each request through [HttpClientFactory::client],
+/// instead of storing the client, so they automatically use the latest network settings.
+///
+/// # Cloning
+///
+/// Cloning the factory will create a new instance that shares the clients with the original.
+#[derive(Clone)]
+pub struct HttpClientFactory {
+    inner: Arc<InnerHttpClientFactory>,
+}
+
+impl HttpClientFactory {
+    /// Creates a new `HttpClientFactoryBuilder` to configure the factory.
+    pub fn builder() -> HttpClientFactoryBuilder {
+        HttpClientFactoryBuilder::default()
+    }
+
+    /// Retrieve the shared HTTP client which uses the current network settings.
+    pub fn client(&self) -> Client {
+        self.inner.client.read().unwrap().clone()
+    }
+
+    /// Retrieve a new client builder which has been configured with the current network settings.
+    /// This can be used by services which require additional client options, such as default headers.
+    ///
+    /// Clients which are built from this builder won't be reconfigured when the network settings change.
+    pub fn client_builder(&self) -> ClientBuilder {
+        let settings = self.inner.settings.read().unwrap();
+        InnerHttpClientFactory::configure(Client::builder(), &settings, self.inner.insecure)
+    }
+
+    /// Update the network settings of the factory, which recreates the shared client.
+    pub fn update(&self, settings: NetworkSettings) {
+        self.inner.update(settings)
+    }
+}
+
+impl Debug for HttpClientFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpClientFactory")
+            .field("insecure", &self.inner.insecure)
+            .field("settings", &self.inner.settings)
+            .finish()
+    }
+}
+
+impl Default for HttpClientFactory {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for [HttpClientFactory].
+#[derive(Debug, Default)]
+pub struct HttpClientFactoryBuilder {
+    config: Option<Arc<ApplicationConfig>>,
+    settings: Option<NetworkSettings>,
+    insecure: bool,
+}
+
+impl HttpClientFactoryBuilder {
+    /// Sets the application config from which the network settings are used.
+    /// The factory will follow the changes of the network settings within the config.
+    pub fn config(mut self, config: Arc<ApplicationConfig>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Sets the network settings to use when no application config has been set.
+    pub fn settings(mut self, settings: NetworkSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Sets whether invalid certificates are accepted by the clients of the factory.
+    pub fn insecure(mut self, insecure: bool) -> Self {
+        self.insecure = insecure;
+        self
+    }
+
+    /// Builds a new instance of [HttpClientFactory].
+    pub fn build(self) -> HttpClientFactory {
+        let settings = self
+            .config
+            .as_ref()
+            .map(|e| e.user_settings_ref().network().clone())
+            .or(self.settings)
+            .unwrap_or_default();
+        let inner = Arc::new(InnerHttpClientFactory {
+            insecure: self.insecure,
+            client: RwLock::new(InnerHttpClientFactory::create_client(
+                &settings,
+                self.insecure,
+            )),
+            settings: RwLock::new(settings),
+        });
+
+        if let Some(config) = self.config {
+            let inner = Arc::downgrade(&inner);
+            config.register(Box::new(move |event| {
+                if let ApplicationConfigEvent::NetworkSettingsChanged(settings) = event {
+                    if let Some(inner) = inner.upgrade() {
+                        inner.update(settings);
+                    }
+                }
+            }));
+        }
+
+        HttpClientFactory { inner }
+    }
+}
+
+#[derive(Debug)]
+struct InnerHttpClientFactory {
+    insecure: bool,
+    client: RwLock<Client>,
+    settings: RwLock<NetworkSettings>,
+}
+
+impl InnerHttpClientFactory {
+    fn update(&self, settings: NetworkSettings) {
+        trace!("Updating http clients with network settings {}", settings);
+        *self.client.write().unwrap() = Self::create_client(&settings, self.insecure);
+        *self.settings.write().unwrap() = settings;
+        debug!("Http clients have been updated with the new network settings");
+    }
+
+    fn create_client(settings: &NetworkSettings, insecure: bool) -> Client {
+        Self::configure(Client::builder(), settings, insecure)
+            .build()
+            .unwrap_or_else(|e| {
+                error!(
+                    "Failed to create http client from the network settings, {}",
+                    e
+                );
+                Client::builder()
+                    .danger_accept_invalid_certs(insecure)
+                    .build()
+                    .expect("expected a new client")
+            })
+    }
+
+    fn configure(
+        builder: ClientBuilder,
+        settings: &NetworkSettings,
+        insecure: bool,
+    ) -> ClientBuilder {
+        let mut builder = builder.danger_accept_invalid_certs(insecure);
+
+        if let Some(uri) = settings.proxy() {
+            match Proxy::all(uri.as_str()) {
+                Ok(mut proxy) => {
+                    if let Some(username) = settings.proxy_username.as_ref() {
+                        proxy = proxy.basic_auth(
+                            username.as_str(),
+                            settings
+                                .proxy_password
+                                .as_ref()
+                                .map(|e| e.as_str())
+                                .unwrap_or(""),
+                        );
+                    }
+                    if !settings.proxy_bypass.is_empty() {
+                        proxy =
+                            proxy.no_proxy(NoProxy::from_string(&settings.proxy_bypass.join(",")));
+                    }
+
+                    builder = builder.proxy(proxy);
+                }
+                Err(e) => warn!("Proxy {} is invalid and will be ignored, {}", uri, e),
+            }
+        }
+        if let Some(user_agent) = settings.user_agent() {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        if settings.request_timeout > 0 {
+            builder = builder.timeout(Duration::from_secs(settings.request_timeout));
+        }
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use tempfile::tempdir;
+    use tokio::runtime::Runtime;
+
+    use crate::testing::init_logger;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_client_proxy() {
+        init_logger();
+        let proxy = MockServer::start();
+        let mock = proxy.mock(|when, then| {
+            when.method(GET)
+                .path("/lorem")
+                .header("user-agent", "PopcornFX/1.0");
+            then.status(200).body("ipsum");
+        });
+        let factory = HttpClientFactory::builder()
+            .settings(NetworkSettings {
+                proxy: Some(proxy.base_url()),
+                user_agent: Some("PopcornFX/1.0".to_string()),
+                ..Default::default()
+            })
+            .build();
+
+        let response = factory
+            .client()
+            .get("http://popcorn-fx.invalid/lorem")
+            .send()
+            .await
+            .expect("expected the request to have been sent through the proxy");
+
+        assert_eq!(200, response.status().as_u16());
+        assert_eq!("ipsum", response.text().await.unwrap());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_client_proxy_credentials() {
+        init_logger();
+        let proxy = MockServer::start();
+        let mock = proxy.mock(|when, then| {
+            when.method(GET)
+                .path("/lorem")
+                .header("proxy-authorization", "Basic dXNlcjpzZWNyZXQ=");
+            then.status(200);
+        });
+        let factory = HttpClientFactory::builder()
+            .settings(NetworkSettings {
+                proxy: Some(proxy.base_url()),
+                proxy_username: Some("user".to_string()),
+                proxy_password: Some("secret".to_string()),
+                ..Default::default()
+            })
+            .build();
+
+        let response = factory
+            .client()
+            .get("http://popcorn-fx.invalid/lorem")
+            .send()
+            .await
+            .expect("expected the request to have been sent through the proxy");
+
+        assert_eq!(200, response.status().as_u16());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_client_proxy_bypass() {
+        init_logger();
+        let proxy = MockServer::start();
+        let server = MockServer::start();
+        let proxy_mock = proxy.mock(|when, then| {
+            when.method(GET).path("/lorem");
+            then.status(500);
+        });
+        let server_mock = server.mock(|when, then| {
+            when.method(GET).path("/lorem");
+            then.status(200);
+        });
+        let factory = HttpClientFactory::builder()
+            .settings(NetworkSettings {
+                proxy: Some(proxy.base_url()),
+                proxy_bypass: vec![server.host()],
+                ..Default::default()
+            })
+            .build();
+
+        let response = factory
+            .client()
+            .get(server.url("/lorem"))
+            .send()
+            .await
+            .expect("expected the request to have been sent");
+
+        assert_eq!(200, response.status().as_u16());
+        server_mock.assert();
+        proxy_mock.assert_hits(0);
+    }
+
+    #[test]
+    fn test_network_settings_changed() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let proxy = MockServer::start();
+        let mock = proxy.mock(|when, then| {
+            when.method(GET).path("/lorem");
+            then.status(200);
+        });
+        let config = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let factory = HttpClientFactory::builder().config(config.clone()).build();
+
+        config.update_network(NetworkSettings {
+            proxy: Some(proxy.base_url()),
+            ..Default::default()
+        });
+        let runtime = Runtime::new().unwrap();
+        let response = runtime
+            .block_on(
+                factory
+                    .client()
+                    .get("http://popcorn-fx.invalid/lorem")
+                    .send(),
+            )
+            .expect("expected the request to have been sent through the proxy");
+
+        assert_eq!(200, response.status().as_u16());
+        mock.assert();
+    }
+}
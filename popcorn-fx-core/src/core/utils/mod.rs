@@ -1,2 +1,4 @@
+pub mod fs;
+pub mod http;
 pub mod network;
 pub mod time;
@@ -249,6 +249,7 @@ pub mod testing {
     use std::fmt::{Display, Formatter};
     use std::fs::OpenOptions;
     use std::io::Read;
+    use std::ops::Range;
     use std::path::PathBuf;
     use std::sync::{Once, Weak};
     use std::{env, fs};
@@ -265,9 +266,9 @@ pub mod testing {
 
     use crate::core::platform::{Platform, PlatformCallback, PlatformData, PlatformInfo};
     use crate::core::playback::MediaNotificationEvent;
-    use crate::core::players::{PlayRequest, Player, PlayerEvent, PlayerState};
+    use crate::core::players::{MediaTrack, PlayRequest, Player, PlayerEvent, PlayerState};
     use crate::core::subtitles::language::SubtitleLanguage;
-    use crate::core::subtitles::model::SubtitleInfo;
+    use crate::core::subtitles::model::{Subtitle, SubtitleInfo};
     use crate::core::subtitles::{SubtitleEvent, SubtitleManager};
     use crate::core::torrents::{
         Torrent, TorrentCallback, TorrentState, TorrentStream, TorrentStreamCallback,
@@ -417,12 +418,21 @@ pub mod testing {
             fn description(&self) -> &str;
             fn graphic_resource(&self) -> Vec<u8>;
             fn state(&self) -> PlayerState;
-            fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>>;
-            async fn play(&self, request: Box<dyn PlayRequest>);
+            fn embedded_playback_supported(&self) -> bool;
+            fn request(&self) -> Option<Weak<PlayRequest>>;
+            async fn play(&self, request: PlayRequest);
             fn pause(&self);
             fn resume(&self);
             fn seek(&self, time: u64);
             fn stop(&self);
+            fn update_subtitle(&self, subtitle: Option<Subtitle>);
+            fn set_volume(&self, volume: u32);
+            fn set_muted(&self, muted: bool);
+            fn supports(&self, mime: &str) -> bool;
+            fn audio_tracks(&self) -> Option<Vec<MediaTrack>>;
+            fn set_audio_track(&self, id: i32);
+            fn embedded_subtitle_tracks(&self) -> Option<Vec<MediaTrack>>;
+            fn set_embedded_subtitle_track(&self, id: i32);
         }
 
         impl Callbacks<PlayerEvent> for Player {
@@ -480,6 +490,8 @@ pub mod testing {
 
             fn total_pieces(&self) -> i32;
 
+            fn piece_length(&self) -> u64;
+
             fn sequential_mode(&self);
 
             fn state(&self) -> TorrentState;
@@ -498,6 +510,8 @@ pub mod testing {
 
             fn stream_state(&self) -> TorrentStreamState;
 
+            fn available_ranges(&self) -> Vec<Range<u64>>;
+
             fn subscribe_stream(&self, callback: TorrentStreamCallback) -> CallbackHandle;
 
             fn unsubscribe_stream(&self, handle: CallbackHandle);
@@ -521,6 +535,14 @@ pub mod testing {
 
             fn enable_screensaver(&self) -> bool;
 
+            fn prevent_sleep(&self) -> bool;
+
+            fn allow_sleep(&self) -> bool;
+
+            fn inhibit_suspend(&self) -> bool;
+
+            fn release_suspend(&self) -> bool;
+
             fn notify_media_event(&self, notification: MediaNotificationEvent);
 
             fn register(&self, callback: PlatformCallback);
@@ -540,6 +562,14 @@ pub mod testing {
 
             fn enable_screensaver(&self) -> bool;
 
+            fn prevent_sleep(&self) -> bool;
+
+            fn allow_sleep(&self) -> bool;
+
+            fn inhibit_suspend(&self) -> bool;
+
+            fn release_suspend(&self) -> bool;
+
             fn notify_media_event(&self, notification: MediaNotificationEvent);
 
             fn register(&self, callback: PlatformCallback);
@@ -636,7 +666,9 @@ mod test {
     use httpmock::MockServer;
     use tempfile::TempDir;
 
-    use crate::core::config::{ApplicationConfig, PopcornProperties, ProviderProperties};
+    use crate::core::config::{
+        ApplicationConfig, PopcornProperties, ProviderProperties, ProviderType,
+    };
     use crate::testing::init_logger;
 
     use super::*;
@@ -652,6 +684,7 @@ mod test {
                     update_channel: String::new(),
                     providers: create_providers(&server),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: Default::default(),
                     tracking: Default::default(),
                 })
@@ -666,17 +699,21 @@ mod test {
         map.insert(
             "movies".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![server.url("")],
                 genres: vec![],
                 sort_by: vec![],
+                categories: vec![],
             },
         );
         map.insert(
             "series".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![server.url("")],
                 genres: vec![],
                 sort_by: vec![],
+                categories: vec![],
             },
         );
         map
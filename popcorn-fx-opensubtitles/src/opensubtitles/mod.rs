@@ -1,4 +1,6 @@
 pub use provider::*;
+pub use retry::*;
 
 mod model;
 mod provider;
+mod retry;
@@ -163,6 +163,10 @@ pub struct OpenSubtitlesFeatureDetails {
     year: Option<i32>,
     title: String,
     imdb_id: i32,
+    #[serde(default)]
+    season_number: Option<u32>,
+    #[serde(default)]
+    episode_number: Option<u32>,
 }
 
 impl OpenSubtitlesFeatureDetails {
@@ -179,12 +183,22 @@ impl OpenSubtitlesFeatureDetails {
             year: Some(year),
             title,
             imdb_id,
+            season_number: None,
+            episode_number: None,
         }
     }
 
     pub fn imdb_id(&self) -> &i32 {
         &self.imdb_id
     }
+
+    pub fn season_number(&self) -> Option<&u32> {
+        self.season_number.as_ref()
+    }
+
+    pub fn episode_number(&self) -> Option<&u32> {
+        self.episode_number.as_ref()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, new)]
@@ -192,7 +206,7 @@ pub struct DownloadRequest {
     file_id: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, new)]
+#[derive(Serialize, Deserialize, Debug, Clone, new)]
 pub struct DownloadResponse {
     link: String,
     file_name: String,
@@ -3,26 +3,31 @@ use std::ffi::OsStr;
 use std::fs;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use derive_more::Display;
 use futures::StreamExt;
 use itertools::Itertools;
 use log::{debug, error, info, trace, warn};
-use reqwest::{Client, ClientBuilder, Response, StatusCode, Url};
-use reqwest::header::HeaderMap;
+use reqwest::{IntoUrl, Method, RequestBuilder, Response, StatusCode, Url};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, RANGE};
 use tokio::fs::OpenOptions;
 
 use popcorn_fx_core::core::config::ApplicationConfig;
 use popcorn_fx_core::core::media::*;
-use popcorn_fx_core::core::subtitles::{Result, SubtitleError, SubtitleFile, SubtitleProvider};
+use popcorn_fx_core::core::subtitles::{
+    Result, SubtitleCache, SubtitleError, SubtitleFile, SubtitleProvider,
+};
+use popcorn_fx_core::core::subtitles::cue::SubtitleCue;
 use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
 use popcorn_fx_core::core::subtitles::matcher::SubtitleMatcher;
 use popcorn_fx_core::core::subtitles::model::{Subtitle, SubtitleInfo, SubtitleType};
 use popcorn_fx_core::core::subtitles::parsers::Parser;
+use popcorn_fx_core::core::utils::http::HttpClientFactory;
 
 use crate::opensubtitles::model::*;
+use crate::opensubtitles::RetryPolicy;
 
 const API_HEADER_KEY: &str = "Api-Key";
 const USER_AGENT_HEADER_KEY: &str = "User-Agent";
@@ -32,13 +37,25 @@ const EPISODE_PARAM_KEY: &str = "episode_number";
 const FILENAME_PARAM_KEY: &str = "query";
 const PAGE_PARAM_KEY: &str = "page";
 const DEFAULT_FILENAME_EXTENSION: &str = ".srt";
+/// The maximum number of bytes which are retrieved from a subtitle file to create a preview
+const PREVIEW_MAX_BYTES: usize = 64 * 1024;
 
 #[derive(Debug, Display)]
 #[display(fmt = "Opensubtitles subtitle provider")]
 pub struct OpensubtitlesProvider {
     settings: Arc<ApplicationConfig>,
-    client: Client,
+    client_factory: HttpClientFactory,
+    /// The default headers which are sent with each API request
+    headers: HeaderMap,
     parsers: HashMap<SubtitleType, Box<dyn Parser>>,
+    retry_policy: RetryPolicy,
+    /// The cache of the downloaded subtitle files
+    cache: SubtitleCache,
+    /// The user credentials which have been rejected by the API
+    rejected_credentials: Mutex<Vec<String>>,
+    /// The download links which have been resolved for the subtitle files, but have not been downloaded yet.
+    /// Each resolved link counts towards the download quota of the user.
+    download_links: Mutex<HashMap<i32, DownloadResponse>>,
 }
 
 impl OpensubtitlesProvider {
@@ -132,6 +149,8 @@ impl OpensubtitlesProvider {
     fn search_result_to_subtitles(data: &Vec<SearchResult>) -> Vec<SubtitleInfo> {
         let mut id: String = String::new();
         let mut imdb_id: String = String::new();
+        let mut season: Option<u32> = None;
+        let mut episode: Option<u32> = None;
         let mut languages: HashMap<SubtitleLanguage, Vec<SubtitleFile>> = HashMap::new();
 
         trace!("Mapping a total of {} subtitle search results", data.len());
@@ -169,6 +188,8 @@ impl OpensubtitlesProvider {
                 if id.is_empty() {
                     id = search_result.id().clone();
                     imdb_id = format!("tt{}", attributes.feature_details().imdb_id());
+                    season = attributes.feature_details().season_number().cloned();
+                    episode = attributes.feature_details().episode_number().cloned();
                 }
             } else {
                 warn!(
@@ -184,11 +205,19 @@ impl OpensubtitlesProvider {
                 let language = key.0;
                 let files = key.1;
 
-                SubtitleInfo::builder()
+                let mut builder = SubtitleInfo::builder()
                     .imdb_id(imdb_id.clone())
                     .language(language.clone())
-                    .files(files.clone())
-                    .build()
+                    .files(files.clone());
+
+                if let Some(season) = season {
+                    builder = builder.season(season);
+                }
+                if let Some(episode) = episode {
+                    builder = builder.episode(episode);
+                }
+
+                builder.build()
             })
             .sorted()
             .collect()
@@ -293,7 +322,7 @@ impl OpensubtitlesProvider {
             .await?;
 
         debug!("Retrieving available subtitles from {}", &url);
-        match self.client.clone().get(url).send().await {
+        match self.get_with_retry(url, |e| e).await {
             Err(err) => Err(SubtitleError::SearchFailed(format!(
                 "OpenSubtitles request failed, {}",
                 err
@@ -302,6 +331,142 @@ impl OpensubtitlesProvider {
         }
     }
 
+    /// Create a new request for the given url with the default API headers and the given user credentials.
+    /// The client is retrieved from the factory for each request, so it always uses the latest network settings.
+    fn request<U: IntoUrl>(
+        &self,
+        method: Method,
+        url: U,
+        credentials: &UserCredentials,
+    ) -> RequestBuilder {
+        let mut headers = self.headers.clone();
+
+        if let Some(api_key) = credentials.api_key.as_ref() {
+            match HeaderValue::from_str(api_key) {
+                Ok(value) => {
+                    headers.insert(API_HEADER_KEY, value);
+                }
+                Err(e) => warn!("OpenSubtitles API key is invalid, {}", e),
+            }
+        }
+        if let Some(user_token) = credentials.user_token.as_ref() {
+            match HeaderValue::from_str(format!("Bearer {}", user_token).as_str()) {
+                Ok(value) => {
+                    headers.insert(AUTHORIZATION, value);
+                }
+                Err(e) => warn!("OpenSubtitles user token is invalid, {}", e),
+            }
+        }
+
+        self.client_factory
+            .client()
+            .request(method, url)
+            .headers(headers)
+    }
+
+    /// Send a new request for the given url with the configured user credentials.
+    ///
+    /// When the API rejects the user credentials, they're ignored for the subsequent requests
+    /// and the request is sent again with anonymous access through the built-in API key.
+    async fn send<U, F>(&self, method: Method, url: U, customizer: F) -> reqwest::Result<Response>
+    where
+        U: IntoUrl + Clone,
+        F: Fn(RequestBuilder) -> RequestBuilder,
+    {
+        let credentials = self.user_credentials();
+        let response = customizer(self.request(method.clone(), url.clone(), &credentials))
+            .send()
+            .await?;
+
+        if !credentials.is_anonymous()
+            && matches!(
+                response.status(),
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+            )
+        {
+            warn!(
+                "OpenSubtitles rejected the configured API key or user token with status {}, falling back to anonymous access",
+                response.status()
+            );
+            self.reject_credentials(credentials);
+            return customizer(self.request(method, url, &UserCredentials::default()))
+                .send()
+                .await;
+        }
+
+        Ok(response)
+    }
+
+    /// Retrieve the user credentials from the subtitle settings.
+    /// Credentials which have been rejected by the API before are ignored.
+    fn user_credentials(&self) -> UserCredentials {
+        let settings = self.settings.user_settings().subtitle().clone();
+        let rejected = self.rejected_credentials.lock().unwrap();
+        let is_accepted = |value: &&String| !rejected.contains(value);
+
+        UserCredentials {
+            api_key: settings.api_key().filter(is_accepted).cloned(),
+            user_token: settings.user_token().filter(is_accepted).cloned(),
+        }
+    }
+
+    fn reject_credentials(&self, credentials: UserCredentials) {
+        let mut rejected = self.rejected_credentials.lock().unwrap();
+
+        rejected.extend(credentials.api_key);
+        rejected.extend(credentials.user_token);
+    }
+
+    /// Execute a GET request for the given url, retrying transient failures based on the
+    /// configured [RetryPolicy].
+    /// The customizer is applied on every attempt of the request, e.g. to add additional headers.
+    ///
+    /// Only GET requests are retried as they're idempotent.
+    /// Client errors, except for rate limiting, are returned immediately.
+    async fn get_with_retry<U, F>(&self, url: U, customizer: F) -> reqwest::Result<Response>
+    where
+        U: IntoUrl + Clone,
+        F: Fn(RequestBuilder) -> RequestBuilder,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let result = self.send(Method::GET, url.clone(), &customizer).await;
+            let delay = match &result {
+                Ok(response) if RetryPolicy::is_retryable_status(response.status()) => {
+                    self.retry_policy.delay(attempt, response)
+                }
+                Err(e) if RetryPolicy::is_retryable_error(e) => self.retry_policy.backoff(attempt),
+                _ => return result,
+            };
+
+            if attempt >= self.retry_policy.max_attempts {
+                warn!(
+                    "OpenSubtitles request failed after {} attempt(s), giving up",
+                    attempt
+                );
+                return result;
+            }
+
+            match &result {
+                Ok(response) => debug!(
+                    "OpenSubtitles request attempt {} failed with status {}, retrying in {}ms",
+                    attempt,
+                    response.status(),
+                    delay.as_millis()
+                ),
+                Err(e) => debug!(
+                    "OpenSubtitles request attempt {} failed, retrying in {}ms, {}",
+                    attempt,
+                    delay.as_millis(),
+                    e
+                ),
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     async fn execute_download_request(
         &self,
         file_id: &i32,
@@ -311,7 +476,7 @@ impl OpensubtitlesProvider {
         let download_link = download_response.link();
 
         debug!("Downloading subtitle file from {}", download_link);
-        match self.client.get(download_link).send().await {
+        match self.get_with_retry(download_link, |e| e).await {
             Ok(e) => self.handle_download_binary_response(file_id, path, e).await,
             Err(err) => Err(SubtitleError::DownloadFailed(
                 file_id.to_string(),
@@ -377,28 +542,43 @@ impl OpensubtitlesProvider {
         }
     }
 
-    async fn handle_download_response(
-        &self,
-        file_id: &i32,
-        path: &Path,
-        response: Response,
-    ) -> Result<String> {
+    /// Retrieve the download link of the given subtitle file.
+    /// A link which has already been resolved for the file is reused, as each request counts towards the download quota of the user.
+    async fn download_link(&self, file_id: &i32) -> Result<DownloadResponse> {
+        if let Some(download_response) = self.download_links.lock().unwrap().get(file_id) {
+            debug!(
+                "Reusing resolved download link of subtitle file {}",
+                file_id
+            );
+            return Ok(download_response.clone());
+        }
+
+        let download_response = self.request_download_link(file_id).await?;
+        self.download_links
+            .lock()
+            .unwrap()
+            .insert(*file_id, download_response.clone());
+        Ok(download_response)
+    }
+
+    /// Request the download link of the given subtitle file.
+    async fn request_download_link(&self, file_id: &i32) -> Result<DownloadResponse> {
+        let url = self.create_download_url().await?;
+        trace!("Requesting subtitle file {}", &url);
+        let download_request = DownloadRequest::new(file_id.clone());
+        let response = self
+            .send(Method::POST, url, |e| e.json(&download_request))
+            .await
+            .map_err(|err| SubtitleError::DownloadFailed(file_id.to_string(), err.to_string()))?;
+
         match response.status() {
             StatusCode::OK => {
-                match response
-                    .json::<DownloadResponse>()
-                    .await
-                    .map_err(|err| {
+                let download_response =
+                    response.json::<DownloadResponse>().await.map_err(|err| {
                         SubtitleError::DownloadFailed(file_id.to_string(), err.to_string())
-                    })
-                    .map(|download_response| async {
-                        trace!("Received download link response {:?}", &download_response);
-                        self.execute_download_request(file_id, path, download_response)
-                            .await
-                    }) {
-                    Ok(e) => e.await,
-                    Err(e) => Err(e),
-                }
+                    })?;
+                trace!("Received download link response {:?}", &download_response);
+                Ok(download_response)
             }
             _ => Err(SubtitleError::DownloadFailed(
                 file_id.to_string(),
@@ -410,6 +590,54 @@ impl OpensubtitlesProvider {
         }
     }
 
+    /// Retrieve the head of the subtitle file from the given download link.
+    /// A range request is made for the first [PREVIEW_MAX_BYTES], if the server doesn't support range requests,
+    /// the response is only read until [PREVIEW_MAX_BYTES] have been received.
+    ///
+    /// It returns the retrieved data and if the data has been truncated.
+    async fn fetch_subtitle_head(
+        &self,
+        file_id: &i32,
+        download_response: DownloadResponse,
+    ) -> Result<(String, bool)> {
+        let download_link = download_response.link();
+
+        debug!("Retrieving subtitle preview from {}", download_link);
+        let response = self
+            .get_with_retry(download_link, |e| {
+                e.header(RANGE, format!("bytes=0-{}", PREVIEW_MAX_BYTES - 1))
+            })
+            .await
+            .map_err(|err| SubtitleError::DownloadFailed(file_id.to_string(), err.to_string()))?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let mut data: Vec<u8> = vec![];
+                let mut stream = response.bytes_stream();
+
+                while let Some(chunk) = stream.next().await {
+                    let chunk = chunk.map_err(|e| {
+                        SubtitleError::DownloadFailed(file_id.to_string(), e.to_string())
+                    })?;
+                    data.extend_from_slice(chunk.as_ref());
+
+                    if data.len() >= PREVIEW_MAX_BYTES {
+                        trace!("Subtitle preview limit has been reached for {}", file_id);
+                        break;
+                    }
+                }
+
+                let truncated = data.len() >= PREVIEW_MAX_BYTES;
+                data.truncate(PREVIEW_MAX_BYTES);
+                Ok((String::from_utf8_lossy(&data).to_string(), truncated))
+            }
+            _ => Err(SubtitleError::DownloadFailed(
+                file_id.to_string(),
+                format!("download failed with status code {}", response.status()),
+            )),
+        }
+    }
+
     /// Retrieve the storage [Path] for the given subtitle file.
     async fn storage_file(&self, file: &SubtitleFile) -> PathBuf {
         let file_name = file.name();
@@ -419,8 +647,8 @@ impl OpensubtitlesProvider {
         settings.directory().join(file_name)
     }
 
-    fn internal_parse(&self, file_path: &Path, info: Option<&SubtitleInfo>) -> Result<Subtitle> {
-        trace!("Parsing subtitle file {}", file_path.to_str().unwrap());
+    /// Retrieve the parser for the given subtitle file, based on the extension of the file.
+    fn subtitle_parser(&self, file_path: &Path) -> Result<&Box<dyn Parser>> {
         let path = String::from(file_path.to_str().unwrap());
         let extension = file_path
             .extension()
@@ -430,10 +658,16 @@ impl OpensubtitlesProvider {
             })?;
         let subtitle_type = SubtitleType::from_extension(&extension)
             .map_err(|err| SubtitleError::ParseFileError(path.clone(), err.to_string()))?;
-        let parser = self
-            .parsers
+
+        self.parsers
             .get(&subtitle_type)
-            .ok_or_else(|| SubtitleError::TypeNotSupported(subtitle_type))?;
+            .ok_or_else(|| SubtitleError::TypeNotSupported(subtitle_type))
+    }
+
+    fn internal_parse(&self, file_path: &Path, info: Option<&SubtitleInfo>) -> Result<Subtitle> {
+        trace!("Parsing subtitle file {}", file_path.to_str().unwrap());
+        let path = String::from(file_path.to_str().unwrap());
+        let parser = self.subtitle_parser(file_path)?;
 
         File::open(&file_path)
             .map(|file| parser.parse_file(file))
@@ -527,6 +761,24 @@ impl SubtitleProvider for OpensubtitlesProvider {
         matcher: &SubtitleMatcher,
     ) -> Result<String> {
         trace!("Starting subtitle download for {}", subtitle_info);
+        let auto_cleaning_enabled = *self
+            .settings
+            .user_settings()
+            .subtitle()
+            .auto_cleaning_enabled();
+
+        // verify if the subtitle has been downloaded before for the same media
+        if let Some(path) = self.cache.get(subtitle_info, auto_cleaning_enabled) {
+            info!(
+                "Using cached subtitle file {:?} for {}",
+                path, subtitle_info
+            );
+            return Ok(path
+                .to_str()
+                .expect("expected the subtitle path to be valid")
+                .to_string());
+        }
+
         let subtitle_file = subtitle_info.best_matching_file(matcher)?;
         let file_location = self.storage_file(&subtitle_file).await;
         let file_id = subtitle_file.file_id();
@@ -539,33 +791,30 @@ impl SubtitleProvider for OpensubtitlesProvider {
                 "Subtitle file {:?} already exists, skipping download",
                 path.as_os_str()
             );
+            self.cache.insert(subtitle_info, path);
             return Ok(path
                 .to_str()
                 .expect("expected the subtitle path to be valid")
                 .to_string());
         }
 
-        let url = self.create_download_url().await?;
         debug!(
             "Starting subtitle download of {} ({}) for IMDB ID {:?}",
             subtitle_file.name(),
             file_id,
             subtitle_info.imdb_id()
         );
-        trace!("Requesting subtitle file {}", &url);
-        match self
-            .client
-            .post(url)
-            .json(&DownloadRequest::new(subtitle_file.file_id().clone()))
-            .send()
-            .await
-        {
-            Ok(response) => self.handle_download_response(file_id, path, response).await,
-            Err(err) => Err(SubtitleError::DownloadFailed(
-                file_id.to_string(),
-                err.to_string(),
-            )),
-        }
+        let download_response = self.download_link(file_id).await?;
+        let result = self
+            .execute_download_request(file_id, path, download_response)
+            .await;
+        // the resolved link is no longer needed once the file has been downloaded,
+        // or might have expired when the download failed
+        self.download_links.lock().unwrap().remove(file_id);
+        let file_path = result?;
+
+        self.cache.insert(subtitle_info, &file_path);
+        Ok(file_path)
     }
 
     async fn download_and_parse(
@@ -582,6 +831,64 @@ impl SubtitleProvider for OpensubtitlesProvider {
         }
     }
 
+    async fn preview(
+        &self,
+        subtitle_info: &SubtitleInfo,
+        matcher: &SubtitleMatcher,
+        cue_count: usize,
+    ) -> Result<Vec<SubtitleCue>> {
+        trace!("Starting subtitle preview for {}", subtitle_info);
+        let auto_cleaning_enabled = *self
+            .settings
+            .user_settings()
+            .subtitle()
+            .auto_cleaning_enabled();
+
+        // use the subtitle file which has been downloaded before for the same media
+        if let Some(path) = self.cache.get(subtitle_info, auto_cleaning_enabled) {
+            debug!("Using cached subtitle file {:?} for preview", path);
+            return self
+                .internal_parse(path.as_path(), Some(subtitle_info))
+                .map(|e| e.cues().iter().take(cue_count).cloned().collect());
+        }
+
+        let subtitle_file = subtitle_info.best_matching_file(matcher)?;
+        let file_location = self.storage_file(&subtitle_file).await;
+        let file_id = subtitle_file.file_id();
+        let path = file_location.as_path();
+
+        // use the downloaded file if it's already available
+        if path.exists() {
+            debug!("Using existing subtitle file {:?} for preview", path);
+            return self
+                .internal_parse(path, Some(subtitle_info))
+                .map(|e| e.cues().iter().take(cue_count).cloned().collect());
+        }
+
+        // the download link is reused by repeated previews and the download of the same file,
+        // as each resolved link counts towards the download quota of the user
+        let parser = self.subtitle_parser(path)?;
+        let download_response = self.download_link(file_id).await?;
+        let (data, truncated) = self.fetch_subtitle_head(file_id, download_response).await?;
+        let mut cues = parser.parse_string(&data);
+
+        // the last cue might be incomplete when the file has been truncated
+        if truncated {
+            cues.pop();
+        }
+
+        debug!(
+            "Retrieved {} preview cues for subtitle file {}",
+            cues.len().min(cue_count),
+            file_id
+        );
+        Ok(cues.into_iter().take(cue_count).collect())
+    }
+
+    fn clear_cache(&self) {
+        self.cache.clear()
+    }
+
     fn parse(&self, file_path: &Path) -> Result<Subtitle> {
         self.internal_parse(file_path, None)
     }
@@ -598,6 +905,20 @@ impl SubtitleProvider for OpensubtitlesProvider {
                     "Converting subtitle to raw format of {} for {}",
                     &output_type, subtitle
                 );
+                let subtitle_settings = self.settings.user_settings().subtitle().clone();
+                let subtitle = if subtitle_settings.line_wrapping_enabled() {
+                    trace!(
+                        "Wrapping subtitle lines to a max length of {}",
+                        subtitle_settings.max_line_length
+                    );
+                    subtitle.wrap(
+                        subtitle_settings.max_line_length as usize,
+                        subtitle_settings.max_lines as usize,
+                    )
+                } else {
+                    subtitle
+                };
+
                 match parser.convert(subtitle.cues()) {
                     Err(err) => {
                         error!("Subtitle parsing to raw {} failed, {}", &output_type, err);
@@ -620,11 +941,26 @@ impl SubtitleProvider for OpensubtitlesProvider {
     }
 }
 
+/// The OpenSubtitles credentials of the user which are sent with each API request.
+#[derive(Debug, Default)]
+struct UserCredentials {
+    api_key: Option<String>,
+    user_token: Option<String>,
+}
+
+impl UserCredentials {
+    /// Verify if no user credentials are present, meaning the built-in API key is used.
+    fn is_anonymous(&self) -> bool {
+        self.api_key.is_none() && self.user_token.is_none()
+    }
+}
+
 #[derive(Default)]
 pub struct OpensubtitlesProviderBuilder {
     settings: Option<Arc<ApplicationConfig>>,
     parsers: HashMap<SubtitleType, Box<dyn Parser>>,
-    insecure: bool,
+    client_factory: Option<HttpClientFactory>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl OpensubtitlesProviderBuilder {
@@ -669,19 +1005,39 @@ impl OpensubtitlesProviderBuilder {
         self
     }
 
-    /// Sets whether insecure connections are allowed the API requests.
+    /// Sets the http client factory used for the API requests.
+    /// If not set, a factory with the default network settings will be used.
     ///
     /// # Examples
     ///
     /// ```no_run
+    /// use popcorn_fx_core::core::utils::http::HttpClientFactory;
     /// use popcorn_fx_opensubtitles::opensubtitles::OpensubtitlesProvider;
     ///
     /// let provider = OpensubtitlesProvider::builder()
-    ///     .insecure(true)
+    ///     .client_factory(HttpClientFactory::builder().insecure(true).build())
     ///     .build();
     /// ```
-    pub fn insecure(mut self, insecure: bool) -> Self {
-        self.insecure = insecure;
+    pub fn client_factory(mut self, client_factory: HttpClientFactory) -> Self {
+        self.client_factory = Some(client_factory);
+        self
+    }
+
+    /// Sets the retry policy which is applied to the idempotent API requests.
+    /// If not set, the [RetryPolicy::default] will be used.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use popcorn_fx_opensubtitles::opensubtitles::{OpensubtitlesProvider, RetryPolicy};
+    ///
+    /// let provider = OpensubtitlesProvider::builder()
+    ///     .retry_policy(RetryPolicy::new(5, Duration::from_secs(1)))
+    ///     .build();
+    /// ```
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
         self
     }
 
@@ -724,20 +1080,25 @@ impl OpensubtitlesProviderBuilder {
 
         OpensubtitlesProvider {
             settings,
-            client: ClientBuilder::new()
-                .default_headers(default_headers)
-                .danger_accept_invalid_certs(self.insecure)
-                .build()
-                .unwrap(),
+            client_factory: self.client_factory.unwrap_or_default(),
+            headers: default_headers,
             parsers: self.parsers,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            cache: SubtitleCache::default(),
+            rejected_credentials: Default::default(),
+            download_links: Default::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
     use httpmock::Method::{GET, POST};
     use httpmock::MockServer;
+    use httpmock::prelude::HttpMockRequest;
     use tokio::runtime;
 
     use popcorn_fx_core::core::config::*;
@@ -756,9 +1117,16 @@ mod test {
         subdirectory: Option<&str>,
     ) -> (MockServer, Arc<ApplicationConfig>) {
         let server = MockServer::start();
+        let settings = create_settings(server.url(""), subdirectory);
+
+        (server, settings)
+    }
+
+    fn create_settings(url: String, subdirectory: Option<&str>) -> Arc<ApplicationConfig> {
         let temp_dir = tempfile::tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let settings = Arc::new(
+
+        Arc::new(
             ApplicationConfig::builder()
                 .storage(temp_path)
                 .properties(PopcornProperties {
@@ -766,8 +1134,9 @@ mod test {
                     update_channel: String::new(),
                     providers: Default::default(),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: SubtitleProperties {
-                        url: server.url(""),
+                        url,
                         user_agent: String::new(),
                         api_token: String::new(),
                     },
@@ -786,17 +1155,21 @@ mod test {
                         font_size: 28,
                         decoration: DecorationType::None,
                         bold: false,
+                        max_line_length: 0,
+                        max_lines: 2,
+                        tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+                        api_key: None,
+                        user_token: None,
                     },
                     ui_settings: Default::default(),
                     server_settings: Default::default(),
                     torrent_settings: Default::default(),
                     playback_settings: Default::default(),
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
-        );
-
-        (server, settings)
+        )
     }
 
     #[test]
@@ -876,6 +1249,74 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_movie_subtitles_retry_on_service_unavailable() {
+        init_logger();
+        static UNAVAILABLE_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+        let (server, settings) = start_mock_server();
+        // the mocks are matched in order of creation, so the service is unavailable for the first 2 requests
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subtitles")
+                .matches(|_: &HttpMockRequest| {
+                    UNAVAILABLE_REQUESTS.fetch_add(1, Ordering::SeqCst) < 2
+                });
+            then.status(503);
+        });
+        let search_mock = server.mock(|when, then| {
+            when.method(GET).path("/subtitles");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("search_result_tt1156398.json"));
+        });
+        let movie = MovieDetails::new(
+            "lorem".to_string(),
+            "tt1156398".to_string(),
+            "2021".to_string(),
+        );
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+            .build();
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime
+            .block_on(service.movie_subtitles(&movie))
+            .expect("expected the search to succeed after retrying");
+
+        assert!(
+            result.len() > 0,
+            "expected at least one subtitle to have been found"
+        );
+        unavailable_mock.assert_hits(2);
+        search_mock.assert_hits(1);
+    }
+
+    #[test]
+    fn test_movie_subtitles_not_found_should_not_retry() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/subtitles");
+            then.status(404);
+        });
+        let movie = MovieDetails::new(
+            "lorem".to_string(),
+            "tt1156398".to_string(),
+            "2021".to_string(),
+        );
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+            .build();
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime.block_on(service.movie_subtitles(&movie));
+
+        assert!(result.is_err(), "expected the search to have failed");
+        mock.assert_hits(1);
+    }
+
     #[test]
     fn test_episode_subtitles() {
         init_logger();
@@ -962,6 +1403,76 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_filename_subtitles_user_credentials() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let filename = "House.of.the.Dragon.S01E01.HMAX.WEBRip.x264-XEN0N.mkv".to_string();
+        settings.update_subtitle(SubtitleSettings {
+            api_key: Some("MyApiKey".to_string()),
+            user_token: Some("MyToken".to_string()),
+            ..settings.user_settings().subtitle().clone()
+        });
+        let service = OpensubtitlesProvider::builder().settings(settings).build();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subtitles")
+                .header(API_HEADER_KEY, "MyApiKey")
+                .header("Authorization", "Bearer MyToken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("search_result_episode.json"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime.block_on(service.file_subtitles(&filename));
+
+        assert!(result.is_ok(), "expected the search to succeed");
+        mock.assert();
+    }
+
+    #[test]
+    fn test_filename_subtitles_rejected_user_token() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let filename = "House.of.the.Dragon.S01E01.HMAX.WEBRip.x264-XEN0N.mkv".to_string();
+        settings.update_subtitle(SubtitleSettings {
+            user_token: Some("ExpiredToken".to_string()),
+            ..settings.user_settings().subtitle().clone()
+        });
+        let service = OpensubtitlesProvider::builder().settings(settings).build();
+        let rejected_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subtitles")
+                .header("Authorization", "Bearer ExpiredToken");
+            then.status(401);
+        });
+        let anonymous_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/subtitles")
+                .matches(|req: &HttpMockRequest| {
+                    !req.headers
+                        .iter()
+                        .flatten()
+                        .any(|(key, _)| key.eq_ignore_ascii_case("Authorization"))
+                });
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(read_test_file_to_string("search_result_episode.json"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        runtime
+            .block_on(service.file_subtitles(&filename))
+            .expect("expected the search to succeed");
+        runtime
+            .block_on(service.file_subtitles(&filename))
+            .expect("expected the search to succeed");
+
+        rejected_mock.assert_hits(1);
+        anonymous_mock.assert_hits(2);
+    }
+
     #[test]
     fn test_download_should_return_the_expected_subtitle() {
         init_logger();
@@ -1032,6 +1543,242 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_download_should_reuse_cached_subtitle_file() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .with_parser(SubtitleType::Srt, Box::new(SrtParser::new()))
+            .build();
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("tt2861424")
+            .season(1)
+            .episode(2)
+            .language(SubtitleLanguage::English)
+            .files(vec![SubtitleFile::builder()
+                .file_id(91135)
+                .name("test-subtitle-file.srt")
+                .url("")
+                .score(0.0)
+                .downloads(0)
+                .build()])
+            .build();
+        let rewatch_info = SubtitleInfo::builder()
+            .imdb_id("tt2861424")
+            .season(1)
+            .episode(2)
+            .language(SubtitleLanguage::English)
+            .build();
+        let matcher = SubtitleMatcher::from_int(None, None);
+        let response_body = read_test_file_to_string("download_response.json");
+        let download_mock = server.mock(|when, then| {
+            when.method(POST).path("/download");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    response_body
+                        .replace("[[host]]", server.host().as_str())
+                        .replace("[[port]]", server.port().to_string().as_str()),
+                );
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/download/example.srt");
+            then.status(200)
+                .header("content-type", "text")
+                .body(read_test_file_to_string("subtitle_example.srt"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let expected_result = runtime
+            .block_on(service.download(&subtitle_info, &matcher))
+            .unwrap();
+        let result = runtime
+            .block_on(service.download(&rewatch_info, &matcher))
+            .expect("expected the cached subtitle file to be returned");
+
+        assert_eq!(expected_result, result);
+        download_mock.assert_hits(1);
+
+        service.clear_cache();
+        let result = runtime.block_on(service.download(&rewatch_info, &matcher));
+
+        assert_eq!(Err(SubtitleError::NoFilesFound), result);
+    }
+
+    #[test]
+    fn test_preview() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let temp_dir = settings
+            .user_settings()
+            .subtitle()
+            .directory()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .with_parser(SubtitleType::Srt, Box::new(SrtParser::new()))
+            .build();
+        let filename = "preview-subtitle-file.srt".to_string();
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("tt7405458")
+            .language(SubtitleLanguage::German)
+            .files(vec![SubtitleFile::builder()
+                .file_id(91135)
+                .name(filename.clone())
+                .url("")
+                .score(0.0)
+                .downloads(0)
+                .build()])
+            .build();
+        let matcher = SubtitleMatcher::from_string(Some(String::new()), Some(String::from("720")));
+        let response_body = read_test_file_to_string("download_response.json");
+        server.mock(|when, then| {
+            when.method(POST).path("/download");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    response_body
+                        .replace("[[host]]", server.host().as_str())
+                        .replace("[[port]]", server.port().to_string().as_str()),
+                );
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/download/example.srt");
+            then.status(200)
+                .header("content-type", "text")
+                .body(read_test_file_to_string("subtitle_example.srt"));
+        });
+        let expected_file: PathBuf = [temp_dir, filename].iter().collect();
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime
+            .block_on(service.preview(&subtitle_info, &matcher, 5))
+            .unwrap();
+
+        assert_eq!(1, result.len(), "expected 1 cue to have been returned");
+        assert_eq!("1", result.get(0).unwrap().id());
+        assert!(
+            !expected_file.exists(),
+            "expected the preview to not have been stored"
+        );
+    }
+
+    #[test]
+    fn test_preview_reuses_download_link() {
+        init_logger();
+        let (server, settings) = start_mock_server();
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .with_parser(SubtitleType::Srt, Box::new(SrtParser::new()))
+            .build();
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("tt7405458")
+            .language(SubtitleLanguage::German)
+            .files(vec![SubtitleFile::builder()
+                .file_id(91137)
+                .name("preview-link-subtitle-file.srt".to_string())
+                .url("")
+                .score(0.0)
+                .downloads(0)
+                .build()])
+            .build();
+        let matcher = SubtitleMatcher::from_string(Some(String::new()), Some(String::from("720")));
+        let response_body = read_test_file_to_string("download_response.json");
+        let download_link_mock = server.mock(|when, then| {
+            when.method(POST).path("/download");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    response_body
+                        .replace("[[host]]", server.host().as_str())
+                        .replace("[[port]]", server.port().to_string().as_str()),
+                );
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/download/example.srt");
+            then.status(200)
+                .header("content-type", "text")
+                .body(read_test_file_to_string("subtitle_example.srt"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        runtime
+            .block_on(service.preview(&subtitle_info, &matcher, 5))
+            .unwrap();
+        runtime
+            .block_on(service.preview(&subtitle_info, &matcher, 5))
+            .unwrap();
+        runtime
+            .block_on(service.download(&subtitle_info, &matcher))
+            .unwrap();
+
+        download_link_mock.assert_hits(1);
+    }
+
+    #[test]
+    fn test_preview_retry_on_service_unavailable() {
+        init_logger();
+        static UNAVAILABLE_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+        let (server, settings) = start_mock_server();
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .with_parser(SubtitleType::Srt, Box::new(SrtParser::new()))
+            .retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+            .build();
+        let subtitle_info = SubtitleInfo::builder()
+            .imdb_id("tt7405458")
+            .language(SubtitleLanguage::German)
+            .files(vec![SubtitleFile::builder()
+                .file_id(91136)
+                .name("preview-retry-subtitle-file.srt".to_string())
+                .url("")
+                .score(0.0)
+                .downloads(0)
+                .build()])
+            .build();
+        let matcher = SubtitleMatcher::from_string(Some(String::new()), Some(String::from("720")));
+        let response_body = read_test_file_to_string("download_response.json");
+        server.mock(|when, then| {
+            when.method(POST).path("/download");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    response_body
+                        .replace("[[host]]", server.host().as_str())
+                        .replace("[[port]]", server.port().to_string().as_str()),
+                );
+        });
+        // the mocks are matched in order of creation, so the download is unavailable for the first request
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/download/example.srt")
+                .matches(|_: &HttpMockRequest| {
+                    UNAVAILABLE_REQUESTS.fetch_add(1, Ordering::SeqCst) < 1
+                });
+            then.status(503);
+        });
+        let download_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/download/example.srt")
+                .header("range", format!("bytes=0-{}", PREVIEW_MAX_BYTES - 1));
+            then.status(200)
+                .header("content-type", "text")
+                .body(read_test_file_to_string("subtitle_example.srt"));
+        });
+        let runtime = runtime::Runtime::new().unwrap();
+
+        let result = runtime
+            .block_on(service.preview(&subtitle_info, &matcher, 5))
+            .expect("expected the preview to succeed after retrying");
+
+        assert_eq!(1, result.len(), "expected 1 cue to have been returned");
+        unavailable_mock.assert_hits(1);
+        download_mock.assert_hits(1);
+    }
+
     #[test]
     fn test_download_should_create_subtitle_directory() {
         init_logger();
@@ -1110,11 +1857,17 @@ mod test {
                 font_size: 28,
                 decoration: DecorationType::None,
                 bold: false,
+                max_line_length: 0,
+                max_lines: 2,
+                tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+                api_key: None,
+                user_token: None,
             },
             ui_settings: UiSettings {
                 default_language: "en".to_string(),
                 ui_scale: UiScale::new(1f32).expect("Expected ui scale to be valid"),
-                start_screen: Category::Movies,
+                start_page: StartPage::Movies,
+                poster_quality: PosterQuality::Medium,
                 maximized: false,
                 native_window_enabled: false,
             },
@@ -1122,6 +1875,7 @@ mod test {
             torrent_settings: TorrentSettings::default(),
             playback_settings: Default::default(),
             tracking_settings: Default::default(),
+            network_settings: Default::default(),
         };
         let settings = Arc::new(
             ApplicationConfig::builder()
@@ -1292,6 +2046,49 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_convert_wrap_lines() {
+        let subtitle = Subtitle::new(
+            vec![SubtitleCue::new(
+                "1".to_string(),
+                1000,
+                5000,
+                vec![SubtitleLine::new(vec![StyledText::new(
+                    "Lorem ipsum dolor sit amet, consectetur adipiscing elit".to_string(),
+                    false,
+                    false,
+                    false,
+                )])],
+            )],
+            None,
+            String::new(),
+        );
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
+        let mut subtitle_settings = settings.user_settings().subtitle().clone();
+        subtitle_settings.max_line_length = 30;
+        subtitle_settings.max_lines = 2;
+        settings.update_subtitle(subtitle_settings);
+        let service = OpensubtitlesProvider::builder()
+            .settings(settings)
+            .with_parser(SubtitleType::Srt, Box::new(SrtParser::new()))
+            .build();
+        let expected_result = "1
+00:00:01,000 --> 00:00:05,000
+Lorem ipsum dolor sit amet,
+consectetur adipiscing elit
+
+";
+
+        let result = service.convert(subtitle, SubtitleType::Srt);
+
+        assert_eq!(
+            expected_result,
+            result.expect("Expected the conversion to have succeeded")
+        )
+    }
+
     #[test]
     fn test_invalid_extensions() {
         let filename1 = OpensubtitlesProvider::subtitle_file_name(
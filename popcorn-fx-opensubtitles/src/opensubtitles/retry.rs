@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Response, StatusCode};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// The retry policy which is applied to the idempotent requests of the subtitle provider.
+///
+/// Failed attempts are retried with an exponential backoff, based on the `base_delay`,
+/// to which a random jitter is added to prevent all clients from retrying at the same moment.
+/// The delay between attempts never exceeds the `max_delay`, not even when requested by the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the initial request.
+    pub max_attempts: u32,
+    /// The base delay which is doubled for each consecutive retry.
+    pub base_delay: Duration,
+    /// The maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - The maximum number of attempts, including the initial request.
+    /// * `base_delay` - The base delay between attempts.
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            max_delay: DEFAULT_MAX_DELAY.max(base_delay),
+        }
+    }
+
+    /// Create a retry policy which never retries a failed request.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    /// Verify if a response with the given status code should be retried.
+    ///
+    /// Only server errors and rate limiting responses are considered to be transient,
+    /// any other client error (e.g. `404`) will never succeed on a retry.
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Verify if the given request error should be retried.
+    pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Retrieve the backoff delay for the given attempt, limited to the `max_delay`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The attempt that failed, starting at `1`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self.base_delay.saturating_mul(2u32.pow(exponent));
+        let jitter_max = self.base_delay.as_millis() as u64;
+        let jitter = if jitter_max > 0 {
+            rand::thread_rng().gen_range(0..jitter_max)
+        } else {
+            0
+        };
+
+        delay
+            .saturating_add(Duration::from_millis(jitter))
+            .min(self.max_delay)
+    }
+
+    /// Retrieve the delay before the request may be retried for the given response.
+    /// The `Retry-After` header is honored when the response indicates a rate limit,
+    /// else the backoff delay of the attempt is used.
+    /// The `Retry-After` delay is limited to the `max_delay` of the policy,
+    /// so an invalid or hostile header can't stall the request indefinitely.
+    pub fn delay(&self, attempt: u32, response: &Response) -> Duration {
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            if let Some(delay) = self.retry_after(response.headers()) {
+                return delay;
+            }
+        }
+
+        self.backoff(attempt)
+    }
+
+    fn retry_after(&self, headers: &HeaderMap) -> Option<Duration> {
+        headers
+            .get(RETRY_AFTER)
+            .and_then(|e| e.to_str().ok())
+            .and_then(|e| e.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .map(|e| e.min(self.max_delay))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable_status() {
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::OK));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100));
+
+        let first = policy.backoff(1);
+        let third = policy.backoff(3);
+
+        assert!(first >= Duration::from_millis(100) && first < Duration::from_millis(200));
+        assert!(third >= Duration::from_millis(400) && third < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_new_max_attempts_minimum() {
+        let policy = RetryPolicy::new(0, Duration::from_millis(100));
+
+        assert_eq!(1, policy.max_attempts);
+    }
+
+    #[test]
+    fn test_backoff_max_delay() {
+        let policy = RetryPolicy::new(20, Duration::from_secs(1));
+
+        let result = policy.backoff(20);
+
+        assert_eq!(DEFAULT_MAX_DELAY, result);
+    }
+
+    #[test]
+    fn test_retry_after_max_delay() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "86400".parse().unwrap());
+
+        let result = policy.retry_after(&headers);
+
+        assert_eq!(Some(DEFAULT_MAX_DELAY), result);
+    }
+}
@@ -2,16 +2,21 @@ use std::env::consts::{ARCH, OS};
 use std::fmt;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::thread::available_parallelism;
+use std::time::Duration;
 
 use log::{debug, error, info, trace, warn};
-use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+use souvlaki::{
+    MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig, SeekDirection,
+};
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
 use tokio::sync::{Mutex, MutexGuard};
 
-use popcorn_fx_core::core::{Callbacks, CoreCallbacks};
+use popcorn_fx_core::core::{block_in_place, Callbacks, CoreCallbacks};
 use popcorn_fx_core::core::platform::{
     Platform, PlatformCallback, PlatformData, PlatformEvent, PlatformInfo, PlatformType,
 };
-use popcorn_fx_core::core::playback::{MediaInfo, MediaNotificationEvent};
+use popcorn_fx_core::core::playback::{DownloadInfo, MediaInfo, MediaNotificationEvent};
 
 #[cfg(target_os = "linux")]
 use crate::platform::platform_linux::PlatformLinux;
@@ -33,6 +38,18 @@ pub trait SystemPlatform: Debug + Send + Sync {
     /// It returns `true` if the screensaver was enabled with success, else `false`.
     fn enable_screensaver(&self) -> bool;
 
+    /// Prevent the system from going to sleep on the current platform.
+    /// It returns `true` if the system sleep has been prevented with success, else `false`.
+    fn prevent_sleep(&self) -> bool;
+
+    /// Allow the system to go to sleep again on the current platform.
+    /// It returns `true` if the system sleep has been allowed with success, else `false`.
+    fn allow_sleep(&self) -> bool;
+
+    /// Show a native notification on the current platform.
+    /// It returns `true` if the notification was shown with success, else `false`.
+    fn notify(&self, summary: &str, body: &str) -> bool;
+
     /// Retrieve the handle of the window for the platform.
     fn window_handle(&self) -> Option<*mut std::ffi::c_void>;
 }
@@ -43,6 +60,8 @@ pub struct DefaultPlatform {
     platform: Arc<Box<dyn SystemPlatform>>,
     controls: Mutex<Option<MediaControls>>,
     callbacks: Arc<CoreCallbacks<PlatformEvent>>,
+    /// The number of active inhibitions of the system suspend
+    suspend_inhibitions: Mutex<u32>,
 }
 
 impl DefaultPlatform {
@@ -90,6 +109,7 @@ impl DefaultPlatform {
             title: Some(&info.title),
             artist: info.subtitle.as_ref().map(|e| e.as_str()),
             cover_url: info.thumb.as_ref().map(|e| e.as_ref()),
+            duration: info.duration.map(Duration::from_millis),
             ..Default::default()
         };
 
@@ -110,6 +130,21 @@ impl DefaultPlatform {
         }
     }
 
+    fn on_download_completed(&self, info: &DownloadInfo) {
+        let summary = match info.titles.len() {
+            1 => "Download completed".to_string(),
+            total => format!("{} downloads completed", total),
+        };
+        let body = info.titles.join("\n");
+
+        trace!("Notifying system of completed downloads {:?}", info.titles);
+        if self.platform.notify(&summary, &body) {
+            info!("System has been notified of the completed downloads");
+        } else {
+            warn!("System notification of the completed downloads failed");
+        }
+    }
+
     fn handle_media_event(event: MediaControlEvent, callbacks: &Arc<CoreCallbacks<PlatformEvent>>) {
         debug!("Received system media control event {:?}", event);
         match event {
@@ -118,6 +153,18 @@ impl DefaultPlatform {
             MediaControlEvent::Toggle => callbacks.invoke(PlatformEvent::TogglePlaybackState),
             MediaControlEvent::Next => callbacks.invoke(PlatformEvent::ForwardMedia),
             MediaControlEvent::Previous => callbacks.invoke(PlatformEvent::RewindMedia),
+            // a seek without an offset is translated into a forward or rewind of the playback
+            MediaControlEvent::Seek(direction) => match direction {
+                SeekDirection::Forward => callbacks.invoke(PlatformEvent::ForwardMedia),
+                SeekDirection::Backward => callbacks.invoke(PlatformEvent::RewindMedia),
+            },
+            MediaControlEvent::SeekBy(direction, offset) => {
+                let offset = offset.as_millis() as i64;
+                match direction {
+                    SeekDirection::Forward => callbacks.invoke(PlatformEvent::SeekMedia(offset)),
+                    SeekDirection::Backward => callbacks.invoke(PlatformEvent::SeekMedia(-offset)),
+                }
+            }
             _ => {}
         }
     }
@@ -148,8 +195,63 @@ impl Platform for DefaultPlatform {
         self.platform.enable_screensaver()
     }
 
+    fn prevent_sleep(&self) -> bool {
+        self.platform.prevent_sleep()
+    }
+
+    fn allow_sleep(&self) -> bool {
+        self.platform.allow_sleep()
+    }
+
+    fn inhibit_suspend(&self) -> bool {
+        let mut inhibitions = block_in_place(self.suspend_inhibitions.lock());
+
+        // only the first inhibition needs to prevent the system sleep
+        if *inhibitions == 0 && !self.platform.prevent_sleep() {
+            return false;
+        }
+
+        *inhibitions += 1;
+        trace!("System suspend is being inhibited {} times", *inhibitions);
+        true
+    }
+
+    fn release_suspend(&self) -> bool {
+        let mut inhibitions = block_in_place(self.suspend_inhibitions.lock());
+
+        match *inhibitions {
+            0 => {
+                trace!("System suspend is not being inhibited");
+                true
+            }
+            1 => {
+                if self.platform.allow_sleep() {
+                    *inhibitions = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => {
+                *inhibitions -= 1;
+                debug!(
+                    "System suspend is still being inhibited {} times",
+                    *inhibitions
+                );
+                true
+            }
+        }
+    }
+
     fn notify_media_event(&self, event: MediaNotificationEvent) {
         trace!("Received platform media notification {:?}", event);
+        // download notifications are not related to the media playback,
+        // so they should never create the system media controls
+        if let MediaNotificationEvent::DownloadCompleted(info) = &event {
+            self.on_download_completed(info);
+            return;
+        }
+
         let mut mutex = futures::executor::block_on(self.controls.lock());
 
         // check if the controls already exist
@@ -160,7 +262,8 @@ impl Platform for DefaultPlatform {
 
         if let Some(mut controls) = mutex.as_mut() {
             match &event {
-                MediaNotificationEvent::StateStarting(info) => {
+                MediaNotificationEvent::StateStarting(info)
+                | MediaNotificationEvent::MediaInfoChanged(info) => {
                     self.on_media_info_changed(&mut controls, info.clone())
                 }
                 MediaNotificationEvent::StatePlaying => self.on_playback_state_changed(
@@ -174,6 +277,7 @@ impl Platform for DefaultPlatform {
                 MediaNotificationEvent::StateStopped => {
                     self.on_playback_state_changed(&mut controls, MediaPlayback::Stopped)
                 }
+                MediaNotificationEvent::DownloadCompleted(_) => {}
             }
         } else {
             warn!("Unable to handle the media playback notification, MediaControls not present")
@@ -198,10 +302,17 @@ impl PlatformData for DefaultPlatform {
             _ => PlatformType::Linux,
         };
         let arch = String::from(ARCH);
+        let cpu_cores = available_parallelism().map(|e| e.get() as u32).unwrap_or(1);
+        let system = System::new_with_specifics(
+            RefreshKind::new().with_memory(MemoryRefreshKind::new().with_ram()),
+        );
 
         PlatformInfo {
             platform_type,
             arch,
+            cpu_cores,
+            total_memory: system.total_memory(),
+            available_memory: system.available_memory(),
         }
     }
 }
@@ -219,6 +330,7 @@ impl Default for DefaultPlatform {
             platform: Arc::new(platform),
             controls: Default::default(),
             callbacks: Arc::new(Default::default()),
+            suspend_inhibitions: Default::default(),
         }
     }
 }
@@ -234,6 +346,8 @@ impl Debug for DefaultPlatform {
 impl Drop for DefaultPlatform {
     fn drop(&mut self) {
         self.enable_screensaver();
+        // release the system sleep regardless of the remaining suspend inhibitions
+        self.platform.allow_sleep();
     }
 }
 
@@ -257,6 +371,12 @@ mod test {
 
             fn enable_screensaver(&self) -> bool;
 
+            fn prevent_sleep(&self) -> bool;
+
+            fn allow_sleep(&self) -> bool;
+
+            fn notify(&self, summary: &str, body: &str) -> bool;
+
             fn window_handle(&self) -> Option<*mut std::ffi::c_void>;
         }
     }
@@ -267,10 +387,12 @@ mod test {
         let mut sys_platform = MockDummySystemPlatform::new();
         sys_platform.expect_disable_screensaver().returning(|| true);
         sys_platform.expect_enable_screensaver().returning(|| false);
+        sys_platform.expect_allow_sleep().returning(|| true);
         let platform = DefaultPlatform {
             platform: Arc::new(Box::new(sys_platform)),
             controls: Default::default(),
             callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
         };
 
         assert!(
@@ -284,10 +406,12 @@ mod test {
         init_logger();
         let mut sys_platform = MockDummySystemPlatform::new();
         sys_platform.expect_enable_screensaver().returning(|| true);
+        sys_platform.expect_allow_sleep().returning(|| true);
         let platform = DefaultPlatform {
             platform: Arc::new(Box::new(sys_platform)),
             controls: Default::default(),
             callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
         };
 
         assert!(
@@ -296,6 +420,114 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_prevent_sleep() {
+        init_logger();
+        let mut sys_platform = MockDummySystemPlatform::new();
+        sys_platform
+            .expect_prevent_sleep()
+            .times(1)
+            .returning(|| true);
+        sys_platform.expect_enable_screensaver().returning(|| true);
+        sys_platform.expect_allow_sleep().returning(|| true);
+        let platform = DefaultPlatform {
+            platform: Arc::new(Box::new(sys_platform)),
+            controls: Default::default(),
+            callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
+        };
+
+        assert!(
+            platform.prevent_sleep(),
+            "expected the system sleep to be prevented"
+        )
+    }
+
+    #[test]
+    fn test_inhibit_suspend_reference_counted() {
+        init_logger();
+        let (tx, rx) = channel();
+        let tx_prevent = tx.clone();
+        let mut sys_platform = MockDummySystemPlatform::new();
+        sys_platform
+            .expect_prevent_sleep()
+            .times(1)
+            .returning(move || {
+                tx_prevent.send("prevent").unwrap();
+                true
+            });
+        sys_platform.expect_enable_screensaver().returning(|| true);
+        sys_platform.expect_allow_sleep().returning(move || {
+            tx.send("allow").unwrap();
+            true
+        });
+        let platform = DefaultPlatform {
+            platform: Arc::new(Box::new(sys_platform)),
+            controls: Default::default(),
+            callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
+        };
+
+        assert!(
+            platform.inhibit_suspend(),
+            "expected the suspend to be inhibited"
+        );
+        assert!(
+            platform.inhibit_suspend(),
+            "expected the suspend to be inhibited"
+        );
+        assert!(
+            platform.release_suspend(),
+            "expected the inhibition to be released"
+        );
+        assert_eq!(
+            vec!["prevent"],
+            rx.try_iter().collect::<Vec<&str>>(),
+            "expected the system suspend to still be inhibited"
+        );
+
+        assert!(
+            platform.release_suspend(),
+            "expected the inhibition to be released"
+        );
+        assert_eq!(vec!["allow"], rx.try_iter().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn test_notify_media_event_download_completed() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut sys_platform = MockDummySystemPlatform::new();
+        sys_platform
+            .expect_notify()
+            .times(1)
+            .returning(move |summary, body| {
+                tx.send((summary.to_string(), body.to_string())).unwrap();
+                true
+            });
+        sys_platform.expect_window_handle().times(0);
+        sys_platform.expect_enable_screensaver().returning(|| true);
+        sys_platform.expect_allow_sleep().returning(|| true);
+        let platform = DefaultPlatform {
+            platform: Arc::new(Box::new(sys_platform)),
+            controls: Default::default(),
+            callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
+        };
+
+        platform.notify_media_event(MediaNotificationEvent::DownloadCompleted(DownloadInfo {
+            titles: vec!["Lorem".to_string(), "Ipsum".to_string()],
+        }));
+
+        let (summary, body) = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!("2 downloads completed", summary);
+        assert_eq!("Lorem\nIpsum", body);
+        assert!(
+            futures::executor::block_on(platform.controls.lock()).is_none(),
+            "expected no media controls to have been created"
+        );
+    }
+
     #[test]
     fn test_drop_default_platform() {
         init_logger();
@@ -304,10 +536,15 @@ mod test {
             .expect_enable_screensaver()
             .returning(|| true)
             .times(1);
+        sys_platform
+            .expect_allow_sleep()
+            .returning(|| true)
+            .times(1);
         let platform = DefaultPlatform {
             platform: Arc::new(Box::new(sys_platform)),
             controls: Default::default(),
             callbacks: Default::default(),
+            suspend_inhibitions: Default::default(),
         };
 
         drop(platform);
@@ -333,6 +570,11 @@ mod test {
 
         assert_eq!(platform_type, result.platform_type);
         assert_eq!(arch.to_string(), result.arch);
+        assert!(result.cpu_cores > 0, "expected at least one cpu core");
+        assert!(
+            result.available_memory <= result.total_memory,
+            "expected the available memory to not exceed the total memory"
+        );
     }
 
     #[test]
@@ -346,6 +588,7 @@ mod test {
             title: "Lorem".to_string(),
             subtitle: None,
             thumb: None,
+            duration: None,
         }));
         // verify that the other events don't crash the program
         // when no controls are present
@@ -390,4 +633,80 @@ mod test {
         let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
         assert_eq!(PlatformEvent::ForwardMedia, result);
     }
+
+    #[test]
+    fn test_handle_media_seek_event() {
+        let (tx, rx) = channel();
+        let callbacks = Arc::new(CoreCallbacks::default());
+
+        callbacks.add(Box::new(move |event| tx.send(event).unwrap()));
+        DefaultPlatform::handle_media_event(
+            MediaControlEvent::Seek(SeekDirection::Forward),
+            &callbacks.clone(),
+        );
+        DefaultPlatform::handle_media_event(
+            MediaControlEvent::SeekBy(SeekDirection::Backward, Duration::from_secs(10)),
+            &callbacks.clone(),
+        );
+
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(PlatformEvent::ForwardMedia, result);
+        let result = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(PlatformEvent::SeekMedia(-10000), result);
+    }
+
+    /// Verify the MPRIS interface which is exposed over the D-Bus session bus.
+    /// This test requires an active session bus and is only executed with the `dbus-tests` feature.
+    #[cfg(all(target_os = "linux", feature = "dbus-tests"))]
+    #[test]
+    fn test_mpris_player_commands() {
+        use dbus::blocking::Connection;
+
+        init_logger();
+        let bus_name = format!("org.mpris.MediaPlayer2.{}", DBUS_NAME);
+        let timeout = Duration::from_secs(2);
+        let (tx, rx) = channel();
+        let platform = DefaultPlatform::default();
+        platform.register(Box::new(move |event| tx.send(event).unwrap()));
+        let conn = Connection::new_session().expect("expected a session bus connection");
+
+        platform.notify_media_event(MediaNotificationEvent::StateStarting(MediaInfo {
+            title: "Lorem".to_string(),
+            subtitle: None,
+            thumb: Some("http://localhost/ipsum.jpg".to_string()),
+            duration: Some(120000),
+        }));
+        platform.notify_media_event(MediaNotificationEvent::StatePlaying);
+
+        let proxy = conn.with_proxy(bus_name.as_str(), "/org/mpris/MediaPlayer2", timeout);
+        let _: () = proxy
+            .method_call("org.mpris.MediaPlayer2.Player", "PlayPause", ())
+            .expect("expected the PlayPause command to succeed");
+        let result = rx.recv_timeout(timeout).unwrap();
+        assert_eq!(PlatformEvent::TogglePlaybackState, result);
+
+        let _: () = proxy
+            .method_call("org.mpris.MediaPlayer2.Player", "Next", ())
+            .expect("expected the Next command to succeed");
+        let result = rx.recv_timeout(timeout).unwrap();
+        assert_eq!(PlatformEvent::ForwardMedia, result);
+
+        let _: () = proxy
+            .method_call("org.mpris.MediaPlayer2.Player", "Seek", (-10_000_000i64,))
+            .expect("expected the Seek command to succeed");
+        let result = rx.recv_timeout(timeout).unwrap();
+        assert_eq!(PlatformEvent::SeekMedia(-10000), result);
+
+        // the MPRIS interface should be unregistered when the playback stops
+        platform.notify_media_event(MediaNotificationEvent::StateStopped);
+        let (names,): (Vec<String>,) = conn
+            .with_proxy("org.freedesktop.DBus", "/org/freedesktop/DBus", timeout)
+            .method_call("org.freedesktop.DBus", "ListNames", ())
+            .expect("expected the bus names");
+        assert!(
+            !names.contains(&bus_name),
+            "expected {} to have been released",
+            bus_name
+        );
+    }
 }
@@ -1,3 +1,6 @@
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
 use log::{debug, error, info, trace, warn};
 
 use popcorn_fx_core::core::platform;
@@ -9,11 +12,18 @@ use x11rb::rust_connection::{ConnectionError, RustConnection};
 
 use crate::platform::SystemPlatform;
 
+const SYSTEMD_INHIBIT_COMMAND: &str = "systemd-inhibit";
+const NOTIFY_SEND_COMMAND: &str = "notify-send";
+
 /// The linux platform specific implementation
 #[derive(Debug)]
 pub struct PlatformLinux {
     /// The X11 server connection
     conn: Option<RustConnection>,
+    /// The systemd inhibitor process which prevents the system from going to sleep
+    sleep_inhibitor: Mutex<Option<Child>>,
+    /// The function which spawns the process of the sleep inhibitor command
+    spawn_sleep_inhibitor: fn(&mut Command) -> std::io::Result<Child>,
 }
 
 impl PlatformLinux {
@@ -60,6 +70,26 @@ impl PlatformLinux {
     }
 }
 
+impl PlatformLinux {
+    /// Create the systemd inhibitor command which blocks the system from going to sleep.
+    /// The inhibitor is held for as long as the stdin of the inhibited process remains open.
+    fn sleep_inhibitor_command() -> Command {
+        let mut command = Command::new(SYSTEMD_INHIBIT_COMMAND);
+        command
+            .args([
+                "--what=sleep:idle",
+                "--who=Popcorn Time",
+                "--why=Media playback is active",
+                "--mode=block",
+                "cat",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        command
+    }
+}
+
 impl SystemPlatform for PlatformLinux {
     fn disable_screensaver(&self) -> bool {
         if self.conn.is_none() {
@@ -99,6 +129,73 @@ impl SystemPlatform for PlatformLinux {
         }
     }
 
+    fn prevent_sleep(&self) -> bool {
+        let mut mutex = self.sleep_inhibitor.lock().unwrap();
+        if mutex.is_some() {
+            trace!("System sleep is already being prevented");
+            return true;
+        }
+
+        trace!("Starting systemd sleep inhibitor");
+        match (self.spawn_sleep_inhibitor)(&mut Self::sleep_inhibitor_command()) {
+            Ok(child) => {
+                info!("System sleep has been prevented");
+                *mutex = Some(child);
+                true
+            }
+            Err(e) => {
+                warn!("Unable to prevent system sleep, {}", e);
+                false
+            }
+        }
+    }
+
+    fn allow_sleep(&self) -> bool {
+        let mut mutex = self.sleep_inhibitor.lock().unwrap();
+
+        if let Some(mut child) = mutex.take() {
+            // closing the stdin will terminate the inhibited process, which releases the inhibitor
+            drop(child.stdin.take());
+            match child.wait() {
+                Ok(_) => {
+                    info!("System sleep has been allowed");
+                    true
+                }
+                Err(e) => {
+                    error!("Failed to release systemd sleep inhibitor, {}", e);
+                    let _ = child.kill();
+                    false
+                }
+            }
+        } else {
+            trace!("System sleep not prevented, not trying to release the sleep inhibitor");
+            true
+        }
+    }
+
+    fn notify(&self, summary: &str, body: &str) -> bool {
+        trace!("Sending desktop notification \"{}\"", summary);
+        match Command::new(NOTIFY_SEND_COMMAND)
+            .args(["--app-name=Popcorn Time", summary, body])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+        {
+            Ok(status) if status.success() => {
+                debug!("Desktop notification has been sent");
+                true
+            }
+            Ok(status) => {
+                warn!("Failed to send desktop notification, {}", status);
+                false
+            }
+            Err(e) => {
+                warn!("Unable to send desktop notification, {}", e);
+                false
+            }
+        }
+    }
+
     fn window_handle(&self) -> Option<*mut std::ffi::c_void> {
         None
     }
@@ -117,17 +214,27 @@ impl Default for PlatformLinux {
             })
             .unwrap();
 
-        Self { conn }
+        Self {
+            conn,
+            sleep_inhibitor: Default::default(),
+            spawn_sleep_inhibitor: Command::spawn,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use popcorn_fx_core::testing::init_logger;
 
     use crate::platform::platform_linux::PlatformLinux;
     use crate::platform::SystemPlatform;
 
+    use super::*;
+
+    static SPAWNED_INHIBITORS: AtomicUsize = AtomicUsize::new(0);
+
     /* NOTE: Github actions is unable to activate the DPMS and XScreenSaver within xvfb */
     /* thereby actually verifying the results of the actions is useless as they will always fail within the CI */
 
@@ -146,6 +253,62 @@ mod test {
         let _ = platform.enable_screensaver();
     }
 
+    #[test]
+    fn test_sleep_inhibitor_command() {
+        let command = PlatformLinux::sleep_inhibitor_command();
+
+        assert_eq!(SYSTEMD_INHIBIT_COMMAND, command.get_program());
+        assert_eq!(
+            vec![
+                "--what=sleep:idle",
+                "--who=Popcorn Time",
+                "--why=Media playback is active",
+                "--mode=block",
+                "cat",
+            ],
+            command.get_args().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_prevent_sleep() {
+        init_logger();
+        let platform = PlatformLinux {
+            conn: None,
+            sleep_inhibitor: Default::default(),
+            spawn_sleep_inhibitor: spawn_fake_inhibitor,
+        };
+
+        assert!(
+            platform.prevent_sleep(),
+            "expected the system sleep to have been prevented"
+        );
+        assert!(
+            platform.prevent_sleep(),
+            "expected the system sleep to still be prevented"
+        );
+        assert_eq!(1, SPAWNED_INHIBITORS.load(Ordering::SeqCst));
+
+        assert!(
+            platform.allow_sleep(),
+            "expected the system sleep to have been allowed"
+        );
+        assert!(
+            platform.sleep_inhibitor.lock().unwrap().is_none(),
+            "expected the sleep inhibitor to have been released"
+        );
+    }
+
+    /// Spawn a plain `cat` process in place of the systemd inhibitor, which also terminates when its stdin is closed.
+    fn spawn_fake_inhibitor(command: &mut Command) -> std::io::Result<Child> {
+        assert_eq!(SYSTEMD_INHIBIT_COMMAND, command.get_program());
+        SPAWNED_INHIBITORS.fetch_add(1, Ordering::SeqCst);
+        Command::new("cat")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+    }
+
     #[test]
     fn test_window_handle() {
         let platform = PlatformLinux::default();
@@ -1,13 +1,17 @@
 use std::ffi::c_int;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
 
 use core_foundation::base::TCFType;
 use core_foundation::string::{CFString, CFStringRef};
-use log::{debug, warn};
+use log::{debug, info, trace, warn};
 
 use crate::platform::SystemPlatform;
 
 const KIOPMASSERTIONLEVEL_ON: u32 = 255;
 const KIOPMASSERTIONLEVEL_OFF: u32 = 0;
+const KIOPMASSERTION_PREVENT_IDLE_SLEEP: &str = "PreventUserIdleSystemSleep";
+const OSASCRIPT_COMMAND: &str = "osascript";
 
 #[link(name = "IOKit", kind = "framework")]
 extern "C" {
@@ -18,14 +22,24 @@ extern "C" {
         AssertionName: CFStringRef,
         AssertionID: *mut u32,
     ) -> c_int;
+
+    #[allow(non_snake_case)]
+    fn IOPMAssertionRelease(AssertionID: u32) -> c_int;
 }
 
-#[derive(Debug, Default)]
-pub struct PlatformMac {}
+#[derive(Debug)]
+pub struct PlatformMac {
+    /// The IOPMAssertion which prevents the system from going to sleep
+    sleep_assertion: Mutex<Option<u32>>,
+    /// The function which creates the IOPMAssertion that prevents the system from going to sleep
+    create_sleep_assertion: fn() -> Result<u32, c_int>,
+    /// The function which releases the IOPMAssertion with the given id
+    release_sleep_assertion: fn(u32) -> c_int,
+}
 
 impl PlatformMac {
     fn call_io_assertion(&self, assertion_level: u32) -> bool {
-        let prevent_sleep = CFString::new("PreventUserIdleSystemSleep");
+        let prevent_sleep = CFString::new(KIOPMASSERTION_PREVENT_IDLE_SLEEP);
         let reason = CFString::new("Media playback application is active");
         #[allow(unused_mut)]
             let mut id = Box::new(u32::MIN);
@@ -53,6 +67,42 @@ impl PlatformMac {
     }
 }
 
+impl PlatformMac {
+    /// Create the IOPMAssertion which prevents the system from going to idle sleep.
+    ///
+    /// It returns the id of the created assertion, or the IOKit return code on failure.
+    fn create_prevent_sleep_assertion() -> Result<u32, c_int> {
+        let assertion_type = CFString::new(KIOPMASSERTION_PREVENT_IDLE_SLEEP);
+        let reason = CFString::new("Media playback is active");
+        let mut id = u32::MIN;
+
+        let result = unsafe {
+            IOPMAssertionCreateWithName(
+                assertion_type.as_concrete_TypeRef(),
+                KIOPMASSERTIONLEVEL_ON,
+                reason.as_concrete_TypeRef(),
+                &mut id,
+            )
+        };
+
+        if result == 0 {
+            Ok(id)
+        } else {
+            Err(result)
+        }
+    }
+
+    /// Release the IOPMAssertion with the given id.
+    fn release_assertion(id: u32) -> c_int {
+        unsafe { IOPMAssertionRelease(id) }
+    }
+
+    /// Convert the given value into a quoted AppleScript string literal.
+    fn apple_script_string(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
 impl SystemPlatform for PlatformMac {
     fn disable_screensaver(&self) -> bool {
         let result = self.call_io_assertion(KIOPMASSERTIONLEVEL_ON);
@@ -66,18 +116,102 @@ impl SystemPlatform for PlatformMac {
         result
     }
 
+    fn prevent_sleep(&self) -> bool {
+        let mut mutex = self.sleep_assertion.lock().unwrap();
+        if mutex.is_some() {
+            trace!("System sleep is already being prevented");
+            return true;
+        }
+
+        match (self.create_sleep_assertion)() {
+            Ok(id) => {
+                info!("System sleep has been prevented");
+                *mutex = Some(id);
+                true
+            }
+            Err(result) => {
+                warn!(
+                    "Failed to prevent system sleep, IOPMAssertion returned {}",
+                    result
+                );
+                false
+            }
+        }
+    }
+
+    fn allow_sleep(&self) -> bool {
+        let mut mutex = self.sleep_assertion.lock().unwrap();
+
+        if let Some(id) = mutex.take() {
+            let result = (self.release_sleep_assertion)(id);
+            if result == 0 {
+                info!("System sleep has been allowed");
+                true
+            } else {
+                warn!("Failed to release IOPMAssertion {}, {}", id, result);
+                false
+            }
+        } else {
+            trace!("System sleep not prevented, not trying to release IOPMAssertion");
+            true
+        }
+    }
+
+    fn notify(&self, summary: &str, body: &str) -> bool {
+        let script = format!(
+            "display notification {} with title {}",
+            Self::apple_script_string(body),
+            Self::apple_script_string(summary)
+        );
+
+        trace!("Sending user notification \"{}\"", summary);
+        match Command::new(OSASCRIPT_COMMAND)
+            .args(["-e", script.as_str()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+        {
+            Ok(status) if status.success() => {
+                debug!("User notification has been sent");
+                true
+            }
+            Ok(status) => {
+                warn!("Failed to send user notification, {}", status);
+                false
+            }
+            Err(e) => {
+                warn!("Unable to send user notification, {}", e);
+                false
+            }
+        }
+    }
+
     fn window_handle(&self) -> Option<*mut std::ffi::c_void> {
         None
     }
 }
 
+impl Default for PlatformMac {
+    fn default() -> Self {
+        Self {
+            sleep_assertion: Default::default(),
+            create_sleep_assertion: Self::create_prevent_sleep_assertion,
+            release_sleep_assertion: Self::release_assertion,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
     use popcorn_fx_core::testing::init_logger;
 
     use crate::platform::SystemPlatform;
 
-    use super::PlatformMac;
+    use super::*;
+
+    static RELEASED_ASSERTION: AtomicU32 = AtomicU32::new(0);
 
     #[test]
     fn disable_screensaver_macos_should_return_true() {
@@ -100,6 +234,38 @@ mod test {
         assert_eq!(true, platform.enable_screensaver());
     }
 
+    #[test]
+    fn test_prevent_sleep() {
+        init_logger();
+        let platform = PlatformMac {
+            sleep_assertion: Default::default(),
+            create_sleep_assertion: || Ok(12),
+            release_sleep_assertion: |id| {
+                RELEASED_ASSERTION.store(id, Ordering::SeqCst);
+                0
+            },
+        };
+
+        assert_eq!(true, platform.prevent_sleep());
+        assert_eq!(Some(12), *platform.sleep_assertion.lock().unwrap());
+        assert_eq!(true, platform.allow_sleep());
+        assert_eq!(12, RELEASED_ASSERTION.load(Ordering::SeqCst));
+        assert_eq!(None, *platform.sleep_assertion.lock().unwrap());
+    }
+
+    #[test]
+    fn test_prevent_sleep_failed() {
+        init_logger();
+        let platform = PlatformMac {
+            sleep_assertion: Default::default(),
+            create_sleep_assertion: || Err(-1),
+            release_sleep_assertion: |_| 0,
+        };
+
+        assert_eq!(false, platform.prevent_sleep());
+        assert_eq!(None, *platform.sleep_assertion.lock().unwrap());
+    }
+
     #[test]
     fn test_window_handle() {
         init_logger();
@@ -107,4 +273,11 @@ mod test {
 
         assert_eq!(None, platform.window_handle())
     }
+
+    #[test]
+    fn test_apple_script_string() {
+        let result = PlatformMac::apple_script_string("Lorem \"ipsum\" \\ dolor");
+
+        assert_eq!("\"Lorem \\\"ipsum\\\" \\\\ dolor\"", result);
+    }
 }
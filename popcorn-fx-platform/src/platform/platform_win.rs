@@ -1,11 +1,12 @@
-use log::{info, trace, warn};
+use log::{debug, info, trace, warn};
 use tokio::sync::Mutex;
 
 use windows::core::{PCWSTR, PWSTR};
 use windows::core::Result;
 use windows::Win32::Foundation::HANDLE;
 use windows::Win32::System::Power::{
-    PowerClearRequest, PowerCreateRequest, PowerRequestDisplayRequired, PowerSetRequest,
+    PowerClearRequest, PowerCreateRequest, PowerRequestDisplayRequired, PowerRequestSystemRequired,
+    PowerSetRequest, POWER_REQUEST_TYPE,
 };
 use windows::Win32::System::Threading::{
     POWER_REQUEST_CONTEXT_SIMPLE_STRING, REASON_CONTEXT, REASON_CONTEXT_0,
@@ -20,10 +21,17 @@ const WINDOW_NAME: &str = "Popcorn Time";
 pub struct PlatformWin {
     /// The power request which has been made to the windows system
     screensaver_request: Mutex<Option<HANDLE>>,
+    /// The power request which keeps the windows system awake
+    sleep_request: Mutex<Option<HANDLE>>,
 }
 
-impl SystemPlatform for PlatformWin {
-    fn disable_screensaver(&self) -> bool {
+impl PlatformWin {
+    /// Create and set a new power request of the given type on the windows system.
+    /// The handle of the request is stored in the given mutex, so it can be cleared later on.
+    fn set_power_request(
+        request: &Mutex<Option<HANDLE>>,
+        request_type: POWER_REQUEST_TYPE,
+    ) -> bool {
         let mut encoded = "Popcorn FX playing media"
             .encode_utf16()
             .chain([0u16])
@@ -38,50 +46,101 @@ impl SystemPlatform for PlatformWin {
         };
 
         unsafe {
-            trace!("Creating new windows power request");
-            let request: Result<HANDLE> = PowerCreateRequest(&context);
+            trace!("Creating new windows power request {:?}", request_type);
+            let result: Result<HANDLE> = PowerCreateRequest(&context);
 
-            return match request {
+            return match result {
                 Err(ex) => {
-                    warn!("Failed to disable windows screensaver, {}", ex);
+                    warn!("Failed to create windows power request, {}", ex);
                     false
                 }
                 Ok(handle) => {
-                    trace!("Storing windows screensaver handle");
-                    let mut mutex = self.screensaver_request.blocking_lock();
+                    trace!("Storing windows power request handle");
+                    let mut mutex = request.blocking_lock();
                     *mutex = Some(handle);
 
-                    if PowerSetRequest(handle, PowerRequestDisplayRequired).as_bool() {
-                        info!("Screensaver has been disabled");
-                        true
-                    } else {
-                        false
-                    }
+                    PowerSetRequest(handle, request_type).as_bool()
                 }
             };
         }
     }
 
-    fn enable_screensaver(&self) -> bool {
-        // verify if a request was made before to disable it
+    /// Clear the power request of the given type which has been stored in the given mutex.
+    /// It returns `true` when no request was made before.
+    fn clear_power_request(
+        request: &Mutex<Option<HANDLE>>,
+        request_type: POWER_REQUEST_TYPE,
+    ) -> bool {
+        // verify if a request was made before
         // otherwise, ignore this call
-        let mut mutex = self.screensaver_request.blocking_lock();
+        let mut mutex = request.blocking_lock();
 
         if let Some(handle) = *mutex {
-            if unsafe { PowerClearRequest(handle, PowerRequestDisplayRequired).as_bool() } {
-                info!("Screensaver has been enabled");
+            if unsafe { PowerClearRequest(handle, request_type).as_bool() } {
                 *mutex = None;
                 true
             } else {
-                warn!("Failed to enabled windows screensaver");
                 false
             }
         } else {
-            trace!("Windows screensaver not disabled, not trying to clear power request");
+            trace!(
+                "Windows power request {:?} not set, not trying to clear power request",
+                request_type
+            );
+            true
+        }
+    }
+}
+
+impl SystemPlatform for PlatformWin {
+    fn disable_screensaver(&self) -> bool {
+        if Self::set_power_request(&self.screensaver_request, PowerRequestDisplayRequired) {
+            info!("Screensaver has been disabled");
+            true
+        } else {
+            warn!("Failed to disable windows screensaver");
+            false
+        }
+    }
+
+    fn enable_screensaver(&self) -> bool {
+        if Self::clear_power_request(&self.screensaver_request, PowerRequestDisplayRequired) {
+            info!("Screensaver has been enabled");
+            true
+        } else {
+            warn!("Failed to enabled windows screensaver");
+            false
+        }
+    }
+
+    fn prevent_sleep(&self) -> bool {
+        if Self::set_power_request(&self.sleep_request, PowerRequestSystemRequired) {
+            info!("System sleep has been prevented");
+            true
+        } else {
+            warn!("Failed to prevent windows system sleep");
+            false
+        }
+    }
+
+    fn allow_sleep(&self) -> bool {
+        if Self::clear_power_request(&self.sleep_request, PowerRequestSystemRequired) {
+            info!("System sleep has been allowed");
             true
+        } else {
+            warn!("Failed to allow windows system sleep");
+            false
         }
     }
 
+    fn notify(&self, summary: &str, _: &str) -> bool {
+        debug!(
+            "Unable to show notification \"{}\", native notifications are not supported on windows",
+            summary
+        );
+        false
+    }
+
     fn window_handle(&self) -> Option<*mut std::ffi::c_void> {
         let mut encoded_name = WINDOW_NAME
             .encode_utf16()
@@ -113,6 +172,7 @@ impl Default for PlatformWin {
     fn default() -> Self {
         Self {
             screensaver_request: Mutex::new(None),
+            sleep_request: Mutex::new(None),
         }
     }
 }
@@ -150,6 +210,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_windows_prevent_sleep() {
+        let platform = PlatformWin::default();
+
+        assert_eq!(
+            platform.prevent_sleep(),
+            true,
+            "Expected the system sleep to have been prevented"
+        );
+        assert_eq!(
+            platform.allow_sleep(),
+            true,
+            "Expected the system sleep to have been allowed"
+        );
+    }
+
     #[test]
     fn test_window_handle() {
         init_logger();
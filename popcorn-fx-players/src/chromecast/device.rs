@@ -82,6 +82,9 @@ pub trait FxCastDevice: Debug + Send + Sync {
     /// Retrieves the status of the cast device.
     fn device_status(&self) -> chromecast::Result<receiver::Status>;
 
+    /// Updates the volume level and/or mute state of the cast device.
+    fn set_volume(&self, volume: receiver::Volume) -> chromecast::Result<receiver::Volume>;
+
     /// Receives messages from the Chromecast device.
     fn receive(&self) -> chromecast::Result<ChannelMessage>;
 }
@@ -212,6 +215,13 @@ impl FxCastDevice for DefaultCastDevice {
             .map_err(|e| ChromecastError::Connection(e.to_string()))
     }
 
+    fn set_volume(&self, volume: receiver::Volume) -> chromecast::Result<receiver::Volume> {
+        self.0
+            .receiver
+            .set_volume(volume)
+            .map_err(|e| ChromecastError::Connection(e.to_string()))
+    }
+
     fn receive(&self) -> chromecast::Result<ChannelMessage> {
         self.0
             .receive()
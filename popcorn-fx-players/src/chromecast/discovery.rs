@@ -1,16 +1,24 @@
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use derive_more::Display;
 use itertools::Itertools;
 use log::{debug, info, trace, warn};
 use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use tokio::net::TcpStream;
 use tokio::runtime::Runtime;
 use tokio::sync::Mutex;
-
-use popcorn_fx_core::core::block_in_place;
-use popcorn_fx_core::core::players::PlayerManager;
+use tokio::time;
+
+use popcorn_fx_core::core::{
+    block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
+};
+use popcorn_fx_core::core::config::ApplicationConfig;
+use popcorn_fx_core::core::players::{
+    KnownDevice, KnownDeviceType, KnownDevicesCache, PlayerManager,
+};
 use popcorn_fx_core::core::subtitles::SubtitleServer;
 
 use crate::chromecast::device::DefaultCastDevice;
@@ -18,10 +26,11 @@ use crate::chromecast::player::ChromecastPlayer;
 #[cfg(feature = "transcoder")]
 use crate::chromecast::transcode::VlcTranscoderDiscovery;
 use crate::chromecast::transcode::{NoOpTranscoder, Transcoder};
-use crate::{chromecast, Discovery, DiscoveryError, DiscoveryState};
+use crate::{chromecast, Discovery, DiscoveryError, DiscoveryEvent, DiscoveryState};
 
 pub(crate) const SERVICE_TYPE: &str = "_googlecast._tcp.local.";
 const INFO_UNKNOWN: &str = "Unknown";
+const VERIFICATION_TIMEOUT_SECONDS: u64 = 3;
 
 #[derive(Debug, Display)]
 #[display(fmt = "Chromecast device discovery")]
@@ -38,6 +47,8 @@ impl ChromecastDiscovery {
         service_daemon: ServiceDaemon,
         player_manager: Arc<Box<dyn PlayerManager>>,
         subtitle_server: Arc<SubtitleServer>,
+        settings: Option<Arc<ApplicationConfig>>,
+        known_devices: Option<Arc<KnownDevicesCache>>,
         runtime: Arc<Runtime>,
     ) -> Self {
         let transcoder = Arc::new(Self::resolve_transcoder());
@@ -48,8 +59,11 @@ impl ChromecastDiscovery {
                 service_daemon,
                 transcoder,
                 subtitle_server,
+                settings,
+                known_devices,
                 discovered_devices: Default::default(),
                 state: Mutex::new(DiscoveryState::Stopped),
+                callbacks: Default::default(),
                 runtime,
             }),
         }
@@ -74,6 +88,16 @@ impl ChromecastDiscovery {
     }
 }
 
+impl Callbacks<DiscoveryEvent> for ChromecastDiscovery {
+    fn add(&self, callback: CoreCallback<DiscoveryEvent>) -> CallbackHandle {
+        self.inner.callbacks.add(callback)
+    }
+
+    fn remove(&self, handle: CallbackHandle) {
+        self.inner.callbacks.remove(handle)
+    }
+}
+
 #[async_trait]
 impl Discovery for ChromecastDiscovery {
     fn state(&self) -> DiscoveryState {
@@ -99,6 +123,10 @@ impl Discovery for ChromecastDiscovery {
 
             self.inner.update_state_async(DiscoveryState::Running).await;
             let inner = self.inner.clone();
+            self.inner.runtime.spawn(async move {
+                inner.register_known_devices().await;
+            });
+            let inner = self.inner.clone();
             self.inner.runtime.spawn(async move {
                 trace!("Starting the Chromecast MDNS discovery service receiver");
                 while let Ok(event) = receiver.recv() {
@@ -151,6 +179,8 @@ impl Drop for ChromecastDiscovery {
 pub struct ChromecastDiscoveryBuilder {
     player_manager: Option<Arc<Box<dyn PlayerManager>>>,
     subtitle_server: Option<Arc<SubtitleServer>>,
+    settings: Option<Arc<ApplicationConfig>>,
+    known_devices: Option<Arc<KnownDevicesCache>>,
     runtime: Option<Arc<Runtime>>,
 }
 
@@ -175,6 +205,18 @@ impl ChromecastDiscoveryBuilder {
         self
     }
 
+    /// Sets the application settings which are used for styling the subtitles of discovered devices.
+    pub fn settings(mut self, settings: Arc<ApplicationConfig>) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Sets the known devices cache which is used to register previously discovered devices on startup.
+    pub fn known_devices(mut self, known_devices: Arc<KnownDevicesCache>) -> Self {
+        self.known_devices = Some(known_devices);
+        self
+    }
+
     pub fn build(self) -> ChromecastDiscovery {
         let runtime = self.runtime.unwrap_or_else(|| {
             Arc::new(
@@ -193,6 +235,8 @@ impl ChromecastDiscoveryBuilder {
                 .expect("expected a player manager to have been set"),
             self.subtitle_server
                 .expect("expected a subtitle server to have been set"),
+            self.settings,
+            self.known_devices,
             runtime,
         )
     }
@@ -203,8 +247,11 @@ struct InnerChromecastDiscovery {
     service_daemon: ServiceDaemon,
     transcoder: Arc<Box<dyn Transcoder>>,
     subtitle_server: Arc<SubtitleServer>,
+    settings: Option<Arc<ApplicationConfig>>,
+    known_devices: Option<Arc<KnownDevicesCache>>,
     discovered_devices: Mutex<Vec<String>>,
     state: Mutex<DiscoveryState>,
+    callbacks: CoreCallbacks<DiscoveryEvent>,
     runtime: Arc<Runtime>,
 }
 
@@ -217,56 +264,178 @@ impl InnerChromecastDiscovery {
     }
 
     async fn handle_event(&self, event: ServiceEvent) {
-        if let ServiceEvent::ServiceResolved(info) = event {
-            trace!("Discovered Chromecast device: {:?}", info);
-            if let Some(addr) = info
-                .get_addresses()
-                .into_iter()
-                .find_or_first(|e| e.is_ipv4())
-                .map(|e| e.to_string())
+        match event {
+            ServiceEvent::ServiceResolved(info) => self.handle_service_resolved(info).await,
+            ServiceEvent::ServiceRemoved(_, fullname) => {
+                trace!("Chromecast device {} has been removed", fullname);
+                self.remove_device(fullname.as_str()).await
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_service_resolved(&self, info: ServiceInfo) {
+        trace!("Discovered Chromecast device: {:?}", info);
+        if let Some(addr) = info
+            .get_addresses()
+            .into_iter()
+            .find_or_first(|e| e.is_ipv4())
+            .map(|e| e.to_string())
+        {
+            let mut mutex = self.discovered_devices.lock().await;
+            let id = info.get_fullname().to_string();
+            let port = info.get_port();
+            let name = info.get_property_val_str("fn").unwrap_or(INFO_UNKNOWN);
+            let model = info.get_property_val_str("md").unwrap_or(INFO_UNKNOWN);
+
+            if !mutex.contains(&id) {
+                match self.register_device(id.as_str(), name, model, addr.as_str(), port) {
+                    Ok(_) => {
+                        mutex.push(id.clone());
+                        self.callbacks
+                            .invoke(DiscoveryEvent::DeviceAdded(id.clone()));
+                    }
+                    Err(e) => {
+                        warn!("Failed to connect to Chromecast device: {}", e);
+                        return;
+                    }
+                }
+            } else {
+                trace!("Chromecast device {} is already known", id);
+            }
+
+            if let Some(known_devices) = self.known_devices.as_ref() {
+                known_devices.update(KnownDevice {
+                    id,
+                    name: name.to_string(),
+                    device_type: KnownDeviceType::Chromecast,
+                    address: addr,
+                    port,
+                    model: Some(model.to_string()),
+                    location: None,
+                    last_seen: 0,
+                    failed_verifications: 0,
+                });
+            }
+        } else {
+            warn!("Chromecast device {:?} has no available IPv4 address", info);
+        }
+    }
+
+    /// Register the previously discovered Chromecast devices from the known devices cache.
+    /// The devices are registered as unverified players and are verified in the background,
+    /// unregistering the ones which are no longer reachable.
+    async fn register_known_devices(&self) {
+        let known_devices = match self.known_devices.as_ref() {
+            Some(e) => e.clone(),
+            None => return,
+        };
+
+        for device in known_devices.devices(&KnownDeviceType::Chromecast) {
             {
                 let mut mutex = self.discovered_devices.lock().await;
-                let id = info.get_fullname().to_string();
-                let port = info.get_port();
+                if mutex.contains(&device.id) {
+                    continue;
+                }
 
-                if !mutex.contains(&id) {
-                    match self.register_device(info, addr, port).await {
-                        Ok(_) => mutex.push(id),
-                        Err(e) => warn!("Failed to connect to Chromecast device: {}", e),
+                debug!("Registering unverified Chromecast device {}", device.id);
+                match self.register_device(
+                    device.id.as_str(),
+                    device.name.as_str(),
+                    device.model.as_deref().unwrap_or(INFO_UNKNOWN),
+                    device.address.as_str(),
+                    device.port,
+                ) {
+                    Ok(_) => {
+                        mutex.push(device.id.clone());
+                        self.callbacks
+                            .invoke(DiscoveryEvent::DeviceAdded(device.id.clone()));
+                    }
+                    Err(e) => {
+                        warn!("Failed to register known Chromecast device, {}", e);
+                        continue;
                     }
-                } else {
-                    trace!("Chromecast device {} is already known", id);
                 }
+            }
+
+            if Self::verify_device(&device).await {
+                known_devices.verified(device.id.as_str());
             } else {
-                warn!("Chromecast device {:?} has no available IPv4 address", info);
+                warn!(
+                    "Known Chromecast device {} is unreachable, unregistering player",
+                    device.id
+                );
+                known_devices.verification_failed(device.id.as_str());
+                self.remove_device(device.id.as_str()).await;
+            }
+        }
+    }
+
+    /// Remove the given device which is no longer available on the network.
+    /// The player of the device is unregistered from the player manager.
+    async fn remove_device(&self, id: &str) {
+        {
+            let mut mutex = self.discovered_devices.lock().await;
+            match mutex.iter().position(|e| e == id) {
+                Some(position) => {
+                    mutex.remove(position);
+                }
+                None => {
+                    trace!("Chromecast device {} is unknown, ignoring removal", id);
+                    return;
+                }
             }
         }
+
+        info!("Chromecast device {} is no longer available", id);
+        self.player_manager.remove_player(id);
+        self.callbacks
+            .invoke(DiscoveryEvent::DeviceRemoved(id.to_string()));
     }
 
-    async fn register_device<S: Into<String>>(
+    /// Verify if the given known device is still reachable on its last known address.
+    async fn verify_device(device: &KnownDevice) -> bool {
+        trace!(
+            "Verifying Chromecast device {} at {}:{}",
+            device.id,
+            device.address,
+            device.port
+        );
+        matches!(
+            time::timeout(
+                Duration::from_secs(VERIFICATION_TIMEOUT_SECONDS),
+                TcpStream::connect((device.address.as_str(), device.port)),
+            )
+            .await,
+            Ok(Ok(_))
+        )
+    }
+
+    fn register_device(
         &self,
-        info: ServiceInfo,
-        addr: S,
+        device_id: &str,
+        device_name: &str,
+        device_model: &str,
+        addr: &str,
         port: u16,
     ) -> chromecast::Result<()> {
-        let device_id = info.get_fullname();
-        let device_name = info.get_property_val_str("fn").unwrap_or(INFO_UNKNOWN);
-        let device_model = info.get_property_val_str("md").unwrap_or(INFO_UNKNOWN);
-
-        match ChromecastPlayer::<DefaultCastDevice>::builder()
+        let mut builder = ChromecastPlayer::<DefaultCastDevice>::builder()
             .id(device_id)
             .name(device_name)
             .cast_model(device_model)
-            .cast_address(addr.into())
+            .cast_address(addr)
             .cast_port(port)
             .subtitle_server(self.subtitle_server.clone())
             .transcoder(self.transcoder.clone())
-            .cast_device_factory(Box::new(|addr, port| DefaultCastDevice::new(addr, port)))
-            .build()
-        {
+            .cast_device_factory(Box::new(|addr, port| DefaultCastDevice::new(addr, port)));
+        if let Some(settings) = self.settings.as_ref() {
+            builder = builder.settings(settings.clone());
+        }
+
+        match builder.build() {
             Ok(player) => {
                 if !self.player_manager.add_player(Box::new(player)) {
-                    warn!("Failed to add Chromecast player {:?}", info);
+                    warn!("Failed to add Chromecast player {}", device_id);
                 }
 
                 Ok(())
@@ -358,6 +527,56 @@ mod tests {
         mdns.daemon.shutdown().unwrap();
     }
 
+    #[test]
+    fn test_device_removed() {
+        init_logger();
+        let (tx_added, rx_added) = channel();
+        let (tx_removed, rx_removed) = channel();
+        let (tx_event, rx_event) = channel();
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().returning(move |e| {
+            if e.name() == "Chromecast test device" {
+                tx_added.send(e.id().to_string()).unwrap();
+            }
+            true
+        });
+        player_manager
+            .expect_remove_player()
+            .returning(move |id| tx_removed.send(id.to_string()).unwrap());
+        let mut test_instance = TestInstance::new_mdns();
+        let mdns = test_instance.mdns.take().unwrap();
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = ChromecastDiscovery::builder()
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .runtime(test_instance.runtime.clone())
+            .subtitle_server(subtitle_server)
+            .build();
+
+        discovery.add(Box::new(move |event| {
+            if let DiscoveryEvent::DeviceRemoved(id) = event {
+                tx_event.send(id).unwrap();
+            }
+        }));
+        test_instance
+            .runtime
+            .block_on(discovery.start_discovery())
+            .unwrap();
+
+        let device_id = rx_added.recv_timeout(Duration::from_secs(3)).unwrap();
+        mdns.daemon
+            .unregister(device_id.as_str())
+            .expect("expected the service to have been unregistered");
+
+        let result = rx_removed.recv_timeout(Duration::from_secs(3)).unwrap();
+        assert_eq!(device_id, result);
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(device_id, result);
+
+        discovery.stop_discovery().unwrap();
+        mdns.daemon.shutdown().unwrap();
+    }
+
     #[test]
     fn test_stop_discovery() {
         init_logger();
@@ -161,6 +161,7 @@ mod tests {
                 .subtitle_server(Arc::new(subtitle_server))
                 .transcoder(Arc::new(transcoder))
                 .heartbeat_seconds(2)
+                .reconnect_backoff_millis(10)
                 .build()
                 .unwrap();
 
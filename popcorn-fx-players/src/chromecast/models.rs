@@ -3,7 +3,16 @@ use std::collections::HashMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+use popcorn_fx_core::core::config::{DecorationType, SubtitleSettings};
+
 const PAYLOAD_TYPE_LOAD: &str = "LOAD";
+const PAYLOAD_TYPE_EDIT_TRACKS_INFO: &str = "EDIT_TRACKS_INFO";
+/// The subtitle font size which corresponds with a font scale of `1.0` on the receiver.
+const DEFAULT_SUBTITLE_FONT_SIZE: f32 = 28.0;
+const COLOR_TRANSPARENT: &str = "#00000000";
+const COLOR_BLACK: &str = "#000000FF";
+const COLOR_BLACK_SEE_THROUGH: &str = "#000000AA";
+const COLOR_WHITE: &str = "#FFFFFFFF";
 const METADATA_TYPE_MOVIE: i16 = 1;
 const METADATA_TYPE_TV_SHOW: i16 = 2;
 
@@ -28,6 +37,25 @@ pub struct LoadCommand {
     pub active_track_ids: Option<Vec<u32>>,
 }
 
+/// Represents a command to update the active tracks of the current media session on the Chromecast device.
+/// https://developers.google.com/cast/docs/reference/web_sender/chrome.cast.media.EditTracksInfoRequest
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTracksInfoCommand {
+    /// The unique identifier for the request.
+    pub request_id: u64,
+    /// The media session identifier of the media to update.
+    pub media_session_id: i32,
+    /// The type of payload.
+    #[serde(rename = "type", serialize_with = "serialize_edit_tracks_info_type")]
+    pub payload_type: (),
+    /// The IDs of the tracks which should be active, an empty list disables all tracks.
+    pub active_track_ids: Vec<u32>,
+    /// The style settings for text tracks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_track_style: Option<TextTrackStyle>,
+}
+
 /// Represents media content to be loaded on the Chromecast device.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -174,11 +202,59 @@ pub struct TextTrackStyle {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub font_scale: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_style: Option<TextTrackFontStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foreground_color: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub window_color: Option<String>,
 }
 
+impl Default for TextTrackStyle {
+    fn default() -> Self {
+        Self {
+            background_color: Some(COLOR_TRANSPARENT.to_string()),
+            custom_data: None,
+            edge_color: Some(COLOR_BLACK.to_string()),
+            edge_type: Some(TextTrackEdgeType::Outline),
+            font_family: None,
+            font_scale: None,
+            font_style: None,
+            foreground_color: Some(COLOR_WHITE.to_string()),
+            window_color: None,
+        }
+    }
+}
+
+impl From<&SubtitleSettings> for TextTrackStyle {
+    fn from(value: &SubtitleSettings) -> Self {
+        let (background_color, edge_type) = match value.decoration {
+            DecorationType::None => (COLOR_TRANSPARENT, TextTrackEdgeType::None),
+            DecorationType::Outline => (COLOR_TRANSPARENT, TextTrackEdgeType::Outline),
+            DecorationType::OpaqueBackground => (COLOR_BLACK, TextTrackEdgeType::None),
+            DecorationType::SeeThroughBackground => {
+                (COLOR_BLACK_SEE_THROUGH, TextTrackEdgeType::None)
+            }
+        };
+        let font_style = if value.bold {
+            TextTrackFontStyle::Bold
+        } else {
+            TextTrackFontStyle::Normal
+        };
+
+        Self {
+            background_color: Some(background_color.to_string()),
+            custom_data: None,
+            edge_color: Some(COLOR_BLACK.to_string()),
+            edge_type: Some(edge_type),
+            font_family: Some(value.font_family.family()),
+            font_scale: Some(value.font_size as f32 / DEFAULT_SUBTITLE_FONT_SIZE),
+            font_style: Some(font_style),
+            foreground_color: Some(COLOR_WHITE.to_string()),
+            window_color: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum TextTrackEdgeType {
@@ -189,6 +265,17 @@ pub enum TextTrackEdgeType {
     Depressed,
 }
 
+/// Possible text track font styles.
+/// https://developers.google.com/cast/docs/reference/web_sender/chrome.cast.media#.TextTrackFontStyle
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TextTrackFontStyle {
+    Normal,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+
 /// Represents errors that occur during media parsing.
 #[derive(Debug, Clone, Error)]
 pub enum MediaParseError {
@@ -358,6 +445,14 @@ fn serialize_payload_type<S: Serializer>(_: &(), serializer: S) -> Result<S::Ok,
     serializer.serialize_str(PAYLOAD_TYPE_LOAD)
 }
 
+/// Serializes the payload type for the EditTracksInfoCommand.
+fn serialize_edit_tracks_info_type<S: Serializer>(
+    _: &(),
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(PAYLOAD_TYPE_EDIT_TRACKS_INFO)
+}
+
 /// Serializes the metadata type for movie metadata.
 fn serialize_movie_metadata_type<S: Serializer>(_: &(), serializer: S) -> Result<S::Ok, S::Error> {
     serializer.serialize_i16(METADATA_TYPE_MOVIE)
@@ -365,6 +460,9 @@ fn serialize_movie_metadata_type<S: Serializer>(_: &(), serializer: S) -> Result
 
 #[cfg(test)]
 mod tests {
+    use popcorn_fx_core::core::config::{SubtitleFamily, TvModeSubtitle};
+    use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
+
     use super::*;
 
     #[test]
@@ -378,4 +476,60 @@ mod tests {
         
         assert_eq!(expected_result, result);
     }
+
+    #[test]
+    fn test_text_track_style_from_subtitle_settings() {
+        let settings = SubtitleSettings {
+            directory: "/tmp/subtitles".to_string(),
+            auto_cleaning_enabled: false,
+            default_subtitle: SubtitleLanguage::None,
+            font_family: SubtitleFamily::Verdana,
+            font_size: 42,
+            decoration: DecorationType::SeeThroughBackground,
+            bold: true,
+            max_line_length: 0,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: None,
+        };
+
+        let result = serde_json::to_value(TextTrackStyle::from(&settings)).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "backgroundColor": "#000000AA",
+                "edgeColor": "#000000FF",
+                "edgeType": "NONE",
+                "fontFamily": "Verdana",
+                "fontScale": 1.5,
+                "fontStyle": "BOLD",
+                "foregroundColor": "#FFFFFFFF",
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_serialize_edit_tracks_info_command() {
+        let command = EditTracksInfoCommand {
+            request_id: 0,
+            media_session_id: 12,
+            payload_type: (),
+            active_track_ids: vec![0],
+            text_track_style: None,
+        };
+
+        let result = serde_json::to_value(&command).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "requestId": 0,
+                "mediaSessionId": 12,
+                "type": "EDIT_TRACKS_INFO",
+                "activeTrackIds": [0],
+            }),
+            result
+        );
+    }
 }
@@ -5,17 +5,19 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use derive_more::Display;
-use log::{debug, error, trace, warn};
+use log::{debug, error, info, trace, warn};
 use rust_cast::channels::heartbeat::HeartbeatResponse;
 use rust_cast::channels::media::{MediaResponse, Status, StatusEntry};
-use rust_cast::channels::receiver::{Application, CastDeviceApp};
+use rust_cast::channels::receiver::{Application, CastDeviceApp, ReceiverResponse, Volume};
 use rust_cast::{channels, ChannelMessage};
 use tokio::runtime::Runtime;
 use tokio::sync::{Mutex, RwLock};
 use tokio::{runtime, time};
 use tokio_util::sync::CancellationToken;
 
-use popcorn_fx_core::core::players::{PlayRequest, Player, PlayerEvent, PlayerState};
+use popcorn_fx_core::core::config::ApplicationConfig;
+use popcorn_fx_core::core::players::{MediaTrack, PlayRequest, Player, PlayerEvent, PlayerState};
+use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
 use popcorn_fx_core::core::subtitles::model::{Subtitle, SubtitleType};
 use popcorn_fx_core::core::subtitles::SubtitleServer;
 use popcorn_fx_core::core::{
@@ -24,18 +26,24 @@ use popcorn_fx_core::core::{
 
 use crate::chromecast;
 use crate::chromecast::device::{FxCastDevice, DEFAULT_RECEIVER};
-use crate::chromecast::transcode::{NoOpTranscoder, Transcoder};
+use crate::chromecast::transcode::{NoOpTranscoder, TranscodeProfile, Transcoder};
 use crate::chromecast::{
-    ChromecastError, Image, LoadCommand, Media, MediaDetailedErrorCode, MediaError, Metadata,
-    MovieMetadata, StreamType, TextTrackEdgeType, TextTrackStyle, TextTrackType, Track, TrackType,
+    ChromecastError, EditTracksInfoCommand, Image, LoadCommand, Media, MediaDetailedErrorCode,
+    MediaError, Metadata, MovieMetadata, StreamType, TextTrackStyle, TextTrackType, Track,
+    TrackType,
 };
 
 const GRAPHIC_RESOURCE: &[u8] = include_bytes!("../../resources/external-chromecast-icon.png");
 const DESCRIPTION: &str =
     "Chromecast streaming media device which allows the playback of videos on your TV.";
 const DEFAULT_HEARTBEAT_INTERVAL_SECONDS: u64 = 30;
+const DEFAULT_RECONNECT_BACKOFF_MILLIS: u64 = 500;
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
 const MEDIA_CHANNEL_NAMESPACE: &str = "urn:x-cast:com.google.cast.media";
+/// The subtitle format which is served to the Chromecast, which silently ignores subtitles in any other format.
+const SUBTITLE_FORMAT: SubtitleType = SubtitleType::Vtt;
 const SUBTITLE_CONTENT_TYPE: &str = "text/vtt";
+const SUBTITLE_TRACK_ID: u32 = 0;
 const MESSAGE_TYPE_ERROR: &str = "ERROR";
 
 /// The type of the factory function used to create the Chromecast client device.
@@ -58,7 +66,9 @@ impl<D: FxCastDevice> ChromecastPlayer<D> {
         cast_device_factory: DeviceFactory<D>,
         subtitle_server: Arc<SubtitleServer>,
         transcoder: Arc<Box<dyn Transcoder>>,
+        settings: Option<Arc<ApplicationConfig>>,
         heartbeat_seconds: u64,
+        reconnect_backoff_millis: u64,
         runtime: Arc<Runtime>,
     ) -> chromecast::Result<Self> {
         let name = name.into();
@@ -89,13 +99,22 @@ impl<D: FxCastDevice> ChromecastPlayer<D> {
             cast_address,
             cast_port,
             request: Default::default(),
+            transcoding: Default::default(),
             state: Mutex::new(PlayerState::Ready),
             cast_device: RwLock::new(cast_device),
             cast_device_factory,
             cast_app: Default::default(),
             cast_media_session_id: Default::default(),
             subtitle_server,
+            subtitle_track_url: Default::default(),
+            active_track_ids: Default::default(),
+            active_audio_track_id: Default::default(),
+            volume: Default::default(),
+            last_known_time: Default::default(),
+            reconnect_backoff: Duration::from_millis(reconnect_backoff_millis),
+            reconnect_lock: Default::default(),
             transcoder,
+            settings,
             callbacks: Default::default(),
             runtime,
             status_check_token: Default::default(),
@@ -137,6 +156,7 @@ impl<D: FxCastDevice> ChromecastPlayer<D> {
 
             if let Err(e) = ping_result {
                 warn!("Failed to ping Chromecast {}, {}", inner.name, e);
+                inner.reconnect().await;
             }
             time::sleep(Duration::from_secs(heartbeat_seconds)).await;
         }
@@ -173,7 +193,13 @@ impl<D: FxCastDevice> ChromecastPlayer<D> {
                 Ok(e) => inner.handle_status_update(e).await,
                 Err(e) => {
                     error!("Failed to retrieve chromecast status, {}", e);
-                    break;
+                    if e == ChromecastError::AppNotInitialized || cancellation_token.is_cancelled()
+                    {
+                        break;
+                    }
+                    if !inner.reconnect().await {
+                        break;
+                    }
                 }
             }
             time::sleep(Duration::from_secs(1)).await;
@@ -215,12 +241,12 @@ impl<D: FxCastDevice + 'static> Player for ChromecastPlayer<D> {
         self.inner.state()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         let mutex = block_in_place(self.inner.request.lock());
         mutex.as_ref().map(|e| Arc::downgrade(e))
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, mut request: PlayRequest) {
         trace!(
             "Starting Chromecast {} playback for {:?}",
             self.name(),
@@ -241,17 +267,10 @@ impl<D: FxCastDevice + 'static> Player for ChromecastPlayer<D> {
                 // self.inner.runtime.spawn(Self::start_message_handler(inner, cancellation_token));
 
                 // serve the chromecast subtitle if one is present
-                let subtitle_url = request.subtitle().map(|e| e.clone()).and_then(|e| {
-                    match self.inner.subtitle_server.serve(e, SubtitleType::Vtt) {
-                        Ok(e) => Some(e),
-                        Err(e) => {
-                            error!("Failed to serve subtitle, {}", e);
-                            None
-                        }
-                    }
-                });
+                let subtitle_url = self.inner.subtitle_url(&request);
+                request.subtitle.url = subtitle_url.clone();
 
-                if let Err(e) = self.inner.load(&app, &request, subtitle_url).await {
+                if let Err(e) = self.inner.load(&app, &request, subtitle_url, None).await {
                     error!("Failed to load Chromecast media, {}", e);
                     self.inner.update_state_async(PlayerState::Error).await;
                     return;
@@ -267,7 +286,8 @@ impl<D: FxCastDevice + 'static> Player for ChromecastPlayer<D> {
                 {
                     trace!("Updating Chromecast player request to {:?}", request);
                     let mut mutex = self.inner.request.lock().await;
-                    *mutex = Some(Arc::new(request))
+                    *mutex = Some(Arc::new(request));
+                    *self.inner.transcoding.lock().await = false;
                 }
             }
             Err(e) => {
@@ -292,6 +312,29 @@ impl<D: FxCastDevice + 'static> Player for ChromecastPlayer<D> {
     fn stop(&self) {
         block_in_place(self.inner.stop())
     }
+
+    fn update_subtitle(&self, subtitle: Option<Subtitle>) {
+        block_in_place(self.inner.update_subtitle(subtitle))
+    }
+
+    fn set_volume(&self, volume: u32) {
+        block_in_place(self.inner.set_volume(volume))
+    }
+
+    fn set_muted(&self, muted: bool) {
+        block_in_place(self.inner.set_muted(muted))
+    }
+
+    fn audio_tracks(&self) -> Option<Vec<MediaTrack>> {
+        block_in_place(self.inner.request.lock())
+            .as_ref()
+            .map(|e| e.audio_tracks())
+            .filter(|e| !e.is_empty())
+    }
+
+    fn set_audio_track(&self, id: i32) {
+        block_in_place(self.inner.set_audio_track(id))
+    }
 }
 
 pub struct ChromecastPlayerBuilder<D: FxCastDevice> {
@@ -303,7 +346,9 @@ pub struct ChromecastPlayerBuilder<D: FxCastDevice> {
     cast_device_factory: Option<DeviceFactory<D>>,
     subtitle_server: Option<Arc<SubtitleServer>>,
     transcoder: Option<Arc<Box<dyn Transcoder>>>,
+    settings: Option<Arc<ApplicationConfig>>,
     heartbeat_seconds: Option<u64>,
+    reconnect_backoff_millis: Option<u64>,
     runtime: Option<Arc<Runtime>>,
 }
 
@@ -318,7 +363,9 @@ impl<D: FxCastDevice> ChromecastPlayerBuilder<D> {
             cast_device_factory: None,
             subtitle_server: None,
             transcoder: None,
+            settings: None,
             heartbeat_seconds: None,
+            reconnect_backoff_millis: None,
             runtime: None,
         }
     }
@@ -363,11 +410,25 @@ impl<D: FxCastDevice> ChromecastPlayerBuilder<D> {
         self
     }
 
+    /// Set the application settings from which the subtitle styling is derived.
+    /// When no settings are set, a default subtitle style will be used.
+    pub fn settings(mut self, settings: Arc<ApplicationConfig>) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
     pub fn heartbeat_seconds(mut self, heartbeat_seconds: u64) -> Self {
         self.heartbeat_seconds = Some(heartbeat_seconds);
         self
     }
 
+    /// Set the initial delay between reconnection attempts when the connection with the device has been lost.
+    /// The delay is doubled after each failed attempt.
+    pub fn reconnect_backoff_millis(mut self, reconnect_backoff_millis: u64) -> Self {
+        self.reconnect_backoff_millis = Some(reconnect_backoff_millis);
+        self
+    }
+
     pub fn runtime(mut self, runtime: Arc<Runtime>) -> Self {
         self.runtime = Some(runtime);
         self
@@ -390,6 +451,9 @@ impl<D: FxCastDevice> ChromecastPlayerBuilder<D> {
         let heartbeat_seconds = self
             .heartbeat_seconds
             .unwrap_or(DEFAULT_HEARTBEAT_INTERVAL_SECONDS);
+        let reconnect_backoff_millis = self
+            .reconnect_backoff_millis
+            .unwrap_or(DEFAULT_RECONNECT_BACKOFF_MILLIS);
         let transcoder = self.transcoder.unwrap_or_else(|| {
             warn!("No transcoder set, using no-op transcoder");
             Arc::new(Box::new(NoOpTranscoder {}))
@@ -414,7 +478,9 @@ impl<D: FxCastDevice> ChromecastPlayerBuilder<D> {
             cast_device_factory,
             subtitle_server,
             transcoder,
+            self.settings,
             heartbeat_seconds,
+            reconnect_backoff_millis,
             runtime,
         )
     }
@@ -423,7 +489,9 @@ impl<D: FxCastDevice> ChromecastPlayerBuilder<D> {
 struct InnerChromecastPlayer<D: FxCastDevice> {
     id: String,
     name: String,
-    request: Mutex<Option<Arc<Box<dyn PlayRequest>>>>,
+    request: Mutex<Option<Arc<PlayRequest>>>,
+    /// Indicates if the loaded request is a live transcoding of the original media
+    transcoding: Mutex<bool>,
     state: Mutex<PlayerState>,
     cast_model: String,
     cast_address: String,
@@ -433,7 +501,22 @@ struct InnerChromecastPlayer<D: FxCastDevice> {
     cast_app: Mutex<Option<Application>>,
     cast_media_session_id: Mutex<Option<i32>>,
     subtitle_server: Arc<SubtitleServer>,
+    /// The subtitle url of the text track which has been loaded on the receiver
+    subtitle_track_url: Mutex<Option<String>>,
+    /// The text tracks which have been selected on the receiver
+    active_track_ids: Mutex<Vec<u32>>,
+    /// The alternate audio track which has been selected on the receiver
+    active_audio_track_id: Mutex<Option<u32>>,
+    /// The last known volume of the receiver
+    volume: Mutex<Option<u32>>,
+    /// The last known playback time in millis of the receiver
+    last_known_time: Mutex<Option<u64>>,
+    /// The initial delay between reconnection attempts
+    reconnect_backoff: Duration,
+    /// Prevents multiple reconnections from running at the same time
+    reconnect_lock: Mutex<()>,
     transcoder: Arc<Box<dyn Transcoder>>,
+    settings: Option<Arc<ApplicationConfig>>,
     callbacks: CoreCallbacks<PlayerEvent>,
     runtime: Arc<Runtime>,
     status_check_token: Mutex<CancellationToken>,
@@ -530,13 +613,15 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
             if let Some(request) = mutex.take() {
                 trace!("Starting transcoding process for {:?}", request);
                 let request_url = request.url();
-                match self.transcoder.transcode(request_url).await {
+                let profile = TranscodeProfile::for_cast_model(self.cast_model.as_str());
+                debug!(
+                    "Using transcoding profile {} for Chromecast model {}",
+                    profile, self.cast_model
+                );
+                match self.transcoder.transcode_with(request_url, &profile).await {
                     Ok(output) => {
                         debug!("Received transcoding output {:?}", output);
-                        let request = Arc::new(Box::new(TranscodingPlayRequest {
-                            url: output.url,
-                            request,
-                        }) as Box<dyn PlayRequest>);
+                        let mut request = Self::transcoding_request(&request, output.url);
 
                         // serve the chromecast subtitle if one is present
                         let subtitle_url: Option<String>;
@@ -545,10 +630,12 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
                         } else {
                             subtitle_url = None;
                         }
+                        request.subtitle.url = subtitle_url.clone();
 
-                        match self.load(app, &request, subtitle_url).await {
+                        match self.load(app, &request, subtitle_url, None).await {
                             Ok(_) => {
-                                *mutex = Some(request);
+                                *mutex = Some(Arc::new(request));
+                                *self.transcoding.lock().await = true;
                                 drop(mutex);
 
                                 let _ = self.resume().await;
@@ -592,42 +679,285 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
         .await
     }
 
+    /// Load the given request on the Chromecast receiver app.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The receiver app to load the media on.
+    /// * `request` - The request to load.
+    /// * `subtitle_url` - The url of the subtitle track to show, if any.
+    /// * `current_time` - The time in millis to start the playback from, else the auto resume timestamp of the request is used.
     async fn load(
         &self,
         app: &Application,
-        request: &Box<dyn PlayRequest>,
+        request: &PlayRequest,
         subtitle_url: Option<String>,
+        current_time: Option<u64>,
     ) -> chromecast::Result<()> {
-        return self
+        let active_track_ids = if subtitle_url.is_some() {
+            vec![SUBTITLE_TRACK_ID]
+        } else {
+            vec![]
+        };
+
+        self.try_command(|| async {
+            let cast_device = self.cast_device.read().await;
+            let media = Self::request_to_media_payload(
+                request,
+                subtitle_url.clone(),
+                self.text_track_style(),
+            );
+            let load = LoadCommand {
+                request_id: 0,
+                session_id: app.session_id.to_string(),
+                payload_type: (),
+                media,
+                autoplay: true,
+                current_time: current_time
+                    .or_else(|| request.auto_resume_timestamp())
+                    .map(|e| Self::parse_to_chromecast_time(e))
+                    .unwrap_or(0f32),
+                active_track_ids: Some(active_track_ids.clone()).filter(|e| !e.is_empty()),
+            };
+
+            trace!("Sending load command {:?}", load);
+            if let Err(e) = cast_device.broadcast_message(MEDIA_CHANNEL_NAMESPACE, &load) {
+                return Err(ChromecastError::AppInitializationFailed(e.to_string()));
+            }
+
+            Ok(())
+        })
+        .await?;
+
+        *self.subtitle_track_url.lock().await = subtitle_url;
+        *self.active_track_ids.lock().await = active_track_ids;
+        *self.active_audio_track_id.lock().await = None;
+        Ok(())
+    }
+
+    /// Update the subtitle of the current Chromecast playback.
+    ///
+    /// If the subtitle has already been loaded as text track on the receiver, only the active track selection is updated.
+    /// Otherwise, the media is reloaded with the new subtitle track at the current playback time.
+    /// A [PlayerEvent::SubtitleChanged] is emitted once the subtitle has been updated.
+    async fn update_subtitle(&self, subtitle: Option<Subtitle>) {
+        trace!(
+            "Updating Chromecast {} subtitle to {:?}",
+            self.name,
+            subtitle
+        );
+        let language = subtitle
+            .as_ref()
+            .map(|e| {
+                e.info()
+                    .map(|info| info.language().clone())
+                    .unwrap_or(SubtitleLanguage::Custom)
+            })
+            .unwrap_or(SubtitleLanguage::None);
+        let subtitle_url = match subtitle {
+            None => {
+                debug!("Disabling Chromecast {} subtitle track", self.name);
+                self.update_active_tracks(vec![]).await;
+                self.callbacks
+                    .invoke(PlayerEvent::SubtitleChanged(SubtitleLanguage::None));
+                return;
+            }
+            Some(subtitle) => match self.subtitle_server.serve(subtitle, SUBTITLE_FORMAT) {
+                Ok(e) => e,
+                Err(e) => {
+                    error!("Failed to serve subtitle, {}", e);
+                    return;
+                }
+            },
+        };
+
+        let is_track_loaded = self
+            .subtitle_track_url
+            .lock()
+            .await
+            .as_ref()
+            .filter(|e| **e == subtitle_url)
+            .is_some();
+        if is_track_loaded {
+            debug!("Activating Chromecast {} subtitle track", self.name);
+            self.update_active_tracks(vec![SUBTITLE_TRACK_ID]).await;
+        } else if !self.reload_with_subtitle(subtitle_url).await {
+            return;
+        }
+
+        self.callbacks
+            .invoke(PlayerEvent::SubtitleChanged(language));
+    }
+
+    /// Reload the current media on the receiver with the given subtitle url as text track.
+    ///
+    /// It returns `true` when the media has been reloaded with the subtitle track, else `false`.
+    async fn reload_with_subtitle(&self, subtitle_url: String) -> bool {
+        let app = self.cast_app.lock().await.clone();
+        let request = self.request.lock().await.clone();
+
+        if let (Some(app), Some(request)) = (app, request) {
+            let current_time = self
+                .status()
+                .await
+                .ok()
+                .and_then(|e| e.entries.into_iter().next())
+                .and_then(|e| e.current_time)
+                .map(|e| Self::parse_to_popcorn_fx_time(e));
+
+            debug!(
+                "Reloading Chromecast {} media with subtitle track {}",
+                self.name, subtitle_url
+            );
+            match self
+                .load(&app, &request, Some(subtitle_url), current_time)
+                .await
+            {
+                Ok(_) => {
+                    self.resume().await;
+                    true
+                }
+                Err(e) => {
+                    error!("Failed to load Chromecast subtitle track, {}", e);
+                    false
+                }
+            }
+        } else {
+            warn!(
+                "Unable to update Chromecast {} subtitle, no playback is active",
+                self.name
+            );
+            false
+        }
+    }
+
+    async fn update_active_tracks(&self, active_track_ids: Vec<u32>) {
+        *self.active_track_ids.lock().await = active_track_ids;
+        self.send_active_tracks().await;
+    }
+
+    /// Select the alternate audio track of the current Chromecast playback.
+    ///
+    /// The track id should match the track number within the media container, as known by the receiver.
+    /// A [PlayerEvent::AudioTrackChanged] is emitted once the selection has been sent to the receiver.
+    async fn set_audio_track(&self, id: i32) {
+        let track_id = match u32::try_from(id) {
+            Ok(e) => e,
+            Err(_) => {
+                warn!("Invalid Chromecast {} audio track id {}", self.name, id);
+                return;
+            }
+        };
+
+        debug!("Selecting Chromecast {} audio track {}", self.name, id);
+        *self.active_audio_track_id.lock().await = Some(track_id);
+        self.send_active_tracks().await;
+        self.callbacks.invoke(PlayerEvent::AudioTrackChanged(id));
+    }
+
+    /// Send the active track selection to the receiver through an `EDIT_TRACKS_INFO` command.
+    async fn send_active_tracks(&self) {
+        let audio_track_id = self.active_audio_track_id.lock().await.clone();
+        if self.subtitle_track_url.lock().await.is_none() && audio_track_id.is_none() {
+            trace!("Chromecast {} has no tracks loaded", self.name);
+            return;
+        }
+
+        let media_session_id = match self.cast_media_session_id.lock().await.clone() {
+            Some(e) => e,
+            None => {
+                warn!(
+                    "Unable to update Chromecast {} tracks, media session id is unknown",
+                    self.name
+                );
+                return;
+            }
+        };
+        let command = EditTracksInfoCommand {
+            request_id: 0,
+            media_session_id,
+            payload_type: (),
+            active_track_ids: self
+                .active_track_ids
+                .lock()
+                .await
+                .iter()
+                .cloned()
+                .chain(audio_track_id)
+                .collect(),
+            text_track_style: Some(self.text_track_style()),
+        };
+
+        trace!("Sending edit tracks info command {:?}", command);
+        if let Err(e) = self
             .try_command(|| async {
                 let cast_device = self.cast_device.read().await;
-                let active_track_ids = if subtitle_url.is_some() {
-                    Some(vec![0])
-                } else {
-                    None
-                };
-                let media = Self::request_to_media_payload(request, subtitle_url.clone());
-                let load = LoadCommand {
-                    request_id: 0,
-                    session_id: app.session_id.to_string(),
-                    payload_type: (),
-                    media,
-                    autoplay: true,
-                    current_time: request
-                        .auto_resume_timestamp()
-                        .map(|e| Self::parse_to_chromecast_time(e))
-                        .unwrap_or(0f32),
-                    active_track_ids,
-                };
-
-                trace!("Sending load command {:?}", load);
-                if let Err(e) = cast_device.broadcast_message(MEDIA_CHANNEL_NAMESPACE, &load) {
-                    return Err(ChromecastError::AppInitializationFailed(e.to_string()));
-                }
+                cast_device.broadcast_message(MEDIA_CHANNEL_NAMESPACE, &command)
+            })
+            .await
+        {
+            error!("Failed to update Chromecast {} tracks, {}", self.name, e);
+        }
+    }
+
+    /// Update the volume level of the receiver.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - The volume level between 0 and 100.
+    async fn set_volume(&self, volume: u32) {
+        let level = volume.min(100) as f32 / 100.0;
+
+        trace!("Updating Chromecast {} volume to {}", self.name, level);
+        self.update_volume(Some(level), None).await
+    }
+
+    /// Update the mute state of the receiver.
+    async fn set_muted(&self, muted: bool) {
+        trace!("Updating Chromecast {} muted to {}", self.name, muted);
+        self.update_volume(None, Some(muted)).await
+    }
 
-                Ok(())
+    async fn update_volume(&self, level: Option<f32>, muted: Option<bool>) {
+        match self
+            .try_command(|| async {
+                let cast_device = self.cast_device.read().await;
+                cast_device.set_volume(Volume { level, muted })
             })
-            .await;
+            .await
+        {
+            Ok(volume) => self.handle_volume_update(&volume).await,
+            Err(e) => error!("Failed to update Chromecast {} volume, {}", self.name, e),
+        }
+    }
+
+    /// Handle a volume update of the receiver.
+    /// A muted receiver is reported as volume `0`.
+    async fn handle_volume_update(&self, volume: &Volume) {
+        let level = match (volume.muted, volume.level) {
+            (Some(true), _) => 0,
+            (_, Some(level)) => (level * 100.0).round() as u32,
+            _ => return,
+        };
+
+        {
+            let mut mutex = self.volume.lock().await;
+            if *mutex == Some(level) {
+                return;
+            }
+            *mutex = Some(level);
+        }
+
+        debug!("Chromecast {} volume changed to {}", self.name, level);
+        self.callbacks.invoke(PlayerEvent::VolumeChanged(level));
+    }
+
+    /// Retrieve the text track style based on the subtitle settings of the user.
+    fn text_track_style(&self) -> TextTrackStyle {
+        self.settings
+            .as_ref()
+            .map(|e| TextTrackStyle::from(e.user_settings_ref().subtitle()))
+            .unwrap_or_default()
     }
 
     async fn stop_app(&self) -> chromecast::Result<()> {
@@ -731,6 +1061,7 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
             trace!("Removing media session id for {}", self.name);
             let _ = mutex.take();
         }
+        let _ = self.last_known_time.lock().await.take();
 
         if let Err(e) = self.stop_app().await {
             error!("Failed to stop Chromecast playback, {}", e);
@@ -776,25 +1107,42 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
             status
         );
         if let Some(e) = status.entries.get(0) {
+            let mut is_session_changed = false;
+
             {
                 let mut mutex = self.cast_media_session_id.lock().await;
-                if mutex.is_none() {
-                    *mutex = Some(e.media_session_id.clone());
-                    debug!(
-                        "Received Chromecast media session id {}",
-                        e.media_session_id
-                    );
+                match mutex.clone() {
+                    None => {
+                        *mutex = Some(e.media_session_id.clone());
+                        debug!(
+                            "Received Chromecast media session id {}",
+                            e.media_session_id
+                        );
+                    }
+                    Some(id) if id != e.media_session_id => {
+                        *mutex = Some(e.media_session_id.clone());
+                        debug!(
+                            "Chromecast media session id changed from {} to {}",
+                            id, e.media_session_id
+                        );
+                        is_session_changed = true;
+                    }
+                    _ => {}
                 }
             }
 
+            // the receiver doesn't remember the track selection when it has been restarted
+            if is_session_changed {
+                self.send_active_tracks().await;
+            }
+
             // update the playback state of the player
             self.on_player_state_changed(e).await;
 
             if let Some(time) = e.current_time {
-                self.callbacks
-                    .invoke(PlayerEvent::TimeChanged(Self::parse_to_popcorn_fx_time(
-                        time,
-                    )));
+                let time = Self::parse_to_popcorn_fx_time(time);
+                *self.last_known_time.lock().await = Some(time);
+                self.callbacks.invoke(PlayerEvent::TimeChanged(time));
             }
             if let Some(media) = &e.media {
                 if let Some(duration) = media.duration {
@@ -834,9 +1182,9 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
     /// # Returns
     ///
     /// The subtitle URL if available, or `None` if the subtitle is not present or could not be served.
-    fn subtitle_url(&self, request: &Box<dyn PlayRequest>) -> Option<String> {
+    fn subtitle_url(&self, request: &PlayRequest) -> Option<String> {
         request.subtitle().map(|e| e.clone()).and_then(|e| {
-            match self.subtitle_server.serve(e, SubtitleType::Vtt) {
+            match self.subtitle_server.serve(e, SUBTITLE_FORMAT) {
                 Ok(e) => Some(e),
                 Err(e) => {
                     error!("Failed to serve subtitle, {}", e);
@@ -852,6 +1200,7 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
             Ok(e) => match e {
                 ChannelMessage::Media(response) => self.handle_media_event(response).await,
                 ChannelMessage::Heartbeat(response) => self.handle_heartbeat_event(response).await,
+                ChannelMessage::Receiver(response) => self.handle_receiver_event(response).await,
                 _ => {}
             },
             Err(e) => {
@@ -881,6 +1230,13 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
         }
     }
 
+    async fn handle_receiver_event(&self, event: ReceiverResponse) {
+        trace!("Handling receiver response event {:?}", event);
+        if let ReceiverResponse::Status(status) = event {
+            self.handle_volume_update(&status.volume).await;
+        }
+    }
+
     async fn handle_heartbeat_event(&self, event: HeartbeatResponse) {
         trace!("Handling heartbeat response event {:?}", event);
         if let HeartbeatResponse::Ping = event {
@@ -899,9 +1255,8 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
 
             {
                 let mutex = self.request.lock().await;
-                if let Some(request) = mutex.as_ref() {
-                    is_transcoding_request =
-                        Some(request.downcast_ref::<TranscodingPlayRequest>().is_some());
+                if mutex.is_some() {
+                    is_transcoding_request = Some(*self.transcoding.lock().await);
                 }
             }
 
@@ -976,9 +1331,129 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
         Ok(())
     }
 
+    /// Try to restore the connection with the Chromecast device after it has been lost.
+    ///
+    /// Each attempt establishes a new connection and resumes the active media session on it.
+    /// Failed attempts are retried with an exponential backoff until [MAX_RECONNECT_ATTEMPTS] has been reached,
+    /// after which the player gives up and transitions to the [PlayerState::Error] state.
+    ///
+    /// # Returns
+    ///
+    /// It returns `true` when the connection has been restored, else `false`.
+    async fn reconnect(&self) -> bool {
+        let _lock = self.reconnect_lock.lock().await;
+
+        // the connection might have already been restored by another reconnection in the meantime
+        if self.cast_device.read().await.ping().is_ok() {
+            trace!("Chromecast {} connection is still alive", self.name);
+            return true;
+        }
+
+        let mut backoff = self.reconnect_backoff;
+        for attempt in 1..=MAX_RECONNECT_ATTEMPTS {
+            if self.shutdown_token.is_cancelled() {
+                return false;
+            }
+
+            debug!(
+                "Reconnecting to Chromecast {}, attempt {}/{}",
+                self.name, attempt, MAX_RECONNECT_ATTEMPTS
+            );
+            self.callbacks.invoke(PlayerEvent::Reconnecting(attempt));
+            match self.resume_session().await {
+                Ok(_) => {
+                    info!("Chromecast {} connection has been restored", self.name);
+                    self.callbacks.invoke(PlayerEvent::Reconnected);
+                    return true;
+                }
+                Err(e) => warn!(
+                    "Failed to reconnect to Chromecast {} (attempt {}), {}",
+                    self.name, attempt, e
+                ),
+            }
+
+            if attempt < MAX_RECONNECT_ATTEMPTS {
+                time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+
+        error!(
+            "Failed to reconnect to Chromecast {} after {} attempts",
+            self.name, MAX_RECONNECT_ATTEMPTS
+        );
+        self.callbacks.invoke(PlayerEvent::ReconnectFailed);
+        self.update_state_async(PlayerState::Error).await;
+        false
+    }
+
+    /// Establish a new connection with the Chromecast device and resume the active media session on it.
+    ///
+    /// When the receiver app is still running the known session, the player re-attaches to its media session.
+    /// Otherwise, the media is loaded again at the last known playback time.
+    async fn resume_session(&self) -> chromecast::Result<()> {
+        self.reestablish_connection().await?;
+        {
+            let cast_device = self.cast_device.read().await;
+            cast_device.connect(DEFAULT_RECEIVER)?;
+            cast_device.ping()?;
+        }
+
+        let app = self.cast_app.lock().await.clone();
+        let request = self.request.lock().await.clone();
+        let (app, request) = match (app, request) {
+            (Some(app), Some(request)) => (app, request),
+            _ => {
+                trace!("Chromecast {} has no media session to resume", self.name);
+                return Ok(());
+            }
+        };
+
+        let is_app_running = self
+            .cast_device
+            .read()
+            .await
+            .device_status()?
+            .applications
+            .iter()
+            .any(|e| e.session_id == app.session_id);
+        if is_app_running {
+            let media_session_id = *self.cast_media_session_id.lock().await;
+            let status: Status;
+
+            {
+                let cast_device = self.cast_device.read().await;
+                trace!("Re-attaching to Chromecast {} app {:?}", self.name, app);
+                cast_device.connect(app.transport_id.to_string())?;
+                status =
+                    cast_device.media_status(app.transport_id.to_string(), media_session_id)?;
+            }
+
+            if !status.entries.is_empty() {
+                debug!("Re-attached to Chromecast {} media session", self.name);
+                self.handle_status_update(status).await;
+                return Ok(());
+            }
+        }
+
+        debug!(
+            "Chromecast {} media session is no longer available, loading the media again",
+            self.name
+        );
+        let _ = self.cast_app.lock().await.take();
+        let _ = self.cast_media_session_id.lock().await.take();
+        let app = self.start_app().await?;
+        self.connect().await?;
+
+        let subtitle_url = self.subtitle_track_url.lock().await.clone();
+        let current_time = *self.last_known_time.lock().await;
+        self.load(&app, &request, subtitle_url, current_time).await
+    }
+
     fn request_to_media_payload(
-        request: &Box<dyn PlayRequest>,
+        request: &PlayRequest,
         subtitle_url: Option<String>,
+        text_track_style: TextTrackStyle,
     ) -> Media {
         let mut images: Vec<Image> = Vec::new();
         let subtitle = Self::create_media_subtitle(request);
@@ -1008,19 +1483,10 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
             })),
             custom_data: None,
             duration: None,
-            text_track_style: Some(TextTrackStyle {
-                background_color: Some("#00000000".to_string()),
-                custom_data: None,
-                edge_color: Some("#000000FF".to_string()),
-                edge_type: Some(TextTrackEdgeType::Outline),
-                font_family: None,
-                font_scale: None,
-                foreground_color: Some("#FFFFFFFF".to_string()),
-                window_color: None,
-            }),
+            text_track_style: Some(text_track_style),
             tracks: subtitle_url.map(|e| {
                 vec![Track {
-                    track_id: 0,
+                    track_id: SUBTITLE_TRACK_ID as u64,
                     track_type: TrackType::Text,
                     track_content_id: e.to_string(),
                     track_content_type: SUBTITLE_CONTENT_TYPE.to_string(),
@@ -1032,7 +1498,22 @@ impl<D: FxCastDevice> InnerChromecastPlayer<D> {
         }
     }
 
-    fn create_media_subtitle(request: &Box<dyn PlayRequest>) -> String {
+    /// Create the request for the live transcoding of the given request.
+    ///
+    /// The auto resume timestamp is not supported for media which is being transcoded,
+    /// as this would otherwise require us to support seeking within live transcodings.
+    fn transcoding_request(request: &PlayRequest, url: String) -> PlayRequest {
+        if request.auto_resume_timestamp().is_some() {
+            warn!("Auto resume timestamps are not supported for live transcoding media playbacks");
+        }
+
+        let mut request = request.clone();
+        request.url = url;
+        request.auto_resume_timestamp = None;
+        request
+    }
+
+    fn create_media_subtitle(request: &PlayRequest) -> String {
         let separator = if request.caption().is_some() {
             " - "
         } else {
@@ -1077,6 +1558,9 @@ impl<D: FxCastDevice> Debug for InnerChromecastPlayer<D> {
             .field("cast_address", &self.cast_address)
             .field("cast_port", &self.cast_port)
             .field("cast_app", &self.cast_app)
+            .field("subtitle_track_url", &self.subtitle_track_url)
+            .field("active_track_ids", &self.active_track_ids)
+            .field("active_audio_track_id", &self.active_audio_track_id)
             .field("callbacks", &self.callbacks)
             .field("runtime", &self.runtime)
             .field("cancellation_token", &self.shutdown_token)
@@ -1091,69 +1575,18 @@ impl<D: FxCastDevice> Drop for InnerChromecastPlayer<D> {
     }
 }
 
-#[derive(Debug, Display)]
-#[display(fmt = "Transcoding play request for {}", url)]
-struct TranscodingPlayRequest {
-    pub url: String,
-    pub request: Arc<Box<dyn PlayRequest>>,
-}
-
-impl PlayRequest for TranscodingPlayRequest {
-    fn url(&self) -> &str {
-        self.url.as_str()
-    }
-
-    fn title(&self) -> &str {
-        self.request.title()
-    }
-
-    fn caption(&self) -> Option<String> {
-        self.request.caption()
-    }
-
-    fn thumbnail(&self) -> Option<String> {
-        self.request.thumbnail()
-    }
-
-    fn background(&self) -> Option<String> {
-        self.request.background()
-    }
-
-    fn quality(&self) -> Option<String> {
-        self.request.quality()
-    }
-
-    fn auto_resume_timestamp(&self) -> Option<u64> {
-        if let Some(_) = self.request.auto_resume_timestamp() {
-            warn!("Auto resume timestamps are not supported for live transcoding media playbacks");
-        }
-
-        // the auto resume timestamp is not supported for media when it's being transcoded
-        // this will otherwise require us to support seeking within live transcodings
-        None
-    }
-
-    fn subtitles_enabled(&self) -> bool {
-        self.request.subtitles_enabled()
-    }
-
-    fn subtitle(&self) -> Option<&Subtitle> {
-        self.request.subtitle()
-    }
-}
-
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::mpsc::channel;
 
     use popcorn_fx_core::core::media::MovieOverview;
-    use popcorn_fx_core::core::players::{PlayMediaRequest, PlayUrlRequest};
     use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
     use popcorn_fx_core::core::subtitles::model::SubtitleInfo;
     use popcorn_fx_core::core::subtitles::MockSubtitleProvider;
+    use popcorn_fx_core::core::utils::network::available_socket;
     use popcorn_fx_core::testing::init_logger;
     use rust_cast::channels::media::StatusEntry;
-    use rust_cast::channels::receiver::Volume;
     use rust_cast::channels::{media, receiver};
     use serde_json::Number;
 
@@ -1179,7 +1612,9 @@ mod tests {
             Box::new(|_, _| Ok(create_default_device())),
             Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider)))),
             Arc::new(Box::new(transcoder)),
+            None,
             500,
+            100,
             Arc::new(runtime),
         );
 
@@ -1302,22 +1737,17 @@ mod tests {
             rating: None,
             images: Default::default(),
         };
-        let request = Box::new(PlayMediaRequest {
-            base: PlayUrlRequest {
-                url: url.to_string(),
-                title: "FooBar".to_string(),
-                caption: Some("MyCaption".to_string()),
-                thumb: Some("http://localhost/my-thumb.png".to_string()),
-                background: Some("http://localhost/my-background.png".to_string()),
-                auto_resume_timestamp: Some(28000),
-                subtitles_enabled: true,
-                subtitle: None,
-            },
-            parent_media: None,
-            media: Box::new(movie),
-            quality: "720p".to_string(),
-            torrent_stream: Default::default(),
-        });
+        let request = PlayRequest::builder()
+            .url(url)
+            .title("FooBar")
+            .caption("MyCaption")
+            .thumb("http://localhost/my-thumb.png")
+            .background("http://localhost/my-background.png")
+            .auto_resume_timestamp(28000)
+            .subtitles_enabled(true)
+            .media(Box::new(movie))
+            .quality("720p")
+            .build();
         let (tx, rx) = channel();
         let player = test_instance.player.take().unwrap();
 
@@ -1338,6 +1768,218 @@ mod tests {
         assert_eq!(url.to_string(), command.media.url);
     }
 
+    #[test]
+    fn test_player_play_subtitle_track() {
+        init_logger();
+        let url = "http://localhost:8900/my-video.mkv";
+        let (tx_command, rx_command) = channel::<LoadCommand>();
+        let mut provider = MockSubtitleProvider::new();
+        provider
+            .expect_convert()
+            .times(1)
+            .return_const(Ok("WEBVTT".to_string()));
+        let mut transcoder = MockTranscoder::new();
+        transcoder.expect_stop().return_const(());
+        let mut test_instance = TestInstance::new_player_with_additions(
+            Box::new(move || {
+                let mut device = MockFxCastDevice::new();
+                default_device_responses(&mut device);
+                device
+                    .expect_device_status()
+                    .return_const(Ok(receiver::Status {
+                        request_id: 1,
+                        applications: vec![],
+                        is_active_input: false,
+                        is_stand_by: true,
+                        volume: Volume {
+                            level: None,
+                            muted: None,
+                        },
+                    }));
+                device.expect_launch_app().return_const(Ok(Application {
+                    app_id: "MyAppId".to_string(),
+                    session_id: "MySessionId".to_string(),
+                    transport_id: "MyTransportId".to_string(),
+                    namespaces: vec![],
+                    display_name: "".to_string(),
+                    status_text: "".to_string(),
+                }));
+                let sender = tx_command.clone();
+                device.expect_broadcast_message::<LoadCommand>().returning(
+                    move |_namespace, command| {
+                        sender.send(command.clone()).unwrap();
+                        Ok(())
+                    },
+                );
+                device
+                    .expect_play::<String>()
+                    .return_const(Ok(status_entry(media::PlayerState::Playing)));
+                default_device_status_response(&mut device);
+                device
+            }),
+            Box::new(provider),
+            Box::new(transcoder),
+        );
+        let request = PlayRequest::builder()
+            .url(url)
+            .title("My Video")
+            .subtitles_enabled(true)
+            .subtitle(Subtitle::new(
+                vec![],
+                Some(
+                    SubtitleInfo::builder()
+                        .imdb_id("tt12345678")
+                        .language(SubtitleLanguage::English)
+                        .build(),
+                ),
+                "MySubtitleFile.srt".to_string(),
+            ))
+            .build();
+        let player = test_instance.player.take().unwrap();
+
+        test_instance.runtime.block_on(player.play(request));
+
+        let command = rx_command.recv_timeout(Duration::from_millis(200)).unwrap();
+        let result = serde_json::to_value(&command).unwrap();
+        assert_eq!("LOAD", result["type"]);
+        assert_eq!(serde_json::json!([0]), result["activeTrackIds"]);
+        let tracks = result["media"]["tracks"]
+            .as_array()
+            .expect("expected the media to contain tracks");
+        assert_eq!(1, tracks.len(), "expected a single subtitle track");
+        assert_eq!(0, tracks[0]["trackId"]);
+        assert_eq!("TEXT", tracks[0]["type"]);
+        assert_eq!("SUBTITLES", tracks[0]["subtype"]);
+        assert_eq!(SUBTITLE_CONTENT_TYPE, tracks[0]["trackContentType"]);
+        let track_url = tracks[0]["trackContentId"].as_str().unwrap();
+        assert!(
+            track_url.ends_with("MySubtitleFile.vtt"),
+            "expected the WebVTT subtitle url, got {} instead",
+            track_url
+        );
+        let request = player
+            .request()
+            .and_then(|e| e.upgrade())
+            .expect("expected the request to have been stored");
+        assert_eq!(Some(track_url), request.subtitle.url.as_deref());
+        assert_eq!(
+            serde_json::to_value(TextTrackStyle::default()).unwrap(),
+            result["media"]["textTrackStyle"]
+        );
+    }
+
+    #[test]
+    fn test_player_update_subtitle_disable() {
+        init_logger();
+        let (tx, rx) = channel::<EditTracksInfoCommand>();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            let mut device = create_default_device();
+            let sender = tx.clone();
+            device
+                .expect_broadcast_message::<EditTracksInfoCommand>()
+                .returning(move |_namespace, command| {
+                    sender.send(command.clone()).unwrap();
+                    Ok(())
+                });
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+
+        test_instance.runtime.block_on(async {
+            *player.inner.subtitle_track_url.lock().await =
+                Some("http://localhost:9876/MySubtitleFile.vtt".to_string());
+            *player.inner.active_track_ids.lock().await = vec![SUBTITLE_TRACK_ID];
+            *player.inner.cast_media_session_id.lock().await = Some(12);
+        });
+        test_instance
+            .runtime
+            .block_on(player.inner.update_subtitle(None));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(12, result.media_session_id);
+        assert_eq!(Vec::<u32>::new(), result.active_track_ids);
+    }
+
+    #[test]
+    fn test_player_set_audio_track() {
+        init_logger();
+        let (tx, rx) = channel::<EditTracksInfoCommand>();
+        let (tx_event, rx_event) = channel();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            let mut device = create_default_device();
+            let sender = tx.clone();
+            device
+                .expect_broadcast_message::<EditTracksInfoCommand>()
+                .returning(move |_namespace, command| {
+                    sender.send(command.clone()).unwrap();
+                    Ok(())
+                });
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::AudioTrackChanged(_) = &event {
+                tx_event.send(event).unwrap();
+            }
+        }));
+
+        test_instance.runtime.block_on(async {
+            *player.inner.subtitle_track_url.lock().await =
+                Some("http://localhost:9876/MySubtitleFile.vtt".to_string());
+            *player.inner.active_track_ids.lock().await = vec![SUBTITLE_TRACK_ID];
+            *player.inner.cast_media_session_id.lock().await = Some(12);
+        });
+        test_instance
+            .runtime
+            .block_on(player.inner.set_audio_track(2));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(12, result.media_session_id);
+        assert_eq!(vec![SUBTITLE_TRACK_ID, 2], result.active_track_ids);
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerEvent::AudioTrackChanged(2), result);
+    }
+
+    #[test]
+    fn test_player_handle_status_update_session_changed() {
+        init_logger();
+        let (tx, rx) = channel::<EditTracksInfoCommand>();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            let mut device = create_default_device();
+            let sender = tx.clone();
+            device
+                .expect_broadcast_message::<EditTracksInfoCommand>()
+                .returning(move |_namespace, command| {
+                    sender.send(command.clone()).unwrap();
+                    Ok(())
+                });
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+        let mut entry = status_entry(media::PlayerState::Playing);
+        entry.media_session_id = 2;
+
+        test_instance.runtime.block_on(async {
+            *player.inner.subtitle_track_url.lock().await =
+                Some("http://localhost:9876/MySubtitleFile.vtt".to_string());
+            *player.inner.active_track_ids.lock().await = vec![SUBTITLE_TRACK_ID];
+            *player.inner.cast_media_session_id.lock().await = Some(1);
+        });
+        test_instance
+            .runtime
+            .block_on(player.inner.handle_status_update(Status {
+                request_id: 0,
+                entries: vec![entry],
+            }));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(
+            2, result.media_session_id,
+            "expected the track selection to be sent to the new media session"
+        );
+        assert_eq!(vec![SUBTITLE_TRACK_ID], result.active_track_ids);
+    }
+
     #[test]
     fn test_player_pause() {
         init_logger();
@@ -1418,6 +2060,120 @@ mod tests {
         assert_eq!(Some(14f32), result);
     }
 
+    #[test]
+    fn test_player_set_volume() {
+        init_logger();
+        let (tx, rx) = channel();
+        let (tx_event, rx_event) = channel();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            let mut device = create_default_device();
+            let sender = tx.clone();
+            device
+                .expect_set_volume()
+                .times(1)
+                .returning(move |volume| {
+                    let level = volume.level;
+                    sender.send(volume).unwrap();
+                    Ok(Volume {
+                        level,
+                        muted: Some(false),
+                    })
+                });
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx_event.send(event).unwrap();
+            }
+        }));
+        player.set_volume(50);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(Some(0.5), result.level);
+        assert_eq!(None, result.muted);
+
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(50, volume);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_player_set_muted() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            let mut device = create_default_device();
+            let sender = tx.clone();
+            device
+                .expect_set_volume()
+                .times(1)
+                .returning(move |volume| {
+                    let muted = volume.muted;
+                    sender.send(volume).unwrap();
+                    Ok(Volume {
+                        level: Some(1.0),
+                        muted,
+                    })
+                });
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+
+        player.set_muted(true);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(None, result.level);
+        assert_eq!(Some(true), result.muted);
+        assert_eq!(Some(0), *block_in_place(player.inner.volume.lock()));
+    }
+
+    #[test]
+    fn test_player_handle_event_receiver_status() {
+        init_logger();
+        let (tx, rx) = channel();
+        let mut test_instance = create_default_test_instance();
+        let player = test_instance.player.take().unwrap();
+        let runtime = test_instance.runtime.clone();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        runtime.block_on(player.inner.handle_event(Ok(ChannelMessage::Receiver(
+            ReceiverResponse::Status(receiver::Status {
+                request_id: 1,
+                applications: vec![],
+                is_active_input: true,
+                is_stand_by: false,
+                volume: Volume {
+                    level: Some(0.8),
+                    muted: Some(false),
+                },
+            }),
+        ))));
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(80, volume);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
+                result
+            );
+        }
+    }
+
     #[test]
     fn test_player_stop() {
         init_logger();
@@ -1460,23 +2216,21 @@ mod tests {
         let original_url = "http://localhost:9876/my-video.mp4";
         let transcoding_url = "http://localhost:9875/my-transcoded-video.mp4";
         let subtitle_url = "http://localhost:9876/my-subtitle.srt";
-        let request = Box::new(
-            PlayUrlRequest::builder()
-                .url(original_url)
-                .title("My Video")
-                .subtitles_enabled(true)
-                .subtitle(Subtitle::new(
-                    vec![],
-                    Some(
-                        SubtitleInfo::builder()
-                            .imdb_id("tt12345678")
-                            .language(SubtitleLanguage::English)
-                            .build(),
-                    ),
-                    "MySubtitleFile.srt".to_string(),
-                ))
-                .build(),
-        );
+        let request = PlayRequest::builder()
+            .url(original_url)
+            .title("My Video")
+            .subtitles_enabled(true)
+            .subtitle(Subtitle::new(
+                vec![],
+                Some(
+                    SubtitleInfo::builder()
+                        .imdb_id("tt12345678")
+                        .language(SubtitleLanguage::English)
+                        .build(),
+                ),
+                "MySubtitleFile.srt".to_string(),
+            ))
+            .build();
         let response = MediaResponse::NotImplemented(
             MESSAGE_TYPE_ERROR.to_string(),
             serde_json::Value::Object(
@@ -1501,13 +2255,16 @@ mod tests {
             .return_const(Ok(subtitle_url.to_string()));
         let (tx, rx) = channel();
         let mut transcoder = MockTranscoder::new();
-        transcoder.expect_transcode().times(1).returning(move |e| {
-            tx.send(e.to_string()).unwrap();
-            Ok(TranscodeOutput {
-                url: transcoding_url.to_string(),
-                output_type: TranscodeType::Live,
-            })
-        });
+        transcoder
+            .expect_transcode_with()
+            .times(1)
+            .returning(move |e, profile| {
+                tx.send((e.to_string(), profile.clone())).unwrap();
+                Ok(TranscodeOutput {
+                    url: transcoding_url.to_string(),
+                    output_type: TranscodeType::Live,
+                })
+            });
         transcoder.expect_stop().times(1).return_const(());
         let mut test_instance = TestInstance::new_player_with_additions(
             Box::new(move || {
@@ -1573,8 +2330,9 @@ mod tests {
                 .handle_event(Ok(ChannelMessage::Media(response))),
         );
 
-        let transcode_url = rx.recv_timeout(Duration::from_millis(250)).unwrap();
+        let (transcode_url, profile) = rx.recv_timeout(Duration::from_millis(250)).unwrap();
         assert_eq!(original_url, transcode_url);
+        assert_eq!(TranscodeProfile::full_hd(), profile);
 
         let request_url = player
             .request()
@@ -1654,6 +2412,234 @@ mod tests {
         assert_eq!(transport_id.to_string(), result.transport_id);
     }
 
+    #[test]
+    fn test_player_reconnect_resume_session() {
+        init_logger();
+        let session_id = "MySessionId";
+        let transport_id = "MyTransportId";
+        let connected = Arc::new(AtomicBool::new(true));
+        let factory_invocations = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = channel();
+        let device_connected = connected.clone();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            // every new connection after the initial one restores the connection with the device
+            if factory_invocations.fetch_add(1, Ordering::SeqCst) > 0 {
+                device_connected.store(true, Ordering::SeqCst);
+            }
+
+            let mut device = MockFxCastDevice::new();
+            let ping_connected = device_connected.clone();
+            device.expect_connect::<&str>().return_const(Ok(()));
+            device.expect_connect::<String>().return_const(Ok(()));
+            device.expect_ping().returning(move || {
+                if ping_connected.load(Ordering::SeqCst) {
+                    Ok(())
+                } else {
+                    Err(ChromecastError::Connection("connection lost".to_string()))
+                }
+            });
+            device
+                .expect_device_status()
+                .return_const(Ok(receiver::Status {
+                    request_id: 1,
+                    applications: vec![application(session_id, transport_id)],
+                    is_active_input: true,
+                    is_stand_by: false,
+                    volume: Volume {
+                        level: None,
+                        muted: None,
+                    },
+                }));
+            device.expect_launch_app().times(0).return_const(Err(
+                ChromecastError::AppInitializationFailed(
+                    "Should not have been invoked".to_string(),
+                ),
+            ));
+            device.expect_stop_app::<String>().return_const(Ok(()));
+            default_device_status_response(&mut device);
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+
+        player.add(Box::new(move |event| match &event {
+            PlayerEvent::Reconnecting(_)
+            | PlayerEvent::Reconnected
+            | PlayerEvent::ReconnectFailed => tx.send(event).unwrap(),
+            _ => {}
+        }));
+        test_instance.runtime.block_on(async {
+            *player.inner.cast_app.lock().await = Some(application(session_id, transport_id));
+            *player.inner.request.lock().await = Some(Arc::new(play_request()));
+            *player.inner.cast_media_session_id.lock().await = Some(0);
+        });
+        connected.store(false, Ordering::SeqCst);
+
+        let result = test_instance.runtime.block_on(player.inner.reconnect());
+        assert_eq!(
+            true, result,
+            "expected the connection to have been restored"
+        );
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerEvent::Reconnecting(1), result);
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerEvent::Reconnected, result);
+        let result = test_instance
+            .runtime
+            .block_on(player.inner.cast_app.lock())
+            .clone()
+            .map(|e| e.session_id);
+        assert_eq!(
+            Some(session_id.to_string()),
+            result,
+            "expected the existing session to have been re-attached"
+        );
+    }
+
+    #[test]
+    fn test_player_reconnect_load_media() {
+        init_logger();
+        let connected = Arc::new(AtomicBool::new(true));
+        let factory_invocations = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = channel::<LoadCommand>();
+        let device_connected = connected.clone();
+        let mut test_instance = TestInstance::new_player(Box::new(move || {
+            if factory_invocations.fetch_add(1, Ordering::SeqCst) > 0 {
+                device_connected.store(true, Ordering::SeqCst);
+            }
+
+            let mut device = MockFxCastDevice::new();
+            let ping_connected = device_connected.clone();
+            device.expect_connect::<&str>().return_const(Ok(()));
+            device.expect_connect::<String>().return_const(Ok(()));
+            device.expect_ping().returning(move || {
+                if ping_connected.load(Ordering::SeqCst) {
+                    Ok(())
+                } else {
+                    Err(ChromecastError::Connection("connection lost".to_string()))
+                }
+            });
+            // the receiver has been restarted, so the original session is gone
+            device
+                .expect_device_status()
+                .return_const(Ok(receiver::Status {
+                    request_id: 1,
+                    applications: vec![],
+                    is_active_input: true,
+                    is_stand_by: false,
+                    volume: Volume {
+                        level: None,
+                        muted: None,
+                    },
+                }));
+            device
+                .expect_launch_app()
+                .return_const(Ok(application("MyNewSessionId", "MyNewTransportId")));
+            let sender = tx.clone();
+            device.expect_broadcast_message::<LoadCommand>().returning(
+                move |_namespace, command| {
+                    sender.send(command.clone()).unwrap();
+                    Ok(())
+                },
+            );
+            device.expect_stop_app::<String>().return_const(Ok(()));
+            device
+        }));
+        let player = test_instance.player.take().unwrap();
+
+        test_instance.runtime.block_on(async {
+            *player.inner.cast_app.lock().await = Some(application("MySessionId", "MyTransportId"));
+            *player.inner.request.lock().await = Some(Arc::new(play_request()));
+            *player.inner.cast_media_session_id.lock().await = Some(3);
+            *player.inner.last_known_time.lock().await = Some(20000);
+        });
+        connected.store(false, Ordering::SeqCst);
+
+        let result = test_instance.runtime.block_on(player.inner.reconnect());
+        assert_eq!(
+            true, result,
+            "expected the connection to have been restored"
+        );
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("MyNewSessionId".to_string(), result.session_id);
+        assert_eq!(
+            20f32, result.current_time,
+            "expected the media to be loaded at the last known time"
+        );
+        assert_eq!(
+            None,
+            test_instance
+                .runtime
+                .block_on(player.inner.cast_media_session_id.lock())
+                .clone(),
+            "expected the previous media session id to have been removed"
+        );
+    }
+
+    #[test]
+    fn test_player_reconnect_failed() {
+        init_logger();
+        let factory_invocations = Arc::new(AtomicUsize::new(0));
+        let connected = Arc::new(AtomicBool::new(true));
+        let device_connected = connected.clone();
+        let (tx, rx) = channel();
+        let addr = available_socket();
+        let player = ChromecastPlayer::builder()
+            .id("MyChromecastId")
+            .name("MyChromecastName")
+            .cast_model("Chromecast")
+            .cast_address(addr.ip().to_string())
+            .cast_port(addr.port())
+            .cast_device_factory(Box::new(move |_, _| {
+                // the device is only reachable for the initial connection
+                if factory_invocations.fetch_add(1, Ordering::SeqCst) > 0 {
+                    return Err(ChromecastError::Connection(
+                        "device unreachable".to_string(),
+                    ));
+                }
+
+                let mut device = MockFxCastDevice::new();
+                let ping_connected = device_connected.clone();
+                device.expect_connect::<&str>().return_const(Ok(()));
+                device.expect_ping().returning(move || {
+                    if ping_connected.load(Ordering::SeqCst) {
+                        Ok(())
+                    } else {
+                        Err(ChromecastError::Connection("connection lost".to_string()))
+                    }
+                });
+                Ok(device)
+            }))
+            .subtitle_server(Arc::new(SubtitleServer::new(Arc::new(Box::new(
+                MockSubtitleProvider::new(),
+            )))))
+            .transcoder(Arc::new(Box::new(MockTranscoder::new())))
+            .heartbeat_seconds(60)
+            .reconnect_backoff_millis(5)
+            .build()
+            .unwrap();
+
+        player.add(Box::new(move |event| match &event {
+            PlayerEvent::Reconnecting(_)
+            | PlayerEvent::Reconnected
+            | PlayerEvent::ReconnectFailed => tx.send(event).unwrap(),
+            _ => {}
+        }));
+        connected.store(false, Ordering::SeqCst);
+
+        let result = player.inner.runtime.block_on(player.inner.reconnect());
+        assert_eq!(false, result, "expected the reconnection to have failed");
+
+        for attempt in 1..=MAX_RECONNECT_ATTEMPTS {
+            let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+            assert_eq!(PlayerEvent::Reconnecting(attempt), result);
+        }
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerEvent::ReconnectFailed, result);
+        assert_eq!(PlayerState::Error, player.state());
+    }
+
     fn create_default_test_instance() -> TestInstance {
         TestInstance::new_player(Box::new(move || {
             let mut device = create_default_device();
@@ -1711,4 +2697,22 @@ mod tests {
             supported_media_commands: 0,
         }
     }
+
+    fn application(session_id: &str, transport_id: &str) -> Application {
+        Application {
+            app_id: CastDeviceApp::DefaultMediaReceiver.to_string(),
+            session_id: session_id.to_string(),
+            transport_id: transport_id.to_string(),
+            namespaces: vec![],
+            display_name: "".to_string(),
+            status_text: "".to_string(),
+        }
+    }
+
+    fn play_request() -> PlayRequest {
+        PlayRequest::builder()
+            .url("http://localhost:8900/my-video.mkv")
+            .title("FooBar")
+            .build()
+    }
 }
@@ -61,6 +61,13 @@ pub type libvlc_media_player_play = extern "C" fn(media_player: libvlc_media_pla
 /// Represents the libvlc_media_player_stop function signature.
 #[allow(non_camel_case_types)]
 pub type libvlc_media_player_stop = extern "C" fn(media_player: libvlc_media_player_t);
+/// Represents the libvlc_media_player_get_time function signature.
+///
+/// # Returns
+///
+/// The current media time in millis, or -1 if there is no media.
+#[allow(non_camel_case_types)]
+pub type libvlc_media_player_get_time = extern "C" fn(media_player: libvlc_media_player_t) -> i64;
 
 /// Represents a handle to the VLC library and associated plugins.
 #[derive(Debug, Display)]
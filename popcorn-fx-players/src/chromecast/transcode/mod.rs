@@ -7,11 +7,13 @@ use mockall::automock;
 
 pub use errors::*;
 pub use none::*;
+pub use profile::*;
 pub use vlc::*;
 
 mod errors;
 mod lib_vlc;
 mod none;
+mod profile;
 mod vlc;
 
 /// Represents the type of transcoding.
@@ -40,6 +42,20 @@ pub enum TranscodeState {
     Error,
 }
 
+/// The status of the transcoding process.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscodeStatus {
+    /// The state of the transcoding process.
+    pub state: TranscodeState,
+    /// The profile which is being used by the transcoding process, if any.
+    pub profile: Option<TranscodeProfile>,
+    /// The hardware encoder which is being used by the transcoding process, if any.
+    pub hardware_encoder: Option<HardwareEncoder>,
+    /// The measured transcoding speed, relative to the playback speed of the media.
+    /// A speed below `1.0` indicates that the transcoding can't keep up with the playback.
+    pub speed: Option<f32>,
+}
+
 /// The output of a transcoding process.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TranscodeOutput {
@@ -56,16 +72,44 @@ pub trait Transcoder: Debug + Sync + Send {
     /// Gets the current state of the transcoder.
     fn state(&self) -> TranscodeState;
 
-    /// Transcodes the input media stream into a transcoded output stream.
+    /// Gets the current status of the transcoder, which includes the active profile and measured speed.
+    fn status(&self) -> TranscodeStatus {
+        TranscodeStatus {
+            state: self.state(),
+            profile: None,
+            hardware_encoder: None,
+            speed: None,
+        }
+    }
+
+    /// Transcodes the input media stream into a transcoded output stream with the default profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `url`: The URL of the input media.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the output of the transcoded media if successful, or an error if transcoding fails.
+    async fn transcode(&self, url: &str) -> Result<TranscodeOutput> {
+        self.transcode_with(url, &TranscodeProfile::default()).await
+    }
+
+    /// Transcodes the input media stream into a transcoded output stream with the given profile.
     ///
     /// # Arguments
     ///
     /// * `url`: The URL of the input media.
+    /// * `profile`: The profile which describes the transcoded output.
     ///
     /// # Returns
     ///
     /// A `Result` containing the output of the transcoded media if successful, or an error if transcoding fails.
-    async fn transcode(&self, url: &str) -> Result<TranscodeOutput>;
+    async fn transcode_with(
+        &self,
+        url: &str,
+        profile: &TranscodeProfile,
+    ) -> Result<TranscodeOutput>;
 
     /// Stops the current transcoding process.
     async fn stop(&self);
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 
 use crate::chromecast::transcode;
-use crate::chromecast::transcode::{TranscodeError, TranscodeOutput, Transcoder, TranscodeState};
+use crate::chromecast::transcode::{
+    TranscodeError, TranscodeOutput, TranscodeProfile, Transcoder, TranscodeState,
+};
 
 /// A no-operation transcoder implementation.
 #[derive(Debug)]
@@ -14,18 +16,23 @@ impl Transcoder for NoOpTranscoder {
         TranscodeState::Stopped
     }
 
-    /// Transcodes the input media.
+    /// Transcodes the input media with the given profile.
     ///
     /// This method always returns an error indicating that transcoding is unsupported.
     ///
     /// # Arguments
     ///
     /// * `_input`: A reference to the input media.
+    /// * `_profile`: The profile of the transcoded output.
     ///
     /// # Returns
     ///
     /// An error indicating that transcoding is unsupported.
-    async fn transcode(&self, _input: &str) -> transcode::Result<TranscodeOutput> {
+    async fn transcode_with(
+        &self,
+        _input: &str,
+        _profile: &TranscodeProfile,
+    ) -> transcode::Result<TranscodeOutput> {
         Err(TranscodeError::Unsupported)
     }
 
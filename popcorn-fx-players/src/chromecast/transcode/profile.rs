@@ -0,0 +1,246 @@
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+use derive_more::Display;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use libloading::Library;
+use log::debug;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use log::trace;
+
+/// The cast model names which are able to play 4K media.
+const ULTRA_HD_CAST_MODELS: [&str; 3] = ["ultra", "google tv", "4k"];
+#[cfg(target_os = "linux")]
+const DRI_DIRECTORY: &str = "/dev/dri";
+#[cfg(target_os = "linux")]
+const DRI_RENDER_NODE_PREFIX: &str = "renderD";
+#[cfg(target_os = "linux")]
+const NVENC_LIBRARIES: [&str; 2] = ["libnvidia-encode.so.1", "libnvidia-encode.so"];
+#[cfg(target_os = "windows")]
+const NVENC_LIBRARIES: [&str; 2] = ["nvEncodeAPI64.dll", "nvEncodeAPI.dll"];
+
+/// The video codec of the transcoded output.
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum VideoCodec {
+    /// The H.264/AVC video codec.
+    #[display(fmt = "h264")]
+    H264,
+    /// The H.265/HEVC video codec.
+    #[display(fmt = "hevc")]
+    Hevc,
+}
+
+/// A hardware video encoder which can be used to accelerate the transcoding process.
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum HardwareEncoder {
+    /// The Video Acceleration API encoder, available on Linux.
+    #[display(fmt = "VAAPI")]
+    Vaapi,
+    /// The NVIDIA hardware encoder.
+    #[display(fmt = "NVENC")]
+    Nvenc,
+    /// The Apple hardware encoder, available on macOS.
+    #[display(fmt = "VideoToolbox")]
+    VideoToolbox,
+}
+
+impl HardwareEncoder {
+    /// Detect the hardware encoders which are available on the current system.
+    ///
+    /// # Returns
+    ///
+    /// The available hardware encoders in order of preference, or an empty list when none are available.
+    pub fn detect() -> Vec<HardwareEncoder> {
+        let mut encoders = vec![];
+
+        if Self::is_nvenc_available() {
+            encoders.push(HardwareEncoder::Nvenc);
+        }
+        if Self::is_vaapi_available() {
+            encoders.push(HardwareEncoder::Vaapi);
+        }
+        if cfg!(target_os = "macos") {
+            encoders.push(HardwareEncoder::VideoToolbox);
+        }
+
+        debug!("Detected hardware encoders {:?}", encoders);
+        encoders
+    }
+
+    /// Get the name of the encoder for the given video codec.
+    ///
+    /// # Returns
+    ///
+    /// The `avcodec` encoder name, e.g. `h264_nvenc`.
+    pub fn encoder_name(&self, codec: &VideoCodec) -> String {
+        let suffix = match self {
+            HardwareEncoder::Vaapi => "vaapi",
+            HardwareEncoder::Nvenc => "nvenc",
+            HardwareEncoder::VideoToolbox => "videotoolbox",
+        };
+
+        format!("{}_{}", codec, suffix)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn is_vaapi_available() -> bool {
+        Path::new(DRI_DIRECTORY)
+            .read_dir()
+            .map(|entries| {
+                entries.filter_map(|e| e.ok()).any(|e| {
+                    e.file_name()
+                        .to_str()
+                        .map(|e| e.starts_with(DRI_RENDER_NODE_PREFIX))
+                        .unwrap_or(false)
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn is_vaapi_available() -> bool {
+        false
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
+    fn is_nvenc_available() -> bool {
+        NVENC_LIBRARIES
+            .iter()
+            .any(|filename| match unsafe { Library::new(filename) } {
+                Ok(_) => true,
+                Err(e) => {
+                    trace!("NVENC library {} not found, {}", filename, e);
+                    false
+                }
+            })
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    fn is_nvenc_available() -> bool {
+        false
+    }
+}
+
+/// The transcoding profile which describes the output of a transcoding process.
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(
+    fmt = "{} ({} {}x{} @ {}kbps)",
+    name,
+    codec,
+    max_width,
+    max_height,
+    max_bitrate
+)]
+pub struct TranscodeProfile {
+    /// The name of the profile.
+    pub name: String,
+    /// The video codec of the transcoded output.
+    pub codec: VideoCodec,
+    /// The maximum video bitrate of the transcoded output in kbps.
+    pub max_bitrate: u32,
+    /// The maximum width of the transcoded output.
+    pub max_width: u32,
+    /// The maximum height of the transcoded output.
+    pub max_height: u32,
+    /// Indicates if a hardware encoder should be used when one is available.
+    pub hardware_acceleration: bool,
+}
+
+impl TranscodeProfile {
+    /// The profile for 1080p capable devices, such as the Chromecast 3rd generation.
+    pub fn full_hd() -> Self {
+        Self {
+            name: "1080p".to_string(),
+            codec: VideoCodec::H264,
+            max_bitrate: 8000,
+            max_width: 1920,
+            max_height: 1080,
+            hardware_acceleration: true,
+        }
+    }
+
+    /// The profile for 4K capable devices, such as the Chromecast Ultra.
+    pub fn ultra_hd() -> Self {
+        Self {
+            name: "2160p".to_string(),
+            codec: VideoCodec::H264,
+            max_bitrate: 20000,
+            max_width: 3840,
+            max_height: 2160,
+            hardware_acceleration: true,
+        }
+    }
+
+    /// Get the most appropriate profile for the given cast model.
+    ///
+    /// # Arguments
+    ///
+    /// * `cast_model` - The model name of the cast device, e.g. `Chromecast Ultra`.
+    pub fn for_cast_model(cast_model: &str) -> Self {
+        let cast_model = cast_model.to_lowercase();
+
+        if ULTRA_HD_CAST_MODELS.iter().any(|e| cast_model.contains(e)) {
+            Self::ultra_hd()
+        } else {
+            Self::full_hd()
+        }
+    }
+}
+
+impl Default for TranscodeProfile {
+    /// The software based profile which is supported by all cast devices.
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            codec: VideoCodec::H264,
+            max_bitrate: 2048,
+            max_width: 1920,
+            max_height: 1080,
+            hardware_acceleration: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoder_name() {
+        assert_eq!(
+            "h264_nvenc",
+            HardwareEncoder::Nvenc.encoder_name(&VideoCodec::H264)
+        );
+        assert_eq!(
+            "hevc_vaapi",
+            HardwareEncoder::Vaapi.encoder_name(&VideoCodec::Hevc)
+        );
+        assert_eq!(
+            "h264_videotoolbox",
+            HardwareEncoder::VideoToolbox.encoder_name(&VideoCodec::H264)
+        );
+    }
+
+    #[test]
+    fn test_for_cast_model() {
+        assert_eq!(
+            TranscodeProfile::full_hd(),
+            TranscodeProfile::for_cast_model("Chromecast")
+        );
+        assert_eq!(
+            TranscodeProfile::ultra_hd(),
+            TranscodeProfile::for_cast_model("Chromecast Ultra")
+        );
+        assert_eq!(
+            TranscodeProfile::ultra_hd(),
+            TranscodeProfile::for_cast_model("Google TV Streamer")
+        );
+    }
+
+    #[test]
+    fn test_transcode_profile_display() {
+        let profile = TranscodeProfile::full_hd();
+
+        assert_eq!("1080p (h264 1920x1080 @ 8000kbps)", profile.to_string());
+    }
+}
@@ -2,6 +2,7 @@ use std::{env, fs};
 use std::ffi::{c_char, CString};
 use std::path::PathBuf;
 use std::string::ToString;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use libloading::Library;
@@ -13,8 +14,8 @@ use popcorn_fx_core::core::block_in_place;
 use popcorn_fx_core::core::utils::network::available_socket;
 
 use crate::chromecast::transcode;
-use crate::chromecast::transcode::{TranscodeError, TranscodeOutput, Transcoder, TranscodeState, TranscodeType};
-use crate::chromecast::transcode::lib_vlc::{LibraryHandle, libvlc_instance_t, libvlc_media_add_option, libvlc_media_new_location, libvlc_media_player_new, libvlc_media_player_play, libvlc_media_player_release, libvlc_media_player_set_media, libvlc_media_player_stop, libvlc_media_player_t, libvlc_media_release, libvlc_media_t, LibvlcInstanceT};
+use crate::chromecast::transcode::{HardwareEncoder, TranscodeError, TranscodeOutput, TranscodeProfile, Transcoder, TranscodeState, TranscodeStatus, TranscodeType};
+use crate::chromecast::transcode::lib_vlc::{LibraryHandle, libvlc_instance_t, libvlc_media_add_option, libvlc_media_new_location, libvlc_media_player_get_time, libvlc_media_player_new, libvlc_media_player_play, libvlc_media_player_release, libvlc_media_player_set_media, libvlc_media_player_stop, libvlc_media_player_t, libvlc_media_release, libvlc_media_t, LibvlcInstanceT};
 
 #[cfg(target_family = "unix")]
 const PATH_SEPARATOR: &str = ":";
@@ -76,6 +77,12 @@ pub struct VlcTranscoder {
     media_player: Mutex<Option<LibvlcInstanceT<libvlc_media_player_t>>>,
     media: Mutex<Option<LibvlcInstanceT<libvlc_media_t>>>,
     state: Mutex<TranscodeState>,
+    /// The hardware encoders which have been detected on the system
+    hardware_encoders: Vec<HardwareEncoder>,
+    /// The profile and hardware encoder of the active transcoding process
+    active_profile: Mutex<Option<(TranscodeProfile, Option<HardwareEncoder>)>>,
+    /// The instant on which the active transcoding process has been started
+    started_at: Mutex<Option<Instant>>,
 }
 
 impl VlcTranscoder {
@@ -108,9 +115,44 @@ impl VlcTranscoder {
             media_player: Default::default(),
             media: Default::default(),
             state: Mutex::new(TranscodeState::Unknown),
+            hardware_encoders: HardwareEncoder::detect(),
+            active_profile: Default::default(),
+            started_at: Default::default(),
         }
     }
 
+    /// Get the hardware encoders which have been detected by the transcoder.
+    pub fn hardware_encoders(&self) -> &[HardwareEncoder] {
+        self.hardware_encoders.as_slice()
+    }
+
+    /// Create the VLC stream output option for the given profile.
+    /// The hardware encoder is only used when the profile allows hardware acceleration.
+    fn stream_output_option(profile: &TranscodeProfile, encoder: Option<&HardwareEncoder>, destination: &str) -> String {
+        let video_encoder = encoder
+            .filter(|_| profile.hardware_acceleration)
+            .map(|e| format!(",venc=avcodec{{codec={}}}", e.encoder_name(&profile.codec)))
+            .unwrap_or_default();
+
+        format!(":sout=#transcode{{vcodec={}{},vb={},fps=24,maxwidth={},maxheight={},acodec=mp3,ab=128,channels=2,threads=0}}:std{{mux=avformat{{mux=matroska,options={{live=1}},reset-ts}},dst={},access=http}}",
+                profile.codec, video_encoder, profile.max_bitrate, profile.max_width, profile.max_height, destination)
+    }
+
+    /// Measure the transcoding speed relative to the playback speed of the media.
+    fn measure_speed(&self) -> Option<f32> {
+        let media_player = (*block_in_place(self.media_player.lock()))?;
+        let started_at = (*block_in_place(self.started_at.lock()))?;
+        let native_fn = self.library.get::<libvlc_media_player_get_time>(b"libvlc_media_player_get_time\0").ok()?;
+        let media_time = native_fn(media_player.0);
+        let elapsed = started_at.elapsed().as_millis();
+
+        if media_time < 0 || elapsed == 0 {
+            return None;
+        }
+
+        Some(media_time as f32 / elapsed as f32)
+    }
+
     async fn update_state_async(&self, state: TranscodeState) {
         let mut mutex = self.state.lock().await;
         trace!("Updating transcoder state to {:?}", state);
@@ -233,7 +275,27 @@ impl Transcoder for VlcTranscoder {
         mutex.clone()
     }
 
-    async fn transcode(&self, url: &str) -> transcode::Result<TranscodeOutput> {
+    fn status(&self) -> TranscodeStatus {
+        let state = self.state();
+        let (profile, hardware_encoder) = block_in_place(self.active_profile.lock())
+            .clone()
+            .map(|(profile, encoder)| (Some(profile), encoder))
+            .unwrap_or((None, None));
+        let speed = if state == TranscodeState::Transcoding {
+            self.measure_speed()
+        } else {
+            None
+        };
+
+        TranscodeStatus {
+            state,
+            profile,
+            hardware_encoder,
+            speed,
+        }
+    }
+
+    async fn transcode_with(&self, url: &str, profile: &TranscodeProfile) -> transcode::Result<TranscodeOutput> {
         self.update_state_async(TranscodeState::Preparing).await;
         let filename = PathBuf::from(url)
             .file_name()
@@ -243,6 +305,10 @@ impl Transcoder for VlcTranscoder {
             .to_string();
         let socket = available_socket();
         let destination = format!("{}/{}", socket, filename);
+        let encoder = self.hardware_encoders.first()
+            .filter(|_| profile.hardware_acceleration)
+            .copied();
+        debug!("Transcoding {} with profile {} and hardware encoder {:?}", url, profile, encoder);
 
         let media_player = self.media_player.lock().await.clone();
         let media_player = match media_player {
@@ -250,7 +316,7 @@ impl Transcoder for VlcTranscoder {
             None => self.create_media_player().await?,
         };
         let media = self.create_media(url, &[
-            format!(":sout=#transcode{{vcodec=h264,vb=2048,fps=24,maxwidth=1920,maxheight=1080,acodec=mp3,ab=128,channels=2,threads=0}}:std{{mux=avformat{{mux=matroska,options={{live=1}},reset-ts}},dst={},access=http}}", destination).as_str(),
+            Self::stream_output_option(profile, encoder.as_ref(), destination.as_str()).as_str(),
             ":demux-filter=demux_chromecast",
             ":sout-mux-caching=8192",
             ":sout-all",
@@ -261,6 +327,8 @@ impl Transcoder for VlcTranscoder {
         self.change_media(media_player, media)?;
         self.play(media_player)?;
 
+        *self.active_profile.lock().await = Some((profile.clone(), encoder));
+        *self.started_at.lock().await = Some(Instant::now());
         self.update_state_async(TranscodeState::Transcoding).await;
         Ok(TranscodeOutput {
             url: format!("http://{}", destination),
@@ -274,6 +342,8 @@ impl Transcoder for VlcTranscoder {
         let _ = self.stop_player().await;
         self.release_media().await;
         self.release_media_player().await;
+        self.active_profile.lock().await.take();
+        self.started_at.lock().await.take();
     }
 }
 
@@ -506,6 +576,17 @@ mod tests {
         runtime.block_on(transcoder.stop());
     }
 
+    #[test]
+    fn test_vlc_transcoder_stream_output_option() {
+        let destination = "127.0.0.1:9000/my-video.mp4";
+
+        let result = VlcTranscoder::stream_output_option(&TranscodeProfile::default(), Some(&HardwareEncoder::Nvenc), destination);
+        assert_eq!(":sout=#transcode{vcodec=h264,vb=2048,fps=24,maxwidth=1920,maxheight=1080,acodec=mp3,ab=128,channels=2,threads=0}:std{mux=avformat{mux=matroska,options={live=1},reset-ts},dst=127.0.0.1:9000/my-video.mp4,access=http}", result);
+
+        let result = VlcTranscoder::stream_output_option(&TranscodeProfile::ultra_hd(), Some(&HardwareEncoder::Vaapi), destination);
+        assert_eq!(":sout=#transcode{vcodec=h264,venc=avcodec{codec=h264_vaapi},vb=20000,fps=24,maxwidth=3840,maxheight=2160,acodec=mp3,ab=128,channels=2,threads=0}:std{mux=avformat{mux=matroska,options={live=1},reset-ts},dst=127.0.0.1:9000/my-video.mp4,access=http}", result);
+    }
+
     #[test]
     fn test_vlc_transcoder_find_filename_pattern() {
         init_logger();
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use log::{trace, warn};
+
+use crate::dlna::DlnaError;
+
+/// The wildcard value used within a protocol info field.
+const PROTOCOL_INFO_WILDCARD: &str = "*";
+
+/// Represents a single UPnP `protocolInfo` entry which is supported by a renderer.
+///
+/// The entry has the format `<protocol>:<network>:<contentFormat>:<additionalInfo>`,
+/// e.g. `http-get:*:video/mp4:DLNA.ORG_PN=AVC_MP4_BL_CIF15_AAC_520`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtocolInfo {
+    /// The transport protocol, e.g. `http-get`.
+    pub protocol: String,
+    /// The network on which the protocol is supported.
+    pub network: String,
+    /// The content format of the media, which is the MIME type for `http-get`.
+    pub content_format: String,
+    /// The additional info of the entry, such as the DLNA profile.
+    pub additional_info: String,
+}
+
+impl ProtocolInfo {
+    /// Retrieve the DLNA profile name of this entry, if present.
+    pub fn profile(&self) -> Option<&str> {
+        self.additional_info
+            .split(';')
+            .find_map(|e| e.trim().strip_prefix("DLNA.ORG_PN="))
+    }
+
+    /// Verify if this entry accepts the given MIME type.
+    /// MIME parameters, such as `codecs`, are ignored during the comparison.
+    pub fn matches(&self, mime: &str) -> bool {
+        let mime = normalize_mime(mime);
+        let content_format = normalize_mime(self.content_format.as_str());
+
+        if content_format == PROTOCOL_INFO_WILDCARD {
+            return true;
+        }
+
+        match content_format.strip_suffix("/*") {
+            Some(media_type) => mime
+                .split_once('/')
+                .map(|(e, _)| e == media_type)
+                .unwrap_or(false),
+            None => content_format == mime,
+        }
+    }
+}
+
+impl FromStr for ProtocolInfo {
+    type Err = DlnaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.trim().splitn(4, ':').collect();
+
+        if fields.len() != 4 || fields.iter().take(3).any(|e| e.is_empty()) {
+            return Err(DlnaError::InvalidProtocolInfo(s.to_string()));
+        }
+
+        Ok(Self {
+            protocol: fields[0].to_string(),
+            network: fields[1].to_string(),
+            content_format: fields[2].to_string(),
+            additional_info: fields[3].to_string(),
+        })
+    }
+}
+
+/// The media capabilities of a DLNA renderer, as reported by the `GetProtocolInfo` action
+/// of the `ConnectionManager` service.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RendererCapabilities {
+    /// The protocol info entries which can be rendered by the device.
+    pub sink: Vec<ProtocolInfo>,
+}
+
+impl RendererCapabilities {
+    /// Verify if the renderer is able to play media of the given MIME type over HTTP.
+    ///
+    /// When the renderer didn't report any capabilities, it's assumed that the MIME type is supported.
+    pub fn supports(&self, mime: &str) -> bool {
+        if self.sink.is_empty() {
+            return true;
+        }
+
+        self.sink
+            .iter()
+            .filter(|e| e.protocol == "http-get")
+            .any(|e| e.matches(mime))
+    }
+
+    /// Retrieve the DLNA profiles which are supported by the renderer.
+    pub fn profiles(&self) -> Vec<&str> {
+        self.sink.iter().filter_map(|e| e.profile()).collect()
+    }
+}
+
+impl From<HashMap<String, String>> for RendererCapabilities {
+    fn from(map: HashMap<String, String>) -> Self {
+        let sink = map
+            .get("Sink")
+            .map(|e| parse_protocol_info_list(e.as_str()))
+            .unwrap_or_default();
+
+        trace!("Parsed {} renderer protocol info entries", sink.len());
+        Self { sink }
+    }
+}
+
+/// Parse the given comma separated `protocolInfo` list.
+/// Invalid entries are ignored.
+fn parse_protocol_info_list(value: &str) -> Vec<ProtocolInfo> {
+    value
+        .split(',')
+        .map(|e| e.trim())
+        .filter(|e| !e.is_empty())
+        .filter_map(|e| match ProtocolInfo::from_str(e) {
+            Ok(info) => Some(info),
+            Err(err) => {
+                warn!("Ignoring renderer protocol info, {}", err);
+                None
+            }
+        })
+        .collect()
+}
+
+fn normalize_mime(mime: &str) -> String {
+    mime.split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use popcorn_fx_core::testing::init_logger;
+
+    use super::*;
+
+    #[test]
+    fn test_protocol_info_from_str() {
+        let result =
+            ProtocolInfo::from_str("http-get:*:video/mp4:DLNA.ORG_PN=AVC_MP4_BL_CIF15_AAC_520")
+                .unwrap();
+
+        assert_eq!(
+            ProtocolInfo {
+                protocol: "http-get".to_string(),
+                network: "*".to_string(),
+                content_format: "video/mp4".to_string(),
+                additional_info: "DLNA.ORG_PN=AVC_MP4_BL_CIF15_AAC_520".to_string(),
+            },
+            result
+        );
+        assert_eq!(Some("AVC_MP4_BL_CIF15_AAC_520"), result.profile());
+    }
+
+    #[test]
+    fn test_protocol_info_from_str_invalid() {
+        let result = ProtocolInfo::from_str("http-get:*:video/mp4");
+
+        assert!(
+            result.is_err(),
+            "expected an error to be returned, but got {:?} instead",
+            result
+        );
+    }
+
+    #[test]
+    fn test_protocol_info_matches() {
+        let info = ProtocolInfo::from_str("http-get:*:video/mp4:*").unwrap();
+        assert_eq!(true, info.matches("video/mp4"));
+        assert_eq!(true, info.matches("Video/MP4; codecs=\"avc1.42E01E\""));
+        assert_eq!(false, info.matches("video/x-matroska"));
+
+        let info = ProtocolInfo::from_str("http-get:*:video/*:*").unwrap();
+        assert_eq!(true, info.matches("video/x-matroska"));
+        assert_eq!(false, info.matches("audio/mpeg"));
+
+        let info = ProtocolInfo::from_str("http-get:*:*:*").unwrap();
+        assert_eq!(true, info.matches("audio/mpeg"));
+    }
+
+    #[test]
+    fn test_renderer_capabilities_from_hashmap() {
+        init_logger();
+        let map: HashMap<String, String> = vec![
+            ("Source".to_string(), "".to_string()),
+            ("Sink".to_string(), "http-get:*:video/mp4:DLNA.ORG_PN=AVC_MP4_MP_SD_AAC_MULT5,invalid,http-get:*:video/x-msvideo:*".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = RendererCapabilities::from(map);
+
+        assert_eq!(2, result.sink.len());
+        assert_eq!(vec!["AVC_MP4_MP_SD_AAC_MULT5"], result.profiles());
+    }
+
+    #[test]
+    fn test_renderer_capabilities_supports() {
+        init_logger();
+        let capabilities = RendererCapabilities {
+            sink: parse_protocol_info_list(
+                "http-get:*:video/mp4:*, http-get:*:audio/mpeg:*, rtsp-rtp-udp:*:video/x-matroska:*",
+            ),
+        };
+
+        assert_eq!(true, capabilities.supports("video/mp4"));
+        assert_eq!(true, capabilities.supports("audio/mpeg"));
+        assert_eq!(
+            false,
+            capabilities.supports("video/x-matroska"),
+            "expected only http-get entries to be taken into account"
+        );
+        assert_eq!(false, capabilities.supports("video/webm"));
+    }
+
+    #[test]
+    fn test_renderer_capabilities_supports_unknown() {
+        let capabilities = RendererCapabilities::default();
+
+        assert_eq!(true, capabilities.supports("video/x-matroska"));
+    }
+}
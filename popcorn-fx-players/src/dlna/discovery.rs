@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -13,16 +14,25 @@ use tokio::sync::Mutex;
 use tokio::time;
 use tokio_util::sync::CancellationToken;
 
-use popcorn_fx_core::core::block_in_place;
-use popcorn_fx_core::core::players::PlayerManager;
+use popcorn_fx_core::core::{
+    block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
+};
+use popcorn_fx_core::core::players::{
+    KnownDevice, KnownDeviceType, KnownDevicesCache, Player, PlayerManager,
+};
 use popcorn_fx_core::core::subtitles::SubtitleServer;
 
-use crate::{Discovery, DiscoveryState};
-use crate::dlna::{DlnaError, DlnaPlayer, errors};
+use crate::{Discovery, DiscoveryEvent, DiscoveryState};
+use crate::dlna::{DlnaError, DlnaPlayer, errors, RendererCapabilities};
 
 pub(crate) const SSDP_QUERY_URN: URN = URN::device("schemas-upnp-org", "MediaRenderer", 1);
 pub(crate) const AV_TRANSPORT: URN = URN::service("schemas-upnp-org", "AVTransport", 1);
+pub(crate) const RENDERING_CONTROL: URN = URN::service("schemas-upnp-org", "RenderingControl", 1);
+pub(crate) const CONNECTION_MANAGER: URN = URN::service("schemas-upnp-org", "ConnectionManager", 1);
 const DEFAULT_INTERVAL_SECONDS: u64 = 120;
+const VERIFICATION_TIMEOUT_SECONDS: u64 = 3;
+/// The number of consecutive searches a device can miss before it's considered to be no longer available.
+const EXPIRY_MISSED_SEARCHES: u32 = 2;
 
 /// Represents a DLNA discovery service responsible for discovering DLNA devices within the local network.
 #[derive(Display)]
@@ -39,6 +49,16 @@ impl DlnaDiscovery {
     }
 }
 
+impl Callbacks<DiscoveryEvent> for DlnaDiscovery {
+    fn add(&self, callback: CoreCallback<DiscoveryEvent>) -> CallbackHandle {
+        self.inner.callbacks.add(callback)
+    }
+
+    fn remove(&self, handle: CallbackHandle) {
+        self.inner.callbacks.remove(handle)
+    }
+}
+
 #[async_trait]
 impl Discovery for DlnaDiscovery {
     fn state(&self) -> DiscoveryState {
@@ -53,6 +73,7 @@ impl Discovery for DlnaDiscovery {
             let inner = self.inner.clone();
             self.runtime.spawn(async move {
                 inner.update_state(DiscoveryState::Running);
+                inner.register_known_devices().await;
                 loop {
                     if inner.cancel_token.is_cancelled() {
                         break;
@@ -99,6 +120,7 @@ impl Drop for DlnaDiscovery {
 pub struct DlnaDiscoveryBuilder {
     player_manager: Option<Arc<Box<dyn PlayerManager>>>,
     subtitle_server: Option<Arc<SubtitleServer>>,
+    known_devices: Option<Arc<KnownDevicesCache>>,
     runtime: Option<Arc<Runtime>>,
     interval_seconds: Option<u64>,
 }
@@ -133,6 +155,12 @@ impl DlnaDiscoveryBuilder {
         self
     }
 
+    /// Sets the known devices cache which is used to register previously discovered devices on startup.
+    pub fn known_devices(mut self, known_devices: Arc<KnownDevicesCache>) -> Self {
+        self.known_devices = Some(known_devices);
+        self
+    }
+
     /// Builds the DLNA discovery instance.
     ///
     /// # Panics
@@ -154,7 +182,9 @@ impl DlnaDiscoveryBuilder {
                 subtitle_server: self
                     .subtitle_server
                     .expect("expected a subtitle server to have been set"),
+                known_devices: self.known_devices,
                 state: Mutex::new(DiscoveryState::Stopped),
+                callbacks: Default::default(),
                 cancel_token: Default::default(),
             }),
             runtime,
@@ -162,12 +192,24 @@ impl DlnaDiscoveryBuilder {
     }
 }
 
+/// A DLNA device which has been discovered on the network.
+#[derive(Debug, Clone, Default)]
+struct DiscoveredDevice {
+    /// The id of the registered player, if the device supports media playback.
+    player_id: Option<String>,
+    /// The number of consecutive searches to which the device didn't respond.
+    missed_searches: u32,
+}
+
 struct InnerDlnaDiscovery {
     interval_seconds: u64,
     player_manager: Arc<Box<dyn PlayerManager>>,
-    discovered_devices: Mutex<Vec<String>>,
+    /// The discovered devices, stored by their description location.
+    discovered_devices: Mutex<HashMap<String, DiscoveredDevice>>,
     subtitle_server: Arc<SubtitleServer>,
+    known_devices: Option<Arc<KnownDevicesCache>>,
     state: Mutex<DiscoveryState>,
+    callbacks: CoreCallbacks<DiscoveryEvent>,
     cancel_token: CancellationToken,
 }
 
@@ -201,24 +243,81 @@ impl InnerDlnaDiscovery {
         .map_err(|e| DlnaError::Discovery(e.to_string()))?;
 
         trace!("Received DLNA device responses");
+        self.start_search_cycle().await;
         while let Some(response) = responses.next().await {
             if let Err(e) = self.handle_response(response).await {
                 warn!("Failed to handle DLNA device response, {}", e);
             }
         }
+        self.remove_expired_devices().await;
 
         Ok(())
     }
 
+    /// Mark the start of a new search cycle, increasing the missed searches of all discovered devices.
+    /// The counter is reset for each device which responds during the search.
+    async fn start_search_cycle(&self) {
+        let mut mutex = self.discovered_devices.lock().await;
+        for device in mutex.values_mut() {
+            device.missed_searches += 1;
+        }
+    }
+
+    /// Remove the discovered devices which didn't respond to the last [EXPIRY_MISSED_SEARCHES] searches.
+    /// The players of these devices are unregistered from the player manager.
+    async fn remove_expired_devices(&self) {
+        let mut expired_devices = vec![];
+
+        {
+            let mut mutex = self.discovered_devices.lock().await;
+            mutex.retain(|location, device| {
+                if device.missed_searches >= EXPIRY_MISSED_SEARCHES {
+                    expired_devices.push((location.clone(), device.clone()));
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        for (location, device) in expired_devices {
+            info!("DLNA device {} is no longer available", location);
+            if let Some(player_id) = device.player_id {
+                self.player_manager.remove_player(player_id.as_str());
+                self.callbacks
+                    .invoke(DiscoveryEvent::DeviceRemoved(player_id));
+            }
+        }
+    }
+
+    /// Mark the device at the given location as seen during the current search.
+    ///
+    /// It returns `true` when the device was already discovered, else `false`.
+    async fn mark_seen(&self, location: &str) -> bool {
+        let mut mutex = self.discovered_devices.lock().await;
+        match mutex.get_mut(location) {
+            Some(device) => {
+                device.missed_searches = 0;
+                true
+            }
+            None => false,
+        }
+    }
+
     async fn handle_response(&self, response: Result<SearchResponse, Error>) -> errors::Result<()> {
         trace!("Received DLNA response {:?}", response);
-        let uri = response
+        let uri: rupnp::http::Uri = response
             .map_err(|e| DlnaError::Device(e.to_string()))
             .and_then(|e| {
                 e.location()
                     .parse()
                     .map_err(|err: InvalidUri| DlnaError::Uri(err.to_string()))
             })?;
+        if self.mark_seen(uri.to_string().as_str()).await {
+            trace!("DLNA device {} has already been discovered", uri);
+            return Ok(());
+        }
+
         debug!("Requesting DLNA device info from {}", uri);
         let device = Device::from_url(uri)
             .await
@@ -238,26 +337,138 @@ impl InnerDlnaDiscovery {
 
     async fn is_already_discovered(&self, device: &Device) -> bool {
         let mutex = self.discovered_devices.lock().await;
-        mutex.contains(&device.url().to_string())
+        mutex.contains_key(&device.url().to_string())
+    }
+
+    /// Register the previously discovered DLNA devices from the known devices cache.
+    /// The device description is retrieved from the last known location, which also verifies that the device is still reachable.
+    async fn register_known_devices(&self) {
+        let known_devices = match self.known_devices.as_ref() {
+            Some(e) => e.clone(),
+            None => return,
+        };
+
+        for known_device in known_devices.devices(&KnownDeviceType::Dlna) {
+            let uri = match known_device
+                .location
+                .as_ref()
+                .and_then(|e| e.parse::<rupnp::http::Uri>().ok())
+            {
+                Some(e) => e,
+                None => {
+                    warn!(
+                        "Known DLNA device {} has no valid location",
+                        known_device.id
+                    );
+                    known_devices.forget(known_device.id.as_str());
+                    continue;
+                }
+            };
+
+            debug!("Verifying known DLNA device {} at {}", known_device.id, uri);
+            match time::timeout(
+                Duration::from_secs(VERIFICATION_TIMEOUT_SECONDS),
+                Device::from_url(uri),
+            )
+            .await
+            {
+                Ok(Ok(device)) => {
+                    if !self.is_already_discovered(&device).await {
+                        self.add_player(device).await;
+                    }
+                }
+                _ => {
+                    warn!("Known DLNA device {} is unreachable", known_device.id);
+                    known_devices.verification_failed(known_device.id.as_str());
+                }
+            }
+        }
     }
 
     async fn add_player(&self, device: Device) {
         let name = device.friendly_name().to_string();
         let device_url = device.url().to_string();
+        let mut player_id = None;
 
         if let Some(service) = device.find_service(&AV_TRANSPORT).cloned() {
+            let address = device.url().host().unwrap_or_default().to_string();
+            let port = device.url().port_u16().unwrap_or(80);
+            let capabilities = Self::capabilities(&device).await;
             trace!("Creating new player from {:?}", device);
-            let player = DlnaPlayer::new(device, service, self.subtitle_server.clone());
+            let player =
+                DlnaPlayer::new(device, service, capabilities, self.subtitle_server.clone());
+            let id = player.id().to_string();
 
             trace!("Adding new DLNA player {:?}", player);
-            self.player_manager.add_player(Box::new(player));
-            info!("Registered new DLNA player {}", name);
+            if self.player_manager.add_player(Box::new(player)) {
+                info!("Registered new DLNA player {}", name);
+                self.callbacks
+                    .invoke(DiscoveryEvent::DeviceAdded(id.clone()));
+            }
+            player_id = Some(id.clone());
+
+            if let Some(known_devices) = self.known_devices.as_ref() {
+                known_devices.update(KnownDevice {
+                    id,
+                    name,
+                    device_type: KnownDeviceType::Dlna,
+                    address,
+                    port,
+                    model: None,
+                    location: Some(device_url.clone()),
+                    last_seen: 0,
+                    failed_verifications: 0,
+                });
+            }
         } else {
             info!("DLNA device {} doesn't support AV transport service", name)
         }
 
         let mut mutex = self.discovered_devices.lock().await;
-        mutex.push(device_url);
+        mutex.insert(
+            device_url,
+            DiscoveredDevice {
+                player_id,
+                missed_searches: 0,
+            },
+        );
+    }
+
+    /// Retrieve the media capabilities of the given device through the `ConnectionManager` service.
+    /// Devices which don't support the service, or fail to report their protocol info, will have unknown capabilities.
+    async fn capabilities(device: &Device) -> RendererCapabilities {
+        let name = device.friendly_name();
+
+        match device.find_service(&CONNECTION_MANAGER) {
+            Some(service) => {
+                trace!("Requesting DLNA device {} protocol info", name);
+                match service.action(device.url(), "GetProtocolInfo", "").await {
+                    Ok(response) => {
+                        let capabilities = RendererCapabilities::from(response);
+                        debug!(
+                            "DLNA device {} supports {} protocol info entries",
+                            name,
+                            capabilities.sink.len()
+                        );
+                        capabilities
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to retrieve DLNA device {} protocol info, {}",
+                            name, e
+                        );
+                        RendererCapabilities::default()
+                    }
+                }
+            }
+            None => {
+                debug!(
+                    "DLNA device {} doesn't support connection manager service",
+                    name
+                );
+                RendererCapabilities::default()
+            }
+        }
     }
 }
 
@@ -265,7 +476,7 @@ impl InnerDlnaDiscovery {
 mod tests {
     use std::sync::mpsc::channel;
 
-    use httpmock::Method::GET;
+    use httpmock::Method::{GET, POST};
     use httpmock::MockServer;
 
     use popcorn_fx_core::assert_timeout;
@@ -273,7 +484,10 @@ mod tests {
     use popcorn_fx_core::core::subtitles::MockSubtitleProvider;
     use popcorn_fx_core::testing::init_logger;
 
-    use crate::dlna::tests::{DEFAULT_SSDP_DESCRIPTION_RESPONSE, MockUdpServer};
+    use crate::dlna::tests::{
+        DEFAULT_PROTOCOL_INFO_RESPONSE, DEFAULT_SSDP_DESCRIPTION_RESPONSE, MockUdpServer,
+        NO_AV_TRANSPORT_DESCRIPTION_RESPONSE,
+    };
 
     use super::*;
 
@@ -339,12 +553,263 @@ mod tests {
         assert_eq!("test", player.name());
     }
 
+    #[test]
+    fn test_add_player_capabilities() {
+        init_logger();
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/description.xml");
+            then.status(200)
+                .header("Content-Type", "text/xml")
+                .body(DEFAULT_SSDP_DESCRIPTION_RESPONSE);
+        });
+        server.mock(|when, then| {
+            when.method(POST).path("/ConnectionManager/control").header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:ConnectionManager:1#GetProtocolInfo\"",
+            );
+            then.status(200)
+                .header("Content-Type", "text/xml; charset=\"utf-8\"")
+                .body(DEFAULT_PROTOCOL_INFO_RESPONSE);
+        });
+        let (tx, rx) = channel();
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().returning(move |e| {
+            if let Ok(player) = e.downcast::<DlnaPlayer>() {
+                tx.send(player).unwrap();
+            }
+
+            true
+        });
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = DlnaDiscovery::builder()
+            .runtime(runtime.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .subtitle_server(subtitle_server)
+            .build();
+        let addr = format!("http://{}/description.xml", server.address());
+        let device = runtime
+            .block_on(Device::from_url(addr.parse().unwrap()))
+            .unwrap();
+
+        runtime.block_on(discovery.inner.add_player(device));
+
+        let player = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(5, player.capabilities().sink.len());
+        assert_eq!(true, player.supports("video/mp4"));
+        assert_eq!(true, player.supports("video/x-msvideo"));
+        assert_eq!(false, player.supports("video/x-matroska"));
+    }
+
+    #[test]
+    fn test_add_player_without_av_transport() {
+        init_logger();
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/description.xml");
+            then.status(200)
+                .header("Content-Type", "text/xml")
+                .body(NO_AV_TRANSPORT_DESCRIPTION_RESPONSE);
+        });
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().times(0);
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = DlnaDiscovery::builder()
+            .runtime(runtime.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .subtitle_server(subtitle_server)
+            .build();
+        let addr = format!("http://{}/description.xml", server.address());
+        let device = runtime
+            .block_on(Device::from_url(addr.parse().unwrap()))
+            .unwrap();
+
+        runtime.block_on(discovery.inner.add_player(device));
+
+        let discovered_devices = runtime.block_on(discovery.inner.discovered_devices.lock());
+        assert_eq!(
+            1,
+            discovered_devices.len(),
+            "expected the device to have been marked as discovered"
+        );
+    }
+
+    #[test]
+    fn test_remove_expired_devices() {
+        init_logger();
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/description.xml");
+            then.status(200)
+                .header("Content-Type", "text/xml")
+                .body(DEFAULT_SSDP_DESCRIPTION_RESPONSE);
+        });
+        let (tx_removed, rx_removed) = channel();
+        let (tx_event, rx_event) = channel();
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().return_const(true);
+        player_manager
+            .expect_remove_player()
+            .times(1)
+            .returning(move |id| tx_removed.send(id.to_string()).unwrap());
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = DlnaDiscovery::builder()
+            .runtime(runtime.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .subtitle_server(subtitle_server)
+            .build();
+        let addr = format!("http://{}/description.xml", server.address());
+        let device = runtime
+            .block_on(Device::from_url(addr.parse().unwrap()))
+            .unwrap();
+        let device_url = device.url().to_string();
+
+        discovery.add(Box::new(move |event| {
+            if let DiscoveryEvent::DeviceRemoved(id) = event {
+                tx_event.send(id).unwrap();
+            }
+        }));
+        runtime.block_on(async {
+            discovery.inner.add_player(device).await;
+
+            discovery.inner.start_search_cycle().await;
+            assert_eq!(
+                true,
+                discovery.inner.mark_seen(device_url.as_str()).await,
+                "expected the device to have been discovered"
+            );
+            discovery.inner.remove_expired_devices().await;
+
+            discovery.inner.start_search_cycle().await;
+            discovery.inner.remove_expired_devices().await;
+            assert_eq!(
+                1,
+                discovery.inner.discovered_devices.lock().await.len(),
+                "expected the device to be kept after a single missed search"
+            );
+
+            discovery.inner.start_search_cycle().await;
+            discovery.inner.remove_expired_devices().await;
+        });
+
+        let result = rx_removed.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("[urn:schemas-upnp-org:device:MediaRenderer:1]test", result);
+        let result = rx_event.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("[urn:schemas-upnp-org:device:MediaRenderer:1]test", result);
+        assert_eq!(
+            0,
+            runtime.block_on(discovery.inner.discovered_devices.lock()).len(),
+            "expected the device to have been removed"
+        );
+    }
+
+    #[test]
+    fn test_register_known_devices() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/description.xml");
+            then.status(200)
+                .header("Content-Type", "text/xml")
+                .body(DEFAULT_SSDP_DESCRIPTION_RESPONSE);
+        });
+        let location = format!("http://{}/description.xml", server.address());
+        let known_devices = Arc::new(KnownDevicesCache::new(temp_path));
+        known_devices.update(KnownDevice {
+            id: "[urn:schemas-upnp-org:device:MediaRenderer:1]test".to_string(),
+            name: "test".to_string(),
+            device_type: KnownDeviceType::Dlna,
+            address: "127.0.0.1".to_string(),
+            port: server.port(),
+            model: None,
+            location: Some(location.clone()),
+            last_seen: 0,
+            failed_verifications: 0,
+        });
+        let (tx, rx) = channel();
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().returning(move |e| {
+            tx.send(e.id().to_string()).unwrap();
+            true
+        });
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = DlnaDiscovery::builder()
+            .runtime(runtime.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .subtitle_server(subtitle_server)
+            .known_devices(known_devices.clone())
+            .build();
+
+        runtime.block_on(discovery.inner.register_known_devices());
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!("[urn:schemas-upnp-org:device:MediaRenderer:1]test", result);
+        let result = known_devices.devices(&KnownDeviceType::Dlna);
+        assert_eq!(1, result.len());
+        assert_eq!(Some(location), result[0].location);
+    }
+
+    #[test]
+    fn test_register_known_devices_unreachable() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let known_devices = Arc::new(KnownDevicesCache::new(temp_path));
+        known_devices.update(KnownDevice {
+            id: "MyUnreachableDevice".to_string(),
+            name: "unreachable".to_string(),
+            device_type: KnownDeviceType::Dlna,
+            address: "127.0.0.1".to_string(),
+            port: 1,
+            model: None,
+            location: Some("http://127.0.0.1:1/description.xml".to_string()),
+            last_seen: 0,
+            failed_verifications: 0,
+        });
+        let mut player_manager = MockPlayerManager::new();
+        player_manager.expect_add_player().times(0);
+        let subtitle_provider = MockSubtitleProvider::new();
+        let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
+        let discovery = DlnaDiscovery::builder()
+            .runtime(runtime.clone())
+            .player_manager(Arc::new(Box::new(player_manager)))
+            .subtitle_server(subtitle_server)
+            .known_devices(known_devices.clone())
+            .build();
+
+        runtime.block_on(discovery.inner.register_known_devices());
+        assert_eq!(
+            1,
+            known_devices.devices(&KnownDeviceType::Dlna).len(),
+            "expected the device to be kept after the first failed verification"
+        );
+
+        runtime.block_on(discovery.inner.register_known_devices());
+        assert_eq!(
+            0,
+            known_devices.devices(&KnownDeviceType::Dlna).len(),
+            "expected the device to have been dropped after the second failed verification"
+        );
+    }
+
     #[test]
     fn test_stop_discovery() {
         init_logger();
         let runtime = Arc::new(Runtime::new().unwrap());
         let mut player_manager = MockPlayerManager::new();
         player_manager.expect_add_player().return_const(true);
+        player_manager.expect_remove_player().return_const(());
         let subtitle_provider = MockSubtitleProvider::new();
         let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
         let server = DlnaDiscovery::builder()
@@ -20,6 +20,9 @@ pub enum DlnaError {
     /// Indicates an invalid device URI.
     #[error("invalid device URI: {0}")]
     Uri(String),
+    /// Indicates an invalid protocol info entry reported by a renderer.
+    #[error("protocol info is invalid: {0}")]
+    InvalidProtocolInfo(String),
     /// Indicates command for the device service failed.
     #[error("failed to execute service command")]
     ServiceCommand,
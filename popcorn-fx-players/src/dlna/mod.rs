@@ -1,7 +1,9 @@
+pub use capabilities::*;
 pub use discovery::*;
 pub use errors::*;
 pub use player::*;
 
+mod capabilities;
 mod discovery;
 mod errors;
 mod models;
@@ -42,10 +44,66 @@ mod tests {
                     <eventSubURL>/AVTransport/event</eventSubURL>
                     <SCPDURL>/AVTransport/scpd.xml</SCPDURL>
                   </service>
+                  <service>
+                    <serviceType>urn:schemas-upnp-org:service:RenderingControl:1</serviceType>
+                    <serviceId>urn:upnp-org:serviceId:RenderingControl</serviceId>
+                    <controlURL>/RenderingControl/control</controlURL>
+                    <eventSubURL>/RenderingControl/event</eventSubURL>
+                    <SCPDURL>/RenderingControl/scpd.xml</SCPDURL>
+                  </service>
+                  <service>
+                    <serviceType>urn:schemas-upnp-org:service:ConnectionManager:1</serviceType>
+                    <serviceId>urn:upnp-org:serviceId:ConnectionManager</serviceId>
+                    <controlURL>/ConnectionManager/control</controlURL>
+                    <eventSubURL>/ConnectionManager/event</eventSubURL>
+                    <SCPDURL>/ConnectionManager/scpd.xml</SCPDURL>
+                  </service>
+                </serviceList>
+            </device>
+        </root>"#;
+
+    pub const NO_AV_TRANSPORT_DESCRIPTION_RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <root xmlns="urn:schemas-upnp-org:device-1-0">
+            <specVersion>
+                <major>1</major>
+                <minor>0</minor>
+            </specVersion>
+            <device>
+                <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+                <friendlyName>speaker</friendlyName>
+                <manufacturer>MediaTech Inc.</manufacturer>
+                <modelName>SP-100</modelName>
+                <UDN>uuid:12345678-1234-1234-1234-123456789012</UDN>
+                <serviceList>
+                  <service>
+                    <serviceType>urn:schemas-upnp-org:service:RenderingControl:1</serviceType>
+                    <serviceId>urn:upnp-org:serviceId:RenderingControl</serviceId>
+                    <controlURL>/RenderingControl/control</controlURL>
+                    <eventSubURL>/RenderingControl/event</eventSubURL>
+                    <SCPDURL>/RenderingControl/scpd.xml</SCPDURL>
+                  </service>
+                  <service>
+                    <serviceType>urn:schemas-upnp-org:service:ConnectionManager:1</serviceType>
+                    <serviceId>urn:upnp-org:serviceId:ConnectionManager</serviceId>
+                    <controlURL>/ConnectionManager/control</controlURL>
+                    <eventSubURL>/ConnectionManager/event</eventSubURL>
+                    <SCPDURL>/ConnectionManager/scpd.xml</SCPDURL>
+                  </service>
                 </serviceList>
             </device>
         </root>"#;
 
+    pub const DEFAULT_PROTOCOL_INFO_RESPONSE: &str = r#"<?xml version="1.0"?>
+        <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"
+                    s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/">
+          <s:Body>
+            <u:GetProtocolInfoResponse xmlns:u="urn:schemas-upnp-org:service:ConnectionManager:1">
+              <Source></Source>
+              <Sink>http-get:*:video/mp4:DLNA.ORG_PN=AVC_MP4_BL_CIF15_AAC_520;DLNA.ORG_OP=01,http-get:*:video/mpeg:DLNA.ORG_PN=MPEG_PS_PAL,http-get:*:video/x-msvideo:*,http-get:*:audio/mpeg:DLNA.ORG_PN=MP3,http-get:*:text/srt:*</Sink>
+            </u:GetProtocolInfoResponse>
+          </s:Body>
+        </s:Envelope>"#;
+
     pub struct SsdpServer {
         inner: Arc<InnerSsdpServer>,
     }
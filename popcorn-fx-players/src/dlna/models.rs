@@ -5,6 +5,9 @@ use popcorn_fx_core::core::players::PlayerState;
 
 use crate::dlna;
 
+/// The value returned by renderers for state variables which they don't support.
+const UPNP_NOT_IMPLEMENTED: &str = "NOT_IMPLEMENTED";
+
 /// Represents an event received from UPnP.
 #[derive(Debug, PartialEq)]
 pub enum UpnpEvent {
@@ -12,6 +15,8 @@ pub enum UpnpEvent {
     Time(PositionInfo),
     /// An event containing transport information.
     State(TransportInfo),
+    /// An event containing volume information.
+    Volume(VolumeInfo),
 }
 
 /// Represents position information received from UPnP.
@@ -46,13 +51,32 @@ impl From<HashMap<String, String>> for PositionInfo {
                 .map(|e| e.parse().unwrap_or(-1))
                 .unwrap_or(-1),
             rel_time: map.get("RelTime").cloned().unwrap_or_default(),
-            track: map.get("Track").unwrap().parse().unwrap(),
+            track: map
+                .get("Track")
+                .and_then(|e| e.parse().ok())
+                .unwrap_or_default(),
             track_metadata: map.get("TrackMetaData").cloned().unwrap_or_default(),
             track_duration: map.get("TrackDuration").cloned().unwrap_or_default(),
         }
     }
 }
 
+impl PositionInfo {
+    /// Retrieve the current playback time of the track in millis.
+    ///
+    /// It returns [None] when the renderer didn't report a valid time.
+    pub fn time(&self) -> Option<u64> {
+        parse_upnp_time(self.rel_time.as_str())
+    }
+
+    /// Retrieve the duration of the track in millis.
+    ///
+    /// It returns [None] when the renderer didn't report a valid duration.
+    pub fn duration(&self) -> Option<u64> {
+        parse_upnp_time(self.track_duration.as_str()).filter(|e| *e > 0)
+    }
+}
+
 /// Represents transport information received from UPnP.
 #[derive(Debug, PartialEq)]
 pub struct TransportInfo {
@@ -77,6 +101,24 @@ impl From<HashMap<String, String>> for TransportInfo {
     }
 }
 
+/// Represents volume information received from UPnP.
+#[derive(Debug, PartialEq)]
+pub struct VolumeInfo {
+    /// The current volume level.
+    pub current_volume: u32,
+}
+
+impl From<HashMap<String, String>> for VolumeInfo {
+    fn from(map: HashMap<String, String>) -> Self {
+        Self {
+            current_volume: map
+                .get("CurrentVolume")
+                .and_then(|e| e.parse().ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
 /// Represents the state of a UPnP instance.
 #[derive(Debug, PartialEq)]
 pub enum UpnpState {
@@ -122,6 +164,49 @@ impl From<&UpnpState> for PlayerState {
     }
 }
 
+/// Format the given time in millis to the `H+:MM:SS` notation expected by UPnP AVTransport actions.
+pub fn format_upnp_time(time: u64) -> String {
+    let seconds = time / 1000;
+
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// Parse the given UPnP time notation `H+:MM:SS[.F+]` into millis.
+///
+/// It returns [None] when the renderer reported the value as `NOT_IMPLEMENTED` or when the value is invalid.
+pub fn parse_upnp_time(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if value.is_empty() || value == UPNP_NOT_IMPLEMENTED {
+        return None;
+    }
+
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let (time, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let mut parts = time.split(':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: u64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || minutes > 59 || seconds > 59 {
+        return None;
+    }
+
+    // the fraction is either given as decimal digits or as F0/F1, the latter is ignored
+    let millis = if !fraction.is_empty() && fraction.chars().all(|e| e.is_ascii_digit()) {
+        format!("{:0<3}", &fraction[..fraction.len().min(3)])
+            .parse::<u64>()
+            .unwrap_or_default()
+    } else {
+        0
+    };
+
+    Some((((hours * 60) + minutes) * 60 + seconds) * 1000 + millis)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +243,42 @@ mod tests {
         assert_eq!(expected_result, result);
     }
 
+    #[test]
+    fn test_position_info_not_implemented() {
+        let map: HashMap<String, String> = vec![
+            ("RelTime".to_string(), UPNP_NOT_IMPLEMENTED.to_string()),
+            ("TrackDuration".to_string(), "0:00:00".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = PositionInfo::from(map);
+
+        assert_eq!(None, result.time());
+        assert_eq!(None, result.duration());
+    }
+
+    #[test]
+    fn test_format_upnp_time() {
+        assert_eq!("0:00:14", format_upnp_time(14000));
+        assert_eq!("0:05:32", format_upnp_time(332500));
+        assert_eq!("1:20:45", format_upnp_time(4845000));
+        assert_eq!("26:00:00", format_upnp_time(93600000));
+    }
+
+    #[test]
+    fn test_parse_upnp_time() {
+        assert_eq!(Some(135000), parse_upnp_time("00:02:15"));
+        assert_eq!(Some(4845000), parse_upnp_time("1:20:45"));
+        assert_eq!(Some(12500), parse_upnp_time("0:00:12.5"));
+        assert_eq!(Some(12000), parse_upnp_time("0:00:12.1/3"));
+        assert_eq!(Some(93600000), parse_upnp_time("26:00:00"));
+        assert_eq!(None, parse_upnp_time(UPNP_NOT_IMPLEMENTED));
+        assert_eq!(None, parse_upnp_time(""));
+        assert_eq!(None, parse_upnp_time("00:19"));
+        assert_eq!(None, parse_upnp_time("00:61:00"));
+    }
+
     #[test]
     fn test_transport_info_from_hashmap() {
         let status = "OK";
@@ -179,6 +300,18 @@ mod tests {
         assert_eq!(expected_result, result);
     }
 
+    #[test]
+    fn test_volume_info_from_hashmap() {
+        let map: HashMap<String, String> = vec![("CurrentVolume".to_string(), "35".to_string())]
+            .into_iter()
+            .collect();
+        let expected_result = VolumeInfo { current_volume: 35 };
+
+        let result = VolumeInfo::from(map);
+
+        assert_eq!(expected_result, result);
+    }
+
     #[test]
     fn test_upnp_state_from_str() {
         let result = UpnpState::from_str("STOPPED").unwrap();
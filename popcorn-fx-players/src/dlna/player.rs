@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use derive_more::Display;
-use log::{debug, error, trace};
+use log::{debug, error, trace, warn};
 use rupnp::{Device, Service};
 use tokio::runtime::Runtime;
 use tokio::sync::mpsc::error::SendError;
@@ -18,15 +18,13 @@ use xml::escape::escape_str_attribute;
 use popcorn_fx_core::core::players::{PlayRequest, Player, PlayerEvent, PlayerState};
 use popcorn_fx_core::core::subtitles::model::SubtitleType;
 use popcorn_fx_core::core::subtitles::SubtitleServer;
-use popcorn_fx_core::core::utils::time::{
-    parse_millis_from_time, parse_str_from_time, parse_time_from_millis, parse_time_from_str,
-};
 use popcorn_fx_core::core::{
     block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
 };
 
 use crate::dlna;
-use crate::dlna::models::{PositionInfo, TransportInfo, UpnpEvent};
+use crate::dlna::models::{format_upnp_time, PositionInfo, TransportInfo, UpnpEvent, VolumeInfo};
+use crate::dlna::{RendererCapabilities, RENDERING_CONTROL};
 
 const DLNA_GRAPHIC_RESOURCE: &[u8] = include_bytes!("../../resources/external-dlna-icon.png");
 const DLNA_PLAYER_DESCRIPTION: &str = "DLNA Player";
@@ -54,6 +52,8 @@ pub struct DlnaPlayer {
 
 impl DlnaPlayer {
     /// Creates a new DLNA player instance for the give UPnP [Device] and [Service].
+    /// The volume of the player can only be controlled when the device supports the `RenderingControl` service.
+    /// The given [RendererCapabilities] are used to verify which media types can be played by the device.
     ///
     /// # Example
     ///
@@ -62,7 +62,8 @@ impl DlnaPlayer {
     /// ```rust,no_run
     /// use rupnp::Device;
     /// use ssdp_client::SearchTarget::URN;
-    /// use popcorn_fx_players::dlna::DlnaPlayer;
+    /// use popcorn_fx_core::core::subtitles::SubtitleServer;
+    /// use popcorn_fx_players::dlna::{DlnaPlayer, RendererCapabilities};
     /// use std::sync::Arc;
     ///
     /// #[tokio::main]
@@ -72,10 +73,17 @@ impl DlnaPlayer {
     ///     let device = Device::from_url(uri).await.unwrap();
     ///     let service = device.find_service(service_uri).unwrap().clone();
     ///
-    ///     let player = DlnaPlayer::new(device, service);
+    ///     let subtitle_server: Arc<SubtitleServer> = todo!();
+    ///
+    ///     let player = DlnaPlayer::new(device, service, RendererCapabilities::default(), subtitle_server);
     /// }
     /// ```
-    pub fn new(device: Device, service: Service, subtitle_server: Arc<SubtitleServer>) -> Self {
+    pub fn new(
+        device: Device,
+        service: Service,
+        capabilities: RendererCapabilities,
+        subtitle_server: Arc<SubtitleServer>,
+    ) -> Self {
         let name = device.friendly_name().to_string();
         let id = format!("[{}]{}", device.device_type(), name);
         let (tx, mut rx) = channel(10);
@@ -85,16 +93,20 @@ impl DlnaPlayer {
             .thread_name(format!("dlna-{}", name))
             .build()
             .expect("expected a new runtime");
+        let rendering_control = device.find_service(&RENDERING_CONTROL).cloned();
         let instance = Arc::new(InnerPlayer {
             id,
             device,
             service,
+            rendering_control,
+            capabilities,
             event_sender: tx,
             request: Default::default(),
             playback_state: Default::default(),
             subtitle_server,
             callbacks: Default::default(),
             event_poller_activated: Default::default(),
+            position_info_supported: Mutex::new(true),
             cancellation_token: Default::default(),
             runtime,
         });
@@ -109,6 +121,7 @@ impl DlnaPlayer {
                             match event {
                                 UpnpEvent::Time(e) => inner_instance.handle_time_event(e).await,
                                 UpnpEvent::State(e) => inner_instance.handle_state_event(e).await,
+                                UpnpEvent::Volume(e) => inner_instance.handle_volume_event(e).await,
                             }
                         } else {
                             break;
@@ -137,6 +150,11 @@ impl DlnaPlayer {
 
         Self { inner: instance }
     }
+
+    /// Retrieve the media capabilities of the renderer.
+    pub fn capabilities(&self) -> &RendererCapabilities {
+        &self.inner.capabilities
+    }
 }
 
 impl Callbacks<PlayerEvent> for DlnaPlayer {
@@ -171,11 +189,11 @@ impl Player for DlnaPlayer {
         self.inner.state()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         self.inner.request()
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, request: PlayRequest) {
         self.inner.play(request).await
     }
 
@@ -194,6 +212,18 @@ impl Player for DlnaPlayer {
     fn stop(&self) {
         self.inner.stop()
     }
+
+    fn set_volume(&self, volume: u32) {
+        self.inner.set_volume(volume)
+    }
+
+    fn set_muted(&self, muted: bool) {
+        self.inner.set_muted(muted)
+    }
+
+    fn supports(&self, mime: &str) -> bool {
+        self.inner.capabilities.supports(mime)
+    }
 }
 
 #[derive(Debug, Display)]
@@ -202,29 +232,34 @@ struct InnerPlayer {
     id: String,
     device: Device,
     service: Service,
+    rendering_control: Option<Service>,
+    capabilities: RendererCapabilities,
     event_sender: Sender<UpnpEvent>,
-    request: Mutex<Option<Arc<Box<dyn PlayRequest>>>>,
+    request: Mutex<Option<Arc<PlayRequest>>>,
     playback_state: Mutex<PlaybackState>,
     subtitle_server: Arc<SubtitleServer>,
     callbacks: CoreCallbacks<PlayerEvent>,
     event_poller_activated: Mutex<bool>,
+    /// Indicates if the renderer supports the `GetPositionInfo` action
+    position_info_supported: Mutex<bool>,
     cancellation_token: CancellationToken,
     runtime: Runtime,
 }
 
 impl InnerPlayer {
-    fn handle_subtitle(&self, request: &Box<dyn PlayRequest>) -> (String, String) {
+    fn handle_subtitle(&self, request: &mut PlayRequest) -> (String, String) {
         let mut subtitle_attributes = String::new();
         let mut video_resource_attributes = String::new();
 
-        if let Some(subtitle) = request.subtitle() {
+        if let Some(subtitle) = request.subtitle().cloned() {
             trace!("Trying to serve DLNA subtitle {} for {}", subtitle.file(), request.url());
             match self
                 .subtitle_server
-                .serve(subtitle.clone(), UPNP_PLAYER_SUBTITLE_FORMAT)
+                .serve(subtitle, UPNP_PLAYER_SUBTITLE_FORMAT)
             {
                 Ok(subtitle_url) => {
                     debug!("Serving DLNA subtitle at {}", subtitle_url);
+                    request.subtitle.url = Some(subtitle_url.clone());
                     subtitle_attributes = format!(
                         r#"<res protocolInfo="http-get:*:text/{subtitle_type}:*">{subtitle_uri}</res>
                            <res protocolInfo="http-get:*:smi/caption:*">{subtitle_uri}</res>
@@ -246,10 +281,6 @@ impl InnerPlayer {
         return (subtitle_attributes, video_resource_attributes);
     }
 
-    fn update_state(&self, state: PlayerState) {
-        block_in_place(self.update_state_async(state))
-    }
-
     async fn update_state_async(&self, state: PlayerState) {
         {
             let mut mutex = self.playback_state.lock().await;
@@ -280,6 +311,22 @@ impl InnerPlayer {
         &self,
         action: &str,
         payload: &str,
+    ) -> dlna::Result<HashMap<String, String>> {
+        match self.execute_query_action(action, payload).await {
+            Ok(e) => Ok(e),
+            Err(e) => {
+                self.update_state_async(PlayerState::Error).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Execute the given AVTransport action without changing the player state when it fails.
+    /// This should be used for informational actions which are optional for renderers.
+    async fn execute_query_action(
+        &self,
+        action: &str,
+        payload: &str,
     ) -> dlna::Result<HashMap<String, String>> {
         trace!("Executing UPnP {} command with payload {}", action, payload);
         self.service
@@ -291,20 +338,54 @@ impl InnerPlayer {
             })
             .map_err(|e| {
                 error!("Failed to execute {} UPnP action, {}", action, e);
-                self.update_state(PlayerState::Error);
                 dlna::DlnaError::ServiceCommand
             })
     }
 
-    async fn poll_event_info(&self) {
-        if let Ok(info) = self
-            .execute_action("GetPositionInfo", UPNP_PLAYER_POSITION_PAYLOAD)
+    async fn execute_rendering_action(
+        &self,
+        action: &str,
+        payload: &str,
+    ) -> dlna::Result<HashMap<String, String>> {
+        let service = self.rendering_control.as_ref().ok_or_else(|| {
+            debug!("UPnP device {} doesn't support rendering control", self.id);
+            dlna::DlnaError::ServiceCommand
+        })?;
+
+        trace!("Executing UPnP {} command with payload {}", action, payload);
+        service
+            .action(self.device.url(), action, payload)
             .await
-        {
-            trace!("Received UPnP position info: {:?}", info);
-            let event = UpnpEvent::Time(PositionInfo::from(info));
-            if let Err(e) = self.event_sender.send(event).await {
-                self.handle_poll_event_error(e).await;
+            .map(|e| {
+                trace!("Received command {} response: {:?}", action, e);
+                e
+            })
+            .map_err(|e| {
+                error!("Failed to execute {} UPnP action, {}", action, e);
+                dlna::DlnaError::ServiceCommand
+            })
+    }
+
+    async fn poll_event_info(&self) {
+        if *self.position_info_supported.lock().await {
+            match self
+                .execute_query_action("GetPositionInfo", UPNP_PLAYER_POSITION_PAYLOAD)
+                .await
+            {
+                Ok(info) => {
+                    trace!("Received UPnP position info: {:?}", info);
+                    let event = UpnpEvent::Time(PositionInfo::from(info));
+                    if let Err(e) = self.event_sender.send(event).await {
+                        self.handle_poll_event_error(e).await;
+                    }
+                }
+                Err(_) => {
+                    warn!(
+                        "UPnP device {} doesn't support position info, disabling position polling",
+                        self.id
+                    );
+                    *self.position_info_supported.lock().await = false;
+                }
             }
         }
         if let Ok(info) = self
@@ -317,6 +398,18 @@ impl InnerPlayer {
                 self.handle_poll_event_error(e).await;
             }
         }
+        if self.rendering_control.is_some() {
+            if let Ok(info) = self
+                .execute_rendering_action("GetVolume", UPNP_PLAYER_VOLUME_PAYLOAD)
+                .await
+            {
+                trace!("Received UPnP volume info: {:?}", info);
+                let event = UpnpEvent::Volume(VolumeInfo::from(info));
+                if let Err(e) = self.event_sender.send(event).await {
+                    self.handle_poll_event_error(e).await;
+                }
+            }
+        }
     }
 
     async fn handle_poll_event_error(&self, e: SendError<UpnpEvent>) {
@@ -328,9 +421,7 @@ impl InnerPlayer {
     async fn handle_time_event(&self, event: PositionInfo) {
         let mut mutex = self.playback_state.lock().await;
 
-        if let Ok(duration) = parse_time_from_str(event.track_duration.as_str()) {
-            let duration = parse_millis_from_time(&duration);
-
+        if let Some(duration) = event.duration() {
             if mutex.duration != duration {
                 mutex.duration = duration;
                 self.callbacks
@@ -338,9 +429,7 @@ impl InnerPlayer {
             }
         }
 
-        if let Ok(time) = parse_time_from_str(event.rel_time.as_str()) {
-            let time = parse_millis_from_time(&time);
-
+        if let Some(time) = event.time() {
             if mutex.time != time {
                 mutex.time = time;
                 self.callbacks.invoke(PlayerEvent::TimeChanged(time));
@@ -358,6 +447,26 @@ impl InnerPlayer {
                 .invoke(PlayerEvent::StateChanged(player_state));
         }
     }
+
+    /// Handle a mute state change of the player.
+    /// A muted player is reported as volume `0`, an unmuted player reports its last known volume.
+    async fn handle_mute_event(&self, muted: bool) {
+        if muted {
+            self.callbacks.invoke(PlayerEvent::VolumeChanged(0));
+        } else if let Some(volume) = self.playback_state.lock().await.volume {
+            self.callbacks.invoke(PlayerEvent::VolumeChanged(volume));
+        }
+    }
+
+    async fn handle_volume_event(&self, event: VolumeInfo) {
+        let mut mutex = self.playback_state.lock().await;
+
+        if mutex.volume != Some(event.current_volume) {
+            mutex.volume = Some(event.current_volume);
+            self.callbacks
+                .invoke(PlayerEvent::VolumeChanged(event.current_volume));
+        }
+    }
 }
 
 impl Callbacks<PlayerEvent> for InnerPlayer {
@@ -393,12 +502,12 @@ impl Player for InnerPlayer {
         mutex.state.clone()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         let mutex = block_in_place(self.request.lock());
         mutex.as_ref().map(|e| Arc::downgrade(e))
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, mut request: PlayRequest) {
         trace!("Starting DLNA playback for {:?}", request);
         let extension = PathBuf::from(request.url())
             .extension()
@@ -406,7 +515,7 @@ impl Player for InnerPlayer {
             .unwrap_or("mpeg".to_string());
 
         // process the playback subtitle information
-        let (subtitle_attributes, video_resource_attributes) = self.handle_subtitle(&request);
+        let (subtitle_attributes, video_resource_attributes) = self.handle_subtitle(&mut request);
 
         let video_resource = format!(
             r#"<res protocolInfo="http-get:*:video/{video_type}:DLNA.ORG_OP=01;DLNA.ORG_FLAGS=01100000000000000000000000000000" {video_attributes}>{video_uri}</res>"#,
@@ -456,6 +565,7 @@ impl Player for InnerPlayer {
         }
 
         trace!("Starting DLNA playback");
+        *self.position_info_supported.lock().await = true;
         self.resume();
         self.start_event_poller().await;
 
@@ -476,9 +586,13 @@ impl Player for InnerPlayer {
 
     fn pause(&self) {
         block_in_place(async {
-            let _ = self
+            if self
                 .execute_action("Pause", UPNP_PLAYER_PAUSE_PAYLOAD)
-                .await;
+                .await
+                .is_ok()
+            {
+                self.update_state_async(PlayerState::Paused).await;
+            }
         })
     }
 
@@ -489,10 +603,9 @@ impl Player for InnerPlayer {
     }
 
     fn seek(&self, time: u64) {
-        let time = parse_time_from_millis(time);
-        let time_str = parse_str_from_time(&time);
+        let time_str = format_upnp_time(time);
         block_in_place(async {
-            let _ = self
+            if self
                 .execute_action(
                     "Seek",
                     format!(
@@ -505,16 +618,78 @@ impl Player for InnerPlayer {
                     )
                     .as_str(),
                 )
-                .await;
+                .await
+                .is_ok()
+            {
+                // inform the listeners directly as the renderer might not report the position
+                self.playback_state.lock().await.time = time;
+                self.callbacks.invoke(PlayerEvent::TimeChanged(time));
+            }
         })
     }
 
     fn stop(&self) {
         block_in_place(async {
-            let _ = self.execute_action("Stop", UPNP_PLAYER_STOP_PAYLOAD).await;
+            if self
+                .execute_action("Stop", UPNP_PLAYER_STOP_PAYLOAD)
+                .await
+                .is_ok()
+            {
+                self.update_state_async(PlayerState::Stopped).await;
+            }
             self.stop_event_poller().await;
         })
     }
+
+    fn set_volume(&self, volume: u32) {
+        let volume = volume.min(100);
+        block_in_place(async {
+            if self
+                .execute_rendering_action(
+                    "SetVolume",
+                    format!(
+                        r#"
+                <InstanceID>0</InstanceID>
+                <Channel>Master</Channel>
+                <DesiredVolume>{}</DesiredVolume>
+            "#,
+                        volume
+                    )
+                    .as_str(),
+                )
+                .await
+                .is_ok()
+            {
+                self.handle_volume_event(VolumeInfo {
+                    current_volume: volume,
+                })
+                .await;
+            }
+        })
+    }
+
+    fn set_muted(&self, muted: bool) {
+        block_in_place(async {
+            match self
+                .execute_rendering_action(
+                    "SetMute",
+                    format!(
+                        r#"
+                <InstanceID>0</InstanceID>
+                <Channel>Master</Channel>
+                <DesiredMute>{}</DesiredMute>
+            "#,
+                        if muted { 1 } else { 0 }
+                    )
+                    .as_str(),
+                )
+                .await
+            {
+                Ok(_) => self.handle_mute_event(muted).await,
+                Err(e) => error!("Failed to update the mute state of {}, {}", self.id, e),
+            }
+        })
+    }
 }
 
 impl Drop for InnerPlayer {
@@ -532,15 +707,18 @@ struct PlaybackState {
     pub duration: u64,
     /// The state of the player.
     pub state: PlayerState,
+    /// The last known volume of the player, if known.
+    pub volume: Option<u32>,
 }
 
 impl Default for PlaybackState {
-    /// Creates a default `PlaybackState` instance with zero time, zero duration, and `PlayerState::Ready`.
+    /// Creates a default `PlaybackState` instance with zero time, zero duration, `PlayerState::Ready` and an unknown volume.
     fn default() -> Self {
         Self {
             time: 0,
             duration: 0,
             state: PlayerState::Ready,
+            volume: None,
         }
     }
 }
@@ -554,7 +732,7 @@ mod tests {
     use httpmock::{Mock, MockServer};
     use tokio::runtime::Runtime;
 
-    use popcorn_fx_core::core::players::PlayUrlRequestBuilder;
+    use popcorn_fx_core::core::players::PlayRequestBuilder;
     use popcorn_fx_core::core::subtitles::MockSubtitleProvider;
     use popcorn_fx_core::testing::init_logger;
 
@@ -590,6 +768,18 @@ mod tests {
           </s:Body>
         </s:Envelope>"#;
 
+    const RESPONSE_GET_TRANSPORT_INFO: &str = r#"<?xml version="1.0"?>
+        <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"
+                    s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/">
+          <s:Body>
+            <u:GetTransportInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1">
+              <CurrentTransportState>PLAYING</CurrentTransportState>
+              <CurrentTransportStatus>OK</CurrentTransportStatus>
+              <CurrentSpeed>1</CurrentSpeed>
+            </u:GetTransportInfoResponse>
+          </s:Body>
+        </s:Envelope>"#;
+
     struct TestInstance {
         runtime: Arc<Runtime>,
         server: MockServer,
@@ -662,15 +852,27 @@ mod tests {
     }
 
     #[test]
-    fn test_play() {
+    fn test_supports_unknown_capabilities() {
         init_logger();
-        let request = Box::new(
-            PlayUrlRequestBuilder::builder()
-                .url("http://localhost/my-video.mp4")
-                .title("FooBar")
-                .subtitles_enabled(true)
-                .build(),
+        let instance = new_test_instance();
+        let player = instance.player_instance();
+
+        let result = player.supports("video/x-matroska");
+
+        assert_eq!(
+            true, result,
+            "expected unknown capabilities to support every MIME type"
         );
+    }
+
+    #[test]
+    fn test_play() {
+        init_logger();
+        let request = PlayRequestBuilder::builder()
+            .url("http://localhost/my-video.mp4")
+            .title("FooBar")
+            .subtitles_enabled(true)
+            .build();
         let instance = new_test_instance();
         let init_mock = create_init_mock(&instance);
         let play_mock = instance.server().mock(|when, then| {
@@ -722,6 +924,7 @@ mod tests {
 
         player.pause();
 
+        assert_eq!(PlayerState::Paused, player.state());
         pause_mock.assert();
     }
 
@@ -761,7 +964,9 @@ mod tests {
                 .header(
                     "soapaction",
                     "\"urn:schemas-upnp-org:service:AVTransport:1#Seek\"",
-                );
+                )
+                .body_contains("<Unit>REL_TIME</Unit>")
+                .body_contains("<Target>0:00:14</Target>");
             then.status(200)
                 .header("content-type", "text/xml; charset=\"utf-8\"")
                 .body(
@@ -776,10 +981,18 @@ mod tests {
                     </s:Envelope>"#,
                 );
         });
+        let (tx, rx) = channel();
         let player = instance.player_instance();
 
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::TimeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
         player.seek(14000);
 
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerEvent::TimeChanged(14000), result);
         seek_mock.assert();
     }
 
@@ -805,6 +1018,7 @@ mod tests {
 
         player.stop();
 
+        assert_eq!(PlayerState::Stopped, player.state());
         let result = block_in_place(player.inner.event_poller_activated.lock());
         assert_eq!(
             false, *result,
@@ -813,6 +1027,151 @@ mod tests {
         stop_mock.assert();
     }
 
+    #[test]
+    fn test_set_volume() {
+        init_logger();
+        let instance = new_test_instance();
+        let volume_mock = instance.server().mock(|when, then| {
+            when.method(POST)
+                .path("/RenderingControl/control")
+                .header("soapaction", "\"urn:schemas-upnp-org:service:RenderingControl:1#SetVolume\"")
+                .body_contains("<DesiredVolume>40</DesiredVolume>");
+            then.status(200)
+                .body(r#"<s:Envelope s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/" xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+                    <s:Body>
+                        <u:SetVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"/>
+                    </s:Body>
+                </s:Envelope>"#);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        player.set_volume(40);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(40, volume);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
+                result
+            );
+        }
+        volume_mock.assert();
+    }
+
+    #[test]
+    fn test_set_muted() {
+        init_logger();
+        let instance = new_test_instance();
+        let mute_mock = instance.server().mock(|when, then| {
+            when.method(POST)
+                .path("/RenderingControl/control")
+                .header("soapaction", "\"urn:schemas-upnp-org:service:RenderingControl:1#SetMute\"")
+                .body_contains("<DesiredMute>1</DesiredMute>");
+            then.status(200)
+                .body(r#"<s:Envelope s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/" xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+                    <s:Body>
+                        <u:SetMuteResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"/>
+                    </s:Body>
+                </s:Envelope>"#);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        player.set_muted(true);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(0, volume);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
+                result
+            );
+        }
+        mute_mock.assert();
+    }
+
+    #[test]
+    fn test_set_muted_unmute() {
+        init_logger();
+        let instance = new_test_instance();
+        instance.server().mock(|when, then| {
+            when.method(POST)
+                .path("/RenderingControl/control")
+                .header("soapaction", "\"urn:schemas-upnp-org:service:RenderingControl:1#SetMute\"")
+                .body_contains("<DesiredMute>0</DesiredMute>");
+            then.status(200)
+                .body(r#"<s:Envelope s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/" xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+                    <s:Body>
+                        <u:SetMuteResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1"/>
+                    </s:Body>
+                </s:Envelope>"#);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+        block_in_place(player.inner.playback_state.lock()).volume = Some(30);
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        player.set_muted(false);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(30, volume);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_muted_failed() {
+        init_logger();
+        let instance = new_test_instance();
+        instance.server().mock(|when, then| {
+            when.method(POST).path("/RenderingControl/control").header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:RenderingControl:1#SetMute\"",
+            );
+            then.status(500);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        player.set_muted(true);
+
+        let result = rx.recv_timeout(Duration::from_millis(100));
+        assert!(
+            result.is_err(),
+            "expected no volume event to have been invoked"
+        );
+    }
+
     #[test]
     fn test_poll_event_info_position_info() {
         init_logger();
@@ -866,6 +1225,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_poll_event_info_position_not_implemented() {
+        init_logger();
+        let instance = new_test_instance();
+        let position_mock = instance.server().mock(|when, then| {
+            when.method(POST).path("/AVTransport/control").header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:AVTransport:1#GetPositionInfo\"",
+            );
+            then.status(500)
+                .header("Content-Type", "text/xml; charset=\"utf-8\"")
+                .body(
+                    r#"<?xml version="1.0"?>
+                    <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"
+                                s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/">
+                      <s:Body>
+                        <s:Fault>
+                          <faultcode>s:Client</faultcode>
+                          <faultstring>UPnPError</faultstring>
+                          <detail>
+                            <UPnPError xmlns="urn:schemas-upnp-org:control-1-0">
+                              <errorCode>401</errorCode>
+                              <errorDescription>Invalid Action</errorDescription>
+                            </UPnPError>
+                          </detail>
+                        </s:Fault>
+                      </s:Body>
+                    </s:Envelope>"#,
+                );
+        });
+        instance.server().mock(|when, then| {
+            when.method(POST).path("/AVTransport/control").header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:AVTransport:1#GetTransportInfo\"",
+            );
+            then.status(200)
+                .header("Content-Type", "text/xml; charset=\"utf-8\"")
+                .body(RESPONSE_GET_TRANSPORT_INFO);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+
+        player.add(Box::new(move |event| match &event {
+            PlayerEvent::DurationChanged(_) | PlayerEvent::TimeChanged(_) => {
+                tx.send(event).unwrap()
+            }
+            _ => {}
+        }));
+        player
+            .inner
+            .runtime
+            .block_on(player.inner.poll_event_info());
+        player
+            .inner
+            .runtime
+            .block_on(player.inner.poll_event_info());
+
+        let result = rx.recv_timeout(Duration::from_millis(100));
+        assert!(
+            result.is_err(),
+            "expected no time events to have been published"
+        );
+        assert_ne!(
+            PlayerState::Error,
+            player.state(),
+            "expected the player to not be in an error state"
+        );
+        assert_eq!(
+            false,
+            *block_in_place(player.inner.position_info_supported.lock()),
+            "expected the position polling to have been disabled"
+        );
+        position_mock.assert_hits(1);
+    }
+
     #[test]
     fn test_poll_event_info_transport_info() {
         init_logger();
@@ -880,21 +1314,57 @@ mod tests {
                 .header("Content-Type", "text/xml; charset=\"utf-8\"")
                 .body(RESPONSE_GET_POSITION);
         });
+        instance.server().mock(|when, then| {
+            when.method(POST).path("/AVTransport/control").header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:AVTransport:1#GetTransportInfo\"",
+            );
+            then.status(200)
+                .header("Content-Type", "text/xml; charset=\"utf-8\"")
+                .body(RESPONSE_GET_TRANSPORT_INFO);
+        });
+        let (tx, rx) = channel();
+        let player = instance.player_instance();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::StateChanged(_) = &event {
+                tx.send(event).unwrap();
+            }
+        }));
+        player
+            .inner
+            .runtime
+            .block_on(player.inner.poll_event_info());
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        if let PlayerEvent::StateChanged(state) = result {
+            assert_eq!(PlayerState::Playing, state);
+        } else {
+            assert!(
+                false,
+                "expected PlayerEvent::StateChanged, but got {:?} instead",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_poll_event_info_volume_info() {
+        init_logger();
+        let instance = new_test_instance();
         instance.server().mock(|when, then| {
             when.method(POST)
-                .path("/AVTransport/control")
-                .header("soapaction", "\"urn:schemas-upnp-org:service:AVTransport:1#GetTransportInfo\"");
+                .path("/RenderingControl/control")
+                .header("soapaction", "\"urn:schemas-upnp-org:service:RenderingControl:1#GetVolume\"");
             then.status(200)
                 .header("Content-Type", "text/xml; charset=\"utf-8\"")
                 .body(r#"<?xml version="1.0"?>
-                    <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" 
+                    <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"
                                 s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/">
                       <s:Body>
-                        <u:GetTransportInfoResponse xmlns:u="urn:schemas-upnp-org:service:AVTransport:1">
-                          <CurrentTransportState>PLAYING</CurrentTransportState>
-                          <CurrentTransportStatus>OK</CurrentTransportStatus>
-                          <CurrentSpeed>1</CurrentSpeed>
-                        </u:GetTransportInfoResponse>
+                        <u:GetVolumeResponse xmlns:u="urn:schemas-upnp-org:service:RenderingControl:1">
+                          <CurrentVolume>25</CurrentVolume>
+                        </u:GetVolumeResponse>
                       </s:Body>
                     </s:Envelope>"#);
         });
@@ -902,7 +1372,7 @@ mod tests {
         let player = instance.player_instance();
 
         player.add(Box::new(move |event| {
-            if let PlayerEvent::StateChanged(_) = &event {
+            if let PlayerEvent::VolumeChanged(_) = &event {
                 tx.send(event).unwrap();
             }
         }));
@@ -912,12 +1382,12 @@ mod tests {
             .block_on(player.inner.poll_event_info());
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
-        if let PlayerEvent::StateChanged(state) = result {
-            assert_eq!(PlayerState::Playing, state);
+        if let PlayerEvent::VolumeChanged(volume) = result {
+            assert_eq!(25, volume);
         } else {
             assert!(
                 false,
-                "expected PlayerEvent::StateChanged, but got {:?} instead",
+                "expected PlayerEvent::VolumeChanged, but got {:?} instead",
                 result
             );
         }
@@ -955,7 +1425,12 @@ mod tests {
         let service = device.find_service(&AV_TRANSPORT).cloned().unwrap();
         let subtitle_provider = MockSubtitleProvider::new();
         let subtitle_server = Arc::new(SubtitleServer::new(Arc::new(Box::new(subtitle_provider))));
-        let player = Arc::new(DlnaPlayer::new(device, service, subtitle_server));
+        let player = Arc::new(DlnaPlayer::new(
+            device,
+            service,
+            RendererCapabilities::default(),
+            subtitle_server,
+        ));
 
         TestInstance {
             runtime,
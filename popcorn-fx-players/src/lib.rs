@@ -6,6 +6,8 @@ use async_trait::async_trait;
 use derive_more::Display;
 use thiserror::Error;
 
+use popcorn_fx_core::core::Callbacks;
+
 #[cfg(feature = "chromecast")]
 pub mod chromecast;
 #[cfg(feature = "dlna")]
@@ -44,9 +46,24 @@ pub enum DiscoveryState {
     Error,
 }
 
+/// Represents the events which are emitted by a discovery process.
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum DiscoveryEvent {
+    /// Indicates that a new device has been discovered, containing the id of its registered player.
+    #[display(fmt = "Device {} has been discovered", _0)]
+    DeviceAdded(String),
+    /// Indicates that a device is no longer available, containing the id of its removed player.
+    #[display(fmt = "Device {} has been removed", _0)]
+    DeviceRemoved(String),
+}
+
 /// This trait defines a generic interface for discovering media players.
+///
+/// A running discovery process keeps discovering devices in the background until it's stopped,
+/// registering new players with the player manager and removing the players of devices which are no longer available.
+/// These changes are reported through the [DiscoveryEvent] callbacks.
 #[async_trait]
-pub trait Discovery: Display + Send + Sync {
+pub trait Discovery: Display + Send + Sync + Callbacks<DiscoveryEvent> {
     /// Returns the current state of the discovery process.
     fn state(&self) -> DiscoveryState;
 
@@ -6,12 +6,14 @@ use derive_more::Display;
 use log::{debug, info, trace};
 use tokio::sync::Mutex;
 
-use popcorn_fx_core::core::block_in_place;
+use popcorn_fx_core::core::{
+    block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
+};
 use popcorn_fx_core::core::players::PlayerManager;
 use popcorn_fx_core::core::subtitles::{SubtitleManager, SubtitleProvider};
 
-use crate::{Discovery, DiscoveryError, DiscoveryState};
-use crate::vlc::VlcPlayer;
+use crate::{Discovery, DiscoveryError, DiscoveryEvent, DiscoveryState};
+use crate::vlc::{VLC_ID, VlcPlayer};
 
 /// VLC discovery service responsible for searching and registering an external VLC player.
 #[derive(Debug, Display)]
@@ -21,6 +23,7 @@ pub struct VlcDiscovery {
     subtitle_provider: Arc<Box<dyn SubtitleProvider>>,
     player_manager: Arc<Box<dyn PlayerManager>>,
     state: Mutex<DiscoveryState>,
+    callbacks: CoreCallbacks<DiscoveryEvent>,
 }
 
 impl VlcDiscovery {
@@ -35,6 +38,7 @@ impl VlcDiscovery {
             subtitle_provider,
             player_manager,
             state: Mutex::new(DiscoveryState::Stopped),
+            callbacks: Default::default(),
         }
     }
 
@@ -56,6 +60,16 @@ impl VlcDiscovery {
     }
 }
 
+impl Callbacks<DiscoveryEvent> for VlcDiscovery {
+    fn add(&self, callback: CoreCallback<DiscoveryEvent>) -> CallbackHandle {
+        self.callbacks.add(callback)
+    }
+
+    fn remove(&self, handle: CallbackHandle) {
+        self.callbacks.remove(handle)
+    }
+}
+
 #[async_trait]
 impl Discovery for VlcDiscovery {
     fn state(&self) -> DiscoveryState {
@@ -89,6 +103,8 @@ impl Discovery for VlcDiscovery {
                 debug!("Created new external VLC player {:?}", vlc_player);
                 if self.player_manager.add_player(Box::new(vlc_player)) {
                     info!("Added new external VLC player");
+                    self.callbacks
+                        .invoke(DiscoveryEvent::DeviceAdded(VLC_ID.to_string()));
                 } else {
                     self.update_state_async(DiscoveryState::Error).await;
                     return Err(DiscoveryError::Initialization(
@@ -123,8 +139,6 @@ mod tests {
     use popcorn_fx_core::core::subtitles::MockSubtitleProvider;
     use popcorn_fx_core::testing::{init_logger, MockSubtitleManager};
 
-    use crate::vlc::VLC_ID;
-
     use super::*;
 
     #[test]
@@ -26,11 +26,11 @@ use url::Url;
 use popcorn_fx_core::core::{
     block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
 };
-use popcorn_fx_core::core::players::{Player, PlayerEvent, PlayerState, PlayRequest};
+use popcorn_fx_core::core::players::{MediaTrack, Player, PlayerEvent, PlayerState, PlayRequest};
 use popcorn_fx_core::core::subtitles::{SubtitleManager, SubtitleProvider};
 use popcorn_fx_core::core::subtitles::matcher::SubtitleMatcher;
 
-use crate::vlc::VlcStatus;
+use crate::vlc::{VlcStatus, VLC_VOLUME_FULL};
 
 pub const VLC_ID: &str = "vlc";
 const VLC_GRAPHIC_RESOURCE: &[u8] = include_bytes!("../../resources/external-vlc-icon.png");
@@ -43,6 +43,8 @@ const COMMAND_PLAY_PAUSE: &str = "pl_pause";
 const COMMAND_STOP: &str = "pl_stop";
 const COMMAND_SEEK: &str = "seek";
 const COMMAND_VOLUME: &str = "volume";
+const COMMAND_AUDIO_TRACK: &str = "audio_track";
+const COMMAND_SUBTITLE_TRACK: &str = "subtitle_track";
 
 /// Represents an external VLC player instance.
 #[derive(Debug, Display)]
@@ -90,11 +92,11 @@ impl Player for VlcPlayer {
         self.inner.state()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         self.inner.request()
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, request: PlayRequest) {
         self.inner.play(request).await;
         let cancel_token = CancellationToken::new();
 
@@ -142,6 +144,30 @@ impl Player for VlcPlayer {
 
         self.inner.stop()
     }
+
+    fn set_volume(&self, volume: u32) {
+        self.inner.set_volume(volume)
+    }
+
+    fn set_muted(&self, muted: bool) {
+        self.inner.set_muted(muted)
+    }
+
+    fn audio_tracks(&self) -> Option<Vec<MediaTrack>> {
+        self.inner.audio_tracks()
+    }
+
+    fn set_audio_track(&self, id: i32) {
+        self.inner.set_audio_track(id)
+    }
+
+    fn embedded_subtitle_tracks(&self) -> Option<Vec<MediaTrack>> {
+        self.inner.embedded_subtitle_tracks()
+    }
+
+    fn set_embedded_subtitle_track(&self, id: i32) {
+        self.inner.set_embedded_subtitle_track(id)
+    }
 }
 
 impl Drop for VlcPlayer {
@@ -264,6 +290,7 @@ impl VlcPlayerBuilder {
                 request: Default::default(),
                 process: Default::default(),
                 state: Default::default(),
+                volume: Mutex::new(100),
                 callbacks: Default::default(),
                 runtime,
                 subtitle_manager: self
@@ -285,9 +312,11 @@ struct InnerVlcPlayer {
     client: Client,
     socket: SocketAddr,
     options: String,
-    request: Mutex<Option<Arc<Box<dyn PlayRequest>>>>,
+    request: Mutex<Option<Arc<PlayRequest>>>,
     process: Mutex<Option<Child>>,
     state: Mutex<PlayerState>,
+    /// The last known volume percentage of the VLC player, used to restore the volume when unmuted
+    volume: Mutex<u32>,
     callbacks: CoreCallbacks<PlayerEvent>,
     runtime: Arc<Runtime>,
     subtitle_manager: Arc<Box<dyn SubtitleManager>>,
@@ -305,17 +334,26 @@ impl InnerVlcPlayer {
 
     async fn check_status(&self) -> bool {
         trace!("Checking external VLC player status for {:?}", self);
+        if self.process_exited().await {
+            info!("External VLC player has been closed");
+            self.update_state_async(PlayerState::Stopped).await;
+            return false;
+        }
+
         return match self.retrieve_status().await {
             Ok(status) => {
                 debug!("Received external VLC status {:?}", status);
+                let volume = status.volume_percentage();
                 self.update_state_async(PlayerState::from(status.state))
                     .await;
                 self.callbacks
                     .invoke(PlayerEvent::TimeChanged(status.time * 1000));
                 self.callbacks
                     .invoke(PlayerEvent::DurationChanged(status.length * 1000));
-                self.callbacks
-                    .invoke(PlayerEvent::VolumeChanged(status.volume));
+                if volume > 0 {
+                    *self.volume.lock().await = volume;
+                }
+                self.callbacks.invoke(PlayerEvent::VolumeChanged(volume));
                 true
             }
             Err(e) => {
@@ -326,6 +364,30 @@ impl InnerVlcPlayer {
         };
     }
 
+    /// Verify if the spawned VLC process has exited, e.g. when the user closed the player manually.
+    /// The process handle is released when the process has exited.
+    async fn process_exited(&self) -> bool {
+        let mut mutex = self.process.lock().await;
+        let exit_status = match mutex.as_mut() {
+            Some(process) => match process.try_wait() {
+                Ok(e) => e,
+                Err(e) => {
+                    warn!("Failed to check the VLC process status, {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        if let Some(exit_status) = exit_status {
+            debug!("VLC process exited with {}", exit_status);
+            let _ = mutex.take();
+            return true;
+        }
+
+        false
+    }
+
     async fn retrieve_status(&self) -> Result<VlcStatus, VlcError> {
         let uri = self.build_uri(vec![]);
         debug!("Retrieving status from {}", uri);
@@ -350,12 +412,31 @@ impl InnerVlcPlayer {
             .await
     }
 
-    async fn execute_command(&self, command: VlcCommand) {
+    async fn execute_command(&self, command: VlcCommand) -> bool {
         let uri = self.build_uri(command.as_query_params());
         debug!("Exeucting VLC command {}", uri);
         match self.execute_request(uri).await {
-            Ok(_) => debug!("VLC command {} has been executed with success", command),
-            Err(e) => warn!("Failed to executed VLC command {}, {}", command, e),
+            Ok(_) => {
+                debug!("VLC command {} has been executed with success", command);
+                true
+            }
+            Err(e) => {
+                warn!("Failed to executed VLC command {}, {}", command, e);
+                false
+            }
+        }
+    }
+
+    fn retrieve_tracks<F>(&self, mapper: F) -> Option<Vec<MediaTrack>>
+    where
+        F: FnOnce(VlcStatus) -> Vec<MediaTrack>,
+    {
+        match block_in_place(self.retrieve_status()) {
+            Ok(status) => Some(mapper(status)),
+            Err(e) => {
+                warn!("Failed to retrieve VLC media tracks, {}", e);
+                None
+            }
         }
     }
 
@@ -404,12 +485,12 @@ impl Player for InnerVlcPlayer {
         block_in_place(self.state.lock()).clone()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         let mutex = block_in_place(self.request.lock());
         mutex.as_ref().map(|e| Arc::downgrade(e))
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, mut request: PlayRequest) {
         trace!("Trying to start VLC playback for {:?}", request);
         let filename = Path::new(request.url())
             .file_name()
@@ -428,6 +509,7 @@ impl Player for InnerVlcPlayer {
                 Ok(uri) => {
                     debug!("Adding VLC player subtitle file {}", uri);
                     command.arg(format!("--sub-file={}", uri));
+                    request.subtitle.url = Some(uri);
                 }
                 Err(e) => warn!("Failed to download VLC player subtitle file, {}", e),
             }
@@ -457,11 +539,15 @@ impl Player for InnerVlcPlayer {
     }
 
     fn pause(&self) {
-        block_in_place(self.execute_command(VlcCommand::builder().name(COMMAND_PLAY_PAUSE).build()))
+        block_in_place(
+            self.execute_command(VlcCommand::builder().name(COMMAND_PLAY_PAUSE).build()),
+        );
     }
 
     fn resume(&self) {
-        block_in_place(self.execute_command(VlcCommand::builder().name(COMMAND_PLAY_PAUSE).build()))
+        block_in_place(
+            self.execute_command(VlcCommand::builder().name(COMMAND_PLAY_PAUSE).build()),
+        );
     }
 
     fn seek(&self, mut time: u64) {
@@ -474,7 +560,7 @@ impl Player for InnerVlcPlayer {
 
         block_in_place(
             self.execute_command(VlcCommand::builder().name(COMMAND_SEEK).value(time).build()),
-        )
+        );
     }
 
     fn stop(&self) {
@@ -490,8 +576,73 @@ impl Player for InnerVlcPlayer {
             }
         }
 
-        self.callbacks
-            .invoke(PlayerEvent::StateChanged(PlayerState::Stopped));
+        block_in_place(self.update_state_async(PlayerState::Stopped));
+    }
+
+    fn audio_tracks(&self) -> Option<Vec<MediaTrack>> {
+        self.retrieve_tracks(|status| status.audio_tracks())
+    }
+
+    fn set_audio_track(&self, id: i32) {
+        debug!("Updating VLC audio track to {}", id);
+        if block_in_place(
+            self.execute_command(
+                VlcCommand::builder()
+                    .name(COMMAND_AUDIO_TRACK)
+                    .value(id)
+                    .build(),
+            ),
+        ) {
+            self.callbacks.invoke(PlayerEvent::AudioTrackChanged(id));
+        }
+    }
+
+    fn embedded_subtitle_tracks(&self) -> Option<Vec<MediaTrack>> {
+        self.retrieve_tracks(|status| status.subtitle_tracks())
+    }
+
+    fn set_embedded_subtitle_track(&self, id: i32) {
+        debug!("Updating VLC subtitle track to {}", id);
+        if block_in_place(
+            self.execute_command(
+                VlcCommand::builder()
+                    .name(COMMAND_SUBTITLE_TRACK)
+                    .value(id)
+                    .build(),
+            ),
+        ) {
+            self.callbacks.invoke(PlayerEvent::SubtitleTrackChanged(id));
+        }
+    }
+
+    fn set_volume(&self, volume: u32) {
+        let volume = volume.min(100);
+        debug!("Updating VLC volume to {}", volume);
+        block_in_place(async {
+            if self
+                .execute_command(
+                    VlcCommand::builder()
+                        .name(COMMAND_VOLUME)
+                        .value(volume * VLC_VOLUME_FULL / 100)
+                        .build(),
+                )
+                .await
+            {
+                if volume > 0 {
+                    *self.volume.lock().await = volume;
+                }
+                self.callbacks.invoke(PlayerEvent::VolumeChanged(volume));
+            }
+        })
+    }
+
+    fn set_muted(&self, muted: bool) {
+        if muted {
+            self.set_volume(0);
+        } else {
+            let volume = *block_in_place(self.volume.lock());
+            self.set_volume(volume);
+        }
     }
 }
 
@@ -568,7 +719,9 @@ mod tests {
     use httpmock::Method::GET;
     use httpmock::MockServer;
 
-    use popcorn_fx_core::core::players::MockPlayRequest;
+    use popcorn_fx_core::core::players::PlayRequestBuilder;
+    use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
+    use popcorn_fx_core::core::subtitles::model::SubtitleInfo;
     use popcorn_fx_core::core::subtitles::MockSubtitleProvider;
     use popcorn_fx_core::testing::{init_logger, MockSubtitleManager};
 
@@ -646,11 +799,10 @@ mod tests {
     fn test_play() {
         init_logger();
         let title = "FooBarTitle";
-        let mut request = MockPlayRequest::new();
-        request
-            .expect_url()
-            .return_const("http://localhost:8080/myvideo.mp4".to_string());
-        request.expect_title().return_const(title.to_string());
+        let request = PlayRequestBuilder::builder()
+            .url("http://localhost:8080/myvideo.mp4")
+            .title(title)
+            .build();
         let mut manager = MockSubtitleManager::new();
         manager.expect_preferred_subtitle().return_const(None);
         let provider = MockSubtitleProvider::new();
@@ -659,7 +811,7 @@ mod tests {
             .subtitle_provider(Arc::new(Box::new(provider)))
             .build();
 
-        block_in_place(player.play(Box::new(request)));
+        block_in_place(player.play(request));
 
         let result = block_in_place(player.inner.process.lock());
         assert!(
@@ -674,6 +826,40 @@ mod tests {
         assert_eq!(title.to_string(), result.title());
     }
 
+    #[test]
+    fn test_play_subtitle() {
+        init_logger();
+        let subtitle_uri = "/tmp/myvideo.srt";
+        let request = PlayRequestBuilder::builder()
+            .url("http://localhost:8080/myvideo.mp4")
+            .title("FooBar")
+            .build();
+        let mut manager = MockSubtitleManager::new();
+        manager.expect_preferred_subtitle().return_const(Some(
+            SubtitleInfo::builder()
+                .imdb_id("tt12345678")
+                .language(SubtitleLanguage::English)
+                .build(),
+        ));
+        let mut provider = MockSubtitleProvider::new();
+        provider
+            .expect_download()
+            .times(1)
+            .returning(|_, _| Ok(subtitle_uri.to_string()));
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .build();
+
+        block_in_place(player.play(request));
+
+        let result = player
+            .request()
+            .and_then(|e| e.upgrade())
+            .expect("expected the request to have been stored");
+        assert_eq!(Some(subtitle_uri), result.subtitle.url.as_deref());
+    }
+
     #[test]
     fn test_stop() {
         init_logger();
@@ -684,10 +870,10 @@ mod tests {
                 .query_param(COMMAND_NAME_PARAM, COMMAND_STOP);
             then.status(200);
         });
-        let mut request = MockPlayRequest::new();
-        request
-            .expect_url()
-            .return_const("http://localhost:8080/myvideo.mp4".to_string());
+        let request = PlayRequestBuilder::builder()
+            .url("http://localhost:8080/myvideo.mp4")
+            .title("FooBar")
+            .build();
         let mut manager = MockSubtitleManager::new();
         manager.expect_preferred_subtitle().return_const(None);
         let provider = MockSubtitleProvider::new();
@@ -697,7 +883,7 @@ mod tests {
             .address(server.address().clone())
             .build();
 
-        block_in_place(player.play(Box::new(request)));
+        block_in_place(player.play(request));
         player.stop();
 
         let result = block_in_place(player.inner.process.lock());
@@ -830,6 +1016,113 @@ mod tests {
         mock.assert();
     }
 
+    #[test]
+    fn test_audio_tracks() {
+        init_logger();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path(STATUS_URI);
+            then.status(200)
+                .header("Content-Type", "application/xml")
+                .body(
+                    r#"<?xml version="1.0" encoding="utf-8" standalone="yes" ?>
+<root>
+    <time>1</time>
+    <length>6300</length>
+    <state>playing</state>
+    <volume>256</volume>
+    <information>
+        <category name="Stream 1">
+            <info name="Type">Audio</info>
+            <info name="Language">English</info>
+        </category>
+    </information>
+</root>"#,
+                );
+        });
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+
+        let result = player.audio_tracks();
+
+        assert_eq!(
+            Some(vec![MediaTrack {
+                id: 1,
+                name: "English".to_string(),
+                language: Some("English".to_string()),
+            }]),
+            result
+        );
+        assert_eq!(Some(vec![]), player.embedded_subtitle_tracks());
+    }
+
+    #[test]
+    fn test_set_audio_track() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(move |when, then| {
+            when.method(GET)
+                .path(STATUS_URI)
+                .query_param(COMMAND_NAME_PARAM, COMMAND_AUDIO_TRACK)
+                .query_param(COMMAND_VALUE_PARAM, "2");
+            then.status(200);
+        });
+        let (tx, rx) = channel();
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+
+        player.add(Box::new(move |event| match event {
+            PlayerEvent::AudioTrackChanged(e) => tx.send(e).unwrap(),
+            _ => {}
+        }));
+        player.set_audio_track(2);
+
+        mock.assert();
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(2, result);
+    }
+
+    #[test]
+    fn test_set_embedded_subtitle_track() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(move |when, then| {
+            when.method(GET)
+                .path(STATUS_URI)
+                .query_param(COMMAND_NAME_PARAM, COMMAND_SUBTITLE_TRACK)
+                .query_param(COMMAND_VALUE_PARAM, "3");
+            then.status(200);
+        });
+        let (tx, rx) = channel();
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+
+        player.add(Box::new(move |event| match event {
+            PlayerEvent::SubtitleTrackChanged(e) => tx.send(e).unwrap(),
+            _ => {}
+        }));
+        player.set_embedded_subtitle_track(3);
+
+        mock.assert();
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(3, result);
+    }
+
     #[test]
     fn test_seek_time_invalid() {
         init_logger();
@@ -853,4 +1146,112 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn test_set_volume() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(move |when, then| {
+            when.method(GET)
+                .path(STATUS_URI)
+                .query_param(COMMAND_NAME_PARAM, COMMAND_VOLUME)
+                .query_param(COMMAND_VALUE_PARAM, "128");
+            then.status(200);
+        });
+        let (tx, rx) = channel();
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::VolumeChanged(e) = event {
+                tx.send(e).unwrap()
+            }
+        }));
+        player.set_volume(50);
+
+        mock.assert();
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(50, result);
+    }
+
+    #[test]
+    fn test_set_muted() {
+        init_logger();
+        let server = MockServer::start();
+        let mute_mock = server.mock(move |when, then| {
+            when.method(GET)
+                .path(STATUS_URI)
+                .query_param(COMMAND_NAME_PARAM, COMMAND_VOLUME)
+                .query_param(COMMAND_VALUE_PARAM, "0");
+            then.status(200);
+        });
+        let unmute_mock = server.mock(move |when, then| {
+            when.method(GET)
+                .path(STATUS_URI)
+                .query_param(COMMAND_NAME_PARAM, COMMAND_VOLUME)
+                .query_param(COMMAND_VALUE_PARAM, "192");
+            then.status(200);
+        });
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+
+        *block_in_place(player.inner.volume.lock()) = 75;
+        player.set_muted(true);
+        mute_mock.assert();
+
+        player.set_muted(false);
+        unmute_mock.assert();
+    }
+
+    #[test]
+    fn test_check_status_process_exited() {
+        init_logger();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path(STATUS_URI);
+            then.status(200);
+        });
+        let (tx, rx) = channel();
+        let manager = MockSubtitleManager::new();
+        let provider = MockSubtitleProvider::new();
+        let player = VlcPlayer::builder()
+            .subtitle_manager(Arc::new(Box::new(manager)))
+            .subtitle_provider(Arc::new(Box::new(provider)))
+            .address(server.address().clone())
+            .build();
+        let mut process = Command::new("rustc")
+            .arg("--version")
+            .spawn()
+            .expect("expected the process to have been spawned");
+        process.wait().unwrap();
+        *block_in_place(player.inner.process.lock()) = Some(process);
+        *block_in_place(player.inner.state.lock()) = PlayerState::Playing;
+
+        player.add(Box::new(move |event| {
+            if let PlayerEvent::StateChanged(e) = event {
+                tx.send(e).unwrap()
+            }
+        }));
+        let result = block_in_place(player.inner.check_status());
+        assert!(!result, "expected the status check to have stopped");
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(PlayerState::Stopped, result);
+        let result = block_in_place(player.inner.process.lock());
+        assert!(
+            result.is_none(),
+            "expected the VLC process handle to have been released"
+        );
+        mock.assert_hits(0);
+    }
 }
@@ -1,6 +1,15 @@
 use serde::Deserialize;
 
-use popcorn_fx_core::core::players::PlayerState;
+use popcorn_fx_core::core::players::{MediaTrack, PlayerState};
+
+const STREAM_CATEGORY_PREFIX: &str = "Stream ";
+const INFO_TYPE: &str = "Type";
+const INFO_LANGUAGE: &str = "Language";
+const INFO_DESCRIPTION: &str = "Description";
+const TRACK_TYPE_AUDIO: &str = "Audio";
+const TRACK_TYPE_SUBTITLE: &str = "Subtitle";
+/// The VLC volume level which corresponds with 100% of the volume.
+pub const VLC_VOLUME_FULL: u32 = 256;
 
 /// Represents the state of a VLC player.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -36,6 +45,97 @@ pub struct VlcStatus {
     pub volume: u32,
     /// The state of the VLC player.
     pub state: VlcState,
+    /// The information of the media being played, if available.
+    #[serde(default)]
+    pub information: Option<VlcInformation>,
+}
+
+impl VlcStatus {
+    /// Retrieve the volume level of the VLC player as a percentage between 0-100.
+    pub fn volume_percentage(&self) -> u32 {
+        (self.volume * 100 / VLC_VOLUME_FULL).min(100)
+    }
+
+    /// Retrieve the audio tracks of the media being played.
+    pub fn audio_tracks(&self) -> Vec<MediaTrack> {
+        self.tracks(TRACK_TYPE_AUDIO)
+    }
+
+    /// Retrieve the subtitle tracks of the media being played.
+    pub fn subtitle_tracks(&self) -> Vec<MediaTrack> {
+        self.tracks(TRACK_TYPE_SUBTITLE)
+    }
+
+    fn tracks(&self, track_type: &str) -> Vec<MediaTrack> {
+        self.information
+            .as_ref()
+            .map(|e| {
+                e.categories
+                    .iter()
+                    .filter(|e| e.info(INFO_TYPE) == Some(track_type))
+                    .filter_map(|e| MediaTrack::try_from(e).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Represents the media information of a VLC player.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct VlcInformation {
+    /// The information categories of the media, such as the meta data and streams.
+    #[serde(rename = "category", default)]
+    pub categories: Vec<VlcCategory>,
+}
+
+/// Represents a single media information category of a VLC player.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct VlcCategory {
+    /// The name of the category, e.g. `Stream 1`.
+    pub name: String,
+    /// The information entries of the category.
+    #[serde(rename = "info", default)]
+    pub infos: Vec<VlcInfo>,
+}
+
+impl VlcCategory {
+    /// Retrieve the value of the information entry with the given name.
+    pub fn info(&self, name: &str) -> Option<&str> {
+        self.infos
+            .iter()
+            .find(|e| e.name == name)
+            .map(|e| e.value.as_str())
+    }
+}
+
+impl TryFrom<&VlcCategory> for MediaTrack {
+    type Error = ();
+
+    fn try_from(value: &VlcCategory) -> Result<Self, Self::Error> {
+        let id = value
+            .name
+            .strip_prefix(STREAM_CATEGORY_PREFIX)
+            .and_then(|e| e.trim().parse::<i32>().ok())
+            .ok_or(())?;
+        let language = value.info(INFO_LANGUAGE).map(|e| e.to_string());
+        let name = value
+            .info(INFO_DESCRIPTION)
+            .map(|e| e.to_string())
+            .or_else(|| language.clone())
+            .unwrap_or_else(|| format!("Track {}", id));
+
+        Ok(Self { id, name, language })
+    }
+}
+
+/// Represents a single media information entry of a VLC player.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct VlcInfo {
+    /// The name of the information entry.
+    pub name: String,
+    /// The value of the information entry.
+    #[serde(rename = "$value", default)]
+    pub value: String,
 }
 
 #[cfg(test)]
@@ -66,6 +166,7 @@ mod tests {
             length: 56000,
             volume: 256,
             state: VlcState::Paused,
+            information: None,
         };
 
         let result: VlcStatus =
@@ -73,4 +174,79 @@ mod tests {
 
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_volume_percentage() {
+        let mut status = VlcStatus {
+            time: 0,
+            length: 0,
+            volume: 128,
+            state: VlcState::Playing,
+            information: None,
+        };
+        assert_eq!(50, status.volume_percentage());
+
+        status.volume = 512;
+        assert_eq!(100, status.volume_percentage());
+    }
+
+    #[test]
+    fn test_tracks() {
+        let response = r#"<?xml version="1.0" encoding="utf-8" standalone="yes" ?>
+<root>
+    <time>200</time>
+    <length>56000</length>
+    <state>playing</state>
+    <volume>256</volume>
+    <information>
+        <category name="meta">
+            <info name="filename">movie.mkv</info>
+        </category>
+        <category name="Stream 0">
+            <info name="Type">Video</info>
+            <info name="Codec">H264 - MPEG-4 AVC (part 10) (avc1)</info>
+        </category>
+        <category name="Stream 1">
+            <info name="Type">Audio</info>
+            <info name="Language">English</info>
+        </category>
+        <category name="Stream 2">
+            <info name="Type">Audio</info>
+            <info name="Language">French</info>
+            <info name="Description">Commentary</info>
+        </category>
+        <category name="Stream 3">
+            <info name="Type">Subtitle</info>
+        </category>
+    </information>
+</root>
+"#;
+
+        let result: VlcStatus =
+            from_str(response).expect("expected the vlc response to have been parsed");
+
+        assert_eq!(
+            vec![
+                MediaTrack {
+                    id: 1,
+                    name: "English".to_string(),
+                    language: Some("English".to_string()),
+                },
+                MediaTrack {
+                    id: 2,
+                    name: "Commentary".to_string(),
+                    language: Some("French".to_string()),
+                },
+            ],
+            result.audio_tracks()
+        );
+        assert_eq!(
+            vec![MediaTrack {
+                id: 3,
+                name: "Track 3".to_string(),
+                language: None,
+            }],
+            result.subtitle_tracks()
+        );
+    }
 }
@@ -1,5 +1,7 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Weak};
 
 use async_trait::async_trait;
@@ -11,10 +13,13 @@ use popcorn_fx_core::core::config::{ApplicationConfig, CleaningMode, TorrentSett
 use popcorn_fx_core::core::events::{Event, EventPublisher, PlayerStoppedEvent};
 use popcorn_fx_core::core::storage::Storage;
 use popcorn_fx_core::core::torrents::{
-    Torrent, TorrentError, TorrentFileInfo, TorrentInfo, TorrentManager, TorrentManagerCallback,
-    TorrentManagerState, TorrentWrapper,
+    AddTorrentResult, Magnet, Torrent, TorrentError, TorrentEvent, TorrentFileInfo,
+    TorrentHandleCallback, TorrentHandleEvent, TorrentInfo, TorrentManager, TorrentManagerCallback,
+    TorrentManagerState, TorrentState, TorrentSummary, TorrentWrapper,
+};
+use popcorn_fx_core::core::{
+    block_in_place, events, torrents, CallbackHandle, Callbacks, CoreCallbacks,
 };
-use popcorn_fx_core::core::{block_in_place, events, torrents};
 
 const CLEANUP_WATCH_THRESHOLD: f64 = 85f64;
 const CLEANUP_AFTER: fn() -> Duration = || Duration::days(10);
@@ -43,6 +48,12 @@ pub type ResolveTorrentCallback =
 /// The callback function can be used to invoke cancellation logic, typically to stop and clean up torrent-related tasks or processes.
 pub type CancelTorrentCallback = Box<dyn Fn(String) + Send + Sync>;
 
+/// A callback function type for pausing or resuming a torrent.
+///
+/// The function takes a `String` argument representing the handle of the torrent,
+/// and a `bool` indicating whether the torrent should be paused (`true`) or resumed (`false`).
+pub type PauseTorrentCallback = Box<dyn Fn(String, bool) + Send + Sync>;
+
 /// The default torrent manager of the application.
 /// It currently only cleans the torrent directory if needed.
 /// No actual torrent implementation is available.
@@ -57,6 +68,9 @@ impl DefaultTorrentManager {
             inner: Arc::new(InnerTorrentManager {
                 settings,
                 torrents: Default::default(),
+                info_hashes: Default::default(),
+                summaries: Default::default(),
+                subscriptions: Default::default(),
                 resolve_torrent_info_callback: Mutex::new(Box::new(|_| {
                     panic!("No torrent info resolver configured")
                 })),
@@ -66,6 +80,11 @@ impl DefaultTorrentManager {
                 cancel_torrent_callback: Mutex::new(Box::new(|_| {
                     panic!("No cancel torrent callback configured")
                 })),
+                pause_torrent_callback: Mutex::new(Box::new(|_, _| {
+                    panic!("No pause torrent callback configured")
+                })),
+                paused: Mutex::new(false),
+                user_paused: Default::default(),
             }),
         };
 
@@ -104,6 +123,13 @@ impl DefaultTorrentManager {
         *guard = callback;
         info!("Updated torrent cancel callback");
     }
+
+    pub fn register_pause_callback(&self, callback: PauseTorrentCallback) {
+        trace!("Updating torrent pause callback");
+        let mut guard = block_in_place(self.inner.pause_torrent_callback.lock());
+        *guard = callback;
+        info!("Updated torrent pause callback");
+    }
 }
 
 #[async_trait]
@@ -125,7 +151,7 @@ impl TorrentManager for DefaultTorrentManager {
         file_info: &TorrentFileInfo,
         torrent_directory: &str,
         auto_download: bool,
-    ) -> torrents::Result<Weak<Box<dyn Torrent>>> {
+    ) -> torrents::Result<AddTorrentResult> {
         self.inner
             .create(file_info, torrent_directory, auto_download)
             .await
@@ -139,18 +165,73 @@ impl TorrentManager for DefaultTorrentManager {
         self.inner.by_handle(handle)
     }
 
+    fn by_info_hash(
+        &self,
+        info_hash: &str,
+        file_info: &TorrentFileInfo,
+    ) -> Option<Weak<Box<dyn Torrent>>> {
+        self.inner.by_info_hash(info_hash, file_info)
+    }
+
+    fn find_torrent(&self, info_hash: &str) -> Option<Weak<Box<dyn Torrent>>> {
+        self.inner.find_torrent(info_hash)
+    }
+
+    fn active_torrents(&self) -> Vec<TorrentSummary> {
+        self.inner.active_torrents()
+    }
+
     fn remove(&self, handle: &str) {
         self.inner.remove(handle)
     }
+
+    fn subscribe(&self, handle: &str, callback: TorrentHandleCallback) -> Option<CallbackHandle> {
+        self.inner.subscribe(handle, callback)
+    }
+
+    fn unsubscribe(&self, handle: &str, callback_handle: CallbackHandle) {
+        self.inner.unsubscribe(handle, callback_handle)
+    }
+
+    fn pause(&self, handle: &str) {
+        self.inner.pause(handle)
+    }
+
+    fn resume(&self, handle: &str) {
+        self.inner.resume(handle)
+    }
+
+    fn pause_all(&self) {
+        self.inner.pause_all()
+    }
+
+    fn resume_all(&self) {
+        self.inner.resume_all()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.inner.is_paused()
+    }
 }
 
 struct InnerTorrentManager {
     /// The settings of the application
     settings: Arc<ApplicationConfig>,
     torrents: Mutex<Vec<Arc<Box<dyn Torrent>>>>,
+    /// The resolved torrent information by info hash
+    info_hashes: Mutex<HashMap<String, TorrentInfo>>,
+    /// The last known summary of the active torrents by handle
+    summaries: Arc<Mutex<HashMap<String, TorrentSummary>>>,
+    /// The event subscriptions of the active torrents by handle
+    subscriptions: Arc<Mutex<HashMap<String, CoreCallbacks<TorrentHandleEvent>>>>,
     resolve_torrent_info_callback: Mutex<ResolveTorrentInfoCallback>,
     resolve_torrent_callback: Mutex<ResolveTorrentCallback>,
     cancel_torrent_callback: Mutex<CancelTorrentCallback>,
+    pause_torrent_callback: Mutex<PauseTorrentCallback>,
+    /// Indicates if all torrents have been paused
+    paused: Mutex<bool>,
+    /// The handles of the torrents which have been paused individually by the user
+    user_paused: Mutex<HashSet<String>>,
 }
 
 impl InnerTorrentManager {
@@ -197,6 +278,130 @@ impl InnerTorrentManager {
         }
     }
 
+    fn invoke_pause_callback(&self, handle: &str, paused: bool) {
+        let callback = block_in_place(self.pause_torrent_callback.lock());
+        callback(handle.to_string(), paused);
+    }
+
+    /// Retrieve the info hash of the resolved torrent info which contains the given file.
+    fn info_hash_of(&self, file_info: &TorrentFileInfo) -> Option<String> {
+        let info_hashes = block_in_place(self.info_hashes.lock());
+        info_hashes
+            .iter()
+            .find(|(_, info)| {
+                info.files.iter().any(|e| {
+                    e.file_index == file_info.file_index && e.file_path == file_info.file_path
+                })
+            })
+            .map(|(info_hash, _)| info_hash.clone())
+    }
+
+    /// Start tracking the summary of the given torrent.
+    /// The summary is updated with the stats from the events of the torrent.
+    fn track_summary(&self, torrent: &Arc<Box<dyn Torrent>>, file_info: &TorrentFileInfo) {
+        let handle = torrent.handle().to_string();
+        let summary = TorrentSummary {
+            handle: handle.clone(),
+            info_hash: Self::reported_info_hash(torrent)
+                .map(|e| e.to_string())
+                .or_else(|| self.info_hash_of(file_info)),
+            name: file_info.filename().to_string(),
+            state: TorrentState::Creating,
+            progress: 0.0,
+            download_speed: 0,
+            upload_speed: 0,
+        };
+
+        trace!("Tracking torrent summary {:?}", summary);
+        block_in_place(self.summaries.lock()).insert(handle.clone(), summary);
+        block_in_place(self.subscriptions.lock()).insert(handle.clone(), CoreCallbacks::default());
+        let summaries = self.summaries.clone();
+        let subscriptions = self.subscriptions.clone();
+        torrent.subscribe(Box::new(move |event| {
+            let previous_state = {
+                let mut summaries = block_in_place(summaries.lock());
+                match summaries.get_mut(handle.as_str()) {
+                    Some(summary) => {
+                        let previous_state = summary.state.clone();
+                        match &event {
+                            TorrentEvent::StateChanged(state) => summary.state = state.clone(),
+                            TorrentEvent::DownloadStatus(status) => {
+                                summary.progress = status.progress;
+                                summary.download_speed = status.download_speed;
+                                summary.upload_speed = status.upload_speed;
+                            }
+                            _ => {}
+                        }
+                        previous_state
+                    }
+                    None => return,
+                }
+            };
+
+            if let TorrentEvent::StateChanged(state) = event {
+                // release the lock before invoking the subscribers, as they might unsubscribe
+                let callbacks = block_in_place(subscriptions.lock())
+                    .get(handle.as_str())
+                    .cloned();
+                if let Some(callbacks) = callbacks {
+                    for event in Self::handle_events(previous_state, state) {
+                        callbacks.invoke(event);
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Translate a state transition of a torrent into the events for its subscribers.
+    fn handle_events(previous_state: TorrentState, state: TorrentState) -> Vec<TorrentHandleEvent> {
+        let mut events = vec![TorrentHandleEvent::StateChanged(state.clone())];
+
+        if previous_state == TorrentState::Creating && state != TorrentState::Error {
+            events.push(TorrentHandleEvent::MetadataReceived);
+        }
+        if previous_state != TorrentState::Completed && state == TorrentState::Completed {
+            events.push(TorrentHandleEvent::Completed);
+        }
+
+        events
+    }
+
+    /// Close the subscriptions of the given torrent handle.
+    /// The subscribers receive a final [TorrentHandleEvent::Removed] event.
+    fn close_subscriptions(&self, handle: &str) {
+        let callbacks = block_in_place(self.subscriptions.lock()).remove(handle);
+        if let Some(callbacks) = callbacks {
+            debug!("Closing the subscriptions of torrent {}", handle);
+            callbacks.invoke(TorrentHandleEvent::Removed);
+        }
+    }
+
+    fn handles(&self) -> Vec<String> {
+        let torrents = block_in_place(self.torrents.lock());
+        torrents.iter().map(|e| e.handle().to_string()).collect()
+    }
+
+    /// Retrieve the info hash of the given torrent as it has been reported by the torrent session.
+    fn reported_info_hash(torrent: &Arc<Box<dyn Torrent>>) -> Option<&str> {
+        torrent
+            .downcast_ref::<TorrentWrapper>()
+            .and_then(|e| e.info_hash.as_deref())
+    }
+
+    /// Find the active torrents which have been reported by the torrent session with the given info hash.
+    fn find_by_info_hash(&self, info_hash: &str) -> Vec<Arc<Box<dyn Torrent>>> {
+        let torrents = block_in_place(self.torrents.lock());
+        torrents
+            .iter()
+            .filter(|e| {
+                Self::reported_info_hash(e)
+                    .map(|e| e.eq_ignore_ascii_case(info_hash))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect()
+    }
+
     fn find_by_filename(&self, filename: &str) -> Option<Arc<Box<dyn Torrent>>> {
         let torrents = block_in_place(self.torrents.lock());
 
@@ -214,6 +419,14 @@ impl InnerTorrentManager {
             .map(|e| e.clone())
     }
 
+    fn find_by_filepath(&self, filepath: &Path) -> Option<Arc<Box<dyn Torrent>>> {
+        let torrents = block_in_place(self.torrents.lock());
+        torrents
+            .iter()
+            .find(|e| e.file().as_path() == filepath)
+            .cloned()
+    }
+
     fn remove_by_filename(&self, filename: &str) {
         let mut torrents = block_in_place(self.torrents.lock());
         let position = torrents.iter().position(|e| {
@@ -227,6 +440,9 @@ impl InnerTorrentManager {
 
         if let Some(position) = position {
             let torrent = torrents.remove(position);
+            drop(torrents);
+            block_in_place(self.summaries.lock()).remove(torrent.handle());
+            self.close_subscriptions(torrent.handle());
             debug!("Removed torrent {:?}", torrent)
         } else {
             warn!(
@@ -291,6 +507,8 @@ impl Debug for InnerTorrentManager {
         f.debug_struct("InnerTorrentManager")
             .field("settings", &self.settings)
             .field("torrents", &self.torrents)
+            .field("paused", &self.paused)
+            .field("user_paused", &self.user_paused)
             .finish()
     }
 }
@@ -307,8 +525,22 @@ impl TorrentManager for InnerTorrentManager {
 
     async fn info<'a>(&'a self, url: &'a str) -> torrents::Result<TorrentInfo> {
         debug!("Resolving torrent magnet url {}", url);
-        let callback = block_in_place(self.resolve_torrent_info_callback.lock());
-        callback(url.to_string())
+        let info = {
+            let callback = block_in_place(self.resolve_torrent_info_callback.lock());
+            callback(url.to_string())?
+        };
+
+        // prefer the magnet uri of the resolved info, as torrent files resolve to the same info hash as their magnet
+        if let Some(info_hash) = [info.uri.as_str(), url]
+            .iter()
+            .find_map(|e| Magnet::from_str(e).ok().and_then(|e| e.info_hash()))
+        {
+            trace!("Storing torrent info of info hash {}", info_hash);
+            let mut info_hashes = block_in_place(self.info_hashes.lock());
+            info_hashes.insert(info_hash, info.clone());
+        }
+
+        Ok(info)
     }
 
     async fn create(
@@ -316,7 +548,20 @@ impl TorrentManager for InnerTorrentManager {
         file_info: &TorrentFileInfo,
         torrent_directory: &str,
         auto_download: bool,
-    ) -> torrents::Result<Weak<Box<dyn Torrent>>> {
+    ) -> torrents::Result<AddTorrentResult> {
+        let filepath = PathBuf::from(torrent_directory).join(file_info.file_path());
+        if let Some(torrent) = self.find_by_filepath(&filepath) {
+            info!(
+                "Torrent {} is already active for {:?}, reusing the existing torrent",
+                torrent.handle(),
+                filepath
+            );
+            return Ok(AddTorrentResult {
+                torrent: Arc::downgrade(&torrent),
+                already_present: true,
+            });
+        }
+
         debug!("Resolving torrent info {:?}", file_info);
         let torrent_wrapper: TorrentWrapper;
 
@@ -329,18 +574,32 @@ impl TorrentManager for InnerTorrentManager {
         let wrapper = Arc::new(Box::new(torrent_wrapper) as Box<dyn Torrent>);
         let handle = wrapper.handle();
 
-        if self.by_handle(handle).is_none() {
-            let mut mutex = block_in_place(self.torrents.lock());
-            debug!("Adding torrent with handle {}", handle);
-            mutex.push(wrapper.clone());
-        } else {
+        if let Some(existing) = self.by_handle(handle) {
             warn!(
-                "Duplicate handle {} detected, unable to add torrent",
+                "Duplicate handle {} detected, returning the existing torrent",
                 handle
             );
+            return Ok(AddTorrentResult {
+                torrent: existing,
+                already_present: true,
+            });
         }
 
-        Ok(Arc::downgrade(&wrapper))
+        self.track_summary(&wrapper, file_info);
+        let mut mutex = block_in_place(self.torrents.lock());
+        debug!("Adding torrent with handle {}", handle);
+        mutex.push(wrapper.clone());
+        drop(mutex);
+
+        if self.is_paused() {
+            debug!("Torrent manager is paused, pausing new torrent {}", handle);
+            self.invoke_pause_callback(handle, true);
+        }
+
+        Ok(AddTorrentResult {
+            torrent: Arc::downgrade(&wrapper),
+            already_present: false,
+        })
     }
 
     fn by_handle(&self, handle: &str) -> Option<Weak<Box<dyn Torrent>>> {
@@ -351,6 +610,41 @@ impl TorrentManager for InnerTorrentManager {
             .map(|e| Arc::downgrade(e))
     }
 
+    fn by_info_hash(
+        &self,
+        info_hash: &str,
+        file_info: &TorrentFileInfo,
+    ) -> Option<Weak<Box<dyn Torrent>>> {
+        let info_hash = info_hash.to_lowercase();
+        trace!(
+            "Searching for existing torrent of info hash {} with file {}",
+            info_hash,
+            file_info
+        );
+        self.find_by_info_hash(info_hash.as_str())
+            .into_iter()
+            .find(|e| e.file().ends_with(file_info.file_path()))
+            .map(|e| Arc::downgrade(&e))
+    }
+
+    fn find_torrent(&self, info_hash: &str) -> Option<Weak<Box<dyn Torrent>>> {
+        let info_hash = info_hash.to_lowercase();
+        trace!("Searching for active torrent of info hash {}", info_hash);
+        self.find_by_info_hash(info_hash.as_str())
+            .first()
+            .map(Arc::downgrade)
+    }
+
+    fn active_torrents(&self) -> Vec<TorrentSummary> {
+        let torrents = block_in_place(self.torrents.lock());
+        let summaries = block_in_place(self.summaries.lock());
+
+        torrents
+            .iter()
+            .filter_map(|e| summaries.get(e.handle()).cloned())
+            .collect()
+    }
+
     fn remove(&self, handle: &str) {
         let mut mutex = block_in_place(self.torrents.lock());
         let position = mutex.iter().position(|e| e.handle() == handle);
@@ -359,12 +653,119 @@ impl TorrentManager for InnerTorrentManager {
             debug!("Removing torrent with handle {}", handle);
             let torrent = mutex.remove(position);
             drop(mutex);
+            block_in_place(self.user_paused.lock()).remove(handle);
+            block_in_place(self.summaries.lock()).remove(handle);
+            self.close_subscriptions(handle);
 
             let mutex = block_in_place(self.cancel_torrent_callback.lock());
             mutex(torrent.handle().to_string());
         }
     }
 
+    fn subscribe(&self, handle: &str, callback: TorrentHandleCallback) -> Option<CallbackHandle> {
+        let subscriptions = block_in_place(self.subscriptions.lock());
+
+        if let Some(callbacks) = subscriptions.get(handle) {
+            debug!("Subscribing callback to torrent {}", handle);
+            return Some(callbacks.add(callback));
+        }
+
+        drop(subscriptions);
+        warn!(
+            "Unable to subscribe to {}, torrent has been removed",
+            handle
+        );
+        callback(TorrentHandleEvent::Removed);
+        None
+    }
+
+    fn unsubscribe(&self, handle: &str, callback_handle: CallbackHandle) {
+        let subscriptions = block_in_place(self.subscriptions.lock());
+
+        if let Some(callbacks) = subscriptions.get(handle) {
+            debug!("Unsubscribing callback from torrent {}", handle);
+            callbacks.remove(callback_handle);
+        }
+    }
+
+    fn pause(&self, handle: &str) {
+        if self.by_handle(handle).is_none() {
+            warn!("Unable to pause torrent {}, torrent not found", handle);
+            return;
+        }
+
+        debug!("Pausing torrent {}", handle);
+        block_in_place(self.user_paused.lock()).insert(handle.to_string());
+        self.invoke_pause_callback(handle, true);
+    }
+
+    fn resume(&self, handle: &str) {
+        if self.by_handle(handle).is_none() {
+            warn!("Unable to resume torrent {}, torrent not found", handle);
+            return;
+        }
+
+        block_in_place(self.user_paused.lock()).remove(handle);
+        if self.is_paused() {
+            debug!(
+                "Torrent manager is paused, torrent {} will be resumed with all torrents",
+                handle
+            );
+        } else {
+            debug!("Resuming torrent {}", handle);
+            self.invoke_pause_callback(handle, false);
+        }
+    }
+
+    fn pause_all(&self) {
+        {
+            let mut paused = block_in_place(self.paused.lock());
+            if *paused {
+                trace!("Torrent manager is already paused");
+                return;
+            }
+            *paused = true;
+        }
+
+        let handles = self.handles();
+        debug!("Pausing all {} torrents", handles.len());
+        for handle in handles {
+            self.invoke_pause_callback(handle.as_str(), true);
+        }
+        info!("All torrents have been paused");
+    }
+
+    fn resume_all(&self) {
+        {
+            let mut paused = block_in_place(self.paused.lock());
+            if !*paused {
+                trace!("Torrent manager is not paused");
+                return;
+            }
+            *paused = false;
+        }
+
+        let user_paused = block_in_place(self.user_paused.lock()).clone();
+        let handles: Vec<String> = self
+            .handles()
+            .into_iter()
+            .filter(|e| !user_paused.contains(e))
+            .collect();
+        debug!(
+            "Resuming {} torrents, {} torrents remain paused by the user",
+            handles.len(),
+            user_paused.len()
+        );
+        for handle in handles {
+            self.invoke_pause_callback(handle.as_str(), false);
+        }
+        info!("All torrents have been resumed");
+    }
+
+    fn is_paused(&self) -> bool {
+        *block_in_place(self.paused.lock())
+    }
+
     fn cleanup(&self) {
         let settings = self.settings.user_settings();
         let settings = settings.torrent();
@@ -392,8 +793,8 @@ mod test {
 
     use utime::set_file_times;
 
-    use popcorn_fx_core::core::config::{PopcornSettings, TorrentSettings};
-    use popcorn_fx_core::core::torrents::TorrentState;
+    use popcorn_fx_core::core::config::{EncryptionMode, PopcornSettings, TorrentSettings};
+    use popcorn_fx_core::core::torrents::{DownloadStatus, TorrentState};
     use popcorn_fx_core::testing::{copy_test_file, init_logger};
 
     use super::*;
@@ -409,6 +810,509 @@ mod test {
         assert_eq!(TorrentManagerState::Running, manager.state())
     }
 
+    #[test]
+    fn test_by_info_hash() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let magnet_uri = "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7";
+        let filename = "lorem.mp4";
+        let filepath = PathBuf::from(temp_path).join(filename);
+        let file_info = TorrentFileInfo {
+            filename: filename.to_string(),
+            file_path: filename.to_string(),
+            file_size: 28000,
+            file_index: 0,
+        };
+        let torrent_info = TorrentInfo {
+            uri: magnet_uri.to_string(),
+            name: "lorem".to_string(),
+            directory_name: None,
+            total_files: 1,
+            files: vec![file_info.clone()],
+        };
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        manager.register_resolve_callback(Box::new(move |_, _, _| TorrentWrapper {
+            handle: "MyHandle".to_string(),
+            filepath: filepath.clone(),
+            info_hash: Some("eadaf0efea39406914414d359e0ea16416409bd7".to_string()),
+            has_bytes: Mutex::new(Box::new(|_| true)),
+            has_piece: Mutex::new(Box::new(|_| true)),
+            total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
+            prioritize_bytes: Mutex::new(Box::new(|_| {})),
+            prioritize_pieces: Mutex::new(Box::new(|_| {})),
+            sequential_mode: Mutex::new(Box::new(|| {})),
+            torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
+            callbacks: Default::default(),
+        }));
+        let torrent_info_callback = torrent_info.clone();
+        manager
+            .register_resolve_info_callback(Box::new(move |_| Ok(torrent_info_callback.clone())));
+
+        let result = manager.by_info_hash("eadaf0efea39406914414d359e0ea16416409bd7", &file_info);
+        assert!(
+            result.is_none(),
+            "expected no torrent to be found before it has been created"
+        );
+
+        block_in_place(manager.info(magnet_uri)).expect("expected the torrent info");
+        block_in_place(manager.create(&file_info, temp_path, true))
+            .expect("expected the torrent to have been created");
+        let result = manager
+            .by_info_hash("EADAF0EFEA39406914414D359E0EA16416409BD7", &file_info)
+            .and_then(|e| e.upgrade())
+            .expect("expected the existing torrent to have been found");
+
+        assert_eq!("MyHandle", result.handle());
+    }
+
+    #[test]
+    fn test_by_info_hash_same_filename() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap().to_string();
+        let lorem_info = TorrentFileInfo {
+            filename: "video.mp4".to_string(),
+            file_path: "Lorem/video.mp4".to_string(),
+            file_size: 28000,
+            file_index: 0,
+        };
+        let ipsum_info = TorrentFileInfo {
+            filename: "video.mp4".to_string(),
+            file_path: "Ipsum/video.mp4".to_string(),
+            file_size: 28000,
+            file_index: 0,
+        };
+        let settings = default_config(temp_path.as_str(), CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let callback_path = temp_path.clone();
+        manager.register_resolve_callback(Box::new(move |file_info, _, _| TorrentWrapper {
+            handle: file_info.file_path.clone(),
+            filepath: PathBuf::from(callback_path.as_str()).join(file_info.file_path()),
+            info_hash: Some(if file_info.file_path.starts_with("Lorem") {
+                "eadaf0efea39406914414d359e0ea16416409bd7".to_string()
+            } else {
+                "2f6b4ad1a0cd7bd2a5c0ea39fb6e2e2b1dd0c4a5".to_string()
+            }),
+            has_bytes: Mutex::new(Box::new(|_| true)),
+            has_piece: Mutex::new(Box::new(|_| true)),
+            total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
+            prioritize_bytes: Mutex::new(Box::new(|_| {})),
+            prioritize_pieces: Mutex::new(Box::new(|_| {})),
+            sequential_mode: Mutex::new(Box::new(|| {})),
+            torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
+            callbacks: Default::default(),
+        }));
+
+        block_in_place(manager.create(&lorem_info, temp_path.as_str(), true))
+            .expect("expected the torrent to have been created");
+        block_in_place(manager.create(&ipsum_info, temp_path.as_str(), true))
+            .expect("expected the torrent to have been created");
+
+        let result = manager
+            .by_info_hash("2F6B4AD1A0CD7BD2A5C0EA39FB6E2E2B1DD0C4A5", &ipsum_info)
+            .and_then(|e| e.upgrade())
+            .expect("expected the existing torrent to have been found");
+        assert_eq!("Ipsum/video.mp4", result.handle());
+
+        let result = manager.by_info_hash("2f6b4ad1a0cd7bd2a5c0ea39fb6e2e2b1dd0c4a5", &lorem_info);
+        assert!(
+            result.is_none(),
+            "expected no torrent to be found for a file of another torrent"
+        );
+
+        let result = manager.by_info_hash("3b245504cf5f11bbdbe1201cea6a6bf45aee1bc0", &lorem_info);
+        assert!(
+            result.is_none(),
+            "expected no torrent to be found for an unknown info hash"
+        );
+    }
+
+    #[test]
+    fn test_create_already_present() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filename = "lorem.mp4";
+        let filepath = PathBuf::from(temp_path).join(filename);
+        let file_info = TorrentFileInfo {
+            filename: filename.to_string(),
+            file_path: filename.to_string(),
+            file_size: 28000,
+            file_index: 0,
+        };
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        manager.register_resolve_callback(Box::new(move |_, _, _| {
+            tx.send(()).unwrap();
+            TorrentWrapper {
+                handle: "MyHandle".to_string(),
+                filepath: filepath.clone(),
+                info_hash: None,
+                has_bytes: Mutex::new(Box::new(|_| true)),
+                has_piece: Mutex::new(Box::new(|_| true)),
+                total_pieces: Mutex::new(Box::new(|| 10)),
+                piece_length: Mutex::new(Box::new(|| 1024)),
+                prioritize_bytes: Mutex::new(Box::new(|_| {})),
+                prioritize_pieces: Mutex::new(Box::new(|_| {})),
+                sequential_mode: Mutex::new(Box::new(|| {})),
+                torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+                write_failures: Default::default(),
+                callbacks: Default::default(),
+            }
+        }));
+
+        let result = block_in_place(manager.create(&file_info, temp_path, true))
+            .expect("expected the torrent to have been created");
+        assert_eq!(false, result.already_present);
+
+        let result = block_in_place(manager.create(&file_info, temp_path, true))
+            .expect("expected the existing torrent to have been returned");
+        assert_eq!(true, result.already_present);
+        assert_eq!(
+            "MyHandle",
+            result
+                .torrent
+                .upgrade()
+                .expect("expected the torrent to still be alive")
+                .handle()
+        );
+        assert_eq!(
+            1,
+            rx.try_iter().count(),
+            "expected only one torrent to have been resolved"
+        );
+    }
+
+    #[test]
+    fn test_find_torrent() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let info_hash = "eadaf0efea39406914414d359e0ea16416409bd7";
+        let filename = "lorem.mp4";
+        let filepath = PathBuf::from(temp_path).join(filename);
+        let file_info = TorrentFileInfo {
+            filename: filename.to_string(),
+            file_path: filename.to_string(),
+            file_size: 28000,
+            file_index: 0,
+        };
+        let torrent_info = TorrentInfo {
+            uri: format!("magnet:?xt=urn:btih:{}", info_hash),
+            name: "lorem".to_string(),
+            directory_name: None,
+            total_files: 1,
+            files: vec![file_info.clone()],
+        };
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        manager.register_resolve_callback(Box::new(move |_, _, _| TorrentWrapper {
+            handle: "MyHandle".to_string(),
+            filepath: filepath.clone(),
+            info_hash: Some(info_hash.to_string()),
+            has_bytes: Mutex::new(Box::new(|_| true)),
+            has_piece: Mutex::new(Box::new(|_| true)),
+            total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
+            prioritize_bytes: Mutex::new(Box::new(|_| {})),
+            prioritize_pieces: Mutex::new(Box::new(|_| {})),
+            sequential_mode: Mutex::new(Box::new(|| {})),
+            torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
+            callbacks: Default::default(),
+        }));
+        manager.register_resolve_info_callback(Box::new(move |_| Ok(torrent_info.clone())));
+
+        // resolve the info from a torrent file, which resolves into the magnet uri of the content
+        block_in_place(manager.info("file:///tmp/lorem.torrent"))
+            .expect("expected the torrent info");
+        assert!(
+            manager.find_torrent(info_hash).is_none(),
+            "expected no torrent to be found before it has been created"
+        );
+        block_in_place(manager.create(&file_info, temp_path, true))
+            .expect("expected the torrent to have been created");
+
+        let result = manager
+            .find_torrent(info_hash.to_uppercase().as_str())
+            .and_then(|e| e.upgrade())
+            .expect("expected the active torrent to have been found");
+        assert_eq!("MyHandle", result.handle());
+    }
+
+    #[test]
+    fn test_active_torrents() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let info_hash = "eadaf0efea39406914414d359e0ea16416409bd7";
+        let file_info = create_file_info("lorem.mp4");
+        let torrent_info = TorrentInfo {
+            uri: format!("magnet:?xt=urn:btih:{}", info_hash),
+            name: "lorem".to_string(),
+            directory_name: None,
+            total_files: 1,
+            files: vec![file_info.clone()],
+        };
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        register_resolve_callback(&manager, temp_path);
+        manager.register_resolve_info_callback(Box::new(move |_| Ok(torrent_info.clone())));
+
+        block_in_place(manager.info(format!("magnet:?xt=urn:btih:{}", info_hash).as_str()))
+            .expect("expected the torrent info");
+        block_in_place(manager.create(&file_info, temp_path, true))
+            .expect("expected the torrent to have been created");
+        block_in_place(manager.create(&create_file_info("ipsum.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        let torrent = manager
+            .by_handle("lorem.mp4")
+            .and_then(|e| e.upgrade())
+            .expect("expected the torrent to have been found");
+        let wrapper = torrent
+            .downcast_ref::<TorrentWrapper>()
+            .expect("expected a torrent wrapper");
+        wrapper.state_changed(TorrentState::Downloading);
+        wrapper.download_status(DownloadStatus {
+            progress: 0.5,
+            seeds: 10,
+            peers: 5,
+            download_speed: 2048,
+            upload_speed: 512,
+            downloaded: 14000,
+            total_size: 28000,
+            request_queue_depth: 24,
+        });
+
+        let result = manager.active_torrents();
+
+        assert_eq!(
+            vec![
+                TorrentSummary {
+                    handle: "lorem.mp4".to_string(),
+                    info_hash: Some(info_hash.to_string()),
+                    name: "lorem.mp4".to_string(),
+                    state: TorrentState::Downloading,
+                    progress: 0.5,
+                    download_speed: 2048,
+                    upload_speed: 512,
+                },
+                TorrentSummary {
+                    handle: "ipsum.mp4".to_string(),
+                    info_hash: None,
+                    name: "ipsum.mp4".to_string(),
+                    state: TorrentState::Creating,
+                    progress: 0.0,
+                    download_speed: 0,
+                    upload_speed: 0,
+                },
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_active_torrents_removed() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        register_resolve_callback(&manager, temp_path);
+        manager.register_cancel_callback(Box::new(|_| {}));
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        block_in_place(manager.create(&create_file_info("ipsum.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+
+        manager.remove("lorem.mp4");
+        let result: Vec<String> = manager
+            .active_torrents()
+            .into_iter()
+            .map(|e| e.handle)
+            .collect();
+
+        assert_eq!(vec!["ipsum.mp4".to_string()], result);
+    }
+
+    #[test]
+    fn test_subscribe() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        register_resolve_callback(&manager, temp_path);
+        manager.register_cancel_callback(Box::new(|_| {}));
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        let torrent = manager
+            .by_handle("lorem.mp4")
+            .and_then(|e| e.upgrade())
+            .expect("expected the torrent to have been found");
+        let wrapper = torrent
+            .downcast_ref::<TorrentWrapper>()
+            .expect("expected a torrent wrapper");
+
+        let result = manager.subscribe(
+            "lorem.mp4",
+            Box::new(move |event| {
+                tx.send(event).unwrap();
+            }),
+        );
+        assert!(result.is_some(), "expected a subscription handle");
+
+        wrapper.state_changed(TorrentState::Ready);
+        wrapper.state_changed(TorrentState::Completed);
+        manager.remove("lorem.mp4");
+
+        let result: Vec<TorrentHandleEvent> = rx.try_iter().collect();
+        assert_eq!(
+            vec![
+                TorrentHandleEvent::StateChanged(TorrentState::Ready),
+                TorrentHandleEvent::MetadataReceived,
+                TorrentHandleEvent::StateChanged(TorrentState::Completed),
+                TorrentHandleEvent::Completed,
+                TorrentHandleEvent::Removed,
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_subscribe_removed_torrent() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        register_resolve_callback(&manager, temp_path);
+        manager.register_cancel_callback(Box::new(|_| {}));
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        manager.remove("lorem.mp4");
+
+        let result = manager.subscribe(
+            "lorem.mp4",
+            Box::new(move |event| {
+                tx.send(event).unwrap();
+            }),
+        );
+
+        assert_eq!(None, result);
+        assert_eq!(
+            vec![TorrentHandleEvent::Removed],
+            rx.try_iter().collect::<Vec<TorrentHandleEvent>>()
+        );
+    }
+
+    #[test]
+    fn test_pause_all() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        register_resolve_callback(&manager, temp_path);
+        manager.register_pause_callback(Box::new(move |handle, paused| {
+            tx.send((handle, paused)).unwrap();
+        }));
+
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        manager.pause_all();
+        assert_eq!(true, manager.is_paused());
+        assert_eq!(
+            ("lorem.mp4".to_string(), true),
+            rx.recv_timeout(std::time::Duration::from_millis(200))
+                .unwrap()
+        );
+
+        // torrents which are added while paused should start paused
+        block_in_place(manager.create(&create_file_info("ipsum.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        assert_eq!(
+            ("ipsum.mp4".to_string(), true),
+            rx.recv_timeout(std::time::Duration::from_millis(200))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resume_all_should_not_resume_user_paused_torrents() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        register_resolve_callback(&manager, temp_path);
+        manager.register_pause_callback(Box::new(move |handle, paused| {
+            tx.send((handle, paused)).unwrap();
+        }));
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+        block_in_place(manager.create(&create_file_info("ipsum.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+
+        manager.pause("lorem.mp4");
+        manager.pause_all();
+        manager.resume_all();
+
+        assert_eq!(false, manager.is_paused());
+        let result: Vec<(String, bool)> = rx.try_iter().collect();
+        assert_eq!(
+            vec![
+                ("lorem.mp4".to_string(), true),
+                ("lorem.mp4".to_string(), true),
+                ("ipsum.mp4".to_string(), true),
+                ("ipsum.mp4".to_string(), false),
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_resume_while_paused() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let settings = default_config(temp_path, CleaningMode::Off);
+        let manager = DefaultTorrentManager::new(settings, Arc::new(EventPublisher::default()));
+        let (tx, rx) = channel();
+        register_resolve_callback(&manager, temp_path);
+        manager.register_pause_callback(Box::new(move |handle, paused| {
+            tx.send((handle, paused)).unwrap();
+        }));
+        block_in_place(manager.create(&create_file_info("lorem.mp4"), temp_path, true))
+            .expect("expected the torrent to have been created");
+
+        manager.pause("lorem.mp4");
+        manager.pause_all();
+        manager.resume("lorem.mp4");
+        manager.resume_all();
+
+        let result: Vec<(String, bool)> = rx.try_iter().collect();
+        assert_eq!(
+            vec![
+                ("lorem.mp4".to_string(), true),
+                ("lorem.mp4".to_string(), true),
+                ("lorem.mp4".to_string(), false),
+            ],
+            result
+        );
+    }
+
     #[test]
     fn test_on_player_stopped() {
         init_logger();
@@ -438,13 +1342,16 @@ mod test {
         manager.register_resolve_callback(Box::new(move |_, _, _| TorrentWrapper {
             handle: "MyHandle".to_string(),
             filepath: filepath.clone(),
+            info_hash: None,
             has_bytes: Mutex::new(Box::new(|_| true)),
             has_piece: Mutex::new(Box::new(|_| true)),
             total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
             prioritize_bytes: Mutex::new(Box::new(|_| {})),
             prioritize_pieces: Mutex::new(Box::new(|_| {})),
             sequential_mode: Mutex::new(Box::new(|| {})),
             torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
             callbacks: Default::default(),
         }));
         let torrent_info_callback = torrent_info.clone();
@@ -484,6 +1391,7 @@ mod test {
             event_publisher.publish(Event::PlayerStopped(PlayerStoppedEvent {
                 url: "http://localhost:8081/lorem%20ipsum%3D%5Bdolor%5D.mp4".to_string(),
                 media: None,
+                parent_media: None,
                 time: Some(55000),
                 duration: Some(60000),
             }));
@@ -587,6 +1495,34 @@ mod test {
         )
     }
 
+    fn register_resolve_callback(manager: &DefaultTorrentManager, temp_path: &str) {
+        let temp_path = temp_path.to_string();
+        manager.register_resolve_callback(Box::new(move |file_info, _, _| TorrentWrapper {
+            handle: file_info.filename.clone(),
+            filepath: PathBuf::from(temp_path.as_str()).join(file_info.file_path()),
+            info_hash: None,
+            has_bytes: Mutex::new(Box::new(|_| true)),
+            has_piece: Mutex::new(Box::new(|_| true)),
+            total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
+            prioritize_bytes: Mutex::new(Box::new(|_| {})),
+            prioritize_pieces: Mutex::new(Box::new(|_| {})),
+            sequential_mode: Mutex::new(Box::new(|| {})),
+            torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
+            callbacks: Default::default(),
+        }));
+    }
+
+    fn create_file_info(filename: &str) -> TorrentFileInfo {
+        TorrentFileInfo {
+            filename: filename.to_string(),
+            file_path: filename.to_string(),
+            file_size: 28000,
+            file_index: 0,
+        }
+    }
+
     fn default_config(temp_path: &str, cleaning_mode: CleaningMode) -> Arc<ApplicationConfig> {
         Arc::new(
             ApplicationConfig::builder()
@@ -601,9 +1537,25 @@ mod test {
                         connections_limit: 0,
                         download_rate_limit: 0,
                         upload_rate_limit: 0,
+                        stream_idle_timeout: 0,
+                        remove_idle_torrents: false,
+                        bind_interface: None,
+                        encryption_mode: EncryptionMode::Prefer,
+                        auto_collect_finished: false,
+                        initial_buffer_size: 0,
+                        peer_connect_timeout: 15,
+                        announce_to_all_trackers: true,
+                        request_timeout: 20,
+                        ip_filter: None,
+                        preallocate: false,
+                        dht_read_only: false,
+                        max_metadata_size: 10 * 1024 * 1024,
+                        max_request_queue_depth: 500,
+                        dual_stack: true,
                     },
                     playback_settings: Default::default(),
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         )
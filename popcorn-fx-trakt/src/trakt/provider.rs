@@ -10,13 +10,13 @@ use async_trait::async_trait;
 use chrono::{Local, Utc};
 use log::{debug, error, info, trace, warn};
 use oauth2::{
-    AuthorizationCode, AuthUrl, ClientId, ClientSecret, CsrfToken, RedirectUrl, TokenResponse,
-    TokenUrl,
+    AuthorizationCode, AuthUrl, ClientId, ClientSecret, CsrfToken, HttpRequest, HttpResponse,
+    RedirectUrl, TokenResponse, TokenUrl,
 };
 use oauth2::basic::{BasicClient, BasicTokenResponse};
-use oauth2::reqwest::async_http_client;
 use reqwest::Client;
 use reqwest::header::HeaderMap;
+use reqwest::redirect::Policy;
 use thiserror::Error;
 use tokio::runtime::Runtime;
 use tokio::sync::{Mutex, oneshot};
@@ -34,6 +34,7 @@ use popcorn_fx_core::core::media::MediaIdentifier;
 use popcorn_fx_core::core::media::tracking::{
     AuthorizationError, OpenAuthorization, TrackingError, TrackingEvent, TrackingProvider,
 };
+use popcorn_fx_core::core::utils::http::HttpClientFactory;
 
 use crate::trakt::{AddToWatchList, Movie, MovieId, WatchedMovie};
 
@@ -66,14 +67,18 @@ pub enum TraktError {
 pub struct TraktProvider {
     config: Arc<ApplicationConfig>,
     oauth_client: BasicClient,
-    client: Client,
+    client_factory: HttpClientFactory,
     open_authorization_callback: Mutex<OpenAuthorization>,
     runtime: Arc<Runtime>,
     callbacks: CoreCallbacks<TrackingEvent>,
 }
 
 impl TraktProvider {
-    pub fn new(config: Arc<ApplicationConfig>, runtime: Arc<Runtime>) -> Result<Self> {
+    pub fn new(
+        config: Arc<ApplicationConfig>,
+        client_factory: HttpClientFactory,
+        runtime: Arc<Runtime>,
+    ) -> Result<Self> {
         let tracking: TrackingProperties;
         let client: &TrackingClientProperties;
         {
@@ -99,7 +104,7 @@ impl TraktProvider {
         Ok(Self {
             config,
             oauth_client,
-            client: Self::create_new_client(client),
+            client_factory,
             open_authorization_callback: Mutex::new(Box::new(|uri: String| {
                 match open::that(uri.as_str()) {
                     Ok(_) => true,
@@ -197,7 +202,7 @@ impl TraktProvider {
         trace!("Exchanging refresh token {}", refresh_token);
         self.oauth_client
             .exchange_refresh_token(&oauth2::RefreshToken::new(refresh_token))
-            .request_async(async_http_client)
+            .request_async(|request| self.oauth_request(request))
             .await
             .map_err(|e| TraktError::TokenError(e.to_string()))
     }
@@ -229,13 +234,71 @@ impl TraktProvider {
         Err(TraktError::NoAvailablePorts)
     }
 
-    fn create_new_client(properties: &TrackingClientProperties) -> Client {
+    /// Create a new client for the Trakt api from the current network settings.
+    fn client(&self) -> result::Result<Client, TrackingError> {
+        let properties = self.properties();
         let mut headers = HeaderMap::new();
 
         headers.insert("trakt-api-version", "2".parse().unwrap());
-        headers.insert("trakt-api-key", properties.client_id.parse().unwrap());
+        headers.insert(
+            "trakt-api-key",
+            properties.client().client_id.parse().unwrap(),
+        );
+
+        self.client_factory
+            .client_builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| {
+                error!("Failed to create Trakt client, {}", e);
+                TrackingError::Request
+            })
+    }
 
-        Client::builder().default_headers(headers).build().unwrap()
+    /// Execute the given OAuth request through a client of the current network settings.
+    /// Redirects are not followed, as required by the OAuth token endpoints.
+    async fn oauth_request(
+        &self,
+        request: HttpRequest,
+    ) -> result::Result<HttpResponse, reqwest::Error> {
+        let client = self
+            .client_factory
+            .client_builder()
+            .redirect(Policy::none())
+            .build()?;
+        let mut builder = client
+            .request(
+                request
+                    .method
+                    .as_str()
+                    .parse()
+                    .expect("expected a valid http method"),
+                request.url.as_str(),
+            )
+            .body(request.body);
+        for (name, value) in request.headers.iter() {
+            builder = builder.header(name.as_str(), value.as_bytes());
+        }
+
+        let response = builder.send().await?;
+        let status_code = oauth2::http::StatusCode::from_u16(response.status().as_u16())
+            .expect("expected a valid status code");
+        let mut headers = oauth2::http::HeaderMap::new();
+        for (name, value) in response.headers().iter() {
+            if let (Ok(name), Ok(value)) = (
+                oauth2::http::HeaderName::from_bytes(name.as_str().as_bytes()),
+                oauth2::http::HeaderValue::from_bytes(value.as_bytes()),
+            ) {
+                headers.append(name, value);
+            }
+        }
+        let body = response.bytes().await?.to_vec();
+
+        Ok(HttpResponse {
+            status_code,
+            headers,
+            body,
+        })
     }
 
     fn properties(&self) -> TrackingProperties {
@@ -306,7 +369,7 @@ impl TrackingProvider for TraktProvider {
                     return match self
                         .oauth_client
                         .exchange_code(AuthorizationCode::new(callback.authorization_code))
-                        .request_async(async_http_client)
+                        .request_async(|request| self.oauth_request(request))
                         .await
                     {
                         Ok(e) => {
@@ -354,7 +417,7 @@ impl TrackingProvider for TraktProvider {
         uri.set_path("/sync/watchlist");
 
         let response = self
-            .client
+            .client()?
             .post(uri)
             .bearer_auth(bearer_token)
             .json(&AddToWatchList {
@@ -400,7 +463,7 @@ impl TrackingProvider for TraktProvider {
         uri.set_path("/sync/watched/movies");
 
         let response = self
-            .client
+            .client()?
             .get(uri)
             .bearer_auth(bearer_token)
             .send()
@@ -429,7 +492,7 @@ impl Debug for TraktProvider {
         f.debug_struct("TraktProvider")
             .field("config", &self.config)
             .field("oauth_client", &self.oauth_client)
-            .field("client", &self.client)
+            .field("client_factory", &self.client_factory)
             .field("runtime", &self.runtime)
             .field("callbacks", &self.callbacks)
             .finish()
@@ -467,7 +530,7 @@ mod tests {
         let runtime = Arc::new(Runtime::new().unwrap());
         let settings = Arc::new(ApplicationConfig::builder().storage(temp_path).build());
 
-        let result = TraktProvider::new(settings, runtime);
+        let result = TraktProvider::new(settings, HttpClientFactory::default(), runtime);
 
         if let Err(e) = result {
             assert!(false, "failed to create new Trakt instance, {}", e)
@@ -490,6 +553,7 @@ mod tests {
                     torrent_settings: Default::default(),
                     playback_settings: Default::default(),
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         );
@@ -502,7 +566,7 @@ mod tests {
                 scopes: None,
             },
         );
-        let trakt = TraktProvider::new(settings, runtime).unwrap();
+        let trakt = TraktProvider::new(settings, HttpClientFactory::default(), runtime).unwrap();
 
         let result = trakt.is_authorized();
 
@@ -525,10 +589,11 @@ mod tests {
                     torrent_settings: Default::default(),
                     playback_settings: Default::default(),
                     tracking_settings: Default::default(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         );
-        let trakt = TraktProvider::new(settings, runtime).unwrap();
+        let trakt = TraktProvider::new(settings, HttpClientFactory::default(), runtime).unwrap();
 
         let result = trakt.is_authorized();
 
@@ -568,6 +633,7 @@ mod tests {
                     update_channel: "".to_string(),
                     providers: Default::default(),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: Default::default(),
                     tracking: vec![(
                         "trakt".to_string(),
@@ -587,7 +653,7 @@ mod tests {
                 .build(),
         );
         let (tx, rx) = channel();
-        let trakt = TraktProvider::new(settings, runtime).unwrap();
+        let trakt = TraktProvider::new(settings, HttpClientFactory::default(), runtime).unwrap();
 
         trakt.register_open_authorization(Box::new(move |uri| {
             tx.send(uri).unwrap();
@@ -655,7 +721,7 @@ mod tests {
                 scopes: None,
             },
         );
-        let trakt = TraktProvider::new(settings, runtime).unwrap();
+        let trakt = TraktProvider::new(settings, HttpClientFactory::default(), runtime).unwrap();
 
         let settings = trakt.config.user_settings().tracking_settings;
         assert!(
@@ -711,6 +777,7 @@ mod tests {
                     update_channel: Default::default(),
                     providers: Default::default(),
                     enhancers: Default::default(),
+                    torrent_sources: Default::default(),
                     subtitle: Default::default(),
                     tracking: vec![(
                         "trakt".to_string(),
@@ -744,10 +811,11 @@ mod tests {
                             },
                         )
                         .build(),
+                    network_settings: Default::default(),
                 })
                 .build(),
         );
-        let trakt = TraktProvider::new(settings, runtime).unwrap();
+        let trakt = TraktProvider::new(settings, HttpClientFactory::default(), runtime).unwrap();
 
         let result = block_in_place(trakt.watched_movies());
 
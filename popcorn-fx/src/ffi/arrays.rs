@@ -68,9 +68,9 @@ mod tests {
             },
             rating: None,
         };
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let array = from_c_owned(load_fanart(&mut instance, &MediaItemC::from(media)));
+        let array = from_c_owned(load_fanart(&instance, &MediaItemC::from(media)));
         dispose_byte_array(Box::new(array))
     }
 
@@ -7,7 +7,7 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `callback` - a callback function pointer of type `PlaybackControlsCallbackC`.
 ///
 /// # Safety
@@ -17,7 +17,7 @@ use crate::PopcornFX;
 /// The callback function will be invoked whenever a playback control event occurs in the system.
 #[no_mangle]
 pub extern "C" fn register_playback_controls(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: PlaybackControlsCallbackC,
 ) {
     trace!("Registering new playback controls callback from C");
@@ -51,8 +51,8 @@ mod test {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_playback_controls(&mut instance, playback_controls_callback);
+        register_playback_controls(&instance, playback_controls_callback);
     }
 }
@@ -2,7 +2,7 @@ use log::trace;
 
 use popcorn_fx_core::core::events::LOWEST_ORDER;
 
-use crate::ffi::{EventC, EventCCallback};
+use crate::ffi::{EventC, EventCCallback, EventSubscriberCCallback};
 use crate::PopcornFX;
 
 /// Publish a new application event over the FFI layer.
@@ -10,7 +10,7 @@ use crate::PopcornFX;
 ///
 /// _Please keep in mind that the consumption of the event chain is not communicated over the FFI layer_
 #[no_mangle]
-pub extern "C" fn publish_event(popcorn_fx: &mut PopcornFX, event: EventC) {
+pub extern "C" fn publish_event(popcorn_fx: &PopcornFX, event: EventC) {
     trace!("Handling EventPublisher bridge event of C for {:?}", event);
     if let Some(event) = event.into_event() {
         let event_publisher = popcorn_fx.event_publisher().clone();
@@ -29,10 +29,10 @@ pub extern "C" fn publish_event(popcorn_fx: &mut PopcornFX, event: EventC) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A C-compatible function pointer representing the callback to be registered.
 #[no_mangle]
-pub extern "C" fn register_event_callback(popcorn_fx: &mut PopcornFX, callback: EventCCallback) {
+pub extern "C" fn register_event_callback(popcorn_fx: &PopcornFX, callback: EventCCallback) {
     popcorn_fx.event_publisher().register(
         Box::new(move |e| {
             trace!("Executing EventPublisher bridge event callback for {}", e);
@@ -43,6 +43,36 @@ pub extern "C" fn register_event_callback(popcorn_fx: &mut PopcornFX, callback:
     );
 }
 
+/// Register an event subscriber with the PopcornFX event publisher.
+///
+/// The subscriber first receives the buffered events which have been published before the subscription,
+/// these events are flagged as replayed. Reconnecting subscribers should provide the last sequence number they've received,
+/// so only the events published after it are replayed.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `callback` - A C-compatible function pointer representing the subscriber to be registered.
+/// * `last_sequence` - The last received event sequence number, or `0` when no events have been received.
+#[no_mangle]
+pub extern "C" fn register_event_subscriber(
+    popcorn_fx: &PopcornFX,
+    callback: EventSubscriberCCallback,
+    last_sequence: u64,
+) {
+    trace!(
+        "Registering event subscriber from C with last sequence {}",
+        last_sequence
+    );
+    popcorn_fx.event_publisher().subscribe(
+        Box::new(move |e| {
+            trace!("Executing EventPublisher bridge subscriber for {:?}", e);
+            callback(EventC::from(e.event), e.sequence, e.replayed);
+        }),
+        Some(last_sequence),
+    );
+}
+
 /// Dispose of the given event from the event bridge.
 ///
 /// This function takes ownership of a boxed `EventC` object, releasing its resources.
@@ -65,6 +95,7 @@ mod test {
     use tempfile::tempdir;
 
     use popcorn_fx_core::core::events::{DEFAULT_ORDER, Event};
+    use popcorn_fx_core::core::torrents::TorrentVersion;
     use popcorn_fx_core::into_c_string;
     use popcorn_fx_core::testing::init_logger;
 
@@ -77,13 +108,20 @@ mod test {
         info!("Event callback received {:?}", event);
     }
 
+    extern "C" fn event_subscriber_callback(event: EventC, sequence: u64, replayed: bool) {
+        info!(
+            "Event subscriber received {:?} (sequence: {}, replayed: {})",
+            event, sequence, replayed
+        );
+    }
+
     #[test]
     fn test_publish() {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
         let (tx, rx) = channel();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.event_publisher().register(
             Box::new(move |e| {
@@ -92,7 +130,7 @@ mod test {
             }),
             DEFAULT_ORDER,
         );
-        publish_event(&mut instance, EventC::ClosePlayer);
+        publish_event(&instance, EventC::ClosePlayer);
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
 
@@ -105,9 +143,9 @@ mod test {
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
         let (tx, rx) = channel();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_event_callback(&mut instance, event_callback);
+        register_event_callback(&instance, event_callback);
         instance.event_publisher().register(
             Box::new(move |e| {
                 tx.send(e).unwrap();
@@ -122,6 +160,29 @@ mod test {
         assert!(result.is_err(), "expected the event to have been consumed");
     }
 
+    #[test]
+    fn test_register_event_subscriber() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        register_event_subscriber(&instance, event_subscriber_callback, 0);
+        instance.event_publisher().subscribe(
+            Box::new(move |e| {
+                tx.send(e).unwrap();
+            }),
+            None,
+        );
+
+        instance.event_publisher().publish(Event::ClosePlayer);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(Event::ClosePlayer, result.event);
+        assert_eq!(false, result.replayed);
+    }
+
     #[test]
     fn test_dispose_event_value() {
         dispose_event_value(EventC::TorrentDetailsLoaded(TorrentInfoC {
@@ -129,6 +190,7 @@ mod test {
             name: into_c_string("Foo".to_string()),
             directory_name: into_c_string("Bar".to_string()),
             total_files: 20,
+            version: TorrentVersion::V1,
             files: CArray::from(vec![]),
         }))
     }
@@ -1,12 +1,14 @@
 use std::os::raw::c_char;
 use std::ptr;
 
-use log::{error, info, trace};
+use log::{debug, error, info, trace};
 
 use popcorn_fx_core::core::media::Category;
 use popcorn_fx_core::from_c_string;
 
-use crate::ffi::{favorites_to_c, GenreC, SortByC, VecFavoritesC};
+use crate::ffi::{
+    favorites_to_c, GenreC, MediaErrorC, MediaSetC, MediaSetResult, SortByC, VecFavoritesC,
+};
 use crate::PopcornFX;
 
 /// Retrieves available favorites from a PopcornFX instance.
@@ -21,7 +23,7 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a PopcornFX instance.
+/// * `popcorn_fx` - A reference to a PopcornFX instance.
 /// * `genre` - A pointer to a GenreC struct, representing the genre filter.
 /// * `sort_by` - A pointer to a SortByC struct, representing the sorting criteria.
 /// * `keywords` - A pointer to a C-style string containing search keywords.
@@ -31,9 +33,11 @@ use crate::PopcornFX;
 ///
 /// If successful, returns a pointer to a VecFavoritesC struct containing the retrieved favorites.
 /// Returns a null pointer if an error occurs during the retrieval process.
+///
+/// Deprecated: use [retrieve_favorites] instead, which reports the reason of a failure.
 #[no_mangle]
 pub extern "C" fn retrieve_available_favorites(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     genre: &GenreC,
     sort_by: &SortByC,
     keywords: *mut c_char,
@@ -76,6 +80,67 @@ pub extern "C" fn retrieve_available_favorites(
     }
 }
 
+/// Retrieves the available favorites from a PopcornFX instance.
+///
+/// This function retrieves favorites from the provided `popcorn_fx` instance,
+/// filtering them based on the specified `genre`, `sort_by`, `keywords`, and `page`.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a PopcornFX instance.
+/// * `genre` - A pointer to a GenreC struct, representing the genre filter.
+/// * `sort_by` - A pointer to a SortByC struct, representing the sorting criteria.
+/// * `keywords` - A pointer to a C-style string containing search keywords.
+/// * `page` - The page number for pagination.
+///
+/// # Returns
+///
+/// It returns the [MediaSetC] of favorites on success,
+/// else the [MediaErrorC] describing the reason of the failure.
+/// The media set should be disposed with `dispose_media_items`.
+#[no_mangle]
+pub extern "C" fn retrieve_favorites(
+    popcorn_fx: &PopcornFX,
+    genre: &GenreC,
+    sort_by: &SortByC,
+    keywords: *mut c_char,
+    page: u32,
+) -> MediaSetResult {
+    let genre = genre.to_struct();
+    let sort_by = sort_by.to_struct();
+    let keywords = from_c_string(keywords);
+
+    trace!(
+        "Retrieving favorites from C for genre: {:?}, sort_by: {:?}, page: {}",
+        genre,
+        sort_by,
+        page
+    );
+    match popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.providers().retrieve(
+            &Category::Favorites,
+            &genre,
+            &sort_by,
+            &keywords,
+            page,
+        )) {
+        Ok(e) => {
+            info!("Retrieved a total of {} favorites, {:?}", e.len(), &e);
+            if e.len() > 0 {
+                MediaSetResult::Ok(MediaSetC::from_media(e))
+            } else {
+                debug!("No favorites have been found");
+                MediaSetResult::Err(MediaErrorC::NoItemsFound)
+            }
+        }
+        Err(e) => {
+            error!("Failed to retrieve favorites, {}", e);
+            MediaSetResult::from(e)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tempfile::tempdir;
@@ -83,6 +148,7 @@ mod tests {
     use popcorn_fx_core::core::media::{Genre, SortBy};
     use popcorn_fx_core::testing::init_logger;
 
+    use crate::ffi::dispose_media_items;
     use crate::test::default_args;
 
     use super::*;
@@ -92,10 +158,10 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let result = retrieve_available_favorites(
-            &mut instance,
+            &instance,
             &GenreC::from(Genre::all()),
             &SortByC::from(SortBy::new("Watched".to_string(), "watched".to_string())),
             ptr::null_mut(),
@@ -107,4 +173,28 @@ mod tests {
             "expected the favorites set to be non-null"
         );
     }
+
+    #[test]
+    fn test_retrieve_favorites_no_items() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = retrieve_favorites(
+            &instance,
+            &GenreC::from(Genre::all()),
+            &SortByC::from(SortBy::new("Watched".to_string(), "watched".to_string())),
+            ptr::null_mut(),
+            0,
+        );
+
+        match result {
+            MediaSetResult::Err(e) => assert_eq!(MediaErrorC::NoItemsFound, e),
+            MediaSetResult::Ok(set) => {
+                dispose_media_items(set);
+                assert!(false, "expected MediaSetResult::Err")
+            }
+        }
+    }
 }
@@ -3,9 +3,10 @@ use std::ptr;
 
 use log::{trace, warn};
 
-use popcorn_fx_core::{from_c_string, into_c_owned};
+use popcorn_fx_core::core::images::ImageSize;
+use popcorn_fx_core::{from_c_string, from_c_vec, into_c_owned};
 
-use crate::ffi::{ByteArray, MediaItemC};
+use crate::ffi::{ByteArray, ImageCacheStatsC, MediaItemC};
 use crate::PopcornFX;
 
 /// Retrieve the default poster (placeholder) image data as a C compatible byte array.
@@ -15,7 +16,7 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 ///
 /// # Returns
 ///
@@ -25,7 +26,7 @@ use crate::PopcornFX;
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
 #[no_mangle]
-pub extern "C" fn poster_placeholder(popcorn_fx: &mut PopcornFX) -> *mut ByteArray {
+pub extern "C" fn poster_placeholder(popcorn_fx: &PopcornFX) -> *mut ByteArray {
     trace!("Retrieving the default poster image from C");
     into_c_owned(ByteArray::from(popcorn_fx.image_loader().default_poster()))
 }
@@ -37,13 +38,13 @@ pub extern "C" fn poster_placeholder(popcorn_fx: &mut PopcornFX) -> *mut ByteArr
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
 #[no_mangle]
-pub extern "C" fn artwork_placeholder(popcorn_fx: &mut PopcornFX) -> *mut ByteArray {
+pub extern "C" fn artwork_placeholder(popcorn_fx: &PopcornFX) -> *mut ByteArray {
     trace!("Retrieving the default artwork image from C");
     into_c_owned(ByteArray::from(popcorn_fx.image_loader().default_artwork()))
 }
@@ -54,7 +55,7 @@ pub extern "C" fn artwork_placeholder(popcorn_fx: &mut PopcornFX) -> *mut ByteAr
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to the `PopcornFX` instance that will load the image data.
+/// * `popcorn_fx` - a reference to the `PopcornFX` instance that will load the image data.
 /// * `media` - a C-compatible media item holder that contains information about the media item to load.
 ///
 /// # Returns
@@ -66,7 +67,7 @@ pub extern "C" fn artwork_placeholder(popcorn_fx: &mut PopcornFX) -> *mut ByteAr
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
 #[no_mangle]
-pub extern "C" fn load_fanart(popcorn_fx: &mut PopcornFX, media: &MediaItemC) -> *mut ByteArray {
+pub extern "C" fn load_fanart(popcorn_fx: &PopcornFX, media: &MediaItemC) -> *mut ByteArray {
     trace!("Loading fanart from C for {:?}", media);
     let image_loader = popcorn_fx.image_loader().clone();
     popcorn_fx.runtime().block_on(async move {
@@ -86,14 +87,14 @@ pub extern "C" fn load_fanart(popcorn_fx: &mut PopcornFX, media: &MediaItemC) ->
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `media` - a reference to a `MediaItemC` object that represents the media item to load.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
 #[no_mangle]
-pub extern "C" fn load_poster(popcorn_fx: &mut PopcornFX, media: &MediaItemC) -> *mut ByteArray {
+pub extern "C" fn load_poster(popcorn_fx: &PopcornFX, media: &MediaItemC) -> *mut ByteArray {
     trace!("Loading poster from C for {:?}", media);
     let image_loader = popcorn_fx.image_loader().clone();
     popcorn_fx.runtime().block_on(async move {
@@ -113,14 +114,14 @@ pub extern "C" fn load_poster(popcorn_fx: &mut PopcornFX, media: &MediaItemC) ->
 ///
 /// # Arguments
 ///
-/// * popcorn_fx - a mutable reference to a PopcornFX instance.
+/// * popcorn_fx - a reference to a PopcornFX instance.
 /// * url - a pointer to a null-terminated C string that contains the URL from which to load the image data.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the returned byte array should be disposed of using the dispose_byte_array function.
 #[no_mangle]
-pub extern "C" fn load_image(popcorn_fx: &mut PopcornFX, url: *mut c_char) -> *mut ByteArray {
+pub extern "C" fn load_image(popcorn_fx: &PopcornFX, url: *mut c_char) -> *mut ByteArray {
     trace!("Loading image url from C for {:?}", url);
     let url = from_c_string(url);
     let image_loader = popcorn_fx.image_loader().clone();
@@ -135,13 +136,108 @@ pub extern "C" fn load_image(popcorn_fx: &mut PopcornFX, url: *mut c_char) -> *m
     })
 }
 
+/// Load the image data from the given URL, resized to fit within the given width and height.
+///
+/// The aspect ratio of the image is preserved and the image is never upscaled.
+/// The resized image is cached separately for each requested size.
+/// If the image data couldn't be decoded, the original image data is returned instead.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `url` - a pointer to a null-terminated C string that contains the URL from which to load the image data.
+/// * `width` - the maximum width of the image, `0` for an unbounded width.
+/// * `height` - the maximum height of the image, `0` for an unbounded height.
+///
+/// # Returns
+///
+/// A pointer to a `ByteArray` containing the image data, or a null pointer when the data couldn't be loaded.
+///
+/// # Safety
+///
+/// This function should only be called from C code, and the returned byte array should be disposed of using the `dispose_byte_array` function.
+#[no_mangle]
+pub extern "C" fn load_image_resized(
+    popcorn_fx: &PopcornFX,
+    url: *mut c_char,
+    width: u32,
+    height: u32,
+) -> *mut ByteArray {
+    let url = from_c_string(url);
+    let size = ImageSize::new(width, height);
+    trace!("Loading image url from C for {} with size {}", url, size);
+    let image_loader = popcorn_fx.image_loader().clone();
+    popcorn_fx.runtime().block_on(async move {
+        match image_loader.load_resized(url.as_str(), size).await {
+            None => {
+                warn!("Failed to load the image data from url {}", url);
+                ptr::null_mut()
+            }
+            Some(data) => into_c_owned(ByteArray::from(data)),
+        }
+    })
+}
+
+/// Prefetch the image data of the given URLs into the image cache.
+///
+/// The images are fetched concurrently and this function returns once all URLs have been cached or failed.
+/// URLs which are already cached or being fetched are skipped.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `len` - the number of URLs within the array.
+/// * `urls` - a pointer to an array of null-terminated C strings that contain the image URLs.
+///
+/// # Returns
+///
+/// The number of images which have been cached by the prefetch.
+#[no_mangle]
+pub extern "C" fn prefetch_images(popcorn_fx: &PopcornFX, len: i32, urls: *mut *mut c_char) -> i32 {
+    trace!("Prefetching {} image urls from C", len);
+    let urls = from_c_vec(urls, len)
+        .into_iter()
+        .map(|e| from_c_string(e))
+        .collect::<Vec<String>>();
+    let image_loader = popcorn_fx.image_loader().clone();
+    popcorn_fx
+        .runtime()
+        .block_on(async move { image_loader.prefetch(urls).await as i32 })
+}
+
+/// Retrieve the statistics of the image cache.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// The C-compatible statistics of the image cache.
+#[no_mangle]
+pub extern "C" fn image_cache_stats(popcorn_fx: &PopcornFX) -> ImageCacheStatsC {
+    trace!("Retrieving the image cache statistics from C");
+    ImageCacheStatsC::from(popcorn_fx.image_loader().cache_stats())
+}
+
+/// Remove all cached images from the image cache.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+#[no_mangle]
+pub extern "C" fn clear_image_cache(popcorn_fx: &PopcornFX) {
+    trace!("Clearing the image cache from C");
+    popcorn_fx.image_loader().clear_cache()
+}
+
 #[cfg(test)]
 mod test {
     use httpmock::Method::GET;
     use httpmock::MockServer;
     use tempfile::tempdir;
 
-    use popcorn_fx_core::{from_c_owned, from_c_vec, into_c_string};
+    use popcorn_fx_core::{from_c_owned, into_c_string, into_c_vec};
     use popcorn_fx_core::core::media::{Images, MovieDetails, ShowDetails};
     use popcorn_fx_core::testing::{init_logger, read_test_file_to_bytes};
 
@@ -154,9 +250,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let array = from_c_owned(poster_placeholder(&mut instance));
+        let array = from_c_owned(poster_placeholder(&instance));
         let result = from_c_vec(array.values, array.len);
 
         assert!(result.len() > 0)
@@ -189,9 +285,9 @@ mod test {
             trailer: "".to_string(),
             torrents: Default::default(),
         };
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let array = from_c_owned(load_fanart(&mut instance, &MediaItemC::from(media)));
+        let array = from_c_owned(load_fanart(&instance, &MediaItemC::from(media)));
         let result = from_c_vec(array.values, array.len);
 
         assert_eq!(expected_result, result)
@@ -228,9 +324,9 @@ mod test {
             episodes: vec![],
             liked: None,
         };
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let array = from_c_owned(load_poster(&mut instance, &MediaItemC::from(media)));
+        let array = from_c_owned(load_poster(&instance, &MediaItemC::from(media)));
         let result = from_c_vec(array.values, array.len);
 
         assert_eq!(expected_result, result)
@@ -247,14 +343,110 @@ mod test {
             when.method(GET).path("/image.png");
             then.status(200).body(expected_result.as_slice());
         });
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let array = from_c_owned(load_image(
-            &mut instance,
+            &instance,
             into_c_string(server.url("/image.png")),
         ));
         let result = from_c_vec(array.values, array.len);
 
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_load_image_resized() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let original = read_test_file_to_bytes("image.jpg");
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/image.jpg");
+            then.status(200).body(original.as_slice());
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let array = from_c_owned(load_image_resized(
+            &instance,
+            into_c_string(server.url("/image.jpg")),
+            200,
+            0,
+        ));
+        let result = from_c_vec(array.values, array.len);
+
+        assert_eq!(vec![0xFF, 0xD8], result[..2].to_vec());
+        assert!(
+            result.len() < original.len(),
+            "expected the image to have been resized"
+        );
+    }
+
+    #[test]
+    fn test_prefetch_images() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/image.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.jpg").as_slice());
+        });
+        let (urls, len) = into_c_vec(vec![
+            into_c_string(server.url("/image.png")),
+            into_c_string(server.url("/image.png")),
+        ]);
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = prefetch_images(&instance, len, urls);
+
+        assert_eq!(1, result);
+        mock.assert_hits(1);
+    }
+
+    #[test]
+    fn test_image_cache_stats() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/image.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.jpg").as_slice());
+        });
+        let url = server.url("/image.png");
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let _ = from_c_owned(load_image(&instance, into_c_string(url.clone())));
+        let _ = from_c_owned(load_image(&instance, into_c_string(url)));
+        let result = image_cache_stats(&instance);
+
+        assert_eq!(1, result.entries);
+        assert_eq!(1, result.hits);
+        assert_eq!(1, result.misses);
+    }
+
+    #[test]
+    fn test_clear_image_cache() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/image.png");
+            then.status(200)
+                .body(read_test_file_to_bytes("image.jpg").as_slice());
+        });
+        let url = server.url("/image.png");
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let _ = from_c_owned(load_image(&instance, into_c_string(url.clone())));
+        clear_image_cache(&instance);
+        assert_eq!(0, image_cache_stats(&instance).entries);
+
+        let _ = from_c_owned(load_image(&instance, into_c_string(url)));
+        mock.assert_hits(2);
+    }
 }
@@ -1,14 +1,17 @@
 use std::os::raw::c_char;
+use std::ptr;
 
-use log::{trace, warn};
+use log::{error, trace, warn};
 
 use popcorn_fx_core::core::Handle;
+use popcorn_fx_core::core::config::Quality;
+use popcorn_fx_core::core::loader::{LoadingPolicy, PreferredQuality};
 use popcorn_fx_core::core::playlists::PlaylistItem;
 use popcorn_fx_core::core::torrents::{TorrentFileInfo, TorrentInfo};
-use popcorn_fx_core::from_c_string;
+use popcorn_fx_core::{from_c_owned, from_c_string, from_c_vec_owned};
 
 use crate::ffi::{
-    LoaderEventC, LoaderEventCallback, LoadingHandleC, TorrentFileInfoC, TorrentInfoC,
+    CArray, LoaderEventC, LoaderEventCallback, LoadingHandleC, TorrentFileInfoC, TorrentInfoC,
 };
 use crate::PopcornFX;
 
@@ -19,13 +22,10 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the PopcornFX instance to register the callback with.
+/// * `instance` - A reference to the PopcornFX instance to register the callback with.
 /// * `callback` - A C-compatible callback function that will be invoked when loader state change events occur.
 #[no_mangle]
-pub extern "C" fn register_loader_callback(
-    instance: &mut PopcornFX,
-    callback: LoaderEventCallback,
-) {
+pub extern "C" fn register_loader_callback(instance: &PopcornFX, callback: LoaderEventCallback) {
     trace!("Registering new loader callback");
     instance.media_loader().subscribe(Box::new(move |e| {
         trace!("Invoking loader C callback for {}", e);
@@ -35,19 +35,19 @@ pub extern "C" fn register_loader_callback(
 
 /// Load a media item using the media loader from a C-compatible URL.
 ///
-/// This function takes a mutable reference to a `PopcornFX` instance and a C-compatible string (`*mut c_char`) representing the URL of the media item to load.
+/// This function takes a reference to a `PopcornFX` instance and a C-compatible string (`*mut c_char`) representing the URL of the media item to load.
 /// It uses the media loader to load the media item asynchronously and returns a handle (represented as a `LoadingHandleC`) for the loading process.
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `url` - A C-compatible string representing the URL of the media item to load.
 ///
 /// # Returns
 ///
 /// A `LoadingHandleC` representing the loading process associated with the loaded item.
 #[no_mangle]
-pub extern "C" fn loader_load(instance: &mut PopcornFX, url: *mut c_char) -> LoadingHandleC {
+pub extern "C" fn loader_load(instance: &PopcornFX, url: *mut c_char) -> LoadingHandleC {
     let url = from_c_string(url);
     trace!("Loading new loader url {} from C", url);
     let handle = instance.media_loader().load_url(url.as_str());
@@ -64,7 +64,7 @@ pub extern "C" fn loader_load(instance: &mut PopcornFX, url: *mut c_char) -> Loa
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the PopcornFX instance.
+/// * `instance` - A reference to the PopcornFX instance.
 /// * `torrent_info` - Information about the torrent.
 /// * `torrent_file` - Details of the torrent file.
 ///
@@ -73,7 +73,7 @@ pub extern "C" fn loader_load(instance: &mut PopcornFX, url: *mut c_char) -> Loa
 /// Returns a handle to the loading process.
 #[no_mangle]
 pub extern "C" fn loader_load_torrent_file(
-    instance: &mut PopcornFX,
+    instance: &PopcornFX,
     torrent_info: TorrentInfoC,
     torrent_file: TorrentFileInfoC,
 ) -> LoadingHandleC {
@@ -96,13 +96,101 @@ pub extern "C" fn loader_load_torrent_file(
     handle.value() as *const i64
 }
 
+/// Loads a single file of a multi-file torrent by its index within the torrent, e.g. an episode of a season pack.
+///
+/// # Safety
+///
+/// This function accepts a value of the C struct `TorrentInfoC` as argument.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the PopcornFX instance.
+/// * `torrent_info` - Information about the torrent.
+/// * `file_index` - The index of the file within the torrent to load.
+///
+/// # Returns
+///
+/// Returns a handle to the loading process, or a null pointer when the file index is out of range.
+#[no_mangle]
+pub extern "C" fn loader_load_torrent_file_index(
+    instance: &PopcornFX,
+    torrent_info: TorrentInfoC,
+    file_index: i32,
+) -> LoadingHandleC {
+    trace!(
+        "Loading torrent file index {} from C for info: {:?}",
+        file_index,
+        torrent_info
+    );
+    let torrent_info = TorrentInfo::from(torrent_info);
+    let torrent_file = match torrent_info.by_file_index(file_index) {
+        Ok(e) => e,
+        Err(e) => {
+            error!("Unable to load torrent {}, {}", torrent_info.name, e);
+            return ptr::null();
+        }
+    };
+    let item = PlaylistItem::builder()
+        .title(torrent_file.filename())
+        .torrent_info(torrent_info)
+        .torrent_file_info(torrent_file)
+        .subtitles_enabled(true)
+        .build();
+
+    let handle = instance.media_loader().load_playlist_item(item);
+
+    handle.value() as *const i64
+}
+
+/// Downloads a torrent file using its information and file details, without streaming or playing it.
+///
+/// The torrent file is downloaded in the background and can be streamed later on through `loader_load_torrent_file`,
+/// which reuses the already downloaded pieces.
+///
+/// # Safety
+///
+/// This function accepts values to C structs (`TorrentInfoC` and `TorrentFileInfoC`) as arguments.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the PopcornFX instance.
+/// * `torrent_info` - Information about the torrent.
+/// * `torrent_file` - Details of the torrent file.
+///
+/// # Returns
+///
+/// Returns a handle to the loading process.
+#[no_mangle]
+pub extern "C" fn loader_download_torrent_file(
+    instance: &PopcornFX,
+    torrent_info: TorrentInfoC,
+    torrent_file: TorrentFileInfoC,
+) -> LoadingHandleC {
+    trace!(
+        "Downloading torrent file from C for info: {:?}, file: {:?}",
+        torrent_info,
+        torrent_file
+    );
+    let torrent_info = TorrentInfo::from(torrent_info);
+    let torrent_file = TorrentFileInfo::from(torrent_file);
+    let item = PlaylistItem::builder()
+        .title(torrent_file.filename())
+        .torrent_info(torrent_info)
+        .torrent_file_info(torrent_file)
+        .build();
+
+    let handle = instance.media_loader().download_playlist_item(item);
+
+    handle.value() as *const i64
+}
+
 /// Cancels the current media loading process initiated by the `MediaLoader`.
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn loader_cancel(instance: &mut PopcornFX, handle: LoadingHandleC) {
+pub extern "C" fn loader_cancel(instance: &PopcornFX, handle: LoadingHandleC) {
     if !handle.is_null() {
         trace!("Cancelling the loader");
         let handle = Handle::from(handle as i64);
@@ -112,6 +200,87 @@ pub extern "C" fn loader_cancel(instance: &mut PopcornFX, handle: LoadingHandleC
     }
 }
 
+/// Update the policy which is applied when a new media item is loaded while another item is still loading.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `policy` - The new loading policy.
+#[no_mangle]
+pub extern "C" fn loader_set_policy(instance: &PopcornFX, policy: LoadingPolicy) {
+    trace!("Updating loader policy to {} from C", policy);
+    instance.media_loader().set_policy(policy);
+}
+
+/// Retrieve the handle of the loading task which is currently being processed by the media loader.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns the handle of the active loading task, or a null pointer when no media item is being loaded.
+#[no_mangle]
+pub extern "C" fn loader_active_task(instance: &PopcornFX) -> LoadingHandleC {
+    trace!("Retrieving the active loader task from C");
+    match instance.media_loader().active_task() {
+        Some(handle) => handle.value() as *const i64,
+        None => ptr::null(),
+    }
+}
+
+/// Retrieve the handles of the loading tasks which are waiting for the active loading task to complete.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A CArray of the queued loading handles in the order in which they will be started.
+#[no_mangle]
+pub extern "C" fn loader_queued_tasks(instance: &PopcornFX) -> CArray<i64> {
+    trace!("Retrieving the queued loader tasks from C");
+    let handles: Vec<i64> = instance
+        .media_loader()
+        .queued_tasks()
+        .into_iter()
+        .map(|e| e.value())
+        .collect();
+
+    CArray::from(handles)
+}
+
+/// Update the preferred quality which is used to load media items without a quality.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `quality` - The preferred quality, or `ptr::null()` to select the quality automatically.
+#[no_mangle]
+pub extern "C" fn loader_set_preferred_quality(instance: &PopcornFX, quality: *mut Quality) {
+    let quality = if !quality.is_null() {
+        PreferredQuality::Fixed(from_c_owned(quality))
+    } else {
+        PreferredQuality::Auto
+    };
+
+    trace!("Updating preferred loader quality to {} from C", quality);
+    instance.quality_selector().set_preferred_quality(quality);
+}
+
+/// Dispose of a C-style array of loading handles.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of loading handles to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_loading_handle_set(set: Box<CArray<i64>>) {
+    trace!("Disposing loading handle set {:?}", set);
+    drop(from_c_vec_owned(set.items, set.len));
+}
+
 /// Dispose of a C-compatible LoaderEventC value.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible LoaderEventC value.
@@ -139,8 +308,9 @@ mod tests {
     };
     use popcorn_fx_core::core::media::MovieDetails;
     use popcorn_fx_core::core::playlists::PlaylistItem;
-    use popcorn_fx_core::into_c_string;
+    use popcorn_fx_core::core::torrents::TorrentVersion;
     use popcorn_fx_core::testing::init_logger;
+    use popcorn_fx_core::{into_c_owned, into_c_string};
 
     use crate::ffi::CArray;
     use crate::test::default_args;
@@ -181,23 +351,43 @@ mod tests {
             auto_resume_timestamp: None,
             subtitles_enabled: false,
         };
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_loader_callback(&mut instance, loader_callback);
+        register_loader_callback(&instance, loader_callback);
         let result = instance.media_loader().load_playlist_item(item);
 
         assert_ne!(result.value(), 0);
     }
 
+    #[test]
+    fn test_loader_set_preferred_quality() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        loader_set_preferred_quality(&instance, into_c_owned(Quality::P720));
+        assert_eq!(
+            PreferredQuality::Fixed(Quality::P720),
+            instance.quality_selector().preferred_quality()
+        );
+
+        loader_set_preferred_quality(&instance, ptr::null_mut());
+        assert_eq!(
+            PreferredQuality::Auto,
+            instance.quality_selector().preferred_quality()
+        );
+    }
+
     #[test]
     fn test_loader_load() {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
         let url = "magnet:?xt=urn:btih:9a5c24e8164dfe5a98d2437b7f4d6ec9a7e2e045&dn=Another%20Example%20File&tr=http%3A%2F%2Ftracker.anotherexample.com%3A56789%2Fannounce&xl=987654321&sf=Another%20Folder";
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let result = loader_load(&mut instance, into_c_string(url.to_string()));
+        let result = loader_load(&instance, into_c_string(url.to_string()));
 
         assert_ne!(0i64, result as i64);
     }
@@ -213,6 +403,7 @@ mod tests {
             name: into_c_string("TorrentName"),
             directory_name: ptr::null_mut(),
             total_files: 0,
+            version: TorrentVersion::V1,
             files: CArray::from(vec![]),
         };
         let torrent_file = TorrentFileInfoC {
@@ -227,12 +418,12 @@ mod tests {
             tx.send(e.clone()).unwrap();
             LoadingResult::Ok(e)
         });
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance
             .media_loader()
             .add(Box::new(loading_strategy), HIGHEST_ORDER);
-        let result = loader_load_torrent_file(&mut instance, torrent_info, torrent_file);
+        let result = loader_load_torrent_file(&instance, torrent_info, torrent_file);
         assert_ne!(0, result as i64);
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
@@ -240,14 +431,161 @@ mod tests {
         assert_eq!(Some(true), result.subtitles_enabled);
     }
 
+    #[test]
+    fn test_loader_load_torrent_file_index() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filename = "Episode5.mkv";
+        let torrent_info = TorrentInfoC {
+            uri: into_c_string("TorrentUri"),
+            name: into_c_string("TorrentName"),
+            directory_name: ptr::null_mut(),
+            total_files: 2,
+            version: TorrentVersion::V1,
+            files: CArray::from(vec![
+                TorrentFileInfoC {
+                    filename: into_c_string("Episode1.mkv"),
+                    file_path: into_c_string("Season1/Episode1.mkv"),
+                    file_size: 128000,
+                    file_index: 0,
+                },
+                TorrentFileInfoC {
+                    filename: into_c_string(filename),
+                    file_path: into_c_string("Season1/Episode5.mkv"),
+                    file_size: 128000,
+                    file_index: 4,
+                },
+            ]),
+        };
+        let (tx, rx) = channel();
+        let mut loading_strategy = MockLoadingStrategy::new();
+        loading_strategy.expect_process().returning(move |e, _, _| {
+            tx.send(e.clone()).unwrap();
+            LoadingResult::Ok(e)
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance
+            .media_loader()
+            .add(Box::new(loading_strategy), HIGHEST_ORDER);
+        let result = loader_load_torrent_file_index(&instance, torrent_info, 4);
+        assert_ne!(0, result as i64);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(Some(filename.to_string()), result.title);
+        assert_eq!(
+            Some(4),
+            result.torrent_file_info.as_ref().map(|e| e.file_index)
+        );
+    }
+
+    #[test]
+    fn test_loader_load_torrent_file_index_out_of_range() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let torrent_info = TorrentInfoC {
+            uri: into_c_string("TorrentUri"),
+            name: into_c_string("TorrentName"),
+            directory_name: ptr::null_mut(),
+            total_files: 1,
+            version: TorrentVersion::V1,
+            files: CArray::from(vec![TorrentFileInfoC {
+                filename: into_c_string("Episode1.mkv"),
+                file_path: into_c_string("Season1/Episode1.mkv"),
+                file_size: 128000,
+                file_index: 0,
+            }]),
+        };
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = loader_load_torrent_file_index(&instance, torrent_info, 3);
+
+        assert!(result.is_null(), "expected a null handle to be returned");
+    }
+
+    #[test]
+    fn test_loader_download_torrent_file() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filename = "MyVideoFile.mkv";
+        let torrent_info = TorrentInfoC {
+            uri: into_c_string("TorrentUri"),
+            name: into_c_string("TorrentName"),
+            directory_name: ptr::null_mut(),
+            total_files: 0,
+            version: TorrentVersion::V1,
+            files: CArray::from(vec![]),
+        };
+        let torrent_file = TorrentFileInfoC {
+            filename: into_c_string(filename),
+            file_path: into_c_string("/tmp/some/path/MyVideoFile.mkv"),
+            file_size: 128000,
+            file_index: 0,
+        };
+        let (tx, rx) = channel();
+        let mut loading_strategy = MockLoadingStrategy::new();
+        loading_strategy.expect_process().returning(move |e, _, _| {
+            tx.send(e.clone()).unwrap();
+            LoadingResult::Completed
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance
+            .media_loader()
+            .add(Box::new(loading_strategy), HIGHEST_ORDER);
+        let result = loader_download_torrent_file(&instance, torrent_info, torrent_file);
+        assert_ne!(0, result as i64);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(Some(filename.to_string()), result.title);
+        assert_eq!(true, result.download_only);
+    }
+
     #[test]
     fn test_loader_cancel() {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        loader_cancel(&instance, 874458i64 as *const i64);
+    }
+
+    #[test]
+    fn test_loader_queued_tasks() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut loading_strategy = MockLoadingStrategy::new();
+        loading_strategy.expect_process().returning(|_, _, _| {
+            std::thread::sleep(Duration::from_millis(500));
+            LoadingResult::Completed
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance
+            .media_loader()
+            .add(Box::new(loading_strategy), HIGHEST_ORDER);
+        assert_eq!(ptr::null(), loader_active_task(&instance));
+
+        loader_set_policy(&instance, LoadingPolicy::Queue);
+        let handle1 = loader_load(&instance, into_c_string("http://localhost/video1.mp4"));
+        let handle2 = loader_load(&instance, into_c_string("http://localhost/video2.mp4"));
+
+        assert_eq!(handle1, loader_active_task(&instance));
+        let result = loader_queued_tasks(&instance);
+        assert_eq!(vec![handle2 as i64], Vec::<i64>::from(result));
+    }
+
+    #[test]
+    fn test_dispose_loading_handle_set() {
+        init_logger();
+        let set = CArray::from(vec![1i64, 2i64]);
 
-        loader_cancel(&mut instance, 874458i64 as *const i64);
+        dispose_loading_handle_set(Box::new(set));
     }
 
     #[test]
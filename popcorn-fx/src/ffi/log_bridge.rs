@@ -1,10 +1,11 @@
 use std::os::raw::c_char;
 
-use log::{debug, error, info, trace, warn};
+use log::{debug, error, info, trace, warn, LevelFilter};
 
 use popcorn_fx_core::from_c_string;
 
-use crate::ffi::LogLevel;
+use crate::ffi::{LogLevel, LogRecordC, LogRecordCallbackC};
+use crate::PopcornFX;
 
 /// Logs a message sent over FFI using the Rust logger.
 ///
@@ -26,12 +27,86 @@ pub extern "C" fn log(target: *mut c_char, message: *mut c_char, level: LogLevel
     }
 }
 
+/// Set the log level of the given backend module at runtime.
+/// The level applies to the module and all its submodules, unless they have a level of their own.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `target` - The module path, e.g. `popcorn_fx_torrent`.
+/// * `level` - The new log level of the module.
+///
+/// # Returns
+///
+/// It returns `true` when the log level has been changed, else `false` when the log levels can't be controlled.
+#[no_mangle]
+pub extern "C" fn set_log_level(
+    popcorn_fx: &PopcornFX,
+    target: *mut c_char,
+    level: LogLevel,
+) -> bool {
+    let target = from_c_string(target);
+    trace!("Updating log level of {} to {:?} from C", target, level);
+
+    match popcorn_fx.log_controller() {
+        Some(controller) => {
+            controller.set_level(target.as_str(), LevelFilter::from(level));
+            true
+        }
+        None => {
+            warn!(
+                "Unable to update log level of {}, log levels can't be controlled at runtime",
+                target
+            );
+            false
+        }
+    }
+}
+
+/// Subscribe to the backend log records.
+/// The most recent log records are replayed to the callback before new records are forwarded.
+/// Only one subscriber is supported, a new subscription replaces the previous one.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback which receives the backend log records.
+#[no_mangle]
+pub extern "C" fn register_log_record_callback(
+    popcorn_fx: &PopcornFX,
+    callback: LogRecordCallbackC,
+) {
+    trace!("Registering new log record callback for C");
+    match popcorn_fx.log_buffer() {
+        Some(buffer) => buffer.subscribe(Box::new(move |record| {
+            callback(LogRecordC::from(record));
+        })),
+        None => warn!("Unable to subscribe to the log records, default logger is not active"),
+    }
+}
+
+/// Remove the subscription of the backend log records.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+#[no_mangle]
+pub extern "C" fn unregister_log_record_callback(popcorn_fx: &PopcornFX) {
+    trace!("Removing log record callback from C");
+    if let Some(buffer) = popcorn_fx.log_buffer() {
+        buffer.unsubscribe();
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use tempfile::tempdir;
+
     use popcorn_fx_core::into_c_string;
     use popcorn_fx_core::testing::init_logger;
 
     use crate::ffi::LogLevel::{Debug, Error, Info, Trace, Warn};
+    use crate::test::default_args;
 
     use super::*;
 
@@ -65,4 +140,33 @@ mod test {
             Error,
         );
     }
+
+    #[test]
+    fn test_set_log_level() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        let expected_result = instance.log_controller().is_some();
+
+        let result = set_log_level(
+            &instance,
+            into_c_string("popcorn_fx_torrent".to_string()),
+            Debug,
+        );
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn test_register_log_record_callback() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        extern "C" fn callback(_: LogRecordC) {}
+
+        register_log_record_callback(&instance, callback);
+        unregister_log_record_callback(&instance);
+    }
 }
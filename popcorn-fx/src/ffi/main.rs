@@ -40,7 +40,7 @@ pub extern "C" fn new_popcorn_fx(len: i32, args: *mut *mut c_char) -> *mut Popco
 
 /// Starts the discovery process for external players such as VLC and DLNA servers.
 #[no_mangle]
-pub extern "C" fn discover_external_players(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn discover_external_players(popcorn_fx: &PopcornFX) {
     trace!("Starting external player discovery from C");
     popcorn_fx.start_discovery_external_players();
 }
@@ -103,9 +103,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        discover_external_players(&mut instance);
+        discover_external_players(&instance);
     }
 
     #[test]
@@ -17,6 +17,12 @@ use crate::ffi::TorrentInfoC;
 /// with the specified signature.
 pub type EventCCallback = extern "C" fn(EventC);
 
+/// A type alias for a C-compatible event subscriber callback function.
+///
+/// The callback receives the `EventC`, the sequence number of the event,
+/// and a flag indicating if the event is being replayed.
+pub type EventSubscriberCCallback = extern "C" fn(EventC, u64, bool);
+
 /// The C compatible [Event] representation.
 #[repr(C)]
 #[derive(Debug)]
@@ -41,6 +47,9 @@ pub enum EventC {
     TorrentDetailsLoaded(TorrentInfoC),
     /// Invoked when the player should be closed
     ClosePlayer,
+    /// Invoked when an unexpected error occurred within the application
+    /// 1st argument is a pointer to the error message (C string)
+    Error(*mut c_char),
 }
 
 impl EventC {
@@ -58,6 +67,7 @@ impl EventC {
                 Some(Event::TorrentDetailsLoaded(TorrentInfo::from(e)))
             }
             EventC::ClosePlayer => Some(Event::ClosePlayer),
+            EventC::Error(message) => Some(Event::Error(from_c_string(message))),
             _ => None,
         }
     }
@@ -78,6 +88,7 @@ impl From<Event> for EventC {
             Event::LoadingCompleted => EventC::LoadingCompleted,
             Event::TorrentDetailsLoaded(e) => EventC::TorrentDetailsLoaded(TorrentInfoC::from(e)),
             Event::ClosePlayer => EventC::ClosePlayer,
+            Event::Error(message) => EventC::Error(into_c_string(message)),
         }
     }
 }
@@ -165,6 +176,17 @@ mod test {
         assert_eq!(Event::LoadingCompleted, event);
     }
 
+    #[test]
+    fn test_from_event_c_error_to_event() {
+        let message = "lorem ipsum";
+
+        let event = EventC::Error(into_c_string(message.to_string()))
+            .into_event()
+            .unwrap();
+
+        assert_eq!(Event::Error(message.to_string()), event);
+    }
+
     #[test]
     fn test_from_event_c_player_stopped_to_event() {
         let event = EventC::PlayerStopped.into_event();
@@ -0,0 +1,66 @@
+use popcorn_fx_core::core::images::ImageCacheStats;
+
+/// The C-compatible representation of the [ImageCacheStats] struct.
+///
+/// # Fields
+///
+/// * `entries` - The number of cached image urls.
+/// * `size` - The total size in bytes of the cached image files.
+/// * `max_size` - The maximum size in bytes of the cached image files.
+/// * `hits` - The number of requests which have been served from the cache.
+/// * `misses` - The number of requests which couldn't be served from the cache.
+/// * `revalidations` - The number of stale cached images which have been revalidated.
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+pub struct ImageCacheStatsC {
+    pub entries: i32,
+    pub size: u64,
+    pub max_size: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub revalidations: u64,
+}
+
+impl From<ImageCacheStats> for ImageCacheStatsC {
+    fn from(value: ImageCacheStats) -> Self {
+        Self {
+            entries: value.entries as i32,
+            size: value.size,
+            max_size: value.max_size,
+            hits: value.hits,
+            misses: value.misses,
+            revalidations: value.revalidations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_image_cache_stats() {
+        let stats = ImageCacheStats {
+            entries: 12,
+            size: 20480,
+            max_size: 40960,
+            hits: 8,
+            misses: 4,
+            revalidations: 1,
+        };
+
+        let result = ImageCacheStatsC::from(stats);
+
+        assert_eq!(
+            ImageCacheStatsC {
+                entries: 12,
+                size: 20480,
+                max_size: 40960,
+                hits: 8,
+                misses: 4,
+                revalidations: 1,
+            },
+            result
+        );
+    }
+}
@@ -3,7 +3,8 @@ use std::os::raw::c_char;
 use std::ptr;
 
 use popcorn_fx_core::core::loader::{
-    LoaderEvent, LoadingError, LoadingProgress, LoadingStartedEvent, LoadingState,
+    LoaderEvent, LoadingError, LoadingProgress, LoadingStageProgress, LoadingStartedEvent,
+    LoadingState, QualitySelection,
 };
 use popcorn_fx_core::{from_c_string, into_c_string};
 
@@ -24,6 +25,8 @@ pub enum LoaderEventC {
     StateChanged(i64, LoadingState),
     ProgressChanged(i64, LoadingProgressC),
     LoaderError(i64, LoadingErrorC),
+    StageProgressChanged(i64, LoadingStageProgressC),
+    QualitySelected(i64, QualitySelectionC),
 }
 
 impl From<LoaderEvent> for LoaderEventC {
@@ -39,6 +42,12 @@ impl From<LoaderEvent> for LoaderEventC {
             LoaderEvent::ProgressChanged(handle, e) => {
                 LoaderEventC::ProgressChanged(handle.value(), LoadingProgressC::from(e))
             }
+            LoaderEvent::StageProgressChanged(handle, e) => {
+                LoaderEventC::StageProgressChanged(handle.value(), LoadingStageProgressC::from(e))
+            }
+            LoaderEvent::QualitySelected(handle, e) => {
+                LoaderEventC::QualitySelected(handle.value(), QualitySelectionC::from(e))
+            }
         }
     }
 }
@@ -151,6 +160,9 @@ impl From<LoadingError> for LoadingErrorC {
                 LoadingErrorC::TorrentError(into_c_string(e.to_string()))
             }
             LoadingError::MediaError(e) => LoadingErrorC::MediaError(into_c_string(e)),
+            LoadingError::YoutubeError(e) => {
+                LoadingErrorC::MediaError(into_c_string(e.to_string()))
+            }
             LoadingError::TimeoutError(e) => LoadingErrorC::TimeoutError(into_c_string(e)),
             LoadingError::InvalidData(e) => LoadingErrorC::InvalidData(into_c_string(e)),
             LoadingError::Cancelled => LoadingErrorC::Cancelled,
@@ -191,6 +203,59 @@ impl From<LoadingProgress> for LoadingProgressC {
     }
 }
 
+/// A C-compatible struct representing the progress of a specific loading stage.
+#[repr(C)]
+#[derive(Debug)]
+pub struct LoadingStageProgressC {
+    /// The loading stage to which the progress applies.
+    pub stage: LoadingState,
+    /// The progress of the stage as a percentage between 0 and 100.
+    pub percent: f32,
+    /// Additional information about the progress of the stage, or `ptr::null()` if not available.
+    pub detail: *mut c_char,
+}
+
+impl From<LoadingStageProgress> for LoadingStageProgressC {
+    fn from(value: LoadingStageProgress) -> Self {
+        let detail = if let Some(e) = value.detail {
+            into_c_string(e)
+        } else {
+            ptr::null_mut()
+        };
+
+        Self {
+            stage: value.stage,
+            percent: value.percent,
+            detail,
+        }
+    }
+}
+
+/// A C-compatible struct representing the quality which has been selected for a media item.
+#[repr(C)]
+#[derive(Debug)]
+pub struct QualitySelectionC {
+    /// The selected quality, e.g. `1080p`.
+    pub quality: *mut c_char,
+    /// Indicates if the quality has been selected automatically.
+    pub auto_selected: bool,
+    /// The display text of the selection, e.g. `Auto (1080p)`.
+    pub label: *mut c_char,
+    /// The newline separated reasons which lead to the selected quality.
+    pub reasons: *mut c_char,
+}
+
+impl From<QualitySelection> for QualitySelectionC {
+    fn from(value: QualitySelection) -> Self {
+        Self {
+            quality: into_c_string(value.quality.clone()),
+            auto_selected: value.auto,
+            label: into_c_string(value.to_string()),
+            reasons: into_c_string(value.reasons.join("\n")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use popcorn_fx_core::core::Handle;
@@ -215,6 +280,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_loader_event_c_from_stage_progress() {
+        let event = LoaderEvent::StageProgressChanged(
+            Handle::new(),
+            LoadingStageProgress {
+                stage: LoadingState::DownloadingSubtitle,
+                percent: 100.0,
+                detail: Some("lorem.srt".to_string()),
+            },
+        );
+
+        let result = LoaderEventC::from(event);
+
+        if let LoaderEventC::StageProgressChanged(_, result) = result {
+            assert_eq!(LoadingState::DownloadingSubtitle, result.stage);
+            assert_eq!(100.0, result.percent);
+            assert_eq!("lorem.srt".to_string(), from_c_string(result.detail));
+        } else {
+            assert!(
+                false,
+                "expected LoaderEventC::StageProgressChanged, but got {:?} instead",
+                result
+            )
+        }
+    }
+
+    #[test]
+    fn test_loader_event_c_from_quality_selected() {
+        let event = LoaderEvent::QualitySelected(
+            Handle::new(),
+            QualitySelection {
+                quality: "1080p".to_string(),
+                auto: true,
+                reasons: vec![
+                    "estimated bandwidth is 2000 KB/s".to_string(),
+                    "2160p requires 3750 KB/s".to_string(),
+                ],
+            },
+        );
+
+        let result = LoaderEventC::from(event);
+
+        if let LoaderEventC::QualitySelected(_, result) = result {
+            assert_eq!("1080p".to_string(), from_c_string(result.quality));
+            assert_eq!(true, result.auto_selected);
+            assert_eq!("Auto (1080p)".to_string(), from_c_string(result.label));
+            assert_eq!(
+                "estimated bandwidth is 2000 KB/s\n2160p requires 3750 KB/s".to_string(),
+                from_c_string(result.reasons)
+            );
+        } else {
+            assert!(
+                false,
+                "expected LoaderEventC::QualitySelected, but got {:?} instead",
+                result
+            )
+        }
+    }
+
     #[test]
     fn test_loading_started_event_c_from() {
         let url = "MyUrl";
@@ -1,9 +1,20 @@
+use std::os::raw::c_char;
+
+use log::{Level, LevelFilter};
+
+use popcorn_fx_core::into_c_string;
+
+use crate::LogRecord;
+
+/// The C callback type which receives the forwarded backend log records.
+pub type LogRecordCallbackC = extern "C" fn(LogRecordC);
+
 /// The C-compatible logging level for log messages sent over FFI.
 ///
 /// This enum represents the different logging levels that can be used to send log messages from Rust to C code.
 /// It includes five different levels of logging: `Trace`, `Debug`, `Info`, `Warn`, and `Error`.
 #[repr(i32)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LogLevel {
     Off = 0,
     Trace = 1,
@@ -12,3 +23,87 @@ pub enum LogLevel {
     Warn = 4,
     Error = 5,
 }
+
+impl From<Level> for LogLevel {
+    fn from(value: Level) -> Self {
+        match value {
+            Level::Error => LogLevel::Error,
+            Level::Warn => LogLevel::Warn,
+            Level::Info => LogLevel::Info,
+            Level::Debug => LogLevel::Debug,
+            Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Trace => LevelFilter::Trace,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Error => LevelFilter::Error,
+        }
+    }
+}
+
+/// The C compatible representation of a backend log record.
+#[repr(C)]
+#[derive(Debug)]
+pub struct LogRecordC {
+    /// The unix timestamp in millis at which the record has been logged
+    pub timestamp: i64,
+    /// The level of the record
+    pub level: LogLevel,
+    /// The target (module) of the record
+    pub target: *mut c_char,
+    /// The formatted message of the record
+    pub message: *mut c_char,
+}
+
+impl From<LogRecord> for LogRecordC {
+    fn from(value: LogRecord) -> Self {
+        Self {
+            timestamp: value.timestamp,
+            level: LogLevel::from(value.level),
+            target: into_c_string(value.target),
+            message: into_c_string(value.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use popcorn_fx_core::from_c_string;
+
+    use super::*;
+
+    #[test]
+    fn test_log_record_c_from() {
+        let record = LogRecord {
+            timestamp: 1000,
+            level: Level::Debug,
+            target: "popcorn_fx_torrent".to_string(),
+            message: "lorem ipsum".to_string(),
+        };
+
+        let result = LogRecordC::from(record);
+
+        assert_eq!(1000, result.timestamp);
+        assert_eq!(LogLevel::Debug, result.level);
+        assert_eq!(
+            "popcorn_fx_torrent".to_string(),
+            from_c_string(result.target)
+        );
+        assert_eq!("lorem ipsum".to_string(), from_c_string(result.message));
+    }
+
+    #[test]
+    fn test_level_filter_from_log_level() {
+        assert_eq!(LevelFilter::Off, LevelFilter::from(LogLevel::Off));
+        assert_eq!(LevelFilter::Trace, LevelFilter::from(LogLevel::Trace));
+        assert_eq!(LevelFilter::Error, LevelFilter::from(LogLevel::Error));
+    }
+}
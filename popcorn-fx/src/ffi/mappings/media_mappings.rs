@@ -12,6 +12,8 @@ use popcorn_fx_core::core::media::{
     MovieDetails, MovieOverview, Rating, ShowDetails, ShowOverview, SortBy, TorrentInfo,
 };
 use popcorn_fx_core::core::media::favorites::FavoriteEvent;
+use popcorn_fx_core::core::media::providers::ConnectionTestResult;
+use popcorn_fx_core::core::media::resume::ContinueWatchingItem;
 use popcorn_fx_core::core::media::watched::WatchedEvent;
 
 /// The C compatible media result for an array of media items.
@@ -24,10 +26,7 @@ pub enum MediaSetResult {
 
 impl From<MediaError> for MediaSetResult {
     fn from(value: MediaError) -> Self {
-        match value {
-            MediaError::NoAvailableProviders => Self::Err(MediaErrorC::NoAvailableProviders),
-            _ => Self::Err(MediaErrorC::Failed),
-        }
+        Self::Err(MediaErrorC::from(value))
     }
 }
 
@@ -41,10 +40,7 @@ pub enum MediaResult {
 
 impl From<MediaError> for MediaResult {
     fn from(value: MediaError) -> Self {
-        match value {
-            MediaError::NoAvailableProviders => Self::Err(MediaErrorC::NoAvailableProviders),
-            _ => Self::Err(MediaErrorC::Failed),
-        }
+        Self::Err(MediaErrorC::from(value))
     }
 }
 
@@ -55,6 +51,14 @@ pub enum MediaErrorC {
     Failed = 0,
     NoItemsFound = 1,
     NoAvailableProviders = 2,
+    /// The connection with the media provider couldn't be established, e.g. no network connection.
+    ProviderConnectionFailed = 3,
+    /// The media provider responded with an unexpected status code.
+    ProviderRequestFailed = 4,
+    /// The response of the media provider couldn't be parsed.
+    ProviderParsingFailed = 5,
+    /// The media type is not supported for the requested action.
+    MediaTypeNotSupported = 6,
 }
 
 impl From<MediaError> for MediaErrorC {
@@ -62,6 +66,10 @@ impl From<MediaError> for MediaErrorC {
         match value {
             MediaError::NoAvailableProviders => MediaErrorC::NoAvailableProviders,
             MediaError::ProviderNotFound(_) => MediaErrorC::NoAvailableProviders,
+            MediaError::ProviderConnectionFailed => MediaErrorC::ProviderConnectionFailed,
+            MediaError::ProviderRequestFailed(_, _) => MediaErrorC::ProviderRequestFailed,
+            MediaError::ProviderParsingFailed(_) => MediaErrorC::ProviderParsingFailed,
+            MediaError::MediaTypeNotSupported(_) => MediaErrorC::MediaTypeNotSupported,
             _ => MediaErrorC::Failed,
         }
     }
@@ -109,6 +117,41 @@ impl MediaSetC {
         }
     }
 
+    /// Create a new media set from the given media items.
+    /// Media items which are not a movie or show overview are ignored.
+    pub fn from_media(media: Vec<Box<dyn MediaOverview>>) -> Self {
+        let mut movies: Vec<MovieOverviewC> = vec![];
+        let mut shows: Vec<ShowOverviewC> = vec![];
+
+        for media in media.into_iter() {
+            if media.media_type() == MediaType::Movie {
+                movies.push(MovieOverviewC::from(
+                    *media
+                        .into_any()
+                        .downcast::<MovieOverview>()
+                        .expect("expected the media to be a movie overview"),
+                ))
+            } else if media.media_type() == MediaType::Show {
+                shows.push(ShowOverviewC::from(
+                    *media
+                        .into_any()
+                        .downcast::<ShowOverview>()
+                        .expect("expected the media to be a show overview"),
+                ));
+            }
+        }
+
+        let (movies, movies_len) = into_c_vec(movies);
+        let (shows, shows_len) = into_c_vec(shows);
+
+        Self {
+            movies,
+            movies_len,
+            shows,
+            shows_len,
+        }
+    }
+
     pub fn movies(&self) -> Vec<MovieOverview> {
         if self.movies.is_null() {
             return vec![];
@@ -707,6 +750,70 @@ impl From<Episode> for MediaItemC {
     }
 }
 
+/// A C-compatible struct representing a media item which can be continued watching.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct ContinueWatchingItemC {
+    /// A pointer to the media item to continue watching.
+    pub media: *mut MediaItemC,
+    /// A pointer to the parent media item, if applicable.
+    pub parent_media: *mut MediaItemC,
+    /// The playback progress of the media item as a percentage.
+    pub progress: f32,
+    /// A pointer to the timestamp to resume the playback from, if applicable.
+    pub timestamp: *const u64,
+}
+
+impl From<ContinueWatchingItem> for ContinueWatchingItemC {
+    fn from(value: ContinueWatchingItem) -> Self {
+        let parent_media = if let Some(e) = value.parent_media {
+            into_c_owned(MediaItemC::from(e))
+        } else {
+            ptr::null_mut()
+        };
+        let timestamp = if let Some(e) = value.timestamp {
+            e as *const u64
+        } else {
+            ptr::null()
+        };
+
+        Self {
+            media: into_c_owned(MediaItemC::from(value.media)),
+            parent_media,
+            progress: value.progress,
+            timestamp,
+        }
+    }
+}
+
+/// A C-compatible struct representing the connection test result of a single provider uri.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct ProviderConnectionResultC {
+    /// The tested provider uri.
+    pub uri: *mut c_char,
+    /// The measured latency in millis, only applicable when the connection succeeded.
+    pub latency: u64,
+    /// The reason why the connection failed, or a null pointer when the connection succeeded.
+    pub error: *mut c_char,
+}
+
+impl From<ConnectionTestResult> for ProviderConnectionResultC {
+    fn from(value: ConnectionTestResult) -> Self {
+        let (uri, result) = value;
+        let (latency, error) = match result {
+            Ok(e) => (e.as_millis() as u64, ptr::null_mut()),
+            Err(e) => (0, into_c_string(e)),
+        };
+
+        Self {
+            uri: into_c_string(uri),
+            latency,
+            error,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct GenreC {
@@ -1286,6 +1393,32 @@ mod test {
             MediaErrorC::Failed,
             MediaErrorC::from(MediaError::FavoriteNotFound(String::new()))
         );
+        assert_eq!(
+            MediaErrorC::ProviderConnectionFailed,
+            MediaErrorC::from(MediaError::ProviderConnectionFailed)
+        );
+        assert_eq!(
+            MediaErrorC::ProviderRequestFailed,
+            MediaErrorC::from(MediaError::ProviderRequestFailed(String::new(), 404))
+        );
+        assert_eq!(
+            MediaErrorC::ProviderParsingFailed,
+            MediaErrorC::from(MediaError::ProviderParsingFailed(String::new()))
+        );
+    }
+
+    #[test]
+    fn test_media_set_result_from_media_error() {
+        let result = MediaSetResult::from(MediaError::ProviderConnectionFailed);
+
+        match result {
+            MediaSetResult::Err(e) => assert_eq!(MediaErrorC::ProviderConnectionFailed, e),
+            _ => assert!(
+                false,
+                "expected MediaSetResult::Err, got {:?} instead",
+                result
+            ),
+        }
     }
 
     #[test]
@@ -1434,4 +1567,43 @@ mod test {
         
         assert_eq!(expected_result, result)
     }
+
+    #[test]
+    fn test_continue_watching_item_c_from() {
+        let item = ContinueWatchingItem {
+            media: Box::new(MovieOverview::new(
+                "My movie".to_string(),
+                "tt0000001".to_string(),
+                "2020".to_string(),
+            )),
+            parent_media: None,
+            progress: 42.5,
+            timestamp: Some(120000),
+        };
+
+        let result = ContinueWatchingItemC::from(item);
+
+        assert!(!result.media.is_null(), "expected a media item");
+        assert!(result.parent_media.is_null(), "expected no parent media");
+        assert_eq!(42.5, result.progress);
+        assert_eq!(120000, result.timestamp as u64);
+    }
+
+    #[test]
+    fn test_provider_connection_result_c_from() {
+        let uri = "https://lorem.ipsum";
+
+        let result = ProviderConnectionResultC::from((
+            uri.to_string(),
+            Ok(std::time::Duration::from_millis(250)),
+        ));
+        assert_eq!(uri.to_string(), from_c_string(result.uri));
+        assert_eq!(250, result.latency);
+        assert!(result.error.is_null(), "expected no error");
+
+        let result =
+            ProviderConnectionResultC::from((uri.to_string(), Err("timed out".to_string())));
+        assert_eq!(0, result.latency);
+        assert_eq!("timed out".to_string(), from_c_string(result.error));
+    }
 }
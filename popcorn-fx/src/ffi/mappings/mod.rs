@@ -1,12 +1,15 @@
 pub use arrays::*;
 pub use controls::*;
 pub use events::*;
+pub use images::*;
 pub use loader::*;
 pub use log_bridge::*;
 pub use media_mappers::*;
 pub use media_mappings::*;
+pub use platform::*;
 pub use players::*;
 pub use playlists::*;
+pub use screen::*;
 pub use settings::*;
 pub use subtitle::*;
 pub use torrents::*;
@@ -17,13 +20,16 @@ pub use version_info::*;
 mod arrays;
 mod controls;
 mod events;
+mod images;
 mod loader;
 mod log_bridge;
 mod media_mappers;
 mod media_mappings;
+mod platform;
 mod players;
 mod playlists;
 mod result;
+mod screen;
 mod settings;
 mod subtitle;
 mod torrents;
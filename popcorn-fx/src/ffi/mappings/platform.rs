@@ -0,0 +1,80 @@
+use std::ptr;
+
+use popcorn_fx_core::core::platform::{PlatformInfo, PlatformType};
+use popcorn_fx_core::into_c_owned;
+
+/// The C compatible representation of the platform information, used for diagnostic purposes.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PlatformInfoC {
+    /// The platform type
+    pub platform_type: PlatformType,
+    /// The number of logical cpu cores available to the application
+    pub cpu_cores: u32,
+    /// The total memory of the system in bytes
+    pub total_memory: u64,
+    /// The available memory of the system in bytes
+    pub available_memory: u64,
+    /// The free disk space in bytes of the application data directory, if known
+    pub data_free_space: *mut u64,
+    /// The free disk space in bytes of the torrent directory, if known
+    pub torrent_free_space: *mut u64,
+    /// The total number of crashes which have been reported by the application
+    pub crash_count: u32,
+}
+
+impl PlatformInfoC {
+    /// Create a new C compatible platform info from the given info, disk space of the application directories
+    /// and reported crashes.
+    pub fn from(
+        info: PlatformInfo,
+        data_free_space: Option<u64>,
+        torrent_free_space: Option<u64>,
+        crash_count: u32,
+    ) -> Self {
+        Self {
+            platform_type: info.platform_type,
+            cpu_cores: info.cpu_cores,
+            total_memory: info.total_memory,
+            available_memory: info.available_memory,
+            data_free_space: data_free_space
+                .map(|e| into_c_owned(e))
+                .unwrap_or(ptr::null_mut()),
+            torrent_free_space: torrent_free_space
+                .map(|e| into_c_owned(e))
+                .unwrap_or(ptr::null_mut()),
+            crash_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use popcorn_fx_core::from_c_owned;
+
+    use super::*;
+
+    #[test]
+    fn test_platform_info_c_from() {
+        let info = PlatformInfo {
+            platform_type: PlatformType::Linux,
+            arch: "x86_64".to_string(),
+            cpu_cores: 8,
+            total_memory: 16_000_000,
+            available_memory: 4_000_000,
+        };
+
+        let result = PlatformInfoC::from(info, Some(2048), None, 3);
+
+        assert_eq!(PlatformType::Linux, result.platform_type);
+        assert_eq!(8, result.cpu_cores);
+        assert_eq!(16_000_000, result.total_memory);
+        assert_eq!(4_000_000, result.available_memory);
+        assert_eq!(2048, from_c_owned(result.data_free_space));
+        assert!(
+            result.torrent_free_space.is_null(),
+            "expected the torrent free space to be unknown"
+        );
+        assert_eq!(3, result.crash_count);
+    }
+}
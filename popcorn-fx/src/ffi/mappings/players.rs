@@ -13,9 +13,9 @@ use popcorn_fx_core::core::{
     block_in_place, CallbackHandle, Callbacks, CoreCallback, CoreCallbacks,
 };
 use popcorn_fx_core::core::players::{
-    Player, PlayerEvent, PlayerManagerEvent, PlayerState, PlayMediaRequest, PlayRequest,
-    PlayStreamRequest, PlayUrlRequest,
+    MediaTrack, Player, PlayerEvent, PlayerManagerEvent, PlayerState, PlayRequest,
 };
+use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
 
 use crate::ffi::PlayerChangedEventC;
 
@@ -37,6 +37,9 @@ pub type PlayerSeekCallback = extern "C" fn(u64);
 /// A C-compatible callback function type for player stop events.
 pub type PlayerStopCallback = extern "C" fn();
 
+/// A C-compatible callback function type for player audio track selection events.
+pub type PlayerAudioTrackCallback = extern "C" fn(i32);
+
 /// A C-compatible enum representing player events.
 #[repr(C)]
 #[derive(Debug)]
@@ -45,6 +48,12 @@ pub enum PlayerEventC {
     TimeChanged(u64),
     StateChanged(PlayerState),
     VolumeChanged(u32),
+    Reconnecting(u32),
+    Reconnected,
+    ReconnectFailed,
+    AudioTrackChanged(i32),
+    SubtitleTrackChanged(i32),
+    SubtitleChanged(SubtitleLanguage),
 }
 
 impl From<PlayerEventC> for PlayerEvent {
@@ -55,6 +64,12 @@ impl From<PlayerEventC> for PlayerEvent {
             PlayerEventC::TimeChanged(e) => PlayerEvent::TimeChanged(e.clone()),
             PlayerEventC::StateChanged(e) => PlayerEvent::StateChanged(e.clone()),
             PlayerEventC::VolumeChanged(e) => PlayerEvent::VolumeChanged(e.clone()),
+            PlayerEventC::Reconnecting(e) => PlayerEvent::Reconnecting(e.clone()),
+            PlayerEventC::Reconnected => PlayerEvent::Reconnected,
+            PlayerEventC::ReconnectFailed => PlayerEvent::ReconnectFailed,
+            PlayerEventC::AudioTrackChanged(e) => PlayerEvent::AudioTrackChanged(e),
+            PlayerEventC::SubtitleTrackChanged(e) => PlayerEvent::SubtitleTrackChanged(e),
+            PlayerEventC::SubtitleChanged(e) => PlayerEvent::SubtitleChanged(e),
         }
     }
 }
@@ -66,6 +81,12 @@ impl From<PlayerEvent> for PlayerEventC {
             PlayerEvent::TimeChanged(e) => PlayerEventC::TimeChanged(e),
             PlayerEvent::StateChanged(e) => PlayerEventC::StateChanged(e),
             PlayerEvent::VolumeChanged(e) => PlayerEventC::VolumeChanged(e),
+            PlayerEvent::Reconnecting(e) => PlayerEventC::Reconnecting(e),
+            PlayerEvent::Reconnected => PlayerEventC::Reconnected,
+            PlayerEvent::ReconnectFailed => PlayerEventC::ReconnectFailed,
+            PlayerEvent::AudioTrackChanged(e) => PlayerEventC::AudioTrackChanged(e),
+            PlayerEvent::SubtitleTrackChanged(e) => PlayerEventC::SubtitleTrackChanged(e),
+            PlayerEvent::SubtitleChanged(e) => PlayerEventC::SubtitleChanged(e),
         }
     }
 }
@@ -148,6 +169,8 @@ pub struct PlayerRegistrationC {
     pub seek_callback: PlayerSeekCallback,
     /// A callback function pointer for the "stop" action.
     pub stop_callback: PlayerStopCallback,
+    /// A callback function pointer for the "audio track" selection action.
+    pub audio_track_callback: PlayerAudioTrackCallback,
 }
 
 #[repr(C)]
@@ -165,7 +188,8 @@ pub struct PlayerWrapper {
     resume_callback: Mutex<Box<dyn Fn() + Send + Sync>>,
     seek_callback: Mutex<Box<dyn Fn(u64) + Send + Sync>>,
     stop_callback: Mutex<Box<dyn Fn() + Send + Sync>>,
-    play_request: Mutex<Option<Arc<Box<dyn PlayRequest>>>>,
+    audio_track_callback: Mutex<Box<dyn Fn(i32) + Send + Sync>>,
+    play_request: Mutex<Option<Arc<PlayRequest>>>,
     callbacks: CoreCallbacks<PlayerEvent>,
 }
 
@@ -207,12 +231,16 @@ impl Player for PlayerWrapper {
         self.state.clone()
     }
 
-    fn request(&self) -> Option<Weak<Box<dyn PlayRequest>>> {
+    fn embedded_playback_supported(&self) -> bool {
+        self.embedded_playback_supported
+    }
+
+    fn request(&self) -> Option<Weak<PlayRequest>> {
         let mutex = block_in_place(self.play_request.lock());
         mutex.as_ref().map(|e| Arc::downgrade(e))
     }
 
-    async fn play(&self, request: Box<dyn PlayRequest>) {
+    async fn play(&self, request: PlayRequest) {
         trace!(
             "Invoking play callback on C player for {:?} with {:?}",
             self,
@@ -255,6 +283,20 @@ impl Player for PlayerWrapper {
             callback();
         }
     }
+
+    fn audio_tracks(&self) -> Option<Vec<MediaTrack>> {
+        block_in_place(self.play_request.lock())
+            .as_ref()
+            .map(|e| e.audio_tracks())
+            .filter(|e| !e.is_empty())
+    }
+
+    fn set_audio_track(&self, id: i32) {
+        {
+            let callback = block_in_place(self.audio_track_callback.lock());
+            callback(id);
+        }
+    }
 }
 
 impl Debug for PlayerWrapper {
@@ -297,6 +339,9 @@ impl From<PlayerRegistrationC> for PlayerWrapper {
         let seek_callback: Box<dyn Fn(u64) + Send + Sync> =
             Box::new(move |time| seek_callback(time));
         let stop_callback: Box<dyn Fn() + Send + Sync> = Box::new(move || stop_callback());
+        let audio_track_callback = value.audio_track_callback;
+        let audio_track_callback: Box<dyn Fn(i32) + Send + Sync> =
+            Box::new(move |id| audio_track_callback(id));
 
         Self {
             id,
@@ -310,6 +355,7 @@ impl From<PlayerRegistrationC> for PlayerWrapper {
             resume_callback: Mutex::new(resume_callback),
             seek_callback: Mutex::new(seek_callback),
             stop_callback: Mutex::new(stop_callback),
+            audio_track_callback: Mutex::new(audio_track_callback),
             play_request: Default::default(),
             callbacks: Default::default(),
         }
@@ -375,6 +421,56 @@ impl From<Vec<PlayerC>> for PlayerSet {
     }
 }
 
+/// A C-compatible struct representing a media track of the active playback.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct MediaTrackC {
+    /// The unique identifier of the track within the media.
+    pub id: i32,
+    /// A pointer to a null-terminated C string representing the name of the track.
+    pub name: *mut c_char,
+    /// A pointer to a null-terminated C string representing the language of the track, or [ptr::null_mut] if unknown.
+    pub language: *mut c_char,
+}
+
+impl From<MediaTrack> for MediaTrackC {
+    fn from(value: MediaTrack) -> Self {
+        trace!("Converting MediaTrack to MediaTrackC for {:?}", value);
+        Self {
+            id: value.id,
+            name: into_c_string(value.name),
+            language: value
+                .language
+                .map(|e| into_c_string(e))
+                .unwrap_or(ptr::null_mut()),
+        }
+    }
+}
+
+/// Represents a set of media tracks in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct MediaTrackSet {
+    /// Pointer to an array of media tracks.
+    pub tracks: *mut MediaTrackC,
+    /// Length of the media track array.
+    pub len: i32,
+}
+
+impl From<Vec<MediaTrack>> for MediaTrackSet {
+    fn from(value: Vec<MediaTrack>) -> Self {
+        trace!("Converting media tracks to MediaTrackSet");
+        let (tracks, len) = into_c_vec(
+            value
+                .into_iter()
+                .map(|e| MediaTrackC::from(e))
+                .collect::<Vec<MediaTrackC>>(),
+        );
+
+        Self { tracks, len }
+    }
+}
+
 /// Represents events related to player management in C-compatible form.
 #[repr(C)]
 #[derive(Debug)]
@@ -391,6 +487,20 @@ pub enum PlayerManagerEventC {
     PlayerTimeChanged(u64),
     /// Indicates a change in the state of a player.
     PlayerStateChanged(PlayerState),
+    /// Indicates that the active player is trying to reconnect, containing the current attempt.
+    PlayerReconnecting(u32),
+    /// Indicates that the active player has been reconnected.
+    PlayerReconnected,
+    /// Indicates that the active player gave up on reconnecting.
+    PlayerReconnectFailed,
+    /// Indicates a change in the active audio track of the active player.
+    PlayerAudioTrackChanged(i32),
+    /// Indicates a change in the active embedded subtitle track of the active player.
+    PlayerSubtitleTrackChanged(i32),
+    /// Indicates a change in the active subtitle of the active player.
+    PlayerSubtitleChanged(SubtitleLanguage),
+    /// Indicates that the playback fell back to another player.
+    PlayerFallback(PlayerChangedEventC),
 }
 
 impl From<PlayerManagerEvent> for PlayerManagerEventC {
@@ -421,6 +531,21 @@ impl From<PlayerManagerEvent> for PlayerManagerEventC {
                         .expect("expected the play request to still be in scope"),
                 )
             }
+            PlayerManagerEvent::PlayerReconnecting(e) => PlayerManagerEventC::PlayerReconnecting(e),
+            PlayerManagerEvent::PlayerReconnected => PlayerManagerEventC::PlayerReconnected,
+            PlayerManagerEvent::PlayerReconnectFailed => PlayerManagerEventC::PlayerReconnectFailed,
+            PlayerManagerEvent::PlayerAudioTrackChanged(e) => {
+                PlayerManagerEventC::PlayerAudioTrackChanged(e)
+            }
+            PlayerManagerEvent::PlayerSubtitleTrackChanged(e) => {
+                PlayerManagerEventC::PlayerSubtitleTrackChanged(e)
+            }
+            PlayerManagerEvent::PlayerSubtitleChanged(e) => {
+                PlayerManagerEventC::PlayerSubtitleChanged(e)
+            }
+            PlayerManagerEvent::PlayerFallback(e) => {
+                PlayerManagerEventC::PlayerFallback(PlayerChangedEventC::from(e))
+            }
         }
     }
 }
@@ -448,11 +573,15 @@ pub struct PlayRequestC {
     pub stream_handle: *mut i64,
     /// Indicates whether subtitles are enabled for the media.
     pub subtitles_enabled: bool,
+    /// The url on which the active subtitle is being served, or [ptr::null_mut] if not available.
+    pub subtitle_url: *mut c_char,
+    /// The audio tracks which are available within the media, or [ptr::null_mut] if unknown.
+    pub audio_tracks: *mut MediaTrackSet,
 }
 
-impl From<&PlayUrlRequest> for PlayRequestC {
-    fn from(value: &PlayUrlRequest) -> Self {
-        trace!("Converting PlayUrlRequest to PlayRequestC for {:?}", value);
+impl From<&PlayRequest> for PlayRequestC {
+    fn from(value: &PlayRequest) -> Self {
+        trace!("Converting PlayRequest to PlayRequestC for {:?}", value);
         let caption = if let Some(caption) = value.caption() {
             into_c_string(caption)
         } else {
@@ -478,154 +607,41 @@ impl From<&PlayUrlRequest> for PlayRequestC {
         } else {
             ptr::null_mut()
         };
-
-        Self {
-            url: into_c_string(value.url.clone()),
-            title: into_c_string(value.title.clone()),
-            thumb,
-            caption,
-            background,
-            quality,
-            auto_resume_timestamp,
-            subtitles_enabled: value.subtitles_enabled,
-            stream_handle: ptr::null_mut(),
-        }
-    }
-}
-
-impl From<&PlayStreamRequest> for PlayRequestC {
-    fn from(value: &PlayStreamRequest) -> Self {
-        trace!(
-            "Converting PlayStreamRequest to PlayRequestC for {:?}",
-            value
-        );
-        let caption = if let Some(caption) = value.caption() {
-            into_c_string(caption)
+        let subtitle_url = if let Some(e) = value.subtitle.url.as_ref() {
+            into_c_string(e.clone())
         } else {
             ptr::null_mut()
         };
-        let thumb = if let Some(thumb) = value.thumbnail() {
-            into_c_string(thumb)
-        } else {
-            ptr::null_mut()
-        };
-        let background = if let Some(background) = value.background() {
-            into_c_string(background)
-        } else {
-            ptr::null_mut()
-        };
-        let quality = if let Some(quality) = value.quality() {
-            into_c_string(quality)
-        } else {
-            ptr::null_mut()
-        };
-        let auto_resume_timestamp = if let Some(e) = value.auto_resume_timestamp() {
-            into_c_owned(e)
-        } else {
-            ptr::null_mut()
-        };
-        let stream_handle = if let Some(e) = value.torrent_stream.upgrade() {
+        let audio_tracks = Some(value.audio_tracks())
+            .filter(|e| !e.is_empty())
+            .map(|e| into_c_owned(MediaTrackSet::from(e)))
+            .unwrap_or(ptr::null_mut());
+        let stream_handle = if let Some(e) = value.torrent_stream.as_ref().and_then(|e| e.upgrade())
+        {
             into_c_owned(e.stream_handle().value())
         } else {
             ptr::null_mut()
         };
 
         Self {
-            url: into_c_string(value.base.url.clone()),
-            title: into_c_string(value.base.title.clone()),
+            url: into_c_string(value.url()),
+            title: into_c_string(value.title()),
             caption,
             thumb,
             background,
             quality,
             auto_resume_timestamp,
-            subtitles_enabled: value.subtitles_enabled(),
             stream_handle,
-        }
-    }
-}
-
-impl From<&PlayMediaRequest> for PlayRequestC {
-    fn from(value: &PlayMediaRequest) -> Self {
-        trace!(
-            "Converting PlayMediaRequest to PlayRequestC for {:?}",
-            value
-        );
-        let caption = if let Some(caption) = value.caption() {
-            into_c_string(caption)
-        } else {
-            ptr::null_mut()
-        };
-        let thumb = if let Some(thumb) = value.thumbnail() {
-            into_c_string(thumb)
-        } else {
-            ptr::null_mut()
-        };
-        let background = if let Some(background) = value.background() {
-            into_c_string(background)
-        } else {
-            ptr::null_mut()
-        };
-        let quality = if let Some(quality) = value.quality() {
-            into_c_string(quality)
-        } else {
-            ptr::null_mut()
-        };
-        let auto_resume_timestamp = if let Some(e) = value.auto_resume_timestamp() {
-            into_c_owned(e)
-        } else {
-            ptr::null_mut()
-        };
-        let stream_handle = if let Some(e) = value.torrent_stream.upgrade() {
-            into_c_owned(e.stream_handle().value())
-        } else {
-            ptr::null_mut()
-        };
-
-        Self {
-            url: into_c_string(value.base.url.clone()),
-            title: into_c_string(value.base.title.clone()),
-            caption,
-            thumb,
-            background,
-            quality,
-            auto_resume_timestamp,
             subtitles_enabled: value.subtitles_enabled(),
-            stream_handle,
+            subtitle_url,
+            audio_tracks,
         }
     }
 }
 
-impl From<Box<dyn PlayRequest>> for PlayRequestC {
-    fn from(value: Box<dyn PlayRequest>) -> Self {
-        Self::from(&value)
-    }
-}
-
-impl From<&Box<dyn PlayRequest>> for PlayRequestC {
-    fn from(value: &Box<dyn PlayRequest>) -> Self {
-        if let Some(value) = value.downcast_ref::<PlayMediaRequest>() {
-            return PlayRequestC::from(value);
-        } else if let Some(value) = value.downcast_ref::<PlayStreamRequest>() {
-            return PlayRequestC::from(value);
-        } else if let Some(value) = value.downcast_ref::<PlayUrlRequest>() {
-            return PlayRequestC::from(value);
-        }
-
-        panic!("Unexpected play request {:?}", value)
-    }
-}
-
-impl From<Arc<Box<dyn PlayRequest>>> for PlayRequestC {
-    fn from(value: Arc<Box<dyn PlayRequest>>) -> Self {
-        if let Some(value) = value.downcast_ref::<PlayMediaRequest>() {
-            return PlayRequestC::from(value);
-        } else if let Some(value) = value.downcast_ref::<PlayStreamRequest>() {
-            return PlayRequestC::from(value);
-        } else if let Some(value) = value.downcast_ref::<PlayUrlRequest>() {
-            return PlayRequestC::from(value);
-        }
-
-        panic!("Unexpected play request {:?}", value)
+impl From<Arc<PlayRequest>> for PlayRequestC {
+    fn from(value: Arc<PlayRequest>) -> Self {
+        Self::from(&*value)
     }
 }
 
@@ -669,6 +685,11 @@ mod tests {
         info!("Player stop C callback invoked");
     }
 
+    #[no_mangle]
+    extern "C" fn audio_track_callback(id: i32) {
+        info!("Player audio track C callback invoked with {}", id);
+    }
+
     #[test]
     fn test_from_player() {
         init_logger();
@@ -754,6 +775,36 @@ mod tests {
         assert_eq!(player_id.to_string(), from_c_string(result.id));
     }
 
+    #[test]
+    fn test_from_media_tracks() {
+        init_logger();
+        let tracks = vec![
+            MediaTrack {
+                id: 1,
+                name: "Track 1".to_string(),
+                language: Some("English".to_string()),
+            },
+            MediaTrack {
+                id: 2,
+                name: "Track 2".to_string(),
+                language: None,
+            },
+        ];
+
+        let set = MediaTrackSet::from(tracks);
+        assert_eq!(2, set.len);
+
+        let vec = from_c_vec(set.tracks, set.len);
+        assert_eq!(1, vec[0].id);
+        assert_eq!("Track 1".to_string(), from_c_string(vec[0].name));
+        assert_eq!("English".to_string(), from_c_string(vec[0].language));
+        assert_eq!(2, vec[1].id);
+        assert!(
+            vec[1].language.is_null(),
+            "expected the unknown language to be a null pointer"
+        );
+    }
+
     #[test]
     fn test_from_player_c() {
         init_logger();
@@ -775,6 +826,7 @@ mod tests {
             resume_callback,
             seek_callback,
             stop_callback,
+            audio_track_callback,
         };
 
         let wrapper = PlayerWrapper::from(player);
@@ -816,19 +868,37 @@ mod tests {
                 result
             );
         }
+
+        let result = PlayerManagerEventC::from(PlayerManagerEvent::PlayerSubtitleChanged(
+            SubtitleLanguage::English,
+        ));
+        if let PlayerManagerEventC::PlayerSubtitleChanged(e) = result {
+            assert_eq!(SubtitleLanguage::English, e);
+        } else {
+            assert!(
+                false,
+                "expected PlayerManagerEventC::PlayerSubtitleChanged, got {:?} instead",
+                result
+            );
+        }
     }
 
     #[test]
-    fn test_play_request_c_from_play_url_request() {
+    fn test_play_request_c_from_url_request() {
         let url = "https://localhost:8090/foo.mp4";
         let title = "FooBar";
         let thumb = "MyThumb.png";
         let background = "MyBackground.png";
-        let request = PlayUrlRequest::builder()
+        let request = PlayRequest::builder()
             .url(url)
             .title(title)
             .thumb(thumb)
             .background(background)
+            .audio_tracks(vec![MediaTrack {
+                id: 2,
+                name: "Japanese".to_string(),
+                language: Some("jpn".to_string()),
+            }])
             .build();
 
         let result = PlayRequestC::from(&request);
@@ -837,10 +907,19 @@ mod tests {
         assert_eq!(title.to_string(), from_c_string(result.title));
         assert_eq!(thumb.to_string(), from_c_string(result.thumb));
         assert_eq!(background.to_string(), from_c_string(result.background));
+        let audio_tracks = from_c_owned(result.audio_tracks);
+        let audio_tracks = from_c_vec(audio_tracks.tracks, audio_tracks.len);
+        assert_eq!(1, audio_tracks.len());
+        assert_eq!(2, audio_tracks[0].id);
+        assert_eq!("jpn".to_string(), from_c_string(audio_tracks[0].language));
+        assert_eq!(ptr::null_mut(), result.quality);
+        assert_eq!(ptr::null_mut(), result.auto_resume_timestamp);
+        assert_eq!(ptr::null_mut(), result.stream_handle);
+        assert_eq!(ptr::null_mut(), result.subtitle_url);
     }
 
     #[test]
-    fn test_play_request_c_from_play_stream_request() {
+    fn test_play_request_c_from_stream_request() {
         let url = "https://localhost:15200/MyStream.mkv";
         let title = "Stream title";
         let thumb = "MyThumb.png";
@@ -852,7 +931,7 @@ mod tests {
             .times(1)
             .return_const(handle.clone());
         let torrent_stream = Arc::new(Box::new(torrent_stream) as Box<dyn TorrentStream>);
-        let request = PlayStreamRequest::builder()
+        let request = PlayRequest::builder()
             .url(url)
             .title(title)
             .thumb(thumb)
@@ -870,11 +949,13 @@ mod tests {
     }
 
     #[test]
-    fn test_play_request_c_from_play_media_request() {
+    fn test_play_request_c_from_media_request() {
         let url = "https://localhost:8090/foo.mp4";
         let title = "FooBar";
         let thumb = "MyThumb.png";
         let background = "MyBackground.png";
+        let quality = "1080p";
+        let subtitle_url = "http://localhost:9000/subtitle.vtt";
         let handle = Handle::new();
         let mut torrent_stream = MockTorrentStream::new();
         torrent_stream
@@ -889,21 +970,29 @@ mod tests {
             rating: None,
             images: Default::default(),
         };
-        let request = PlayMediaRequest::builder()
+        let request = PlayRequest::builder()
             .url(url)
             .title(title)
             .thumb(thumb)
             .background(background)
+            .quality(quality)
+            .auto_resume_timestamp(18000)
+            .subtitles_enabled(true)
+            .subtitle_url(subtitle_url)
             .media(Box::new(movie))
             .torrent_stream(Arc::downgrade(&torrent_stream))
             .build();
 
-        let result = PlayRequestC::from(&request);
+        let result = PlayRequestC::from(Arc::new(request));
 
         assert_eq!(url.to_string(), from_c_string(result.url));
         assert_eq!(title.to_string(), from_c_string(result.title));
         assert_eq!(thumb.to_string(), from_c_string(result.thumb));
         assert_eq!(background.to_string(), from_c_string(result.background));
+        assert_eq!(quality.to_string(), from_c_string(result.quality));
+        assert_eq!(18000, from_c_owned(result.auto_resume_timestamp));
         assert_eq!(handle.value(), from_c_owned(result.stream_handle));
+        assert_eq!(true, result.subtitles_enabled);
+        assert_eq!(subtitle_url.to_string(), from_c_string(result.subtitle_url));
     }
 }
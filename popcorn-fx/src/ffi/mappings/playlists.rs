@@ -180,6 +180,8 @@ pub enum PlaylistManagerEventC {
     PlayingNext(PlayingNextInfoC),
     /// Represents a state change event in the playlist manager.
     StateChanged(PlaylistState),
+    /// Represents a playlist item which has been skipped while restoring the playlist.
+    ItemSkipped(*mut c_char),
 }
 
 impl From<PlaylistManagerEvent> for PlaylistManagerEventC {
@@ -194,6 +196,9 @@ impl From<PlaylistManagerEvent> for PlaylistManagerEventC {
                 PlaylistManagerEventC::PlayingNext(PlayingNextInfoC::from(e))
             }
             PlaylistManagerEvent::StateChanged(e) => PlaylistManagerEventC::StateChanged(e),
+            PlaylistManagerEvent::ItemSkipped(e) => {
+                PlaylistManagerEventC::ItemSkipped(into_c_string(e))
+            }
         }
     }
 }
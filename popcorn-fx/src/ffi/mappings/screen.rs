@@ -0,0 +1,114 @@
+use std::os::raw::c_char;
+
+use popcorn_fx_core::core::screen::{DisplayInfo, ScreenEvent};
+use popcorn_fx_core::{from_c_string, into_c_string};
+
+/// Type alias for a callback that handles the screen events.
+pub type ScreenEventCallbackC = extern "C" fn(ScreenEventC);
+
+/// Type alias for a callback which moves the application to the display with the given id.
+pub type MoveToDisplayCallbackC = extern "C" fn(u32);
+
+/// The C compatible representation of a connected display.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct DisplayInfoC {
+    /// The unique id of the display
+    pub id: u32,
+    /// The name of the display
+    pub name: *mut c_char,
+    /// The horizontal position of the display within the virtual screen
+    pub x: i32,
+    /// The vertical position of the display within the virtual screen
+    pub y: i32,
+    /// The width of the display in pixels
+    pub width: u32,
+    /// The height of the display in pixels
+    pub height: u32,
+    /// Indicates if the display is the primary display of the system
+    pub primary: bool,
+}
+
+impl From<DisplayInfo> for DisplayInfoC {
+    fn from(value: DisplayInfo) -> Self {
+        Self {
+            id: value.id,
+            name: into_c_string(value.name),
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+            primary: value.primary,
+        }
+    }
+}
+
+impl From<&DisplayInfoC> for DisplayInfo {
+    fn from(value: &DisplayInfoC) -> Self {
+        Self {
+            id: value.id,
+            name: from_c_string(value.name),
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+            primary: value.primary,
+        }
+    }
+}
+
+/// Represents an event of the screen service in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScreenEventC {
+    /// Indicates a change in the fullscreen state of the application.
+    FullscreenChanged(bool),
+    /// Indicates that the application has been moved to another display.
+    DisplayChanged(u32),
+}
+
+impl From<ScreenEvent> for ScreenEventC {
+    fn from(value: ScreenEvent) -> Self {
+        match value {
+            ScreenEvent::FullscreenChanged(e) => ScreenEventC::FullscreenChanged(e),
+            ScreenEvent::DisplayChanged(e) => ScreenEventC::DisplayChanged(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display_info_c_from() {
+        let display = DisplayInfo {
+            id: 1,
+            name: "eDP-1".to_string(),
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            primary: true,
+        };
+
+        let result = DisplayInfoC::from(display.clone());
+        assert_eq!("eDP-1".to_string(), from_c_string(result.name));
+        assert_eq!(1920, result.width);
+
+        let result = DisplayInfo::from(&result);
+        assert_eq!(display, result);
+    }
+
+    #[test]
+    fn test_screen_event_c_from() {
+        assert_eq!(
+            ScreenEventC::FullscreenChanged(true),
+            ScreenEventC::from(ScreenEvent::FullscreenChanged(true))
+        );
+        assert_eq!(
+            ScreenEventC::DisplayChanged(3),
+            ScreenEventC::from(ScreenEvent::DisplayChanged(3))
+        );
+    }
+}
@@ -2,17 +2,20 @@ use std::os::raw::c_char;
 use std::path::PathBuf;
 use std::ptr;
 
-use log::trace;
+use log::{trace, warn};
 
+use popcorn_fx_core::core::config;
 use popcorn_fx_core::core::config::{
-    ApplicationConfigEvent, CleaningMode, DecorationType, LastSync, MediaTrackingSyncState,
-    PlaybackSettings, PopcornSettings, Quality, ServerSettings, SubtitleFamily, SubtitleSettings,
-    TorrentSettings, TrackingSettings, UiScale, UiSettings,
+    ApplicationConfigEvent, CleaningMode, ConfigError, DecorationType, EncryptionMode, LastSync,
+    MediaTrackingSyncState, NetworkSettings, PlaybackSettings, PopcornSettings, PosterQuality,
+    Quality, ServerSettings, StartPage, SubtitleFamily, SubtitleSettings, TorrentSettings,
+    TrackingSettings, TvModeSubtitle, UiScale, UiSettings,
 };
-use popcorn_fx_core::core::media::Category;
 use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
 use popcorn_fx_core::{from_c_owned, from_c_string, into_c_owned, into_c_string};
 
+use crate::ffi::StringArray;
+
 /// The C callback for the setting events.
 pub type ApplicationConfigCallbackC = extern "C" fn(ApplicationConfigEventC);
 
@@ -34,6 +37,10 @@ pub enum ApplicationConfigEventC {
     PlaybackSettingsChanged(PlaybackSettingsC),
     /// Invoked when the tracking settings have been changed
     TrackingSettingsChanged(TrackingSettingsC),
+    /// Invoked when the network settings have been changed
+    NetworkSettingsChanged(NetworkSettingsC),
+    /// Invoked when the persisted settings couldn't be loaded, e.g. because a migration failed
+    SettingsError,
 }
 
 impl From<ApplicationConfigEvent> for ApplicationConfigEventC {
@@ -58,6 +65,34 @@ impl From<ApplicationConfigEvent> for ApplicationConfigEventC {
             ApplicationConfigEvent::TrackingSettingsChanged(e) => {
                 ApplicationConfigEventC::TrackingSettingsChanged(TrackingSettingsC::from(&e))
             }
+            ApplicationConfigEvent::NetworkSettingsChanged(e) => {
+                ApplicationConfigEventC::NetworkSettingsChanged(NetworkSettingsC::from(&e))
+            }
+            ApplicationConfigEvent::SettingsError(_) => ApplicationConfigEventC::SettingsError,
+        }
+    }
+}
+
+/// The C compatible result of importing the application settings.
+#[repr(C)]
+#[derive(Debug)]
+pub enum SettingsImportResultC {
+    /// Indicates that the settings have been imported
+    Ok,
+    /// Indicates that the settings file could not be read
+    ReadFailed,
+    /// Indicates that the settings file contains unknown or invalid fields
+    InvalidFields(StringArray),
+}
+
+impl From<config::Result<()>> for SettingsImportResultC {
+    fn from(value: config::Result<()>) -> Self {
+        match value {
+            Ok(_) => SettingsImportResultC::Ok,
+            Err(ConfigError::InvalidSettings(fields)) => {
+                SettingsImportResultC::InvalidFields(StringArray::from(fields))
+            }
+            Err(_) => SettingsImportResultC::ReadFailed,
         }
     }
 }
@@ -78,6 +113,8 @@ pub struct PopcornSettingsC {
     pub playback_settings: PlaybackSettingsC,
     /// The tracking settings of the application
     pub tracking_settings: TrackingSettingsC,
+    /// The network settings of the application
+    pub network_settings: NetworkSettingsC,
 }
 
 impl From<PopcornSettings> for PopcornSettingsC {
@@ -90,6 +127,7 @@ impl From<PopcornSettings> for PopcornSettingsC {
             server_settings: ServerSettingsC::from(value.server()),
             playback_settings: PlaybackSettingsC::from(value.playback()),
             tracking_settings: TrackingSettingsC::from(value.tracking()),
+            network_settings: NetworkSettingsC::from(value.network()),
         }
     }
 }
@@ -113,6 +151,16 @@ pub struct SubtitleSettingsC {
     pub decoration: DecorationType,
     /// Indicates if the subtitle should be rendered in a bold font
     pub bold: bool,
+    /// The max number of characters per line of a converted subtitle, `0` disables the line wrapping
+    pub max_line_length: u32,
+    /// The max number of lines per converted subtitle cue
+    pub max_lines: u32,
+    /// The default subtitle selection in TV or kiosk mode
+    pub tv_mode_subtitle: TvModeSubtitle,
+    /// The custom OpenSubtitles API key, can be `ptr::null()`
+    pub api_key: *mut c_char,
+    /// The OpenSubtitles user token, can be `ptr::null()`
+    pub user_token: *mut c_char,
 }
 
 impl From<&SubtitleSettings> for SubtitleSettingsC {
@@ -125,6 +173,11 @@ impl From<&SubtitleSettings> for SubtitleSettingsC {
             font_size: value.font_size,
             decoration: value.decoration,
             bold: value.bold,
+            max_line_length: value.max_line_length,
+            max_lines: value.max_lines,
+            tv_mode_subtitle: value.tv_mode_subtitle,
+            api_key: NetworkSettingsC::into_c_string_or_null(value.api_key.as_ref()),
+            user_token: NetworkSettingsC::into_c_string_or_null(value.user_token.as_ref()),
         }
     }
 }
@@ -139,6 +192,11 @@ impl From<SubtitleSettingsC> for SubtitleSettings {
             font_size: value.font_size,
             decoration: value.decoration,
             bold: value.bold,
+            max_line_length: value.max_line_length,
+            max_lines: value.max_lines,
+            tv_mode_subtitle: value.tv_mode_subtitle,
+            api_key: NetworkSettingsC::from_c_string_or_none(value.api_key),
+            user_token: NetworkSettingsC::from_c_string_or_none(value.user_token),
         }
     }
 }
@@ -157,6 +215,36 @@ pub struct TorrentSettingsC {
     pub download_rate_limit: u32,
     /// The upload rate limit
     pub upload_rate_limit: u32,
+    /// The idle timeout in seconds of a stream, `0` disables the stopping of idle streams
+    pub stream_idle_timeout: u32,
+    /// Indicates if the torrent of an idle stream should be removed
+    pub remove_idle_torrents: bool,
+    /// The local address to which the torrent traffic is bound, can be `ptr::null()`
+    pub bind_interface: *mut c_char,
+    /// The encryption mode of the peer connections
+    pub encryption_mode: EncryptionMode,
+    /// Indicates if finished stream torrents are automatically added to the torrent collection
+    pub auto_collect_finished: bool,
+    /// The initial buffer size in bytes of a stream
+    pub initial_buffer_size: u32,
+    /// The peer connect timeout in seconds
+    pub peer_connect_timeout: u32,
+    /// Indicates if torrents are announced to all trackers and tiers at once
+    pub announce_to_all_trackers: bool,
+    /// The block request timeout in seconds
+    pub request_timeout: u32,
+    /// The path to the peer ip blocklist file, can be `ptr::null()`
+    pub ip_filter: *mut c_char,
+    /// Indicates if the torrent files are fully allocated on disk
+    pub preallocate: bool,
+    /// Indicates if the DHT node runs in read-only mode
+    pub dht_read_only: bool,
+    /// The max size in bytes of the torrent metadata which is accepted from peers
+    pub max_metadata_size: u32,
+    /// The max number of outstanding block requests to a single peer
+    pub max_request_queue_depth: u32,
+    /// Indicates if the session listens on both IPv4 and IPv6
+    pub dual_stack: bool,
 }
 
 impl From<&TorrentSettings> for TorrentSettingsC {
@@ -167,18 +255,73 @@ impl From<&TorrentSettings> for TorrentSettingsC {
             connections_limit: value.connections_limit,
             download_rate_limit: value.download_rate_limit,
             upload_rate_limit: value.upload_rate_limit,
+            stream_idle_timeout: value.stream_idle_timeout,
+            remove_idle_torrents: value.remove_idle_torrents,
+            bind_interface: match &value.bind_interface {
+                None => ptr::null_mut(),
+                Some(e) => into_c_string(e.to_string()),
+            },
+            encryption_mode: value.encryption_mode.clone(),
+            auto_collect_finished: value.auto_collect_finished,
+            initial_buffer_size: value.initial_buffer_size,
+            peer_connect_timeout: value.peer_connect_timeout().as_secs() as u32,
+            announce_to_all_trackers: value.announce_to_all_trackers,
+            request_timeout: value.request_timeout,
+            ip_filter: match &value.ip_filter {
+                None => ptr::null_mut(),
+                Some(e) => into_c_string(e.to_string_lossy().to_string()),
+            },
+            preallocate: value.preallocate,
+            dht_read_only: value.dht_read_only,
+            max_metadata_size: value.max_metadata_size(),
+            max_request_queue_depth: value.max_request_queue_depth(),
+            dual_stack: value.dual_stack,
         }
     }
 }
 
 impl From<TorrentSettingsC> for TorrentSettings {
     fn from(value: TorrentSettingsC) -> Self {
+        let bind_interface = if !value.bind_interface.is_null() {
+            let address = from_c_string(value.bind_interface);
+            match address.trim() {
+                "" => None,
+                e => e
+                    .parse()
+                    .map_err(|err| warn!("Invalid bind interface address {}, {}", e, err))
+                    .ok(),
+            }
+        } else {
+            None
+        };
+        let ip_filter = if !value.ip_filter.is_null() {
+            Some(PathBuf::from(from_c_string(value.ip_filter)))
+                .filter(|e| !e.as_os_str().is_empty())
+        } else {
+            None
+        };
+
         Self {
             directory: PathBuf::from(from_c_string(value.directory)),
             cleaning_mode: value.cleaning_mode,
             connections_limit: value.connections_limit,
             download_rate_limit: value.download_rate_limit,
             upload_rate_limit: value.upload_rate_limit,
+            stream_idle_timeout: value.stream_idle_timeout,
+            remove_idle_torrents: value.remove_idle_torrents,
+            bind_interface,
+            encryption_mode: value.encryption_mode,
+            auto_collect_finished: value.auto_collect_finished,
+            initial_buffer_size: value.initial_buffer_size,
+            peer_connect_timeout: value.peer_connect_timeout,
+            announce_to_all_trackers: value.announce_to_all_trackers,
+            request_timeout: value.request_timeout,
+            ip_filter,
+            preallocate: value.preallocate,
+            dht_read_only: value.dht_read_only,
+            max_metadata_size: value.max_metadata_size,
+            max_request_queue_depth: value.max_request_queue_depth,
+            dual_stack: value.dual_stack,
         }
     }
 }
@@ -191,8 +334,10 @@ pub struct UiSettingsC {
     pub default_language: *mut c_char,
     /// The ui scale of the application
     pub ui_scale: UiScale,
-    /// The default start screen of the application
-    pub start_screen: Category,
+    /// The page which is shown when the application is started
+    pub start_page: StartPage,
+    /// The quality of the poster images which are requested
+    pub poster_quality: PosterQuality,
     /// The indication if the UI was maximized the last time the application was closed
     pub maximized: bool,
     /// The indication if the UI should use a native window rather than the borderless stage
@@ -204,7 +349,8 @@ impl From<&UiSettings> for UiSettingsC {
         Self {
             default_language: into_c_string(value.default_language.clone()),
             ui_scale: value.ui_scale.clone(),
-            start_screen: value.start_screen.clone(),
+            start_page: value.start_page.clone(),
+            poster_quality: value.poster_quality.clone(),
             maximized: value.maximized,
             native_window_enabled: value.native_window_enabled,
         }
@@ -216,7 +362,8 @@ impl From<UiSettingsC> for UiSettings {
         Self {
             default_language: from_c_string(value.default_language),
             ui_scale: value.ui_scale,
-            start_screen: value.start_screen,
+            start_page: value.start_page,
+            poster_quality: value.poster_quality,
             maximized: value.maximized,
             native_window_enabled: value.native_window_enabled,
         }
@@ -256,7 +403,10 @@ impl From<ServerSettingsC> for ServerSettings {
             None
         };
 
-        Self { api_server }
+        Self {
+            api_server,
+            provider_uris: Default::default(),
+        }
     }
 }
 
@@ -270,6 +420,10 @@ pub struct PlaybackSettingsC {
     pub fullscreen: bool,
     /// Indicates if the next episode of the show will be played
     pub auto_play_next_episode_enabled: bool,
+    /// The id of the default player, or [ptr::null_mut] if no default player has been set
+    pub default_player: *mut c_char,
+    /// The percentage of the media which should have been played before it's marked as watched
+    pub watched_threshold_percent: u32,
 }
 
 impl From<&PlaybackSettings> for PlaybackSettingsC {
@@ -278,11 +432,17 @@ impl From<&PlaybackSettings> for PlaybackSettingsC {
             None => ptr::null_mut(),
             Some(e) => into_c_owned(e.clone()),
         };
+        let default_player = match &value.default_player {
+            None => ptr::null_mut(),
+            Some(e) => into_c_string(e.clone()),
+        };
 
         Self {
             quality,
             fullscreen: value.fullscreen,
             auto_play_next_episode_enabled: value.auto_play_next_episode_enabled,
+            default_player,
+            watched_threshold_percent: value.watched_threshold_percent,
         }
     }
 }
@@ -294,11 +454,96 @@ impl From<PlaybackSettingsC> for PlaybackSettings {
         } else {
             None
         };
+        let default_player = if !value.default_player.is_null() {
+            Some(from_c_string(value.default_player))
+        } else {
+            None
+        };
 
         Self {
             quality,
             fullscreen: value.fullscreen,
             auto_play_next_episode_enabled: value.auto_play_next_episode_enabled,
+            default_player,
+            watched_threshold_percent: value.watched_threshold_percent,
+        }
+    }
+}
+
+/// The C compatible network settings.
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+pub struct NetworkSettingsC {
+    /// The proxy uri through which requests are sent, can be `ptr::null()`
+    pub proxy: *mut c_char,
+    /// The username to authenticate with the proxy, can be `ptr::null()`
+    pub proxy_username: *mut c_char,
+    /// The password to authenticate with the proxy, can be `ptr::null()`
+    pub proxy_password: *mut c_char,
+    /// The comma separated hosts which bypass the proxy, can be `ptr::null()`
+    pub proxy_bypass: *mut c_char,
+    /// The custom user agent of the requests, can be `ptr::null()`
+    pub user_agent: *mut c_char,
+    /// The timeout in seconds of each request, 0 disables the timeout
+    pub request_timeout: u64,
+    /// Indicates if the last crash report is sent along with the next update check
+    pub send_crash_reports: bool,
+}
+
+impl NetworkSettingsC {
+    fn into_c_string_or_null(value: Option<&String>) -> *mut c_char {
+        match value {
+            None => ptr::null_mut(),
+            Some(e) => into_c_string(e.clone()),
+        }
+    }
+
+    fn from_c_string_or_none(ptr: *mut c_char) -> Option<String> {
+        if !ptr.is_null() {
+            Some(from_c_string(ptr)).filter(|e| !e.trim().is_empty())
+        } else {
+            None
+        }
+    }
+}
+
+impl From<&NetworkSettings> for NetworkSettingsC {
+    fn from(value: &NetworkSettings) -> Self {
+        let proxy_bypass = value.proxy_bypass.join(",");
+
+        Self {
+            proxy: Self::into_c_string_or_null(value.proxy.as_ref()),
+            proxy_username: Self::into_c_string_or_null(value.proxy_username.as_ref()),
+            proxy_password: Self::into_c_string_or_null(value.proxy_password.as_ref()),
+            proxy_bypass: Self::into_c_string_or_null(
+                Some(&proxy_bypass).filter(|e| !e.is_empty()),
+            ),
+            user_agent: Self::into_c_string_or_null(value.user_agent.as_ref()),
+            request_timeout: value.request_timeout,
+            send_crash_reports: value.send_crash_reports,
+        }
+    }
+}
+
+impl From<NetworkSettingsC> for NetworkSettings {
+    fn from(value: NetworkSettingsC) -> Self {
+        let proxy_bypass = NetworkSettingsC::from_c_string_or_none(value.proxy_bypass)
+            .map(|e| {
+                e.split(',')
+                    .map(|e| e.trim().to_string())
+                    .filter(|e| !e.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            proxy: NetworkSettingsC::from_c_string_or_none(value.proxy),
+            proxy_username: NetworkSettingsC::from_c_string_or_none(value.proxy_username),
+            proxy_password: NetworkSettingsC::from_c_string_or_none(value.proxy_password),
+            proxy_bypass,
+            user_agent: NetworkSettingsC::from_c_string_or_none(value.user_agent),
+            request_timeout: value.request_timeout,
+            send_crash_reports: value.send_crash_reports,
         }
     }
 }
@@ -384,6 +629,11 @@ mod test {
             font_size: 22,
             decoration: DecorationType::None,
             bold: false,
+            max_line_length: 0,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: None,
         };
         let loaded_event = ApplicationConfigEvent::SettingsLoaded;
         let subtitle_event = ApplicationConfigEvent::SubtitleSettingsChanged(subtitle.clone());
@@ -404,6 +654,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_from_application_settings_error_event() {
+        let event = ApplicationConfigEvent::SettingsError(ConfigError::MigrationFailed(
+            1,
+            "lorem".to_string(),
+        ));
+
+        let result = ApplicationConfigEventC::from(event);
+
+        assert_eq!(ApplicationConfigEventC::SettingsError, result);
+    }
+
     #[test]
     fn test_from_subtitle_settings() {
         let directory = "/var/lorem/ipsum";
@@ -417,6 +679,11 @@ mod test {
             font_size: 28,
             decoration: DecorationType::Outline,
             bold: true,
+            max_line_length: 60,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: Some("MyToken".to_string()),
         };
 
         let result = SubtitleSettingsC::from(&settings);
@@ -428,6 +695,11 @@ mod test {
         assert_eq!(28, result.font_size);
         assert_eq!(DecorationType::Outline, result.decoration);
         assert_eq!(true, result.bold);
+        assert_eq!(60, result.max_line_length);
+        assert_eq!(2, result.max_lines);
+        assert_eq!(TvModeSubtitle::InterfaceLanguage, result.tv_mode_subtitle);
+        assert_eq!(ptr::null_mut(), result.api_key);
+        assert_eq!("MyToken".to_string(), from_c_string(result.user_token));
     }
 
     #[test]
@@ -442,6 +714,11 @@ mod test {
             font_size,
             decoration: DecorationType::OpaqueBackground,
             bold: true,
+            max_line_length: 42,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::UserPreference,
+            api_key: into_c_string("MyApiKey"),
+            user_token: ptr::null_mut(),
         };
         let expected_result = SubtitleSettings {
             directory: directory.to_string(),
@@ -451,6 +728,11 @@ mod test {
             font_size,
             decoration: DecorationType::OpaqueBackground,
             bold: true,
+            max_line_length: 42,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::UserPreference,
+            api_key: Some("MyApiKey".to_string()),
+            user_token: None,
         };
 
         let result = SubtitleSettings::from(settings);
@@ -467,6 +749,21 @@ mod test {
             connections_limit: 100,
             download_rate_limit: 0,
             upload_rate_limit: 0,
+            stream_idle_timeout: 120,
+            remove_idle_torrents: true,
+            bind_interface: Some("10.8.0.2".parse().unwrap()),
+            encryption_mode: EncryptionMode::Require,
+            auto_collect_finished: true,
+            initial_buffer_size: 2048,
+            peer_connect_timeout: 30,
+            announce_to_all_trackers: true,
+            request_timeout: 10,
+            ip_filter: Some(PathBuf::from("/tmp/lorem/blocklist.p2p")),
+            preallocate: true,
+            dht_read_only: true,
+            max_metadata_size: 20 * 1024 * 1024,
+            max_request_queue_depth: 1000,
+            dual_stack: false,
         };
 
         let result = TorrentSettingsC::from(&settings);
@@ -474,6 +771,24 @@ mod test {
         assert_eq!(directory.to_string(), from_c_string(result.directory));
         assert_eq!(CleaningMode::Off, result.cleaning_mode);
         assert_eq!(100, result.connections_limit);
+        assert_eq!(120, result.stream_idle_timeout);
+        assert_eq!(true, result.remove_idle_torrents);
+        assert_eq!("10.8.0.2".to_string(), from_c_string(result.bind_interface));
+        assert_eq!(EncryptionMode::Require, result.encryption_mode);
+        assert_eq!(true, result.auto_collect_finished);
+        assert_eq!(2048, result.initial_buffer_size);
+        assert_eq!(30, result.peer_connect_timeout);
+        assert_eq!(true, result.announce_to_all_trackers);
+        assert_eq!(10, result.request_timeout);
+        assert_eq!(
+            "/tmp/lorem/blocklist.p2p".to_string(),
+            from_c_string(result.ip_filter)
+        );
+        assert_eq!(true, result.preallocate);
+        assert_eq!(true, result.dht_read_only);
+        assert_eq!(20 * 1024 * 1024, result.max_metadata_size);
+        assert_eq!(1000, result.max_request_queue_depth);
+        assert_eq!(false, result.dual_stack);
     }
 
     #[test]
@@ -486,6 +801,21 @@ mod test {
             connections_limit,
             download_rate_limit: 10,
             upload_rate_limit: 20,
+            stream_idle_timeout: 60,
+            remove_idle_torrents: false,
+            bind_interface: into_c_string("fd00::2".to_string()),
+            encryption_mode: EncryptionMode::Disable,
+            auto_collect_finished: false,
+            initial_buffer_size: 4096,
+            peer_connect_timeout: 30,
+            announce_to_all_trackers: true,
+            request_timeout: 10,
+            ip_filter: into_c_string("/tmp/lorem/blocklist.p2p".to_string()),
+            preallocate: true,
+            dht_read_only: true,
+            max_metadata_size: 20 * 1024 * 1024,
+            max_request_queue_depth: 1000,
+            dual_stack: false,
         };
         let expected_result = TorrentSettings {
             directory: PathBuf::from(directory),
@@ -493,6 +823,21 @@ mod test {
             connections_limit,
             download_rate_limit: 10,
             upload_rate_limit: 20,
+            stream_idle_timeout: 60,
+            remove_idle_torrents: false,
+            bind_interface: Some("fd00::2".parse().unwrap()),
+            encryption_mode: EncryptionMode::Disable,
+            auto_collect_finished: false,
+            initial_buffer_size: 4096,
+            peer_connect_timeout: 30,
+            announce_to_all_trackers: true,
+            request_timeout: 10,
+            ip_filter: Some(PathBuf::from("/tmp/lorem/blocklist.p2p")),
+            preallocate: true,
+            dht_read_only: true,
+            max_metadata_size: 20 * 1024 * 1024,
+            max_request_queue_depth: 1000,
+            dual_stack: false,
         };
 
         let result = TorrentSettings::from(settings);
@@ -500,6 +845,36 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_torrent_settings_from_invalid_bind_interface() {
+        let settings = TorrentSettingsC {
+            directory: into_c_string("/tmp/lorem/torrent".to_string()),
+            cleaning_mode: CleaningMode::Off,
+            connections_limit: 100,
+            download_rate_limit: 0,
+            upload_rate_limit: 0,
+            stream_idle_timeout: 0,
+            remove_idle_torrents: false,
+            bind_interface: into_c_string("lorem".to_string()),
+            encryption_mode: EncryptionMode::Prefer,
+            auto_collect_finished: false,
+            initial_buffer_size: 4096,
+            peer_connect_timeout: 30,
+            announce_to_all_trackers: true,
+            request_timeout: 10,
+            ip_filter: ptr::null_mut(),
+            preallocate: true,
+            dht_read_only: true,
+            max_metadata_size: 20 * 1024 * 1024,
+            max_request_queue_depth: 1000,
+            dual_stack: false,
+        };
+
+        let result = TorrentSettings::from(settings);
+
+        assert_eq!(None, result.bind_interface)
+    }
+
     #[test]
     fn test_from_ui_settings() {
         let language = "en";
@@ -507,7 +882,8 @@ mod test {
         let settings = UiSettings {
             default_language: language.to_string(),
             ui_scale: ui_scale.clone(),
-            start_screen: Category::Movies,
+            start_page: StartPage::Favorites,
+            poster_quality: PosterQuality::Low,
             maximized: true,
             native_window_enabled: false,
         };
@@ -516,7 +892,8 @@ mod test {
 
         assert_eq!(language.to_string(), from_c_string(result.default_language));
         assert_eq!(ui_scale, result.ui_scale);
-        assert_eq!(Category::Movies, result.start_screen);
+        assert_eq!(StartPage::Favorites, result.start_page);
+        assert_eq!(PosterQuality::Low, result.poster_quality);
         assert_eq!(true, result.maximized);
         assert_eq!(false, result.native_window_enabled);
     }
@@ -527,14 +904,16 @@ mod test {
         let settings = UiSettingsC {
             default_language: into_c_string("en".to_string()),
             ui_scale: ui_scale.clone(),
-            start_screen: Category::Series,
+            start_page: StartPage::Series,
+            poster_quality: PosterQuality::High,
             maximized: true,
             native_window_enabled: false,
         };
         let expected_result = UiSettings {
             default_language: "en".to_string(),
             ui_scale,
-            start_screen: Category::Series,
+            start_page: StartPage::Series,
+            poster_quality: PosterQuality::High,
             maximized: true,
             native_window_enabled: false,
         };
@@ -549,6 +928,7 @@ mod test {
         let api_server = "http://localhost:8080";
         let settings = ServerSettings {
             api_server: Some(api_server.to_string()),
+            provider_uris: Default::default(),
         };
 
         let result = ServerSettingsC::from(&settings);
@@ -558,7 +938,10 @@ mod test {
 
     #[test]
     fn test_from_server_settings_none_api_server() {
-        let settings = ServerSettings { api_server: None };
+        let settings = ServerSettings {
+            api_server: None,
+            provider_uris: Default::default(),
+        };
 
         let result = ServerSettingsC::from(&settings);
 
@@ -573,6 +956,7 @@ mod test {
         };
         let expected_result = ServerSettings {
             api_server: Some(api_server.to_string()),
+            provider_uris: Default::default(),
         };
 
         let result = ServerSettings::from(settings);
@@ -580,12 +964,69 @@ mod test {
         assert_eq!(expected_result, result)
     }
 
+    #[test]
+    fn test_from_network_settings() {
+        let settings = NetworkSettings {
+            proxy: Some("http://localhost:3128".to_string()),
+            proxy_username: Some("user".to_string()),
+            proxy_password: None,
+            proxy_bypass: vec!["localhost".to_string(), "192.168.0.0/16".to_string()],
+            user_agent: None,
+            request_timeout: 30,
+            send_crash_reports: true,
+        };
+
+        let result = NetworkSettingsC::from(&settings);
+
+        assert_eq!(
+            "http://localhost:3128".to_string(),
+            from_c_string(result.proxy)
+        );
+        assert_eq!("user".to_string(), from_c_string(result.proxy_username));
+        assert_eq!(ptr::null_mut(), result.proxy_password);
+        assert_eq!(
+            "localhost,192.168.0.0/16".to_string(),
+            from_c_string(result.proxy_bypass)
+        );
+        assert_eq!(ptr::null_mut(), result.user_agent);
+        assert_eq!(30, result.request_timeout);
+        assert_eq!(true, result.send_crash_reports);
+    }
+
+    #[test]
+    fn test_from_network_settings_c() {
+        let settings = NetworkSettingsC {
+            proxy: into_c_string("http://localhost:3128"),
+            proxy_username: ptr::null_mut(),
+            proxy_password: into_c_string(""),
+            proxy_bypass: into_c_string("localhost, *.local,"),
+            user_agent: into_c_string("PopcornFX"),
+            request_timeout: 0,
+            send_crash_reports: true,
+        };
+        let expected_result = NetworkSettings {
+            proxy: Some("http://localhost:3128".to_string()),
+            proxy_username: None,
+            proxy_password: None,
+            proxy_bypass: vec!["localhost".to_string(), "*.local".to_string()],
+            user_agent: Some("PopcornFX".to_string()),
+            request_timeout: 0,
+            send_crash_reports: true,
+        };
+
+        let result = NetworkSettings::from(settings);
+
+        assert_eq!(expected_result, result)
+    }
+
     #[test]
     fn test_from_playback_settings() {
         let settings = PlaybackSettings {
             quality: Some(Quality::P1080),
             fullscreen: true,
             auto_play_next_episode_enabled: false,
+            default_player: Some("MyPlayer".to_string()),
+            watched_threshold_percent: 90,
         };
 
         let result = PlaybackSettingsC::from(&settings);
@@ -593,6 +1034,8 @@ mod test {
         assert_eq!(Quality::P1080, from_c_owned(result.quality));
         assert_eq!(true, result.fullscreen);
         assert_eq!(false, result.auto_play_next_episode_enabled);
+        assert_eq!("MyPlayer".to_string(), from_c_string(result.default_player));
+        assert_eq!(90, result.watched_threshold_percent);
     }
 
     #[test]
@@ -601,11 +1044,15 @@ mod test {
             quality: ptr::null_mut(),
             fullscreen: true,
             auto_play_next_episode_enabled: true,
+            default_player: into_c_string("MyPlayer".to_string()),
+            watched_threshold_percent: 80,
         };
         let expected_result = PlaybackSettings {
             quality: None,
             fullscreen: true,
             auto_play_next_episode_enabled: true,
+            default_player: Some("MyPlayer".to_string()),
+            watched_threshold_percent: 80,
         };
 
         let result = PlaybackSettings::from(settings);
@@ -359,6 +359,32 @@ impl Drop for SubtitleC {
     }
 }
 
+/// The C array of [SubtitleCue] items.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct SubtitleCueSet {
+    /// The subtitle cue array
+    pub cues: *mut SubtitleCueC,
+    /// The length of the array
+    pub len: i32,
+}
+
+impl From<Vec<SubtitleCue>> for SubtitleCueSet {
+    fn from(value: Vec<SubtitleCue>) -> Self {
+        trace!("Converting subtitle cues to C set");
+        let (cues, len) = into_c_vec(value.iter().map(SubtitleCueC::from).collect());
+
+        Self { cues, len }
+    }
+}
+
+impl Drop for SubtitleCueSet {
+    fn drop(&mut self) {
+        trace!("Dropping {:?}", self);
+        drop(from_c_vec_owned(self.cues, self.len));
+    }
+}
+
 /// Represents a cue in a subtitle track in a C-compatible format.
 #[repr(C)]
 #[derive(Debug, Clone)]
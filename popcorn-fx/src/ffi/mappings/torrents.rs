@@ -1,11 +1,13 @@
+use std::ops::Range;
 use std::os::raw::c_char;
 use std::ptr;
 
 use log::trace;
 
 use popcorn_fx_core::core::torrents::{
-    DownloadStatus, TorrentError, TorrentFileInfo, TorrentInfo, TorrentManagerState, TorrentState,
-    TorrentStreamEvent, TorrentStreamState, TorrentWrapper,
+    DownloadStatus, InfoHash, StreamBufferStatus, TorrentError, TorrentFileInfo,
+    TorrentHandleEvent, TorrentInfo, TorrentManagerState, TorrentState, TorrentStreamEvent,
+    TorrentStreamState, TorrentSummary, TorrentVersion, TorrentWrapper,
 };
 use popcorn_fx_core::{from_c_string, into_c_string, into_c_vec};
 
@@ -21,6 +23,9 @@ pub type HasPieceCallbackC = extern "C" fn(u32) -> bool;
 /// Type alias for a callback that retrieves the total pieces of the torrent.
 pub type TotalPiecesCallbackC = extern "C" fn() -> i32;
 
+/// Type alias for a callback that retrieves the piece length of the torrent.
+pub type PieceLengthCallbackC = extern "C" fn() -> u64;
+
 /// Type alias for a callback that prioritizes bytes.
 pub type PrioritizeBytesCallbackC = extern "C" fn(i32, *mut u64);
 
@@ -47,9 +52,15 @@ pub type ResolveTorrentCallback = extern "C" fn(
 /// Type alias for a callback that cancels a torrent download.
 pub type CancelTorrentCallback = extern "C" fn(*mut c_char);
 
+/// Type alias for a callback that pauses or resumes a torrent download.
+pub type PauseTorrentCallback = extern "C" fn(handle: *mut c_char, paused: bool);
+
 /// Type alias for a callback that handles torrent stream events.
 pub type TorrentStreamEventCallback = extern "C" fn(TorrentStreamEventC);
 
+/// Type alias for a callback that handles the events of a single torrent.
+pub type TorrentHandleEventCallback = extern "C" fn(TorrentHandleEventC);
+
 /// A C-compatible enum representing various errors related to torrents.
 #[repr(C)]
 #[derive(Debug, Clone)]
@@ -70,6 +81,10 @@ pub enum TorrentErrorC {
     TorrentResolvingFailed(*mut c_char),
     /// Represents an error indicating failure during torrent collection loading.
     TorrentCollectionLoadingFailed(*mut c_char),
+    /// Represents an error indicating that the magnet is not stored within the torrent collection.
+    MagnetNotStored(*mut c_char),
+    /// Represents an error indicating a failure while writing the torrent data.
+    StorageError(*mut c_char),
 }
 
 impl From<TorrentError> for TorrentErrorC {
@@ -90,6 +105,10 @@ impl From<TorrentError> for TorrentErrorC {
             TorrentError::TorrentCollectionLoadingFailed(error) => {
                 TorrentErrorC::TorrentCollectionLoadingFailed(into_c_string(error))
             }
+            TorrentError::MagnetNotStored(magnet_uri) => {
+                TorrentErrorC::MagnetNotStored(into_c_string(magnet_uri))
+            }
+            TorrentError::StorageError(error) => TorrentErrorC::StorageError(into_c_string(error)),
         }
     }
 }
@@ -112,6 +131,10 @@ impl From<TorrentErrorC> for TorrentError {
             TorrentErrorC::TorrentCollectionLoadingFailed(error) => {
                 TorrentError::TorrentCollectionLoadingFailed(from_c_string(error))
             }
+            TorrentErrorC::MagnetNotStored(magnet_uri) => {
+                TorrentError::MagnetNotStored(from_c_string(magnet_uri))
+            }
+            TorrentErrorC::StorageError(error) => TorrentError::StorageError(from_c_string(error)),
         }
     }
 }
@@ -124,9 +147,12 @@ pub struct TorrentC {
     pub handle: *mut c_char,
     /// The filepath to the torrent file
     pub filepath: *mut c_char,
+    /// The info hash of the torrent as reported by the torrent session, or null when unknown
+    pub info_hash: *mut c_char,
     pub has_byte_callback: HasByteCallbackC,
     pub has_piece_callback: HasPieceCallbackC,
     pub total_pieces: TotalPiecesCallbackC,
+    pub piece_length: PieceLengthCallbackC,
     pub prioritize_bytes: PrioritizeBytesCallbackC,
     pub prioritize_pieces: PrioritizePiecesCallbackC,
     pub sequential_mode: SequentialModeCallbackC,
@@ -139,12 +165,18 @@ impl From<TorrentC> for TorrentWrapper {
         Self::new(
             from_c_string(value.handle),
             from_c_string(value.filepath),
+            if !value.info_hash.is_null() {
+                Some(from_c_string(value.info_hash))
+            } else {
+                None
+            },
             Box::new(move |bytes| -> bool {
                 let (bytes, len) = into_c_vec(bytes.to_vec());
                 (value.has_byte_callback)(len, bytes)
             }),
             Box::new(move |piece| (value.has_piece_callback)(piece)),
             Box::new(move || (value.total_pieces)()),
+            Box::new(move || (value.piece_length)()),
             Box::new(move |bytes| {
                 let (bytes, len) = into_c_vec(bytes.to_vec());
                 (value.prioritize_bytes)(len, bytes)
@@ -172,6 +204,8 @@ pub struct TorrentInfoC {
     pub total_files: i32,
     /// A set of `TorrentFileInfoC` structs representing individual files within the torrent.
     pub files: CArray<TorrentFileInfoC>,
+    /// The BitTorrent protocol version of the torrent, being v1, v2 or hybrid.
+    pub version: TorrentVersion,
 }
 
 impl From<TorrentInfo> for TorrentInfoC {
@@ -187,6 +221,7 @@ impl From<TorrentInfo> for TorrentInfoC {
             .into_iter()
             .map(|e| TorrentFileInfoC::from(e))
             .collect();
+        let version = value.version().unwrap_or(TorrentVersion::V1);
 
         Self {
             uri: into_c_string(value.uri),
@@ -194,6 +229,7 @@ impl From<TorrentInfo> for TorrentInfoC {
             directory_name,
             total_files: value.total_files,
             files: CArray::from(torrent_info_files),
+            version,
         }
     }
 }
@@ -276,6 +312,8 @@ pub struct DownloadStatusC {
     pub downloaded: u64,
     /// The total size of the torrent in bytes.
     pub total_size: u64,
+    /// The effective block request queue depth of the connected peers.
+    pub request_queue_depth: u32,
 }
 
 impl From<DownloadStatusC> for DownloadStatus {
@@ -288,6 +326,7 @@ impl From<DownloadStatusC> for DownloadStatus {
             upload_speed: value.upload_speed,
             downloaded: value.downloaded,
             total_size: value.total_size,
+            request_queue_depth: value.request_queue_depth,
         }
     }
 }
@@ -302,6 +341,35 @@ impl From<DownloadStatus> for DownloadStatusC {
             upload_speed: value.upload_speed,
             downloaded: value.downloaded,
             total_size: value.total_size,
+            request_queue_depth: value.request_queue_depth,
+        }
+    }
+}
+
+/// Represents the buffer health of a torrent stream in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamBufferStatusC {
+    /// The current playback offset within the stream in bytes.
+    pub offset: u64,
+    /// The contiguous downloaded bytes ahead of the playback offset.
+    pub buffered_bytes: u64,
+    /// The initial amount of bytes which should be buffered before the playback is started.
+    pub initial_buffer_size: u64,
+    /// The current download rate in bytes per second.
+    pub download_speed: u32,
+    /// The estimated time in millis until the initial buffer has been filled, or `-1` when unknown.
+    pub eta: i64,
+}
+
+impl From<StreamBufferStatus> for StreamBufferStatusC {
+    fn from(value: StreamBufferStatus) -> Self {
+        Self {
+            offset: value.offset,
+            buffered_bytes: value.buffered_bytes,
+            initial_buffer_size: value.initial_buffer_size,
+            download_speed: value.download_speed,
+            eta: value.eta.map(|e| e.as_millis() as i64).unwrap_or(-1),
         }
     }
 }
@@ -314,6 +382,8 @@ pub enum TorrentStreamEventC {
     StateChanged(TorrentStreamState),
     /// Indicates a change in the download status of the torrent stream.
     DownloadStatus(DownloadStatusC),
+    /// Indicates a change in the buffer health of the torrent stream.
+    BufferStatus(StreamBufferStatusC),
 }
 
 impl From<TorrentStreamEvent> for TorrentStreamEventC {
@@ -323,6 +393,111 @@ impl From<TorrentStreamEvent> for TorrentStreamEventC {
             TorrentStreamEvent::DownloadStatus(e) => {
                 TorrentStreamEventC::DownloadStatus(DownloadStatusC::from(e))
             }
+            TorrentStreamEvent::BufferStatus(e) => {
+                TorrentStreamEventC::BufferStatus(StreamBufferStatusC::from(e))
+            }
+        }
+    }
+}
+
+/// Represents an event of a single torrent in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub enum TorrentHandleEventC {
+    /// Indicates a change in the state of the torrent.
+    StateChanged(TorrentState),
+    /// Indicates that the metadata of the torrent has been received.
+    MetadataReceived,
+    /// Indicates that the torrent has completed downloading.
+    Completed,
+    /// Indicates that the torrent has been removed, no further events will be received.
+    Removed,
+}
+
+impl From<TorrentHandleEvent> for TorrentHandleEventC {
+    fn from(value: TorrentHandleEvent) -> Self {
+        match value {
+            TorrentHandleEvent::StateChanged(e) => TorrentHandleEventC::StateChanged(e),
+            TorrentHandleEvent::MetadataReceived => TorrentHandleEventC::MetadataReceived,
+            TorrentHandleEvent::Completed => TorrentHandleEventC::Completed,
+            TorrentHandleEvent::Removed => TorrentHandleEventC::Removed,
+        }
+    }
+}
+
+/// Represents the info hash of a torrent in C-compatible form.
+#[repr(C)]
+#[derive(Debug)]
+pub struct InfoHashC {
+    /// The v1 (SHA-1) info hash as hex string, or `ptr::null()` if not available.
+    pub v1: *mut c_char,
+    /// The v2 (SHA-256) info hash as hex string, or `ptr::null()` if not available.
+    pub v2: *mut c_char,
+}
+
+impl From<InfoHash> for InfoHashC {
+    fn from(value: InfoHash) -> Self {
+        trace!("Converting InfoHash to C for {:?}", value);
+        Self {
+            v1: value.v1().map(into_c_string).unwrap_or(ptr::null_mut()),
+            v2: value.v2().map(into_c_string).unwrap_or(ptr::null_mut()),
+        }
+    }
+}
+
+/// Represents the summary of an active torrent in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct TorrentSummaryC {
+    /// The unique handle of the torrent.
+    pub handle: *mut c_char,
+    /// The info hash of the torrent, or `ptr::null()` if not known.
+    pub info_hash: *mut c_char,
+    /// The name of the torrent file which is being downloaded.
+    pub name: *mut c_char,
+    /// The last known state of the torrent.
+    pub state: TorrentState,
+    /// The last known progress between 0 and 1 of the torrent.
+    pub progress: f32,
+    /// The last known download rate in bytes per second.
+    pub download_speed: u32,
+    /// The last known upload rate in bytes per second.
+    pub upload_speed: u32,
+}
+
+impl From<TorrentSummary> for TorrentSummaryC {
+    fn from(value: TorrentSummary) -> Self {
+        trace!("Converting TorrentSummary to C for {:?}", value);
+        Self {
+            handle: into_c_string(value.handle),
+            info_hash: value
+                .info_hash
+                .map(into_c_string)
+                .unwrap_or(ptr::null_mut()),
+            name: into_c_string(value.name),
+            state: value.state,
+            progress: value.progress,
+            download_speed: value.download_speed,
+            upload_speed: value.upload_speed,
+        }
+    }
+}
+
+/// Represents a downloaded byte range of a torrent stream in C-compatible form.
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ByteRangeC {
+    /// The first byte of the range, inclusive.
+    pub start: u64,
+    /// The last byte of the range, exclusive.
+    pub end: u64,
+}
+
+impl From<Range<u64>> for ByteRangeC {
+    fn from(value: Range<u64>) -> Self {
+        Self {
+            start: value.start,
+            end: value.end,
         }
     }
 }
@@ -330,6 +505,7 @@ impl From<TorrentStreamEvent> for TorrentStreamEventC {
 #[cfg(test)]
 mod tests {
     use std::ptr;
+    use std::time::Duration;
 
     use popcorn_fx_core::into_c_string;
     use popcorn_fx_core::testing::init_logger;
@@ -346,6 +522,7 @@ mod tests {
             name: into_c_string(name.to_string()),
             directory_name: ptr::null_mut(),
             total_files,
+            version: TorrentVersion::V1,
             files: CArray::from(Vec::<TorrentFileInfoC>::new()),
         };
         let expected_result = TorrentInfo {
@@ -416,6 +593,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 230,
             total_size: 158965,
+            request_queue_depth: 24,
         };
         let expected_result = DownloadStatusC {
             progress: 0.6,
@@ -425,6 +603,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 230,
             total_size: 158965,
+            request_queue_depth: 24,
         };
 
         let result = DownloadStatusC::from(status);
@@ -442,6 +621,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 230,
             total_size: 158965,
+            request_queue_depth: 24,
         };
         let expected_result = DownloadStatus {
             progress: 0.6,
@@ -451,6 +631,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 230,
             total_size: 158965,
+            request_queue_depth: 24,
         };
 
         let result = DownloadStatus::from(status_c);
@@ -483,6 +664,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 8200,
             total_size: 20000,
+            request_queue_depth: 24,
         };
         let expected_result = DownloadStatusC {
             progress: 0.35,
@@ -492,6 +674,7 @@ mod tests {
             upload_speed: 16,
             downloaded: 8200,
             total_size: 20000,
+            request_queue_depth: 24,
         };
         let event = TorrentStreamEvent::DownloadStatus(status);
 
@@ -506,6 +689,75 @@ mod tests {
                 result
             )
         }
+
+        let event = TorrentStreamEvent::BufferStatus(StreamBufferStatus {
+            offset: 1024,
+            buffered_bytes: 2048,
+            initial_buffer_size: 4096,
+            download_speed: 512,
+            eta: Some(Duration::from_secs(4)),
+        });
+
+        let result = TorrentStreamEventC::from(event);
+
+        if let TorrentStreamEventC::BufferStatus(result) = result {
+            assert_eq!(
+                StreamBufferStatusC {
+                    offset: 1024,
+                    buffered_bytes: 2048,
+                    initial_buffer_size: 4096,
+                    download_speed: 512,
+                    eta: 4000,
+                },
+                result
+            )
+        } else {
+            assert!(
+                false,
+                "expected TorrentStreamEventC::BufferStatus, but got {:?} instead",
+                result
+            )
+        }
+    }
+
+    #[test]
+    fn test_stream_buffer_status_c_from_unknown_eta() {
+        let status = StreamBufferStatus {
+            offset: 0,
+            buffered_bytes: 0,
+            initial_buffer_size: 4096,
+            download_speed: 0,
+            eta: None,
+        };
+
+        let result = StreamBufferStatusC::from(status);
+
+        assert_eq!(-1, result.eta);
+    }
+
+    #[test]
+    fn test_torrent_handle_event_c_from() {
+        let event = TorrentHandleEvent::StateChanged(TorrentState::Paused);
+
+        let result = TorrentHandleEventC::from(event);
+
+        if let TorrentHandleEventC::StateChanged(result) = result {
+            assert_eq!(TorrentState::Paused, result)
+        } else {
+            assert!(
+                false,
+                "expected TorrentHandleEventC::StateChanged, but got {:?} instead",
+                result
+            )
+        }
+
+        let result = TorrentHandleEventC::from(TorrentHandleEvent::Removed);
+
+        assert!(
+            matches!(result, TorrentHandleEventC::Removed),
+            "expected TorrentHandleEventC::Removed, but got {:?} instead",
+            result
+        );
     }
 
     #[test]
@@ -544,4 +796,41 @@ mod tests {
             error
         );
     }
+
+    #[test]
+    fn test_torrent_summary_c_from() {
+        init_logger();
+        let summary = TorrentSummary {
+            handle: "MyHandle".to_string(),
+            info_hash: None,
+            name: "lorem.mp4".to_string(),
+            state: TorrentState::Downloading,
+            progress: 0.25,
+            download_speed: 1024,
+            upload_speed: 256,
+        };
+
+        let result = TorrentSummaryC::from(summary);
+
+        assert_eq!("MyHandle".to_string(), from_c_string(result.handle));
+        assert_eq!(ptr::null_mut(), result.info_hash);
+        assert_eq!("lorem.mp4".to_string(), from_c_string(result.name));
+        assert_eq!(TorrentState::Downloading, result.state);
+        assert_eq!(0.25, result.progress);
+        assert_eq!(1024, result.download_speed);
+        assert_eq!(256, result.upload_speed);
+    }
+
+    #[test]
+    fn test_byte_range_c_from() {
+        let result = ByteRangeC::from(1024..4096);
+
+        assert_eq!(
+            ByteRangeC {
+                start: 1024,
+                end: 4096,
+            },
+            result
+        );
+    }
 }
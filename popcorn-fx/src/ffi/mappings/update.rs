@@ -1,5 +1,6 @@
 use popcorn_fx_core::core::updater::{
-    DownloadProgress, InstallationProgress, UpdateEvent, UpdateState,
+    DownloadProgress, InstallationProgress, UpdateError, UpdateEvent, UpdateState,
+    VerificationProgress,
 };
 
 use crate::ffi::VersionInfoC;
@@ -16,6 +17,9 @@ pub type UpdateCallbackC = extern "C" fn(UpdateEventC);
 /// * `StateChanged(state)` - Invoked when the state of the updater has changed
 /// * `UpdateAvailable(version)` - Invoked when a new update is available
 /// * `DownloadProgress(progress)` - Invoked when the update download progresses
+/// * `InstallationProgress(progress)` - Invoked when the update installation progresses
+/// * `Failed(error)` - Invoked when the update process failed
+/// * `VerificationProgress(progress)` - Invoked when the update verification progresses
 #[repr(C)]
 #[derive(Debug, PartialEq)]
 pub enum UpdateEventC {
@@ -23,6 +27,8 @@ pub enum UpdateEventC {
     UpdateAvailable(VersionInfoC),
     DownloadProgress(DownloadProgressC),
     InstallationProgress(InstallationProgressC),
+    Failed(UpdateErrorC),
+    VerificationProgress(VerificationProgressC),
 }
 
 impl From<UpdateEvent> for UpdateEventC {
@@ -40,6 +46,10 @@ impl From<UpdateEvent> for UpdateEventC {
             UpdateEvent::InstallationProgress(progress) => {
                 UpdateEventC::InstallationProgress(InstallationProgressC::from(progress))
             }
+            UpdateEvent::Failed(error) => UpdateEventC::Failed(UpdateErrorC::from(error)),
+            UpdateEvent::VerificationProgress(progress) => {
+                UpdateEventC::VerificationProgress(VerificationProgressC::from(progress))
+            }
         }
     }
 }
@@ -57,6 +67,8 @@ pub enum UpdateStateC {
     Installing = 5,
     InstallationFinished = 6,
     Error = 7,
+    /// Indicates that the downloaded update is being verified.
+    Verifying = 8,
 }
 
 impl From<UpdateState> for UpdateStateC {
@@ -66,6 +78,7 @@ impl From<UpdateState> for UpdateStateC {
             UpdateState::UpdateAvailable => UpdateStateC::UpdateAvailable,
             UpdateState::NoUpdateAvailable => UpdateStateC::NoUpdateAvailable,
             UpdateState::Downloading => UpdateStateC::Downloading,
+            UpdateState::Verifying => UpdateStateC::Verifying,
             UpdateState::DownloadFinished => UpdateStateC::DownloadFinished,
             UpdateState::Installing => UpdateStateC::Installing,
             UpdateState::InstallationFinished => UpdateStateC::InstallationFinished,
@@ -74,6 +87,56 @@ impl From<UpdateState> for UpdateStateC {
     }
 }
 
+/// The C compatible reason of a failed update process.
+#[repr(i32)]
+#[derive(Debug, PartialEq)]
+pub enum UpdateErrorC {
+    /// The update channel or download couldn't be reached, e.g. no network connection.
+    ConnectionFailed = 0,
+    /// The configured update channel is invalid.
+    InvalidUpdateChannel = 1,
+    /// The update channel returned an invalid response.
+    InvalidResponse = 2,
+    /// The version information of the application or update is invalid.
+    InvalidVersion = 3,
+    /// No update is available for the current platform.
+    PlatformUpdateUnavailable = 4,
+    /// The update download failed.
+    DownloadFailed = 5,
+    /// The update couldn't be written to or read from the disk.
+    IO = 6,
+    /// The update couldn't be extracted.
+    ExtractionFailed = 7,
+    /// The update action isn't allowed in the current update state.
+    UpdateNotAvailable = 8,
+    /// The downloaded update doesn't match the expected checksum.
+    ChecksumMismatch = 9,
+    /// No previous version is available to roll back to.
+    RollbackUnavailable = 10,
+}
+
+impl From<UpdateError> for UpdateErrorC {
+    fn from(value: UpdateError) -> Self {
+        match value {
+            UpdateError::ConnectionFailed(_) => UpdateErrorC::ConnectionFailed,
+            UpdateError::InvalidUpdateChannel(_) => UpdateErrorC::InvalidUpdateChannel,
+            UpdateError::Response(_) => UpdateErrorC::InvalidResponse,
+            UpdateError::InvalidApplicationVersion(_, _)
+            | UpdateError::InvalidRuntimeVersion(_, _)
+            | UpdateError::UnknownVersion => UpdateErrorC::InvalidVersion,
+            UpdateError::PlatformUpdateUnavailable => UpdateErrorC::PlatformUpdateUnavailable,
+            UpdateError::InvalidDownloadUrl(_) | UpdateError::DownloadFailed(_, _) => {
+                UpdateErrorC::DownloadFailed
+            }
+            UpdateError::IO(_) | UpdateError::ArchiveLocationAlreadyExists => UpdateErrorC::IO,
+            UpdateError::ExtractionFailed(_) => UpdateErrorC::ExtractionFailed,
+            UpdateError::UpdateNotAvailable(_) => UpdateErrorC::UpdateNotAvailable,
+            UpdateError::ChecksumMismatch(_) => UpdateErrorC::ChecksumMismatch,
+            UpdateError::RollbackUnavailable => UpdateErrorC::RollbackUnavailable,
+        }
+    }
+}
+
 /// The C-compatible representation of the [DownloadProgress] struct.
 ///
 /// This struct is used to provide C code access to the download progress of an update event.
@@ -82,18 +145,27 @@ impl From<UpdateState> for UpdateStateC {
 ///
 /// * `total_size` - The total size of the update download in bytes.
 /// * `downloaded` - The total number of bytes downloaded so far.
+/// * `percentage` - The download progress as a percentage between 0 and 100.
 #[repr(C)]
 #[derive(Debug, PartialEq)]
 pub struct DownloadProgressC {
     pub total_size: u64,
     pub downloaded: u64,
+    pub percentage: f32,
 }
 
 impl From<DownloadProgress> for DownloadProgressC {
     fn from(value: DownloadProgress) -> Self {
+        let percentage = if value.total_size > 0 {
+            (value.downloaded as f64 / value.total_size as f64 * 100.0).min(100.0) as f32
+        } else {
+            0.0
+        };
+
         Self {
             total_size: value.total_size,
             downloaded: value.downloaded,
+            percentage,
         }
     }
 }
@@ -123,6 +195,28 @@ impl From<InstallationProgress> for InstallationProgressC {
     }
 }
 
+/// The C-compatible representation of the [VerificationProgress] struct.
+///
+/// # Fields
+///
+/// * `task` - The current task being verified.
+/// * `total_tasks` - The total number of tasks that need to be verified.
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+pub struct VerificationProgressC {
+    pub task: u16,
+    pub total_tasks: u16,
+}
+
+impl From<VerificationProgress> for VerificationProgressC {
+    fn from(value: VerificationProgress) -> Self {
+        Self {
+            task: value.task,
+            total_tasks: value.total_tasks,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -171,6 +265,10 @@ mod test {
             UpdateStateC::Downloading,
             UpdateStateC::from(UpdateState::Downloading)
         );
+        assert_eq!(
+            UpdateStateC::Verifying,
+            UpdateStateC::from(UpdateState::Verifying)
+        );
         assert_eq!(
             UpdateStateC::DownloadFinished,
             UpdateStateC::from(UpdateState::DownloadFinished)
@@ -196,6 +294,67 @@ mod test {
 
         assert_eq!(progress_c.total_size, 1024);
         assert_eq!(progress_c.downloaded, 512);
+        assert_eq!(progress_c.percentage, 50.0);
+    }
+
+    #[test]
+    fn test_from_download_progress_unknown_size() {
+        let progress = DownloadProgress {
+            total_size: 0,
+            downloaded: 512,
+        };
+
+        let progress_c = DownloadProgressC::from(progress);
+
+        assert_eq!(progress_c.percentage, 0.0);
+    }
+
+    #[test]
+    fn test_from_update_event_failed() {
+        let event = UpdateEvent::Failed(UpdateError::ConnectionFailed("lorem".to_string()));
+
+        let result = UpdateEventC::from(event);
+
+        assert_eq!(UpdateEventC::Failed(UpdateErrorC::ConnectionFailed), result);
+    }
+
+    #[test]
+    fn test_from_update_error() {
+        assert_eq!(
+            UpdateErrorC::ConnectionFailed,
+            UpdateErrorC::from(UpdateError::ConnectionFailed("lorem".to_string()))
+        );
+        assert_eq!(
+            UpdateErrorC::InvalidResponse,
+            UpdateErrorC::from(UpdateError::Response("ipsum".to_string()))
+        );
+        assert_eq!(
+            UpdateErrorC::InvalidVersion,
+            UpdateErrorC::from(UpdateError::UnknownVersion)
+        );
+        assert_eq!(
+            UpdateErrorC::DownloadFailed,
+            UpdateErrorC::from(UpdateError::DownloadFailed(
+                "404".to_string(),
+                "dolor.deb".to_string()
+            ))
+        );
+        assert_eq!(
+            UpdateErrorC::ExtractionFailed,
+            UpdateErrorC::from(UpdateError::ExtractionFailed("sit".to_string()))
+        );
+        assert_eq!(
+            UpdateErrorC::UpdateNotAvailable,
+            UpdateErrorC::from(UpdateError::UpdateNotAvailable(UpdateState::Downloading))
+        );
+        assert_eq!(
+            UpdateErrorC::ChecksumMismatch,
+            UpdateErrorC::from(UpdateError::ChecksumMismatch("amet.deb".to_string()))
+        );
+        assert_eq!(
+            UpdateErrorC::RollbackUnavailable,
+            UpdateErrorC::from(UpdateError::RollbackUnavailable)
+        );
     }
 
     #[test]
@@ -210,4 +369,22 @@ mod test {
         assert_eq!(c_progress.task, progress.task);
         assert_eq!(c_progress.total_tasks, progress.total_tasks);
     }
+
+    #[test]
+    fn test_from_update_event_verification_progress() {
+        let event = UpdateEvent::VerificationProgress(VerificationProgress {
+            task: 1,
+            total_tasks: 2,
+        });
+
+        let result = UpdateEventC::from(event);
+
+        assert_eq!(
+            UpdateEventC::VerificationProgress(VerificationProgressC {
+                task: 1,
+                total_tasks: 2,
+            }),
+            result
+        );
+    }
 }
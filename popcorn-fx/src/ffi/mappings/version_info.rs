@@ -62,10 +62,14 @@ mod test {
             application: PatchInfo {
                 version: version.to_string(),
                 platforms: Default::default(),
+                checksums: Default::default(),
+                deltas: Default::default(),
             },
             runtime: PatchInfo {
                 version: runtime_version.to_string(),
                 platforms: Default::default(),
+                checksums: Default::default(),
+                deltas: Default::default(),
             },
         };
 
@@ -1,14 +1,15 @@
 use std::os::raw::c_char;
 
-use log::{debug, error, info, trace};
+use log::{debug, error, info, trace, warn};
 
-use popcorn_fx_core::{from_c_string, from_c_vec};
+use popcorn_fx_core::{from_c_string, from_c_vec, into_c_owned};
 use popcorn_fx_core::core::media::{
     Category, MediaType, MovieDetails, MovieOverview, ShowDetails, ShowOverview,
 };
 
 use crate::ffi::{
-    GenreC, MediaErrorC, MediaItemC, MediaResult, MediaSetC, MediaSetResult, SortByC,
+    CArray, ContinueWatchingItemC, GenreC, MediaErrorC, MediaItemC, MediaResult, MediaSetC,
+    MediaSetResult, ProviderConnectionResultC, SortByC, StringArray,
 };
 use crate::PopcornFX;
 
@@ -17,7 +18,7 @@ use crate::PopcornFX;
 /// It returns the [VecMovieC] reference on success, else [ptr::null_mut].
 #[no_mangle]
 pub extern "C" fn retrieve_available_movies(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     genre: &GenreC,
     sort_by: &SortByC,
     keywords: *mut c_char,
@@ -66,7 +67,7 @@ pub extern "C" fn retrieve_available_movies(
 /// It returns an array of [ShowOverviewC] items on success, else a [ptr::null_mut].
 #[no_mangle]
 pub extern "C" fn retrieve_available_shows(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     genre: &GenreC,
     sort_by: &SortByC,
     keywords: *mut c_char,
@@ -116,7 +117,7 @@ pub extern "C" fn retrieve_available_shows(
 /// It returns the [MediaItemC] on success, else a [ptr::null_mut].
 #[no_mangle]
 pub extern "C" fn retrieve_media_details(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     media: &MediaItemC,
 ) -> MediaResult {
     trace!("Retrieving media details from C for {:?}", media);
@@ -164,11 +165,271 @@ pub extern "C" fn retrieve_media_details(
 /// Reset all available api stats for the movie api.
 /// This will make all disabled api's available again.
 #[no_mangle]
-pub extern "C" fn reset_movie_apis(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn reset_movie_apis(popcorn_fx: &PopcornFX) {
     trace!("Resetting the movie api providers from C");
     popcorn_fx.providers().reset_api(&Category::Movies)
 }
 
+/// Invalidate the cached media pages of the providers.
+/// The next retrieved pages will be requested from the providers again.
+#[no_mangle]
+pub extern "C" fn invalidate_provider_cache(popcorn_fx: &PopcornFX) {
+    trace!("Invalidating the provider cache from C");
+    popcorn_fx.providers().invalidate_cache()
+}
+
+/// Retrieve the provider uri's of the given category.
+/// The uri overrides of the user take precedence over the bundled provider uri's.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the provider uri's of.
+///
+/// # Returns
+///
+/// It returns the array of uri's, which should be disposed with `dispose_string_array`.
+#[no_mangle]
+pub extern "C" fn retrieve_provider_uris(
+    popcorn_fx: &PopcornFX,
+    category: Category,
+) -> *mut StringArray {
+    trace!("Retrieving provider uris of {} from C", category);
+    into_c_owned(StringArray::from(
+        popcorn_fx.settings().provider_uris(&category),
+    ))
+}
+
+/// Override the provider uri's of the given category.
+///
+/// Each uri is verified with a test request against the provider before the overrides are accepted.
+/// Once accepted, the provider of the category is reset to use the new uri's.
+/// An empty array removes the overrides of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to override the provider uri's of.
+/// * `len` - The number of uri's within the array.
+/// * `uris` - A pointer to an array of null-terminated C strings that contain the provider uri's.
+///
+/// # Returns
+///
+/// It returns `true` when the uri's have been accepted, else `false`.
+#[no_mangle]
+pub extern "C" fn update_provider_uris(
+    popcorn_fx: &PopcornFX,
+    category: Category,
+    len: i32,
+    uris: *mut *mut c_char,
+) -> bool {
+    trace!("Updating {} provider uris of {} from C", len, category);
+    let uris = from_c_vec(uris, len)
+        .into_iter()
+        .map(|e| from_c_string(e))
+        .filter(|e| !e.trim().is_empty())
+        .collect::<Vec<String>>();
+
+    for uri in uris.iter() {
+        if let Err(e) = popcorn_fx
+            .runtime()
+            .block_on(popcorn_fx.providers().verify_uri(&category, uri.as_str()))
+        {
+            warn!(
+                "Provider uri {} of {} has been rejected, {}",
+                uri, category, e
+            );
+            return false;
+        }
+    }
+
+    popcorn_fx.settings().update_provider_uris(&category, uris);
+    popcorn_fx.providers().reset_api(&category);
+    popcorn_fx.providers().invalidate_cache();
+    info!("Provider uris of {} have been updated", category);
+    true
+}
+
+/// Remove the provider uri overrides of the given category.
+/// This restores the bundled provider uri's and resets the provider of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to reset the provider uri's of.
+#[no_mangle]
+pub extern "C" fn reset_provider_uris(popcorn_fx: &PopcornFX, category: Category) {
+    trace!("Resetting the provider uris of {} from C", category);
+    popcorn_fx.settings().reset_provider_uris(&category);
+    popcorn_fx.providers().reset_api(&category);
+    popcorn_fx.providers().invalidate_cache();
+}
+
+/// Test the connection of each configured provider uri of the given category.
+///
+/// This is a diagnostic call which measures the latency of each uri with a short timeout,
+/// without affecting the disabled uri's or failure statistics of the provider.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to test the provider uri's of.
+///
+/// # Returns
+///
+/// A CArray of ProviderConnectionResultC, which should be disposed with `dispose_provider_connection_results`.
+#[no_mangle]
+pub extern "C" fn test_provider_connection(
+    popcorn_fx: &PopcornFX,
+    category: Category,
+) -> CArray<ProviderConnectionResultC> {
+    trace!("Testing the provider connection of {} from C", category);
+    let results: Vec<ProviderConnectionResultC> = popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.providers().test_connection(&category))
+        .into_iter()
+        .map(ProviderConnectionResultC::from)
+        .collect();
+
+    debug!(
+        "Tested the connection of {} provider uris for {}",
+        results.len(),
+        category
+    );
+    CArray::from(results)
+}
+
+/// Dispose of a C-style array of provider connection results.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ProviderConnectionResultC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_provider_connection_results(set: Box<CArray<ProviderConnectionResultC>>) {
+    trace!("Disposing provider connection results {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
+/// Verify if the given category is supported by one of the configured media providers.
+/// Categories which aren't supported should be hidden from the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to verify.
+///
+/// # Returns
+///
+/// It returns `true` when a provider is available for the category, else `false`.
+#[no_mangle]
+pub extern "C" fn is_category_supported(popcorn_fx: &PopcornFX, category: Category) -> bool {
+    trace!("Verifying if category {} is supported from C", category);
+    popcorn_fx.providers().is_supported(&category)
+}
+
+/// Retrieve the available genres of the given category.
+///
+/// The genres advertised by the media provider are merged with the configured genres of the category,
+/// which allows new genres of a provider to be shown without a config update.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the genres of.
+///
+/// # Returns
+///
+/// It returns the array of genre keys, which should be disposed with `dispose_string_array`.
+#[no_mangle]
+pub extern "C" fn retrieve_available_genres(
+    popcorn_fx: &PopcornFX,
+    category: Category,
+) -> *mut StringArray {
+    trace!("Retrieving available genres of {} from C", category);
+    let genres = popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.providers().available_genres(&category));
+
+    debug!("Retrieved available genres {:?} for {}", genres, category);
+    into_c_owned(StringArray::from(genres))
+}
+
+/// Retrieve the available sort options of the given category.
+///
+/// The sort options advertised by the media provider are merged with the configured sort options of the category.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `category` - The category to retrieve the sort options of.
+///
+/// # Returns
+///
+/// It returns the array of sort keys, which should be disposed with `dispose_string_array`.
+#[no_mangle]
+pub extern "C" fn retrieve_available_sort_options(
+    popcorn_fx: &PopcornFX,
+    category: Category,
+) -> *mut StringArray {
+    trace!("Retrieving available sort options of {} from C", category);
+    let sort_options = popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.providers().available_sort_options(&category));
+
+    debug!(
+        "Retrieved available sort options {:?} for {}",
+        sort_options, category
+    );
+    into_c_owned(StringArray::from(sort_options))
+}
+
+/// Retrieve the media items which can be continued watching.
+///
+/// The items are ordered by the most recently played first, and at most `limit` items are returned.
+/// When an episode has been finished, the next episode of the show is returned instead.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `limit` - The maximum number of items to retrieve.
+///
+/// # Returns
+///
+/// A CArray of ContinueWatchingItemC representing the continue watching items.
+#[no_mangle]
+pub extern "C" fn continue_watching(
+    popcorn_fx: &PopcornFX,
+    limit: u32,
+) -> CArray<ContinueWatchingItemC> {
+    trace!(
+        "Retrieving continue watching items from C with limit {}",
+        limit
+    );
+    let items: Vec<ContinueWatchingItemC> = popcorn_fx
+        .auto_resume_service()
+        .continue_watching(limit as usize)
+        .into_iter()
+        .map(ContinueWatchingItemC::from)
+        .collect();
+
+    info!(
+        "Retrieved a total of {} continue watching items",
+        items.len()
+    );
+    CArray::from(items)
+}
+
+/// Dispose of a C-style array of continue watching items.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ContinueWatchingItemC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_continue_watching_set(set: Box<CArray<ContinueWatchingItemC>>) {
+    trace!("Disposing continue watching set {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
 /// Dispose of a C-compatible media set.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible media set.
@@ -200,9 +461,9 @@ mod test {
     use httpmock::MockServer;
     use tempfile::tempdir;
 
-    use popcorn_fx_core::core::config::ProviderProperties;
+    use popcorn_fx_core::core::config::{ProviderProperties, ProviderType};
     use popcorn_fx_core::core::media::{Genre, SortBy};
-    use popcorn_fx_core::into_c_string;
+    use popcorn_fx_core::{from_c_owned, into_c_string, into_c_vec};
     use popcorn_fx_core::testing::{init_logger, read_test_file_to_bytes};
 
     use crate::test::default_args;
@@ -216,10 +477,10 @@ mod test {
         let temp_path = temp_dir.path().to_str().unwrap();
         let genre = GenreC::from(Genre::all());
         let sort_by = SortByC::from(SortBy::new(String::from("trending"), String::new()));
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let result = retrieve_available_movies(
-            &mut instance,
+            &instance,
             &genre,
             &sort_by,
             into_c_string("".to_string()),
@@ -241,10 +502,10 @@ mod test {
         let sort_by = SortByC::from(SortBy::new(String::from("trending"), String::new()));
         let mut popcorn_fx_args = default_args(temp_path);
         popcorn_fx_args.properties.providers = HashMap::new();
-        let mut instance = PopcornFX::new(popcorn_fx_args);
+        let instance = PopcornFX::new(popcorn_fx_args);
 
         let result = retrieve_available_movies(
-            &mut instance,
+            &instance,
             &genre,
             &sort_by,
             into_c_string("".to_string()),
@@ -262,9 +523,184 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        reset_movie_apis(&instance);
+    }
+
+    #[test]
+    fn test_invalidate_provider_cache() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        invalidate_provider_cache(&instance);
+    }
+
+    #[test]
+    fn test_update_provider_uris() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("[]");
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+        let (uris, len) = into_c_vec(vec![into_c_string(server.url(""))]);
+
+        let result = update_provider_uris(&instance, Category::Movies, len, uris);
+        assert_eq!(
+            true, result,
+            "expected the provider uris to have been accepted"
+        );
+
+        let array = from_c_owned(retrieve_provider_uris(&instance, Category::Movies));
+        let result: Vec<String> = from_c_vec(array.values, array.len)
+            .into_iter()
+            .map(|e| from_c_string(e))
+            .collect();
+        assert_eq!(vec![server.url("")], result);
+
+        reset_provider_uris(&instance, Category::Movies);
+        assert_eq!(
+            instance
+                .settings()
+                .properties()
+                .provider("movies")
+                .unwrap()
+                .uris()
+                .to_vec(),
+            instance.settings().provider_uris(&Category::Movies)
+        );
+    }
+
+    #[test]
+    fn test_update_provider_uris_rejected() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(404);
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+        let expected_result = instance.settings().provider_uris(&Category::Movies);
+        let (uris, len) = into_c_vec(vec![into_c_string(server.url(""))]);
 
-        reset_movie_apis(&mut instance);
+        let result = update_provider_uris(&instance, Category::Movies, len, uris);
+
+        assert_eq!(
+            false, result,
+            "expected the provider uris to have been rejected"
+        );
+        assert_eq!(
+            expected_result,
+            instance.settings().provider_uris(&Category::Movies)
+        );
+    }
+
+    #[test]
+    fn test_test_provider_connection() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/movies/1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("[]");
+        });
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance
+            .settings()
+            .update_provider_uris(&Category::Movies, vec![server.url("")]);
+        instance.providers().reset_api(&Category::Movies);
+
+        let set = test_provider_connection(&instance, Category::Movies);
+        let result = from_c_vec(set.items, set.len);
+
+        assert_eq!(1, result.len());
+        assert_eq!(server.url(""), from_c_string(result[0].uri));
+        assert!(
+            result[0].error.is_null(),
+            "expected the connection to succeed"
+        );
+    }
+
+    #[test]
+    fn test_is_category_supported() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut popcorn_fx_args = default_args(temp_path);
+        popcorn_fx_args.properties.providers = vec![(
+            "catalog".to_string(),
+            ProviderProperties {
+                provider_type: ProviderType::Catalog,
+                uris: vec!["http://localhost/catalog.json".to_string()],
+                genres: vec![],
+                sort_by: vec![],
+                categories: vec![Category::Movies],
+            },
+        )]
+        .into_iter()
+        .collect();
+        let instance = PopcornFX::new(popcorn_fx_args);
+
+        assert_eq!(true, is_category_supported(&instance, Category::Movies));
+        assert_eq!(false, is_category_supported(&instance, Category::Series));
+        assert_eq!(true, is_category_supported(&instance, Category::Favorites));
+    }
+
+    #[test]
+    fn test_retrieve_available_genres() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let array = from_c_owned(retrieve_available_genres(&instance, Category::Favorites));
+        let result: Vec<String> = from_c_vec(array.values, array.len)
+            .into_iter()
+            .map(|e| from_c_string(e))
+            .collect();
+
+        assert_eq!(Some(&"all".to_string()), result.get(0));
+        assert!(
+            result.contains(&"movies".to_string()),
+            "expected the genres to contain movies, but got {:?} instead",
+            result
+        );
+    }
+
+    #[test]
+    fn test_retrieve_available_sort_options() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let array = from_c_owned(retrieve_available_sort_options(
+            &instance,
+            Category::Favorites,
+        ));
+        let result: Vec<String> = from_c_vec(array.values, array.len)
+            .into_iter()
+            .map(|e| from_c_string(e))
+            .collect();
+
+        assert!(
+            result.contains(&"watched".to_string()),
+            "expected the sort options to contain watched, but got {:?} instead",
+            result
+        );
     }
 
     #[test]
@@ -274,10 +710,10 @@ mod test {
         let temp_path = temp_dir.path().to_str().unwrap();
         let genre = GenreC::from(Genre::all());
         let sort_by = SortByC::from(SortBy::new(String::from("trending"), String::new()));
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let result = retrieve_available_shows(
-            &mut instance,
+            &instance,
             &genre,
             &sort_by,
             into_c_string("".to_string()),
@@ -299,10 +735,10 @@ mod test {
         let sort_by = SortByC::from(SortBy::new(String::from("trending"), String::new()));
         let mut popcorn_fx_args = default_args(temp_path);
         popcorn_fx_args.properties.providers = HashMap::new();
-        let mut instance = PopcornFX::new(popcorn_fx_args);
+        let instance = PopcornFX::new(popcorn_fx_args);
 
         let result = retrieve_available_shows(
-            &mut instance,
+            &instance,
             &genre,
             &sort_by,
             into_c_string("".to_string()),
@@ -341,16 +777,18 @@ mod test {
         popcorn_fx_args.properties.providers = vec![(
             "series".to_string(),
             ProviderProperties {
+                provider_type: ProviderType::Popcorn,
                 uris: vec![server.url("/")],
                 genres: vec![],
                 sort_by: vec![],
+                categories: vec![],
             },
         )]
         .into_iter()
         .collect();
-        let mut instance = PopcornFX::new(popcorn_fx_args);
+        let instance = PopcornFX::new(popcorn_fx_args);
 
-        let media_result = retrieve_media_details(&mut instance, &MediaItemC::from(show));
+        let media_result = retrieve_media_details(&instance, &MediaItemC::from(show));
 
         match media_result {
             MediaResult::Ok(e) => {
@@ -385,9 +823,9 @@ mod test {
         };
         let mut popcorn_fx_args = default_args(temp_path);
         popcorn_fx_args.properties.providers = HashMap::new();
-        let mut instance = PopcornFX::new(popcorn_fx_args);
+        let instance = PopcornFX::new(popcorn_fx_args);
 
-        let media_result = retrieve_media_details(&mut instance, &MediaItemC::from(show));
+        let media_result = retrieve_media_details(&instance, &MediaItemC::from(show));
 
         if let MediaResult::Err(e) = media_result {
             assert_eq!(MediaErrorC::NoAvailableProviders, e)
@@ -405,16 +843,29 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let genre = GenreC::from(Genre::all());
         let sort_by = SortByC::from(SortBy::new("trending".to_string(), String::new()));
         let keywords = into_c_string(String::new());
 
-        let result = retrieve_available_shows(&mut instance, &genre, &sort_by, keywords, 1);
+        let result = retrieve_available_shows(&instance, &genre, &sort_by, keywords, 1);
 
         match result {
             MediaSetResult::Ok(items) => dispose_media_items(items),
             _ => panic!("Expected MediaSetResult::Ok"),
         }
     }
+
+    #[test]
+    fn test_continue_watching() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = continue_watching(&instance, 10);
+
+        assert_eq!(0, result.len);
+        dispose_continue_watching_set(Box::new(result));
+    }
 }
@@ -9,10 +9,12 @@ pub use main::*;
 pub use mappings::*;
 pub use media::*;
 pub use options::*;
+pub use platform::*;
 pub use players::*;
 pub use playlists::*;
 pub use properties::*;
 pub use screen::*;
+pub use storage::*;
 pub use subtitles::*;
 pub use torrent_collection_c::*;
 pub use torrents::*;
@@ -30,10 +32,12 @@ mod main;
 mod mappings;
 mod media;
 mod options;
+mod platform;
 mod players;
 mod playlists;
 mod properties;
 mod screen;
+mod storage;
 mod subtitles;
 mod torrent_collection_c;
 mod torrents;
@@ -2,7 +2,7 @@ use crate::PopcornFX;
 
 /// Verify if the FX embedded video player has been disabled.
 #[no_mangle]
-pub extern "C" fn is_fx_video_player_enabled(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_fx_video_player_enabled(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().enable_fx_video_player
 }
 
@@ -10,26 +10,26 @@ pub extern "C" fn is_fx_video_player_enabled(popcorn_fx: &mut PopcornFX) -> bool
 /// The disabling of the mouse should be implemented by the UI implementation and has no behavior on
 /// the backend itself.
 #[no_mangle]
-pub extern "C" fn is_mouse_disabled(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_mouse_disabled(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().disable_mouse
 }
 
 /// Verify if the TV mode is activated for the application.
 #[no_mangle]
-pub extern "C" fn is_tv_mode(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_tv_mode(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().tv
 }
 
 /// Verify if the application should be maximized on startup.
 #[no_mangle]
-pub extern "C" fn is_maximized(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_maximized(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().maximized
 }
 
 /// Verify if the application should started in kiosk mode.
 /// The behavior of kiosk mode is dependant on the UI implementation and not delegated by the backend.
 #[no_mangle]
-pub extern "C" fn is_kiosk_mode(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_kiosk_mode(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().kiosk
 }
 
@@ -37,13 +37,13 @@ pub extern "C" fn is_kiosk_mode(popcorn_fx: &mut PopcornFX) -> bool {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// `true` if the YouTube video player is enabled, otherwise `false`.
 #[no_mangle]
-pub extern "C" fn is_youtube_video_player_enabled(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_youtube_video_player_enabled(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().enable_youtube_video_player
 }
 
@@ -51,13 +51,13 @@ pub extern "C" fn is_youtube_video_player_enabled(popcorn_fx: &mut PopcornFX) ->
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// `true` if the VLC video player is enabled, otherwise `false`.
 #[no_mangle]
-pub extern "C" fn is_vlc_video_player_enabled(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_vlc_video_player_enabled(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.opts().enable_vlc_video_player
 }
 
@@ -76,8 +76,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: true,
             enable_fx_video_player: false,
@@ -86,12 +87,14 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_youtube_video_player_enabled(&mut instance);
+        let result = is_youtube_video_player_enabled(&instance);
 
         assert_eq!(true, result)
     }
@@ -101,8 +104,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: true,
@@ -111,12 +115,14 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_fx_video_player_enabled(&mut instance);
+        let result = is_fx_video_player_enabled(&instance);
 
         assert_eq!(true, result, "expected FX video player to be enabled");
     }
@@ -126,8 +132,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -136,12 +143,14 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_vlc_video_player_enabled(&mut instance);
+        let result = is_vlc_video_player_enabled(&instance);
 
         assert_eq!(true, result)
     }
@@ -151,8 +160,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: true,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -161,12 +171,14 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_mouse_disabled(&mut instance);
+        let result = is_mouse_disabled(&instance);
 
         assert_eq!(true, result)
     }
@@ -176,8 +188,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -186,12 +199,14 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_tv_mode(&mut instance);
+        let result = is_tv_mode(&instance);
 
         assert_eq!(true, result)
     }
@@ -201,8 +216,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -211,12 +227,14 @@ mod test {
             maximized: true,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_maximized(&mut instance);
+        let result = is_maximized(&instance);
 
         assert_eq!(true, result)
     }
@@ -226,8 +244,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(PopcornFxArgs {
+        let instance = PopcornFX::new(PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -236,12 +255,14 @@ mod test {
             maximized: true,
             kiosk: true,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: temp_dir.path().join("data").to_str().unwrap().to_string(),
             properties: Default::default(),
         });
 
-        let result = is_kiosk_mode(&mut instance);
+        let result = is_kiosk_mode(&instance);
 
         assert_eq!(true, result)
     }
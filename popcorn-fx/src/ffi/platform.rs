@@ -0,0 +1,84 @@
+use log::trace;
+
+use popcorn_fx_core::core::utils::fs::available_space;
+use popcorn_fx_core::{from_c_owned, into_c_owned};
+
+use crate::ffi::PlatformInfoC;
+use crate::PopcornFX;
+
+/// Retrieve the platform information of the current system, which is used for diagnostic purposes.
+///
+/// The information, such as the available memory and free disk space of the application data and torrent directories,
+/// is gathered at the moment of the call.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+///
+/// # Returns
+///
+/// It returns the platform info, which should be disposed with `dispose_platform_info`.
+#[no_mangle]
+pub extern "C" fn retrieve_platform_info(popcorn_fx: &PopcornFX) -> *mut PlatformInfoC {
+    trace!("Retrieving platform info from C");
+    let torrent_directory = popcorn_fx
+        .settings()
+        .user_settings()
+        .torrent()
+        .directory()
+        .clone();
+
+    into_c_owned(PlatformInfoC::from(
+        popcorn_fx.platform().info(),
+        available_space(popcorn_fx.opts().data_directory.as_str()),
+        available_space(torrent_directory),
+        popcorn_fx.crash_reporter().crash_count(),
+    ))
+}
+
+/// Dispose of the given platform info.
+///
+/// # Arguments
+///
+/// * `info` - A boxed `PlatformInfoC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_platform_info(info: Box<PlatformInfoC>) {
+    trace!("Disposing platform info {:?}", info);
+    if !info.data_free_space.is_null() {
+        drop(from_c_owned(info.data_free_space));
+    }
+    if !info.torrent_free_space.is_null() {
+        drop(from_c_owned(info.torrent_free_space));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempfile::tempdir;
+
+    use popcorn_fx_core::testing::init_logger;
+
+    use crate::test::default_args;
+
+    use super::*;
+
+    #[test]
+    fn test_retrieve_platform_info() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = retrieve_platform_info(&instance);
+
+        assert!(
+            !result.is_null(),
+            "expected the platform info to be returned"
+        );
+        let info = from_c_owned(result);
+        assert!(info.cpu_cores > 0, "expected at least one cpu core");
+        assert!(info.total_memory >= info.available_memory);
+
+        dispose_platform_info(Box::new(info));
+    }
+}
@@ -1,14 +1,17 @@
 use std::os::raw::c_char;
+use std::path::Path;
 use std::ptr;
 
 use log::{debug, error, info, trace, warn};
 
-use popcorn_fx_core::{from_c_string, into_c_owned};
+use popcorn_fx_core::{from_c_owned, from_c_string, into_c_owned, into_c_string};
 use popcorn_fx_core::core::players::{Player, PlayerEvent};
+use popcorn_fx_core::core::subtitles::matcher::SubtitleMatcher;
+use popcorn_fx_core::core::subtitles::model::{Subtitle, SubtitleInfo};
 
 use crate::ffi::{
-    PlayerC, PlayerEventC, PlayerManagerEventC, PlayerManagerEventCallback, PlayerRegistrationC,
-    PlayerSet, PlayerWrapper, PlayerWrapperC,
+    MediaTrackSet, PlayerC, PlayerEventC, PlayerManagerEventC, PlayerManagerEventCallback,
+    PlayerRegistrationC, PlayerSet, PlayerWrapper, PlayerWrapperC, SubtitleC, SubtitleInfoC,
 };
 use crate::PopcornFX;
 
@@ -21,13 +24,13 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerC` instance representing the active player, or a null pointer if there is no active player.
 #[no_mangle]
-pub extern "C" fn active_player(popcorn_fx: &mut PopcornFX) -> *mut PlayerC {
+pub extern "C" fn active_player(popcorn_fx: &PopcornFX) -> *mut PlayerC {
     trace!("Retrieving C active player");
     match popcorn_fx.player_manager().active_player() {
         None => ptr::null_mut(),
@@ -48,10 +51,10 @@ pub extern "C" fn active_player(popcorn_fx: &mut PopcornFX) -> *mut PlayerC {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 #[no_mangle]
-pub extern "C" fn set_active_player(popcorn_fx: &mut PopcornFX, player_id: *mut c_char) {
+pub extern "C" fn set_active_player(popcorn_fx: &PopcornFX, player_id: *mut c_char) {
     let player_id = from_c_string(player_id);
     trace!("Updating active player from C to {}", player_id);
 
@@ -60,6 +63,212 @@ pub extern "C" fn set_active_player(popcorn_fx: &mut PopcornFX, player_id: *mut
         .set_active_player(player_id.as_str());
 }
 
+/// Retrieve the id of the default player which is used when no player has been selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a null-terminated C string representing the default player id,
+/// or a null pointer if no default player has been set.
+#[no_mangle]
+pub extern "C" fn default_player(popcorn_fx: &PopcornFX) -> *mut c_char {
+    trace!("Retrieving C default player");
+    popcorn_fx
+        .settings()
+        .user_settings()
+        .playback_settings
+        .default_player
+        .map(|e| into_c_string(e))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Set the default player which is used when no player has been selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID),
+///   or a null pointer to clear the default player.
+#[no_mangle]
+pub extern "C" fn set_default_player(popcorn_fx: &PopcornFX, player_id: *mut c_char) {
+    let player_id = if !player_id.is_null() {
+        Some(from_c_string(player_id))
+    } else {
+        None
+    };
+    trace!("Updating default player from C to {:?}", player_id);
+
+    let mut settings = popcorn_fx.settings().user_settings().playback_settings;
+    settings.default_player = player_id;
+    popcorn_fx.settings().update_playback(settings);
+}
+
+/// Retrieve the audio tracks of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a `MediaTrackSet` containing the audio tracks,
+/// or a null pointer if there is no active player or the active player is unable to enumerate the tracks.
+#[no_mangle]
+pub extern "C" fn active_player_audio_tracks(popcorn_fx: &PopcornFX) -> *mut MediaTrackSet {
+    trace!("Retrieving C active player audio tracks");
+    popcorn_fx
+        .player_manager()
+        .active_player()
+        .and_then(|e| e.upgrade())
+        .and_then(|e| e.audio_tracks())
+        .map(|e| into_c_owned(MediaTrackSet::from(e)))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Select the audio track of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `track_id` - The unique identifier of the audio track to select.
+#[no_mangle]
+pub extern "C" fn active_player_set_audio_track(popcorn_fx: &PopcornFX, track_id: i32) {
+    trace!("Updating C active player audio track to {}", track_id);
+    match popcorn_fx
+        .player_manager()
+        .active_player()
+        .and_then(|e| e.upgrade())
+    {
+        Some(player) => player.set_audio_track(track_id),
+        None => warn!("Unable to update audio track, no active player"),
+    }
+}
+
+/// Retrieve the embedded subtitle tracks of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// Returns a pointer to a `MediaTrackSet` containing the embedded subtitle tracks,
+/// or a null pointer if there is no active player or the active player is unable to enumerate the tracks.
+#[no_mangle]
+pub extern "C" fn active_player_embedded_subtitle_tracks(
+    popcorn_fx: &PopcornFX,
+) -> *mut MediaTrackSet {
+    trace!("Retrieving C active player embedded subtitle tracks");
+    popcorn_fx
+        .player_manager()
+        .active_player()
+        .and_then(|e| e.upgrade())
+        .and_then(|e| e.embedded_subtitle_tracks())
+        .map(|e| into_c_owned(MediaTrackSet::from(e)))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Select the embedded subtitle track of the media which is being played by the active player.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `track_id` - The unique identifier of the embedded subtitle track to select.
+#[no_mangle]
+pub extern "C" fn active_player_set_embedded_subtitle_track(popcorn_fx: &PopcornFX, track_id: i32) {
+    trace!(
+        "Updating C active player embedded subtitle track to {}",
+        track_id
+    );
+    match popcorn_fx
+        .player_manager()
+        .active_player()
+        .and_then(|e| e.upgrade())
+    {
+        Some(player) => player.set_embedded_subtitle_track(track_id),
+        None => warn!("Unable to update embedded subtitle track, no active player"),
+    }
+}
+
+/// Set the active subtitle of the given player during playback.
+///
+/// The subtitle is downloaded and parsed based on the current play request of the player, after which the player loads it.
+/// The "disabled" subtitle removes the subtitle track from the player.
+/// The player confirms the change through a `SubtitleChanged` player event.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
+/// * `subtitle` - A reference to the subtitle info which should become active.
+#[no_mangle]
+pub extern "C" fn set_active_subtitle(
+    popcorn_fx: &PopcornFX,
+    player_id: *mut c_char,
+    subtitle: &SubtitleInfoC,
+) {
+    let player_id = from_c_string(player_id);
+    let subtitle_info = SubtitleInfo::from(subtitle);
+    trace!(
+        "Updating active subtitle of player {} from C to {:?}",
+        player_id,
+        subtitle_info
+    );
+    let player = match popcorn_fx
+        .player_manager()
+        .by_id(player_id.as_str())
+        .and_then(|e| e.upgrade())
+    {
+        Some(e) => e,
+        None => {
+            warn!(
+                "Unable to update active subtitle, player {} not found",
+                player_id
+            );
+            return;
+        }
+    };
+
+    if subtitle_info.is_none() {
+        debug!("Disabling subtitle of player {}", player_id);
+        popcorn_fx.subtitle_manager().disable_subtitle();
+        player.update_subtitle(None);
+        return;
+    }
+
+    popcorn_fx
+        .subtitle_manager()
+        .update_subtitle(subtitle_info.clone());
+
+    let (filename, quality) = player
+        .request()
+        .and_then(|e| e.upgrade())
+        .map(|request| {
+            let filename = Path::new(request.url())
+                .file_name()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_string());
+            (filename, request.quality())
+        })
+        .unwrap_or_default();
+    let matcher = SubtitleMatcher::from_string(filename, quality);
+
+    match popcorn_fx.runtime().block_on(
+        popcorn_fx
+            .subtitle_provider()
+            .download_and_parse(&subtitle_info, &matcher),
+    ) {
+        Ok(subtitle) => player.update_subtitle(Some(subtitle)),
+        Err(e) => error!(
+            "Failed to update active subtitle of player {}, {}",
+            player_id, e
+        ),
+    }
+}
+
 /// Retrieve a pointer to a `PlayerSet` containing information about all players managed by PopcornFX.
 ///
 /// # Safety
@@ -69,13 +278,13 @@ pub extern "C" fn set_active_player(popcorn_fx: &mut PopcornFX, player_id: *mut
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerSet` containing information about all players managed by PopcornFX.
 #[no_mangle]
-pub extern "C" fn players(popcorn_fx: &mut PopcornFX) -> *mut PlayerSet {
+pub extern "C" fn players(popcorn_fx: &PopcornFX) -> *mut PlayerSet {
     trace!("Retrieving players from C");
     let players = popcorn_fx
         .player_manager()
@@ -98,14 +307,14 @@ pub extern "C" fn players(popcorn_fx: &mut PopcornFX) -> *mut PlayerSet {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Returns
 ///
 /// Returns a pointer to a `PlayerC` instance representing the player if found, or a null pointer if no player with the given ID exists.
 #[no_mangle]
-pub extern "C" fn player_by_id(popcorn_fx: &mut PopcornFX, player_id: *mut c_char) -> *mut PlayerC {
+pub extern "C" fn player_by_id(popcorn_fx: &PopcornFX, player_id: *mut c_char) -> *mut PlayerC {
     let player_id = from_c_string(player_id);
     trace!("Retrieving C player by id {}", player_id);
 
@@ -127,7 +336,7 @@ pub extern "C" fn player_by_id(popcorn_fx: &mut PopcornFX, player_id: *mut c_cha
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Returns
@@ -135,7 +344,7 @@ pub extern "C" fn player_by_id(popcorn_fx: &mut PopcornFX, player_id: *mut c_cha
 /// Returns a pointer to a `PlayerWrapperC` instance representing the player if found, or a null pointer if no player with the given ID exists.
 #[no_mangle]
 pub extern "C" fn player_pointer_by_id(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     player_id: *mut c_char,
 ) -> *mut PlayerWrapperC {
     let player_id = from_c_string(player_id);
@@ -157,11 +366,11 @@ pub extern "C" fn player_pointer_by_id(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A C-compatible callback function that will be invoked when player manager events occur.
 #[no_mangle]
 pub extern "C" fn register_player_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: PlayerManagerEventCallback,
 ) {
     trace!("Registering new player manager callback");
@@ -181,7 +390,7 @@ pub extern "C" fn register_player_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player` - A `PlayerRegistrationC` instance to be registered with the player manager.
 ///
 /// # Notes
@@ -189,7 +398,7 @@ pub extern "C" fn register_player_callback(
 /// This function registers a player with the PopcornFX player manager using the provided `PlayerC` instance.
 /// It logs an info message if the registration is successful and a warning message if registration fails.
 #[no_mangle]
-pub extern "C" fn register_player(popcorn_fx: &mut PopcornFX, player: PlayerRegistrationC) {
+pub extern "C" fn register_player(popcorn_fx: &PopcornFX, player: PlayerRegistrationC) {
     trace!("Registering new C player {:?}", player);
     let player = PlayerWrapper::from(player);
     let id = player.id().to_string();
@@ -210,7 +419,7 @@ pub extern "C" fn register_player(popcorn_fx: &mut PopcornFX, player: PlayerRegi
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `player_id` - A pointer to a null-terminated C string representing the player's unique identifier (ID).
 ///
 /// # Notes
@@ -218,13 +427,31 @@ pub extern "C" fn register_player(popcorn_fx: &mut PopcornFX, player: PlayerRegi
 /// This function removes a player with the specified ID from the PopcornFX player manager.
 /// It converts the `player_id` C string to a Rust String and logs a trace message to indicate the removal.
 #[no_mangle]
-pub extern "C" fn remove_player(popcorn_fx: &mut PopcornFX, player_id: *mut c_char) {
+pub extern "C" fn remove_player(popcorn_fx: &PopcornFX, player_id: *mut c_char) {
     let id = from_c_string(player_id);
 
     trace!("Removing C player ID {}", id);
     popcorn_fx.player_manager().remove_player(id.as_str());
 }
 
+/// Forget a previously discovered external player device.
+///
+/// The device is removed from the known devices cache and its player is removed from the player manager.
+/// The device will be registered again when it's discovered on the network.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `device_id` - A pointer to a null-terminated C string representing the device's unique identifier (ID).
+#[no_mangle]
+pub extern "C" fn forget_device(popcorn_fx: &PopcornFX, device_id: *mut c_char) {
+    let id = from_c_string(device_id);
+
+    trace!("Forgetting C device ID {}", id);
+    popcorn_fx.known_devices().forget(id.as_str());
+    popcorn_fx.player_manager().remove_player(id.as_str());
+}
+
 /// Invoke a player event on a wrapped player instance.
 ///
 /// # Safety
@@ -331,6 +558,50 @@ pub extern "C" fn player_seek(player: &mut PlayerWrapperC, time: u64) {
     }
 }
 
+/// Updates the mute state of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` pointer.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `muted` - `true` to mute the player, else `false`.
+#[no_mangle]
+pub extern "C" fn player_set_muted(player: &mut PlayerWrapperC, muted: bool) {
+    trace!("Updating player muted from C {:?}", player);
+    if let Some(player) = player.instance() {
+        trace!("Updating player muted for {} to {}", player, muted);
+        player.set_muted(muted);
+    } else {
+        warn!("Unable to update player muted from C, player instance has been disposed");
+    }
+}
+
+/// Updates the volume of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` pointer.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `volume` - The new volume level, between 0 and 100.
+#[no_mangle]
+pub extern "C" fn player_set_volume(player: &mut PlayerWrapperC, volume: u32) {
+    trace!("Updating player volume from C {:?}", player);
+    if let Some(player) = player.instance() {
+        trace!("Updating player volume for {} to {}", player, volume);
+        player.set_volume(volume);
+    } else {
+        warn!("Unable to update player volume from C, player instance has been disposed");
+    }
+}
+
 /// Stops the player associated with the given `PlayerWrapperC` instance.
 ///
 /// # Safety
@@ -352,6 +623,39 @@ pub extern "C" fn player_stop(player: &mut PlayerWrapperC) {
     }
 }
 
+/// Updates the subtitle of the player associated with the given `PlayerWrapperC` instance.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `player` and `subtitle` pointers.
+/// The ownership of the `subtitle` is transferred to the player.
+///
+/// # Arguments
+///
+/// * `player` - A mutable reference to a `PlayerWrapperC` instance.
+/// * `subtitle` - A pointer to the new subtitle, or a null pointer to disable the subtitle.
+#[no_mangle]
+pub extern "C" fn player_update_subtitle(player: &mut PlayerWrapperC, subtitle: *mut SubtitleC) {
+    trace!("Updating player subtitle from C {:?}", player);
+    let subtitle = if !subtitle.is_null() {
+        Some(Subtitle::from(from_c_owned(subtitle)))
+    } else {
+        None
+    };
+
+    if let Some(player) = player.instance() {
+        trace!(
+            "Updating player subtitle for {} with {:?}",
+            player,
+            subtitle
+        );
+        player.update_subtitle(subtitle);
+    } else {
+        warn!("Unable to update player subtitle from C, player instance has been disposed");
+    }
+}
+
 /// Dispose of a C-compatible player manager event.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible player manager event.
@@ -413,6 +717,22 @@ pub extern "C" fn dispose_player(player: Box<PlayerC>) {
     drop(player);
 }
 
+/// Disposes of the `MediaTrackSet` instance and deallocates its memory.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it interacts with external code (C/C++),
+/// and the caller is responsible for ensuring the safety of the provided `set` pointer.
+///
+/// # Arguments
+///
+/// * `set` - A box containing the `MediaTrackSet` instance to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_media_track_set(set: Box<MediaTrackSet>) {
+    trace!("Disposing media track set {:?}", set);
+    drop(set);
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -421,9 +741,11 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use popcorn_fx_core::{from_c_owned, from_c_vec, into_c_string, into_c_vec};
+    use popcorn_fx_core::{from_c_vec, into_c_string, into_c_vec};
     use popcorn_fx_core::core::Callbacks;
-    use popcorn_fx_core::core::players::{PlayerManagerEvent, PlayerState};
+    use popcorn_fx_core::core::players::{
+        KnownDevice, KnownDeviceType, MediaTrack, PlayerManagerEvent, PlayerState,
+    };
     use popcorn_fx_core::testing::{init_logger, MockPlayer};
 
     use crate::ffi::PlayRequestC;
@@ -456,13 +778,18 @@ mod tests {
         // no-op
     }
 
+    #[no_mangle]
+    extern "C" fn audio_track_registration_callback(_: i32) {
+        // no-op
+    }
+
     #[test]
     fn test_active_player() {
         init_logger();
         let player_id = "Lorem";
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let player = PlayerWrapper::from(PlayerRegistrationC {
             id: into_c_string(player_id.to_string()),
             name: into_c_string("FooBar".to_string()),
@@ -476,15 +803,93 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         });
 
         instance.player_manager().add_player(Box::new(player));
-        set_active_player(&mut instance, into_c_string(player_id.to_string()));
-        let result = from_c_owned(active_player(&mut instance));
+        set_active_player(&instance, into_c_string(player_id.to_string()));
+        let result = from_c_owned(active_player(&instance));
 
         assert_eq!(player_id.to_string(), from_c_string(result.id));
     }
 
+    #[test]
+    fn test_set_default_player() {
+        init_logger();
+        let player_id = "MyDefaultPlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        set_default_player(&instance, into_c_string(player_id.to_string()));
+        let result = default_player(&instance);
+        assert_eq!(player_id.to_string(), from_c_string(result));
+
+        set_default_player(&instance, ptr::null_mut());
+        let result = default_player(&instance);
+        assert!(
+            result.is_null(),
+            "expected the default player to have been cleared"
+        );
+    }
+
+    #[test]
+    fn test_active_player_audio_tracks() {
+        init_logger();
+        let player_id = "TrackPlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player.expect_name().return_const("TrackPlayer".to_string());
+        player.expect_add().return_const(1i64);
+        player
+            .expect_audio_tracks()
+            .return_const(Some(vec![MediaTrack {
+                id: 2,
+                name: "Track 2".to_string(),
+                language: Some("English".to_string()),
+            }]));
+        player
+            .expect_set_audio_track()
+            .withf(|id| *id == 2)
+            .times(1)
+            .return_const(());
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance.player_manager().add_player(Box::new(player));
+        set_active_player(&instance, into_c_string(player_id.to_string()));
+        let result = from_c_owned(active_player_audio_tracks(&instance));
+        let tracks = from_c_vec(result.tracks, result.len);
+        assert_eq!(1, tracks.len());
+        assert_eq!(2, tracks[0].id);
+
+        active_player_set_audio_track(&instance, 2);
+    }
+
+    #[test]
+    fn test_active_player_embedded_subtitle_tracks_not_supported() {
+        init_logger();
+        let player_id = "DlnaPlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player.expect_name().return_const("DlnaPlayer".to_string());
+        player.expect_add().return_const(1i64);
+        player.expect_embedded_subtitle_tracks().return_const(None);
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance.player_manager().add_player(Box::new(player));
+        set_active_player(&instance, into_c_string(player_id.to_string()));
+        let result = active_player_embedded_subtitle_tracks(&instance);
+
+        assert!(
+            result.is_null(),
+            "expected a null pointer to be returned when the tracks can't be enumerated"
+        );
+    }
+
     #[test]
     fn test_players() {
         init_logger();
@@ -492,7 +897,7 @@ mod tests {
         let graphic_resource_vec = vec![80, 20];
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let (graphic_resource, graphic_resource_len) = into_c_vec(graphic_resource_vec.clone());
         let player = PlayerRegistrationC {
             id: into_c_string(player_id.to_string()),
@@ -507,10 +912,11 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         };
 
-        register_player(&mut instance, player);
-        let set = from_c_owned(players(&mut instance));
+        register_player(&instance, player);
+        let set = from_c_owned(players(&instance));
         let players = from_c_vec(set.players, set.len);
 
         let result = players.get(0).unwrap();
@@ -528,7 +934,7 @@ mod tests {
         let graphic_resource_vec = vec![155, 30, 16];
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let (graphic_resource, graphic_resource_len) = into_c_vec(graphic_resource_vec.clone());
         let player = PlayerRegistrationC {
             id: into_c_string(player_id.to_string()),
@@ -543,11 +949,12 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         };
 
-        register_player(&mut instance, player);
+        register_player(&instance, player);
         let player = from_c_owned(player_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
@@ -563,7 +970,7 @@ mod tests {
         let player_id = "Id123";
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let player = PlayerRegistrationC {
             id: into_c_string(player_id.to_string()),
             name: into_c_string("FooBar".to_string()),
@@ -577,9 +984,10 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         };
 
-        register_player(&mut instance, player);
+        register_player(&instance, player);
 
         let players = instance.player_manager().players();
         if let Some(player) = players.get(0) {
@@ -595,7 +1003,7 @@ mod tests {
         let player_id = "Id123";
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let player = PlayerRegistrationC {
             id: into_c_string(player_id.to_string()),
             name: into_c_string("FooBar".to_string()),
@@ -609,11 +1017,12 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         };
 
-        register_player(&mut instance, player);
+        register_player(&instance, player);
         assert_eq!(1, instance.player_manager().players().len());
-        remove_player(&mut instance, into_c_string(player_id.to_string()));
+        remove_player(&instance, into_c_string(player_id.to_string()));
         assert_eq!(
             0,
             instance.player_manager().players().len(),
@@ -621,6 +1030,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_forget_device() {
+        init_logger();
+        let device_id = "MyDevice";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance.known_devices().update(KnownDevice {
+            id: device_id.to_string(),
+            name: "Living room".to_string(),
+            device_type: KnownDeviceType::Chromecast,
+            address: "192.168.0.10".to_string(),
+            port: 8009,
+            model: None,
+            location: None,
+            last_seen: 0,
+            failed_verifications: 0,
+        });
+
+        forget_device(&instance, into_c_string(device_id.to_string()));
+
+        assert_eq!(
+            0,
+            instance
+                .known_devices()
+                .devices(&KnownDeviceType::Chromecast)
+                .len(),
+            "expected the device to have been forgotten"
+        );
+    }
+
     #[test]
     fn test_invoke_player_event() {
         init_logger();
@@ -638,6 +1078,7 @@ mod tests {
             resume_callback: resume_registration_callback,
             seek_callback: seek_registration_callback,
             stop_callback: stop_registration_callback,
+            audio_track_callback: audio_track_registration_callback,
         });
         let (tx, rx) = channel();
         player.add(Box::new(move |e| {
@@ -670,11 +1111,11 @@ mod tests {
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_pause().times(1).return_const(());
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.player_manager().add_player(Box::new(player));
         let mut ptr = from_c_owned(player_pointer_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
@@ -690,11 +1131,11 @@ mod tests {
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_resume().times(1).return_const(());
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.player_manager().add_player(Box::new(player));
         let mut ptr = from_c_owned(player_pointer_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
@@ -710,17 +1151,71 @@ mod tests {
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_seek().times(1).return_const(());
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.player_manager().add_player(Box::new(player));
         let mut ptr = from_c_owned(player_pointer_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
         player_seek(&mut ptr, 28000);
     }
 
+    #[test]
+    fn test_player_set_muted() {
+        init_logger();
+        let player_id = "TestPlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player
+            .expect_set_muted()
+            .times(1)
+            .returning(move |e| tx.send(e).unwrap());
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance.player_manager().add_player(Box::new(player));
+        let mut ptr = from_c_owned(player_pointer_by_id(
+            &instance,
+            into_c_string(player_id.to_string()),
+        ));
+
+        player_set_muted(&mut ptr, true);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(true, result);
+    }
+
+    #[test]
+    fn test_player_set_volume() {
+        init_logger();
+        let player_id = "TestPlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player
+            .expect_set_volume()
+            .times(1)
+            .returning(move |e| tx.send(e).unwrap());
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance.player_manager().add_player(Box::new(player));
+        let mut ptr = from_c_owned(player_pointer_by_id(
+            &instance,
+            into_c_string(player_id.to_string()),
+        ));
+
+        player_set_volume(&mut ptr, 60);
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(60, result);
+    }
+
     #[test]
     fn test_player_stop() {
         init_logger();
@@ -730,17 +1225,77 @@ mod tests {
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_stop().times(1).return_const(());
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.player_manager().add_player(Box::new(player));
         let mut ptr = from_c_owned(player_pointer_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
         player_stop(&mut ptr);
     }
 
+    #[test]
+    fn test_set_active_subtitle_disabled() {
+        init_logger();
+        let player_id = "SubtitlePlayer";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player
+            .expect_update_subtitle()
+            .times(1)
+            .returning(move |e| tx.send(e).unwrap());
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance.player_manager().add_player(Box::new(player));
+
+        set_active_subtitle(
+            &instance,
+            into_c_string(player_id.to_string()),
+            &SubtitleInfoC::from(SubtitleInfo::none()),
+        );
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            result.is_none(),
+            "expected the subtitle to have been disabled"
+        );
+        assert_eq!(true, instance.subtitle_manager().is_disabled());
+    }
+
+    #[test]
+    fn test_player_update_subtitle() {
+        init_logger();
+        let player_id = "FooBar";
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let (tx, rx) = channel();
+        let mut player = MockPlayer::new();
+        player.expect_id().return_const(player_id.to_string());
+        player
+            .expect_update_subtitle()
+            .times(1)
+            .returning(move |e| tx.send(e).unwrap());
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        instance.player_manager().add_player(Box::new(player));
+        let mut ptr = from_c_owned(player_pointer_by_id(
+            &instance,
+            into_c_string(player_id.to_string()),
+        ));
+
+        player_update_subtitle(&mut ptr, ptr::null_mut());
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert!(
+            result.is_none(),
+            "expected the subtitle to have been disabled"
+        );
+    }
+
     #[test]
     fn test_dispose_player_manager_event() {
         init_logger();
@@ -766,11 +1321,11 @@ mod tests {
         let mut player = MockPlayer::new();
         player.expect_id().return_const(player_id.to_string());
         player.expect_resume().times(1).return_const(());
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.player_manager().add_player(Box::new(player));
         let ptr = from_c_owned(player_pointer_by_id(
-            &mut instance,
+            &instance,
             into_c_string(player_id.to_string()),
         ));
 
@@ -792,4 +1347,16 @@ mod tests {
 
         dispose_player(Box::new(player_c));
     }
+
+    #[test]
+    fn test_dispose_media_track_set() {
+        init_logger();
+        let set = MediaTrackSet::from(vec![MediaTrack {
+            id: 1,
+            name: "Track 1".to_string(),
+            language: None,
+        }]);
+
+        dispose_media_track_set(Box::new(set));
+    }
 }
@@ -1,6 +1,6 @@
 use std::ptr;
 
-use log::trace;
+use log::{trace, warn};
 
 use popcorn_fx_core::core::playlists::{Playlist, PlaylistItem};
 use popcorn_fx_core::from_c_vec;
@@ -10,12 +10,12 @@ use crate::PopcornFX;
 
 /// Play a playlist from C by converting it to the Rust data structure and starting playback asynchronously.
 ///
-/// This function takes a mutable reference to a `PopcornFX` instance and a C-compatible array of `PlaylistItemC` items.
+/// This function takes a reference to a `PopcornFX` instance and a C-compatible array of `PlaylistItemC` items.
 /// It converts the C array into a Rust `Playlist` and starts playback asynchronously using the playlist manager.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `playlist` - A C-compatible array of `PlaylistItemC` items representing the playlist to play.
 ///
 /// # Returns
@@ -24,7 +24,7 @@ use crate::PopcornFX;
 /// Otherwise, if an error occurs or the playlist is empty, a null pointer is returned.
 #[no_mangle]
 pub extern "C" fn play_playlist(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     playlist: CArray<PlaylistItemC>,
 ) -> *const i64 {
     trace!("Converting playlist from C for {:?}", playlist);
@@ -44,18 +44,18 @@ pub extern "C" fn play_playlist(
 /// Play the next item in the playlist from C.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and attempts to start playback of the next item in the playlist managed by the `PlaylistManager`.
+/// It takes a reference to a `PopcornFX` instance and attempts to start playback of the next item in the playlist managed by the `PlaylistManager`.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// A raw pointer to an `i64` representing the handle of the playlist item if playback was successfully started;
 /// otherwise, a null pointer if there are no more items to play or if an error occurred during playback initiation.
 #[no_mangle]
-pub extern "C" fn play_next_playlist_item(popcorn_fx: &mut PopcornFX) -> *const i64 {
+pub extern "C" fn play_next_playlist_item(popcorn_fx: &PopcornFX) -> *const i64 {
     trace!("Playing next item in playlist from C");
     popcorn_fx
         .playlist_manager()
@@ -67,21 +67,87 @@ pub extern "C" fn play_next_playlist_item(popcorn_fx: &mut PopcornFX) -> *const
 /// Stop the playback of the current playlist from C.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and stops the playback of the currently playing item in the playlist.
+/// It takes a reference to a `PopcornFX` instance and stops the playback of the currently playing item in the playlist.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn stop_playlist(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn stop_playlist(popcorn_fx: &PopcornFX) {
     trace!("Stopping current playlist from C");
     popcorn_fx.playlist_manager().stop();
 }
 
+/// Move a queued item of the playlist to a new position from C.
+///
+/// The item which is currently being played is not part of the queued items and won't be interrupted.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `from` - The current index of the queued item.
+/// * `to` - The new index of the queued item, which is clamped to the end of the playlist.
+///
+/// # Returns
+///
+/// `true` when the item has been moved, else `false` when the `from` index is out of range.
+#[no_mangle]
+pub extern "C" fn move_playlist_item(popcorn_fx: &PopcornFX, from: u32, to: u32) -> bool {
+    trace!("Moving playlist item from {} to {} from C", from, to);
+    match popcorn_fx
+        .playlist_manager()
+        .move_item(from as usize, to as usize)
+    {
+        Ok(_) => true,
+        Err(e) => {
+            warn!("Failed to move playlist item, {}", e);
+            false
+        }
+    }
+}
+
+/// Remove the queued item at the given index from the playlist from C.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `index` - The index of the queued item to remove.
+///
+/// # Returns
+///
+/// `true` when the item has been removed, else `false` when the index is out of range.
+#[no_mangle]
+pub extern "C" fn remove_playlist_item(popcorn_fx: &PopcornFX, index: u32) -> bool {
+    trace!("Removing playlist item {} from C", index);
+    match popcorn_fx.playlist_manager().remove_item(index as usize) {
+        Ok(_) => true,
+        Err(e) => {
+            warn!("Failed to remove playlist item, {}", e);
+            false
+        }
+    }
+}
+
+/// Insert the given item at the index within the queued items of the playlist from C.
+/// An index beyond the end of the playlist appends the item.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
+/// * `index` - The index at which the item should be inserted.
+/// * `item` - The C-compatible playlist item to insert.
+#[no_mangle]
+pub extern "C" fn insert_playlist_item(popcorn_fx: &PopcornFX, index: u32, item: PlaylistItemC) {
+    trace!("Inserting playlist item {:?} at {} from C", item, index);
+    popcorn_fx
+        .playlist_manager()
+        .insert_at(index as usize, PlaylistItem::from(item));
+}
+
 /// Registers a C-compatible callback function to receive playlist manager events.
 ///
 /// This function is exposed as a C-compatible function and is intended to be called from C or other languages.
-/// It takes a mutable reference to a `PopcornFX` instance and a C-compatible callback function as arguments.
+/// It takes a reference to a `PopcornFX` instance and a C-compatible callback function as arguments.
 ///
 /// The function registers the provided callback function with the `PlaylistManager` from the `PopcornFX` instance.
 /// When a playlist manager event occurs, the callback function is invoked with the corresponding C-compatible event data.
@@ -93,11 +159,11 @@ pub extern "C" fn stop_playlist(popcorn_fx: &mut PopcornFX) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `callback` - The C-compatible callback function to be registered.
 #[no_mangle]
 pub extern "C" fn register_playlist_manager_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: PlaylistManagerCallbackC,
 ) {
     trace!("Registering new C callback for playlist manager events");
@@ -114,13 +180,13 @@ pub extern "C" fn register_playlist_manager_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// A CArray of PlaylistItemC representing the playlist.
 #[no_mangle]
-pub extern "C" fn playlist(popcorn_fx: &mut PopcornFX) -> CArray<PlaylistItemC> {
+pub extern "C" fn playlist(popcorn_fx: &PopcornFX) -> CArray<PlaylistItemC> {
     trace!("Retrieving playlist from C");
     let vec: Vec<PlaylistItemC> = popcorn_fx
         .playlist_manager()
@@ -205,7 +271,7 @@ mod test {
         let playlist = CArray::from(vec![item]);
         let (tx, rx) = channel();
         let (tx_state, rx_state) = channel();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance
             .playlist_manager()
@@ -214,7 +280,7 @@ mod test {
                 PlaylistManagerEvent::StateChanged(state) => tx_state.send(state).unwrap(),
                 _ => {}
             }));
-        play_playlist(&mut instance, playlist);
+        play_playlist(&instance, playlist);
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert_eq!(
@@ -264,10 +330,10 @@ mod test {
                 subtitles_enabled: false,
             }),
         ]);
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        play_playlist(&mut instance, playlist);
-        let handle = play_next_playlist_item(&mut instance);
+        play_playlist(&instance, playlist);
+        let handle = play_next_playlist_item(&instance);
         assert!(
             !handle.is_null(),
             "expected a valid loader handle to have been returned"
@@ -279,7 +345,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         instance.playlist_manager().play(Playlist::from_iter(vec![
             PlaylistItem {
@@ -310,12 +376,55 @@ mod test {
             },
         ]));
 
-        stop_playlist(&mut instance);
+        stop_playlist(&instance);
 
         let result = instance.playlist_manager().has_next();
         assert_eq!(false, result, "expected the playlist to be empty");
     }
 
+    #[test]
+    fn test_move_playlist_item() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance.playlist_manager().play(Playlist::from_iter(
+            vec!["Item1", "Item2", "Item3", "Item4"]
+                .into_iter()
+                .map(|e| PlaylistItem::builder().title(e).build()),
+        ));
+
+        let result = move_playlist_item(&instance, 2, 0);
+        assert_eq!(true, result, "expected the item to have been moved");
+        let result: Vec<String> = instance
+            .playlist_manager()
+            .playlist()
+            .iter()
+            .map(|e| e.title.clone())
+            .collect();
+        assert_eq!(vec!["Item4", "Item2", "Item3"], result);
+
+        let result = move_playlist_item(&instance, 3, 0);
+        assert_eq!(false, result, "expected the out of range index to fail");
+    }
+
+    #[test]
+    fn test_remove_playlist_item() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance.playlist_manager().play(Playlist::from_iter(
+            vec!["Item1", "Item2", "Item3"]
+                .into_iter()
+                .map(|e| PlaylistItem::builder().title(e).build()),
+        ));
+
+        assert_eq!(true, remove_playlist_item(&instance, 0));
+        assert_eq!(false, remove_playlist_item(&instance, 1));
+        assert_eq!(1, instance.playlist_manager().playlist().items.len());
+    }
+
     #[test]
     fn test_dispose_playlist_item() {
         init_logger();
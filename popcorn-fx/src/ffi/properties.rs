@@ -13,7 +13,7 @@ use crate::PopcornFX;
 /// It returns an empty list when the provider name doesn't exist.
 #[no_mangle]
 pub extern "C" fn retrieve_provider_genres(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     name: *mut c_char,
 ) -> *mut StringArray {
     let name = from_c_string(name);
@@ -32,7 +32,7 @@ pub extern "C" fn retrieve_provider_genres(
 /// It returns an empty list when the provider name doesn't exist.
 #[no_mangle]
 pub extern "C" fn retrieve_provider_sort_by(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     name: *mut c_char,
 ) -> *mut StringArray {
     let name = from_c_string(name);
@@ -62,10 +62,10 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let array = from_c_owned(retrieve_provider_genres(
-            &mut instance,
+            &instance,
             into_c_string("series".to_string()),
         ));
         let result: Vec<String> = from_c_vec(array.values, array.len)
@@ -84,10 +84,10 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let result = retrieve_provider_genres(
-            &mut instance,
+            &instance,
             into_c_string("lorem ipsum dolor estla".to_string()),
         );
 
@@ -99,10 +99,10 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
         let array = from_c_owned(retrieve_provider_sort_by(
-            &mut instance,
+            &instance,
             into_c_string("favorites".to_string()),
         ));
         let result: Vec<String> = from_c_vec(array.values, array.len)
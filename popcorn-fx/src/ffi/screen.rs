@@ -1,7 +1,9 @@
-use log::trace;
+use log::{trace, warn};
 
-use popcorn_fx_core::core::screen::DefaultScreenService;
+use popcorn_fx_core::core::screen::{DefaultScreenService, DisplayInfo};
+use popcorn_fx_core::from_c_vec;
 
+use crate::ffi::{CArray, DisplayInfoC, MoveToDisplayCallbackC, ScreenEventC, ScreenEventCallbackC};
 use crate::PopcornFX;
 
 /// Type definition for a callback function that checks if the application is in fullscreen mode.
@@ -22,11 +24,11 @@ pub type FullscreenCallback = extern "C" fn(bool);
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `callback` - The callback function to be registered for checking the fullscreen state.
 #[no_mangle]
 pub extern "C" fn register_is_fullscreen_callback(
-    instance: &mut PopcornFX,
+    instance: &PopcornFX,
     callback: IsFullscreenCallback,
 ) {
     trace!("Registering new is fullscreen callback for C");
@@ -49,13 +51,10 @@ pub extern "C" fn register_is_fullscreen_callback(
 ///
 /// # Arguments
 ///
-/// * `instance` - A mutable reference to the `PopcornFX` instance.
+/// * `instance` - A reference to the `PopcornFX` instance.
 /// * `callback` - The fullscreen callback function to be registered.
 #[no_mangle]
-pub extern "C" fn register_fullscreen_callback(
-    instance: &mut PopcornFX,
-    callback: FullscreenCallback,
-) {
+pub extern "C" fn register_fullscreen_callback(instance: &PopcornFX, callback: FullscreenCallback) {
     trace!("Registering new fullscreen callback for C");
     if let Some(screen) = instance
         .screen_service()
@@ -68,11 +67,148 @@ pub extern "C" fn register_fullscreen_callback(
     }
 }
 
+/// Register a callback function which moves the application to the display with the given id.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback function to be registered for moving the application window.
+#[no_mangle]
+pub extern "C" fn register_move_to_display_callback(
+    instance: &PopcornFX,
+    callback: MoveToDisplayCallbackC,
+) {
+    trace!("Registering new move to display callback for C");
+    if let Some(screen) = instance
+        .screen_service()
+        .downcast_ref::<DefaultScreenService>()
+    {
+        screen.register_move_to_display_callback(Box::new(move |id| {
+            trace!("Calling move to display callback with {}", id);
+            callback(id);
+        }));
+    }
+}
+
+/// Register a new callback for the events of the screen service.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `callback` - The callback function which receives the screen events.
+#[no_mangle]
+pub extern "C" fn register_screen_event_callback(
+    instance: &PopcornFX,
+    callback: ScreenEventCallbackC,
+) {
+    trace!("Registering new screen event callback for C");
+    instance.screen_service().register(Box::new(move |event| {
+        trace!("Invoking screen event C callback for {}", event);
+        callback(ScreenEventC::from(event));
+    }));
+}
+
+/// Update the displays which are connected to the system.
+/// This should be invoked by the UI shell each time the connected displays have been changed.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `displays` - A pointer to the array of connected displays.
+/// * `len` - The number of displays within the array.
+#[no_mangle]
+pub extern "C" fn update_screen_displays(
+    instance: &PopcornFX,
+    displays: *mut DisplayInfoC,
+    len: i32,
+) {
+    trace!("Updating {} screen displays from C", len);
+    let displays = from_c_vec(displays, len)
+        .iter()
+        .map(DisplayInfo::from)
+        .collect::<Vec<DisplayInfo>>();
+
+    if let Some(screen) = instance
+        .screen_service()
+        .downcast_ref::<DefaultScreenService>()
+    {
+        screen.update_displays(displays);
+    }
+}
+
+/// Retrieve the displays which are connected to the system.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A CArray of DisplayInfoC, which should be disposed with `dispose_screen_displays`.
+#[no_mangle]
+pub extern "C" fn retrieve_screen_displays(instance: &PopcornFX) -> CArray<DisplayInfoC> {
+    trace!("Retrieving the screen displays from C");
+    CArray::from(
+        instance
+            .screen_service()
+            .displays()
+            .into_iter()
+            .map(DisplayInfoC::from)
+            .collect::<Vec<DisplayInfoC>>(),
+    )
+}
+
+/// Update the fullscreen state of the application.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `fullscreen` - `true` to activate fullscreen mode, `false` to deactivate it.
+#[no_mangle]
+pub extern "C" fn set_fullscreen(instance: &PopcornFX, fullscreen: bool) {
+    trace!("Updating the fullscreen state to {} from C", fullscreen);
+    instance.screen_service().fullscreen(fullscreen);
+}
+
+/// Move the application to the display with the given id.
+///
+/// # Arguments
+///
+/// * `instance` - A reference to the `PopcornFX` instance.
+/// * `id` - The id of the display to move the application to.
+///
+/// # Returns
+///
+/// It returns `true` when the application is being moved, else `false` when the display is unknown.
+#[no_mangle]
+pub extern "C" fn move_to_display(instance: &PopcornFX, id: u32) -> bool {
+    trace!("Moving the application to display {} from C", id);
+    match instance.screen_service().move_to_display(id) {
+        Ok(_) => true,
+        Err(e) => {
+            warn!("Unable to move the application to display {}, {}", id, e);
+            false
+        }
+    }
+}
+
+/// Dispose of a C-style array of displays.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `DisplayInfoC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_screen_displays(set: Box<CArray<DisplayInfoC>>) {
+    trace!("Disposing screen displays {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
 #[cfg(test)]
 mod tests {
     use log::info;
     use tempfile::tempdir;
 
+    use popcorn_fx_core::into_c_vec;
     use popcorn_fx_core::testing::init_logger;
 
     use crate::test::default_args;
@@ -88,14 +224,22 @@ mod tests {
         info!("Received fullscreen callback {}", value);
     }
 
+    extern "C" fn move_to_display_callback(id: u32) {
+        info!("Received move to display callback {}", id);
+    }
+
+    extern "C" fn screen_event_callback(event: ScreenEventC) {
+        info!("Received screen event callback {:?}", event);
+    }
+
     #[test]
     fn test_register_is_fullscreen_callback() {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_is_fullscreen_callback(&mut instance, is_fullscreen_callback);
+        register_is_fullscreen_callback(&instance, is_fullscreen_callback);
 
         let result = instance.screen_service().is_fullscreen();
         assert_eq!(true, result);
@@ -106,9 +250,37 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_fullscreen_callback(&mut instance, fullscreen_callback);
+        register_fullscreen_callback(&instance, fullscreen_callback);
         instance.screen_service().fullscreen(true);
     }
+
+    #[test]
+    fn test_move_to_display() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        let (displays, len) = into_c_vec(vec![DisplayInfoC::from(DisplayInfo {
+            id: 1,
+            name: "HDMI-1".to_string(),
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            primary: true,
+        })]);
+        register_move_to_display_callback(&instance, move_to_display_callback);
+        register_screen_event_callback(&instance, screen_event_callback);
+
+        update_screen_displays(&instance, displays, len);
+        let set = retrieve_screen_displays(&instance);
+        let result = from_c_vec(set.items, set.len);
+        assert_eq!(1, result.len());
+        assert_eq!(1, result[0].id);
+
+        assert_eq!(true, move_to_display(&instance, 1));
+        assert_eq!(false, move_to_display(&instance, 2));
+    }
 }
@@ -0,0 +1,229 @@
+use std::os::raw::c_char;
+use std::ptr;
+
+use log::{error, trace};
+
+use popcorn_fx_core::{from_c_string, into_c_owned, into_c_string};
+
+use crate::ffi::StringArray;
+use crate::PopcornFX;
+
+/// Retrieves the value of the given key within the namespace from the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+///
+/// # Returns
+///
+/// A pointer to a C-style string containing the stored value, or a null pointer if the key doesn't exist.
+#[no_mangle]
+pub extern "C" fn storage_get(
+    popcorn_fx: &PopcornFX,
+    namespace: *mut c_char,
+    key: *mut c_char,
+) -> *mut c_char {
+    let namespace = from_c_string(namespace);
+    let key = from_c_string(key);
+
+    trace!("Retrieving storage value {}.{} from C", namespace, key);
+    match popcorn_fx
+        .key_value_storage()
+        .get(namespace.as_str(), key.as_str())
+    {
+        Some(e) => into_c_string(e),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Stores the given value for the key within the namespace in the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+/// * `value` - A pointer to a C-style string containing the value to store.
+///
+/// # Returns
+///
+/// Returns `true` if the value has been stored, otherwise `false`.
+#[no_mangle]
+pub extern "C" fn storage_set(
+    popcorn_fx: &PopcornFX,
+    namespace: *mut c_char,
+    key: *mut c_char,
+    value: *mut c_char,
+) -> bool {
+    let namespace = from_c_string(namespace);
+    let key = from_c_string(key);
+    let value = from_c_string(value);
+
+    trace!("Updating storage value {}.{} from C", namespace, key);
+    match popcorn_fx
+        .key_value_storage()
+        .set(namespace.as_str(), key.as_str(), value.as_str())
+    {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Failed to store value {}.{}, {}", namespace, key, e);
+            false
+        }
+    }
+}
+
+/// Removes the given key within the namespace from the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace of the key.
+/// * `key` - A pointer to a C-style string containing the key.
+#[no_mangle]
+pub extern "C" fn storage_delete(popcorn_fx: &PopcornFX, namespace: *mut c_char, key: *mut c_char) {
+    let namespace = from_c_string(namespace);
+    let key = from_c_string(key);
+
+    trace!("Removing storage value {}.{} from C", namespace, key);
+    if let Err(e) = popcorn_fx
+        .key_value_storage()
+        .delete(namespace.as_str(), key.as_str())
+    {
+        error!("Failed to remove value {}.{}, {}", namespace, key, e);
+    }
+}
+
+/// Retrieves the keys which are stored within the given namespace of the key-value storage.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+/// * `namespace` - A pointer to a C-style string containing the namespace.
+///
+/// # Returns
+///
+/// A pointer to a `StringArray` containing the keys of the namespace.
+#[no_mangle]
+pub extern "C" fn storage_keys(popcorn_fx: &PopcornFX, namespace: *mut c_char) -> *mut StringArray {
+    let namespace = from_c_string(namespace);
+
+    trace!("Retrieving storage keys of {} from C", namespace);
+    into_c_owned(StringArray::from(
+        popcorn_fx.key_value_storage().keys(namespace.as_str()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use popcorn_fx_core::{from_c_owned, from_c_string_owned, from_c_vec};
+    use popcorn_fx_core::core::storage::MAX_VALUE_SIZE;
+    use popcorn_fx_core::testing::init_logger;
+
+    use crate::test::default_args;
+
+    use super::*;
+
+    #[test]
+    fn test_storage_set() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = storage_set(
+            &instance,
+            into_c_string("ui".to_string()),
+            into_c_string("last_tab".to_string()),
+            into_c_string("favorites".to_string()),
+        );
+        assert_eq!(true, result);
+
+        let result = from_c_string_owned(storage_get(
+            &instance,
+            into_c_string("ui".to_string()),
+            into_c_string("last_tab".to_string()),
+        ));
+        assert_eq!("favorites".to_string(), result);
+    }
+
+    #[test]
+    fn test_storage_set_value_too_large() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = storage_set(
+            &instance,
+            into_c_string("ui".to_string()),
+            into_c_string("geometry".to_string()),
+            into_c_string("a".repeat(MAX_VALUE_SIZE + 1)),
+        );
+
+        assert_eq!(false, result);
+    }
+
+    #[test]
+    fn test_storage_get_unknown_key() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result = storage_get(
+            &instance,
+            into_c_string("ui".to_string()),
+            into_c_string("unknown".to_string()),
+        );
+
+        assert!(result.is_null(), "expected a null pointer to be returned");
+    }
+
+    #[test]
+    fn test_storage_delete() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance
+            .key_value_storage()
+            .set("ui", "last_tab", "movies")
+            .unwrap();
+
+        storage_delete(
+            &instance,
+            into_c_string("ui".to_string()),
+            into_c_string("last_tab".to_string()),
+        );
+
+        assert_eq!(None, instance.key_value_storage().get("ui", "last_tab"));
+    }
+
+    #[test]
+    fn test_storage_keys() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        instance
+            .key_value_storage()
+            .set("ui", "width", "800")
+            .unwrap();
+        instance
+            .key_value_storage()
+            .set("ui", "height", "600")
+            .unwrap();
+
+        let result = from_c_owned(storage_keys(&instance, into_c_string("ui".to_string())));
+        let keys: Vec<String> = from_c_vec(result.values, result.len)
+            .into_iter()
+            .map(|e| from_c_string(e))
+            .collect();
+
+        assert_eq!(vec!["height".to_string(), "width".to_string()], keys);
+    }
+}
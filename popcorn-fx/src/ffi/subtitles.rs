@@ -1,12 +1,13 @@
-use std::ptr;
+use std::os::raw::c_char;
+use std::{ptr, slice};
 
-use log::trace;
+use log::{error, trace};
 
-use popcorn_fx_core::{from_c_vec, into_c_owned};
-use popcorn_fx_core::core::subtitles::model::SubtitleInfo;
+use popcorn_fx_core::{from_c_string, from_c_vec, into_c_owned, into_c_string};
+use popcorn_fx_core::core::subtitles::model::{Subtitle, SubtitleInfo, SubtitleType};
 use popcorn_fx_core::core::subtitles::SubtitleCallback;
 
-use crate::ffi::{SubtitleC, SubtitleEventC, SubtitleInfoC, SubtitleInfoSet};
+use crate::ffi::{SubtitleC, SubtitleCueSet, SubtitleEventC, SubtitleInfoC, SubtitleInfoSet};
 use crate::PopcornFX;
 
 /// The C callback for the subtitle events.
@@ -16,14 +17,14 @@ pub type SubtitleCallbackC = extern "C" fn(SubtitleEventC);
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 ///
 /// # Returns
 ///
 /// Returns a pointer to the preferred subtitle information in C-compatible format.
 /// If no preferred subtitle is found, it returns a null pointer.
 #[no_mangle]
-pub extern "C" fn retrieve_preferred_subtitle(popcorn_fx: &mut PopcornFX) -> *mut SubtitleInfoC {
+pub extern "C" fn retrieve_preferred_subtitle(popcorn_fx: &PopcornFX) -> *mut SubtitleInfoC {
     trace!("Retrieving preferred subtitle from C");
     match popcorn_fx.subtitle_manager().preferred_subtitle() {
         None => ptr::null_mut(),
@@ -40,13 +41,13 @@ pub extern "C" fn retrieve_preferred_subtitle(popcorn_fx: &mut PopcornFX) -> *mu
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// A pointer to a `SubtitleInfoSet` instance.
 #[no_mangle]
-pub extern "C" fn default_subtitle_options(popcorn_fx: &mut PopcornFX) -> *mut SubtitleInfoSet {
+pub extern "C" fn default_subtitle_options(popcorn_fx: &PopcornFX) -> *mut SubtitleInfoSet {
     trace!("Retrieving default subtitle options");
     let subtitles = popcorn_fx.subtitle_provider().default_subtitle_options();
     let subtitles: Vec<SubtitleInfoC> = subtitles.into_iter().map(SubtitleInfoC::from).collect();
@@ -89,7 +90,7 @@ pub extern "C" fn subtitle_custom() -> *mut SubtitleInfoC {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `subtitles_ptr` - Pointer to the array of subtitles in C-compatible form.
 /// * `len` - The length of the subtitles array.
 ///
@@ -98,7 +99,7 @@ pub extern "C" fn subtitle_custom() -> *mut SubtitleInfoC {
 /// A pointer to the selected default subtitle in C-compatible form.
 #[no_mangle]
 pub extern "C" fn select_or_default_subtitle(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     set: &mut SubtitleInfoSet,
 ) -> *mut SubtitleInfoC {
     trace!("Retrieving default subtitle selection from C for {:?}", set);
@@ -125,13 +126,10 @@ pub extern "C" fn select_or_default_subtitle(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - A function pointer to the C callback function.
 #[no_mangle]
-pub extern "C" fn register_subtitle_callback(
-    popcorn_fx: &mut PopcornFX,
-    callback: SubtitleCallbackC,
-) {
+pub extern "C" fn register_subtitle_callback(popcorn_fx: &PopcornFX, callback: SubtitleCallbackC) {
     trace!("Wrapping C callback for SubtitleCallback");
     let wrapper: SubtitleCallback = Box::new(move |event| {
         let event_c = SubtitleEventC::from(event);
@@ -151,13 +149,111 @@ pub extern "C" fn register_subtitle_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn cleanup_subtitles_directory(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn cleanup_subtitles_directory(popcorn_fx: &PopcornFX) {
     trace!("Cleaning subtitles directory from C");
     popcorn_fx.subtitle_manager().cleanup()
 }
 
+/// Clear the cache of the downloaded subtitle files.
+/// The next download of a subtitle will retrieve the file again from the subtitle provider.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
+#[no_mangle]
+pub extern "C" fn clear_subtitle_cache(popcorn_fx: &PopcornFX) {
+    trace!("Clearing subtitle cache from C");
+    popcorn_fx.subtitle_provider().clear_cache()
+}
+
+/// Retrieves the number of cues within the given subtitle.
+///
+/// # Arguments
+///
+/// * `subtitle` - A reference to the `SubtitleC` instance.
+///
+/// # Returns
+///
+/// The number of cues of the subtitle, or `0` when the subtitle doesn't contain any cues.
+#[no_mangle]
+pub extern "C" fn subtitle_cue_count(subtitle: &SubtitleC) -> usize {
+    if subtitle.cues.is_null() || subtitle.len <= 0 {
+        return 0;
+    }
+
+    subtitle.len as usize
+}
+
+/// Retrieves the total duration of the given subtitle.
+///
+/// # Arguments
+///
+/// * `subtitle` - A reference to the `SubtitleC` instance.
+///
+/// # Returns
+///
+/// The end time in millis of the last cue of the subtitle, or `0` when the subtitle doesn't contain any cues.
+#[no_mangle]
+pub extern "C" fn subtitle_total_duration(subtitle: &SubtitleC) -> u64 {
+    let len = subtitle_cue_count(subtitle);
+    if len == 0 {
+        return 0;
+    }
+
+    let cues = unsafe { slice::from_raw_parts(subtitle.cues, len) };
+    cues.iter().map(|e| e.end_time).max().unwrap_or_default()
+}
+
+/// Converts the given subtitle to the raw format of the given subtitle type.
+///
+/// The [SubtitleType::PlainText] type outputs a readable transcript of the subtitle dialogue without any timestamps.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `subtitle` - A reference to the `SubtitleC` instance to convert.
+/// * `subtitle_type` - The raw output format of the subtitle.
+///
+/// # Returns
+///
+/// The raw subtitle data, or a null pointer when the conversion failed.
+/// <i>The returned reference should be managed by the caller.</i>
+#[no_mangle]
+pub extern "C" fn subtitle_to_raw(
+    popcorn_fx: &PopcornFX,
+    subtitle: &SubtitleC,
+    subtitle_type: SubtitleType,
+) -> *mut c_char {
+    trace!(
+        "Converting subtitle C {:?} to raw {}",
+        subtitle,
+        subtitle_type
+    );
+    let len = subtitle_cue_count(subtitle);
+    let cues = if len > 0 {
+        unsafe { slice::from_raw_parts(subtitle.cues, len) }
+            .iter()
+            .map(|e| e.to_cue())
+            .collect()
+    } else {
+        vec![]
+    };
+    let subtitle = Subtitle::new(cues, None, from_c_string(subtitle.file));
+
+    match popcorn_fx
+        .subtitle_provider()
+        .convert(subtitle, subtitle_type)
+    {
+        Ok(e) => into_c_string(e),
+        Err(e) => {
+            error!("Failed to convert subtitle to raw format, {}", e);
+            ptr::null_mut()
+        }
+    }
+}
+
 /// Frees the memory allocated for the `SubtitleInfoSet` structure.
 ///
 /// # Safety
@@ -197,6 +293,19 @@ pub extern "C" fn dispose_subtitle(subtitle: Box<SubtitleC>) {
     drop(subtitle)
 }
 
+/// Frees the memory allocated for the `SubtitleCueSet` structure.
+///
+/// # Safety
+///
+/// This function is marked as `unsafe` because it's assumed that the `SubtitleCueSet` structure was allocated using `Box`,
+/// and dropping a `Box` pointing to valid memory is safe. However, if the `SubtitleCueSet` was allocated in a different way
+/// or if the memory was already deallocated, calling this function could lead to undefined behavior.
+#[no_mangle]
+pub extern "C" fn dispose_subtitle_cue_set(set: Box<SubtitleCueSet>) {
+    trace!("Disposing subtitle cue set C {:?}", set);
+    drop(set)
+}
+
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
@@ -204,10 +313,9 @@ mod test {
     use log::info;
     use tempfile::tempdir;
 
-    use popcorn_fx_core::{from_c_owned, from_c_vec};
+    use popcorn_fx_core::{from_c_owned, from_c_string_owned, from_c_vec};
     use popcorn_fx_core::core::subtitles::cue::{StyledText, SubtitleCue, SubtitleLine};
     use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
-    use popcorn_fx_core::core::subtitles::model::Subtitle;
     use popcorn_fx_core::core::subtitles::SubtitleFile;
     use popcorn_fx_core::testing::{copy_test_file, init_logger};
 
@@ -225,10 +333,10 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         let expected_result = vec![SubtitleInfo::none(), SubtitleInfo::custom()];
 
-        let set_ptr = from_c_owned(default_subtitle_options(&mut instance));
+        let set_ptr = from_c_owned(default_subtitle_options(&instance));
         let result: Vec<SubtitleInfo> = from_c_vec(set_ptr.subtitles, set_ptr.len)
             .into_iter()
             .map(SubtitleInfo::from)
@@ -260,9 +368,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
-        register_subtitle_callback(&mut instance, subtitle_callback);
+        register_subtitle_callback(&instance, subtitle_callback);
         instance
             .subtitle_manager()
             .update_subtitle(SubtitleInfo::none())
@@ -273,7 +381,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         let filepath = copy_test_file(
             instance
                 .settings()
@@ -285,7 +393,7 @@ mod test {
             None,
         );
 
-        cleanup_subtitles_directory(&mut instance);
+        cleanup_subtitles_directory(&instance);
 
         assert_eq!(
             false,
@@ -294,12 +402,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_clear_subtitle_cache() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+
+        clear_subtitle_cache(&instance);
+    }
+
     #[test]
     fn test_select_or_default_subtitle() {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         let info = SubtitleInfo::builder()
             .imdb_id("tt200002")
             .language(SubtitleLanguage::English)
@@ -313,7 +431,7 @@ mod test {
             .build();
         let mut set = SubtitleInfoSet::from(vec![SubtitleInfoC::from(info.clone())]);
 
-        let result = from_c_owned(select_or_default_subtitle(&mut instance, &mut set));
+        let result = from_c_owned(select_or_default_subtitle(&instance, &mut set));
 
         assert_eq!(info, SubtitleInfo::from(result));
     }
@@ -323,9 +441,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
-        let result = retrieve_preferred_subtitle(&mut instance);
+        let result = retrieve_preferred_subtitle(&instance);
 
         assert_eq!(ptr::null_mut(), result);
     }
@@ -349,6 +467,90 @@ mod test {
         dispose_subtitle_info(Box::new(info));
     }
 
+    #[test]
+    fn test_subtitle_cue_count() {
+        let subtitle = SubtitleC::from(Subtitle::new(
+            vec![
+                SubtitleCue::new("1".to_string(), 1000, 4000, vec![]),
+                SubtitleCue::new("2".to_string(), 5000, 9500, vec![]),
+            ],
+            None,
+            "lorem.srt".to_string(),
+        ));
+
+        let result = subtitle_cue_count(&subtitle);
+
+        assert_eq!(2, result);
+    }
+
+    #[test]
+    fn test_subtitle_total_duration() {
+        let subtitle = SubtitleC::from(Subtitle::new(
+            vec![
+                SubtitleCue::new("1".to_string(), 1000, 4000, vec![]),
+                SubtitleCue::new("2".to_string(), 5000, 9500, vec![]),
+            ],
+            None,
+            "lorem.srt".to_string(),
+        ));
+
+        let result = subtitle_total_duration(&subtitle);
+
+        assert_eq!(9500, result);
+    }
+
+    #[test]
+    fn test_subtitle_to_raw_plain_text() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+        let subtitle = SubtitleC::from(Subtitle::new(
+            vec![
+                SubtitleCue::new(
+                    "1".to_string(),
+                    1000,
+                    4000,
+                    vec![SubtitleLine::new(vec![StyledText::new(
+                        "Lorem ipsum".to_string(),
+                        true,
+                        false,
+                        false,
+                    )])],
+                ),
+                SubtitleCue::new(
+                    "2".to_string(),
+                    5000,
+                    9500,
+                    vec![SubtitleLine::new(vec![StyledText::new(
+                        "dolor sit amet.".to_string(),
+                        false,
+                        false,
+                        false,
+                    )])],
+                ),
+            ],
+            None,
+            "lorem.srt".to_string(),
+        ));
+
+        let result = from_c_string_owned(subtitle_to_raw(
+            &instance,
+            &subtitle,
+            SubtitleType::PlainText,
+        ));
+
+        assert_eq!("Lorem ipsum dolor sit amet.\n", result.as_str());
+    }
+
+    #[test]
+    fn test_subtitle_empty() {
+        let subtitle = SubtitleC::from(Subtitle::new(vec![], None, "lorem.srt".to_string()));
+
+        assert_eq!(0, subtitle_cue_count(&subtitle));
+        assert_eq!(0, subtitle_total_duration(&subtitle));
+    }
+
     #[test]
     fn test_dispose_subtitle() {
         let subtitle = Subtitle::new(
@@ -32,6 +32,14 @@ pub struct MagnetInfoC {
     pub name: *mut c_char,
     /// The magnet uri to the torrent
     pub magnet_uri: *mut c_char,
+    /// The unix timestamp in millis at which the magnet has been added, or `0` when unknown
+    pub added_at: i64,
+    /// The unix timestamp in millis at which the magnet has been last used, or `0` when never used
+    pub last_used_at: i64,
+    /// The total size in bytes of the torrent files, or `0` when not resolved
+    pub total_size: u64,
+    /// The total number of files within the torrent, or `0` when not resolved
+    pub total_files: u32,
 }
 
 impl From<MagnetInfo> for MagnetInfoC {
@@ -39,6 +47,10 @@ impl From<MagnetInfo> for MagnetInfoC {
         Self {
             name: into_c_string(value.name),
             magnet_uri: into_c_string(value.magnet_uri),
+            added_at: value.added_at.unwrap_or_default(),
+            last_used_at: value.last_used_at.unwrap_or_default(),
+            total_size: value.total_size.unwrap_or_default(),
+            total_files: value.total_files.unwrap_or_default(),
         }
     }
 }
@@ -56,6 +68,7 @@ mod test {
         let infos = vec![MagnetInfo {
             name: name.to_string(),
             magnet_uri: magnet_uri.to_string(),
+            ..Default::default()
         }];
 
         let set = TorrentCollectionSet::from(infos.clone());
@@ -74,11 +87,19 @@ mod test {
         let info = MagnetInfo {
             name: name.to_string(),
             magnet_uri: uri.to_string(),
+            added_at: Some(1000),
+            last_used_at: None,
+            total_size: Some(4096),
+            total_files: Some(3),
         };
 
         let result = MagnetInfoC::from(info.clone());
 
         assert_eq!(name.to_string(), from_c_string(result.name));
         assert_eq!(uri.to_string(), from_c_string(result.magnet_uri));
+        assert_eq!(1000, result.added_at);
+        assert_eq!(0, result.last_used_at);
+        assert_eq!(4096, result.total_size);
+        assert_eq!(3, result.total_files);
     }
 }
@@ -1,18 +1,19 @@
 use std::os::raw::c_char;
 use std::ptr;
 
-use log::{trace, warn};
+use log::{debug, trace, warn};
 
 use popcorn_fx_core::core::torrents::{
-    DownloadStatus, TorrentError, TorrentInfo, TorrentState, TorrentWrapper,
+    DownloadStatus, InfoHash, TorrentError, TorrentInfo, TorrentState, TorrentWrapper,
 };
 use popcorn_fx_core::core::Handle;
-use popcorn_fx_core::{from_c_string, into_c_string};
+use popcorn_fx_core::{from_c_string, from_c_vec, into_c_owned, into_c_string};
 use popcorn_fx_torrent::torrent::DefaultTorrentManager;
 
 use crate::ffi::{
-    CancelTorrentCallback, DownloadStatusC, ResolveTorrentCallback, ResolveTorrentInfoCallback,
-    TorrentFileInfoC, TorrentStreamEventC, TorrentStreamEventCallback,
+    ByteRangeC, CArray, CancelTorrentCallback, DownloadStatusC, InfoHashC, PauseTorrentCallback,
+    ResolveTorrentCallback, ResolveTorrentInfoCallback, TorrentFileInfoC, TorrentHandleEventC,
+    TorrentHandleEventCallback, TorrentStreamEventC, TorrentStreamEventCallback, TorrentSummaryC,
 };
 use crate::PopcornFX;
 
@@ -20,12 +21,12 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `state` - The new state of the torrent.
 #[no_mangle]
 pub extern "C" fn torrent_state_changed(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     handle: *mut c_char,
     state: TorrentState,
 ) {
@@ -51,15 +52,11 @@ pub extern "C" fn torrent_state_changed(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `piece` - The index of the finished piece.
 #[no_mangle]
-pub extern "C" fn torrent_piece_finished(
-    popcorn_fx: &mut PopcornFX,
-    handle: *mut c_char,
-    piece: u32,
-) {
+pub extern "C" fn torrent_piece_finished(popcorn_fx: &PopcornFX, handle: *mut c_char, piece: u32) {
     let handle = from_c_string(handle);
     if let Some(torrent) = popcorn_fx
         .torrent_manager()
@@ -81,12 +78,12 @@ pub extern "C" fn torrent_piece_finished(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `handle` - The handle to the torrent.
 /// * `download_status` - The new download status of the torrent.
 #[no_mangle]
 pub extern "C" fn torrent_download_status(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     handle: *mut c_char,
     download_status: DownloadStatusC,
 ) {
@@ -108,13 +105,47 @@ pub extern "C" fn torrent_download_status(
     }
 }
 
+/// Callback function for handling a failure to write the torrent data to the storage.
+///
+/// Transient failures are retried by the torrent, after too many consecutive failures the torrent
+/// is put in an error state and a [popcorn_fx_core::core::torrents::TorrentEvent::Error] is invoked.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle to the torrent.
+/// * `error` - The description of the write failure.
+#[no_mangle]
+pub extern "C" fn torrent_write_error(
+    popcorn_fx: &PopcornFX,
+    handle: *mut c_char,
+    error: *mut c_char,
+) {
+    let handle = from_c_string(handle);
+    let error = from_c_string(error);
+    if let Some(torrent) = popcorn_fx
+        .torrent_manager()
+        .by_handle(handle.as_str())
+        .and_then(|e| e.upgrade())
+    {
+        if let Some(wrapper) = torrent.downcast_ref::<TorrentWrapper>() {
+            wrapper.write_failed(error);
+        }
+    } else {
+        warn!(
+            "Unable to process torrent write error, handle {} not found",
+            handle
+        );
+    }
+}
+
 /// Registers a new C-compatible resolve torrent callback function with PopcornFX.
 ///
 /// This function allows registering a callback that will be invoked when torrent resolution is complete.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `callback` - The C-compatible resolve torrent callback function to be registered.
 ///
 /// # Example
@@ -138,7 +169,7 @@ pub extern "C" fn torrent_download_status(
 /// This function performs unsafe operations, as it deals with raw C-compatible function pointers.
 #[no_mangle]
 pub extern "C" fn torrent_resolve_info_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: ResolveTorrentInfoCallback,
 ) {
     trace!("Registering new C resolve torrent info callback");
@@ -172,11 +203,11 @@ pub extern "C" fn torrent_resolve_info_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `callback` - The `ResolveTorrentCallback` function to be registered.
 #[no_mangle]
 pub extern "C" fn register_torrent_resolve_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: ResolveTorrentCallback,
 ) {
     trace!("Registering new C resolve torrent callback");
@@ -210,13 +241,10 @@ pub extern "C" fn register_torrent_resolve_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `callback` - A `CancelTorrentCallback` function that will be registered to handle cancel torrent events.
 #[no_mangle]
-pub extern "C" fn torrent_cancel_callback(
-    popcorn_fx: &mut PopcornFX,
-    callback: CancelTorrentCallback,
-) {
+pub extern "C" fn torrent_cancel_callback(popcorn_fx: &PopcornFX, callback: CancelTorrentCallback) {
     trace!("Registering new C cancel torrent callback");
     if let Some(manager) = popcorn_fx
         .torrent_manager()
@@ -229,13 +257,130 @@ pub extern "C" fn torrent_cancel_callback(
     }
 }
 
+/// Register a new C-compatible pause torrent callback with a Rust PopcornFX instance.
+///
+/// This function registers a callback that handles the pausing and resuming of torrents.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `callback` - A `PauseTorrentCallback` function that will be invoked when a torrent should be paused or resumed.
+#[no_mangle]
+pub extern "C" fn torrent_pause_callback(popcorn_fx: &PopcornFX, callback: PauseTorrentCallback) {
+    trace!("Registering new C pause torrent callback");
+    if let Some(manager) = popcorn_fx
+        .torrent_manager()
+        .downcast_ref::<DefaultTorrentManager>()
+    {
+        manager.register_pause_callback(Box::new(move |handle, paused| {
+            trace!(
+                "Executing pause torrent callback for {:?} with paused {}",
+                handle,
+                paused
+            );
+            callback(into_c_string(handle), paused);
+        }));
+    }
+}
+
+/// Pause the torrent with the given handle on behalf of the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent to pause.
+#[no_mangle]
+pub extern "C" fn pause_torrent(popcorn_fx: &PopcornFX, handle: *mut c_char) {
+    let handle = from_c_string(handle);
+    trace!("Pausing torrent {} from C", handle);
+    popcorn_fx.torrent_manager().pause(handle.as_str());
+}
+
+/// Resume the torrent with the given handle which has been paused by the user.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent to resume.
+#[no_mangle]
+pub extern "C" fn resume_torrent(popcorn_fx: &PopcornFX, handle: *mut c_char) {
+    let handle = from_c_string(handle);
+    trace!("Resuming torrent {} from C", handle);
+    popcorn_fx.torrent_manager().resume(handle.as_str());
+}
+
+/// Pause all torrents at once.
+/// Torrents which are added while paused will start in a paused state.
+#[no_mangle]
+pub extern "C" fn pause_all_torrents(popcorn_fx: &PopcornFX) {
+    trace!("Pausing all torrents from C");
+    popcorn_fx.torrent_manager().pause_all();
+}
+
+/// Resume all torrents which have been paused by [pause_all_torrents].
+/// Torrents which have been paused individually through [pause_torrent] remain paused.
+#[no_mangle]
+pub extern "C" fn resume_all_torrents(popcorn_fx: &PopcornFX) {
+    trace!("Resuming all torrents from C");
+    popcorn_fx.torrent_manager().resume_all();
+}
+
+/// Verify if all torrents have been paused.
+///
+/// # Returns
+///
+/// It returns `true` when all torrents are paused, else `false`.
+#[no_mangle]
+pub extern "C" fn is_torrents_paused(popcorn_fx: &PopcornFX) -> bool {
+    trace!("Retrieving torrents paused state from C");
+    popcorn_fx.torrent_manager().is_paused()
+}
+
+/// Retrieve the summaries of all active torrents.
+///
+/// The summaries are a snapshot of the last known stats of the torrents,
+/// which makes it cheap to list all torrents at once.
+/// Torrents which are being removed are not included.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+///
+/// # Returns
+///
+/// A CArray of TorrentSummaryC representing the active torrents.
+#[no_mangle]
+pub extern "C" fn active_torrents(popcorn_fx: &PopcornFX) -> CArray<TorrentSummaryC> {
+    trace!("Retrieving active torrents from C");
+    let torrents: Vec<TorrentSummaryC> = popcorn_fx
+        .torrent_manager()
+        .active_torrents()
+        .into_iter()
+        .map(TorrentSummaryC::from)
+        .collect();
+
+    debug!("Retrieved a total of {} active torrents", torrents.len());
+    CArray::from(torrents)
+}
+
+/// Dispose of a C-style array of torrent summaries.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `TorrentSummaryC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_torrent_summary_set(set: Box<CArray<TorrentSummaryC>>) {
+    trace!("Disposing torrent summary set {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
 /// Registers a new torrent stream event callback.
 ///
 /// This function registers a callback function to receive torrent stream events.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the PopcornFX instance.
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
 /// * `stream_handle` - The handle of the torrent stream.
 /// * `callback` - The callback function to be invoked when torrent stream events occur.
 ///
@@ -244,7 +389,7 @@ pub extern "C" fn torrent_cancel_callback(
 /// A pointer to an integer value representing the handle of the registered callback, or a null pointer if registration fails.
 #[no_mangle]
 pub extern "C" fn register_torrent_stream_event_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     stream_handle: i64,
     callback: TorrentStreamEventCallback,
 ) -> *const i64 {
@@ -268,7 +413,7 @@ pub extern "C" fn register_torrent_stream_event_callback(
 
 #[no_mangle]
 pub extern "C" fn remove_torrent_stream_event_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     stream_handle: *const i64,
     callback_handle: *const i64,
 ) {
@@ -284,14 +429,162 @@ pub extern "C" fn remove_torrent_stream_event_callback(
         .unsubscribe(handle, callback_handle);
 }
 
+/// Retrieve the downloaded byte ranges of the torrent stream with the given handle.
+///
+/// The ranges are file-relative, sorted and coalesced, which allows the ranges to be polled
+/// frequently to visualize the buffered parts of the stream.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `stream_handle` - The handle of the torrent stream.
+///
+/// # Returns
+///
+/// A CArray of ByteRangeC representing the downloaded ranges, which is empty when the stream handle is unknown.
+#[no_mangle]
+pub extern "C" fn torrent_stream_available_ranges(
+    popcorn_fx: &PopcornFX,
+    stream_handle: i64,
+) -> CArray<ByteRangeC> {
+    trace!(
+        "Retrieving available ranges of torrent stream {} from C",
+        stream_handle
+    );
+    let ranges: Vec<ByteRangeC> = popcorn_fx
+        .torrent_stream_server()
+        .available_ranges(Handle::from(stream_handle))
+        .unwrap_or_default()
+        .into_iter()
+        .map(ByteRangeC::from)
+        .collect();
+
+    CArray::from(ranges)
+}
+
+/// Dispose of a C-style array of byte ranges.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `ByteRangeC` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_byte_range_set(set: Box<CArray<ByteRangeC>>) {
+    trace!("Disposing byte range set {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
+/// Registers a new callback for the events of the torrent with the given handle.
+///
+/// When the torrent has already been removed, the callback is invoked once with the `Removed` event
+/// and no callback is registered.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent.
+/// * `callback` - The callback function to be invoked when torrent events occur.
+///
+/// # Returns
+///
+/// A pointer to an integer value representing the handle of the registered callback, or a null pointer if the torrent has been removed.
+#[no_mangle]
+pub extern "C" fn register_torrent_event_callback(
+    popcorn_fx: &PopcornFX,
+    handle: *mut c_char,
+    callback: TorrentHandleEventCallback,
+) -> *const i64 {
+    let handle = from_c_string(handle);
+    trace!(
+        "Registering a new torrent event callback for handle {}",
+        handle
+    );
+    popcorn_fx
+        .torrent_manager()
+        .subscribe(
+            handle.as_str(),
+            Box::new(move |event| {
+                trace!("Invoking torrent event C callback for {:?}", event);
+                callback(TorrentHandleEventC::from(event))
+            }),
+        )
+        .map(|handle| handle.value() as *const i64)
+        .unwrap_or(ptr::null())
+}
+
+/// Removes a torrent event callback which has been registered through [register_torrent_event_callback].
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `handle` - The handle of the torrent.
+/// * `callback_handle` - The handle of the registered callback.
+#[no_mangle]
+pub extern "C" fn remove_torrent_event_callback(
+    popcorn_fx: &PopcornFX,
+    handle: *mut c_char,
+    callback_handle: *const i64,
+) {
+    let handle = from_c_string(handle);
+    trace!(
+        "Removing torrent event callback handle {:?} of {}",
+        callback_handle,
+        handle
+    );
+    let callback_handle = Handle::from(callback_handle as i64);
+    popcorn_fx
+        .torrent_manager()
+        .unsubscribe(handle.as_str(), callback_handle);
+}
+
 /// Clean the torrents directory.
 /// This will remove all existing torrents from the system.
 #[no_mangle]
-pub extern "C" fn cleanup_torrents_directory(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn cleanup_torrents_directory(popcorn_fx: &PopcornFX) {
     trace!("Cleaning torrents directory from C");
     popcorn_fx.torrent_manager().cleanup();
 }
 
+/// Compute the info hash of the given magnet uri or torrent file path without adding the torrent.
+///
+/// # Arguments
+///
+/// * `uri_or_path` - The magnet uri or the path to a torrent file.
+///
+/// # Returns
+///
+/// It returns the computed info hash, or a null pointer when the info hash couldn't be computed.
+#[no_mangle]
+pub extern "C" fn compute_info_hash(uri_or_path: *mut c_char) -> *mut InfoHashC {
+    let uri_or_path = from_c_string(uri_or_path);
+    trace!("Computing info hash from C for {}", uri_or_path);
+    let result = if uri_or_path.starts_with("magnet:") {
+        InfoHash::from_magnet(uri_or_path.as_str())
+    } else {
+        match std::fs::read(uri_or_path.as_str()) {
+            Ok(bytes) => InfoHash::from_file_bytes(bytes.as_slice()),
+            Err(e) => {
+                warn!("Failed to read torrent file {}, {}", uri_or_path, e);
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    match result {
+        Ok(e) => into_c_owned(InfoHashC::from(e)),
+        Err(e) => {
+            warn!("Failed to compute info hash of {}, {}", uri_or_path, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Frees the memory allocated for the `InfoHashC` structure.
+#[no_mangle]
+pub extern "C" fn dispose_info_hash(info_hash: Box<InfoHashC>) {
+    trace!("Disposing info hash {:?}", info_hash);
+    drop(info_hash);
+}
+
 #[no_mangle]
 pub extern "C" fn dispose_torrent_stream_event_value(event: TorrentStreamEventC) {
     trace!("Disposing torrent stream event from C {:?}", event);
@@ -311,10 +604,10 @@ mod test {
 
     use popcorn_fx_core::core::block_in_place;
     use popcorn_fx_core::core::torrents::{
-        MockTorrent, Torrent, TorrentEvent, TorrentFileInfo, TorrentManager,
+        MockTorrent, Torrent, TorrentEvent, TorrentFileInfo, TorrentManager, MAX_WRITE_ATTEMPTS,
     };
     use popcorn_fx_core::testing::{copy_test_file, init_logger};
-    use popcorn_fx_core::{assert_timeout_eq, into_c_string};
+    use popcorn_fx_core::{assert_timeout_eq, from_c_owned, into_c_string};
 
     use crate::ffi::TorrentC;
     use crate::test::{default_args, new_instance};
@@ -336,6 +629,11 @@ mod test {
         10
     }
 
+    #[no_mangle]
+    extern "C" fn piece_length_callback() -> u64 {
+        1024
+    }
+
     #[no_mangle]
     extern "C" fn prioritize_bytes_callback(_: i32, _: *mut u64) {}
 
@@ -355,6 +653,11 @@ mod test {
         info!("Received torrent stream event {:?}", event);
     }
 
+    #[no_mangle]
+    extern "C" fn torrent_handle_event_callback(event: TorrentHandleEventC) {
+        info!("Received torrent event {:?}", event);
+    }
+
     #[no_mangle]
     extern "C" fn torrent_resolve_callback(
         file_info: TorrentFileInfoC,
@@ -365,9 +668,11 @@ mod test {
         TorrentC {
             handle: into_c_string("MyHandle"),
             filepath: into_c_string("/tmp/pmy-path"),
+            info_hash: ptr::null_mut(),
             has_byte_callback: has_bytes_callback,
             has_piece_callback,
             total_pieces: total_pieces_callback,
+            piece_length: piece_length_callback,
             prioritize_bytes: prioritize_bytes_callback,
             prioritize_pieces: prioritize_pieces_callback,
             sequential_mode: sequential_mode_callback,
@@ -380,7 +685,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let handle = "MyHandle";
         let torrent_file_info = TorrentFileInfo {
             filename: "".to_string(),
@@ -397,13 +702,16 @@ mod test {
             let wrapper = TorrentWrapper {
                 handle: handle.to_string(),
                 filepath: Default::default(),
+                info_hash: None,
                 has_bytes: Mutex::new(Box::new(|_| true)),
                 has_piece: Mutex::new(Box::new(|_| true)),
                 total_pieces: Mutex::new(Box::new(|| 10)),
+                piece_length: Mutex::new(Box::new(|| 1024)),
                 prioritize_bytes: Mutex::new(Box::new(|_| {})),
                 prioritize_pieces: Mutex::new(Box::new(|_| {})),
                 sequential_mode: Mutex::new(Box::new(|| {})),
                 torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+                write_failures: Default::default(),
                 callbacks: Default::default(),
             };
             let tx_wrapper = tx.clone();
@@ -413,7 +721,7 @@ mod test {
             wrapper
         }));
         match block_in_place(torrent_manager.create(&torrent_file_info, temp_path, true)) {
-            Ok(result) => assert_eq!(handle, result.upgrade().unwrap().handle()),
+            Ok(result) => assert_eq!(handle, result.torrent.upgrade().unwrap().handle()),
             Err(e) => assert!(false, "expected torrent to have been created, got {}", e),
         }
 
@@ -421,7 +729,7 @@ mod test {
             .by_handle(handle)
             .expect("expected the torrent handle to have been found");
         torrent_state_changed(
-            &mut instance,
+            &instance,
             into_c_string(handle.to_string()),
             TorrentState::Starting,
         );
@@ -438,13 +746,78 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_torrent_write_error() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        let handle = "MyHandle";
+        let torrent_file_info = TorrentFileInfo {
+            filename: "".to_string(),
+            file_path: temp_path.to_string(),
+            file_size: 18000,
+            file_index: 0,
+        };
+
+        let (tx, rx) = channel();
+        let manager = instance.torrent_manager().clone();
+        let torrent_manager = manager.downcast_ref::<DefaultTorrentManager>().unwrap();
+
+        torrent_manager.register_resolve_callback(Box::new(move |_, _, _| {
+            let wrapper = TorrentWrapper {
+                handle: handle.to_string(),
+                filepath: Default::default(),
+                info_hash: None,
+                has_bytes: Mutex::new(Box::new(|_| true)),
+                has_piece: Mutex::new(Box::new(|_| true)),
+                total_pieces: Mutex::new(Box::new(|| 10)),
+                piece_length: Mutex::new(Box::new(|| 1024)),
+                prioritize_bytes: Mutex::new(Box::new(|_| {})),
+                prioritize_pieces: Mutex::new(Box::new(|_| {})),
+                sequential_mode: Mutex::new(Box::new(|| {})),
+                torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+                write_failures: Default::default(),
+                callbacks: Default::default(),
+            };
+            let tx_wrapper = tx.clone();
+            wrapper.subscribe(Box::new(move |event| {
+                tx_wrapper.send(event).unwrap();
+            }));
+            wrapper
+        }));
+        block_in_place(torrent_manager.create(&torrent_file_info, temp_path, true))
+            .expect("expected torrent to have been created");
+
+        for _ in 0..MAX_WRITE_ATTEMPTS {
+            torrent_write_error(
+                &instance,
+                into_c_string(handle.to_string()),
+                into_c_string("No space left on device".to_string()),
+            );
+        }
+
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        match result {
+            TorrentEvent::Error(e) => assert_eq!(
+                TorrentError::StorageError("No space left on device".to_string()),
+                e
+            ),
+            _ => assert!(
+                false,
+                "expected TorrentEvent::Error, but got {} instead",
+                result
+            ),
+        }
+    }
+
     #[test]
     fn test_torrent_piece_finished() {
         init_logger();
         let handle = "MyHandleId654";
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
         let manager = instance
             .torrent_manager()
@@ -453,17 +826,20 @@ mod test {
         manager.register_resolve_callback(Box::new(|_, _, _| TorrentWrapper {
             handle: handle.to_string(),
             filepath: Default::default(),
+            info_hash: None,
             has_bytes: Mutex::new(Box::new(|_| true)),
             has_piece: Mutex::new(Box::new(|_| true)),
             total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
             prioritize_bytes: Mutex::new(Box::new(|_| {})),
             prioritize_pieces: Mutex::new(Box::new(|_| {})),
             sequential_mode: Mutex::new(Box::new(|| {})),
             torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
             callbacks: Default::default(),
         }));
 
-        torrent_piece_finished(&mut instance, into_c_string(handle), 5);
+        torrent_piece_finished(&instance, into_c_string(handle), 5);
     }
 
     #[test]
@@ -471,9 +847,160 @@ mod test {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
+
+        register_torrent_resolve_callback(&instance, torrent_resolve_callback);
+    }
+
+    #[no_mangle]
+    extern "C" fn torrent_pause_callback_test(handle: *mut c_char, paused: bool) {
+        info!(
+            "Received torrent pause callback for {} with paused {}",
+            from_c_string(handle),
+            paused
+        );
+    }
+
+    #[test]
+    fn test_pause_all_torrents() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+        let torrent_file_info = TorrentFileInfo {
+            filename: "".to_string(),
+            file_path: temp_path.to_string(),
+            file_size: 18000,
+            file_index: 0,
+        };
+        register_torrent_resolve_callback(&instance, torrent_resolve_callback);
+        torrent_pause_callback(&instance, torrent_pause_callback_test);
+        block_in_place(
+            instance
+                .torrent_manager()
+                .create(&torrent_file_info, temp_path, true),
+        )
+        .expect("expected the torrent to have been created");
+
+        pause_all_torrents(&instance);
+        assert_eq!(true, is_torrents_paused(&instance));
+
+        resume_all_torrents(&instance);
+        assert_eq!(false, is_torrents_paused(&instance));
+    }
+
+    #[test]
+    fn test_active_torrents() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+        let torrent_file_info = TorrentFileInfo {
+            filename: "lorem.mp4".to_string(),
+            file_path: temp_path.to_string(),
+            file_size: 18000,
+            file_index: 0,
+        };
+        register_torrent_resolve_callback(&instance, torrent_resolve_callback);
+        block_in_place(
+            instance
+                .torrent_manager()
+                .create(&torrent_file_info, temp_path, true),
+        )
+        .expect("expected the torrent to have been created");
+
+        let result: Vec<TorrentSummaryC> = Vec::from(active_torrents(&instance));
+
+        assert_eq!(1, result.len());
+        assert_eq!("MyHandle".to_string(), from_c_string(result[0].handle));
+        assert_eq!("lorem.mp4".to_string(), from_c_string(result[0].name));
+        assert_eq!(TorrentState::Creating, result[0].state);
+    }
+
+    #[test]
+    fn test_register_torrent_event_callback() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+        let torrent_file_info = TorrentFileInfo {
+            filename: "lorem.mp4".to_string(),
+            file_path: temp_path.to_string(),
+            file_size: 18000,
+            file_index: 0,
+        };
+        register_torrent_resolve_callback(&instance, torrent_resolve_callback);
+        block_in_place(
+            instance
+                .torrent_manager()
+                .create(&torrent_file_info, temp_path, true),
+        )
+        .expect("expected the torrent to have been created");
+
+        let result = register_torrent_event_callback(
+            &instance,
+            into_c_string("MyHandle"),
+            torrent_handle_event_callback,
+        );
+        assert!(!result.is_null(), "expected a callback handle");
+        remove_torrent_event_callback(&instance, into_c_string("MyHandle"), result);
+
+        let result = register_torrent_event_callback(
+            &instance,
+            into_c_string("UnknownHandle"),
+            torrent_handle_event_callback,
+        );
+        assert!(
+            result.is_null(),
+            "expected no callback handle for a removed torrent"
+        );
+    }
+
+    #[test]
+    fn test_pause_torrent() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = new_instance(temp_path);
+        let (tx, rx) = channel();
+        let manager = instance
+            .torrent_manager()
+            .downcast_ref::<DefaultTorrentManager>()
+            .unwrap();
+        manager.register_resolve_callback(Box::new(|_, _, _| TorrentWrapper {
+            handle: "MyHandle".to_string(),
+            filepath: Default::default(),
+            info_hash: None,
+            has_bytes: Mutex::new(Box::new(|_| true)),
+            has_piece: Mutex::new(Box::new(|_| true)),
+            total_pieces: Mutex::new(Box::new(|| 10)),
+            piece_length: Mutex::new(Box::new(|| 1024)),
+            prioritize_bytes: Mutex::new(Box::new(|_| {})),
+            prioritize_pieces: Mutex::new(Box::new(|_| {})),
+            sequential_mode: Mutex::new(Box::new(|| {})),
+            torrent_state: Mutex::new(Box::new(|| TorrentState::Downloading)),
+            write_failures: Default::default(),
+            callbacks: Default::default(),
+        }));
+        manager.register_pause_callback(Box::new(move |handle, paused| {
+            tx.send((handle, paused)).unwrap();
+        }));
+        let torrent_file_info = TorrentFileInfo {
+            filename: "".to_string(),
+            file_path: temp_path.to_string(),
+            file_size: 18000,
+            file_index: 0,
+        };
+        block_in_place(manager.create(&torrent_file_info, temp_path, true))
+            .expect("expected the torrent to have been created");
+
+        pause_torrent(&instance, into_c_string("MyHandle"));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(("MyHandle".to_string(), true), result);
 
-        register_torrent_resolve_callback(&mut instance, torrent_resolve_callback);
+        resume_torrent(&instance, into_c_string("MyHandle"));
+        let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
+        assert_eq!(("MyHandle".to_string(), false), result);
     }
 
     #[test]
@@ -481,7 +1008,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         let mut torrent_settings = instance.settings().user_settings().torrent_settings;
         torrent_settings.directory = PathBuf::from(temp_path);
         instance.settings().update_torrent(torrent_settings);
@@ -491,7 +1018,7 @@ mod test {
             Some("torrents/subdir/example.mp4"),
         );
 
-        cleanup_torrents_directory(&mut instance);
+        cleanup_torrents_directory(&instance);
 
         assert_timeout_eq!(
             Duration::from_millis(200),
@@ -524,7 +1051,7 @@ mod test {
             .return_const(TorrentState::Downloading);
         torrent.expect_prioritize_pieces().return_const(());
         let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
         let stream = instance
             .torrent_stream_server()
@@ -535,14 +1062,112 @@ mod test {
 
         let stream_handle_value = stream.stream_handle().value();
         let callback = register_torrent_stream_event_callback(
-            &mut instance,
+            &instance,
             stream_handle_value,
             torrent_stream_event_callback,
         ) as i64;
         remove_torrent_stream_event_callback(
-            &mut instance,
+            &instance,
             stream_handle_value as *const i64,
             callback as *const i64,
         );
     }
+
+    #[test]
+    fn test_torrent_stream_available_ranges() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = temp_dir.path().join("lorem.mp4");
+        let mut torrent = MockTorrent::new();
+        torrent.expect_file().return_const(filepath.clone());
+        torrent.expect_total_pieces().return_const(2);
+        torrent.expect_piece_length().return_const(1024u64);
+        torrent.expect_has_piece().returning(|piece| piece == 1);
+        torrent.expect_subscribe().return_const(Handle::new());
+        torrent
+            .expect_state()
+            .return_const(TorrentState::Downloading);
+        torrent.expect_prioritize_pieces().return_const(());
+        let torrent = Arc::new(Box::new(torrent) as Box<dyn Torrent>);
+        std::fs::write(&filepath, vec![0u8; 2048]).unwrap();
+        let instance = new_instance(temp_path);
+        let stream = instance
+            .torrent_stream_server()
+            .start_stream(Arc::downgrade(&torrent))
+            .expect("expected a stream to have been returned")
+            .upgrade()
+            .expect("expected the stream instance to still be valid");
+
+        let result = Vec::from(torrent_stream_available_ranges(
+            &instance,
+            stream.stream_handle().value(),
+        ));
+        assert_eq!(
+            vec![ByteRangeC {
+                start: 1024,
+                end: 2048,
+            }],
+            result
+        );
+
+        let result = Vec::from(torrent_stream_available_ranges(
+            &instance,
+            Handle::new().value(),
+        ));
+        assert_eq!(Vec::<ByteRangeC>::new(), result);
+    }
+
+    #[test]
+    fn test_compute_info_hash_magnet() {
+        init_logger();
+        let uri = into_c_string(
+            "magnet:?xt=urn:btih:631a31dd0a46257d5078c0dee4e66e26f73e42ac&xt=urn:btmh:1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb",
+        );
+
+        let result = from_c_owned(compute_info_hash(uri));
+
+        assert_eq!(
+            "631a31dd0a46257d5078c0dee4e66e26f73e42ac".to_string(),
+            from_c_string(result.v1)
+        );
+        assert_eq!(
+            "d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb".to_string(),
+            from_c_string(result.v2)
+        );
+    }
+
+    #[test]
+    fn test_compute_info_hash_file() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let filepath = temp_dir.path().join("lorem.torrent");
+        std::fs::write(
+            &filepath,
+            "d4:infod6:lengthi12e4:name9:lorem.txt12:piece lengthi16384e6:pieces0:ee",
+        )
+        .unwrap();
+
+        let result = from_c_owned(compute_info_hash(into_c_string(filepath.to_str().unwrap())));
+
+        assert_eq!(
+            "7510f3ba36f619d005eb3919c526ad2d1aa39120".to_string(),
+            from_c_string(result.v1)
+        );
+        assert!(result.v2.is_null(), "expected no v2 info hash");
+    }
+
+    #[test]
+    fn test_compute_info_hash_invalid() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let filepath = temp_dir.path().join("unknown.torrent");
+
+        let result = compute_info_hash(into_c_string(filepath.to_str().unwrap()));
+
+        assert!(
+            result.is_null(),
+            "expected no info hash to have been returned"
+        );
+    }
 }
@@ -9,11 +9,11 @@ use crate::PopcornFX;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - The callback function to be registered.
 #[no_mangle]
 pub extern "C" fn register_tracking_authorization_open(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: AuthorizationOpenC,
 ) {
     trace!("Registering new tracking authorization open callback from C");
@@ -29,11 +29,11 @@ pub extern "C" fn register_tracking_authorization_open(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 /// * `callback` - The callback function to be registered.
 #[no_mangle]
 pub extern "C" fn register_tracking_provider_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: TrackingEventCCallback,
 ) {
     trace!("Registering new tracking provider callback for C");
@@ -47,13 +47,13 @@ pub extern "C" fn register_tracking_provider_callback(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// Returns `true` if the tracking provider is authorized, otherwise `false`.
 #[no_mangle]
-pub extern "C" fn tracking_is_authorized(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn tracking_is_authorized(popcorn_fx: &PopcornFX) -> bool {
     trace!("Checking if the current tracker is authorized from C");
     popcorn_fx.tracking_provider().is_authorized()
 }
@@ -62,9 +62,9 @@ pub extern "C" fn tracking_is_authorized(popcorn_fx: &mut PopcornFX) -> bool {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn tracking_authorize(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn tracking_authorize(popcorn_fx: &PopcornFX) {
     let tracking_service = popcorn_fx.tracking_provider().clone();
     popcorn_fx.runtime().spawn(async move {
         match tracking_service.authorize().await {
@@ -78,9 +78,9 @@ pub extern "C" fn tracking_authorize(popcorn_fx: &mut PopcornFX) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn tracking_disconnect(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn tracking_disconnect(popcorn_fx: &PopcornFX) {
     trace!("Disconnecting tracker");
     let tracking_service = popcorn_fx.tracking_provider().clone();
     popcorn_fx
@@ -133,9 +133,9 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
-        register_tracking_authorization_open(&mut instance, authorization_open);
+        register_tracking_authorization_open(&instance, authorization_open);
     }
 
     #[test]
@@ -143,9 +143,9 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
-        register_tracking_provider_callback(&mut instance, tracking_event_c_callback);
+        register_tracking_provider_callback(&instance, tracking_event_c_callback);
     }
 
     #[test]
@@ -153,9 +153,9 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
 
-        let result = tracking_is_authorized(&mut instance);
+        let result = tracking_is_authorized(&instance);
 
         assert!(!result, "expected the tracker to not have been authorized");
     }
@@ -166,7 +166,7 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
         let (tx, rx) = channel();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         let properties = instance.settings().properties();
         let expected_uri = properties
             .tracker("trakt")
@@ -201,7 +201,7 @@ mod tests {
                 true
             }));
 
-        tracking_authorize(&mut instance);
+        tracking_authorize(&instance);
 
         let result = rx.recv_timeout(Duration::from_millis(200)).unwrap();
         assert!(result.starts_with(expected_uri.as_str()))
@@ -212,7 +212,7 @@ mod tests {
         init_logger();
         let temp_dir = tempdir().unwrap();
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = new_instance(temp_path);
+        let instance = new_instance(temp_path);
         instance.settings().update_tracker(
             "trakt",
             Tracker {
@@ -227,7 +227,7 @@ mod tests {
             instance.tracking_provider().is_authorized(),
             "expected the tracker to have been authorized"
         );
-        tracking_disconnect(&mut instance);
+        tracking_disconnect(&instance);
 
         assert_timeout_eq!(
             Duration::from_millis(200),
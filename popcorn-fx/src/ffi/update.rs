@@ -2,18 +2,19 @@ use std::ptr;
 
 use log::{error, trace};
 
-use popcorn_fx_core::into_c_owned;
+use popcorn_fx_core::core::updater::UpdateChannel;
+use popcorn_fx_core::{from_c_vec, into_c_owned};
 
-use crate::ffi::{UpdateCallbackC, UpdateEventC, UpdateStateC, VersionInfoC};
+use crate::ffi::{CArray, UpdateCallbackC, UpdateEventC, UpdateStateC, VersionInfoC};
 use crate::PopcornFX;
 
 /// Retrieve the latest release version information from the update channel.
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn version_info(popcorn_fx: &mut PopcornFX) -> *mut VersionInfoC {
+pub extern "C" fn version_info(popcorn_fx: &PopcornFX) -> *mut VersionInfoC {
     trace!("Retrieving version info");
     let runtime = popcorn_fx.runtime();
     match runtime.block_on(popcorn_fx.updater().version_info()) {
@@ -29,13 +30,13 @@ pub extern "C" fn version_info(popcorn_fx: &mut PopcornFX) -> *mut VersionInfoC
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 ///
 /// # Returns
 ///
 /// The current update state of the application as a [UpdateStateC] value.
 #[no_mangle]
-pub extern "C" fn update_state(popcorn_fx: &mut PopcornFX) -> UpdateStateC {
+pub extern "C" fn update_state(popcorn_fx: &PopcornFX) -> UpdateStateC {
     trace!("Retrieving update state from C");
     UpdateStateC::from(popcorn_fx.updater().state())
 }
@@ -44,9 +45,9 @@ pub extern "C" fn update_state(popcorn_fx: &mut PopcornFX) -> UpdateStateC {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn check_for_updates(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn check_for_updates(popcorn_fx: &PopcornFX) {
     trace!("Checking for new updates from C");
     popcorn_fx.updater().check_for_updates()
 }
@@ -55,9 +56,9 @@ pub extern "C" fn check_for_updates(popcorn_fx: &mut PopcornFX) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn download_update(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn download_update(popcorn_fx: &PopcornFX) {
     let updater = popcorn_fx.updater().clone();
     popcorn_fx.runtime().spawn(async move {
         if let Err(e) = updater.download().await {
@@ -70,15 +71,83 @@ pub extern "C" fn download_update(popcorn_fx: &mut PopcornFX) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 #[no_mangle]
-pub extern "C" fn install_update(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn install_update(popcorn_fx: &PopcornFX) {
     trace!("Starting installation update from C");
     if let Err(e) = popcorn_fx.updater().install() {
         error!("Failed to start update, {}", e);
     }
 }
 
+/// Retrieve the update channel from which new application versions are received.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+#[no_mangle]
+pub extern "C" fn update_channel(popcorn_fx: &PopcornFX) -> UpdateChannel {
+    trace!("Retrieving update channel from C");
+    popcorn_fx.updater().channel()
+}
+
+/// Retrieve the update channels which can be selected.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+///
+/// # Returns
+///
+/// A C-compatible array of the available update channels.
+#[no_mangle]
+pub extern "C" fn available_update_channels(popcorn_fx: &PopcornFX) -> CArray<UpdateChannel> {
+    trace!("Retrieving available update channels from C");
+    CArray::from(popcorn_fx.updater().available_channels())
+}
+
+/// Switch the update channel from which new application versions are received.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+/// * `channel` - the update channel to select.
+#[no_mangle]
+pub extern "C" fn set_update_channel(popcorn_fx: &PopcornFX, channel: UpdateChannel) {
+    trace!("Updating update channel to {} from C", channel);
+    if let Err(e) = popcorn_fx.updater().set_channel(channel) {
+        error!("Failed to update the update channel, {}", e);
+    }
+}
+
+/// Roll back the application to the previously installed version.
+///
+/// The application needs to be relaunched when the rollback succeeded,
+/// which is indicated by the [UpdateStateC::InstallationFinished] state.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
+#[no_mangle]
+pub extern "C" fn rollback_update(popcorn_fx: &PopcornFX) {
+    trace!("Rolling back update from C");
+    let runtime = popcorn_fx.runtime();
+    if let Err(e) = runtime.block_on(popcorn_fx.updater().rollback_update()) {
+        error!("Failed to roll back update, {}", e);
+    }
+}
+
+/// Dispose of a C-style array of update channels.
+///
+/// # Arguments
+///
+/// * `set` - A boxed C-style array of `UpdateChannel` to be disposed of.
+#[no_mangle]
+pub extern "C" fn dispose_update_channel_set(set: Box<CArray<UpdateChannel>>) {
+    trace!("Disposing update channel set {:?}", set);
+    drop(from_c_vec(set.items, set.len));
+}
+
 /// Register a new callback for update events.
 ///
 /// This function registers a new callback listener for update events in the PopcornFX application.
@@ -89,14 +158,14 @@ pub extern "C" fn install_update(popcorn_fx: &mut PopcornFX) {
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - a mutable reference to a `PopcornFX` instance.
+/// * `popcorn_fx` - a reference to a `PopcornFX` instance.
 /// * `callback` - a C-compatible function that will be invoked when an update event occurs.
 ///
 /// # Safety
 ///
 /// This function should only be called from C code, and the provided `callback` function should be a valid C function pointer.
 #[no_mangle]
-pub extern "C" fn register_update_callback(popcorn_fx: &mut PopcornFX, callback: UpdateCallbackC) {
+pub extern "C" fn register_update_callback(popcorn_fx: &PopcornFX, callback: UpdateCallbackC) {
     trace!("Registering new update callback from C");
     popcorn_fx
         .updater()
@@ -148,9 +217,9 @@ mod test {
         });
         let mut popcorn_fx_args = default_args(temp_path);
         popcorn_fx_args.properties.update_channel = server.url("/update/");
-        let mut instance = PopcornFX::new(popcorn_fx_args);
+        let instance = PopcornFX::new(popcorn_fx_args);
 
-        let result = from_c_owned(version_info(&mut instance));
+        let result = from_c_owned(version_info(&instance));
 
         assert_eq!(
             "0.2.0".to_string(),
@@ -164,9 +233,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        check_for_updates(&mut instance);
+        check_for_updates(&instance);
     }
 
     #[test]
@@ -174,9 +243,9 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let result = update_state(&mut instance);
+        let result = update_state(&instance);
 
         match result {
             UpdateStateC::CheckingForNewVersion => {}
@@ -185,13 +254,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_available_update_channels() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        let result: Vec<UpdateChannel> = Vec::from(available_update_channels(&instance));
+
+        assert_eq!(vec![UpdateChannel::Stable, UpdateChannel::Beta], result);
+    }
+
+    #[test]
+    fn test_set_update_channel() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let args = default_args(temp_path);
+        std::fs::create_dir_all(args.data_directory.as_str()).unwrap();
+        let instance = PopcornFX::new(args);
+
+        set_update_channel(&instance, UpdateChannel::Beta);
+
+        assert_eq!(UpdateChannel::Beta, update_channel(&instance));
+    }
+
     #[test]
     fn test_download_update() {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        download_update(&mut instance);
+        download_update(&instance);
     }
 }
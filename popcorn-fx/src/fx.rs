@@ -1,13 +1,15 @@
+use std::collections::HashMap;
 use std::env;
+use std::future::Future;
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::sync::{Arc, Once};
+use std::sync::{Arc, Once, OnceLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use clap::Parser;
+use clap::{Args, Parser};
 use derive_more::Display;
 use directories::{BaseDirs, UserDirs};
-use log::{error, info, LevelFilter, warn};
+use log::{debug, error, info, LevelFilter, warn};
 use log4rs::append::console::ConsoleAppender;
 use log4rs::append::rolling_file::policy::compound::CompoundPolicy;
 use log4rs::append::rolling_file::policy::compound::roll::fixed_window::FixedWindowRoller;
@@ -16,43 +18,54 @@ use log4rs::append::rolling_file::RollingFileAppender;
 use log4rs::config::{Appender, Logger, Root};
 use log4rs::Config;
 use log4rs::encode::pattern::PatternEncoder;
+use log4rs::filter::threshold::ThresholdFilter;
 use tokio::runtime::Runtime;
 
 use popcorn_fx_core::core::block_in_place;
 use popcorn_fx_core::core::cache::CacheManager;
-use popcorn_fx_core::core::config::{ApplicationConfig, PopcornProperties};
+use popcorn_fx_core::core::config::{ApplicationConfig, PopcornProperties, ProviderType};
+use popcorn_fx_core::core::crash::CrashReporter;
 use popcorn_fx_core::core::events::EventPublisher;
-use popcorn_fx_core::core::images::{DefaultImageLoader, ImageLoader};
+use popcorn_fx_core::core::images::{
+    DefaultImageLoader, ImageCache, ImageLoader, DEFAULT_IMAGE_CACHE_SIZE,
+};
 use popcorn_fx_core::core::loader::{
-    AutoResumeLoadingStrategy, DefaultMediaLoader, LoadingStrategy, MediaLoader,
-    MediaTorrentUrlLoadingStrategy, PlayerLoadingStrategy, SubtitlesLoadingStrategy,
-    TorrentDetailsLoadingStrategy, TorrentInfoLoadingStrategy, TorrentLoadingStrategy,
-    TorrentStreamLoadingStrategy,
+    AudioTracksLoadingStrategy, AutoResumeLoadingStrategy, BandwidthHistory, DefaultMediaLoader,
+    DirectUrlLoadingStrategy, LoadingPolicy, LoadingStrategy, MediaLoader,
+    MediaTorrentUrlLoadingStrategy, PlayerLoadingStrategy, QualitySelector,
+    SubtitlesLoadingStrategy, TorrentDetailsLoadingStrategy, TorrentInfoLoadingStrategy,
+    TorrentLoadingStrategy, TorrentStreamLoadingStrategy, YoutubeLoadingStrategy,
 };
+use popcorn_fx_core::core::media::Category;
 use popcorn_fx_core::core::media::favorites::{
     DefaultFavoriteService, FavoriteCacheUpdater, FavoriteService,
 };
 use popcorn_fx_core::core::media::providers::{
-    FavoritesProvider, MovieProvider, ProviderManager, ShowProvider,
+    CatalogProvider, FavoritesProvider, MovieProvider, ProviderManager, ShowProvider,
 };
 use popcorn_fx_core::core::media::providers::enhancers::ThumbEnhancer;
+use popcorn_fx_core::core::media::providers::torrent_sources::{
+    TorrentSourceAggregator, TorrentSourceProvider, TorrentioSourceProvider,
+};
 use popcorn_fx_core::core::media::resume::{AutoResumeService, DefaultAutoResumeService};
 use popcorn_fx_core::core::media::tracking::{SyncMediaTracking, TrackingProvider};
 use popcorn_fx_core::core::media::watched::{DefaultWatchedService, WatchedService};
 use popcorn_fx_core::core::platform::PlatformData;
 use popcorn_fx_core::core::playback::PlaybackControls;
-use popcorn_fx_core::core::players::{DefaultPlayerManager, PlayerManager};
+use popcorn_fx_core::core::players::{DefaultPlayerManager, KnownDevicesCache, PlayerManager};
 use popcorn_fx_core::core::playlists::PlaylistManager;
 use popcorn_fx_core::core::screen::{DefaultScreenService, ScreenService};
+use popcorn_fx_core::core::storage::{data_migrations, KeyValueStorage, MigrationRunner};
 use popcorn_fx_core::core::subtitles::{
     DefaultSubtitleManager, SubtitleManager, SubtitleProvider, SubtitleServer,
 };
 use popcorn_fx_core::core::subtitles::model::SubtitleType;
-use popcorn_fx_core::core::subtitles::parsers::{SrtParser, VttParser};
-use popcorn_fx_core::core::torrents::{TorrentManager, TorrentStreamServer};
-use popcorn_fx_core::core::torrents::collection::TorrentCollection;
+use popcorn_fx_core::core::subtitles::parsers::{SrtParser, TranscriptParser, VttParser};
+use popcorn_fx_core::core::torrents::{DownloadNotifier, TorrentManager, TorrentStreamServer};
+use popcorn_fx_core::core::torrents::collection::{TorrentAutoCollector, TorrentCollection};
 use popcorn_fx_core::core::torrents::stream::DefaultTorrentStreamServer;
 use popcorn_fx_core::core::updater::Updater;
+use popcorn_fx_core::core::utils::http::HttpClientFactory;
 use popcorn_fx_opensubtitles::opensubtitles::OpensubtitlesProvider;
 use popcorn_fx_platform::platform::DefaultPlatform;
 use popcorn_fx_players::chromecast::ChromecastDiscovery;
@@ -62,7 +75,13 @@ use popcorn_fx_players::vlc::VlcDiscovery;
 use popcorn_fx_torrent::torrent::DefaultTorrentManager;
 use popcorn_fx_trakt::trakt::TraktProvider;
 
+use crate::logging::{LogBuffer, LogController, DEFAULT_LOG_BUFFER_CAPACITY, LOG_BUFFER_APPENDER};
+
 static INIT: Once = Once::new();
+/// The buffer of the most recent backend log records of the default logger.
+static LOG_BUFFER: OnceLock<Arc<LogBuffer>> = OnceLock::new();
+/// The log level controller of the default logger.
+static LOG_CONTROLLER: OnceLock<LogController> = OnceLock::new();
 
 const LOG_FILENAME: &str = "log4.yml";
 const LOG_FORMAT_CONSOLE: &str = "\x1B[37m{d(%Y-%m-%d %H:%M:%S%.3f)}\x1B[0m {h({l:>5.5})} \x1B[35m{I:>6.6}\x1B[0m \x1B[37m---\x1B[0m \x1B[37m[{T:>15.15}]\x1B[0m \x1B[36m{t:<40.40}\x1B[0m \x1B[37m:\x1B[0m {m}{n}";
@@ -73,6 +92,8 @@ const FILE_APPENDER: &str = "file";
 const LOG_FILE_DIRECTORY: &str = "logs";
 const LOG_FILE_NAME: &str = "popcorn-time.log";
 const LOG_FILE_SIZE: u64 = 50 * 1024 * 1024;
+const LOG_FILE_COUNT: u32 = 5;
+const EVENT_REPLAY_BUFFER_SIZE: usize = 10;
 const DEFAULT_APP_DIRECTORY: fn() -> String = || {
     UserDirs::new()
         .map(|e| PathBuf::from(e.home_dir()))
@@ -88,6 +109,17 @@ const DEFAULT_DATA_DIRECTORY: fn() -> String = || {
         .expect("expected a data directory to exist")
 };
 
+/// Parse the given loading policy argument, the name of the policy is case-insensitive
+/// and can be written in kebab-case, e.g. `cancel-active`.
+fn parse_loading_policy(value: &str) -> Result<LoadingPolicy, String> {
+    match value.to_lowercase().replace(['-', '_'], "").as_str() {
+        "concurrent" => Ok(LoadingPolicy::Concurrent),
+        "cancelactive" => Ok(LoadingPolicy::CancelActive),
+        "queue" => Ok(LoadingPolicy::Queue),
+        _ => Err(format!("invalid loading policy {}", value)),
+    }
+}
+
 /// The options for the [PopcornFX] instance.
 #[derive(Debug, Clone, Display, Parser)]
 #[command(name = "popcorn-fx")]
@@ -105,6 +137,9 @@ pub struct PopcornFxArgs {
     /// This allows you to bring your own logger for the instance which should support [log].
     #[arg(long, global = true, default_value_t = false)]
     pub disable_logger: bool,
+    /// The options of the rolling log file of the default `log4rs` logger.
+    #[command(flatten)]
+    pub log_file: LogFileArgs,
     /// Disable the mouse within the application.
     #[arg(long, default_value_t = false)]
     pub disable_mouse: bool,
@@ -129,6 +164,14 @@ pub struct PopcornFxArgs {
     /// Indicates if insecure TLS connections are allowed
     #[arg(long, default_value_t = false)]
     pub insecure: bool,
+    /// The number of events of each event type which are replayed to late event subscribers, such as the frontend.
+    /// A size of `0` disables the replaying of events.
+    #[arg(long, default_value_t = EVENT_REPLAY_BUFFER_SIZE)]
+    pub event_replay_buffer_size: usize,
+    /// The policy of the media loader when a new media item is loaded while another item is still loading.
+    /// The active load is cancelled by default, `concurrent` starts the loads next to each other and `queue` waits for it.
+    #[arg(long, default_value_t = LoadingPolicy::default(), value_parser = parse_loading_policy)]
+    pub loading_policy: LoadingPolicy,
     /// The properties of the application which are constant during the lifecycle of [PopcornFX]
     #[arg(skip = PopcornProperties::new_auto())]
     pub properties: PopcornProperties,
@@ -140,6 +183,7 @@ impl Default for PopcornFxArgs {
             app_directory: DEFAULT_APP_DIRECTORY(),
             data_directory: DEFAULT_DATA_DIRECTORY(),
             disable_logger: false,
+            log_file: LogFileArgs::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -148,14 +192,58 @@ impl Default for PopcornFxArgs {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: EVENT_REPLAY_BUFFER_SIZE,
+            loading_policy: LoadingPolicy::default(),
             properties: PopcornProperties::new_auto(),
         }
     }
 }
 
+/// The options of the rolling log file which is written by the default `log4rs` logger.
+/// These options have no effect when the logger is disabled through [PopcornFxArgs::disable_logger].
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct LogFileArgs {
+    /// Disable writing the logs to the rolling log file.
+    #[arg(long = "disable-log-file", default_value_t = false)]
+    pub disabled: bool,
+    /// The path of the log file.
+    /// Defaults to `logs/popcorn-time.log` within the app directory when not set.
+    #[arg(long = "log-file")]
+    pub path: Option<String>,
+    /// The log level of the log file.
+    /// Defaults to the root log level when not set.
+    #[arg(long = "log-file-level")]
+    pub level: Option<String>,
+    /// The max size in bytes of the log file before it's rolled over.
+    #[arg(long = "log-file-max-size", default_value_t = LOG_FILE_SIZE)]
+    pub max_size: u64,
+    /// The number of rolled over log files which are retained.
+    #[arg(long = "log-file-count", default_value_t = LOG_FILE_COUNT)]
+    pub max_files: u32,
+}
+
+impl Default for LogFileArgs {
+    fn default() -> Self {
+        Self {
+            disabled: false,
+            path: None,
+            level: None,
+            max_size: LOG_FILE_SIZE,
+            max_files: LOG_FILE_COUNT,
+        }
+    }
+}
+
 /// The [PopcornFX] application instance.
 /// This is the main entry into the FX application and manages all known data.
 ///
+/// # Thread safety
+///
+/// The instance can be shared between threads, all FFI functions only borrow it through a shared reference.
+/// Mutable state is guarded by the services of the instance, which makes concurrent calls safe.
+/// The only exception is [dispose_popcorn_fx], which takes ownership of the instance
+/// and should only be invoked once all other calls have completed.
+///
 /// # Examples
 ///
 /// Create a simple instance with default values.
@@ -168,10 +256,13 @@ impl Default for PopcornFxArgs {
 pub struct PopcornFX {
     auto_resume_service: Arc<Box<dyn AutoResumeService>>,
     cache_manager: Arc<CacheManager>,
+    crash_reporter: Arc<CrashReporter>,
     event_publisher: Arc<EventPublisher>,
     favorite_cache_updater: Arc<FavoriteCacheUpdater>,
     favorites_service: Arc<Box<dyn FavoriteService>>,
     image_loader: Arc<Box<dyn ImageLoader>>,
+    key_value_storage: Arc<KeyValueStorage>,
+    known_devices: Arc<KnownDevicesCache>,
     media_loader: Arc<Box<dyn MediaLoader>>,
     platform: Arc<Box<dyn PlatformData>>,
     playback_controls: Arc<PlaybackControls>,
@@ -179,6 +270,7 @@ pub struct PopcornFX {
     player_manager: Arc<Box<dyn PlayerManager>>,
     playlist_manager: Arc<PlaylistManager>,
     providers: Arc<ProviderManager>,
+    quality_selector: Arc<QualitySelector>,
     screen_service: Arc<Box<dyn ScreenService>>,
     settings: Arc<ApplicationConfig>,
     subtitle_manager: Arc<Box<dyn SubtitleManager>>,
@@ -211,13 +303,27 @@ impl PopcornFX {
         info!("Creating new popcorn fx instance with {:?}", args);
         let app_directory_path = args.app_directory.as_str();
         let runtime = Arc::new(Self::new_runtime());
-        let event_publisher = Arc::new(EventPublisher::default());
+        let event_publisher = Arc::new(EventPublisher::with_replay_buffer(
+            args.event_replay_buffer_size,
+        ));
+        let crash_reporter = Arc::new(CrashReporter::new(
+            app_directory_path,
+            event_publisher.clone(),
+        ));
+        crash_reporter.install();
+        if let Err(e) = MigrationRunner::new(app_directory_path, data_migrations()).run() {
+            error!("Failed to migrate the application data directory, {}", e);
+        }
         let settings = Arc::new(
             ApplicationConfig::builder()
                 .storage(app_directory_path)
                 .properties(args.properties.clone())
                 .build(),
         );
+        let client_factory = HttpClientFactory::builder()
+            .config(settings.clone())
+            .insecure(args.insecure)
+            .build();
         let cache_manager = Arc::new(
             CacheManager::builder()
                 .runtime(runtime.clone())
@@ -229,14 +335,18 @@ impl PopcornFX {
                 .settings(settings.clone())
                 .with_parser(SubtitleType::Srt, Box::new(SrtParser::default()))
                 .with_parser(SubtitleType::Vtt, Box::new(VttParser::default()))
-                .insecure(args.insecure)
+                .with_parser(
+                    SubtitleType::PlainText,
+                    Box::new(TranscriptParser::default()),
+                )
+                .client_factory(client_factory.clone())
                 .build(),
         ));
         let subtitle_server = Arc::new(SubtitleServer::new(subtitle_provider.clone()));
-        let subtitle_manager = Arc::new(Box::new(DefaultSubtitleManager::new(
-            settings.clone(),
-            event_publisher.clone(),
-        )) as Box<dyn SubtitleManager>);
+        let subtitle_manager = Arc::new(Box::new(
+            DefaultSubtitleManager::new(settings.clone(), event_publisher.clone())
+                .with_tv_mode(args.tv || args.kiosk),
+        ) as Box<dyn SubtitleManager>);
         let platform = Arc::new(Box::new(DefaultPlatform::default()) as Box<dyn PlatformData>);
         let favorites_service =
             Arc::new(Box::new(DefaultFavoriteService::new(app_directory_path))
@@ -247,7 +357,7 @@ impl PopcornFX {
         )) as Box<dyn WatchedService>);
         let providers = Arc::new(Self::default_providers(
             &settings,
-            &args,
+            &client_factory,
             &cache_manager,
             &favorites_service,
             &watched_service,
@@ -256,14 +366,23 @@ impl PopcornFX {
             settings.clone(),
             event_publisher.clone(),
         )) as Box<dyn TorrentManager>);
-        let torrent_stream_server = Arc::new(
-            Box::new(DefaultTorrentStreamServer::default()) as Box<dyn TorrentStreamServer>
-        );
-        let torrent_collection = Arc::new(TorrentCollection::new(app_directory_path));
+        let torrent_stream_server = Arc::new(Box::new(
+            DefaultTorrentStreamServer::builder()
+                .settings(settings.clone())
+                .torrent_manager(torrent_manager.clone())
+                .build(),
+        ) as Box<dyn TorrentStreamServer>);
+        let torrent_collection = Arc::new(TorrentCollection::new(
+            app_directory_path,
+            torrent_manager.clone(),
+        ));
+        let key_value_storage = Arc::new(KeyValueStorage::new(app_directory_path));
+        let known_devices = Arc::new(KnownDevicesCache::new(app_directory_path));
         let auto_resume_service = Arc::new(Box::new(
             DefaultAutoResumeService::builder()
                 .storage_directory(app_directory_path)
                 .event_publisher(event_publisher.clone())
+                .watched_service(watched_service.clone())
                 .build(),
         ) as Box<dyn AutoResumeService>);
         let favorite_cache_updater = Arc::new(
@@ -277,20 +396,20 @@ impl PopcornFX {
             Updater::builder()
                 .settings(settings.clone())
                 .platform(platform.clone())
-                .insecure(args.insecure)
+                .client_factory(client_factory.clone())
                 .data_path(args.data_directory.as_str())
+                .crash_reporter(crash_reporter.clone())
                 .runtime(runtime.clone())
                 .build(),
         );
-        let playback_controls = Arc::new(
-            PlaybackControls::builder()
-                .platform(platform.clone())
-                .event_publisher(event_publisher.clone())
-                .build(),
-        );
-        let image_loader = Arc::new(
-            Box::new(DefaultImageLoader::new(cache_manager.clone())) as Box<dyn ImageLoader>
-        );
+        let image_loader = Arc::new(Box::new(DefaultImageLoader::new(
+            Arc::new(ImageCache::new(
+                app_directory_path,
+                DEFAULT_IMAGE_CACHE_SIZE,
+            )),
+            client_factory.clone(),
+            settings.clone(),
+        )) as Box<dyn ImageLoader>);
         let screen_service =
             Arc::new(Box::new(DefaultScreenService::new()) as Box<dyn ScreenService>);
         let player_manager = Arc::new(Box::new(DefaultPlayerManager::new(
@@ -299,9 +418,29 @@ impl PopcornFX {
             torrent_manager.clone(),
             torrent_stream_server.clone(),
             screen_service.clone(),
+            watched_service.clone(),
+            platform.clone(),
         )) as Box<dyn PlayerManager>);
+        let playback_controls = Arc::new(
+            PlaybackControls::builder()
+                .platform(platform.clone())
+                .event_publisher(event_publisher.clone())
+                .player_manager(player_manager.clone())
+                .build(),
+        );
+        let bandwidth_history = Arc::new(BandwidthHistory::new(app_directory_path));
+        let quality_selector = Arc::new(QualitySelector::new(bandwidth_history.clone()));
+        let torrent_sources = Arc::new(Self::default_torrent_sources(&settings, &client_factory));
         let loading_chain: Vec<Box<dyn LoadingStrategy>> = vec![
-            Box::new(MediaTorrentUrlLoadingStrategy::new()),
+            Box::new(MediaTorrentUrlLoadingStrategy::new(
+                quality_selector.clone(),
+                torrent_sources,
+            )),
+            Box::new(YoutubeLoadingStrategy::new(
+                client_factory.clone(),
+                args.enable_youtube_video_player,
+            )),
+            Box::new(DirectUrlLoadingStrategy::new(client_factory.clone())),
             Box::new(TorrentInfoLoadingStrategy::new(torrent_manager.clone())),
             Box::new(AutoResumeLoadingStrategy::new(auto_resume_service.clone())),
             Box::new(SubtitlesLoadingStrategy::new(
@@ -314,19 +453,31 @@ impl PopcornFX {
             )),
             Box::new(TorrentStreamLoadingStrategy::new(
                 torrent_stream_server.clone(),
+                DownloadNotifier::new(platform.clone(), runtime.clone()),
+                TorrentAutoCollector::new(
+                    torrent_collection.clone(),
+                    settings.clone(),
+                    event_publisher.clone(),
+                    runtime.clone(),
+                ),
+                bandwidth_history.clone(),
             )),
+            Box::new(AudioTracksLoadingStrategy::new()),
             Box::new(TorrentDetailsLoadingStrategy::new(event_publisher.clone())),
             Box::new(PlayerLoadingStrategy::new(player_manager.clone())),
         ];
-        let media_loader =
-            Arc::new(Box::new(DefaultMediaLoader::new(loading_chain)) as Box<dyn MediaLoader>);
+        let media_loader = Arc::new(Box::new(
+            DefaultMediaLoader::new(loading_chain).with_policy(args.loading_policy),
+        ) as Box<dyn MediaLoader>);
         let playlist_manager = Arc::new(PlaylistManager::new(
+            app_directory_path,
             player_manager.clone(),
             event_publisher.clone(),
             media_loader.clone(),
         ));
+        playlist_manager.restore_playlist();
         let tracking_provider = Arc::new(Box::new(
-            TraktProvider::new(settings.clone(), runtime.clone()).unwrap(),
+            TraktProvider::new(settings.clone(), client_factory.clone(), runtime.clone()).unwrap(),
         ) as Box<dyn TrackingProvider>);
         let tracking_sync = Arc::new(
             SyncMediaTracking::builder()
@@ -342,6 +493,8 @@ impl PopcornFX {
                     .runtime(runtime.clone())
                     .player_manager(player_manager.clone())
                     .subtitle_server(subtitle_server.clone())
+                    .settings(settings.clone())
+                    .known_devices(known_devices.clone())
                     .build(),
             )),
             Arc::new(Box::new(
@@ -349,6 +502,7 @@ impl PopcornFX {
                     .runtime(runtime.clone())
                     .player_manager(player_manager.clone())
                     .subtitle_server(subtitle_server.clone())
+                    .known_devices(known_devices.clone())
                     .build(),
             )),
             Arc::new(Box::new(VlcDiscovery::new(
@@ -362,7 +516,7 @@ impl PopcornFX {
         // the application instance creation.
         // The screensaver will be automatically enabled when the platform instance is dropped
         let platform_async = platform.clone();
-        runtime.spawn(async move {
+        Self::spawn_monitored(&runtime, async move {
             if platform_async.disable_screensaver() {
                 info!("Operating System screensaver has been disabled");
             } else {
@@ -373,16 +527,20 @@ impl PopcornFX {
         Self {
             auto_resume_service,
             cache_manager,
+            crash_reporter,
             event_publisher,
             favorite_cache_updater,
             favorites_service,
             image_loader,
+            key_value_storage,
+            known_devices,
             media_loader,
             platform,
             playback_controls,
             player_manager,
             playlist_manager,
             providers,
+            quality_selector,
             screen_service,
             settings,
             subtitle_manager,
@@ -412,17 +570,17 @@ impl PopcornFX {
     }
 
     /// Retrieve the subtitle server instance.
-    pub fn subtitle_server(&mut self) -> &mut Arc<SubtitleServer> {
-        &mut self.subtitle_server
+    pub fn subtitle_server(&self) -> &Arc<SubtitleServer> {
+        &self.subtitle_server
     }
 
     /// Retrieve the subtitle manager instance.
-    pub fn subtitle_manager(&mut self) -> &mut Arc<Box<dyn SubtitleManager>> {
-        &mut self.subtitle_manager
+    pub fn subtitle_manager(&self) -> &Arc<Box<dyn SubtitleManager>> {
+        &self.subtitle_manager
     }
 
     /// The system platform on which the Popcorn FX instance is running.
-    pub fn platform(&mut self) -> &Arc<Box<dyn PlatformData>> {
+    pub fn platform(&self) -> &Arc<Box<dyn PlatformData>> {
         &self.platform
     }
 
@@ -432,32 +590,32 @@ impl PopcornFX {
     }
 
     /// The favorite service of [PopcornFX] which handles all liked items and actions.
-    pub fn favorite_service(&mut self) -> &Arc<Box<dyn FavoriteService>> {
+    pub fn favorite_service(&self) -> &Arc<Box<dyn FavoriteService>> {
         &self.favorites_service
     }
 
     /// The watched service of [PopcornFX] which handles all watched items and actions.
-    pub fn watched_service(&mut self) -> &Arc<Box<dyn WatchedService>> {
+    pub fn watched_service(&self) -> &Arc<Box<dyn WatchedService>> {
         &self.watched_service
     }
 
     /// The torrent manager to create, manage and delete torrents.
-    pub fn torrent_manager(&mut self) -> &Arc<Box<dyn TorrentManager>> {
+    pub fn torrent_manager(&self) -> &Arc<Box<dyn TorrentManager>> {
         &self.torrent_manager
     }
 
     /// The torrent stream server which handles the video streams.
-    pub fn torrent_stream_server(&mut self) -> &Arc<Box<dyn TorrentStreamServer>> {
+    pub fn torrent_stream_server(&self) -> &Arc<Box<dyn TorrentStreamServer>> {
         &self.torrent_stream_server
     }
 
     /// The torrent collection that stores magnet uri info.
-    pub fn torrent_collection(&mut self) -> &Arc<TorrentCollection> {
-        &mut self.torrent_collection
+    pub fn torrent_collection(&self) -> &Arc<TorrentCollection> {
+        &self.torrent_collection
     }
 
     /// The auto-resume service which handles the resume timestamps of videos.
-    pub fn auto_resume_service(&mut self) -> &Arc<Box<dyn AutoResumeService>> {
+    pub fn auto_resume_service(&self) -> &Arc<Box<dyn AutoResumeService>> {
         &self.auto_resume_service
     }
 
@@ -478,15 +636,33 @@ impl PopcornFX {
 
     /// Reload the settings of this instance.
     /// This will read the settings from the storage and notify all subscribers of new changes.
-    pub fn reload_settings(&mut self) {
+    pub fn reload_settings(&self) {
         block_in_place(async { self.settings.reload() })
     }
 
+    /// Retrieve the crash reporter of the FX instance.
+    pub fn crash_reporter(&self) -> &Arc<CrashReporter> {
+        &self.crash_reporter
+    }
+
     /// Retrieve the event publisher of the FX instance.
     pub fn event_publisher(&self) -> &Arc<EventPublisher> {
         &self.event_publisher
     }
 
+    /// Retrieve the buffer of the most recent backend log records.
+    /// It returns [None] when the default logger has not been initialized.
+    pub fn log_buffer(&self) -> Option<&Arc<LogBuffer>> {
+        LOG_BUFFER.get()
+    }
+
+    /// Retrieve the controller of the backend log levels.
+    /// It returns [None] when the default logger has not been initialized
+    /// or has been configured through a `log4rs` config file.
+    pub fn log_controller(&self) -> Option<&LogController> {
+        LOG_CONTROLLER.get()
+    }
+
     /// Retrieve the player manager of the FX instance.
     pub fn player_manager(&self) -> &Arc<Box<dyn PlayerManager>> {
         &self.player_manager
@@ -517,6 +693,21 @@ impl PopcornFX {
         &self.tracking_sync
     }
 
+    /// Retrieve the key-value storage of the FX instance.
+    pub fn key_value_storage(&self) -> &Arc<KeyValueStorage> {
+        &self.key_value_storage
+    }
+
+    /// Retrieve the cache of previously discovered external player devices.
+    pub fn known_devices(&self) -> &Arc<KnownDevicesCache> {
+        &self.known_devices
+    }
+
+    /// Retrieve the quality selector which is used to select the quality of media items.
+    pub fn quality_selector(&self) -> &Arc<QualitySelector> {
+        &self.quality_selector
+    }
+
     /// Retrieve the given runtime pool from this Popcorn FX instance.
     pub fn runtime(&self) -> &Runtime {
         &self.runtime
@@ -532,7 +723,7 @@ impl PopcornFX {
     /// This will start new threads in the background for handling the discovery processes.
     pub fn start_discovery_external_players(&self) {
         let player_discovery_services = self.player_discovery_services.clone();
-        self.runtime.spawn(async move {
+        self.spawn(async move {
             for service in player_discovery_services {
                 if let Err(e) = service.start_discovery().await {
                     error!("Failed to start {}, {}", service, e);
@@ -541,92 +732,201 @@ impl PopcornFX {
         });
     }
 
+    /// Spawn the given future as a new task on the runtime of this instance.
+    /// A panic within the task is passed to the [CrashReporter] by the installed panic hook
+    /// and logged once the task has been aborted, instead of silently being dropped.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Self::spawn_monitored(&self.runtime, future)
+    }
+
+    fn spawn_monitored<F>(runtime: &Runtime, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = runtime.spawn(future);
+        runtime.spawn(async move {
+            if let Err(e) = handle.await {
+                if e.is_panic() {
+                    error!("Runtime task has been aborted, {}", e);
+                }
+            }
+        });
+    }
+
     fn initialize_logger(args: &PopcornFxArgs) {
         INIT.call_once(|| {
-            let config: Config;
-            let root_level = env::var("LOG_LEVEL").unwrap_or("Info".to_string());
             let log_path = env::current_dir()
                 .expect("Home directory should exist")
                 .join(LOG_FILENAME);
 
             if log_path.exists() {
-                match log4rs::config::load_config_file(log_path, Default::default()) {
+                // the log4rs file config is reloaded by log4rs itself when it defines a refresh rate,
+                // so the log levels of the modules can't be controlled at runtime
+                match log4rs::config::load_config_file(log_path, Default::default())
+                    .map(log4rs::init_config)
+                {
                     Err(ex) => panic!("failed to initialize logger through file, {}", ex),
-                    Ok(e) => config = e,
-                };
-            } else {
-                let rolling_file_appender = Self::create_rolling_file_appender(args);
-                let mut config_builder = Config::builder()
-                    .appender(
-                        Appender::builder().build(
-                            CONSOLE_APPENDER,
-                            Box::new(
-                                ConsoleAppender::builder()
-                                    .encoder(Box::new(PatternEncoder::new(LOG_FORMAT_CONSOLE)))
-                                    .build(),
-                            ),
-                        ),
-                    )
-                    .appender(rolling_file_appender);
-
-                for (logger, logging) in args.properties.loggers.iter() {
-                    config_builder = config_builder.logger(Logger::builder().build(
-                        logger,
-                        match LevelFilter::from_str(logging.level.as_str()) {
-                            Ok(e) => e,
-                            Err(e) => {
-                                eprintln!("Failed to parse log level for {}, {}", logger, e);
-                                LevelFilter::Info
-                            }
-                        },
-                    ));
+                    Ok(Err(e)) => eprintln!("Failed to configure logger, {}", e),
+                    Ok(Ok(_)) => info!("Popcorn FX logger has been initialized"),
                 }
-
-                config = config_builder
-                    .build(
-                        Root::builder()
-                            .appender(CONSOLE_APPENDER)
-                            .appender(FILE_APPENDER)
-                            .build(LevelFilter::from_str(root_level.as_str()).unwrap()),
-                    )
-                    .unwrap()
+                return;
             }
 
+            let log_buffer = LOG_BUFFER
+                .get_or_init(|| Arc::new(LogBuffer::new(DEFAULT_LOG_BUFFER_CAPACITY)))
+                .clone();
+            let (config, file_appender_error) =
+                Self::create_logger_config(args, &HashMap::new(), &log_buffer);
+
             match log4rs::init_config(config) {
-                Ok(_) => info!("Popcorn FX logger has been initialized"),
+                Ok(handle) => {
+                    let args = args.clone();
+                    let controller = LogController::new(
+                        handle,
+                        Box::new(move |levels| {
+                            Self::create_logger_config(&args, levels, &log_buffer).0
+                        }),
+                    );
+
+                    let _ = LOG_CONTROLLER.set(controller);
+                    info!("Popcorn FX logger has been initialized")
+                }
                 Err(e) => eprintln!("Failed to configure logger, {}", e),
             }
+            if let Some(e) = file_appender_error {
+                warn!(
+                    "Log file is unavailable, falling back to console logging, {}",
+                    e
+                );
+            }
         });
     }
 
-    fn create_rolling_file_appender(args: &PopcornFxArgs) -> Appender {
-        let log_path = PathBuf::from(args.app_directory.clone())
-            .join(LOG_FILE_DIRECTORY)
-            .join(LOG_FILE_NAME);
+    /// Create the logger config for the given args and runtime log levels of the modules.
+    /// The runtime log levels take precedence over the loggers of the application properties.
+    ///
+    /// It returns the logger config and the reason why the log file is unavailable, if applicable.
+    fn create_logger_config(
+        args: &PopcornFxArgs,
+        levels: &HashMap<String, LevelFilter>,
+        log_buffer: &Arc<LogBuffer>,
+    ) -> (Config, Option<String>) {
+        let mut file_appender_error: Option<String> = None;
+        let root_level = env::var("LOG_LEVEL").unwrap_or("Info".to_string());
+        let root_level = Self::parse_level(root_level.as_str(), LevelFilter::Info);
+        let file_level = args
+            .log_file
+            .level
+            .as_ref()
+            .map(|e| Self::parse_level(e.as_str(), root_level))
+            .unwrap_or(root_level);
+        let rolling_file_appender = if args.log_file.disabled {
+            None
+        } else {
+            match Self::create_rolling_file_appender(args, file_level) {
+                Ok(e) => Some(e),
+                Err(e) => {
+                    file_appender_error = Some(e);
+                    None
+                }
+            }
+        };
+        let mut config_builder = Config::builder()
+            .appender(
+                Appender::builder()
+                    .filter(Box::new(ThresholdFilter::new(root_level)))
+                    .build(
+                        CONSOLE_APPENDER,
+                        Box::new(
+                            ConsoleAppender::builder()
+                                .encoder(Box::new(PatternEncoder::new(LOG_FORMAT_CONSOLE)))
+                                .build(),
+                        ),
+                    ),
+            )
+            .appender(log_buffer.appender());
+        let mut root = Root::builder()
+            .appender(CONSOLE_APPENDER)
+            .appender(LOG_BUFFER_APPENDER);
+        if let Some(appender) = rolling_file_appender {
+            config_builder = config_builder.appender(appender);
+            root = root.appender(FILE_APPENDER);
+        }
+
+        let mut logger_levels: HashMap<String, LevelFilter> = HashMap::new();
+        for (logger, logging) in args.properties.loggers.iter() {
+            logger_levels.insert(
+                logger.clone(),
+                match LevelFilter::from_str(logging.level.as_str()) {
+                    Ok(e) => e,
+                    Err(e) => {
+                        eprintln!("Failed to parse log level for {}, {}", logger, e);
+                        LevelFilter::Info
+                    }
+                },
+            );
+        }
+        logger_levels.extend(levels.iter().map(|(k, v)| (k.clone(), *v)));
+        for (logger, level) in logger_levels {
+            config_builder = config_builder.logger(Logger::builder().build(logger, level));
+        }
+
+        let config = config_builder
+            // the root level should allow the most verbose level of the appenders
+            .build(root.build(root_level.max(file_level)))
+            .unwrap();
+        (config, file_appender_error)
+    }
+
+    fn create_rolling_file_appender(
+        args: &PopcornFxArgs,
+        level: LevelFilter,
+    ) -> Result<Appender, String> {
+        let log_path = args
+            .log_file
+            .path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                PathBuf::from(args.app_directory.clone())
+                    .join(LOG_FILE_DIRECTORY)
+                    .join(LOG_FILE_NAME)
+            });
+        let file_stem = log_path
+            .file_stem()
+            .and_then(|e| e.to_str())
+            .unwrap_or("popcorn-time");
+        let roller_pattern = log_path.with_file_name(format!("{}.{{}}.log", file_stem));
+        let roller = FixedWindowRoller::builder()
+            .base(1)
+            .build(
+                roller_pattern.to_str().unwrap_or("popcorn-time.{}.log"),
+                args.log_file.max_files,
+            )
+            .map_err(|e| format!("invalid log file roller, {}", e))?;
         let policy = CompoundPolicy::new(
-            Box::new(SizeTrigger::new(LOG_FILE_SIZE)),
-            Box::new(
-                FixedWindowRoller::builder()
-                    .base(1)
-                    .build("popcorn-time.{}.log", 5)
-                    .expect("expected the window roller to be valid"),
-            ),
+            Box::new(SizeTrigger::new(args.log_file.max_size)),
+            Box::new(roller),
         );
+        let appender = RollingFileAppender::builder()
+            .encoder(Box::new(PatternEncoder::new(LOG_FORMAT_FILE)))
+            .append(false)
+            .build(log_path.clone(), Box::new(policy))
+            .map_err(|e| format!("unable to write log file {:?}, {}", log_path, e))?;
+
+        Ok(Appender::builder()
+            .filter(Box::new(ThresholdFilter::new(level)))
+            .build(FILE_APPENDER, Box::new(appender)))
+    }
 
-        Appender::builder().build(
-            FILE_APPENDER,
-            Box::new(
-                RollingFileAppender::builder()
-                    .encoder(Box::new(PatternEncoder::new(LOG_FORMAT_FILE)))
-                    .append(false)
-                    .build(log_path.clone(), Box::new(policy))
-                    .map_err(|e| {
-                        eprintln!("Invalid log path {:?}, {}", log_path, e);
-                        e
-                    })
-                    .unwrap(),
-            ),
-        )
+    fn parse_level(level: &str, default: LevelFilter) -> LevelFilter {
+        LevelFilter::from_str(level).unwrap_or_else(|_| {
+            eprintln!("Invalid log level {}, using {} instead", level, default);
+            default
+        })
     }
 
     fn new_runtime() -> Runtime {
@@ -644,21 +944,61 @@ impl PopcornFX {
 
     fn default_providers(
         settings: &Arc<ApplicationConfig>,
-        args: &PopcornFxArgs,
+        client_factory: &HttpClientFactory,
         cache_manager: &Arc<CacheManager>,
         favorites: &Arc<Box<dyn FavoriteService>>,
         watched: &Arc<Box<dyn WatchedService>>,
     ) -> ProviderManager {
-        let movie_provider = Box::new(MovieProvider::new(
-            settings.clone(),
-            cache_manager.clone(),
-            args.insecure,
-        ));
-        let show_provider = Box::new(ShowProvider::new(
-            settings.clone(),
-            cache_manager.clone(),
-            args.insecure,
-        ));
+        let properties = settings.properties();
+        let is_popcorn_provider = |name: &str| {
+            properties
+                .provider(name)
+                .map(|e| e.provider_type == ProviderType::Popcorn)
+                .unwrap_or(false)
+        };
+        let mut builder = ProviderManager::builder();
+
+        // custom catalog providers are registered first, so they overrule the default providers
+        for (name, provider_properties) in properties
+            .providers
+            .iter()
+            .filter(|(_, e)| e.provider_type == ProviderType::Catalog)
+        {
+            for category in provider_properties.categories(name) {
+                if category == Category::Favorites {
+                    warn!("Catalog provider {} cannot provide {}", name, category);
+                    continue;
+                }
+
+                debug!("Registering catalog provider {} for {}", name, category);
+                let provider =
+                    CatalogProvider::new(category, provider_properties, client_factory.clone());
+                builder = builder
+                    .with_provider(Box::new(provider.clone()))
+                    .with_details_provider(Box::new(provider));
+            }
+        }
+        if is_popcorn_provider(Category::Movies.name().as_str()) {
+            let movie_provider = Box::new(MovieProvider::new(
+                settings.clone(),
+                cache_manager.clone(),
+                client_factory.clone(),
+            ));
+            builder = builder
+                .with_provider(movie_provider.clone())
+                .with_details_provider(movie_provider);
+        }
+        if is_popcorn_provider(Category::Series.name().as_str()) {
+            let show_provider = Box::new(ShowProvider::new(
+                settings.clone(),
+                cache_manager.clone(),
+                client_factory.clone(),
+            ));
+            builder = builder
+                .with_provider(show_provider.clone())
+                .with_details_provider(show_provider);
+        }
+
         let favorites_provider =
             Box::new(FavoritesProvider::new(favorites.clone(), watched.clone()));
         let thumb_enhancer = Box::new(ThumbEnhancer::new(
@@ -669,17 +1009,36 @@ impl PopcornFX {
                 .expect("expected the tvdb properties to be present")
                 .clone(),
             cache_manager.clone(),
+            client_factory.clone(),
         ));
 
-        ProviderManager::builder()
-            .with_provider(movie_provider.clone())
-            .with_provider(show_provider.clone())
+        builder
             .with_provider(favorites_provider)
-            .with_details_provider(movie_provider)
-            .with_details_provider(show_provider)
             .with_enhancer(thumb_enhancer)
+            .with_properties(&properties)
             .build()
     }
+
+    fn default_torrent_sources(
+        settings: &Arc<ApplicationConfig>,
+        client_factory: &HttpClientFactory,
+    ) -> TorrentSourceAggregator {
+        let properties = settings.properties();
+        let providers: Vec<Box<dyn TorrentSourceProvider>> = properties
+            .torrent_sources
+            .iter()
+            .map(|(name, source_properties)| {
+                debug!("Registering torrent source {}", name);
+                Box::new(TorrentioSourceProvider::new(
+                    name,
+                    source_properties.clone(),
+                    client_factory.clone(),
+                )) as Box<dyn TorrentSourceProvider>
+            })
+            .collect();
+
+        TorrentSourceAggregator::new(providers)
+    }
 }
 
 impl Default for PopcornFX {
@@ -709,7 +1068,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut popcorn_fx = PopcornFX::new(default_args(temp_path));
+        let popcorn_fx = PopcornFX::new(default_args(temp_path));
 
         let _ = popcorn_fx.platform().info();
         let _ = popcorn_fx.subtitle_server();
@@ -727,7 +1086,7 @@ mod test {
         let id = "tt00000021544";
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut popcorn_fx = PopcornFX::new(default_args(temp_path));
+        let popcorn_fx = PopcornFX::new(default_args(temp_path));
 
         let result = popcorn_fx.favorite_service().is_liked(id);
 
@@ -740,7 +1099,7 @@ mod test {
         let filename = "something-totally_random123qwe.mp4";
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut popcorn_fx = PopcornFX::new(default_args(temp_path));
+        let popcorn_fx = PopcornFX::new(default_args(temp_path));
 
         let result = popcorn_fx
             .auto_resume_service()
@@ -754,7 +1113,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut popcorn_fx = PopcornFX::new(default_args(temp_path));
+        let popcorn_fx = PopcornFX::new(default_args(temp_path));
 
         let result = popcorn_fx
             .torrent_collection()
@@ -769,7 +1128,7 @@ mod test {
         let temp_dir = tempdir().expect("expected a temp dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
         let (tx, rx) = channel();
-        let mut popcorn_fx = PopcornFX::new(default_args(temp_path));
+        let popcorn_fx = PopcornFX::new(default_args(temp_path));
         copy_test_file(temp_path, "settings.json", None);
 
         let mutex = popcorn_fx.settings();
@@ -792,6 +1151,10 @@ mod test {
             app_directory: temp_path.to_string(),
             data_directory: temp_path.to_string(),
             disable_logger: false,
+            log_file: LogFileArgs {
+                level: Some("debug".to_string()),
+                ..Default::default()
+            },
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -800,6 +1163,8 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             properties: PopcornProperties {
                 loggers: HashMap::from([
                     (
@@ -818,6 +1183,7 @@ mod test {
                 update_channel: String::new(),
                 providers: Default::default(),
                 enhancers: Default::default(),
+                torrent_sources: Default::default(),
                 subtitle: Default::default(),
                 tracking: Default::default(),
             },
@@ -826,4 +1192,71 @@ mod test {
         // should not panic on the invalid level
         PopcornFX::initialize_logger(&args);
     }
+
+    #[test]
+    fn test_create_rolling_file_appender() {
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut args = default_args(temp_path);
+        args.log_file.path = Some(
+            temp_dir
+                .path()
+                .join("custom")
+                .join("fx.log")
+                .to_str()
+                .unwrap()
+                .to_string(),
+        );
+
+        let result = PopcornFX::create_rolling_file_appender(&args, LevelFilter::Debug);
+
+        assert!(
+            result.is_ok(),
+            "expected the appender to have been created, but got {:?} instead",
+            result.err()
+        );
+        assert!(temp_dir.path().join("custom").join("fx.log").exists());
+    }
+
+    #[test]
+    fn test_create_rolling_file_appender_not_writable() {
+        let temp_dir = tempdir().expect("expected a temp dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let mut args = default_args(temp_path);
+        std::fs::write(temp_dir.path().join("logs"), "").unwrap();
+        args.log_file.path = Some(
+            temp_dir
+                .path()
+                .join("logs")
+                .join("popcorn-time.log")
+                .to_str()
+                .unwrap()
+                .to_string(),
+        );
+
+        let result = PopcornFX::create_rolling_file_appender(&args, LevelFilter::Info);
+
+        assert!(
+            result.is_err(),
+            "expected the appender creation to have failed"
+        );
+    }
+
+    #[test]
+    fn test_parse_loading_policy() {
+        assert_eq!(
+            Ok(LoadingPolicy::CancelActive),
+            parse_loading_policy(LoadingPolicy::default().to_string().as_str())
+        );
+        assert_eq!(Ok(LoadingPolicy::Concurrent), parse_loading_policy("concurrent"));
+        assert_eq!(
+            Ok(LoadingPolicy::CancelActive),
+            parse_loading_policy("cancel-active")
+        );
+        assert_eq!(Ok(LoadingPolicy::Queue), parse_loading_policy("Queue"));
+        assert!(
+            parse_loading_policy("lorem").is_err(),
+            "expected an invalid policy to be rejected"
+        );
+    }
 }
@@ -1,13 +1,16 @@
 extern crate core;
 
 use std::os::raw::c_char;
+use std::path::Path;
 use std::{mem, ptr};
 
 use log::{debug, error, info, trace, warn};
 
 pub use fx::*;
+pub use logging::*;
 use popcorn_fx_core::core::config::{
-    PlaybackSettings, ServerSettings, SubtitleSettings, TorrentSettings, UiSettings,
+    NetworkSettings, PlaybackSettings, ServerSettings, SettingsSection, SubtitleSettings,
+    TorrentSettings, UiSettings,
 };
 use popcorn_fx_core::core::media::favorites::FavoriteCallback;
 use popcorn_fx_core::core::media::watched::WatchedCallback;
@@ -15,6 +18,7 @@ use popcorn_fx_core::core::media::*;
 use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
 use popcorn_fx_core::core::subtitles::matcher::SubtitleMatcher;
 use popcorn_fx_core::core::subtitles::model::SubtitleInfo;
+use popcorn_fx_core::core::torrents::collection::{CollectionSort, TorrentCollectionEvent};
 use popcorn_fx_core::{
     from_c_into_boxed, from_c_owned, from_c_string, from_c_vec, into_c_owned, into_c_string,
 };
@@ -25,6 +29,7 @@ use crate::ffi::*;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 mod fx;
+mod logging;
 
 /// Retrieve the available subtitles for the given [MovieDetailsC].
 ///
@@ -34,7 +39,7 @@ mod fx;
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `movie` - A reference to the `MovieDetailsC` for which subtitles are to be retrieved.
 ///
 /// # Returns
@@ -43,7 +48,7 @@ mod fx;
 /// <i>The returned reference should be managed by the caller.</i>
 #[no_mangle]
 pub extern "C" fn movie_subtitles(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     movie: &MovieDetailsC,
 ) -> *mut SubtitleInfoSet {
     let movie_instance = MovieDetails::from(movie);
@@ -75,7 +80,7 @@ pub extern "C" fn movie_subtitles(
 ///
 /// # Arguments
 ///
-/// * `popcorn_fx` - A mutable reference to the `PopcornFX` instance.
+/// * `popcorn_fx` - A reference to the `PopcornFX` instance.
 /// * `show` - A reference to the `ShowDetailsC` containing information about the show.
 /// * `episode` - A reference to the `EpisodeC` for which subtitles are to be retrieved.
 ///
@@ -85,7 +90,7 @@ pub extern "C" fn movie_subtitles(
 /// <i>The returned reference should be managed by the caller.</i>
 #[no_mangle]
 pub extern "C" fn episode_subtitles(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     show: &ShowDetailsC,
     episode: &EpisodeC,
 ) -> *mut SubtitleInfoSet {
@@ -114,7 +119,7 @@ pub extern "C" fn episode_subtitles(
 /// Retrieve the available subtitles for the given filename
 #[no_mangle]
 pub extern "C" fn filename_subtitles(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     filename: *mut c_char,
 ) -> *mut SubtitleInfoSet {
     let filename_rust = from_c_string(filename);
@@ -142,9 +147,7 @@ pub extern "C" fn filename_subtitles(
 ///
 /// It returns the preferred subtitle language.
 #[no_mangle]
-pub extern "C" fn retrieve_preferred_subtitle_language(
-    popcorn_fx: &mut PopcornFX,
-) -> SubtitleLanguage {
+pub extern "C" fn retrieve_preferred_subtitle_language(popcorn_fx: &PopcornFX) -> SubtitleLanguage {
     popcorn_fx.subtitle_manager().preferred_language()
 }
 
@@ -152,14 +155,14 @@ pub extern "C" fn retrieve_preferred_subtitle_language(
 ///
 /// It returns true when the subtitle track should be disabled, else false.
 #[no_mangle]
-pub extern "C" fn is_subtitle_disabled(popcorn_fx: &mut PopcornFX) -> bool {
+pub extern "C" fn is_subtitle_disabled(popcorn_fx: &PopcornFX) -> bool {
     popcorn_fx.subtitle_manager().is_disabled()
 }
 
 /// Update the preferred subtitle for the [Media] item playback.
 /// This action will reset any custom configured subtitle files.
 #[no_mangle]
-pub extern "C" fn update_subtitle(popcorn_fx: &mut PopcornFX, subtitle: &SubtitleInfoC) {
+pub extern "C" fn update_subtitle(popcorn_fx: &PopcornFX, subtitle: &SubtitleInfoC) {
     popcorn_fx
         .subtitle_manager()
         .update_subtitle(SubtitleInfo::from(subtitle))
@@ -169,7 +172,7 @@ pub extern "C" fn update_subtitle(popcorn_fx: &mut PopcornFX, subtitle: &Subtitl
 /// This action will reset any preferred subtitle.
 #[no_mangle]
 pub extern "C" fn update_subtitle_custom_file(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     custom_filepath: *mut c_char,
 ) {
     let custom_filepath = from_c_string(custom_filepath);
@@ -183,7 +186,7 @@ pub extern "C" fn update_subtitle_custom_file(
 /// Disable the subtitle track on request of the user.
 /// This will make the [is_subtitle_disabled] return `true`.
 #[no_mangle]
-pub extern "C" fn disable_subtitle(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn disable_subtitle(popcorn_fx: &PopcornFX) {
     trace!("Disabling the subtitle track");
     popcorn_fx.subtitle_manager().disable_subtitle()
 }
@@ -191,7 +194,7 @@ pub extern "C" fn disable_subtitle(popcorn_fx: &mut PopcornFX) {
 /// Reset the current preferred subtitle configuration.
 /// This will remove any selected [SubtitleInfo] or custom subtitle file.
 #[no_mangle]
-pub extern "C" fn reset_subtitle(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn reset_subtitle(popcorn_fx: &PopcornFX) {
     popcorn_fx.subtitle_manager().reset()
 }
 
@@ -200,7 +203,7 @@ pub extern "C" fn reset_subtitle(popcorn_fx: &mut PopcornFX) {
 /// It returns the filepath to the subtitle on success, else [ptr::null_mut].
 #[no_mangle]
 pub extern "C" fn download(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     subtitle: &SubtitleInfoC,
     matcher: SubtitleMatcherC,
 ) -> *mut c_char {
@@ -233,7 +236,7 @@ pub extern "C" fn download(
 /// It returns the [SubtitleC] reference on success, else [ptr::null_mut].
 #[no_mangle]
 pub extern "C" fn download_and_parse_subtitle(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     subtitle: &SubtitleInfoC,
     matcher: SubtitleMatcherC,
 ) -> *mut SubtitleC {
@@ -262,10 +265,48 @@ pub extern "C" fn download_and_parse_subtitle(
     }
 }
 
+/// Preview the given subtitle info by retrieving the first cues of the best matching subtitle file.
+///
+/// It returns the [SubtitleCueSet] reference on success, else [ptr::null_mut].
+#[no_mangle]
+pub extern "C" fn preview_subtitle(
+    popcorn_fx: &PopcornFX,
+    subtitle: &SubtitleInfoC,
+    matcher: SubtitleMatcherC,
+    cue_count: i32,
+) -> *mut SubtitleCueSet {
+    trace!(
+        "Previewing subtitle from C for info: {:?}, matcher: {:?}, cue_count: {}",
+        subtitle,
+        matcher,
+        cue_count
+    );
+    let subtitle_info = SubtitleInfo::from(subtitle);
+    let matcher = SubtitleMatcher::from(matcher);
+
+    match popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.subtitle_provider().preview(
+            &subtitle_info,
+            &matcher,
+            cue_count.max(0) as usize,
+        )) {
+        Ok(e) => {
+            let result = SubtitleCueSet::from(e);
+            debug!("Returning subtitle preview {:?}", result);
+            into_c_owned(result)
+        }
+        Err(e) => {
+            error!("Failed to preview subtitle, {}", e);
+            ptr::null_mut()
+        }
+    }
+}
+
 /// Reset all available api stats for the movie api.
 /// This will make all disabled api's available again.
 #[no_mangle]
-pub extern "C" fn reset_show_apis(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn reset_show_apis(popcorn_fx: &PopcornFX) {
     popcorn_fx.providers().reset_api(&Category::Series)
 }
 
@@ -274,7 +315,7 @@ pub extern "C" fn reset_show_apis(popcorn_fx: &mut PopcornFX) {
 ///
 /// It will return false if all fields in the [MediaItemC] are [ptr::null_mut].
 #[no_mangle]
-pub extern "C" fn is_media_liked(popcorn_fx: &mut PopcornFX, favorite: &mut MediaItemC) -> bool {
+pub extern "C" fn is_media_liked(popcorn_fx: &PopcornFX, favorite: &mut MediaItemC) -> bool {
     trace!("Verifying if media is liked for {:?}", favorite);
     match favorite.as_identifier() {
         None => {
@@ -299,7 +340,7 @@ pub extern "C" fn is_media_liked(popcorn_fx: &mut PopcornFX, favorite: &mut Medi
 ///
 /// It will return an array of favorites on success, else [ptr::null_mut].
 #[no_mangle]
-pub extern "C" fn retrieve_all_favorites(popcorn_fx: &mut PopcornFX) -> *mut VecFavoritesC {
+pub extern "C" fn retrieve_all_favorites(popcorn_fx: &PopcornFX) -> *mut VecFavoritesC {
     match popcorn_fx.favorite_service().all() {
         Ok(e) => favorites_to_c(e),
         Err(e) => {
@@ -312,7 +353,7 @@ pub extern "C" fn retrieve_all_favorites(popcorn_fx: &mut PopcornFX) -> *mut Vec
 /// Add the media item to the favorites.
 /// Duplicate favorite media items are ignored.
 #[no_mangle]
-pub extern "C" fn add_to_favorites(popcorn_fx: &mut PopcornFX, favorite: &MediaItemC) {
+pub extern "C" fn add_to_favorites(popcorn_fx: &PopcornFX, favorite: &MediaItemC) {
     let media: Box<dyn MediaIdentifier>;
 
     if !favorite.movie_overview.is_null() {
@@ -350,7 +391,7 @@ pub extern "C" fn add_to_favorites(popcorn_fx: &mut PopcornFX, favorite: &MediaI
 
 /// Remove the media item from favorites.
 #[no_mangle]
-pub extern "C" fn remove_from_favorites(popcorn_fx: &mut PopcornFX, favorite: &MediaItemC) {
+pub extern "C" fn remove_from_favorites(popcorn_fx: &PopcornFX, favorite: &MediaItemC) {
     match favorite.as_identifier() {
         None => error!("Unable to remove favorite, all FavoriteC fields are null"),
         Some(e) => popcorn_fx.favorite_service().remove(e),
@@ -360,7 +401,7 @@ pub extern "C" fn remove_from_favorites(popcorn_fx: &mut PopcornFX, favorite: &M
 /// Register a new callback listener for favorite events.
 #[no_mangle]
 pub extern "C" fn register_favorites_event_callback<'a>(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: extern "C" fn(FavoriteEventC),
 ) {
     trace!("Wrapping C callback for FavoriteCallback");
@@ -375,7 +416,7 @@ pub extern "C" fn register_favorites_event_callback<'a>(
 ///
 /// It returns true when the item is watched, else false.
 #[no_mangle]
-pub extern "C" fn is_media_watched(popcorn_fx: &mut PopcornFX, watchable: &MediaItemC) -> bool {
+pub extern "C" fn is_media_watched(popcorn_fx: &PopcornFX, watchable: &MediaItemC) -> bool {
     match watchable.as_identifier() {
         Some(media) => {
             let media_id = media.to_string();
@@ -396,7 +437,7 @@ pub extern "C" fn is_media_watched(popcorn_fx: &mut PopcornFX, watchable: &Media
 ///
 /// It returns an array of watched id's.
 #[no_mangle]
-pub extern "C" fn retrieve_all_watched(popcorn_fx: &mut PopcornFX) -> StringArray {
+pub extern "C" fn retrieve_all_watched(popcorn_fx: &PopcornFX) -> StringArray {
     trace!("Retrieving all watched media id's");
     match popcorn_fx.watched_service().all() {
         Ok(e) => {
@@ -414,7 +455,7 @@ pub extern "C" fn retrieve_all_watched(popcorn_fx: &mut PopcornFX) -> StringArra
 ///
 /// It returns an array of watched movie id's.
 #[no_mangle]
-pub extern "C" fn retrieve_watched_movies(popcorn_fx: &mut PopcornFX) -> *mut StringArray {
+pub extern "C" fn retrieve_watched_movies(popcorn_fx: &PopcornFX) -> *mut StringArray {
     match popcorn_fx.watched_service().watched_movies() {
         Ok(e) => {
             debug!("Retrieved watched items {:?}", &e);
@@ -431,7 +472,7 @@ pub extern "C" fn retrieve_watched_movies(popcorn_fx: &mut PopcornFX) -> *mut St
 ///
 /// It returns  an array of watched show id's.
 #[no_mangle]
-pub extern "C" fn retrieve_watched_shows(popcorn_fx: &mut PopcornFX) -> *mut StringArray {
+pub extern "C" fn retrieve_watched_shows(popcorn_fx: &PopcornFX) -> *mut StringArray {
     match popcorn_fx.watched_service().watched_shows() {
         Ok(e) => {
             debug!("Retrieved watched items {:?}", &e);
@@ -446,7 +487,7 @@ pub extern "C" fn retrieve_watched_shows(popcorn_fx: &mut PopcornFX) -> *mut Str
 
 /// Add the given media item to the watched list.
 #[no_mangle]
-pub extern "C" fn add_to_watched(popcorn_fx: &mut PopcornFX, watchable: &MediaItemC) {
+pub extern "C" fn add_to_watched(popcorn_fx: &PopcornFX, watchable: &MediaItemC) {
     match watchable.as_identifier() {
         Some(e) => {
             let id = e.imdb_id().to_string();
@@ -463,7 +504,7 @@ pub extern "C" fn add_to_watched(popcorn_fx: &mut PopcornFX, watchable: &MediaIt
 
 /// Remove the given media item from the watched list.
 #[no_mangle]
-pub extern "C" fn remove_from_watched(popcorn_fx: &mut PopcornFX, watchable: &MediaItemC) {
+pub extern "C" fn remove_from_watched(popcorn_fx: &PopcornFX, watchable: &MediaItemC) {
     match watchable.as_identifier() {
         Some(e) => popcorn_fx.watched_service().remove(e),
         None => {
@@ -475,7 +516,7 @@ pub extern "C" fn remove_from_watched(popcorn_fx: &mut PopcornFX, watchable: &Me
 /// Register a new callback listener for watched events.
 #[no_mangle]
 pub extern "C" fn register_watched_event_callback<'a>(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: extern "C" fn(WatchedEventC),
 ) {
     trace!("Wrapping C callback for WatchedCallback");
@@ -489,7 +530,7 @@ pub extern "C" fn register_watched_event_callback<'a>(
 /// Verify if the given magnet uri has already been stored.
 #[no_mangle]
 pub extern "C" fn torrent_collection_is_stored(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     magnet_uri: *mut c_char,
 ) -> bool {
     let magnet_uri = from_c_string(magnet_uri);
@@ -505,7 +546,7 @@ pub extern "C" fn torrent_collection_is_stored(
 /// Retrieve all stored magnets from the torrent collection.
 /// It returns the set on success, else [ptr::null_mut].
 #[no_mangle]
-pub extern "C" fn torrent_collection_all(popcorn_fx: &mut PopcornFX) -> *mut TorrentCollectionSet {
+pub extern "C" fn torrent_collection_all(popcorn_fx: &PopcornFX) -> *mut TorrentCollectionSet {
     trace!("Retrieving torrent collection magnets");
     match popcorn_fx.torrent_collection().all() {
         Ok(e) => {
@@ -522,7 +563,7 @@ pub extern "C" fn torrent_collection_all(popcorn_fx: &mut PopcornFX) -> *mut Tor
 /// Add the given magnet info to the torrent collection.
 #[no_mangle]
 pub extern "C" fn torrent_collection_add(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     name: *mut c_char,
     magnet_uri: *mut c_char,
 ) {
@@ -537,17 +578,88 @@ pub extern "C" fn torrent_collection_add(
 
 /// Remove the given magnet uri from the torrent collection.
 #[no_mangle]
-pub extern "C" fn torrent_collection_remove(popcorn_fx: &mut PopcornFX, magnet_uri: *mut c_char) {
+pub extern "C" fn torrent_collection_remove(popcorn_fx: &PopcornFX, magnet_uri: *mut c_char) {
     let magnet_uri = from_c_string(magnet_uri);
     trace!("Removing magnet {} from torrent collection", magnet_uri);
 
     popcorn_fx.torrent_collection().remove(magnet_uri.as_str());
 }
 
+/// Search the stored magnets of the torrent collection of which the name contains the given query.
+/// It returns the set of matching magnets in the given sort order on success, else [ptr::null_mut].
+#[no_mangle]
+pub extern "C" fn torrent_collection_search(
+    popcorn_fx: &PopcornFX,
+    query: *mut c_char,
+    sort: CollectionSort,
+) -> *mut TorrentCollectionSet {
+    let query = from_c_string(query);
+    trace!(
+        "Searching torrent collection for \"{}\" sorted by {}",
+        query,
+        sort
+    );
+
+    match popcorn_fx.torrent_collection().search(query.as_str(), sort) {
+        Ok(e) => into_c_owned(TorrentCollectionSet::from(e)),
+        Err(e) => {
+            error!("Failed to search torrent collection, {}", e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Retrieve the stored magnet of the torrent collection with its resolved torrent metadata.
+/// The metadata is only resolved the first time, after which the stored metadata is returned.
+///
+/// It returns the magnet info on success, else [ptr::null_mut].
+#[no_mangle]
+pub extern "C" fn torrent_collection_resolve(
+    popcorn_fx: &PopcornFX,
+    magnet_uri: *mut c_char,
+) -> *mut MagnetInfoC {
+    let magnet_uri = from_c_string(magnet_uri);
+    trace!("Resolving torrent collection magnet {}", magnet_uri);
+
+    match popcorn_fx
+        .runtime()
+        .block_on(popcorn_fx.torrent_collection().resolve(magnet_uri.as_str()))
+    {
+        Ok(e) => into_c_owned(MagnetInfoC::from(e)),
+        Err(e) => {
+            error!("Failed to resolve magnet {}, {}", magnet_uri, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Mark the given magnet uri of the torrent collection as used.
+#[no_mangle]
+pub extern "C" fn torrent_collection_mark_used(popcorn_fx: &PopcornFX, magnet_uri: *mut c_char) {
+    let magnet_uri = from_c_string(magnet_uri);
+    trace!("Marking torrent collection magnet {} as used", magnet_uri);
+
+    popcorn_fx
+        .torrent_collection()
+        .mark_used(magnet_uri.as_str());
+}
+
+/// Register a new callback listener for the torrent collection events.
+#[no_mangle]
+pub extern "C" fn register_torrent_collection_callback(
+    popcorn_fx: &PopcornFX,
+    callback: extern "C" fn(TorrentCollectionEvent),
+) {
+    trace!("Registering new C callback for torrent collection events");
+    popcorn_fx
+        .torrent_collection()
+        .subscribe(Box::new(move |event| callback(event)));
+}
+
 /// Retrieve the application settings.
 /// These are the setting preferences of the users for the popcorn FX instance.
 #[no_mangle]
-pub extern "C" fn application_settings(popcorn_fx: &mut PopcornFX) -> *mut PopcornSettingsC {
+pub extern "C" fn application_settings(popcorn_fx: &PopcornFX) -> *mut PopcornSettingsC {
     trace!("Retrieving application settings");
     let mutex = popcorn_fx.settings();
     into_c_owned(PopcornSettingsC::from(mutex.user_settings()))
@@ -555,7 +667,7 @@ pub extern "C" fn application_settings(popcorn_fx: &mut PopcornFX) -> *mut Popco
 
 /// Reload the settings of the application.
 #[no_mangle]
-pub extern "C" fn reload_settings(popcorn_fx: &mut PopcornFX) {
+pub extern "C" fn reload_settings(popcorn_fx: &PopcornFX) {
     trace!("Reloading the popcorn fx settings");
     popcorn_fx.reload_settings()
 }
@@ -563,7 +675,7 @@ pub extern "C" fn reload_settings(popcorn_fx: &mut PopcornFX) {
 /// Register a new callback for all setting events.
 #[no_mangle]
 pub extern "C" fn register_settings_callback(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     callback: ApplicationConfigCallbackC,
 ) {
     trace!("Registering application settings callback");
@@ -579,7 +691,7 @@ pub extern "C" fn register_settings_callback(
 /// Update the subtitle settings with the new value.
 #[no_mangle]
 pub extern "C" fn update_subtitle_settings(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     subtitle_settings: SubtitleSettingsC,
 ) {
     trace!(
@@ -593,7 +705,7 @@ pub extern "C" fn update_subtitle_settings(
 /// Update the torrent settings with the new value.
 #[no_mangle]
 pub extern "C" fn update_torrent_settings(
-    popcorn_fx: &mut PopcornFX,
+    popcorn_fx: &PopcornFX,
     torrent_settings: TorrentSettingsC,
 ) {
     trace!("Updating the torrent settings from {:?}", torrent_settings);
@@ -603,7 +715,7 @@ pub extern "C" fn update_torrent_settings(
 
 /// Update the ui settings with the new value.
 #[no_mangle]
-pub extern "C" fn update_ui_settings(popcorn_fx: &mut PopcornFX, settings: UiSettingsC) {
+pub extern "C" fn update_ui_settings(popcorn_fx: &PopcornFX, settings: UiSettingsC) {
     trace!("Updating the ui settings from {:?}", settings);
     let settings = UiSettings::from(settings);
     popcorn_fx.settings().update_ui(settings);
@@ -611,23 +723,107 @@ pub extern "C" fn update_ui_settings(popcorn_fx: &mut PopcornFX, settings: UiSet
 
 /// Update the server settings with the new value.
 #[no_mangle]
-pub extern "C" fn update_server_settings(popcorn_fx: &mut PopcornFX, settings: ServerSettingsC) {
+pub extern "C" fn update_server_settings(popcorn_fx: &PopcornFX, settings: ServerSettingsC) {
     trace!("Updating the server settings from {:?}", settings);
-    let settings = ServerSettings::from(settings);
+    let mut settings = ServerSettings::from(settings);
+    // the provider uri overrides are not part of the C settings and are managed separately
+    settings.provider_uris = popcorn_fx
+        .settings()
+        .user_settings_ref()
+        .server()
+        .provider_uris
+        .clone();
     popcorn_fx.settings().update_server(settings);
 }
 
 /// Update the playback settings with the new value.
 #[no_mangle]
-pub extern "C" fn update_playback_settings(
-    popcorn_fx: &mut PopcornFX,
-    settings: PlaybackSettingsC,
-) {
+pub extern "C" fn update_playback_settings(popcorn_fx: &PopcornFX, settings: PlaybackSettingsC) {
     trace!("Updating the playback settings from {:?}", settings);
     let settings = PlaybackSettings::from(settings);
     popcorn_fx.settings().update_playback(settings);
 }
 
+/// Update the network settings with the new value.
+#[no_mangle]
+pub extern "C" fn update_network_settings(popcorn_fx: &PopcornFX, settings: NetworkSettingsC) {
+    trace!("Updating the network settings from {:?}", settings);
+    let settings = NetworkSettings::from(settings);
+    popcorn_fx.settings().update_network(settings);
+}
+
+/// Export the current application settings to the given file path.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `path` - The path of the file to which the settings should be written.
+///
+/// # Returns
+///
+/// It returns `true` when the settings have been exported, else `false`.
+#[no_mangle]
+pub extern "C" fn export_settings(popcorn_fx: &PopcornFX, path: *mut c_char) -> bool {
+    let path = from_c_string(path);
+    trace!("Exporting the application settings to {}", path);
+    match popcorn_fx.settings().export(Path::new(path.as_str())) {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Failed to export the application settings, {}", e);
+            false
+        }
+    }
+}
+
+/// Import the application settings from the given file path.
+/// The current settings remain unchanged when the file couldn't be read or contains invalid fields.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `path` - The path of the file from which the settings should be read.
+///
+/// # Returns
+///
+/// It returns the [SettingsImportResultC] of the import, which should be disposed through [dispose_settings_import_result].
+#[no_mangle]
+pub extern "C" fn import_settings(
+    popcorn_fx: &PopcornFX,
+    path: *mut c_char,
+) -> SettingsImportResultC {
+    let path = from_c_string(path);
+    trace!("Importing the application settings from {}", path);
+    let result = popcorn_fx.settings().import(Path::new(path.as_str()));
+    if let Err(e) = &result {
+        warn!("Failed to import the application settings, {}", e);
+    }
+
+    SettingsImportResultC::from(result)
+}
+
+/// Reset the given section of the application settings to its default values.
+///
+/// # Arguments
+///
+/// * `popcorn_fx` - A reference to the PopcornFX instance.
+/// * `section` - The settings section to reset.
+#[no_mangle]
+pub extern "C" fn reset_settings_section(popcorn_fx: &PopcornFX, section: SettingsSection) {
+    trace!("Resetting the {} settings from C", section);
+    popcorn_fx.settings().reset_section(section);
+}
+
+/// Dispose of the given settings import result.
+///
+/// # Arguments
+///
+/// * `result` - The settings import result to dispose.
+#[no_mangle]
+pub extern "C" fn dispose_settings_import_result(result: SettingsImportResultC) {
+    trace!("Disposing settings import result {:?}", result);
+    drop(result);
+}
+
 /// Dispose of a C-compatible MediaItemC value wrapped in a Box.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible MediaItemC value
@@ -669,6 +865,12 @@ pub extern "C" fn dispose_torrent_collection(collection_set: Box<TorrentCollecti
     trace!("Disposing collection set {:?}", collection_set)
 }
 
+/// Dispose the [MagnetInfoC] from memory.
+#[no_mangle]
+pub extern "C" fn dispose_magnet_info(info: Box<MagnetInfoC>) {
+    trace!("Disposing magnet info {:?}", info)
+}
+
 /// Dispose of a C-compatible favorites collection.
 ///
 /// This function is responsible for cleaning up resources associated with a C-compatible favorites collection.
@@ -690,10 +892,11 @@ pub extern "C" fn dispose_favorites(favorites: Box<VecFavoritesC>) {
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
+    use std::thread;
 
     use tempfile::tempdir;
 
-    use popcorn_fx_core::core::config::{DecorationType, SubtitleFamily};
+    use popcorn_fx_core::core::config::{DecorationType, SubtitleFamily, TvModeSubtitle};
     use popcorn_fx_core::core::subtitles::language::SubtitleLanguage;
     use popcorn_fx_core::from_c_owned;
     use popcorn_fx_core::testing::{copy_test_file, init_logger};
@@ -708,6 +911,7 @@ mod test {
     pub fn default_args(temp_path: &str) -> PopcornFxArgs {
         PopcornFxArgs {
             disable_logger: true,
+            log_file: Default::default(),
             disable_mouse: false,
             enable_youtube_video_player: false,
             enable_fx_video_player: false,
@@ -716,6 +920,8 @@ mod test {
             maximized: false,
             kiosk: false,
             insecure: false,
+            event_replay_buffer_size: 0,
+            loading_policy: Default::default(),
             app_directory: temp_path.to_string(),
             data_directory: PathBuf::from(temp_path)
                 .join("data")
@@ -756,7 +962,7 @@ mod test {
     fn test_is_liked_movie_overview() {
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let movie = MovieOverview::new(
             "".to_string(),
             "tt0000000122".to_string(),
@@ -764,7 +970,7 @@ mod test {
         );
         let mut media = MediaItemC::from(movie);
 
-        let result = is_media_liked(&mut instance, &mut media);
+        let result = is_media_liked(&instance, &mut media);
 
         assert_eq!(false, result)
     }
@@ -773,7 +979,7 @@ mod test {
     fn test_is_liked_movie_details() {
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let movie = MovieDetails::new(
             "".to_string(),
             "tt0000000111".to_string(),
@@ -781,11 +987,50 @@ mod test {
         );
         let mut media = MediaItemC::from(movie);
 
-        let result = is_media_liked(&mut instance, &mut media);
+        let result = is_media_liked(&instance, &mut media);
 
         assert_eq!(false, result)
     }
 
+    #[test]
+    fn test_favorites_concurrent_access() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        thread::scope(|s| {
+            for thread_index in 0..8 {
+                let instance = &instance;
+                s.spawn(move || {
+                    for index in 0..25 {
+                        let movie = MovieOverview::new(
+                            format!("Movie {}", index),
+                            format!("tt{:04}{:04}", thread_index, index),
+                            "2020".to_string(),
+                        );
+                        let mut media = MediaItemC::from(movie);
+
+                        add_to_favorites(instance, &media);
+                        assert_eq!(true, is_media_liked(instance, &mut media));
+
+                        let favorites = retrieve_all_favorites(instance);
+                        assert!(
+                            !favorites.is_null(),
+                            "expected the favorites to have been retrieved"
+                        );
+                        dispose_favorites(from_c_into_boxed(favorites));
+                        dispose_media_item(Box::new(media));
+                    }
+                });
+            }
+        });
+
+        let favorites = from_c_into_boxed(retrieve_all_favorites(&instance));
+        assert_eq!(200, favorites.movies_len);
+        dispose_favorites(favorites);
+    }
+
     #[test]
     fn test_update_subtitle() {
         let language1 = SubtitleLanguage::Finnish;
@@ -802,24 +1047,22 @@ mod test {
         let info_c2 = SubtitleInfoC::from(subtitle2.clone());
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        update_subtitle(&mut instance, &info_c1);
-        let info_result =
-            SubtitleInfo::from(&from_c_owned(retrieve_preferred_subtitle(&mut instance)));
-        let language_result = retrieve_preferred_subtitle_language(&mut instance);
+        update_subtitle(&instance, &info_c1);
+        let info_result = SubtitleInfo::from(&from_c_owned(retrieve_preferred_subtitle(&instance)));
+        let language_result = retrieve_preferred_subtitle_language(&instance);
         assert_eq!(subtitle1, info_result);
         assert_eq!(language1, language_result);
 
-        update_subtitle(&mut instance, &info_c2);
-        let info_result =
-            SubtitleInfo::from(&from_c_owned(retrieve_preferred_subtitle(&mut instance)));
-        let language_result = retrieve_preferred_subtitle_language(&mut instance);
+        update_subtitle(&instance, &info_c2);
+        let info_result = SubtitleInfo::from(&from_c_owned(retrieve_preferred_subtitle(&instance)));
+        let language_result = retrieve_preferred_subtitle_language(&instance);
         assert_eq!(subtitle2, info_result);
         assert_eq!(language2, language_result);
 
-        reset_subtitle(&mut instance);
-        let preferred_result = retrieve_preferred_subtitle_language(&mut instance);
+        reset_subtitle(&instance);
+        let preferred_result = retrieve_preferred_subtitle_language(&instance);
         assert_eq!(SubtitleLanguage::None, preferred_result);
     }
 
@@ -828,16 +1071,16 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        let disabled = is_subtitle_disabled(&mut instance);
+        let disabled = is_subtitle_disabled(&instance);
         assert!(
             !disabled,
             "expected the subtitle track to be enabled by default"
         );
 
-        disable_subtitle(&mut instance);
-        let result = is_subtitle_disabled(&mut instance);
+        disable_subtitle(&instance);
+        let result = is_subtitle_disabled(&instance);
 
         assert!(result, "expected the subtitle track to be disabled")
     }
@@ -847,11 +1090,10 @@ mod test {
         let magnet_uri = "magnet:?MagnetA";
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         copy_test_file(temp_path, "torrent-collection.json", None);
 
-        let result =
-            torrent_collection_is_stored(&mut instance, into_c_string(magnet_uri.to_string()));
+        let result = torrent_collection_is_stored(&instance, into_c_string(magnet_uri.to_string()));
 
         assert_eq!(true, result)
     }
@@ -861,14 +1103,72 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         copy_test_file(temp_path, "torrent-collection.json", None);
 
-        let result = from_c_owned(torrent_collection_all(&mut instance));
+        let result = from_c_owned(torrent_collection_all(&instance));
 
         assert_eq!(1, result.len)
     }
 
+    #[test]
+    fn test_torrent_collection_search() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        copy_test_file(temp_path, "torrent-collection.json", None);
+
+        let result = from_c_owned(torrent_collection_search(
+            &instance,
+            into_c_string("magnet".to_string()),
+            CollectionSort::Name,
+        ));
+        assert_eq!(1, result.len);
+
+        let result = from_c_owned(torrent_collection_search(
+            &instance,
+            into_c_string("lorem".to_string()),
+            CollectionSort::AddedAt,
+        ));
+        assert_eq!(0, result.len);
+    }
+
+    #[test]
+    fn test_torrent_collection_mark_used() {
+        init_logger();
+        let magnet_uri = "magnet:?MagnetA";
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        copy_test_file(temp_path, "torrent-collection.json", None);
+
+        torrent_collection_mark_used(&instance, into_c_string(magnet_uri.to_string()));
+
+        let set = from_c_owned(torrent_collection_all(&instance));
+        let result = from_c_vec(set.magnets, set.len);
+        assert_ne!(0, result[0].last_used_at);
+    }
+
+    #[test]
+    fn test_register_torrent_collection_callback() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+
+        extern "C" fn callback(_: TorrentCollectionEvent) {}
+
+        register_torrent_collection_callback(&instance, callback);
+        torrent_collection_add(
+            &instance,
+            into_c_string("lorem".to_string()),
+            into_c_string(
+                "magnet:?xt=urn:btih:EADAF0EFEA39406914414D359E0EA16416409BD7".to_string(),
+            ),
+        );
+    }
+
     #[test]
     fn test_register_settings_callback() {
         init_logger();
@@ -883,10 +1183,10 @@ mod test {
             None,
             None,
         ));
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
 
-        register_settings_callback(&mut instance, settings_callback);
-        update_subtitle_settings(&mut instance, subtitle_c);
+        register_settings_callback(&instance, settings_callback);
+        update_subtitle_settings(&instance, subtitle_c);
     }
 
     #[test]
@@ -894,7 +1194,7 @@ mod test {
         init_logger();
         let temp_dir = tempdir().expect("expected a tempt dir to be created");
         let temp_path = temp_dir.path().to_str().unwrap();
-        let mut instance = PopcornFX::new(default_args(temp_path));
+        let instance = PopcornFX::new(default_args(temp_path));
         let settings = SubtitleSettings {
             directory: format!("{}/subtitles", temp_path),
             auto_cleaning_enabled: false,
@@ -903,15 +1203,118 @@ mod test {
             font_size: 32,
             decoration: DecorationType::SeeThroughBackground,
             bold: true,
+            max_line_length: 0,
+            max_lines: 2,
+            tv_mode_subtitle: TvModeSubtitle::InterfaceLanguage,
+            api_key: None,
+            user_token: None,
         };
 
-        update_subtitle_settings(&mut instance, SubtitleSettingsC::from(&settings));
+        update_subtitle_settings(&instance, SubtitleSettingsC::from(&settings));
         let config = instance.settings().user_settings();
         let result = config.subtitle();
 
         assert_eq!(&settings, result)
     }
 
+    #[test]
+    fn test_update_network_settings() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let instance = PopcornFX::new(default_args(temp_path));
+        let settings = NetworkSettings {
+            proxy: Some("http://localhost:3128".to_string()),
+            proxy_username: Some("user".to_string()),
+            proxy_password: Some("secret".to_string()),
+            proxy_bypass: vec!["localhost".to_string()],
+            user_agent: None,
+            request_timeout: 60,
+            send_crash_reports: true,
+        };
+
+        update_network_settings(&instance, NetworkSettingsC::from(&settings));
+        let config = instance.settings().user_settings();
+        let result = config.network();
+
+        assert_eq!(&settings, result)
+    }
+
+    #[test]
+    fn test_export_import_settings() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = temp_dir.path().join("export.json");
+        let instance = PopcornFX::new(default_args(temp_path));
+        let settings = ServerSettings {
+            api_server: Some("http://localhost:9090".to_string()),
+            provider_uris: Default::default(),
+        };
+
+        instance.settings().update_server(settings.clone());
+        let result = export_settings(
+            &instance,
+            into_c_string(filepath.to_str().unwrap().to_string()),
+        );
+        assert_eq!(true, result, "expected the settings to have been exported");
+
+        reset_settings_section(&instance, SettingsSection::Server);
+        assert_eq!(
+            ServerSettings::default(),
+            instance.settings().user_settings().server_settings
+        );
+
+        let result = import_settings(
+            &instance,
+            into_c_string(filepath.to_str().unwrap().to_string()),
+        );
+        match &result {
+            SettingsImportResultC::Ok => {}
+            _ => assert!(
+                false,
+                "expected SettingsImportResultC::Ok, got {:?}",
+                result
+            ),
+        }
+        assert_eq!(
+            settings,
+            instance.settings().user_settings().server_settings
+        );
+        dispose_settings_import_result(result);
+    }
+
+    #[test]
+    fn test_import_settings_invalid_fields() {
+        init_logger();
+        let temp_dir = tempdir().expect("expected a tempt dir to be created");
+        let temp_path = temp_dir.path().to_str().unwrap();
+        let filepath = temp_dir.path().join("import.json");
+        let instance = PopcornFX::new(default_args(temp_path));
+        std::fs::write(&filepath, r#"{"server_settings":{"lorem":"ipsum"}}"#).unwrap();
+
+        let result = import_settings(
+            &instance,
+            into_c_string(filepath.to_str().unwrap().to_string()),
+        );
+
+        match &result {
+            SettingsImportResultC::InvalidFields(fields) => {
+                let fields = from_c_vec(fields.values, fields.len)
+                    .into_iter()
+                    .map(|e| from_c_string(e))
+                    .collect::<Vec<String>>();
+                assert_eq!(vec!["server_settings.lorem".to_string()], fields);
+            }
+            _ => assert!(
+                false,
+                "expected SettingsImportResultC::InvalidFields, got {:?}",
+                result
+            ),
+        }
+        dispose_settings_import_result(result);
+    }
+
     #[test]
     fn test_dispose_media_item() {
         let movie = MovieOverview::new(String::new(), String::from("tt54698542"), String::new());
@@ -0,0 +1,401 @@
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+use log::{info, Level, LevelFilter, Record};
+use log4rs::append::Append;
+use log4rs::config::Appender;
+use log4rs::Config;
+
+/// The default number of log records which are retained by the [LogBuffer].
+pub const DEFAULT_LOG_BUFFER_CAPACITY: usize = 500;
+/// The name of the [LogBuffer] appender within the logger config.
+pub const LOG_BUFFER_APPENDER: &str = "buffer";
+/// The target prefix of the log records which originate from the frontend.
+const FRONTEND_TARGET_PREFIX: &str = "jvm::";
+
+thread_local! {
+    /// Indicates if the current thread is forwarding a log record to the subscriber.
+    /// This prevents a subscriber which logs from the callback from deadlocking the [LogBuffer].
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The callback which receives the forwarded backend log records.
+pub type LogRecordCallback = Box<dyn Fn(LogRecord) + Send + Sync>;
+
+/// The factory which creates the logger config for the given log levels of the modules.
+pub type LoggerConfigFactory = Box<dyn Fn(&HashMap<String, LevelFilter>) -> Config + Send + Sync>;
+
+/// A log record of the backend which is forwarded to the log subscriber.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// The unix timestamp in millis at which the record has been logged.
+    pub timestamp: i64,
+    /// The level of the record.
+    pub level: Level,
+    /// The target (module) of the record.
+    pub target: String,
+    /// The formatted message of the record.
+    pub message: String,
+}
+
+impl From<&Record<'_>> for LogRecord {
+    fn from(value: &Record<'_>) -> Self {
+        Self {
+            timestamp: Utc::now().timestamp_millis(),
+            level: value.level(),
+            target: value.target().to_string(),
+            message: value.args().to_string(),
+        }
+    }
+}
+
+/// The bounded ring buffer of the most recent backend log records.
+///
+/// The buffer is registered as appender of the `log4rs` logger, so it receives each record
+/// which passes the log level of its module. Records are retained within the buffer and forwarded
+/// to the subscriber, if one is present. Records of the frontend are ignored, as the frontend
+/// is the one subscribing to the backend logs.
+pub struct LogBuffer {
+    capacity: usize,
+    records: Mutex<VecDeque<LogRecord>>,
+    subscriber: Mutex<Option<Arc<LogRecordCallback>>>,
+}
+
+impl LogBuffer {
+    /// Create a new log buffer which retains at most the given number of records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+            subscriber: Default::default(),
+        }
+    }
+
+    /// Retrieve the retained log records, ordered from oldest to newest.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Subscribe to the backend log records, replacing the current subscriber if any.
+    /// The retained records are replayed to the subscriber before new records are forwarded.
+    pub fn subscribe(&self, callback: LogRecordCallback) {
+        // the subscriber lock is held during the replay, so records which are pushed in the meantime
+        // are forwarded after the replay instead of being lost or replayed twice
+        let mut subscriber = self.subscriber.lock().unwrap();
+        let records = self.records();
+
+        Self::forward(|| {
+            for record in records {
+                callback(record);
+            }
+        });
+        *subscriber = Some(Arc::new(callback));
+    }
+
+    /// Remove the current subscriber of the backend log records.
+    pub fn unsubscribe(&self) {
+        *self.subscriber.lock().unwrap() = None;
+    }
+
+    /// Create a new `log4rs` appender for this buffer.
+    pub fn appender(self: &Arc<Self>) -> Appender {
+        Appender::builder().build(
+            LOG_BUFFER_APPENDER,
+            Box::new(LogBufferAppender {
+                buffer: self.clone(),
+            }),
+        )
+    }
+
+    fn push(&self, record: LogRecord) {
+        // records which are logged by the subscriber itself are only retained
+        if FORWARDING.with(|e| e.get()) {
+            self.retain(record);
+            return;
+        }
+
+        let callback = {
+            let subscriber = self.subscriber.lock().unwrap();
+            self.retain(record.clone());
+            subscriber.clone()
+        };
+
+        // the callback is invoked outside the subscriber lock,
+        // so a slow subscriber doesn't block the other logging threads
+        if let Some(callback) = callback {
+            Self::forward(|| callback(record));
+        }
+    }
+
+    fn retain(&self, record: LogRecord) {
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    fn forward<F: FnOnce()>(action: F) {
+        FORWARDING.with(|e| e.set(true));
+        action();
+        FORWARDING.with(|e| e.set(false));
+    }
+}
+
+impl Debug for LogBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogBuffer")
+            .field("capacity", &self.capacity)
+            .field("records", &self.records.lock().map(|e| e.len()).ok())
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+struct LogBufferAppender {
+    buffer: Arc<LogBuffer>,
+}
+
+impl Append for LogBufferAppender {
+    fn append(&self, record: &Record) -> anyhow::Result<()> {
+        if !record.target().starts_with(FRONTEND_TARGET_PREFIX) {
+            self.buffer.push(LogRecord::from(record));
+        }
+        Ok(())
+    }
+
+    fn flush(&self) {}
+}
+
+/// The controller of the `log4rs` logger which changes the log levels of modules at runtime,
+/// without requiring the process to be restarted.
+///
+/// Each change rebuilds the logger config through the [LoggerConfigFactory] with the updated levels.
+pub struct LogController {
+    levels: Mutex<HashMap<String, LevelFilter>>,
+    factory: LoggerConfigFactory,
+    applier: Box<dyn Fn(Config) + Send + Sync>,
+}
+
+impl LogController {
+    /// Create a new controller for the given `log4rs` logger handle.
+    pub fn new(handle: log4rs::Handle, factory: LoggerConfigFactory) -> Self {
+        Self::with_applier(factory, Box::new(move |config| handle.set_config(config)))
+    }
+
+    fn with_applier(
+        factory: LoggerConfigFactory,
+        applier: Box<dyn Fn(Config) + Send + Sync>,
+    ) -> Self {
+        Self {
+            levels: Default::default(),
+            factory,
+            applier,
+        }
+    }
+
+    /// Set the log level of the given module.
+    /// The level applies to the module and all its submodules, unless they have a level of their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The module path, e.g. `popcorn_fx_torrent`.
+    /// * `level` - The new log level of the module.
+    pub fn set_level(&self, target: &str, level: LevelFilter) {
+        {
+            let mut levels = self.levels.lock().unwrap();
+            levels.insert(target.to_string(), level);
+            (self.applier)((self.factory)(&levels));
+        }
+
+        info!("Log level of {} has been changed to {}", target, level);
+    }
+
+    /// Retrieve the log levels of the modules which have been changed at runtime.
+    pub fn levels(&self) -> HashMap<String, LevelFilter> {
+        self.levels.lock().unwrap().clone()
+    }
+}
+
+impl Debug for LogController {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogController")
+            .field("levels", &self.levels)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use log::Log;
+    use log4rs::config::{Logger, Root};
+
+    use super::*;
+
+    #[test]
+    fn test_log_buffer_capacity() {
+        let buffer = Arc::new(LogBuffer::new(2));
+        let appender = LogBufferAppender {
+            buffer: buffer.clone(),
+        };
+
+        for message in ["lorem", "ipsum", "dolor"] {
+            appender
+                .append(
+                    &Record::builder()
+                        .args(format_args!("{}", message))
+                        .level(Level::Info)
+                        .target("popcorn_fx")
+                        .build(),
+                )
+                .unwrap();
+        }
+
+        let result: Vec<String> = buffer.records().into_iter().map(|e| e.message).collect();
+        assert_eq!(vec!["ipsum".to_string(), "dolor".to_string()], result);
+    }
+
+    #[test]
+    fn test_log_buffer_ignore_frontend_records() {
+        let buffer = Arc::new(LogBuffer::new(10));
+        let appender = LogBufferAppender {
+            buffer: buffer.clone(),
+        };
+
+        appender
+            .append(
+                &Record::builder()
+                    .args(format_args!("lorem"))
+                    .level(Level::Info)
+                    .target("jvm::com.github.yoep.popcorn")
+                    .build(),
+            )
+            .unwrap();
+
+        assert_eq!(Vec::<LogRecord>::new(), buffer.records());
+    }
+
+    #[test]
+    fn test_log_buffer_subscribe() {
+        let buffer = Arc::new(LogBuffer::new(10));
+        let received = Arc::new(Mutex::new(Vec::<String>::new()));
+        let appender = LogBufferAppender {
+            buffer: buffer.clone(),
+        };
+        let append = |message: &str| {
+            appender
+                .append(
+                    &Record::builder()
+                        .args(format_args!("{}", message))
+                        .level(Level::Warn)
+                        .target("popcorn_fx_core")
+                        .build(),
+                )
+                .unwrap()
+        };
+
+        append("lorem");
+        let callback_received = received.clone();
+        buffer.subscribe(Box::new(move |record| {
+            callback_received.lock().unwrap().push(record.message);
+        }));
+        append("ipsum");
+        buffer.unsubscribe();
+        append("dolor");
+
+        let result = received.lock().unwrap().clone();
+        assert_eq!(vec!["lorem".to_string(), "ipsum".to_string()], result);
+    }
+
+    #[test]
+    fn test_log_buffer_unsubscribe_from_callback() {
+        let buffer = Arc::new(LogBuffer::new(10));
+        let received = Arc::new(Mutex::new(Vec::<String>::new()));
+        let appender = LogBufferAppender {
+            buffer: buffer.clone(),
+        };
+        let append = |message: &str| {
+            appender
+                .append(
+                    &Record::builder()
+                        .args(format_args!("{}", message))
+                        .level(Level::Warn)
+                        .target("popcorn_fx_core")
+                        .build(),
+                )
+                .unwrap()
+        };
+
+        let callback_buffer = buffer.clone();
+        let callback_received = received.clone();
+        buffer.subscribe(Box::new(move |record| {
+            callback_received.lock().unwrap().push(record.message);
+            callback_buffer.unsubscribe();
+        }));
+        append("lorem");
+        append("ipsum");
+
+        let result = received.lock().unwrap().clone();
+        assert_eq!(vec!["lorem".to_string()], result);
+    }
+
+    #[test]
+    fn test_log_controller_set_level() {
+        let buffer = Arc::new(LogBuffer::new(10));
+        let logger: Arc<Mutex<Option<log4rs::Logger>>> = Default::default();
+        let factory_buffer = buffer.clone();
+        let applier_logger = logger.clone();
+        let controller = LogController::with_applier(
+            Box::new(move |levels| {
+                levels
+                    .iter()
+                    .fold(
+                        Config::builder().appender(factory_buffer.appender()),
+                        |builder, (name, level)| {
+                            builder.logger(Logger::builder().build(name, *level))
+                        },
+                    )
+                    .build(
+                        Root::builder()
+                            .appender(LOG_BUFFER_APPENDER)
+                            .build(LevelFilter::Info),
+                    )
+                    .unwrap()
+            }),
+            Box::new(move |config| {
+                *applier_logger.lock().unwrap() = Some(log4rs::Logger::new(config));
+            }),
+        );
+        let log_torrent_record = || {
+            logger.lock().unwrap().as_ref().unwrap().log(
+                &Record::builder()
+                    .args(format_args!("lorem"))
+                    .level(Level::Debug)
+                    .target("popcorn_fx_torrent::torrent::session")
+                    .build(),
+            )
+        };
+
+        controller.set_level("popcorn_fx_core", LevelFilter::Trace);
+        log_torrent_record();
+        assert_eq!(
+            0,
+            buffer.records().len(),
+            "expected the debug record to not have been forwarded"
+        );
+
+        controller.set_level("popcorn_fx_torrent", LevelFilter::Debug);
+        log_torrent_record();
+        let result = buffer.records();
+        assert_eq!(1, result.len());
+        assert_eq!(Level::Debug, result[0].level);
+        assert_eq!("popcorn_fx_torrent::torrent::session", result[0].target);
+        assert_eq!(
+            Some(&LevelFilter::Debug),
+            controller.levels().get("popcorn_fx_torrent")
+        );
+    }
+}
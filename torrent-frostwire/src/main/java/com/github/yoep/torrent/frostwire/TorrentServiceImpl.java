@@ -1,6 +1,9 @@
 package com.github.yoep.torrent.frostwire;
 
+import com.frostwire.jlibtorrent.AddTorrentParams;
 import com.frostwire.jlibtorrent.Priority;
+import com.frostwire.jlibtorrent.StorageMode;
+import com.frostwire.jlibtorrent.TorrentFlags;
 import com.frostwire.jlibtorrent.TorrentHandle;
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
@@ -33,20 +36,24 @@ import java.util.concurrent.atomic.AtomicReference;
 public class TorrentServiceImpl implements TorrentService {
     private final TorrentSessionManager sessionManager;
     private final TorrentResolverService torrentResolverService;
+    private final TorrentSettingsService torrentSettingsService;
     private final FxLib fxLib;
     private final PopcornFx instance;
     private final ExecutorService executorService;
     private final ResolveTorrentInfoCallback resolveTorrentInfoCallback = createResolveTorrentInfoCallback();
     private final ResolveTorrentCallback resolveTorrentCallback = createResolveTorrentCallback();
     private final CancelTorrentCallback cancelTorrentCallback = createCancelTorrentCallback();
+    private final PauseTorrentCallback pauseTorrentCallback = createPauseTorrentCallback();
 
     private final List<com.github.yoep.popcorn.backend.adapters.torrent.TorrentInfoWrapper> torrentInfos = new ArrayList<>();
     private final List<TorrentWrapper> torrentWrappers = new ArrayList<>();
     private final Map<Handle, StreamListenerHolder> torrentStreamCallbacks = new HashMap<>();
 
-    public TorrentServiceImpl(TorrentSessionManager sessionManager, TorrentResolverService torrentResolverService, FxLib fxLib, PopcornFx instance, ExecutorService executorService) {
+    public TorrentServiceImpl(TorrentSessionManager sessionManager, TorrentResolverService torrentResolverService, TorrentSettingsService torrentSettingsService,
+                              FxLib fxLib, PopcornFx instance, ExecutorService executorService) {
         this.sessionManager = sessionManager;
         this.torrentResolverService = torrentResolverService;
+        this.torrentSettingsService = torrentSettingsService;
         this.fxLib = fxLib;
         this.instance = instance;
         this.executorService = executorService;
@@ -222,6 +229,21 @@ public class TorrentServiceImpl implements TorrentService {
         fxLib.cleanup_torrents_directory(instance);
     }
 
+    @Override
+    public void pauseAll() {
+        fxLib.pause_all_torrents(instance);
+    }
+
+    @Override
+    public void resumeAll() {
+        fxLib.resume_all_torrents(instance);
+    }
+
+    @Override
+    public boolean isPaused() {
+        return fxLib.is_torrents_paused(instance) == 1;
+    }
+
     //endregion
 
     //region Functions
@@ -230,6 +252,19 @@ public class TorrentServiceImpl implements TorrentService {
         fxLib.torrent_resolve_info_callback(instance, resolveTorrentInfoCallback);
         fxLib.register_torrent_resolve_callback(instance, resolveTorrentCallback);
         fxLib.torrent_cancel_callback(instance, cancelTorrentCallback);
+        fxLib.torrent_pause_callback(instance, pauseTorrentCallback);
+    }
+
+    private AddTorrentParams createAddTorrentParams(TorrentInfoWrapper torrentInfo, File torrentDirectory, Priority[] priorities) {
+        var params = AddTorrentParams.createInstance();
+        params.torrentInfo(torrentInfo.getNative());
+        params.savePath(torrentDirectory.getAbsolutePath());
+        params.filePriorities(priorities);
+        // the torrent is managed by the application and not by the session queue
+        params.flags(params.flags().and_(TorrentFlags.AUTO_MANAGED.inv()));
+        // the files are allocated by the disk thread of the session, which doesn't block the torrent creation
+        params.storageMode(torrentSettingsService.isPreallocate() ? StorageMode.STORAGE_MODE_ALLOCATE : StorageMode.STORAGE_MODE_SPARSE);
+        return params;
     }
 
     private TorrentHandle internalCreateTorrentHandle(TorrentFileInfo torrentFile, File torrentDirectory) {
@@ -267,7 +302,7 @@ public class TorrentServiceImpl implements TorrentService {
         sessionManager.addListener(creationListener);
 
         // start the creation of the torrent by downloading it
-        session.download(torrentInfo.getNative(), torrentDirectory, null, priorities, null);
+        session.swig().async_add_torrent(createAddTorrentParams(torrentInfo, torrentDirectory, priorities).swig());
 
         // pause this thread and wait for the torrent to be created
         synchronized (this) {
@@ -352,6 +387,20 @@ public class TorrentServiceImpl implements TorrentService {
                 .ifPresent(this::remove);
     }
 
+    private PauseTorrentCallback createPauseTorrentCallback() {
+        return (handle, paused) -> torrentWrappers.stream()
+                .filter(e -> Objects.equals(e.getHandle(), handle))
+                .findFirst()
+                .map(TorrentWrapper::getTorrent)
+                .ifPresent(torrent -> {
+                    if (paused == 1) {
+                        torrent.pause();
+                    } else {
+                        torrent.resume();
+                    }
+                });
+    }
+
     //endregion
 
     private record StreamListenerHolder(Handle streamHandle, TorrentStreamListener listener, TorrentStreamEventCallback callback) {
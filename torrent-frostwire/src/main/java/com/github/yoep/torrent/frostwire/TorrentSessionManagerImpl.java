@@ -1,6 +1,11 @@
 package com.github.yoep.torrent.frostwire;
 
 import com.frostwire.jlibtorrent.*;
+import com.frostwire.jlibtorrent.swig.bdecode_node;
+import com.frostwire.jlibtorrent.swig.error_code;
+import com.frostwire.jlibtorrent.swig.libtorrent;
+import com.frostwire.jlibtorrent.swig.session_handle;
+import com.github.yoep.popcorn.backend.BackendConstants;
 import com.github.yoep.popcorn.backend.adapters.torrent.InvalidTorrentSessionStateException;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentException;
 import com.github.yoep.popcorn.backend.adapters.torrent.state.SessionState;
@@ -9,17 +14,31 @@ import javafx.beans.property.ReadOnlyObjectWrapper;
 import lombok.extern.slf4j.Slf4j;
 
 import javax.annotation.PreDestroy;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.Objects;
+import java.util.Optional;
 
 @Slf4j
 public class TorrentSessionManagerImpl implements TorrentSessionManager {
     public static final String STATE_PROPERTY = "state";
+    static final String DHT_STATE_FILENAME = "dht-state.dat";
 
     private final ReadOnlyObjectWrapper<SessionState> state = new ReadOnlyObjectWrapper<>(this, STATE_PROPERTY, SessionState.CREATING);
 
+    private final Path dhtStateFile;
+
     private SessionManager session;
     private TorrentException error;
 
     public TorrentSessionManagerImpl() {
+        this(Path.of(System.getProperty("user.home"), BackendConstants.POPCORN_HOME_DIRECTORY, DHT_STATE_FILENAME));
+    }
+
+    TorrentSessionManagerImpl(Path dhtStateFile) {
+        Objects.requireNonNull(dhtStateFile, "dhtStateFile cannot be null");
+        this.dhtStateFile = dhtStateFile;
         init();
     }
 
@@ -91,7 +110,9 @@ public class TorrentSessionManagerImpl implements TorrentSessionManager {
         state.set(SessionState.INITIALIZING);
 
         try {
-            var sessionParams = new SessionParams();
+            var sessionParams = readDhtState(dhtStateFile)
+                    .map(TorrentSessionManagerImpl::createSessionParams)
+                    .orElseGet(SessionParams::new);
             session = new SessionManager();
 
             log.trace("Starting torrent session");
@@ -127,14 +148,69 @@ public class TorrentSessionManagerImpl implements TorrentSessionManager {
 
     @PreDestroy
     public void onDestroy() {
-        if (session != null)
+        if (session != null) {
+            saveDhtState();
             session.stop();
+        }
     }
 
     //endregion
 
     //region Functions
 
+    private void saveDhtState() {
+        try {
+            // only the DHT nodes are stored, the session settings are always applied from the application settings
+            var state = new SessionHandle(session.swig()).saveState(session_handle.save_dht_state);
+            writeDhtState(dhtStateFile, state);
+            log.debug("Stored torrent DHT state in {}", dhtStateFile);
+        } catch (Exception ex) {
+            log.warn("Failed to store torrent DHT state, {}", ex.getMessage(), ex);
+        }
+    }
+
+    private static SessionParams createSessionParams(byte[] state) {
+        var buffer = Vectors.bytes2byte_vector(state);
+        var node = new bdecode_node();
+        var error = new error_code();
+
+        if (bdecode_node.bdecode(buffer, node, error) != 0) {
+            log.warn("Failed to decode the stored torrent DHT state, {}", error.message());
+            return new SessionParams();
+        }
+
+        var params = libtorrent.read_session_params(node, session_handle.save_dht_state);
+        buffer.clear(); // keeps the decoded buffer alive until the state has been read
+        var dhtState = params.getDht_state();
+        if (dhtState.getNodes().empty() && dhtState.getNodes6().empty()) {
+            log.debug("Stored torrent DHT state contains no nodes, bootstrapping from the default routers");
+            return new SessionParams();
+        }
+
+        // unreachable stored nodes time out and are evicted from the routing table,
+        // the default routers are still used to bootstrap next to the stored nodes
+        log.debug("Bootstrapping torrent DHT from {} stored nodes", dhtState.getNodes().size() + dhtState.getNodes6().size());
+        return new SessionParams(params);
+    }
+
+    static Optional<byte[]> readDhtState(Path path) {
+        try {
+            if (Files.isRegularFile(path)) {
+                return Optional.of(Files.readAllBytes(path))
+                        .filter(e -> e.length > 0);
+            }
+        } catch (IOException ex) {
+            log.warn("Failed to read torrent DHT state {}, {}", path, ex.getMessage(), ex);
+        }
+
+        return Optional.empty();
+    }
+
+    static void writeDhtState(Path path, byte[] state) throws IOException {
+        Files.createDirectories(path.getParent());
+        Files.write(path, state);
+    }
+
     private void checkSessionState() {
         var state = getState();
 
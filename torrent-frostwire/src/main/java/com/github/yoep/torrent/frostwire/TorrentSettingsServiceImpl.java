@@ -1,19 +1,53 @@
 package com.github.yoep.torrent.frostwire;
 
 import com.frostwire.jlibtorrent.SettingsPack;
+import com.frostwire.jlibtorrent.swig.address;
+import com.frostwire.jlibtorrent.swig.dht_settings;
+import com.frostwire.jlibtorrent.swig.error_code;
+import com.frostwire.jlibtorrent.swig.ip_filter;
+import com.frostwire.jlibtorrent.swig.settings_pack;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentSettingsService;
 import com.github.yoep.popcorn.backend.adapters.torrent.state.SessionState;
+import com.github.yoep.popcorn.backend.settings.models.EncryptionMode;
+import com.github.yoep.torrent.frostwire.model.IpRange;
 import javafx.beans.value.ChangeListener;
 import lombok.extern.slf4j.Slf4j;
 
+import java.io.IOException;
+import java.net.Inet6Address;
+import java.net.InetAddress;
+import java.net.NetworkInterface;
+import java.net.SocketException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
 import java.util.Objects;
 
 @Slf4j
 public class TorrentSettingsServiceImpl implements TorrentSettingsService {
+    static final int LISTEN_PORT = 6881;
+    static final String IPV4_LISTEN_INTERFACE = "0.0.0.0:" + LISTEN_PORT;
+    static final String IPV6_LISTEN_INTERFACE = "[::]:" + LISTEN_PORT;
+    static final int MIN_PEER_CONNECT_TIMEOUT = 1;
+    static final int MAX_PEER_CONNECT_TIMEOUT = 120;
+    static final int MIN_REQUEST_TIMEOUT = 1;
+    static final int MIN_MAX_METADATA_SIZE = 1024 * 1024;
+    static final int MAX_MAX_METADATA_SIZE = 64 * 1024 * 1024;
+    static final int MIN_MAX_REQUEST_QUEUE_DEPTH = 1;
+    static final int MAX_MAX_REQUEST_QUEUE_DEPTH = 5000;
+
     private final SettingsPack settings = defaultSettings();
     private final ChangeListener<SessionState> sessionListener = createSessionListener();
     private final TorrentSessionManager sessionManager;
 
+    private List<IpRange> ipFilter = Collections.emptyList();
+    private InetAddress bindAddress;
+    private boolean dualStack = true;
+    private boolean preallocate;
+    private boolean dhtReadOnly;
+
     public TorrentSettingsServiceImpl(TorrentSessionManager sessionManager) {
         Objects.requireNonNull(sessionManager, "sessionManager cannot be null");
         this.sessionManager = sessionManager;
@@ -43,6 +77,150 @@ public class TorrentSettingsServiceImpl implements TorrentSettingsService {
         return this;
     }
 
+    @Override
+    public TorrentSettingsService bindInterface(InetAddress address) {
+        if (address != null) {
+            // the address is always bound, even when unavailable, to prevent the traffic from leaking over another interface
+            if (!isInterfaceAvailable(address)) {
+                log.warn("Interface of {} is unavailable, torrent connections will fail until it becomes available", address.getHostAddress());
+            }
+
+            log.debug("Binding torrent traffic to {}", address.getHostAddress());
+            settings.setString(settings_pack.string_types.outgoing_interfaces.swigValue(), address.getHostAddress());
+        } else {
+            log.debug("Binding torrent traffic to any interface");
+            settings.setString(settings_pack.string_types.outgoing_interfaces.swigValue(), "");
+        }
+
+        this.bindAddress = address;
+        settings.listenInterfaces(listenInterfaces(bindAddress, dualStack));
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService dualStack(boolean dualStack) {
+        log.debug("Updating torrent dual stack listening to {}", dualStack);
+        this.dualStack = dualStack;
+        settings.listenInterfaces(listenInterfaces(bindAddress, dualStack));
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService encryptionMode(EncryptionMode encryptionMode) {
+        Objects.requireNonNull(encryptionMode, "encryptionMode cannot be null");
+        // prefer falls back to plaintext for peers without encryption support, require rejects them
+        var policy = switch (encryptionMode) {
+            case PREFER -> settings_pack.enc_policy.pe_enabled;
+            case REQUIRE -> settings_pack.enc_policy.pe_forced;
+            case DISABLE -> settings_pack.enc_policy.pe_disabled;
+        };
+        // a required encryption should encrypt the whole stream and not only the handshake
+        var level = encryptionMode == EncryptionMode.REQUIRE ? settings_pack.enc_level.pe_rc4 : settings_pack.enc_level.pe_both;
+
+        log.debug("Updating torrent peer encryption mode to {}", encryptionMode);
+        settings.setInteger(settings_pack.int_types.out_enc_policy.swigValue(), policy.swigValue());
+        settings.setInteger(settings_pack.int_types.in_enc_policy.swigValue(), policy.swigValue());
+        settings.setInteger(settings_pack.int_types.allowed_enc_level.swigValue(), level.swigValue());
+        settings.setBoolean(settings_pack.bool_types.prefer_rc4.swigValue(), encryptionMode != EncryptionMode.DISABLE);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService peerConnectTimeout(int peerConnectTimeout) {
+        var timeout = clampPeerConnectTimeout(peerConnectTimeout);
+
+        log.debug("Updating torrent peer connect timeout to {} seconds", timeout);
+        settings.setInteger(settings_pack.int_types.peer_connect_timeout.swigValue(), timeout);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService announceToAllTrackers(boolean announceToAllTrackers) {
+        log.debug("Updating torrent announce to all trackers to {}", announceToAllTrackers);
+        // the trackers are announced in parallel, each tracker still respects its own announce interval
+        settings.setBoolean(settings_pack.bool_types.announce_to_all_trackers.swigValue(), announceToAllTrackers);
+        settings.setBoolean(settings_pack.bool_types.announce_to_all_tiers.swigValue(), announceToAllTrackers);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService requestTimeout(int requestTimeout) {
+        var timeout = Math.max(MIN_REQUEST_TIMEOUT, requestTimeout);
+
+        log.debug("Updating torrent block request timeout to {} seconds", timeout);
+        // timed out blocks are re-requested from other peers, the unresponsive peer is snubbed
+        settings.setInteger(settings_pack.int_types.request_timeout.swigValue(), timeout);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService maxMetadataSize(int maxMetadataSize) {
+        var size = clampMaxMetadataSize(maxMetadataSize);
+
+        log.debug("Updating torrent max metadata size to {} bytes", size);
+        // peers advertising a larger metadata size are rejected and the metadata is requested from other peers
+        settings.setInteger(settings_pack.int_types.max_metadata_size.swigValue(), size);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService maxRequestQueueDepth(int maxRequestQueueDepth) {
+        var depth = Math.max(MIN_MAX_REQUEST_QUEUE_DEPTH, Math.min(MAX_MAX_REQUEST_QUEUE_DEPTH, maxRequestQueueDepth));
+
+        log.debug("Updating torrent max request queue depth to {}", depth);
+        // the queue of each peer is sized from its download rate and the request queue time, bounded by this max
+        settings.setInteger(settings_pack.int_types.max_out_request_queue.swigValue(), depth);
+        applySettings();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService ipFilter(Path path) {
+        if (path != null) {
+            try {
+                ipFilter = parseIpFilter(Files.readAllLines(path));
+                log.info("Loaded {} blocked ip ranges from {}", ipFilter.size(), path);
+            } catch (IOException ex) {
+                // the current ranges are kept, as clearing them would allow the blocked peers to connect
+                log.error("Failed to read ip filter {}, {}", path, ex.getMessage(), ex);
+            }
+        } else {
+            log.debug("Clearing the torrent ip filter");
+            ipFilter = Collections.emptyList();
+        }
+
+        applyIpFilter();
+        return this;
+    }
+
+    @Override
+    public TorrentSettingsService preallocate(boolean preallocate) {
+        // the storage mode is applied when a torrent is added, existing torrents keep their current storage mode
+        log.debug("Updating torrent file preallocation to {}", preallocate);
+        this.preallocate = preallocate;
+        return this;
+    }
+
+    @Override
+    public boolean isPreallocate() {
+        return preallocate;
+    }
+
+    @Override
+    public TorrentSettingsService dhtReadOnly(boolean dhtReadOnly) {
+        log.debug("Updating torrent DHT read-only mode to {}", dhtReadOnly);
+        this.dhtReadOnly = dhtReadOnly;
+        applyDhtSettings();
+        return this;
+    }
+
     //endregion
 
     //region PostConstruct
@@ -56,16 +234,105 @@ public class TorrentSettingsServiceImpl implements TorrentSettingsService {
     //region Functions
 
     private void applySettings() {
+        // the settings are applied to the session once it's running
+        if (sessionManager.getState() != SessionState.RUNNING) {
+            log.trace("Torrent session is not running, settings will be applied on start");
+            return;
+        }
+
         sessionManager
                 .getSession()
                 .applySettings(settings);
     }
 
+    private void applyIpFilter() {
+        if (sessionManager.getState() != SessionState.RUNNING) {
+            log.trace("Torrent session is not running, ip filter will be applied on start");
+            return;
+        }
+
+        // the ip filter is applied to both the inbound and outbound peer connections, including the DHT peers
+        var filter = new ip_filter();
+        var error = new error_code();
+        for (var range : ipFilter) {
+            filter.add_rule(
+                    address.from_string(range.first().getHostAddress(), error),
+                    address.from_string(range.last().getHostAddress(), error),
+                    ip_filter.access_flags.blocked.swigValue());
+        }
+
+        sessionManager
+                .getSession()
+                .swig()
+                .set_ip_filter(filter);
+    }
+
+    private void applyDhtSettings() {
+        if (sessionManager.getState() != SessionState.RUNNING) {
+            log.trace("Torrent session is not running, DHT settings will be applied on start");
+            return;
+        }
+
+        // a read-only node still sends its own get_peers and announce queries, with the "ro" flag set
+        var session = sessionManager.getSession().swig();
+        var dhtSettings = session.get_dht_settings();
+        dhtSettings.setRead_only(dhtReadOnly);
+        session.set_dht_settings(dhtSettings);
+    }
+
+    static List<IpRange> parseIpFilter(List<String> lines) {
+        var ranges = new ArrayList<IpRange>();
+
+        for (int i = 0; i < lines.size(); i++) {
+            var line = lines.get(i).trim();
+            if (line.isEmpty() || line.startsWith("#")) {
+                continue;
+            }
+
+            try {
+                ranges.add(IpRange.parse(line));
+            } catch (IllegalArgumentException ex) {
+                log.warn("Skipping invalid ip filter line {}, {}", i + 1, ex.getMessage());
+            }
+        }
+
+        return ranges;
+    }
+
+    static String listenInterfaces(InetAddress bindAddress, boolean dualStack) {
+        if (bindAddress != null) {
+            var host = bindAddress instanceof Inet6Address ? "[" + bindAddress.getHostAddress() + "]" : bindAddress.getHostAddress();
+            return host + ":" + LISTEN_PORT;
+        }
+
+        // the DHT runs on each listen socket, an IPv6 socket also runs the IPv6 DHT with the v6 bootstrap nodes
+        return dualStack ? IPV4_LISTEN_INTERFACE + "," + IPV6_LISTEN_INTERFACE : IPV4_LISTEN_INTERFACE;
+    }
+
+    static int clampPeerConnectTimeout(int peerConnectTimeout) {
+        return Math.max(MIN_PEER_CONNECT_TIMEOUT, Math.min(MAX_PEER_CONNECT_TIMEOUT, peerConnectTimeout));
+    }
+
+    static int clampMaxMetadataSize(int maxMetadataSize) {
+        return Math.max(MIN_MAX_METADATA_SIZE, Math.min(MAX_MAX_METADATA_SIZE, maxMetadataSize));
+    }
+
+    static boolean isInterfaceAvailable(InetAddress address) {
+        try {
+            return NetworkInterface.getByInetAddress(address) != null;
+        } catch (SocketException ex) {
+            log.warn("Failed to verify the interface of {}, {}", address, ex.getMessage(), ex);
+            return false;
+        }
+    }
+
     private ChangeListener<SessionState> createSessionListener() {
         return (observable, oldValue, newValue) -> {
             if (newValue == SessionState.RUNNING) {
                 // apply the default settings
                 applySettings();
+                applyIpFilter();
+                applyDhtSettings();
             }
         };
     }
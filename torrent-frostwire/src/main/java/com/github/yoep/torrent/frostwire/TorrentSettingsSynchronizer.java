@@ -0,0 +1,81 @@
+package com.github.yoep.torrent.frostwire;
+
+import com.github.yoep.popcorn.backend.adapters.torrent.TorrentSettingsService;
+import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
+import com.github.yoep.popcorn.backend.settings.ApplicationConfigEvent;
+import com.github.yoep.popcorn.backend.settings.models.TorrentSettings;
+import lombok.extern.slf4j.Slf4j;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.nio.file.Path;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * Synchronizes the torrent settings of the application with the torrent session settings.
+ * The settings are applied on startup and each time the torrent settings of the application are changed.
+ */
+@Slf4j
+public class TorrentSettingsSynchronizer {
+    private final ApplicationConfig applicationConfig;
+    private final TorrentSettingsService torrentSettingsService;
+
+    public TorrentSettingsSynchronizer(ApplicationConfig applicationConfig, TorrentSettingsService torrentSettingsService) {
+        Objects.requireNonNull(applicationConfig, "applicationConfig cannot be null");
+        Objects.requireNonNull(torrentSettingsService, "torrentSettingsService cannot be null");
+        this.applicationConfig = applicationConfig;
+        this.torrentSettingsService = torrentSettingsService;
+        init();
+    }
+
+    //region PostConstruct
+
+    private void init() {
+        onTorrentSettingsChanged(applicationConfig.getSettings().getTorrentSettings());
+        applicationConfig.register(event -> {
+            if (event.getTag() == ApplicationConfigEvent.Tag.TORRENT_SETTINGS_CHANGED) {
+                onTorrentSettingsChanged(event.getUnion().getTorrentSettingsChanged_body().getSettings());
+            }
+        });
+    }
+
+    //endregion
+
+    //region Functions
+
+    private void onTorrentSettingsChanged(TorrentSettings settings) {
+        log.debug("Applying torrent settings {}", settings);
+        torrentSettingsService.dualStack(settings.getDualStack() == 1);
+        updateBindInterface(settings.getBindInterface());
+        Optional.ofNullable(settings.getEncryptionMode())
+                .ifPresent(torrentSettingsService::encryptionMode);
+        torrentSettingsService.peerConnectTimeout(settings.getPeerConnectTimeout());
+        torrentSettingsService.announceToAllTrackers(settings.getAnnounceToAllTrackers() == 1);
+        torrentSettingsService.requestTimeout(settings.getRequestTimeout());
+        torrentSettingsService.ipFilter(Optional.ofNullable(settings.getIpFilter())
+                .filter(e -> !e.isBlank())
+                .map(Path::of)
+                .orElse(null));
+        torrentSettingsService.preallocate(settings.getPreallocate() == 1);
+        torrentSettingsService.dhtReadOnly(settings.getDhtReadOnly() == 1);
+        torrentSettingsService.maxMetadataSize(settings.getMaxMetadataSize());
+        torrentSettingsService.maxRequestQueueDepth(settings.getMaxRequestQueueDepth());
+    }
+
+    private void updateBindInterface(String bindInterface) {
+        if (bindInterface == null || bindInterface.isBlank()) {
+            torrentSettingsService.bindInterface(null);
+            return;
+        }
+
+        try {
+            torrentSettingsService.bindInterface(InetAddress.getByName(bindInterface));
+        } catch (UnknownHostException ex) {
+            // keep the current binding as falling back to any interface would leak the traffic
+            log.error("Failed to parse torrent bind interface {}, {}", bindInterface, ex.getMessage(), ex);
+        }
+    }
+
+    //endregion
+}
@@ -17,8 +17,10 @@ import lombok.Builder;
  *                      to download. i.e. excluding any pieces that we have but have priority 0 (i.e. not wanted).
  * @param totalSize     The total number of bytes we want to download. This may be smaller than the total
  *                      torrent size in case any pieces are prioritized to 0, i.e. not wanted.
+ * @param requestQueueDepth The average number of outstanding block requests which are pipelined to each
+ *                      connected peer. It adapts to the download rate of the peers.
  */
 @Builder
-public record FrostDownloadStatus(float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long totalSize)
+public record FrostDownloadStatus(float progress, int seeds, int peers, int downloadSpeed, int uploadSpeed, long downloaded, long totalSize, int requestQueueDepth)
         implements DownloadStatus {
 }
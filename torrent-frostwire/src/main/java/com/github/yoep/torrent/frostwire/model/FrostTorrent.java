@@ -5,6 +5,7 @@ import com.frostwire.jlibtorrent.alerts.Alert;
 import com.frostwire.jlibtorrent.alerts.AlertType;
 import com.frostwire.jlibtorrent.alerts.MetadataFailedAlert;
 import com.frostwire.jlibtorrent.alerts.PieceFinishedAlert;
+import com.frostwire.jlibtorrent.swig.peer_info_vector;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentException;
 import com.github.yoep.popcorn.backend.adapters.torrent.listeners.TorrentListener;
 import com.github.yoep.popcorn.backend.adapters.torrent.model.Torrent;
@@ -75,6 +76,11 @@ public class FrostTorrent implements Torrent, AlertListener {
         return filename;
     }
 
+    @Override
+    public String getInfoHash() {
+        return handle.infoHash().toHex();
+    }
+
     @Override
     public File getFile() {
         if (!handle.isValid()) {
@@ -369,6 +375,7 @@ public class FrostTorrent implements Torrent, AlertListener {
                 .peers(status.numPeers())
                 .downloaded(status.totalWantedDone())
                 .totalSize(status.totalWanted())
+                .requestQueueDepth(requestQueueDepth())
                 .build();
 
         // check if the torrent state is finished
@@ -382,6 +389,21 @@ public class FrostTorrent implements Torrent, AlertListener {
         }
     }
 
+    private int requestQueueDepth() {
+        var peers = new peer_info_vector();
+        handle.swig().get_peer_info(peers);
+        if (peers.empty())
+            return 0;
+
+        // the target queue length of a peer is derived from its download rate and bounded by the max out request queue
+        var total = 0L;
+        for (int i = 0; i < peers.size(); i++) {
+            total += peers.get(i).getTarget_dl_queue_length();
+        }
+
+        return (int) (total / peers.size());
+    }
+
     private void safeInvoke(Runnable runnable) {
         try {
             runnable.run();
@@ -0,0 +1,129 @@
+package com.github.yoep.torrent.frostwire.model;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.util.Arrays;
+import java.util.Objects;
+import java.util.regex.Pattern;
+
+/**
+ * An inclusive range of ip addresses, used to block peers within the torrent session.
+ *
+ * @param first The first address of the range.
+ * @param last  The last address of the range.
+ */
+public record IpRange(InetAddress first, InetAddress last) {
+    private static final Pattern IPV4_PATTERN = Pattern.compile("^(\\d{1,3})\\.(\\d{1,3})\\.(\\d{1,3})\\.(\\d{1,3})$");
+    private static final Pattern P2P_PATTERN = Pattern.compile("^.*:(\\d{1,3}(?:\\.\\d{1,3}){3})\\s*-\\s*(\\d{1,3}(?:\\.\\d{1,3}){3})$");
+
+    public IpRange {
+        Objects.requireNonNull(first, "first cannot be null");
+        Objects.requireNonNull(last, "last cannot be null");
+    }
+
+    /**
+     * Parse the given ip range value.
+     * The value can be a single address, a CIDR (e.g. {@code 10.0.0.0/8}), a range (e.g. {@code 10.0.0.1-10.0.0.255})
+     * or a P2P formatted range (e.g. {@code Some organization:10.0.0.1-10.0.0.255}).
+     *
+     * @param value The ip range value to parse.
+     * @return Returns the parsed ip range.
+     * @throws IllegalArgumentException Is thrown when the value is not a valid ip range.
+     */
+    public static IpRange parse(String value) {
+        Objects.requireNonNull(value, "value cannot be null");
+        var range = value.trim();
+        var p2pMatcher = P2P_PATTERN.matcher(range);
+
+        if (p2pMatcher.matches()) {
+            return of(parseAddress(p2pMatcher.group(1)), parseAddress(p2pMatcher.group(2)));
+        }
+        if (range.contains("/")) {
+            return parseCidr(range);
+        }
+        if (range.contains("-")) {
+            var separator = range.indexOf('-');
+            return of(parseAddress(range.substring(0, separator)), parseAddress(range.substring(separator + 1)));
+        }
+
+        var address = parseAddress(range);
+        return new IpRange(address, address);
+    }
+
+    private static IpRange of(InetAddress first, InetAddress last) {
+        var firstBytes = first.getAddress();
+        var lastBytes = last.getAddress();
+
+        if (firstBytes.length != lastBytes.length) {
+            throw new IllegalArgumentException("range addresses " + first.getHostAddress() + " and " + last.getHostAddress() + " are of a different family");
+        }
+        if (Arrays.compareUnsigned(firstBytes, lastBytes) > 0) {
+            throw new IllegalArgumentException("range start " + first.getHostAddress() + " is after the range end " + last.getHostAddress());
+        }
+
+        return new IpRange(first, last);
+    }
+
+    private static IpRange parseCidr(String value) {
+        var separator = value.indexOf('/');
+        var address = parseAddress(value.substring(0, separator));
+        var bytes = address.getAddress();
+        int prefixLength;
+
+        try {
+            prefixLength = Integer.parseInt(value.substring(separator + 1).trim());
+        } catch (NumberFormatException ex) {
+            throw new IllegalArgumentException("invalid CIDR prefix length in " + value, ex);
+        }
+        if (prefixLength < 0 || prefixLength > bytes.length * 8) {
+            throw new IllegalArgumentException("invalid CIDR prefix length in " + value);
+        }
+
+        var first = bytes.clone();
+        var last = bytes.clone();
+        for (int i = 0; i < bytes.length; i++) {
+            var bits = Math.max(0, Math.min(8, prefixLength - i * 8));
+            var mask = (byte) (0xFF << (8 - bits));
+            first[i] = (byte) (bytes[i] & mask);
+            last[i] = (byte) (bytes[i] | ~mask);
+        }
+
+        return new IpRange(toAddress(first), toAddress(last));
+    }
+
+    private static InetAddress parseAddress(String value) {
+        var address = value.trim();
+        var ipv4Matcher = IPV4_PATTERN.matcher(address);
+
+        // the address is parsed without InetAddress.getByName for IPv4, as it would resolve invalid addresses as a hostname
+        if (ipv4Matcher.matches()) {
+            var bytes = new byte[4];
+            for (int i = 0; i < bytes.length; i++) {
+                var octet = Integer.parseInt(ipv4Matcher.group(i + 1));
+                if (octet > 255) {
+                    throw new IllegalArgumentException("invalid IPv4 address " + address);
+                }
+                bytes[i] = (byte) octet;
+            }
+            return toAddress(bytes);
+        }
+        // an IPv6 address always contains a colon, which is never resolved as a hostname
+        if (address.contains(":")) {
+            try {
+                return InetAddress.getByName(address);
+            } catch (UnknownHostException ex) {
+                throw new IllegalArgumentException("invalid IPv6 address " + address, ex);
+            }
+        }
+
+        throw new IllegalArgumentException("invalid ip address " + address);
+    }
+
+    private static InetAddress toAddress(byte[] bytes) {
+        try {
+            return InetAddress.getByAddress(bytes);
+        } catch (UnknownHostException ex) {
+            throw new IllegalArgumentException("invalid ip address length " + bytes.length, ex);
+        }
+    }
+}
@@ -2,6 +2,7 @@ package com.github.yoep.torrent.frostwire;
 
 import com.github.yoep.popcorn.backend.FxLib;
 import com.github.yoep.popcorn.backend.PopcornFx;
+import com.github.yoep.popcorn.backend.adapters.torrent.TorrentSettingsService;
 import com.github.yoep.popcorn.backend.adapters.torrent.TorrentStreamListener;
 import com.github.yoep.popcorn.backend.adapters.torrent.state.TorrentHealthState;
 import com.github.yoep.popcorn.backend.lib.Handle;
@@ -27,6 +28,8 @@ class TorrentServiceImplTest {
     @Mock
     private TorrentResolverService torrentResolverService;
     @Mock
+    private TorrentSettingsService torrentSettingsService;
+    @Mock
     private FxLib fxLib;
     @Mock
     private PopcornFx instance;
@@ -97,6 +100,29 @@ class TorrentServiceImplTest {
         verify(fxLib).cleanup_torrents_directory(instance);
     }
 
+    @Test
+    void testPauseAll() {
+        service.pauseAll();
+
+        verify(fxLib).pause_all_torrents(instance);
+    }
+
+    @Test
+    void testResumeAll() {
+        service.resumeAll();
+
+        verify(fxLib).resume_all_torrents(instance);
+    }
+
+    @Test
+    void testIsPaused() {
+        when(fxLib.is_torrents_paused(instance)).thenReturn((byte) 1);
+
+        var result = service.isPaused();
+
+        assertEquals(true, result);
+    }
+
     @Test
     void testAddStreamListener() {
         var callbackHandle = 8566L;
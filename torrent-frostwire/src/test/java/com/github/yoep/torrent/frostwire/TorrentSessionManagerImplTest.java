@@ -0,0 +1,47 @@
+package com.github.yoep.torrent.frostwire;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.Optional;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TorrentSessionManagerImplTest {
+    @TempDir
+    Path workingDir;
+
+    @Test
+    void testReadDhtState_whenFileDoesNotExist_shouldReturnEmpty() {
+        var result = TorrentSessionManagerImpl.readDhtState(workingDir.resolve(TorrentSessionManagerImpl.DHT_STATE_FILENAME));
+
+        assertEquals(Optional.empty(), result);
+    }
+
+    @Test
+    void testReadDhtState_whenFileIsEmpty_shouldReturnEmpty() throws IOException {
+        var file = workingDir.resolve(TorrentSessionManagerImpl.DHT_STATE_FILENAME);
+        Files.write(file, new byte[0]);
+
+        var result = TorrentSessionManagerImpl.readDhtState(file);
+
+        assertEquals(Optional.empty(), result);
+    }
+
+    @Test
+    void testWriteDhtState_shouldStoreTheStateForTheNextStartup() throws IOException {
+        var file = workingDir.resolve("lorem").resolve(TorrentSessionManagerImpl.DHT_STATE_FILENAME);
+        var state = "d3:dhtd5:nodes6:abcdefee".getBytes();
+
+        TorrentSessionManagerImpl.writeDhtState(file, state);
+        var result = TorrentSessionManagerImpl.readDhtState(file);
+
+        assertTrue(result.isPresent(), "expected the DHT state to have been stored");
+        assertArrayEquals(state, result.get());
+    }
+}
@@ -0,0 +1,83 @@
+package com.github.yoep.torrent.frostwire;
+
+import com.github.yoep.torrent.frostwire.model.IpRange;
+import org.junit.jupiter.api.Test;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TorrentSettingsServiceImplTest {
+    @Test
+    void testIsInterfaceAvailable_whenAddressIsLoopback_shouldReturnTrue() {
+        var result = TorrentSettingsServiceImpl.isInterfaceAvailable(InetAddress.getLoopbackAddress());
+
+        assertTrue(result, "expected the loopback interface to be available");
+    }
+
+    @Test
+    void testIsInterfaceAvailable_whenAddressIsNotLocal_shouldReturnFalse() throws UnknownHostException {
+        var address = InetAddress.getByName("203.0.113.10");
+
+        var result = TorrentSettingsServiceImpl.isInterfaceAvailable(address);
+
+        assertFalse(result, "expected the interface to be unavailable");
+    }
+
+    @Test
+    void testParseIpFilter_whenLineIsInvalid_shouldSkipTheLine() throws UnknownHostException {
+        var lines = List.of(
+                "# blocked ranges",
+                "10.0.0.0/8",
+                "lorem ipsum",
+                "",
+                "Some organization:192.168.1.1-192.168.1.255",
+                "300.0.0.1-300.0.0.2");
+
+        var result = TorrentSettingsServiceImpl.parseIpFilter(lines);
+
+        assertEquals(List.of(
+                new IpRange(InetAddress.getByName("10.0.0.0"), InetAddress.getByName("10.255.255.255")),
+                new IpRange(InetAddress.getByName("192.168.1.1"), InetAddress.getByName("192.168.1.255"))
+        ), result);
+    }
+
+    @Test
+    void testListenInterfaces_whenDualStackIsEnabled_shouldListenOnIpv4AndIpv6() {
+        var result = TorrentSettingsServiceImpl.listenInterfaces(null, true);
+
+        assertEquals("0.0.0.0:6881,[::]:6881", result);
+    }
+
+    @Test
+    void testListenInterfaces_whenDualStackIsDisabled_shouldOnlyListenOnIpv4() {
+        var result = TorrentSettingsServiceImpl.listenInterfaces(null, false);
+
+        assertEquals("0.0.0.0:6881", result);
+    }
+
+    @Test
+    void testListenInterfaces_whenBindAddressIsIpv6_shouldOnlyListenOnTheAddress() throws UnknownHostException {
+        var result = TorrentSettingsServiceImpl.listenInterfaces(InetAddress.getByName("fd00::2"), true);
+
+        assertEquals("[fd00:0:0:0:0:0:0:2]:6881", result);
+    }
+
+    @Test
+    void testClampPeerConnectTimeout() {
+        assertEquals(30, TorrentSettingsServiceImpl.clampPeerConnectTimeout(30));
+        assertEquals(TorrentSettingsServiceImpl.MIN_PEER_CONNECT_TIMEOUT, TorrentSettingsServiceImpl.clampPeerConnectTimeout(0));
+        assertEquals(TorrentSettingsServiceImpl.MAX_PEER_CONNECT_TIMEOUT, TorrentSettingsServiceImpl.clampPeerConnectTimeout(3600));
+    }
+
+    @Test
+    void testClampMaxMetadataSize() {
+        assertEquals(20 * 1024 * 1024, TorrentSettingsServiceImpl.clampMaxMetadataSize(20 * 1024 * 1024));
+        assertEquals(TorrentSettingsServiceImpl.MIN_MAX_METADATA_SIZE, TorrentSettingsServiceImpl.clampMaxMetadataSize(0));
+        assertEquals(TorrentSettingsServiceImpl.MAX_MAX_METADATA_SIZE, TorrentSettingsServiceImpl.clampMaxMetadataSize(Integer.MAX_VALUE));
+    }
+}
@@ -0,0 +1,216 @@
+package com.github.yoep.torrent.frostwire;
+
+import com.github.yoep.popcorn.backend.adapters.torrent.TorrentSettingsService;
+import com.github.yoep.popcorn.backend.settings.ApplicationConfig;
+import com.github.yoep.popcorn.backend.settings.ApplicationConfigEvent;
+import com.github.yoep.popcorn.backend.settings.ApplicationConfigEventCallback;
+import com.github.yoep.popcorn.backend.settings.models.ApplicationSettings;
+import com.github.yoep.popcorn.backend.settings.models.EncryptionMode;
+import com.github.yoep.popcorn.backend.settings.models.TorrentSettings;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.extension.ExtendWith;
+import org.mockito.Mock;
+import org.mockito.junit.jupiter.MockitoExtension;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.nio.file.Path;
+import java.util.concurrent.atomic.AtomicReference;
+
+import static org.mockito.ArgumentMatchers.isA;
+import static org.mockito.Mockito.*;
+
+@ExtendWith(MockitoExtension.class)
+class TorrentSettingsSynchronizerTest {
+    @Mock
+    private ApplicationConfig applicationConfig;
+    @Mock
+    private TorrentSettingsService torrentSettingsService;
+    @Mock
+    private ApplicationSettings settings;
+
+    private final TorrentSettings torrentSettings = new TorrentSettings();
+    private final AtomicReference<ApplicationConfigEventCallback> callbackHolder = new AtomicReference<>();
+
+    @BeforeEach
+    void setUp() {
+        lenient().when(applicationConfig.getSettings()).thenReturn(settings);
+        lenient().when(settings.getTorrentSettings()).thenReturn(torrentSettings);
+        lenient().doAnswer(invocation -> {
+            callbackHolder.set(invocation.getArgument(0, ApplicationConfigEventCallback.class));
+            return null;
+        }).when(applicationConfig).register(isA(ApplicationConfigEventCallback.class));
+    }
+
+    @Test
+    void testInit_whenBindInterfaceIsSet_shouldBindTheSessionToTheAddress() throws UnknownHostException {
+        torrentSettings.bindInterface = "10.8.0.2";
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).bindInterface(InetAddress.getByName("10.8.0.2"));
+    }
+
+    @Test
+    void testInit_whenBindInterfaceIsNotSet_shouldBindTheSessionToAnyInterface() {
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).bindInterface(null);
+    }
+
+    @Test
+    void testOnTorrentSettingsChanged_whenBindInterfaceIsChanged_shouldBindTheSessionToTheNewAddress() throws UnknownHostException {
+        var changedSettings = new TorrentSettings();
+        changedSettings.bindInterface = "fd00::2";
+        var event = new ApplicationConfigEvent.ByValue();
+        event.tag = ApplicationConfigEvent.Tag.TORRENT_SETTINGS_CHANGED;
+        event.union = new ApplicationConfigEvent.ApplicationConfigEventUnion.ByValue();
+        event.union.torrentSettingsChanged_body = new ApplicationConfigEvent.TorrentSettingsChanged_Body();
+        event.union.torrentSettingsChanged_body.settings = changedSettings;
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        callbackHolder.get().callback(event);
+
+        verify(torrentSettingsService).bindInterface(InetAddress.getByName("fd00::2"));
+    }
+
+    @Test
+    void testInit_whenEncryptionModeIsRequire_shouldRequireEncryptedPeerConnections() {
+        torrentSettings.encryptionMode = EncryptionMode.REQUIRE;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).encryptionMode(EncryptionMode.REQUIRE);
+    }
+
+    @Test
+    void testOnTorrentSettingsChanged_whenEncryptionModeIsChanged_shouldUpdateTheEncryptionMode() {
+        torrentSettings.encryptionMode = EncryptionMode.PREFER;
+        var changedSettings = new TorrentSettings();
+        changedSettings.encryptionMode = EncryptionMode.DISABLE;
+        var event = new ApplicationConfigEvent.ByValue();
+        event.tag = ApplicationConfigEvent.Tag.TORRENT_SETTINGS_CHANGED;
+        event.union = new ApplicationConfigEvent.ApplicationConfigEventUnion.ByValue();
+        event.union.torrentSettingsChanged_body = new ApplicationConfigEvent.TorrentSettingsChanged_Body();
+        event.union.torrentSettingsChanged_body.settings = changedSettings;
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        callbackHolder.get().callback(event);
+
+        verify(torrentSettingsService).encryptionMode(EncryptionMode.PREFER);
+        verify(torrentSettingsService).encryptionMode(EncryptionMode.DISABLE);
+    }
+
+    @Test
+    void testInit_whenPeerConnectTimeoutIsSet_shouldUpdateThePeerConnectTimeout() {
+        torrentSettings.peerConnectTimeout = 30;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).peerConnectTimeout(30);
+    }
+
+    @Test
+    void testInit_whenAnnounceToAllTrackersIsEnabled_shouldAnnounceToAllTrackers() {
+        torrentSettings.announceToAllTrackers = 1;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).announceToAllTrackers(true);
+    }
+
+    @Test
+    void testInit_whenRequestTimeoutIsSet_shouldUpdateTheRequestTimeout() {
+        torrentSettings.requestTimeout = 10;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).requestTimeout(10);
+    }
+
+    @Test
+    void testInit_whenIpFilterIsSet_shouldUpdateTheIpFilter() {
+        torrentSettings.ipFilter = "/tmp/blocklist.p2p";
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).ipFilter(Path.of("/tmp/blocklist.p2p"));
+    }
+
+    @Test
+    void testInit_whenIpFilterIsNotSet_shouldClearTheIpFilter() {
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).ipFilter(null);
+    }
+
+    @Test
+    void testInit_whenPreallocateIsEnabled_shouldPreallocateTheTorrentFiles() {
+        torrentSettings.preallocate = 1;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).preallocate(true);
+    }
+
+    @Test
+    void testOnTorrentSettingsChanged_whenPreallocateIsDisabled_shouldUseSparseTorrentFiles() {
+        torrentSettings.preallocate = 1;
+        var changedSettings = new TorrentSettings();
+        var event = new ApplicationConfigEvent.ByValue();
+        event.tag = ApplicationConfigEvent.Tag.TORRENT_SETTINGS_CHANGED;
+        event.union = new ApplicationConfigEvent.ApplicationConfigEventUnion.ByValue();
+        event.union.torrentSettingsChanged_body = new ApplicationConfigEvent.TorrentSettingsChanged_Body();
+        event.union.torrentSettingsChanged_body.settings = changedSettings;
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        callbackHolder.get().callback(event);
+
+        verify(torrentSettingsService).preallocate(true);
+        verify(torrentSettingsService).preallocate(false);
+    }
+
+    @Test
+    void testInit_whenDhtReadOnlyIsEnabled_shouldRunTheDhtInReadOnlyMode() {
+        torrentSettings.dhtReadOnly = 1;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).dhtReadOnly(true);
+    }
+
+    @Test
+    void testInit_whenDhtReadOnlyIsDisabled_shouldParticipateInTheDht() {
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).dhtReadOnly(false);
+    }
+
+    @Test
+    void testInit_whenMaxMetadataSizeIsOversized_shouldApplyTheMaxMetadataSize() {
+        torrentSettings.maxMetadataSize = Integer.MAX_VALUE;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).maxMetadataSize(Integer.MAX_VALUE);
+    }
+
+    @Test
+    void testInit_whenMaxRequestQueueDepthIsSet_shouldUpdateTheMaxRequestQueueDepth() {
+        torrentSettings.maxRequestQueueDepth = 1000;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).maxRequestQueueDepth(1000);
+    }
+
+    @Test
+    void testInit_whenDualStackIsEnabled_shouldListenOnIpv4AndIpv6() {
+        torrentSettings.dualStack = 1;
+
+        new TorrentSettingsSynchronizer(applicationConfig, torrentSettingsService);
+
+        verify(torrentSettingsService).dualStack(true);
+    }
+}
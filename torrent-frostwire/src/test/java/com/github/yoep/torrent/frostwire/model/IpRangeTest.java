@@ -0,0 +1,68 @@
+package com.github.yoep.torrent.frostwire.model;
+
+import org.junit.jupiter.api.Test;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class IpRangeTest {
+    @Test
+    void testParse_whenValueIsSingleAddress_shouldReturnRangeOfTheAddress() throws UnknownHostException {
+        var address = InetAddress.getByName("10.0.0.1");
+
+        var result = IpRange.parse("10.0.0.1");
+
+        assertEquals(new IpRange(address, address), result);
+    }
+
+    @Test
+    void testParse_whenValueIsCidr_shouldReturnTheNetworkRange() throws UnknownHostException {
+        var expectedResult = new IpRange(InetAddress.getByName("192.168.0.0"), InetAddress.getByName("192.168.15.255"));
+
+        var result = IpRange.parse("192.168.10.20/20");
+
+        assertEquals(expectedResult, result);
+    }
+
+    @Test
+    void testParse_whenValueIsIpv6Cidr_shouldReturnTheNetworkRange() throws UnknownHostException {
+        var expectedResult = new IpRange(InetAddress.getByName("fd00::"), InetAddress.getByName("fd00::ffff:ffff:ffff:ffff"));
+
+        var result = IpRange.parse("fd00::/64");
+
+        assertEquals(expectedResult, result);
+    }
+
+    @Test
+    void testParse_whenValueIsRange_shouldReturnTheRange() throws UnknownHostException {
+        var expectedResult = new IpRange(InetAddress.getByName("10.0.0.1"), InetAddress.getByName("10.0.0.255"));
+
+        var result = IpRange.parse("10.0.0.1 - 10.0.0.255");
+
+        assertEquals(expectedResult, result);
+    }
+
+    @Test
+    void testParse_whenValueIsP2pRange_shouldReturnTheRange() throws UnknownHostException {
+        var expectedResult = new IpRange(InetAddress.getByName("10.0.0.1"), InetAddress.getByName("10.0.0.255"));
+
+        var result = IpRange.parse("Anti-piracy organization:10.0.0.1-10.0.0.255");
+
+        assertEquals(expectedResult, result);
+    }
+
+    @Test
+    void testParse_whenRangeIsReversed_shouldThrowIllegalArgumentException() {
+        assertThrows(IllegalArgumentException.class, () -> IpRange.parse("10.0.0.255-10.0.0.1"));
+    }
+
+    @Test
+    void testParse_whenAddressIsInvalid_shouldThrowIllegalArgumentException() {
+        assertThrows(IllegalArgumentException.class, () -> IpRange.parse("10.0.0.256"));
+        assertThrows(IllegalArgumentException.class, () -> IpRange.parse("lorem"));
+        assertThrows(IllegalArgumentException.class, () -> IpRange.parse("10.0.0.0/33"));
+    }
+}